@@ -79,13 +79,18 @@ from daft.io import (
     from_glob_path,
     read_csv,
     read_deltalake,
+    read_excel,
     read_hudi,
     read_iceberg,
     read_json,
+    read_kafka,
     read_parquet,
     read_sql,
     read_lance,
+    read_stream,
+    read_xml,
 )
+from daft.runners.query_event_log import set_query_event_log
 from daft.series import Series
 from daft.sql import sql, sql_expr
 from daft.udf import udf
@@ -120,18 +125,23 @@ __all__ = [
     "planning_config_ctx",
     "read_csv",
     "read_deltalake",
+    "read_excel",
     "read_hudi",
     "read_iceberg",
     "read_json",
+    "read_kafka",
     "read_lance",
     "read_parquet",
     "read_sql",
+    "read_stream",
     "read_table",
+    "read_xml",
     "refresh_logger",
     "register_table",
     "register_viz_hook",
     "set_execution_config",
     "set_planning_config",
+    "set_query_event_log",
     "sql",
     "sql_expr",
     "to_struct",
@@ -49,18 +49,52 @@ from typing import TYPE_CHECKING
 
 if TYPE_CHECKING:
     from pyiceberg.catalog import Catalog as PyIcebergCatalog
+    from daft.io import IOConfig
     from daft.unity_catalog import UnityCatalog
 
 
 __all__ = [
     "read_table",
+    "register_hive_metastore_catalog",
+    "register_iceberg_rest_catalog",
     "register_python_catalog",
     "register_table",
     "unregister_catalog",
 ]
 
-# Forward imports from the native catalog which don't require Python wrappers
-unregister_catalog = native_catalog.unregister_catalog
+# The PyIceberg catalogs registered with Daft by name, which SQL's `CREATE TABLE` creates Iceberg tables in
+_pyiceberg_catalogs: dict[str, PyIcebergCatalog] = {}
+
+
+def unregister_catalog(catalog_name: str | None) -> bool:
+    """Unregisters a catalog from Daft.
+
+    This function removes a previously registered catalog from the Daft catalog system.
+
+    Args:
+        catalog_name (Optional[str]): The name of the catalog to unregister. If None, the default catalog will be unregistered.
+
+    Returns:
+        bool: True if a catalog was successfully unregistered, False otherwise.
+
+    Example:
+        >>> import daft
+        >>> daft.catalog.unregister_catalog("my_catalog")
+        True
+    """
+    _pyiceberg_catalogs.pop(catalog_name or "default", None)
+    return native_catalog.unregister_catalog(catalog_name)
+
+
+def _get_pyiceberg_catalog(name: str | None) -> PyIcebergCatalog:
+    """The registered PyIceberg catalog with the name, or the default catalog if no name is given."""
+    catalog = _pyiceberg_catalogs.get(name or "default")
+    if catalog is None:
+        raise ValueError(
+            f"No PyIceberg catalog is registered as {name or 'the default catalog'}, "
+            "register one with `daft.catalog.register_python_catalog`"
+        )
+    return catalog
 
 
 def read_table(name: str) -> DataFrame:
@@ -148,6 +182,9 @@ def register_python_catalog(catalog: PyIcebergCatalog | UnityCatalog, name: str
         from daft.catalog.pyiceberg import PyIcebergCatalogAdaptor
 
         python_catalog = PyIcebergCatalogAdaptor(catalog)
+        name = native_catalog.register_python_catalog(python_catalog, name)
+        _pyiceberg_catalogs[name] = catalog
+        return name
     elif _UNITY_AVAILABLE and isinstance(catalog, UnityCatalog):
         from daft.catalog.unity import UnityCatalogAdaptor
 
@@ -156,3 +193,59 @@ def register_python_catalog(catalog: PyIcebergCatalog | UnityCatalog, name: str
         raise ValueError(f"Unsupported catalog type: {type(catalog)}")
 
     return native_catalog.register_python_catalog(python_catalog, name)
+
+
+def register_iceberg_rest_catalog(
+    uri: str,
+    warehouse: str | None = None,
+    token: str | None = None,
+    name: str | None = None,
+    io_config: IOConfig | None = None,
+) -> str:
+    """Registers an Iceberg REST catalog with Daft, using Daft's native client instead of PyIceberg.
+
+    Tables are read from the Parquet data files of their current snapshot. Tables with delete files are not supported.
+    The tables of the catalog can also be queried by name with :func:`daft.sql` and over Spark Connect.
+
+    Args:
+        uri (str): Base uri of the REST catalog, e.g. `"http://localhost:8181"`.
+        warehouse (str | None, optional): Warehouse to request from the catalog, if it serves several.
+        token (str | None, optional): Bearer token used to authenticate with the catalog.
+        name (str | None, optional): The name to register the catalog under. If None, this catalog is registered as the default catalog.
+        io_config (IOConfig | None, optional): IOConfig used to read the tables' files.
+
+    Returns:
+        str: The name of the registered catalog.
+
+    Example:
+        >>> daft.catalog.register_iceberg_rest_catalog("http://localhost:8181", name="lake")
+        >>> daft.catalog.read_table("lake.my_namespace.my_table")
+        >>> daft.sql("SELECT * FROM lake.my_namespace.my_table")
+
+    """
+    return native_catalog.register_iceberg_rest_catalog(uri, warehouse, token, name, io_config)
+
+
+def register_hive_metastore_catalog(uri: str, name: str | None = None, io_config: IOConfig | None = None) -> str:
+    """Registers a Hive Metastore with Daft, resolving tables' locations, formats and partitions over its thrift API.
+
+    Parquet, JSON and delimited text tables are supported. Requires the `thrift` package and the `hive_metastore`
+    bindings, which are installed with `pyiceberg[hive]`.
+
+    Args:
+        uri (str): Uri of the metastore, e.g. `"thrift://localhost:9083"`.
+        name (str | None, optional): The name to register the catalog under. If None, this catalog is registered as the default catalog.
+        io_config (IOConfig | None, optional): IOConfig used to read the tables' files.
+
+    Returns:
+        str: The name of the registered catalog.
+
+    Example:
+        >>> daft.catalog.register_hive_metastore_catalog("thrift://localhost:9083", name="hive")
+        >>> daft.catalog.read_table("hive.my_db.my_table")
+
+    """
+    from daft.catalog.hive import HiveMetastoreCatalog, HiveMetastoreCatalogAdaptor
+
+    python_catalog = HiveMetastoreCatalogAdaptor(HiveMetastoreCatalog(uri, io_config=io_config))
+    return native_catalog.register_python_catalog(python_catalog, name)
@@ -0,0 +1,312 @@
+from __future__ import annotations
+
+import dataclasses
+import re
+from typing import TYPE_CHECKING
+from urllib.parse import urlparse
+
+from daft.catalog.python_catalog import PythonCatalog, PythonCatalogTable
+from daft.datatype import DataType, TimeUnit
+
+if TYPE_CHECKING:
+    from daft.dataframe import DataFrame
+    from daft.io import IOConfig
+
+# Value Hive uses in partition paths for partitions whose value is null
+_HIVE_DEFAULT_PARTITION = "__HIVE_DEFAULT_PARTITION__"
+
+# Default field delimiter of text tables, which is ctrl-A
+_HIVE_DEFAULT_FIELD_DELIMITER = "\x01"
+
+# Default value that text tables store for nulls
+_HIVE_DEFAULT_NULL_FORMAT = "\\N"
+
+# Columns added to the scan of a partitioned table to match its files to their partitions
+_FILE_PATH_COLUMN = "__hive_file_path"
+_PARTITION_LOCATION_COLUMN = "__hive_partition_location"
+
+# Optional scheme of a path, which is ignored when matching files to partition locations since the metastore may
+# store locations under a different scheme than the one the files are listed with, e.g. s3a:// rather than s3://
+_SCHEME_PATTERN = r"^(?:[a-zA-Z][a-zA-Z0-9+.\-]*://)?"
+
+_HIVE_PRIMITIVE_TYPES = {
+    "boolean": DataType.bool(),
+    "tinyint": DataType.int8(),
+    "smallint": DataType.int16(),
+    "int": DataType.int32(),
+    "integer": DataType.int32(),
+    "bigint": DataType.int64(),
+    "float": DataType.float32(),
+    "double": DataType.float64(),
+    "string": DataType.string(),
+    "binary": DataType.binary(),
+    "date": DataType.date(),
+    "timestamp": DataType.timestamp(TimeUnit.us()),
+}
+
+
+def _hive_type_to_daft(hive_type: str) -> DataType:
+    """Maps a primitive Hive column type, as stored in the metastore, to a Daft DataType."""
+    hive_type = hive_type.strip().lower()
+    if hive_type in _HIVE_PRIMITIVE_TYPES:
+        return _HIVE_PRIMITIVE_TYPES[hive_type]
+    if hive_type.startswith("varchar") or hive_type.startswith("char"):
+        return DataType.string()
+    decimal = re.fullmatch(r"decimal(?:\((\d+)\s*,\s*(\d+)\))?", hive_type)
+    if decimal is not None:
+        precision, scale = decimal.groups()
+        # Hive defaults to decimal(10, 0) when no precision is given
+        return DataType.decimal128(int(precision or 10), int(scale or 0))
+    raise ValueError(f"Unsupported Hive column type: {hive_type}")
+
+
+@dataclasses.dataclass(frozen=True)
+class HiveFileFormat:
+    """File format of a Hive table, derived from the input format and serde stored in the metastore."""
+
+    format: str
+    delimiter: str | None = None
+    quote: str | None = None
+    escape_char: str | None = None
+    # Value that stands for null in text tables
+    null_format: str | None = None
+
+
+def _hive_file_format(input_format: str | None, serde_lib: str | None, serde_params: dict[str, str]) -> HiveFileFormat:
+    input_format = (input_format or "").lower()
+    serde_lib = (serde_lib or "").lower()
+
+    if "parquet" in input_format or "parquet" in serde_lib:
+        return HiveFileFormat("parquet")
+    if "jsonserde" in serde_lib:
+        return HiveFileFormat("json")
+    if "opencsvserde" in serde_lib:
+        return HiveFileFormat(
+            "csv",
+            delimiter=serde_params.get("separatorChar", ","),
+            quote=serde_params.get("quoteChar", '"'),
+            escape_char=serde_params.get("escapeChar"),
+        )
+    if "lazysimpleserde" in serde_lib or "textinputformat" in input_format:
+        delimiter = serde_params.get("field.delim", serde_params.get("serialization.format"))
+        # serialization.format is sometimes stored as the ordinal of the delimiter character
+        if delimiter is not None and delimiter.isdigit():
+            delimiter = chr(int(delimiter))
+        return HiveFileFormat(
+            "csv",
+            delimiter=delimiter or _HIVE_DEFAULT_FIELD_DELIMITER,
+            escape_char=serde_params.get("escape.delim"),
+            null_format=serde_params.get("serialization.null.format", _HIVE_DEFAULT_NULL_FORMAT),
+        )
+    raise ValueError(f"Unsupported Hive table format with input format {input_format!r} and serde {serde_lib!r}")
+
+
+@dataclasses.dataclass(frozen=True)
+class HivePartition:
+    location: str
+    values: list[str | None]
+
+
+@dataclasses.dataclass(frozen=True)
+class HiveTable:
+    """A table resolved from the Hive Metastore."""
+
+    name: str
+    location: str
+    file_format: HiveFileFormat
+    columns: list[tuple[str, str]]
+    partition_columns: list[tuple[str, str]]
+    partitions: list[HivePartition]
+
+    def _read_locations(
+        self, locations: list[str], io_config: IOConfig | None, file_path_column: str | None = None
+    ) -> DataFrame:
+        import daft
+        from daft.expressions import col, lit
+
+        schema = {name: _hive_type_to_daft(hive_type) for name, hive_type in self.columns}
+        if self.file_format.format == "parquet":
+            return daft.read_parquet(locations, io_config=io_config, file_path_column=file_path_column)
+        elif self.file_format.format == "json":
+            return daft.read_json(
+                locations, schema=schema, infer_schema=False, io_config=io_config, file_path_column=file_path_column
+            )
+
+        null_format = self.file_format.null_format
+        df = daft.read_csv(
+            locations,
+            # Columns are read as strings to replace the null marker before they are parsed
+            schema=schema if null_format is None else {name: DataType.string() for name in schema},
+            infer_schema=False,
+            has_headers=False,
+            delimiter=self.file_format.delimiter,
+            quote=self.file_format.quote,
+            escape_char=self.file_format.escape_char,
+            io_config=io_config,
+            file_path_column=file_path_column,
+        )
+        if null_format is None:
+            return df
+        return df.with_columns(
+            {
+                name: (col(name) == null_format).if_else(lit(None), col(name)).cast(dtype)
+                for name, dtype in schema.items()
+            }
+        )
+
+    def to_dataframe(self, io_config: IOConfig | None = None) -> DataFrame:
+        import daft
+        from daft.expressions import col
+
+        if not self.partition_columns:
+            return self._read_locations([self.location], io_config)
+
+        if not self.partitions:
+            raise ValueError(f"Hive table {self.name} is partitioned but has no partitions to read")
+
+        # Partition values are not stored in the files and the partition locations need not follow the hive-style
+        # `key=value` layout, so all locations are read in one scan and each file is joined with the values of the
+        # partition whose location it is under.
+        locations = {_strip_scheme(partition.location): partition for partition in self.partitions}
+        # Longer locations come first so that files match the most specific of nested locations
+        pattern = "|".join(_escape_regex(location) for location in sorted(locations, key=len, reverse=True))
+        partition_values = daft.from_pydict(
+            {
+                _PARTITION_LOCATION_COLUMN: list(locations),
+                **{
+                    name: [partition.values[i] for partition in locations.values()]
+                    for i, (name, _) in enumerate(self.partition_columns)
+                },
+            }
+        ).with_columns(
+            {
+                name: col(name).cast(DataType.string()).cast(_hive_type_to_daft(hive_type))
+                for name, hive_type in self.partition_columns
+            }
+        )
+
+        df = self._read_locations(
+            list(dict.fromkeys(partition.location for partition in self.partitions)),
+            io_config,
+            file_path_column=_FILE_PATH_COLUMN,
+        )
+        df = df.with_column(
+            _PARTITION_LOCATION_COLUMN, col(_FILE_PATH_COLUMN).str.extract(f"{_SCHEME_PATTERN}({pattern})/", 1)
+        )
+        return df.join(partition_values, on=_PARTITION_LOCATION_COLUMN).exclude(
+            _FILE_PATH_COLUMN, _PARTITION_LOCATION_COLUMN
+        )
+
+
+def _strip_scheme(location: str) -> str:
+    return re.sub(_SCHEME_PATTERN, "", location).rstrip("/")
+
+
+def _escape_regex(text: str) -> str:
+    """Escapes the characters of `text` that are special in the regex syntax of Daft's string functions."""
+    return re.sub(r"([\\.+*?()|\[\]{}^$])", r"\\\1", text)
+
+
+class HiveMetastoreCatalog:
+    """Client to access tables registered in a Hive Metastore over its thrift API.
+
+    Requires the `thrift` package and the generated `hive_metastore` bindings, which are installed with `pyiceberg[hive]`.
+
+    Example of reading a dataframe from a table in the Hive Metastore:
+
+    >>> catalog = HiveMetastoreCatalog("thrift://localhost:9083")
+    >>> df = catalog.load_table("my_db.my_table").to_dataframe()
+    """
+
+    def __init__(self, uri: str, io_config: IOConfig | None = None):
+        parsed = urlparse(uri)
+        if parsed.scheme != "thrift" or parsed.hostname is None:
+            raise ValueError(f"Expected a Hive Metastore uri of the form thrift://host:port, but received: {uri}")
+        self._host = parsed.hostname
+        self._port = parsed.port or 9083
+        self._io_config = io_config
+
+    def _client(self):
+        from hive_metastore.ThriftHiveMetastore import Client
+        from thrift.protocol import TBinaryProtocol
+        from thrift.transport import TSocket, TTransport
+
+        transport = TTransport.TBufferedTransport(TSocket.TSocket(self._host, self._port))
+        return transport, Client(TBinaryProtocol.TBinaryProtocol(transport))
+
+    def _call(self, method: str, *args):
+        transport, client = self._client()
+        transport.open()
+        try:
+            return getattr(client, method)(*args)
+        finally:
+            transport.close()
+
+    def list_databases(self) -> list[str]:
+        return self._call("get_all_databases")
+
+    def list_tables(self, database_name: str) -> list[str]:
+        return [f"{database_name}.{table}" for table in self._call("get_all_tables", database_name)]
+
+    def load_table(self, table_name: str) -> HiveTable:
+        """Resolves a table's location, format and partitions from the metastore.
+
+        Args:
+            table_name (str): Name of the table in the form `database.table`
+
+        Returns:
+            HiveTable
+        """
+        if table_name.count(".") != 1:
+            raise ValueError(f"Expected table name with format `database`.`table`, but received: {table_name}")
+        database_name, name = table_name.split(".")
+
+        table = self._call("get_table", database_name, name)
+        storage = table.sd
+        partition_columns = [(col.name, col.type) for col in table.partitionKeys or []]
+        partitions = []
+        if partition_columns:
+            # -1 requests all partitions of the table
+            partitions = [
+                HivePartition(
+                    location=partition.sd.location,
+                    values=[None if value == _HIVE_DEFAULT_PARTITION else value for value in partition.values],
+                )
+                for partition in self._call("get_partitions", database_name, name, -1)
+            ]
+
+        return HiveTable(
+            name=table_name,
+            location=storage.location,
+            file_format=_hive_file_format(
+                storage.inputFormat, storage.serdeInfo.serializationLib, storage.serdeInfo.parameters or {}
+            ),
+            columns=[(col.name, col.type) for col in storage.cols],
+            partition_columns=partition_columns,
+            partitions=partitions,
+        )
+
+
+class HiveMetastoreCatalogAdaptor(PythonCatalog):
+    def __init__(self, hive_catalog: HiveMetastoreCatalog):
+        self._catalog = hive_catalog
+
+    def list_tables(self, prefix: str) -> list[str]:
+        if prefix == "":
+            return [tbl for db in self._catalog.list_databases() for tbl in self._catalog.list_tables(db)]
+        elif "." not in prefix:
+            return self._catalog.list_tables(prefix)
+        else:
+            raise ValueError(f"Unrecognized database name, expected a database without '.' but received: {prefix}")
+
+    def load_table(self, name: str) -> HiveTableAdaptor:
+        return HiveTableAdaptor(self._catalog.load_table(name), self._catalog._io_config)
+
+
+class HiveTableAdaptor(PythonCatalogTable):
+    def __init__(self, hive_table: HiveTable, io_config: IOConfig | None):
+        self._table = hive_table
+        self._io_config = io_config
+
+    def to_dataframe(self) -> DataFrame:
+        return self._table.to_dataframe(self._io_config)
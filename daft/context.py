@@ -10,6 +10,7 @@ from typing import TYPE_CHECKING, ClassVar, Literal
 from daft.daft import IOConfig, PyDaftExecutionConfig, PyDaftPlanningConfig
 
 if TYPE_CHECKING:
+    from daft.datatype import DataType
     from daft.runners.runner import Runner
 
 logger = logging.getLogger(__name__)
@@ -293,6 +294,8 @@ def planning_config_ctx(**kwargs):
 def set_planning_config(
     config: PyDaftPlanningConfig | None = None,
     default_io_config: IOConfig | None = None,
+    default_collation: str | None = None,
+    default_schema_hints: dict[str, DataType] | None = None,
 ) -> DaftContext:
     """Globally sets various configuration parameters which control Daft plan construction behavior.
 
@@ -303,13 +306,27 @@ def set_planning_config(
             that the old (current) config should be used.
         default_io_config: A default IOConfig to use in the absence of one being explicitly passed into any Expression (e.g. `.url.download()`)
             or Dataframe operation (e.g. `daft.read_parquet()`).
+        default_collation: Collation of the string comparisons in filters and of the string sort keys of the DataFrame
+            filters and sorts and SQL queries built afterwards, e.g. `"case_insensitive"` or a locale such as `"de-DE"`.
+            Defaults to None, which compares the UTF-8 bytes of the strings. It only applies to filters and sorts: joins,
+            groupbys, distincts and min/max aggregations still compare the UTF-8 bytes, which can be collated by using
+            `Expression.str.collation_key()` as their keys.
+        default_schema_hints: Types of columns that override the types inferred by all reads of files whose schemas are
+            inferred, e.g. `daft.read_csv()`. They're merged with the `schema` given to each read, which can hint the
+            types of other columns but raises a ValueError if it gives a column a different type.
     """
+    from daft.io.common import _get_schema_from_dict
+
     # Replace values in the DaftPlanningConfig with user-specified overrides
     ctx = get_context()
     with ctx._lock:
         old_daft_planning_config = ctx._daft_planning_config if config is None else config
         new_daft_planning_config = old_daft_planning_config.with_config_values(
             default_io_config=default_io_config,
+            default_collation=default_collation,
+            default_schema_hints=_get_schema_from_dict(default_schema_hints)._schema
+            if default_schema_hints is not None
+            else None,
         )
 
         ctx._daft_planning_config = new_daft_planning_config
@@ -352,6 +369,24 @@ def set_execution_config(
     shuffle_algorithm: str | None = None,
     pre_shuffle_merge_threshold: int | None = None,
     enable_ray_tracing: bool | None = None,
+    checkpoint_dir: str | None = None,
+    enable_speculative_scans: bool | None = None,
+    speculative_scan_multiplier: float | None = None,
+    local_num_gpus: int | None = None,
+    enable_skew_join_salting: bool | None = None,
+    skew_join_salt_factor: int | None = None,
+    skew_join_heavy_hitter_threshold: float | None = None,
+    temp_dir: str | None = None,
+    temp_space_quota_bytes: int | None = None,
+    native_parallelism: int | None = None,
+    native_parquet_writer: bool | None = None,
+    native_csv_writer: bool | None = None,
+    state_store_backend: Literal["memory", "rocksdb"] | None = None,
+    compress_buffered_partitions: bool | None = None,
+    udf_shared_memory_transport: bool | None = None,
+    sort_memory_limit_bytes: int | None = None,
+    native_iceberg_commit: bool | None = None,
+    repartition_memory_limit_bytes: int | None = None,
 ) -> DaftContext:
     """Globally sets various configuration parameters which control various aspects of Daft execution.
 
@@ -392,9 +427,62 @@ def set_execution_config(
         enable_aqe: Enables Adaptive Query Execution, Defaults to False
         enable_native_executor: Enables the native executor, Defaults to False
         default_morsel_size: Default size of morsels used for the new local executor. Defaults to 131072 rows.
-        shuffle_algorithm: The shuffle algorithm to use. Defaults to "map_reduce". Other options are "pre_shuffle_merge".
+        shuffle_algorithm: The shuffle algorithm to use. Defaults to "map_reduce". Other options are "pre_shuffle_merge", and
+            "flight_shuffle", with which hash partitioning on the Ray Runner pushes partitions into a shuffle worker on each
+            node, from which they're fetched over Arrow Flight authenticated with a token that's shared by the workers.
         pre_shuffle_merge_threshold: Memory threshold in bytes for pre-shuffle merge. Defaults to 1GB
         enable_ray_tracing: Enable tracing for Ray. Accessible in `/tmp/ray/session_latest/logs/daft` after the run completes. Defaults to False.
+        checkpoint_dir: Local directory to persist the outputs of completed query stages to when running multi-stage queries
+            on the Native Runner, so that a query that fails late resumes from its last completed stage when rerun. Stages are
+            only resumed if the query's plan and the files it reads did not change, and the checkpoints of a query are deleted
+            once it succeeds. The state of stateful streaming operators is also checkpointed there. Defaults to None, which
+            disables checkpointing.
+        enable_speculative_scans: Launch a duplicate attempt of scan tasks on the Native Runner that take far longer than the
+            other scan tasks of the query to produce their first output, streaming the outputs of whichever attempt produces
+            output first. This helps on object stores with occasional slow requests. Defaults to False.
+        speculative_scan_multiplier: How many times longer than the median scan task a scan task must wait for its first
+            output before a duplicate attempt is launched. Defaults to 3.0.
+        local_num_gpus: Number of GPUs that the Native Runner schedules UDFs requesting GPUs on. The number of GPU UDF
+            morsels executed concurrently is limited so that their requests fit in this pool. Defaults to the number of
+            visible CUDA devices.
+        enable_skew_join_salting: Look for hot join keys in a sample of one side of shuffled hash joins, and spread the
+            rows of those keys across several partitions by salting them, while copying the matching rows of the other
+            side to each of those partitions. This prevents a single partition from holding most of the rows of a skewed
+            join. Only applies to inner, left, right, semi and anti joins, and isn't used by the Native Runner.
+            Defaults to False.
+        skew_join_salt_factor: Number of partitions that the rows of each hot join key are spread across. Defaults to 8.
+        skew_join_heavy_hitter_threshold: How many times more rows than an evenly sized partition a join key must have
+            in the sample to be salted. Defaults to 1.0.
+        temp_dir: Local directory that the Native Runner writes spill files to. Each query writes to its own
+            subdirectory, which is removed when the query completes, fails or is cancelled. Defaults to the system temp
+            directory.
+        temp_space_quota_bytes: Maximum total size of the spill files of all queries writing to the same temp directory.
+            Queries that would exceed it fail instead of filling up the disk. Defaults to None, which means no limit.
+        native_parallelism: Maximum number of morsels that each operator of the Native Runner processes at once, which
+            bounds the CPU and memory used by a query. Defaults to None, which processes up to one morsel per CPU.
+        native_parquet_writer: Whether the Native Runner writes Parquet files with Daft's native writer instead of PyArrow.
+            The native writer doesn't hold the GIL, so several files can be written at once. Writes with encryption
+            always use PyArrow. Defaults to True.
+        native_csv_writer: Whether the Native Runner writes CSV files with Daft's native writer instead of PyArrow.
+            Defaults to True.
+        state_store_backend: Where stateful streaming operators of the Native Runner, such as window aggregations, keep
+            their state between micro-batches: "memory", or "rocksdb" for state that may be larger than memory, which
+            requires Daft to be built with the ``rocksdb`` feature. If ``checkpoint_dir`` is set, the state is
+            checkpointed there after each micro-batch and restored when the query is rerun. Defaults to "memory".
+        compress_buffered_partitions: Whether blocking operators of the Native Runner, such as sorts and aggregations,
+            compress the partitions that they buffer until all of their input has arrived with LZ4. They're decompressed
+            when they're processed, which trades CPU time for fitting more data in memory. Defaults to False.
+        udf_shared_memory_transport: Whether the Native Runner passes partitions to and from the processes of UDFs that
+            run in an actor pool through shared memory in the Arrow format, rather than by pickling them. Only takes
+            effect on Linux, and partitions with Python columns are always pickled. Defaults to True.
+        sort_memory_limit_bytes: Size of the partitions that each sort of the Native Runner buffers before it sorts them
+            into a run that it spills to ``temp_dir``. Once all of its input has arrived, the sort merges its runs,
+            reading them back a batch at a time, which lets it sort more data than fits in memory. Defaults to 2GB.
+        native_iceberg_commit: Whether ``write_iceberg`` commits snapshots with Daft's native Iceberg commit instead of
+            PyIceberg. Only tables of REST catalogs and file-system tables, whose metadata files are numbered versions
+            with a version hint, are supported. Defaults to False.
+        repartition_memory_limit_bytes: Size of the partitions that each hash repartition of the Native Runner buffers
+            before it spills them to ``temp_dir``, reading them back once all of its input has arrived. Defaults to 2GB.
     """
     # Replace values in the DaftExecutionConfig with user-specified overrides
     ctx = get_context()
@@ -425,6 +513,24 @@ def set_execution_config(
             shuffle_algorithm=shuffle_algorithm,
             pre_shuffle_merge_threshold=pre_shuffle_merge_threshold,
             enable_ray_tracing=enable_ray_tracing,
+            checkpoint_dir=checkpoint_dir,
+            enable_speculative_scans=enable_speculative_scans,
+            speculative_scan_multiplier=speculative_scan_multiplier,
+            local_num_gpus=local_num_gpus,
+            enable_skew_join_salting=enable_skew_join_salting,
+            skew_join_salt_factor=skew_join_salt_factor,
+            skew_join_heavy_hitter_threshold=skew_join_heavy_hitter_threshold,
+            temp_dir=temp_dir,
+            temp_space_quota_bytes=temp_space_quota_bytes,
+            native_parallelism=native_parallelism,
+            native_parquet_writer=native_parquet_writer,
+            native_csv_writer=native_csv_writer,
+            state_store_backend=state_store_backend,
+            compress_buffered_partitions=compress_buffered_partitions,
+            udf_shared_memory_transport=udf_shared_memory_transport,
+            sort_memory_limit_bytes=sort_memory_limit_bytes,
+            native_iceberg_commit=native_iceberg_commit,
+            repartition_memory_limit_bytes=repartition_memory_limit_bytes,
         )
 
         ctx._daft_execution_config = new_daft_execution_config
@@ -5,6 +5,8 @@ from typing import TYPE_CHECKING, Any, Callable, Iterator, Literal
 
 from daft.dataframe.display import MermaidOptions
 from daft.execution import physical_plan
+from daft.io.parquet_encryption import ParquetEncryptionConfig
+from daft.io.pushdowns import Term
 from daft.io.scan import ScanOperator
 from daft.plan_scheduler.physical_plan_scheduler import PartitionT
 from daft.runners.partitioning import PartitionCacheEntry
@@ -74,6 +76,7 @@ class ImageFormat(Enum):
     TIFF: int
     GIF: int
     BMP: int
+    WEBP: int
 
     @staticmethod
     def from_format_string(mode: str) -> ImageFormat:
@@ -177,6 +180,10 @@ class FileFormat(Enum):
     Parquet: int
     Csv: int
     Json: int
+    Xml: int
+    Excel: int
+    Kafka: int
+    Range: int
 
     def ext(self): ...
 
@@ -222,6 +229,31 @@ class CsvSourceConfig:
         chunk_size: int | None = None,
     ): ...
 
+class CsvQuoteStyle(Enum):
+    """Which values of a written CSV file are quoted."""
+
+    Needed: int
+    AllValid: int
+    Never: int
+
+class CsvWriteOptions:
+    """Options of written CSV files."""
+
+    delimiter: str
+    quote: str
+    quote_style: CsvQuoteStyle
+    header: bool
+    null_value: str
+
+    def __init__(
+        self,
+        delimiter: str = ",",
+        quote: str = '"',
+        quote_style: Literal["needed", "all_valid", "none"] = "needed",
+        header: bool = True,
+        null_value: str = "",
+    ): ...
+
 class JsonSourceConfig:
     """Configuration of a JSON data source."""
 
@@ -234,6 +266,58 @@ class JsonSourceConfig:
         chunk_size: int | None = None,
     ): ...
 
+class XmlSourceConfig:
+    """Configuration of an XML data source."""
+
+    row_tag: str
+    attribute_prefix: str
+    value_tag: str
+
+    def __init__(
+        self,
+        row_tag: str,
+        attribute_prefix: str | None = None,
+        value_tag: str | None = None,
+    ): ...
+
+class ExcelSourceConfig:
+    """Configuration of an Excel data source."""
+
+    sheet_name: str | None
+    header_row: int | None
+
+    def __init__(
+        self,
+        sheet_name: str | None = None,
+        header_row: int | None = None,
+    ): ...
+
+class KafkaValueFormat(Enum):
+    """Format of the values of the messages of a Kafka topic."""
+
+    Json: int
+    Avro: int
+
+class KafkaSourceConfig:
+    """Configuration of a Kafka data source."""
+
+    bootstrap_servers: str
+    topic: str
+    value_format: KafkaValueFormat
+    avro_schema: str | None
+    consumer_options: dict[str, str]
+    poll_timeout_ms: int
+
+    def __init__(
+        self,
+        bootstrap_servers: str,
+        topic: str,
+        value_format: KafkaValueFormat,
+        avro_schema: str | None = None,
+        consumer_options: dict[str, str] | None = None,
+        poll_timeout_ms: int | None = None,
+    ): ...
+
 class DatabaseSourceConfig:
     """Configuration of a database data source."""
 
@@ -245,7 +329,15 @@ class DatabaseSourceConfig:
 class FileFormatConfig:
     """Configuration for parsing a particular file format (Parquet, CSV, JSON)."""
 
-    config: ParquetSourceConfig | CsvSourceConfig | JsonSourceConfig | DatabaseSourceConfig
+    config: (
+        ParquetSourceConfig
+        | CsvSourceConfig
+        | JsonSourceConfig
+        | XmlSourceConfig
+        | ExcelSourceConfig
+        | KafkaSourceConfig
+        | DatabaseSourceConfig
+    )
 
     @staticmethod
     def from_parquet_config(config: ParquetSourceConfig) -> FileFormatConfig:
@@ -262,6 +354,21 @@ class FileFormatConfig:
         """Create a JSON file format config."""
         ...
 
+    @staticmethod
+    def from_xml_config(config: XmlSourceConfig) -> FileFormatConfig:
+        """Create an XML file format config."""
+        ...
+
+    @staticmethod
+    def from_excel_config(config: ExcelSourceConfig) -> FileFormatConfig:
+        """Create an Excel file format config."""
+        ...
+
+    @staticmethod
+    def from_kafka_config(config: KafkaSourceConfig) -> FileFormatConfig:
+        """Create a Kafka file format config."""
+        ...
+
     @staticmethod
     def from_database_config(config: DatabaseSourceConfig) -> FileFormatConfig:
         """Create a database file format config."""
@@ -361,6 +468,7 @@ class FileInfo:
     file_path: str
     file_size: int | None
     num_rows: int | None
+    etag: str | None
 
 class FileInfos:
     """Metadata for a collection of files."""
@@ -368,13 +476,35 @@ class FileInfos:
     file_paths: list[str]
     file_sizes: list[int | None]
     num_rows: list[int | None]
+    etags: list[str | None]
 
     @staticmethod
-    def from_infos(file_paths: list[str], file_sizes: list[int | None], num_rows: list[int | None]) -> FileInfos: ...
+    def from_infos(
+        file_paths: list[str],
+        file_sizes: list[int | None],
+        num_rows: list[int | None],
+        etags: list[str | None] | None = None,
+    ) -> FileInfos: ...
+    @staticmethod
+    def merge(infos: list[FileInfos]) -> FileInfos:
+        """Combine the listings of multiple prefixes or partitions into one.
+
+        Files with the same path and etag are only kept once, and the files are sorted by path.
+        """
+        ...
+
     def extend(self, new_infos: FileInfos) -> FileInfos:
         """Concatenate two FileInfos together."""
         ...
 
+    def total_size_bytes(self) -> int | None:
+        """Total size of the files, or None if the size of any file is unknown."""
+        ...
+
+    def total_num_rows(self) -> int | None:
+        """Total number of rows in the files, or None if the number of rows of any file is unknown."""
+        ...
+
     def __getitem__(self, idx: int) -> FileInfo: ...
     def __len__(self) -> int: ...
 
@@ -568,6 +698,7 @@ class IOConfig:
     azure: AzureConfig
     gcs: GCSConfig
     http: HTTPConfig
+    transform: Any | None
 
     def __init__(
         self,
@@ -575,6 +706,7 @@ class IOConfig:
         azure: AzureConfig | None = None,
         gcs: GCSConfig | None = None,
         http: HTTPConfig | None = None,
+        transform: Any | None = None,
     ): ...
     def replace(
         self,
@@ -582,6 +714,7 @@ class IOConfig:
         azure: AzureConfig | None = None,
         gcs: GCSConfig | None = None,
         http: HTTPConfig | None = None,
+        transform: Any | None = None,
     ) -> IOConfig:
         """Replaces values if provided, returning a new IOConfig."""
         ...
@@ -595,6 +728,20 @@ class StorageConfig:
 
     def __init__(self, multithreaded_io: bool, io_config: IOConfig | None): ...
 
+class LocalityHints:
+    """Hints about where the data that a ScanTask reads lives, which distributed runners use to schedule it close to its data."""
+
+    region: str | None
+    zone: str | None
+    cached_node_ids: list[str]
+
+    def __init__(
+        self,
+        region: str | None = None,
+        zone: str | None = None,
+        cached_node_ids: list[str] | None = None,
+    ): ...
+
 class ScanTask:
     """A batch of scan tasks for reading data from an external source."""
 
@@ -606,6 +753,20 @@ class ScanTask:
         """Estimate the In Memory Size of this ScanTask."""
         ...
 
+    def locality_hints(self) -> LocalityHints | None:
+        """Get the hints about where the data of this ScanTask lives."""
+        ...
+
+    def with_locality_hints(self, locality_hints: LocalityHints) -> ScanTask:
+        """Create a copy of this ScanTask with the given hints about where its data lives."""
+        ...
+
+    def with_column_defaults(self, column_defaults: list[tuple[str, PyExpr]]) -> ScanTask:
+        """Create a copy of this ScanTask that fills the given columns with the values of their expressions for
+        sources that don't have them.
+        """
+        ...
+
     @staticmethod
     def catalog_scan_task(
         file: str,
@@ -650,6 +811,9 @@ class ScanTask:
         """Create a Python factory function Scan Task."""
         ...
 
+    def __eq__(self, other: ScanTask) -> bool: ...  # type: ignore[override]
+    def __hash__(self) -> int: ...
+
 class ScanOperatorHandle:
     """A handle to a scan operator."""
 
@@ -671,6 +835,15 @@ class ScanOperatorHandle:
         file_path_column: str | None = None,
     ) -> ScanOperatorHandle: ...
     @staticmethod
+    def kafka_scan(
+        config: KafkaSourceConfig,
+        schema: PySchema,
+        partitions: list[int] | None,
+        start: str | int | dict[int, int],
+        end: str | int | dict[int, int],
+        max_messages_per_task: int | None = None,
+    ) -> ScanOperatorHandle: ...
+    @staticmethod
     def from_python_scan_operator(operator: ScanOperator) -> ScanOperatorHandle: ...
 
 def logical_plan_table_scan(scan_operator: ScanOperatorHandle) -> LogicalPlanBuilder: ...
@@ -716,6 +889,12 @@ class Pushdowns:
     def filter_required_column_names(self) -> list[str]:
         """List of field names that are required by the filter predicate."""
         ...
+    def filter_terms(self) -> list[Term]:
+        """The predicates of the filters that can be translated into terms, leaving out the others."""
+        ...
+    def filter_terms_json(self) -> str:
+        """The predicates of :meth:`filter_terms` serialized into a JSON array of terms."""
+        ...
 
 def read_parquet(
     uri: str,
@@ -804,6 +983,52 @@ def read_json_schema(
     multithreaded_io: bool | None = None,
 ): ...
 
+class DeltaSnapshot:
+    """State of a Delta Lake table at a version."""
+
+    @property
+    def version(self) -> int: ...
+    @property
+    def partition_columns(self) -> list[str]: ...
+    @property
+    def schema_string(self) -> str: ...
+    def schema_matches(self, schema: PySchema) -> builtins.bool: ...
+    def partition_values(self) -> dict[str, dict[str, str | None]]: ...
+    def files(self) -> list[tuple[str, int, int | None]]: ...
+
+def delta_load_snapshot(table_uri: str, io_config: IOConfig | None = None) -> DeltaSnapshot | None: ...
+def delta_commit(
+    table_uri: str,
+    snapshot: DeltaSnapshot | None,
+    mode: Literal["append", "overwrite", "compact"],
+    schema: PySchema,
+    partition_columns: list[str],
+    add_actions: list[str],
+    overwrite_schema: builtins.bool = False,
+    name: str | None = None,
+    description: str | None = None,
+    configuration: dict[str, str | None] | None = None,
+    custom_metadata: dict[str, str] | None = None,
+    replaced_paths: list[str] | None = None,
+    max_retries: int = 15,
+    allow_unsafe_rename: builtins.bool = False,
+    io_config: IOConfig | None = None,
+) -> int: ...
+
+def iceberg_commit(
+    mode: Literal["append", "overwrite", "replace"],
+    data_files: list[dict[str, Any]],
+    table_location: str | None = None,
+    rest_uri: str | None = None,
+    rest_table: str | None = None,
+    rest_warehouse: str | None = None,
+    rest_token: str | None = None,
+    snapshot_properties: dict[str, str] | None = None,
+    replaced_paths: list[str] | None = None,
+    max_attempts: int = 4,
+    io_config: IOConfig | None = None,
+) -> int: ...
+
 class PyTimeUnit:
     @staticmethod
     def nanoseconds() -> PyTimeUnit: ...
@@ -927,6 +1152,19 @@ class PySchema:
     def _truncated_table_html(self) -> str: ...
     def _truncated_table_string(self) -> str: ...
     def apply_hints(self, hints: PySchema) -> PySchema: ...
+    def merge_hints(self, other: PySchema) -> PySchema: ...
+    def diff(self, other: PySchema) -> PySchemaDiff: ...
+
+class PySchemaDiff:
+    def changes(self) -> list[tuple[str, Literal["added", "removed", "type_changed", "moved"], str]]: ...
+    def is_empty(self) -> bool: ...
+    def can_append(self) -> bool: ...
+    def can_union(self) -> bool: ...
+    def append_incompatibilities(self) -> list[tuple[str, str]]: ...
+    def union_incompatibilities(self) -> list[tuple[str, str]]: ...
+    def breaking_changes(self) -> list[tuple[str, str]]: ...
+    def __reduce__(self) -> tuple: ...
+    def __repr__(self) -> str: ...
 
 class PyExpr:
     def alias(self, name: str) -> PyExpr: ...
@@ -944,6 +1182,8 @@ class PyExpr:
     def any_value(self, ignore_nulls: bool) -> PyExpr: ...
     def agg_list(self) -> PyExpr: ...
     def agg_concat(self) -> PyExpr: ...
+    def top_k(self, k: int) -> PyExpr: ...
+    def approx_heavy_hitters(self, k: int) -> PyExpr: ...
     def __add__(self, other: PyExpr) -> PyExpr: ...
     def __sub__(self, other: PyExpr) -> PyExpr: ...
     def __mul__(self, other: PyExpr) -> PyExpr: ...
@@ -1055,6 +1295,18 @@ def tokenize_decode(
     pattern: str | None,
     special_tokens: str | None,
 ) -> PyExpr: ...
+def tokenize(
+    expr: PyExpr,
+    tokenizer_path: str,
+    add_special_tokens: bool,
+    io_config: IOConfig | None,
+) -> PyExpr: ...
+def detokenize(
+    expr: PyExpr,
+    tokenizer_path: str,
+    skip_special_tokens: bool,
+    io_config: IOConfig | None,
+) -> PyExpr: ...
 def minhash(
     expr: PyExpr,
     num_hashes: int,
@@ -1075,16 +1327,75 @@ class SQLFunctionStub:
     @property
     def arg_names(self) -> list[str]: ...
 
-def sql(sql: str, catalog: PyCatalog, daft_planning_config: PyDaftPlanningConfig) -> LogicalPlanBuilder: ...
+def sql(
+    sql: str, catalog: PyCatalog, daft_planning_config: PyDaftPlanningConfig
+) -> tuple[LogicalPlanBuilder, PyCatalog]: ...
 def sql_expr(sql: str) -> PyExpr: ...
 def list_sql_functions() -> list[SQLFunctionStub]: ...
 def utf8_count_matches(expr: PyExpr, patterns: PyExpr, whole_words: bool, case_sensitive: bool) -> PyExpr: ...
 def to_struct(inputs: list[PyExpr]) -> PyExpr: ...
-def connect_start(addr: str = "sc://0.0.0.0:0") -> ConnectionHandle: ...
+def clustering_key(inputs: list[PyExpr], curve: str) -> PyExpr: ...
+def connect_start(
+    addr: str = "sc://0.0.0.0:0",
+    metrics_addr: str | None = None,
+    max_concurrent_queries: int | None = None,
+    max_concurrent_queries_per_session: int | None = None,
+    max_queued_queries: int | None = None,
+) -> ConnectionHandle: ...
 
 class ConnectionHandle:
     def shutdown(self) -> None: ...
     def port(self) -> int: ...
+    def metrics_port(self) -> int | None: ...
+
+def flight_sql_start(
+    addr: str = "127.0.0.1:0",
+    catalog: PyCatalog | None = None,
+    config: PyDaftExecutionConfig | None = None,
+    authenticate: Callable[[str | None], bool] | None = None,
+) -> FlightSqlServerHandle: ...
+
+class FlightSqlServerHandle:
+    def shutdown(self) -> None: ...
+    def port(self) -> int: ...
+
+class FlightShuffleWorker:
+    def __init__(
+        self,
+        addr: str = "127.0.0.1:0",
+        auth_token: str | None = None,
+        config: PyDaftExecutionConfig | None = None,
+    ) -> None: ...
+    def port(self) -> int: ...
+    def push_partition(
+        self,
+        query_id: str,
+        stage_id: int,
+        num_partitions: int,
+        partition_by: list[PyExpr],
+        partition: PyMicroPartition,
+    ) -> None: ...
+    def finish_stage(self, query_id: str, stage_id: int) -> bool: ...
+    def remove_query(self, query_id: str) -> None: ...
+    def shutdown(self) -> None: ...
+
+def fetch_flight_shuffle_partition(
+    addresses: list[str],
+    query_id: str,
+    stage_id: int,
+    partition: int,
+    auth_token: str | None = None,
+) -> PyMicroPartition: ...
+
+def pgwire_start(
+    addr: str = "127.0.0.1:0",
+    catalog: PyCatalog | None = None,
+    config: PyDaftExecutionConfig | None = None,
+) -> PgWireServerHandle: ...
+
+class PgWireServerHandle:
+    def shutdown(self) -> None: ...
+    def port(self) -> int: ...
 
 # expr numeric ops
 def abs(expr: PyExpr) -> PyExpr: ...
@@ -1122,6 +1433,7 @@ def image_decode(expr: PyExpr, raise_on_error: bool, mode: ImageMode | None = No
 def image_encode(expr: PyExpr, image_format: ImageFormat) -> PyExpr: ...
 def image_resize(expr: PyExpr, w: int, h: int) -> PyExpr: ...
 def image_to_mode(expr: PyExpr, mode: ImageMode) -> PyExpr: ...
+def image_to_tensor(expr: PyExpr) -> PyExpr: ...
 
 # ---
 # expr.float namespace
@@ -1136,6 +1448,12 @@ def fill_nan(expr: PyExpr, fill_value: PyExpr) -> PyExpr: ...
 # ---
 def json_query(expr: PyExpr, query: str) -> PyExpr: ...
 
+# ---
+# expr.binary namespace
+# ---
+def decode_protobuf(expr: PyExpr, descriptor_set: bytes, message_name: str) -> PyExpr: ...
+def decode_msgpack(expr: PyExpr, dtype: PyDataType) -> PyExpr: ...
+
 # ---
 # expr.dt namespace
 # ---
@@ -1199,12 +1517,19 @@ def utf8_to_datetime(expr: PyExpr, format: str, timezone: str | None = None) ->
 def utf8_normalize(
     expr: PyExpr, remove_punct: bool, lowercase: bool, nfd_unicode: bool, white_space: bool
 ) -> PyExpr: ...
+def utf8_collation_key(expr: PyExpr, collation: str) -> PyExpr: ...
 
 class PyCatalog:
     @staticmethod
     def new() -> PyCatalog: ...
     def register_table(self, name: str, logical_plan_builder: LogicalPlanBuilder) -> None: ...
     def copy_from(self, other: PyCatalog) -> None: ...
+    def copy_views_from(self, other: PyCatalog) -> None: ...
+    def list_tables(self) -> list[str]: ...
+    def get_schema(self, name: str) -> PySchema | None: ...
+    def is_view(self, name: str) -> bool: ...
+    def has_pending_writes(self) -> bool: ...
+    def table_format_write(self) -> tuple[str, str, str | None, str | None, list[str], bool] | None: ...
 
 class PySeries:
     @staticmethod
@@ -1388,7 +1713,11 @@ class PyTable:
     def partition_by_hash(self, exprs: list[PyExpr], num_partitions: int) -> list[PyTable]: ...
     def partition_by_random(self, num_partitions: int, seed: int) -> list[PyTable]: ...
     def partition_by_range(
-        self, partition_keys: list[PyExpr], boundaries: PyTable, descending: list[bool]
+        self,
+        partition_keys: list[PyExpr],
+        boundaries: PyTable,
+        descending: list[bool],
+        nulls_first: list[bool],
     ) -> list[PyTable]: ...
     def partition_by_value(self, partition_keys: list[PyExpr]) -> tuple[list[PyTable], PyTable]: ...
     def add_monotonically_increasing_id(self, partition_num: int, column_name: str) -> PyTable: ...
@@ -1435,6 +1764,7 @@ class PyMicroPartition:
     def eval_expression_list(self, exprs: list[PyExpr]) -> PyMicroPartition: ...
     def take(self, idx: PySeries) -> PyMicroPartition: ...
     def filter(self, exprs: list[PyExpr]) -> PyMicroPartition: ...
+    def assert_unique(self, keys: list[PyExpr]) -> None: ...
     def sort(self, sort_keys: list[PyExpr], descending: list[bool], nulls_first: list[bool]) -> PyMicroPartition: ...
     def argsort(self, sort_keys: list[PyExpr], descending: list[bool], nulls_first: list[bool]) -> PySeries: ...
     def agg(self, to_agg: list[PyExpr], group_by: list[PyExpr]) -> PyMicroPartition: ...
@@ -1480,7 +1810,11 @@ class PyMicroPartition:
     def partition_by_hash(self, exprs: list[PyExpr], num_partitions: int) -> list[PyMicroPartition]: ...
     def partition_by_random(self, num_partitions: int, seed: int) -> list[PyMicroPartition]: ...
     def partition_by_range(
-        self, partition_keys: list[PyExpr], boundaries: PyTable, descending: list[bool]
+        self,
+        partition_keys: list[PyExpr],
+        boundaries: PyTable,
+        descending: list[bool],
+        nulls_first: list[bool],
     ) -> list[PyMicroPartition]: ...
     def partition_by_value(self, exprs: list[PyExpr]) -> tuple[list[PyMicroPartition], PyMicroPartition]: ...
     def add_monotonically_increasing_id(self, partition_num: int, column_name: str) -> PyMicroPartition: ...
@@ -1572,6 +1906,22 @@ class AdaptivePhysicalPlanScheduler:
         num_rows: int,
     ) -> None: ...
 
+class WindowFunction:
+    """A function evaluated over the rows of a window partition."""
+
+    @staticmethod
+    def row_number() -> WindowFunction: ...
+    @staticmethod
+    def rank() -> WindowFunction: ...
+    @staticmethod
+    def lag(expr: PyExpr, offset: int) -> WindowFunction: ...
+    @staticmethod
+    def lead(expr: PyExpr, offset: int) -> WindowFunction: ...
+    @staticmethod
+    def sum(expr: PyExpr, preceding: int | None = None, following: int | None = 0) -> WindowFunction: ...
+    @staticmethod
+    def mean(expr: PyExpr, preceding: int | None = None, following: int | None = 0) -> WindowFunction: ...
+
 class LogicalPlanBuilder:
     """A logical plan builder, which simplifies constructing logical plans via a fluent interface.
 
@@ -1589,12 +1939,25 @@ class LogicalPlanBuilder:
         num_partitions: int,
         size_bytes: int,
         num_rows: int,
+        cached_plan: LogicalPlanBuilder | None = None,
+    ) -> LogicalPlanBuilder: ...
+    @staticmethod
+    def stream_scan(
+        name: str,
+        batch_iterator_factory: Callable[[], Iterator[PyMicroPartition | None]],
+        schema: PySchema,
+        poll_interval_ms: int,
+        watermark_column: str | None = None,
+        watermark_delay: int | None = None,
     ) -> LogicalPlanBuilder: ...
     def with_planning_config(self, daft_planning_config: PyDaftPlanningConfig) -> LogicalPlanBuilder: ...
+    def with_execution_config(self, daft_execution_config: PyDaftExecutionConfig) -> LogicalPlanBuilder: ...
+    def execution_config(self) -> PyDaftExecutionConfig | None: ...
     def select(self, to_select: list[PyExpr]) -> LogicalPlanBuilder: ...
     def with_columns(self, columns: list[PyExpr]) -> LogicalPlanBuilder: ...
     def exclude(self, to_exclude: list[str]) -> LogicalPlanBuilder: ...
     def filter(self, predicate: PyExpr) -> LogicalPlanBuilder: ...
+    def assert_unique(self, keys: list[PyExpr]) -> LogicalPlanBuilder: ...
     def limit(self, limit: int, eager: bool) -> LogicalPlanBuilder: ...
     def explode(self, to_explode: list[PyExpr]) -> LogicalPlanBuilder: ...
     def unpivot(
@@ -1610,12 +1973,36 @@ class LogicalPlanBuilder:
         partition_by: list[PyExpr],
         num_partitions: int | None,
     ) -> LogicalPlanBuilder: ...
+    def range_repartition(
+        self,
+        partition_by: list[PyExpr],
+        descending: list[bool],
+        num_partitions: int | None,
+    ) -> LogicalPlanBuilder: ...
     def random_shuffle(self, num_partitions: int | None) -> LogicalPlanBuilder: ...
     def into_partitions(self, num_partitions: int) -> LogicalPlanBuilder: ...
     def coalesce(self, num_partitions: int) -> LogicalPlanBuilder: ...
     def distinct(self) -> LogicalPlanBuilder: ...
     def sample(self, fraction: float, with_replacement: bool, seed: int | None) -> LogicalPlanBuilder: ...
     def aggregate(self, agg_exprs: list[PyExpr], groupby_exprs: list[PyExpr]) -> LogicalPlanBuilder: ...
+    def window_aggregate(
+        self,
+        agg_exprs: list[PyExpr],
+        groupby_exprs: list[PyExpr],
+        event_time_column: str,
+        window_size: int,
+        window_slide: int | None = None,
+        watermark_delay: int | None = None,
+    ) -> LogicalPlanBuilder: ...
+    def window(
+        self,
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+        nulls_first: list[bool],
+        functions: list[WindowFunction],
+        names: list[str],
+    ) -> LogicalPlanBuilder: ...
     def pivot(
         self,
         groupby_exprs: list[PyExpr],
@@ -1636,6 +2023,7 @@ class LogicalPlanBuilder:
     ) -> LogicalPlanBuilder: ...
     def concat(self, other: LogicalPlanBuilder) -> LogicalPlanBuilder: ...
     def intersect(self, other: LogicalPlanBuilder, is_all: bool) -> LogicalPlanBuilder: ...
+    def compare(self, other: LogicalPlanBuilder, keys: list[str]) -> LogicalPlanBuilder: ...
     def add_monotonically_increasing_id(self, column_name: str | None) -> LogicalPlanBuilder: ...
     def table_write(
         self,
@@ -1644,13 +2032,21 @@ class LogicalPlanBuilder:
         partition_cols: list[PyExpr] | None = None,
         compression: str | None = None,
         io_config: IOConfig | None = None,
+        parquet_encryption: ParquetEncryptionConfig | None = None,
+        csv_options: CsvWriteOptions | None = None,
+        job_id: str | None = None,
+        attempt_id: str | None = None,
+        constraints: list[tuple[str, PyExpr]] | None = None,
+        on_constraint_violation: str | None = None,
+        quarantine_dir: str | None = None,
+        quarantine_format: FileFormat | None = None,
     ) -> LogicalPlanBuilder: ...
     def iceberg_write(
         self,
         table_name: str,
         table_location: str,
         partition_spec_id: int,
-        partition_cols: list[PyExpr],
+        partition_fields: list[PartitionField],
         iceberg_schema: IcebergSchema,
         iceberg_properties: IcebergTableProperties,
         catalog_columns: list[str],
@@ -1680,6 +2076,7 @@ class LogicalPlanBuilder:
     def to_adaptive_physical_plan_scheduler(self, cfg: PyDaftExecutionConfig) -> AdaptivePhysicalPlanScheduler: ...
     def repr_ascii(self, simple: bool) -> str: ...
     def repr_mermaid(self, options: MermaidOptions) -> str: ...
+    def column_lineage(self) -> str: ...
 
 class NativeExecutor:
     @staticmethod
@@ -1689,6 +2086,16 @@ class NativeExecutor:
     def run(
         self, psets: dict[str, list[PartitionT]], cfg: PyDaftExecutionConfig, results_buffer_size: int | None
     ) -> Iterator[PyMicroPartition]: ...
+    def execute_streaming(
+        self,
+        psets: dict[str, list[PartitionT]],
+        cfg: PyDaftExecutionConfig,
+        buffer_size: int = 1,
+        ordered: bool = True,
+    ) -> Iterator[PyMicroPartition]: ...
+    def preview(
+        self, psets: dict[str, list[PartitionT]], cfg: PyDaftExecutionConfig, num_rows: int
+    ) -> Iterator[PyMicroPartition]: ...
 
 class PyDaftExecutionConfig:
     @staticmethod
@@ -1718,6 +2125,24 @@ class PyDaftExecutionConfig:
         enable_ray_tracing: bool | None = None,
         shuffle_algorithm: str | None = None,
         pre_shuffle_merge_threshold: int | None = None,
+        checkpoint_dir: str | None = None,
+        enable_speculative_scans: bool | None = None,
+        speculative_scan_multiplier: float | None = None,
+        local_num_gpus: int | None = None,
+        enable_skew_join_salting: bool | None = None,
+        skew_join_salt_factor: int | None = None,
+        skew_join_heavy_hitter_threshold: float | None = None,
+        temp_dir: str | None = None,
+        temp_space_quota_bytes: int | None = None,
+        native_parallelism: int | None = None,
+        native_parquet_writer: bool | None = None,
+        native_csv_writer: bool | None = None,
+        state_store_backend: Literal["memory", "rocksdb"] | None = None,
+        compress_buffered_partitions: bool | None = None,
+        udf_shared_memory_transport: bool | None = None,
+        sort_memory_limit_bytes: int | None = None,
+        native_iceberg_commit: bool | None = None,
+        repartition_memory_limit_bytes: int | None = None,
     ) -> PyDaftExecutionConfig: ...
     @property
     def scan_tasks_min_size_bytes(self) -> int: ...
@@ -1763,6 +2188,42 @@ class PyDaftExecutionConfig:
     def pre_shuffle_merge_threshold(self) -> int: ...
     @property
     def enable_ray_tracing(self) -> bool: ...
+    @property
+    def checkpoint_dir(self) -> str | None: ...
+    @property
+    def enable_speculative_scans(self) -> bool: ...
+    @property
+    def speculative_scan_multiplier(self) -> float: ...
+    @property
+    def local_num_gpus(self) -> int | None: ...
+    @property
+    def enable_skew_join_salting(self) -> bool: ...
+    @property
+    def skew_join_salt_factor(self) -> int: ...
+    @property
+    def skew_join_heavy_hitter_threshold(self) -> float: ...
+    @property
+    def temp_dir(self) -> str | None: ...
+    @property
+    def temp_space_quota_bytes(self) -> int | None: ...
+    @property
+    def native_parallelism(self) -> int | None: ...
+    @property
+    def native_parquet_writer(self) -> bool: ...
+    @property
+    def native_csv_writer(self) -> bool: ...
+    @property
+    def state_store_backend(self) -> Literal["memory", "rocksdb"]: ...
+    @property
+    def compress_buffered_partitions(self) -> bool: ...
+    @property
+    def udf_shared_memory_transport(self) -> bool: ...
+    @property
+    def sort_memory_limit_bytes(self) -> int: ...
+    @property
+    def native_iceberg_commit(self) -> bool: ...
+    @property
+    def repartition_memory_limit_bytes(self) -> int: ...
 
 class PyDaftPlanningConfig:
     @staticmethod
@@ -1770,9 +2231,15 @@ class PyDaftPlanningConfig:
     def with_config_values(
         self,
         default_io_config: IOConfig | None = None,
+        default_collation: str | None = None,
+        default_schema_hints: PySchema | None = None,
     ) -> PyDaftPlanningConfig: ...
     @property
     def default_io_config(self) -> IOConfig: ...
+    @property
+    def default_collation(self) -> str | None: ...
+    @property
+    def default_schema_hints(self) -> PySchema | None: ...
 
 def build_type() -> str: ...
 def version() -> str: ...
@@ -4,8 +4,16 @@ from daft.daft import LogicalPlanBuilder as PyLogicalPlanBuilder
 
 if TYPE_CHECKING:
     from daft.catalog.python_catalog import PythonCatalog
+    from daft.daft import IOConfig
 
 def read_table(name: str) -> PyLogicalPlanBuilder: ...
 def register_table(name: str, plan_builder: PyLogicalPlanBuilder) -> str: ...
 def register_python_catalog(catalog: PythonCatalog, catalog_name: str | None) -> str: ...
+def register_iceberg_rest_catalog(
+    uri: str,
+    warehouse: str | None = None,
+    token: str | None = None,
+    catalog_name: str | None = None,
+    io_config: IOConfig | None = None,
+) -> str: ...
 def unregister_catalog(catalog_name: str | None) -> bool: ...
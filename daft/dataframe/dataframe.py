@@ -4,11 +4,13 @@
 # in order to support runtime typechecking across different Python versions.
 # For technical details, see https://github.com/Eventual-Inc/Daft/pull/630
 
+import datetime
 import io
 import multiprocessing
 import os
 import pathlib
 import typing
+import uuid
 import warnings
 from dataclasses import dataclass
 from functools import partial, reduce
@@ -32,12 +34,12 @@ from typing import (
 from daft.api_annotations import DataframePublicAPI
 from daft.context import get_context
 from daft.convert import InputListType
-from daft.daft import FileFormat, IOConfig, JoinStrategy, JoinType, check_column_name_validity
+from daft.daft import CsvWriteOptions, FileFormat, IOConfig, JoinStrategy, JoinType, check_column_name_validity
 from daft.dataframe.preview import DataFramePreview
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
 from daft.expressions import Expression, ExpressionsProjection, col, lit
-from daft.filesystem import overwrite_files
+from daft.filesystem import commit_write_manifest, delete_files, overwrite_files
 from daft.logical.builder import LogicalPlanBuilder
 from daft.runners.partitioning import LocalPartitionSet, PartitionCacheEntry, PartitionSet
 from daft.table import MicroPartition
@@ -52,8 +54,9 @@ if TYPE_CHECKING:
     import ray
     import torch
 
-    from daft.io import DataCatalogTable
+    from daft.io import DataCatalogTable, ParquetEncryptionConfig, WriteConstraint
     from daft.unity_catalog import UnityCatalogTable
+    from daft.window import WindowFunction
 
 from daft.logical.schema import Schema
 
@@ -64,6 +67,15 @@ ColumnInputType = Union[Expression, str]
 ManyColumnsInputType = Union[ColumnInputType, Iterable[ColumnInputType]]
 
 
+def _quarantine_file_format(quarantine_format: Optional[str]) -> Optional[FileFormat]:
+    if quarantine_format is None:
+        return None
+    formats = {"parquet": FileFormat.Parquet, "csv": FileFormat.Csv}
+    if quarantine_format not in formats:
+        raise ValueError(f"Quarantined rows can only be written as `parquet` or `csv`, got: {quarantine_format}")
+    return formats[quarantine_format]
+
+
 def to_logical_plan_builder(*parts: MicroPartition) -> LogicalPlanBuilder:
     """Creates a Daft DataFrame from a single Table.
 
@@ -144,6 +156,7 @@ class DataFrame:
                 num_partitions=num_partitions,
                 size_bytes=size_bytes,
                 num_rows=num_rows,
+                cached_plan=self.__builder,
             )
 
     def _get_current_builder(self) -> LogicalPlanBuilder:
@@ -169,7 +182,9 @@ class DataFrame:
         Args:
             show_all (bool): Whether to show the optimized logical plan and the physical plan in addition to the
                 unoptimized logical plan.
-            format (str): The format to print the plan in. one of 'ascii' or 'mermaid'
+            format (str): The format to print the plan in. One of 'ascii', 'mermaid' or 'html'. 'mermaid' and 'html'
+                show the output schema of each node of the logical plans, and 'html' renders the mermaid diagram
+                in a self-contained HTML document that can be displayed in notebooks or embedded in docs.
             simple (bool): Whether to only show the type of op for each node in the plan, rather than showing details
                 of how each op is configured.
 
@@ -181,7 +196,7 @@ class DataFrame:
             from daft.dataframe.display import MermaidFormatter
             from daft.utils import in_notebook
 
-            instance = MermaidFormatter(self.__builder, show_all, simple, is_cached)
+            instance = MermaidFormatter(self.__builder, show_all, simple, is_cached, verbose=True)
             if file is not None:
                 # if we are printing to a file, we print the markdown representation of the plan
                 text = instance._repr_markdown_()
@@ -193,6 +208,19 @@ class DataFrame:
                 # if we are not in a notebook, we return the raw markdown instead of the class instance
                 return repr(instance)
 
+        if format == "html":
+            from daft.dataframe.display import HtmlFormatter
+            from daft.utils import in_notebook
+
+            instance = HtmlFormatter(self.__builder, show_all, simple, is_cached, verbose=True)
+            if file is not None:
+                print(instance.to_html(), file=file)
+            if in_notebook():
+                # if in a notebook, we return the class instance and let jupyter render its HTML
+                return instance
+            else:
+                return instance.to_html()
+
         print_to_file = partial(print, file=file)
 
         if self._result_cache is not None:
@@ -210,7 +238,7 @@ class DataFrame:
             print_to_file(builder.pretty_print(simple))
             if get_context().get_or_create_runner().name != "native":
                 print_to_file("\n== Physical Plan ==\n")
-                physical_plan_scheduler = builder.to_physical_plan_scheduler(get_context().daft_execution_config)
+                physical_plan_scheduler = builder.to_physical_plan_scheduler(builder.execution_config())
                 print_to_file(physical_plan_scheduler.pretty_print(simple, format=format))
         else:
             print_to_file(
@@ -218,11 +246,57 @@ class DataFrame:
             )
         return None
 
+    @DataframePublicAPI
+    def column_lineage(self) -> Dict[str, Any]:
+        """Returns the source columns that each column of the DataFrame is derived from.
+
+        Lineage is traced through projections, joins, aggregations and the other operations of the DataFrame's
+        logical plan, back to the columns of the files or in-memory data that the DataFrame reads.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"a": [1, 2], "b": [3, 4]})
+            >>> df = df.select((daft.col("a") + daft.col("b")).alias("sum"))
+            >>> lineage = df.column_lineage()
+            >>> [c["column"] for c in lineage["columns"][0]["sources"]]
+            ['a', 'b']
+
+        Returns:
+            Dict[str, Any]: A JSON-compatible dictionary, with a list of the DataFrame's ``"sources"`` (each with a
+                ``"name"`` and the ``"files"`` it reads) and a list of its ``"columns"``. Each column has a ``"name"``
+                and a list of ``"sources"``, which are the index of a source and the name of a column of that source.
+        """
+        return self.__builder.column_lineage()
+
     def num_partitions(self) -> int:
         # We need to run the optimizer since that could change the number of partitions
-        return (
-            self.__builder.optimize().to_physical_plan_scheduler(get_context().daft_execution_config).num_partitions()
-        )
+        builder = self.__builder.optimize()
+        return builder.to_physical_plan_scheduler(builder.execution_config()).num_partitions()
+
+    @DataframePublicAPI
+    def with_execution_config(self, **overrides: Any) -> "DataFrame":
+        """Returns a DataFrame that runs with the given execution config values instead of those of the current context.
+
+        The overrides apply only to this DataFrame and the DataFrames derived from it, which is useful for tuning one
+        query without changing the config of every other query, e.g. through :func:`daft.set_execution_config`. Config
+        values that are not overridden are those of the current context when this method is called. When DataFrames
+        with different configs are combined, such as in a join, the config of the left DataFrame is used.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"a": [1, 2, 3]})
+            >>> df = df.with_execution_config(default_morsel_size=1, native_parallelism=1)
+            >>> df.where(daft.col("a") > 1).to_pydict()
+            {'a': [2, 3]}
+
+        Args:
+            **overrides: Keyword arguments accepted by :func:`daft.set_execution_config`.
+
+        Returns:
+            DataFrame: DataFrame that runs with the overridden execution config.
+        """
+        daft_execution_config = self.__builder.execution_config().with_config_values(**overrides)
+        return DataFrame(self.__builder.with_execution_config(daft_execution_config))
 
     @DataframePublicAPI
     def schema(self) -> Schema:
@@ -555,6 +629,14 @@ class DataFrame:
         write_mode: Literal["append", "overwrite"] = "append",
         partition_cols: Optional[List[ColumnInputType]] = None,
         io_config: Optional[IOConfig] = None,
+        encryption: Optional["ParquetEncryptionConfig"] = None,
+        cluster_by: Optional[List[ColumnInputType]] = None,
+        clustering: Literal["z-order", "hilbert"] = "z-order",
+        job_id: Optional[str] = None,
+        constraints: Optional[List["WriteConstraint"]] = None,
+        on_constraint_violation: Literal["fail", "drop", "quarantine"] = "fail",
+        quarantine_dir: Optional[Union[str, pathlib.Path]] = None,
+        quarantine_format: Optional[Literal["parquet", "csv"]] = None,
     ) -> "DataFrame":
         """Writes the DataFrame as parquet files, returning a new DataFrame with paths to the files that were written.
 
@@ -569,6 +651,21 @@ class DataFrame:
             write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace table with new data. Defaults to "append".
             partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
             io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            encryption (Optional[ParquetEncryptionConfig], optional): Columns to encrypt with Parquet modular encryption,
+                and the keys to encrypt them with. Defaults to None, which writes unencrypted files.
+            cluster_by (Optional[List[ColumnInputType]], optional): Numeric or temporal columns to cluster the written rows by, so that readers filtering on any of them can skip more row groups and files. Defaults to None.
+            clustering (str, optional): The space-filling curve to sort rows along when `cluster_by` is set, either "z-order" or "hilbert". Defaults to "z-order".
+            job_id (Optional[str], optional): ID of the write job, which makes retries of the write safe. Files are written under staging names and committed
+                once the write succeeds, replacing the files of earlier writes with the same job ID. Defaults to None.
+            constraints (Optional[List[WriteConstraint]], optional): Checks that every written row has to pass, such as
+                ``WriteConstraint.not_null("id")``. Only supported by the native runner. Defaults to None.
+            on_constraint_violation (str, optional): What to do with rows that violate any of the `constraints`: `fail` fails the write,
+                `drop` skips the rows, and `quarantine` writes them to `quarantine_dir` instead. Defaults to "fail".
+            quarantine_dir (Optional[str], optional): Root file path to write the rows violating `constraints` to, if `on_constraint_violation` is `quarantine`.
+                The quarantined rows are partitioned like the written rows, and have an additional `_violated_constraints` column with the names of the
+                constraints that they violate. Their files are listed in the returned DataFrame too, which then has a `quarantined` column that tells them apart.
+                Unlike the written files, they are not committed under the `job_id`.
+            quarantine_format (Optional[str], optional): Format of the files of quarantined rows, either "parquet" or "csv". Defaults to the format of the write.
 
         Returns:
             DataFrame: The filenames that were written out as strings.
@@ -585,18 +682,34 @@ class DataFrame:
         if partition_cols is not None:
             cols = self.__column_input_to_expression(tuple(partition_cols))
 
-        builder = self._builder.write_tabular(
+        attempt_id = str(uuid.uuid4()) if job_id is not None else None
+        source = self if cluster_by is None else self._sort_by_clustering_curve(cluster_by, clustering)
+        builder = source._builder.write_tabular(
             root_dir=root_dir,
             partition_cols=cols,
             file_format=FileFormat.Parquet,
             compression=compression,
             io_config=io_config,
+            parquet_encryption=encryption,
+            job_id=job_id,
+            attempt_id=attempt_id,
+            constraints=constraints,
+            on_constraint_violation=on_constraint_violation,
+            quarantine_dir=quarantine_dir,
+            quarantine_format=_quarantine_file_format(quarantine_format),
         )
         # Block and write, then retrieve data
         write_df = DataFrame(builder)
         write_df.collect()
         assert write_df._result is not None
 
+        # The native runner commits the files of a write in its write sinks, other runners write files in separate
+        # tasks and commit them once all of the tasks succeed
+        if job_id is not None and get_context().get_or_create_runner().name != "native":
+            assert attempt_id is not None
+            write_df = commit_write_manifest(write_df, root_dir, job_id, attempt_id, io_config)
+            write_df.collect()
+
         if write_mode == "overwrite":
             overwrite_files(write_df, root_dir, io_config)
 
@@ -611,7 +724,12 @@ class DataFrame:
             from daft.table.table_io import write_empty_tabular
 
             file_path = write_empty_tabular(
-                root_dir, FileFormat.Parquet, self.schema(), compression=compression, io_config=io_config
+                root_dir,
+                FileFormat.Parquet,
+                self.schema(),
+                compression=compression,
+                io_config=io_config,
+                parquet_encryption=encryption,
             )
 
             return from_pydict(
@@ -627,6 +745,16 @@ class DataFrame:
         write_mode: Literal["append", "overwrite"] = "append",
         partition_cols: Optional[List[ColumnInputType]] = None,
         io_config: Optional[IOConfig] = None,
+        job_id: Optional[str] = None,
+        delimiter: str = ",",
+        quote: str = '"',
+        quote_style: Literal["needed", "all_valid", "none"] = "needed",
+        header: bool = True,
+        null_value: str = "",
+        constraints: Optional[List["WriteConstraint"]] = None,
+        on_constraint_violation: Literal["fail", "drop", "quarantine"] = "fail",
+        quarantine_dir: Optional[Union[str, pathlib.Path]] = None,
+        quarantine_format: Optional[Literal["parquet", "csv"]] = None,
     ) -> "DataFrame":
         """Writes the DataFrame as CSV files, returning a new DataFrame with paths to the files that were written.
 
@@ -640,6 +768,23 @@ class DataFrame:
             write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace table with new data. Defaults to "append".
             partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
             io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            job_id (Optional[str], optional): ID of the write job, which makes retries of the write safe. Files are written under staging names and committed
+                once the write succeeds, replacing the files of earlier writes with the same job ID. Defaults to None.
+            delimiter (str, optional): Character that separates the values of a row. Defaults to ",".
+            quote (str, optional): Character that values are quoted with. Defaults to '"'.
+            quote_style (str, optional): Which values are quoted: `needed` quotes values that contain the delimiter, the quote character or a line break,
+                `all_valid` quotes every non-null value, and `none` quotes no value and fails the write if a value needs quoting. Defaults to "needed".
+            header (bool, optional): Whether to write a row of column names at the start of each file. Defaults to True.
+            null_value (str, optional): String that null values are written as. Defaults to "".
+            constraints (Optional[List[WriteConstraint]], optional): Checks that every written row has to pass, such as
+                ``WriteConstraint.not_null("id")``. Only supported by the native runner. Defaults to None.
+            on_constraint_violation (str, optional): What to do with rows that violate any of the `constraints`: `fail` fails the write,
+                `drop` skips the rows, and `quarantine` writes them to `quarantine_dir` instead. Defaults to "fail".
+            quarantine_dir (Optional[str], optional): Root file path to write the rows violating `constraints` to, if `on_constraint_violation` is `quarantine`.
+                The quarantined rows are partitioned like the written rows, and have an additional `_violated_constraints` column with the names of the
+                constraints that they violate. Their files are listed in the returned DataFrame too, which then has a `quarantined` column that tells them apart.
+                Unlike the written files, they are not committed under the `job_id`.
+            quarantine_format (Optional[str], optional): Format of the files of quarantined rows, either "parquet" or "csv". Defaults to the format of the write.
 
         Returns:
             DataFrame: The filenames that were written out as strings.
@@ -648,15 +793,26 @@ class DataFrame:
             raise ValueError(f"Only support `append` or `overwrite` mode. {write_mode} is unsupported")
 
         io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+        csv_options = CsvWriteOptions(
+            delimiter=delimiter, quote=quote, quote_style=quote_style, header=header, null_value=null_value
+        )
 
         cols: Optional[List[Expression]] = None
         if partition_cols is not None:
             cols = self.__column_input_to_expression(tuple(partition_cols))
+        attempt_id = str(uuid.uuid4()) if job_id is not None else None
         builder = self._builder.write_tabular(
             root_dir=root_dir,
             partition_cols=cols,
             file_format=FileFormat.Csv,
             io_config=io_config,
+            csv_options=csv_options,
+            job_id=job_id,
+            attempt_id=attempt_id,
+            constraints=constraints,
+            on_constraint_violation=on_constraint_violation,
+            quarantine_dir=quarantine_dir,
+            quarantine_format=_quarantine_file_format(quarantine_format),
         )
 
         # Block and write, then retrieve data
@@ -664,6 +820,13 @@ class DataFrame:
         write_df.collect()
         assert write_df._result is not None
 
+        # The native runner commits the files of a write in its write sinks, other runners write files in separate
+        # tasks and commit them once all of the tasks succeed
+        if job_id is not None and get_context().get_or_create_runner().name != "native":
+            assert attempt_id is not None
+            write_df = commit_write_manifest(write_df, root_dir, job_id, attempt_id, io_config)
+            write_df.collect()
+
         if write_mode == "overwrite":
             overwrite_files(write_df, root_dir, io_config)
 
@@ -677,7 +840,9 @@ class DataFrame:
             from daft import from_pydict
             from daft.table.table_io import write_empty_tabular
 
-            file_path = write_empty_tabular(root_dir, FileFormat.Csv, self.schema(), io_config=io_config)
+            file_path = write_empty_tabular(
+                root_dir, FileFormat.Csv, self.schema(), io_config=io_config, csv_options=csv_options
+            )
 
             return from_pydict(
                 {
@@ -687,11 +852,19 @@ class DataFrame:
 
     @DataframePublicAPI
     def write_iceberg(
-        self, table: "pyiceberg.table.Table", mode: str = "append", io_config: Optional[IOConfig] = None
+        self,
+        table: "pyiceberg.table.Table",
+        mode: str = "append",
+        io_config: Optional[IOConfig] = None,
+        audit: Optional[List[Expression]] = None,
+        cluster_by: Optional[List[ColumnInputType]] = None,
+        clustering: Literal["z-order", "hilbert"] = "z-order",
     ) -> "DataFrame":
         """Writes the DataFrame to an `Iceberg <https://iceberg.apache.org/docs/nightly/>`__ table, returning a new DataFrame with the operations that occurred.
 
         Can be run in either `append` or `overwrite` mode which will either appends the rows in the DataFrame or will delete the existing rows and then append the DataFrame rows respectively.
+        The snapshot is committed with PyIceberg, unless the ``native_iceberg_commit`` execution config is set, in which case Daft commits it itself.
+        Native commits support tables of REST catalogs and tables whose metadata files are numbered versions with a version hint.
 
         .. NOTE::
             This call is **blocking** and will execute the DataFrame when called
@@ -700,6 +873,10 @@ class DataFrame:
             table (pyiceberg.table.Table): Destination `PyIceberg Table <https://py.iceberg.apache.org/reference/pyiceberg/table/#pyiceberg.table.Table>`__ to write dataframe to.
             mode (str, optional): Operation mode of the write. `append` or `overwrite` Iceberg Table. Defaults to "append".
             io_config (IOConfig, optional): A custom IOConfig to use when accessing Iceberg object storage data. If provided, configurations set in `table` are ignored.
+            audit (List[Expression], optional): Boolean expressions that every written row must satisfy for the write to be committed.
+                The written data files are read back and audited before the snapshot is committed, and deleted instead if any row does not satisfy an expression. Defaults to None.
+            cluster_by (List[ColumnInputType], optional): Numeric or temporal columns to cluster the written rows by, so that readers filtering on any of them can skip more data files. Defaults to None.
+            clustering (str, optional): The space-filling curve to sort rows along when `cluster_by` is set, either "z-order" or "hilbert". Defaults to "z-order".
 
         Returns:
             DataFrame: The operations that occurred with this write.
@@ -734,7 +911,8 @@ class DataFrame:
         rows = []
         size = []
 
-        builder = self._builder.write_iceberg(table, io_config)
+        source = self if cluster_by is None else self._sort_by_clustering_curve(cluster_by, clustering)
+        builder = source._builder.write_iceberg(table, io_config)
         write_df = DataFrame(builder)
         write_df.collect()
 
@@ -742,13 +920,16 @@ class DataFrame:
         assert "data_file" in write_result
         data_files = write_result["data_file"]
 
+        if audit:
+            self._audit_staged_files([data_file.file_path for data_file in data_files], audit, io_config)
+
         if mode == "overwrite":
             deleted_files = table.scan().plan_files()
         else:
             deleted_files = []
 
-        schema = table.schema()
-        partitioning: Dict[str, list] = {schema.find_field(field.source_id).name: [] for field in table.spec().fields}
+        # Written and deleted files are reported with the values of each field of the partition spec
+        partitioning: Dict[str, list] = {field.name: [] for field in table.spec().fields}
 
         for data_file in data_files:
             operations.append("ADD")
@@ -769,7 +950,9 @@ class DataFrame:
             for field in partitioning.keys():
                 partitioning[field].append(getattr(data_file.partition, field, None))
 
-        if parse(pyiceberg.__version__) >= parse("0.7.0"):
+        if get_context().daft_execution_config.native_iceberg_commit:
+            self._commit_iceberg_natively(table, mode, data_files, io_config)
+        elif parse(pyiceberg.__version__) >= parse("0.7.0"):
             from pyiceberg.table import ALWAYS_TRUE, TableProperties
 
             if parse(pyiceberg.__version__) >= parse("0.8.0"):
@@ -836,6 +1019,76 @@ class DataFrame:
         return from_pydict(with_operations)
 
     @DataframePublicAPI
+    @staticmethod
+    def _commit_iceberg_natively(
+        table: "pyiceberg.table.Table",
+        mode: str,
+        data_files: List[Any],
+        io_config: IOConfig,
+        replaced_paths: Optional[List[str]] = None,
+    ) -> None:
+        """Commits PyIceberg data files to the table as a new snapshot with Daft's native Iceberg commit.
+
+        Tables of REST catalogs are committed through their catalog, and tables whose metadata files are numbered
+        versions in their ``metadata`` directory are committed by writing the next version. Commits in ``replace`` mode
+        remove the data files at ``replaced_paths``.
+        """
+        import re
+
+        from pyiceberg.catalog.rest import RestCatalog
+
+        from daft.daft import iceberg_commit
+
+        spec_fields = table.spec().fields
+        native_data_files = [
+            {
+                "file_path": data_file.file_path,
+                "file_format": data_file.file_format.value,
+                "partition": [getattr(data_file.partition, field.name, None) for field in spec_fields],
+                "record_count": data_file.record_count,
+                "file_size_in_bytes": data_file.file_size_in_bytes,
+                "column_sizes": data_file.column_sizes,
+                "value_counts": data_file.value_counts,
+                "null_value_counts": data_file.null_value_counts,
+                "nan_value_counts": data_file.nan_value_counts,
+                "lower_bounds": data_file.lower_bounds,
+                "upper_bounds": data_file.upper_bounds,
+                "split_offsets": data_file.split_offsets,
+            }
+            for data_file in data_files
+        ]
+
+        if isinstance(table.catalog, RestCatalog):
+            identifier = table.name()
+            # Older versions of PyIceberg prefix identifiers with the name of the catalog
+            if len(identifier) > 2 and identifier[0] == table.catalog.name:
+                identifier = identifier[1:]
+            iceberg_commit(
+                mode,
+                native_data_files,
+                rest_uri=table.catalog.properties["uri"],
+                rest_table=".".join(identifier),
+                rest_warehouse=table.catalog.properties.get("warehouse"),
+                rest_token=table.catalog.properties.get("token"),
+                replaced_paths=replaced_paths,
+                io_config=io_config,
+            )
+        elif re.fullmatch(
+            re.escape(table.location().rstrip("/")) + r"/metadata/v\d+\.metadata\.json", table.metadata_location
+        ):
+            iceberg_commit(
+                mode,
+                native_data_files,
+                table_location=table.location(),
+                replaced_paths=replaced_paths,
+                io_config=io_config,
+            )
+        else:
+            raise ValueError(
+                "Native Iceberg commits only support tables of REST catalogs and tables whose metadata files are "
+                f"numbered versions, found metadata file: {table.metadata_location}"
+            )
+
     def write_deltalake(
         self,
         table: Union[str, pathlib.Path, "DataCatalogTable", "deltalake.DeltaTable", "UnityCatalogTable"],
@@ -849,117 +1102,119 @@ class DataFrame:
         dynamo_table_name: Optional[str] = None,
         allow_unsafe_rename: bool = False,
         io_config: Optional[IOConfig] = None,
+        audit: Optional[List[Expression]] = None,
+        cluster_by: Optional[List[ColumnInputType]] = None,
+        clustering: Literal["z-order", "hilbert"] = "z-order",
     ) -> "DataFrame":
         """Writes the DataFrame to a `Delta Lake <https://docs.delta.io/latest/index.html>`__ table, returning a new DataFrame with the operations that occurred.
 
         .. NOTE::
             This call is **blocking** and will execute the DataFrame when called
 
+        The data files are committed to the table's transaction log by Daft itself, without the `deltalake` package.
+        Concurrent writers are detected when committing, and a write is retried on top of theirs unless it conflicts with them.
+        This relies on conditional writes of the object store, which local filesystems, S3, GCS and Azure support.
+
         Args:
             table (Union[str, pathlib.Path, DataCatalogTable, deltalake.DeltaTable, UnityCatalogTable]): Destination `Delta Lake Table <https://delta-io.github.io/delta-rs/api/delta_table/>`__ or table URI to write dataframe to.
             partition_cols (List[str], optional): How to subpartition each partition further. If table exists, expected to match table's existing partitioning scheme, otherwise creates the table with specified partition columns. Defaults to None.
             mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace table with new data, `error` will raise an error if table already exists, and `ignore` will not write anything if table already exists. Defaults to "append".
+                Appended data may leave out columns of the table, which are filled with nulls, and have types that the table's types represent exactly, such as int32 columns of an int64 table.
             schema_mode (str, optional): Schema mode of the write. If set to `overwrite`, allows replacing the schema of the table when doing `mode=overwrite`. Schema mode `merge` is currently not supported.
             name (str, optional): User-provided identifier for this table.
             description (str, optional): User-provided description for this table.
             configuration (Mapping[str, Optional[str]], optional): A map containing configuration options for the metadata action.
             custom_metadata (Dict[str, str], optional): Custom metadata to add to the commit info.
-            dynamo_table_name (str, optional): Name of the DynamoDB table to be used as the locking provider if writing to S3.
-            allow_unsafe_rename (bool, optional): Whether to allow unsafe rename when writing to S3 or local disk. Defaults to False.
+            dynamo_table_name (str, optional): Name of the DynamoDB table to lock commits to S3 with, for S3-compatible stores without conditional writes.
+                Commits are then made through the `deltalake` package. Ignored for tables outside of S3. Defaults to None.
+            allow_unsafe_rename (bool, optional): Whether to commit to object stores without conditional writes, where concurrent commits can overwrite each other.
+                Such commits are refused otherwise. Defaults to False.
             io_config (IOConfig, optional): configurations to use when interacting with remote storage.
+            audit (List[Expression], optional): Boolean expressions that every written row must satisfy for the write to be committed.
+                The written data files are read back and audited before they are committed to the transaction log, and deleted instead if any row does not satisfy an expression. Defaults to None.
+            cluster_by (List[ColumnInputType], optional): Numeric or temporal columns to cluster the written rows by, so that readers filtering on any of them can skip more data files. Defaults to None.
+            clustering (str, optional): The space-filling curve to sort rows along when `cluster_by` is set, either "z-order" or "hilbert". Defaults to "z-order".
 
         Returns:
             DataFrame: The operations that occurred with this write.
         """
         import json
 
-        import deltalake
         import pyarrow as pa
-        from deltalake.schema import _convert_pa_schema_to_delta
-        from deltalake.writer import AddAction, try_get_deltatable, write_deltalake_pyarrow
-        from packaging.version import parse
 
         from daft import from_pydict
+        from daft.daft import delta_commit, delta_load_snapshot
         from daft.dependencies import unity_catalog
         from daft.filesystem import get_protocol_from_path
         from daft.io import DataCatalogTable
-        from daft.io._deltalake import large_dtypes_kwargs
-        from daft.io.object_store_options import io_config_to_storage_options
 
         if schema_mode == "merge":
             raise ValueError("Schema mode' merge' is not currently supported for write_deltalake.")
 
-        if parse(deltalake.__version__) < parse("0.14.0"):
-            raise ValueError(f"Write delta lake is only supported on deltalake>=0.14.0, found {deltalake.__version__}")
-
         io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
 
-        # Retrieve table_uri and storage_options from various backends
+        # Retrieve table_uri from various backends
         table_uri: str
-        storage_options: dict
 
-        if isinstance(table, deltalake.DeltaTable):
+        if isinstance(table, str):
+            table_uri = table
+        elif isinstance(table, pathlib.Path):
+            table_uri = str(table)
+        elif unity_catalog.module_available() and isinstance(table, unity_catalog.UnityCatalogTable):
+            table_uri = table.table_uri
+            io_config = table.io_config
+        elif isinstance(table, DataCatalogTable):
+            table_uri = table.table_uri(io_config)
+        elif hasattr(table, "table_uri"):
+            # A deltalake.DeltaTable, which is written to through its URI.
             table_uri = table.table_uri
-            storage_options = table._storage_options or {}
-            new_storage_options = io_config_to_storage_options(io_config, table_uri)
-            storage_options.update(new_storage_options or {})
         else:
-            if isinstance(table, str):
-                table_uri = table
-            elif isinstance(table, pathlib.Path):
-                table_uri = str(table)
-            elif unity_catalog.module_available() and isinstance(table, unity_catalog.UnityCatalogTable):
-                table_uri = table.table_uri
-                io_config = table.io_config
-            elif isinstance(table, DataCatalogTable):
-                table_uri = table.table_uri(io_config)
-            else:
-                raise ValueError(f"Expected table to be a path or a DeltaTable, received: {type(table)}")
-
-            if io_config is None:
-                raise ValueError(
-                    "io_config was not provided to write_deltalake and could not be retrieved from defaults."
-                )
-
-            storage_options = io_config_to_storage_options(io_config, table_uri) or {}
-            table = try_get_deltatable(table_uri, storage_options=storage_options)
-
-        # see: https://delta-io.github.io/delta-rs/usage/writing/writing-to-s3-with-locking-provider/
-        scheme = get_protocol_from_path(table_uri)
-        if scheme == "s3" or scheme == "s3a":
-            if dynamo_table_name is not None:
-                storage_options["AWS_S3_LOCKING_PROVIDER"] = "dynamodb"
-                storage_options["DELTA_DYNAMO_TABLE_NAME"] = dynamo_table_name
-            else:
-                storage_options["AWS_S3_ALLOW_UNSAFE_RENAME"] = "true"
+            raise ValueError(f"Expected table to be a path or a DeltaTable, received: {type(table)}")
 
-                if not allow_unsafe_rename:
-                    warnings.warn("No DynamoDB table specified for Delta Lake locking. Defaulting to unsafe writes.")
-        elif scheme == "file":
-            if allow_unsafe_rename:
-                storage_options["MOUNT_ALLOW_UNSAFE_RENAME"] = "true"
+        if io_config is None:
+            raise ValueError("io_config was not provided to write_deltalake and could not be retrieved from defaults.")
 
-        pyarrow_schema = pa.schema((f.name, f.dtype.to_arrow_dtype()) for f in self.schema())
+        # S3-compatible stores that ignore conditional writes can lock commits in DynamoDB instead.
+        # See: https://delta-io.github.io/delta-rs/usage/writing/writing-to-s3-with-locking-provider/
+        use_dynamodb_lock = dynamo_table_name is not None and get_protocol_from_path(table_uri) in ("s3", "s3a")
 
+        snapshot = delta_load_snapshot(table_uri, io_config=io_config)
         large_dtypes = True
-        delta_schema = _convert_pa_schema_to_delta(pyarrow_schema, **large_dtypes_kwargs(large_dtypes))
+        data = self
 
-        if table:
-            if partition_cols and partition_cols != table.metadata().partition_columns:
+        if snapshot is not None:
+            if partition_cols and partition_cols != snapshot.partition_columns:
                 raise ValueError(
-                    f"Expected partition columns to match that of the existing table ({table.metadata().partition_columns}), but received: {partition_cols}"
+                    f"Expected partition columns to match that of the existing table ({snapshot.partition_columns}), but received: {partition_cols}"
                 )
             else:
-                partition_cols = table.metadata().partition_columns
-
-            table.update_incremental()
-
-            table_schema = table.schema().to_pyarrow(as_large_types=large_dtypes)
-            if delta_schema != table_schema and not (mode == "overwrite" and schema_mode == "overwrite"):
-                raise ValueError(
-                    "Schema of data does not match table schema\n"
-                    f"Data schema:\n{delta_schema}\nTable Schema:\n{table_schema}"
-                )
+                partition_cols = snapshot.partition_columns
+
+            if not snapshot.schema_matches(self.schema()._schema) and not (
+                mode == "overwrite" and schema_mode == "overwrite"
+            ):
+                from daft.io import read_deltalake
+
+                table_schema = read_deltalake(table_uri, io_config=io_config).schema()
+                diff = table_schema.diff(self.schema())
+                # Appended data only needs to be representable in the table schema, with missing columns filled with nulls
+                if mode == "append" and diff.can_append():
+                    data = self.select(
+                        *[
+                            col(field.name).cast(field.dtype)
+                            if field.name in self.column_names
+                            else lit(None).cast(field.dtype).alias(field.name)
+                            for field in table_schema
+                        ]
+                    )
+                if data is self or not snapshot.schema_matches(data.schema()._schema):
+                    incompatibilities = diff.append_incompatibilities() if mode == "append" else []
+                    reasons = incompatibilities or [(name, description) for name, _, description in diff.changes()]
+                    raise ValueError(
+                        "Schema of data does not match table schema\n"
+                        + "".join(f"  {name}: {reason}\n" for name, reason in reasons)
+                        + f"Data schema:\n{self.schema()}\nTable Schema:\n{snapshot.schema_string}"
+                    )
             if mode == "error":
                 raise AssertionError("Delta table already exists, write mode set to error.")
             elif mode == "ignore":
@@ -971,16 +1226,17 @@ class DataFrame:
                         "file_name": pa.array([], type=pa.string()),
                     }
                 )
-            version = table.version() + 1
+            version = snapshot.version + 1
         else:
             version = 0
 
         if partition_cols is not None:
             for c in partition_cols:
-                if self.schema()[c].dtype == DataType.binary():
+                if data.schema()[c].dtype == DataType.binary():
                     raise NotImplementedError("Binary partition columns are not yet supported for Delta Lake writes")
 
-        builder = self._builder.write_deltalake(
+        source = data if cluster_by is None else data._sort_by_clustering_curve(cluster_by, clustering)
+        builder = source._builder.write_deltalake(
             table_uri,
             mode,
             version,
@@ -993,7 +1249,7 @@ class DataFrame:
 
         write_result = write_df.to_pydict()
         assert "add_action" in write_result
-        add_actions: List[AddAction] = write_result["add_action"]
+        add_actions = write_result["add_action"]
 
         operations = []
         paths = []
@@ -1007,33 +1263,53 @@ class DataFrame:
             rows.append(stats["numRecords"])
             sizes.append(add_action.size)
 
-        if table is None:
-            write_deltalake_pyarrow(
+        if audit:
+            data._audit_staged_files(
+                [f"{table_uri.rstrip('/')}/{add_action.path}" for add_action in add_actions],
+                audit,
+                io_config,
+                hive_partitioning=bool(partition_cols),
+            )
+
+        if snapshot is not None and mode == "overwrite":
+            for path, size, num_records in snapshot.files():
+                operations.append("DELETE")
+                paths.append(path)
+                rows.append(num_records)
+                sizes.append(size)
+
+        if use_dynamodb_lock:
+            from daft.delta_lake.delta_lake_write import commit_with_dynamodb_lock
+
+            commit_with_dynamodb_lock(
                 table_uri,
-                delta_schema,
-                add_actions,
-                mode,
+                "overwrite" if mode == "overwrite" else "append",
+                pa.schema((field.name, field.dtype.to_arrow_dtype()) for field in data.schema()),
                 partition_cols or [],
+                add_actions,
                 name,
                 description,
                 configuration,
-                storage_options,
                 custom_metadata,
+                dynamo_table_name,
+                io_config,
             )
         else:
-            if mode == "overwrite":
-                old_actions = table.get_add_actions()
-                old_actions_dict = old_actions.to_pydict()
-                for i in range(old_actions.num_rows):
-                    operations.append("DELETE")
-                    paths.append(old_actions_dict["path"][i])
-                    rows.append(old_actions_dict["num_records"][i])
-                    sizes.append(old_actions_dict["size_bytes"][i])
-
-            table._table.create_write_transaction(
-                add_actions, mode, partition_cols or [], delta_schema, None, custom_metadata
+            delta_commit(
+                table_uri,
+                snapshot,
+                "overwrite" if mode == "overwrite" else "append",
+                data.schema()._schema,
+                partition_cols or [],
+                [add_action.to_json() for add_action in add_actions],
+                overwrite_schema=schema_mode == "overwrite",
+                name=name,
+                description=description,
+                configuration=configuration,
+                custom_metadata=custom_metadata,
+                allow_unsafe_rename=allow_unsafe_rename,
+                io_config=io_config,
             )
-            table.update_incremental()
 
         with_operations = from_pydict(
             {
@@ -1046,6 +1322,81 @@ class DataFrame:
 
         return with_operations
 
+    def _sort_by_clustering_curve(self, cluster_by: List[ColumnInputType], clustering: str) -> "DataFrame":
+        """Sorts rows along a Z-order or Hilbert curve through the space of the ``cluster_by`` columns.
+
+        The values of each column are scaled by the range of the column to the bits of its dimension of the curve, so the
+        DataFrame is materialized to compute the ranges before it is sorted.
+        """
+        from daft.daft import clustering_key
+
+        exprs = self.__column_input_to_expression(tuple(cluster_by))
+        if not 1 <= len(exprs) <= 64:
+            raise ValueError(f"Expected 1 to 64 columns to cluster by, got {len(exprs)}")
+
+        schema = self.schema()
+        values = []
+        for expr in exprs:
+            dtype = expr._to_field(schema).dtype
+            if dtype._is_temporal_type():
+                expr = expr.cast(DataType.int64())
+            elif not (dtype._is_numeric_type() or dtype == DataType.bool()):
+                raise ValueError(f"Can only cluster by numeric or temporal columns, got {expr} of type {dtype}")
+            values.append(expr.cast(DataType.float64()))
+
+        materialized = self.collect()
+        bounds = materialized.agg(
+            *[value.min().alias(f"min_{i}") for i, value in enumerate(values)],
+            *[value.max().alias(f"max_{i}") for i, value in enumerate(values)],
+        ).to_pydict()
+
+        # float64 represents integers exactly up to 53 bits
+        scale = float(2 ** min(64 // len(values), 52) - 1)
+        coords = []
+        for i, value in enumerate(values):
+            lo, hi = bounds[f"min_{i}"][0], bounds[f"max_{i}"][0]
+            if lo is None or hi is None or lo == hi:
+                coord = lit(0)
+            else:
+                coord = ((value - lo) / (hi - lo) * scale).floor()
+            coords.append(coord.cast(DataType.uint64())._expr)
+
+        return materialized.sort(Expression._from_pyexpr(clustering_key(coords, clustering)))
+
+    def _audit_staged_files(
+        self,
+        paths: List[str],
+        audit: List[Expression],
+        io_config: IOConfig,
+        hive_partitioning: bool = False,
+    ) -> None:
+        """Audits the data files of a write before they are committed, deleting them if any row fails an audit.
+
+        A row fails an audit expression that evaluates to false or null for it. Partition columns that are only stored
+        in the paths of the files are read from the paths with ``hive_partitioning``.
+        """
+        from daft.io import read_parquet
+
+        if not paths:
+            return
+
+        try:
+            staged = read_parquet(paths, io_config=io_config, hive_partitioning=hive_partitioning)
+            if hive_partitioning:
+                staged = staged.select(*[col(field.name).cast(field.dtype) for field in self.schema()])
+            failures = []
+            for expr in audit:
+                num_failed = staged.where(~expr.fill_null(lit(False))).count_rows()
+                if num_failed > 0:
+                    failures.append(f"{expr}: {num_failed} rows failed")
+        except Exception:
+            delete_files(paths, io_config)
+            raise
+
+        if failures:
+            delete_files(paths, io_config)
+            raise ValueError("Audit of the written data failed, so the write was not committed:\n" + "\n".join(failures))
+
     @DataframePublicAPI
     def write_lance(
         self,
@@ -1340,6 +1691,32 @@ class DataFrame:
         builder = self._builder.distinct()
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def assert_unique(self, *keys: ManyColumnsInputType) -> "DataFrame":
+        """Fails the query if two rows have equal values of ``keys``, otherwise passes the rows through unchanged.
+
+        The check runs as the DataFrame is executed, and its error names some of the duplicate keys. Null keys equal each other.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"id": [1, 2, 2], "x": ["a", "b", "c"]})
+            >>> df.assert_unique("id", "x").count_rows()
+            3
+            >>> df.assert_unique("id").collect()  # doctest: +SKIP
+            Traceback (most recent call last):
+            ...
+            daft.exceptions.DaftCoreException: DaftError::ValueError Found 1 rows with duplicate keys, including: (id=2)
+
+        Args:
+            *keys (Union[str, Expression]): columns whose values must be unique together
+
+        Returns:
+            DataFrame: DataFrame with the same rows, which fails to execute if the keys aren't unique.
+        """
+        key_exprs = self._wildcard_inputs_to_expressions(keys)
+        builder = self._builder.assert_unique(key_exprs)
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def sample(
         self,
@@ -1559,12 +1936,13 @@ class DataFrame:
         self,
         by: Union[ColumnInputType, List[ColumnInputType]],
         desc: Union[bool, List[bool]] = False,
+        nulls_first: Optional[Union[bool, List[bool]]] = None,
     ) -> "DataFrame":
         """Sorts DataFrame globally.
 
         Note:
             * Since this a global sort, this requires an expensive repartition which can be quite slow.
-            * Supports multicolumn sorts and can have unique `descending` flag per column.
+            * Supports multicolumn sorts and can have unique `descending` and `nulls_first` flags per column.
 
         Example:
             >>> import daft
@@ -1606,9 +1984,18 @@ class DataFrame:
             <BLANKLINE>
             (Showing first 4 of 4 rows)
 
+            Nulls sort after all other values in ascending order and before them in descending order, unless
+            `nulls_first` says otherwise:
+
+            >>> df = daft.from_pydict({"x": [2, None, 1]})
+            >>> df.sort("x", nulls_first=True).to_pydict()
+            {'x': [None, 1, 2]}
+
         Args:
             column (Union[ColumnInputType, List[ColumnInputType]]): column to sort by. Can be `str` or expression as well as a list of either.
             desc (Union[bool, List[bool]), optional): Sort by descending order. Defaults to False.
+            nulls_first (Union[bool, List[bool]], optional): Whether to place nulls before all other values. Defaults to
+                the value of `desc`.
 
         Returns:
             DataFrame: Sorted DataFrame.
@@ -1620,7 +2007,7 @@ class DataFrame:
 
         sort_by = self.__column_input_to_expression(by)
 
-        builder = self._builder.sort(sort_by=sort_by, descending=desc, nulls_first=desc)
+        builder = self._builder.sort(sort_by=sort_by, descending=desc, nulls_first=nulls_first)
         return DataFrame(builder)
 
     @DataframePublicAPI
@@ -1678,8 +2065,8 @@ class DataFrame:
     def repartition(self, num: Optional[int], *partition_by: ColumnInputType) -> "DataFrame":
         """Repartitions DataFrame to ``num`` partitions.
 
-        If columns are passed in, then DataFrame will be repartitioned by those, otherwise
-        random repartitioning will occur.
+        If columns are passed in, then DataFrame will be repartitioned by the hash of those, otherwise the rows are
+        spread evenly over the partitions: randomly on distributed runners and round-robin on the native runner.
 
         .. NOTE::
 
@@ -1714,6 +2101,47 @@ class DataFrame:
             builder = self._builder.hash_repartition(num, self.__column_input_to_expression(partition_by))
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def repartition_by_range(
+        self,
+        num: Optional[int],
+        *partition_by: ColumnInputType,
+        desc: Union[bool, List[bool]] = False,
+    ) -> "DataFrame":
+        """Repartitions DataFrame to ``num`` partitions which hold consecutive ranges of the ``partition_by`` columns.
+
+        The boundaries of the ranges are computed from a sample of the DataFrame, in the same way as for
+        :meth:`DataFrame.sort <daft.DataFrame.sort>`, so the partitions have roughly the same number of rows. Unlike a
+        sort, the rows within each partition are not sorted. This is useful to cluster the rows of written files, so
+        that each file covers a narrow range of values which readers can skip with file statistics.
+
+        .. NOTE::
+
+            This function will globally shuffle your data, which is potentially a very expensive operation.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": [3, 1, 2, 4]})
+            >>> df = df.repartition_by_range(2, "x")
+            >>> df.num_partitions()
+            2
+
+        Args:
+            num (Optional[int]): Number of target partitions; if None, the number of partitions will not be changed.
+            *partition_by (Union[str, Expression]): Columns to partition by.
+            desc (Union[bool, List[bool]]): Whether the ranges of each column are in descending order. Defaults to
+                False.
+
+        Returns:
+            DataFrame: Repartitioned DataFrame.
+        """
+        if len(partition_by) == 0:
+            raise ValueError("repartition_by_range requires at least one column to partition by")
+        if isinstance(desc, bool):
+            desc = [desc] * len(partition_by)
+        builder = self._builder.range_repartition(num, self.__column_input_to_expression(partition_by), desc)
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def into_partitions(self, num: int) -> "DataFrame":
         """Splits or coalesces DataFrame to ``num`` partitions. Order is preserved.
@@ -2527,6 +2955,128 @@ class DataFrame:
         builder = self._builder.pivot(group_by_expr, pivot_col_expr, value_col_expr, agg_expr, names)
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def window_agg(
+        self,
+        event_time_col: str,
+        window_size: Union[datetime.timedelta, int],
+        *to_agg: Expression,
+        window_slide: Optional[Union[datetime.timedelta, int]] = None,
+        group_by: Optional[ManyColumnsInputType] = None,
+        watermark_delay: Optional[Union[datetime.timedelta, int]] = None,
+    ) -> "DataFrame":
+        """Performs aggregations within event-time windows, such as the per-minute counts of the events of a stream.
+
+        Windows of ``window_size`` start every ``window_slide``, and are tumbling if no slide is given or hopping if
+        they overlap, in which case each row is aggregated in every window that it falls into. The result has the
+        ``group_by`` columns, ``window_start`` and ``window_end`` columns with the bounds of each window, and the
+        aggregations.
+
+        Windows are output as soon as the watermark passes their end, which trails the latest event time seen so far
+        by ``watermark_delay``, so they can be consumed from an unbounded stream read with :func:`daft.read_stream`.
+        Rows with an event time behind the watermark are late and are dropped. The windows that are still open are
+        output when the input ends. Only the native runner can aggregate windows.
+
+        Example:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict({"t": [1, 4, 6, 12], "x": [1, 2, 3, 4]})
+            >>> df.window_agg("t", 5, col("x").sum()).sort("window_start").to_pydict()  # doctest: +SKIP
+            {'window_start': [0, 5, 10], 'window_end': [5, 10, 15], 'x': [3, 3, 4]}
+
+        Args:
+            event_time_col (str): Integer, timestamp or date column with the event time of each row
+            window_size (Union[timedelta, int]): Length of each window, either as a timedelta or as an integer in the
+                units of the event time column
+            *to_agg (Expression): aggregation expressions
+            window_slide (Optional[Union[timedelta, int]]): Distance between the starts of consecutive windows, which
+                must be at most the window size. Defaults to the window size.
+            group_by (Optional[ManyColumnsInputType]): columns to group by within each window
+            watermark_delay (Optional[Union[timedelta, int]]): How far the watermark trails the latest event time.
+                Defaults to the watermark delay of the stream if it's read with the same event time column, else 0.
+
+        Returns:
+            DataFrame: DataFrame with the aggregations of each window
+        """
+        from daft.io._stream import _duration_in_column_units
+
+        schema = self.schema()
+        if event_time_col not in schema.column_names():
+            raise ValueError(f"Event time column {event_time_col} is not in the schema of the DataFrame: {schema}")
+
+        def in_column_units(
+            duration: Optional[Union[datetime.timedelta, int]], description: str
+        ) -> Optional[int]:
+            if duration is None:
+                return None
+            return _duration_in_column_units(schema, event_time_col, duration, description)
+
+        for expr in to_agg:
+            if not isinstance(expr, Expression):
+                raise ValueError(f"DataFrame.window_agg() only accepts expression type, received: {type(expr)}")
+
+        group_by_exprs = self._column_inputs_to_expressions(group_by) if group_by is not None else []
+        builder = self._builder.window_agg(
+            list(to_agg),
+            group_by_exprs,
+            event_time_col,
+            typing.cast(int, in_column_units(window_size, "window size")),
+            in_column_units(window_slide, "window slide"),
+            in_column_units(watermark_delay, "watermark delay"),
+        )
+        return DataFrame(builder)
+
+    @DataframePublicAPI
+    def window(
+        self,
+        *functions: "WindowFunction",
+        partition_by: Optional[ManyColumnsInputType] = None,
+        order_by: Optional[ManyColumnsInputType] = None,
+        desc: Union[bool, List[bool]] = False,
+        nulls_first: Optional[Union[bool, List[bool]]] = None,
+    ) -> "DataFrame":
+        """Evaluates window functions, such as row numbers or running sums, over the partitions of the DataFrame.
+
+        The rows with equal ``partition_by`` keys form a partition, and the window functions of each row are evaluated
+        over the rows of its partition in the order of the ``order_by`` keys. The result has the columns of the
+        DataFrame followed by a column for each window function, which is named after the function unless it's aliased.
+        The rows are output grouped by partition. Only the native runner can evaluate window functions.
+
+        Example:
+            >>> import daft
+            >>> from daft import col, window
+            >>> df = daft.from_pydict({"k": ["a", "a", "b", "a"], "x": [3, 1, 2, 2]})
+            >>> df = df.window(window.row_number(), window.sum(col("x")).alias("total"), partition_by="k", order_by="x")
+            >>> df.sort(["k", "x"]).to_pydict()  # doctest: +SKIP
+            {'k': ['a', 'a', 'a', 'b'], 'x': [1, 2, 3, 2], 'row_number': [1, 2, 3, 1], 'total': [1, 3, 6, 2]}
+
+        Args:
+            *functions (WindowFunction): window functions from :mod:`daft.window`
+            partition_by (Optional[ManyColumnsInputType]): columns whose equal values form the partitions of the
+                window. Defaults to a single partition with all of the rows.
+            order_by (Optional[ManyColumnsInputType]): columns to order the rows of each partition by
+            desc (Union[bool, List[bool]], optional): Order by descending order. Defaults to False.
+            nulls_first (Union[bool, List[bool]], optional): Whether to place nulls before all other values. Defaults
+                to the value of `desc`.
+
+        Returns:
+            DataFrame: DataFrame with the values of the window functions
+        """
+        from daft.window import WindowFunction
+
+        if len(functions) == 0:
+            raise ValueError("DataFrame.window() requires at least one window function")
+        for function in functions:
+            if not isinstance(function, WindowFunction):
+                raise TypeError(
+                    f"DataFrame.window() only accepts window functions from daft.window, received: {type(function)}"
+                )
+
+        partition_by_exprs = self._column_inputs_to_expressions(partition_by) if partition_by is not None else []
+        order_by_exprs = self._column_inputs_to_expressions(order_by) if order_by is not None else []
+        builder = self._builder.window(list(functions), partition_by_exprs, order_by_exprs, desc, nulls_first)
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def intersect(self, other: "DataFrame") -> "DataFrame":
         """Returns the intersection of two DataFrames.
@@ -2557,6 +3107,34 @@ class DataFrame:
         builder = self._builder.intersect(other._builder)
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def compare(self, other: "DataFrame", on: Union[str, List[str]]) -> "DataFrame":
+        """Returns the row-level differences between this DataFrame and ``other``, whose rows are matched by the ``on`` columns.
+
+        Both DataFrames must have the same columns with the same types, and the ``on`` columns must be unique in each
+        of them. The rows that differ are returned with the ``on`` columns and a ``diff`` column, which is ``"added"``
+        for rows that are only in ``other``, ``"removed"`` for rows that are only in this DataFrame and ``"changed"`` for
+        rows whose other columns differ. Each other column ``c`` is returned as ``left.c`` and ``right.c``, along with
+        whether it changed as ``changed.c``. See :func:`daft.testing.assert_df_equal` to count the differences.
+
+        Example:
+            >>> import daft
+            >>> df1 = daft.from_pydict({"id": [1, 2, 3], "v": ["a", "b", "c"]})
+            >>> df2 = daft.from_pydict({"id": [2, 3, 4], "v": ["b", "x", "d"]})
+            >>> df1.compare(df2, on="id").sort("id").to_pydict()
+            {'id': [1, 3, 4], 'diff': ['removed', 'changed', 'added'], 'left.v': ['a', 'c', None], 'right.v': [None, 'x', 'd'], 'changed.v': [False, True, False]}
+
+        Args:
+            other (DataFrame): DataFrame to compare with
+            on (Union[str, List[str]]): Columns that identify the rows of both DataFrames
+
+        Returns:
+            DataFrame: DataFrame with the rows that differ between the two DataFrames
+        """
+        keys = [on] if isinstance(on, str) else list(on)
+        builder = self._builder.compare(other._builder, keys)
+        return DataFrame(builder)
+
     def _materialize_results(self) -> None:
         """Materializes the results of for this DataFrame and hold a pointer to the results."""
         context = get_context()
@@ -2607,7 +3185,7 @@ class DataFrame:
             # Iteratively retrieve partitions until enough data has been materialized
             tables = []
             seen = 0
-            for table in get_context().get_or_create_runner().run_iter_tables(builder, results_buffer_size=1):
+            for table in get_context().get_or_create_runner().run_preview_tables(builder, n):
                 tables.append(table)
                 seen += len(table)
                 if seen >= n:
@@ -3111,6 +3689,26 @@ class GroupedDataFrame:
         """
         return self.df._apply_agg_fn(Expression.agg_concat, cols, self.group_by)
 
+    def top_k_by(self, col: ColumnInputType, k: int) -> "DataFrame":
+        """Collects the ``k`` largest non-null values of a column in each group, in descending order.
+
+        Each partition only keeps the ``k`` largest values of each of its groups, so this avoids sorting every group.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"pet": ["cat", "dog", "dog", "cat", "cat"], "age": [1, 2, 3, 4, 5]})
+            >>> df.groupby("pet").top_k_by("age", 2).sort("pet").to_pydict()
+            {'pet': ['cat', 'dog'], 'age': [[5, 4], [3, 2]]}
+
+        Args:
+            col (Union[str, Expression]): column to take the largest values of
+            k (int): number of values to keep per group
+
+        Returns:
+            DataFrame: DataFrame with a list of the largest values per group.
+        """
+        return self.df._apply_agg_fn(lambda c: c.top_k(k), (col,), self.group_by)
+
     def agg(self, *to_agg: Union[Expression, Iterable[Expression]]) -> "DataFrame":
         """Perform aggregations on this GroupedDataFrame. Allows for mixed aggregations.
 
@@ -1,3 +1,4 @@
+import html
 from typing import Optional
 
 from daft.context import get_context
@@ -21,31 +22,47 @@ class SubgraphOptions:
 
 class MermaidOptions:
     simple: bool
+    verbose: bool
     bottom_up: bool
     subgraph_options: Optional[SubgraphOptions]
 
-    def __init__(self, simple: bool = False, bottom_up=False, subgraph_options: Optional[SubgraphOptions] = None):
+    def __init__(
+        self,
+        simple: bool = False,
+        bottom_up=False,
+        subgraph_options: Optional[SubgraphOptions] = None,
+        verbose: bool = False,
+    ):
         self.simple = simple
+        self.verbose = verbose
         self.bottom_up = bottom_up
         self.subgraph_options = subgraph_options
 
     def with_subgraph_options(self, name: str, subgraph_id: str):
-        opts = MermaidOptions(self.simple, subgraph_options=SubgraphOptions(name, subgraph_id))
+        opts = MermaidOptions(self.simple, subgraph_options=SubgraphOptions(name, subgraph_id), verbose=self.verbose)
 
         return opts
 
 
 class MermaidFormatter:
-    def __init__(self, builder, show_all: bool = False, simple: bool = False, is_cached: bool = False):
+    def __init__(
+        self,
+        builder,
+        show_all: bool = False,
+        simple: bool = False,
+        is_cached: bool = False,
+        verbose: bool = False,
+    ):
         self.builder = builder
         self.show_all = show_all
         self.simple = simple
         self.is_cached = is_cached
+        self.verbose = verbose
 
     def _repr_markdown_(self):
         builder = self.builder
         output = ""
-        display_opts = MermaidOptions(simple=self.simple)
+        display_opts = MermaidOptions(simple=self.simple, verbose=self.verbose)
         # TODO handle cached plans
         if self.show_all:
             output = "```mermaid\n"
@@ -82,3 +99,52 @@ class MermaidFormatter:
 
     def __repr__(self):
         return self._repr_markdown_()
+
+
+# Version of mermaid.js that HTML plans load to render their diagrams.
+MERMAID_JS_URL = "https://cdn.jsdelivr.net/npm/mermaid@10/dist/mermaid.esm.min.mjs"
+
+
+class HtmlFormatter(MermaidFormatter):
+    """Renders plans as a standalone HTML page, which draws the mermaid diagram of the plans with mermaid.js.
+
+    The page can be opened in a browser, embedded in docs, or displayed in notebooks.
+    """
+
+    def _mermaid_diagram(self) -> str:
+        markdown = self._repr_markdown_()
+        return markdown[len("```mermaid\n") : markdown.rindex("```")]
+
+    def _repr_html_(self) -> str:
+        # The diagram is HTML-escaped because node labels contain expressions such as `col(a) < 1`, and
+        # mermaid.js reads the unescaped text content of the element.
+        diagram = html.escape(self._mermaid_diagram(), quote=False)
+        footer = (
+            ""
+            if self.show_all
+            else "<p>Set <code>show_all=True</code> to also see the Optimized and Physical plans.</p>\n"
+        )
+        return (
+            '<div class="daft-plan">\n'
+            f'<pre class="mermaid">\n{diagram}</pre>\n'
+            f"{footer}"
+            '<script type="module">\n'
+            f'import mermaid from "{MERMAID_JS_URL}";\n'
+            'mermaid.initialize({ startOnLoad: false, flowchart: { htmlLabels: false } });\n'
+            'await mermaid.run({ querySelector: ".daft-plan .mermaid" });\n'
+            "</script>\n"
+            "</div>\n"
+        )
+
+    def to_html(self) -> str:
+        """Returns a self-contained HTML document that renders the plans."""
+        return (
+            "<!DOCTYPE html>\n"
+            "<html>\n"
+            '<head><meta charset="utf-8"><title>Daft Query Plan</title></head>\n'
+            f"<body>\n{self._repr_html_()}</body>\n"
+            "</html>\n"
+        )
+
+    def __repr__(self):
+        return self.to_html()
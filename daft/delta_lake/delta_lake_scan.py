@@ -26,9 +26,36 @@ if TYPE_CHECKING:
     from collections.abc import Iterator
     from datetime import datetime
 
+    from deltalake.schema import Schema as DeltaSchema
+
+    from daft.daft import PyExpr
+
 logger = logging.getLogger(__name__)
 
 
+def delta_generated_columns(schema: DeltaSchema) -> list[tuple[str, PyExpr]]:
+    """The expressions of the generated columns of a Delta Lake schema, which compute the values of the columns
+    for data files that were written before the columns were added to the table.
+    """
+    generated = []
+    for field in schema.fields:
+        generation_expression = field.metadata.get("delta.generationExpression")
+        if generation_expression is None:
+            continue
+        try:
+            expr = daft.sql_expr(generation_expression)
+        except Exception as e:
+            logger.warning(
+                "Generated column %s of the Delta Lake table will be null for data files without it, since its expression %s can't be parsed: %s",
+                field.name,
+                generation_expression,
+                e,
+            )
+            continue
+        generated.append((field.name, expr._expr))
+    return generated
+
+
 class DeltaLakeScanOperator(ScanOperator):
     def __init__(
         self, table_uri: str, storage_config: StorageConfig, version: int | str | datetime | None = None
@@ -107,6 +134,7 @@ class DeltaLakeScanOperator(ScanOperator):
         self._partition_keys = [
             PartitionField(field._field) for field in self._schema if field.name in partition_columns
         ]
+        self._column_defaults = delta_generated_columns(self._table.schema())
 
     def schema(self) -> Schema:
         return self._schema
@@ -234,6 +262,8 @@ class DeltaLakeScanOperator(ScanOperator):
             )
             if st is None:
                 continue
+            if self._column_defaults:
+                st = st.with_column_defaults(self._column_defaults)
             if record_count is not None:
                 rows_left -= record_count
             scan_tasks.append(st)
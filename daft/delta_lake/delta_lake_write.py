@@ -1,32 +1,77 @@
-from typing import TYPE_CHECKING, Dict, Iterator, List, Optional, Tuple
+import dataclasses
+import datetime
+import decimal
+import json
+from typing import Any, Dict, Iterator, List, Optional, Tuple
 
-from daft.context import get_context
 from daft.daft import IOConfig
 from daft.datatype import DataType
-from daft.dependencies import pa
+from daft.dependencies import pa, pq
 from daft.io.common import _get_schema_from_dict
 from daft.table.micropartition import MicroPartition
 from daft.table.partitioning import PartitionedTable, partition_strings_to_path
 
-if TYPE_CHECKING:
-    from deltalake.writer import AddAction
+
+@dataclasses.dataclass(frozen=True)
+class AddAction:
+    """A data file written to a Delta Lake table, as an ``add`` action of the Delta protocol."""
+
+    path: str
+    size: int
+    partition_values: Dict[str, Optional[str]]
+    modification_time: int
+    data_change: bool
+    stats: str
+
+    def to_json(self) -> str:
+        return json.dumps(
+            {
+                "path": self.path,
+                "size": self.size,
+                "partitionValues": self.partition_values,
+                "modificationTime": self.modification_time,
+                "dataChange": self.data_change,
+                "stats": self.stats,
+            }
+        )
+
+
+def _to_delta_arrow_type(dtype: pa.DataType, large_dtypes: bool) -> pa.DataType:
+    """Converts an Arrow type to the type Delta Lake stores it as."""
+    if pa.types.is_timestamp(dtype):
+        return pa.timestamp("us", tz="UTC" if dtype.tz is not None else None)
+    elif pa.types.is_unsigned_integer(dtype):
+        return getattr(pa, f"int{dtype.bit_width}")()
+    elif pa.types.is_string(dtype) or pa.types.is_large_string(dtype):
+        return pa.large_string() if large_dtypes else pa.string()
+    elif pa.types.is_binary(dtype) or pa.types.is_large_binary(dtype) or pa.types.is_fixed_size_binary(dtype):
+        return pa.large_binary() if large_dtypes else pa.binary()
+    elif pa.types.is_list(dtype) or pa.types.is_large_list(dtype) or pa.types.is_fixed_size_list(dtype):
+        value_field = dtype.value_field.with_type(_to_delta_arrow_type(dtype.value_type, large_dtypes))
+        return pa.large_list(value_field) if large_dtypes else pa.list_(value_field)
+    elif pa.types.is_struct(dtype):
+        return pa.struct([field.with_type(_to_delta_arrow_type(field.type, large_dtypes)) for field in dtype])
+    elif pa.types.is_map(dtype):
+        return pa.map_(
+            _to_delta_arrow_type(dtype.key_type, large_dtypes),
+            _to_delta_arrow_type(dtype.item_type, large_dtypes),
+        )
+    return dtype
 
 
 def sanitize_table_for_deltalake(
     table: MicroPartition, large_dtypes: bool, partition_keys: Optional[List[str]] = None
 ) -> pa.Table:
-    from deltalake.schema import _convert_pa_schema_to_delta
-
-    from daft.io._deltalake import large_dtypes_kwargs
-
     arrow_table = table.to_arrow()
 
     # Remove partition keys from the table since they are already encoded as keys
     if partition_keys is not None:
         arrow_table = arrow_table.drop_columns(partition_keys)
 
-    arrow_batch = _convert_pa_schema_to_delta(arrow_table.schema, **large_dtypes_kwargs(large_dtypes))
-    return arrow_table.cast(arrow_batch)
+    delta_schema = pa.schema(
+        [field.with_type(_to_delta_arrow_type(field.type, large_dtypes)) for field in arrow_table.schema]
+    )
+    return arrow_table.cast(delta_schema)
 
 
 def partitioned_table_to_deltalake_iter(
@@ -49,53 +94,133 @@ def partitioned_table_to_deltalake_iter(
         yield converted_arrow_table, "/", {}
 
 
+def _stats_value(value: Any) -> Any:
+    """Converts a parquet statistic to JSON, or returns None if Delta does not keep statistics of its type."""
+    if isinstance(value, (bool, int, float, str)):
+        return value
+    elif isinstance(value, datetime.datetime):
+        if value.tzinfo is not None:
+            value = value.astimezone(datetime.timezone.utc).replace(tzinfo=None)
+        return value.isoformat(timespec="milliseconds") + "Z"
+    elif isinstance(value, datetime.date):
+        return value.isoformat()
+    elif isinstance(value, decimal.Decimal):
+        return float(value)
+    return None
+
+
+def get_file_stats_from_metadata(metadata: pq.FileMetaData) -> Dict[str, Any]:
+    """Collects the Delta statistics of a written parquet file from its footer."""
+    min_values: Dict[str, Any] = {}
+    max_values: Dict[str, Any] = {}
+    null_count: Dict[str, Any] = {}
+
+    def set_nested(values: Dict[str, Any], path: List[str], value: Any) -> None:
+        for key in path[:-1]:
+            values = values.setdefault(key, {})
+        values[path[-1]] = value
+
+    for column_idx in range(metadata.num_columns):
+        column_stats = [metadata.row_group(i).column(column_idx) for i in range(metadata.num_row_groups)]
+        path = column_stats[0].path_in_schema.split(".") if column_stats else []
+        # Statistics are only kept for primitive columns, not for the elements of lists or maps.
+        if not path or any(part in ("list", "element", "key_value") for part in path[1:]):
+            continue
+        stats = [column.statistics for column in column_stats]
+        if any(stat is None for stat in stats):
+            continue
+        if all(stat.has_null_count for stat in stats):
+            set_nested(null_count, path, sum(stat.null_count for stat in stats))
+        if all(stat.has_min_max for stat in stats) and stats:
+            minimum = _stats_value(min(stat.min for stat in stats))
+            maximum = _stats_value(max(stat.max for stat in stats))
+            if minimum is not None and maximum is not None:
+                set_nested(min_values, path, minimum)
+                set_nested(max_values, path, maximum)
+
+    return {
+        "numRecords": metadata.num_rows,
+        "minValues": min_values,
+        "maxValues": max_values,
+        "nullCount": null_count,
+    }
+
+
 def make_deltalake_add_action(
     path,
     metadata,
     size,
     partition_values,
-):
-    import json
-    from datetime import datetime
-
-    import deltalake
-    from deltalake.writer import (
-        AddAction,
-        DeltaJSONEncoder,
-        get_file_stats_from_metadata,
-    )
-    from packaging.version import parse
+    table_path: str = "",
+) -> AddAction:
+    stats = get_file_stats_from_metadata(metadata)
 
-    # added to get_file_stats_from_metadata in deltalake v0.17.4: non-optional "num_indexed_cols" and "columns_to_collect_stats" arguments
-    # https://github.com/delta-io/delta-rs/blob/353e08be0202c45334dcdceee65a8679f35de710/python/deltalake/writer.py#L725
-    if parse(deltalake.__version__) < parse("0.17.4"):
-        file_stats_args = {}
-    else:
-        file_stats_args = {"num_indexed_cols": -1, "columns_to_collect_stats": None}
-
-    stats = get_file_stats_from_metadata(metadata, **file_stats_args)
-
-    # remove leading slash
+    # make the path relative to the table, and remove the leading slash
+    path = path[len(table_path) :] if path.startswith(table_path) else path
     path = path[1:] if path.startswith("/") else path
     return AddAction(
         path,
         size,
         partition_values,
-        int(datetime.now().timestamp() * 1000),
+        int(datetime.datetime.now().timestamp() * 1000),
         True,
-        json.dumps(stats, cls=DeltaJSONEncoder),
+        json.dumps(stats),
     )
 
 
-def make_deltalake_fs(path: str, io_config: Optional[IOConfig] = None):
-    from deltalake.writer import DeltaStorageHandler
-    from pyarrow.fs import PyFileSystem
+def commit_with_dynamodb_lock(
+    table_uri: str,
+    mode: str,
+    schema: pa.Schema,
+    partition_cols: List[str],
+    add_actions: List[AddAction],
+    name: Optional[str],
+    description: Optional[str],
+    configuration: Optional[Dict[str, Optional[str]]],
+    custom_metadata: Optional[Dict[str, str]],
+    dynamo_table_name: str,
+    io_config: IOConfig,
+) -> None:
+    """Commits the add actions to a Delta table on S3 through the `deltalake` package, which serializes commits with
+    a lock in the DynamoDB table ``dynamo_table_name``. See https://delta-io.github.io/delta-rs/usage/writing/writing-to-s3-with-locking-provider/.
+    """
+    from deltalake.writer import AddAction as DeltaAddAction
+    from deltalake.writer import try_get_deltatable, write_deltalake_pyarrow
 
     from daft.io.object_store_options import io_config_to_storage_options
 
-    io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
-    storage_options = io_config_to_storage_options(io_config, path)
-    return PyFileSystem(DeltaStorageHandler(path, storage_options))
+    storage_options = io_config_to_storage_options(io_config, table_uri) or {}
+    storage_options["AWS_S3_LOCKING_PROVIDER"] = "dynamodb"
+    storage_options["DELTA_DYNAMO_TABLE_NAME"] = dynamo_table_name
+
+    delta_schema = pa.schema(field.with_type(_to_delta_arrow_type(field.type, True)) for field in schema)
+    actions = [
+        DeltaAddAction(
+            action.path,
+            action.size,
+            action.partition_values,
+            action.modification_time,
+            action.data_change,
+            action.stats,
+        )
+        for action in add_actions
+    ]
+    table = try_get_deltatable(table_uri, storage_options=storage_options)
+    if table is None:
+        write_deltalake_pyarrow(
+            table_uri,
+            delta_schema,
+            actions,
+            mode,
+            partition_cols,
+            name,
+            description,
+            configuration,
+            storage_options,
+            custom_metadata,
+        )
+    else:
+        table._table.create_write_transaction(actions, mode, partition_cols, delta_schema, None, custom_metadata)
 
 
 class DeltaLakeWriteVisitors:
@@ -120,14 +245,15 @@ class DeltaLakeWriteVisitors:
                 size = 0
 
             add_action = make_deltalake_add_action(
-                written_file.path, written_file.metadata, size, self.partition_values
+                written_file.path, written_file.metadata, size, self.partition_values, self.parent.table_path
             )
 
             self.parent.add_actions.append(add_action)
 
-    def __init__(self, fs: pa.fs.FileSystem):
+    def __init__(self, fs: pa.fs.FileSystem, table_path: str):
         self.add_actions: List[AddAction] = []
         self.fs = fs
+        self.table_path = table_path
 
     def visitor(self, partition_values: Dict[str, Optional[str]]) -> "DeltaLakeWriteVisitors.FileVisitor":
         return self.FileVisitor(self, partition_values)
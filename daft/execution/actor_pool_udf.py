@@ -4,6 +4,7 @@ import logging
 import multiprocessing as mp
 from typing import TYPE_CHECKING
 
+from daft.execution.shared_memory import recv_partition, send_partition
 from daft.expressions import Expression, ExpressionsProjection
 from daft.table import MicroPartition
 
@@ -15,35 +16,40 @@ if TYPE_CHECKING:
 logger = logging.getLogger(__name__)
 
 
-def actor_event_loop(uninitialized_projection: ExpressionsProjection, conn: Connection) -> None:
+def actor_event_loop(
+    uninitialized_projection: ExpressionsProjection, conn: Connection, use_shared_memory: bool
+) -> None:
     """Event loop that runs in a actor process and receives MicroPartitions to evaluate with an initialized UDF projection.
 
-    Terminates once it receives None.
+    Terminates once it receives None. If `use_shared_memory` is set, outputs are sent back through shared memory where
+    possible.
     """
     initialized_projection = ExpressionsProjection([e._initialize_udfs() for e in uninitialized_projection])
 
     while True:
-        input: MicroPartition | None = conn.recv()
+        input = recv_partition(conn)
         if input is None:
             break
 
         output = input.eval_expression_list(initialized_projection)
-        conn.send(output)
+        send_partition(conn, output, use_shared_memory)
 
 
 class ActorHandle:
     """Handle class for initializing, interacting with, and tearing down a single local actor process."""
 
-    def __init__(self, projection: list[PyExpr]) -> None:
+    def __init__(self, projection: list[PyExpr], use_shared_memory: bool = False) -> None:
         self.handle_conn, actor_conn = mp.Pipe()
+        self.use_shared_memory = use_shared_memory
 
         expr_projection = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in projection])
-        self.actor_process = mp.Process(target=actor_event_loop, args=(expr_projection, actor_conn))
+        self.actor_process = mp.Process(target=actor_event_loop, args=(expr_projection, actor_conn, use_shared_memory))
         self.actor_process.start()
 
     def eval_input(self, input: PyMicroPartition) -> PyMicroPartition:
-        self.handle_conn.send(MicroPartition._from_pymicropartition(input))
-        output: MicroPartition = self.handle_conn.recv()
+        send_partition(self.handle_conn, MicroPartition._from_pymicropartition(input), self.use_shared_memory)
+        output = recv_partition(self.handle_conn)
+        assert output is not None
         return output._micropartition
 
     def teardown(self) -> None:
@@ -5,8 +5,9 @@ from dataclasses import dataclass, field
 from typing import TYPE_CHECKING, Generic, Protocol
 
 from daft.context import get_context
-from daft.daft import JoinSide, ResourceRequest
-from daft.expressions import Expression, ExpressionsProjection, col
+from daft.datatype import DataType
+from daft.daft import JoinSide, ResourceRequest, fetch_flight_shuffle_partition
+from daft.expressions import Expression, ExpressionsProjection, col, lit
 from daft.runners.partitioning import (
     Boundaries,
     MaterializedResult,
@@ -22,7 +23,8 @@ if TYPE_CHECKING:
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
-    from daft.daft import FileFormat, IOConfig, JoinType, ScanTask
+    from daft.daft import CsvWriteOptions, FileFormat, IOConfig, JoinType, ScanTask
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
     from daft.logical.map_partition_ops import MapPartitionOp
     from daft.logical.schema import Schema
 
@@ -368,6 +370,10 @@ class WriteFile(SingleOutputInstruction):
     compression: str | None
     partition_cols: ExpressionsProjection | None
     io_config: IOConfig | None
+    parquet_encryption: ParquetEncryptionConfig | None = None
+    csv_options: CsvWriteOptions | None = None
+    job_id: str | None = None
+    attempt_id: str | None = None
 
     def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
         return self._write_file(inputs)
@@ -397,6 +403,10 @@ class WriteFile(SingleOutputInstruction):
             compression=self.compression,
             partition_cols=self.partition_cols,
             io_config=self.io_config,
+            parquet_encryption=self.parquet_encryption,
+            csv_options=self.csv_options,
+            job_id=self.job_id,
+            attempt_id=self.attempt_id,
         )
 
 
@@ -561,6 +571,29 @@ class Project(SingleOutputInstruction):
         ]
 
 
+@dataclass(frozen=True)
+class ExcludeColumns(SingleOutputInstruction):
+    columns: list[str]
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._exclude_columns(inputs)
+
+    def _exclude_columns(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        [input] = inputs
+        projection = ExpressionsProjection([col(name) for name in input.column_names() if name not in self.columns])
+        return [input.eval_expression_list(projection)]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        [input_meta] = input_metadatas
+        return [
+            PartialPartitionMetadata(
+                num_rows=input_meta.num_rows,
+                size_bytes=None,
+                boundaries=input_meta.boundaries,
+            )
+        ]
+
+
 @dataclass(frozen=True)
 class ActorPoolProject(SingleOutputInstruction):
     projection: ExpressionsProjection
@@ -767,6 +800,22 @@ class Pivot(SingleOutputInstruction):
         ]
 
 
+@dataclass(frozen=True)
+class AssertUnique(SingleOutputInstruction):
+    keys: ExpressionsProjection
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._assert_unique(inputs)
+
+    def _assert_unique(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        [input] = inputs
+        input.assert_unique(self.keys)
+        return [input]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        return input_metadatas
+
+
 @dataclass(frozen=True)
 class Unpivot(SingleOutputInstruction):
     ids: ExpressionsProjection
@@ -896,17 +945,45 @@ class ReduceMerge(ReduceInstruction):
         ]
 
 
+@dataclass(frozen=True)
+class FetchFlightShufflePartition(SingleOutputInstruction):
+    """Fetches a partition of a flight shuffle from the shuffle workers that received the outputs of its map tasks."""
+
+    addresses: list[str]
+    shuffle_id: str
+    stage_id: int
+    partition_idx: int
+    auth_token: str = field(repr=False)
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._fetch_flight_shuffle_partition(inputs)
+
+    def _fetch_flight_shuffle_partition(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        assert len(inputs) == 0
+        partition = fetch_flight_shuffle_partition(
+            self.addresses, self.shuffle_id, self.stage_id, self.partition_idx, auth_token=self.auth_token
+        )
+        return [MicroPartition._from_pymicropartition(partition)]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        assert len(input_metadatas) == 0
+        return [PartialPartitionMetadata(num_rows=None, size_bytes=None)]
+
+
 @dataclass(frozen=True)
 class ReduceMergeAndSort(ReduceInstruction):
     sort_by: ExpressionsProjection
     descending: list[bool]
     bounds: MicroPartition
+    nulls_first: list[bool] | None = None
 
     def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
         return self._reduce_merge_and_sort(inputs)
 
     def _reduce_merge_and_sort(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
-        partition = MicroPartition.concat(inputs).sort(self.sort_by, descending=self.descending)
+        partition = MicroPartition.concat(inputs).sort(
+            self.sort_by, descending=self.descending, nulls_first=self.nulls_first
+        )
         return [partition]
 
     def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
@@ -953,6 +1030,51 @@ class ReduceToQuantiles(ReduceInstruction):
         ]
 
 
+SKEW_SALT_COLUMN = "__daft_skew_salt"
+SKEW_KEY_HASH_COLUMN = "__daft_skew_key_hash"
+
+
+def skew_key_hash(keys: ExpressionsProjection) -> Expression:
+    """Hash of all the join keys of a row, used to identify the hot keys of a skewed join."""
+    key_hash = None
+    for key in keys:
+        key_hash = key.hash() if key_hash is None else key.hash(seed=key_hash)
+    assert key_hash is not None, "Skew salting requires at least one join key"
+    return key_hash.alias(SKEW_KEY_HASH_COLUMN)
+
+
+@dataclass(frozen=True)
+class ReduceToHeavyHitters(ReduceInstruction):
+    """Finds the hot keys in samples of key hashes.
+
+    A key is hot if it has more samples than `heavy_hitter_threshold` times the samples of an average partition.
+    """
+
+    num_partitions: int
+    heavy_hitter_threshold: float
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._reduce_to_heavy_hitters(inputs)
+
+    def _reduce_to_heavy_hitters(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        merged = MicroPartition.concat(inputs)
+        min_count = max(1.0, self.heavy_hitter_threshold * len(merged) / self.num_partitions)
+        counts = merged.agg(
+            [col(SKEW_KEY_HASH_COLUMN).count().alias("count")],
+            ExpressionsProjection([col(SKEW_KEY_HASH_COLUMN)]),
+        )
+        hot_keys = counts.filter(ExpressionsProjection([col("count") > lit(min_count)]))
+        return [hot_keys.eval_expression_list(ExpressionsProjection([col(SKEW_KEY_HASH_COLUMN)]))]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        return [
+            PartialPartitionMetadata(
+                num_rows=None,
+                size_bytes=None,
+            )
+        ]
+
+
 def calculate_cross_join_stats(
     left_meta: PartialPartitionMetadata, right_meta: PartialPartitionMetadata
 ) -> tuple[int | None, int | None]:
@@ -1041,10 +1163,56 @@ class FanoutHash(FanoutInstruction):
         return input.partition_by_hash(self.partition_by, num_partitions=self._num_outputs)
 
 
+@dataclass(frozen=True)
+class FanoutSaltedHash(FanoutInstruction):
+    """Hash partitions by `partition_by` and a salt column, which spreads the rows of hot keys across partitions.
+
+    On the salted side, each row of a hot key gets one of `salt_factor` salts. On the replicated side, each row of a
+    hot key is copied once for every salt, so that it still meets all the rows of that key on the salted side.
+    The rows of other keys get a salt of 0 on both sides.
+    """
+
+    partition_by: ExpressionsProjection
+    salt_factor: int
+    replicate: bool
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._fanout_salted_hash(inputs)
+
+    def _fanout_salted_hash(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        [hot_keys, input] = inputs
+        hot_hashes = hot_keys.get_column(SKEW_KEY_HASH_COLUMN)
+        is_hot = skew_key_hash(self.partition_by).is_in(hot_hashes) if len(hot_hashes) > 0 else lit(False)
+        columns = [col(name) for name in input.column_names()]
+
+        if self.replicate:
+
+            def with_salt(partition: MicroPartition, salt: int) -> MicroPartition:
+                salt_expr = lit(salt).cast(DataType.int64()).alias(SKEW_SALT_COLUMN)
+                return partition.eval_expression_list(ExpressionsProjection([*columns, salt_expr]))
+
+            cold = input.filter(ExpressionsProjection([~is_hot]))
+            hot = input.filter(ExpressionsProjection([is_hot]))
+            salted = MicroPartition.concat(
+                [with_salt(cold, 0)] + [with_salt(hot, salt) for salt in range(self.salt_factor)]
+            )
+        else:
+            row_id = "__daft_skew_row_id"
+            salt = is_hot.if_else(col(row_id) % lit(self.salt_factor), lit(0))
+            salted = input.add_monotonically_increasing_id(0, row_id).eval_expression_list(
+                ExpressionsProjection([*columns, salt.cast(DataType.int64()).alias(SKEW_SALT_COLUMN)])
+            )
+
+        return salted.partition_by_hash(
+            ExpressionsProjection([*self.partition_by, col(SKEW_SALT_COLUMN)]), num_partitions=self._num_outputs
+        )
+
+
 @dataclass(frozen=True)
 class FanoutRange(FanoutInstruction, Generic[PartitionT]):
     sort_by: ExpressionsProjection
     descending: list[bool]
+    nulls_first: list[bool] | None = None
 
     def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
         return self._fanout_range(inputs)
@@ -1055,7 +1223,9 @@ class FanoutRange(FanoutInstruction, Generic[PartitionT]):
             return [input]
 
         table_boundaries = boundaries.to_table()
-        partitioned_tables = input.partition_by_range(self.sort_by, table_boundaries, self.descending)
+        partitioned_tables = input.partition_by_range(
+            self.sort_by, table_boundaries, self.descending, nulls_first=self.nulls_first
+        )
 
         # Pad the partitioned_tables with empty tables if fewer than self._num_outputs were returned
         # This can happen when all values are null or empty, which leads to an empty `boundaries` input
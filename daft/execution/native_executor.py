@@ -41,3 +41,37 @@ class NativeExecutor:
             LocalMaterializedResult(MicroPartition._from_pymicropartition(part))
             for part in self._executor.run(psets_mp, daft_execution_config, results_buffer_size)
         )
+
+    def execute_streaming(
+        self,
+        psets: dict[str, list[MaterializedResult[PartitionT]]],
+        daft_execution_config: PyDaftExecutionConfig,
+        buffer_size: int = 1,
+        ordered: bool = True,
+    ) -> Iterator[MicroPartition]:
+        """Runs the plan, yielding its results as they are produced.
+
+        Execution pauses while `buffer_size` results wait to be consumed. If `ordered` is False, results are yielded
+        as soon as any partition produces them rather than in partition order.
+        """
+        psets_mp = {
+            part_id: [part.micropartition()._micropartition for part in parts] for part_id, parts in psets.items()
+        }
+        return (
+            MicroPartition._from_pymicropartition(part)
+            for part in self._executor.execute_streaming(psets_mp, daft_execution_config, buffer_size, ordered)
+        )
+
+    def preview(
+        self,
+        psets: dict[str, list[MaterializedResult[PartitionT]]],
+        daft_execution_config: PyDaftExecutionConfig,
+        num_rows: int,
+    ) -> Iterator[MicroPartition]:
+        psets_mp = {
+            part_id: [part.micropartition()._micropartition for part in parts] for part_id, parts in psets.items()
+        }
+        return (
+            MicroPartition._from_pymicropartition(part)
+            for part in self._executor.preview(psets_mp, daft_execution_config, num_rows)
+        )
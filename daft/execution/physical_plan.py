@@ -42,7 +42,7 @@ from daft.execution.execution_step import (
     SingleOutputPartitionTask,
     calculate_cross_join_stats,
 )
-from daft.expressions import ExpressionsProjection
+from daft.expressions import ExpressionsProjection, col
 from daft.runners.partitioning import (
     MaterializedResult,
     PartitionT,
@@ -59,7 +59,8 @@ if TYPE_CHECKING:
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
-    from daft.daft import FileFormat, IOConfig, JoinType
+    from daft.daft import CsvWriteOptions, FileFormat, IOConfig, JoinType
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
     from daft.logical.schema import Schema
 
 
@@ -98,6 +99,10 @@ def file_write(
     compression: str | None,
     partition_cols: ExpressionsProjection | None,
     io_config: IOConfig | None,
+    parquet_encryption: ParquetEncryptionConfig | None = None,
+    csv_options: CsvWriteOptions | None = None,
+    job_id: str | None = None,
+    attempt_id: str | None = None,
 ) -> InProgressPhysicalPlan[PartitionT]:
     """Write the results of `child_plan` into files described by `write_info`."""
     yield from (
@@ -109,6 +114,10 @@ def file_write(
                 compression=compression,
                 partition_cols=partition_cols,
                 io_config=io_config,
+                parquet_encryption=parquet_encryption,
+                csv_options=csv_options,
+                job_id=job_id,
+                attempt_id=attempt_id,
             ),
         )
         if isinstance(step, PartitionTaskBuilder)
@@ -422,6 +431,190 @@ def hash_join(
                 return
 
 
+def _materialize_plan(
+    child_plan: InProgressPhysicalPlan[PartitionT],
+) -> Generator[None | PartitionTask[PartitionT], None, deque[SingleOutputPartitionTask[PartitionT]]]:
+    """Materializes all the partitions of `child_plan`, and returns them once they are done."""
+    materializations: deque[SingleOutputPartitionTask[PartitionT]] = deque()
+    stage_id = next(stage_id_counter)
+    for step in child_plan:
+        if isinstance(step, PartitionTaskBuilder):
+            step = step.finalize_partition_task_single_output(stage_id=stage_id)
+            materializations.append(step)
+        yield step
+
+    while any(not _.done() for _ in materializations):
+        logger.debug("materialization blocked on completion of sources: %s", materializations)
+        yield None
+
+    return materializations
+
+
+def _sample_hot_keys(
+    sources: deque[SingleOutputPartitionTask[PartitionT]],
+    keys: ExpressionsProjection,
+    num_partitions: int,
+    heavy_hitter_threshold: float,
+) -> Generator[None | PartitionTask[PartitionT], None, SingleOutputPartitionTask[PartitionT]]:
+    """Samples the hashes of `keys` in `sources`, and returns the task which computes the hot keys from them."""
+    sample_size = get_context().daft_execution_config.sample_size_for_sort
+    sample_by = ExpressionsProjection([execution_step.skew_key_hash(keys)])
+    sample_materializations: deque[SingleOutputPartitionTask[PartitionT]] = deque()
+    stage_id_sampling = next(stage_id_counter)
+    for source in sources:
+        sample = (
+            PartitionTaskBuilder[PartitionT](
+                inputs=[source.partition()],
+                partial_metadatas=None,
+            )
+            .add_instruction(
+                instruction=execution_step.Sample(size=sample_size, sort_by=sample_by),
+            )
+            .finalize_partition_task_single_output(stage_id=stage_id_sampling)
+        )
+        sample_materializations.append(sample)
+        yield sample
+
+    while any(not _.done() for _ in sample_materializations):
+        logger.debug("skew salting blocked on completion of all samples: %s", sample_materializations)
+        yield None
+
+    hot_keys = (
+        PartitionTaskBuilder[PartitionT](
+            inputs=[sample.partition() for sample in consume_deque(sample_materializations)],
+            partial_metadatas=None,
+        )
+        .add_instruction(
+            execution_step.ReduceToHeavyHitters(
+                num_partitions=num_partitions,
+                heavy_hitter_threshold=heavy_hitter_threshold,
+            ),
+        )
+        .finalize_partition_task_single_output(stage_id=next(stage_id_counter))
+    )
+    yield hot_keys
+
+    while not hot_keys.done():
+        logger.debug("skew salting blocked on completion of hot keys: %s", hot_keys)
+        yield None
+
+    return hot_keys
+
+
+def _salted_fanout_plan(
+    sources: deque[SingleOutputPartitionTask[PartitionT]],
+    hot_keys: SingleOutputPartitionTask[PartitionT],
+    keys: ExpressionsProjection,
+    num_partitions: int,
+    salt_factor: int,
+    replicate: bool,
+) -> InProgressPhysicalPlan[PartitionT]:
+    return (
+        PartitionTaskBuilder[PartitionT](
+            inputs=[hot_keys.partition(), source.partition()],
+            partial_metadatas=[hot_keys.partition_metadata(), source.partition_metadata()],
+            resource_request=ResourceRequest(
+                memory_bytes=source.partition_metadata().size_bytes,
+            ),
+        ).add_instruction(
+            instruction=execution_step.FanoutSaltedHash(
+                _num_outputs=num_partitions,
+                partition_by=keys,
+                salt_factor=salt_factor,
+                replicate=replicate,
+            ),
+        )
+        for source in consume_deque(sources)
+    )
+
+
+def skew_salted_hash_join(
+    left_plan: InProgressPhysicalPlan[PartitionT],
+    right_plan: InProgressPhysicalPlan[PartitionT],
+    left_on: ExpressionsProjection,
+    right_on: ExpressionsProjection,
+    null_equals_nulls: None | list[bool],
+    how: JoinType,
+    num_partitions: int,
+    salt_factor: int,
+    heavy_hitter_threshold: float,
+    salt_left: bool,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Hash join which spreads the rows of hot keys across `num_partitions` partitions.
+
+    The hot keys are found from samples of the keys of the salted side (the left side if `salt_left`, otherwise the
+    right side). Both sides are then hash partitioned by their keys and a salt, where the rows of hot keys get one of
+    `salt_factor` salts on the salted side, and are copied for every salt on the other side.
+    """
+    left_sources = yield from _materialize_plan(left_plan)
+    right_sources = yield from _materialize_plan(right_plan)
+
+    salted_sources, salted_keys = (left_sources, left_on) if salt_left else (right_sources, right_on)
+    hot_keys = yield from _sample_hot_keys(salted_sources, salted_keys, num_partitions, heavy_hitter_threshold)
+
+    left_fanout_plan = _salted_fanout_plan(
+        left_sources, hot_keys, left_on, num_partitions, salt_factor, replicate=not salt_left
+    )
+    right_fanout_plan = _salted_fanout_plan(
+        right_sources, hot_keys, right_on, num_partitions, salt_factor, replicate=salt_left
+    )
+
+    salt_column = col(execution_step.SKEW_SALT_COLUMN)
+    joined = hash_join(
+        left_plan=reduce(left_fanout_plan, execution_step.ReduceMerge()),
+        right_plan=reduce(right_fanout_plan, execution_step.ReduceMerge()),
+        left_on=ExpressionsProjection([*left_on, salt_column]),
+        right_on=ExpressionsProjection([*right_on, salt_column]),
+        null_equals_nulls=None if null_equals_nulls is None else [*null_equals_nulls, True],
+        how=how,
+    )
+    yield from pipeline_instruction(
+        joined,
+        execution_step.ExcludeColumns([execution_step.SKEW_SALT_COLUMN]),
+        ResourceRequest(),
+    )
+
+
+def co_partitioned_hash_join(
+    left_plan: InProgressPhysicalPlan[PartitionT],
+    right_plan: InProgressPhysicalPlan[PartitionT],
+    left_on: ExpressionsProjection,
+    right_on: ExpressionsProjection,
+    null_equals_nulls: None | list[bool],
+    how: JoinType,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Hash join of sides that are hash partitioned by their keys, where one has a multiple of the other's partitions.
+
+    A row whose keys hash to `h` is in partition `h % n` of a side with `n` partitions, so partition `i` of the side
+    with more partitions only joins with partition `i % n` of the other side.
+    """
+    left_sources = list((yield from _materialize_plan(left_plan)))
+    right_sources = list((yield from _materialize_plan(right_plan)))
+
+    for i in range(max(len(left_sources), len(right_sources))):
+        left = left_sources[i % len(left_sources)]
+        right = right_sources[i % len(right_sources)]
+        left_size_bytes = left.partition_metadata().size_bytes
+        right_size_bytes = right.partition_metadata().size_bytes
+        yield PartitionTaskBuilder[PartitionT](
+            inputs=[left.partition(), right.partition()],
+            partial_metadatas=[left.partition_metadata(), right.partition_metadata()],
+            resource_request=ResourceRequest(
+                memory_bytes=None
+                if left_size_bytes is None or right_size_bytes is None
+                else left_size_bytes + right_size_bytes
+            ),
+        ).add_instruction(
+            instruction=execution_step.HashJoin(
+                left_on=left_on,
+                right_on=right_on,
+                null_equals_nulls=null_equals_nulls,
+                how=how,
+                is_swapped=False,
+            )
+        )
+
+
 def _create_broadcast_join_step(
     broadcaster_parts: deque[SingleOutputPartitionTask[PartitionT]],
     receiver_part: SingleOutputPartitionTask[PartitionT],
@@ -1549,14 +1742,21 @@ def reduce(
         ).add_instruction(reduce_instructions_.popleft())
 
 
-def sort(
+def _sample_range_boundaries(
     child_plan: InProgressPhysicalPlan[PartitionT],
     sort_by: ExpressionsProjection,
     descending: list[bool],
     nulls_first: list[bool],
     num_partitions: int,
-) -> InProgressPhysicalPlan[PartitionT]:
-    """Sort the result of `child_plan` according to `sort_info`."""
+) -> Generator[
+    None | PartitionTask[PartitionT],
+    None,
+    tuple[deque[SingleOutputPartitionTask[PartitionT]], SingleOutputPartitionTask[PartitionT]],
+]:
+    """Materializes `child_plan` and computes the boundaries of `num_partitions` ranges of `sort_by` from samples of it.
+
+    Returns the materialized partitions of `child_plan`, and the task which computes the boundaries.
+    """
     # First, materialize the child plan.
     source_materializations: deque[SingleOutputPartitionTask[PartitionT]] = deque()
     stage_id_children = next(stage_id_counter)
@@ -1566,14 +1766,14 @@ def sort(
             source_materializations.append(step)
         yield step
 
-    # Sample all partitions (to be used for calculating sort boundaries).
+    # Sample all partitions (to be used for calculating range boundaries).
     sample_materializations: deque[SingleOutputPartitionTask[PartitionT]] = deque()
     stage_id_sampling = next(stage_id_counter)
 
     sample_size = get_context().daft_execution_config.sample_size_for_sort
     for source in source_materializations:
         while not source.done():
-            logger.debug("sort blocked on completion of source: %s", source)
+            logger.debug("range partitioning blocked on completion of source: %s", source)
             yield None
 
         sample = (
@@ -1592,12 +1792,12 @@ def sort(
 
     # Wait for samples to materialize.
     while any(not _.done() for _ in sample_materializations):
-        logger.debug("sort blocked on completion of all samples: %s", sample_materializations)
+        logger.debug("range partitioning blocked on completion of all samples: %s", sample_materializations)
         yield None
 
     stage_id_reduce = next(stage_id_counter)
 
-    # Reduce the samples to get sort boundaries.
+    # Reduce the samples to get range boundaries.
     boundaries = (
         PartitionTaskBuilder[PartitionT](
             inputs=[sample.partition() for sample in consume_deque(sample_materializations)],
@@ -1617,11 +1817,21 @@ def sort(
 
     # Wait for boundaries to materialize.
     while not boundaries.done():
-        logger.debug("sort blocked on completion of boundary partition: %s", boundaries)
+        logger.debug("range partitioning blocked on completion of boundary partition: %s", boundaries)
         yield None
 
-    # Create a range fanout plan.
-    range_fanout_plan = (
+    return source_materializations, boundaries
+
+
+def _range_fanout_plan(
+    source_materializations: deque[SingleOutputPartitionTask[PartitionT]],
+    boundaries: SingleOutputPartitionTask[PartitionT],
+    sort_by: ExpressionsProjection,
+    descending: list[bool],
+    nulls_first: list[bool],
+    num_partitions: int,
+) -> InProgressPhysicalPlan[PartitionT]:
+    return (
         PartitionTaskBuilder[PartitionT](
             inputs=[boundaries.partition(), source.partition()],
             partial_metadatas=[boundaries.partition_metadata(), source.partition_metadata()],
@@ -1633,10 +1843,29 @@ def sort(
                 _num_outputs=num_partitions,
                 sort_by=sort_by,
                 descending=descending,
+                nulls_first=nulls_first,
             ),
         )
         for source in consume_deque(source_materializations)
     )
+
+
+def sort(
+    child_plan: InProgressPhysicalPlan[PartitionT],
+    sort_by: ExpressionsProjection,
+    descending: list[bool],
+    nulls_first: list[bool],
+    num_partitions: int,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Sort the result of `child_plan` according to `sort_info`."""
+    source_materializations, boundaries = yield from _sample_range_boundaries(
+        child_plan, sort_by, descending, nulls_first, num_partitions
+    )
+
+    # Create a range fanout plan.
+    range_fanout_plan = _range_fanout_plan(
+        source_materializations, boundaries, sort_by, descending, nulls_first, num_partitions
+    )
     per_partition_bounds = _to_per_partition_bounds(boundaries.micropartition(), num_partitions)
 
     # Execute a sorting reduce on it.
@@ -1647,12 +1876,32 @@ def sort(
                 sort_by=sort_by,
                 descending=descending,
                 bounds=per_part_boundaries,
+                nulls_first=nulls_first,
             )
             for per_part_boundaries in per_partition_bounds
         ],
     )
 
 
+def fanout_by_range(
+    child_plan: InProgressPhysicalPlan[PartitionT],
+    partition_by: ExpressionsProjection,
+    descending: list[bool],
+    num_partitions: int,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Splits the results of `child_plan` into `num_partitions` ranges of `partition_by`.
+
+    The boundaries of the ranges are computed from samples of `child_plan`, in the same way as for sorts, so the
+    partitions are only approximately even in size.
+    """
+    source_materializations, boundaries = yield from _sample_range_boundaries(
+        child_plan, partition_by, descending, descending, num_partitions
+    )
+    yield from _range_fanout_plan(
+        source_materializations, boundaries, partition_by, descending, descending, num_partitions
+    )
+
+
 def fanout_random(child_plan: InProgressPhysicalPlan[PartitionT], num_partitions: int):
     """Splits the results of `child_plan` randomly into a list of `node.num_partitions()` number of partitions."""
     seed = 0
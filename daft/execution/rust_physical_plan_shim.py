@@ -22,6 +22,8 @@ if TYPE_CHECKING:
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
+    from daft.daft import CsvWriteOptions
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
     from daft.table import MicroPartition
 
 
@@ -198,6 +200,17 @@ def sample(
     )
 
 
+def assert_unique(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT], keys: list[PyExpr]
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    keys_projection = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in keys])
+    return physical_plan.pipeline_instruction(
+        child_plan=input,
+        pipeable_instruction=execution_step.AssertUnique(keys=keys_projection),
+        resource_request=ResourceRequest(),
+    )
+
+
 def sort(
     input: physical_plan.InProgressPhysicalPlan[PartitionT],
     sort_by: list[PyExpr],
@@ -232,6 +245,21 @@ def fanout_by_hash(
     )
 
 
+def fanout_by_range(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    num_partitions: int,
+    partition_by: list[PyExpr],
+    descending: list[bool],
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    expr_projection = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in partition_by])
+    return physical_plan.fanout_by_range(
+        child_plan=input,
+        partition_by=expr_projection,
+        descending=descending,
+        num_partitions=num_partitions,
+    )
+
+
 def reduce_merge(
     input: physical_plan.InProgressPhysicalPlan[PartitionT],
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
@@ -259,6 +287,54 @@ def hash_join(
     )
 
 
+def co_partitioned_hash_join(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    right: physical_plan.InProgressPhysicalPlan[PartitionT],
+    left_on: list[PyExpr],
+    right_on: list[PyExpr],
+    null_equals_nulls: list[bool] | None,
+    join_type: JoinType,
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    left_on_expr_proj = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in left_on])
+    right_on_expr_proj = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in right_on])
+    return physical_plan.co_partitioned_hash_join(
+        left_plan=input,
+        right_plan=right,
+        left_on=left_on_expr_proj,
+        right_on=right_on_expr_proj,
+        how=join_type,
+        null_equals_nulls=null_equals_nulls,
+    )
+
+
+def skew_salted_hash_join(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    right: physical_plan.InProgressPhysicalPlan[PartitionT],
+    left_on: list[PyExpr],
+    right_on: list[PyExpr],
+    null_equals_nulls: list[bool] | None,
+    join_type: JoinType,
+    num_partitions: int,
+    salt_factor: int,
+    heavy_hitter_threshold: float,
+    salt_left: bool,
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    left_on_expr_proj = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in left_on])
+    right_on_expr_proj = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in right_on])
+    return physical_plan.skew_salted_hash_join(
+        left_plan=input,
+        right_plan=right,
+        left_on=left_on_expr_proj,
+        right_on=right_on_expr_proj,
+        null_equals_nulls=null_equals_nulls,
+        how=join_type,
+        num_partitions=num_partitions,
+        salt_factor=salt_factor,
+        heavy_hitter_threshold=heavy_hitter_threshold,
+        salt_left=salt_left,
+    )
+
+
 def merge_join_sorted(
     input: physical_plan.InProgressPhysicalPlan[PartitionT],
     right: physical_plan.InProgressPhysicalPlan[PartitionT],
@@ -331,6 +407,10 @@ def write_file(
     compression: str | None,
     partition_cols: list[PyExpr] | None,
     io_config: IOConfig | None,
+    parquet_encryption: ParquetEncryptionConfig | None = None,
+    csv_options: CsvWriteOptions | None = None,
+    job_id: str | None = None,
+    attempt_id: str | None = None,
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
     if partition_cols is not None:
         expr_projection = ExpressionsProjection([Expression._from_pyexpr(expr) for expr in partition_cols])
@@ -344,6 +424,10 @@ def write_file(
         compression,
         expr_projection,
         io_config,
+        parquet_encryption,
+        csv_options,
+        job_id,
+        attempt_id,
     )
 
 
@@ -0,0 +1,86 @@
+"""Passes MicroPartitions between co-located processes through shared memory rather than by pickling them.
+
+A partition is written in the Arrow IPC stream format into an anonymous in-memory file (a memfd), whose file descriptor
+is then sent to the other process over the Unix socket that connects them. The receiving process maps the file into
+its memory and reads the partition from the mapping without copying it.
+
+The lifetime of the shared memory is reference-counted: the kernel keeps the file alive for as long as either process
+holds a descriptor or mapping of it, and the receiving process keeps its mapping alive for as long as any array of the
+partition references it. The memory is freed once the partition and everything derived from it without a copy has been
+dropped, so neither process has to coordinate the cleanup.
+"""
+
+from __future__ import annotations
+
+import mmap
+import os
+import sys
+from dataclasses import dataclass
+from multiprocessing import reduction
+from typing import TYPE_CHECKING
+
+from daft.dependencies import pa
+from daft.logical.schema import Schema
+from daft.table import MicroPartition
+
+if TYPE_CHECKING:
+    from multiprocessing.connection import Connection
+
+SHARED_MEMORY_SUPPORTED = sys.platform == "linux" and hasattr(os, "memfd_create")
+
+
+@dataclass(frozen=True)
+class _SharedPartition:
+    """Message announcing that a partition of `size` bytes follows as a file descriptor of shared memory."""
+
+    schema: Schema
+    size: int
+
+
+def _can_share(partition: MicroPartition) -> bool:
+    schema = partition.schema()
+    # Python objects have no Arrow representation, and a partition without columns has no Arrow table to carry its
+    # number of rows.
+    return len(schema) > 0 and not any(field.dtype._is_python_type() for field in schema)
+
+
+def send_partition(conn: Connection, partition: MicroPartition | None, use_shared_memory: bool) -> None:
+    """Sends `partition` to the process at the other end of `conn`, to be received with `recv_partition`.
+
+    The partition is sent through shared memory if `use_shared_memory` is set and it is supported for the partition and
+    the platform, and pickled otherwise.
+    """
+    if partition is None or not (use_shared_memory and SHARED_MEMORY_SUPPORTED and _can_share(partition)):
+        conn.send(partition)
+        return
+
+    table = partition.to_arrow()
+    fd = os.memfd_create("daft-partition", os.MFD_CLOEXEC)
+    try:
+        with open(fd, "wb", closefd=False) as file:
+            with pa.ipc.new_stream(file, table.schema) as writer:
+                writer.write_table(table)
+            size = file.tell()
+        conn.send(_SharedPartition(partition.schema(), size))
+        # The destination pid is only used on Windows, which has no memfds.
+        reduction.send_handle(conn, fd, os.getpid())
+    finally:
+        # The receiving process holds its own descriptor of the file once it has been sent.
+        os.close(fd)
+
+
+def recv_partition(conn: Connection) -> MicroPartition | None:
+    """Receives a partition that was sent with `send_partition`."""
+    message = conn.recv()
+    if not isinstance(message, _SharedPartition):
+        return message
+
+    fd = reduction.recv_handle(conn)
+    try:
+        mapping = mmap.mmap(fd, message.size, access=mmap.ACCESS_READ)
+    finally:
+        # The mapping keeps the file alive on its own.
+        os.close(fd)
+    # The arrays read from the buffer reference the mapping, which is unmapped once the last of them is dropped.
+    table = pa.ipc.open_stream(pa.py_buffer(mapping)).read_all()
+    return MicroPartition.from_arrow(table).cast_to_schema(message.schema)
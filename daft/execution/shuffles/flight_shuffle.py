@@ -0,0 +1,176 @@
+"""Hash partitioning on the Ray Runner through a shuffle worker on each node, which serves the partitions over Arrow Flight.
+
+Map tasks run as usual, and their outputs are pushed into the shuffle worker of the node they were produced on, which
+hash partitions them into its shuffle cache and spills them to disk past `repartition_memory_limit_bytes`. Reduce tasks
+then fetch their partition from every worker that received map outputs.
+
+The flight servers of the workers listen on the addresses of their nodes so that workers of other nodes can fetch from
+them, and only accept requests with a token that is generated by the driver and shared with the workers when they're
+started.
+"""
+
+from __future__ import annotations
+
+import logging
+import secrets
+import uuid
+from typing import TYPE_CHECKING
+
+import ray  # noqa: TID253
+import ray.experimental  # noqa: TID253
+
+from daft.context import get_context
+from daft.daft import FlightShuffleWorker, ResourceRequest
+from daft.execution import execution_step
+from daft.execution.execution_step import PartitionTaskBuilder, SingleOutputPartitionTask
+from daft.execution.physical_plan import InProgressPhysicalPlan, stage_id_counter
+from daft.runners.partitioning import PartitionT
+
+if TYPE_CHECKING:
+    from daft.daft import PyDaftExecutionConfig, PyExpr
+    from daft.table import MicroPartition
+
+logger = logging.getLogger(__name__)
+
+
+@ray.remote(num_cpus=0)
+class ShuffleWorkerActor:
+    """Runs the shuffle worker of a node."""
+
+    def __init__(self, auth_token: str, config: PyDaftExecutionConfig) -> None:
+        self._host = ray.util.get_node_ip_address()
+        self._worker = FlightShuffleWorker(addr=f"{self._host}:0", auth_token=auth_token, config=config)
+
+    def address(self) -> str:
+        return f"http://{self._host}:{self._worker.port()}"
+
+    def push_partition(
+        self, shuffle_id: str, stage_id: int, num_partitions: int, partition_by: list[PyExpr], partition: MicroPartition
+    ) -> None:
+        self._worker.push_partition(shuffle_id, stage_id, num_partitions, partition_by, partition._micropartition)
+
+    def finish_stage(self, shuffle_id: str, stage_id: int) -> bool:
+        return self._worker.finish_stage(shuffle_id, stage_id)
+
+    def remove_query(self, shuffle_id: str) -> None:
+        self._worker.remove_query(shuffle_id)
+
+
+class ShuffleWorkerManager:
+    """The shuffle workers of the nodes of the cluster, which are started on first use and share one auth token."""
+
+    def __init__(self) -> None:
+        self._auth_token = secrets.token_urlsafe(32)
+        self._actors: dict[str, ray.actor.ActorHandle] = {}
+        self._addresses: dict[str, str] = {}
+
+    @property
+    def auth_token(self) -> str:
+        return self._auth_token
+
+    def actor_for_node(self, node_id: str | None) -> tuple[str, ray.actor.ActorHandle]:
+        """The worker on `node_id`, or on any node if it's unknown, started if it isn't running yet."""
+        if node_id is None:
+            if self._actors:
+                return next(iter(self._actors.items()))
+            node_id = ray.get_runtime_context().get_node_id()
+        if node_id not in self._actors:
+            actor = ShuffleWorkerActor.options(  # type: ignore[attr-defined]
+                scheduling_strategy=ray.util.scheduling_strategies.NodeAffinitySchedulingStrategy(node_id, soft=False),
+            ).remote(self._auth_token, get_context().daft_execution_config)
+            self._actors[node_id] = actor
+            self._addresses[node_id] = ray.get(actor.address.remote())
+        return node_id, self._actors[node_id]
+
+    def address(self, node_id: str) -> str:
+        return self._addresses[node_id]
+
+
+_worker_manager: ShuffleWorkerManager | None = None
+
+
+def _get_worker_manager() -> ShuffleWorkerManager:
+    global _worker_manager
+    if _worker_manager is None:
+        _worker_manager = ShuffleWorkerManager()
+    return _worker_manager
+
+
+def flight_shuffle(
+    map_plan: InProgressPhysicalPlan[PartitionT],
+    num_partitions: int,
+    partition_by: list[PyExpr],
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Hash partitions the outputs of `map_plan` into `num_partitions` partitions through the shuffle workers."""
+    manager = _get_worker_manager()
+    stage_id = next(stage_id_counter)
+    shuffle_id = uuid.uuid4().hex
+
+    in_flight_maps: dict[str, SingleOutputPartitionTask[PartitionT]] = {}
+    pushes: list[ray.ObjectRef] = []
+    nodes_with_outputs: set[str] = set()
+
+    def push_done_maps() -> None:
+        done_maps = [task for task in in_flight_maps.values() if task.done()]
+        if not done_maps:
+            return
+        partitions = [task.partition() for task in done_maps]
+        locations = ray.experimental.get_object_locations(partitions)
+        for task, partition in zip(done_maps, partitions):
+            del in_flight_maps[task.id()]
+            node_ids = locations.get(partition, {}).get("node_ids") or [None]
+            node_id, actor = manager.actor_for_node(node_ids[0])
+            nodes_with_outputs.add(node_id)
+            pushes.append(actor.push_partition.remote(shuffle_id, stage_id, num_partitions, partition_by, partition))
+
+    # Dispatch the map tasks, pushing each output into a worker once it's done.
+    for step in map_plan:
+        if isinstance(step, PartitionTaskBuilder):
+            step = step.finalize_partition_task_single_output(stage_id=stage_id)
+            in_flight_maps[step.id()] = step
+        yield step
+        push_done_maps()
+
+    while in_flight_maps:
+        logger.debug("flight_shuffle blocked on completion of a map task")
+        yield None
+        push_done_maps()
+
+    ray.get(pushes)
+    output_nodes = sorted(nodes_with_outputs)
+    ray.get([manager.actor_for_node(node_id)[1].finish_stage.remote(shuffle_id, stage_id) for node_id in output_nodes])
+    addresses = [manager.address(node_id) for node_id in output_nodes]
+
+    # The reduce tasks must finish before the outputs of the shuffle are dropped from the workers.
+    reduce_stage_id = next(stage_id_counter)
+    reduces = []
+    for partition_idx in range(num_partitions):
+        reduce = (
+            PartitionTaskBuilder[PartitionT](inputs=[], partial_metadatas=None, resource_request=ResourceRequest())
+            .add_instruction(
+                instruction=execution_step.FetchFlightShufflePartition(
+                    addresses=addresses,
+                    shuffle_id=shuffle_id,
+                    stage_id=stage_id,
+                    partition_idx=partition_idx,
+                    auth_token=manager.auth_token,
+                ),
+            )
+            .finalize_partition_task_single_output(stage_id=reduce_stage_id)
+        )
+        reduces.append(reduce)
+        yield reduce
+
+    while any(not reduce.done() for reduce in reduces):
+        logger.debug("flight_shuffle blocked on completion of a reduce task")
+        yield None
+
+    for node_id in output_nodes:
+        manager.actor_for_node(node_id)[1].remove_query.remote(shuffle_id)
+
+    for reduce in reduces:
+        yield PartitionTaskBuilder[PartitionT](
+            inputs=[reduce.partition()],
+            partial_metadatas=[reduce.partition_metadata()],
+            resource_request=ResourceRequest(),
+        )
@@ -24,6 +24,7 @@ from daft.daft import PyExpr as _PyExpr
 from daft.daft import col as _col
 from daft.daft import date_lit as _date_lit
 from daft.daft import decimal_lit as _decimal_lit
+from daft.daft import detokenize as _detokenize
 from daft.daft import duration_lit as _duration_lit
 from daft.daft import list_sort as _list_sort
 from daft.daft import lit as _lit
@@ -31,6 +32,7 @@ from daft.daft import series_lit as _series_lit
 from daft.daft import time_lit as _time_lit
 from daft.daft import timestamp_lit as _timestamp_lit
 from daft.daft import to_struct as _to_struct
+from daft.daft import tokenize as _tokenize
 from daft.daft import tokenize_decode as _tokenize_decode
 from daft.daft import tokenize_encode as _tokenize_encode
 from daft.daft import udf as _udf
@@ -273,6 +275,11 @@ class Expression:
         """Access methods that work on columns of json."""
         return ExpressionJsonNamespace.from_expression(self)
 
+    @property
+    def binary(self) -> ExpressionBinaryNamespace:
+        """Access methods that work on columns of binary data."""
+        return ExpressionBinaryNamespace.from_expression(self)
+
     @staticmethod
     def _from_pyexpr(pyexpr: _PyExpr) -> Expression:
         expr = Expression.__new__(Expression)
@@ -961,6 +968,63 @@ class Expression:
         expr = self._expr.agg_concat()
         return Expression._from_pyexpr(expr)
 
+    def top_k(self, k: builtins.int) -> Expression:
+        """Aggregates the ``k`` largest non-null values in the expression into a list, in descending order.
+
+        Each partition only keeps its own ``k`` largest values, so this is much cheaper than sorting all of the values.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"values": [4, 1, None, 7, 3]})
+            >>> df = df.agg(df["values"].top_k(2).alias("top_values"))
+            >>> df.show()
+            ╭─────────────╮
+            │ top_values  │
+            │ ---         │
+            │ List[Int64] │
+            ╞═════════════╡
+            │ [7, 4]      │
+            ╰─────────────╯
+            <BLANKLINE>
+            (Showing first 1 of 1 rows)
+
+        Args:
+            k: The number of values to keep
+
+        Returns:
+            Expression: a List expression of at most ``k`` values
+        """
+        assert isinstance(k, builtins.int) and k >= 0, f"Expected k to be a non-negative int, but received {k}"
+        expr = self._expr.top_k(k)
+        return Expression._from_pyexpr(expr)
+
+    def approx_heavy_hitters(self, k: builtins.int) -> Expression:
+        """Calculates the approximate ``k`` most frequent non-null values in the expression, with their counts.
+
+        Each partition is summarized by counting its values, and the summaries are merged with the mergeable
+        `SpaceSaving <https://www.cs.ucsb.edu/sites/default/files/documents/2005-23.pdf>`_ algorithm, which keeps a
+        bounded number of values per summary. A reported count is never below the true count of its value, and is exact
+        when the data fits in a single partition.
+
+        The result is a list of ``{"value": ..., "count": ...}`` structs in descending order of count.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"values": ["a", "b", "a", "c", "a", "b"]})
+            >>> df = df.agg(df["values"].approx_heavy_hitters(2).alias("heavy_hitters"))
+            >>> df.to_pydict()
+            {'heavy_hitters': [[{'value': 'a', 'count': 3}, {'value': 'b', 'count': 2}]]}
+
+        Args:
+            k: The number of values to report
+
+        Returns:
+            Expression: a List of Struct[value, count] expression of at most ``k`` values
+        """
+        assert isinstance(k, builtins.int) and k > 0, f"Expected k to be a positive int, but received {k}"
+        expr = self._expr.approx_heavy_hitters(k)
+        return Expression._from_pyexpr(expr)
+
     def _explode(self) -> Expression:
         expr = native.explode(self._expr)
         return Expression._from_pyexpr(expr)
@@ -2908,6 +2972,29 @@ class ExpressionStringNamespace(ExpressionNamespace):
             native.utf8_normalize(self._expr, remove_punct, lowercase, nfd_unicode, white_space)
         )
 
+    def collation_key(self, collation: str = "binary") -> Expression:
+        """Returns binary keys that sort and compare the way the strings do under a collation.
+
+        Sorting, grouping or joining on the keys instead of the strings applies the collation to
+        those operations.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"x": ["b", "C", "a"]})
+            >>> df = df.sort(df["x"].str.collation_key("case_insensitive"))
+            >>> df.to_pydict()["x"]
+            ['a', 'b', 'C']
+
+        Args:
+            collation: ``"binary"`` to compare the UTF-8 bytes of the strings, ``"case_insensitive"``
+                to ignore case, or a locale such as ``"de-DE"`` to use the rules of that locale.
+                Locales require Daft to be built with the ``icu`` feature.
+
+        Returns:
+            Expression: a Binary expression of the collation keys.
+        """
+        return Expression._from_pyexpr(native.utf8_collation_key(self._expr, collation))
+
     def tokenize_encode(
         self,
         tokens_path: str,
@@ -2979,6 +3066,57 @@ class ExpressionStringNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(_tokenize_decode(self._expr, tokens_path, io_config, pattern, special_tokens))
 
+    def tokenize(
+        self,
+        tokenizer_path: str,
+        *,
+        add_special_tokens: bool = True,
+        io_config: IOConfig | None = None,
+    ) -> Expression:
+        """Encodes each string as a list of token ids using a Hugging Face tokenizer.
+
+        Uses https://github.com/huggingface/tokenizers for tokenization. The tokenizer is loaded from a
+        ``tokenizer.json`` file, once per process, and is shared by every batch that is tokenized with it.
+
+        Example:
+            >>> import daft
+            >>> df = daft.from_pydict({"text": ["Hello world", None]})
+            >>> tokenizer = "https://huggingface.co/bert-base-uncased/resolve/main/tokenizer.json"
+            >>> df = df.with_column("ids", df["text"].str.tokenize(tokenizer))  # doctest: +SKIP
+
+        Args:
+            tokenizer_path: The path to a ``tokenizer.json`` file (supports downloading).
+            add_special_tokens (optional): Whether to add the special tokens of the tokenizer's post-processor,
+                such as ``[CLS]`` and ``[SEP]``. Defaults to True.
+            io_config (optional): IOConfig to use when accessing remote storage.
+
+        Returns:
+            Expression: An expression with the token ids of the strings as lists of 32-bit integers.
+        """
+        return Expression._from_pyexpr(_tokenize(self._expr, tokenizer_path, add_special_tokens, io_config))
+
+    def detokenize(
+        self,
+        tokenizer_path: str,
+        *,
+        skip_special_tokens: bool = True,
+        io_config: IOConfig | None = None,
+    ) -> Expression:
+        """Decodes each list of token ids into a string using a Hugging Face tokenizer.
+
+        Uses https://github.com/huggingface/tokenizers for tokenization. The tokenizer is loaded from a
+        ``tokenizer.json`` file, once per process, and is shared by every batch that is detokenized with it.
+
+        Args:
+            tokenizer_path: The path to a ``tokenizer.json`` file (supports downloading).
+            skip_special_tokens (optional): Whether to leave special tokens out of the decoded strings. Defaults to True.
+            io_config (optional): IOConfig to use when accessing remote storage.
+
+        Returns:
+            Expression: An expression with the decoded strings.
+        """
+        return Expression._from_pyexpr(_detokenize(self._expr, tokenizer_path, skip_special_tokens, io_config))
+
     def count_matches(
         self,
         patterns: Any,
@@ -3192,6 +3330,7 @@ class ExpressionListNamespace(ExpressionNamespace):
 
         Args:
             desc: Whether to sort in descending order. Defaults to false. Pass in a boolean column to control for each row.
+            nulls_first: Whether to place nulls before all other values. Defaults to the value of `desc`. Pass in a boolean column to control for each row.
 
         Returns:
             Expression: An expression with the sorted lists
@@ -3456,6 +3595,17 @@ class ExpressionImageNamespace(ExpressionNamespace):
             raise ValueError(f"mode must be a string or ImageMode variant, but got: {mode}")
         return Expression._from_pyexpr(native.image_to_mode(self._expr, mode))
 
+    def to_tensor(self) -> Expression:
+        """Converts images into tensors of shape (height, width, channels).
+
+        Images of a fixed shape are converted into fixed-shape tensors, and other images into
+        tensors of their pixel type.
+
+        Returns:
+            Expression: A Tensor expression representing a tensor column of the images.
+        """
+        return Expression._from_pyexpr(native.image_to_tensor(self._expr))
+
 
 class ExpressionPartitioningNamespace(ExpressionNamespace):
     def days(self) -> Expression:
@@ -3550,6 +3700,55 @@ class ExpressionJsonNamespace(ExpressionNamespace):
         return Expression._from_pyexpr(native.json_query(self._expr, jq_query))
 
 
+class ExpressionBinaryNamespace(ExpressionNamespace):
+    def decode_protobuf(self, descriptor_set: bytes, message_name: str) -> Expression:
+        """Decodes each binary value as a serialized protobuf message, returning a struct of the message's fields.
+
+        Fields of the message become fields of the struct: repeated fields become lists, map fields become maps,
+        nested messages become structs and enums become the names of their values. Unset fields that track presence,
+        like message fields and ``optional`` fields, are null, while other unset fields have their default values.
+
+        Example:
+            >>> import daft
+            >>> with open("events.desc", "rb") as f:  # doctest: +SKIP
+            ...     descriptor_set = f.read()
+            >>> df = daft.read_parquet("s3://bucket/events")  # doctest: +SKIP
+            >>> df = df.with_column("event", df["payload"].binary.decode_protobuf(descriptor_set, "my.package.Event"))  # doctest: +SKIP
+
+        Args:
+            descriptor_set: A serialized ``FileDescriptorSet`` that defines the message, such as the output of
+                ``protoc --include_imports --descriptor_set_out``.
+            message_name: The fully qualified name of the message, such as ``my.package.Event``.
+
+        Returns:
+            Expression: A Struct expression of the decoded messages.
+        """
+        return Expression._from_pyexpr(native.decode_protobuf(self._expr, descriptor_set, message_name))
+
+    def decode_msgpack(self, dtype: DataType) -> Expression:
+        """Decodes each binary value as a MessagePack value of the given type.
+
+        Structs are decoded from maps keyed by field name, where missing fields are null and other keys are
+        ignored, or from arrays of the values of the fields in order. Lists are decoded from arrays and maps from maps.
+
+        Example:
+            >>> import daft
+            >>> from daft import DataType
+            >>> df = daft.from_pydict({"payload": [b"\x82\xa2id\x01\xa4tags\x91\xa1a", None]})
+            >>> dtype = DataType.struct({"id": DataType.int64(), "tags": DataType.list(DataType.string())})
+            >>> df.select(df["payload"].binary.decode_msgpack(dtype)).to_pydict()
+            {'payload': [{'id': 1, 'tags': ['a']}, None]}
+
+        Args:
+            dtype: The type of the decoded values, made of booleans, integers, floats, strings, binaries, lists,
+                structs and maps.
+
+        Returns:
+            Expression: An expression of the decoded values.
+        """
+        return Expression._from_pyexpr(native.decode_msgpack(self._expr, dtype._dtype))
+
+
 class ExpressionEmbeddingNamespace(ExpressionNamespace):
     def cosine_distance(self, other: Expression) -> Expression:
         """Compute the cosine distance between two embeddings."""
@@ -4,14 +4,14 @@ import dataclasses
 import logging
 import os
 import pathlib
+import re
 import sys
 import urllib.parse
 from typing import TYPE_CHECKING, Any, Literal
 
-from daft.convert import from_pydict
+from daft.convert import from_arrow
 from daft.daft import FileFormat, FileInfos, IOConfig, io_glob
-from daft.dependencies import fsspec, pafs
-from daft.expressions.expressions import col, lit
+from daft.dependencies import fsspec, pa, pafs
 from daft.table import MicroPartition
 
 if TYPE_CHECKING:
@@ -315,7 +315,7 @@ def glob_path_with_stats(
 ) -> FileInfos:
     """Glob a path, returning a list ListingInfo."""
     files = io_glob(path, io_config=io_config)
-    filepaths_to_infos = {f["path"]: {"size": f["size"], "type": f["type"]} for f in files}
+    filepaths_to_infos = {f["path"]: {"size": f["size"], "type": f["type"], "etag": f.get("etag")} for f in files}
 
     # Set number of rows if available.
     if file_format is not None and file_format == FileFormat.Parquet:
@@ -329,12 +329,14 @@ def glob_path_with_stats(
     file_paths = []
     file_sizes = []
     num_rows = []
+    etags = []
     for path, infos in filepaths_to_infos.items():
         file_paths.append(path)
         file_sizes.append(infos.get("size"))
         num_rows.append(infos.get("rows"))
+        etags.append(infos.get("etag"))
 
-    return FileInfos.from_infos(file_paths=file_paths, file_sizes=file_sizes, num_rows=num_rows)
+    return FileInfos.from_infos(file_paths=file_paths, file_sizes=file_sizes, num_rows=num_rows, etags=etags)
 
 
 ###
@@ -355,20 +357,117 @@ def overwrite_files(
     root_dir: str | pathlib.Path,
     io_config: IOConfig | None,
 ) -> None:
+    assert manifest._result is not None
+    written_file_paths = manifest._result._get_merged_micropartition().get_column("path").to_pylist()
+    delete_other_files(written_file_paths, root_dir, io_config)
+
+
+def delete_other_files(paths: list[str], root_dir: str | pathlib.Path, io_config: IOConfig | None) -> None:
+    """Deletes the files under `root_dir` other than `paths`, for writes that overwrite their location."""
     [resolved_path], fs = _resolve_paths_and_filesystem(root_dir, io_config=io_config)
     file_selector = pafs.FileSelector(resolved_path, recursive=True)
     try:
-        paths = [info.path for info in fs.get_file_info(file_selector) if info.type == pafs.FileType.File]
+        existing_paths = [info.path for info in fs.get_file_info(file_selector) if info.type == pafs.FileType.File]
     except FileNotFoundError:
         # The root directory does not exist, so there are no files to delete.
         return
 
-    all_file_paths_df = from_pydict({"path": paths})
+    written_paths = set(paths)
+    if paths:
+        # The written paths may name their filesystem, unlike the paths listed by it
+        written_paths.update(_resolve_paths_and_filesystem(paths, io_config=io_config)[0])
+    # TODO: Look into parallelizing this
+    for path in existing_paths:
+        if path not in written_paths:
+            fs.delete_file(path)
+
 
-    assert manifest._result is not None
-    written_file_paths = manifest._result._get_merged_micropartition().get_column("path")
-    to_delete = all_file_paths_df.where(~(col("path").is_in(lit(written_file_paths))))
+def delete_files(paths: list[str], io_config: IOConfig | None) -> None:
+    """Deletes files, such as the data files of a write that was rolled back before being committed."""
+    if not paths:
+        return
+    resolved_paths, fs = _resolve_paths_and_filesystem(paths, io_config=io_config)
+    for path in resolved_paths:
+        fs.delete_file(path)
 
-    # TODO: Look into parallelizing this
-    for entry in to_delete:
-        fs.delete_file(entry["path"])
+
+###
+# Retry-safe writes
+###
+
+_UUID_PATTERN = "[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}"
+
+
+def staged_file_prefix(job_id: str, attempt_id: str) -> str:
+    """Prefix of the names of the files written by an attempt of a write job until the attempt is committed.
+
+    The leading underscore hides the files from readers that skip hidden files, such as Spark.
+    """
+    return f"_staged-{job_id}-{attempt_id}-"
+
+
+def commit_write(
+    paths: list[str],
+    root_dir: str | pathlib.Path,
+    job_id: str,
+    attempt_id: str,
+    io_config: IOConfig | None,
+) -> list[str]:
+    """Commits the files written by an attempt of a write job, returning their committed paths in the same order.
+
+    The files of any other attempt of the job, staged or committed, are deleted before the files of this attempt are
+    renamed from their staged names to names that start with the job ID. A job that is retried, for example after its
+    result was lost, thus leaves the files of only its last attempt visible.
+    """
+    [resolved_path], fs = _resolve_paths_and_filesystem(root_dir, io_config=io_config)
+    try:
+        existing_paths = [
+            info.path
+            for info in fs.get_file_info(pafs.FileSelector(resolved_path, recursive=True))
+            if info.type == pafs.FileType.File
+        ]
+    except FileNotFoundError:
+        existing_paths = []
+
+    # The written paths may name their filesystem, unlike the paths listed by it
+    resolved_paths = _resolve_paths_and_filesystem(paths, io_config=io_config)[0] if paths else []
+
+    # The names of the files of all attempts of the job, as written by `FileWriterBase` and `write_tabular`
+    job_file_name = re.compile(
+        rf"(_staged-{re.escape(job_id)}-{_UUID_PATTERN}|{re.escape(job_id)})-{_UUID_PATTERN}-\d+\..+"
+    )
+    written_paths = set(resolved_paths)
+    for path in existing_paths:
+        if path not in written_paths and job_file_name.fullmatch(path.rsplit("/", 1)[-1]):
+            fs.delete_file(path)
+
+    prefix = staged_file_prefix(job_id, attempt_id)
+    committed_paths = []
+    for path, resolved_path in zip(paths, resolved_paths):
+        dir_path, file_name = path.rsplit("/", 1)
+        if not file_name.startswith(prefix):
+            raise ValueError(f"Expected the files of attempt {attempt_id} of write job {job_id}, got: {path}")
+        committed_name = f"{job_id}-{file_name[len(prefix):]}"
+        fs.move(resolved_path, f"{resolved_path.rsplit('/', 1)[0]}/{committed_name}")
+        committed_paths.append(f"{dir_path}/{committed_name}")
+    return committed_paths
+
+
+def commit_write_manifest(
+    manifest: DataFrame,
+    root_dir: str | pathlib.Path,
+    job_id: str,
+    attempt_id: str,
+    io_config: IOConfig | None,
+) -> DataFrame:
+    """Commits the files in the manifest of an attempt of a write job, returning a manifest of the committed files.
+
+    This is for runners that write files in tasks, which can't see whether the other tasks of the write succeeded.
+    """
+    assert manifest._result is not None
+    written = manifest._result._get_merged_micropartition().to_arrow()
+    committed_paths = commit_write(written["path"].to_pylist(), root_dir, job_id, attempt_id, io_config)
+    path_idx = written.schema.get_field_index("path")
+    path_type = written.schema.field(path_idx).type
+    committed = written.set_column(path_idx, "path", pa.array(committed_paths, type=path_type))
+    return from_arrow(committed)
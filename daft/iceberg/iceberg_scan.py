@@ -26,6 +26,8 @@ from daft.logical.schema import Field, Schema
 if TYPE_CHECKING:
     from collections.abc import Iterator
 
+    from daft.daft import PyExpr
+
     from pyiceberg.partitioning import PartitionField as IcebergPartitionField
     from pyiceberg.partitioning import PartitionSpec as IcebergPartitionSpec
     from pyiceberg.table import Table
@@ -90,16 +92,35 @@ def _iceberg_partition_field_to_daft_partition_field(
     return make_partition_field(result_field, daft_field, transform=tfm)
 
 
+def iceberg_initial_defaults(iceberg_schema: IcebergSchema, schema: Schema) -> list[tuple[str, PyExpr]]:
+    """The initial default values of the columns of an Iceberg schema, which are the values of the columns for
+    data files that were written before the columns were added to the table.
+    """
+    return [
+        (field.name, daft.lit(field.initial_default).cast(schema[field.name].dtype)._expr)
+        for field in iceberg_schema.fields
+        if getattr(field, "initial_default", None) is not None
+    ]
+
+
 def iceberg_partition_spec_to_fields(iceberg_schema: IcebergSchema, spec: IcebergPartitionSpec) -> list[PartitionField]:
     return [_iceberg_partition_field_to_daft_partition_field(iceberg_schema, field) for field in spec.fields]
 
 
 class IcebergScanOperator(ScanOperator):
-    def __init__(self, iceberg_table: Table, snapshot_id: int | None, storage_config: StorageConfig) -> None:
+    def __init__(
+        self,
+        iceberg_table: Table,
+        snapshot_id: int | None,
+        storage_config: StorageConfig,
+        data_files: set[str] | None = None,
+    ) -> None:
+        """Scans the table at `snapshot_id`, or only its data files at `data_files` if given."""
         super().__init__()
         self._table = iceberg_table
         self._snapshot_id = snapshot_id
         self._storage_config = storage_config
+        self._data_files = data_files
 
         iceberg_schema = (
             iceberg_table.schema()
@@ -111,6 +132,7 @@ class IcebergScanOperator(ScanOperator):
         self._schema = Schema.from_pyarrow_schema(arrow_schema)
 
         self._partition_keys = iceberg_partition_spec_to_fields(iceberg_schema, self._table.spec())
+        self._column_defaults = iceberg_initial_defaults(iceberg_schema, self._schema)
 
     def schema(self) -> Schema:
         return self._schema
@@ -173,6 +195,8 @@ class IcebergScanOperator(ScanOperator):
                 break
             file = task.file
             path = file.file_path
+            if self._data_files is not None and path not in self._data_files:
+                continue
             record_count = file.record_count
             file_format = file.file_format
             if file_format == "PARQUET":
@@ -201,6 +225,8 @@ class IcebergScanOperator(ScanOperator):
             )
             if st is None:
                 continue
+            if self._column_defaults:
+                st = st.with_column_defaults(self._column_defaults)
             rows_left -= record_count
             scan_tasks.append(st)
         return iter(scan_tasks)
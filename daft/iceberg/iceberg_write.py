@@ -1,9 +1,8 @@
 import datetime
 import uuid
-import warnings
 from typing import TYPE_CHECKING, Any, Dict, Iterator, List, Optional, Tuple
 
-from daft import Expression, col, lit
+from daft import lit
 from daft.datatype import DataType
 from daft.expressions.expressions import ExpressionsProjection
 from daft.io.common import _get_schema_from_dict
@@ -13,7 +12,6 @@ from daft.table.partitioning import PartitionedTable, partition_strings_to_path
 if TYPE_CHECKING:
     import pyarrow as pa
     from pyiceberg.manifest import DataFile
-    from pyiceberg.partitioning import PartitionField as IcebergPartitionField
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
     from pyiceberg.typedef import Record as IcebergRecord
@@ -77,42 +75,6 @@ def coerce_pyarrow_table_to_schema(pa_table: "pa.Table", schema: "pa.Schema") ->
     return pa.table(columns, schema=schema)
 
 
-def partition_field_to_expr(field: "IcebergPartitionField", schema: "IcebergSchema") -> Expression:
-    from pyiceberg.transforms import (
-        BucketTransform,
-        DayTransform,
-        HourTransform,
-        IdentityTransform,
-        MonthTransform,
-        TruncateTransform,
-        YearTransform,
-    )
-
-    part_col = col(schema.find_field(field.source_id).name)
-
-    if isinstance(field.transform, IdentityTransform):
-        transform_expr = part_col
-    elif isinstance(field.transform, YearTransform):
-        transform_expr = part_col.partitioning.years()
-    elif isinstance(field.transform, MonthTransform):
-        transform_expr = part_col.partitioning.months()
-    elif isinstance(field.transform, DayTransform):
-        transform_expr = part_col.partitioning.days()
-    elif isinstance(field.transform, HourTransform):
-        transform_expr = part_col.partitioning.hours()
-    elif isinstance(field.transform, BucketTransform):
-        transform_expr = part_col.partitioning.iceberg_bucket(field.transform.num_buckets)
-    elif isinstance(field.transform, TruncateTransform):
-        transform_expr = part_col.partitioning.iceberg_truncate(field.transform.width)
-    else:
-        warnings.warn(f"{field.transform} not implemented, Please make an issue!")
-        transform_expr = part_col
-
-    # currently the partitioning expressions change the name of the column
-    # so we need to alias it back to the original column name
-    return transform_expr
-
-
 def to_partition_representation(value: Any):
     """Converts a partition value to the format expected by Iceberg metadata.
 
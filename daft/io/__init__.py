@@ -8,33 +8,51 @@ from daft.daft import (
     S3Config,
     S3Credentials,
 )
+from daft.io._compaction import Compaction
 from daft.io._csv import read_csv
-from daft.io._deltalake import read_deltalake
+from daft.io._deltalake import compact_deltalake, read_deltalake
+from daft.io._excel import read_excel
 from daft.io._hudi import read_hudi
-from daft.io._iceberg import read_iceberg
+from daft.io._iceberg import compact_iceberg, read_iceberg
 from daft.io._json import read_json
+from daft.io._kafka import read_kafka
 from daft.io._lance import read_lance
 from daft.io._parquet import read_parquet
 from daft.io._sql import read_sql
+from daft.io._stream import read_stream
+from daft.io._xml import read_xml
 from daft.io.catalog import DataCatalogTable, DataCatalogType
 from daft.io.file_path import from_glob_path
+from daft.io.parquet_encryption import KmsConfig, ParquetDecryptionConfig, ParquetEncryptionConfig
+from daft.io.write_constraints import WriteConstraint
 
 __all__ = [
     "AzureConfig",
+    "Compaction",
     "DataCatalogTable",
     "DataCatalogType",
     "GCSConfig",
     "HTTPConfig",
     "IOConfig",
+    "KmsConfig",
+    "ParquetDecryptionConfig",
+    "ParquetEncryptionConfig",
     "S3Config",
     "S3Credentials",
+    "WriteConstraint",
+    "compact_deltalake",
+    "compact_iceberg",
     "from_glob_path",
     "read_csv",
     "read_deltalake",
+    "read_excel",
     "read_hudi",
     "read_iceberg",
     "read_json",
+    "read_kafka",
     "read_lance",
     "read_parquet",
     "read_sql",
+    "read_stream",
+    "read_xml",
 ]
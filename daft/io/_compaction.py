@@ -0,0 +1,81 @@
+# isort: dont-add-import: from __future__ import annotations
+
+import os
+from typing import Any, Dict, Hashable, Iterable, List, NamedTuple, Optional, Tuple
+
+from daft import context
+from daft.dataframe import DataFrame
+
+
+class CompactedFile(NamedTuple):
+    """A data file that a compaction removes from or adds to a table."""
+
+    path: str
+    rows: Optional[int]
+    file_size: int
+
+
+class Compaction:
+    """A planned compaction of the small data files of a table, see :func:`daft.io.compact_deltalake` and :func:`daft.io.compact_iceberg`.
+
+    Planning a compaction only reads the metadata of the table. The files it replaces are read and rewritten by
+    :attr:`rewrite`, a lazy DataFrame that can be inspected with :meth:`explain` like any other, and :meth:`execute`
+    runs it and commits the written files in place of the replaced ones.
+    """
+
+    def __init__(
+        self, replaced_files: List[CompactedFile], rewrite: Optional[DataFrame], target_file_size_bytes: int
+    ) -> None:
+        self.replaced_files = replaced_files
+        self.rewrite = rewrite
+        self.target_file_size_bytes = target_file_size_bytes
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}(replaced_files={len(self.replaced_files)}, target_file_size_bytes={self.target_file_size_bytes})"
+
+    def explain(self, show_all: bool = False, **kwargs: Any) -> Any:
+        """Prints the plan that reads the files to replace, see :meth:`DataFrame.explain`."""
+        if self.rewrite is None:
+            print("Nothing to compact", file=kwargs.get("file"))
+            return None
+        return self.rewrite.explain(show_all=show_all, **kwargs)
+
+    def execute(self) -> DataFrame:
+        """Rewrites the replaced files and commits the written files in their place, returning a DataFrame with the operations that occurred.
+
+        The commit fails if any of the replaced files was removed from the table since the compaction was planned.
+
+        .. NOTE::
+            This call is **blocking** and will rewrite the files when called
+        """
+        from daft import from_pydict
+
+        operations: Dict[str, list] = {"operation": [], "rows": [], "file_size": [], "file_name": []}
+        if self.rewrite is None:
+            return from_pydict(operations)
+
+        with context.execution_config_ctx(parquet_target_filesize=self.target_file_size_bytes):
+            added_files = self._write_and_commit(self.rewrite)
+
+        for operation, files in (("ADD", added_files), ("DELETE", self.replaced_files)):
+            for file in files:
+                operations["operation"].append(operation)
+                operations["rows"].append(file.rows)
+                operations["file_size"].append(file.file_size)
+                operations["file_name"].append(os.path.basename(file.path))
+        return from_pydict(operations)
+
+    def _write_and_commit(self, rewrite: DataFrame) -> List[CompactedFile]:
+        """Writes the rows of `rewrite` to the table and commits them in place of the replaced files, returning the written files."""
+        raise NotImplementedError()
+
+
+def _group_small_files(
+    files: Iterable[Tuple[Hashable, CompactedFile]], target_file_size_bytes: int
+) -> Dict[Hashable, List[CompactedFile]]:
+    """Groups the files smaller than `target_file_size_bytes` by their partition, keeping the groups that have more than one file."""
+    groups: Dict[Hashable, List[CompactedFile]] = {}
+    for partition, file in files:
+        if file.file_size < target_file_size_bytes:
+            groups.setdefault(partition, []).append(file)
+    return {partition: files for partition, files in groups.items() if len(files) > 1}
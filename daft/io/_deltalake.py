@@ -1,18 +1,23 @@
 # isort: dont-add-import: from __future__ import annotations
 
-from typing import TYPE_CHECKING, Any, Dict, Optional, Union
+import functools
+import pathlib
+from typing import TYPE_CHECKING, List, Optional, Union
 
 from daft import context
 from daft.api_annotations import PublicAPI
 from daft.daft import IOConfig, ScanOperatorHandle, StorageConfig
 from daft.dataframe import DataFrame
 from daft.dependencies import unity_catalog
+from daft.io._compaction import CompactedFile, Compaction, _group_small_files
 from daft.io.catalog import DataCatalogTable
 from daft.logical.builder import LogicalPlanBuilder
 
 if TYPE_CHECKING:
     from datetime import datetime
 
+    from daft.daft import DeltaSnapshot
+
     from daft.unity_catalog import UnityCatalogTable
 
 
@@ -67,6 +72,10 @@ def read_deltalake(
     elif isinstance(table, DataCatalogTable):
         table_uri = table.table_uri(io_config)
     elif unity_catalog.module_available() and isinstance(table, unity_catalog.UnityCatalogTable):
+        if table.data_source_format is not None and table.data_source_format.upper() != "DELTA":
+            raise ValueError(
+                f"Unity Catalog table {table.table_name} has data source format {table.data_source_format}, but only Delta tables can be read with read_deltalake"
+            )
         table_uri = table.table_uri
 
         # Override the storage_config with the one provided by Unity catalog
@@ -84,14 +93,116 @@ def read_deltalake(
     return DataFrame(builder)
 
 
-def large_dtypes_kwargs(large_dtypes: bool) -> Dict[str, Any]:
-    import deltalake
-    from packaging.version import parse
+class _DeltaLakeCompaction(Compaction):
+    def __init__(
+        self,
+        table_uri: str,
+        snapshot: "DeltaSnapshot",
+        replaced_files: List[CompactedFile],
+        rewrite: Optional[DataFrame],
+        target_file_size_bytes: int,
+        io_config: "IOConfig",
+    ) -> None:
+        super().__init__(replaced_files, rewrite, target_file_size_bytes)
+        self._table_uri = table_uri
+        self._snapshot = snapshot
+        self._io_config = io_config
+
+    def _write_and_commit(self, rewrite: DataFrame) -> List[CompactedFile]:
+        import json
+
+        from daft.daft import delta_commit
+
+        partition_cols = self._snapshot.partition_columns
+        builder = rewrite._builder.write_deltalake(
+            self._table_uri,
+            "append",
+            self._snapshot.version + 1,
+            True,
+            io_config=self._io_config,
+            partition_cols=partition_cols or None,
+        )
+        write_df = DataFrame(builder)
+        write_df.collect()
+        add_actions = write_df.to_pydict()["add_action"]
+
+        delta_commit(
+            self._table_uri,
+            self._snapshot,
+            "compact",
+            rewrite.schema()._schema,
+            partition_cols,
+            [add_action.to_json() for add_action in add_actions],
+            replaced_paths=[file.path for file in self.replaced_files],
+            io_config=self._io_config,
+        )
+        return [
+            CompactedFile(add_action.path, json.loads(add_action.stats)["numRecords"], add_action.size)
+            for add_action in add_actions
+        ]
+
 
-    if parse(deltalake.__version__) < parse("0.19.0"):
-        return {"large_dtypes": large_dtypes}
-    else:
-        from deltalake.schema import ArrowSchemaConversionMode
+@PublicAPI
+def compact_deltalake(
+    table: Union[str, pathlib.Path],
+    target_file_size_bytes: int = 512 * 1024 * 1024,
+    io_config: Optional["IOConfig"] = None,
+) -> Compaction:
+    """Plans the compaction of the small data files of a Delta Lake table into files of about ``target_file_size_bytes``.
+
+    Streaming and frequent small writes leave tables with many small files, which slow down reads. Compaction reads the
+    files smaller than ``target_file_size_bytes`` in each partition of the table, rewrites them into fewer files, and
+    commits an ``OPTIMIZE`` that replaces them without changing the data of the table, so it does not conflict with
+    concurrent appends.
+
+    Planning only reads the transaction log of the table. Call :meth:`~daft.io.Compaction.execute` on the returned
+    plan to rewrite and commit the files.
+
+    Example:
+        >>> compaction = daft.io.compact_deltalake("some-table-uri", target_file_size_bytes=128 * 1024 * 1024)
+        >>> compaction.explain()
+        >>> compaction.execute().show()
+
+    Args:
+        table: URI of the Delta Lake table to compact.
+        target_file_size_bytes (int, optional): Size of the files to compact into. Files at least this large are left as
+            they are. Defaults to 512 MiB.
+        io_config (optional): A custom :class:`~daft.daft.IOConfig` to use when accessing Delta Lake object storage data. Defaults to None.
+
+    Returns:
+        Compaction: The planned compaction of the table.
+    """
+    from daft import lit
+    from daft.daft import delta_load_snapshot
+    from daft.io._parquet import read_parquet
+
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    table_uri = str(table)
+
+    snapshot = delta_load_snapshot(table_uri, io_config=io_config)
+    if snapshot is None:
+        raise ValueError(f"No Delta Lake table found at {table_uri}")
+    schema = read_deltalake(table_uri, io_config=io_config).schema()
+    partition_values = snapshot.partition_values()
+
+    # Files can only be compacted with files of the same partition
+    groups = _group_small_files(
+        (
+            (tuple(sorted(partition_values[path].items())), CompactedFile(path, num_records, size))
+            for path, size, num_records in snapshot.files()
+        ),
+        target_file_size_bytes,
+    )
+
+    parts = []
+    for key, files in groups.items():
+        part = read_parquet([f"{table_uri.rstrip('/')}/{file.path}" for file in files], io_config=io_config)
+        # partition values are only stored in the log, so they are added back to the rows of each file
+        part = part.with_columns(
+            {name: lit(value).cast(schema[name].dtype) for name, value in key if name not in part.column_names}
+        )
+        parts.append(part.select(*schema.column_names()))
+    rewrite = functools.reduce(DataFrame.concat, parts) if parts else None
 
-        schema_conversion_mode = ArrowSchemaConversionMode.LARGE if large_dtypes else ArrowSchemaConversionMode.NORMAL
-        return {"schema_conversion_mode": schema_conversion_mode}
+    replaced_files = [file for files in groups.values() for file in files]
+    return _DeltaLakeCompaction(table_uri, snapshot, replaced_files, rewrite, target_file_size_bytes, io_config)
@@ -0,0 +1,80 @@
+# isort: dont-add-import: from __future__ import annotations
+
+from typing import Dict, List, Optional, Union
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.daft import (
+    ExcelSourceConfig,
+    FileFormatConfig,
+    IOConfig,
+    StorageConfig,
+)
+from daft.dataframe import DataFrame
+from daft.datatype import DataType
+from daft.io.common import get_tabular_files_scan
+
+
+@PublicAPI
+def read_excel(
+    path: Union[str, List[str]],
+    sheet_name: Optional[str] = None,
+    header_row: Optional[int] = 0,
+    infer_schema: bool = True,
+    schema: Optional[Dict[str, DataType]] = None,
+    io_config: Optional["IOConfig"] = None,
+    file_path_column: Optional[str] = None,
+    hive_partitioning: bool = False,
+) -> DataFrame:
+    """Creates a DataFrame from a sheet of each of the given Excel workbook(s).
+
+    A sheet is read from its header row, which has the names of the columns, to its last used row. Columns without a
+    name in the header row, or all of the columns if ``header_row`` is None, are named by their position in the sheet,
+    e.g. ``column_1`` for column A. The types of the columns are inferred from their first rows, where whole numbers
+    are read as integers and dates as timestamps.
+
+    Example:
+        >>> df = daft.read_excel("/path/to/file.xlsx")
+        >>> df = daft.read_excel("/path/to/file.xlsx", sheet_name="Orders", header_row=2)
+        >>> df = daft.read_excel("s3://path/to/files-*.xlsx")
+
+    Args:
+        path (str): Path to Excel workbooks (allows for wildcards)
+        sheet_name (str): Name of the sheet to read from each workbook, defaults to the first sheet.
+        header_row (int): Index of the row with the names of the columns, counting from 0 for row 1 of the sheet, or None if the sheet doesn't have one. Defaults to 0.
+        infer_schema (bool): Whether to infer the schema of the sheet from the first rows of the first workbook, defaults to True.
+        schema (dict[str, DataType]): A schema that is used as the definitive schema for the sheet if infer_schema is False, otherwise it is used as a schema hint that is applied after the schema is inferred.
+        io_config (IOConfig): Config to be used with the native downloader
+        file_path_column: Include the source path(s) as a column with this name. Defaults to None.
+        hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
+
+    returns:
+        DataFrame: parsed DataFrame
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of Excel filepaths")
+
+    if not infer_schema and schema is None:
+        raise ValueError(
+            "Cannot read DataFrame with infer_schema=False and schema=None, please provide a schema or set infer_schema=True"
+        )
+
+    if header_row is not None and header_row < 0:
+        raise ValueError(f"The header row of an Excel source can't be negative, got {header_row}")
+
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    excel_config = ExcelSourceConfig(sheet_name, header_row)
+    file_format_config = FileFormatConfig.from_excel_config(excel_config)
+    storage_config = StorageConfig(True, io_config)
+
+    builder = get_tabular_files_scan(
+        path=path,
+        infer_schema=infer_schema,
+        schema=schema,
+        file_format_config=file_format_config,
+        storage_config=storage_config,
+        file_path_column=file_path_column,
+        hive_partitioning=hive_partitioning,
+    )
+    return DataFrame(builder)
@@ -1,11 +1,12 @@
 # isort: dont-add-import: from __future__ import annotations
 
-from typing import TYPE_CHECKING, Any, Dict, Optional
+from typing import TYPE_CHECKING, Any, Dict, List, Optional
 
 from daft import context
 from daft.api_annotations import PublicAPI
 from daft.daft import IOConfig, ScanOperatorHandle, StorageConfig
 from daft.dataframe import DataFrame
+from daft.io._compaction import CompactedFile, Compaction, _group_small_files
 from daft.logical.builder import LogicalPlanBuilder
 
 if TYPE_CHECKING:
@@ -97,3 +98,134 @@ def read_iceberg(
     handle = ScanOperatorHandle.from_python_scan_operator(iceberg_operator)
     builder = LogicalPlanBuilder.from_tabular_scan(scan_operator=handle)
     return DataFrame(builder)
+
+
+class _IcebergCompaction(Compaction):
+    def __init__(
+        self,
+        table: "pyiceberg.table.Table",
+        replaced_data_files: List["pyiceberg.manifest.DataFile"],
+        rewrite: Optional[DataFrame],
+        target_file_size_bytes: int,
+        io_config: "IOConfig",
+    ) -> None:
+        replaced_files = [
+            CompactedFile(data_file.file_path, data_file.record_count, data_file.file_size_in_bytes)
+            for data_file in replaced_data_files
+        ]
+        super().__init__(replaced_files, rewrite, target_file_size_bytes)
+        self._table = table
+        self._replaced_data_files = replaced_data_files
+        self._io_config = io_config
+
+    def _write_and_commit(self, rewrite: DataFrame) -> List[CompactedFile]:
+        write_df = DataFrame(rewrite._builder.write_iceberg(self._table, self._io_config))
+        write_df.collect()
+        data_files = write_df.to_pydict()["data_file"]
+
+        if context.get_context().daft_execution_config.native_iceberg_commit:
+            DataFrame._commit_iceberg_natively(
+                self._table,
+                "replace",
+                data_files,
+                self._io_config,
+                replaced_paths=[file.path for file in self.replaced_files],
+            )
+        else:
+            # PyIceberg commits the rewrite as an overwrite, which fails if the table changed since it was loaded.
+            tx = self._table.transaction()
+            with tx.update_snapshot().overwrite() as overwrite:
+                for data_file in self._replaced_data_files:
+                    overwrite.delete_data_file(data_file)
+                for data_file in data_files:
+                    overwrite.append_data_file(data_file)
+            tx.commit_transaction()
+
+        return [
+            CompactedFile(data_file.file_path, data_file.record_count, data_file.file_size_in_bytes)
+            for data_file in data_files
+        ]
+
+
+@PublicAPI
+def compact_iceberg(
+    table: "pyiceberg.table.Table",
+    target_file_size_bytes: int = 512 * 1024 * 1024,
+    io_config: Optional["IOConfig"] = None,
+) -> Compaction:
+    """Plans the compaction of the small data files of an Iceberg table into files of about ``target_file_size_bytes``.
+
+    Streaming and frequent small writes leave tables with many small files, which slow down reads. Compaction reads the
+    files smaller than ``target_file_size_bytes`` in each partition of the current snapshot of the table, rewrites them
+    into fewer files, and commits a snapshot that replaces them without changing the data of the table. With the
+    ``native_iceberg_commit`` execution config set, the snapshot is a ``replace`` that is retried on top of concurrent
+    commits as long as they did not remove the replaced files.
+
+    Files that have delete files are left as they are. Planning only reads the metadata of the table. Call
+    :meth:`~daft.io.Compaction.execute` on the returned plan to rewrite and commit the files.
+
+    Example:
+        >>> compaction = daft.io.compact_iceberg(table, target_file_size_bytes=128 * 1024 * 1024)
+        >>> compaction.explain()
+        >>> compaction.execute().show()
+
+    Args:
+        table (pyiceberg.table.Table): `PyIceberg Table <https://py.iceberg.apache.org/reference/pyiceberg/table/#pyiceberg.table.Table>`__ to compact.
+        target_file_size_bytes (int, optional): Size of the files to compact into. Files at least this large are left as
+            they are. Defaults to 512 MiB.
+        io_config (IOConfig, optional): A custom IOConfig to use when accessing Iceberg object storage data. If provided, configurations set in `table` are ignored.
+
+    Returns:
+        Compaction: The planned compaction of the table.
+    """
+    import pyiceberg
+    from packaging.version import parse
+
+    from daft.iceberg.iceberg_scan import IcebergScanOperator
+
+    if parse(pyiceberg.__version__) < parse("0.7.0"):
+        raise ValueError(f"Compacting Iceberg tables requires pyiceberg>=0.7.0, found {pyiceberg.__version__}")
+
+    io_config = (
+        _convert_iceberg_file_io_properties_to_io_config(table.io.properties) if io_config is None else io_config
+    )
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    snapshot = table.current_snapshot()
+    if snapshot is None:
+        return _IcebergCompaction(table, [], None, target_file_size_bytes, io_config)
+
+    # Files can only be compacted with files of the same partition, and files with delete files are left as they are
+    # so that the compaction doesn't depend on their deletes being applied when they are read.
+    specs = table.specs()
+    data_files = {
+        task.file.file_path: task.file
+        for task in table.scan(snapshot_id=snapshot.snapshot_id).plan_files()
+        if not task.delete_files
+    }
+
+    def partition(data_file: "pyiceberg.manifest.DataFile") -> tuple:
+        fields = specs[data_file.spec_id].fields
+        return data_file.spec_id, tuple(getattr(data_file.partition, field.name, None) for field in fields)
+
+    groups = _group_small_files(
+        (
+            (partition(file), CompactedFile(file.file_path, file.record_count, file.file_size_in_bytes))
+            for file in data_files.values()
+        ),
+        target_file_size_bytes,
+    )
+    replaced_data_files = [data_files[file.path] for files in groups.values() for file in files]
+    if not replaced_data_files:
+        return _IcebergCompaction(table, [], None, target_file_size_bytes, io_config)
+
+    multithreaded_io = context.get_context().get_or_create_runner().name != "ray"
+    iceberg_operator = IcebergScanOperator(
+        table,
+        snapshot_id=snapshot.snapshot_id,
+        storage_config=StorageConfig(multithreaded_io, io_config),
+        data_files={data_file.file_path for data_file in replaced_data_files},
+    )
+    handle = ScanOperatorHandle.from_python_scan_operator(iceberg_operator)
+    rewrite = DataFrame(LogicalPlanBuilder.from_tabular_scan(scan_operator=handle))
+    return _IcebergCompaction(table, replaced_data_files, rewrite, target_file_size_bytes, io_config)
@@ -0,0 +1,98 @@
+# isort: dont-add-import: from __future__ import annotations
+
+import datetime
+from typing import Dict, List, Literal, Optional, Union
+
+from daft.api_annotations import PublicAPI
+from daft.daft import KafkaSourceConfig, KafkaValueFormat, ScanOperatorHandle
+from daft.dataframe import DataFrame
+from daft.logical.builder import LogicalPlanBuilder
+from daft.logical.schema import Schema
+
+KafkaOffset = Union[Literal["earliest", "latest"], datetime.datetime, Dict[int, int]]
+
+
+def _to_offset_spec(offset: KafkaOffset) -> Union[str, int, Dict[int, int]]:
+    if isinstance(offset, datetime.datetime):
+        if offset.tzinfo is None:
+            offset = offset.replace(tzinfo=datetime.timezone.utc)
+        return int(offset.timestamp() * 1000)
+    if isinstance(offset, dict) or offset in ("earliest", "latest"):
+        return offset
+    raise ValueError(
+        'Expected a Kafka offset to be "earliest", "latest", a datetime or a dict of offsets per partition, '
+        f"but got {offset}"
+    )
+
+
+@PublicAPI
+def read_kafka(
+    bootstrap_servers: str,
+    topic: str,
+    schema: Schema,
+    *,
+    partitions: Optional[List[int]] = None,
+    start: KafkaOffset = "earliest",
+    end: KafkaOffset = "latest",
+    value_format: Literal["json", "avro"] = "json",
+    avro_schema: Optional[str] = None,
+    consumer_options: Optional[Dict[str, str]] = None,
+    max_messages_per_task: Optional[int] = None,
+    poll_timeout: datetime.timedelta = datetime.timedelta(seconds=30),
+) -> DataFrame:
+    """Creates a DataFrame from a bounded range of the messages of a Kafka topic.
+
+    The start and end of the read are resolved to offsets of each partition of the topic when the DataFrame is created,
+    so it reads the same messages no matter when it is executed. Each partition is read by its own scan task, or by
+    several if ``max_messages_per_task`` is set.
+
+    The values of the messages are decoded into the columns of ``schema``. Columns named ``_kafka_partition``,
+    ``_kafka_offset``, ``_kafka_timestamp`` and ``_kafka_key`` are instead filled from the metadata of the messages.
+
+    Example:
+        >>> schema = daft.Schema._from_field_name_and_types(
+        ...     [("user", daft.DataType.string()), ("_kafka_offset", daft.DataType.int64())]
+        ... )
+        >>> df = daft.read_kafka("localhost:9092", "events", schema)  # doctest: +SKIP
+        >>> df = daft.read_kafka(
+        ...     "localhost:9092", "events", schema, start=datetime.datetime(2024, 1, 1), end={0: 1000, 1: 2000}
+        ... )  # doctest: +SKIP
+
+    Args:
+        bootstrap_servers (str): Comma-separated list of the brokers to connect to.
+        topic (str): The topic to read.
+        schema (Schema): Schema of the values of the messages, and of any of the metadata columns to include.
+        partitions (Optional[List[int]]): Partitions of the topic to read. Defaults to all of them.
+        start: Where to start reading each partition: "earliest", "latest", the first message at or after a datetime,
+            or a dict of offsets per partition. Defaults to "earliest".
+        end: Where to stop reading each partition, exclusive, in the same forms as ``start``. Defaults to "latest".
+        value_format (str): Format of the values of the messages, either "json" or "avro". Defaults to "json".
+        avro_schema (Optional[str]): Writer schema of Avro values as JSON, which is required for Avro values.
+        consumer_options (Optional[Dict[str, str]]): Extra librdkafka consumer options, e.g. for authentication.
+        max_messages_per_task (Optional[int]): Maximum number of offsets of a partition to read in one scan task.
+        poll_timeout (timedelta): How long to wait for messages from a broker before failing. Defaults to 30 seconds.
+
+    Returns:
+        DataFrame: a DataFrame of the messages in the range
+    """
+    formats = {"json": KafkaValueFormat.Json, "avro": KafkaValueFormat.Avro}
+    if value_format not in formats:
+        raise ValueError(f'Expected the value format of a Kafka topic to be "json" or "avro", but got {value_format}')
+
+    config = KafkaSourceConfig(
+        bootstrap_servers,
+        topic,
+        formats[value_format],
+        avro_schema,
+        consumer_options,
+        int(poll_timeout.total_seconds() * 1000),
+    )
+    scan_op = ScanOperatorHandle.kafka_scan(
+        config,
+        schema._schema,
+        partitions,
+        _to_offset_spec(start),
+        _to_offset_spec(end),
+        max_messages_per_task,
+    )
+    return DataFrame(LogicalPlanBuilder.from_tabular_scan(scan_operator=scan_op))
@@ -1,6 +1,6 @@
 # isort: dont-add-import: from __future__ import annotations
 
-from typing import Dict, List, Optional, Union
+from typing import TYPE_CHECKING, Dict, List, Optional, Union
 
 from daft import context
 from daft.api_annotations import PublicAPI
@@ -8,11 +8,16 @@ from daft.daft import (
     FileFormatConfig,
     IOConfig,
     ParquetSourceConfig,
+    ScanOperatorHandle,
     StorageConfig,
 )
 from daft.dataframe import DataFrame
 from daft.datatype import DataType, TimeUnit
 from daft.io.common import get_tabular_files_scan
+from daft.logical.builder import LogicalPlanBuilder
+
+if TYPE_CHECKING:
+    from daft.io.parquet_encryption import ParquetDecryptionConfig
 
 
 @PublicAPI
@@ -26,6 +31,7 @@ def read_parquet(
     hive_partitioning: bool = False,
     coerce_int96_timestamp_unit: Optional[Union[str, TimeUnit]] = None,
     schema_hints: Optional[Dict[str, DataType]] = None,
+    decryption: Optional["ParquetDecryptionConfig"] = None,
     _multithreaded_io: Optional[bool] = None,
     _chunk_size: Optional[int] = None,  # A hidden parameter for testing purposes.
 ) -> DataFrame:
@@ -47,6 +53,9 @@ def read_parquet(
         file_path_column: Include the source path(s) as a column with this name. Defaults to None.
         hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
         coerce_int96_timestamp_unit: TimeUnit to coerce Int96 TimeStamps to. e.g.: [ns, us, ms], Defaults to None.
+        decryption: How to decrypt files that were written with Parquet modular encryption. Encrypted files are read
+            with PyArrow rather than Daft's native reader, and don't support `row_groups`, `schema`,
+            `file_path_column` or `hive_partitioning`. Defaults to None.
         _multithreaded_io: Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
             the amount of system resources (number of connections and thread contention) when running in the Ray runner.
             Defaults to None, which will let Daft decide based on the runner it is currently using.
@@ -70,6 +79,19 @@ def read_parquet(
         (context.get_context().get_or_create_runner().name != "ray") if _multithreaded_io is None else _multithreaded_io
     )
 
+    if decryption is not None:
+        from daft.io.parquet_encryption import EncryptedParquetScanOperator
+
+        if row_groups is not None or schema is not None or file_path_column is not None or hive_partitioning:
+            raise ValueError(
+                "row_groups, schema, file_path_column and hive_partitioning are not supported when reading encrypted Parquet files"
+            )
+        paths = path if isinstance(path, list) else [path]
+        handle = ScanOperatorHandle.from_python_scan_operator(
+            EncryptedParquetScanOperator(paths, decryption, io_config=io_config)
+        )
+        return DataFrame(LogicalPlanBuilder.from_tabular_scan(scan_operator=handle))
+
     if isinstance(coerce_int96_timestamp_unit, str):
         coerce_int96_timestamp_unit = TimeUnit.from_str(coerce_int96_timestamp_unit)
 
@@ -27,6 +27,7 @@ def read_sql(
     infer_schema: bool = True,
     infer_schema_length: int = 10,
     schema: Optional[Dict[str, DataType]] = None,
+    protocol: Optional[str] = None,
 ) -> DataFrame:
     """Create a DataFrame from the results of a SQL query.
 
@@ -43,6 +44,8 @@ def read_sql(
         infer_schema_length (int): The number of rows to scan when inferring the schema, defaults to 10. If infer_schema is False, this parameter is ignored. Note that if Daft is able to use ConnectorX to infer the schema, this parameter is ignored as ConnectorX is an Arrow backed driver.
         schema (Optional[Dict[str, DataType]]): A mapping of column names to datatypes. If infer_schema is False, this schema is used as the definitive schema for the data, otherwise it is used as a schema hint that is applied after the schema is inferred.
             This can be useful if the types can be more precisely determined than what the inference can provide (e.g., if a column can be declared as a fixed-sized list rather than a list).
+        protocol (Optional[str]): The protocol ConnectorX uses to transfer results from the database, defaults to None, which uses the default of the database.
+            For example, "binary" reads Postgres results with binary `COPY` and "cursor" reads them with a server-side cursor, which is needed for Redshift. Only supported when `conn` is a database URL read with ConnectorX.
 
     Returns:
         DataFrame: Dataframe containing the results of the query
@@ -61,6 +64,7 @@ def read_sql(
         #. Execution:
             Daft executes SQL queries using using `ConnectorX <https://sfu-db.github.io/connector-x/intro.html>`_ or `SQLAlchemy <https://docs.sqlalchemy.org/en/20/orm/quickstart.html#create-an-engine>`_,
             preferring ConnectorX unless a SQLAlchemy connection factory is specified or the database dialect is unsupported by ConnectorX.
+            ConnectorX reads results in bulk with the binary protocol of the database where there is one, which can be changed with `protocol`.
 
         #. Pushdowns:
             Daft pushes down operations such as filtering, projections, and limits into the SQL query when possible.
@@ -96,7 +100,13 @@ def read_sql(
     io_config = context.get_context().daft_planning_config.default_io_config
     storage_config = StorageConfig(True, io_config)
 
-    sql_conn = SQLConnection.from_url(conn) if isinstance(conn, str) else SQLConnection.from_connection_factory(conn)
+    sql_conn = (
+        SQLConnection.from_url(conn, protocol) if isinstance(conn, str) else SQLConnection.from_connection_factory(conn)
+    )
+    if protocol is not None and not sql_conn._should_use_connectorx():
+        raise ValueError(
+            f"Failed to execute sql: protocol can only be specified for database URLs read with ConnectorX, got: {sql_conn}"
+        )
     sql_operator = SQLScanOperator(
         sql,
         sql_conn,
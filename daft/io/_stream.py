@@ -0,0 +1,117 @@
+# isort: dont-add-import: from __future__ import annotations
+
+import datetime
+from typing import TYPE_CHECKING, Callable, Iterator, Optional, Union
+
+from daft.api_annotations import PublicAPI
+from daft.daft import PyMicroPartition
+from daft.dataframe import DataFrame
+from daft.dependencies import pa
+from daft.logical.builder import LogicalPlanBuilder
+from daft.logical.schema import Schema
+from daft.table import MicroPartition
+
+if TYPE_CHECKING:
+    StreamBatch = Union[MicroPartition, pa.Table, pa.RecordBatch, dict, None]
+
+_TIME_UNITS_PER_SECOND = {"s": 1, "ms": 1_000, "us": 1_000_000, "ns": 1_000_000_000}
+
+
+def _to_micropartition(batch: "StreamBatch") -> Optional[PyMicroPartition]:
+    if batch is None:
+        return None
+    if isinstance(batch, MicroPartition):
+        return batch._micropartition
+    if isinstance(batch, pa.RecordBatch):
+        batch = pa.Table.from_batches([batch])
+    if isinstance(batch, pa.Table):
+        return MicroPartition.from_arrow(batch)._micropartition
+    if isinstance(batch, dict):
+        return MicroPartition.from_pydict(batch)._micropartition
+    raise TypeError(f"Expected a stream to yield MicroPartitions, Arrow tables, dicts or None, but got {type(batch)}")
+
+
+def _duration_in_column_units(
+    schema: Schema,
+    event_time_column: str,
+    duration: Union[datetime.timedelta, int],
+    description: str = "watermark delay",
+) -> int:
+    if isinstance(duration, int):
+        return duration
+    dtype = schema[event_time_column].dtype
+    arrow_type = dtype.to_arrow_dtype()
+    if pa.types.is_timestamp(arrow_type):
+        return int(duration.total_seconds() * _TIME_UNITS_PER_SECOND[arrow_type.unit])
+    if pa.types.is_date(arrow_type):
+        return duration.days
+    raise ValueError(
+        f"A timedelta {description} requires the event time column {event_time_column} to be a timestamp or date "
+        f"column, but it is {dtype}. Use an integer {description} in the units of the column instead."
+    )
+
+
+@PublicAPI
+def read_stream(
+    batches: Callable[[], Iterator["StreamBatch"]],
+    schema: Schema,
+    *,
+    name: str = "stream",
+    poll_interval: datetime.timedelta = datetime.timedelta(milliseconds=100),
+    event_time_column: Optional[str] = None,
+    watermark_delay: Union[datetime.timedelta, int] = 0,
+) -> DataFrame:
+    """Create a DataFrame from an unbounded stream of micro-batches, such as the messages of a Kafka consumer.
+
+    The stream is read by calling ``batches`` when the DataFrame is executed, and pulling micro-batches from the
+    returned iterator until it is exhausted, which may never happen. The iterator yields ``None`` when no data is
+    available yet, after which it is pulled from again every ``poll_interval``. Only the native runner can read
+    streams; consume them with :meth:`DataFrame.iter_partitions`, a limit or a write rather than collecting them.
+
+    If ``event_time_column`` is given, the stream tracks a watermark which trails the latest event time seen so far by
+    ``watermark_delay``, and drops late rows with an event time behind the watermark.
+
+    Example:
+        >>> import daft
+        >>> def batches():
+        ...     for i in range(3):
+        ...         yield {"x": [i, i + 1]}
+        >>> schema = daft.Schema._from_field_name_and_types([("x", daft.DataType.int64())])
+        >>> df = daft.read_stream(batches, schema)
+        >>> df.limit(4).to_pydict()  # doctest: +SKIP
+        {'x': [0, 1, 1, 2]}
+
+    Args:
+        batches (Callable[[], Iterator]): Function returning an iterator of micro-batches, which may be daft
+            MicroPartitions, Arrow tables or record batches, dicts of columns, or None when no data is available yet.
+        schema (Schema): Schema of the micro-batches, which they are cast to.
+        name (str): Name of the stream in plans.
+        poll_interval (timedelta): How long to wait before pulling from the iterator again after it yields None.
+        event_time_column (Optional[str]): Integer, timestamp or date column with the event time of each row, which
+            the watermark of the stream is tracked from.
+        watermark_delay (Union[timedelta, int]): How far the watermark trails the latest event time, either as a
+            timedelta or as an integer in the units of the event time column. Defaults to 0.
+
+    Returns:
+        DataFrame: a DataFrame of the rows of the stream
+    """
+
+    def batch_iterator() -> Iterator[Optional[PyMicroPartition]]:
+        for batch in batches():
+            yield _to_micropartition(batch)
+
+    delay = None
+    if event_time_column is not None:
+        if event_time_column not in schema.column_names():
+            raise ValueError(f"Event time column {event_time_column} is not in the schema of the stream: {schema}")
+        delay = _duration_in_column_units(schema, event_time_column, watermark_delay)
+
+    builder = LogicalPlanBuilder.from_stream_scan(
+        name=name,
+        batch_iterator_factory=batch_iterator,
+        schema=schema,
+        poll_interval_ms=int(poll_interval.total_seconds() * 1000),
+        watermark_column=event_time_column,
+        watermark_delay=delay,
+    )
+    return DataFrame(builder)
@@ -0,0 +1,79 @@
+# isort: dont-add-import: from __future__ import annotations
+
+from typing import Dict, List, Optional, Union
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.daft import (
+    FileFormatConfig,
+    IOConfig,
+    StorageConfig,
+    XmlSourceConfig,
+)
+from daft.dataframe import DataFrame
+from daft.datatype import DataType
+from daft.io.common import get_tabular_files_scan
+
+
+@PublicAPI
+def read_xml(
+    path: Union[str, List[str]],
+    row_tag: str,
+    infer_schema: bool = True,
+    schema: Optional[Dict[str, DataType]] = None,
+    attribute_prefix: str = "_",
+    value_tag: str = "_VALUE",
+    io_config: Optional["IOConfig"] = None,
+    file_path_column: Optional[str] = None,
+    hive_partitioning: bool = False,
+) -> DataFrame:
+    """Creates a DataFrame from XML file(s), with a row per element with the given row tag.
+
+    The children and attributes of a row element are read as its columns, where the names of the columns of
+    attributes start with ``attribute_prefix``. Children that have children or attributes of their own are read as
+    structs, and children that occur more than once in an element are read as lists. The text of an element that
+    also has children or attributes is read into a field named ``value_tag``.
+
+    Example:
+        >>> df = daft.read_xml("/path/to/file.xml", row_tag="record")
+        >>> df = daft.read_xml("/path/to/directory", row_tag="record")
+        >>> df = daft.read_xml("s3://path/to/files-*.xml.gz", row_tag="record")
+
+    Args:
+        path (str): Path to XML files (allows for wildcards)
+        row_tag (str): Name of the elements that are read as rows, e.g. ``"record"`` for ``<record>...</record>``.
+        infer_schema (bool): Whether to infer the schema of the XML from the first rows of the first file, defaults to True.
+        schema (dict[str, DataType]): A schema that is used as the definitive schema for the XML if infer_schema is False, otherwise it is used as a schema hint that is applied after the schema is inferred.
+        attribute_prefix (str): Prefix of the names of the columns and fields read from attributes, defaults to "_".
+        value_tag (str): Name of the field that holds the text of elements with children or attributes, defaults to "_VALUE".
+        io_config (IOConfig): Config to be used with the native downloader
+        file_path_column: Include the source path(s) as a column with this name. Defaults to None.
+        hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
+
+    returns:
+        DataFrame: parsed DataFrame
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of XML filepaths")
+
+    if not infer_schema and schema is None:
+        raise ValueError(
+            "Cannot read DataFrame with infer_schema=False and schema=None, please provide a schema or set infer_schema=True"
+        )
+
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    xml_config = XmlSourceConfig(row_tag, attribute_prefix, value_tag)
+    file_format_config = FileFormatConfig.from_xml_config(xml_config)
+    storage_config = StorageConfig(True, io_config)
+
+    builder = get_tabular_files_scan(
+        path=path,
+        infer_schema=infer_schema,
+        schema=schema,
+        file_format_config=file_format_config,
+        storage_config=storage_config,
+        file_path_column=file_path_column,
+        hive_partitioning=hive_partitioning,
+    )
+    return DataFrame(builder)
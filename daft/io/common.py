@@ -2,11 +2,13 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING
 
+from daft.context import get_context
 from daft.daft import FileFormatConfig, ScanOperatorHandle, StorageConfig
 from daft.logical.builder import LogicalPlanBuilder
 from daft.logical.schema import Schema
 
 if TYPE_CHECKING:
+    from daft.daft import PySchema
     from daft.datatype import DataType
 
 
@@ -17,6 +19,16 @@ def _get_schema_from_dict(fields: dict[str, DataType]) -> Schema:
         raise NotImplementedError(f"Unsupported schema fields: {type(fields)}")
 
 
+def _get_schema_hints(infer_schema: bool, schema: dict[str, DataType] | None) -> PySchema | None:
+    """The schema of a read, merged with the default schema hints of the planning config if the schema is inferred."""
+    schema_hints = _get_schema_from_dict(schema) if schema is not None else None
+    default_schema_hints = get_context().daft_planning_config.default_schema_hints
+    if infer_schema and default_schema_hints is not None:
+        default_schema_hints = Schema._from_pyschema(default_schema_hints)
+        schema_hints = default_schema_hints if schema_hints is None else default_schema_hints.merge_hints(schema_hints)
+    return schema_hints._schema if schema_hints is not None else None
+
+
 def get_tabular_files_scan(
     path: str | list[str],
     infer_schema: bool,
@@ -41,7 +53,7 @@ def get_tabular_files_scan(
         file_format_config,
         storage_config,
         infer_schema=infer_schema,
-        schema=_get_schema_from_dict(schema)._schema if schema is not None else None,
+        schema=_get_schema_hints(infer_schema, schema),
         file_path_column=file_path_column,
         hive_partitioning=hive_partitioning,
     )
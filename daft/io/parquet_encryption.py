@@ -0,0 +1,224 @@
+from __future__ import annotations
+
+import dataclasses
+from typing import TYPE_CHECKING, Callable, Dict, Iterator, List, Optional
+
+from daft.daft import IOConfig, Pushdowns, PyTable, ScanTask, io_glob
+from daft.dependencies import pq
+from daft.filesystem import _resolve_paths_and_filesystem
+from daft.io.scan import PartitionField, ScanOperator
+from daft.logical.schema import Schema
+from daft.table import Table
+
+if TYPE_CHECKING:
+    import pyarrow.dataset as pads
+    import pyarrow.fs as pafs
+    import pyarrow.parquet.encryption as pqe
+
+
+@dataclasses.dataclass(frozen=True)
+class KmsConfig:
+    """Connection to the Key Management Service (KMS) which wraps the keys of encrypted Parquet files.
+
+    Daft uses Parquet modular encryption as implemented by PyArrow: the data of each file is encrypted with
+    random data keys, which are in turn encrypted ("wrapped") with master keys that never leave the KMS.
+
+    Args:
+        client_factory: Function which returns a ``pyarrow.parquet.encryption.KmsClient`` for a
+            ``pyarrow.parquet.encryption.KmsConnectionConfig``. It is called on the workers that read and write files,
+            so it must be serializable.
+        instance_id: ID of the KMS instance. Defaults to "".
+        instance_url: URL of the KMS instance. Defaults to "".
+        key_access_token: Authorization token passed to the KMS client. Defaults to "DEFAULT".
+        custom_kms_conf: Additional KMS-specific configuration passed to the KMS client. Defaults to {}.
+    """
+
+    client_factory: Callable[[pqe.KmsConnectionConfig], pqe.KmsClient]
+    instance_id: str = ""
+    instance_url: str = ""
+    key_access_token: str = "DEFAULT"
+    custom_kms_conf: Dict[str, str] = dataclasses.field(default_factory=dict)
+
+    def crypto_factory(self) -> pqe.CryptoFactory:
+        import pyarrow.parquet.encryption as pqe
+
+        return pqe.CryptoFactory(self.client_factory)
+
+    def connection_config(self) -> pqe.KmsConnectionConfig:
+        import pyarrow.parquet.encryption as pqe
+
+        return pqe.KmsConnectionConfig(
+            kms_instance_id=self.instance_id,
+            kms_instance_url=self.instance_url,
+            key_access_token=self.key_access_token,
+            custom_kms_conf=self.custom_kms_conf,
+        )
+
+
+@dataclasses.dataclass(frozen=True)
+class ParquetEncryptionConfig:
+    """Which columns of written Parquet files to encrypt, and with which master keys.
+
+    Example:
+        >>> encryption = ParquetEncryptionConfig(  # doctest: +SKIP
+        ...     kms=KmsConfig(client_factory=my_kms_client),
+        ...     footer_key="footer_key_id",
+        ...     column_keys={"pii_key_id": ["ssn", "email"]},
+        ... )
+        >>> df.write_parquet("s3://bucket/path", encryption=encryption)  # doctest: +SKIP
+
+    Args:
+        kms: Connection to the KMS which holds the master keys.
+        footer_key: ID of the master key of the footer of the files, which holds their schema and statistics.
+        column_keys: IDs of master keys, mapped to the columns that are encrypted with each key. Columns which aren't
+            listed are written in plaintext.
+        encryption_algorithm: Either "AES_GCM_V1" or "AES_GCM_CTR_V1". Defaults to "AES_GCM_V1".
+        plaintext_footer: Whether to leave the footer unencrypted, so that readers without access to the keys can
+            still read the unencrypted columns. Defaults to False.
+        double_wrapping: Whether to wrap the data keys with intermediate keys, which reduces the number of calls to the
+            KMS. Defaults to True.
+    """
+
+    kms: KmsConfig
+    footer_key: str
+    column_keys: Dict[str, List[str]]
+    encryption_algorithm: str = "AES_GCM_V1"
+    plaintext_footer: bool = False
+    double_wrapping: bool = True
+
+    def _encryption_configuration(self) -> pqe.EncryptionConfiguration:
+        import pyarrow.parquet.encryption as pqe
+
+        return pqe.EncryptionConfiguration(
+            footer_key=self.footer_key,
+            column_keys=self.column_keys,
+            encryption_algorithm=self.encryption_algorithm,
+            plaintext_footer=self.plaintext_footer,
+            double_wrapping=self.double_wrapping,
+        )
+
+    def file_encryption_properties(self) -> pq.FileEncryptionProperties:
+        """Properties to pass to a ``pyarrow.parquet.ParquetWriter`` to encrypt a single file."""
+        return self.kms.crypto_factory().file_encryption_properties(
+            self.kms.connection_config(), self._encryption_configuration()
+        )
+
+    def dataset_encryption_config(self) -> pads.ParquetEncryptionConfig:
+        """Config to pass to the Parquet write options of ``pyarrow.dataset.write_dataset``."""
+        import pyarrow.dataset as pads
+
+        return pads.ParquetEncryptionConfig(
+            self.kms.crypto_factory(), self.kms.connection_config(), self._encryption_configuration()
+        )
+
+
+@dataclasses.dataclass(frozen=True)
+class ParquetDecryptionConfig:
+    """How to decrypt Parquet files that were written with Parquet modular encryption.
+
+    Files store the IDs of their master keys, so decrypting them only needs a connection to the KMS which holds
+    the keys.
+
+    Args:
+        kms: Connection to the KMS which holds the master keys.
+    """
+
+    kms: KmsConfig
+
+    def file_decryption_properties(self) -> pq.FileDecryptionProperties:
+        """Properties to pass to a ``pyarrow.parquet.ParquetFile`` to decrypt a single file."""
+        import pyarrow.parquet.encryption as pqe
+
+        return self.kms.crypto_factory().file_decryption_properties(
+            self.kms.connection_config(), pqe.DecryptionConfiguration()
+        )
+
+
+def _encrypted_parquet_factory_function(
+    path: str,
+    fs: pafs.FileSystem,
+    decryption: ParquetDecryptionConfig,
+    required_columns: Optional[List[str]],
+) -> Iterator[PyTable]:
+    with fs.open_input_file(path) as f:
+        parquet_file = pq.ParquetFile(f, decryption_properties=decryption.file_decryption_properties())
+        for rb in parquet_file.iter_batches(columns=required_columns):
+            yield Table.from_arrow_record_batches([rb], rb.schema)._table
+
+
+class EncryptedParquetScanOperator(ScanOperator):
+    """Scans Parquet files with encrypted columns or footers.
+
+    Daft's native Parquet reader doesn't implement Parquet modular encryption, so these files are read and decrypted
+    with PyArrow instead.
+    """
+
+    def __init__(
+        self,
+        paths: List[str],
+        decryption: ParquetDecryptionConfig,
+        io_config: Optional[IOConfig] = None,
+    ):
+        files = [file for path in paths for file in io_glob(path, io_config=io_config)]
+        if len(files) == 0:
+            raise FileNotFoundError(f"No Parquet files found at {paths}")
+        self._paths = paths
+        self._sizes: List[Optional[int]] = [file["size"] for file in files]
+        self._resolved_paths, self._fs = _resolve_paths_and_filesystem(
+            [file["path"] for file in files], io_config=io_config
+        )
+        self._decryption = decryption
+        with self._fs.open_input_file(self._resolved_paths[0]) as f:
+            arrow_schema = pq.ParquetFile(f, decryption_properties=decryption.file_decryption_properties()).schema_arrow
+        self._schema = Schema.from_pyarrow_schema(arrow_schema)
+
+    def name(self) -> str:
+        return "EncryptedParquetScanOperator"
+
+    def display_name(self) -> str:
+        return f"EncryptedParquetScanOperator({', '.join(self._paths)})"
+
+    def schema(self) -> Schema:
+        return self._schema
+
+    def partitioning_keys(self) -> List[PartitionField]:
+        return []
+
+    def can_absorb_filter(self) -> bool:
+        return False
+
+    def can_absorb_limit(self) -> bool:
+        return False
+
+    def can_absorb_select(self) -> bool:
+        return False
+
+    def multiline_display(self) -> List[str]:
+        return [
+            self.display_name(),
+            f"Schema = {self.schema()}",
+        ]
+
+    def to_scan_tasks(self, pushdowns: Pushdowns) -> Iterator[ScanTask]:
+        required_columns: Optional[List[str]]
+        if pushdowns.columns is None:
+            required_columns = None
+        else:
+            filter_required_column_names = pushdowns.filter_required_column_names()
+            required_columns = (
+                pushdowns.columns
+                if filter_required_column_names is None
+                else pushdowns.columns + filter_required_column_names
+            )
+
+        for path, size_bytes in zip(self._resolved_paths, self._sizes):
+            yield ScanTask.python_factory_func_scan_task(
+                module=_encrypted_parquet_factory_function.__module__,
+                func_name=_encrypted_parquet_factory_function.__name__,
+                func_args=(path, self._fs, self._decryption, required_columns),
+                schema=self.schema()._schema,
+                num_rows=None,
+                size_bytes=size_bytes,
+                pushdowns=pushdowns,
+                stats=None,
+            )
@@ -0,0 +1,162 @@
+"""Terms that the filters pushed down to a :class:`~daft.io.scan.ScanOperator` are translated into.
+
+Filters are split into the predicates that they're conjunctions of, and each predicate that only uses
+columns, literals of primitive types and the procedures below is translated into a term. Sources that
+can apply some of the predicates while reading can then interpret them without depending on Daft's
+expressions.
+
+Procedures are named after their operators, such as ``==``, ``!=``, ``<``, ``<=``, ``>``, ``>=``, ``&``,
+``|``, ``+``, ``-``, ``*`` and ``/``, or are one of ``not``, ``is_null``, ``not_null``, ``is_in`` (whose
+first argument is the term to look up and whose other arguments are the items) and ``between`` (whose
+arguments are the term, its lower bound and its upper bound).
+
+Terms can be sent to sources outside of the process, such as REST data services or remote workers, as JSON:
+references are ``{"ref": name}``, literals are ``{"lit": value}`` and procedures are
+``{"proc": proc, "args": [...]}``.
+"""
+
+from __future__ import annotations
+
+import json
+import operator
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any, Callable
+
+from daft.expressions import Expression, col, lit
+
+if TYPE_CHECKING:
+    from daft.daft import Pushdowns
+
+
+class Term:
+    """A column reference, a literal or a procedure applied to other terms."""
+
+
+@dataclass(frozen=True)
+class Reference(Term):
+    """A reference to a column."""
+
+    name: str
+
+    def __str__(self) -> str:
+        return self.name
+
+
+@dataclass(frozen=True)
+class Literal(Term):
+    """A literal value: ``None``, or a ``bool``, ``str``, ``int`` or ``float``."""
+
+    value: Any
+
+    def __str__(self) -> str:
+        return repr(self.value)
+
+
+@dataclass(frozen=True)
+class Expr(Term):
+    """A procedure applied to terms."""
+
+    proc: str
+    args: tuple[Term, ...]
+
+    def __str__(self) -> str:
+        return f"({self.proc} {' '.join(str(arg) for arg in self.args)})"
+
+
+def filter_terms(pushdowns: Pushdowns) -> list[Term]:
+    """The predicates of the filters of the pushdowns that can be translated into terms."""
+    return pushdowns.filter_terms()
+
+
+def filter_terms_json(pushdowns: Pushdowns) -> str:
+    """The predicates of :func:`filter_terms` serialized into a JSON array of terms."""
+    return pushdowns.filter_terms_json()
+
+
+def to_json(terms: list[Term]) -> str:
+    """Serializes terms into a JSON array."""
+    return json.dumps([_to_json_value(term) for term in terms], separators=(",", ":"), allow_nan=False)
+
+
+def from_json(data: str) -> list[Term]:
+    """Deserializes terms from a JSON array of terms."""
+    values = json.loads(data)
+    if not isinstance(values, list):
+        raise ValueError(f"Expected a JSON array of terms, but got {data}")
+    return [_from_json_value(value) for value in values]
+
+
+def to_expression(term: Term) -> Expression:
+    """Translates a term back into a Daft expression, so that it can be evaluated by Daft."""
+    if isinstance(term, Reference):
+        return col(term.name)
+    if isinstance(term, Literal):
+        return lit(term.value)
+    if not isinstance(term, Expr):
+        raise TypeError(f"Expected a term, but got {type(term)}")
+
+    args = [to_expression(arg) for arg in term.args]
+    if term.proc == "is_in" and args:
+        return Expression._from_pyexpr(args[0]._expr.is_in([arg._expr for arg in args[1:]]))
+    if term.proc in _UNARY_PROCS:
+        expected_num_args = 1
+        apply: Callable[..., Expression] = _UNARY_PROCS[term.proc]
+    elif term.proc == "between":
+        expected_num_args = 3
+        apply = Expression.between
+    elif term.proc in _BINARY_PROCS:
+        expected_num_args = 2
+        apply = _BINARY_PROCS[term.proc]
+    else:
+        raise ValueError(f"Unknown procedure of a term: {term.proc}")
+    if len(args) != expected_num_args:
+        raise ValueError(f"Procedure {term.proc} of a term expects {expected_num_args} arguments, but got {len(args)}")
+    return apply(*args)
+
+
+_UNARY_PROCS: dict[str, Callable[[Expression], Expression]] = {
+    "not": operator.invert,
+    "is_null": Expression.is_null,
+    "not_null": Expression.not_null,
+}
+
+_BINARY_PROCS: dict[str, Callable[[Expression, Expression], Expression]] = {
+    "==": operator.eq,
+    "!=": operator.ne,
+    "<": operator.lt,
+    "<=": operator.le,
+    ">": operator.gt,
+    ">=": operator.ge,
+    "+": operator.add,
+    "-": operator.sub,
+    "*": operator.mul,
+    "/": operator.truediv,
+    "//": operator.floordiv,
+    "%": operator.mod,
+    "&": operator.and_,
+    "|": operator.or_,
+    "^": operator.xor,
+    "<<": operator.lshift,
+    ">>": operator.rshift,
+}
+
+
+def _to_json_value(term: Term) -> dict[str, Any]:
+    if isinstance(term, Reference):
+        return {"ref": term.name}
+    if isinstance(term, Literal):
+        return {"lit": term.value}
+    if isinstance(term, Expr):
+        return {"proc": term.proc, "args": [_to_json_value(arg) for arg in term.args]}
+    raise TypeError(f"Expected a term, but got {type(term)}")
+
+
+def _from_json_value(value: Any) -> Term:
+    if isinstance(value, dict):
+        if isinstance(value.get("ref"), str):
+            return Reference(value["ref"])
+        if "lit" in value and (value["lit"] is None or isinstance(value["lit"], (bool, str, int, float))):
+            return Literal(value["lit"])
+        if isinstance(value.get("proc"), str) and isinstance(value.get("args"), list):
+            return Expr(value["proc"], tuple(_from_json_value(arg) for arg in value["args"]))
+    raise ValueError(f"Invalid JSON for a term: {json.dumps(value)}")
@@ -4,10 +4,12 @@ import abc
 from typing import TYPE_CHECKING
 
 from daft.daft import PartitionField, PartitionTransform, Pushdowns, ScanTask
+from daft.io.pushdowns import filter_terms
 
 if TYPE_CHECKING:
     from collections.abc import Iterator
 
+    from daft.io.pushdowns import Term
     from daft.logical.schema import Field, Schema
 
 
@@ -46,6 +48,30 @@ class ScanOperator(abc.ABC):
     def can_absorb_select(self) -> bool:
         raise NotImplementedError()
 
+    def can_absorb_predicate(self, predicate: Term) -> bool:
+        """Whether the operator can apply a predicate of the filters that are pushed down to it while scanning.
+
+        Operators that can absorb filters can absorb all of their predicates by default.
+        """
+        return self.can_absorb_filter()
+
+    def absorbable_predicates(self, pushdowns: Pushdowns) -> list[Term]:
+        """The predicates of the filters of the pushdowns that the operator can apply while scanning.
+
+        Predicates that can't be translated into terms are left out. Daft applies the filters to the rows that
+        are scanned regardless, so operators may skip rows that don't match these predicates without having to
+        skip all of them.
+        """
+        return [predicate for predicate in filter_terms(pushdowns) if self.can_absorb_predicate(predicate)]
+
+    def absorbable_columns(self, pushdowns: Pushdowns) -> list[str] | None:
+        """The columns that the operator should read, or None if it can't absorb selections or should read all of them."""
+        return pushdowns.columns if self.can_absorb_select() else None
+
+    def absorbable_limit(self, pushdowns: Pushdowns) -> int | None:
+        """The number of rows that the operator should read, or None if it can't absorb limits or there's no limit."""
+        return pushdowns.limit if self.can_absorb_limit() else None
+
     @abc.abstractmethod
     def multiline_display(self) -> list[str]:
         raise NotImplementedError()
@@ -0,0 +1,50 @@
+from __future__ import annotations
+
+import dataclasses
+import functools
+from typing import Any, List, Optional
+
+from daft.expressions import Expression, col
+
+
+@dataclasses.dataclass(frozen=True)
+class WriteConstraint:
+    """A check that every row written by ``DataFrame.write_parquet`` or ``DataFrame.write_csv`` has to pass.
+
+    Rows are checked as they are written. A row violates the constraint if ``predicate`` is false or null for it, and
+    the write then fails, drops the row or quarantines it depending on its ``on_constraint_violation`` argument.
+
+    Args:
+        name: Name of the constraint, which errors about violations of it refer to.
+        predicate: Boolean expression over the written columns that holds for the rows satisfying the constraint.
+    """
+
+    name: str
+    predicate: Expression
+
+    @classmethod
+    def not_null(cls, column: str) -> WriteConstraint:
+        """Constrains ``column`` to not be null."""
+        return cls(f"{column} IS NOT NULL", col(column).not_null())
+
+    @classmethod
+    def between(cls, column: str, min: Optional[Any] = None, max: Optional[Any] = None) -> WriteConstraint:
+        """Constrains the non-null values of ``column`` to be at least ``min`` and at most ``max``, if given."""
+        if min is None and max is None:
+            raise ValueError("A bounds constraint requires at least one of `min` and `max`")
+
+        names = []
+        predicates = []
+        if min is not None:
+            names.append(f"{column} >= {min!r}")
+            predicates.append(col(column) >= min)
+        if max is not None:
+            names.append(f"{column} <= {max!r}")
+            predicates.append(col(column) <= max)
+        predicate = functools.reduce(lambda left, right: left & right, predicates)
+        return cls(" AND ".join(names), col(column).is_null() | predicate)
+
+    @classmethod
+    def accepted_values(cls, column: str, values: List[Any]) -> WriteConstraint:
+        """Constrains the non-null values of ``column`` to be one of ``values``."""
+        return cls(f"{column} IN {values!r}", col(column).is_null() | col(column).is_in(values))
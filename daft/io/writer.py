@@ -2,13 +2,14 @@ import uuid
 from abc import ABC, abstractmethod
 from typing import TYPE_CHECKING, List, Optional
 
-from daft.daft import IOConfig
-from daft.delta_lake.delta_lake_write import make_deltalake_add_action, make_deltalake_fs, sanitize_table_for_deltalake
+from daft.daft import CsvWriteOptions, IOConfig
+from daft.delta_lake.delta_lake_write import make_deltalake_add_action, sanitize_table_for_deltalake
 from daft.dependencies import pa, pacsv, pq
 from daft.filesystem import (
     _resolve_paths_and_filesystem,
     canonicalize_protocol,
     get_protocol_from_path,
+    staged_file_prefix,
 )
 from daft.iceberg.iceberg_write import (
     coerce_pyarrow_table_to_schema,
@@ -22,11 +23,14 @@ from daft.table.partitioning import (
     partition_values_to_str_mapping,
 )
 from daft.table.table import Table
+from daft.table.table_io import pyarrow_csv_write_options
 
 if TYPE_CHECKING:
     from pyiceberg.schema import Schema as IcebergSchema
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
+
 
 class FileWriterBase(ABC):
     def __init__(
@@ -39,6 +43,8 @@ class FileWriterBase(ABC):
         io_config: Optional[IOConfig] = None,
         version: Optional[int] = None,
         default_partition_fallback: Optional[str] = None,
+        job_id: Optional[str] = None,
+        attempt_id: Optional[str] = None,
     ):
         resolved_path, self.fs = self.resolve_path_and_fs(root_dir, io_config=io_config)
         protocol = get_protocol_from_path(root_dir)
@@ -50,6 +56,10 @@ class FileWriterBase(ABC):
             if version is None
             else f"{version}-{uuid.uuid4()}-{file_idx}.{file_format}"
         )
+        if job_id is not None:
+            # Committed by `commit_write` once every file of the attempt is written
+            assert attempt_id is not None
+            self.file_name = f"{staged_file_prefix(job_id, attempt_id)}{self.file_name}"
         self.partition_values = partition_values
         if self.partition_values is not None:
             self.partition_strings = {
@@ -113,6 +123,9 @@ class ParquetFileWriter(FileWriterBase):
         version: Optional[int] = None,
         default_partition_fallback: Optional[str] = None,
         metadata_collector: Optional[List[pq.FileMetaData]] = None,
+        encryption: Optional["ParquetEncryptionConfig"] = None,
+        job_id: Optional[str] = None,
+        attempt_id: Optional[str] = None,
     ):
         super().__init__(
             root_dir=root_dir,
@@ -123,15 +136,20 @@ class ParquetFileWriter(FileWriterBase):
             io_config=io_config,
             version=version,
             default_partition_fallback=default_partition_fallback,
+            job_id=job_id,
+            attempt_id=attempt_id,
         )
         self.is_closed = False
         self.current_writer: Optional[pq.ParquetWriter] = None
         self.metadata_collector: Optional[List[pq.FileMetaData]] = metadata_collector
+        self.encryption = encryption
 
     def _create_writer(self, schema: pa.Schema) -> pq.ParquetWriter:
         opts = {}
         if self.metadata_collector is not None:
             opts["metadata_collector"] = self.metadata_collector
+        if self.encryption is not None:
+            opts["encryption_properties"] = self.encryption.file_encryption_properties()
         return pq.ParquetWriter(
             self.full_path,
             schema,
@@ -171,6 +189,9 @@ class CSVFileWriter(FileWriterBase):
         file_idx: int,
         partition_values: Optional[Table] = None,
         io_config: Optional[IOConfig] = None,
+        csv_options: Optional[CsvWriteOptions] = None,
+        job_id: Optional[str] = None,
+        attempt_id: Optional[str] = None,
     ):
         super().__init__(
             root_dir=root_dir,
@@ -178,16 +199,22 @@ class CSVFileWriter(FileWriterBase):
             file_format="csv",
             partition_values=partition_values,
             io_config=io_config,
+            job_id=job_id,
+            attempt_id=attempt_id,
         )
         self.file_handle = None
         self.current_writer: Optional[pacsv.CSVWriter] = None
         self.is_closed = False
+        self.write_options = (
+            pacsv.WriteOptions(**pyarrow_csv_write_options(csv_options)) if csv_options is not None else None
+        )
 
     def _create_writer(self, schema: pa.Schema) -> pacsv.CSVWriter:
         self.file_handle = self.fs.open_output_stream(self.full_path)
         return pacsv.CSVWriter(
             self.file_handle,
             schema,
+            write_options=self.write_options,
         )
 
     def write(self, table: MicroPartition) -> int:
@@ -302,7 +329,9 @@ class DeltalakeWriter(ParquetFileWriter):
         self.large_dtypes = large_dtypes
 
     def resolve_path_and_fs(self, root_dir: str, io_config: Optional[IOConfig] = None):
-        return "", make_deltalake_fs(root_dir, io_config)
+        # Paths of the files in the Delta log are relative to the table.
+        self.table_path, fs = super().resolve_path_and_fs(root_dir, io_config=io_config)
+        return self.table_path, fs
 
     def write(self, table: MicroPartition) -> int:
         assert not self.is_closed, "Cannot write to a closed DeltalakeFileWriter"
@@ -334,6 +363,7 @@ class DeltalakeWriter(ParquetFileWriter):
             metadata=metadata,
             size=size,
             partition_values=self.partition_strings,
+            table_path=self.table_path,
         )
 
         return Table.from_pydict({"add_action": [add_action]})
@@ -1,7 +1,8 @@
 from __future__ import annotations
 
 import functools
-from typing import TYPE_CHECKING, Callable
+import json
+from typing import TYPE_CHECKING, Any, Callable, Iterator
 
 from daft.context import get_context
 from daft.daft import (
@@ -23,11 +24,15 @@ if TYPE_CHECKING:
 
     from pyiceberg.table import Table as IcebergTable
 
+    from daft.daft import CsvWriteOptions, PyMicroPartition
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
+    from daft.io.write_constraints import WriteConstraint
     from daft.plan_scheduler.physical_plan_scheduler import (
         AdaptivePhysicalPlanScheduler,
         PhysicalPlanScheduler,
     )
     from daft.runners.partitioning import PartitionCacheEntry
+    from daft.window import WindowFunction
 
 
 def _apply_daft_planning_config_to_initializer(classmethod_func: Callable[..., LogicalPlanBuilder]):
@@ -89,25 +94,41 @@ class LogicalPlanBuilder:
         pyschema = self._builder.schema()
         return Schema._from_pyschema(pyschema)
 
-    def pretty_print(self, simple: bool = False, format: str = "ascii") -> str:
+    def pretty_print(self, simple: bool = False, format: str = "ascii", verbose: bool = False) -> str:
         """Pretty prints the current underlying logical plan."""
         from daft.dataframe.display import MermaidOptions
 
         if format == "ascii":
             return self._builder.repr_ascii(simple)
         elif format == "mermaid":
-            return self._builder.repr_mermaid(MermaidOptions(simple))
+            return self._builder.repr_mermaid(MermaidOptions(simple, verbose=verbose))
         else:
             raise ValueError(f"Unknown format: {format}")
 
     def __repr__(self) -> str:
         return self._builder.repr_ascii(simple=False)
 
+    def column_lineage(self) -> dict[str, Any]:
+        """The source columns that each output column of the current logical plan is derived from."""
+        return json.loads(self._builder.column_lineage())
+
     def optimize(self) -> LogicalPlanBuilder:
         """Optimize the underlying logical plan."""
         builder = self._builder.optimize()
         return LogicalPlanBuilder(builder)
 
+    def with_execution_config(self, daft_execution_config: PyDaftExecutionConfig) -> LogicalPlanBuilder:
+        """Use the given execution config instead of the one of the current context when running this plan."""
+        builder = self._builder.with_execution_config(daft_execution_config)
+        return LogicalPlanBuilder(builder)
+
+    def execution_config(self) -> PyDaftExecutionConfig:
+        """The execution config to run this plan with, which is the one of the current context unless overridden."""
+        daft_execution_config = self._builder.execution_config()
+        if daft_execution_config is None:
+            daft_execution_config = get_context().daft_execution_config
+        return daft_execution_config
+
     @classmethod
     @_apply_daft_planning_config_to_initializer
     def from_in_memory_scan(
@@ -117,7 +138,9 @@ class LogicalPlanBuilder:
         num_partitions: int,
         size_bytes: int,
         num_rows: int,
+        cached_plan: LogicalPlanBuilder | None = None,
     ) -> LogicalPlanBuilder:
+        """Scans cached partitions, which keep the sort order of `cached_plan` if they are its results."""
         builder = _LogicalPlanBuilder.in_memory_scan(
             partition.key,
             partition,
@@ -125,6 +148,7 @@ class LogicalPlanBuilder:
             num_partitions,
             size_bytes,
             num_rows,
+            cached_plan=cached_plan._builder if cached_plan is not None else None,
         )
         return cls(builder)
 
@@ -138,6 +162,28 @@ class LogicalPlanBuilder:
         builder = logical_plan_table_scan(scan_operator)
         return cls(builder)
 
+    @classmethod
+    @_apply_daft_planning_config_to_initializer
+    def from_stream_scan(
+        cls,
+        *,
+        name: str,
+        batch_iterator_factory: Callable[[], Iterator[PyMicroPartition | None]],
+        schema: Schema,
+        poll_interval_ms: int,
+        watermark_column: str | None,
+        watermark_delay: int | None,
+    ) -> LogicalPlanBuilder:
+        builder = _LogicalPlanBuilder.stream_scan(
+            name,
+            batch_iterator_factory,
+            schema._schema,
+            poll_interval_ms,
+            watermark_column,
+            watermark_delay,
+        )
+        return cls(builder)
+
     def select(
         self,
         to_select: list[Expression],
@@ -155,8 +201,16 @@ class LogicalPlanBuilder:
         builder = self._builder.exclude(to_exclude)
         return LogicalPlanBuilder(builder)
 
+    def _with_current_planning_config(self) -> _LogicalPlanBuilder:
+        """The builder with the current DaftPlanningConfig, so that filters and sorts use the default collation set after the sources were created."""
+        return self._builder.with_planning_config(get_context().daft_planning_config)
+
     def filter(self, predicate: Expression) -> LogicalPlanBuilder:
-        builder = self._builder.filter(predicate._expr)
+        builder = self._with_current_planning_config().filter(predicate._expr)
+        return LogicalPlanBuilder(builder)
+
+    def assert_unique(self, keys: list[Expression]) -> LogicalPlanBuilder:
+        builder = self._builder.assert_unique([key._expr for key in keys])
         return LogicalPlanBuilder(builder)
 
     def limit(self, num_rows: int, eager: bool) -> LogicalPlanBuilder:
@@ -208,7 +262,7 @@ class LogicalPlanBuilder:
             nulls_first = descending
         elif isinstance(nulls_first, bool):
             nulls_first = [nulls_first] * len(sort_by_pyexprs)
-        builder = self._builder.sort(sort_by_pyexprs, descending, nulls_first)
+        builder = self._with_current_planning_config().sort(sort_by_pyexprs, descending, nulls_first)
         return LogicalPlanBuilder(builder)
 
     def hash_repartition(self, num_partitions: int | None, partition_by: list[Expression]) -> LogicalPlanBuilder:
@@ -216,6 +270,13 @@ class LogicalPlanBuilder:
         builder = self._builder.hash_repartition(partition_by_pyexprs, num_partitions=num_partitions)
         return LogicalPlanBuilder(builder)
 
+    def range_repartition(
+        self, num_partitions: int | None, partition_by: list[Expression], descending: list[bool]
+    ) -> LogicalPlanBuilder:
+        partition_by_pyexprs = [expr._expr for expr in partition_by]
+        builder = self._builder.range_repartition(partition_by_pyexprs, descending, num_partitions=num_partitions)
+        return LogicalPlanBuilder(builder)
+
     def random_shuffle(self, num_partitions: int | None) -> LogicalPlanBuilder:
         builder = self._builder.random_shuffle(num_partitions)
         return LogicalPlanBuilder(builder)
@@ -238,6 +299,49 @@ class LogicalPlanBuilder:
         builder = self._builder.aggregate([udf._expr], group_by_pyexprs)
         return LogicalPlanBuilder(builder)
 
+    def window_agg(
+        self,
+        to_agg: list[Expression],
+        group_by: list[Expression],
+        event_time_column: str,
+        window_size: int,
+        window_slide: int | None,
+        watermark_delay: int | None,
+    ) -> LogicalPlanBuilder:
+        builder = self._builder.window_aggregate(
+            [expr._expr for expr in to_agg],
+            [expr._expr for expr in group_by],
+            event_time_column,
+            window_size,
+            window_slide,
+            watermark_delay,
+        )
+        return LogicalPlanBuilder(builder)
+
+    def window(
+        self,
+        functions: list[WindowFunction],
+        partition_by: list[Expression],
+        order_by: list[Expression],
+        descending: list[bool] | bool = False,
+        nulls_first: list[bool] | bool | None = None,
+    ) -> LogicalPlanBuilder:
+        if not isinstance(descending, list):
+            descending = [descending] * len(order_by)
+        if nulls_first is None:
+            nulls_first = descending
+        elif isinstance(nulls_first, bool):
+            nulls_first = [nulls_first] * len(order_by)
+        builder = self._builder.window(
+            [expr._expr for expr in partition_by],
+            [expr._expr for expr in order_by],
+            descending,
+            nulls_first,
+            [function._function for function in functions],
+            [function.name() for function in functions],
+        )
+        return LogicalPlanBuilder(builder)
+
     def pivot(
         self,
         group_by: list[Expression],
@@ -279,6 +383,10 @@ class LogicalPlanBuilder:
         builder = self._builder.intersect(other._builder, False)
         return LogicalPlanBuilder(builder)
 
+    def compare(self, other: LogicalPlanBuilder, keys: list[str]) -> LogicalPlanBuilder:
+        builder = self._builder.compare(other._builder, keys)
+        return LogicalPlanBuilder(builder)
+
     def add_monotonically_increasing_id(self, column_name: str | None) -> LogicalPlanBuilder:
         builder = self._builder.add_monotonically_increasing_id(column_name)
         return LogicalPlanBuilder(builder)
@@ -290,15 +398,43 @@ class LogicalPlanBuilder:
         io_config: IOConfig,
         partition_cols: list[Expression] | None = None,
         compression: str | None = None,
+        parquet_encryption: ParquetEncryptionConfig | None = None,
+        csv_options: CsvWriteOptions | None = None,
+        job_id: str | None = None,
+        attempt_id: str | None = None,
+        constraints: list[WriteConstraint] | None = None,
+        on_constraint_violation: str = "fail",
+        quarantine_dir: str | pathlib.Path | None = None,
+        quarantine_format: FileFormat | None = None,
     ) -> LogicalPlanBuilder:
         if file_format != FileFormat.Csv and file_format != FileFormat.Parquet:
             raise ValueError(f"Writing is only supported for Parquet and CSV file formats, but got: {file_format}")
         part_cols_pyexprs = [expr._expr for expr in partition_cols] if partition_cols is not None else None
-        builder = self._builder.table_write(str(root_dir), file_format, part_cols_pyexprs, compression, io_config)
+        constraint_pyexprs = (
+            [(constraint.name, constraint.predicate._expr) for constraint in constraints]
+            if constraints is not None
+            else None
+        )
+        builder = self._builder.table_write(
+            str(root_dir),
+            file_format,
+            part_cols_pyexprs,
+            compression,
+            io_config,
+            parquet_encryption,
+            csv_options,
+            job_id,
+            attempt_id,
+            constraint_pyexprs,
+            on_constraint_violation,
+            str(quarantine_dir) if quarantine_dir is not None else None,
+            quarantine_format,
+        )
         return LogicalPlanBuilder(builder)
 
     def write_iceberg(self, table: IcebergTable, io_config: IOConfig) -> LogicalPlanBuilder:
-        from daft.iceberg.iceberg_write import get_missing_columns, partition_field_to_expr
+        from daft.iceberg.iceberg_scan import iceberg_partition_spec_to_fields
+        from daft.iceberg.iceberg_write import get_missing_columns
 
         name = ".".join(table.name())
         location = f"{table.location()}/data"
@@ -310,11 +446,11 @@ class LogicalPlanBuilder:
             if len(missing_columns) == 0
             else self._builder.with_columns([c._expr for c in missing_columns])
         )
-        partition_cols = [partition_field_to_expr(field, schema)._expr for field in partition_spec.fields]
+        partition_fields = iceberg_partition_spec_to_fields(schema, partition_spec)
         props = table.properties
         columns = [col.name for col in schema.columns]
         builder = builder.iceberg_write(
-            name, location, partition_spec.spec_id, partition_cols, schema, props, columns, io_config
+            name, location, partition_spec.spec_id, partition_fields, schema, props, columns, io_config
         )
         return LogicalPlanBuilder(builder)
 
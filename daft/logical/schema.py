@@ -5,6 +5,7 @@ from typing import TYPE_CHECKING, Iterator
 from daft.daft import CsvParseOptions, JsonParseOptions
 from daft.daft import PyField as _PyField
 from daft.daft import PySchema as _PySchema
+from daft.daft import PySchemaDiff as _PySchemaDiff
 from daft.daft import read_csv_schema as _read_csv_schema
 from daft.daft import read_json_schema as _read_json_schema
 from daft.daft import read_parquet_schema as _read_parquet_schema
@@ -146,6 +147,33 @@ class Schema:
     def apply_hints(self, hints: Schema) -> Schema:
         return Schema._from_pyschema(self._schema.apply_hints(hints._schema))
 
+    def merge_hints(self, other: Schema) -> Schema:
+        """Merges two sets of schema hints, raising a ValueError if they give a column different types."""
+        return Schema._from_pyschema(self._schema.merge_hints(other._schema))
+
+    def diff(self, other: Schema) -> SchemaDiff:
+        """Compares this schema to ``other``, returning the changes that turn this schema into ``other``.
+
+        Example:
+            >>> table_schema = daft.from_pydict({"a": [1], "b": ["x"]}).schema()
+            >>> data_schema = daft.from_pydict({"a": [1.5]}).schema()
+            >>> diff = table_schema.diff(data_schema)
+            >>> diff.can_append()
+            False
+            >>> diff.append_incompatibilities()
+            [('a', "has type Float64, which can't be written to the table type Int64")]
+
+        Args:
+            other (Schema): Schema to compare to, such as the schema of data being written to a table with this schema.
+
+        Returns:
+            SchemaDiff: The fields that were added, removed, moved or changed type.
+        """
+        if not isinstance(other, Schema):
+            raise ValueError(f"Expected Schema, got other: {type(other)}")
+
+        return SchemaDiff._from_pyschemadiff(self._schema.diff(other._schema))
+
     # Takes the unions between two schemas. Throws an error if the schemas contain overlapping keys.
     def union(self, other: Schema) -> Schema:
         if not isinstance(other, Schema):
@@ -206,3 +234,60 @@ class Schema:
                 multithreaded_io=multithreaded_io,
             )
         )
+
+
+class SchemaDiff:
+    """The changes between two schemas, and whether data of one can be appended to or unioned with data of the other.
+
+    Types are compatible when one can represent every value of the other exactly, such as ``Int32`` and ``Int64``.
+    """
+
+    _diff: _PySchemaDiff
+
+    def __init__(self) -> None:
+        raise NotImplementedError("We do not support creating a SchemaDiff via __init__ ")
+
+    @staticmethod
+    def _from_pyschemadiff(diff: _PySchemaDiff) -> SchemaDiff:
+        d = SchemaDiff.__new__(SchemaDiff)
+        d._diff = diff
+        return d
+
+    def changes(self) -> list[tuple[str, str, str]]:
+        """The changed fields, as tuples of their name, the kind of change (one of "added", "removed", "type_changed" or "moved") and a description of it."""
+        return self._diff.changes()
+
+    def is_empty(self) -> bool:
+        return self._diff.is_empty()
+
+    def can_append(self) -> bool:
+        """Whether data with the new schema can be appended to a table with the old schema, filling missing fields with nulls."""
+        return self._diff.can_append()
+
+    def can_union(self) -> bool:
+        """Whether data with the two schemas can be unioned, which requires the same fields in the same order."""
+        return self._diff.can_union()
+
+    def append_incompatibilities(self) -> list[tuple[str, str]]:
+        """The fields that prevent appending data with the new schema to a table with the old schema, with the reason for each."""
+        return self._diff.append_incompatibilities()
+
+    def union_incompatibilities(self) -> list[tuple[str, str]]:
+        """The fields that prevent unioning data with the two schemas, with the reason for each."""
+        return self._diff.union_incompatibilities()
+
+    def breaking_changes(self) -> list[tuple[str, str]]:
+        """The changes that would break readers of a table evolved from the old to the new schema, with the reason for each.
+
+        Fields may be added, reordered, or widened to a type that represents the old type exactly without breaking readers.
+        """
+        return self._diff.breaking_changes()
+
+    def __bool__(self) -> bool:
+        return not self.is_empty()
+
+    def __repr__(self) -> str:
+        return repr(self._diff)
+
+    def __reduce__(self) -> tuple:
+        return SchemaDiff._from_pyschemadiff, (self._diff,)
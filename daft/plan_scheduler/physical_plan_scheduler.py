@@ -35,14 +35,14 @@ class PhysicalPlanScheduler:
     def num_partitions(self) -> int:
         return self._scheduler.num_partitions()
 
-    def pretty_print(self, simple: bool = False, format: str = "ascii") -> str:
+    def pretty_print(self, simple: bool = False, format: str = "ascii", verbose: bool = False) -> str:
         """Pretty prints the current underlying physical plan."""
         from daft.dataframe.display import MermaidOptions
 
         if format == "ascii":
             return self._scheduler.repr_ascii(simple)
         elif format == "mermaid":
-            return self._scheduler.repr_mermaid(MermaidOptions(simple))
+            return self._scheduler.repr_mermaid(MermaidOptions(simple, verbose=verbose))
         else:
             raise ValueError(f"Unknown format: {format}")
 
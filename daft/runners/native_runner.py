@@ -3,10 +3,10 @@ from __future__ import annotations
 import logging
 from typing import TYPE_CHECKING, Iterator
 
-from daft.context import get_context
-from daft.daft import FileFormatConfig, FileInfos, IOConfig
+from daft.daft import FileFormatConfig, FileInfos, IOConfig, PyDaftExecutionConfig
 from daft.execution.native_executor import NativeExecutor
 from daft.filesystem import glob_path_with_stats
+from daft.internal.gpu import cuda_visible_devices
 from daft.runners import runner_io
 from daft.runners.partitioning import (
     LocalMaterializedResult,
@@ -14,6 +14,7 @@ from daft.runners.partitioning import (
     PartitionCacheEntry,
     PartitionSetCache,
 )
+from daft.runners.query_event_log import log_query_events
 from daft.runners.runner import LOCAL_PARTITION_SET_CACHE, Runner
 from daft.table import MicroPartition
 
@@ -30,17 +31,18 @@ class NativeRunnerIO(runner_io.RunnerIO):
         file_format_config: FileFormatConfig | None = None,
         io_config: IOConfig | None = None,
     ) -> FileInfos:
-        file_infos = FileInfos()
         file_format = file_format_config.file_format() if file_format_config is not None else None
+        path_file_infos = []
         for source_path in source_paths:
-            path_file_infos = glob_path_with_stats(source_path, file_format, io_config)
+            file_infos = glob_path_with_stats(source_path, file_format, io_config)
 
-            if len(path_file_infos) == 0:
+            if len(file_infos) == 0:
                 raise FileNotFoundError(f"No files found at {source_path}")
 
-            file_infos.extend(path_file_infos)
+            path_file_infos.append(file_infos)
 
-        return file_infos
+        # Files matched by several of the paths are only listed once.
+        return FileInfos.merge(path_file_infos)
 
 
 class NativeRunner(Runner[MicroPartition]):
@@ -65,13 +67,14 @@ class NativeRunner(Runner[MicroPartition]):
         pset_entry = self.put_partition_set_into_cache(result_pset)
         return pset_entry
 
+    @log_query_events
     def run_iter(
         self,
         builder: LogicalPlanBuilder,
         results_buffer_size: int | None = None,
     ) -> Iterator[LocalMaterializedResult]:
         # NOTE: Freeze and use this same execution config for the entire execution
-        daft_execution_config = get_context().daft_execution_config
+        daft_execution_config = self._execution_config(builder)
 
         # Optimize the logical plan.
         builder = builder.optimize()
@@ -88,3 +91,22 @@ class NativeRunner(Runner[MicroPartition]):
     ) -> Iterator[MicroPartition]:
         for result in self.run_iter(builder, results_buffer_size=results_buffer_size):
             yield result.partition()
+
+    def run_preview_tables(self, builder: LogicalPlanBuilder, num_rows: int) -> Iterator[MicroPartition]:
+        daft_execution_config = self._execution_config(builder)
+
+        builder = builder.optimize()
+        executor = NativeExecutor.from_logical_plan_builder(builder)
+        yield from executor.preview(
+            {k: v.values() for k, v in self._part_set_cache.get_all_partition_sets().items()},
+            daft_execution_config,
+            num_rows,
+        )
+
+    def _execution_config(self, builder: LogicalPlanBuilder) -> PyDaftExecutionConfig:
+        daft_execution_config = builder.execution_config()
+        if daft_execution_config.local_num_gpus is None:
+            daft_execution_config = daft_execution_config.with_config_values(
+                local_num_gpus=len(cuda_visible_devices())
+            )
+        return daft_execution_config
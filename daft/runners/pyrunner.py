@@ -10,7 +10,6 @@ from concurrent import futures
 from dataclasses import dataclass
 from typing import TYPE_CHECKING, Callable, Iterator
 
-from daft.context import get_context
 from daft.daft import FileFormatConfig, FileInfos, IOConfig, ResourceRequest, SystemInfo
 from daft.execution.native_executor import NativeExecutor
 from daft.execution.physical_plan import ActorPoolManager
@@ -29,6 +28,7 @@ from daft.runners.partitioning import (
 )
 from daft.runners.profiler import profiler
 from daft.runners.progress_bar import ProgressBar
+from daft.runners.query_event_log import log_query_events
 from daft.runners.runner import LOCAL_PARTITION_SET_CACHE, Runner
 from daft.table import MicroPartition
 
@@ -274,17 +274,18 @@ class PyRunnerIO(runner_io.RunnerIO):
         file_format_config: FileFormatConfig | None = None,
         io_config: IOConfig | None = None,
     ) -> FileInfos:
-        file_infos = FileInfos()
         file_format = file_format_config.file_format() if file_format_config is not None else None
+        path_file_infos = []
         for source_path in source_paths:
-            path_file_infos = glob_path_with_stats(source_path, file_format, io_config)
+            file_infos = glob_path_with_stats(source_path, file_format, io_config)
 
-            if len(path_file_infos) == 0:
+            if len(file_infos) == 0:
                 raise FileNotFoundError(f"No files found at {source_path}")
 
-            file_infos.extend(path_file_infos)
+            path_file_infos.append(file_infos)
 
-        return file_infos
+        # Files matched by several of the paths are only listed once.
+        return FileInfos.merge(path_file_infos)
 
 
 class PyRunner(Runner[MicroPartition], ActorPoolManager):
@@ -334,6 +335,7 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
         pset_entry = self.put_partition_set_into_cache(result_pset)
         return pset_entry
 
+    @log_query_events
     def run_iter(
         self,
         builder: LogicalPlanBuilder,
@@ -345,7 +347,7 @@ class PyRunner(Runner[MicroPartition], ActorPoolManager):
             "Please report any issues at github.com/Eventual-Inc/Daft/issues",
         )
         # NOTE: Freeze and use this same execution config for the entire execution
-        daft_execution_config = get_context().daft_execution_config
+        daft_execution_config = builder.execution_config()
         execution_id = str(uuid.uuid4())
 
         # Optimize the logical plan.
@@ -0,0 +1,151 @@
+"""Structured log of the queries executed by Daft, for auditing.
+
+Every query run by a runner is recorded as one JSON object containing the query plan, the user and session that ran
+it, its start and end time, the number of rows and bytes in its output, and the cause of failure if it failed.
+
+The log is disabled by default. It can be written to a JSON lines file by setting the `DAFT_QUERY_EVENT_LOG`
+environment variable to its path, or configured with `set_query_event_log`, which also accepts a callable to forward
+events to any other sink.
+"""
+
+from __future__ import annotations
+
+import datetime
+import functools
+import getpass
+import json
+import logging
+import os
+import threading
+import time
+import uuid
+from typing import TYPE_CHECKING, Any, Callable, Iterator, TypeVar, Union
+
+if TYPE_CHECKING:
+    from daft.logical.builder import LogicalPlanBuilder
+    from daft.runners.partitioning import MaterializedResult
+    from daft.runners.runner import Runner
+
+logger = logging.getLogger(__name__)
+
+QueryEventSink = Callable[[dict[str, Any]], None]
+
+# Identifies this process' session in the events, so that queries run by the same session can be grouped
+_SESSION_ID = str(uuid.uuid4())
+
+_SINK_LOCK = threading.Lock()
+_SINK: QueryEventSink | None = None
+_SINK_INITIALIZED = False
+
+R = TypeVar("R", bound="MaterializedResult")
+
+
+class JsonLinesSink:
+    """Appends each event as a line of JSON to a local file."""
+
+    def __init__(self, path: str):
+        self._path = path
+        self._lock = threading.Lock()
+
+    def __call__(self, event: dict[str, Any]) -> None:
+        line = json.dumps(event, default=str)
+        with self._lock, open(self._path, "a") as f:
+            f.write(line + "\n")
+
+
+def set_query_event_log(sink: Union[str, QueryEventSink, None]) -> None:
+    """Configures where query events are logged.
+
+    Args:
+        sink: Path of a JSON lines file to append the events to, a callable that receives each event as a dictionary,
+            or None to disable the log.
+    """
+    global _SINK, _SINK_INITIALIZED
+
+    with _SINK_LOCK:
+        _SINK = JsonLinesSink(sink) if isinstance(sink, str) else sink
+        _SINK_INITIALIZED = True
+
+
+def _get_sink() -> QueryEventSink | None:
+    global _SINK, _SINK_INITIALIZED
+
+    with _SINK_LOCK:
+        if not _SINK_INITIALIZED:
+            path = os.getenv("DAFT_QUERY_EVENT_LOG")
+            _SINK = JsonLinesSink(path) if path else None
+            _SINK_INITIALIZED = True
+        return _SINK
+
+
+def _get_user() -> str | None:
+    try:
+        return getpass.getuser()
+    except Exception:
+        return None
+
+
+def _now() -> str:
+    return datetime.datetime.now(datetime.timezone.utc).isoformat()
+
+
+def log_query_events(
+    run_iter: Callable[..., Iterator[R]],
+) -> Callable[..., Iterator[R]]:
+    """Decorates a runner's `run_iter` to log an event once the query it runs ends.
+
+    A query that is not consumed to completion, such as when only a preview of the results is needed, is logged with
+    the status "cancelled".
+    """
+
+    @functools.wraps(run_iter)
+    def wrapper(self: Runner, builder: LogicalPlanBuilder, *args, **kwargs) -> Iterator[R]:
+        sink = _get_sink()
+        if sink is None:
+            yield from run_iter(self, builder, *args, **kwargs)
+            return
+
+        event: dict[str, Any] = {
+            "query_id": str(uuid.uuid4()),
+            "session_id": _SESSION_ID,
+            "user": _get_user(),
+            "runner": self.name,
+            "plan": builder.pretty_print(simple=False),
+            "start_time": _now(),
+        }
+        start = time.monotonic()
+        output_rows = 0
+        output_bytes = 0
+        status = "cancelled"
+        error: BaseException | None = None
+        try:
+            for result in run_iter(self, builder, *args, **kwargs):
+                metadata = result.metadata()
+                output_rows += metadata.num_rows
+                output_bytes += metadata.size_bytes or 0
+                yield result
+            status = "succeeded"
+        except GeneratorExit:
+            raise
+        except BaseException as e:
+            status = "failed"
+            error = e
+            raise
+        finally:
+            event.update(
+                {
+                    "end_time": _now(),
+                    "duration_seconds": time.monotonic() - start,
+                    "status": status,
+                    "output_rows": output_rows,
+                    "output_bytes": output_bytes,
+                    "error_type": type(error).__name__ if error is not None else None,
+                    "error": str(error) if error is not None else None,
+                }
+            )
+            try:
+                sink(event)
+            except Exception:
+                logger.exception("Failed to log query event")
+
+    return wrapper
@@ -3,6 +3,7 @@ from __future__ import annotations
 import contextlib
 import dataclasses
 import logging
+import random
 import threading
 import time
 import uuid
@@ -39,8 +40,10 @@ from daft.daft import (
     FileFormatConfig,
     FileInfos,
     IOConfig,
+    LocalityHints,
     PyDaftExecutionConfig,
     ResourceRequest,
+    ScanTask,
 )
 from daft.datatype import DataType
 from daft.execution.execution_step import (
@@ -67,6 +70,7 @@ from daft.runners.partitioning import (
     PartitionSetCache,
 )
 from daft.runners.profiler import profiler
+from daft.runners.query_event_log import log_query_events
 from daft.runners.runner import Runner
 from daft.table import MicroPartition
 
@@ -127,15 +131,16 @@ def _glob_path_into_file_infos(
     file_format_config: FileFormatConfig | None,
     io_config: IOConfig | None,
 ) -> FileInfos:
-    file_infos = FileInfos()
     file_format = file_format_config.file_format() if file_format_config is not None else None
+    path_file_infos = []
     for path in paths:
-        path_file_infos = glob_path_with_stats(path, file_format=file_format, io_config=io_config)
-        if len(path_file_infos) == 0:
+        file_infos = glob_path_with_stats(path, file_format=file_format, io_config=io_config)
+        if len(file_infos) == 0:
             raise FileNotFoundError(f"No files found at {path}")
-        file_infos.extend(path_file_infos)
+        path_file_infos.append(file_infos)
 
-    return file_infos
+    # Files matched by several of the paths are only listed once.
+    return FileInfos.merge(path_file_infos)
 
 
 @ray.remote
@@ -460,6 +465,50 @@ def _get_ray_task_options(resource_request: ResourceRequest) -> dict[str, Any]:
     return options
 
 
+# Labels of Ray nodes with the cloud region and availability zone that they run in.
+RAY_REGION_LABEL = "ray.io/availability-region"
+RAY_ZONE_LABEL = "ray.io/availability-zone"
+
+_alive_nodes_cache: tuple[float, list[dict[str, Any]]] | None = None
+
+
+def _alive_ray_nodes(ttl_seconds: int = 10) -> list[dict[str, Any]]:
+    """Gets the alive nodes of the Ray cluster, calling ray.nodes() at most once per `ttl_seconds`."""
+    global _alive_nodes_cache
+    currtime = time.time()
+    if _alive_nodes_cache is None or currtime - _alive_nodes_cache[0] >= ttl_seconds:
+        _alive_nodes_cache = (currtime, [node for node in ray.nodes() if node.get("Alive")])
+    return _alive_nodes_cache[1]
+
+
+def _preferred_scan_node(locality_hints: LocalityHints, nodes: list[dict[str, Any]]) -> str | None:
+    """Picks the node that a scan should run on given hints about where its data lives.
+
+    Nodes that have the data cached are preferred, then nodes in the zone of the data, and then nodes in its region.
+    Scans are spread out at random over the nodes in a zone or region.
+    """
+    alive_node_ids = {node["NodeID"] for node in nodes}
+    for node_id in locality_hints.cached_node_ids:
+        if node_id in alive_node_ids:
+            return node_id
+
+    for label, value in ((RAY_ZONE_LABEL, locality_hints.zone), (RAY_REGION_LABEL, locality_hints.region)):
+        if value is None:
+            continue
+        candidates = [node["NodeID"] for node in nodes if (node.get("Labels") or {}).get(label) == value]
+        if candidates:
+            return random.choice(candidates)
+    return None
+
+
+def _scan_scheduling_strategy(scan_task: ScanTask) -> Any:
+    locality_hints = scan_task.locality_hints()
+    node_id = _preferred_scan_node(locality_hints, _alive_ray_nodes()) if locality_hints is not None else None
+    if node_id is None:
+        return "SPREAD"
+    return ray.util.scheduling_strategies.NodeAffinitySchedulingStrategy(node_id, soft=True)
+
+
 def build_partitions(
     instruction_stack: list[Instruction], partial_metadatas: list[PartitionMetadata], *inputs: MicroPartition
 ) -> list[list[PartitionMetadata] | MicroPartition]:
@@ -1035,7 +1084,7 @@ def _build_partitions(
             else single_partition_pipeline
         )
         if task.instructions and isinstance(task.instructions[0], ScanWithTask):
-            ray_options["scheduling_strategy"] = "SPREAD"
+            ray_options["scheduling_strategy"] = _scan_scheduling_strategy(task.instructions[0].scan_task)
         build_remote = build_remote.options(**ray_options).with_tracing(runner_tracer, task)
         [metadatas_ref, *partitions] = build_remote.remote(
             PartitionTaskContext(job_id=job_id, task_id=task.id(), stage_id=task.stage_id),
@@ -1265,11 +1314,12 @@ class RayRunner(Runner[ray.ObjectRef]):
             else:
                 self.scheduler.stop_plan(result_uuid)
 
+    @log_query_events
     def run_iter(
         self, builder: LogicalPlanBuilder, results_buffer_size: int | None = None
     ) -> Iterator[RayMaterializedResult]:
-        # Grab and freeze the current DaftExecutionConfig
-        daft_execution_config = get_context().daft_execution_config
+        # Grab and freeze the DaftExecutionConfig of the plan
+        daft_execution_config = builder.execution_config()
 
         # Optimize the logical plan.
         builder = builder.optimize()
@@ -65,3 +65,15 @@ class Runner(Generic[PartitionT]):
                 that can be buffered before execution should pause and wait.
         """
         ...
+
+    def run_preview_tables(self, builder: LogicalPlanBuilder, num_rows: int) -> Iterator[MicroPartition]:
+        """Executes enough of the LogicalPlan to preview its first ``num_rows`` rows, e.g. for `df.show()`.
+
+        Runners may take the rows from whichever partitions finish first, rather than from the first partitions,
+        and may stop executing the plan once ``num_rows`` rows have been yielded.
+
+        Args:
+            builder: the builder for the LogicalPlan that is to be previewed
+            num_rows: the number of rows to preview
+        """
+        return self.run_iter_tables(builder, results_buffer_size=1)
@@ -1014,3 +1014,6 @@ class SeriesImageNamespace(SeriesNamespace):
         if not isinstance(mode, ImageMode):
             raise ValueError(f"mode must be a string or ImageMode variant, but got: {mode}")
         return Series._from_pyseries(image.to_mode(self._series, mode))
+
+    def to_tensor(self) -> Series:
+        return Series._from_pyseries(image.to_tensor(self._series))
@@ -1,7 +1,7 @@
 # isort: dont-add-import: from __future__ import annotations
 
 import inspect
-from typing import Optional
+from typing import List, Optional
 
 from daft.api_annotations import PublicAPI
 from daft.context import get_context
@@ -38,6 +38,58 @@ class SQLCatalog:
         self._catalog.copy_from(other._catalog)
 
 
+# Temporary views created with `CREATE TEMP VIEW`, which live for the duration of the Python session
+_SESSION_CATALOG = SQLCatalog({})
+
+
+def _write_table_format(
+    df: DataFrame,
+    name: str,
+    format: str,
+    catalog_name: Optional[str],
+    location: Optional[str],
+    partition_cols: List[str],
+    overwrite: bool,
+    changes: _PyCatalog,
+) -> DataFrame:
+    """Writes the rows of a ``CREATE TABLE ... AS SELECT`` to an Iceberg or Delta Lake table and registers the table
+    with `changes` once the write committed, returning the number of written rows."""
+    import daft
+
+    if format == "deltalake":
+        assert location is not None
+        operations = df.write_deltalake(
+            location, partition_cols=partition_cols or None, mode="overwrite" if overwrite else "error"
+        )
+        table = daft.read_deltalake(location)
+    else:
+        from pyiceberg.exceptions import NoSuchTableError
+
+        from daft.catalog import _get_pyiceberg_catalog
+
+        iceberg_catalog = _get_pyiceberg_catalog(catalog_name)
+        try:
+            iceberg_table = iceberg_catalog.load_table(name)
+        except NoSuchTableError:
+            iceberg_table = None
+        if iceberg_table is not None and not overwrite:
+            raise DaftCoreException(f"Table {name} already exists")
+        if iceberg_table is None:
+            create_kwargs = {} if location is None else {"location": location}
+            iceberg_table = iceberg_catalog.create_table(name, df.schema().to_pyarrow_schema(), **create_kwargs)
+            if partition_cols:
+                with iceberg_table.update_spec() as update:
+                    for partition_col in partition_cols:
+                        update.add_identity(partition_col)
+        operations = df.write_iceberg(iceberg_table, mode="overwrite")
+        table = daft.read_iceberg(iceberg_table)
+
+    changes.register_table(name, table._get_current_builder()._builder)
+    written = operations.to_pydict()
+    num_rows = sum(rows for op, rows in zip(written["operation"], written["rows"]) if op == "ADD")
+    return daft.from_pydict({"num_affected_rows": [num_rows]})
+
+
 @PublicAPI
 def sql_expr(sql: str) -> Expression:
     """Parses a SQL string into a Daft Expression.
@@ -160,12 +212,22 @@ def sql(sql: str, catalog: Optional[SQLCatalog] = None, register_globals: bool =
             Defaults to None, in which case a catalog will be built from variables
             in the callers scope.
         register_globals (bool, optional): Whether to incorporate global
-            variables into the supplied catalog, in which case a copy of the
-            catalog will be made and the original not modified. Defaults to True.
+            variables and temporary views created with ``CREATE TEMP VIEW`` into the
+            supplied catalog, in which case the query is planned against a copy of the
+            catalog. Defaults to True.
+
+    Tables created or changed by statements such as ``CREATE TABLE`` and ``INSERT INTO`` are
+    registered with the supplied catalog, or with the session if no catalog was supplied, so
+    that later queries can read them. Temporary views are always registered with the session.
+    Statements that write tables are executed when they're run, and their tables are only
+    registered once the write committed. ``CREATE TABLE ... WITH (format = 'delta', location = ...)``
+    and ``CREATE TABLE ... WITH (format = 'iceberg')`` write Delta Lake and Iceberg tables, the
+    latter in the registered PyIceberg catalog named by the ``catalog`` option, or the default one.
 
     Returns:
         DataFrame: Dataframe containing the results of the query
     """
+    user_catalog = catalog
     if register_globals:
         try:
             # Caller is back from func, analytics, annotation
@@ -176,6 +238,7 @@ def sql(sql: str, catalog: Optional[SQLCatalog] = None, register_globals: bool =
             # errors above should be AttributeError
             raise DaftCoreException("Cannot get caller environment, please provide a catalog") from exc
         catalog_ = SQLCatalog({k: v for k, v in caller_vars.items() if isinstance(v, DataFrame)})
+        catalog_._copy_from(_SESSION_CATALOG)
         if catalog is not None:
             catalog_._copy_from(catalog)
         catalog = catalog_
@@ -184,6 +247,17 @@ def sql(sql: str, catalog: Optional[SQLCatalog] = None, register_globals: bool =
 
     planning_config = get_context().daft_planning_config
 
-    _py_catalog = catalog._catalog
-    _py_logical = _sql(sql, _py_catalog, planning_config)
-    return DataFrame(LogicalPlanBuilder(_py_logical))
+    _py_logical, _py_changes = _sql(sql, catalog._catalog, planning_config)
+    df = DataFrame(LogicalPlanBuilder(_py_logical))
+    table_format_write = _py_changes.table_format_write()
+    if table_format_write is not None:
+        df = _write_table_format(df, *table_format_write, changes=_py_changes)
+    elif _py_changes.has_pending_writes():
+        # The tables written by the statement are only registered once their files were written.
+        df = df.collect()
+    if user_catalog is not None:
+        user_catalog._catalog.copy_from(_py_changes)
+    else:
+        _SESSION_CATALOG._catalog.copy_from(_py_changes)
+    _SESSION_CATALOG._catalog.copy_views_from(_py_changes)
+    return df
@@ -15,23 +15,31 @@ logger = logging.getLogger(__name__)
 
 
 class SQLConnection:
-    def __init__(self, conn: str | Callable[[], Connection], driver: str, dialect: str, url: str) -> None:
+    def __init__(
+        self,
+        conn: str | Callable[[], Connection],
+        driver: str,
+        dialect: str,
+        url: str,
+        protocol: str | None = None,
+    ) -> None:
         self.conn = conn
         self.dialect = dialect
         self.driver = driver
         self.url = url
+        self.protocol = protocol
 
     def __repr__(self) -> str:
         return f"SQLConnection(conn={self.conn})"
 
     @classmethod
-    def from_url(cls, url: str) -> SQLConnection:
+    def from_url(cls, url: str, protocol: str | None = None) -> SQLConnection:
         scheme = urlparse(url).scheme.strip().lower()
         if "+" in scheme:
             dialect, driver = scheme.split("+")
         else:
             dialect, driver = scheme, ""
-        return cls(url, driver, dialect, url)
+        return cls(url, driver, dialect, url, protocol)
 
     @classmethod
     def from_connection_factory(cls, conn_factory: Callable[[], Connection]) -> SQLConnection:
@@ -130,8 +138,10 @@ class SQLConnection:
 
         assert isinstance(self.conn, str)
         logger.info("Using connectorx to execute sql: %s", sql)
+        # Without a protocol, ConnectorX picks the default of the database, e.g. binary COPY for Postgres
+        kwargs = {"protocol": self.protocol} if self.protocol is not None else {}
         try:
-            table = cx.read_sql(conn=self.conn, query=sql, return_type="arrow")
+            table = cx.read_sql(conn=self.conn, query=sql, return_type="arrow", **kwargs)
             return table
         except Exception as e:
             raise RuntimeError(f"Failed to execute sql: {sql} with url: {self.conn}, error: {e}") from e
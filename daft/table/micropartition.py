@@ -175,6 +175,11 @@ class MicroPartition:
         pyexprs = [e._expr for e in exprs]
         return MicroPartition._from_pymicropartition(self._micropartition.filter(pyexprs))
 
+    def assert_unique(self, keys: ExpressionsProjection) -> None:
+        assert all(isinstance(e, Expression) for e in keys)
+        pyexprs = [e._expr for e in keys]
+        self._micropartition.assert_unique(pyexprs)
+
     def sort(
         self,
         sort_keys: ExpressionsProjection,
@@ -339,15 +344,22 @@ class MicroPartition:
         ]
 
     def partition_by_range(
-        self, partition_keys: ExpressionsProjection, boundaries: Table, descending: list[bool]
+        self,
+        partition_keys: ExpressionsProjection,
+        boundaries: Table,
+        descending: list[bool],
+        nulls_first: list[bool] | None = None,
     ) -> list[MicroPartition]:
         if not isinstance(boundaries, Table):
             raise TypeError(f"Expected a Table for `boundaries` in partition_by_range but got {type(boundaries)}")
 
+        if nulls_first is None:
+            nulls_first = descending
+
         exprs = [e._expr for e in partition_keys]
         return [
             MicroPartition._from_pymicropartition(t)
-            for t in self._micropartition.partition_by_range(exprs, boundaries._table, descending)
+            for t in self._micropartition.partition_by_range(exprs, boundaries._table, descending, nulls_first)
         ]
 
     def partition_by_random(self, num_partitions: int, seed: int) -> list[MicroPartition]:
@@ -11,7 +11,9 @@ from daft.context import get_context
 from daft.daft import (
     CsvConvertOptions,
     CsvParseOptions,
+    CsvQuoteStyle,
     CsvReadOptions,
+    CsvWriteOptions,
     FileFormat,
     IOConfig,
     JsonConvertOptions,
@@ -25,6 +27,7 @@ from daft.filesystem import (
     _resolve_paths_and_filesystem,
     canonicalize_protocol,
     get_protocol_from_path,
+    staged_file_prefix,
 )
 from daft.logical.schema import Schema
 from daft.runners.partitioning import (
@@ -47,6 +50,7 @@ if TYPE_CHECKING:
     from pyiceberg.table import TableProperties as IcebergTableProperties
 
     from daft.expressions.expressions import Expression
+    from daft.io.parquet_encryption import ParquetEncryptionConfig
     from daft.sql.sql_connection import SQLConnection
 
 
@@ -286,6 +290,10 @@ def write_tabular(
     partition_cols: ExpressionsProjection | None = None,
     compression: str | None = None,
     io_config: IOConfig | None = None,
+    parquet_encryption: ParquetEncryptionConfig | None = None,
+    csv_options: CsvWriteOptions | None = None,
+    job_id: str | None = None,
+    attempt_id: str | None = None,
 ) -> MicroPartition:
     [resolved_path], fs = _resolve_paths_and_filesystem(path, io_config=io_config)
     if isinstance(path, pathlib.Path):
@@ -306,11 +314,17 @@ def write_tabular(
         format = pads.ParquetFileFormat()
         inflation_factor = execution_config.parquet_inflation_factor
         target_file_size = execution_config.parquet_target_filesize
-        opts = format.make_write_options(compression=compression, use_compliant_nested_type=False)
+        encryption_opts = (
+            {"encryption_config": parquet_encryption.dataset_encryption_config()}
+            if parquet_encryption is not None
+            else {}
+        )
+        opts = format.make_write_options(compression=compression, use_compliant_nested_type=False, **encryption_opts)
     elif file_format == FileFormat.Csv:
         format = pads.CsvFileFormat()
-        opts = None
+        opts = format.make_write_options(**pyarrow_csv_write_options(csv_options)) if csv_options is not None else None
         assert compression is None
+        assert parquet_encryption is None
         inflation_factor = execution_config.csv_inflation_factor
         target_file_size = execution_config.csv_target_filesize
     else:
@@ -342,6 +356,8 @@ def write_tabular(
             rows_per_row_group=rows_per_row_group,
             create_dir=is_local_fs,
             file_visitor=visitors.visitor(i),
+            # Committed by `commit_write` once every partition is written
+            basename_prefix=staged_file_prefix(job_id, attempt_id) if job_id is not None else "",
         )
 
     return visitors.to_metadata()
@@ -429,7 +445,6 @@ def write_deltalake(
 ):
     from daft.delta_lake.delta_lake_write import (
         DeltaLakeWriteVisitors,
-        make_deltalake_fs,
         partitioned_table_to_deltalake_iter,
     )
 
@@ -438,7 +453,7 @@ def write_deltalake(
 
     is_local_fs = canonicalized_protocol == "file"
 
-    fs = make_deltalake_fs(base_path, io_config=io_config)
+    [resolved_path], fs = _resolve_paths_and_filesystem(base_path, io_config=io_config)
 
     execution_config = get_context().daft_execution_config
 
@@ -451,7 +466,7 @@ def write_deltalake(
 
     partition_keys = ExpressionsProjection([col(c) for c in partition_cols]) if partition_cols is not None else None
     partitioned = PartitionedTable(table, partition_keys)
-    visitors = DeltaLakeWriteVisitors(fs)
+    visitors = DeltaLakeWriteVisitors(fs, resolved_path)
 
     for part_table, part_path, part_values in partitioned_table_to_deltalake_iter(partitioned, large_dtypes):
         size_bytes = part_table.nbytes
@@ -467,7 +482,7 @@ def write_deltalake(
         _write_tabular_arrow_table(
             arrow_table=part_table,
             schema=None,
-            full_path=part_path,
+            full_path=f"{resolved_path}{part_path}".rstrip("/"),
             format=format,
             opts=opts,
             fs=fs,
@@ -551,6 +566,7 @@ def _write_tabular_arrow_table(
     create_dir: bool,
     file_visitor: Callable | None,
     version: int | None = None,
+    basename_prefix: str = "",
 ):
     kwargs = dict()
 
@@ -561,7 +577,7 @@ def _write_tabular_arrow_table(
     if not create_dir:
         kwargs["create_dir"] = False
 
-    basename_template = _generate_basename_template(format.default_extname, version)
+    basename_template = basename_prefix + _generate_basename_template(format.default_extname, version)
 
     def write_dataset():
         pads.write_dataset(
@@ -590,12 +606,29 @@ def _write_tabular_arrow_table(
     )
 
 
+def pyarrow_csv_write_options(csv_options: CsvWriteOptions) -> dict[str, Any]:
+    """Arguments of PyArrow's CSV `WriteOptions` that write CSV files with the given options."""
+    if csv_options.quote != '"' or csv_options.null_value != "":
+        raise ValueError(
+            "Writing CSV files with a custom quote character or null value is only supported by the native CSV writer "
+            "of the Native Runner"
+        )
+    opts: dict[str, Any] = {"include_header": csv_options.header, "delimiter": csv_options.delimiter}
+    if csv_options.quote_style == CsvQuoteStyle.AllValid:
+        opts["quoting_style"] = "all_valid"
+    elif csv_options.quote_style == CsvQuoteStyle.Never:
+        opts["quoting_style"] = "none"
+    return opts
+
+
 def write_empty_tabular(
     path: str | pathlib.Path,
     file_format: FileFormat,
     schema: Schema,
     compression: str | None = None,
     io_config: IOConfig | None = None,
+    parquet_encryption: ParquetEncryptionConfig | None = None,
+    csv_options: CsvWriteOptions | None = None,
 ) -> str:
     table = pa.Table.from_pylist([], schema=schema.to_pyarrow_schema())
 
@@ -609,16 +642,27 @@ def write_empty_tabular(
 
     def write_table():
         if file_format == FileFormat.Parquet:
+            encryption_opts = (
+                {"encryption_properties": parquet_encryption.file_encryption_properties()}
+                if parquet_encryption is not None
+                else {}
+            )
             pq.write_table(
                 table,
                 file_path,
                 compression=compression,
                 use_compliant_nested_type=False,
                 filesystem=fs,
+                **encryption_opts,
             )
         elif file_format == FileFormat.Csv:
             output_file = fs.open_output_stream(file_path)
-            pacsv.write_csv(table, output_file)
+            write_options = (
+                pacsv.WriteOptions(include_header=csv_options.header, delimiter=csv_options.delimiter)
+                if csv_options is not None
+                else None
+            )
+            pacsv.write_csv(table, output_file, write_options=write_options)
         else:
             raise ValueError(f"Unsupported file format {file_format}")
 
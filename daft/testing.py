@@ -0,0 +1,83 @@
+"""Utilities for regression-testing the DataFrames that data pipelines produce."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from daft.datatype import DataType
+from daft.expressions import col
+
+if TYPE_CHECKING:
+    from daft.dataframe import DataFrame
+
+DIFF_KINDS = ("added", "removed", "changed")
+
+
+def diff_counts(diff: DataFrame) -> dict[str, int]:
+    """Counts the differences in a comparison that's returned by :meth:`DataFrame.compare`.
+
+    Example:
+        >>> import daft
+        >>> from daft.testing import diff_counts
+        >>> df1 = daft.from_pydict({"id": [1, 2, 3], "v": ["a", "b", "c"]})
+        >>> df2 = daft.from_pydict({"id": [2, 3, 4], "v": ["b", "x", "d"]})
+        >>> diff_counts(df1.compare(df2, on="id"))
+        {'added': 1, 'removed': 1, 'changed': 1, 'changed.v': 1}
+
+    Args:
+        diff (DataFrame): Row-level differences between two DataFrames
+
+    Returns:
+        dict[str, int]: The numbers of added, removed and changed rows, and of the changes of each column ``c`` as ``changed.c``
+    """
+    changed_columns = [name for name in diff.column_names if name.startswith("changed.")]
+    aggs = [(col("diff") == kind).cast(DataType.int64()).sum().alias(kind) for kind in DIFF_KINDS]
+    aggs += [col(name).cast(DataType.int64()).sum().alias(name) for name in changed_columns]
+    [counts] = diff.agg(*aggs).to_pylist()
+    return {name: count or 0 for name, count in counts.items()}
+
+
+def assert_df_equal(
+    actual: DataFrame,
+    expected: DataFrame,
+    on: str | list[str] | None = None,
+    max_rows_shown: int = 10,
+) -> None:
+    """Asserts that two DataFrames have the same rows, comparing them with :meth:`DataFrame.compare`.
+
+    Example:
+        >>> import daft
+        >>> from daft.testing import assert_df_equal
+        >>> df1 = daft.from_pydict({"id": [1, 2], "v": ["a", "b"]})
+        >>> df2 = daft.from_pydict({"id": [2, 1], "v": ["b", "a"]})
+        >>> assert_df_equal(df1, df2, on="id")
+
+    Args:
+        actual (DataFrame): DataFrame that's checked
+        expected (DataFrame): DataFrame with the expected rows
+        on (str | list[str] | None): Columns that identify the rows of both DataFrames, which must be unique in each of
+            them. Defaults to all columns, in which case rows can only be added or removed.
+        max_rows_shown (int): How many of the rows that differ are shown when the assertion fails
+
+    Raises:
+        AssertionError: If the DataFrames have different rows, with the numbers of differences and some of the rows
+            that differ.
+    """
+    if actual.schema() != expected.schema():
+        raise AssertionError(f"DataFrames have different schemas:\n{actual.schema()}\nvs\n{expected.schema()}")
+
+    diff = expected.compare(actual, on=actual.column_names if on is None else on).collect()
+    if len(diff) == 0:
+        return
+
+    counts = diff_counts(diff)
+    summary = ", ".join(f"{counts[kind]} {kind}" for kind in DIFF_KINDS)
+    changes = ", ".join(
+        f"{name.removeprefix('changed.')} ({count})"
+        for name, count in counts.items()
+        if name.startswith("changed.") and count > 0
+    )
+    message = f"DataFrames have different rows: {summary}"
+    if changes:
+        message += f"; changed columns: {changes}"
+    raise AssertionError(f"{message}\n{diff.limit(max_rows_shown).collect()!r}")
@@ -1,19 +1,75 @@
 from __future__ import annotations
 
 import dataclasses
+import datetime
 import warnings
-from typing import Callable
+from typing import Any, Callable, Literal
 from urllib.parse import urlparse
 
 import unitycatalog
 
-from daft.io import AzureConfig, IOConfig, S3Config
+from daft.io import AzureConfig, GCSConfig, IOConfig, S3Config, S3Credentials
+
+UnityCredentialsOperation = Literal["READ", "READ_WRITE"]
 
 
 @dataclasses.dataclass(frozen=True)
 class UnityCatalogTable:
     table_uri: str
     io_config: IOConfig | None
+    table_name: str | None = None
+    table_id: str | None = None
+    data_source_format: str | None = None
+
+
+def _make_client(endpoint: str, token: str | None) -> unitycatalog.Unitycatalog:
+    return unitycatalog.Unitycatalog(
+        base_url=endpoint.rstrip("/") + "/api/2.1/unity-catalog/",
+        default_headers={"Authorization": f"Bearer {token}"},
+    )
+
+
+def _expiration_to_datetime(expiration_time: int | None) -> datetime.datetime | None:
+    """Converts the expiration time returned by Unity Catalog, in milliseconds since the epoch, to a datetime."""
+    if expiration_time is None:
+        return None
+    return datetime.datetime.fromtimestamp(expiration_time / 1000, tz=datetime.timezone.utc)
+
+
+class _UnityS3CredentialsProvider:
+    """Vends fresh temporary S3 credentials for a table whenever Daft's S3 client finds its credentials expired.
+
+    Only the endpoint and token are stored rather than the client itself, so that the provider can be pickled and
+    shipped to workers along with the IOConfig.
+    """
+
+    def __init__(self, endpoint: str, token: str | None, table_id: str, operation: UnityCredentialsOperation):
+        self._endpoint = endpoint
+        self._token = token
+        self._table_id = table_id
+        self._operation = operation
+        self._client: unitycatalog.Unitycatalog | None = None
+
+    def __getstate__(self) -> dict[str, Any]:
+        state = self.__dict__.copy()
+        state["_client"] = None
+        return state
+
+    def __call__(self) -> S3Credentials:
+        if self._client is None:
+            self._client = _make_client(self._endpoint, self._token)
+        temp_table_credentials = self._client.temporary_table_credentials.create(
+            operation=self._operation, table_id=self._table_id
+        )
+        aws_temp_credentials = temp_table_credentials.aws_temp_credentials
+        if aws_temp_credentials is None:
+            raise ValueError(f"Unity Catalog did not vend AWS credentials for table {self._table_id}")
+        return S3Credentials(
+            key_id=aws_temp_credentials.access_key_id,
+            access_key=aws_temp_credentials.secret_access_key,
+            session_token=aws_temp_credentials.session_token,
+            expiry=_expiration_to_datetime(temp_table_credentials.expiration_time),
+        )
 
 
 class UnityCatalog:
@@ -29,10 +85,9 @@ class UnityCatalog:
     """
 
     def __init__(self, endpoint: str, token: str | None = None):
-        self._client = unitycatalog.Unitycatalog(
-            base_url=endpoint.rstrip("/") + "/api/2.1/unity-catalog/",
-            default_headers={"Authorization": f"Bearer {token}"},
-        )
+        self._endpoint = endpoint
+        self._token = token
+        self._client = _make_client(endpoint, token)
 
     def _paginate_to_completion(
         self,
@@ -90,12 +145,22 @@ class UnityCatalog:
 
         return self._paginate_to_completion(_paginated_list_tables)
 
-    def load_table(self, table_name: str, new_table_storage_path: str | None = None) -> UnityCatalogTable:
+    def load_table(
+        self,
+        table_name: str,
+        new_table_storage_path: str | None = None,
+        operation: UnityCredentialsOperation = "READ_WRITE",
+    ) -> UnityCatalogTable:
         """Loads an existing Unity Catalog table. If the table is not found, and information is provided in the method to create a new table, a new table will be attempted to be registered.
 
+        The returned table carries an IOConfig with temporary credentials vended by Unity Catalog for the table's storage
+        location. On S3 the credentials are refreshed from Unity Catalog when they expire, so long-running reads are not
+        interrupted.
+
         Args:
             table_name (str): Name of the table in Unity Catalog in the form of dot-separated, 3-level namespace
             new_table_storage_path (str, optional): Cloud storage path URI to register a new external table using this path. Unity Catalog will validate if the path is valid and authorized for the principal, else will raise an exception.
+            operation (str, optional): Operation to request credentials for, either "READ" or "READ_WRITE". Defaults to "READ_WRITE".
 
         Returns:
             UnityCatalogTable
@@ -136,38 +201,47 @@ class UnityCatalog:
 
         table_id = table_info.table_id
         storage_location = table_info.storage_location
-        # Grab credentials from Unity catalog and place it into the Table
-        temp_table_credentials = self._client.temporary_table_credentials.create(
-            operation="READ_WRITE", table_id=table_id
+        if storage_location is None:
+            raise ValueError(f"Storage location is missing from Unity Catalog table: {table_name}")
+
+        return UnityCatalogTable(
+            table_uri=storage_location,
+            io_config=self._vend_table_io_config(table_id, storage_location, operation),
+            table_name=table_name,
+            table_id=table_id,
+            data_source_format=table_info.data_source_format,
         )
 
+    def _vend_table_io_config(
+        self, table_id: str, storage_location: str, operation: UnityCredentialsOperation
+    ) -> IOConfig | None:
+        """Grabs temporary credentials for a table from Unity Catalog and places them into an IOConfig."""
+        temp_table_credentials = self._client.temporary_table_credentials.create(operation=operation, table_id=table_id)
+
         scheme = urlparse(storage_location).scheme
         if scheme == "s3" or scheme == "s3a":
             aws_temp_credentials = temp_table_credentials.aws_temp_credentials
-            io_config = (
-                IOConfig(
-                    s3=S3Config(
-                        key_id=aws_temp_credentials.access_key_id,
-                        access_key=aws_temp_credentials.secret_access_key,
-                        session_token=aws_temp_credentials.session_token,
-                    )
+            if aws_temp_credentials is None:
+                return None
+            # The initial credentials are kept on the config for consumers that cannot call the provider, such as
+            # the storage options passed to deltalake, while Daft's own S3 client uses the provider to refresh them.
+            return IOConfig(
+                s3=S3Config(
+                    key_id=aws_temp_credentials.access_key_id,
+                    access_key=aws_temp_credentials.secret_access_key,
+                    session_token=aws_temp_credentials.session_token,
+                    credentials_provider=_UnityS3CredentialsProvider(self._endpoint, self._token, table_id, operation),
                 )
-                if aws_temp_credentials is not None
-                else None
             )
         elif scheme == "gcs" or scheme == "gs":
-            # TO-DO: gather GCS credential vending assets from Unity and construct 'io_config``
-            warnings.warn("GCS credential vending from Unity Catalog is not yet supported.")
-            io_config = None
+            gcp_oauth_token = temp_table_credentials.gcp_oauth_token
+            if gcp_oauth_token is None:
+                return None
+            return IOConfig(gcs=GCSConfig(token=gcp_oauth_token.oauth_token))
         elif scheme == "az" or scheme == "abfs" or scheme == "abfss":
-            io_config = IOConfig(
+            return IOConfig(
                 azure=AzureConfig(sas_token=temp_table_credentials.azure_user_delegation_sas.get("sas_token"))
             )
         else:
             warnings.warn(f"Credentials for scheme {scheme} are not yet supported.")
-            io_config = None
-
-        return UnityCatalogTable(
-            table_uri=storage_location,
-            io_config=io_config,
-        )
+            return None
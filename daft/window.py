@@ -0,0 +1,104 @@
+from __future__ import annotations
+
+from daft.daft import WindowFunction as _WindowFunction
+from daft.expressions import Expression
+
+
+class WindowFunction:
+    """A function that :meth:`DataFrame.window() <daft.DataFrame.window>` evaluates for each row over the rows of
+    its window partition, in the order of the window.
+    """
+
+    _function: _WindowFunction
+    _name: str
+
+    def __init__(self) -> None:
+        raise NotImplementedError("Window functions should be created with the functions of the daft.window module")
+
+    @staticmethod
+    def _from_function(function: _WindowFunction, name: str) -> WindowFunction:
+        window_function = WindowFunction.__new__(WindowFunction)
+        window_function._function = function
+        window_function._name = name
+        return window_function
+
+    def alias(self, name: str) -> WindowFunction:
+        """Names the column with the values of the window function."""
+        return WindowFunction._from_function(self._function, name)
+
+    def name(self) -> str:
+        return self._name
+
+    def __repr__(self) -> str:
+        return f"{self._function!r} as {self._name}"
+
+
+def _check_expression(expr: Expression, function: str) -> None:
+    if not isinstance(expr, Expression):
+        raise TypeError(f"{function}() expects an expression, but got {type(expr)}")
+
+
+def _check_rows(rows: int | None, description: str) -> None:
+    if rows is not None and (not isinstance(rows, int) or rows < 0):
+        raise ValueError(f"The {description} of a window function must be a non-negative integer, but got {rows}")
+
+
+def row_number() -> WindowFunction:
+    """The position of each row within its partition, starting at 1."""
+    return WindowFunction._from_function(_WindowFunction.row_number(), "row_number")
+
+
+def rank() -> WindowFunction:
+    """The rank of each row within its partition, which is the position of the first row that is tied with it in the
+    order of the window, starting at 1. Requires the window to be ordered.
+    """
+    return WindowFunction._from_function(_WindowFunction.rank(), "rank")
+
+
+def lag(expr: Expression, offset: int = 1) -> WindowFunction:
+    """The value of ``expr`` at ``offset`` rows before each row of its partition, or null before the start of the
+    partition.
+    """
+    _check_expression(expr, "lag")
+    _check_rows(offset, "offset")
+    return WindowFunction._from_function(_WindowFunction.lag(expr._expr, offset), expr.name())
+
+
+def lead(expr: Expression, offset: int = 1) -> WindowFunction:
+    """The value of ``expr`` at ``offset`` rows after each row of its partition, or null past the end of the
+    partition.
+    """
+    _check_expression(expr, "lead")
+    _check_rows(offset, "offset")
+    return WindowFunction._from_function(_WindowFunction.lead(expr._expr, offset), expr.name())
+
+
+def sum(expr: Expression, preceding: int | None = None, following: int | None = 0) -> WindowFunction:
+    """The sum of the non-null values of ``expr`` over the ``preceding`` rows, the row itself and the ``following``
+    rows of its partition. Unbounded frames are ``None``, so the sum is a running sum by default.
+    """
+    _check_expression(expr, "sum")
+    _check_rows(preceding, "preceding rows")
+    _check_rows(following, "following rows")
+    return WindowFunction._from_function(_WindowFunction.sum(expr._expr, preceding, following), expr.name())
+
+
+def mean(expr: Expression, preceding: int | None = None, following: int | None = 0) -> WindowFunction:
+    """The mean of the non-null values of ``expr`` over the ``preceding`` rows, the row itself and the ``following``
+    rows of its partition. Unbounded frames are ``None``, so the mean is a running mean by default.
+    """
+    _check_expression(expr, "mean")
+    _check_rows(preceding, "preceding rows")
+    _check_rows(following, "following rows")
+    return WindowFunction._from_function(_WindowFunction.mean(expr._expr, preceding, following), expr.name())
+
+
+__all__ = [
+    "WindowFunction",
+    "lag",
+    "lead",
+    "mean",
+    "rank",
+    "row_number",
+    "sum",
+]
@@ -1,5 +1,6 @@
 #![feature(let_chains)]
 use common_io_config::IOConfig;
+use daft_schema::schema::SchemaRef;
 use serde::{Deserialize, Serialize};
 
 /// Configurations for Daft to use during the building of a Dataframe's plan.
@@ -10,6 +11,14 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Serialize, Deserialize, Default, Debug)]
 pub struct DaftPlanningConfig {
     pub default_io_config: IOConfig,
+    /// Collation of the string comparisons of filters and of the string sort keys of the session,
+    /// such as `case_insensitive`, which applies to DataFrame filters and sorts and to SQL queries.
+    /// None and `binary` compare the UTF-8 bytes of the strings. Joins, group-bys, distincts and
+    /// min/max aggregations always compare the UTF-8 bytes.
+    pub default_collation: Option<String>,
+    /// Schema hints that are applied to the inferred schemas of all file reads, merged with the
+    /// schema hints of each read.
+    pub default_schema_hints: Option<SchemaRef>,
 }
 
 impl DaftPlanningConfig {
@@ -53,6 +62,60 @@ pub struct DaftExecutionConfig {
     pub shuffle_algorithm: String,
     pub pre_shuffle_merge_threshold: usize,
     pub enable_ray_tracing: bool,
+    /// Local directory to persist the outputs of completed query stages to, so that a query that
+    /// fails can resume from its last completed stage when it is rerun
+    pub checkpoint_dir: Option<String>,
+    /// Whether to launch a duplicate attempt of scan tasks that take far longer than the others to
+    /// produce their first output, streaming the outputs of whichever attempt produces output first
+    pub enable_speculative_scans: bool,
+    /// How many times longer than the median scan task a scan task must wait for its first output
+    /// to be duplicated
+    pub speculative_scan_multiplier: f64,
+    /// Number of GPUs that the native executor schedules GPU UDFs on. If unset, the devices listed
+    /// in `CUDA_VISIBLE_DEVICES` are used
+    pub local_num_gpus: Option<usize>,
+    /// Whether hash joins on the Ray Runner look for hot join keys in a sample of one side, and
+    /// spread the rows of those keys across several partitions by salting them
+    pub enable_skew_join_salting: bool,
+    /// Number of partitions that the rows of each hot join key are spread across
+    pub skew_join_salt_factor: usize,
+    /// How many times more rows than an evenly sized partition a join key must have in the
+    /// sample to be salted
+    pub skew_join_heavy_hitter_threshold: f64,
+    /// Local directory under which each query of the native executor writes its spill files, in a
+    /// subdirectory that is removed when the query ends. If unset, the system temp directory is used
+    pub temp_dir: Option<String>,
+    /// Maximum total size of the spill files of all queries writing to the same temp directory
+    pub temp_space_quota_bytes: Option<usize>,
+    /// Maximum number of morsels that each operator of the native executor processes at once. If
+    /// unset, operators process up to one morsel per CPU
+    pub native_parallelism: Option<usize>,
+    /// Whether the native executor writes parquet files with the native writer instead of PyArrow,
+    /// which writes without holding the GIL. Writes with parquet encryption always use PyArrow
+    pub native_parquet_writer: bool,
+    /// Whether the native executor writes CSV files with the native writer instead of PyArrow
+    pub native_csv_writer: bool,
+    /// Where stateful streaming operators of the native executor, such as window aggregations,
+    /// keep their state: `memory` or `rocksdb`. Their state is checkpointed under `checkpoint_dir`
+    /// if it is set
+    pub state_store_backend: String,
+    /// Whether blocking operators of the native executor, such as sorts, compress the morsels that
+    /// they buffer until all of their input has arrived, decompressing them when they're processed
+    pub compress_buffered_partitions: bool,
+    /// Whether the native executor passes morsels to and from the processes of actor pool UDFs
+    /// through shared memory rather than by pickling them. Only takes effect on Linux, and morsels
+    /// with Python columns are always pickled.
+    pub udf_shared_memory_transport: bool,
+    /// Size of the morsels that each sort of the native executor buffers before it sorts them
+    /// into a run that it spills to its temp directory, merging the runs once all of its input
+    /// has arrived
+    pub sort_memory_limit_bytes: usize,
+    /// Whether `write_iceberg` commits snapshots with Daft's native Iceberg commit instead of
+    /// PyIceberg. Only REST catalogs and file-system tables are supported
+    pub native_iceberg_commit: bool,
+    /// Size of the partitions that each hash repartition of the native executor buffers before it
+    /// spills them to its temp directory
+    pub repartition_memory_limit_bytes: usize,
 }
 
 impl Default for DaftExecutionConfig {
@@ -81,6 +144,24 @@ impl Default for DaftExecutionConfig {
             shuffle_algorithm: "map_reduce".to_string(),
             pre_shuffle_merge_threshold: 1024 * 1024 * 1024, // 1GB
             enable_ray_tracing: false,
+            checkpoint_dir: None,
+            enable_speculative_scans: false,
+            speculative_scan_multiplier: 3.0,
+            local_num_gpus: None,
+            enable_skew_join_salting: false,
+            skew_join_salt_factor: 8,
+            skew_join_heavy_hitter_threshold: 1.0,
+            temp_dir: None,
+            temp_space_quota_bytes: None,
+            native_parallelism: None,
+            native_parquet_writer: true,
+            native_csv_writer: true,
+            state_store_backend: "memory".to_string(),
+            compress_buffered_partitions: false,
+            udf_shared_memory_transport: true,
+            sort_memory_limit_bytes: 2 * 1024 * 1024 * 1024, // 2GB
+            native_iceberg_commit: false,
+            repartition_memory_limit_bytes: 2 * 1024 * 1024 * 1024, // 2GB
         }
     }
 }
@@ -118,6 +199,39 @@ impl DaftExecutionConfig {
         if let Ok(val) = std::env::var(shuffle_algorithm_env_var_name) {
             cfg.shuffle_algorithm = val;
         }
+        let checkpoint_dir_env_var_name = "DAFT_CHECKPOINT_DIR";
+        if let Ok(val) = std::env::var(checkpoint_dir_env_var_name)
+            && !val.trim().is_empty()
+        {
+            cfg.checkpoint_dir = Some(val);
+        }
+        let speculative_scans_env_var_name = "DAFT_ENABLE_SPECULATIVE_SCANS";
+        if let Ok(val) = std::env::var(speculative_scans_env_var_name)
+            && matches!(val.trim().to_lowercase().as_str(), "1" | "true")
+        {
+            cfg.enable_speculative_scans = true;
+        }
+        let state_store_backend_env_var_name = "DAFT_STATE_STORE_BACKEND";
+        if let Ok(val) = std::env::var(state_store_backend_env_var_name)
+            && !val.trim().is_empty()
+        {
+            cfg.state_store_backend = val.trim().to_lowercase();
+        }
+        let temp_dir_env_var_name = "DAFT_TEMP_DIR";
+        if let Ok(val) = std::env::var(temp_dir_env_var_name)
+            && !val.trim().is_empty()
+        {
+            cfg.temp_dir = Some(val);
+        }
+        let temp_space_quota_env_var_name = "DAFT_TEMP_SPACE_QUOTA_BYTES";
+        if let Ok(val) = std::env::var(temp_space_quota_env_var_name) {
+            match val.trim().parse::<usize>() {
+                Ok(quota) => cfg.temp_space_quota_bytes = Some(quota),
+                Err(_) => {
+                    log::warn!("Ignoring invalid value for {temp_space_quota_env_var_name}: {val}")
+                }
+            }
+        }
         cfg
     }
 }
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use common_io_config::python::IOConfig as PyIOConfig;
 use common_py_serde::impl_bincode_py_state_serialization;
+use daft_schema::python::schema::PySchema;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -29,12 +30,23 @@ impl PyDaftPlanningConfig {
         }
     }
 
-    fn with_config_values(&mut self, default_io_config: Option<PyIOConfig>) -> PyResult<Self> {
+    fn with_config_values(
+        &mut self,
+        default_io_config: Option<PyIOConfig>,
+        default_collation: Option<String>,
+        default_schema_hints: Option<PySchema>,
+    ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
         if let Some(default_io_config) = default_io_config {
             config.default_io_config = default_io_config.config;
         }
+        if let Some(default_collation) = default_collation {
+            config.default_collation = Some(default_collation);
+        }
+        if let Some(default_schema_hints) = default_schema_hints {
+            config.default_schema_hints = Some(default_schema_hints.schema);
+        }
 
         Ok(Self {
             config: Arc::new(config),
@@ -47,6 +59,16 @@ impl PyDaftPlanningConfig {
             config: self.config.default_io_config.clone(),
         })
     }
+
+    #[getter(default_collation)]
+    fn default_collation(&self) -> Option<String> {
+        self.config.default_collation.clone()
+    }
+
+    #[getter(default_schema_hints)]
+    fn default_schema_hints(&self) -> Option<PySchema> {
+        self.config.default_schema_hints.clone().map(Into::into)
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftPlanningConfig);
@@ -99,6 +121,24 @@ impl PyDaftExecutionConfig {
         shuffle_algorithm: Option<&str>,
         pre_shuffle_merge_threshold: Option<usize>,
         enable_ray_tracing: Option<bool>,
+        checkpoint_dir: Option<String>,
+        enable_speculative_scans: Option<bool>,
+        speculative_scan_multiplier: Option<f64>,
+        local_num_gpus: Option<usize>,
+        enable_skew_join_salting: Option<bool>,
+        skew_join_salt_factor: Option<usize>,
+        skew_join_heavy_hitter_threshold: Option<f64>,
+        temp_dir: Option<String>,
+        temp_space_quota_bytes: Option<usize>,
+        native_parallelism: Option<usize>,
+        native_parquet_writer: Option<bool>,
+        native_csv_writer: Option<bool>,
+        state_store_backend: Option<&str>,
+        compress_buffered_partitions: Option<bool>,
+        udf_shared_memory_transport: Option<bool>,
+        sort_memory_limit_bytes: Option<usize>,
+        native_iceberg_commit: Option<bool>,
+        repartition_memory_limit_bytes: Option<usize>,
     ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
@@ -169,9 +209,12 @@ impl PyDaftExecutionConfig {
             config.default_morsel_size = default_morsel_size;
         }
         if let Some(shuffle_algorithm) = shuffle_algorithm {
-            if !matches!(shuffle_algorithm, "map_reduce" | "pre_shuffle_merge") {
+            if !matches!(
+                shuffle_algorithm,
+                "map_reduce" | "pre_shuffle_merge" | "flight_shuffle"
+            ) {
                 return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    "shuffle_algorithm must be 'map_reduce' or 'pre_shuffle_merge'",
+                    "shuffle_algorithm must be 'map_reduce', 'pre_shuffle_merge' or 'flight_shuffle'",
                 ));
             }
             config.shuffle_algorithm = shuffle_algorithm.to_string();
@@ -184,6 +227,89 @@ impl PyDaftExecutionConfig {
             config.enable_ray_tracing = enable_ray_tracing;
         }
 
+        if let Some(checkpoint_dir) = checkpoint_dir {
+            config.checkpoint_dir = Some(checkpoint_dir);
+        }
+
+        if let Some(enable_speculative_scans) = enable_speculative_scans {
+            config.enable_speculative_scans = enable_speculative_scans;
+        }
+        if let Some(speculative_scan_multiplier) = speculative_scan_multiplier {
+            if speculative_scan_multiplier < 1.0 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "speculative_scan_multiplier must be at least 1.0",
+                ));
+            }
+            config.speculative_scan_multiplier = speculative_scan_multiplier;
+        }
+
+        if let Some(local_num_gpus) = local_num_gpus {
+            config.local_num_gpus = Some(local_num_gpus);
+        }
+
+        if let Some(enable_skew_join_salting) = enable_skew_join_salting {
+            config.enable_skew_join_salting = enable_skew_join_salting;
+        }
+        if let Some(skew_join_salt_factor) = skew_join_salt_factor {
+            if skew_join_salt_factor < 2 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "skew_join_salt_factor must be at least 2",
+                ));
+            }
+            config.skew_join_salt_factor = skew_join_salt_factor;
+        }
+        if let Some(skew_join_heavy_hitter_threshold) = skew_join_heavy_hitter_threshold {
+            if skew_join_heavy_hitter_threshold <= 0.0 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "skew_join_heavy_hitter_threshold must be positive",
+                ));
+            }
+            config.skew_join_heavy_hitter_threshold = skew_join_heavy_hitter_threshold;
+        }
+        if let Some(temp_dir) = temp_dir {
+            config.temp_dir = Some(temp_dir);
+        }
+        if let Some(temp_space_quota_bytes) = temp_space_quota_bytes {
+            config.temp_space_quota_bytes = Some(temp_space_quota_bytes);
+        }
+        if let Some(native_parallelism) = native_parallelism {
+            if native_parallelism == 0 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "native_parallelism must be at least 1",
+                ));
+            }
+            config.native_parallelism = Some(native_parallelism);
+        }
+        if let Some(native_parquet_writer) = native_parquet_writer {
+            config.native_parquet_writer = native_parquet_writer;
+        }
+        if let Some(native_csv_writer) = native_csv_writer {
+            config.native_csv_writer = native_csv_writer;
+        }
+        if let Some(state_store_backend) = state_store_backend {
+            if !matches!(state_store_backend, "memory" | "rocksdb") {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "state_store_backend must be 'memory' or 'rocksdb'",
+                ));
+            }
+            config.state_store_backend = state_store_backend.to_string();
+        }
+        if let Some(compress_buffered_partitions) = compress_buffered_partitions {
+            config.compress_buffered_partitions = compress_buffered_partitions;
+        }
+        if let Some(udf_shared_memory_transport) = udf_shared_memory_transport {
+            config.udf_shared_memory_transport = udf_shared_memory_transport;
+        }
+        if let Some(sort_memory_limit_bytes) = sort_memory_limit_bytes {
+            config.sort_memory_limit_bytes = sort_memory_limit_bytes;
+        }
+        if let Some(native_iceberg_commit) = native_iceberg_commit {
+            config.native_iceberg_commit = native_iceberg_commit;
+        }
+        if let Some(repartition_memory_limit_bytes) = repartition_memory_limit_bytes {
+            config.repartition_memory_limit_bytes = repartition_memory_limit_bytes;
+        }
+
         Ok(Self {
             config: Arc::new(config),
         })
@@ -293,6 +419,96 @@ impl PyDaftExecutionConfig {
     fn enable_ray_tracing(&self) -> PyResult<bool> {
         Ok(self.config.enable_ray_tracing)
     }
+
+    #[getter]
+    fn checkpoint_dir(&self) -> PyResult<Option<String>> {
+        Ok(self.config.checkpoint_dir.clone())
+    }
+
+    #[getter]
+    fn enable_speculative_scans(&self) -> PyResult<bool> {
+        Ok(self.config.enable_speculative_scans)
+    }
+
+    #[getter]
+    fn speculative_scan_multiplier(&self) -> PyResult<f64> {
+        Ok(self.config.speculative_scan_multiplier)
+    }
+
+    #[getter]
+    fn local_num_gpus(&self) -> PyResult<Option<usize>> {
+        Ok(self.config.local_num_gpus)
+    }
+
+    #[getter]
+    fn enable_skew_join_salting(&self) -> PyResult<bool> {
+        Ok(self.config.enable_skew_join_salting)
+    }
+
+    #[getter]
+    fn skew_join_salt_factor(&self) -> PyResult<usize> {
+        Ok(self.config.skew_join_salt_factor)
+    }
+
+    #[getter]
+    fn skew_join_heavy_hitter_threshold(&self) -> PyResult<f64> {
+        Ok(self.config.skew_join_heavy_hitter_threshold)
+    }
+
+    #[getter]
+    fn temp_dir(&self) -> PyResult<Option<String>> {
+        Ok(self.config.temp_dir.clone())
+    }
+
+    #[getter]
+    fn temp_space_quota_bytes(&self) -> PyResult<Option<usize>> {
+        Ok(self.config.temp_space_quota_bytes)
+    }
+
+    #[getter]
+    fn native_parallelism(&self) -> PyResult<Option<usize>> {
+        Ok(self.config.native_parallelism)
+    }
+
+    #[getter]
+    fn native_parquet_writer(&self) -> PyResult<bool> {
+        Ok(self.config.native_parquet_writer)
+    }
+
+    #[getter]
+    fn native_csv_writer(&self) -> PyResult<bool> {
+        Ok(self.config.native_csv_writer)
+    }
+
+    #[getter]
+    fn state_store_backend(&self) -> PyResult<&str> {
+        Ok(self.config.state_store_backend.as_str())
+    }
+
+    #[getter]
+    fn compress_buffered_partitions(&self) -> PyResult<bool> {
+        Ok(self.config.compress_buffered_partitions)
+    }
+
+    #[getter]
+    fn udf_shared_memory_transport(&self) -> PyResult<bool> {
+        Ok(self.config.udf_shared_memory_transport)
+    }
+
+    #[getter]
+    fn sort_memory_limit_bytes(&self) -> PyResult<usize> {
+        Ok(self.config.sort_memory_limit_bytes)
+    }
+
+    #[getter]
+    fn native_iceberg_commit(&self) -> PyResult<bool> {
+        Ok(self.config.native_iceberg_commit)
+    }
+
+    #[getter]
+    fn repartition_memory_limit_bytes(&self) -> PyResult<usize> {
+        Ok(self.config.repartition_memory_limit_bytes)
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftExecutionConfig);
@@ -15,6 +15,9 @@ pub struct MermaidDisplayOptions {
     /// This is useful for large trees.
     /// In simple mode, the display string is just the node's name.
     pub simple: bool,
+    /// verbose mode shows all available details of each node, such as the output schema of
+    /// every node rather than only of the sources. It has no effect in simple mode.
+    pub verbose: bool,
     /// Display the root node at the bottom of the diagram or at the top
     pub bottom_up: bool,
     /// subgraph_options is used to configure the subgraph.
@@ -36,9 +39,10 @@ pub struct SubgraphOptions {
 impl<T: TreeDisplay> MermaidDisplay for T {
     fn repr_mermaid(&self, options: MermaidDisplayOptions) -> String {
         let mut s = String::new();
-        let display_type = match options.simple {
-            true => DisplayLevel::Compact,
-            false => DisplayLevel::Default,
+        let display_type = match (options.simple, options.verbose) {
+            (true, _) => DisplayLevel::Compact,
+            (false, true) => DisplayLevel::Verbose,
+            (false, false) => DisplayLevel::Default,
         };
 
         let mut visitor = MermaidDisplayVisitor::new(
@@ -14,7 +14,7 @@ pub enum DaftError {
     #[error("DaftError::ComputeError {0}")]
     ComputeError(String),
     #[error("DaftError::ArrowError {0}")]
-    ArrowError(arrow2::error::Error),
+    ArrowError(#[source] arrow2::error::Error),
     #[error("DaftError::ValueError {0}")]
     ValueError(String),
     #[cfg(feature = "python")]
@@ -27,19 +27,19 @@ pub enum DaftError {
     #[error("DaftError::InternalError {0}")]
     InternalError(String),
     #[error("ConnectTimeout {0}")]
-    ConnectTimeout(GenericError),
+    ConnectTimeout(#[source] GenericError),
     #[error("ReadTimeout {0}")]
-    ReadTimeout(GenericError),
+    ReadTimeout(#[source] GenericError),
     #[error("ByteStreamError {0}")]
-    ByteStreamError(GenericError),
+    ByteStreamError(#[source] GenericError),
     #[error("SocketError {0}")]
-    SocketError(GenericError),
+    SocketError(#[source] GenericError),
     #[error("ThrottledIo {0}")]
-    ThrottledIo(GenericError),
+    ThrottledIo(#[source] GenericError),
     #[error("MiscTransient {0}")]
-    MiscTransient(GenericError),
+    MiscTransient(#[source] GenericError),
     #[error("DaftError::External {0}")]
-    External(GenericError),
+    External(#[source] GenericError),
     #[error("DaftError::SerdeJsonError {0}")]
     SerdeJsonError(#[from] serde_json::Error),
     #[error("DaftError::FmtError {0}")]
@@ -52,12 +52,163 @@ pub enum DaftError {
     NotImplemented(String),
     #[error("DaftError::CatalogError {0}")]
     CatalogError(String),
+    #[error("{source}\nContext: {context}")]
+    WithContext {
+        context: String,
+        source: Box<DaftError>,
+    },
+}
+
+/// The broad kind of an error, which determines how it is reported to users and clients
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// Invalid arguments or data provided by the user
+    User,
+    /// A query that can't be planned, such as one referencing missing columns or mismatched types
+    Plan,
+    /// A failure reading or writing storage, which may be transient
+    Io,
+    /// A failure while executing a valid query
+    Execution,
+    /// A bug in Daft
+    Internal,
+}
+
+impl ErrorCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Plan => "plan",
+            Self::Io => "io",
+            Self::Execution => "execution",
+            Self::Internal => "internal",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl DaftError {
     pub fn not_implemented<T: std::fmt::Display>(msg: T) -> Self {
         Self::NotImplemented(msg.to_string())
     }
+
+    /// Wraps this error with the expression, operator or step it occurred in, keeping its
+    /// category and code.
+    #[must_use]
+    pub fn context<C: Into<String>>(self, context: C) -> Self {
+        Self::WithContext {
+            context: context.into(),
+            source: Box::new(self),
+        }
+    }
+
+    /// The error wrapped by any contexts
+    pub fn root(&self) -> &Self {
+        match self {
+            Self::WithContext { source, .. } => source.root(),
+            _ => self,
+        }
+    }
+
+    /// Unwraps the error wrapped by any contexts
+    pub fn into_root(self) -> Self {
+        match self {
+            Self::WithContext { source, .. } => source.into_root(),
+            _ => self,
+        }
+    }
+
+    /// The contexts this error is wrapped in, from the outermost to the innermost
+    pub fn contexts(&self) -> Vec<&str> {
+        let mut contexts = Vec::new();
+        let mut error = self;
+        while let Self::WithContext { context, source } = error {
+            contexts.push(context.as_str());
+            error = source;
+        }
+        contexts
+    }
+
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::ValueError(_) | Self::RegexError(_) => ErrorCategory::User,
+            Self::FieldNotFound(_)
+            | Self::SchemaMismatch(_)
+            | Self::TypeError(_)
+            | Self::NotImplemented(_)
+            | Self::CatalogError(_) => ErrorCategory::Plan,
+            Self::IoError(_)
+            | Self::FileNotFound { .. }
+            | Self::ConnectTimeout(_)
+            | Self::ReadTimeout(_)
+            | Self::ByteStreamError(_)
+            | Self::SocketError(_)
+            | Self::ThrottledIo(_)
+            | Self::MiscTransient(_) => ErrorCategory::Io,
+            Self::ComputeError(_)
+            | Self::ArrowError(_)
+            | Self::External(_)
+            | Self::SerdeJsonError(_)
+            | Self::FromUtf8Error(_) => ErrorCategory::Execution,
+            #[cfg(feature = "python")]
+            Self::PyO3Error(_) => ErrorCategory::Execution,
+            Self::InternalError(_) | Self::FmtError(_) => ErrorCategory::Internal,
+            Self::WithContext { source, .. } => source.category(),
+        }
+    }
+
+    /// A stable code identifying the kind of error, which does not change between releases
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::FieldNotFound(_) => "DAFT_FIELD_NOT_FOUND",
+            Self::SchemaMismatch(_) => "DAFT_SCHEMA_MISMATCH",
+            Self::TypeError(_) => "DAFT_TYPE_ERROR",
+            Self::ComputeError(_) => "DAFT_COMPUTE_ERROR",
+            Self::ArrowError(_) => "DAFT_ARROW_ERROR",
+            Self::ValueError(_) => "DAFT_VALUE_ERROR",
+            #[cfg(feature = "python")]
+            Self::PyO3Error(_) => "DAFT_PYTHON_ERROR",
+            Self::IoError(_) => "DAFT_IO_ERROR",
+            Self::FileNotFound { .. } => "DAFT_FILE_NOT_FOUND",
+            Self::InternalError(_) => "DAFT_INTERNAL_ERROR",
+            Self::ConnectTimeout(_) => "DAFT_CONNECT_TIMEOUT",
+            Self::ReadTimeout(_) => "DAFT_READ_TIMEOUT",
+            Self::ByteStreamError(_) => "DAFT_BYTE_STREAM_ERROR",
+            Self::SocketError(_) => "DAFT_SOCKET_ERROR",
+            Self::ThrottledIo(_) => "DAFT_THROTTLED_IO",
+            Self::MiscTransient(_) => "DAFT_TRANSIENT_ERROR",
+            Self::External(_) => "DAFT_EXTERNAL_ERROR",
+            Self::SerdeJsonError(_) => "DAFT_JSON_ERROR",
+            Self::FmtError(_) => "DAFT_FORMAT_ERROR",
+            Self::RegexError(_) => "DAFT_REGEX_ERROR",
+            Self::FromUtf8Error(_) => "DAFT_INVALID_UTF8",
+            Self::NotImplemented(_) => "DAFT_NOT_IMPLEMENTED",
+            Self::CatalogError(_) => "DAFT_CATALOG_ERROR",
+            Self::WithContext { source, .. } => source.code(),
+        }
+    }
+}
+
+/// Adds context to the error of a result, see [`DaftError::context`]
+pub trait DaftResultExt<T> {
+    fn context<C: Into<String>>(self, context: C) -> DaftResult<T>;
+
+    fn with_context<C: Into<String>, F: FnOnce() -> C>(self, f: F) -> DaftResult<T>;
+}
+
+impl<T> DaftResultExt<T> for DaftResult<T> {
+    fn context<C: Into<String>>(self, context: C) -> DaftResult<T> {
+        self.map_err(|e| e.context(context))
+    }
+
+    fn with_context<C: Into<String>, F: FnOnce() -> C>(self, f: F) -> DaftResult<T> {
+        self.map_err(|e| e.context(f()))
+    }
 }
 
 impl From<arrow2::error::Error> for DaftError {
@@ -106,4 +257,32 @@ mod tests {
             _ => panic!("Expected ByteStreamError"),
         }
     }
+
+    #[test]
+    fn test_context_keeps_category_and_code() {
+        use std::error::Error;
+
+        let error = DaftError::TypeError("cannot add Utf8 and Int64".to_string())
+            .context("evaluating expression col(a) + 1")
+            .context("projection");
+        assert_eq!(error.category(), ErrorCategory::Plan);
+        assert_eq!(error.code(), "DAFT_TYPE_ERROR");
+        assert_eq!(
+            error.contexts(),
+            ["projection", "evaluating expression col(a) + 1"]
+        );
+        assert!(matches!(error.root(), DaftError::TypeError(_)));
+        assert_eq!(
+            error.to_string(),
+            "DaftError::TypeError cannot add Utf8 and Int64\nContext: evaluating expression col(a) + 1\nContext: projection"
+        );
+        assert!(error.source().unwrap().source().is_some());
+
+        let result: DaftResult<()> = Err(DaftError::ReadTimeout("timed out".into()));
+        let error = result
+            .with_context(|| "reading s3://bucket/file")
+            .unwrap_err();
+        assert_eq!(error.category(), ErrorCategory::Io);
+        assert_eq!(error.root().source().unwrap().to_string(), "timed out");
+    }
 }
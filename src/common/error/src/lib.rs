@@ -1,4 +1,4 @@
 mod error;
-pub use error::{DaftError, DaftResult};
+pub use error::{DaftError, DaftResult, DaftResultExt, ErrorCategory};
 #[cfg(feature = "python")]
 mod python;
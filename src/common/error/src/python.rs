@@ -1,4 +1,4 @@
-use pyo3::{exceptions::PyFileNotFoundError, import_exception};
+use pyo3::{exceptions::PyFileNotFoundError, import_exception, prelude::*};
 
 use crate::DaftError;
 
@@ -14,6 +14,24 @@ import_exception!(daft.exceptions, MiscTransientError);
 impl std::convert::From<DaftError> for pyo3::PyErr {
     fn from(err: DaftError) -> Self {
         match err {
+            DaftError::WithContext { .. } => {
+                let contexts = err
+                    .contexts()
+                    .iter()
+                    .map(|context| format!("\nContext: {context}"))
+                    .collect::<String>();
+                match err.into_root() {
+                    DaftError::PyO3Error(pyerr) => pyerr,
+                    // Raise the exception type of the wrapped error, with the contexts in its message
+                    root => {
+                        let pyerr = Self::from(root);
+                        Python::with_gil(|py| {
+                            let message = format!("{}{contexts}", pyerr.value_bound(py));
+                            Self::from_type_bound(pyerr.get_type_bound(py), message)
+                        })
+                    }
+                }
+            }
             DaftError::PyO3Error(pyerr) => pyerr,
             DaftError::FileNotFound { path, source } => {
                 PyFileNotFoundError::new_err(format!("File: {path} not found\n{source}"))
@@ -17,6 +17,10 @@ pub enum FileFormat {
     Parquet,
     Csv,
     Json,
+    Xml,
+    Excel,
+    Kafka,
+    Range,
     Database,
     Python,
 }
@@ -29,6 +33,10 @@ impl FileFormat {
             Self::Parquet => "parquet",
             Self::Csv => "csv",
             Self::Json => "json",
+            Self::Xml => "xml",
+            Self::Excel => "xlsx",
+            Self::Kafka => "kafka",
+            Self::Range => "range",
             Self::Database => "db",
             Self::Python => "py",
         }
@@ -39,7 +47,7 @@ impl FromStr for FileFormat {
     type Err = DaftError;
 
     fn from_str(file_format: &str) -> DaftResult<Self> {
-        use FileFormat::{Csv, Database, Json, Parquet};
+        use FileFormat::{Csv, Database, Excel, Json, Parquet, Xml};
 
         if file_format.trim().eq_ignore_ascii_case("parquet") {
             Ok(Parquet)
@@ -47,6 +55,10 @@ impl FromStr for FileFormat {
             Ok(Csv)
         } else if file_format.trim().eq_ignore_ascii_case("json") {
             Ok(Json)
+        } else if file_format.trim().eq_ignore_ascii_case("xml") {
+            Ok(Xml)
+        } else if file_format.trim().eq_ignore_ascii_case("excel") {
+            Ok(Excel)
         } else if file_format.trim().eq_ignore_ascii_case("database") {
             Ok(Database)
         } else {
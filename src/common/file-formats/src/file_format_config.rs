@@ -18,6 +18,11 @@ pub enum FileFormatConfig {
     Parquet(ParquetSourceConfig),
     Csv(CsvSourceConfig),
     Json(JsonSourceConfig),
+    Xml(XmlSourceConfig),
+    Excel(ExcelSourceConfig),
+    Kafka(KafkaSourceConfig),
+    /// Sequential integers, generated rather than read
+    Range,
     #[cfg(feature = "python")]
     Database(DatabaseSourceConfig),
     #[cfg(feature = "python")]
@@ -36,6 +41,10 @@ impl FileFormatConfig {
             Self::Parquet(_) => "Parquet",
             Self::Csv(_) => "Csv",
             Self::Json(_) => "Json",
+            Self::Xml(_) => "Xml",
+            Self::Excel(_) => "Excel",
+            Self::Kafka(_) => "Kafka",
+            Self::Range => "Range",
             #[cfg(feature = "python")]
             Self::Database(_) => "Database",
             #[cfg(feature = "python")]
@@ -49,6 +58,10 @@ impl FileFormatConfig {
             Self::Parquet(source) => source.multiline_display(),
             Self::Csv(source) => source.multiline_display(),
             Self::Json(source) => source.multiline_display(),
+            Self::Xml(source) => source.multiline_display(),
+            Self::Excel(source) => source.multiline_display(),
+            Self::Kafka(source) => source.multiline_display(),
+            Self::Range => vec![],
             #[cfg(feature = "python")]
             Self::Database(source) => source.multiline_display(),
             #[cfg(feature = "python")]
@@ -298,6 +311,218 @@ impl JsonSourceConfig {
 
 impl_bincode_py_state_serialization!(JsonSourceConfig);
 
+/// Configuration for an XML data source.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct XmlSourceConfig {
+    /// Name of the elements that are read as rows, e.g. `record` for `<record>...</record>`.
+    pub row_tag: String,
+    /// Prefix of the names of the columns or fields read from attributes.
+    pub attribute_prefix: String,
+    /// Name of the field that holds the text of elements that also have attributes or children.
+    pub value_tag: String,
+}
+
+impl XmlSourceConfig {
+    pub const DEFAULT_ATTRIBUTE_PREFIX: &'static str = "_";
+    pub const DEFAULT_VALUE_TAG: &'static str = "_VALUE";
+
+    #[must_use]
+    pub fn new_internal(
+        row_tag: String,
+        attribute_prefix: Option<String>,
+        value_tag: Option<String>,
+    ) -> Self {
+        Self {
+            row_tag,
+            attribute_prefix: attribute_prefix
+                .unwrap_or_else(|| Self::DEFAULT_ATTRIBUTE_PREFIX.to_string()),
+            value_tag: value_tag.unwrap_or_else(|| Self::DEFAULT_VALUE_TAG.to_string()),
+        }
+    }
+
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("Row tag = {}", self.row_tag));
+        res.push(format!("Attribute prefix = {}", self.attribute_prefix));
+        res.push(format!("Value tag = {}", self.value_tag));
+        res
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl XmlSourceConfig {
+    /// Create a config for an XML data source.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_tag` - Name of the elements that are read as rows.
+    /// * `attribute_prefix` - Prefix of the names of the columns or fields read from attributes.
+    /// * `value_tag` - Name of the field that holds the text of elements with attributes or children.
+    #[new]
+    fn new(
+        row_tag: String,
+        attribute_prefix: Option<String>,
+        value_tag: Option<String>,
+    ) -> PyResult<Self> {
+        if row_tag.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "The row tag of an XML source can't be empty",
+            ));
+        }
+        Ok(Self::new_internal(row_tag, attribute_prefix, value_tag))
+    }
+}
+
+impl_bincode_py_state_serialization!(XmlSourceConfig);
+
+/// Configuration for an Excel data source.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct ExcelSourceConfig {
+    /// Name of the sheet to read, or the first sheet of the workbook if not set.
+    pub sheet_name: Option<String>,
+    /// Index of the row of the sheet with the names of the columns, after which the rows of data
+    /// start. If not set, every row is data and the columns are named `column_1`, `column_2`, ...
+    pub header_row: Option<usize>,
+}
+
+impl ExcelSourceConfig {
+    #[must_use]
+    pub fn new_internal(sheet_name: Option<String>, header_row: Option<usize>) -> Self {
+        Self {
+            sheet_name,
+            header_row,
+        }
+    }
+
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if let Some(sheet_name) = &self.sheet_name {
+            res.push(format!("Sheet name = {sheet_name}"));
+        }
+        match self.header_row {
+            Some(header_row) => res.push(format!("Header row = {header_row}")),
+            None => res.push("Header row = None".to_string()),
+        }
+        res
+    }
+}
+
+impl Default for ExcelSourceConfig {
+    fn default() -> Self {
+        Self::new_internal(None, Some(0))
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl ExcelSourceConfig {
+    /// Create a config for an Excel data source.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_name` - Name of the sheet to read, or the first sheet of the workbook if not set.
+    /// * `header_row` - Index of the row with the names of the columns, if the sheet has one.
+    #[new]
+    fn new(sheet_name: Option<String>, header_row: Option<usize>) -> Self {
+        Self::new_internal(sheet_name, header_row)
+    }
+}
+
+impl_bincode_py_state_serialization!(ExcelSourceConfig);
+
+/// Format of the values of the messages of a Kafka topic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub enum KafkaValueFormat {
+    /// Each value is a JSON object.
+    Json,
+    /// Each value is a single Avro datum, encoded with the writer schema of the source.
+    Avro,
+}
+
+/// Configuration for a Kafka data source.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct KafkaSourceConfig {
+    pub bootstrap_servers: String,
+    pub topic: String,
+    pub value_format: KafkaValueFormat,
+    /// Writer schema of Avro values, as JSON.
+    pub avro_schema: Option<String>,
+    /// Extra librdkafka consumer options, e.g. `security.protocol` or `sasl.mechanisms`.
+    pub consumer_options: BTreeMap<String, String>,
+    /// How long to wait for messages from a broker before giving up on a read.
+    pub poll_timeout_ms: u64,
+}
+
+impl KafkaSourceConfig {
+    pub const DEFAULT_POLL_TIMEOUT_MS: u64 = 30_000;
+
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("Bootstrap servers = {}", self.bootstrap_servers));
+        res.push(format!("Topic = {}", self.topic));
+        res.push(format!("Value format = {:?}", self.value_format));
+        if !self.consumer_options.is_empty() {
+            res.push(format!(
+                "Consumer options = {{{}}}",
+                self.consumer_options
+                    .keys()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        res
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl KafkaSourceConfig {
+    /// Create a config for a Kafka data source.
+    ///
+    /// # Arguments
+    ///
+    /// * `bootstrap_servers` - Comma-separated list of brokers to connect to.
+    /// * `topic` - The topic to read.
+    /// * `value_format` - The format of the values of the messages.
+    /// * `avro_schema` - Writer schema of the values as JSON, required for Avro values.
+    /// * `consumer_options` - Extra librdkafka consumer options.
+    /// * `poll_timeout_ms` - How long to wait for messages from a broker before giving up on a read.
+    #[new]
+    fn new(
+        bootstrap_servers: String,
+        topic: String,
+        value_format: KafkaValueFormat,
+        avro_schema: Option<String>,
+        consumer_options: Option<BTreeMap<String, String>>,
+        poll_timeout_ms: Option<u64>,
+    ) -> PyResult<Self> {
+        if value_format == KafkaValueFormat::Avro && avro_schema.is_none() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "An Avro schema is required to read Avro values from Kafka",
+            ));
+        }
+        Ok(Self {
+            bootstrap_servers,
+            topic,
+            value_format,
+            avro_schema,
+            consumer_options: consumer_options.unwrap_or_default(),
+            poll_timeout_ms: poll_timeout_ms.unwrap_or(Self::DEFAULT_POLL_TIMEOUT_MS),
+        })
+    }
+}
+
+impl_bincode_py_state_serialization!(KafkaSourceConfig);
+
 /// Configuration for a Database data source.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg(feature = "python")]
@@ -315,7 +540,10 @@ pub struct DatabaseSourceConfig {
 impl PartialEq for DatabaseSourceConfig {
     fn eq(&self, other: &Self) -> bool {
         self.sql == other.sql
-            && Python::with_gil(|py| self.conn.bind(py).eq(other.conn.bind(py)).unwrap())
+            && Python::with_gil(|py| {
+                let (conn, other_conn) = (self.conn.bind(py), other.conn.bind(py));
+                conn.is(other_conn) || conn.eq(other_conn).unwrap_or(false)
+            })
     }
 }
 
@@ -325,12 +553,10 @@ impl Eq for DatabaseSourceConfig {}
 #[cfg(feature = "python")]
 impl Hash for DatabaseSourceConfig {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // The hash of the connection isn't included, since Python hashes of objects are based on
+        // their addresses by default and so differ between processes, like those of the copies of
+        // a config that are shipped to workers.
         self.sql.hash(state);
-        let py_obj_hash = Python::with_gil(|py| self.conn.bind(py).hash());
-        match py_obj_hash {
-            Ok(hash) => hash.hash(state),
-            Err(_) => serde_json::to_vec(self).unwrap().hash(state),
-        }
     }
 }
 
@@ -5,18 +5,26 @@ mod file_format_config;
 #[cfg(feature = "python")]
 pub use file_format_config::DatabaseSourceConfig;
 pub use file_format_config::{
-    CsvSourceConfig, FileFormatConfig, JsonSourceConfig, ParquetSourceConfig,
+    CsvSourceConfig, ExcelSourceConfig, FileFormatConfig, JsonSourceConfig, KafkaSourceConfig,
+    KafkaValueFormat, ParquetSourceConfig, XmlSourceConfig,
 };
 
 #[cfg(feature = "python")]
 pub mod python;
 
+mod write_options;
+pub use write_options::{CsvQuoteStyle, CsvWriteOptions};
+
 impl From<&FileFormatConfig> for FileFormat {
     fn from(file_format_config: &FileFormatConfig) -> Self {
         match file_format_config {
             FileFormatConfig::Parquet(_) => Self::Parquet,
             FileFormatConfig::Csv(_) => Self::Csv,
             FileFormatConfig::Json(_) => Self::Json,
+            FileFormatConfig::Xml(_) => Self::Xml,
+            FileFormatConfig::Excel(_) => Self::Excel,
+            FileFormatConfig::Kafka(_) => Self::Kafka,
+            FileFormatConfig::Range => Self::Range,
             #[cfg(feature = "python")]
             FileFormatConfig::Database(_) => Self::Database,
             #[cfg(feature = "python")]
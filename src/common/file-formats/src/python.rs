@@ -5,8 +5,9 @@ use pyo3::{basic::CompareOp, prelude::*};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    file_format_config::DatabaseSourceConfig, CsvSourceConfig, FileFormat, FileFormatConfig,
-    JsonSourceConfig, ParquetSourceConfig,
+    file_format_config::DatabaseSourceConfig, CsvSourceConfig, ExcelSourceConfig, FileFormat,
+    FileFormatConfig, JsonSourceConfig, KafkaSourceConfig, KafkaValueFormat, ParquetSourceConfig,
+    XmlSourceConfig,
 };
 
 /// Configuration for parsing a particular file format.
@@ -38,6 +39,24 @@ impl PyFileFormatConfig {
         Self(Arc::new(FileFormatConfig::Json(config)))
     }
 
+    /// Create an XML file format config.
+    #[staticmethod]
+    fn from_xml_config(config: XmlSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Xml(config)))
+    }
+
+    /// Create an Excel file format config.
+    #[staticmethod]
+    fn from_excel_config(config: ExcelSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Excel(config)))
+    }
+
+    /// Create a Kafka file format config.
+    #[staticmethod]
+    fn from_kafka_config(config: KafkaSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Kafka(config)))
+    }
+
     /// Create a Database file format config.
     #[staticmethod]
     fn from_database_config(config: DatabaseSourceConfig) -> Self {
@@ -51,8 +70,11 @@ impl PyFileFormatConfig {
             FileFormatConfig::Parquet(config) => config.clone().into_py(py),
             FileFormatConfig::Csv(config) => config.clone().into_py(py),
             FileFormatConfig::Json(config) => config.clone().into_py(py),
+            FileFormatConfig::Xml(config) => config.clone().into_py(py),
+            FileFormatConfig::Excel(config) => config.clone().into_py(py),
+            FileFormatConfig::Kafka(config) => config.clone().into_py(py),
             FileFormatConfig::Database(config) => config.clone().into_py(py),
-            FileFormatConfig::PythonFunction => py.None(),
+            FileFormatConfig::Range | FileFormatConfig::PythonFunction => py.None(),
         }
     }
 
@@ -86,5 +108,6 @@ impl From<Arc<FileFormatConfig>> for PyFileFormatConfig {
 
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<FileFormat>()?;
+    parent.add_class::<KafkaValueFormat>()?;
     Ok(())
 }
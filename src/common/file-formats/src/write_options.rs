@@ -0,0 +1,142 @@
+use std::{fmt::Display, str::FromStr};
+
+use common_error::{DaftError, DaftResult};
+use common_py_serde::impl_bincode_py_state_serialization;
+#[cfg(feature = "python")]
+use pyo3::{exceptions::PyValueError, pyclass, pymethods, PyResult};
+use serde::{Deserialize, Serialize};
+
+/// Which values of a CSV file are quoted, named like the quoting styles of PyArrow's CSV writer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub enum CsvQuoteStyle {
+    /// Only values that contain the delimiter, the quote character or a line break
+    #[default]
+    Needed,
+    /// Every value that isn't null
+    AllValid,
+    /// No value, which fails the write if a value would need to be quoted
+    Never,
+}
+
+impl FromStr for CsvQuoteStyle {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s.to_lowercase().as_str() {
+            "needed" => Ok(Self::Needed),
+            "all_valid" => Ok(Self::AllValid),
+            "none" => Ok(Self::Never),
+            other => Err(DaftError::ValueError(format!(
+                "Unsupported CSV quote style: {other}, expected one of `needed`, `all_valid` or `none`"
+            ))),
+        }
+    }
+}
+
+impl Display for CsvQuoteStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Needed => write!(f, "needed"),
+            Self::AllValid => write!(f, "all_valid"),
+            Self::Never => write!(f, "none"),
+        }
+    }
+}
+
+/// Options of the CSV files written by a write.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct CsvWriteOptions {
+    pub delimiter: char,
+    pub quote: char,
+    pub quote_style: CsvQuoteStyle,
+    /// Whether files start with a row of the column names
+    pub header: bool,
+    /// How null values are written
+    pub null_value: String,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quote: '"',
+            quote_style: CsvQuoteStyle::default(),
+            header: true,
+            null_value: String::new(),
+        }
+    }
+}
+
+impl CsvWriteOptions {
+    /// Checks that the delimiter and quote character can be written as single bytes, and that
+    /// they are distinguishable from each other.
+    pub fn validate(&self) -> DaftResult<()> {
+        if !self.delimiter.is_ascii() || !self.quote.is_ascii() {
+            return Err(DaftError::ValueError(format!(
+                "CSV delimiter and quote must be ASCII characters, but got: {:?} and {:?}",
+                self.delimiter, self.quote
+            )));
+        }
+        if self.delimiter == self.quote {
+            return Err(DaftError::ValueError(format!(
+                "CSV delimiter and quote must be different characters, but both are: {:?}",
+                self.delimiter
+            )));
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("Delimiter = {}", self.delimiter));
+        res.push(format!("Quote = {}", self.quote));
+        res.push(format!("Quote style = {}", self.quote_style));
+        res.push(format!("Header = {}", self.header));
+        if !self.null_value.is_empty() {
+            res.push(format!("Null value = {}", self.null_value));
+        }
+        res
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CsvWriteOptions {
+    /// Create the options of written CSV files.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - The character delimiting the values of a row.
+    /// * `quote` - The character that values are quoted with.
+    /// * `quote_style` - Which values are quoted: `needed`, `all_valid` or `none`.
+    /// * `header` - Whether to write a row of the column names.
+    /// * `null_value` - The string that null values are written as.
+    #[new]
+    #[pyo3(signature = (delimiter=',', quote='"', quote_style="needed", header=true, null_value=String::new()))]
+    fn new(
+        delimiter: char,
+        quote: char,
+        quote_style: &str,
+        header: bool,
+        null_value: String,
+    ) -> PyResult<Self> {
+        let options = Self {
+            delimiter,
+            quote,
+            quote_style: quote_style
+                .parse()
+                .map_err(|e: DaftError| PyValueError::new_err(e.to_string()))?,
+            header,
+            null_value,
+        };
+        options
+            .validate()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(options)
+    }
+}
+
+impl_bincode_py_state_serialization!(CsvWriteOptions);
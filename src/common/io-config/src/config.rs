@@ -2,13 +2,16 @@ use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{AzureConfig, GCSConfig, HTTPConfig, S3Config};
+use crate::{AzureConfig, ByteTransform, GCSConfig, HTTPConfig, S3Config};
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct IOConfig {
     pub s3: S3Config,
     pub azure: AzureConfig,
     pub gcs: GCSConfig,
     pub http: HTTPConfig,
+    /// Transform of the bytes of all objects that are read and written, such as to decrypt and
+    /// encrypt them.
+    pub transform: Option<Box<dyn ByteTransform>>,
 }
 
 impl IOConfig {
@@ -31,6 +34,9 @@ impl IOConfig {
             "HTTP config = {{ {} }}",
             self.http.multiline_display().join(", ")
         ));
+        if let Some(transform) = &self.transform {
+            res.push(format!("Transform = {transform:?}"));
+        }
         res
     }
 }
@@ -6,6 +6,7 @@ mod config;
 mod gcs;
 mod http;
 mod s3;
+mod transform;
 
 use std::{
     fmt::{Debug, Display},
@@ -21,6 +22,7 @@ pub use crate::{
     gcs::GCSConfig,
     http::HTTPConfig,
     s3::{S3Config, S3Credentials},
+    transform::{ByteTransform, TransformError},
 };
 
 #[derive(Clone)]
@@ -11,10 +11,10 @@ use aws_credential_types::{
 use common_py_serde::{
     deserialize_py_object, impl_bincode_py_state_serialization, serialize_py_object,
 };
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyBytes};
 use serde::{Deserialize, Serialize};
 
-use crate::{config, s3::S3CredentialsProvider};
+use crate::{config, s3::S3CredentialsProvider, ByteTransform, TransformError};
 
 /// Create configurations to be used when accessing an S3-compatible system
 ///
@@ -129,6 +129,11 @@ pub struct GCSConfig {
 ///     s3: Configuration to use when accessing URLs with the `s3://` scheme
 ///     azure: Configuration to use when accessing URLs with the `az://` or `abfs://` scheme
 ///     gcs: Configuration to use when accessing URLs with the `gs://` or `gcs://` scheme
+///     transform: Transform of the bytes of all objects that are written and read, such as to encrypt and decrypt them
+///         for client-side encryption. Should have an `encode(path: str, data: bytes) -> bytes` method that's applied
+///         before objects are written and a `decode(path: str, data: bytes) -> bytes` method that's applied after
+///         objects are read. Reads of parts of objects read and decode whole objects, and files that are read and
+///         written through PyArrow instead of Daft's native I/O layer aren't transformed.
 /// Example:
 ///     >>> io_config = IOConfig(s3=S3Config(key_id="xxx", access_key="xxx", num_tries=10), azure=AzureConfig(anonymous=True), gcs=GCSConfig(...))
 ///     >>> daft.read_parquet(["s3://some-path", "az://some-other-path", "gs://path3"], io_config=io_config)
@@ -162,15 +167,17 @@ impl IOConfig {
         azure: Option<AzureConfig>,
         gcs: Option<GCSConfig>,
         http: Option<HTTPConfig>,
-    ) -> Self {
-        Self {
+        transform: Option<Bound<PyAny>>,
+    ) -> PyResult<Self> {
+        Ok(Self {
             config: config::IOConfig {
                 s3: s3.unwrap_or_default().config,
                 azure: azure.unwrap_or_default().config,
                 gcs: gcs.unwrap_or_default().config,
                 http: http.unwrap_or_default().config,
+                transform: transform.map(PyByteTransform::new_boxed).transpose()?,
             },
-        }
+        })
     }
 
     #[must_use]
@@ -180,8 +187,9 @@ impl IOConfig {
         azure: Option<AzureConfig>,
         gcs: Option<GCSConfig>,
         http: Option<HTTPConfig>,
-    ) -> Self {
-        Self {
+        transform: Option<Bound<PyAny>>,
+    ) -> PyResult<Self> {
+        Ok(Self {
             config: config::IOConfig {
                 s3: s3
                     .map(|s3| s3.config)
@@ -195,8 +203,12 @@ impl IOConfig {
                 http: http
                     .map(|http| http.config)
                     .unwrap_or_else(|| self.config.http.clone()),
+                transform: transform
+                    .map(PyByteTransform::new_boxed)
+                    .transpose()?
+                    .or_else(|| self.config.transform.clone()),
             },
-        }
+        })
     }
 
     pub fn __repr__(&self) -> PyResult<String> {
@@ -235,6 +247,16 @@ impl IOConfig {
         })
     }
 
+    /// Transform of the bytes of all objects that are written and read
+    #[getter]
+    pub fn transform(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        Ok(self.config.transform.as_ref().and_then(|t| {
+            t.as_any()
+                .downcast_ref::<PyByteTransform>()
+                .map(|t| t.transform.clone_ref(py))
+        }))
+    }
+
     pub fn __hash__(&self) -> PyResult<u64> {
         use std::{collections::hash_map::DefaultHasher, hash::Hash};
 
@@ -676,6 +698,81 @@ impl S3CredentialsProvider for PyS3CredentialsProvider {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PyByteTransform {
+    #[serde(
+        serialize_with = "serialize_py_object",
+        deserialize_with = "deserialize_py_object"
+    )]
+    pub transform: PyObject,
+    pub hash: isize,
+}
+
+impl PyByteTransform {
+    pub fn new_boxed(transform: Bound<PyAny>) -> PyResult<Box<dyn ByteTransform>> {
+        let hash = transform.hash()?;
+        Ok(Box::new(Self {
+            transform: transform.into(),
+            hash,
+        }))
+    }
+
+    fn call(&self, method: &str, path: &str, data: &[u8]) -> Result<Vec<u8>, TransformError> {
+        Python::with_gil(|py| {
+            let result = self
+                .transform
+                .bind(py)
+                .call_method1(method, (path, PyBytes::new_bound(py, data)))?;
+            Ok::<_, PyErr>(result.downcast::<PyBytes>()?.as_bytes().to_vec())
+        })
+        .map_err(|e| Box::new(e) as TransformError)
+    }
+}
+
+impl PartialEq for PyByteTransform {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+    }
+}
+
+impl Eq for PyByteTransform {}
+
+impl Hash for PyByteTransform {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+#[typetag::serde]
+impl ByteTransform for PyByteTransform {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ByteTransform> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn ByteTransform) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .map_or(false, |other| self == other)
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        self.hash(&mut state);
+    }
+
+    fn encode(&self, path: &str, data: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.call("encode", path, data)
+    }
+
+    fn decode(&self, path: &str, data: &[u8]) -> Result<Vec<u8>, TransformError> {
+        self.call("decode", path, data)
+    }
+}
+
 #[pymethods]
 impl AzureConfig {
     #[allow(clippy::too_many_arguments)]
@@ -0,0 +1,46 @@
+use std::{
+    any::Any,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+};
+
+pub type TransformError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Transforms the bytes of objects as they're written to and read from storage, such as to encrypt
+/// and decrypt them for client-side envelope encryption.
+///
+/// Objects are transformed as a whole, so reads of ranges of transformed objects read and decode
+/// the whole object before taking the range from it.
+#[typetag::serde(tag = "type")]
+pub trait ByteTransform: Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn clone_box(&self) -> Box<dyn ByteTransform>;
+    fn dyn_eq(&self, other: &dyn ByteTransform) -> bool;
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+
+    /// Transforms the bytes of an object before they're written to `path`.
+    fn encode(&self, path: &str, data: &[u8]) -> Result<Vec<u8>, TransformError>;
+
+    /// Transforms the bytes of an object after they're read from `path`, reversing [`Self::encode`].
+    fn decode(&self, path: &str, data: &[u8]) -> Result<Vec<u8>, TransformError>;
+}
+
+impl Clone for Box<dyn ByteTransform> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl PartialEq for Box<dyn ByteTransform> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other.as_ref())
+    }
+}
+
+impl Eq for Box<dyn ByteTransform> {}
+
+impl Hash for Box<dyn ByteTransform> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.dyn_hash(state);
+    }
+}
@@ -36,7 +36,7 @@ pub type PartitionId = usize;
 
 /// ported over from `daft/runners/partitioning.py`
 // TODO: port over the rest of the functionality
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PartitionMetadata {
     pub num_rows: usize,
     pub size_bytes: usize,
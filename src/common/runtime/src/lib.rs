@@ -193,6 +193,11 @@ pub fn get_io_runtime(multi_thread: bool) -> RuntimeRef {
     }
 }
 
+#[must_use]
+pub fn get_compute_pool_num_threads() -> usize {
+    *COMPUTE_RUNTIME_NUM_WORKER_THREADS
+}
+
 #[must_use]
 pub fn get_io_pool_num_threads() -> Option<usize> {
     match tokio::runtime::Handle::try_current() {
@@ -51,6 +51,21 @@ fn apply_partitioning_expr(expr: ExprRef, pfield: &PartitionField) -> Option<Exp
     }
 }
 
+/// The expression that computes the values of a partition field from the rows of a table with its
+/// source column, named after the partition field. Partition fields without a transform take the
+/// values of their source column as-is.
+pub fn partition_values_expr(pfield: &PartitionField) -> ExprRef {
+    let source = col(pfield
+        .source_field
+        .as_ref()
+        .map_or(pfield.field.name.as_str(), |s| s.name.as_str()));
+    let expr = match pfield.transform {
+        Some(PartitionTransform::Void) => null_lit().cast(&pfield.field.dtype),
+        _ => apply_partitioning_expr(source.clone(), pfield).unwrap_or(source),
+    };
+    expr.alias(pfield.field.name.as_str())
+}
+
 /// Grouping of clauses in a conjunctive predicate around partitioning semantics.
 pub struct PredicateGroups {
     // All partition-only filters, which can be applied directly to partition values and can be dropped from the
@@ -8,18 +8,22 @@ mod pushdowns;
 pub mod python;
 mod scan_operator;
 mod scan_task;
+mod term;
 pub mod test;
 
 use std::{fmt::Debug, hash::Hash, sync::Arc};
 
 use daft_schema::schema::SchemaRef;
-pub use expr_rewriter::{rewrite_predicate_for_partitioning, PredicateGroups};
+pub use expr_rewriter::{
+    partition_values_expr, rewrite_predicate_for_partitioning, PredicateGroups,
+};
 pub use partitioning::{PartitionField, PartitionTransform};
 pub use pushdowns::Pushdowns;
 #[cfg(feature = "python")]
 pub use python::register_modules;
 pub use scan_operator::{ScanOperator, ScanOperatorRef};
 pub use scan_task::{ScanTaskLike, ScanTaskLikeRef, SPLIT_AND_MERGE_PASS};
+pub use term::Term;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ScanState {
@@ -3,12 +3,12 @@ use pyo3::prelude::*;
 pub mod pylib {
     use std::sync::Arc;
 
-    use daft_dsl::python::PyExpr;
+    use daft_dsl::{python::PyExpr, LiteralValue};
     use daft_schema::python::field::PyField;
-    use pyo3::{prelude::*, pyclass};
+    use pyo3::{prelude::*, pyclass, types::PyTuple};
     use serde::{Deserialize, Serialize};
 
-    use crate::{PartitionField, PartitionTransform, Pushdowns};
+    use crate::{PartitionField, PartitionTransform, Pushdowns, Term};
 
     #[pyclass(module = "daft.daft", name = "PartitionField", frozen)]
     #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -132,6 +132,58 @@ pub mod pylib {
                 .as_ref()
                 .map(daft_dsl::optimization::get_required_columns)
         }
+
+        /// The predicates of the filters as terms of `daft.io.pushdowns`, leaving out those that
+        /// can't be translated into terms.
+        pub fn filter_terms(&self, py: Python) -> PyResult<Vec<PyObject>> {
+            let module = py.import_bound(pyo3::intern!(py, "daft.io.pushdowns"))?;
+            self.0
+                .filter_terms()
+                .iter()
+                .map(|term| term_to_py(&module, term))
+                .collect()
+        }
+
+        /// The predicates of the filters as a JSON array of terms, for sources outside of the
+        /// process.
+        #[must_use]
+        pub fn filter_terms_json(&self) -> String {
+            self.0.filter_terms_json()
+        }
+    }
+
+    fn term_to_py(module: &Bound<PyModule>, term: &Term) -> PyResult<PyObject> {
+        let py = module.py();
+        let term = match term {
+            Term::Reference(name) => module
+                .getattr(pyo3::intern!(py, "Reference"))?
+                .call1((name,))?,
+            Term::Literal(value) => {
+                let value = match value {
+                    LiteralValue::Boolean(b) => b.into_py(py),
+                    LiteralValue::Utf8(s) => s.into_py(py),
+                    LiteralValue::Int32(i) => i.into_py(py),
+                    LiteralValue::UInt32(i) => i.into_py(py),
+                    LiteralValue::Int64(i) => i.into_py(py),
+                    LiteralValue::UInt64(i) => i.into_py(py),
+                    LiteralValue::Float64(f) => f.into_py(py),
+                    _ => py.None(),
+                };
+                module
+                    .getattr(pyo3::intern!(py, "Literal"))?
+                    .call1((value,))?
+            }
+            Term::Expr { proc, args } => {
+                let args = args
+                    .iter()
+                    .map(|arg| term_to_py(module, arg))
+                    .collect::<PyResult<Vec<_>>>()?;
+                module
+                    .getattr(pyo3::intern!(py, "Expr"))?
+                    .call1((proc, PyTuple::new_bound(py, args)))?
+            }
+        };
+        Ok(term.unbind())
     }
 }
 
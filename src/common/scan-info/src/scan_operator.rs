@@ -5,6 +5,7 @@ use std::{
 };
 
 use common_error::DaftResult;
+use common_file_formats::FileFormat;
 use daft_schema::schema::SchemaRef;
 
 use crate::{PartitionField, Pushdowns, ScanTaskLikeRef};
@@ -32,6 +33,16 @@ pub trait ScanOperator: Send + Sync + Debug {
     fn can_absorb_limit(&self) -> bool;
     fn multiline_display(&self) -> Vec<String>;
 
+    /// Paths or glob patterns of the files that the operator reads, if it reads files.
+    fn file_paths(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Format of the files that the operator reads, if it reads files of a single format.
+    fn file_format(&self) -> Option<FileFormat> {
+        None
+    }
+
     /// If cfg provided, `to_scan_tasks` should apply the appropriate transformations
     /// (merging, splitting) to the outputted scan tasks
     fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>>;
@@ -0,0 +1,238 @@
+use common_error::{DaftError, DaftResult};
+use daft_algebra::boolean::split_conjunction;
+use daft_dsl::{binary_op, col, Expr, ExprRef, LiteralValue, Operator};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Number, Value};
+
+use crate::Pushdowns;
+
+/// An expression in a form that data sources outside of Daft, such as Python scan operators, can
+/// interpret without depending on Daft's expressions: references to columns, literals, and
+/// procedures applied to other terms.
+///
+/// Procedures are named after their operators, like `==`, `&` and `+`, or are one of `not`,
+/// `is_null`, `not_null`, `is_in` and `between`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Term {
+    Reference(String),
+    Literal(LiteralValue),
+    Expr { proc: String, args: Vec<Self> },
+}
+
+impl Term {
+    /// Translates an expression into a term, or returns `None` if the expression uses anything
+    /// other than columns, literals of primitive types, and the procedures that terms support.
+    #[must_use]
+    pub fn try_from_expr(expr: &ExprRef) -> Option<Self> {
+        let proc = |proc: &str, args: &[&ExprRef]| {
+            Some(Self::Expr {
+                proc: proc.to_string(),
+                args: args
+                    .iter()
+                    .map(|arg| Self::try_from_expr(arg))
+                    .collect::<Option<Vec<_>>>()?,
+            })
+        };
+        match expr.as_ref() {
+            Expr::Column(name) => Some(Self::Reference(name.to_string())),
+            Expr::Literal(
+                value @ (LiteralValue::Null
+                | LiteralValue::Boolean(_)
+                | LiteralValue::Utf8(_)
+                | LiteralValue::Int32(_)
+                | LiteralValue::UInt32(_)
+                | LiteralValue::Int64(_)
+                | LiteralValue::UInt64(_)
+                | LiteralValue::Float64(_)),
+            ) => Some(Self::Literal(value.clone())),
+            Expr::Alias(expr, _) => Self::try_from_expr(expr),
+            Expr::BinaryOp { op, left, right } => proc(&op.to_string(), &[left, right]),
+            Expr::Not(expr) => proc("not", &[expr]),
+            Expr::IsNull(expr) => proc("is_null", &[expr]),
+            Expr::NotNull(expr) => proc("not_null", &[expr]),
+            Expr::IsIn(expr, items) => proc(
+                "is_in",
+                &std::iter::once(expr).chain(items).collect::<Vec<_>>(),
+            ),
+            Expr::Between(expr, lower, upper) => proc("between", &[expr, lower, upper]),
+            _ => None,
+        }
+    }
+
+    /// Translates the term back into an expression.
+    pub fn to_expr(&self) -> DaftResult<ExprRef> {
+        match self {
+            Self::Reference(name) => Ok(col(name.as_str())),
+            Self::Literal(value) => Ok(Expr::Literal(value.clone()).into()),
+            Self::Expr { proc, args } => {
+                let mut args = args
+                    .iter()
+                    .map(Self::to_expr)
+                    .collect::<DaftResult<Vec<_>>>()?;
+                let num_args = |n: usize| {
+                    if args.len() == n {
+                        Ok(())
+                    } else {
+                        Err(DaftError::ValueError(format!(
+                            "Procedure {proc} of a term expects {n} arguments, but got {}",
+                            args.len()
+                        )))
+                    }
+                };
+                match proc.as_str() {
+                    "not" | "is_null" | "not_null" => {
+                        num_args(1)?;
+                        let arg = args.remove(0);
+                        Ok(match proc.as_str() {
+                            "not" => arg.not(),
+                            "is_null" => arg.is_null(),
+                            _ => arg.not_null(),
+                        })
+                    }
+                    "is_in" if !args.is_empty() => {
+                        let expr = args.remove(0);
+                        Ok(expr.is_in(args))
+                    }
+                    "between" => {
+                        num_args(3)?;
+                        let upper = args.pop().unwrap();
+                        let lower = args.pop().unwrap();
+                        Ok(args.pop().unwrap().between(lower, upper))
+                    }
+                    _ => {
+                        let op = operator(proc).ok_or_else(|| {
+                            DaftError::ValueError(format!("Unknown procedure of a term: {proc}"))
+                        })?;
+                        num_args(2)?;
+                        let right = args.pop().unwrap();
+                        Ok(binary_op(op, args.pop().unwrap(), right))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Serializes the term into JSON, so that it can be sent to sources outside of the process:
+    /// references are `{"ref": name}`, literals are `{"lit": value}` and procedures are
+    /// `{"proc": proc, "args": [...]}`.
+    ///
+    /// Returns `None` for terms with non-finite floats, which JSON can't represent.
+    #[must_use]
+    pub fn to_json(&self) -> Option<Value> {
+        Some(match self {
+            Self::Reference(name) => json!({ "ref": name }),
+            Self::Literal(value) => json!({
+                "lit": match value {
+                    LiteralValue::Boolean(b) => Value::Bool(*b),
+                    LiteralValue::Utf8(s) => Value::String(s.clone()),
+                    LiteralValue::Int32(i) => Value::from(*i),
+                    LiteralValue::UInt32(i) => Value::from(*i),
+                    LiteralValue::Int64(i) => Value::from(*i),
+                    LiteralValue::UInt64(i) => Value::from(*i),
+                    LiteralValue::Float64(f) => Value::Number(Number::from_f64(*f)?),
+                    _ => Value::Null,
+                }
+            }),
+            Self::Expr { proc, args } => json!({
+                "proc": proc,
+                "args": args.iter().map(Self::to_json).collect::<Option<Vec<_>>>()?,
+            }),
+        })
+    }
+
+    /// Deserializes a term from the JSON that [`Term::to_json`] serializes it into. Integers are
+    /// read as 64-bit integers, so the literals of terms that are read back can be wider than the
+    /// ones they were serialized from.
+    pub fn from_json(value: &Value) -> DaftResult<Self> {
+        let invalid = || DaftError::ValueError(format!("Invalid JSON for a term: {value}"));
+        let Value::Object(object) = value else {
+            return Err(invalid());
+        };
+        if let Some(name) = object.get("ref") {
+            return Ok(Self::Reference(
+                name.as_str().ok_or_else(invalid)?.to_string(),
+            ));
+        }
+        if let Some(lit) = object.get("lit") {
+            return Ok(Self::Literal(match lit {
+                Value::Null => LiteralValue::Null,
+                Value::Bool(b) => LiteralValue::Boolean(*b),
+                Value::String(s) => LiteralValue::Utf8(s.clone()),
+                Value::Number(n) => {
+                    if let Some(i) = n.as_i64() {
+                        LiteralValue::Int64(i)
+                    } else if let Some(i) = n.as_u64() {
+                        LiteralValue::UInt64(i)
+                    } else {
+                        LiteralValue::Float64(n.as_f64().ok_or_else(invalid)?)
+                    }
+                }
+                _ => return Err(invalid()),
+            }));
+        }
+        match (object.get("proc"), object.get("args")) {
+            (Some(Value::String(proc)), Some(Value::Array(args))) => Ok(Self::Expr {
+                proc: proc.clone(),
+                args: args
+                    .iter()
+                    .map(Self::from_json)
+                    .collect::<DaftResult<_>>()?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+fn operator(proc: &str) -> Option<Operator> {
+    Some(match proc {
+        "==" => Operator::Eq,
+        "!=" => Operator::NotEq,
+        "<" => Operator::Lt,
+        "<=" => Operator::LtEq,
+        ">" => Operator::Gt,
+        ">=" => Operator::GtEq,
+        "+" => Operator::Plus,
+        "-" => Operator::Minus,
+        "*" => Operator::Multiply,
+        "/" => Operator::TrueDivide,
+        "//" => Operator::FloorDivide,
+        "%" => Operator::Modulus,
+        "&" => Operator::And,
+        "|" => Operator::Or,
+        "^" => Operator::Xor,
+        "<<" => Operator::ShiftLeft,
+        ">>" => Operator::ShiftRight,
+        _ => return None,
+    })
+}
+
+impl Pushdowns {
+    /// The predicates of the filters of the pushdowns that can be translated into terms. Filters
+    /// are split into the predicates that they're conjunctions of, so that sources can apply the
+    /// ones that they support.
+    #[must_use]
+    pub fn filter_terms(&self) -> Vec<Term> {
+        self.filters
+            .as_ref()
+            .map(|filters| {
+                split_conjunction(filters)
+                    .iter()
+                    .filter_map(Term::try_from_expr)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The predicates of [`Pushdowns::filter_terms`] serialized into a JSON array, leaving out
+    /// those that can't be represented in JSON.
+    #[must_use]
+    pub fn filter_terms_json(&self) -> String {
+        Value::Array(
+            self.filter_terms()
+                .iter()
+                .filter_map(Term::to_json)
+                .collect(),
+        )
+        .to_string()
+    }
+}
@@ -0,0 +1,449 @@
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use snafu::{ResultExt, Snafu};
+use url::Url;
+
+use crate::metadata::{
+    CatalogConfig, CommitTableRequest, CommitTableResponse, ErrorResponse, ListNamespacesResponse,
+    ListTablesResponse, LoadTableResult, Namespace, TableIdent, TableMetadata, TableRequirement,
+    TableUpdate,
+};
+
+/// Separator of the levels of a nested namespace in a URL path, as required by the spec
+const NAMESPACE_SEPARATOR: &str = "\u{1f}";
+
+/// Query parameter of the token of the page of a listing to return, see `next-page-token`
+const PAGE_TOKEN_PARAM: &str = "pageToken";
+
+/// Initial delay before retrying a conflicting commit, doubled after every attempt
+const COMMIT_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Unable to create HTTP client: {}", source))]
+    UnableToCreateClient { source: reqwest::Error },
+
+    #[snafu(display("Invalid Iceberg REST catalog uri {}: {}", uri, source))]
+    InvalidUri {
+        uri: String,
+        source: url::ParseError,
+    },
+
+    #[snafu(display("Request to {} failed: {}", path, source))]
+    RequestFailed {
+        path: String,
+        source: reqwest::Error,
+    },
+
+    #[snafu(display(
+        "Iceberg REST catalog returned {} ({}) for {}: {}",
+        status,
+        error_type,
+        path,
+        message
+    ))]
+    UnexpectedStatus {
+        path: String,
+        status: StatusCode,
+        error_type: String,
+        message: String,
+    },
+
+    #[snafu(display(
+        "Commit to table {} failed after {} attempts because of concurrent commits",
+        table,
+        attempts
+    ))]
+    CommitConflict { table: String, attempts: usize },
+}
+
+impl Error {
+    fn is_status(&self, expected: StatusCode) -> bool {
+        matches!(self, Self::UnexpectedStatus { status, .. } if *status == expected)
+    }
+
+    /// Whether the error is because the requested namespace or table does not exist
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        self.is_status(StatusCode::NOT_FOUND)
+    }
+
+    /// Whether the error is because the requirements of a commit no longer hold
+    #[must_use]
+    pub fn is_conflict(&self) -> bool {
+        self.is_status(StatusCode::CONFLICT)
+    }
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Configuration of an [`IcebergRestClient`]
+#[derive(Debug, Clone, Default)]
+pub struct IcebergRestConfig {
+    /// Base uri of the catalog, e.g. `http://localhost:8181`
+    pub uri: String,
+    /// Warehouse to request from the catalog, if the catalog serves several
+    pub warehouse: Option<String>,
+    /// Bearer token sent with every request
+    pub token: Option<String>,
+}
+
+/// A client of the [Iceberg REST catalog](https://iceberg.apache.org/concepts/catalog/#rest-catalog)
+#[derive(Debug, Clone)]
+pub struct IcebergRestClient {
+    base_url: Url,
+    /// Path prefix returned by the catalog config endpoint, inserted after `v1/`
+    prefix: Option<String>,
+    token: Option<String>,
+    client: reqwest::Client,
+}
+
+impl IcebergRestClient {
+    /// Creates a client, fetching the catalog configuration to determine the path prefix to use.
+    pub async fn try_new(config: IcebergRestConfig) -> Result<Self> {
+        let mut uri = config.uri.clone();
+        if !uri.ends_with('/') {
+            uri.push('/');
+        }
+        let base_url = Url::parse(&uri).context(InvalidUriSnafu {
+            uri: config.uri.clone(),
+        })?;
+        if base_url.cannot_be_a_base() {
+            return Err(Error::InvalidUri {
+                uri: config.uri,
+                source: url::ParseError::RelativeUrlWithCannotBeABaseBase,
+            });
+        }
+        let client = reqwest::ClientBuilder::default()
+            .build()
+            .context(UnableToCreateClientSnafu)?;
+
+        let mut rest_client = Self {
+            base_url,
+            prefix: None,
+            token: config.token,
+            client,
+        };
+
+        let query = config
+            .warehouse
+            .map(|warehouse| vec![("warehouse".to_string(), warehouse)])
+            .unwrap_or_default();
+        let catalog_config: CatalogConfig = rest_client
+            .send(Method::GET, &["config"], &query, None::<&()>)
+            .await?;
+        rest_client.prefix = catalog_config
+            .overrides
+            .get("prefix")
+            .or_else(|| catalog_config.defaults.get("prefix"))
+            .cloned();
+
+        Ok(rest_client)
+    }
+
+    /// Lists the namespaces directly under `parent`, or the top-level namespaces if there is no parent
+    pub async fn list_namespaces(&self, parent: Option<&Namespace>) -> Result<Vec<Namespace>> {
+        let parent = parent.map(|parent| parent.join(NAMESPACE_SEPARATOR));
+        let mut namespaces = Vec::new();
+        let mut page_token = None;
+        loop {
+            let query = parent
+                .iter()
+                .map(|parent| ("parent".to_string(), parent.clone()))
+                .chain(page_token.map(|token| (PAGE_TOKEN_PARAM.to_string(), token)))
+                .collect::<Vec<_>>();
+            let response: ListNamespacesResponse = self
+                .send(
+                    Method::GET,
+                    &self.path(&["namespaces"]),
+                    &query,
+                    None::<&()>,
+                )
+                .await?;
+            namespaces.extend(response.namespaces);
+            page_token = response.next_page_token;
+            if page_token.is_none() {
+                return Ok(namespaces);
+            }
+        }
+    }
+
+    /// Lists the tables in a namespace
+    pub async fn list_tables(&self, namespace: &Namespace) -> Result<Vec<TableIdent>> {
+        let namespace = namespace.join(NAMESPACE_SEPARATOR);
+        let mut tables = Vec::new();
+        let mut page_token = None;
+        loop {
+            let query = page_token
+                .map(|token| vec![(PAGE_TOKEN_PARAM.to_string(), token)])
+                .unwrap_or_default();
+            let response: ListTablesResponse = self
+                .send(
+                    Method::GET,
+                    &self.path(&["namespaces", &namespace, "tables"]),
+                    &query,
+                    None::<&()>,
+                )
+                .await?;
+            tables.extend(response.identifiers);
+            page_token = response.next_page_token;
+            if page_token.is_none() {
+                return Ok(tables);
+            }
+        }
+    }
+
+    /// Loads the metadata of a table
+    pub async fn load_table(&self, table: &TableIdent) -> Result<LoadTableResult> {
+        let namespace = table.namespace.join(NAMESPACE_SEPARATOR);
+        self.send(
+            Method::GET,
+            &self.path(&["namespaces", &namespace, "tables", &table.name]),
+            &[],
+            None::<&()>,
+        )
+        .await
+    }
+
+    /// Commits updates to a table, which the catalog only applies if all requirements hold
+    pub async fn commit_table(
+        &self,
+        table: &TableIdent,
+        requirements: Vec<TableRequirement>,
+        updates: Vec<TableUpdate>,
+    ) -> Result<CommitTableResponse> {
+        let namespace = table.namespace.join(NAMESPACE_SEPARATOR);
+        let request = CommitTableRequest {
+            identifier: table.clone(),
+            requirements,
+            updates,
+        };
+        self.send(
+            Method::POST,
+            &self.path(&["namespaces", &namespace, "tables", &table.name]),
+            &[],
+            Some(&request),
+        )
+        .await
+    }
+
+    /// Commits to a table with optimistic concurrency.
+    ///
+    /// `build_commit` produces the requirements and updates from the latest table metadata. If
+    /// the commit conflicts with a concurrent one, the metadata is reloaded and the commit is
+    /// rebuilt and retried, up to `max_attempts` times in total.
+    pub async fn commit_table_with_retries<F>(
+        &self,
+        table: &TableIdent,
+        max_attempts: usize,
+        mut build_commit: F,
+    ) -> Result<CommitTableResponse>
+    where
+        F: FnMut(&TableMetadata) -> (Vec<TableRequirement>, Vec<TableUpdate>),
+    {
+        let mut delay = COMMIT_RETRY_BASE_DELAY;
+        for attempt in 1..=max_attempts {
+            let metadata = self.load_table(table).await?.metadata;
+            let (requirements, updates) = build_commit(&metadata);
+
+            match self.commit_table(table, requirements, updates).await {
+                Err(err) if err.is_conflict() && attempt < max_attempts => {
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                Err(err) if err.is_conflict() => break,
+                result => return result,
+            }
+        }
+        Err(Error::CommitConflict {
+            table: table.to_string(),
+            attempts: max_attempts,
+        })
+    }
+
+    /// Path segments of an endpoint, including the catalog prefix, which is a path of its own
+    /// segments if it contains slashes
+    fn path(&self, segments: &[&str]) -> Vec<String> {
+        self.prefix
+            .iter()
+            .flat_map(|prefix| prefix.split('/').filter(|segment| !segment.is_empty()))
+            .chain(segments.iter().copied())
+            .map(str::to_string)
+            .collect()
+    }
+
+    async fn send<T: DeserializeOwned, B: serde::Serialize>(
+        &self,
+        method: Method,
+        segments: &[impl AsRef<str>],
+        query: &[(String, String)],
+        body: Option<&B>,
+    ) -> Result<T> {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .expect("base url is checked to be a base when creating the client")
+            .pop_if_empty()
+            .push("v1")
+            .extend(segments);
+        let path = url.path().to_string();
+
+        let mut request = self.client.request(method, url).query(query);
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        let response = request
+            .send()
+            .await
+            .context(RequestFailedSnafu { path: path.clone() })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            let (error_type, message) = match serde_json::from_str::<ErrorResponse>(&body) {
+                Ok(ErrorResponse { error }) => (error.error_type, error.message),
+                Err(_) => ("Unknown".to_string(), body),
+            };
+            return Err(Error::UnexpectedStatus {
+                path,
+                status,
+                error_type,
+                message,
+            });
+        }
+
+        response.json().await.context(RequestFailedSnafu { path })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::json;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    /// Starts a mock catalog that answers each request with the status and JSON body that
+    /// `respond` returns for its path and query, returning the uri of the catalog and the paths
+    /// and queries it was requested with.
+    async fn mock_catalog(
+        respond: fn(&str) -> (u16, serde_json::Value),
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let uri = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                // The requests of the client have no body, so they end with the headers.
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let read = stream.read(&mut buf).await.unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..read]);
+                }
+                let request = String::from_utf8_lossy(&request);
+                let target = request.split(' ').nth(1).unwrap_or_default().to_string();
+                let (status, body) = respond(&target);
+                recorded.lock().unwrap().push(target);
+
+                let body = body.to_string();
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        (uri, requests)
+    }
+
+    fn not_found() -> (u16, serde_json::Value) {
+        (
+            404,
+            json!({"error": {"message": "not found", "type": "NoSuchTableException", "code": 404}}),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_lists_all_pages_under_prefix() {
+        let (uri, requests) = mock_catalog(|target| match target {
+            "/v1/config?warehouse=wh" => (200, json!({"overrides": {"prefix": "wh/main"}})),
+            "/v1/wh/main/namespaces" => (
+                200,
+                json!({"namespaces": [["a"]], "next-page-token": "p2"}),
+            ),
+            "/v1/wh/main/namespaces?pageToken=p2" => (200, json!({"namespaces": [["b"]]})),
+            "/v1/wh/main/namespaces/a/tables" => (
+                200,
+                json!({"identifiers": [{"namespace": ["a"], "name": "t1"}], "next-page-token": "p2"}),
+            ),
+            "/v1/wh/main/namespaces/a/tables?pageToken=p2" => (
+                200,
+                json!({"identifiers": [{"namespace": ["a"], "name": "t2"}]}),
+            ),
+            _ => not_found(),
+        })
+        .await;
+        let client = IcebergRestClient::try_new(IcebergRestConfig {
+            uri,
+            warehouse: Some("wh".to_string()),
+            token: None,
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            client.list_namespaces(None).await.unwrap(),
+            vec![vec!["a".to_string()], vec!["b".to_string()]]
+        );
+        let tables = client.list_tables(&vec!["a".to_string()]).await.unwrap();
+        assert_eq!(
+            tables.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec!["a.t1", "a.t2"]
+        );
+        assert_eq!(
+            requests.lock().unwrap().as_slice(),
+            [
+                "/v1/config?warehouse=wh",
+                "/v1/wh/main/namespaces",
+                "/v1/wh/main/namespaces?pageToken=p2",
+                "/v1/wh/main/namespaces/a/tables",
+                "/v1/wh/main/namespaces/a/tables?pageToken=p2",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_table() {
+        let (uri, _) = mock_catalog(|target| match target {
+            "/v1/config" => (200, json!({})),
+            _ => not_found(),
+        })
+        .await;
+        let client = IcebergRestClient::try_new(IcebergRestConfig {
+            uri,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        let table = TableIdent::parse("a.missing").unwrap();
+        let err = client.load_table(&table).await.unwrap_err();
+        assert!(err.is_not_found(), "{err}");
+        assert!(!err.is_conflict());
+    }
+}
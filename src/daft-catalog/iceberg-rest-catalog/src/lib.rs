@@ -0,0 +1,4 @@
+mod client;
+pub mod metadata;
+
+pub use client::{Error, IcebergRestClient, IcebergRestConfig, Result};
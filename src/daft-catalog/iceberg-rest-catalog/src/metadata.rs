@@ -0,0 +1,300 @@
+//! Types of the Iceberg REST catalog API that Daft reads and writes.
+//!
+//! Only the parts of the table metadata that Daft needs are modeled, see the
+//! [Iceberg REST catalog spec](https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml).
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A (possibly nested) namespace, e.g. `["accounting", "tax"]`
+pub type Namespace = Vec<String>;
+
+/// Identifier of a table within a namespace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableIdent {
+    pub namespace: Namespace,
+    pub name: String,
+}
+
+impl TableIdent {
+    /// Parses a dot-delimited identifier such as `"accounting.tax.paid"`, where the last
+    /// component is the table name and the others are the namespace.
+    #[must_use]
+    pub fn parse(identifier: &str) -> Option<Self> {
+        let mut parts = identifier
+            .split('.')
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        let name = parts.pop()?;
+        if name.is_empty() || parts.is_empty() {
+            return None;
+        }
+        Some(Self {
+            namespace: parts,
+            name,
+        })
+    }
+}
+
+impl std::fmt::Display for TableIdent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.namespace.join("."), self.name)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CatalogConfig {
+    #[serde(default)]
+    pub defaults: HashMap<String, String>,
+    #[serde(default)]
+    pub overrides: HashMap<String, String>,
+}
+
+/// A page of namespaces, followed by more if there is a `next-page-token`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ListNamespacesResponse {
+    pub namespaces: Vec<Namespace>,
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+}
+
+/// A page of tables, followed by more if there is a `next-page-token`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ListTablesResponse {
+    pub identifiers: Vec<TableIdent>,
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LoadTableResult {
+    pub metadata_location: Option<String>,
+    pub metadata: TableMetadata,
+    #[serde(default)]
+    pub config: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CommitTableResponse {
+    pub metadata_location: String,
+    pub metadata: TableMetadata,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableMetadata {
+    pub format_version: u8,
+    pub table_uuid: String,
+    pub location: String,
+    pub last_updated_ms: Option<i64>,
+    pub last_sequence_number: Option<i64>,
+    pub current_schema_id: Option<i64>,
+    /// Schemas are kept as JSON, and converted to Daft schemas when the table is scanned
+    #[serde(default)]
+    pub schemas: Vec<serde_json::Value>,
+    /// The only schema of tables of format version 1 that don't list their schemas
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<serde_json::Value>,
+    pub default_spec_id: Option<i64>,
+    /// Partition specs are kept as JSON like the schemas they refer to
+    #[serde(default)]
+    pub partition_specs: Vec<serde_json::Value>,
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub refs: HashMap<String, SnapshotReference>,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+impl TableMetadata {
+    /// The snapshot the table currently points to, if the table has any data
+    #[must_use]
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        // format version 1 uses -1 to signal that there is no current snapshot
+        self.current_snapshot_id
+            .filter(|id| *id >= 0)
+            .and_then(|id| self.snapshot(id))
+    }
+
+    /// The schema of the table, as JSON
+    #[must_use]
+    pub fn current_schema(&self) -> Option<&serde_json::Value> {
+        match self.current_schema_id {
+            Some(id) => self
+                .schemas
+                .iter()
+                .find(|schema| schema["schema-id"].as_i64() == Some(id)),
+            None => self.schema.as_ref().or(self.schemas.last()),
+        }
+    }
+
+    #[must_use]
+    pub fn snapshot(&self, snapshot_id: i64) -> Option<&Snapshot> {
+        self.snapshots.iter().find(|s| s.snapshot_id == snapshot_id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Snapshot {
+    pub snapshot_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_snapshot_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence_number: Option<i64>,
+    pub timestamp_ms: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest_list: Option<String>,
+    #[serde(default)]
+    pub summary: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SnapshotReference {
+    pub snapshot_id: i64,
+    #[serde(rename = "type")]
+    pub ref_type: String,
+}
+
+/// Assertions the catalog checks before applying a commit, see `TableRequirement` in the spec
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum TableRequirement {
+    AssertTableUuid {
+        uuid: String,
+    },
+    AssertRefSnapshotId {
+        #[serde(rename = "ref")]
+        ref_name: String,
+        #[serde(rename = "snapshot-id")]
+        snapshot_id: Option<i64>,
+    },
+    AssertCurrentSchemaId {
+        #[serde(rename = "current-schema-id")]
+        current_schema_id: i64,
+    },
+    AssertDefaultSpecId {
+        #[serde(rename = "default-spec-id")]
+        default_spec_id: i64,
+    },
+}
+
+/// Changes applied to the table metadata by a commit, see `TableUpdate` in the spec
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "kebab-case")]
+pub enum TableUpdate {
+    AddSnapshot {
+        snapshot: Snapshot,
+    },
+    SetSnapshotRef {
+        #[serde(rename = "ref-name")]
+        ref_name: String,
+        #[serde(rename = "type")]
+        ref_type: String,
+        #[serde(rename = "snapshot-id")]
+        snapshot_id: i64,
+    },
+    SetProperties {
+        updates: HashMap<String, String>,
+    },
+    RemoveProperties {
+        removals: Vec<String>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct CommitTableRequest {
+    pub identifier: TableIdent,
+    pub requirements: Vec<TableRequirement>,
+    pub updates: Vec<TableUpdate>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ErrorResponse {
+    pub error: ErrorModel,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ErrorModel {
+    pub message: String,
+    #[serde(rename = "type")]
+    pub error_type: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_table_ident() {
+        assert_eq!(
+            TableIdent::parse("accounting.tax.paid"),
+            Some(TableIdent {
+                namespace: vec!["accounting".to_string(), "tax".to_string()],
+                name: "paid".to_string(),
+            })
+        );
+        assert_eq!(TableIdent::parse("paid"), None);
+        assert_eq!(TableIdent::parse("accounting."), None);
+    }
+
+    #[test]
+    fn test_deserialize_load_table_result() {
+        let json = r#"{
+            "metadata-location": "s3://bucket/tbl/metadata/00001.metadata.json",
+            "metadata": {
+                "format-version": 2,
+                "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+                "location": "s3://bucket/tbl",
+                "current-schema-id": 0,
+                "schemas": [{"type": "struct", "schema-id": 0, "fields": []}],
+                "current-snapshot-id": 2,
+                "snapshots": [
+                    {"snapshot-id": 1, "timestamp-ms": 10, "manifest-list": "s3://bucket/tbl/metadata/snap-1.avro", "summary": {"operation": "append"}},
+                    {"snapshot-id": 2, "parent-snapshot-id": 1, "timestamp-ms": 20, "manifest-list": "s3://bucket/tbl/metadata/snap-2.avro", "summary": {"operation": "append"}}
+                ],
+                "refs": {"main": {"snapshot-id": 2, "type": "branch"}},
+                "properties": {"owner": "daft"}
+            }
+        }"#;
+        let result: LoadTableResult = serde_json::from_str(json).unwrap();
+        let snapshot = result.metadata.current_snapshot().unwrap();
+        assert_eq!(snapshot.snapshot_id, 2);
+        assert_eq!(snapshot.parent_snapshot_id, Some(1));
+        assert_eq!(result.metadata.refs["main"].snapshot_id, 2);
+    }
+
+    #[test]
+    fn test_no_current_snapshot() {
+        let json = r#"{
+            "format-version": 1,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": "s3://bucket/tbl",
+            "current-snapshot-id": -1
+        }"#;
+        let metadata: TableMetadata = serde_json::from_str(json).unwrap();
+        assert!(metadata.current_snapshot().is_none());
+    }
+
+    #[test]
+    fn test_serialize_requirements() {
+        let requirement = TableRequirement::AssertRefSnapshotId {
+            ref_name: "main".to_string(),
+            snapshot_id: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&requirement).unwrap(),
+            serde_json::json!({"type": "assert-ref-snapshot-id", "ref": "main", "snapshot-id": null})
+        );
+    }
+}
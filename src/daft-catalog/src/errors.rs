@@ -21,6 +21,12 @@ pub enum Error {
     ))]
     InvalidTableName { name: String },
 
+    #[snafu(display("Error from {} catalog: {}", catalog_type, message))]
+    CatalogBackend {
+        catalog_type: String,
+        message: String,
+    },
+
     #[cfg(feature = "python")]
     #[snafu(display("Python error during {}: {}", context, source))]
     PythonError {
@@ -36,7 +42,8 @@ impl From<Error> for common_error::DaftError {
         match &err {
             Error::TableNotFound { .. }
             | Error::CatalogNotFound { .. }
-            | Error::InvalidTableName { .. } => {
+            | Error::InvalidTableName { .. }
+            | Error::CatalogBackend { .. } => {
                 common_error::DaftError::CatalogError(err.to_string())
             }
             #[cfg(feature = "python")]
@@ -17,6 +17,7 @@ use errors::{Error, Result};
 pub mod global_catalog {
     use std::sync::{Arc, RwLock};
 
+    use daft_logical_plan::LogicalPlanBuilder;
     use lazy_static::lazy_static;
 
     use crate::{DaftMetaCatalog, DataCatalog};
@@ -41,6 +42,14 @@ pub mod global_catalog {
             .unwrap()
             .unregister_catalog(name)
     }
+
+    /// Reads a table from the global DaftMetaCatalog, see [`DaftMetaCatalog::read_table`]
+    pub fn read_table(table_identifier: &str) -> crate::errors::Result<LogicalPlanBuilder> {
+        GLOBAL_DAFT_META_CATALOG
+            .read()
+            .unwrap()
+            .read_table(table_identifier)
+    }
 }
 
 /// Name of the default catalog
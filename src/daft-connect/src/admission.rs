@@ -0,0 +1,323 @@
+//! Admission control of the queries that the connect server executes.
+//!
+//! Queries beyond the server-wide or per-session limit of concurrently running queries wait in a
+//! queue, from which sessions take turns so that one session submitting many queries can't starve
+//! the others. Once the queue is full, further queries are rejected with `UNAVAILABLE`, which
+//! Spark Connect clients retry with backoff.
+
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use tokio::sync::oneshot;
+use tonic::Status;
+use uuid::Uuid;
+
+/// Limits of the [`AdmissionController`], which are all unlimited if `None`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AdmissionConfig {
+    /// Maximum number of queries running at once across all sessions
+    pub max_concurrent_queries: Option<usize>,
+    /// Maximum number of queries running at once in a single session
+    pub max_concurrent_queries_per_session: Option<usize>,
+    /// Maximum number of queries waiting to run, beyond which queries are rejected
+    pub max_queued_queries: Option<usize>,
+}
+
+#[derive(Default)]
+struct State {
+    running: usize,
+    running_per_session: HashMap<Uuid, usize>,
+    /// Waiting queries of each session, in the order they arrived
+    queued: HashMap<Uuid, VecDeque<oneshot::Sender<AdmissionPermit>>>,
+    /// Sessions with waiting queries, in the order they take turns
+    turns: VecDeque<Uuid>,
+    num_queued: usize,
+}
+
+/// Decides when the queries of each session may run.
+///
+/// Waiting queries are admitted as soon as capacity frees up, so a query that has capacity
+/// never overtakes a waiting query that could run instead.
+#[derive(Default)]
+pub struct AdmissionController {
+    config: AdmissionConfig,
+    state: Mutex<State>,
+}
+
+impl AdmissionController {
+    pub fn new(config: AdmissionConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Waits until a query of `session` may run, and returns the permit that it runs under
+    ///
+    /// Fails with `UNAVAILABLE` if the query would have to wait but the queue is full.
+    pub async fn admit(self: &Arc<Self>, session: Uuid) -> Result<AdmissionPermit, Status> {
+        let receiver = {
+            let mut state = self.state.lock().unwrap();
+            if self.has_capacity(&state, session) {
+                return Ok(self.grant(&mut state, session));
+            }
+
+            if let Some(max_queued_queries) = self.config.max_queued_queries {
+                if state.num_queued >= max_queued_queries {
+                    Self::prune(&mut state);
+                }
+                if state.num_queued >= max_queued_queries {
+                    return Err(Status::unavailable(format!(
+                        "Too many queries are waiting to run ({}), retry later",
+                        state.num_queued
+                    )));
+                }
+            }
+
+            let (sender, receiver) = oneshot::channel();
+            let queue = state.queued.entry(session).or_default();
+            if queue.is_empty() {
+                state.turns.push_back(session);
+            }
+            queue.push_back(sender);
+            state.num_queued += 1;
+            receiver
+        };
+
+        receiver
+            .await
+            .map_err(|_| Status::aborted("The server shut down before the query could run"))
+    }
+
+    /// Number of queries that are running
+    pub fn num_running(&self) -> usize {
+        self.state.lock().unwrap().running
+    }
+
+    /// Number of queries that are waiting to run
+    pub fn num_queued(&self) -> usize {
+        self.state.lock().unwrap().num_queued
+    }
+
+    fn has_capacity(&self, state: &State, session: Uuid) -> bool {
+        let running_in_session = state
+            .running_per_session
+            .get(&session)
+            .copied()
+            .unwrap_or(0);
+        self.config
+            .max_concurrent_queries
+            .map_or(true, |max| state.running < max)
+            && self
+                .config
+                .max_concurrent_queries_per_session
+                .map_or(true, |max| running_in_session < max)
+    }
+
+    fn grant(self: &Arc<Self>, state: &mut State, session: Uuid) -> AdmissionPermit {
+        state.running += 1;
+        *state.running_per_session.entry(session).or_default() += 1;
+        AdmissionPermit {
+            controller: self.clone(),
+            session,
+        }
+    }
+
+    fn release(self: &Arc<Self>, session: Uuid) {
+        let mut unclaimed = Vec::new();
+        {
+            let mut state = self.state.lock().unwrap();
+            state.running -= 1;
+            if let Entry::Occupied(mut running) = state.running_per_session.entry(session) {
+                *running.get_mut() -= 1;
+                if *running.get() == 0 {
+                    running.remove();
+                }
+            }
+            self.dispatch(&mut state, &mut unclaimed);
+        }
+        // The permits of queries that stopped waiting release their capacity again, which needs
+        // the lock
+        drop(unclaimed);
+    }
+
+    /// Admits waiting queries while there is capacity, with sessions taking turns
+    fn dispatch(self: &Arc<Self>, state: &mut State, unclaimed: &mut Vec<AdmissionPermit>) {
+        let mut num_blocked = 0;
+        while num_blocked < state.turns.len() {
+            let session = state.turns.pop_front().unwrap();
+            if !self.has_capacity(state, session) {
+                state.turns.push_back(session);
+                num_blocked += 1;
+                continue;
+            }
+            num_blocked = 0;
+
+            let queue = state.queued.get_mut(&session).unwrap();
+            let sender = queue.pop_front().unwrap();
+            if queue.is_empty() {
+                state.queued.remove(&session);
+            } else {
+                state.turns.push_back(session);
+            }
+            state.num_queued -= 1;
+
+            let permit = self.grant(state, session);
+            if let Err(permit) = sender.send(permit) {
+                unclaimed.push(permit);
+            }
+        }
+    }
+
+    /// Forgets the queries that stopped waiting, e.g. because their client went away
+    fn prune(state: &mut State) {
+        let mut num_queued = 0;
+        state.queued.retain(|_, queue| {
+            queue.retain(|sender| !sender.is_closed());
+            num_queued += queue.len();
+            !queue.is_empty()
+        });
+        let queued = &state.queued;
+        state.turns.retain(|session| queued.contains_key(session));
+        state.num_queued = num_queued;
+    }
+}
+
+/// Lets a query run until it is dropped, see [`AdmissionController::admit`]
+pub struct AdmissionPermit {
+    controller: Arc<AdmissionController>,
+    session: Uuid,
+}
+
+impl Drop for AdmissionPermit {
+    fn drop(&mut self) {
+        self.controller.release(self.session);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+
+    use super::*;
+
+    fn controller(
+        max_concurrent_queries: Option<usize>,
+        max_concurrent_queries_per_session: Option<usize>,
+        max_queued_queries: Option<usize>,
+    ) -> Arc<AdmissionController> {
+        Arc::new(AdmissionController::new(AdmissionConfig {
+            max_concurrent_queries,
+            max_concurrent_queries_per_session,
+            max_queued_queries,
+        }))
+    }
+
+    async fn try_admit(
+        controller: &Arc<AdmissionController>,
+        session: Uuid,
+    ) -> Option<AdmissionPermit> {
+        timeout(Duration::from_millis(50), controller.admit(session))
+            .await
+            .ok()
+            .map(Result::unwrap)
+    }
+
+    #[tokio::test]
+    async fn test_unlimited_by_default() {
+        let controller = Arc::new(AdmissionController::new(AdmissionConfig::default()));
+        let session = Uuid::new_v4();
+
+        let mut permits = Vec::new();
+        for _ in 0..100 {
+            permits.push(controller.admit(session).await.unwrap());
+        }
+        assert_eq!(controller.num_running(), 100);
+
+        drop(permits);
+        assert_eq!(controller.num_running(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_per_session_limit() {
+        let controller = controller(None, Some(1), None);
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let permit = controller.admit(a).await.unwrap();
+        assert!(try_admit(&controller, a).await.is_none());
+        // Other sessions are unaffected by the limit of the first
+        let _other = controller.admit(b).await.unwrap();
+
+        let waiting = tokio::spawn({
+            let controller = controller.clone();
+            async move { controller.admit(a).await }
+        });
+        tokio::task::yield_now().await;
+        drop(permit);
+        let _permit = waiting.await.unwrap().unwrap();
+        assert_eq!(controller.num_running(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_once_queue_is_full() {
+        let controller = controller(Some(1), None, Some(1));
+        let session = Uuid::new_v4();
+
+        let _permit = controller.admit(session).await.unwrap();
+        let waiting = tokio::spawn({
+            let controller = controller.clone();
+            async move { controller.admit(session).await }
+        });
+        while controller.num_queued() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        let Err(status) = controller.admit(session).await else {
+            panic!("Expected the query to be rejected");
+        };
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+
+        // Queries that stop waiting free up their place in the queue
+        waiting.abort();
+        let _ = waiting.await;
+        assert!(try_admit(&controller, session).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sessions_take_turns() {
+        let controller = controller(Some(1), None, None);
+        let (heavy, light) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let permit = controller.admit(heavy).await.unwrap();
+        let (order_tx, mut order_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut waiting = Vec::new();
+        for (i, session) in [heavy, heavy, heavy, light].into_iter().enumerate() {
+            let controller = controller.clone();
+            let order_tx = order_tx.clone();
+            waiting.push(tokio::spawn(async move {
+                let _permit = controller.admit(session).await.unwrap();
+                order_tx.send(i).unwrap();
+            }));
+            while controller.num_queued() <= i {
+                tokio::task::yield_now().await;
+            }
+        }
+
+        drop(permit);
+        for handle in waiting {
+            handle.await.unwrap();
+        }
+        drop(order_tx);
+
+        let mut order = Vec::new();
+        while let Some(i) = order_rx.recv().await {
+            order.push(i);
+        }
+        // The light session runs before the heavy session's second query, despite arriving last
+        assert_eq!(order, vec![0, 3, 1, 2]);
+    }
+}
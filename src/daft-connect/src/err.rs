@@ -1,3 +1,9 @@
+use std::collections::HashMap;
+
+use common_error::{DaftError, ErrorCategory};
+use prost::Message;
+use tonic::{Code, Status};
+
 #[macro_export]
 macro_rules! invalid_argument_err {
     ($arg: tt) => {{
@@ -22,3 +28,115 @@ macro_rules! not_found_err {
         Err(::tonic::Status::not_found(msg))
     }};
 }
+
+/// `google.rpc.Status`, which carries the details of an error in its gRPC trailers
+#[derive(Clone, PartialEq, prost::Message)]
+struct RpcStatus {
+    #[prost(int32, tag = "1")]
+    code: i32,
+    #[prost(string, tag = "2")]
+    message: String,
+    #[prost(message, repeated, tag = "3")]
+    details: Vec<prost_types::Any>,
+}
+
+/// `google.rpc.ErrorInfo`, from which Spark Connect clients raise the matching PySpark exception
+#[derive(Clone, PartialEq, prost::Message)]
+struct ErrorInfo {
+    #[prost(string, tag = "1")]
+    reason: String,
+    #[prost(string, tag = "2")]
+    domain: String,
+    #[prost(map = "string, string", tag = "3")]
+    metadata: HashMap<String, String>,
+}
+
+/// The gRPC code and the Spark exception class that Spark Connect clients raise for an error
+fn classify(error: &DaftError) -> (Code, &'static str) {
+    match (error.root(), error.category()) {
+        (DaftError::NotImplemented(_), _) => (
+            Code::Unimplemented,
+            "java.lang.UnsupportedOperationException",
+        ),
+        (_, ErrorCategory::User) => (Code::InvalidArgument, "java.lang.IllegalArgumentException"),
+        (_, ErrorCategory::Plan) => (
+            Code::InvalidArgument,
+            "org.apache.spark.sql.AnalysisException",
+        ),
+        (_, ErrorCategory::Io) => (Code::Unavailable, "org.apache.spark.SparkRuntimeException"),
+        (_, ErrorCategory::Execution) => (Code::Internal, "org.apache.spark.SparkRuntimeException"),
+        (_, ErrorCategory::Internal) => (Code::Internal, "org.apache.spark.SparkException"),
+    }
+}
+
+/// Converts an error into a status with the Spark error details of the [`DaftError`] that caused
+/// it, if any, so that clients raise the matching exception, such as an `AnalysisException` for a
+/// missing column.
+pub fn status_from_report(report: &eyre::Report) -> Status {
+    let message = format!("Error in Daft server: {report:?}");
+    let Some(error) = report
+        .chain()
+        .find_map(|error| error.downcast_ref::<DaftError>())
+    else {
+        return Status::internal(message);
+    };
+
+    let (code, class) = classify(error);
+    let info = ErrorInfo {
+        reason: class.to_string(),
+        domain: "org.apache.spark".to_string(),
+        metadata: HashMap::from([
+            ("classes".to_string(), format!("[\"{class}\"]")),
+            ("errorClass".to_string(), error.code().to_string()),
+            ("category".to_string(), error.category().to_string()),
+        ]),
+    };
+    let details = RpcStatus {
+        code: code as i32,
+        message: message.clone(),
+        details: vec![prost_types::Any {
+            type_url: "type.googleapis.com/google.rpc.ErrorInfo".to_string(),
+            value: info.encode_to_vec(),
+        }],
+    };
+    Status::with_details(code, message, details.encode_to_vec().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use eyre::WrapErr;
+
+    use super::*;
+
+    fn error_info(status: &Status) -> ErrorInfo {
+        let details = RpcStatus::decode(status.details()).unwrap();
+        assert_eq!(details.code, status.code() as i32);
+        assert_eq!(details.message, status.message());
+        ErrorInfo::decode(details.details[0].value.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_status_from_daft_error() {
+        let report = eyre::Report::new(
+            DaftError::FieldNotFound("Column \"a\" not found".to_string()).context("projection"),
+        )
+        .wrap_err("Failed to translate relation");
+        let status = status_from_report(&report);
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        let info = error_info(&status);
+        assert_eq!(info.reason, "org.apache.spark.sql.AnalysisException");
+        assert_eq!(info.metadata["errorClass"], "DAFT_FIELD_NOT_FOUND");
+        assert_eq!(info.metadata["category"], "plan");
+
+        let status = status_from_report(&eyre::Report::new(DaftError::not_implemented("pivot")));
+        assert_eq!(status.code(), Code::Unimplemented);
+    }
+
+    #[test]
+    fn test_status_from_other_error() {
+        let status = status_from_report(&eyre::eyre!("boom"));
+        assert_eq!(status.code(), Code::Internal);
+        assert!(status.details().is_empty());
+    }
+}
@@ -6,8 +6,11 @@
 #![feature(stmt_expr_attributes)]
 #![feature(try_trait_v2_residual)]
 
+use std::sync::Arc;
+
 use dashmap::DashMap;
 use eyre::Context;
+use futures::StreamExt;
 #[cfg(feature = "python")]
 use pyo3::types::PyModuleMethods;
 use spark_connect::{
@@ -25,10 +28,18 @@ use tonic::{transport::Server, Request, Response, Status};
 use tracing::info;
 use uuid::Uuid;
 
-use crate::session::Session;
+pub use crate::admission::AdmissionConfig;
+use crate::{
+    admission::{AdmissionController, AdmissionPermit},
+    metrics::{MetricsLayer, ServerMetrics},
+    op::execute::ExecuteStream,
+    session::Session,
+};
 
+mod admission;
 mod config;
 mod err;
+mod metrics;
 mod op;
 
 mod session;
@@ -39,6 +50,7 @@ pub mod util;
 pub struct ConnectionHandle {
     shutdown_signal: Option<tokio::sync::oneshot::Sender<()>>,
     port: u16,
+    metrics_port: Option<u16>,
 }
 
 #[cfg_attr(feature = "python", pyo3::pymethods)]
@@ -53,16 +65,54 @@ impl ConnectionHandle {
     pub fn port(&self) -> u16 {
         self.port
     }
+
+    /// Port of the Prometheus `/metrics` endpoint, if the server was started with one
+    pub fn metrics_port(&self) -> Option<u16> {
+        self.metrics_port
+    }
 }
 
 pub fn start(addr: &str) -> eyre::Result<ConnectionHandle> {
+    start_with_metrics(addr, None)
+}
+
+/// Starts the server, additionally serving Prometheus metrics on `GET /metrics` at `metrics_addr`
+/// (e.g. `"0.0.0.0:9090"`) if given
+pub fn start_with_metrics(
+    addr: &str,
+    metrics_addr: Option<&str>,
+) -> eyre::Result<ConnectionHandle> {
+    start_with_config(addr, metrics_addr, AdmissionConfig::default())
+}
+
+/// Starts the server like [`start_with_metrics`], limiting how many queries run at once by
+/// `admission`
+pub fn start_with_config(
+    addr: &str,
+    metrics_addr: Option<&str>,
+    admission: AdmissionConfig,
+) -> eyre::Result<ConnectionHandle> {
     info!("Daft-Connect server listening on {addr}");
     let addr = util::parse_spark_connect_address(addr)?;
 
     let listener = std::net::TcpListener::bind(addr)?;
     let port = listener.local_addr()?.port();
 
-    let service = DaftSparkConnectService::default();
+    let metrics_listener = metrics_addr
+        .map(|metrics_addr| {
+            std::net::TcpListener::bind(metrics_addr)
+                .wrap_err_with(|| format!("Failed to bind metrics endpoint to {metrics_addr}"))
+        })
+        .transpose()?;
+    let metrics_port = metrics_listener
+        .as_ref()
+        .map(|listener| listener.local_addr().map(|addr| addr.port()))
+        .transpose()?;
+
+    let service = DaftSparkConnectService::new(admission);
+    let server_metrics = service.metrics.clone();
+    let sessions = service.client_to_session.clone();
+    let admission_controller = service.admission.clone();
 
     info!("Daft-Connect server listening on {addr}");
 
@@ -71,6 +121,7 @@ pub fn start(addr: &str) -> eyre::Result<ConnectionHandle> {
     let handle = ConnectionHandle {
         shutdown_signal: Some(shutdown_signal),
         port,
+        metrics_port,
     };
 
     std::thread::spawn(move || {
@@ -90,19 +141,36 @@ pub fn start(addr: &str) -> eyre::Result<ConnectionHandle> {
                 }
             };
 
-            let result = tokio::select! {
+            let serve_metrics = async {
+                let Some(listener) = metrics_listener else {
+                    return std::future::pending::<eyre::Result<()>>().await;
+                };
+                listener.set_nonblocking(true)?;
+                let listener = tokio::net::TcpListener::from_std(listener)
+                    .wrap_err("Failed to create TcpListener for the metrics endpoint")?;
+                info!("Daft-Connect metrics served on {:?}", listener.local_addr());
+                metrics::serve(listener, move || {
+                    server_metrics.render(sessions.len(), admission_controller.num_queued())
+                })
+                .await;
+                Ok::<_, eyre::Report>(())
+            };
+
+            tokio::select! {
                 result = Server::builder()
+                    .layer(MetricsLayer::new(service.metrics.clone()))
                     .add_service(SparkConnectServiceServer::new(service))
                     .serve_with_incoming(incoming)=> {
+                    result.wrap_err_with(|| format!("Failed to start server on {addr}"))
+                }
+                result = serve_metrics => {
                     result
                 }
                 _ = shutdown_receiver => {
                     info!("Received shutdown signal");
                     Ok(())
                 }
-            };
-
-            result.wrap_err_with(|| format!("Failed to start server on {addr}"))
+            }
         });
 
         if let Err(e) = result {
@@ -117,19 +185,40 @@ pub fn start(addr: &str) -> eyre::Result<ConnectionHandle> {
 
 #[derive(Default)]
 pub struct DaftSparkConnectService {
-    client_to_session: DashMap<Uuid, Session>, // To track session data
+    client_to_session: Arc<DashMap<Uuid, Session>>, // To track session data
+    metrics: Arc<ServerMetrics>,
+    admission: Arc<AdmissionController>,
 }
 
 impl DaftSparkConnectService {
+    pub fn new(admission: AdmissionConfig) -> Self {
+        Self {
+            admission: Arc::new(AdmissionController::new(admission)),
+            ..Default::default()
+        }
+    }
+
+    /// Counts the query as running until its result stream is exhausted or dropped, which is
+    /// also when it gives up its `permit`
+    fn track_query(&self, stream: ExecuteStream, permit: AdmissionPermit) -> ExecuteStream {
+        let query = self.metrics.start_query();
+        Box::pin(stream.map(move |item| {
+            let _query = &query;
+            let _permit = &permit;
+            item
+        }))
+    }
+
+    fn parse_session_id(session_id: &str) -> Result<Uuid, Status> {
+        Uuid::parse_str(session_id)
+            .map_err(|_| Status::invalid_argument("Invalid session_id format, must be a UUID"))
+    }
+
     fn get_session(
         &self,
         session_id: &str,
     ) -> Result<dashmap::mapref::one::RefMut<Uuid, Session>, Status> {
-        let Ok(uuid) = Uuid::parse_str(session_id) else {
-            return Err(Status::invalid_argument(
-                "Invalid session_id format, must be a UUID",
-            ));
-        };
+        let uuid = Self::parse_session_id(session_id)?;
 
         let res = self
             .client_to_session
@@ -156,6 +245,13 @@ impl SparkConnectService for DaftSparkConnectService {
     ) -> Result<Response<Self::ExecutePlanStream>, Status> {
         let request = request.into_inner();
 
+        // Wait to be admitted before taking the session, which would otherwise block the
+        // other requests to its shard of the session map while the query is queued
+        let permit = self
+            .admission
+            .admit(Self::parse_session_id(&request.session_id)?)
+            .await?;
+
         let session = self.get_session(&request.session_id)?;
 
         let Some(operation) = request.operation_id else {
@@ -176,7 +272,7 @@ impl SparkConnectService for DaftSparkConnectService {
         match plan {
             OpType::Root(relation) => {
                 let result = session.handle_root_command(relation, operation).await?;
-                return Ok(Response::new(result));
+                return Ok(Response::new(self.track_query(result, permit)));
             }
             OpType::Command(command) => {
                 let Some(command) = command.command_type else {
@@ -189,7 +285,7 @@ impl SparkConnectService for DaftSparkConnectService {
                     }
                     CommandType::WriteOperation(op) => {
                         let result = session.handle_write_command(op, operation).await?;
-                        return Ok(Response::new(result));
+                        return Ok(Response::new(self.track_query(result, permit)));
                     }
                     CommandType::CreateDataframeView(_) => {
                         unimplemented_err!("CreateDataframeView not implemented")
@@ -197,8 +293,9 @@ impl SparkConnectService for DaftSparkConnectService {
                     CommandType::WriteOperationV2(_) => {
                         unimplemented_err!("WriteOperationV2 not implemented")
                     }
-                    CommandType::SqlCommand(..) => {
-                        unimplemented_err!("SQL execution not yet implemented")
+                    CommandType::SqlCommand(command) => {
+                        let result = session.handle_sql_command(command, operation).await?;
+                        return Ok(Response::new(self.track_query(result, permit)));
                     }
                     CommandType::WriteStreamOperationStart(_) => {
                         unimplemented_err!("WriteStreamOperationStart not implemented")
@@ -346,6 +443,37 @@ impl SparkConnectService for DaftSparkConnectService {
 
                 Ok(Response::new(response))
             }
+            Analyze::Explain(Explain { plan, explain_mode }) => {
+                let Some(Plan { op_type }) = plan else {
+                    return Err(Status::invalid_argument("plan is required"));
+                };
+
+                let Some(OpType::Root(relation)) = op_type else {
+                    return Err(Status::invalid_argument("op_type is required to be root"));
+                };
+
+                let Ok(explain_mode) = explain::ExplainMode::try_from(explain_mode) else {
+                    return invalid_argument_err!("Invalid explain mode: {explain_mode}");
+                };
+
+                let explain_string =
+                    match translation::relation_to_explain_string(relation, explain_mode).await {
+                        Ok(explain_string) => explain_string,
+                        Err(e) => {
+                            return invalid_argument_err!("Failed to explain relation: {e:?}");
+                        }
+                    };
+
+                let explain = analyze_plan_response::Explain { explain_string };
+
+                let response = AnalyzePlanResponse {
+                    session_id,
+                    server_side_session_id: String::new(),
+                    result: Some(analyze_plan_response::Result::Explain(explain)),
+                };
+
+                Ok(Response::new(response))
+            }
             other => unimplemented_err!("Analyze plan operation is not yet implemented: {other:?}"),
         }
     }
@@ -411,9 +539,27 @@ impl SparkConnectService for DaftSparkConnectService {
 
 #[cfg(feature = "python")]
 #[pyo3::pyfunction]
-#[pyo3(name = "connect_start", signature = (addr = "sc://0.0.0.0:0"))]
-pub fn py_connect_start(addr: &str) -> pyo3::PyResult<ConnectionHandle> {
-    start(addr).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{e:?}")))
+#[pyo3(name = "connect_start", signature = (
+    addr = "sc://0.0.0.0:0",
+    metrics_addr = None,
+    max_concurrent_queries = None,
+    max_concurrent_queries_per_session = None,
+    max_queued_queries = None,
+))]
+pub fn py_connect_start(
+    addr: &str,
+    metrics_addr: Option<&str>,
+    max_concurrent_queries: Option<usize>,
+    max_concurrent_queries_per_session: Option<usize>,
+    max_queued_queries: Option<usize>,
+) -> pyo3::PyResult<ConnectionHandle> {
+    let admission = AdmissionConfig {
+        max_concurrent_queries,
+        max_concurrent_queries_per_session,
+        max_queued_queries,
+    };
+    start_with_config(addr, metrics_addr, admission)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{e:?}")))
 }
 
 #[cfg(feature = "python")]
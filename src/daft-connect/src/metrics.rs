@@ -0,0 +1,503 @@
+//! Prometheus metrics of the connect server, served as plain text on `GET /metrics`.
+//!
+//! The metrics are few and simple enough that they are kept in atomics and rendered in the
+//! [text exposition format](https://prometheus.io/docs/instrumenting/exposition_formats/)
+//! directly, rather than pulling in a metrics library. Requests are observed by the
+//! [`MetricsLayer`] around the gRPC service, until their response body ends.
+
+use std::{
+    fmt::Write as _,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{ready, Context, Poll},
+    time::{Duration, Instant},
+};
+
+use dashmap::DashMap;
+use http::HeaderMap;
+use http_body::{Body, Frame, SizeHint};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+use tracing::warn;
+
+/// Upper bounds of the request latency histogram buckets, in seconds
+const LATENCY_BUCKETS: [f64; 10] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 5.0, 30.0];
+
+/// Largest request head accepted by the metrics endpoint
+const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+
+/// Time a client of the metrics endpoint has to send its request head, so that idle connections
+/// don't hold on to their tasks
+const REQUEST_HEAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Path prefix of the requests to the Spark Connect service
+const SERVICE_PATH_PREFIX: &str = "/spark.connect.SparkConnectService/";
+
+/// The RPC methods of the Spark Connect service and their names in the metrics. Requests to any
+/// other path are counted as `unknown`, so that clients can't create arbitrarily many series.
+const METHODS: [(&str, &str); 10] = [
+    ("ExecutePlan", "execute_plan"),
+    ("AnalyzePlan", "analyze_plan"),
+    ("Config", "config"),
+    ("AddArtifacts", "add_artifacts"),
+    ("ArtifactStatus", "artifact_status"),
+    ("Interrupt", "interrupt"),
+    ("ReattachExecute", "reattach_execute"),
+    ("ReleaseExecute", "release_execute"),
+    ("ReleaseSession", "release_session"),
+    ("FetchErrorDetails", "fetch_error_details"),
+];
+
+const UNKNOWN_METHOD: &str = "unknown";
+
+#[derive(Default)]
+struct RequestMetrics {
+    count: AtomicU64,
+    errors: AtomicU64,
+    /// Sum of the latencies, in microseconds
+    latency_sum_us: AtomicU64,
+    /// Non-cumulative counts of each bucket, the last one being `+Inf`
+    latency_buckets: [AtomicU64; LATENCY_BUCKETS.len() + 1],
+}
+
+impl RequestMetrics {
+    fn observe(&self, latency: Duration, is_err: bool) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        if is_err {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.latency_sum_us.fetch_add(
+            u64::try_from(latency.as_micros()).unwrap_or(u64::MAX),
+            Ordering::Relaxed,
+        );
+        let seconds = latency.as_secs_f64();
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| seconds <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Metrics collected by the connect server
+#[derive(Default)]
+pub struct ServerMetrics {
+    requests: DashMap<&'static str, RequestMetrics>,
+    running_queries: AtomicU64,
+}
+
+impl ServerMetrics {
+    /// Records the outcome and latency of a request to the RPC `method`
+    pub fn observe(&self, method: &'static str, latency: Duration, is_err: bool) {
+        match self.requests.get(method) {
+            Some(metrics) => metrics.observe(latency, is_err),
+            None => self
+                .requests
+                .entry(method)
+                .or_default()
+                .observe(latency, is_err),
+        }
+    }
+
+    /// Marks a query as running until the returned guard is dropped
+    pub fn start_query(self: &Arc<Self>) -> RunningQuery {
+        self.running_queries.fetch_add(1, Ordering::Relaxed);
+        RunningQuery {
+            metrics: self.clone(),
+        }
+    }
+
+    /// Renders the metrics in the Prometheus text format
+    pub fn render(&self, active_sessions: usize, queued_queries: usize) -> String {
+        let mut out = String::new();
+        let mut methods = self
+            .requests
+            .iter()
+            .map(|entry| *entry.key())
+            .collect::<Vec<_>>();
+        methods.sort_unstable();
+
+        out.push_str(
+            "# HELP daft_connect_requests_total Number of requests handled, by RPC method.\n",
+        );
+        out.push_str("# TYPE daft_connect_requests_total counter\n");
+        for method in &methods {
+            let count = self
+                .requests
+                .get(method)
+                .unwrap()
+                .count
+                .load(Ordering::Relaxed);
+            let _ = writeln!(
+                out,
+                "daft_connect_requests_total{{method=\"{method}\"}} {count}"
+            );
+        }
+
+        out.push_str("# HELP daft_connect_request_errors_total Number of requests that returned an error, by RPC method.\n");
+        out.push_str("# TYPE daft_connect_request_errors_total counter\n");
+        for method in &methods {
+            let errors = self
+                .requests
+                .get(method)
+                .unwrap()
+                .errors
+                .load(Ordering::Relaxed);
+            let _ = writeln!(
+                out,
+                "daft_connect_request_errors_total{{method=\"{method}\"}} {errors}"
+            );
+        }
+
+        out.push_str("# HELP daft_connect_request_duration_seconds Time to handle a request, by RPC method, until the response, including all of its streamed results, is complete.\n");
+        out.push_str("# TYPE daft_connect_request_duration_seconds histogram\n");
+        for method in &methods {
+            let metrics = self.requests.get(method).unwrap();
+            let mut cumulative = 0;
+            for (i, bucket) in metrics.latency_buckets.iter().enumerate() {
+                cumulative += bucket.load(Ordering::Relaxed);
+                let bound = LATENCY_BUCKETS
+                    .get(i)
+                    .map_or_else(|| "+Inf".to_string(), ToString::to_string);
+                let _ = writeln!(
+                    out,
+                    "daft_connect_request_duration_seconds_bucket{{method=\"{method}\",le=\"{bound}\"}} {cumulative}"
+                );
+            }
+            #[allow(clippy::cast_precision_loss)]
+            let sum = metrics.latency_sum_us.load(Ordering::Relaxed) as f64 / 1_000_000.0;
+            let _ = writeln!(
+                out,
+                "daft_connect_request_duration_seconds_sum{{method=\"{method}\"}} {sum}"
+            );
+            let _ = writeln!(
+                out,
+                "daft_connect_request_duration_seconds_count{{method=\"{method}\"}} {}",
+                metrics.count.load(Ordering::Relaxed)
+            );
+        }
+
+        let running_queries = self.running_queries.load(Ordering::Relaxed);
+        let pool_size = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+
+        for (name, kind, help, value) in [
+            (
+                "daft_connect_active_sessions",
+                "gauge",
+                "Number of sessions known to the server.",
+                active_sessions.to_string(),
+            ),
+            (
+                "daft_connect_running_queries",
+                "gauge",
+                "Number of queries whose results are being streamed.",
+                running_queries.to_string(),
+            ),
+            (
+                "daft_connect_queued_queries",
+                "gauge",
+                "Number of queries waiting to be admitted.",
+                queued_queries.to_string(),
+            ),
+            (
+                "daft_connect_executor_pool_threads",
+                "gauge",
+                "Number of threads of the executor compute pool.",
+                pool_size.to_string(),
+            ),
+        ] {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} {kind}");
+            let _ = writeln!(out, "{name} {value}");
+        }
+
+        out
+    }
+}
+
+/// Guard of a running query, see [`ServerMetrics::start_query`]
+pub struct RunningQuery {
+    metrics: Arc<ServerMetrics>,
+}
+
+impl Drop for RunningQuery {
+    fn drop(&mut self) {
+        self.metrics.running_queries.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Observes every request to the service it wraps in [`ServerMetrics`], from when the request is
+/// received until its response body ends, so that streamed results are included.
+#[derive(Clone)]
+pub struct MetricsLayer {
+    metrics: Arc<ServerMetrics>,
+}
+
+impl MetricsLayer {
+    pub fn new(metrics: Arc<ServerMetrics>) -> Self {
+        Self { metrics }
+    }
+}
+
+impl<S> Layer<S> for MetricsLayer {
+    type Service = MetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsService {
+            inner,
+            metrics: self.metrics.clone(),
+        }
+    }
+}
+
+/// Service of the [`MetricsLayer`]
+#[derive(Clone)]
+pub struct MetricsService<S> {
+    inner: S,
+    metrics: Arc<ServerMetrics>,
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for MetricsService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+    ResBody: Body + Unpin,
+{
+    type Response = http::Response<ObservedBody<ResBody>>;
+    type Error = S::Error;
+    type Future =
+        Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send + 'static>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        let observation = Observation {
+            metrics: self.metrics.clone(),
+            method: method_name(request.uri().path()),
+            start: Instant::now(),
+        };
+        let response = self.inner.call(request);
+        Box::pin(async move {
+            let response = match response.await {
+                Ok(response) => response,
+                Err(e) => {
+                    observation.finish(true);
+                    return Err(e);
+                }
+            };
+            // errors before any results are sent as a response of only headers
+            let observation = match response.headers().get(GRPC_STATUS) {
+                Some(_) => {
+                    observation.finish(is_grpc_error(response.headers()));
+                    None
+                }
+                None => Some(observation),
+            };
+            Ok(response.map(|inner| ObservedBody { inner, observation }))
+        })
+    }
+}
+
+const GRPC_STATUS: &str = "grpc-status";
+
+fn is_grpc_error(headers: &HeaderMap) -> bool {
+    headers
+        .get(GRPC_STATUS)
+        .is_some_and(|status| status.as_bytes() != b"0")
+}
+
+/// Name of the RPC method of a gRPC request path in the metrics, e.g. `execute_plan` for
+/// `/spark.connect.SparkConnectService/ExecutePlan`, see [`METHODS`]
+fn method_name(path: &str) -> &'static str {
+    path.strip_prefix(SERVICE_PATH_PREFIX)
+        .and_then(|rpc| METHODS.iter().find(|(method, _)| *method == rpc))
+        .map_or(UNKNOWN_METHOD, |(_, name)| name)
+}
+
+/// A request whose response is not complete yet
+struct Observation {
+    metrics: Arc<ServerMetrics>,
+    method: &'static str,
+    start: Instant,
+}
+
+impl Observation {
+    fn finish(self, is_err: bool) {
+        self.metrics
+            .observe(self.method, self.start.elapsed(), is_err);
+    }
+}
+
+/// Response body that finishes the observation of its request when it ends, with the status in
+/// its trailers. A body dropped before it ends, e.g. because the client went away, counts as an
+/// error.
+pub struct ObservedBody<B> {
+    inner: B,
+    observation: Option<Observation>,
+}
+
+impl<B: Body + Unpin> Body for ObservedBody<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let frame = ready!(Pin::new(&mut self.inner).poll_frame(cx));
+        let is_err = match &frame {
+            Some(Ok(frame)) => frame.trailers_ref().map(is_grpc_error),
+            Some(Err(_)) => Some(true),
+            None => Some(false),
+        };
+        if let Some(is_err) = is_err
+            && let Some(observation) = self.observation.take()
+        {
+            observation.finish(is_err);
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<B> Drop for ObservedBody<B> {
+    fn drop(&mut self) {
+        if let Some(observation) = self.observation.take() {
+            observation.finish(true);
+        }
+    }
+}
+
+/// Serves `GET /metrics` on the listener until the future is dropped
+pub async fn serve(listener: TcpListener, render: impl Fn() -> String + Send + Sync + 'static) {
+    let render = Arc::new(render);
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                warn!("Failed to accept metrics connection: {e}");
+                continue;
+            }
+        };
+        let render = render.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, render.as_ref()).await {
+                warn!("Failed to serve metrics: {e}");
+            }
+        });
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    render: &(impl Fn() -> String + ?Sized),
+) -> std::io::Result<()> {
+    let mut head = Vec::new();
+    let read_head = async {
+        let mut buf = [0; 1024];
+        while !head.windows(4).any(|w| w == b"\r\n\r\n") && head.len() < MAX_REQUEST_HEAD_BYTES {
+            let n = stream.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            head.extend_from_slice(&buf[..n]);
+        }
+        Ok::<_, std::io::Error>(())
+    };
+    tokio::time::timeout(REQUEST_HEAD_TIMEOUT, read_head)
+        .await
+        .map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "Timed out reading the metrics request",
+            )
+        })??;
+
+    let head = String::from_utf8_lossy(&head);
+    let mut request_line = head.lines().next().unwrap_or_default().split_whitespace();
+    let (status, content_type, body) = match (request_line.next(), request_line.next()) {
+        (Some("GET"), Some("/metrics")) => (
+            "200 OK",
+            "text/plain; version=0.0.4; charset=utf-8",
+            render(),
+        ),
+        (Some("GET"), _) => ("404 Not Found", "text/plain", "Not Found\n".to_string()),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain",
+            "Method Not Allowed\n".to_string(),
+        ),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_metrics() {
+        let metrics = Arc::new(ServerMetrics::default());
+        metrics.observe("config", Duration::from_millis(1), false);
+        metrics.observe("config", Duration::from_millis(2), true);
+        let query = metrics.start_query();
+
+        let rendered = metrics.render(3, 2);
+        assert!(rendered.contains("daft_connect_requests_total{method=\"config\"} 2\n"));
+        assert!(rendered.contains("daft_connect_request_errors_total{method=\"config\"} 1\n"));
+        assert!(rendered.contains(
+            "daft_connect_request_duration_seconds_bucket{method=\"config\",le=\"+Inf\"} 2\n"
+        ));
+        assert!(
+            rendered.contains("daft_connect_request_duration_seconds_count{method=\"config\"} 2\n")
+        );
+        assert!(rendered.contains("daft_connect_active_sessions 3\n"));
+        assert!(rendered.contains("daft_connect_running_queries 1\n"));
+        assert!(rendered.contains("daft_connect_queued_queries 2\n"));
+
+        drop(query);
+        assert!(metrics
+            .render(0, 0)
+            .contains("daft_connect_running_queries 0\n"));
+    }
+
+    #[test]
+    fn test_method_name() {
+        assert_eq!(
+            method_name("/spark.connect.SparkConnectService/ExecutePlan"),
+            "execute_plan"
+        );
+        assert_eq!(
+            method_name("/spark.connect.SparkConnectService/Config"),
+            "config"
+        );
+        assert_eq!(
+            method_name("/spark.connect.SparkConnectService/MadeUp"),
+            "unknown"
+        );
+        assert_eq!(method_name("/other.Service/Config"), "unknown");
+    }
+}
@@ -2,15 +2,16 @@ use arrow2::io::ipc::write::StreamWriter;
 use daft_table::Table;
 use eyre::Context;
 use spark_connect::{
-    execute_plan_response::{ArrowBatch, ResponseType, ResultComplete},
+    execute_plan_response::{ArrowBatch, ResponseType, ResultComplete, SqlCommandResult},
     spark_connect_service_server::SparkConnectService,
-    ExecutePlanResponse,
+    ExecutePlanResponse, Relation,
 };
 use uuid::Uuid;
 
 use crate::{DaftSparkConnectService, Session};
 
 mod root;
+mod sql;
 mod write;
 
 pub type ExecuteStream = <DaftSparkConnectService as SparkConnectService>::ExecutePlanStream;
@@ -48,6 +49,22 @@ impl PlanIds {
         }
     }
 
+    /// Returns the relation that the client runs for a SQL command.
+    pub fn sql_command_result(&self, relation: Relation) -> ExecutePlanResponse {
+        ExecutePlanResponse {
+            session_id: self.session.to_string(),
+            server_side_session_id: self.server_side_session.to_string(),
+            operation_id: self.operation.to_string(),
+            response_id: Uuid::new_v4().to_string(),
+            metrics: None,
+            observed_metrics: vec![],
+            schema: None,
+            response_type: Some(ResponseType::SqlCommandResult(SqlCommandResult {
+                relation: Some(relation),
+            })),
+        }
+    }
+
     pub fn gen_response(&self, table: &Table) -> eyre::Result<ExecutePlanResponse> {
         let mut data = Vec::new();
 
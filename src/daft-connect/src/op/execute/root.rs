@@ -7,6 +7,7 @@ use spark_connect::{ExecutePlanResponse, Relation};
 use tonic::{codegen::tokio_stream::wrappers::ReceiverStream, Status};
 
 use crate::{
+    err::status_from_report,
     op::execute::{ExecuteStream, PlanIds},
     session::Session,
     translation,
@@ -69,7 +70,7 @@ impl Session {
         let stream = ReceiverStream::new(rx);
 
         let stream = stream
-            .map_err(|e| Status::internal(format!("Error in Daft server: {e:?}")))
+            .map_err(|e| status_from_report(&e))
             .chain(stream::once(ready(Ok(finished))));
 
         Ok(Box::pin(stream))
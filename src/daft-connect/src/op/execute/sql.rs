@@ -0,0 +1,74 @@
+use eyre::bail;
+use futures::stream;
+use spark_connect::{relation::RelType, Relation, RelationCommon, Sql, SqlCommand};
+use tonic::Status;
+
+use crate::{
+    err::status_from_report,
+    op::execute::{ExecuteStream, PlanIds},
+    session::Session,
+    translation,
+};
+
+impl Session {
+    /// Handles `spark.sql(...)`, which sends the query as a command and then runs the relation
+    /// that's returned, so the query is only planned here to report errors eagerly like Spark does.
+    pub async fn handle_sql_command(
+        &self,
+        command: SqlCommand,
+        operation_id: String,
+    ) -> Result<ExecuteStream, Status> {
+        let context = PlanIds {
+            session: self.client_side_session_id().to_string(),
+            server_side_session: self.server_side_session_id().to_string(),
+            operation: operation_id,
+        };
+
+        let finished = context.finished();
+        let pset = self.psets.clone();
+
+        let result = async move {
+            let SqlCommand {
+                sql,
+                args,
+                pos_args,
+                named_arguments,
+                pos_arguments,
+                input,
+            } = command;
+
+            let relation = match input {
+                Some(input) => input,
+                None => Relation {
+                    common: Some(RelationCommon {
+                        source_info: String::new(),
+                        plan_id: None,
+                        origin: None,
+                    }),
+                    rel_type: Some(RelType::Sql(Sql {
+                        query: sql,
+                        args,
+                        pos_args,
+                        named_arguments,
+                        pos_arguments,
+                    })),
+                },
+            };
+
+            let Some(RelType::Sql(_)) = &relation.rel_type else {
+                bail!("Input of a SQL command must be a SQL relation");
+            };
+
+            let translator = translation::SparkAnalyzer::new(&pset);
+            translator.to_logical_plan(relation.clone()).await?;
+
+            Ok(context.sql_command_result(relation))
+        };
+
+        let response = result.await.map_err(|e| status_from_report(&e))?;
+
+        let stream = stream::iter([Ok(response), Ok(finished)]);
+
+        Ok(Box::pin(stream))
+    }
+}
@@ -1,8 +1,11 @@
-use std::future::ready;
+use std::{io::ErrorKind, path::Path};
 
 use common_daft_config::DaftExecutionConfig;
-use common_file_formats::FileFormat;
+use common_file_formats::{CsvWriteOptions, FileFormat};
 use daft_local_execution::NativeExecutor;
+use daft_logical_plan::WriteAttempt;
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
 use eyre::{bail, WrapErr};
 use spark_connect::{
     write_operation::{SaveMode, SaveType},
@@ -12,9 +15,10 @@ use tonic::Status;
 use tracing::warn;
 
 use crate::{
+    err::status_from_report,
     op::execute::{ExecuteStream, PlanIds},
     session::Session,
-    translation,
+    translation::{self, Options},
 };
 
 impl Session {
@@ -23,8 +27,9 @@ impl Session {
         operation: WriteOperation,
         operation_id: String,
     ) -> Result<ExecuteStream, Status> {
-        use futures::StreamExt;
+        use futures::{StreamExt, TryStreamExt};
 
+        let job_id = operation_id.clone();
         let context = PlanIds {
             session: self.client_side_session_id().to_string(),
             server_side_session: self.server_side_session_id().to_string(),
@@ -66,36 +71,16 @@ impl Session {
                 warn!("Ignoring sort_column_names: {sort_column_names:?} (not yet implemented)");
             }
 
-            if !partitioning_columns.is_empty() {
-                // todo(completeness): implement partitioning
-                warn!(
-                    "Ignoring partitioning_columns: {partitioning_columns:?} (not yet implemented)"
-                );
-            }
-
             if let Some(bucket_by) = bucket_by {
                 // todo(completeness): implement bucketing
                 warn!("Ignoring bucket_by: {bucket_by:?} (not yet implemented)");
             }
 
-            if !options.is_empty() {
-                // todo(completeness): implement options
-                warn!("Ignoring options: {options:?} (not yet implemented)");
-            }
-
             if !clustering_columns.is_empty() {
                 // todo(completeness): implement clustering
                 warn!("Ignoring clustering_columns: {clustering_columns:?} (not yet implemented)");
             }
 
-            match mode {
-                SaveMode::Unspecified => {}
-                SaveMode::Append => {}
-                SaveMode::Overwrite => {}
-                SaveMode::ErrorIfExists => {}
-                SaveMode::Ignore => {}
-            }
-
             let Some(save_type) = save_type else {
                 bail!("Save type is required");
             };
@@ -108,37 +93,146 @@ impl Session {
                 }
             };
 
+            let partition_cols = (!partitioning_columns.is_empty()).then(|| {
+                partitioning_columns
+                    .iter()
+                    .map(|name| daft_dsl::col(name.as_str()))
+                    .collect()
+            });
+
+            let mut options = Options::new(options);
+            let (compression, csv_options) = match file_format {
+                FileFormat::Parquet => {
+                    let compression = options
+                        .take_string("compression")
+                        .unwrap_or_else(|| "snappy".to_string());
+                    (Some(compression), None)
+                }
+                FileFormat::Csv => (None, Some(csv_write_options(&mut options)?)),
+                _ => (None, None),
+            };
+            options.warn_unused();
+
+            if !prepare_path(&path, mode).await? {
+                return Ok(None);
+            }
+
             let translator = translation::SparkAnalyzer::new(&pset);
 
             let plan = translator.to_logical_plan(input).await?;
 
+            // Clients retry an operation with the same ID, so a write that is re-executed replaces
+            // the files of its earlier executions rather than adding to them. Committing the
+            // staged files of an attempt requires Python, so other builds write their files under
+            // their final names.
+            let write_attempt = cfg!(feature = "python").then(|| WriteAttempt::new(job_id));
             let plan = plan
-                .table_write(&path, file_format, None, None, None)
+                .table_write(
+                    &path,
+                    file_format,
+                    partition_cols,
+                    compression,
+                    None,
+                    None,
+                    csv_options,
+                    write_attempt,
+                    None,
+                )
                 .wrap_err("Failed to create table write plan")?;
 
             let optimized_plan = plan.optimize()?;
             let cfg = DaftExecutionConfig::default();
             let native_executor = NativeExecutor::from_logical_plan_builder(&optimized_plan)?;
 
-            let mut result_stream = native_executor.run(&pset, cfg.into(), None)?.into_stream();
+            let result_stream = native_executor.run(&pset, cfg.into(), None)?.into_stream();
 
             // this is so we make sure the operation is actually done
             // before we return
             //
             // an example where this is important is if we write to a parquet file
             // and then read immediately after, we need to wait for the write to finish
-            while let Some(_result) = result_stream.next().await {}
+            let partitions = result_stream.try_collect::<Vec<_>>().await?;
+            if partitions.is_empty() {
+                return Ok(None);
+            }
+
+            // The paths of the written files (and their partition values) are returned to the
+            // client as the commit metadata of the write.
+            let written = MicroPartition::concat(partitions)?;
+            let written = Table::concat(&written.get_tables()?)?;
+            let response = context.gen_response(&written)?;
 
-            Ok(())
+            Ok(Some(response))
         };
 
         use futures::TryFutureExt;
 
-        let result = result.map_err(|e| Status::internal(format!("Error in Daft server: {e:?}")));
+        let result = result.map_err(|e| status_from_report(&e));
 
-        let future = result.and_then(|()| ready(Ok(finished)));
-        let stream = futures::stream::once(future);
+        let stream = futures::stream::once(result)
+            .map_ok(move |response| {
+                let responses = response
+                    .into_iter()
+                    .chain(std::iter::once(finished.clone()));
+                futures::stream::iter(responses.map(Ok::<_, Status>))
+            })
+            .try_flatten();
 
         Ok(Box::pin(stream))
     }
 }
+
+/// Applies the save mode of a write to the path that's written to, returning whether to write.
+///
+/// Only local paths can be checked for existing files, so other save modes than appending to
+/// remote paths aren't supported yet.
+async fn prepare_path(path: &str, mode: SaveMode) -> eyre::Result<bool> {
+    if matches!(mode, SaveMode::Unspecified | SaveMode::Append) {
+        return Ok(true);
+    }
+
+    let dir = match path.split_once("://") {
+        None => Path::new(path),
+        Some(("file", dir)) => Path::new(dir),
+        Some(_) => bail!("Save mode {mode:?} is only supported for local paths; got {path}"),
+    };
+
+    let exists = match tokio::fs::read_dir(dir).await {
+        Ok(mut entries) => entries.next_entry().await?.is_some(),
+        Err(e) if e.kind() == ErrorKind::NotFound => false,
+        Err(e) => return Err(e).wrap_err(format!("Failed to list {path}")),
+    };
+
+    match mode {
+        SaveMode::ErrorIfExists if exists => bail!("Path {path} already exists"),
+        SaveMode::Ignore => Ok(!exists),
+        SaveMode::Overwrite if exists => {
+            tokio::fs::remove_dir_all(dir)
+                .await
+                .wrap_err(format!("Failed to remove {path}"))?;
+            Ok(true)
+        }
+        _ => Ok(true),
+    }
+}
+
+/// Translates the options of a CSV writer.
+fn csv_write_options(options: &mut Options) -> eyre::Result<CsvWriteOptions> {
+    let mut csv_options = CsvWriteOptions::default();
+    if let Some(delimiter) = options.take_char("sep")? {
+        csv_options.delimiter = delimiter;
+    }
+    if let Some(delimiter) = options.take_char("delimiter")? {
+        csv_options.delimiter = delimiter;
+    }
+    if let Some(quote) = options.take_char("quote")? {
+        csv_options.quote = quote;
+    }
+    if let Some(header) = options.take_bool("header")? {
+        csv_options.header = header;
+    }
+    if let Some(null_value) = options.take_string("nullvalue") {
+        csv_options.null_value = null_value;
+    }
+    Ok(csv_options)
+}
@@ -1,13 +1,17 @@
 //! Translation between Spark Connect and Daft
 
 mod datatype;
+mod explain;
 mod expr;
 mod literal;
 mod logical_plan;
+mod options;
 mod schema;
 
 pub use datatype::{to_daft_datatype, to_spark_datatype};
+pub use explain::relation_to_explain_string;
 pub use expr::to_daft_expr;
 pub use literal::to_daft_literal;
 pub use logical_plan::SparkAnalyzer;
+pub use options::Options;
 pub use schema::relation_to_schema;
@@ -0,0 +1,664 @@
+//! Explains of Daft plans in the format of Spark's `explain`, so that tooling that parses the
+//! output of Spark keeps working against Daft.
+//!
+//! Daft plans don't have a separate physical plan in Spark's sense, so the physical plan is the
+//! optimized logical plan, with each operator named by its counterpart among Spark's physical
+//! operators, e.g. `HashAggregate` for aggregations and `Exchange` for repartitions.
+
+use common_scan_info::ScanState;
+use daft_dsl::{AggExpr, Expr, ExprRef, LiteralValue, Operator};
+use daft_logical_plan::{
+    ops, partitioning::RepartitionSpec, JoinStrategy, JoinType, LogicalPlan, LogicalPlanBuilder,
+    SourceInfo,
+};
+use daft_micropartition::partitioning::InMemoryPartitionSetCache;
+use daft_schema::dtype::DataType;
+use eyre::bail;
+use itertools::Itertools;
+use spark_connect::{analyze_plan_request::explain::ExplainMode, Relation};
+
+use super::SparkAnalyzer;
+
+#[tracing::instrument(skip_all)]
+pub async fn relation_to_explain_string(
+    input: Relation,
+    mode: ExplainMode,
+) -> eyre::Result<String> {
+    // We're just explaining the plan here, so we don't need to use a persistent cache as it won't be used
+    let pset = InMemoryPartitionSetCache::empty();
+    let translator = SparkAnalyzer::new(&pset);
+    let plan = Box::pin(translator.to_logical_plan(input)).await?;
+    explain(&plan, mode)
+}
+
+/// Explains a plan in the given mode, with the sections that Spark has for the mode.
+pub fn explain(plan: &LogicalPlanBuilder, mode: ExplainMode) -> eyre::Result<String> {
+    let optimized = plan.optimize()?;
+    let physical = SparkNode::from_plan(&optimized.build(), true);
+
+    let explain = match mode {
+        ExplainMode::Unspecified | ExplainMode::Simple => {
+            format!("== Physical Plan ==\n{}\n", physical.tree(false))
+        }
+        ExplainMode::Extended => {
+            let schema = plan.schema();
+            let output = schema
+                .fields
+                .values()
+                .map(|field| format!("{}: {}", field.name, spark_type_name(&field.dtype)))
+                .join(", ");
+            let logical = plan.build().repr_indent();
+            format!(
+                "== Parsed Logical Plan ==\n{logical}\n\n\
+                 == Analyzed Logical Plan ==\n{output}\n{logical}\n\n\
+                 == Optimized Logical Plan ==\n{}\n\n\
+                 == Physical Plan ==\n{}\n",
+                optimized.build().repr_indent(),
+                physical.tree(false)
+            )
+        }
+        ExplainMode::Formatted => {
+            format!(
+                "== Physical Plan ==\n{}\n\n\n{}",
+                physical.tree(true),
+                physical.details()
+            )
+        }
+        ExplainMode::Codegen | ExplainMode::Cost => {
+            bail!("Explain mode {mode:?} not yet supported")
+        }
+    };
+    Ok(explain)
+}
+
+/// An operator of a plan as Spark would name and describe it.
+struct SparkNode {
+    /// Name of the operator, e.g. `Filter` or `FileScan parquet`.
+    name: String,
+    /// What follows the name in simple explains, e.g. the condition of a filter.
+    arguments: String,
+    /// Properties of the operator in formatted explains, e.g. `Condition : (a > 1)`.
+    details: Vec<String>,
+    output: Vec<String>,
+    children: Vec<SparkNode>,
+}
+
+impl SparkNode {
+    fn from_plan(plan: &LogicalPlan, is_root: bool) -> Self {
+        let children = plan
+            .children()
+            .into_iter()
+            .map(|child| Self::from_plan(child, false))
+            .collect();
+        let output = plan.schema().names();
+        let (name, arguments, details) = match plan {
+            LogicalPlan::Source(source) => describe_source(source, &output),
+            LogicalPlan::Project(ops::Project { projection, .. })
+            | LogicalPlan::ActorPoolProject(ops::ActorPoolProject { projection, .. }) => {
+                let projection = exprs(projection);
+                (
+                    "Project",
+                    format!("[{projection}]"),
+                    vec![format!("Arguments: [{projection}]")],
+                )
+            }
+            LogicalPlan::Filter(ops::Filter { predicate, .. }) => {
+                let predicate = spark_expr(predicate);
+                (
+                    "Filter",
+                    predicate.clone(),
+                    vec![format!("Condition : {predicate}")],
+                )
+            }
+            LogicalPlan::Limit(ops::Limit { limit, .. }) => (
+                // Spark collects the rows of a limit at the root of a plan to the driver
+                if is_root {
+                    "CollectLimit"
+                } else {
+                    "GlobalLimit"
+                },
+                limit.to_string(),
+                vec![format!("Arguments: {limit}")],
+            ),
+            LogicalPlan::Explode(ops::Explode { to_explode, .. }) => {
+                let generators = to_explode
+                    .iter()
+                    .map(|e| format!("explode({})", spark_expr(e)))
+                    .join(", ");
+                (
+                    "Generate",
+                    generators.clone(),
+                    vec![format!("Arguments: {generators}")],
+                )
+            }
+            LogicalPlan::Unpivot(ops::Unpivot { ids, values, .. }) => {
+                let arguments = format!("[{}], [{}]", exprs(ids), exprs(values));
+                (
+                    "Expand",
+                    arguments.clone(),
+                    vec![format!("Arguments: {arguments}")],
+                )
+            }
+            LogicalPlan::Sort(ops::Sort {
+                sort_by,
+                descending,
+                nulls_first,
+                ..
+            }) => {
+                let order = sort_order(sort_by, descending, nulls_first);
+                (
+                    "Sort",
+                    format!("[{order}], true, 0"),
+                    vec![format!("Arguments: [{order}], true, 0")],
+                )
+            }
+            LogicalPlan::TopN(ops::TopN {
+                sort_by,
+                descending,
+                nulls_first,
+                limit,
+                ..
+            }) => {
+                let order = sort_order(sort_by, descending, nulls_first);
+                let output = output.join(", ");
+                (
+                    "TakeOrderedAndProject",
+                    format!("(limit={limit}, orderBy=[{order}], output=[{output}])"),
+                    vec![format!("Arguments: {limit}, [{order}], [{output}]")],
+                )
+            }
+            LogicalPlan::Repartition(ops::Repartition {
+                repartition_spec, ..
+            }) => {
+                let partitioning = spark_partitioning(repartition_spec);
+                (
+                    "Exchange",
+                    partitioning.clone(),
+                    vec![format!("Arguments: {partitioning}")],
+                )
+            }
+            LogicalPlan::Distinct(_) => hash_aggregate(plan.schema().names(), vec![]),
+            LogicalPlan::Aggregate(ops::Aggregate {
+                aggregations,
+                groupby,
+                ..
+            }) => hash_aggregate(
+                groupby.iter().map(spark_expr).collect(),
+                aggregations.iter().map(spark_expr).collect(),
+            ),
+            LogicalPlan::WindowAggregate(ops::WindowAggregate {
+                aggregations,
+                groupby,
+                event_time_column,
+                window_size,
+                window_slide,
+                ..
+            }) => hash_aggregate(
+                groupby
+                    .iter()
+                    .map(spark_expr)
+                    .chain([format!(
+                        "window({event_time_column}, {window_size}, {window_slide})"
+                    )])
+                    .collect(),
+                aggregations.iter().map(spark_expr).collect(),
+            ),
+            LogicalPlan::Window(ops::Window {
+                partition_by,
+                order_by,
+                descending,
+                nulls_first,
+                functions,
+                names,
+                ..
+            }) => {
+                let functions = functions
+                    .iter()
+                    .zip(names)
+                    .map(|(function, name)| format!("{function} AS {name}"))
+                    .join(", ");
+                let arguments = format!(
+                    "[{functions}], [{}], [{}]",
+                    exprs(partition_by),
+                    sort_order(order_by, descending, nulls_first)
+                );
+                (
+                    "Window",
+                    arguments.clone(),
+                    vec![format!("Arguments: {arguments}")],
+                )
+            }
+            LogicalPlan::Pivot(ops::Pivot {
+                group_by,
+                pivot_column,
+                value_column,
+                aggregation,
+                names,
+                ..
+            }) => {
+                let arguments = format!(
+                    "[{}], {}, {}, {}, [{}]",
+                    exprs(group_by),
+                    spark_expr(pivot_column),
+                    spark_expr(value_column),
+                    spark_agg(aggregation),
+                    names.join(", ")
+                );
+                (
+                    "Pivot",
+                    arguments.clone(),
+                    vec![format!("Arguments: {arguments}")],
+                )
+            }
+            LogicalPlan::Concat(_) | LogicalPlan::Union(_) => ("Union", String::new(), vec![]),
+            LogicalPlan::Intersect(_) => ("Intersect", String::new(), vec![]),
+            LogicalPlan::Join(join) => describe_join(join),
+            LogicalPlan::Sink(_) => ("WriteFiles", String::new(), vec![]),
+            LogicalPlan::Sample(ops::Sample {
+                fraction,
+                with_replacement,
+                seed,
+                ..
+            }) => {
+                let arguments = format!(
+                    "0.0, {fraction}, {with_replacement}, {}",
+                    seed.map_or_else(|| "null".to_string(), |seed| seed.to_string())
+                );
+                (
+                    "Sample",
+                    arguments.clone(),
+                    vec![format!("Arguments: {arguments}")],
+                )
+            }
+            LogicalPlan::MonotonicallyIncreasingId(ops::MonotonicallyIncreasingId {
+                column_name,
+                ..
+            }) => {
+                let projection = plan
+                    .children()
+                    .first()
+                    .map(|input| input.schema().names())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain([format!("monotonically_increasing_id() AS {column_name}")])
+                    .join(", ");
+                (
+                    "Project",
+                    format!("[{projection}]"),
+                    vec![format!("Arguments: [{projection}]")],
+                )
+            }
+            LogicalPlan::AssertUnique(ops::AssertUnique { keys, .. }) => {
+                let num_keys = keys.len();
+                let keys = exprs(keys);
+                (
+                    "AssertUnique",
+                    format!("[{keys}]"),
+                    vec![format!("Keys [{num_keys}]: [{keys}]")],
+                )
+            }
+        };
+        Self {
+            name: name.to_string(),
+            arguments,
+            details,
+            output,
+            children,
+        }
+    }
+
+    fn line(&self) -> String {
+        if self.arguments.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{} {}", self.name, self.arguments)
+        }
+    }
+
+    /// Name of the operator in formatted explains, where Spark names file scans `Scan <format>`.
+    fn formatted_name(&self) -> &str {
+        self.name.strip_prefix("File").unwrap_or(&self.name)
+    }
+
+    /// The tree of operators, where the children of an operator are indented under it. Operators
+    /// are numbered in formatted explains, in the order that their details are listed.
+    fn tree(&self, formatted: bool) -> String {
+        let mut lines = vec![];
+        let mut next_id = 1;
+        self.write_tree(formatted, None, "", &mut next_id, &mut lines);
+        lines.reverse();
+        lines.join("\n")
+    }
+
+    /// Writes the lines of the tree in reverse, since operators are numbered from the leaves up.
+    fn write_tree(
+        &self,
+        formatted: bool,
+        is_last: Option<bool>,
+        indent: &str,
+        next_id: &mut usize,
+        lines: &mut Vec<String>,
+    ) {
+        let child_indent = match is_last {
+            None => String::new(),
+            Some(true) => format!("{indent}   "),
+            Some(false) => format!("{indent}:  "),
+        };
+        for (i, child) in self.children.iter().enumerate().rev() {
+            child.write_tree(
+                formatted,
+                Some(i == self.children.len() - 1),
+                &child_indent,
+                next_id,
+                lines,
+            );
+        }
+        let label = if formatted {
+            let label = format!("{} ({next_id})", self.formatted_name());
+            *next_id += 1;
+            label
+        } else {
+            self.line()
+        };
+        lines.push(match is_last {
+            None => label,
+            Some(true) => format!("{indent}+- {label}"),
+            Some(false) => format!("{indent}:- {label}"),
+        });
+    }
+
+    /// The details of the operators of formatted explains, from the leaves up.
+    fn details(&self) -> String {
+        let mut sections = vec![];
+        self.write_details(&mut sections);
+        sections
+            .into_iter()
+            .enumerate()
+            .map(|(i, section)| format!("({}) {section}\n", i + 1))
+            .join("\n")
+    }
+
+    fn write_details(&self, sections: &mut Vec<String>) {
+        // children are numbered in the same order as in the tree, where the last child comes first
+        for child in self.children.iter().rev() {
+            child.write_details(sections);
+        }
+        let mut lines = vec![self.formatted_name().to_string()];
+        let input = self
+            .children
+            .iter()
+            .flat_map(|child| child.output.iter().cloned())
+            .collect::<Vec<_>>();
+        lines.push(format!(
+            "Output [{}]: [{}]",
+            self.output.len(),
+            self.output.join(", ")
+        ));
+        if !self.children.is_empty() {
+            lines.push(format!("Input [{}]: [{}]", input.len(), input.join(", ")));
+        }
+        lines.extend(self.details.iter().cloned());
+        sections.push(lines.join("\n"));
+    }
+}
+
+fn describe_source(source: &ops::Source, output: &[String]) -> (&'static str, String, Vec<String>) {
+    let columns = format!("[{}]", output.join(", "));
+    match source.source_info.as_ref() {
+        SourceInfo::Physical(scan_info) => {
+            let scan_op = match &scan_info.scan_state {
+                ScanState::Operator(scan_op) => Some(&scan_op.0),
+                ScanState::Tasks(_) => None,
+            };
+            let format = scan_op.and_then(|scan_op| scan_op.file_format());
+            let pushdowns = &scan_info.pushdowns;
+            let filters = |filters: &Option<ExprRef>| {
+                filters
+                    .as_ref()
+                    .map(|filters| conjuncts(filters).join(", "))
+                    .unwrap_or_default()
+            };
+            let read_schema = format!(
+                "struct<{}>",
+                source
+                    .output_schema
+                    .fields
+                    .values()
+                    .map(|field| format!("{}:{}", field.name, spark_type_name(&field.dtype)))
+                    .join(",")
+            );
+            let mut details = vec!["Batched: false".to_string()];
+            if let Some(format) = format {
+                details.push(format!("Format: {format:?}"));
+            }
+            if let Some(scan_op) = scan_op {
+                let paths = scan_op.file_paths();
+                if !paths.is_empty() {
+                    details.push(format!("Location: [{}]", paths.join(", ")));
+                }
+            }
+            details.push(format!(
+                "PartitionFilters: [{}]",
+                filters(&pushdowns.partition_filters)
+            ));
+            details.push(format!("PushedFilters: [{}]", filters(&pushdowns.filters)));
+            details.push(format!("ReadSchema: {read_schema}"));
+
+            // Spark names scans of files by their format, e.g. `FileScan parquet`
+            let (name, columns) = match format {
+                Some(format) => (
+                    "FileScan",
+                    format!("{} {columns}", format!("{format:?}").to_lowercase()),
+                ),
+                None => ("BatchScan", columns),
+            };
+            (name, format!("{columns} {}", details.join(", ")), details)
+        }
+        SourceInfo::InMemory(_) => ("LocalTableScan", columns, vec![]),
+        SourceInfo::PlaceHolder(_) | SourceInfo::Stream(_) => ("Scan", columns, vec![]),
+    }
+}
+
+fn describe_join(join: &ops::Join) -> (&'static str, String, Vec<String>) {
+    let join_type = match join.join_type {
+        JoinType::Inner => "Inner",
+        JoinType::Left => "LeftOuter",
+        JoinType::Right => "RightOuter",
+        JoinType::Outer => "FullOuter",
+        JoinType::Anti => "LeftAnti",
+        JoinType::Semi => "LeftSemi",
+    };
+    let left_keys = exprs(&join.left_on);
+    let right_keys = exprs(&join.right_on);
+    let details = vec![
+        format!("Left keys [{}]: [{left_keys}]", join.left_on.len()),
+        format!("Right keys [{}]: [{right_keys}]", join.right_on.len()),
+        format!("Join type: {join_type}"),
+    ];
+    let (name, arguments) = match join.join_strategy {
+        Some(JoinStrategy::Broadcast) => (
+            "BroadcastHashJoin",
+            format!("[{left_keys}], [{right_keys}], {join_type}, BuildRight, false"),
+        ),
+        Some(JoinStrategy::SortMerge) => (
+            "SortMergeJoin",
+            format!("[{left_keys}], [{right_keys}], {join_type}"),
+        ),
+        Some(JoinStrategy::Cross) => ("CartesianProduct", String::new()),
+        Some(JoinStrategy::Hash) | None => (
+            "ShuffledHashJoin",
+            format!("[{left_keys}], [{right_keys}], {join_type}, BuildRight"),
+        ),
+    };
+    (name, arguments, details)
+}
+
+fn hash_aggregate(
+    keys: Vec<String>,
+    functions: Vec<String>,
+) -> (&'static str, String, Vec<String>) {
+    let details = vec![
+        format!("Keys [{}]: [{}]", keys.len(), keys.join(", ")),
+        format!(
+            "Functions [{}]: [{}]",
+            functions.len(),
+            functions.join(", ")
+        ),
+    ];
+    (
+        "HashAggregate",
+        format!(
+            "(keys=[{}], functions=[{}])",
+            keys.join(", "),
+            functions.join(", ")
+        ),
+        details,
+    )
+}
+
+fn spark_partitioning(spec: &RepartitionSpec) -> String {
+    let num_partitions = |n: Option<usize>| n.map(|n| format!(", {n}")).unwrap_or_default();
+    match spec {
+        RepartitionSpec::Hash(config) => format!(
+            "hashpartitioning({}{})",
+            exprs(&config.by),
+            num_partitions(config.num_partitions)
+        ),
+        RepartitionSpec::Range(config) => {
+            // nulls come first in descending orders, as they do in sorts by default
+            let nulls_first = config.descending.clone();
+            format!(
+                "rangepartitioning({}{})",
+                sort_order(&config.by, &config.descending, &nulls_first),
+                num_partitions(config.num_partitions)
+            )
+        }
+        RepartitionSpec::Random(config) => format!(
+            "RoundRobinPartitioning({})",
+            config
+                .num_partitions
+                .map_or_else(String::new, |n| n.to_string())
+        ),
+        RepartitionSpec::IntoPartitions(config) => {
+            format!("RoundRobinPartitioning({})", config.num_partitions)
+        }
+    }
+}
+
+fn sort_order(sort_by: &[ExprRef], descending: &[bool], nulls_first: &[bool]) -> String {
+    sort_by
+        .iter()
+        .zip(descending)
+        .zip(nulls_first)
+        .map(|((e, descending), nulls_first)| {
+            format!(
+                "{} {} {}",
+                spark_expr(e),
+                if *descending { "DESC" } else { "ASC" },
+                if *nulls_first {
+                    "NULLS FIRST"
+                } else {
+                    "NULLS LAST"
+                }
+            )
+        })
+        .join(", ")
+}
+
+fn conjuncts(expr: &ExprRef) -> Vec<String> {
+    match expr.as_ref() {
+        Expr::BinaryOp {
+            op: Operator::And,
+            left,
+            right,
+        } => {
+            let mut conjuncts = conjuncts(left);
+            conjuncts.extend(self::conjuncts(right));
+            conjuncts
+        }
+        _ => vec![spark_expr(expr)],
+    }
+}
+
+fn exprs(exprs: &[ExprRef]) -> String {
+    exprs.iter().map(spark_expr).join(", ")
+}
+
+/// An expression as Spark would print it, e.g. `(a > 1)` rather than Daft's `col(a) > lit(1)`.
+fn spark_expr(expr: &ExprRef) -> String {
+    match expr.as_ref() {
+        Expr::Column(name) => name.to_string(),
+        Expr::Alias(e, name) => format!("{} AS {name}", spark_expr(e)),
+        Expr::Literal(LiteralValue::Null) => "null".to_string(),
+        Expr::Literal(LiteralValue::Utf8(s)) => s.clone(),
+        Expr::Literal(value) => value.to_string(),
+        Expr::BinaryOp {
+            op: Operator::NotEq,
+            left,
+            right,
+        } => format!("NOT ({} = {})", spark_expr(left), spark_expr(right)),
+        Expr::BinaryOp { op, left, right } => {
+            let op = match op {
+                Operator::Eq => "=".to_string(),
+                Operator::And => "AND".to_string(),
+                Operator::Or => "OR".to_string(),
+                Operator::FloorDivide => "div".to_string(),
+                op => op.to_string(),
+            };
+            format!("({} {op} {})", spark_expr(left), spark_expr(right))
+        }
+        Expr::Not(e) => format!("NOT {}", spark_expr(e)),
+        Expr::IsNull(e) => format!("isnull({})", spark_expr(e)),
+        Expr::NotNull(e) => format!("isnotnull({})", spark_expr(e)),
+        Expr::Cast(e, dtype) => format!("cast({} as {})", spark_expr(e), spark_type_name(dtype)),
+        Expr::Agg(agg) => spark_agg(agg),
+        _ => expr.to_string(),
+    }
+}
+
+fn spark_agg(agg: &AggExpr) -> String {
+    match agg {
+        AggExpr::Count(e, _) => format!("count({})", spark_expr(e)),
+        AggExpr::CountDistinct(e) => format!("count(distinct {})", spark_expr(e)),
+        AggExpr::Sum(e) => format!("sum({})", spark_expr(e)),
+        AggExpr::Mean(e) => format!("avg({})", spark_expr(e)),
+        AggExpr::Min(e) => format!("min({})", spark_expr(e)),
+        AggExpr::Max(e) => format!("max({})", spark_expr(e)),
+        AggExpr::Stddev(e) => format!("stddev_pop({})", spark_expr(e)),
+        _ => agg.to_string(),
+    }
+}
+
+/// Name of a dtype in Spark's `simpleString` format, as used in read schemas.
+fn spark_type_name(dtype: &DataType) -> String {
+    match dtype {
+        DataType::Null => "void".to_string(),
+        DataType::Boolean => "boolean".to_string(),
+        DataType::Int8 => "tinyint".to_string(),
+        DataType::Int16 => "smallint".to_string(),
+        DataType::Int32 => "int".to_string(),
+        DataType::Int64 => "bigint".to_string(),
+        DataType::Float32 => "float".to_string(),
+        DataType::Float64 => "double".to_string(),
+        DataType::Decimal128(precision, scale) => format!("decimal({precision},{scale})"),
+        DataType::Utf8 => "string".to_string(),
+        DataType::Binary | DataType::FixedSizeBinary(_) => "binary".to_string(),
+        DataType::Date => "date".to_string(),
+        DataType::Timestamp(_, None) => "timestamp_ntz".to_string(),
+        DataType::Timestamp(..) => "timestamp".to_string(),
+        DataType::List(child) | DataType::FixedSizeList(child, _) => {
+            format!("array<{}>", spark_type_name(child))
+        }
+        DataType::Struct(fields) => format!(
+            "struct<{}>",
+            fields
+                .iter()
+                .map(|field| format!("{}:{}", field.name, spark_type_name(&field.dtype)))
+                .join(",")
+        ),
+        DataType::Map { key, value } => {
+            format!("map<{},{}>", spark_type_name(key), spark_type_name(value))
+        }
+        other => other.to_string().to_lowercase(),
+    }
+}
@@ -164,3 +164,281 @@ pub fn to_daft_expr(expression: &Expression) -> eyre::Result<daft_dsl::ExprRef>
         spark_expr::ExprType::Extension(_) => bail!("Extension expressions not yet supported"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use daft_dsl::{col, lit, null_lit, Expr, LiteralValue};
+    use daft_schema::{dtype::DataType, time_unit::TimeUnit};
+    use spark_connect::expression::{literal::LiteralType, Literal, UnresolvedFunction};
+
+    use super::*;
+
+    fn expression(expr_type: spark_expr::ExprType) -> Expression {
+        Expression {
+            common: None,
+            expr_type: Some(expr_type),
+        }
+    }
+
+    fn literal(literal_type: LiteralType) -> Expression {
+        expression(spark_expr::ExprType::Literal(Literal {
+            literal_type: Some(literal_type),
+        }))
+    }
+
+    fn attribute(name: &str) -> Expression {
+        expression(spark_expr::ExprType::UnresolvedAttribute(
+            spark_expr::UnresolvedAttribute {
+                unparsed_identifier: name.to_string(),
+                plan_id: None,
+                is_metadata_column: None,
+            },
+        ))
+    }
+
+    fn function(name: &str, arguments: Vec<Expression>) -> Expression {
+        expression(spark_expr::ExprType::UnresolvedFunction(
+            UnresolvedFunction {
+                function_name: name.to_string(),
+                arguments,
+                is_distinct: false,
+                is_user_defined_function: false,
+            },
+        ))
+    }
+
+    fn decimal(value: &str, precision: Option<i32>, scale: Option<i32>) -> Expression {
+        literal(LiteralType::Decimal(
+            spark_connect::expression::literal::Decimal {
+                value: value.to_string(),
+                precision,
+                scale,
+            },
+        ))
+    }
+
+    #[test]
+    fn test_literals() -> eyre::Result<()> {
+        let cases = [
+            (
+                LiteralType::Null(spark_connect::DataType::default()),
+                null_lit(),
+            ),
+            (LiteralType::Boolean(true), lit(true)),
+            (LiteralType::Byte(1), lit(1).cast(&DataType::Int8)),
+            (LiteralType::Short(1), lit(1).cast(&DataType::Int16)),
+            (LiteralType::Integer(1), lit(1)),
+            (LiteralType::Long(1), lit(1i64)),
+            (LiteralType::Float(1.5), lit(1.5)),
+            (LiteralType::Double(1.5), lit(1.5)),
+            (LiteralType::String("a".to_string()), lit("a")),
+            (LiteralType::Binary(vec![1, 2]), lit([1u8, 2].as_slice())),
+            (
+                LiteralType::Date(1),
+                Expr::Literal(LiteralValue::Date(1)).arced(),
+            ),
+            (
+                LiteralType::Timestamp(1),
+                Expr::Literal(LiteralValue::Timestamp(1, TimeUnit::Microseconds, None)).arced(),
+            ),
+            (
+                LiteralType::TimestampNtz(1),
+                Expr::Literal(LiteralValue::Timestamp(1, TimeUnit::Microseconds, None)).arced(),
+            ),
+        ];
+        for (literal_type, expected) in cases {
+            let expr = literal(literal_type);
+            assert_eq!(to_daft_expr(&expr)?, expected, "{expr:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_literals() -> eyre::Result<()> {
+        let cases = [
+            (decimal("12.50", None, None), 1250, 4, 2),
+            (decimal("-0.5", None, None), -5, 1, 1),
+            (decimal("+7", None, None), 7, 1, 0),
+            (decimal(".25", None, None), 25, 2, 2),
+            (decimal("1.5", Some(10), Some(3)), 1500, 10, 3),
+        ];
+        for (expr, unscaled, precision, scale) in cases {
+            assert_eq!(
+                to_daft_expr(&expr)?,
+                Expr::Literal(LiteralValue::Decimal(unscaled, precision, scale)).arced(),
+                "{expr:?}"
+            );
+        }
+
+        for expr in [
+            decimal("", None, None),
+            decimal("1.2.3", None, None),
+            decimal("--1", None, None),
+            decimal("1e5", None, None),
+            decimal("1.25", None, Some(1)),
+            decimal("1.5", Some(40), None),
+            decimal("123.4", Some(3), Some(1)),
+        ] {
+            assert!(to_daft_expr(&expr).is_err(), "{expr:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_attribute() -> eyre::Result<()> {
+        assert_eq!(to_daft_expr(&attribute("a"))?, col("a"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_functions() -> eyre::Result<()> {
+        let cases = [
+            ("+", daft_dsl::Operator::Plus),
+            ("-", daft_dsl::Operator::Minus),
+            ("*", daft_dsl::Operator::Multiply),
+            ("/", daft_dsl::Operator::TrueDivide),
+            ("%", daft_dsl::Operator::Modulus),
+            ("==", daft_dsl::Operator::Eq),
+            ("!=", daft_dsl::Operator::NotEq),
+            ("<", daft_dsl::Operator::Lt),
+            ("<=", daft_dsl::Operator::LtEq),
+            (">", daft_dsl::Operator::Gt),
+            (">=", daft_dsl::Operator::GtEq),
+            ("and", daft_dsl::Operator::And),
+            ("or", daft_dsl::Operator::Or),
+        ];
+        let operands = [
+            (attribute("a"), col("a")),
+            (literal(LiteralType::Integer(1)), lit(1)),
+            (literal(LiteralType::Double(1.5)), lit(1.5)),
+            (literal(LiteralType::Boolean(true)), lit(true)),
+            (literal(LiteralType::String("a".to_string())), lit("a")),
+        ];
+        for (name, op) in cases {
+            for (left, expected_left) in &operands {
+                let expr = function(name, vec![left.clone(), attribute("b")]);
+                assert_eq!(
+                    to_daft_expr(&expr)?,
+                    daft_dsl::binary_op(op, expected_left.clone(), col("b")),
+                    "{expr:?}"
+                );
+            }
+
+            let expr = function(name, vec![attribute("a")]);
+            assert!(to_daft_expr(&expr).is_err(), "{expr:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_functions() -> eyre::Result<()> {
+        // not((a + 1) * 2 > b)
+        let expr = function(
+            "not",
+            vec![function(
+                ">",
+                vec![
+                    function(
+                        "*",
+                        vec![
+                            function("+", vec![attribute("a"), literal(LiteralType::Long(1))]),
+                            literal(LiteralType::Long(2)),
+                        ],
+                    ),
+                    attribute("b"),
+                ],
+            )],
+        );
+        let plus = daft_dsl::binary_op(daft_dsl::Operator::Plus, col("a"), lit(1i64));
+        let multiply = daft_dsl::binary_op(daft_dsl::Operator::Multiply, plus, lit(2i64));
+        let expected = multiply.gt(col("b")).not();
+        assert_eq!(to_daft_expr(&expr)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_functions() -> eyre::Result<()> {
+        let cases = [
+            (
+                function("UPPER", vec![attribute("a")]),
+                daft_functions::utf8::upper(col("a")),
+            ),
+            (
+                function(
+                    "substring",
+                    vec![attribute("a"), literal(LiteralType::Integer(2))],
+                ),
+                daft_functions::utf8::substr(
+                    col("a"),
+                    lit(2).gt(lit(0)).if_else(
+                        daft_dsl::binary_op(daft_dsl::Operator::Minus, lit(2), lit(1)),
+                        lit(2),
+                    ),
+                    null_lit(),
+                ),
+            ),
+            (
+                function(
+                    "when",
+                    vec![
+                        attribute("a"),
+                        literal(LiteralType::Integer(1)),
+                        attribute("b"),
+                        literal(LiteralType::Integer(2)),
+                    ],
+                ),
+                col("a").if_else(lit(1), col("b").if_else(lit(2), null_lit())),
+            ),
+            (
+                function(
+                    "coalesce",
+                    vec![attribute("a"), literal(LiteralType::Integer(0))],
+                ),
+                daft_functions::coalesce::coalesce(vec![col("a"), lit(0)]),
+            ),
+        ];
+        for (expr, expected) in cases {
+            assert_eq!(to_daft_expr(&expr)?, expected, "{expr:?}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_distinct() -> eyre::Result<()> {
+        let mut expr = function("count", vec![attribute("a")]);
+        if let Some(spark_expr::ExprType::UnresolvedFunction(function)) = &mut expr.expr_type {
+            function.is_distinct = true;
+        }
+        assert_eq!(to_daft_expr(&expr)?, col("a").count_distinct());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_function_lists_supported_functions() {
+        let err = to_daft_expr(&function("unknown", vec![attribute("a")])).unwrap_err();
+        let message = format!("{err:#}");
+        assert!(
+            message.contains("supported functions are") && message.contains("upper"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_expressions() {
+        let unsupported = [
+            function("unknown", vec![attribute("a")]),
+            expression(spark_expr::ExprType::ExpressionString(
+                spark_expr::ExpressionString {
+                    expression: "a + 1".to_string(),
+                },
+            )),
+            Expression {
+                common: None,
+                expr_type: None,
+            },
+        ];
+        for expr in unsupported {
+            assert!(to_daft_expr(&expr).is_err(), "{expr:?}");
+        }
+    }
+}
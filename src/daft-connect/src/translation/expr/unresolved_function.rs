@@ -1,10 +1,113 @@
+use std::{collections::HashMap, sync::LazyLock};
+
 use daft_core::count_mode::CountMode;
+use daft_dsl::{binary_op, lit, null_lit, Expr, ExprRef, LiteralValue, Operator};
+use daft_functions::{coalesce::coalesce, numeric, temporal, utf8};
+use daft_schema::{dtype::DataType, time_unit::TimeUnit};
 use eyre::{bail, Context};
+use itertools::Itertools;
 use spark_connect::expression::UnresolvedFunction;
 
 use crate::translation::to_daft_expr;
 
-pub fn unresolved_to_daft_expr(f: &UnresolvedFunction) -> eyre::Result<daft_dsl::ExprRef> {
+/// Translates the arguments of a Spark function, already translated to Daft, into a Daft
+/// expression.
+type SparkFunction = fn(Vec<ExprRef>) -> eyre::Result<ExprRef>;
+
+/// The Spark functions that can be translated, by their name in Spark Connect.
+///
+/// Spark operators, such as `+` and `and`, are sent as functions as well.
+static SPARK_FUNCTIONS: LazyLock<HashMap<&'static str, SparkFunction>> = LazyLock::new(|| {
+    let mut functions: HashMap<&'static str, SparkFunction> = HashMap::new();
+    // operators
+    functions.insert("!=", |args| binary(args, Operator::NotEq));
+    functions.insert("%", |args| binary(args, Operator::Modulus));
+    functions.insert("*", |args| binary(args, Operator::Multiply));
+    functions.insert("+", |args| binary(args, Operator::Plus));
+    functions.insert("-", |args| binary(args, Operator::Minus));
+    functions.insert("/", |args| binary(args, Operator::TrueDivide));
+    functions.insert("<", |args| binary(args, Operator::Lt));
+    functions.insert("<=", |args| binary(args, Operator::LtEq));
+    functions.insert("==", |args| binary(args, Operator::Eq));
+    functions.insert(">", |args| binary(args, Operator::Gt));
+    functions.insert(">=", |args| binary(args, Operator::GtEq));
+    functions.insert("and", |args| binary(args, Operator::And));
+    functions.insert("or", |args| binary(args, Operator::Or));
+    functions.insert("not", |args| unary(args, Expr::not));
+    // nulls and conditionals
+    functions.insert("isnull", |args| unary(args, Expr::is_null));
+    functions.insert("isnotnull", |args| unary(args, Expr::not_null));
+    functions.insert("coalesce", handle_coalesce);
+    functions.insert("ifnull", |args| handle_coalesce(exactly::<2>(args)?.into()));
+    functions.insert("nvl", |args| handle_coalesce(exactly::<2>(args)?.into()));
+    functions.insert("when", handle_when);
+    // aggregations
+    functions.insert("avg", |args| unary(args, Expr::mean));
+    functions.insert("collect_list", |args| unary(args, Expr::agg_list));
+    functions.insert("count", |args| unary(args, |arg| arg.count(CountMode::All)));
+    functions.insert("max", |args| unary(args, Expr::max));
+    functions.insert("mean", |args| unary(args, Expr::mean));
+    functions.insert("min", |args| unary(args, Expr::min));
+    functions.insert("sum", |args| unary(args, Expr::sum));
+    // strings
+    functions.insert("char_length", |args| unary(args, utf8::length));
+    functions.insert("character_length", |args| unary(args, utf8::length));
+    functions.insert("concat", handle_concat);
+    functions.insert("contains", |args| binary_fn(args, utf8::contains));
+    functions.insert("endswith", |args| binary_fn(args, utf8::endswith));
+    functions.insert("ilike", |args| binary_fn(args, utf8::ilike));
+    functions.insert("left", |args| binary_fn(args, utf8::left));
+    functions.insert("length", |args| unary(args, utf8::length));
+    functions.insert("like", |args| binary_fn(args, utf8::like));
+    functions.insert("lower", |args| unary(args, utf8::lower));
+    functions.insert("lpad", |args| ternary_fn(args, utf8::lpad));
+    functions.insert("ltrim", |args| unary(args, utf8::lstrip));
+    functions.insert("regexp_replace", |args| {
+        let [input, pattern, replacement] = exactly(args)?;
+        Ok(utf8::replace(input, pattern, replacement, true))
+    });
+    functions.insert("repeat", |args| binary_fn(args, utf8::repeat));
+    functions.insert("replace", handle_replace);
+    functions.insert("reverse", |args| unary(args, utf8::reverse));
+    functions.insert("right", |args| binary_fn(args, utf8::right));
+    functions.insert("rpad", |args| ternary_fn(args, utf8::rpad));
+    functions.insert("rtrim", |args| unary(args, utf8::rstrip));
+    functions.insert("split", handle_split);
+    functions.insert("startswith", |args| binary_fn(args, utf8::startswith));
+    functions.insert("substr", handle_substring);
+    functions.insert("substring", handle_substring);
+    functions.insert("trim", |args| {
+        unary(args, |arg| utf8::rstrip(utf8::lstrip(arg)))
+    });
+    functions.insert("upper", |args| unary(args, utf8::upper));
+    // math
+    functions.insert("abs", |args| unary(args, numeric::abs::abs));
+    functions.insert("cbrt", |args| unary(args, numeric::cbrt::cbrt));
+    functions.insert("ceil", |args| unary(args, numeric::ceil::ceil));
+    functions.insert("exp", |args| unary(args, numeric::exp::exp));
+    functions.insert("floor", |args| unary(args, numeric::floor::floor));
+    functions.insert("ln", |args| unary(args, numeric::log::ln));
+    functions.insert("log10", |args| unary(args, numeric::log::log10));
+    functions.insert("log2", |args| unary(args, numeric::log::log2));
+    functions.insert("round", handle_round);
+    functions.insert("sign", |args| unary(args, numeric::sign::sign));
+    functions.insert("signum", |args| unary(args, numeric::sign::sign));
+    functions.insert("sqrt", |args| unary(args, numeric::sqrt::sqrt));
+    // dates and times
+    functions.insert("date_add", |args| handle_date_add(args, Operator::Plus));
+    functions.insert("date_sub", |args| handle_date_add(args, Operator::Minus));
+    functions.insert("day", |args| unary(args, temporal::dt_day));
+    functions.insert("dayofmonth", |args| unary(args, temporal::dt_day));
+    functions.insert("dayofweek", handle_dayofweek);
+    functions.insert("hour", |args| unary(args, temporal::dt_hour));
+    functions.insert("minute", |args| unary(args, temporal::dt_minute));
+    functions.insert("month", |args| unary(args, temporal::dt_month));
+    functions.insert("second", |args| unary(args, temporal::dt_second));
+    functions.insert("year", |args| unary(args, temporal::dt_year));
+    functions
+});
+
+pub fn unresolved_to_daft_expr(f: &UnresolvedFunction) -> eyre::Result<ExprRef> {
     let UnresolvedFunction {
         function_name,
         arguments,
@@ -14,115 +117,178 @@ pub fn unresolved_to_daft_expr(f: &UnresolvedFunction) -> eyre::Result<daft_dsl:
 
     let arguments: Vec<_> = arguments.iter().map(to_daft_expr).try_collect()?;
 
-    if *is_distinct {
-        bail!("Distinct not yet supported");
-    }
-
     if *is_user_defined_function {
         bail!("User-defined functions not yet supported");
     }
 
-    match function_name.as_str() {
-        "%" => handle_binary_op(arguments, daft_dsl::Operator::Modulus),
-        "<" => handle_binary_op(arguments, daft_dsl::Operator::Lt),
-        "<=" => handle_binary_op(arguments, daft_dsl::Operator::LtEq),
-        "==" => handle_binary_op(arguments, daft_dsl::Operator::Eq),
-        ">" => handle_binary_op(arguments, daft_dsl::Operator::Gt),
-        ">=" => handle_binary_op(arguments, daft_dsl::Operator::GtEq),
-        "count" => handle_count(arguments),
-        "isnotnull" => handle_isnotnull(arguments),
-        "isnull" => handle_isnull(arguments),
-        "not" => not(arguments),
-        "sum" => handle_sum(arguments),
-        n => bail!("Unresolved function {n:?} not yet supported"),
+    let name = function_name.to_lowercase();
+    if *is_distinct {
+        return match name.as_str() {
+            "count" => unary(arguments, Expr::count_distinct),
+            _ => bail!("Distinct not yet supported for function {function_name:?}"),
+        };
     }
-    .wrap_err_with(|| format!("Failed to handle function {function_name:?}"))
-}
 
-pub fn handle_sum(arguments: Vec<daft_dsl::ExprRef>) -> eyre::Result<daft_dsl::ExprRef> {
-    let arguments: [daft_dsl::ExprRef; 1] = match arguments.try_into() {
-        Ok(arguments) => arguments,
-        Err(arguments) => {
-            bail!("requires exactly one argument; got {arguments:?}");
-        }
+    let Some(function) = SPARK_FUNCTIONS.get(name.as_str()) else {
+        bail!(
+            "Unresolved function {function_name:?} not yet supported; the supported functions are: {}",
+            SPARK_FUNCTIONS.keys().sorted().join(", ")
+        );
     };
-
-    let [arg] = arguments;
-    Ok(arg.sum())
+    function(arguments).wrap_err_with(|| format!("Failed to handle function {function_name:?}"))
 }
 
-/// If the arguments are exactly one, return it. Otherwise, return an error.
-pub fn to_single(arguments: Vec<daft_dsl::ExprRef>) -> eyre::Result<daft_dsl::ExprRef> {
-    let arguments: [daft_dsl::ExprRef; 1] = match arguments.try_into() {
-        Ok(arguments) => arguments,
+/// The arguments if there are exactly `N` of them. Otherwise, return an error.
+fn exactly<const N: usize>(arguments: Vec<ExprRef>) -> eyre::Result<[ExprRef; N]> {
+    match arguments.try_into() {
+        Ok(arguments) => Ok(arguments),
         Err(arguments) => {
-            bail!("requires exactly one argument; got {arguments:?}");
+            bail!("requires exactly {N} argument(s); got {arguments:?}");
         }
-    };
+    }
+}
 
-    let [arg] = arguments;
+fn unary(arguments: Vec<ExprRef>, f: impl Fn(ExprRef) -> ExprRef) -> eyre::Result<ExprRef> {
+    let [arg] = exactly(arguments)?;
+    Ok(f(arg))
+}
 
-    Ok(arg)
+fn binary(arguments: Vec<ExprRef>, op: Operator) -> eyre::Result<ExprRef> {
+    let [left, right] = exactly(arguments)?;
+    Ok(binary_op(op, left, right))
 }
 
-pub fn not(arguments: Vec<daft_dsl::ExprRef>) -> eyre::Result<daft_dsl::ExprRef> {
-    let arg = to_single(arguments)?;
-    Ok(arg.not())
+fn binary_fn(
+    arguments: Vec<ExprRef>,
+    f: impl Fn(ExprRef, ExprRef) -> ExprRef,
+) -> eyre::Result<ExprRef> {
+    let [first, second] = exactly(arguments)?;
+    Ok(f(first, second))
 }
 
-pub fn handle_binary_op(
-    arguments: Vec<daft_dsl::ExprRef>,
-    op: daft_dsl::Operator,
-) -> eyre::Result<daft_dsl::ExprRef> {
-    let arguments: [daft_dsl::ExprRef; 2] = match arguments.try_into() {
-        Ok(arguments) => arguments,
-        Err(arguments) => {
-            bail!("requires exactly two arguments; got {arguments:?}");
-        }
-    };
+fn ternary_fn(
+    arguments: Vec<ExprRef>,
+    f: impl Fn(ExprRef, ExprRef, ExprRef) -> ExprRef,
+) -> eyre::Result<ExprRef> {
+    let [first, second, third] = exactly(arguments)?;
+    Ok(f(first, second, third))
+}
 
-    let [left, right] = arguments;
+/// The value of an integer literal argument, e.g. the number of decimals of `round`.
+fn to_integer_literal(arg: &ExprRef) -> eyre::Result<i64> {
+    match arg.as_ref() {
+        Expr::Literal(LiteralValue::Int32(i)) => Ok(i64::from(*i)),
+        Expr::Literal(LiteralValue::Int64(i)) => Ok(*i),
+        _ => bail!("expected an integer literal; got {arg}"),
+    }
+}
 
-    Ok(daft_dsl::binary_op(op, left, right))
+fn handle_coalesce(arguments: Vec<ExprRef>) -> eyre::Result<ExprRef> {
+    if arguments.is_empty() {
+        bail!("requires at least one argument");
+    }
+    Ok(coalesce(arguments))
 }
 
-pub fn handle_count(arguments: Vec<daft_dsl::ExprRef>) -> eyre::Result<daft_dsl::ExprRef> {
-    let arguments: [daft_dsl::ExprRef; 1] = match arguments.try_into() {
-        Ok(arguments) => arguments,
-        Err(arguments) => {
-            bail!("requires exactly one argument; got {arguments:?}");
-        }
+/// `when(c1, v1, c2, v2, ..., otherwise)`, where the `otherwise` value is optional and defaults
+/// to null.
+fn handle_when(mut arguments: Vec<ExprRef>) -> eyre::Result<ExprRef> {
+    if arguments.len() < 2 {
+        bail!("requires at least a condition and a value; got {arguments:?}");
+    }
+    let mut result = if arguments.len() % 2 == 1 {
+        arguments.pop().expect("arguments are not empty")
+    } else {
+        null_lit()
     };
+    // The conditions are checked in order, so the expression is built from the last one
+    while let (Some(value), Some(condition)) = (arguments.pop(), arguments.pop()) {
+        result = condition.if_else(value, result);
+    }
+    Ok(result)
+}
 
-    let [arg] = arguments;
-
-    let count = arg.count(CountMode::All);
+fn handle_concat(arguments: Vec<ExprRef>) -> eyre::Result<ExprRef> {
+    arguments
+        .into_iter()
+        .reduce(|left, right| binary_op(Operator::Plus, left, right))
+        .ok_or_else(|| eyre::eyre!("requires at least one argument"))
+}
 
-    Ok(count)
+/// `replace(str, search, replacement)`, where the replacement defaults to the empty string.
+fn handle_replace(mut arguments: Vec<ExprRef>) -> eyre::Result<ExprRef> {
+    if arguments.len() == 2 {
+        arguments.push(lit(""));
+    }
+    let [input, search, replacement] = exactly(arguments)?;
+    Ok(utf8::replace(input, search, replacement, false))
 }
 
-pub fn handle_isnull(arguments: Vec<daft_dsl::ExprRef>) -> eyre::Result<daft_dsl::ExprRef> {
-    let arguments: [daft_dsl::ExprRef; 1] = match arguments.try_into() {
-        Ok(arguments) => arguments,
+/// `split(str, regex, limit)`, where only the default limit of `-1`, which splits on all
+/// matches, is supported.
+fn handle_split(arguments: Vec<ExprRef>) -> eyre::Result<ExprRef> {
+    let (input, pattern) = match <[ExprRef; 3]>::try_from(arguments) {
+        Ok([input, pattern, limit]) => {
+            if to_integer_literal(&limit)? > 0 {
+                bail!("a positive limit is not yet supported; got {limit}");
+            }
+            (input, pattern)
+        }
         Err(arguments) => {
-            bail!("requires exactly one argument; got {arguments:?}");
+            let [input, pattern] = exactly(arguments)?;
+            (input, pattern)
         }
     };
+    Ok(utf8::split(input, pattern, true))
+}
 
-    let [arg] = arguments;
-
-    Ok(arg.is_null())
+/// `substring(str, pos, len)`, where positions start at 1 and the length defaults to the rest of
+/// the string.
+fn handle_substring(mut arguments: Vec<ExprRef>) -> eyre::Result<ExprRef> {
+    if arguments.len() == 2 {
+        arguments.push(null_lit());
+    }
+    let [input, pos, len] = exactly(arguments)?;
+    // Daft positions start at 0, and position 0 means the start of the string in Spark as well
+    let start = pos
+        .clone()
+        .gt(lit(0))
+        .if_else(binary_op(Operator::Minus, pos.clone(), lit(1)), pos);
+    Ok(utf8::substr(input, start, len))
 }
 
-pub fn handle_isnotnull(arguments: Vec<daft_dsl::ExprRef>) -> eyre::Result<daft_dsl::ExprRef> {
-    let arguments: [daft_dsl::ExprRef; 1] = match arguments.try_into() {
-        Ok(arguments) => arguments,
+/// `round(x, scale)`, where the scale must be an integer literal and defaults to 0.
+fn handle_round(arguments: Vec<ExprRef>) -> eyre::Result<ExprRef> {
+    let (input, scale) = match <[ExprRef; 2]>::try_from(arguments) {
+        Ok([input, scale]) => (input, to_integer_literal(&scale)?),
         Err(arguments) => {
-            bail!("requires exactly one argument; got {arguments:?}");
+            let [input] = exactly(arguments)?;
+            (input, 0)
         }
     };
+    Ok(numeric::round::round(input, i32::try_from(scale)?))
+}
 
-    let [arg] = arguments;
+/// `date_add(start, days)` and `date_sub(start, days)`.
+fn handle_date_add(arguments: Vec<ExprRef>, op: Operator) -> eyre::Result<ExprRef> {
+    let [start, days] = exactly(arguments)?;
+    let duration = binary_op(
+        Operator::Multiply,
+        days.cast(&DataType::Int64),
+        lit(24 * 60 * 60i64),
+    )
+    .cast(&DataType::Duration(TimeUnit::Seconds));
+    Ok(binary_op(op, start.cast(&DataType::Date), duration))
+}
 
-    Ok(arg.not_null())
+/// `dayofweek(date)`, which starts at 1 for Sunday in Spark while Daft starts at 0 for Monday.
+fn handle_dayofweek(arguments: Vec<ExprRef>) -> eyre::Result<ExprRef> {
+    let [input] = exactly(arguments)?;
+    let day_of_week = temporal::dt_day_of_week(input).cast(&DataType::Int32);
+    let from_sunday = binary_op(
+        Operator::Modulus,
+        binary_op(Operator::Plus, day_of_week, lit(1)),
+        lit(7),
+    );
+    Ok(binary_op(Operator::Plus, from_sunday, lit(1)))
 }
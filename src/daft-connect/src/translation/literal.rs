@@ -1,8 +1,12 @@
 use daft_core::datatypes::IntervalValue;
-use eyre::bail;
-use spark_connect::expression::{literal::LiteralType, Literal};
+use daft_dsl::{Expr, LiteralValue};
+use daft_schema::{dtype::DataType, time_unit::TimeUnit};
+use eyre::{bail, ensure, WrapErr};
+use spark_connect::expression::{
+    literal::{self, LiteralType},
+    Literal,
+};
 
-// todo(test): add tests for this esp in Python
 pub fn to_daft_literal(literal: &Literal) -> eyre::Result<daft_dsl::ExprRef> {
     let Some(literal) = &literal.literal_type else {
         bail!("Literal is required");
@@ -12,15 +16,15 @@ pub fn to_daft_literal(literal: &Literal) -> eyre::Result<daft_dsl::ExprRef> {
         LiteralType::Array(_) => bail!("Array literals not yet supported"),
         LiteralType::Binary(bytes) => Ok(daft_dsl::lit(bytes.as_slice())),
         LiteralType::Boolean(b) => Ok(daft_dsl::lit(*b)),
-        LiteralType::Byte(_) => bail!("Byte literals not yet supported"),
+        LiteralType::Byte(b) => Ok(daft_dsl::lit(*b).cast(&DataType::Int8)),
         LiteralType::CalendarInterval(_) => {
             bail!("Calendar interval literals not yet supported")
         }
-        LiteralType::Date(d) => Ok(daft_dsl::lit(*d)),
+        LiteralType::Date(d) => Ok(Expr::Literal(LiteralValue::Date(*d)).arced()),
         LiteralType::DayTimeInterval(_) => {
             bail!("Day-time interval literals not yet supported")
         }
-        LiteralType::Decimal(_) => bail!("Decimal literals not yet supported"),
+        LiteralType::Decimal(d) => to_daft_decimal(d),
         LiteralType::Double(d) => Ok(daft_dsl::lit(*d)),
         LiteralType::Float(f) => {
             let f = f64::from(*f);
@@ -33,16 +37,13 @@ pub fn to_daft_literal(literal: &Literal) -> eyre::Result<daft_dsl::ExprRef> {
             // todo(correctness): is it ok to assume type is i32 here?
             Ok(daft_dsl::null_lit())
         }
-        LiteralType::Short(_) => bail!("Short literals not yet supported"),
+        LiteralType::Short(s) => Ok(daft_dsl::lit(*s).cast(&DataType::Int16)),
         LiteralType::String(s) => Ok(daft_dsl::lit(s.as_str())),
         LiteralType::Struct(_) => bail!("Struct literals not yet supported"),
-        LiteralType::Timestamp(ts) => {
-            // todo(correctness): is it ok that the type is different logically?
-            Ok(daft_dsl::lit(*ts))
-        }
-        LiteralType::TimestampNtz(ts) => {
-            // todo(correctness): is it ok that the type is different logically?
-            Ok(daft_dsl::lit(*ts))
+        // Spark timestamps are in microseconds since the epoch, and are translated to timestamps
+        // without a timezone like the Spark timestamp type.
+        LiteralType::Timestamp(ts) | LiteralType::TimestampNtz(ts) => {
+            Ok(Expr::Literal(LiteralValue::Timestamp(*ts, TimeUnit::Microseconds, None)).arced())
         }
         LiteralType::YearMonthInterval(value) => {
             let interval = IntervalValue::new(*value, 0, 0);
@@ -50,3 +51,64 @@ pub fn to_daft_literal(literal: &Literal) -> eyre::Result<daft_dsl::ExprRef> {
         }
     }
 }
+
+/// Parses the string representation of a decimal literal such as `-12.50`, whose precision and
+/// scale default to its number of digits and of fractional digits.
+fn to_daft_decimal(decimal: &literal::Decimal) -> eyre::Result<daft_dsl::ExprRef> {
+    let literal::Decimal {
+        value,
+        precision,
+        scale,
+    } = decimal;
+
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (integral, fractional) = digits.split_once('.').unwrap_or((digits, ""));
+    ensure!(
+        !(integral.is_empty() && fractional.is_empty())
+            && integral
+                .chars()
+                .chain(fractional.chars())
+                .all(|c| c.is_ascii_digit()),
+        "Invalid decimal literal {value:?}"
+    );
+
+    let scale = match scale {
+        Some(scale) => usize::try_from(*scale).wrap_err("Decimal scale must be non-negative")?,
+        None => fractional.len(),
+    };
+    ensure!(
+        fractional.len() <= scale,
+        "Decimal literal {value:?} has more fractional digits than its scale {scale}"
+    );
+    let digits = format!("{integral}{fractional:0<scale$}");
+    let significant_digits = digits.trim_start_matches('0').len();
+    let precision = match precision {
+        Some(precision) => {
+            usize::try_from(*precision).wrap_err("Decimal precision must be non-negative")?
+        }
+        None => significant_digits.max(scale).max(1),
+    };
+    ensure!(
+        significant_digits <= precision,
+        "Decimal literal {value:?} has more digits than its precision {precision}"
+    );
+    ensure!(
+        scale <= precision && precision <= 38,
+        "Invalid decimal precision {precision} and scale {scale}; the scale must be at most the \
+        precision, which must be at most 38"
+    );
+
+    let unscaled: i128 = digits
+        .parse()
+        .wrap_err_with(|| format!("Invalid decimal literal {value:?}"))?;
+    let unscaled = if negative { -unscaled } else { unscaled };
+    Ok(Expr::Literal(LiteralValue::Decimal(
+        unscaled,
+        precision as u8,
+        scale as i8,
+    ))
+    .arced())
+}
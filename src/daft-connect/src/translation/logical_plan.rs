@@ -19,12 +19,19 @@ use spark_connect::{relation::RelType, Limit, Relation, ShowString};
 use tracing::warn;
 
 mod aggregate;
+mod deduplicate;
 mod drop;
 mod filter;
+mod join;
 mod local_relation;
 mod project;
 mod range;
 mod read;
+mod sample;
+mod set_op;
+mod slice;
+mod sort;
+mod sql;
 mod to_df;
 mod with_columns;
 mod with_columns_renamed;
@@ -64,6 +71,7 @@ impl SparkAnalyzer<'_> {
             num_partitions,
             size_bytes,
             num_rows,
+            None,
         )?)
     }
 
@@ -118,6 +126,10 @@ impl SparkAnalyzer<'_> {
             RelType::Read(r) => read::read(r)
                 .await
                 .wrap_err("Failed to apply read to logical plan"),
+            RelType::Deduplicate(d) => self
+                .deduplicate(*d)
+                .await
+                .wrap_err("Failed to apply deduplicate to logical plan"),
             RelType::Drop(d) => self
                 .drop(*d)
                 .await
@@ -126,6 +138,31 @@ impl SparkAnalyzer<'_> {
                 .filter(*f)
                 .await
                 .wrap_err("Failed to apply filter to logical plan"),
+            RelType::Join(j) => self
+                .join(*j)
+                .await
+                .wrap_err("Failed to apply join to logical plan"),
+            RelType::Sample(s) => self
+                .sample(*s)
+                .await
+                .wrap_err("Failed to apply sample to logical plan"),
+            RelType::SetOp(s) => self
+                .set_op(*s)
+                .await
+                .wrap_err("Failed to apply set_op to logical plan"),
+            RelType::Sort(s) => self
+                .sort(*s)
+                .await
+                .wrap_err("Failed to apply sort to logical plan"),
+            RelType::Sql(s) => self.sql(s).wrap_err("Failed to apply sql to logical plan"),
+            RelType::Offset(o) => self
+                .offset(*o)
+                .await
+                .wrap_err("Failed to apply offset to logical plan"),
+            RelType::Tail(t) => self
+                .tail(*t)
+                .await
+                .wrap_err("Failed to apply tail to logical plan"),
             RelType::ShowString(ss) => {
                 let Some(plan_id) = common.plan_id else {
                     bail!("Plan ID is required for LocalRelation");
@@ -138,6 +175,21 @@ impl SparkAnalyzer<'_> {
         }
     }
 
+    /// Executes a plan and concatenates its results into a single table.
+    async fn collect_table(&self, plan: LogicalPlanBuilder) -> eyre::Result<Table> {
+        let optimized_plan = tokio::task::spawn_blocking(move || plan.optimize())
+            .await
+            .unwrap()?;
+
+        let cfg = Arc::new(DaftExecutionConfig::default());
+        let native_executor = NativeExecutor::from_logical_plan_builder(&optimized_plan)?;
+        let result_stream = native_executor.run(self.psets, cfg, None)?.into_stream();
+        let batch = result_stream.try_collect::<Vec<_>>().await?;
+        let single_batch = MicroPartition::concat(batch)?;
+        let tbls = single_batch.get_tables()?;
+        Ok(Table::concat(&tbls)?)
+    }
+
     async fn limit(&self, limit: Limit) -> eyre::Result<LogicalPlanBuilder> {
         let Limit { input, limit } = limit;
 
@@ -145,6 +197,10 @@ impl SparkAnalyzer<'_> {
             bail!("input must be set");
         };
 
+        if limit < 0 {
+            bail!("limit must be non-negative; got {limit}");
+        }
+
         let plan = Box::pin(self.to_logical_plan(*input)).await?;
 
         plan.limit(i64::from(limit), false)
@@ -176,17 +232,7 @@ impl SparkAnalyzer<'_> {
         let plan = Box::pin(self.to_logical_plan(*input)).await?;
         let plan = plan.limit(num_rows as i64, true)?;
 
-        let optimized_plan = tokio::task::spawn_blocking(move || plan.optimize())
-            .await
-            .unwrap()?;
-
-        let cfg = Arc::new(DaftExecutionConfig::default());
-        let native_executor = NativeExecutor::from_logical_plan_builder(&optimized_plan)?;
-        let result_stream = native_executor.run(self.psets, cfg, None)?.into_stream();
-        let batch = result_stream.try_collect::<Vec<_>>().await?;
-        let single_batch = MicroPartition::concat(batch)?;
-        let tbls = single_batch.get_tables()?;
-        let tbl = Table::concat(&tbls)?;
+        let tbl = self.collect_table(plan).await?;
         let output = tbl.to_comfy_table(None).to_string();
 
         let s = LiteralValue::Utf8(output)
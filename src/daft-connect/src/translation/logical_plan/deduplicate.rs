@@ -0,0 +1,62 @@
+use daft_dsl::col;
+use daft_logical_plan::LogicalPlanBuilder;
+use eyre::{bail, ensure};
+use itertools::Itertools;
+
+use super::SparkAnalyzer;
+
+impl SparkAnalyzer<'_> {
+    pub async fn deduplicate(
+        &self,
+        deduplicate: spark_connect::Deduplicate,
+    ) -> eyre::Result<LogicalPlanBuilder> {
+        let spark_connect::Deduplicate {
+            input,
+            column_names,
+            all_columns_as_keys,
+            within_watermark,
+        } = deduplicate;
+
+        let Some(input) = input else {
+            bail!("input is required");
+        };
+
+        if within_watermark.unwrap_or(false) {
+            bail!("Deduplicating within the watermark is not yet supported");
+        }
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        if all_columns_as_keys.unwrap_or(false) || column_names.is_empty() {
+            return Ok(plan.distinct()?);
+        }
+
+        let schema = plan.schema();
+        for name in &column_names {
+            ensure!(
+                schema.has_field(name),
+                "Cannot deduplicate on column {name:?}, which is not in {:?}",
+                schema.names()
+            );
+        }
+
+        // Keep the first row of each group of rows with equal keys, and restore the column order.
+        let all_columns = schema.names();
+        let aggregations: Vec<_> = all_columns
+            .iter()
+            .filter(|name| !column_names.contains(name))
+            .map(|name| col(name.as_str()).any_value(false))
+            .collect();
+        if aggregations.is_empty() {
+            return Ok(plan.distinct()?);
+        }
+        let groupby = column_names
+            .iter()
+            .unique()
+            .map(|name| col(name.as_str()))
+            .collect();
+        let projection = all_columns.iter().map(|name| col(name.as_str())).collect();
+
+        Ok(plan.aggregate(aggregations, groupby)?.select(projection)?)
+    }
+}
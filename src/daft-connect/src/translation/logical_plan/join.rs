@@ -0,0 +1,137 @@
+use daft_dsl::{Expr, ExprRef, Operator};
+use daft_logical_plan::{JoinType, LogicalPlanBuilder};
+use daft_schema::schema::Schema;
+use eyre::{bail, WrapErr};
+use spark_connect::join::JoinType as SparkJoinType;
+
+use super::SparkAnalyzer;
+use crate::translation::to_daft_expr;
+
+impl SparkAnalyzer<'_> {
+    pub async fn join(&self, join: spark_connect::Join) -> eyre::Result<LogicalPlanBuilder> {
+        let spark_connect::Join {
+            left,
+            right,
+            join_condition,
+            join_type,
+            using_columns,
+            join_data_type,
+        } = join;
+
+        let Some(left) = left else {
+            bail!("left is required");
+        };
+
+        let Some(right) = right else {
+            bail!("right is required");
+        };
+
+        if let Some(join_data_type) = join_data_type {
+            bail!("Join data types not yet supported; got {join_data_type:?}");
+        }
+
+        let join_type = SparkJoinType::try_from(join_type)
+            .wrap_err_with(|| format!("Invalid join type: {join_type:?}"))?;
+
+        let left = Box::pin(self.to_logical_plan(*left)).await?;
+        let right = Box::pin(self.to_logical_plan(*right)).await?;
+
+        let join_type = match join_type {
+            SparkJoinType::Unspecified => {
+                bail!("JoinType must be specified; got Unspecified")
+            }
+            SparkJoinType::Inner => JoinType::Inner,
+            SparkJoinType::FullOuter => JoinType::Outer,
+            SparkJoinType::LeftOuter => JoinType::Left,
+            SparkJoinType::RightOuter => JoinType::Right,
+            SparkJoinType::LeftAnti => JoinType::Anti,
+            SparkJoinType::LeftSemi => JoinType::Semi,
+            SparkJoinType::Cross => {
+                if join_condition.is_some() || !using_columns.is_empty() {
+                    bail!("Cross joins can't have a join condition or using columns");
+                }
+                return Ok(left.cross_join(&right, None, None)?);
+            }
+        };
+
+        // Columns in `using_columns` are merged into one column per key, like they are in Spark,
+        // while both sides of a join condition are kept.
+        let (left_on, right_on, keep_join_keys) = match (join_condition, using_columns) {
+            (Some(_), using_columns) if !using_columns.is_empty() => {
+                bail!("A join can't have both a join condition and using columns")
+            }
+            (Some(condition), _) => {
+                let condition = to_daft_expr(&condition)?;
+                let (left_on, right_on) =
+                    equi_join_keys(&condition, &left.schema(), &right.schema())?;
+                (left_on, right_on, true)
+            }
+            (None, using_columns) => {
+                let keys: Vec<_> = using_columns
+                    .iter()
+                    .map(|column| daft_dsl::col(column.as_str()))
+                    .collect();
+                (keys.clone(), keys, false)
+            }
+        };
+
+        // Spark treats an inner join without any keys as a cross join
+        if left_on.is_empty() && join_type == JoinType::Inner {
+            return Ok(left.cross_join(&right, None, None)?);
+        }
+
+        let plan = left
+            .join(
+                &right,
+                left_on.clone(),
+                right_on.clone(),
+                join_type,
+                None,
+                None,
+                None,
+                keep_join_keys,
+            )
+            .wrap_err_with(|| format!("Failed to apply join to logical plan left_on={left_on:?} right_on={right_on:?}"))?;
+
+        Ok(plan)
+    }
+}
+
+/// Splits a join condition into the keys of each side of the join. Only conjunctions of
+/// equalities between a column of each side are supported.
+fn equi_join_keys(
+    condition: &ExprRef,
+    left_schema: &Schema,
+    right_schema: &Schema,
+) -> eyre::Result<(Vec<ExprRef>, Vec<ExprRef>)> {
+    match condition.as_ref() {
+        Expr::BinaryOp {
+            op: Operator::And,
+            left,
+            right,
+        } => {
+            let (mut left_on, mut right_on) = equi_join_keys(left, left_schema, right_schema)?;
+            let (more_left_on, more_right_on) = equi_join_keys(right, left_schema, right_schema)?;
+            left_on.extend(more_left_on);
+            right_on.extend(more_right_on);
+            Ok((left_on, right_on))
+        }
+        Expr::BinaryOp {
+            op: Operator::Eq,
+            left,
+            right,
+        } => {
+            let (Expr::Column(a), Expr::Column(b)) = (left.as_ref(), right.as_ref()) else {
+                bail!("Join conditions must compare columns; got {condition}");
+            };
+            if left_schema.has_field(a) && right_schema.has_field(b) {
+                Ok((vec![left.clone()], vec![right.clone()]))
+            } else if left_schema.has_field(b) && right_schema.has_field(a) {
+                Ok((vec![right.clone()], vec![left.clone()]))
+            } else {
+                bail!("Join condition {condition} must compare a column of each side of the join")
+            }
+        }
+        _ => bail!("Only equality join conditions are supported; got {condition}"),
+    }
+}
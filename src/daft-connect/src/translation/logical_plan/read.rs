@@ -20,7 +20,8 @@ pub async fn read(read: spark_connect::Read) -> eyre::Result<LogicalPlanBuilder>
     let builder = match read_type {
         ReadType::NamedTable(table) => {
             let name = table.unparsed_identifier;
-            bail!("Tried to read from table {name} but it is not yet implemented. Try to read from a path instead.");
+            daft_catalog::global_catalog::read_table(&name)
+                .wrap_err_with(|| format!("Failed to read table {name}"))
         }
         ReadType::DataSource(source) => data_source::data_source(source)
             .await
@@ -1,8 +1,11 @@
 use daft_logical_plan::LogicalPlanBuilder;
-use daft_scan::builder::{CsvScanBuilder, ParquetScanBuilder};
-use eyre::{bail, ensure, WrapErr};
+use daft_scan::builder::{CsvScanBuilder, JsonScanBuilder, ParquetScanBuilder};
+use daft_schema::schema::SchemaRef;
+use eyre::{bail, ensure, eyre, WrapErr};
 use tracing::warn;
 
+use crate::translation::Options;
+
 pub async fn data_source(
     data_source: spark_connect::read::DataSource,
 ) -> eyre::Result<LogicalPlanBuilder> {
@@ -20,35 +23,99 @@ pub async fn data_source(
 
     ensure!(!paths.is_empty(), "Paths are required");
 
-    if let Some(schema) = schema {
-        warn!("Ignoring schema: {schema:?}; not yet implemented");
-    }
-
-    if !options.is_empty() {
-        warn!("Ignoring options: {options:?}; not yet implemented");
-    }
-
     if !predicates.is_empty() {
         warn!("Ignoring predicates: {predicates:?}; not yet implemented");
     }
 
+    let schema = match schema.as_deref() {
+        None | Some("") => None,
+        Some(schema) => parse_schema(schema)?,
+    };
+
+    let mut options = Options::new(options);
+
     let plan = match &*format {
-        "parquet" => ParquetScanBuilder::new(paths)
-            .finish()
-            .await
-            .wrap_err("Failed to create parquet scan builder")?,
-        "csv" => CsvScanBuilder::new(paths)
-            .finish()
-            .await
-            .wrap_err("Failed to create csv scan builder")?,
+        "parquet" => {
+            let mut builder = ParquetScanBuilder::new(paths);
+            if let Some(schema) = schema {
+                builder = builder.schema(schema).infer_schema(false);
+            }
+            options.warn_unused();
+
+            builder
+                .finish()
+                .await
+                .wrap_err("Failed to create parquet scan builder")?
+        }
+        "csv" => {
+            let mut builder = CsvScanBuilder::new(paths);
+            if let Some(schema) = schema {
+                builder = builder.schema(schema).infer_schema(false);
+            }
+            if let Some(has_headers) = options.take_bool("header")? {
+                builder = builder.has_headers(has_headers);
+            }
+            if let Some(delimiter) = options.take_char("sep")? {
+                builder = builder.delimiter(delimiter);
+            }
+            if let Some(delimiter) = options.take_char("delimiter")? {
+                builder = builder.delimiter(delimiter);
+            }
+            if let Some(quote) = options.take_char("quote")? {
+                builder = builder.quote(quote);
+            }
+            if let Some(escape_char) = options.take_char("escape")? {
+                builder = builder.escape_char(escape_char);
+            }
+            if let Some(comment) = options.take_char("comment")? {
+                builder = builder.comment(comment);
+            }
+            // Schemas are always inferred unless they're given, so only `inferSchema=false` has no
+            // effect.
+            if options.take_bool("inferschema")? == Some(false) {
+                warn!("Ignoring inferSchema=false; schemas are inferred unless they're given");
+            }
+            options.warn_unused();
+
+            builder
+                .finish()
+                .await
+                .wrap_err("Failed to create csv scan builder")?
+        }
         "json" => {
-            // todo(completeness): implement json reading
-            bail!("json reading is not yet implemented");
+            let mut builder = JsonScanBuilder::new(paths);
+            if let Some(schema) = schema {
+                builder = builder.schema(schema).infer_schema(false);
+            }
+            if options.take_bool("multiline")? == Some(true) {
+                bail!("multiLine JSON reading is not yet implemented; only JSON Lines files are supported");
+            }
+            options.warn_unused();
+
+            builder
+                .finish()
+                .await
+                .wrap_err("Failed to create json scan builder")?
         }
         other => {
-            bail!("Unsupported format: {other}; only parquet and csv are supported");
+            bail!("Unsupported format: {other}; only parquet, csv and json are supported");
         }
     };
 
     Ok(plan)
 }
+
+/// Parses a schema given to a reader, which Spark clients send as a DDL string if it was given as
+/// one or as JSON if it was given as a `StructType`.
+fn parse_schema(schema: &str) -> eyre::Result<Option<SchemaRef>> {
+    if schema.trim_start().starts_with('{') {
+        // todo(completeness): parse schemas given as `StructType`s
+        warn!("Ignoring schema: {schema}; only DDL-formatted schemas are supported");
+        return Ok(None);
+    }
+
+    let schema = daft_sql::sql_schema(schema)
+        .map_err(|e| eyre!("Failed to parse schema {schema:?}: {e}"))?;
+
+    Ok(Some(schema))
+}
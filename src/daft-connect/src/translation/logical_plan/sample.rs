@@ -0,0 +1,41 @@
+use daft_logical_plan::LogicalPlanBuilder;
+use eyre::{bail, ensure};
+
+use super::SparkAnalyzer;
+
+impl SparkAnalyzer<'_> {
+    pub async fn sample(&self, sample: spark_connect::Sample) -> eyre::Result<LogicalPlanBuilder> {
+        let spark_connect::Sample {
+            input,
+            lower_bound,
+            upper_bound,
+            with_replacement,
+            seed,
+            deterministic_order,
+        } = sample;
+
+        let Some(input) = input else {
+            bail!("input is required");
+        };
+
+        // Spark only samples between a nonzero lower bound and an upper bound to split a DataFrame
+        // with `randomSplit`, which relies on every split seeing the same random values.
+        if deterministic_order || lower_bound > 0.0 {
+            bail!(
+                "Sampling between bounds (e.g. randomSplit) is not supported; use sample instead"
+            );
+        }
+
+        ensure!(
+            upper_bound >= 0.0,
+            "Sampling fraction must be non-negative, got {upper_bound}"
+        );
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        // Spark seeds are signed, so reinterpret their bits rather than rejecting negative seeds.
+        let seed = seed.map(|seed| u64::from_ne_bytes(seed.to_ne_bytes()));
+
+        Ok(plan.sample(upper_bound, with_replacement.unwrap_or(false), seed)?)
+    }
+}
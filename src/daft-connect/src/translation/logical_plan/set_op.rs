@@ -0,0 +1,165 @@
+use daft_dsl::{col, null_lit, ExprRef};
+use daft_logical_plan::{JoinType, LogicalPlanBuilder};
+use eyre::{bail, ensure, WrapErr};
+use spark_connect::set_operation::SetOpType;
+
+use super::SparkAnalyzer;
+
+impl SparkAnalyzer<'_> {
+    pub async fn set_op(
+        &self,
+        set_op: spark_connect::SetOperation,
+    ) -> eyre::Result<LogicalPlanBuilder> {
+        let spark_connect::SetOperation {
+            left_input,
+            right_input,
+            set_op_type,
+            is_all,
+            by_name,
+            allow_missing_columns,
+        } = set_op;
+
+        let Some(left_input) = left_input else {
+            bail!("left_input is required");
+        };
+
+        let Some(right_input) = right_input else {
+            bail!("right_input is required");
+        };
+
+        let set_op_type = SetOpType::try_from(set_op_type)
+            .wrap_err_with(|| format!("Invalid set operation type: {set_op_type:?}"))?;
+        let is_all = is_all.unwrap_or(false);
+        let by_name = by_name.unwrap_or(false);
+        let allow_missing_columns = allow_missing_columns.unwrap_or(false);
+
+        ensure!(
+            set_op_type == SetOpType::Union || !by_name,
+            "Only unions can match columns by name"
+        );
+
+        let left = Box::pin(self.to_logical_plan(*left_input)).await?;
+        let right = Box::pin(self.to_logical_plan(*right_input)).await?;
+
+        // Both sides end up with the columns of the result, in the same order
+        let (left, right) = if by_name {
+            align_by_name(left, right, allow_missing_columns)?
+        } else {
+            let right = align_by_position(&left, right)?;
+            (left, right)
+        };
+
+        match set_op_type {
+            SetOpType::Unspecified => bail!("SetOpType must be specified; got Unspecified"),
+            SetOpType::Union => Ok(left.union(&right, is_all)?),
+            SetOpType::Intersect => {
+                ensure!(!is_all, "INTERSECT ALL is not yet supported");
+                Ok(left.intersect(&right, false)?)
+            }
+            SetOpType::Except => {
+                ensure!(!is_all, "EXCEPT ALL is not yet supported");
+                // Rows of the left side without an equal row on the right, comparing nulls as
+                // equal like Spark does
+                let keys: Vec<ExprRef> = left.schema().names().into_iter().map(col).collect();
+                let num_keys = keys.len();
+                Ok(left
+                    .join_with_null_safe_equal(
+                        &right,
+                        keys.clone(),
+                        keys,
+                        Some(vec![true; num_keys]),
+                        JoinType::Anti,
+                        None,
+                        None,
+                        None,
+                        false,
+                    )?
+                    .distinct()?)
+            }
+        }
+    }
+}
+
+/// Renames the columns of `right` to those of `left`, matching them by position
+fn align_by_position(
+    left: &LogicalPlanBuilder,
+    right: LogicalPlanBuilder,
+) -> eyre::Result<LogicalPlanBuilder> {
+    let left_names = left.schema().names();
+    let right_names = right.schema().names();
+    ensure!(
+        left_names.len() == right_names.len(),
+        "Both sides of a set operation must have the same number of columns, \
+        but got {} and {}",
+        left_names.len(),
+        right_names.len()
+    );
+
+    let renamed = right_names
+        .into_iter()
+        .zip(left_names)
+        .map(|(right_name, left_name)| col(right_name).alias(left_name))
+        .collect();
+    Ok(right.select(renamed)?)
+}
+
+/// Orders the columns of `right` like those of `left`, matching them by name.
+///
+/// If `allow_missing_columns`, columns that only one side has are added to the other side as
+/// nulls, after the columns of `left`.
+fn align_by_name(
+    left: LogicalPlanBuilder,
+    right: LogicalPlanBuilder,
+    allow_missing_columns: bool,
+) -> eyre::Result<(LogicalPlanBuilder, LogicalPlanBuilder)> {
+    let left_schema = left.schema();
+    let right_schema = right.schema();
+
+    if !allow_missing_columns {
+        for name in left_schema.names() {
+            ensure!(
+                right_schema.has_field(&name),
+                "Cannot union by name, column `{name}` is missing from the right side"
+            );
+        }
+        for name in right_schema.names() {
+            ensure!(
+                left_schema.has_field(&name),
+                "Cannot union by name, column `{name}` is missing from the left side"
+            );
+        }
+        let right = right.select(left_schema.names().into_iter().map(col).collect())?;
+        return Ok((left, right));
+    }
+
+    let names: Vec<String> = left_schema
+        .names()
+        .into_iter()
+        .chain(
+            right_schema
+                .names()
+                .into_iter()
+                .filter(|name| !left_schema.has_field(name)),
+        )
+        .collect();
+
+    let project = |plan: LogicalPlanBuilder| -> eyre::Result<LogicalPlanBuilder> {
+        let schema = plan.schema();
+        let columns = names
+            .iter()
+            .map(|name| {
+                if schema.has_field(name) {
+                    Ok(col(name.as_str()))
+                } else {
+                    let field = left_schema
+                        .get_field(name)
+                        .or_else(|_| right_schema.get_field(name))?;
+                    Ok(null_lit().cast(&field.dtype).alias(name.as_str()))
+                }
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        Ok(plan.select(columns)?)
+    };
+
+    Ok((project(left)?, project(right)?))
+}
@@ -0,0 +1,71 @@
+use daft_dsl::{col, lit};
+use daft_logical_plan::{ops::WindowFunction, LogicalPlanBuilder};
+use eyre::bail;
+
+use super::SparkAnalyzer;
+
+/// Column with the position of each row of the input of an offset or tail, which orders the rows
+/// as they are read even after a shuffle
+const ROW_ID_COLUMN: &str = "__spark_connect_row_id";
+/// Column with the number of each row of the input of an offset, in the order of the row ids
+const ROW_NUMBER_COLUMN: &str = "__spark_connect_row_number";
+
+// Daft doesn't have logical operators that skip rows or take the last rows of their input, so
+// offsets are rewritten into a filter on the row numbers of the input and tails into a descending
+// sort and limit on the positions of its rows, which are then sorted back into their order.
+impl SparkAnalyzer<'_> {
+    pub async fn offset(&self, offset: spark_connect::Offset) -> eyre::Result<LogicalPlanBuilder> {
+        let spark_connect::Offset { input, offset } = offset;
+
+        let Some(input) = input else {
+            bail!("input is required");
+        };
+
+        if offset < 0 {
+            bail!("offset must be non-negative; got {offset}");
+        }
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let plan = plan
+            .add_monotonically_increasing_id(Some(ROW_ID_COLUMN))?
+            .window(
+                vec![],
+                vec![col(ROW_ID_COLUMN)],
+                vec![false],
+                vec![false],
+                vec![WindowFunction::RowNumber],
+                vec![ROW_NUMBER_COLUMN.to_string()],
+            )?
+            .filter(col(ROW_NUMBER_COLUMN).gt(lit(offset as u64)))?
+            .exclude(vec![
+                ROW_ID_COLUMN.to_string(),
+                ROW_NUMBER_COLUMN.to_string(),
+            ])?;
+
+        Ok(plan)
+    }
+
+    pub async fn tail(&self, tail: spark_connect::Tail) -> eyre::Result<LogicalPlanBuilder> {
+        let spark_connect::Tail { input, limit } = tail;
+
+        let Some(input) = input else {
+            bail!("input is required");
+        };
+
+        if limit < 0 {
+            bail!("limit must be non-negative; got {limit}");
+        }
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let plan = plan
+            .add_monotonically_increasing_id(Some(ROW_ID_COLUMN))?
+            .sort(vec![col(ROW_ID_COLUMN)], vec![true], vec![false])?
+            .limit(i64::from(limit), false)?
+            .sort(vec![col(ROW_ID_COLUMN)], vec![false], vec![false])?
+            .exclude(vec![ROW_ID_COLUMN.to_string()])?;
+
+        Ok(plan)
+    }
+}
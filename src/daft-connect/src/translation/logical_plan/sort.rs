@@ -0,0 +1,76 @@
+use daft_logical_plan::LogicalPlanBuilder;
+use eyre::{bail, WrapErr};
+use spark_connect::expression::sort_order::{NullOrdering, SortDirection};
+use tracing::warn;
+
+use super::SparkAnalyzer;
+use crate::translation::to_daft_expr;
+
+impl SparkAnalyzer<'_> {
+    pub async fn sort(&self, sort: spark_connect::Sort) -> eyre::Result<LogicalPlanBuilder> {
+        let spark_connect::Sort {
+            input,
+            order,
+            is_global,
+        } = sort;
+
+        let Some(input) = input else {
+            bail!("input is required");
+        };
+
+        if order.is_empty() {
+            bail!("order is required");
+        }
+
+        if is_global == Some(false) {
+            warn!("Sorting within partitions is not yet supported; sorting globally instead");
+        }
+
+        let mut sort_by = Vec::with_capacity(order.len());
+        let mut descending = Vec::with_capacity(order.len());
+        let mut nulls_first = Vec::with_capacity(order.len());
+
+        for sort_order in order {
+            let spark_connect::expression::SortOrder {
+                child,
+                direction,
+                null_ordering,
+            } = sort_order;
+
+            let Some(child) = child else {
+                bail!("Sort order child is required");
+            };
+
+            let direction = SortDirection::try_from(direction)
+                .wrap_err_with(|| format!("Invalid sort direction: {direction}"))?;
+
+            let null_ordering = NullOrdering::try_from(null_ordering)
+                .wrap_err_with(|| format!("Invalid sort nulls: {null_ordering}"))?;
+
+            let is_descending = match direction {
+                SortDirection::Unspecified | SortDirection::Ascending => false,
+                SortDirection::Descending => true,
+            };
+
+            // Like in Spark, nulls come first in ascending order and last in descending order
+            // unless their ordering is specified.
+            let is_nulls_first = match null_ordering {
+                NullOrdering::SortNullsUnspecified => !is_descending,
+                NullOrdering::SortNullsFirst => true,
+                NullOrdering::SortNullsLast => false,
+            };
+
+            sort_by.push(to_daft_expr(&child)?);
+            descending.push(is_descending);
+            nulls_first.push(is_nulls_first);
+        }
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let plan = plan
+            .sort(sort_by, descending, nulls_first)
+            .wrap_err("Failed to apply sort to logical plan")?;
+
+        Ok(plan)
+    }
+}
@@ -0,0 +1,40 @@
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_sql::{catalog::SQLCatalog, SQLPlanner};
+use eyre::{bail, eyre};
+use spark_connect::Sql;
+
+use super::SparkAnalyzer;
+
+impl SparkAnalyzer<'_> {
+    pub fn sql(&self, sql: Sql) -> eyre::Result<LogicalPlanBuilder> {
+        let Sql {
+            query,
+            args,
+            pos_args,
+            named_arguments,
+            pos_arguments,
+        } = sql;
+
+        if !args.is_empty()
+            || !pos_args.is_empty()
+            || !named_arguments.is_empty()
+            || !pos_arguments.is_empty()
+        {
+            bail!("Parameterized SQL queries are not yet supported");
+        }
+
+        // todo(completeness): register temporary views with the catalog once they're supported
+        let mut planner = SQLPlanner::new(SQLCatalog::new());
+        let plan = planner
+            .plan_sql(&query)
+            .map_err(|e| eyre!("Failed to plan SQL query {query:?}: {e}"))?;
+        if let Some(write) = planner.catalog().table_format_write() {
+            bail!(
+                "Creating {} tables is only supported by daft.sql",
+                write.format.as_str()
+            );
+        }
+
+        Ok(LogicalPlanBuilder::new(plan, None))
+    }
+}
@@ -1,5 +1,6 @@
+use daft_dsl::col;
 use daft_logical_plan::LogicalPlanBuilder;
-use eyre::{bail, WrapErr};
+use eyre::{bail, ensure, WrapErr};
 
 use super::SparkAnalyzer;
 impl SparkAnalyzer<'_> {
@@ -13,16 +14,22 @@ impl SparkAnalyzer<'_> {
             bail!("Input is required");
         };
 
-        let mut plan = Box::pin(self.to_logical_plan(*input)).await?;
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
 
-        let column_names: Vec<_> = column_names
+        // `toDF` renames the columns by position, so there must be a name for every column.
+        let old_names = plan.schema().names();
+        ensure!(
+            old_names.len() == column_names.len(),
+            "Number of column names must match the number of columns; got {column_names:?} for columns {old_names:?}"
+        );
+
+        let projection = old_names
             .iter()
-            .map(|s| daft_dsl::col(s.as_str()))
+            .zip(&column_names)
+            .map(|(old_name, new_name)| col(old_name.as_str()).alias(new_name.as_str()))
             .collect();
 
-        plan = plan
-            .select(column_names)
-            .wrap_err("Failed to add columns to logical plan")?;
-        Ok(plan)
+        plan.select(projection)
+            .wrap_err("Failed to rename columns of logical plan")
     }
 }
@@ -21,28 +21,35 @@ impl SparkAnalyzer<'_> {
 
         let plan = Box::pin(self.to_logical_plan(*input)).await?;
 
-        // todo: let's implement this directly into daft
-
-        // Convert the rename mappings into expressions
-        let rename_exprs = if !rename_columns_map.is_empty() {
-            // Use rename_columns_map if provided (legacy format)
-            rename_columns_map
-                .into_iter()
-                .map(|(old_name, new_name)| col(old_name.as_str()).alias(new_name.as_str()))
-                .collect()
+        let old_names = plan.schema().names();
+        let mut new_names = old_names.clone();
+        if !rename_columns_map.is_empty() {
+            // Use rename_columns_map if provided (legacy format), whose renames apply at once
+            for name in &mut new_names {
+                if let Some(new_name) = rename_columns_map.get(name) {
+                    name.clone_from(new_name);
+                }
+            }
         } else {
-            // Use renames if provided (new format)
-            renames
-                .into_iter()
-                .map(|rename| col(rename.col_name.as_str()).alias(rename.new_col_name.as_str()))
-                .collect()
-        };
-
-        // Apply the rename expressions to the plan
-        let plan = plan
-            .select(rename_exprs)
-            .wrap_err("Failed to apply rename expressions to logical plan")?;
-
-        Ok(plan)
+            // Use renames if provided (new format), which apply one after the other
+            for rename in renames {
+                for name in &mut new_names {
+                    if *name == rename.col_name {
+                        name.clone_from(&rename.new_col_name);
+                    }
+                }
+            }
+        }
+
+        // Columns that aren't renamed are kept as they are, in the same order.
+        // Renaming a column that doesn't exist is a no-op, like in Spark.
+        let projection = old_names
+            .iter()
+            .zip(&new_names)
+            .map(|(old_name, new_name)| col(old_name.as_str()).alias(new_name.as_str()))
+            .collect();
+
+        plan.select(projection)
+            .wrap_err("Failed to apply rename expressions to logical plan")
     }
 }
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use eyre::bail;
+use tracing::warn;
+
+/// The options of a reader or writer, whose keys are case-insensitive in Spark.
+pub struct Options(HashMap<String, String>);
+
+impl Options {
+    pub fn new(options: HashMap<String, String>) -> Self {
+        Self(
+            options
+                .into_iter()
+                .map(|(key, value)| (key.to_lowercase(), value))
+                .collect(),
+        )
+    }
+
+    pub fn take_bool(&mut self, key: &str) -> eyre::Result<Option<bool>> {
+        self.0
+            .remove(key)
+            .map(|value| match value.to_lowercase().as_str() {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => bail!("Option {key} must be true or false; got {value}"),
+            })
+            .transpose()
+    }
+
+    pub fn take_char(&mut self, key: &str) -> eyre::Result<Option<char>> {
+        self.0
+            .remove(key)
+            .map(|value| {
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(c),
+                    _ => bail!("Option {key} must be a single character; got {value:?}"),
+                }
+            })
+            .transpose()
+    }
+
+    pub fn take_string(&mut self, key: &str) -> Option<String> {
+        self.0.remove(key)
+    }
+
+    pub fn warn_unused(self) {
+        if !self.0.is_empty() {
+            warn!("Ignoring options: {:?}; not yet implemented", self.0);
+        }
+    }
+}
@@ -9,13 +9,14 @@ pub fn idx_sort<I, F>(
     cmp: F,
     length: usize,
     descending: bool,
+    nulls_first: bool,
 ) -> PrimitiveArray<I>
 where
     I: Index,
     F: Fn(&I, &I) -> std::cmp::Ordering,
 {
     let (mut indices, start_idx, end_idx) =
-        generate_initial_indices::<I>(validity, length, descending);
+        generate_initial_indices::<I>(validity, length, nulls_first);
     let indices_slice = &mut indices.as_mut_slice()[start_idx..end_idx];
 
     if !descending {
@@ -32,14 +33,14 @@ pub fn multi_column_idx_sort<I, F>(
     overall_cmp: F,
     others_cmp: &DynComparator,
     length: usize,
-    first_col_desc: bool,
+    first_col_nulls_first: bool,
 ) -> PrimitiveArray<I>
 where
     I: Index,
     F: Fn(&I, &I) -> std::cmp::Ordering,
 {
     let (mut indices, start_idx, end_idx) =
-        generate_initial_indices::<I>(first_col_validity, length, first_col_desc);
+        generate_initial_indices::<I>(first_col_validity, length, first_col_nulls_first);
     let indices_slice = &mut indices.as_mut_slice()[start_idx..end_idx];
 
     indices_slice.sort_unstable_by(|a, b| overall_cmp(a, b));
@@ -59,7 +60,7 @@ where
 fn generate_initial_indices<I>(
     validity: Option<&Bitmap>,
     length: usize,
-    descending: bool,
+    nulls_first: bool,
 ) -> (Vec<I>, usize, usize)
 where
     I: Index,
@@ -69,7 +70,7 @@ where
 
     if let Some(validity) = validity {
         let mut indices = vec![I::default(); length];
-        if descending {
+        if nulls_first {
             let mut nulls = 0;
             let mut valids = 0;
             validity
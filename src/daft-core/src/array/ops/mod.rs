@@ -65,7 +65,7 @@ use std::hash::BuildHasher;
 use common_error::DaftResult;
 pub use hll_sketch::HLL_SKETCH_DTYPE;
 pub use sort::{build_multi_array_bicompare, build_multi_array_compare};
-pub use utf8::{PadPlacement, Utf8NormalizeOptions};
+pub use utf8::{Collation, PadPlacement, Utf8NormalizeOptions};
 
 use crate::count_mode::CountMode;
 
@@ -1,6 +1,8 @@
 use std::{
     borrow::Cow,
+    fmt::{Display, Formatter},
     iter::{self, Repeat, Take},
+    str::FromStr,
     sync::Arc,
 };
 
@@ -351,7 +353,96 @@ pub struct Utf8NormalizeOptions {
     pub white_space: bool,
 }
 
+/// How strings are ordered and compared with each other.
+///
+/// Strings are collated by comparing their collation keys, see [`Utf8Array::collation_key`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum Collation {
+    /// By the bytes of their UTF-8 encoding, i.e. by code point
+    #[default]
+    Binary,
+    /// By their lowercase form, ignoring the case of letters
+    CaseInsensitive,
+    /// By the collation rules of a locale such as `de-DE`, which requires the `icu` feature
+    Locale(String),
+}
+
+impl FromStr for Collation {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "" => Err(DaftError::ValueError(
+                "Collation must be `binary`, `case_insensitive` or a locale, got an empty string"
+                    .to_string(),
+            )),
+            "binary" => Ok(Self::Binary),
+            "case_insensitive" | "nocase" => Ok(Self::CaseInsensitive),
+            _ => Ok(Self::Locale(s.trim().to_string())),
+        }
+    }
+}
+
+impl Display for Collation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Binary => write!(f, "binary"),
+            Self::CaseInsensitive => write!(f, "case_insensitive"),
+            Self::Locale(locale) => write!(f, "{locale}"),
+        }
+    }
+}
+
+#[cfg(feature = "icu")]
+fn locale_collation_key(arr: &Utf8Array, locale: &str) -> DaftResult<BinaryArray> {
+    use icu_collator::{options::CollatorOptions, Collator, CollatorPreferences};
+    use icu_locale_core::Locale;
+
+    let parsed = locale.parse::<Locale>().map_err(|e| {
+        DaftError::ValueError(format!("Invalid locale for collation: {locale}: {e}"))
+    })?;
+    let collator = Collator::try_new(
+        CollatorPreferences::from(&parsed),
+        CollatorOptions::default(),
+    )
+    .map_err(|e| DaftError::ValueError(format!("No collation rules for locale {locale}: {e}")))?;
+    Ok(BinaryArray::from_iter(
+        arr.name(),
+        arr.as_arrow().iter().map(|s| {
+            s.map(|s| {
+                let mut key = Vec::new();
+                // Writing to a Vec can't fail
+                let _ = collator.write_sort_key_to(s, &mut key);
+                key
+            })
+        }),
+    ))
+}
+
+#[cfg(not(feature = "icu"))]
+fn locale_collation_key(_arr: &Utf8Array, locale: &str) -> DaftResult<BinaryArray> {
+    Err(DaftError::ValueError(format!(
+        "Collation by locale ({locale}) requires Daft to be built with the `icu` feature"
+    )))
+}
+
 impl Utf8Array {
+    /// Keys whose byte-wise order is the order of the strings under `collation`, so that sorting,
+    /// comparing, grouping or joining by the keys collates the strings.
+    pub fn collation_key(&self, collation: &Collation) -> DaftResult<BinaryArray> {
+        match collation {
+            Collation::Binary => Ok(BinaryArray::from_iter(
+                self.name(),
+                self.as_arrow().iter().map(|s| s.map(str::as_bytes)),
+            )),
+            Collation::CaseInsensitive => Ok(BinaryArray::from_iter(
+                self.name(),
+                self.as_arrow().iter().map(|s| s.map(str::to_lowercase)),
+            )),
+            Collation::Locale(locale) => locale_collation_key(self, locale),
+        }
+    }
+
     pub fn endswith(&self, pattern: &Self) -> DaftResult<BooleanArray> {
         self.binary_broadcasted_compare(
             pattern,
@@ -1475,4 +1566,34 @@ mod tests {
         assert!(result.as_arrow().value(2));
         Ok(())
     }
+
+    #[test]
+    fn check_case_insensitive_collation_key() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                "ABC".into(),
+                "abc".into(),
+                "abd".into(),
+                None,
+            ])),
+        ));
+        let keys = data.collation_key(&"case_insensitive".parse()?)?;
+        assert_eq!(keys.get(0), keys.get(1));
+        assert!(keys.get(1) < keys.get(2));
+        assert_eq!(keys.get(3), None);
+        Ok(())
+    }
+
+    #[test]
+    fn check_parse_collation() -> DaftResult<()> {
+        assert_eq!("Binary".parse::<Collation>()?, Collation::Binary);
+        assert_eq!("nocase".parse::<Collation>()?, Collation::CaseInsensitive);
+        assert_eq!(
+            "de-DE".parse::<Collation>()?,
+            Collation::Locale("de-DE".to_string())
+        );
+        assert!("".parse::<Collation>().is_err());
+        Ok(())
+    }
 }
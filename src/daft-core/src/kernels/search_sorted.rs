@@ -434,27 +434,33 @@ pub fn build_compare_with_nulls(
     left: &dyn Array,
     right: &dyn Array,
     reversed: bool,
+    nulls_first: bool,
 ) -> Result<DynComparator> {
     let comparator = build_compare_with_nan(left, right)?;
     let left_is_valid = build_is_valid(left);
     let right_is_valid = build_is_valid(right);
+    let null_ordering = if nulls_first {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
 
     if reversed {
         Ok(Box::new(move |i: usize, j: usize| {
             match (left_is_valid(i), right_is_valid(j)) {
                 (true, true) => comparator(i, j).reverse(),
-                (false, true) => Ordering::Less,
+                (false, true) => null_ordering,
                 (false, false) => Ordering::Equal,
-                (true, false) => Ordering::Greater,
+                (true, false) => null_ordering.reverse(),
             }
         }))
     } else {
         Ok(Box::new(move |i: usize, j: usize| {
             match (left_is_valid(i), right_is_valid(j)) {
                 (true, true) => comparator(i, j),
-                (false, true) => Ordering::Greater,
+                (false, true) => null_ordering,
                 (false, false) => Ordering::Equal,
-                (true, false) => Ordering::Less,
+                (true, false) => null_ordering.reverse(),
             }
         }))
     }
@@ -496,7 +502,8 @@ pub fn build_partial_compare_with_nulls(
 pub fn search_sorted_multi_array(
     sorted_arrays: &Vec<&dyn Array>,
     key_arrays: &Vec<&dyn Array>,
-    input_reversed: &Vec<bool>,
+    input_reversed: &[bool],
+    nulls_first: &[bool],
 ) -> Result<PrimitiveArray<u64>> {
     if sorted_arrays.is_empty() || key_arrays.is_empty() {
         return Err(Error::InvalidArgumentError(
@@ -531,8 +538,16 @@ pub fn search_sorted_multi_array(
         }
     }
     let mut cmp_list = Vec::with_capacity(sorted_arrays.len());
-    for ((sorted_arr, key_arr), reversed) in zip(sorted_arrays, key_arrays).zip(input_reversed) {
-        cmp_list.push(build_compare_with_nulls(*sorted_arr, *key_arr, *reversed)?);
+    for (((sorted_arr, key_arr), reversed), nulls_first) in zip(sorted_arrays, key_arrays)
+        .zip(input_reversed)
+        .zip(nulls_first)
+    {
+        cmp_list.push(build_compare_with_nulls(
+            *sorted_arr,
+            *key_arr,
+            *reversed,
+            *nulls_first,
+        )?);
     }
 
     let combined_comparator = |a_idx: usize, b_idx: usize| -> Ordering {
@@ -0,0 +1,236 @@
+use std::cmp::Reverse;
+
+use arrow2::offset::OffsetsBuffer;
+use common_error::{DaftError, DaftResult};
+use indexmap::{
+    map::{raw_entry_v1::RawEntryMut, RawEntryApiV1},
+    IndexMap,
+};
+
+use crate::{
+    array::{
+        ops::{arrow2::comparison::build_is_equal, as_arrow::AsArrow, GroupIndices},
+        ListArray, StructArray,
+    },
+    datatypes::{BooleanArray, DataType, Field, Int64Array, UInt64Array},
+    kernels::search_sorted::build_is_valid,
+    series::{IntoSeries, Series},
+    utils::identity_hash_set::IdentityBuildHasher,
+};
+
+/// The type of the summaries of `approx_heavy_hitters`: a list of the values that were counted,
+/// with their counts, in descending order of count.
+#[must_use]
+pub fn heavy_hitters_dtype(value_dtype: &DataType) -> DataType {
+    DataType::List(Box::new(DataType::Struct(vec![
+        Field::new("value", value_dtype.clone()),
+        Field::new("count", DataType::UInt64),
+    ])))
+}
+
+struct IndexRef {
+    index: usize,
+    hash: u64,
+}
+
+impl std::hash::Hash for IndexRef {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+/// Counts the values of each group of entries, adds the floor of the group to every count, and
+/// keeps the `capacity` values with the highest counts as a SpaceSaving summary of the group.
+fn summarize_counts(
+    name: &str,
+    values: &Series,
+    counts: &[u64],
+    groups: impl Iterator<Item = (Vec<usize>, u64)>,
+    capacity: usize,
+) -> DaftResult<Series> {
+    let hashes = values.hash(None)?;
+    let arrow_values = values.to_arrow();
+    let is_equal = build_is_equal(
+        arrow_values.as_ref(),
+        arrow_values.as_ref(),
+        false, // nulls are never counted
+        true,  // NaNs are counted as one value
+    )?;
+    let is_valid = build_is_valid(arrow_values.as_ref());
+
+    let mut indices = vec![];
+    let mut summary_counts = vec![];
+    let mut offsets = vec![0_i64];
+    let mut map: IndexMap<IndexRef, u64, IdentityBuildHasher> = IndexMap::default();
+    for (entries, floor) in groups {
+        map.clear();
+        for index in entries {
+            if !is_valid(index) {
+                continue;
+            }
+            let hash = hashes.get(index).unwrap();
+            match map
+                .raw_entry_mut_v1()
+                .from_hash(hash, |other| is_equal(other.index, index))
+            {
+                RawEntryMut::Occupied(mut entry) => *entry.get_mut() += counts[index],
+                RawEntryMut::Vacant(vacant) => {
+                    vacant.insert(IndexRef { index, hash }, counts[index]);
+                }
+            }
+        }
+        // The sort is stable, so values with equal counts stay in the order they were first seen.
+        let mut counted = map
+            .drain(..)
+            .map(|(value, count)| (value.index, count + floor))
+            .collect::<Vec<_>>();
+        counted.sort_by_key(|&(_, count)| Reverse(count));
+        counted.truncate(capacity);
+        for (index, count) in counted {
+            indices.push(index as u64);
+            summary_counts.push(count);
+        }
+        offsets.push(indices.len() as i64);
+    }
+
+    let summary_values = values
+        .take(&UInt64Array::from(("idx", indices)).into_series())?
+        .rename("value");
+    let summary_counts = UInt64Array::from(("count", summary_counts)).into_series();
+    let dtype = heavy_hitters_dtype(values.data_type());
+    let DataType::List(entry_dtype) = &dtype else {
+        unreachable!("Heavy hitters summaries should be lists")
+    };
+    let entries = StructArray::new(
+        Field::new("entries", entry_dtype.as_ref().clone()),
+        vec![summary_values, summary_counts],
+        None,
+    );
+    Ok(ListArray::new(
+        Field::new(name, dtype),
+        entries.into_series(),
+        OffsetsBuffer::try_from(offsets)?,
+        None,
+    )
+    .into_series())
+}
+
+/// The row indices of each group, or of the whole series as one group.
+fn group_rows(len: usize, groups: Option<&GroupIndices>) -> Vec<Vec<usize>> {
+    match groups {
+        Some(groups) => groups
+            .iter()
+            .map(|group| group.iter().map(|&index| index as usize).collect())
+            .collect(),
+        None => vec![(0..len).collect()],
+    }
+}
+
+impl Series {
+    /// The `k` largest non-null values of each group, in descending order.
+    pub fn top_k(&self, groups: Option<&GroupIndices>, k: usize) -> DaftResult<Self> {
+        let valid_groups = group_rows(self.len(), groups)
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .filter(|&index| self.is_valid(index))
+                    .map(|index| index as u64)
+                    .collect()
+            })
+            .collect::<GroupIndices>();
+        let lists = self.agg_list(Some(&valid_groups))?;
+        let descending = BooleanArray::from(("descending", [true].as_slice())).into_series();
+        let nulls_first = BooleanArray::from(("nulls_first", [false].as_slice())).into_series();
+        let start = Int64Array::from(("start", vec![0])).into_series();
+        let end = Int64Array::from(("end", vec![k as i64])).into_series();
+        lists
+            .list_sort(&descending, &nulls_first)?
+            .list_slice(&start, &end)
+    }
+
+    /// Summarizes the most frequent non-null values of each group by counting them and keeping
+    /// the `capacity` values with the highest counts.
+    ///
+    /// The summaries are exact, and can be merged into approximate summaries of the union of their
+    /// groups with [`Self::merge_heavy_hitters`].
+    pub fn approx_heavy_hitters(
+        &self,
+        groups: Option<&GroupIndices>,
+        capacity: usize,
+    ) -> DaftResult<Self> {
+        let counts = vec![1; self.len()];
+        let groups = group_rows(self.len(), groups)
+            .into_iter()
+            .map(|group| (group, 0));
+        summarize_counts(self.name(), self, &counts, groups, capacity)
+    }
+
+    /// Merges the heavy hitters summaries of each group into one summary of at most `capacity`
+    /// values, as in the mergeable SpaceSaving algorithm.
+    ///
+    /// A value that is missing from a full summary may have been counted up to the lowest count in
+    /// it, so its count in the merged summary includes that lowest count. The merged counts are
+    /// therefore never below the true counts.
+    pub fn merge_heavy_hitters(
+        &self,
+        groups: Option<&GroupIndices>,
+        capacity: usize,
+    ) -> DaftResult<Self> {
+        let DataType::List(entry_dtype) = self.data_type() else {
+            return Err(DaftError::TypeError(format!(
+                "Expected input to merge_heavy_hitters to be a list but received {}",
+                self.data_type()
+            )));
+        };
+        if !matches!(entry_dtype.as_ref(), DataType::Struct(fields) if fields.len() == 2) {
+            return Err(DaftError::TypeError(format!(
+                "Expected input to merge_heavy_hitters to be a list of value and count structs but received {}",
+                self.data_type()
+            )));
+        }
+        let summaries = self.list()?;
+        let entries = summaries.flat_child.struct_()?;
+        let values = &entries.children[0];
+        let counts = entries.children[1].u64()?.as_arrow();
+        let offsets = summaries.offsets();
+
+        // The floor of a summary is its lowest count if it is full, or zero otherwise. Each
+        // value's count is kept relative to the floor of its summary, and the floors of all of the
+        // summaries of a group are added back to the merged counts.
+        let mut floors = Vec::with_capacity(summaries.len());
+        let mut relative_counts = vec![0; counts.len()];
+        for (summary, range) in offsets.ranges().enumerate() {
+            let floor =
+                if summaries.is_valid(summary) && (range.end - range.start) as usize >= capacity {
+                    range
+                        .clone()
+                        .map(|index| counts.value(index as usize))
+                        .min()
+                        .unwrap_or(0)
+                } else {
+                    0
+                };
+            for index in range {
+                relative_counts[index as usize] = counts.value(index as usize) - floor;
+            }
+            floors.push(floor);
+        }
+
+        let groups = group_rows(summaries.len(), groups)
+            .into_iter()
+            .map(|group| {
+                let floor = group.iter().map(|&summary| floors[summary]).sum();
+                let entries = group
+                    .iter()
+                    .filter(|&&summary| summaries.is_valid(summary))
+                    .flat_map(|&summary| {
+                        let (start, end) = offsets.start_end(summary);
+                        start..end
+                    })
+                    .collect();
+                (entries, floor)
+            });
+        summarize_counts(self.name(), values, &relative_counts, groups, capacity)
+    }
+}
@@ -21,6 +21,7 @@ pub mod float;
 pub mod floor;
 pub mod groups;
 pub mod hash;
+pub mod heavy_hitters;
 pub mod if_else;
 pub mod is_in;
 pub mod len;
@@ -2,13 +2,11 @@ use common_error::{DaftError, DaftResult};
 
 use crate::{
     series::{array_impl::IntoSeries, Series},
-    utils::{ensure_nulls_first, ensure_nulls_first_arr},
     with_match_comparable_daft_types,
 };
 
 impl Series {
     pub fn argsort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
-        ensure_nulls_first(descending, nulls_first)?;
         let series = self.as_physical()?;
         with_match_comparable_daft_types!(series.data_type(), |$T| {
             let downcasted = series.downcast::<<$T as DaftDataType>::ArrayType>()?;
@@ -21,7 +19,6 @@ impl Series {
         descending: &[bool],
         nulls_first: &[bool],
     ) -> DaftResult<Self> {
-        ensure_nulls_first_arr(descending, nulls_first)?;
         if sort_keys.len() != descending.len() {
             return Err(DaftError::ValueError(format!(
                 "sort_keys and descending length must match, got {} vs {}",
@@ -1,7 +1,7 @@
 use common_error::{DaftError, DaftResult};
 
 use crate::{
-    array::ops::{PadPlacement, Utf8NormalizeOptions},
+    array::ops::{Collation, PadPlacement, Utf8NormalizeOptions},
     datatypes::*,
     series::{array_impl::IntoSeries, Series},
     with_match_integer_daft_types,
@@ -259,6 +259,10 @@ impl Series {
         self.with_utf8_array(|arr| Ok(arr.normalize(opts)?.into_series()))
     }
 
+    pub fn utf8_collation_key(&self, collation: &Collation) -> DaftResult<Self> {
+        self.with_utf8_array(|arr| Ok(arr.collation_key(collation)?.into_series()))
+    }
+
     pub fn utf8_count_matches(
         &self,
         patterns: &Self,
@@ -158,7 +158,7 @@ pub async fn stream_csv(
     let uri = uri.as_str();
     let (source_type, _) = parse_url(uri)?;
     let is_compressed = CompressionCodec::from_uri(uri).is_some();
-    if matches!(source_type, SourceType::File) && !is_compressed {
+    if matches!(source_type, SourceType::File) && !is_compressed && !io_client.has_transform() {
         let stream = stream_csv_local(
             uri,
             convert_options,
@@ -235,7 +235,7 @@ async fn read_csv_single_into_table(
 ) -> DaftResult<Table> {
     let (source_type, _) = parse_url(uri)?;
     let is_compressed = CompressionCodec::from_uri(uri).is_some();
-    if matches!(source_type, SourceType::File) && !is_compressed {
+    if matches!(source_type, SourceType::File) && !is_compressed && !io_client.has_transform() {
         return read_csv_local(
             uri,
             convert_options,
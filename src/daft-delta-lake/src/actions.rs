@@ -0,0 +1,250 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use snafu::ResultExt;
+
+use crate::{InvalidActionSnafu, Result};
+
+/// Reader and writer versions a client needs to support to read or write a table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Protocol {
+    pub min_reader_version: i32,
+    pub min_writer_version: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reader_features: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub writer_features: Option<Vec<String>>,
+}
+
+impl Protocol {
+    /// The lowest protocol that supports the given schema, where `timestamp_ntz` columns need the
+    /// `timestampNtz` table feature.
+    pub fn for_schema(uses_timestamp_ntz: bool) -> Self {
+        if uses_timestamp_ntz {
+            let features = Some(vec!["timestampNtz".to_string()]);
+            Self {
+                min_reader_version: 3,
+                min_writer_version: 7,
+                reader_features: features.clone(),
+                writer_features: features,
+            }
+        } else {
+            Self {
+                min_reader_version: 1,
+                min_writer_version: 2,
+                reader_features: None,
+                writer_features: None,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Format {
+    pub provider: String,
+    #[serde(default)]
+    pub options: BTreeMap<String, String>,
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self {
+            provider: "parquet".to_string(),
+            options: BTreeMap::new(),
+        }
+    }
+}
+
+/// Schema, partitioning and configuration of a table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    pub id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub format: Format,
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: BTreeMap<String, String>,
+    pub created_time: Option<i64>,
+}
+
+impl Metadata {
+    /// Value of the `delta.checkpointInterval` table property, if set to a valid interval.
+    pub fn checkpoint_interval(&self) -> Option<i64> {
+        self.configuration
+            .get("delta.checkpointInterval")
+            .and_then(|interval| interval.parse().ok())
+            .filter(|interval| *interval > 0)
+    }
+}
+
+/// Decodes the path of a data file in the log, which is a URI whose special characters, e.g. in
+/// the partition values of the path, may be percent-encoded differently by different writers.
+pub fn decode_path(path: &str) -> Cow<'_, str> {
+    urlencoding::decode(path).unwrap_or(Cow::Borrowed(path))
+}
+
+/// A data file that is part of the table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Add {
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: BTreeMap<String, Option<String>>,
+    pub size: i64,
+    pub modification_time: i64,
+    pub data_change: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<String>,
+}
+
+impl Add {
+    /// Number of records in the file, from its statistics.
+    pub fn num_records(&self) -> Option<i64> {
+        let stats: Value = serde_json::from_str(self.stats.as_ref()?).ok()?;
+        stats.get("numRecords")?.as_i64()
+    }
+}
+
+/// A data file that was removed from the table, kept as a tombstone until it is vacuumed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Remove {
+    pub path: String,
+    pub deletion_timestamp: Option<i64>,
+    pub data_change: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extended_file_metadata: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition_values: Option<BTreeMap<String, Option<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+}
+
+impl Remove {
+    /// Removes `add` from the table at `deletion_timestamp`.
+    pub fn from_add(add: &Add, deletion_timestamp: i64) -> Self {
+        Self {
+            path: add.path.clone(),
+            deletion_timestamp: Some(deletion_timestamp),
+            data_change: true,
+            extended_file_metadata: Some(true),
+            partition_values: Some(add.partition_values.clone()),
+            size: Some(add.size),
+        }
+    }
+}
+
+/// The latest version of an application's transactions, for idempotent writes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Txn {
+    pub app_id: String,
+    pub version: i64,
+    pub last_updated: Option<i64>,
+}
+
+/// A single action of a commit. Commit files hold one action per line as a JSON object whose only
+/// key is the kind of the action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Action {
+    Protocol(Protocol),
+    MetaData(Metadata),
+    Add(Add),
+    Remove(Remove),
+    Txn(Txn),
+    CommitInfo(serde_json::Map<String, Value>),
+}
+
+/// Parses the actions of a commit file at `path`. Kinds of actions that do not affect which files
+/// are in the table, such as change data files, are skipped.
+pub fn parse_commit(path: &str, bytes: &[u8]) -> Result<Vec<Action>> {
+    let mut actions = Vec::new();
+    for line in bytes.split(|b| *b == b'\n') {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        let value: serde_json::Map<String, Value> =
+            serde_json::from_slice(line).context(InvalidActionSnafu { path })?;
+        if let Some(action) = action_from_json(value).context(InvalidActionSnafu { path })? {
+            actions.push(action);
+        }
+    }
+    Ok(actions)
+}
+
+/// Converts a JSON object of a single action to an [`Action`], or `None` if it is of a kind that
+/// is skipped.
+pub(crate) fn action_from_json(
+    mut value: serde_json::Map<String, Value>,
+) -> serde_json::Result<Option<Action>> {
+    const KINDS: [&str; 6] = ["protocol", "metaData", "add", "remove", "txn", "commitInfo"];
+    value.retain(|kind, _| KINDS.contains(&kind.as_str()));
+    if value.is_empty() {
+        return Ok(None);
+    }
+    serde_json::from_value(Value::Object(value)).map(Some)
+}
+
+/// Serializes actions into the contents of a commit file.
+pub fn serialize_commit(actions: &[Action]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for action in actions {
+        serde_json::to_writer(&mut bytes, action).expect("Delta actions are always serializable");
+        bytes.push(b'\n');
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_roundtrip() {
+        let add = Add {
+            path: "part=1/0-abc-0.parquet".to_string(),
+            partition_values: BTreeMap::from([("part".to_string(), Some("1".to_string()))]),
+            size: 100,
+            modification_time: 1,
+            data_change: true,
+            stats: Some(r#"{"numRecords":3}"#.to_string()),
+        };
+        let actions = vec![
+            Action::Protocol(Protocol::for_schema(false)),
+            Action::Add(add.clone()),
+            Action::Remove(Remove::from_add(&add, 2)),
+        ];
+
+        let bytes = serialize_commit(&actions);
+        assert!(std::str::from_utf8(&bytes)
+            .unwrap()
+            .starts_with(r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#));
+        assert_eq!(parse_commit("commit", &bytes).unwrap(), actions);
+        assert_eq!(add.num_records(), Some(3));
+    }
+
+    #[test]
+    fn test_parse_commit_skips_unknown_actions() {
+        let commit = br#"{"cdc":{"path":"_change_data/a.parquet"}}
+
+{"txn":{"appId":"app","version":3}}
+"#;
+        let actions = parse_commit("commit", commit).unwrap();
+        assert_eq!(
+            actions,
+            vec![Action::Txn(Txn {
+                app_id: "app".to_string(),
+                version: 3,
+                last_updated: None,
+            })]
+        );
+    }
+}
@@ -0,0 +1,393 @@
+//! Parquet checkpoints of the log, which hold the state of a table at a version as one action per
+//! row, in a column named after the kind of the action.
+
+use std::io::Cursor;
+
+use arrow2::{
+    array::{
+        Array, BooleanArray, Int32Array, Int64Array, ListArray, MapArray, StructArray, Utf8Array,
+    },
+    chunk::Chunk,
+    datatypes::{DataType, Field, PhysicalType, PrimitiveType, Schema},
+    io::parquet::{
+        read::{infer_schema, read_metadata, FileReader},
+        write::{
+            transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version,
+            WriteOptions,
+        },
+    },
+    offset::Offsets,
+};
+use serde_json::Value;
+use snafu::ResultExt;
+
+use crate::{
+    actions::{action_from_json, Action},
+    CheckpointSnafu, InvalidActionSnafu, Result,
+};
+
+fn string_map() -> DataType {
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Utf8, true),
+    ]);
+    DataType::Map(Box::new(Field::new("key_value", entries, false)), false)
+}
+
+fn string_list() -> DataType {
+    DataType::List(Box::new(Field::new("element", DataType::Utf8, true)))
+}
+
+fn checkpoint_schema() -> Schema {
+    let field = |name: &str, data_type| Field::new(name, data_type, true);
+    let add = DataType::Struct(vec![
+        field("path", DataType::Utf8),
+        field("partitionValues", string_map()),
+        field("size", DataType::Int64),
+        field("modificationTime", DataType::Int64),
+        field("dataChange", DataType::Boolean),
+        field("stats", DataType::Utf8),
+    ]);
+    let remove = DataType::Struct(vec![
+        field("path", DataType::Utf8),
+        field("deletionTimestamp", DataType::Int64),
+        field("dataChange", DataType::Boolean),
+        field("extendedFileMetadata", DataType::Boolean),
+        field("partitionValues", string_map()),
+        field("size", DataType::Int64),
+    ]);
+    let metadata = DataType::Struct(vec![
+        field("id", DataType::Utf8),
+        field("name", DataType::Utf8),
+        field("description", DataType::Utf8),
+        field(
+            "format",
+            DataType::Struct(vec![
+                field("provider", DataType::Utf8),
+                field("options", string_map()),
+            ]),
+        ),
+        field("schemaString", DataType::Utf8),
+        field("partitionColumns", string_list()),
+        field("configuration", string_map()),
+        field("createdTime", DataType::Int64),
+    ]);
+    let protocol = DataType::Struct(vec![
+        field("minReaderVersion", DataType::Int32),
+        field("minWriterVersion", DataType::Int32),
+        field("readerFeatures", string_list()),
+        field("writerFeatures", string_list()),
+    ]);
+    let txn = DataType::Struct(vec![
+        field("appId", DataType::Utf8),
+        field("version", DataType::Int64),
+        field("lastUpdated", DataType::Int64),
+    ]);
+    Schema::from(vec![
+        field("txn", txn),
+        field("add", add),
+        field("remove", remove),
+        field("metaData", metadata),
+        field("protocol", protocol),
+    ])
+}
+
+/// Builds an array of `data_type` from JSON values, where values of the wrong type become nulls.
+fn json_to_array(values: &[&Value], data_type: &DataType) -> Box<dyn Array> {
+    match data_type {
+        DataType::Boolean => {
+            BooleanArray::from(values.iter().map(|v| v.as_bool()).collect::<Vec<_>>()).boxed()
+        }
+        DataType::Int32 => Int32Array::from(
+            values
+                .iter()
+                .map(|v| v.as_i64().and_then(|v| i32::try_from(v).ok()))
+                .collect::<Vec<_>>(),
+        )
+        .boxed(),
+        DataType::Int64 => {
+            Int64Array::from(values.iter().map(|v| v.as_i64()).collect::<Vec<_>>()).boxed()
+        }
+        DataType::Utf8 => {
+            Utf8Array::<i32>::from(values.iter().map(|v| v.as_str()).collect::<Vec<_>>()).boxed()
+        }
+        DataType::Struct(fields) => {
+            let children = fields
+                .iter()
+                .map(|field| {
+                    let child = values
+                        .iter()
+                        .map(|v| v.get(&field.name).unwrap_or(&Value::Null))
+                        .collect::<Vec<_>>();
+                    json_to_array(&child, &field.data_type)
+                })
+                .collect();
+            let validity = values.iter().map(|v| v.is_object()).collect();
+            StructArray::new(data_type.clone(), children, Some(validity)).boxed()
+        }
+        DataType::List(field) => {
+            let lists = values.iter().map(|v| v.as_array());
+            let offsets =
+                Offsets::<i32>::try_from_lengths(lists.clone().map(|l| l.map_or(0, Vec::len)))
+                    .expect("checkpoint lists are too short to overflow");
+            let items = lists.clone().flatten().flatten().collect::<Vec<_>>();
+            let validity = lists.map(|l| l.is_some()).collect();
+            ListArray::<i32>::new(
+                data_type.clone(),
+                offsets.into(),
+                json_to_array(&items, &field.data_type),
+                Some(validity),
+            )
+            .boxed()
+        }
+        DataType::Map(field, _) => {
+            let maps = values.iter().map(|v| v.as_object());
+            let offsets =
+                Offsets::<i32>::try_from_lengths(maps.clone().map(|m| m.map_or(0, |m| m.len())))
+                    .expect("checkpoint maps are too short to overflow");
+            let keys = maps
+                .clone()
+                .flatten()
+                .flat_map(|m| m.keys().map(|k| Value::String(k.clone())))
+                .collect::<Vec<_>>();
+            let entries = maps
+                .clone()
+                .flatten()
+                .flat_map(|m| m.values())
+                .collect::<Vec<_>>();
+            let DataType::Struct(entry_fields) = &field.data_type else {
+                unreachable!("map entries are structs")
+            };
+            let entries = StructArray::new(
+                field.data_type.clone(),
+                vec![
+                    json_to_array(&keys.iter().collect::<Vec<_>>(), &entry_fields[0].data_type),
+                    json_to_array(&entries, &entry_fields[1].data_type),
+                ],
+                None,
+            );
+            let validity = maps.map(|m| m.is_some()).collect();
+            MapArray::new(
+                data_type.clone(),
+                offsets.into(),
+                entries.boxed(),
+                Some(validity),
+            )
+            .boxed()
+        }
+        _ => unreachable!("checkpoint schema only has the types above"),
+    }
+}
+
+/// Converts the value at `index` of `array` to JSON. Values of types that do not appear in
+/// actions, such as the parsed statistics some writers add to checkpoints, become nulls.
+fn array_value_to_json(array: &dyn Array, index: usize) -> Value {
+    if array.is_null(index) {
+        return Value::Null;
+    }
+    let any = array.as_any();
+    match array.data_type().to_physical_type() {
+        PhysicalType::Boolean => {
+            Value::from(any.downcast_ref::<BooleanArray>().unwrap().value(index))
+        }
+        PhysicalType::Primitive(PrimitiveType::Int32) => {
+            Value::from(any.downcast_ref::<Int32Array>().unwrap().value(index))
+        }
+        PhysicalType::Primitive(PrimitiveType::Int64) => {
+            Value::from(any.downcast_ref::<Int64Array>().unwrap().value(index))
+        }
+        PhysicalType::Utf8 => {
+            Value::from(any.downcast_ref::<Utf8Array<i32>>().unwrap().value(index))
+        }
+        PhysicalType::LargeUtf8 => {
+            Value::from(any.downcast_ref::<Utf8Array<i64>>().unwrap().value(index))
+        }
+        PhysicalType::Struct => {
+            let array = any.downcast_ref::<StructArray>().unwrap();
+            Value::Object(
+                array
+                    .fields()
+                    .iter()
+                    .zip(array.values())
+                    .map(|(field, child)| {
+                        (
+                            field.name.clone(),
+                            array_value_to_json(child.as_ref(), index),
+                        )
+                    })
+                    .collect(),
+            )
+        }
+        PhysicalType::List => {
+            let list = any.downcast_ref::<ListArray<i32>>().unwrap().value(index);
+            Value::Array(
+                (0..list.len())
+                    .map(|i| array_value_to_json(list.as_ref(), i))
+                    .collect(),
+            )
+        }
+        PhysicalType::LargeList => {
+            let list = any.downcast_ref::<ListArray<i64>>().unwrap().value(index);
+            Value::Array(
+                (0..list.len())
+                    .map(|i| array_value_to_json(list.as_ref(), i))
+                    .collect(),
+            )
+        }
+        PhysicalType::Map => {
+            let entries = any.downcast_ref::<MapArray>().unwrap().value(index);
+            let entries = entries.as_any().downcast_ref::<StructArray>().unwrap();
+            let [keys, values] = entries.values() else {
+                return Value::Null;
+            };
+            Value::Object(
+                (0..entries.len())
+                    .filter_map(|i| {
+                        let key = array_value_to_json(keys.as_ref(), i);
+                        let key = key.as_str()?.to_string();
+                        Some((key, array_value_to_json(values.as_ref(), i)))
+                    })
+                    .collect(),
+            )
+        }
+        _ => Value::Null,
+    }
+}
+
+/// Writes the actions of a snapshot to a checkpoint.
+pub fn write_checkpoint(actions: &[Action]) -> Result<Vec<u8>> {
+    let schema = checkpoint_schema();
+    let rows = actions
+        .iter()
+        .map(|action| serde_json::to_value(action).expect("Delta actions are always serializable"))
+        .collect::<Vec<_>>();
+    let columns = schema
+        .fields
+        .iter()
+        .map(|field| {
+            let values = rows
+                .iter()
+                .map(|row| row.get(&field.name).unwrap_or(&Value::Null))
+                .collect::<Vec<_>>();
+            json_to_array(&values, &field.data_type)
+        })
+        .collect::<Vec<_>>();
+    let chunk = Chunk::new(columns);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        version: Version::V2,
+        compression: CompressionOptions::Snappy,
+        data_pagesize_limit: None,
+    };
+    let encodings = schema
+        .fields
+        .iter()
+        .map(|field| transverse(&field.data_type, |_| Encoding::Plain))
+        .collect();
+    let row_groups =
+        RowGroupIterator::try_new(std::iter::once(Ok(chunk)), &schema, options, encodings)
+            .context(CheckpointSnafu)?;
+
+    let mut writer = FileWriter::try_new(Vec::new(), schema, options).context(CheckpointSnafu)?;
+    for group in row_groups {
+        writer
+            .write(group.context(CheckpointSnafu)?)
+            .context(CheckpointSnafu)?;
+    }
+    writer.end(None).context(CheckpointSnafu)?;
+    Ok(writer.into_inner())
+}
+
+/// Reads the actions of a checkpoint at `path`.
+pub fn read_checkpoint(path: &str, bytes: &[u8]) -> Result<Vec<Action>> {
+    const KINDS: [&str; 5] = ["txn", "add", "remove", "metaData", "protocol"];
+
+    let mut reader = Cursor::new(bytes);
+    let metadata = read_metadata(&mut reader).context(CheckpointSnafu)?;
+    let schema = infer_schema(&metadata)
+        .context(CheckpointSnafu)?
+        .filter(|_, field| KINDS.contains(&field.name.as_str()));
+    let names = schema
+        .fields
+        .iter()
+        .map(|field| field.name.clone())
+        .collect::<Vec<_>>();
+
+    let mut actions = Vec::new();
+    for chunk in FileReader::new(reader, metadata.row_groups, schema, None, None, None) {
+        let chunk = chunk.context(CheckpointSnafu)?;
+        for row in 0..chunk.len() {
+            for (name, column) in names.iter().zip(chunk.columns()) {
+                if column.is_null(row) {
+                    continue;
+                }
+                let value = serde_json::Map::from_iter([(
+                    name.clone(),
+                    array_value_to_json(column.as_ref(), row),
+                )]);
+                if let Some(action) =
+                    action_from_json(value).context(InvalidActionSnafu { path })?
+                {
+                    actions.push(action);
+                }
+            }
+        }
+    }
+    Ok(actions)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::actions::{Add, Format, Metadata, Protocol, Remove, Txn};
+
+    #[test]
+    fn test_checkpoint_roundtrip() {
+        let add = Add {
+            path: "a=1/0-abc-0.parquet".to_string(),
+            partition_values: BTreeMap::from([
+                ("a".to_string(), Some("1".to_string())),
+                ("b".to_string(), None),
+            ]),
+            size: 10,
+            modification_time: 2,
+            data_change: true,
+            stats: Some(r#"{"numRecords":1}"#.to_string()),
+        };
+        let actions = vec![
+            Action::Protocol(Protocol::for_schema(true)),
+            Action::MetaData(Metadata {
+                id: "id".to_string(),
+                name: Some("table".to_string()),
+                description: None,
+                format: Format::default(),
+                schema_string: r#"{"type":"struct","fields":[]}"#.to_string(),
+                partition_columns: vec!["a".to_string(), "b".to_string()],
+                configuration: BTreeMap::from([(
+                    "delta.checkpointInterval".to_string(),
+                    "5".to_string(),
+                )]),
+                created_time: Some(1),
+            }),
+            Action::Txn(Txn {
+                app_id: "app".to_string(),
+                version: 1,
+                last_updated: None,
+            }),
+            Action::Add(add.clone()),
+            Action::Remove(Remove::from_add(
+                &Add {
+                    path: "removed.parquet".to_string(),
+                    ..add
+                },
+                3,
+            )),
+        ];
+
+        let bytes = write_checkpoint(&actions).unwrap();
+        assert_eq!(read_checkpoint("checkpoint", &bytes).unwrap(), actions);
+    }
+}
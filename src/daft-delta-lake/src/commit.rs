@@ -0,0 +1,423 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde_json::{json, Value};
+use snafu::OptionExt;
+
+use crate::{
+    actions::{decode_path, Action, Add, Format, Metadata, Protocol, Remove},
+    schema::uses_timestamp_ntz,
+    snapshot::Snapshot,
+    ConcurrentAppendSnafu, ConcurrentCreateSnafu, ConcurrentDeleteSnafu,
+    ConcurrentTransactionSnafu, MetadataChangedSnafu, ReplacedFileMissingSnafu, Result,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteMode {
+    /// Adds the written files to the table.
+    Append,
+    /// Replaces the files of the table with the written files.
+    Overwrite,
+    /// Replaces the files in [`DeltaWrite::replaced_paths`] with the written files, which hold
+    /// the same rows in fewer files.
+    Compact,
+}
+
+impl WriteMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Append => "Append",
+            Self::Overwrite => "Overwrite",
+            Self::Compact => "Compact",
+        }
+    }
+}
+
+/// A write of data files to a table, which becomes a [`Transaction`] once the snapshot it is
+/// written on top of is known.
+#[derive(Debug, Clone)]
+pub struct DeltaWrite {
+    pub mode: WriteMode,
+    /// The `schemaString` of the written data.
+    pub schema_string: String,
+    pub partition_columns: Vec<String>,
+    /// Whether an overwrite may replace the schema of an existing table.
+    pub overwrite_schema: bool,
+    /// Name, description and configuration of the table if the write creates it.
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub configuration: BTreeMap<String, String>,
+    /// Extra entries for the commit info of the write.
+    pub custom_metadata: BTreeMap<String, String>,
+    pub adds: Vec<Add>,
+    /// Paths of the files that a compaction replaces with the written files.
+    pub replaced_paths: Vec<String>,
+}
+
+impl DeltaWrite {
+    /// Builds the transaction of this write on top of `snapshot`, or of the creation of the table
+    /// if there is no snapshot, at time `now` in milliseconds since the epoch.
+    ///
+    /// Fails if a compaction replaces files that are not part of `snapshot`.
+    pub fn into_transaction(self, snapshot: Option<&Snapshot>, now: i64) -> Result<Transaction> {
+        let mut actions = Vec::new();
+        match snapshot {
+            None => {
+                actions.push(Action::Protocol(Protocol::for_schema(uses_timestamp_ntz(
+                    &self.schema_string,
+                ))));
+                actions.push(Action::MetaData(Metadata {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    name: self.name.clone(),
+                    description: self.description.clone(),
+                    format: Format::default(),
+                    schema_string: self.schema_string.clone(),
+                    partition_columns: self.partition_columns.clone(),
+                    configuration: self.configuration.clone(),
+                    created_time: Some(now),
+                }));
+            }
+            Some(snapshot) => {
+                let replaces_schema = self.mode == WriteMode::Overwrite
+                    && self.overwrite_schema
+                    && snapshot.metadata.schema_string != self.schema_string;
+                if replaces_schema {
+                    if uses_timestamp_ntz(&self.schema_string)
+                        && snapshot.protocol.min_writer_version < 7
+                    {
+                        actions.push(Action::Protocol(Protocol::for_schema(true)));
+                    }
+                    actions.push(Action::MetaData(Metadata {
+                        schema_string: self.schema_string.clone(),
+                        partition_columns: self.partition_columns.clone(),
+                        ..snapshot.metadata.clone()
+                    }));
+                }
+                match self.mode {
+                    WriteMode::Append => {}
+                    WriteMode::Overwrite => actions.extend(
+                        snapshot
+                            .files
+                            .values()
+                            .map(|add| Action::Remove(Remove::from_add(add, now))),
+                    ),
+                    // Rewriting files does not change the data of the table, which readers of
+                    // the change data feed and streaming readers rely on to skip the commit.
+                    WriteMode::Compact => {
+                        for path in &self.replaced_paths {
+                            let add = snapshot.files.get(decode_path(path).as_ref()).context(
+                                ReplacedFileMissingSnafu {
+                                    version: snapshot.version,
+                                    path,
+                                },
+                            )?;
+                            actions.push(Action::Remove(Remove {
+                                data_change: false,
+                                ..Remove::from_add(add, now)
+                            }));
+                        }
+                    }
+                }
+            }
+        }
+        let is_blind_append = self.mode == WriteMode::Append && snapshot.is_some();
+
+        let mut commit_info = serde_json::Map::new();
+        commit_info.insert("timestamp".to_string(), json!(now));
+        commit_info.insert(
+            "operation".to_string(),
+            json!(match (snapshot, self.mode) {
+                (None, _) => "CREATE TABLE",
+                (Some(_), WriteMode::Compact) => "OPTIMIZE",
+                (Some(_), _) => "WRITE",
+            }),
+        );
+        commit_info.insert(
+            "operationParameters".to_string(),
+            json!({
+                "mode": self.mode.as_str(),
+                "partitionBy": serde_json::to_string(&self.partition_columns)
+                    .expect("partition columns are always serializable"),
+            }),
+        );
+        if let Some(snapshot) = snapshot {
+            commit_info.insert("readVersion".to_string(), json!(snapshot.version));
+        }
+        commit_info.insert("isBlindAppend".to_string(), json!(is_blind_append));
+        commit_info.insert(
+            "engineInfo".to_string(),
+            json!(format!("daft/{}", env!("CARGO_PKG_VERSION"))),
+        );
+        for (key, value) in self.custom_metadata {
+            commit_info.insert(key, Value::String(value));
+        }
+
+        let data_change = self.mode != WriteMode::Compact;
+        actions.extend(
+            self.adds
+                .into_iter()
+                .map(|add| Action::Add(Add { data_change, ..add })),
+        );
+        actions.push(Action::CommitInfo(commit_info));
+        Ok(Transaction {
+            read_version: snapshot.map(|snapshot| snapshot.version),
+            actions,
+        })
+    }
+}
+
+/// The actions of a commit, along with the version of the table they were based on.
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    /// Version of the snapshot the transaction read, or `None` if it creates the table.
+    pub read_version: Option<i64>,
+    pub actions: Vec<Action>,
+}
+
+impl Transaction {
+    /// Whether the transaction depends on the files of the table, rather than only adding to it
+    /// or rearranging files without changing its data.
+    fn reads_files(&self) -> bool {
+        self.actions.iter().any(|action| match action {
+            Action::Remove(remove) => remove.data_change,
+            Action::MetaData(_) => true,
+            _ => false,
+        })
+    }
+
+    /// Checks whether the transaction can still be committed after another writer committed the
+    /// `winning` actions at `version`, which happened after the transaction read the table.
+    ///
+    /// Appends only conflict with changes to the metadata or protocol of the table. Transactions
+    /// that remove files or replace the metadata also conflict with concurrent appends, whose
+    /// files they would miss, and with concurrent removals of the same files. Compactions only
+    /// conflict with concurrent removals of the files they replace.
+    pub fn check_conflicts(&self, version: i64, winning: &[Action]) -> Result<()> {
+        if self.read_version.is_none() {
+            return ConcurrentCreateSnafu { version }.fail();
+        }
+        let removed = self
+            .actions
+            .iter()
+            .filter_map(|action| match action {
+                Action::Remove(remove) => Some(decode_path(&remove.path)),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        let app_ids = self
+            .actions
+            .iter()
+            .filter_map(|action| match action {
+                Action::Txn(txn) => Some(txn.app_id.as_str()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        for action in winning {
+            match action {
+                Action::Protocol(_) | Action::MetaData(_) => {
+                    return MetadataChangedSnafu { version }.fail();
+                }
+                Action::Add(add) if add.data_change && self.reads_files() => {
+                    return ConcurrentAppendSnafu { version }.fail();
+                }
+                Action::Remove(remove) if removed.contains(&decode_path(&remove.path)) => {
+                    return ConcurrentDeleteSnafu {
+                        version,
+                        path: remove.path.clone(),
+                    }
+                    .fail();
+                }
+                Action::Txn(txn) if app_ids.contains(txn.app_id.as_str()) => {
+                    return ConcurrentTransactionSnafu {
+                        version,
+                        app_id: txn.app_id.clone(),
+                    }
+                    .fail();
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    const SCHEMA: &str = r#"{"type":"struct","fields":[]}"#;
+
+    fn add(path: &str) -> Add {
+        Add {
+            path: path.to_string(),
+            partition_values: BTreeMap::new(),
+            size: 1,
+            modification_time: 0,
+            data_change: true,
+            stats: None,
+        }
+    }
+
+    fn write(mode: WriteMode, adds: &[&str]) -> DeltaWrite {
+        DeltaWrite {
+            mode,
+            schema_string: SCHEMA.to_string(),
+            partition_columns: vec![],
+            overwrite_schema: false,
+            name: None,
+            description: None,
+            configuration: BTreeMap::new(),
+            custom_metadata: BTreeMap::from([("job".to_string(), "1".to_string())]),
+            adds: adds.iter().map(|path| add(path)).collect(),
+            replaced_paths: vec![],
+        }
+    }
+
+    fn snapshot(files: &[&str]) -> Snapshot {
+        let create = write(WriteMode::Append, files)
+            .into_transaction(None, 0)
+            .unwrap();
+        Snapshot::replay(0, create.actions).unwrap()
+    }
+
+    #[test]
+    fn test_create_transaction() {
+        let txn = write(WriteMode::Append, &["a.parquet"])
+            .into_transaction(None, 5)
+            .unwrap();
+        assert_eq!(txn.read_version, None);
+        assert!(matches!(txn.actions[0], Action::Protocol(_)));
+        let Action::MetaData(metadata) = &txn.actions[1] else {
+            panic!("expected metadata");
+        };
+        assert_eq!(metadata.schema_string, SCHEMA);
+        assert_eq!(metadata.created_time, Some(5));
+        assert_eq!(txn.actions[2], Action::Add(add("a.parquet")));
+        let Action::CommitInfo(info) = &txn.actions[3] else {
+            panic!("expected commit info");
+        };
+        assert_eq!(info["operation"], "CREATE TABLE");
+        assert_eq!(info["job"], "1");
+    }
+
+    #[test]
+    fn test_overwrite_removes_existing_files() {
+        let snapshot = snapshot(&["a.parquet", "b.parquet"]);
+        let txn = write(WriteMode::Overwrite, &["c.parquet"])
+            .into_transaction(Some(&snapshot), 5)
+            .unwrap();
+        assert_eq!(txn.read_version, Some(0));
+        let removed = txn
+            .actions
+            .iter()
+            .filter_map(|action| match action {
+                Action::Remove(remove) => Some(remove.path.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(removed, vec!["a.parquet", "b.parquet"]);
+
+        let mut state = snapshot.checkpoint_actions(5);
+        state.extend(txn.actions);
+        let files = Snapshot::replay(1, state).unwrap().files;
+        assert_eq!(files.keys().collect::<Vec<_>>(), vec!["c.parquet"]);
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let snapshot = snapshot(&["a.parquet"]);
+        let append = write(WriteMode::Append, &["b.parquet"])
+            .into_transaction(Some(&snapshot), 1)
+            .unwrap();
+        let overwrite = write(WriteMode::Overwrite, &["c.parquet"])
+            .into_transaction(Some(&snapshot), 1)
+            .unwrap();
+        let create = write(WriteMode::Append, &["d.parquet"])
+            .into_transaction(None, 1)
+            .unwrap();
+
+        // Appends never conflict with each other, but do with overwrites.
+        assert!(append.check_conflicts(1, &append.actions).is_ok());
+        assert!(matches!(
+            overwrite.check_conflicts(1, &append.actions),
+            Err(Error::ConcurrentAppend { version: 1 })
+        ));
+        assert!(matches!(
+            overwrite.check_conflicts(1, &overwrite.actions),
+            Err(Error::ConcurrentAppend { .. } | Error::ConcurrentDelete { .. })
+        ));
+        assert!(matches!(
+            append.check_conflicts(1, &create.actions),
+            Err(Error::MetadataChanged { version: 1 })
+        ));
+        assert!(matches!(
+            create.check_conflicts(0, &create.actions),
+            Err(Error::ConcurrentCreate { version: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_compaction_replaces_files_without_changing_data() {
+        let snapshot = snapshot(&["a.parquet", "b.parquet", "c.parquet"]);
+        let compact = DeltaWrite {
+            replaced_paths: vec!["a.parquet".to_string(), "b.parquet".to_string()],
+            ..write(WriteMode::Compact, &["ab.parquet"])
+        }
+        .into_transaction(Some(&snapshot), 5)
+        .unwrap();
+        assert!(compact.actions.iter().all(|action| match action {
+            Action::Add(add) => !add.data_change,
+            Action::Remove(remove) => !remove.data_change,
+            _ => true,
+        }));
+        let Some(Action::CommitInfo(info)) = compact.actions.last() else {
+            panic!("expected commit info");
+        };
+        assert_eq!(info["operation"], "OPTIMIZE");
+
+        let mut state = snapshot.checkpoint_actions(5);
+        state.extend(compact.actions.clone());
+        let files = Snapshot::replay(1, state).unwrap().files;
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec!["ab.parquet", "c.parquet"]
+        );
+
+        // Compactions only conflict with removals of the files they replace.
+        let append = write(WriteMode::Append, &["d.parquet"])
+            .into_transaction(Some(&snapshot), 1)
+            .unwrap();
+        let overwrite = write(WriteMode::Overwrite, &["e.parquet"])
+            .into_transaction(Some(&snapshot), 1)
+            .unwrap();
+        assert!(compact.check_conflicts(1, &append.actions).is_ok());
+        assert!(append.check_conflicts(1, &compact.actions).is_ok());
+        assert!(matches!(
+            compact.check_conflicts(1, &overwrite.actions),
+            Err(Error::ConcurrentDelete { .. })
+        ));
+    }
+
+    #[test]
+    fn test_compaction_matches_decoded_paths() {
+        let snapshot = snapshot(&["city=New%20York/a.parquet"]);
+        let compact = DeltaWrite {
+            replaced_paths: vec!["city=New York/a.parquet".to_string()],
+            ..write(WriteMode::Compact, &["b.parquet"])
+        }
+        .into_transaction(Some(&snapshot), 5)
+        .unwrap();
+        assert!(compact.actions.iter().any(|action| matches!(
+            action,
+            Action::Remove(remove) if remove.path == "city=New%20York/a.parquet"
+        )));
+
+        // Files that are no longer part of the table can't be replaced.
+        let missing = DeltaWrite {
+            replaced_paths: vec!["c.parquet".to_string()],
+            ..write(WriteMode::Compact, &["b.parquet"])
+        }
+        .into_transaction(Some(&snapshot), 5);
+        assert!(matches!(missing, Err(Error::ReplacedFileMissing { .. })));
+    }
+}
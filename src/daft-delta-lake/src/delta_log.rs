@@ -0,0 +1,379 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use daft_io::IOClient;
+use serde::{Deserialize, Serialize};
+use snafu::{OptionExt, ResultExt};
+
+use crate::{
+    actions::{parse_commit, serialize_commit, Action},
+    checkpoint::{read_checkpoint, write_checkpoint},
+    commit::Transaction,
+    now_millis,
+    snapshot::Snapshot,
+    InvalidActionSnafu, MissingCheckpointSnafu, Result, TooManyRetriesSnafu, UnsafeCommitSnafu,
+};
+
+/// How many commits are made between checkpoints of a table, unless its
+/// `delta.checkpointInterval` property says otherwise.
+pub const DEFAULT_CHECKPOINT_INTERVAL: i64 = 10;
+
+/// How many times a commit is retried at a later version when concurrent writers commit first.
+pub const DEFAULT_MAX_COMMIT_RETRIES: usize = 15;
+
+/// Contents of the `_last_checkpoint` file, which points readers at the latest checkpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LastCheckpoint {
+    version: i64,
+    size: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parts: Option<i64>,
+}
+
+/// The `_delta_log` directory of a table, which holds a JSON file of actions for each version of
+/// the table, and parquet checkpoints of the state of the table at some of the versions.
+pub struct DeltaLog {
+    table_uri: String,
+    io_client: Arc<IOClient>,
+}
+
+impl DeltaLog {
+    pub fn new(table_uri: &str, io_client: Arc<IOClient>) -> Self {
+        Self {
+            table_uri: table_uri.trim_end_matches('/').to_string(),
+            io_client,
+        }
+    }
+
+    fn log_file_uri(&self, file_name: &str) -> String {
+        format!("{}/_delta_log/{file_name}", self.table_uri)
+    }
+
+    fn commit_uri(&self, version: i64) -> String {
+        self.log_file_uri(&format!("{version:020}.json"))
+    }
+
+    fn checkpoint_uris(&self, version: i64, parts: Option<i64>) -> Vec<String> {
+        match parts {
+            None => vec![self.log_file_uri(&format!("{version:020}.checkpoint.parquet"))],
+            Some(parts) => (1..=parts)
+                .map(|part| {
+                    self.log_file_uri(&format!(
+                        "{version:020}.checkpoint.{part:010}.{parts:010}.parquet"
+                    ))
+                })
+                .collect(),
+        }
+    }
+
+    /// Reads the file at `uri`, or returns `None` if it does not exist.
+    async fn get(&self, uri: &str) -> Result<Option<Bytes>> {
+        let bytes = async {
+            self.io_client
+                .single_url_get(uri.to_string(), None, None)
+                .await?
+                .bytes()
+                .await
+        };
+        match bytes.await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(daft_io::Error::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Reads the actions committed at `version`, or returns `None` if it was not committed yet.
+    pub async fn read_commit(&self, version: i64) -> Result<Option<Vec<Action>>> {
+        let uri = self.commit_uri(version);
+        match self.get(&uri).await? {
+            Some(bytes) => Ok(Some(parse_commit(&uri, &bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn read_last_checkpoint(&self) -> Result<Option<LastCheckpoint>> {
+        let uri = self.log_file_uri("_last_checkpoint");
+        match self.get(&uri).await? {
+            Some(bytes) => Ok(Some(
+                serde_json::from_slice(&bytes).context(InvalidActionSnafu { path: uri })?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Loads the latest snapshot of the table, or of the table at `max_version` if it is given,
+    /// by replaying its commits since the last checkpoint. Returns `None` if the table does not
+    /// exist.
+    pub async fn load_snapshot(&self, max_version: Option<i64>) -> Result<Option<Snapshot>> {
+        let mut version = -1;
+        let mut actions = Vec::new();
+        let last_checkpoint = self
+            .read_last_checkpoint()
+            .await?
+            .filter(|checkpoint| max_version.map_or(true, |max| checkpoint.version <= max));
+        if let Some(checkpoint) = last_checkpoint {
+            for uri in self.checkpoint_uris(checkpoint.version, checkpoint.parts) {
+                let bytes = self
+                    .get(&uri)
+                    .await?
+                    .context(MissingCheckpointSnafu { path: &uri })?;
+                actions.extend(read_checkpoint(&uri, &bytes)?);
+            }
+            version = checkpoint.version;
+        }
+        while max_version.map_or(true, |max| version < max) {
+            let Some(commit) = self.read_commit(version + 1).await? else {
+                break;
+            };
+            actions.extend(commit);
+            version += 1;
+        }
+        if version < 0 {
+            return Ok(None);
+        }
+        Snapshot::replay(version, actions).map(Some)
+    }
+
+    /// Commits a transaction at the version after the one it read. If other writers committed
+    /// that version first, their commits are checked for conflicts with the transaction, which is
+    /// then retried at the next version, up to `max_retries` times.
+    ///
+    /// Commits rely on the object store failing to write a commit file that already exists, so
+    /// two writers cannot both commit the same version. Stores without conditional writes only
+    /// check for the file before writing it, which can lose commits under contention, so
+    /// committing to them fails unless `allow_unsafe_rename` is set.
+    ///
+    /// Returns the version that the transaction was committed at.
+    pub async fn commit(
+        &self,
+        txn: &Transaction,
+        max_retries: usize,
+        allow_unsafe_rename: bool,
+    ) -> Result<i64> {
+        if !allow_unsafe_rename
+            && !self
+                .io_client
+                .supports_put_if_absent(&self.table_uri)
+                .await?
+        {
+            return UnsafeCommitSnafu {
+                table_uri: &self.table_uri,
+            }
+            .fail();
+        }
+        let data = Bytes::from(serialize_commit(&txn.actions));
+        let mut version = txn.read_version.map_or(0, |read_version| read_version + 1);
+        for _ in 0..=max_retries {
+            let uri = self.commit_uri(version);
+            match self
+                .io_client
+                .single_url_put_if_absent(&uri, data.clone(), None)
+                .await
+            {
+                Ok(()) => {
+                    self.maybe_checkpoint(version).await;
+                    return Ok(version);
+                }
+                Err(daft_io::Error::AlreadyExists { .. }) => {
+                    let winning = self.read_commit(version).await?.unwrap_or_default();
+                    txn.check_conflicts(version, &winning)?;
+                    version += 1;
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+        TooManyRetriesSnafu { max_retries }.fail()
+    }
+
+    /// Writes a checkpoint at `version` if it is a multiple of the checkpoint interval of the
+    /// table. The commit at `version` has already succeeded by then, so failing to checkpoint only
+    /// makes later reads of the log slower and is logged rather than returned.
+    async fn maybe_checkpoint(&self, version: i64) {
+        if version == 0 {
+            return;
+        }
+        let result = async {
+            let Some(snapshot) = self.load_snapshot(Some(version)).await? else {
+                return Ok(());
+            };
+            let interval = snapshot
+                .metadata
+                .checkpoint_interval()
+                .unwrap_or(DEFAULT_CHECKPOINT_INTERVAL);
+            if version % interval == 0 {
+                self.write_checkpoint(&snapshot).await?;
+            }
+            Ok::<_, crate::Error>(())
+        }
+        .await;
+        if let Err(err) = result {
+            log::warn!(
+                "Failed to write a checkpoint of version {version} of the Delta table at {}: {err}",
+                self.table_uri
+            );
+        }
+    }
+
+    /// Writes a checkpoint of `snapshot` and points `_last_checkpoint` at it.
+    pub async fn write_checkpoint(&self, snapshot: &Snapshot) -> Result<()> {
+        let actions = snapshot.checkpoint_actions(now_millis());
+        let checkpoint = write_checkpoint(&actions)?;
+        let [uri] = &self.checkpoint_uris(snapshot.version, None)[..] else {
+            unreachable!("single-part checkpoints have one file")
+        };
+        self.io_client
+            .single_url_put(uri, Bytes::from(checkpoint), None)
+            .await?;
+
+        let last_checkpoint = LastCheckpoint {
+            version: snapshot.version,
+            size: actions.len() as i64,
+            parts: None,
+        };
+        let last_checkpoint =
+            serde_json::to_vec(&last_checkpoint).expect("last checkpoint is always serializable");
+        self.io_client
+            .single_url_put(
+                &self.log_file_uri("_last_checkpoint"),
+                Bytes::from(last_checkpoint),
+                None,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use daft_io::get_io_client;
+
+    use super::*;
+    use crate::{
+        actions::Add,
+        commit::{DeltaWrite, WriteMode},
+        Error,
+    };
+
+    fn write(mode: WriteMode, path: &str) -> DeltaWrite {
+        DeltaWrite {
+            mode,
+            schema_string: r#"{"type":"struct","fields":[]}"#.to_string(),
+            partition_columns: vec![],
+            overwrite_schema: false,
+            name: None,
+            description: None,
+            configuration: BTreeMap::from([(
+                "delta.checkpointInterval".to_string(),
+                "2".to_string(),
+            )]),
+            custom_metadata: BTreeMap::new(),
+            adds: vec![Add {
+                path: path.to_string(),
+                partition_values: BTreeMap::new(),
+                size: 1,
+                modification_time: 0,
+                data_change: true,
+                stats: None,
+            }],
+            replaced_paths: vec![],
+        }
+    }
+
+    fn paths(snapshot: &Snapshot) -> Vec<&str> {
+        snapshot.files.keys().map(String::as_str).collect()
+    }
+
+    #[tokio::test]
+    async fn test_commit_and_checkpoint() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let table_uri = format!("file://{}", dir.path().to_string_lossy());
+        let log = DeltaLog::new(
+            &table_uri,
+            get_io_client(false, Default::default()).unwrap(),
+        );
+        assert!(log.load_snapshot(None).await?.is_none());
+
+        for (version, path) in ["a", "b", "c"].into_iter().enumerate() {
+            let snapshot = log.load_snapshot(None).await?;
+            let txn = write(WriteMode::Append, path)
+                .into_transaction(snapshot.as_ref(), 0)
+                .unwrap();
+            assert_eq!(log.commit(&txn, 0, false).await?, version as i64);
+        }
+        // Version 2 is a multiple of the checkpoint interval.
+        assert!(dir
+            .path()
+            .join("_delta_log/00000000000000000002.checkpoint.parquet")
+            .exists());
+        assert!(dir.path().join("_delta_log/_last_checkpoint").exists());
+
+        let snapshot = log.load_snapshot(None).await?.unwrap();
+        assert_eq!(snapshot.version, 2);
+        assert_eq!(paths(&snapshot), vec!["a", "b", "c"]);
+        let snapshot = log.load_snapshot(Some(1)).await?.unwrap();
+        assert_eq!(paths(&snapshot), vec!["a", "b"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_commits() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let table_uri = format!("file://{}", dir.path().to_string_lossy());
+        let log = DeltaLog::new(
+            &table_uri,
+            get_io_client(false, Default::default()).unwrap(),
+        );
+        let create = write(WriteMode::Append, "a")
+            .into_transaction(None, 0)
+            .unwrap();
+        log.commit(&create, 0, false).await?;
+
+        // Two writers read version 0, and the second to commit retries on top of the first.
+        let snapshot = log.load_snapshot(None).await?.unwrap();
+        let first = write(WriteMode::Append, "b")
+            .into_transaction(Some(&snapshot), 0)
+            .unwrap();
+        let second = write(WriteMode::Append, "c")
+            .into_transaction(Some(&snapshot), 0)
+            .unwrap();
+        assert_eq!(log.commit(&first, 0, false).await?, 1);
+        assert!(matches!(
+            log.commit(&second, 0, false).await,
+            Err(Error::TooManyRetries { max_retries: 0 })
+        ));
+        assert_eq!(log.commit(&second, 1, false).await?, 2);
+
+        // An overwrite based on a stale snapshot conflicts with the appends it did not see.
+        let overwrite = write(WriteMode::Overwrite, "d")
+            .into_transaction(Some(&snapshot), 0)
+            .unwrap();
+        assert!(matches!(
+            log.commit(&overwrite, 3, false).await,
+            Err(Error::ConcurrentAppend { version: 1 })
+        ));
+
+        let snapshot = log.load_snapshot(None).await?.unwrap();
+        assert_eq!(paths(&snapshot), vec!["a", "b", "c"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_commit_requires_conditional_writes() -> Result<()> {
+        // HTTP sources cannot write objects only if they are absent.
+        let log = DeltaLog::new(
+            "http://localhost/table",
+            get_io_client(false, Default::default()).unwrap(),
+        );
+        let create = write(WriteMode::Append, "a")
+            .into_transaction(None, 0)
+            .unwrap();
+        assert!(matches!(
+            log.commit(&create, 0, false).await,
+            Err(Error::UnsafeCommit { .. })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+//! Commits to the transaction logs of Delta Lake tables.
+//!
+//! The state of a Delta table is the result of replaying the actions in its `_delta_log`
+//! directory, where each version of the table is a JSON file of actions and some versions are
+//! also checkpointed to parquet. A write builds a [`Transaction`] from a [`DeltaWrite`] on top of
+//! the latest [`Snapshot`] of the table, and [`DeltaLog::commit`] then atomically creates the file
+//! of the next version, retrying at later versions if concurrent writers got there first and did
+//! not make conflicting changes.
+
+pub mod actions;
+mod checkpoint;
+mod commit;
+mod delta_log;
+#[cfg(feature = "python")]
+pub mod python;
+mod schema;
+mod snapshot;
+
+pub use commit::{DeltaWrite, Transaction, WriteMode};
+use common_error::DaftError;
+pub use delta_log::{DeltaLog, DEFAULT_CHECKPOINT_INTERVAL, DEFAULT_MAX_COMMIT_RETRIES};
+pub use schema::{delta_schema_string, schemas_match, uses_timestamp_ntz};
+use snafu::Snafu;
+pub use snapshot::Snapshot;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Delta log IO error: {}", source), context(false))]
+    Io { source: daft_io::Error },
+
+    #[snafu(display("Unable to parse Delta log file {}: {}", path, source))]
+    InvalidAction {
+        path: String,
+        source: serde_json::Error,
+    },
+
+    #[snafu(display("Unable to read or write Delta checkpoint: {}", source))]
+    Checkpoint { source: arrow2::error::Error },
+
+    #[snafu(display("Delta checkpoint {} is missing", path))]
+    MissingCheckpoint { path: String },
+
+    #[snafu(display("Delta table at version {} has no protocol", version))]
+    MissingProtocol { version: i64 },
+
+    #[snafu(display("Delta table at version {} has no metadata", version))]
+    MissingMetadata { version: i64 },
+
+    #[snafu(display("Type {} is not supported by Delta Lake", dtype))]
+    UnsupportedType { dtype: String },
+
+    #[snafu(display(
+        "Delta table was created concurrently by a commit at version {}",
+        version
+    ))]
+    ConcurrentCreate { version: i64 },
+
+    #[snafu(display(
+        "Metadata of Delta table was changed concurrently by a commit at version {}",
+        version
+    ))]
+    MetadataChanged { version: i64 },
+
+    #[snafu(display(
+        "Files were concurrently added to Delta table by a commit at version {}",
+        version
+    ))]
+    ConcurrentAppend { version: i64 },
+
+    #[snafu(display(
+        "File {} was concurrently removed from Delta table by a commit at version {}",
+        path,
+        version
+    ))]
+    ConcurrentDelete { version: i64, path: String },
+
+    #[snafu(display(
+        "File {} to replace is not part of Delta table at version {}",
+        path,
+        version
+    ))]
+    ReplacedFileMissing { version: i64, path: String },
+
+    #[snafu(display(
+        "Transaction of application {} was concurrently committed to Delta table at version {}",
+        app_id,
+        version
+    ))]
+    ConcurrentTransaction { version: i64, app_id: String },
+
+    #[snafu(display(
+        "Gave up committing to Delta table after {} retries due to concurrent commits",
+        max_retries
+    ))]
+    TooManyRetries { max_retries: usize },
+
+    #[snafu(display(
+        "Committing to Delta table {} is unsafe because its object store does not support conditional writes; set allow_unsafe_rename to commit anyway",
+        table_uri
+    ))]
+    UnsafeCommit { table_uri: String },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Io { source } => source.into(),
+            _ => Self::External(err.into()),
+        }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The current time in milliseconds since the epoch, which is how Delta stores timestamps.
+fn now_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64)
+}
@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_io::{get_io_client, python::IOConfig};
+use daft_schema::python::schema::PySchema;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::{
+    actions::Add, now_millis, schemas_match, DeltaLog, DeltaWrite, Snapshot, WriteMode,
+    DEFAULT_MAX_COMMIT_RETRIES,
+};
+
+fn delta_log(table_uri: &str, io_config: Option<IOConfig>) -> DaftResult<DeltaLog> {
+    let io_client = get_io_client(true, io_config.unwrap_or_default().config.into())?;
+    Ok(DeltaLog::new(table_uri, io_client))
+}
+
+/// State of a Delta table at a version.
+#[pyclass(module = "daft.daft", name = "DeltaSnapshot", frozen)]
+pub struct PyDeltaSnapshot {
+    snapshot: Snapshot,
+}
+
+#[pymethods]
+impl PyDeltaSnapshot {
+    #[getter]
+    fn version(&self) -> i64 {
+        self.snapshot.version
+    }
+
+    #[getter]
+    fn partition_columns(&self) -> Vec<String> {
+        self.snapshot.metadata.partition_columns.clone()
+    }
+
+    #[getter]
+    fn schema_string(&self) -> String {
+        self.snapshot.metadata.schema_string.clone()
+    }
+
+    /// Whether data with `schema` can be written to the table without replacing its schema.
+    fn schema_matches(&self, schema: PySchema) -> PyResult<bool> {
+        let schema_string = crate::delta_schema_string(&schema.schema).map_err(DaftError::from)?;
+        Ok(schemas_match(
+            &schema_string,
+            &self.snapshot.metadata.schema_string,
+        ))
+    }
+
+    /// The partition values of each data file of the table, by path.
+    fn partition_values(&self) -> BTreeMap<String, BTreeMap<String, Option<String>>> {
+        self.snapshot
+            .files
+            .iter()
+            .map(|(path, add)| (path.clone(), add.partition_values.clone()))
+            .collect()
+    }
+
+    /// The data files of the table, as tuples of their path, size and number of records.
+    fn files(&self) -> Vec<(String, i64, Option<i64>)> {
+        self.snapshot
+            .files
+            .values()
+            .map(|add| (add.path.clone(), add.size, add.num_records()))
+            .collect()
+    }
+}
+
+/// Loads the latest snapshot of the Delta table at `table_uri`, or returns `None` if there is no
+/// table there.
+#[pyfunction]
+#[pyo3(signature = (table_uri, io_config=None))]
+pub fn delta_load_snapshot(
+    py: Python,
+    table_uri: &str,
+    io_config: Option<IOConfig>,
+) -> PyResult<Option<PyDeltaSnapshot>> {
+    let snapshot = py.allow_threads(|| -> DaftResult<_> {
+        let log = delta_log(table_uri, io_config)?;
+        Ok(get_io_runtime(true).block_on_current_thread(log.load_snapshot(None))?)
+    })?;
+    Ok(snapshot.map(|snapshot| PyDeltaSnapshot { snapshot }))
+}
+
+/// Commits data files written to the Delta table at `table_uri` on top of `snapshot`, creating
+/// the table if `snapshot` is `None`. Each add action is the JSON of an `add` action of the Delta
+/// protocol. Compactions replace the files at `replaced_paths` with the added files. Commits to
+/// object stores without conditional writes fail unless `allow_unsafe_rename` is set. Returns the
+/// version of the commit.
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (
+    table_uri,
+    snapshot,
+    mode,
+    schema,
+    partition_columns,
+    add_actions,
+    overwrite_schema=false,
+    name=None,
+    description=None,
+    configuration=None,
+    custom_metadata=None,
+    replaced_paths=None,
+    max_retries=DEFAULT_MAX_COMMIT_RETRIES,
+    allow_unsafe_rename=false,
+    io_config=None
+))]
+pub fn delta_commit(
+    py: Python,
+    table_uri: &str,
+    snapshot: Option<PyRef<PyDeltaSnapshot>>,
+    mode: &str,
+    schema: PySchema,
+    partition_columns: Vec<String>,
+    add_actions: Vec<String>,
+    overwrite_schema: bool,
+    name: Option<String>,
+    description: Option<String>,
+    configuration: Option<BTreeMap<String, Option<String>>>,
+    custom_metadata: Option<BTreeMap<String, String>>,
+    replaced_paths: Option<Vec<String>>,
+    max_retries: usize,
+    allow_unsafe_rename: bool,
+    io_config: Option<IOConfig>,
+) -> PyResult<i64> {
+    let mode = match mode {
+        "append" => WriteMode::Append,
+        "overwrite" => WriteMode::Overwrite,
+        "compact" => WriteMode::Compact,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Delta commits must append, overwrite or compact, found mode: {mode}"
+            )))
+        }
+    };
+    let adds = add_actions
+        .iter()
+        .map(|add| serde_json::from_str::<Add>(add))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| PyValueError::new_err(format!("Invalid Delta add action: {err}")))?;
+    let write = DeltaWrite {
+        mode,
+        schema_string: crate::delta_schema_string(&schema.schema).map_err(DaftError::from)?,
+        partition_columns,
+        overwrite_schema,
+        name,
+        description,
+        configuration: configuration
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(key, value)| Some((key, value?)))
+            .collect(),
+        custom_metadata: custom_metadata.unwrap_or_default(),
+        adds,
+        replaced_paths: replaced_paths.unwrap_or_default(),
+    };
+    let txn = write
+        .into_transaction(snapshot.as_deref().map(|s| &s.snapshot), now_millis())
+        .map_err(DaftError::from)?;
+    let version = py.allow_threads(|| -> DaftResult<_> {
+        let log = delta_log(table_uri, io_config)?;
+        Ok(get_io_runtime(true).block_on_current_thread(log.commit(
+            &txn,
+            max_retries,
+            allow_unsafe_rename,
+        ))?)
+    })?;
+    Ok(version)
+}
+
+pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
+    parent.add_class::<PyDeltaSnapshot>()?;
+    parent.add_function(wrap_pyfunction_bound!(delta_load_snapshot, parent)?)?;
+    parent.add_function(wrap_pyfunction_bound!(delta_commit, parent)?)?;
+    Ok(())
+}
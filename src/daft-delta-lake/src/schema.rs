@@ -0,0 +1,139 @@
+//! Conversion of Daft schemas to the JSON schemas in the metadata of Delta tables.
+
+use daft_schema::{dtype::DataType, field::Field, schema::Schema};
+use serde_json::{json, Value};
+
+use crate::{Result, UnsupportedTypeSnafu};
+
+fn delta_type(dtype: &DataType) -> Result<Value> {
+    Ok(match dtype {
+        DataType::Boolean => json!("boolean"),
+        DataType::Int8 | DataType::UInt8 => json!("byte"),
+        DataType::Int16 | DataType::UInt16 => json!("short"),
+        DataType::Int32 | DataType::UInt32 => json!("integer"),
+        DataType::Int64 | DataType::UInt64 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Decimal128(precision, scale) => json!(format!("decimal({precision},{scale})")),
+        DataType::Utf8 => json!("string"),
+        DataType::Binary | DataType::FixedSizeBinary(_) => json!("binary"),
+        DataType::Date => json!("date"),
+        DataType::Timestamp(_, Some(_)) => json!("timestamp"),
+        DataType::Timestamp(_, None) => json!("timestamp_ntz"),
+        DataType::List(child) | DataType::FixedSizeList(child, _) => json!({
+            "type": "array",
+            "elementType": delta_type(child)?,
+            "containsNull": true,
+        }),
+        DataType::Map { key, value } => json!({
+            "type": "map",
+            "keyType": delta_type(key)?,
+            "valueType": delta_type(value)?,
+            "valueContainsNull": true,
+        }),
+        DataType::Struct(fields) => delta_struct(fields)?,
+        _ => {
+            return UnsupportedTypeSnafu {
+                dtype: dtype.to_string(),
+            }
+            .fail()
+        }
+    })
+}
+
+fn delta_struct<'a>(fields: impl IntoIterator<Item = &'a Field>) -> Result<Value> {
+    let fields = fields
+        .into_iter()
+        .map(|field| {
+            Ok(json!({
+                "name": field.name,
+                "type": delta_type(&field.dtype)?,
+                "nullable": true,
+                "metadata": {},
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(json!({"type": "struct", "fields": fields}))
+}
+
+/// The `schemaString` of the metadata of a table with `schema`.
+pub fn delta_schema_string(schema: &Schema) -> Result<String> {
+    Ok(delta_struct(schema.fields.values())?.to_string())
+}
+
+/// Whether a Delta schema uses `timestamp_ntz`, which needs the `timestampNtz` table feature.
+pub fn uses_timestamp_ntz(schema_string: &str) -> bool {
+    schema_string.contains(r#""timestamp_ntz""#)
+}
+
+/// Removes nullability and field metadata from a Delta schema, which writes do not need to match.
+fn strip_nullability(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            for key in ["nullable", "containsNull", "valueContainsNull", "metadata"] {
+                object.remove(key);
+            }
+            object.values_mut().for_each(strip_nullability);
+        }
+        Value::Array(values) => values.iter_mut().for_each(strip_nullability),
+        _ => {}
+    }
+}
+
+/// Whether data with the Delta schema `data` can be written to a table with the Delta schema
+/// `table`, which requires the same fields with the same types in the same order.
+pub fn schemas_match(data: &str, table: &str) -> bool {
+    let (Ok(mut data), Ok(mut table)) = (
+        serde_json::from_str::<Value>(data),
+        serde_json::from_str::<Value>(table),
+    ) else {
+        return false;
+    };
+    strip_nullability(&mut data);
+    strip_nullability(&mut table);
+    data == table
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_schema::time_unit::TimeUnit;
+
+    use super::*;
+
+    #[test]
+    fn test_delta_schema_string() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::UInt32),
+            Field::new("b", DataType::List(Box::new(DataType::Utf8))),
+            Field::new("c", DataType::Timestamp(TimeUnit::Nanoseconds, None)),
+        ])
+        .unwrap();
+        let schema_string = delta_schema_string(&schema).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&schema_string).unwrap(),
+            json!({"type": "struct", "fields": [
+                {"name": "a", "type": "integer", "nullable": true, "metadata": {}},
+                {
+                    "name": "b",
+                    "type": {"type": "array", "elementType": "string", "containsNull": true},
+                    "nullable": true,
+                    "metadata": {},
+                },
+                {"name": "c", "type": "timestamp_ntz", "nullable": true, "metadata": {}},
+            ]})
+        );
+        assert!(uses_timestamp_ntz(&schema_string));
+
+        let unsupported = Schema::new(vec![Field::new("a", DataType::Python)]).unwrap();
+        assert!(delta_schema_string(&unsupported).is_err());
+    }
+
+    #[test]
+    fn test_schemas_match_ignores_nullability() {
+        let data = r#"{"type":"struct","fields":[{"name":"a","type":"long","nullable":true,"metadata":{}}]}"#;
+        let table = r#"{"type":"struct","fields":[{"name":"a","type":"long","nullable":false,"metadata":{"k":"v"}}]}"#;
+        let other = r#"{"type":"struct","fields":[{"name":"b","type":"long","nullable":true,"metadata":{}}]}"#;
+        assert!(schemas_match(data, table));
+        assert!(!schemas_match(data, other));
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use snafu::OptionExt;
+
+use crate::{
+    actions::{decode_path, Action, Add, Metadata, Protocol, Remove, Txn},
+    MissingMetadataSnafu, MissingProtocolSnafu, Result,
+};
+
+/// Tombstones of removed files are kept in checkpoints for this long, which is the default of the
+/// `delta.deletedFileRetentionDuration` table property.
+const TOMBSTONE_RETENTION_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+
+/// State of a table at a version, reconstructed by replaying its log.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub version: i64,
+    pub protocol: Protocol,
+    pub metadata: Metadata,
+    /// Data files of the table, by decoded path.
+    pub files: BTreeMap<String, Add>,
+    /// Removed data files that have not been vacuumed yet, by decoded path.
+    pub tombstones: BTreeMap<String, Remove>,
+    /// Latest transaction version of each application, by application id.
+    pub txns: BTreeMap<String, Txn>,
+}
+
+impl Snapshot {
+    /// Replays the actions of a table up to and including `version`, in log order.
+    pub fn replay(version: i64, actions: impl IntoIterator<Item = Action>) -> Result<Self> {
+        let mut protocol = None;
+        let mut metadata = None;
+        let mut files = BTreeMap::new();
+        let mut tombstones = BTreeMap::new();
+        let mut txns = BTreeMap::new();
+        for action in actions {
+            match action {
+                Action::Protocol(p) => protocol = Some(p),
+                Action::MetaData(m) => metadata = Some(m),
+                Action::Add(add) => {
+                    let path = decode_path(&add.path).into_owned();
+                    tombstones.remove(&path);
+                    files.insert(path, add);
+                }
+                Action::Remove(remove) => {
+                    let path = decode_path(&remove.path).into_owned();
+                    files.remove(&path);
+                    tombstones.insert(path, remove);
+                }
+                Action::Txn(txn) => {
+                    txns.insert(txn.app_id.clone(), txn);
+                }
+                Action::CommitInfo(_) => {}
+            }
+        }
+        Ok(Self {
+            version,
+            protocol: protocol.context(MissingProtocolSnafu { version })?,
+            metadata: metadata.context(MissingMetadataSnafu { version })?,
+            files,
+            tombstones,
+            txns,
+        })
+    }
+
+    /// The actions of a checkpoint of this snapshot at time `now`, which drops tombstones that are
+    /// past their retention.
+    pub fn checkpoint_actions(&self, now: i64) -> Vec<Action> {
+        let mut actions = vec![
+            Action::Protocol(self.protocol.clone()),
+            Action::MetaData(self.metadata.clone()),
+        ];
+        actions.extend(self.txns.values().cloned().map(Action::Txn));
+        actions.extend(self.files.values().cloned().map(Action::Add));
+        actions.extend(
+            self.tombstones
+                .values()
+                .filter(|remove| {
+                    remove
+                        .deletion_timestamp
+                        .map_or(true, |ts| ts > now - TOMBSTONE_RETENTION_MS)
+                })
+                .cloned()
+                .map(Action::Remove),
+        );
+        actions
+    }
+}
@@ -17,6 +17,7 @@ use daft_core::{
         InferDataType,
     },
     prelude::*,
+    series::ops::heavy_hitters::heavy_hitters_dtype,
     utils::supertype::try_get_supertype,
 };
 use derive_more::Display;
@@ -237,6 +238,15 @@ pub enum AggExpr {
     #[display("list({_0})")]
     Concat(ExprRef),
 
+    #[display("top_k({_0}, k={_1})")]
+    TopK(ExprRef, usize),
+
+    #[display("approx_heavy_hitters({_0}, k={_1})")]
+    ApproxHeavyHitters(ExprRef, usize),
+
+    #[display("merge_heavy_hitters({_0}, capacity={_1})")]
+    MergeHeavyHitters(ExprRef, usize),
+
     #[display("{}", function_display_without_formatter(func, inputs)?)]
     MapGroups {
         func: FunctionExpr,
@@ -274,7 +284,10 @@ impl AggExpr {
             | Self::Max(expr)
             | Self::AnyValue(expr, _)
             | Self::List(expr)
-            | Self::Concat(expr) => expr.name(),
+            | Self::Concat(expr)
+            | Self::TopK(expr, _)
+            | Self::ApproxHeavyHitters(expr, _)
+            | Self::MergeHeavyHitters(expr, _) => expr.name(),
             Self::MapGroups { func: _, inputs } => inputs.first().unwrap().name(),
         }
     }
@@ -350,6 +363,20 @@ impl AggExpr {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_concat()"))
             }
+            Self::TopK(expr, k) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_top_k(k={k})"))
+            }
+            Self::ApproxHeavyHitters(expr, k) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_approx_heavy_hitters(k={k})"))
+            }
+            Self::MergeHeavyHitters(expr, capacity) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!(
+                    "{child_id}.local_merge_heavy_hitters(capacity={capacity})"
+                ))
+            }
             Self::MapGroups { func, inputs } => function_semantic_id(func, inputs, schema),
         }
     }
@@ -369,7 +396,10 @@ impl AggExpr {
             | Self::Max(expr)
             | Self::AnyValue(expr, _)
             | Self::List(expr)
-            | Self::Concat(expr) => vec![expr.clone()],
+            | Self::Concat(expr)
+            | Self::TopK(expr, _)
+            | Self::ApproxHeavyHitters(expr, _)
+            | Self::MergeHeavyHitters(expr, _) => vec![expr.clone()],
             Self::MapGroups { func: _, inputs } => inputs.clone(),
         }
     }
@@ -392,6 +422,11 @@ impl AggExpr {
             Self::AnyValue(_, ignore_nulls) => Self::AnyValue(first_child(), *ignore_nulls),
             Self::List(_) => Self::List(first_child()),
             Self::Concat(_) => Self::Concat(first_child()),
+            Self::TopK(_, k) => Self::TopK(first_child(), *k),
+            Self::ApproxHeavyHitters(_, k) => Self::ApproxHeavyHitters(first_child(), *k),
+            Self::MergeHeavyHitters(_, capacity) => {
+                Self::MergeHeavyHitters(first_child(), *capacity)
+            }
             Self::MapGroups { func, inputs: _ } => Self::MapGroups {
                 func: func.clone(),
                 inputs: children,
@@ -518,6 +553,26 @@ impl AggExpr {
                     ))),
                 }
             }
+            Self::TopK(expr, _) => expr.to_field(schema)?.to_list_field(),
+            Self::ApproxHeavyHitters(expr, _) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    field.name.as_str(),
+                    heavy_hitters_dtype(&field.dtype),
+                ))
+            }
+            Self::MergeHeavyHitters(expr, _) => {
+                let field = expr.to_field(schema)?;
+                match &field.dtype {
+                    DataType::List(child) if matches!(child.as_ref(), DataType::Struct(..)) => {
+                        Ok(field)
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to merge_heavy_hitters() to be a list of structs but received dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    ))),
+                }
+            }
             Self::MapGroups { func, inputs } => func.to_field(inputs.as_slice(), schema, func),
         }
     }
@@ -625,6 +680,14 @@ impl Expr {
         Self::Agg(AggExpr::List(self)).into()
     }
 
+    pub fn top_k(self: ExprRef, k: usize) -> ExprRef {
+        Self::Agg(AggExpr::TopK(self, k)).into()
+    }
+
+    pub fn approx_heavy_hitters(self: ExprRef, k: usize) -> ExprRef {
+        Self::Agg(AggExpr::ApproxHeavyHitters(self, k)).into()
+    }
+
     pub fn agg_concat(self: ExprRef) -> ExprRef {
         Self::Agg(AggExpr::Concat(self)).into()
     }
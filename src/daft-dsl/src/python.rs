@@ -337,6 +337,14 @@ impl PyExpr {
         Ok(self.expr.clone().agg_concat().into())
     }
 
+    pub fn top_k(&self, k: usize) -> PyResult<Self> {
+        Ok(self.expr.clone().top_k(k).into())
+    }
+
+    pub fn approx_heavy_hitters(&self, k: usize) -> PyResult<Self> {
+        Ok(self.expr.clone().approx_heavy_hitters(k).into())
+    }
+
     pub fn __add__(&self, other: &Self) -> PyResult<Self> {
         Ok(crate::binary_op(crate::Operator::Plus, self.into(), other.expr.clone()).into())
     }
@@ -0,0 +1,70 @@
+use calamine::Data;
+use daft_core::prelude::*;
+
+/// Infers the dtype of a column from its cells. Columns whose cells are all empty are read as
+/// strings.
+pub(crate) fn infer_dtype<'a>(cells: impl Iterator<Item = &'a Data>) -> DataType {
+    match cells.map(infer_cell).reduce(merge) {
+        None | Some(DataType::Null) => DataType::Utf8,
+        Some(dtype) => dtype,
+    }
+}
+
+fn infer_cell(cell: &Data) -> DataType {
+    match cell {
+        Data::Empty | Data::Error(_) => DataType::Null,
+        Data::Int(_) => DataType::Int64,
+        Data::Float(f) if is_whole(*f) => DataType::Int64,
+        Data::Float(_) => DataType::Float64,
+        Data::Bool(_) => DataType::Boolean,
+        Data::DateTime(dt) if dt.is_duration() => DataType::Duration(TimeUnit::Milliseconds),
+        Data::DateTime(_) => DataType::Timestamp(TimeUnit::Milliseconds, None),
+        Data::String(_) | Data::DateTimeIso(_) | Data::DurationIso(_) => DataType::Utf8,
+    }
+}
+
+/// Whether a float is a whole number that an `i64` can hold exactly, since Excel stores integers
+/// as floats.
+pub(crate) fn is_whole(f: f64) -> bool {
+    f.fract() == 0.0 && f.abs() < (1i64 << 53) as f64
+}
+
+fn merge(a: DataType, b: DataType) -> DataType {
+    match (a, b) {
+        (a, b) if a == b => a,
+        (DataType::Null, other) | (other, DataType::Null) => other,
+        (DataType::Int64, DataType::Float64) | (DataType::Float64, DataType::Int64) => {
+            DataType::Float64
+        }
+        _ => DataType::Utf8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calamine::Data;
+    use daft_core::prelude::*;
+
+    use super::infer_dtype;
+
+    #[test]
+    fn test_infer_numbers() {
+        let cells = [Data::Float(1.0), Data::Empty, Data::Int(3)];
+        assert_eq!(infer_dtype(cells.iter()), DataType::Int64);
+
+        let cells = [Data::Float(1.0), Data::Float(1.5)];
+        assert_eq!(infer_dtype(cells.iter()), DataType::Float64);
+    }
+
+    #[test]
+    fn test_infer_mixed_and_empty() {
+        let cells = [Data::Int(1), Data::String("a".to_string())];
+        assert_eq!(infer_dtype(cells.iter()), DataType::Utf8);
+
+        let cells = [Data::Bool(true), Data::Empty];
+        assert_eq!(infer_dtype(cells.iter()), DataType::Boolean);
+
+        let cells = [Data::Empty, Data::Empty];
+        assert_eq!(infer_dtype(cells.iter()), DataType::Utf8);
+    }
+}
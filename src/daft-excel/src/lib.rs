@@ -0,0 +1,55 @@
+//! Reads of sheets of Excel workbooks, in any of the formats that calamine can read: `.xlsx`,
+//! `.xlsm`, `.xlsb`, `.xls` and `.ods`.
+//!
+//! A sheet is read from its header row, which has the names of the columns, to the last of its
+//! used rows. The dtypes of the columns are inferred from the cells of their first rows, where whole
+//! numbers are read as integers, since Excel stores all numbers as floats, and dates as timestamps.
+
+mod inference;
+mod read;
+
+use common_error::DaftError;
+pub use read::{read_excel, read_excel_bulk, read_excel_schema};
+use snafu::Snafu;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+
+    #[snafu(display("Error reading Excel workbook {}: {}", path, source))]
+    Workbook {
+        path: String,
+        source: calamine::Error,
+    },
+
+    #[snafu(display("Excel workbook {} does not have any sheets", path))]
+    NoSheets { path: String },
+
+    #[snafu(display("Excel workbook {} does not have a sheet named {}", path, sheet))]
+    SheetNotFound { path: String, sheet: String },
+
+    #[snafu(display(
+        "Cannot infer the schema of sheet {} of Excel workbook {} without any columns",
+        sheet,
+        path
+    ))]
+    NoColumns { path: String, sheet: String },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => Self::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Self::IOError { source: err }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
@@ -0,0 +1,310 @@
+use std::{io::Cursor, sync::Arc};
+
+use calamine::{open_workbook_auto_from_rs, Data, Range, Reader};
+use common_error::DaftResult;
+use common_file_formats::ExcelSourceConfig;
+use common_runtime::get_io_runtime;
+use daft_core::prelude::*;
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{StreamExt, TryStreamExt};
+use snafu::{OptionExt, ResultExt};
+
+use crate::{
+    inference::{infer_dtype, is_whole},
+    NoColumnsSnafu, NoSheetsSnafu, Result, SheetNotFoundSnafu, WorkbookSnafu,
+};
+
+/// Number of rows that the schema of a sheet is inferred from.
+const INFERENCE_ROWS: usize = 1024;
+
+/// Infers the schema of a sheet of an Excel workbook from its header row and first rows.
+pub async fn read_excel_schema(
+    uri: &str,
+    config: &ExcelSourceConfig,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let sheet = read_sheet(uri, config, io_client, io_stats).await?;
+    if sheet.column_names.is_empty() {
+        return Err(NoColumnsSnafu {
+            path: uri,
+            sheet: sheet.name,
+        }
+        .build()
+        .into());
+    }
+    let rows = sheet.rows().take(INFERENCE_ROWS).collect::<Vec<_>>();
+    let fields = sheet
+        .column_names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| Field::new(name, infer_dtype(rows.iter().map(|row| cell(row, i)))))
+        .collect();
+    Schema::new(fields)
+}
+
+/// Reads the rows of a sheet of an Excel workbook, or the first `limit` of them, into a table with
+/// the given schema. Only the columns in `include_columns` are read if it's set, and rows that don't
+/// match the predicate are dropped.
+#[allow(clippy::too_many_arguments)]
+pub async fn read_excel(
+    uri: &str,
+    config: &ExcelSourceConfig,
+    schema: SchemaRef,
+    include_columns: Option<&[String]>,
+    limit: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Table> {
+    let sheet = read_sheet(uri, config, io_client, io_stats).await?;
+    // the limit applies to the rows that match the predicate
+    let rows = sheet
+        .rows()
+        .take(match (&predicate, limit) {
+            (None, Some(limit)) => limit,
+            _ => usize::MAX,
+        })
+        .collect::<Vec<_>>();
+
+    let read_columns = include_columns.map(|columns| {
+        let mut read_columns = columns.to_vec();
+        if let Some(predicate) = &predicate {
+            for column in get_required_columns(predicate) {
+                if !read_columns.contains(&column) {
+                    read_columns.push(column);
+                }
+            }
+        }
+        read_columns
+    });
+    let fields = schema
+        .fields
+        .values()
+        .filter(|field| {
+            read_columns
+                .as_ref()
+                .map_or(true, |columns| columns.contains(&field.name))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let columns = fields
+        .iter()
+        .map(|field| {
+            match sheet
+                .column_names
+                .iter()
+                .position(|name| *name == field.name)
+            {
+                Some(i) => {
+                    let cells = rows.iter().map(|row| cell(row, i)).collect::<Vec<_>>();
+                    to_series(&field.name, &field.dtype, &cells)
+                }
+                None => Ok(Series::full_null(&field.name, &field.dtype, rows.len())),
+            }
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    let mut table = Table::new_with_size(Schema::new(fields)?, columns, rows.len())?;
+
+    if let Some(predicate) = predicate {
+        table = table.filter(&[predicate])?;
+        if let Some(limit) = limit {
+            table = table.head(limit)?;
+        }
+    }
+    if let Some(columns) = include_columns {
+        let columns = columns
+            .iter()
+            .filter(|column| table.schema.fields.contains_key(column.as_str()))
+            .collect::<Vec<_>>();
+        table = table.get_columns(&columns)?;
+    }
+    Ok(table)
+}
+
+/// Reads sheets of Excel workbooks into a table each, with up to `num_parallel_tasks` of them read
+/// at a time.
+#[allow(clippy::too_many_arguments)]
+pub fn read_excel_bulk(
+    uris: &[&str],
+    config: &ExcelSourceConfig,
+    schema: SchemaRef,
+    include_columns: Option<&[String]>,
+    limit: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<Table>> {
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    runtime_handle.block_on_current_thread(async {
+        futures::stream::iter(uris.iter().map(|uri| {
+            read_excel(
+                uri,
+                config,
+                schema.clone(),
+                include_columns,
+                limit,
+                predicate.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            )
+        }))
+        .buffered(num_parallel_tasks)
+        .try_collect::<Vec<_>>()
+        .await
+    })
+}
+
+/// The used cells of a sheet, with the names of its columns and the index of its first row after
+/// the header row.
+struct Sheet {
+    name: String,
+    range: Range<Data>,
+    column_names: Vec<String>,
+    first_row: usize,
+}
+
+impl Sheet {
+    fn rows(&self) -> impl Iterator<Item = &[Data]> + '_ {
+        self.range.rows().skip(self.first_row)
+    }
+}
+
+async fn read_sheet(
+    uri: &str,
+    config: &ExcelSourceConfig,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> Result<Sheet> {
+    let bytes = io_client
+        .single_url_get(uri.to_string(), None, io_stats)
+        .await?
+        .bytes()
+        .await?;
+    let mut workbook =
+        open_workbook_auto_from_rs(Cursor::new(bytes)).context(WorkbookSnafu { path: uri })?;
+    let name = match &config.sheet_name {
+        Some(name) => {
+            if !workbook.sheet_names().contains(name) {
+                return SheetNotFoundSnafu {
+                    path: uri,
+                    sheet: name.as_str(),
+                }
+                .fail();
+            }
+            name.clone()
+        }
+        None => workbook
+            .sheet_names()
+            .first()
+            .cloned()
+            .context(NoSheetsSnafu { path: uri })?,
+    };
+    let range = workbook
+        .worksheet_range(&name)
+        .context(WorkbookSnafu { path: uri })?;
+
+    // the range of a sheet starts at its first used row and column, rather than at A1
+    let (start_row, start_col) = range.start().unwrap_or((0, 0));
+    let default_name = |i: usize| format!("column_{}", start_col as usize + i + 1);
+    let (column_names, first_row) = match config.header_row {
+        Some(header_row) if header_row >= start_row as usize => {
+            let offset = header_row - start_row as usize;
+            let header = range.rows().nth(offset).unwrap_or(&[]);
+            let names = (0..range.width())
+                .map(|i| match header.get(i) {
+                    Some(Data::Empty) | None => default_name(i),
+                    Some(cell) => cell.to_string(),
+                })
+                .collect();
+            (names, offset + 1)
+        }
+        // without a header row, or with one above the used cells, columns are named by position
+        _ => ((0..range.width()).map(default_name).collect(), 0),
+    };
+    Ok(Sheet {
+        name,
+        range,
+        column_names,
+        first_row,
+    })
+}
+
+fn cell(row: &[Data], i: usize) -> &Data {
+    row.get(i).unwrap_or(&Data::Empty)
+}
+
+/// Builds a column of the dtype from its cells. Cells that can't be read as the dtype are read as
+/// null.
+fn to_series(name: &str, dtype: &DataType, cells: &[&Data]) -> DaftResult<Series> {
+    match dtype {
+        DataType::Null => Ok(Series::full_null(name, dtype, cells.len())),
+        DataType::Boolean => Ok(BooleanArray::from_iter(
+            name,
+            cells.iter().map(|cell| match cell {
+                Data::Bool(b) => Some(*b),
+                _ => None,
+            }),
+        )
+        .into_series()),
+        dtype if dtype.is_integer() => Int64Array::from_iter(
+            Field::new(name, DataType::Int64),
+            cells.iter().map(|cell| match cell {
+                Data::Int(i) => Some(*i),
+                Data::Float(f) if is_whole(*f) => Some(*f as i64),
+                Data::String(s) => s.trim().parse().ok(),
+                _ => None,
+            }),
+        )
+        .into_series()
+        .cast(dtype),
+        dtype if dtype.is_floating() => Float64Array::from_iter(
+            Field::new(name, DataType::Float64),
+            cells.iter().map(|cell| match cell {
+                Data::Int(i) => Some(*i as f64),
+                Data::Float(f) => Some(*f),
+                Data::String(s) => s.trim().parse().ok(),
+                _ => None,
+            }),
+        )
+        .into_series()
+        .cast(dtype),
+        DataType::Timestamp(..) | DataType::Date => Int64Array::from_iter(
+            Field::new(name, DataType::Int64),
+            cells.iter().map(|cell| match cell {
+                Data::DateTime(dt) if !dt.is_duration() => dt
+                    .as_datetime()
+                    .map(|datetime| datetime.and_utc().timestamp_millis()),
+                _ => None,
+            }),
+        )
+        .into_series()
+        .cast(&DataType::Timestamp(TimeUnit::Milliseconds, None))?
+        .cast(dtype),
+        DataType::Duration(_) => Int64Array::from_iter(
+            Field::new(name, DataType::Int64),
+            cells.iter().map(|cell| match cell {
+                Data::DateTime(dt) => dt.as_duration().map(|d| d.num_milliseconds()),
+                _ => None,
+            }),
+        )
+        .into_series()
+        .cast(&DataType::Duration(TimeUnit::Milliseconds))?
+        .cast(dtype),
+        // other dtypes are parsed from the text of the cells
+        _ => Utf8Array::from_iter(
+            name,
+            cells.iter().map(|cell| match cell {
+                Data::Empty | Data::Error(_) => None,
+                cell => Some(cell.to_string()),
+            }),
+        )
+        .into_series()
+        .cast(dtype),
+    }
+}
@@ -0,0 +1,311 @@
+//! An Arrow Flight SQL endpoint for Daft, so that BI tools and ADBC or JDBC drivers can run SQL
+//! queries on Daft directly.
+//!
+//! Queries are planned with `daft-sql` against the tables of a catalog given when starting the
+//! server, executed on the native executor, and their results streamed back as Arrow record
+//! batches.
+//!
+//! Connections are not encrypted, and are only authenticated when an [`Authenticator`] is given,
+//! while queries can read any file that the server process can read, e.g. with `read_csv`. The
+//! server therefore listens on the loopback interface by default, and should only be exposed to
+//! other hosts behind a trusted network.
+
+mod service;
+
+use std::sync::Arc;
+
+use arrow_flight::flight_service_server::FlightServiceServer;
+use common_daft_config::DaftExecutionConfig;
+use common_error::{DaftError, DaftResult};
+use daft_sql::catalog::SQLCatalog;
+use futures::stream;
+pub use service::DaftFlightSqlService;
+use tonic::{transport::Server, Request, Status};
+
+/// Checks the `authorization` header of each request to the server, e.g. `Bearer <token>`, or
+/// `Basic <credentials>` for clients that authenticate with a handshake, which is `None` when a
+/// client sends no header. Requests whose header is not accepted are rejected as unauthenticated.
+#[derive(Clone)]
+pub struct Authenticator(Arc<dyn Fn(Option<&str>) -> bool + Send + Sync>);
+
+impl Authenticator {
+    pub fn new(authenticate: impl Fn(Option<&str>) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(authenticate))
+    }
+
+    fn check(&self, request: Request<()>) -> Result<Request<()>, Status> {
+        let authorization = request
+            .metadata()
+            .get("authorization")
+            .map(|value| value.to_str())
+            .transpose()
+            .map_err(|_| Status::unauthenticated("Authorization header must be ASCII"))?;
+        if (self.0)(authorization) {
+            Ok(request)
+        } else {
+            Err(Status::unauthenticated("Invalid credentials"))
+        }
+    }
+}
+
+impl std::fmt::Debug for Authenticator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Authenticator")
+    }
+}
+
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+pub struct FlightSqlServerHandle {
+    shutdown_signal: Option<tokio::sync::oneshot::Sender<()>>,
+    port: u16,
+}
+
+#[cfg_attr(feature = "python", pyo3::pymethods)]
+impl FlightSqlServerHandle {
+    pub fn shutdown(&mut self) {
+        let Some(shutdown_signal) = self.shutdown_signal.take() else {
+            return;
+        };
+        // the server may already have stopped on an error
+        let _ = shutdown_signal.send(());
+    }
+
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl Drop for FlightSqlServerHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Starts serving SQL queries on the tables of `catalog` at `addr` (e.g. `"127.0.0.1:0"`) on a
+/// background thread, until the returned handle is shut down or dropped. Requests are only
+/// authenticated with `authenticator` if one is given.
+pub fn start(
+    addr: &str,
+    catalog: SQLCatalog,
+    cfg: Arc<DaftExecutionConfig>,
+    authenticator: Option<Authenticator>,
+) -> DaftResult<FlightSqlServerHandle> {
+    let listener = std::net::TcpListener::bind(addr)?;
+    listener.set_nonblocking(true)?;
+    let port = listener.local_addr()?.port();
+
+    let (shutdown_signal, shutdown_receiver) = tokio::sync::oneshot::channel::<()>();
+    let runtime = tokio::runtime::Runtime::new()?;
+
+    std::thread::spawn(move || {
+        let result = runtime.block_on(async {
+            let listener = tokio::net::TcpListener::from_std(listener)?;
+            let incoming = stream::unfold(listener, |listener| async move {
+                let accepted = listener.accept().await.map(|(stream, _)| stream);
+                Some((accepted, listener))
+            });
+
+            let service = FlightServiceServer::with_interceptor(
+                DaftFlightSqlService::new(catalog, cfg),
+                move |request: Request<()>| match &authenticator {
+                    Some(authenticator) => authenticator.check(request),
+                    None => Ok(request),
+                },
+            );
+            Server::builder()
+                .add_service(service)
+                .serve_with_incoming_shutdown(incoming, async {
+                    let _ = shutdown_receiver.await;
+                })
+                .await
+                .map_err(|e| DaftError::External(e.into()))
+        });
+
+        if let Err(e) = result {
+            log::error!("Flight SQL server error: {e}");
+        }
+    });
+
+    Ok(FlightSqlServerHandle {
+        shutdown_signal: Some(shutdown_signal),
+        port,
+    })
+}
+
+#[cfg(feature = "python")]
+#[pyo3::pyfunction]
+#[pyo3(
+    name = "flight_sql_start",
+    signature = (addr = "127.0.0.1:0", catalog = None, config = None, authenticate = None)
+)]
+pub fn py_flight_sql_start(
+    addr: &str,
+    catalog: Option<daft_sql::python::PyCatalog>,
+    config: Option<common_daft_config::PyDaftExecutionConfig>,
+    authenticate: Option<pyo3::PyObject>,
+) -> pyo3::PyResult<FlightSqlServerHandle> {
+    use pyo3::Python;
+
+    let catalog = catalog.map_or_else(SQLCatalog::new, |catalog| catalog.catalog().clone());
+    let cfg = config.map_or_else(
+        || Arc::new(DaftExecutionConfig::from_env()),
+        |config| config.config,
+    );
+    // `authenticate` is called with the authorization header of each request, and a request is
+    // rejected if it returns False or raises
+    let authenticator = authenticate.map(|authenticate| {
+        Authenticator::new(move |authorization| {
+            Python::with_gil(|py| {
+                authenticate
+                    .call1(py, (authorization,))
+                    .and_then(|accepted| accepted.extract::<bool>(py))
+                    .unwrap_or_else(|e| {
+                        log::warn!("Flight SQL authentication failed with error: {e}");
+                        false
+                    })
+            })
+        })
+    });
+    Ok(start(addr, catalog, cfg, authenticator)?)
+}
+
+#[cfg(feature = "python")]
+pub fn register_modules(parent: &pyo3::Bound<pyo3::types::PyModule>) -> pyo3::PyResult<()> {
+    use pyo3::types::PyModuleMethods;
+
+    parent.add_function(pyo3::wrap_pyfunction_bound!(py_flight_sql_start, parent)?)?;
+    parent.add_class::<FlightSqlServerHandle>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_flight::sql::client::FlightSqlServiceClient;
+    use futures::TryStreamExt;
+    use tonic::transport::Channel;
+
+    use super::*;
+
+    async fn connect(server: &FlightSqlServerHandle) -> FlightSqlServiceClient<Channel> {
+        let channel = Channel::from_shared(format!("http://127.0.0.1:{}", server.port()))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+        FlightSqlServiceClient::new(channel)
+    }
+
+    async fn fetch_num_rows(
+        client: &mut FlightSqlServiceClient<Channel>,
+        info: arrow_flight::FlightInfo,
+    ) -> usize {
+        let mut num_rows = 0;
+        for endpoint in info.endpoint {
+            let batches = client
+                .do_get(endpoint.ticket.unwrap())
+                .await
+                .unwrap()
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+            num_rows += batches.iter().map(|batch| batch.num_rows()).sum::<usize>();
+        }
+        num_rows
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_statement_and_prepared_statement() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        std::fs::write(&path, "a,b\n1,x\n2,y\n3,z\n").unwrap();
+        let sql = format!(
+            "SELECT a, b FROM read_csv('{}') WHERE a > 1",
+            path.display()
+        );
+
+        let server = start(
+            "127.0.0.1:0",
+            SQLCatalog::new(),
+            Arc::new(DaftExecutionConfig::default()),
+            None,
+        )
+        .unwrap();
+        let mut client = connect(&server).await;
+
+        let info = client.execute(sql.clone(), None).await.unwrap();
+        let schema = info.clone().try_decode_schema().unwrap();
+        assert_eq!(
+            schema
+                .fields()
+                .iter()
+                .map(|field| field.name().as_str())
+                .collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        assert_eq!(fetch_num_rows(&mut client, info).await, 2);
+
+        let mut prepared = client.prepare(sql, None).await.unwrap();
+        assert_eq!(prepared.dataset_schema().unwrap().fields().len(), 2);
+        let info = prepared.execute().await.unwrap();
+        assert_eq!(fetch_num_rows(&mut client, info).await, 2);
+        prepared.close().await.unwrap();
+
+        assert!(client
+            .execute("SELECT missing FROM nowhere".to_string(), None)
+            .await
+            .is_err());
+        let err = client
+            .execute(format!("CREATE TABLE t AS {sql}"), None)
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Only SELECT queries are supported"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_metadata_and_authentication() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        std::fs::write(&path, "a,b\n1,x\n").unwrap();
+        let mut catalog = SQLCatalog::new();
+        let plan = daft_sql::SQLPlanner::new(SQLCatalog::new())
+            .plan_sql(&format!("SELECT * FROM read_csv('{}')", path.display()))
+            .unwrap();
+        catalog.register_table("data", plan);
+
+        let authenticator =
+            Authenticator::new(|authorization| authorization == Some("Bearer secret"));
+        let server = start(
+            "127.0.0.1:0",
+            catalog,
+            Arc::new(DaftExecutionConfig::default()),
+            Some(authenticator),
+        )
+        .unwrap();
+        let mut client = connect(&server).await;
+
+        let err = client.get_catalogs().await.unwrap_err();
+        assert!(err.to_string().contains("Invalid credentials"));
+
+        client.set_token("secret".to_string());
+        let info = client.get_catalogs().await.unwrap();
+        assert_eq!(fetch_num_rows(&mut client, info).await, 1);
+
+        let info = client
+            .get_tables(arrow_flight::sql::CommandGetTables {
+                include_schema: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(fetch_num_rows(&mut client, info).await, 1);
+
+        let info = client
+            .get_sql_info(vec![arrow_flight::sql::SqlInfo::FlightSqlServerReadOnly])
+            .await
+            .unwrap();
+        assert_eq!(fetch_num_rows(&mut client, info).await, 1);
+    }
+}
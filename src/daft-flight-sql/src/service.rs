@@ -0,0 +1,422 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use arrow2::io::{
+    flight::{default_ipc_fields, serialize_batch, serialize_schema, serialize_schema_to_info},
+    ipc::{write::WriteOptions, IpcField},
+};
+use arrow_array::RecordBatch;
+use arrow_flight::{
+    encode::FlightDataEncoderBuilder,
+    error::FlightError,
+    flight_service_server::FlightService,
+    sql::{
+        metadata::{SqlInfoData, SqlInfoDataBuilder},
+        server::FlightSqlService,
+        ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+        ActionCreatePreparedStatementResult, CommandGetCatalogs, CommandGetSqlInfo,
+        CommandGetTables, CommandPreparedStatementQuery, CommandStatementQuery, ProstMessageExt,
+        SqlInfo, TicketStatementQuery,
+    },
+    Action, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest,
+    HandshakeResponse, IpcMessage, Ticket,
+};
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use common_daft_config::DaftExecutionConfig;
+use common_error::{DaftError, DaftResult};
+use daft_local_execution::NativeExecutor;
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_micropartition::{partitioning::InMemoryPartitionSetCache, MicroPartition};
+use daft_sql::{catalog::SQLCatalog, SQLPlanner};
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use prost::Message;
+use tonic::{Request, Response, Status, Streaming};
+
+type BoxedStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + 'static>>;
+
+/// The name of the catalog of the tables listed to clients, as a Daft catalog is unnamed
+const CATALOG_NAME: &str = "daft";
+
+fn error_to_status(err: DaftError) -> Status {
+    match err {
+        DaftError::ValueError(msg) | DaftError::FieldNotFound(msg) | DaftError::TypeError(msg) => {
+            Status::invalid_argument(msg)
+        }
+        DaftError::NotImplemented(msg) => Status::unimplemented(msg),
+        err => Status::internal(err.to_string()),
+    }
+}
+
+fn to_flight_data(data: arrow_format::flight::data::FlightData) -> FlightData {
+    FlightData {
+        flight_descriptor: None,
+        data_header: data.data_header.into(),
+        app_metadata: data.app_metadata.into(),
+        data_body: data.data_body.into(),
+    }
+}
+
+/// Encodes the tables of a query result as record batch messages
+fn encode_batches(
+    partition: &MicroPartition,
+    ipc_fields: &[IpcField],
+) -> DaftResult<Vec<FlightData>> {
+    let options = WriteOptions { compression: None };
+    let mut messages = Vec::new();
+    for table in partition.get_tables()?.iter() {
+        let chunk = arrow2::chunk::Chunk::new(table.get_inner_arrow_arrays().collect());
+        let (dictionaries, batch) = serialize_batch(&chunk, ipc_fields, &options)?;
+        messages.extend(dictionaries.into_iter().map(to_flight_data));
+        messages.push(to_flight_data(batch));
+    }
+    Ok(messages)
+}
+
+/// Rejects statements other than queries, e.g. `CREATE TABLE ... AS SELECT`, which writes files
+fn check_is_query(sql: &str) -> Result<(), Status> {
+    let first = sql.split_whitespace().next().unwrap_or_default();
+    match first.to_ascii_uppercase().as_str() {
+        "SELECT" | "WITH" => Ok(()),
+        _ if first.starts_with('(') => Ok(()),
+        _ => Err(Status::invalid_argument(
+            "Only SELECT queries are supported by the Daft Flight SQL server",
+        )),
+    }
+}
+
+/// Encodes an arrow2 schema as an IPC message
+fn encode_schema(arrow_schema: &arrow2::datatypes::Schema) -> Result<Vec<u8>, Status> {
+    let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+    serialize_schema_to_info(arrow_schema, Some(&ipc_fields)).map_err(|e| error_to_status(e.into()))
+}
+
+/// Converts an arrow2 schema to the arrow-rs schema of the metadata builders, through its IPC
+/// encoding
+fn to_arrow_rs_schema(arrow_schema: &arrow2::datatypes::Schema) -> Result<Schema, Status> {
+    Schema::try_from(IpcMessage(encode_schema(arrow_schema)?.into()))
+        .map_err(|e| Status::internal(e.to_string()))
+}
+
+/// The flight info of a metadata request, whose ticket is the request itself
+fn metadata_flight_info(
+    command: &impl ProstMessageExt,
+    schema: &Schema,
+    descriptor: FlightDescriptor,
+) -> Result<Response<FlightInfo>, Status> {
+    let ticket = Ticket {
+        ticket: command.as_any().encode_to_vec().into(),
+    };
+    let info = FlightInfo::new()
+        .try_with_schema(schema)
+        .map_err(|e| Status::internal(e.to_string()))?
+        .with_endpoint(FlightEndpoint::new().with_ticket(ticket))
+        .with_descriptor(descriptor)
+        .with_total_records(-1)
+        .with_total_bytes(-1);
+    Ok(Response::new(info))
+}
+
+/// Streams the record batch that answers a metadata request
+fn metadata_stream(
+    schema: SchemaRef,
+    batch: Result<RecordBatch, ArrowError>,
+) -> Response<BoxedStream<FlightData>> {
+    let batch = batch.map_err(FlightError::from);
+    let stream = FlightDataEncoderBuilder::new()
+        .with_schema(schema)
+        .build(stream::once(async { batch }))
+        .map_err(Status::from);
+    Response::new(Box::pin(stream))
+}
+
+/// Statement handles are the SQL text of the statement, so that no state has to be kept between
+/// the requests that plan and execute a statement
+fn handle_to_sql(handle: &[u8]) -> Result<String, Status> {
+    String::from_utf8(handle.to_vec())
+        .map_err(|_| Status::invalid_argument("Statement handle must be valid UTF-8"))
+}
+
+/// Serves SQL queries over Arrow Flight SQL, planning them against a catalog of tables with
+/// `daft-sql` and executing them on the native executor.
+///
+/// Queries and prepared queries without parameters are supported, and other statements, such as
+/// `CREATE TABLE`, are rejected, so that clients can neither change the tables of the catalog nor
+/// write files. The tables are listed to clients as the tables of a single catalog named `daft`.
+pub struct DaftFlightSqlService {
+    catalog: SQLCatalog,
+    cfg: Arc<DaftExecutionConfig>,
+    /// Integer values of `GetSqlInfo` results registered with
+    /// [`FlightSqlService::register_sql_info`], which take precedence over the defaults
+    registered_sql_info: Mutex<Vec<(SqlInfo, i32)>>,
+}
+
+impl DaftFlightSqlService {
+    #[must_use]
+    pub fn new(catalog: SQLCatalog, cfg: Arc<DaftExecutionConfig>) -> Self {
+        Self {
+            catalog,
+            cfg,
+            registered_sql_info: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The information about the server that answers `GetSqlInfo` requests
+    fn sql_info(&self) -> Result<SqlInfoData, Status> {
+        let mut builder = SqlInfoDataBuilder::new();
+        builder.append(SqlInfo::FlightSqlServerName, "Daft");
+        builder.append(SqlInfo::FlightSqlServerVersion, env!("CARGO_PKG_VERSION"));
+        builder.append(SqlInfo::FlightSqlServerReadOnly, true);
+        builder.append(SqlInfo::FlightSqlServerSql, true);
+        builder.append(SqlInfo::FlightSqlServerSubstrait, false);
+        builder.append(SqlInfo::FlightSqlServerCancel, false);
+        builder.append(SqlInfo::SqlDdlCatalog, false);
+        builder.append(SqlInfo::SqlDdlSchema, false);
+        builder.append(SqlInfo::SqlDdlTable, false);
+        for (info, value) in self.registered_sql_info.lock().unwrap().iter() {
+            builder.append(*info, *value);
+        }
+        builder.build().map_err(|e| Status::internal(e.to_string()))
+    }
+
+    async fn plan(&self, sql: String) -> Result<LogicalPlanBuilder, Status> {
+        check_is_query(&sql)?;
+        let catalog = self.catalog.clone();
+        // planning may list files of scans
+        tokio::task::spawn_blocking(move || {
+            let mut planner = SQLPlanner::new(catalog);
+            let plan = planner
+                .plan_sql(&sql)
+                .map_err(|e| Status::invalid_argument(e.to_string()))?;
+            LogicalPlanBuilder::from(plan)
+                .optimize()
+                .map_err(error_to_status)
+        })
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?
+    }
+
+    /// The schema of the results of `plan`, encoded as an IPC message
+    fn schema_bytes(plan: &LogicalPlanBuilder) -> Result<Vec<u8>, Status> {
+        encode_schema(&plan.schema().to_arrow().map_err(error_to_status)?)
+    }
+
+    async fn flight_info(
+        &self,
+        sql: String,
+        ticket: Ticket,
+        descriptor: FlightDescriptor,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let plan = self.plan(sql).await?;
+        let mut info = FlightInfo::new()
+            .with_endpoint(FlightEndpoint::new().with_ticket(ticket))
+            .with_descriptor(descriptor)
+            .with_total_records(-1)
+            .with_total_bytes(-1);
+        info.schema = Self::schema_bytes(&plan)?.into();
+        Ok(Response::new(info))
+    }
+
+    /// Runs a query, streaming its schema followed by its results
+    async fn execute(&self, sql: String) -> Result<BoxedStream<FlightData>, Status> {
+        let plan = self.plan(sql).await?;
+        let arrow_schema = plan.schema().to_arrow().map_err(error_to_status)?;
+        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+        let schema_message = to_flight_data(serialize_schema(&arrow_schema, Some(&ipc_fields)));
+
+        let executor = NativeExecutor::from_logical_plan_builder(&plan).map_err(error_to_status)?;
+        let results = executor
+            .run(&InMemoryPartitionSetCache::empty(), self.cfg.clone(), None)
+            .map_err(error_to_status)?
+            .into_stream();
+        let batches = results
+            .map(move |partition| {
+                // the query is cancelled once the executor is dropped along with the stream
+                let _executor = &executor;
+                let messages = encode_batches(&partition?, &ipc_fields)?;
+                Ok(stream::iter(messages.into_iter().map(Ok::<_, Status>)))
+            })
+            .map_err(error_to_status)
+            .try_flatten();
+
+        Ok(Box::pin(
+            stream::once(async { Ok(schema_message) }).chain(batches),
+        ))
+    }
+}
+
+#[tonic::async_trait]
+impl FlightSqlService for DaftFlightSqlService {
+    type FlightService = Self;
+
+    /// Answers with the `authorization` header of the request, which the server has already
+    /// checked, so that clients which authenticate with a handshake, e.g. with a username and
+    /// password, send it along with the requests that follow
+    async fn do_handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<
+        Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
+        Status,
+    > {
+        let authorization = request.metadata().get("authorization").cloned();
+        let handshake = HandshakeResponse::default();
+        let mut response: Response<BoxedStream<HandshakeResponse>> =
+            Response::new(Box::pin(stream::once(async { Ok(handshake) })));
+        if let Some(authorization) = authorization {
+            response
+                .metadata_mut()
+                .insert("authorization", authorization);
+        }
+        Ok(response)
+    }
+
+    async fn get_flight_info_statement(
+        &self,
+        query: CommandStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let ticket = TicketStatementQuery {
+            statement_handle: query.query.clone().into(),
+        };
+        let ticket = Ticket {
+            ticket: ticket.as_any().encode_to_vec().into(),
+        };
+        self.flight_info(query.query, ticket, request.into_inner())
+            .await
+    }
+
+    async fn get_flight_info_prepared_statement(
+        &self,
+        query: CommandPreparedStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let sql = handle_to_sql(&query.prepared_statement_handle)?;
+        let ticket = Ticket {
+            ticket: query.as_any().encode_to_vec().into(),
+        };
+        self.flight_info(sql, ticket, request.into_inner()).await
+    }
+
+    async fn do_get_statement(
+        &self,
+        ticket: TicketStatementQuery,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let sql = handle_to_sql(&ticket.statement_handle)?;
+        Ok(Response::new(self.execute(sql).await?))
+    }
+
+    async fn do_get_prepared_statement(
+        &self,
+        query: CommandPreparedStatementQuery,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let sql = handle_to_sql(&query.prepared_statement_handle)?;
+        Ok(Response::new(self.execute(sql).await?))
+    }
+
+    async fn do_action_create_prepared_statement(
+        &self,
+        query: ActionCreatePreparedStatementRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        let plan = self.plan(query.query.clone()).await?;
+        Ok(ActionCreatePreparedStatementResult {
+            prepared_statement_handle: query.query.into(),
+            dataset_schema: Self::schema_bytes(&plan)?.into(),
+            parameter_schema: Default::default(),
+        })
+    }
+
+    async fn do_action_close_prepared_statement(
+        &self,
+        _query: ActionClosePreparedStatementRequest,
+        _request: Request<Action>,
+    ) -> Result<(), Status> {
+        Ok(())
+    }
+
+    async fn get_flight_info_sql_info(
+        &self,
+        query: CommandGetSqlInfo,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let sql_info = self.sql_info()?;
+        let schema = query.clone().into_builder(&sql_info).schema();
+        metadata_flight_info(&query, &schema, request.into_inner())
+    }
+
+    async fn do_get_sql_info(
+        &self,
+        query: CommandGetSqlInfo,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let sql_info = self.sql_info()?;
+        let builder = query.into_builder(&sql_info);
+        Ok(metadata_stream(builder.schema(), builder.build()))
+    }
+
+    async fn get_flight_info_catalogs(
+        &self,
+        query: CommandGetCatalogs,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let schema = query.clone().into_builder().schema();
+        metadata_flight_info(&query, &schema, request.into_inner())
+    }
+
+    async fn do_get_catalogs(
+        &self,
+        query: CommandGetCatalogs,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let mut builder = query.into_builder();
+        builder.append(CATALOG_NAME);
+        Ok(metadata_stream(builder.schema(), builder.build()))
+    }
+
+    async fn get_flight_info_tables(
+        &self,
+        query: CommandGetTables,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let schema = query.clone().into_builder().schema();
+        metadata_flight_info(&query, &schema, request.into_inner())
+    }
+
+    /// Lists the tables of the catalog that match the filters of the request, which the builder
+    /// applies
+    async fn do_get_tables(
+        &self,
+        query: CommandGetTables,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let include_schema = query.include_schema;
+        let mut builder = query.into_builder();
+        for name in self.catalog.table_names() {
+            let table_schema = match self.catalog.get_schema(&name) {
+                Some(schema) if include_schema => {
+                    to_arrow_rs_schema(&schema.to_arrow().map_err(error_to_status)?)?
+                }
+                _ => Schema::empty(),
+            };
+            let table_type = if self.catalog.is_view(&name) {
+                "VIEW"
+            } else {
+                "TABLE"
+            };
+            builder
+                .append(CATALOG_NAME, "", &name, table_type, &table_schema)
+                .map_err(|e| Status::internal(e.to_string()))?;
+        }
+        Ok(metadata_stream(builder.schema(), builder.build()))
+    }
+
+    /// Registers an integer value of `result`, e.g. a bitmask of supported features, to answer
+    /// `GetSqlInfo` requests with
+    async fn register_sql_info(&self, id: i32, result: &SqlInfo) {
+        self.registered_sql_info.lock().unwrap().push((*result, id));
+    }
+}
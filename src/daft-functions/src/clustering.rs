@@ -0,0 +1,228 @@
+//! Keys along space-filling curves, so that sorting by them clusters rows which are close in several
+//! columns at once.
+
+use std::str::FromStr;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+/// The number of bits of a clustering key, which are divided evenly between its dimensions
+const KEY_BITS: usize = 64;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ClusteringCurve {
+    /// Interleaves the bits of the coordinates, which is cheap but jumps between distant points
+    ZOrder,
+    /// Keeps consecutive keys adjacent, which clusters better at the cost of more computation
+    Hilbert,
+}
+
+impl FromStr for ClusteringCurve {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "z-order" | "zorder" => Ok(Self::ZOrder),
+            "hilbert" => Ok(Self::Hilbert),
+            _ => Err(DaftError::ValueError(format!(
+                "Unsupported clustering curve: {s}, expected one of: z-order, hilbert"
+            ))),
+        }
+    }
+}
+
+/// Interleaves the low `bits` bits of the coordinates, starting with the most significant bit of
+/// the first coordinate
+fn interleave(coords: &[u64], bits: usize) -> u64 {
+    let mut key = 0;
+    for bit in (0..bits).rev() {
+        for coord in coords {
+            key = (key << 1) | ((coord >> bit) & 1);
+        }
+    }
+    key
+}
+
+/// Transforms coordinates in place into the transposed Hilbert index, whose interleaved bits are
+/// the index along the curve.
+///
+/// See J. Skilling, "Programming the Hilbert curve", AIP Conference Proceedings 707 (2004).
+fn axes_to_transpose(coords: &mut [u64], bits: usize) {
+    let num_dims = coords.len();
+    let top = 1u64 << (bits - 1);
+
+    let mut q = top;
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..num_dims {
+            if coords[i] & q != 0 {
+                coords[0] ^= p;
+            } else {
+                let t = (coords[0] ^ coords[i]) & p;
+                coords[0] ^= t;
+                coords[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+
+    for i in 1..num_dims {
+        coords[i] ^= coords[i - 1];
+    }
+    let mut t = 0;
+    let mut q = top;
+    while q > 1 {
+        if coords[num_dims - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for coord in coords.iter_mut() {
+        *coord ^= t;
+    }
+}
+
+fn clustering_key(coords: &mut [u64], bits: usize, curve: ClusteringCurve) -> u64 {
+    if curve == ClusteringCurve::Hilbert {
+        axes_to_transpose(coords, bits);
+    }
+    interleave(coords, bits)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ClusteringKeyFunction {
+    pub curve: ClusteringCurve,
+}
+
+impl ClusteringKeyFunction {
+    fn bits_per_dim(num_inputs: usize) -> DaftResult<usize> {
+        if !(1..=KEY_BITS).contains(&num_inputs) {
+            return Err(DaftError::ValueError(format!(
+                "Expected 1 to {KEY_BITS} inputs to clustering_key, got {num_inputs}"
+            )));
+        }
+        Ok(KEY_BITS / num_inputs)
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for ClusteringKeyFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "clustering_key"
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        let bits = Self::bits_per_dim(inputs.len())?;
+        let mask = u64::MAX >> (KEY_BITS - bits);
+        let inputs = inputs
+            .iter()
+            .map(|input| input.cast(&DataType::UInt64))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let arrays = inputs
+            .iter()
+            .map(|input| input.u64())
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let len = inputs[0].len();
+        let mut coords = vec![0; arrays.len()];
+        let keys = (0..len)
+            .map(|row| {
+                for (coord, array) in coords.iter_mut().zip(&arrays) {
+                    // nulls are placed at the start of each dimension
+                    *coord = array.get(row).unwrap_or(0) & mask;
+                }
+                clustering_key(&mut coords, bits, self.curve)
+            })
+            .collect::<Vec<_>>();
+        Ok(UInt64Array::from((inputs[0].name(), keys)).into_series())
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        Self::bits_per_dim(inputs.len())?;
+        let fields = inputs
+            .iter()
+            .map(|input| input.to_field(schema))
+            .collect::<DaftResult<Vec<_>>>()?;
+        if let Some(field) = fields.iter().find(|field| !field.dtype.is_integer()) {
+            return Err(DaftError::TypeError(format!(
+                "Expected integer inputs to clustering_key, got {field}"
+            )));
+        }
+        Ok(Field::new(fields[0].name.clone(), DataType::UInt64))
+    }
+}
+
+/// The key of each row along `curve` through the space whose coordinates are `inputs`. Each input
+/// is an unsigned coordinate of `64 / inputs.len()` bits, with higher bits ignored, so inputs
+/// should be scaled to that range first.
+#[must_use]
+pub fn clustering_key_expr(inputs: Vec<ExprRef>, curve: ClusteringCurve) -> ExprRef {
+    ScalarFunction::new(ClusteringKeyFunction { curve }, inputs).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(points: &[[u64; 2]], curve: ClusteringCurve) -> Vec<u64> {
+        points
+            .iter()
+            .map(|point| clustering_key(&mut point.clone(), 32, curve))
+            .collect()
+    }
+
+    #[test]
+    fn test_z_order_interleaves_bits() {
+        assert_eq!(
+            keys(
+                &[[0, 0], [0, 1], [1, 0], [1, 1], [2, 0]],
+                ClusteringCurve::ZOrder
+            ),
+            [0, 1, 2, 3, 8]
+        );
+    }
+
+    #[test]
+    fn test_hilbert_keys_are_adjacent() {
+        let points = (0..4)
+            .flat_map(|x| (0..4).map(move |y| [x, y]))
+            .collect::<Vec<_>>();
+        let mut ordered = keys(&points, ClusteringCurve::Hilbert)
+            .into_iter()
+            .zip(points)
+            .collect::<Vec<_>>();
+        ordered.sort_unstable();
+
+        // the curve fills the square at the origin first, moving one step at a time
+        assert_eq!(
+            ordered.iter().map(|(key, _)| *key).collect::<Vec<_>>(),
+            (0..16).collect::<Vec<_>>()
+        );
+        for pair in ordered.windows(2) {
+            let ([x0, y0], [x1, y1]) = (pair[0].1, pair[1].1);
+            assert_eq!(x0.abs_diff(x1) + y0.abs_diff(y1), 1);
+        }
+    }
+
+    #[test]
+    fn test_evaluate() -> DaftResult<()> {
+        let x = Int64Array::from(("x", vec![1, 0])).into_series();
+        let y = UInt8Array::from(("y", vec![0, 1])).into_series();
+        let function = ClusteringKeyFunction {
+            curve: ClusteringCurve::ZOrder,
+        };
+        let keys = function.evaluate(&[x, y])?;
+        assert_eq!(keys.name(), "x");
+        assert_eq!(keys.u64()?.as_slice(), [2, 1]);
+        Ok(())
+    }
+}
@@ -0,0 +1,249 @@
+use arrow2::{bitmap::Bitmap, offset::OffsetsBuffer};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+
+/// A value decoded from a serialized payload, before it's written to a column. Structs hold the
+/// values of their fields in the order of the fields of their dtype.
+#[derive(Debug)]
+pub(super) enum DecodedValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Utf8(String),
+    Binary(Vec<u8>),
+    List(Vec<DecodedValue>),
+    Struct(Vec<DecodedValue>),
+    Map(Vec<(DecodedValue, DecodedValue)>),
+}
+
+impl DecodedValue {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Bool(_) => "boolean",
+            Self::Int(_) | Self::UInt(_) => "integer",
+            Self::Float(_) => "float",
+            Self::Utf8(_) => "string",
+            Self::Binary(_) => "binary",
+            Self::List(_) => "list",
+            Self::Struct(_) => "struct",
+            Self::Map(_) => "map",
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+}
+
+fn mismatch(value: &DecodedValue, dtype: &DataType) -> DaftError {
+    DaftError::ValueError(format!("Cannot decode {} value as {dtype}", value.kind()))
+}
+
+/// Checks that decoded values can be written to columns of the dtype, which is made of booleans,
+/// numbers, strings, binaries, lists, structs and maps.
+pub(super) fn check_decodable(dtype: &DataType) -> DaftResult<()> {
+    match dtype {
+        DataType::Null
+        | DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Utf8
+        | DataType::Binary => Ok(()),
+        DataType::List(child) => check_decodable(child),
+        DataType::Struct(fields) => fields
+            .iter()
+            .try_for_each(|field| check_decodable(&field.dtype)),
+        DataType::Map { key, value } => {
+            check_decodable(key)?;
+            check_decodable(value)
+        }
+        other => Err(DaftError::TypeError(format!(
+            "Decoding values as {other} is not supported"
+        ))),
+    }
+}
+
+/// Builds a column of the dtype from decoded values, one per row.
+pub(super) fn to_series(
+    name: &str,
+    dtype: &DataType,
+    values: Vec<DecodedValue>,
+) -> DaftResult<Series> {
+    match dtype {
+        DataType::Null => match values.iter().find(|value| !value.is_null()) {
+            Some(value) => Err(mismatch(value, dtype)),
+            None => Ok(Series::full_null(name, dtype, values.len())),
+        },
+        DataType::Boolean => {
+            let data = values
+                .iter()
+                .map(|value| match value {
+                    DecodedValue::Null => Ok(None),
+                    DecodedValue::Bool(b) => Ok(Some(*b)),
+                    other => Err(mismatch(other, dtype)),
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok(BooleanArray::from_iter(name, data.into_iter()).into_series())
+        }
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+            let data = values
+                .iter()
+                .map(|value| match value {
+                    DecodedValue::Null => Ok(None),
+                    DecodedValue::Int(i) => Ok(Some(*i)),
+                    DecodedValue::UInt(u) => i64::try_from(*u).map(Some).map_err(|_| {
+                        DaftError::ValueError(format!("Integer {u} is out of range of {dtype}"))
+                    }),
+                    other => Err(mismatch(other, dtype)),
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Int64Array::from_iter(Field::new(name, DataType::Int64), data.into_iter())
+                .into_series()
+                .cast(dtype)
+        }
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+            let data = values
+                .iter()
+                .map(|value| match value {
+                    DecodedValue::Null => Ok(None),
+                    DecodedValue::UInt(u) => Ok(Some(*u)),
+                    DecodedValue::Int(i) => u64::try_from(*i).map(Some).map_err(|_| {
+                        DaftError::ValueError(format!("Integer {i} is out of range of {dtype}"))
+                    }),
+                    other => Err(mismatch(other, dtype)),
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            UInt64Array::from_iter(Field::new(name, DataType::UInt64), data.into_iter())
+                .into_series()
+                .cast(dtype)
+        }
+        DataType::Float32 | DataType::Float64 => {
+            let data = values
+                .iter()
+                .map(|value| match value {
+                    DecodedValue::Null => Ok(None),
+                    DecodedValue::Float(f) => Ok(Some(*f)),
+                    DecodedValue::Int(i) => Ok(Some(*i as f64)),
+                    DecodedValue::UInt(u) => Ok(Some(*u as f64)),
+                    other => Err(mismatch(other, dtype)),
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Float64Array::from_iter(Field::new(name, DataType::Float64), data.into_iter())
+                .into_series()
+                .cast(dtype)
+        }
+        DataType::Utf8 => {
+            let data = values
+                .into_iter()
+                .map(|value| match value {
+                    DecodedValue::Null => Ok(None),
+                    DecodedValue::Utf8(s) => Ok(Some(s)),
+                    other => Err(mismatch(&other, dtype)),
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok(Utf8Array::from_iter(name, data.into_iter()).into_series())
+        }
+        DataType::Binary => {
+            let data = values
+                .into_iter()
+                .map(|value| match value {
+                    DecodedValue::Null => Ok(None),
+                    DecodedValue::Binary(b) => Ok(Some(b)),
+                    DecodedValue::Utf8(s) => Ok(Some(s.into_bytes())),
+                    other => Err(mismatch(&other, dtype)),
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok(BinaryArray::from_iter(name, data.into_iter()).into_series())
+        }
+        DataType::List(child_dtype) => {
+            let validity = validity(&values);
+            let mut offsets = Vec::with_capacity(values.len() + 1);
+            offsets.push(0i64);
+            let mut flat_values = Vec::new();
+            for value in values {
+                match value {
+                    DecodedValue::Null => {}
+                    DecodedValue::List(items) => flat_values.extend(items),
+                    other => return Err(mismatch(&other, dtype)),
+                }
+                offsets.push(flat_values.len() as i64);
+            }
+            let flat_child = to_series(name, child_dtype, flat_values)?;
+            Ok(ListArray::new(
+                Field::new(name, dtype.clone()),
+                flat_child,
+                OffsetsBuffer::try_from(offsets)?,
+                Some(validity),
+            )
+            .into_series())
+        }
+        DataType::Struct(fields) => {
+            let validity = validity(&values);
+            let mut columns = fields
+                .iter()
+                .map(|_| Vec::with_capacity(values.len()))
+                .collect::<Vec<_>>();
+            for value in values {
+                match value {
+                    DecodedValue::Null => columns
+                        .iter_mut()
+                        .for_each(|column| column.push(DecodedValue::Null)),
+                    DecodedValue::Struct(field_values) if field_values.len() == fields.len() => {
+                        columns
+                            .iter_mut()
+                            .zip(field_values)
+                            .for_each(|(column, value)| column.push(value));
+                    }
+                    other => return Err(mismatch(&other, dtype)),
+                }
+            }
+            let children = fields
+                .iter()
+                .zip(columns)
+                .map(|(field, column)| to_series(&field.name, &field.dtype, column))
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok(
+                StructArray::new(Field::new(name, dtype.clone()), children, Some(validity))
+                    .into_series(),
+            )
+        }
+        DataType::Map { key, value } => {
+            let entries = values
+                .into_iter()
+                .map(|value| match value {
+                    DecodedValue::Null => Ok(DecodedValue::Null),
+                    DecodedValue::Map(pairs) => Ok(DecodedValue::List(
+                        pairs
+                            .into_iter()
+                            .map(|(k, v)| DecodedValue::Struct(vec![k, v]))
+                            .collect(),
+                    )),
+                    other => Err(mismatch(&other, dtype)),
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            let entries_dtype = DataType::List(Box::new(DataType::Struct(vec![
+                Field::new("key", *key.clone()),
+                Field::new("value", *value.clone()),
+            ])));
+            to_series(name, &entries_dtype, entries)?.cast(dtype)
+        }
+        other => Err(DaftError::TypeError(format!(
+            "Decoding values as {other} is not supported"
+        ))),
+    }
+}
+
+fn validity(values: &[DecodedValue]) -> Bitmap {
+    values.iter().map(|value| !value.is_null()).collect()
+}
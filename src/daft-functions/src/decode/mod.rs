@@ -0,0 +1,25 @@
+use daft_core::prelude::DataType;
+use daft_dsl::{functions::ScalarFunction, ExprRef};
+pub use msgpack::DecodeMsgpackFunction;
+pub use protobuf::DecodeProtobufFunction;
+
+mod builder;
+mod msgpack;
+mod protobuf;
+
+/// Decodes each binary value as a protobuf message of a message type of the descriptor set
+pub fn decode_protobuf(data: ExprRef, descriptor_set: Vec<u8>, message_name: &str) -> ExprRef {
+    ScalarFunction::new(
+        DecodeProtobufFunction {
+            descriptor_set,
+            message_name: message_name.to_string(),
+        },
+        vec![data],
+    )
+    .into()
+}
+
+/// Decodes each binary value as a msgpack value of the dtype
+pub fn decode_msgpack(data: ExprRef, dtype: DataType) -> ExprRef {
+    ScalarFunction::new(DecodeMsgpackFunction { dtype }, vec![data]).into()
+}
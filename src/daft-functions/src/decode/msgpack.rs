@@ -0,0 +1,136 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{functions::ScalarUDF, ExprRef};
+use rmpv::Value;
+use serde::{Deserialize, Serialize};
+
+use super::builder::{check_decodable, to_series, DecodedValue};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DecodeMsgpackFunction {
+    /// The dtype of the decoded values
+    pub dtype: DataType,
+}
+
+#[typetag::serde]
+impl ScalarUDF for DecodeMsgpackFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "decode_msgpack"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                if field.dtype != DataType::Binary {
+                    return Err(DaftError::TypeError(format!(
+                        "Expects input to decode_msgpack to be binary, but received {field}",
+                    )));
+                }
+                check_decodable(&self.dtype)?;
+                Ok(Field::new(field.name, self.dtype.clone()))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let values = data
+                    .binary()?
+                    .as_arrow()
+                    .iter()
+                    .map(|bytes| match bytes {
+                        Some(mut bytes) => {
+                            let value = rmpv::decode::read_value(&mut bytes).map_err(|e| {
+                                DaftError::ValueError(format!("Error decoding msgpack value: {e}"))
+                            })?;
+                            if !bytes.is_empty() {
+                                return Err(DaftError::ValueError(format!(
+                                    "Error decoding msgpack value: {} bytes remain after the value",
+                                    bytes.len()
+                                )));
+                            }
+                            msgpack_value(value, &self.dtype)
+                        }
+                        None => Ok(DecodedValue::Null),
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                to_series(data.name(), &self.dtype, values)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Converts a msgpack value to a value of the dtype. Structs are decoded from maps keyed by field
+/// name, where missing fields are null and other keys are ignored, or from arrays of the values of
+/// the fields, which is how serializers like `rmp-serde` write structs by default.
+fn msgpack_value(value: Value, dtype: &DataType) -> DaftResult<DecodedValue> {
+    Ok(match (value, dtype) {
+        (Value::Nil, _) => DecodedValue::Null,
+        (Value::Boolean(b), _) => DecodedValue::Bool(b),
+        (Value::Integer(i), _) => match i.as_u64() {
+            Some(u) => DecodedValue::UInt(u),
+            None => DecodedValue::Int(i.as_i64().unwrap()),
+        },
+        (Value::F32(f), _) => DecodedValue::Float(f64::from(f)),
+        (Value::F64(f), _) => DecodedValue::Float(f),
+        (Value::String(s), _) => DecodedValue::Utf8(s.into_str().ok_or_else(|| {
+            DaftError::ValueError("Msgpack string is not valid UTF-8".to_string())
+        })?),
+        (Value::Binary(b), _) => DecodedValue::Binary(b),
+        (Value::Array(items), DataType::List(child)) => DecodedValue::List(
+            items
+                .into_iter()
+                .map(|item| msgpack_value(item, child))
+                .collect::<DaftResult<_>>()?,
+        ),
+        (Value::Array(items), DataType::Struct(fields)) if items.len() == fields.len() => {
+            DecodedValue::Struct(
+                items
+                    .into_iter()
+                    .zip(fields)
+                    .map(|(item, field)| msgpack_value(item, &field.dtype))
+                    .collect::<DaftResult<_>>()?,
+            )
+        }
+        (Value::Map(entries), DataType::Struct(fields)) => {
+            let mut values = fields
+                .iter()
+                .map(|_| DecodedValue::Null)
+                .collect::<Vec<_>>();
+            for (key, value) in entries {
+                let position = key
+                    .as_str()
+                    .and_then(|key| fields.iter().position(|field| field.name == key));
+                if let Some(idx) = position {
+                    values[idx] = msgpack_value(value, &fields[idx].dtype)?;
+                }
+            }
+            DecodedValue::Struct(values)
+        }
+        (Value::Map(entries), DataType::Map { key, value }) => DecodedValue::Map(
+            entries
+                .into_iter()
+                .map(|(k, v)| Ok((msgpack_value(k, key)?, msgpack_value(v, value)?)))
+                .collect::<DaftResult<_>>()?,
+        ),
+        (value, dtype) => {
+            return Err(DaftError::ValueError(format!(
+                "Cannot decode msgpack value {value} as {dtype}"
+            )))
+        }
+    })
+}
@@ -0,0 +1,215 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{functions::ScalarUDF, ExprRef};
+use prost_reflect::{
+    DescriptorPool, DynamicMessage, FieldDescriptor, Kind, MapKey, MessageDescriptor, Value,
+};
+use serde::{Deserialize, Serialize};
+
+use super::builder::{to_series, DecodedValue};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DecodeProtobufFunction {
+    /// A serialized `FileDescriptorSet` that defines the message, e.g. from `protoc --descriptor_set_out`
+    pub descriptor_set: Vec<u8>,
+    /// The fully qualified name of the message, e.g. `my.package.Event`
+    pub message_name: String,
+}
+
+impl DecodeProtobufFunction {
+    fn message_descriptor(&self) -> DaftResult<MessageDescriptor> {
+        let pool = DescriptorPool::decode(self.descriptor_set.as_slice())
+            .map_err(|e| DaftError::ValueError(format!("Invalid protobuf descriptor set: {e}")))?;
+        pool.get_message_by_name(&self.message_name).ok_or_else(|| {
+            DaftError::ValueError(format!(
+                "Protobuf message {} is not defined by the descriptor set",
+                self.message_name
+            ))
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for DecodeProtobufFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "decode_protobuf"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                if field.dtype != DataType::Binary {
+                    return Err(DaftError::TypeError(format!(
+                        "Expects input to decode_protobuf to be binary, but received {field}",
+                    )));
+                }
+                let dtype = message_dtype(&self.message_descriptor()?, &mut Vec::new())?;
+                Ok(Field::new(field.name, dtype))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let descriptor = self.message_descriptor()?;
+                let dtype = message_dtype(&descriptor, &mut Vec::new())?;
+                let values = data
+                    .binary()?
+                    .as_arrow()
+                    .iter()
+                    .map(|bytes| match bytes {
+                        Some(bytes) => {
+                            let message = DynamicMessage::decode(descriptor.clone(), bytes)
+                                .map_err(|e| {
+                                    DaftError::ValueError(format!(
+                                        "Error decoding protobuf message {}: {e}",
+                                        self.message_name
+                                    ))
+                                })?;
+                            Ok(message_value(&message))
+                        }
+                        None => Ok(DecodedValue::Null),
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                to_series(data.name(), &dtype, values)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// The struct dtype of a message, with a field per field of the message. Enums are decoded as the
+/// names of their values.
+fn message_dtype(message: &MessageDescriptor, parents: &mut Vec<String>) -> DaftResult<DataType> {
+    if parents.iter().any(|parent| parent == message.full_name()) {
+        return Err(DaftError::TypeError(format!(
+            "Protobuf message {} is recursive, which can't be decoded as a struct",
+            message.full_name()
+        )));
+    }
+    parents.push(message.full_name().to_string());
+    let fields = message
+        .fields()
+        .map(|field| Ok(Field::new(field.name(), field_dtype(&field, parents)?)))
+        .collect::<DaftResult<Vec<_>>>()?;
+    parents.pop();
+    Ok(DataType::Struct(fields))
+}
+
+fn field_dtype(field: &FieldDescriptor, parents: &mut Vec<String>) -> DaftResult<DataType> {
+    match field.kind() {
+        Kind::Message(entry) if field.is_map() => Ok(DataType::Map {
+            key: Box::new(kind_dtype(entry.map_entry_key_field().kind(), parents)?),
+            value: Box::new(kind_dtype(entry.map_entry_value_field().kind(), parents)?),
+        }),
+        kind if field.is_list() => Ok(DataType::List(Box::new(kind_dtype(kind, parents)?))),
+        kind => kind_dtype(kind, parents),
+    }
+}
+
+fn kind_dtype(kind: Kind, parents: &mut Vec<String>) -> DaftResult<DataType> {
+    Ok(match kind {
+        Kind::Double => DataType::Float64,
+        Kind::Float => DataType::Float32,
+        Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => DataType::Int32,
+        Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => DataType::Int64,
+        Kind::Uint32 | Kind::Fixed32 => DataType::UInt32,
+        Kind::Uint64 | Kind::Fixed64 => DataType::UInt64,
+        Kind::Bool => DataType::Boolean,
+        Kind::String | Kind::Enum(_) => DataType::Utf8,
+        Kind::Bytes => DataType::Binary,
+        Kind::Message(message) => message_dtype(&message, parents)?,
+    })
+}
+
+/// The values of the fields of a message. Unset fields that track presence, like message fields
+/// and `optional` fields, are null while other unset fields have their default values.
+fn message_value(message: &DynamicMessage) -> DecodedValue {
+    DecodedValue::Struct(
+        message
+            .descriptor()
+            .fields()
+            .map(|field| {
+                if field.supports_presence() && !message.has_field(&field) {
+                    DecodedValue::Null
+                } else {
+                    field_value(&field, &message.get_field(&field))
+                }
+            })
+            .collect(),
+    )
+}
+
+fn field_value(field: &FieldDescriptor, value: &Value) -> DecodedValue {
+    match (field.kind(), value) {
+        (Kind::Message(entry), Value::Map(map)) => {
+            let value_kind = entry.map_entry_value_field().kind();
+            // map entries are sorted by key, since maps of protobuf messages are unordered
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            DecodedValue::Map(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (map_key_value(key), kind_value(&value_kind, value)))
+                    .collect(),
+            )
+        }
+        (kind, Value::List(items)) => {
+            DecodedValue::List(items.iter().map(|item| kind_value(&kind, item)).collect())
+        }
+        (kind, value) => kind_value(&kind, value),
+    }
+}
+
+fn kind_value(kind: &Kind, value: &Value) -> DecodedValue {
+    match value {
+        Value::Bool(b) => DecodedValue::Bool(*b),
+        Value::I32(i) => DecodedValue::Int(i64::from(*i)),
+        Value::I64(i) => DecodedValue::Int(*i),
+        Value::U32(u) => DecodedValue::UInt(u64::from(*u)),
+        Value::U64(u) => DecodedValue::UInt(*u),
+        Value::F32(f) => DecodedValue::Float(f64::from(*f)),
+        Value::F64(f) => DecodedValue::Float(*f),
+        Value::String(s) => DecodedValue::Utf8(s.clone()),
+        Value::Bytes(b) => DecodedValue::Binary(b.to_vec()),
+        Value::EnumNumber(number) => {
+            // numbers that aren't values of the enum, e.g. from a newer schema, are kept as is
+            let name = match kind {
+                Kind::Enum(descriptor) => descriptor
+                    .get_value(*number)
+                    .map(|value| value.name().to_string()),
+                _ => None,
+            };
+            DecodedValue::Utf8(name.unwrap_or_else(|| number.to_string()))
+        }
+        Value::Message(message) => message_value(message),
+        Value::List(items) => {
+            DecodedValue::List(items.iter().map(|item| kind_value(kind, item)).collect())
+        }
+        Value::Map(_) => unreachable!("maps are only values of map fields"),
+    }
+}
+
+fn map_key_value(key: &MapKey) -> DecodedValue {
+    match key {
+        MapKey::Bool(b) => DecodedValue::Bool(*b),
+        MapKey::I32(i) => DecodedValue::Int(i64::from(*i)),
+        MapKey::I64(i) => DecodedValue::Int(*i),
+        MapKey::U32(u) => DecodedValue::UInt(u64::from(*u)),
+        MapKey::U64(u) => DecodedValue::UInt(*u),
+        MapKey::String(s) => DecodedValue::Utf8(s.clone()),
+    }
+}
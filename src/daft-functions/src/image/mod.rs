@@ -3,3 +3,4 @@ pub mod decode;
 pub mod encode;
 pub mod resize;
 pub mod to_mode;
+pub mod to_tensor;
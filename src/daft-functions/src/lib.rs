@@ -1,6 +1,8 @@
 #![feature(async_closure)]
+pub mod clustering;
 pub mod coalesce;
 pub mod count_matches;
+pub mod decode;
 pub mod distance;
 pub mod float;
 pub mod hash;
@@ -0,0 +1,17 @@
+use daft_core::python::PyDataType;
+use daft_dsl::python::PyExpr;
+use pyo3::{pyfunction, PyResult};
+
+#[pyfunction]
+pub fn decode_protobuf(
+    expr: PyExpr,
+    descriptor_set: &[u8],
+    message_name: &str,
+) -> PyResult<PyExpr> {
+    Ok(crate::decode::decode_protobuf(expr.into(), descriptor_set.to_vec(), message_name).into())
+}
+
+#[pyfunction]
+pub fn decode_msgpack(expr: PyExpr, dtype: PyDataType) -> PyResult<PyExpr> {
+    Ok(crate::decode::decode_msgpack(expr.into(), dtype.dtype).into())
+}
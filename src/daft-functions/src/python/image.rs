@@ -6,6 +6,7 @@ use crate::image::{decode::ImageDecode, encode::ImageEncode};
 
 simple_python_wrapper!(image_crop, crate::image::crop::crop, [expr: PyExpr, bbox: PyExpr]);
 simple_python_wrapper!(image_to_mode, crate::image::to_mode::image_to_mode, [expr: PyExpr, mode: ImageMode]);
+simple_python_wrapper!(image_to_tensor, crate::image::to_tensor::image_to_tensor, [expr: PyExpr]);
 
 #[pyfunction]
 pub fn image_decode(
@@ -42,6 +42,13 @@ pub fn minhash(
     Ok(expr.into())
 }
 
+#[pyfunction]
+pub fn clustering_key(inputs: Vec<PyExpr>, curve: &str) -> PyResult<PyExpr> {
+    let curve: crate::clustering::ClusteringCurve = curve.parse()?;
+    let inputs = inputs.into_iter().map(Into::into).collect();
+    Ok(crate::clustering::clustering_key_expr(inputs, curve).into())
+}
+
 #[pyfunction]
 pub fn hash(expr: PyExpr, seed: Option<PyExpr>) -> PyResult<PyExpr> {
     Ok(crate::hash::hash(expr.into(), seed.map(Into::into)).into())
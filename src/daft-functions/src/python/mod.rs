@@ -13,6 +13,7 @@ macro_rules! simple_python_wrapper {
 }
 
 mod coalesce;
+mod decode;
 mod distance;
 mod float;
 mod image;
@@ -37,6 +38,8 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     }
 
     add!(coalesce::coalesce);
+    add!(decode::decode_protobuf);
+    add!(decode::decode_msgpack);
     add!(distance::cosine_distance);
 
     add!(float::is_inf);
@@ -46,6 +49,7 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
 
     add!(image::image_crop);
     add!(image::image_to_mode);
+    add!(image::image_to_tensor);
     add!(image::image_decode);
     add!(image::image_encode);
     add!(image::image_resize);
@@ -68,6 +72,7 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(misc::utf8_count_matches);
     add!(misc::hash);
     add!(misc::minhash);
+    add!(misc::clustering_key);
 
     add!(numeric::abs);
     add!(numeric::cbrt);
@@ -109,11 +114,14 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
 
     add!(tokenize::tokenize_encode);
     add!(tokenize::tokenize_decode);
+    add!(tokenize::tokenize);
+    add!(tokenize::detokenize);
 
     add!(uri::url_download);
     add!(uri::url_upload);
 
     add!(utf8::utf8_capitalize);
+    add!(utf8::utf8_collation_key);
     add!(utf8::utf8_contains);
     add!(utf8::utf8_endswith);
     add!(utf8::utf8_extract);
@@ -39,3 +39,35 @@ pub fn tokenize_decode(
     )
     .into())
 }
+
+#[pyfunction]
+pub fn tokenize(
+    expr: PyExpr,
+    tokenizer_path: &str,
+    add_special_tokens: bool,
+    io_config: Option<IOConfig>,
+) -> PyResult<PyExpr> {
+    Ok(crate::tokenize::tokenize(
+        expr.into(),
+        tokenizer_path,
+        io_config.map(|config| config.config),
+        add_special_tokens,
+    )
+    .into())
+}
+
+#[pyfunction]
+pub fn detokenize(
+    expr: PyExpr,
+    tokenizer_path: &str,
+    skip_special_tokens: bool,
+    io_config: Option<IOConfig>,
+) -> PyResult<PyExpr> {
+    Ok(crate::tokenize::detokenize(
+        expr.into(),
+        tokenizer_path,
+        io_config.map(|config| config.config),
+        skip_special_tokens,
+    )
+    .into())
+}
@@ -1,4 +1,4 @@
-use daft_core::array::ops::Utf8NormalizeOptions;
+use daft_core::array::ops::{Collation, Utf8NormalizeOptions};
 use daft_dsl::python::PyExpr;
 use pyo3::{pyfunction, PyResult};
 
@@ -48,6 +48,12 @@ pub fn utf8_normalize(
     .into())
 }
 
+#[pyfunction]
+pub fn utf8_collation_key(expr: PyExpr, collation: &str) -> PyResult<PyExpr> {
+    let collation: Collation = collation.parse()?;
+    Ok(crate::utf8::collation_key(expr.into(), collation).into())
+}
+
 #[pyfunction]
 pub fn utf8_to_date(expr: PyExpr, format: &str) -> PyResult<PyExpr> {
     Ok(crate::utf8::to_date(expr.into(), format).into())
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{functions::ScalarUDF, ExprRef};
+use daft_io::IOConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::tokenize::hf_tokenizer::get_hf_tokenizer;
+
+fn detokenize_array(
+    arr: &ListArray,
+    tokenizer_path: &str,
+    io_config: Option<Arc<IOConfig>>,
+    skip_special_tokens: bool,
+) -> DaftResult<Utf8Array> {
+    let tokenizer = get_hf_tokenizer(tokenizer_path, io_config)?;
+    let ids = arr.flat_child.cast(&DataType::UInt32)?;
+    let ids = ids.u32()?.as_arrow().values();
+    let offsets = arr.offsets();
+    let strs = (0..offsets.len() - 1)
+        .map(|i| {
+            let tokens = &ids[offsets[i] as usize..offsets[i + 1] as usize];
+            tokenizer
+                .decode(tokens, skip_special_tokens)
+                .map_err(|e| DaftError::ComputeError(format!("Error detokenizing {tokens:?}: {e}")))
+        })
+        .collect::<DaftResult<Vec<String>>>()?;
+    Utf8Array::from_iter(arr.name(), strs.iter().map(Some)).with_validity(arr.validity().cloned())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct HfDetokenizeFunction {
+    pub tokenizer_path: String,
+    pub io_config: Option<Arc<IOConfig>>,
+    pub skip_special_tokens: bool,
+}
+
+#[typetag::serde]
+impl ScalarUDF for HfDetokenizeFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "detokenize"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                match &field.dtype {
+                    DataType::List(inner) if inner.is_integer() => {
+                        Ok(Field::new(field.name, DataType::Utf8))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to detokenize to be list[integer], but received {field}",
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => match data.data_type() {
+                DataType::List(_) => Ok(detokenize_array(
+                    data.list()?,
+                    &self.tokenizer_path,
+                    self.io_config.clone(),
+                    self.skip_special_tokens,
+                )?
+                .into_series()),
+                dt => Err(DaftError::TypeError(format!(
+                    "Detokenize not implemented for type {dt}"
+                ))),
+            },
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
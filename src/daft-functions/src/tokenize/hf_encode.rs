@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{MutableArray, MutablePrimitiveArray, PrimitiveArray},
+    offset::OffsetsBuffer,
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{functions::ScalarUDF, ExprRef};
+use daft_io::IOConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::tokenize::hf_tokenizer::get_hf_tokenizer;
+
+fn tokenize_array(
+    arr: &Utf8Array,
+    tokenizer_path: &str,
+    io_config: Option<Arc<IOConfig>>,
+    add_special_tokens: bool,
+) -> DaftResult<ListArray> {
+    let tokenizer = get_hf_tokenizer(tokenizer_path, io_config)?;
+
+    let mut flat_child = MutablePrimitiveArray::<i32>::new();
+    let mut offsets: Vec<i64> = Vec::with_capacity(arr.len() + 1);
+    offsets.push(0);
+    for s_opt in arr.as_arrow() {
+        if let Some(s) = s_opt {
+            let encoding = tokenizer
+                .encode(s, add_special_tokens)
+                .map_err(|e| DaftError::ComputeError(format!("Error tokenizing {s:?}: {e}")))?;
+            flat_child.extend(encoding.get_ids().iter().map(|id| Some(*id as i32)));
+        }
+        offsets.push(flat_child.len() as i64);
+    }
+    let flat_child: PrimitiveArray<i32> = flat_child.into();
+    let child_series = Series::from_arrow(
+        Field::new("flat_child", DataType::Int32).into(),
+        Box::new(flat_child),
+    )?;
+    let offsets = OffsetsBuffer::try_from(offsets)?;
+    Ok(ListArray::new(
+        Field::new(arr.name(), DataType::List(Box::new(DataType::Int32))),
+        child_series,
+        offsets,
+        arr.validity().cloned(),
+    ))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct HfTokenizeFunction {
+    pub tokenizer_path: String,
+    pub io_config: Option<Arc<IOConfig>>,
+    pub add_special_tokens: bool,
+}
+
+#[typetag::serde]
+impl ScalarUDF for HfTokenizeFunction {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "tokenize"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => {
+                let field = data.to_field(schema)?;
+                match &field.dtype {
+                    DataType::Utf8 => Ok(Field::new(
+                        field.name,
+                        DataType::List(Box::new(DataType::Int32)),
+                    )),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to tokenize to be utf8, but received {field}",
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => data.with_utf8_array(|arr| {
+                Ok(tokenize_array(
+                    arr,
+                    &self.tokenizer_path,
+                    self.io_config.clone(),
+                    self.add_special_tokens,
+                )?
+                .into_series())
+            }),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
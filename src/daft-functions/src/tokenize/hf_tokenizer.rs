@@ -0,0 +1,41 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex},
+};
+
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_io::{get_io_client, IOConfig};
+use tokenizers::Tokenizer;
+
+// Tokenizers that were loaded by this process, by path. Loading a tokenizer means downloading and
+// parsing its tokenizer.json, so it's done once by the first batch that uses it rather than by
+// every batch of an operator.
+static TOKENIZERS: LazyLock<Mutex<HashMap<String, Arc<Tokenizer>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Gets the Hugging Face tokenizer of a tokenizer.json file, loading it if this process hasn't yet.
+pub fn get_hf_tokenizer(
+    tokenizer_path: &str,
+    io_config: Option<Arc<IOConfig>>,
+) -> DaftResult<Arc<Tokenizer>> {
+    let mut tokenizers = TOKENIZERS.lock().unwrap();
+    if let Some(tokenizer) = tokenizers.get(tokenizer_path) {
+        return Ok(tokenizer.clone());
+    }
+
+    let client = get_io_client(false, io_config.unwrap_or_default())?;
+    let runtime = get_io_runtime(false);
+    let path = tokenizer_path.to_string();
+    let file_bytes = runtime
+        .block_on(async move { client.single_url_get(path, None, None).await?.bytes().await })??;
+    let tokenizer = Tokenizer::from_bytes(&file_bytes).map_err(|e| {
+        DaftError::ValueError(format!(
+            "Error loading Hugging Face tokenizer from {tokenizer_path}: {e}"
+        ))
+    })?;
+
+    let tokenizer = Arc::new(tokenizer);
+    tokenizers.insert(tokenizer_path.to_string(), tokenizer.clone());
+    Ok(tokenizer)
+}
@@ -2,10 +2,15 @@ use daft_dsl::{functions::ScalarFunction, ExprRef};
 use daft_io::IOConfig;
 pub use decode::TokenizeDecodeFunction;
 pub use encode::TokenizeEncodeFunction;
+pub use hf_decode::HfDetokenizeFunction;
+pub use hf_encode::HfTokenizeFunction;
 
 mod bpe;
 mod decode;
 mod encode;
+mod hf_decode;
+mod hf_encode;
+mod hf_tokenizer;
 mod special_tokens;
 
 pub fn tokenize_encode(
@@ -47,3 +52,37 @@ pub fn tokenize_decode(
     )
     .into()
 }
+
+pub fn tokenize(
+    data: ExprRef,
+    tokenizer_path: &str,
+    io_config: Option<IOConfig>,
+    add_special_tokens: bool,
+) -> ExprRef {
+    ScalarFunction::new(
+        HfTokenizeFunction {
+            tokenizer_path: tokenizer_path.to_string(),
+            io_config: io_config.map(std::convert::Into::into),
+            add_special_tokens,
+        },
+        vec![data],
+    )
+    .into()
+}
+
+pub fn detokenize(
+    data: ExprRef,
+    tokenizer_path: &str,
+    io_config: Option<IOConfig>,
+    skip_special_tokens: bool,
+) -> ExprRef {
+    ScalarFunction::new(
+        HfDetokenizeFunction {
+            tokenizer_path: tokenizer_path.to_string(),
+            io_config: io_config.map(std::convert::Into::into),
+            skip_special_tokens,
+        },
+        vec![data],
+    )
+    .into()
+}
@@ -0,0 +1,61 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::Collation,
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Utf8CollationKey {
+    pub collation: Collation,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Utf8CollationKey {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn name(&self) -> &'static str {
+        "collation_key"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [data] => match data.to_field(schema) {
+                Ok(data_field) => match &data_field.dtype {
+                    DataType::Utf8 => Ok(Field::new(data_field.name, DataType::Binary)),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to collation_key to be utf8, but received {data_field}",
+                    ))),
+                },
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [data] => data.utf8_collation_key(&self.collation),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// Binary keys of the strings of `input` that sort and compare the way the strings do under
+/// `collation`.
+#[must_use]
+pub fn utf8_collation_key(input: ExprRef, collation: Collation) -> ExprRef {
+    ScalarFunction::new(Utf8CollationKey { collation }, vec![input]).into()
+}
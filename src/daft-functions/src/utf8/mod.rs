@@ -1,4 +1,5 @@
 mod capitalize;
+mod collation_key;
 mod contains;
 mod endswith;
 mod extract;
@@ -28,6 +29,7 @@ mod to_datetime;
 mod upper;
 
 pub use capitalize::{utf8_capitalize as capitalize, Utf8Capitalize};
+pub use collation_key::{utf8_collation_key as collation_key, Utf8CollationKey};
 pub use contains::{utf8_contains as contains, Utf8Contains};
 pub use endswith::{utf8_endswith as endswith, Utf8Endswith};
 pub use extract::{utf8_extract as extract, Utf8Extract};
@@ -0,0 +1,460 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use daft_catalog_iceberg_rest_catalog::{
+    metadata::{Snapshot, TableIdent, TableMetadata, TableRequirement, TableUpdate},
+    IcebergRestClient,
+};
+use daft_io::IOClient;
+use serde_json::{json, Value};
+use snafu::ResultExt;
+
+use crate::{
+    commit::{IcebergWrite, WriteMode},
+    now_millis, ConcurrentOverwriteSnafu, InvalidMetadataSnafu, Result, TableNotFoundSnafu,
+    TooManyAttemptsSnafu, UnsafeCommitSnafu,
+};
+
+/// How many times a commit is attempted before giving up on concurrent commits.
+pub const DEFAULT_MAX_COMMIT_ATTEMPTS: usize = 4;
+
+/// The branch that writes commit to.
+const MAIN_BRANCH: &str = "main";
+
+/// Where the current metadata of a table is tracked.
+pub enum IcebergCatalog {
+    /// Tables whose metadata files are numbered versions in their `metadata` directory, as in
+    /// the Hadoop catalog of Iceberg. `version-hint.text` holds the latest version, and commits
+    /// rely on the object store failing to write a version that already exists.
+    FileSystem { table_location: String },
+    /// Tables of an Iceberg REST catalog, which checks the requirements of commits itself.
+    Rest {
+        client: Arc<IcebergRestClient>,
+        table: TableIdent,
+    },
+}
+
+/// The metadata of a table as loaded for a commit.
+struct LoadedMetadata {
+    metadata: TableMetadata,
+    /// Version and raw JSON of the metadata file of a file-system table, which commits copy so
+    /// as to keep the fields that [`TableMetadata`] does not model.
+    file: Option<(i64, Value)>,
+}
+
+impl IcebergCatalog {
+    fn metadata_uri(table_location: &str, file_name: &str) -> String {
+        format!(
+            "{}/metadata/{file_name}",
+            table_location.trim_end_matches('/')
+        )
+    }
+
+    fn version_uri(table_location: &str, version: i64) -> String {
+        Self::metadata_uri(table_location, &format!("v{version}.metadata.json"))
+    }
+
+    /// Loads the current metadata of the table.
+    pub async fn load_metadata(&self, io_client: &IOClient) -> Result<TableMetadata> {
+        Ok(self.load(io_client).await?.metadata)
+    }
+
+    async fn load(&self, io_client: &IOClient) -> Result<LoadedMetadata> {
+        match self {
+            Self::FileSystem { table_location } => {
+                let (version, raw) = load_latest_version(io_client, table_location).await?;
+                let metadata =
+                    serde_json::from_value(raw.clone()).context(InvalidMetadataSnafu {
+                        path: Self::version_uri(table_location, version),
+                    })?;
+                Ok(LoadedMetadata {
+                    metadata,
+                    file: Some((version, raw)),
+                })
+            }
+            Self::Rest { client, table } => Ok(LoadedMetadata {
+                metadata: client.load_table(table).await?.metadata,
+                file: None,
+            }),
+        }
+    }
+
+    /// Commits a write as a new snapshot on top of the current snapshot of the table.
+    ///
+    /// If another writer commits first, appends are rewritten on top of its snapshot and retried,
+    /// up to `max_attempts` attempts in total. Overwrites are not retried on top of a different
+    /// snapshot, since they would delete files that their writer never saw. Replaces are retried
+    /// as long as the files they replace are still part of the table.
+    ///
+    /// Commits to file-system tables fail if their object store cannot write a file only if it
+    /// is absent, since concurrent commits could then overwrite each other.
+    ///
+    /// Returns the committed snapshot.
+    pub async fn commit(
+        &self,
+        io_client: &IOClient,
+        write: &IcebergWrite,
+        max_attempts: usize,
+    ) -> Result<Snapshot> {
+        if let Self::FileSystem { table_location } = self {
+            if !io_client.supports_put_if_absent(table_location).await? {
+                return UnsafeCommitSnafu {
+                    location: table_location,
+                }
+                .fail();
+            }
+        }
+        // Snapshot ids only need to be unique within the table, and are positive by convention.
+        let snapshot_id = (uuid::Uuid::new_v4().as_u64_pair().0 & i64::MAX as u64) as i64;
+        let mut read_snapshot_id = None;
+        for _ in 0..max_attempts {
+            let loaded = self.load(io_client).await?;
+            let current_snapshot_id = loaded
+                .metadata
+                .current_snapshot()
+                .map(|snapshot| snapshot.snapshot_id);
+            match read_snapshot_id {
+                Some(expected)
+                    if write.mode == WriteMode::Overwrite && expected != current_snapshot_id =>
+                {
+                    return ConcurrentOverwriteSnafu {
+                        expected,
+                        found: current_snapshot_id,
+                    }
+                    .fail();
+                }
+                Some(_) => {}
+                None => read_snapshot_id = Some(current_snapshot_id),
+            }
+
+            let snapshot = write
+                .write_snapshot(io_client, &loaded.metadata, snapshot_id, now_millis())
+                .await?;
+            if self.try_commit(io_client, &loaded, &snapshot).await? {
+                return Ok(snapshot);
+            }
+        }
+        TooManyAttemptsSnafu {
+            attempts: max_attempts,
+        }
+        .fail()
+    }
+
+    /// Points the table at `snapshot`, returning whether the commit went through or lost to a
+    /// concurrent commit.
+    async fn try_commit(
+        &self,
+        io_client: &IOClient,
+        loaded: &LoadedMetadata,
+        snapshot: &Snapshot,
+    ) -> Result<bool> {
+        match self {
+            Self::FileSystem { table_location } => {
+                let (version, raw) = loaded
+                    .file
+                    .as_ref()
+                    .expect("file-system tables are loaded with their metadata file");
+                let previous_uri = Self::version_uri(table_location, *version);
+                let updated = apply_snapshot(raw.clone(), &previous_uri, snapshot);
+                let uri = Self::version_uri(table_location, version + 1);
+                let data = serde_json::to_vec(&updated).expect("table metadata is serializable");
+                match io_client
+                    .single_url_put_if_absent(&uri, Bytes::from(data), None)
+                    .await
+                {
+                    Ok(()) => {}
+                    Err(daft_io::Error::AlreadyExists { .. }) => return Ok(false),
+                    Err(err) => return Err(err.into()),
+                }
+                // The hint only saves readers from probing for later versions, so failing to
+                // update it does not fail the commit.
+                let hint_uri = Self::metadata_uri(table_location, "version-hint.text");
+                let hint = Bytes::from((version + 1).to_string());
+                if let Err(err) = io_client.single_url_put(&hint_uri, hint, None).await {
+                    log::warn!("Failed to update the version hint of the Iceberg table at {table_location}: {err}");
+                }
+                Ok(true)
+            }
+            Self::Rest { client, table } => {
+                let requirements = vec![
+                    TableRequirement::AssertTableUuid {
+                        uuid: loaded.metadata.table_uuid.clone(),
+                    },
+                    TableRequirement::AssertRefSnapshotId {
+                        ref_name: MAIN_BRANCH.to_string(),
+                        snapshot_id: snapshot.parent_snapshot_id,
+                    },
+                ];
+                let updates = vec![
+                    TableUpdate::AddSnapshot {
+                        snapshot: snapshot.clone(),
+                    },
+                    TableUpdate::SetSnapshotRef {
+                        ref_name: MAIN_BRANCH.to_string(),
+                        ref_type: "branch".to_string(),
+                        snapshot_id: snapshot.snapshot_id,
+                    },
+                ];
+                match client.commit_table(table, requirements, updates).await {
+                    Ok(_) => Ok(true),
+                    Err(err) if err.is_conflict() => Ok(false),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    }
+}
+
+/// Finds the latest metadata file of a file-system table, starting from the version in its
+/// version hint and probing for later versions in case the hint is stale.
+async fn load_latest_version(io_client: &IOClient, table_location: &str) -> Result<(i64, Value)> {
+    let get = |uri: String| async move {
+        let bytes = async {
+            io_client
+                .single_url_get(uri.clone(), None, None)
+                .await?
+                .bytes()
+                .await
+        };
+        match bytes.await {
+            Ok(bytes) => Ok(Some((uri, bytes))),
+            Err(daft_io::Error::NotFound { .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    };
+
+    let hint_uri = IcebergCatalog::metadata_uri(table_location, "version-hint.text");
+    let mut version = match get(hint_uri).await? {
+        Some((uri, hint)) => String::from_utf8_lossy(&hint)
+            .trim()
+            .parse::<i64>()
+            .map_err(|err| crate::Error::Invalid {
+                what: "version hint".to_string(),
+                reason: format!("{uri}: {err}"),
+            })?,
+        None => 1,
+    };
+    let mut latest = None;
+    while let Some(file) = get(IcebergCatalog::version_uri(table_location, version)).await? {
+        latest = Some((version, file));
+        version += 1;
+    }
+    let Some((version, (uri, bytes))) = latest else {
+        return TableNotFoundSnafu {
+            location: table_location,
+        }
+        .fail();
+    };
+    let raw = serde_json::from_slice(&bytes).context(InvalidMetadataSnafu { path: uri })?;
+    Ok((version, raw))
+}
+
+/// Adds `snapshot` to the raw JSON of table metadata and makes it the current snapshot, logging
+/// the change of current snapshot and of metadata file as the spec requires.
+fn apply_snapshot(mut metadata: Value, previous_uri: &str, snapshot: &Snapshot) -> Value {
+    let previous_updated_ms = metadata["last-updated-ms"].clone();
+    let append = |metadata: &mut Value, key: &str, entry: Value| match &mut metadata[key] {
+        Value::Array(entries) => entries.push(entry),
+        field => *field = json!([entry]),
+    };
+
+    metadata["last-sequence-number"] = json!(snapshot.sequence_number);
+    metadata["last-updated-ms"] = json!(snapshot.timestamp_ms);
+    metadata["current-snapshot-id"] = json!(snapshot.snapshot_id);
+    append(
+        &mut metadata,
+        "snapshots",
+        serde_json::to_value(snapshot).expect("snapshots are serializable"),
+    );
+    metadata["refs"][MAIN_BRANCH] = json!({
+        "snapshot-id": snapshot.snapshot_id,
+        "type": "branch",
+    });
+    append(
+        &mut metadata,
+        "snapshot-log",
+        json!({"snapshot-id": snapshot.snapshot_id, "timestamp-ms": snapshot.timestamp_ms}),
+    );
+    append(
+        &mut metadata,
+        "metadata-log",
+        json!({"metadata-file": previous_uri, "timestamp-ms": previous_updated_ms}),
+    );
+    metadata
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use daft_io::get_io_client;
+
+    use super::*;
+    use crate::{DataFile, Error};
+
+    fn create_table(dir: &std::path::Path) -> String {
+        let metadata = json!({
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": format!("file://{}", dir.to_string_lossy()),
+            "last-sequence-number": 0,
+            "last-updated-ms": 0,
+            "last-column-id": 1,
+            "current-schema-id": 0,
+            "schemas": [{"type": "struct", "schema-id": 0, "fields": [
+                {"id": 1, "name": "id", "required": false, "type": "long"}
+            ]}],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}],
+            "current-snapshot-id": -1,
+            "properties": {}
+        });
+        std::fs::create_dir_all(dir.join("metadata")).unwrap();
+        std::fs::write(
+            dir.join("metadata/v1.metadata.json"),
+            serde_json::to_vec(&metadata).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(dir.join("metadata/version-hint.text"), "1").unwrap();
+        format!("file://{}", dir.to_string_lossy())
+    }
+
+    fn write(mode: WriteMode, path: &str) -> IcebergWrite {
+        IcebergWrite {
+            mode,
+            data_files: vec![DataFile {
+                file_path: path.to_string(),
+                file_format: "PARQUET".to_string(),
+                record_count: 10,
+                file_size_in_bytes: 100,
+                ..Default::default()
+            }],
+            snapshot_properties: BTreeMap::new(),
+            replaced_paths: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_system_commits() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let catalog = IcebergCatalog::FileSystem {
+            table_location: create_table(dir.path()),
+        };
+        let io_client = get_io_client(false, Default::default()).unwrap();
+
+        let first = catalog
+            .commit(&io_client, &write(WriteMode::Append, "a.parquet"), 1)
+            .await?;
+        let second = catalog
+            .commit(&io_client, &write(WriteMode::Append, "b.parquet"), 1)
+            .await?;
+        assert_eq!(second.parent_snapshot_id, Some(first.snapshot_id));
+        assert_eq!(second.summary["total-records"], "20");
+        let overwrite = catalog
+            .commit(&io_client, &write(WriteMode::Overwrite, "c.parquet"), 1)
+            .await?;
+        assert_eq!(overwrite.summary["deleted-data-files"], "2");
+        assert_eq!(overwrite.summary["total-records"], "10");
+
+        let metadata = catalog.load_metadata(&io_client).await?;
+        assert_eq!(metadata.last_sequence_number, Some(3));
+        assert_eq!(metadata.current_snapshot(), Some(&overwrite));
+        assert_eq!(metadata.refs["main"].snapshot_id, overwrite.snapshot_id);
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("metadata/version-hint.text")).unwrap(),
+            "4"
+        );
+        let raw: Value = serde_json::from_slice(
+            &std::fs::read(dir.path().join("metadata/v4.metadata.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(raw["metadata-log"].as_array().unwrap().len(), 3);
+        assert_eq!(raw["default-sort-order-id"], 0);
+
+        // The overwrite lists the manifest of its file and those deleting the earlier files.
+        let manifest_list = std::fs::read(
+            overwrite
+                .manifest_list
+                .unwrap()
+                .strip_prefix("file://")
+                .unwrap(),
+        )
+        .unwrap();
+        let manifests = crate::manifest::read_manifest_list(&manifest_list)?;
+        let counts = manifests
+            .iter()
+            .map(|manifest| (manifest.added_files_count, manifest.deleted_files_count))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![(1, 0), (0, 1), (0, 1)]);
+
+        // Replaces only remove the files they replace, and fail if those are no longer there.
+        catalog
+            .commit(&io_client, &write(WriteMode::Append, "d.parquet"), 1)
+            .await?;
+        let replace = IcebergWrite {
+            replaced_paths: vec!["c.parquet".to_string(), "d.parquet".to_string()],
+            ..write(WriteMode::Replace, "cd.parquet")
+        };
+        let replaced = catalog.commit(&io_client, &replace, 1).await?;
+        assert_eq!(replaced.summary["operation"], "replace");
+        assert_eq!(replaced.summary["deleted-data-files"], "2");
+        assert_eq!(replaced.summary["total-data-files"], "1");
+        assert!(matches!(
+            catalog.commit(&io_client, &replace, 1).await,
+            Err(Error::ReplacedFilesMissing { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_file_system_concurrent_commits() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let catalog = IcebergCatalog::FileSystem {
+            table_location: create_table(dir.path()),
+        };
+        let io_client = get_io_client(false, Default::default()).unwrap();
+
+        // A commit of a version that already exists loses, and a stale version hint is skipped
+        // past by probing for later versions.
+        let loaded = catalog.load(&io_client).await?;
+        let snapshot = write(WriteMode::Append, "a.parquet")
+            .write_snapshot(&io_client, &loaded.metadata, 1, 0)
+            .await?;
+        assert!(catalog.try_commit(&io_client, &loaded, &snapshot).await?);
+        assert!(!catalog.try_commit(&io_client, &loaded, &snapshot).await?);
+        std::fs::write(dir.path().join("metadata/version-hint.text"), "1").unwrap();
+
+        let appended = catalog
+            .commit(&io_client, &write(WriteMode::Append, "b.parquet"), 1)
+            .await?;
+        assert_eq!(appended.parent_snapshot_id, Some(1));
+        assert_eq!(appended.sequence_number, Some(2));
+
+        assert!(matches!(
+            catalog
+                .commit(&io_client, &write(WriteMode::Append, "c.parquet"), 0)
+                .await,
+            Err(Error::TooManyAttempts { attempts: 0 })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_file_system_commit_requires_conditional_writes() -> Result<()> {
+        // HTTP sources cannot write objects only if they are absent.
+        let catalog = IcebergCatalog::FileSystem {
+            table_location: "http://localhost/table".to_string(),
+        };
+        let io_client = get_io_client(false, Default::default()).unwrap();
+        assert!(matches!(
+            catalog
+                .commit(&io_client, &write(WriteMode::Append, "a.parquet"), 1)
+                .await,
+            Err(Error::UnsafeCommit { .. })
+        ));
+        Ok(())
+    }
+}
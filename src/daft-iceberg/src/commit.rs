@@ -0,0 +1,342 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use bytes::Bytes;
+use daft_catalog_iceberg_rest_catalog::metadata::{Snapshot, TableMetadata};
+use daft_io::IOClient;
+
+use crate::{
+    manifest::{
+        read_manifest_list, write_added_manifest, write_deleted_manifest, write_manifest_list,
+        DataFile,
+    },
+    partition::PartitionType,
+    ReplacedFilesMissingSnafu, Result, UnsupportedFormatVersionSnafu,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteMode {
+    /// Adds the written files to the table.
+    Append,
+    /// Replaces the files of the table with the written files.
+    Overwrite,
+    /// Replaces the files in [`IcebergWrite::replaced_paths`] with the written files, which hold
+    /// the same rows, e.g. when compacting small files.
+    Replace,
+}
+
+impl WriteMode {
+    /// The operation recorded in the summary of the snapshot.
+    fn operation(self) -> &'static str {
+        match self {
+            Self::Append => "append",
+            Self::Overwrite => "overwrite",
+            Self::Replace => "replace",
+        }
+    }
+}
+
+/// A write of data files to a table, which becomes a new snapshot of the table once committed.
+#[derive(Debug, Clone)]
+pub struct IcebergWrite {
+    pub mode: WriteMode,
+    /// Data files written with the current schema and default partition spec of the table.
+    pub data_files: Vec<DataFile>,
+    /// Extra entries for the summary of the snapshot.
+    pub snapshot_properties: BTreeMap<String, String>,
+    /// Paths of the data files that a replace removes from the table.
+    pub replaced_paths: Vec<String>,
+}
+
+/// Numbers of data files and records in a snapshot summary.
+#[derive(Debug, Default)]
+struct SummaryCounts {
+    files: i64,
+    records: i64,
+}
+
+impl IcebergWrite {
+    /// Writes the manifests and manifest list of a snapshot of this write on top of the current
+    /// snapshot of the table described by `metadata`, returning the snapshot to commit. Manifests
+    /// are written to the `metadata` directory of the table under names unique to the attempt.
+    pub(crate) async fn write_snapshot(
+        &self,
+        io_client: &IOClient,
+        metadata: &TableMetadata,
+        snapshot_id: i64,
+        now: i64,
+    ) -> Result<Snapshot> {
+        if metadata.format_version != 2 {
+            return UnsupportedFormatVersionSnafu {
+                version: metadata.format_version,
+            }
+            .fail();
+        }
+        let partition_type = PartitionType::try_new(metadata)?;
+        let sequence_number = metadata.last_sequence_number.unwrap_or(0) + 1;
+        let parent = metadata.current_snapshot();
+        let attempt_id = uuid::Uuid::new_v4();
+        let metadata_uri = |file_name: String| {
+            format!(
+                "{}/metadata/{file_name}",
+                metadata.location.trim_end_matches('/')
+            )
+        };
+        let put = |uri: String, bytes: Vec<u8>| async move {
+            io_client
+                .single_url_put(&uri, Bytes::from(bytes), None)
+                .await
+        };
+
+        let parent_manifests = match parent.and_then(|parent| parent.manifest_list.clone()) {
+            Some(manifest_list) => {
+                let bytes = io_client
+                    .single_url_get(manifest_list, None, None)
+                    .await?
+                    .bytes()
+                    .await?;
+                read_manifest_list(&bytes)?
+            }
+            None => Vec::new(),
+        };
+
+        let mut manifests = Vec::new();
+        let mut deleted = SummaryCounts::default();
+        if !self.data_files.is_empty() {
+            let uri = metadata_uri(format!("{attempt_id}-m0.avro"));
+            let (bytes, manifest) = write_added_manifest(
+                &uri,
+                &partition_type,
+                snapshot_id,
+                sequence_number,
+                &self.data_files,
+            )?;
+            put(uri, bytes).await?;
+            manifests.push(manifest);
+        }
+        match self.mode {
+            WriteMode::Append => manifests.extend(parent_manifests),
+            // Delete files only apply to the data files being deleted, so delete manifests are
+            // dropped along with them.
+            WriteMode::Overwrite => {
+                for (index, manifest) in parent_manifests
+                    .iter()
+                    .filter(|manifest| manifest.is_data())
+                    .enumerate()
+                {
+                    if manifest.added_files_count + manifest.existing_files_count == 0 {
+                        continue;
+                    }
+                    let bytes = io_client
+                        .single_url_get(manifest.manifest_path.clone(), None, None)
+                        .await?
+                        .bytes()
+                        .await?;
+                    let uri = metadata_uri(format!("{attempt_id}-m{}.avro", index + 1));
+                    if let Some((bytes, rewritten)) = write_deleted_manifest(
+                        &uri,
+                        manifest,
+                        &bytes,
+                        snapshot_id,
+                        sequence_number,
+                        |_| true,
+                    )? {
+                        put(uri, bytes).await?;
+                        deleted.files += rewritten.deleted_files_count;
+                        deleted.records += rewritten.deleted_rows_count;
+                        manifests.push(rewritten);
+                    }
+                }
+            }
+            // Only the manifests that list replaced files are rewritten, and delete manifests are
+            // kept since they may apply to the files that are not replaced.
+            WriteMode::Replace => {
+                let mut remaining = self
+                    .replaced_paths
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<HashSet<_>>();
+                for (index, manifest) in parent_manifests.into_iter().enumerate() {
+                    let has_live_files =
+                        manifest.added_files_count + manifest.existing_files_count > 0;
+                    if remaining.is_empty() || !manifest.is_data() || !has_live_files {
+                        manifests.push(manifest);
+                        continue;
+                    }
+                    let bytes = io_client
+                        .single_url_get(manifest.manifest_path.clone(), None, None)
+                        .await?
+                        .bytes()
+                        .await?;
+                    let uri = metadata_uri(format!("{attempt_id}-m{}.avro", index + 1));
+                    match write_deleted_manifest(
+                        &uri,
+                        &manifest,
+                        &bytes,
+                        snapshot_id,
+                        sequence_number,
+                        |path| remaining.remove(path),
+                    )? {
+                        Some((bytes, rewritten)) => {
+                            put(uri, bytes).await?;
+                            deleted.files += rewritten.deleted_files_count;
+                            deleted.records += rewritten.deleted_rows_count;
+                            manifests.push(rewritten);
+                        }
+                        None => manifests.push(manifest),
+                    }
+                }
+                if !remaining.is_empty() {
+                    let mut paths = remaining.into_iter().map(String::from).collect::<Vec<_>>();
+                    paths.sort();
+                    return ReplacedFilesMissingSnafu { paths }.fail();
+                }
+            }
+        }
+
+        let manifest_list_uri = metadata_uri(format!("snap-{snapshot_id}-{attempt_id}.avro"));
+        let manifest_list = write_manifest_list(
+            &manifests,
+            snapshot_id,
+            parent.map(|parent| parent.snapshot_id),
+            sequence_number,
+        )?;
+        put(manifest_list_uri.clone(), manifest_list).await?;
+
+        Ok(Snapshot {
+            snapshot_id,
+            parent_snapshot_id: parent.map(|parent| parent.snapshot_id),
+            sequence_number: Some(sequence_number),
+            timestamp_ms: now,
+            manifest_list: Some(manifest_list_uri),
+            summary: self.summary(parent, &deleted),
+            schema_id: Some(partition_type.schema_id),
+        })
+    }
+
+    fn summary(
+        &self,
+        parent: Option<&Snapshot>,
+        deleted: &SummaryCounts,
+    ) -> HashMap<String, String> {
+        let added = SummaryCounts {
+            files: self.data_files.len() as i64,
+            records: self.data_files.iter().map(|file| file.record_count).sum(),
+        };
+        let added_size = self
+            .data_files
+            .iter()
+            .map(|file| file.file_size_in_bytes)
+            .sum::<i64>();
+        let parent_total = |key: &str| {
+            parent
+                .and_then(|parent| parent.summary.get(key))
+                .and_then(|total| total.parse::<i64>().ok())
+        };
+
+        let mut summary = HashMap::from([
+            ("operation".to_string(), self.mode.operation().to_string()),
+            ("added-data-files".to_string(), added.files.to_string()),
+            ("added-records".to_string(), added.records.to_string()),
+            ("added-files-size".to_string(), added_size.to_string()),
+        ]);
+        if self.mode != WriteMode::Append {
+            summary.insert("deleted-data-files".to_string(), deleted.files.to_string());
+            summary.insert("deleted-records".to_string(), deleted.records.to_string());
+        }
+        if self.mode == WriteMode::Overwrite {
+            summary.insert("total-data-files".to_string(), added.files.to_string());
+            summary.insert("total-records".to_string(), added.records.to_string());
+            summary.insert("total-files-size".to_string(), added_size.to_string());
+            summary.insert("total-delete-files".to_string(), "0".to_string());
+        } else {
+            // Totals are only tracked while they can be derived from those of the parent, and
+            // the sizes of replaced files are unknown.
+            let mut totals = vec![
+                ("total-data-files", added.files - deleted.files),
+                ("total-records", added.records - deleted.records),
+            ];
+            if self.mode == WriteMode::Append {
+                totals.push(("total-files-size", added_size));
+            }
+            for (key, change) in totals {
+                let parent_total = if parent.is_some() {
+                    parent_total(key)
+                } else {
+                    Some(0)
+                };
+                if let Some(parent_total) = parent_total {
+                    summary.insert(key.to_string(), (parent_total + change).to_string());
+                }
+            }
+        }
+        summary.extend(self.snapshot_properties.clone());
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(mode: WriteMode) -> IcebergWrite {
+        IcebergWrite {
+            mode,
+            data_files: vec![DataFile {
+                file_path: "a.parquet".to_string(),
+                file_format: "PARQUET".to_string(),
+                record_count: 10,
+                file_size_in_bytes: 100,
+                ..Default::default()
+            }],
+            snapshot_properties: BTreeMap::from([("job".to_string(), "1".to_string())]),
+            replaced_paths: vec![],
+        }
+    }
+
+    fn parent(summary: &[(&str, &str)]) -> Snapshot {
+        Snapshot {
+            snapshot_id: 1,
+            parent_snapshot_id: None,
+            sequence_number: Some(1),
+            timestamp_ms: 0,
+            manifest_list: None,
+            summary: summary
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            schema_id: Some(0),
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let append = write(WriteMode::Append);
+        let summary = append.summary(None, &SummaryCounts::default());
+        assert_eq!(summary["operation"], "append");
+        assert_eq!(summary["total-records"], "10");
+        assert_eq!(summary["job"], "1");
+
+        let parent_with_totals = parent(&[("total-records", "5"), ("total-data-files", "1")]);
+        let summary = append.summary(Some(&parent_with_totals), &SummaryCounts::default());
+        assert_eq!(summary["total-records"], "15");
+        assert_eq!(summary["total-data-files"], "2");
+        assert!(!summary.contains_key("total-files-size"));
+
+        let overwrite = write(WriteMode::Overwrite);
+        let deleted = SummaryCounts {
+            files: 1,
+            records: 5,
+        };
+        let summary = overwrite.summary(Some(&parent_with_totals), &deleted);
+        assert_eq!(summary["operation"], "overwrite");
+        assert_eq!(summary["deleted-records"], "5");
+        assert_eq!(summary["total-records"], "10");
+
+        let replace = write(WriteMode::Replace);
+        let summary = replace.summary(Some(&parent_with_totals), &deleted);
+        assert_eq!(summary["operation"], "replace");
+        assert_eq!(summary["deleted-data-files"], "1");
+        assert_eq!(summary["total-records"], "10");
+        assert_eq!(summary["total-data-files"], "1");
+    }
+}
@@ -0,0 +1,114 @@
+//! Scans of and commits of data files to Iceberg tables.
+//!
+//! Each snapshot of an Iceberg table points at a manifest list, an Avro file listing the
+//! manifests of the snapshot, which in turn are Avro files listing data files. A write describes
+//! its data files with an [`IcebergWrite`], which writes a manifest of the added files, along with
+//! manifests marking existing files as deleted if it overwrites the table, and a manifest list of
+//! them and the manifests of the current snapshot. [`IcebergCatalog::commit`] then swaps the
+//! current snapshot of the table for the new one, retrying on top of concurrent commits.
+//!
+//! Only tables of format version 2 can be written to.
+//!
+//! Tables of an Iceberg REST catalog are scanned through the [`IcebergRestCatalog`], a
+//! [`daft_catalog::DataCatalog`] whose tables are read from the live data files in the manifests
+//! of their current snapshot.
+
+mod catalog;
+mod commit;
+mod manifest;
+mod partition;
+#[cfg(feature = "python")]
+pub mod python;
+mod rest_catalog;
+mod scan;
+
+pub use catalog::{IcebergCatalog, DEFAULT_MAX_COMMIT_ATTEMPTS};
+pub use commit::{IcebergWrite, WriteMode};
+use common_error::DaftError;
+pub use manifest::{DataFile, PartitionValue};
+pub use rest_catalog::{IcebergRestCatalog, IcebergRestTable};
+use snafu::Snafu;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Iceberg IO error: {}", source), context(false))]
+    Io { source: daft_io::Error },
+
+    #[snafu(display("Unable to read or write Iceberg manifest: {}", source))]
+    Avro { source: apache_avro::Error },
+
+    #[snafu(display("Unable to parse Iceberg table metadata {}: {}", path, source))]
+    InvalidMetadata {
+        path: String,
+        source: serde_json::Error,
+    },
+
+    #[snafu(display("Iceberg {} is invalid: {}", what, reason))]
+    Invalid { what: String, reason: String },
+
+    #[snafu(display(
+        "Writing to Iceberg tables of format version {} is not supported",
+        version
+    ))]
+    UnsupportedFormatVersion { version: u8 },
+
+    #[snafu(display("Unable to scan Iceberg table {}: {}", table, reason))]
+    UnsupportedScan { table: String, reason: String },
+
+    #[snafu(display("Unable to plan a scan of Iceberg table {}: {}", table, source))]
+    UnableToPlanScan { table: String, source: DaftError },
+
+    #[snafu(display("No Iceberg table found at {}", location))]
+    TableNotFound { location: String },
+
+    #[snafu(display("Iceberg REST catalog error: {}", source), context(false))]
+    Catalog {
+        source: daft_catalog_iceberg_rest_catalog::Error,
+    },
+
+    #[snafu(display(
+        "Iceberg table was changed concurrently from snapshot {:?} to {:?} while overwriting it",
+        expected,
+        found
+    ))]
+    ConcurrentOverwrite {
+        expected: Option<i64>,
+        found: Option<i64>,
+    },
+
+    #[snafu(display(
+        "Data files to replace are no longer part of the Iceberg table: {}",
+        paths.join(", ")
+    ))]
+    ReplacedFilesMissing { paths: Vec<String> },
+
+    #[snafu(display(
+        "Gave up committing to Iceberg table after {} attempts due to concurrent commits",
+        attempts
+    ))]
+    TooManyAttempts { attempts: usize },
+
+    #[snafu(display(
+        "Committing to the Iceberg table at {} is unsafe because its object store does not support conditional writes",
+        location
+    ))]
+    UnsafeCommit { location: String },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Io { source } => source.into(),
+            _ => Self::External(err.into()),
+        }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The current time in milliseconds since the epoch, which is how Iceberg stores timestamps.
+fn now_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64)
+}
@@ -0,0 +1,802 @@
+use std::collections::BTreeMap;
+
+use apache_avro::{types::Value as AvroValue, Codec, Reader, Schema, Writer};
+use serde_json::json;
+use snafu::ResultExt;
+
+use crate::{partition::PartitionType, AvroSnafu, Error, Result};
+
+/// Status of a manifest entry, see the manifest entry fields in the spec.
+const STATUS_EXISTING: i32 = 0;
+const STATUS_ADDED: i32 = 1;
+const STATUS_DELETED: i32 = 2;
+
+/// Content of data manifests and data files, as opposed to delete manifests and files.
+const CONTENT_DATA: i32 = 0;
+
+/// A value of a partition field. Dates are days, and times and timestamps are microseconds,
+/// since the epoch. Binary values also hold fixed, UUID and decimal values, the latter as the
+/// big-endian two's complement of their unscaled value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartitionValue {
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    Binary(Vec<u8>),
+}
+
+/// A data file written to a table. Statistics are keyed by the field id of their column, and
+/// bounds are the single-value serialization of the bound.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataFile {
+    pub file_path: String,
+    /// Format of the file, e.g. `PARQUET`.
+    pub file_format: String,
+    /// The partition values of the file, in the order of the fields of the partition spec.
+    pub partition: Vec<Option<PartitionValue>>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub column_sizes: BTreeMap<i32, i64>,
+    pub value_counts: BTreeMap<i32, i64>,
+    pub null_value_counts: BTreeMap<i32, i64>,
+    pub nan_value_counts: BTreeMap<i32, i64>,
+    pub lower_bounds: BTreeMap<i32, Vec<u8>>,
+    pub upper_bounds: BTreeMap<i32, Vec<u8>>,
+    pub split_offsets: Option<Vec<i64>>,
+}
+
+fn required(name: &str, avro_type: serde_json::Value, field_id: i64) -> serde_json::Value {
+    json!({"name": name, "type": avro_type, "field-id": field_id})
+}
+
+/// An optional field, which Avro represents as a union with null.
+fn optional(name: &str, avro_type: serde_json::Value, field_id: i64) -> serde_json::Value {
+    json!({"name": name, "type": ["null", avro_type], "default": null, "field-id": field_id})
+}
+
+/// The Avro schema of a map with int keys, which Iceberg stores as an array of key-value records.
+fn int_map(key_id: i64, value_id: i64, value_type: &str) -> serde_json::Value {
+    json!({
+        "type": "array",
+        "logicalType": "map",
+        "items": {
+            "type": "record",
+            "name": format!("k{key_id}_v{value_id}"),
+            "fields": [
+                {"name": "key", "type": "int", "field-id": key_id},
+                {"name": "value", "type": value_type, "field-id": value_id},
+            ],
+        },
+    })
+}
+
+/// The Avro schema of the entries of manifests of format version 2.
+fn manifest_entry_schema(partition_type: &PartitionType) -> Result<Schema> {
+    let data_file = json!({
+        "type": "record",
+        "name": "r2",
+        "fields": [
+            required("content", json!("int"), 134),
+            required("file_path", json!("string"), 100),
+            required("file_format", json!("string"), 101),
+            required("partition", partition_type.avro_schema(), 102),
+            required("record_count", json!("long"), 103),
+            required("file_size_in_bytes", json!("long"), 104),
+            optional("column_sizes", int_map(117, 118, "long"), 108),
+            optional("value_counts", int_map(119, 120, "long"), 109),
+            optional("null_value_counts", int_map(121, 122, "long"), 110),
+            optional("nan_value_counts", int_map(138, 139, "long"), 137),
+            optional("lower_bounds", int_map(126, 127, "bytes"), 125),
+            optional("upper_bounds", int_map(129, 130, "bytes"), 128),
+            optional("key_metadata", json!("bytes"), 131),
+            optional(
+                "split_offsets",
+                json!({"type": "array", "items": "long", "element-id": 133}),
+                132
+            ),
+            optional("sort_order_id", json!("int"), 140),
+        ],
+    });
+    let schema = json!({
+        "type": "record",
+        "name": "manifest_entry",
+        "fields": [
+            required("status", json!("int"), 0),
+            optional("snapshot_id", json!("long"), 1),
+            optional("sequence_number", json!("long"), 3),
+            optional("file_sequence_number", json!("long"), 4),
+            required("data_file", data_file, 2),
+        ],
+    });
+    Schema::parse(&schema).context(AvroSnafu)
+}
+
+/// The Avro schema of the manifest files in manifest lists of format version 2.
+fn manifest_file_schema() -> Result<Schema> {
+    let field_summary = json!({
+        "type": "record",
+        "name": "r508",
+        "fields": [
+            required("contains_null", json!("boolean"), 509),
+            optional("contains_nan", json!("boolean"), 518),
+            optional("lower_bound", json!("bytes"), 510),
+            optional("upper_bound", json!("bytes"), 511),
+        ],
+    });
+    let schema = json!({
+        "type": "record",
+        "name": "manifest_file",
+        "fields": [
+            required("manifest_path", json!("string"), 500),
+            required("manifest_length", json!("long"), 501),
+            required("partition_spec_id", json!("int"), 502),
+            required("content", json!("int"), 517),
+            required("sequence_number", json!("long"), 515),
+            required("min_sequence_number", json!("long"), 516),
+            required("added_snapshot_id", json!("long"), 503),
+            required("added_files_count", json!("int"), 504),
+            required("existing_files_count", json!("int"), 505),
+            required("deleted_files_count", json!("int"), 506),
+            required("added_rows_count", json!("long"), 512),
+            required("existing_rows_count", json!("long"), 513),
+            required("deleted_rows_count", json!("long"), 514),
+            optional(
+                "partitions",
+                json!({"type": "array", "items": field_summary, "element-id": 508}),
+                507
+            ),
+            optional("key_metadata", json!("bytes"), 519),
+        ],
+    });
+    Schema::parse(&schema).context(AvroSnafu)
+}
+
+fn some(value: AvroValue) -> AvroValue {
+    AvroValue::Union(1, Box::new(value))
+}
+
+fn none() -> AvroValue {
+    AvroValue::Union(0, Box::new(AvroValue::Null))
+}
+
+fn int_map_value<V>(map: &BTreeMap<i32, V>, to_value: impl Fn(&V) -> AvroValue) -> AvroValue {
+    some(AvroValue::Array(
+        map.iter()
+            .map(|(key, value)| {
+                AvroValue::Record(vec![
+                    ("key".to_string(), AvroValue::Int(*key)),
+                    ("value".to_string(), to_value(value)),
+                ])
+            })
+            .collect(),
+    ))
+}
+
+fn invalid_manifest(reason: impl Into<String>) -> Error {
+    Error::Invalid {
+        what: "manifest".to_string(),
+        reason: reason.into(),
+    }
+}
+
+/// Looks up a field of an Avro record, unwrapping it if it is an optional field.
+fn record_field<'a>(record: &'a AvroValue, name: &str) -> Option<&'a AvroValue> {
+    let AvroValue::Record(fields) = record else {
+        return None;
+    };
+    let value = &fields.iter().find(|(field, _)| field == name)?.1;
+    match value {
+        AvroValue::Union(_, value) if **value == AvroValue::Null => None,
+        AvroValue::Union(_, value) => Some(value),
+        AvroValue::Null => None,
+        value => Some(value),
+    }
+}
+
+fn int_field(record: &AvroValue, name: &str) -> Result<i64> {
+    match record_field(record, name) {
+        Some(AvroValue::Int(value)) => Ok((*value).into()),
+        Some(AvroValue::Long(value)) => Ok(*value),
+        value => Err(invalid_manifest(format!(
+            "expected an integer {name}, found {value:?}"
+        ))),
+    }
+}
+
+fn optional_int_field(record: &AvroValue, name: &str) -> Result<Option<i64>> {
+    match record_field(record, name) {
+        None => Ok(None),
+        Some(_) => int_field(record, name).map(Some),
+    }
+}
+
+/// Sets a field of an Avro record, wrapping it in the union of an optional field if the record
+/// already holds one.
+fn set_record_field(record: &mut AvroValue, name: &str, value: AvroValue) {
+    let AvroValue::Record(fields) = record else {
+        return;
+    };
+    if let Some((_, field)) = fields.iter_mut().find(|(field, _)| field == name) {
+        *field = match field {
+            AvroValue::Union(..) => some(value),
+            _ => value,
+        };
+    }
+}
+
+/// A manifest listed in the manifest list of a snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ManifestFile {
+    pub manifest_path: String,
+    pub manifest_length: i64,
+    pub partition_spec_id: i64,
+    /// Whether the manifest lists data files or delete files.
+    pub content: i64,
+    pub sequence_number: i64,
+    pub min_sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_files_count: i64,
+    pub existing_files_count: i64,
+    pub deleted_files_count: i64,
+    pub added_rows_count: i64,
+    pub existing_rows_count: i64,
+    pub deleted_rows_count: i64,
+    /// Summaries of the partition fields across the files of the manifest, kept as Avro values
+    /// since they are only carried over from earlier manifest lists.
+    pub partitions: Option<AvroValue>,
+    pub key_metadata: Option<AvroValue>,
+}
+
+impl ManifestFile {
+    pub fn is_data(&self) -> bool {
+        self.content == i64::from(CONTENT_DATA)
+    }
+
+    fn from_avro(record: &AvroValue) -> Result<Self> {
+        let manifest_path = match record_field(record, "manifest_path") {
+            Some(AvroValue::String(path)) => path.clone(),
+            path => return Err(invalid_manifest(format!("invalid manifest path {path:?}"))),
+        };
+        // Manifest lists written by format version 1 lack sequence numbers, which are 0 then.
+        let sequence_number = optional_int_field(record, "sequence_number")?.unwrap_or(0);
+        Ok(Self {
+            manifest_path,
+            manifest_length: int_field(record, "manifest_length")?,
+            partition_spec_id: int_field(record, "partition_spec_id")?,
+            content: optional_int_field(record, "content")?.unwrap_or(0),
+            sequence_number,
+            min_sequence_number: optional_int_field(record, "min_sequence_number")?
+                .unwrap_or(sequence_number),
+            added_snapshot_id: int_field(record, "added_snapshot_id")?,
+            added_files_count: optional_int_field(record, "added_files_count")?
+                .or(optional_int_field(record, "added_data_files_count")?)
+                .unwrap_or(0),
+            existing_files_count: optional_int_field(record, "existing_files_count")?
+                .or(optional_int_field(record, "existing_data_files_count")?)
+                .unwrap_or(0),
+            deleted_files_count: optional_int_field(record, "deleted_files_count")?
+                .or(optional_int_field(record, "deleted_data_files_count")?)
+                .unwrap_or(0),
+            added_rows_count: optional_int_field(record, "added_rows_count")?.unwrap_or(0),
+            existing_rows_count: optional_int_field(record, "existing_rows_count")?.unwrap_or(0),
+            deleted_rows_count: optional_int_field(record, "deleted_rows_count")?.unwrap_or(0),
+            partitions: record_field(record, "partitions").cloned(),
+            key_metadata: record_field(record, "key_metadata").cloned(),
+        })
+    }
+
+    fn to_avro(&self) -> AvroValue {
+        let int = |value: i64| AvroValue::Int(value as i32);
+        AvroValue::Record(vec![
+            (
+                "manifest_path".to_string(),
+                AvroValue::String(self.manifest_path.clone()),
+            ),
+            (
+                "manifest_length".to_string(),
+                AvroValue::Long(self.manifest_length),
+            ),
+            ("partition_spec_id".to_string(), int(self.partition_spec_id)),
+            ("content".to_string(), int(self.content)),
+            (
+                "sequence_number".to_string(),
+                AvroValue::Long(self.sequence_number),
+            ),
+            (
+                "min_sequence_number".to_string(),
+                AvroValue::Long(self.min_sequence_number),
+            ),
+            (
+                "added_snapshot_id".to_string(),
+                AvroValue::Long(self.added_snapshot_id),
+            ),
+            ("added_files_count".to_string(), int(self.added_files_count)),
+            (
+                "existing_files_count".to_string(),
+                int(self.existing_files_count),
+            ),
+            (
+                "deleted_files_count".to_string(),
+                int(self.deleted_files_count),
+            ),
+            (
+                "added_rows_count".to_string(),
+                AvroValue::Long(self.added_rows_count),
+            ),
+            (
+                "existing_rows_count".to_string(),
+                AvroValue::Long(self.existing_rows_count),
+            ),
+            (
+                "deleted_rows_count".to_string(),
+                AvroValue::Long(self.deleted_rows_count),
+            ),
+            (
+                "partitions".to_string(),
+                self.partitions.clone().map_or_else(none, some),
+            ),
+            (
+                "key_metadata".to_string(),
+                self.key_metadata.clone().map_or_else(none, some),
+            ),
+        ])
+    }
+}
+
+/// Writes a manifest of data files added by snapshot `snapshot_id`, which is to be written to
+/// `manifest_path`. The manifest entries inherit their sequence number from the manifest list.
+pub(crate) fn write_added_manifest(
+    manifest_path: &str,
+    partition_type: &PartitionType,
+    snapshot_id: i64,
+    sequence_number: i64,
+    data_files: &[DataFile],
+) -> Result<(Vec<u8>, ManifestFile)> {
+    let schema = manifest_entry_schema(partition_type)?;
+    let mut writer = manifest_writer(&schema, partition_type)?;
+    for data_file in data_files {
+        let longs = |value: &i64| AvroValue::Long(*value);
+        let bytes = |value: &Vec<u8>| AvroValue::Bytes(value.clone());
+        let data_file_value = AvroValue::Record(vec![
+            ("content".to_string(), AvroValue::Int(CONTENT_DATA)),
+            (
+                "file_path".to_string(),
+                AvroValue::String(data_file.file_path.clone()),
+            ),
+            (
+                "file_format".to_string(),
+                AvroValue::String(data_file.file_format.clone()),
+            ),
+            (
+                "partition".to_string(),
+                partition_type.avro_value(&data_file.file_path, &data_file.partition)?,
+            ),
+            (
+                "record_count".to_string(),
+                AvroValue::Long(data_file.record_count),
+            ),
+            (
+                "file_size_in_bytes".to_string(),
+                AvroValue::Long(data_file.file_size_in_bytes),
+            ),
+            (
+                "column_sizes".to_string(),
+                int_map_value(&data_file.column_sizes, longs),
+            ),
+            (
+                "value_counts".to_string(),
+                int_map_value(&data_file.value_counts, longs),
+            ),
+            (
+                "null_value_counts".to_string(),
+                int_map_value(&data_file.null_value_counts, longs),
+            ),
+            (
+                "nan_value_counts".to_string(),
+                int_map_value(&data_file.nan_value_counts, longs),
+            ),
+            (
+                "lower_bounds".to_string(),
+                int_map_value(&data_file.lower_bounds, bytes),
+            ),
+            (
+                "upper_bounds".to_string(),
+                int_map_value(&data_file.upper_bounds, bytes),
+            ),
+            ("key_metadata".to_string(), none()),
+            (
+                "split_offsets".to_string(),
+                data_file
+                    .split_offsets
+                    .as_ref()
+                    .map_or_else(none, |offsets| {
+                        some(AvroValue::Array(
+                            offsets.iter().copied().map(AvroValue::Long).collect(),
+                        ))
+                    }),
+            ),
+            ("sort_order_id".to_string(), none()),
+        ]);
+        writer
+            .append(AvroValue::Record(vec![
+                ("status".to_string(), AvroValue::Int(STATUS_ADDED)),
+                (
+                    "snapshot_id".to_string(),
+                    some(AvroValue::Long(snapshot_id)),
+                ),
+                ("sequence_number".to_string(), none()),
+                ("file_sequence_number".to_string(), none()),
+                ("data_file".to_string(), data_file_value),
+            ]))
+            .context(AvroSnafu)?;
+    }
+    let bytes = writer.into_inner().context(AvroSnafu)?;
+    let manifest = ManifestFile {
+        manifest_path: manifest_path.to_string(),
+        manifest_length: bytes.len() as i64,
+        partition_spec_id: partition_type.spec_id,
+        content: i64::from(CONTENT_DATA),
+        sequence_number,
+        min_sequence_number: sequence_number,
+        added_snapshot_id: snapshot_id,
+        added_files_count: data_files.len() as i64,
+        existing_files_count: 0,
+        deleted_files_count: 0,
+        added_rows_count: data_files.iter().map(|file| file.record_count).sum(),
+        existing_rows_count: 0,
+        deleted_rows_count: 0,
+        partitions: None,
+        key_metadata: None,
+    };
+    Ok((bytes, manifest))
+}
+
+fn manifest_writer<'a>(
+    schema: &'a Schema,
+    partition_type: &PartitionType,
+) -> Result<Writer<'a, Vec<u8>>> {
+    let mut writer = Writer::with_codec(schema, Vec::new(), Codec::Deflate);
+    let metadata = [
+        ("schema", partition_type.schema.to_string()),
+        ("schema-id", partition_type.schema_id.to_string()),
+        ("partition-spec", partition_type.spec_fields.to_string()),
+        ("partition-spec-id", partition_type.spec_id.to_string()),
+        ("format-version", "2".to_string()),
+        ("content", "data".to_string()),
+    ];
+    for (key, value) in metadata {
+        writer
+            .add_user_metadata(key.to_string(), value)
+            .context(AvroSnafu)?;
+    }
+    Ok(writer)
+}
+
+/// Rewrites the live entries of a data manifest for snapshot `snapshot_id`, marking the data
+/// files whose path `delete` returns true for as deleted and keeping the others as existing, and
+/// dropping the entries that were already deleted. The rewritten manifest keeps the schema and
+/// metadata of the original one, and is to be written to `manifest_path`.
+///
+/// Returns `None` if no live entry is deleted, in which case the manifest is unchanged.
+pub(crate) fn write_deleted_manifest(
+    manifest_path: &str,
+    manifest: &ManifestFile,
+    manifest_bytes: &[u8],
+    snapshot_id: i64,
+    sequence_number: i64,
+    mut delete: impl FnMut(&str) -> bool,
+) -> Result<Option<(Vec<u8>, ManifestFile)>> {
+    let reader = Reader::new(manifest_bytes).context(AvroSnafu)?;
+    let schema = reader.writer_schema().clone();
+    let user_metadata = reader.user_metadata().clone();
+    let mut writer = Writer::with_codec(&schema, Vec::new(), Codec::Deflate);
+    for (key, value) in user_metadata {
+        writer.add_user_metadata(key, value).context(AvroSnafu)?;
+    }
+
+    let mut deleted_files = 0;
+    let mut deleted_rows = 0;
+    let mut existing_files = 0;
+    let mut existing_rows = 0;
+    let mut min_sequence_number = None::<i64>;
+    for entry in reader {
+        let mut entry = entry.context(AvroSnafu)?;
+        if int_field(&entry, "status")? == i64::from(STATUS_DELETED) {
+            continue;
+        }
+        // Entries without sequence numbers inherit those of their manifest, which the rewritten
+        // manifest does not share, so they are written out explicitly.
+        let data_sequence_number =
+            optional_int_field(&entry, "sequence_number")?.unwrap_or(manifest.sequence_number);
+        let file_sequence_number =
+            optional_int_field(&entry, "file_sequence_number")?.unwrap_or(manifest.sequence_number);
+        let data_file = record_field(&entry, "data_file");
+        let record_count = data_file
+            .map(|data_file| int_field(data_file, "record_count"))
+            .transpose()?
+            .unwrap_or(0);
+        let is_deleted = match data_file.and_then(|data_file| record_field(data_file, "file_path"))
+        {
+            Some(AvroValue::String(path)) => delete(path),
+            path => return Err(invalid_manifest(format!("invalid data file path {path:?}"))),
+        };
+
+        if is_deleted {
+            set_record_field(&mut entry, "status", AvroValue::Int(STATUS_DELETED));
+            set_record_field(&mut entry, "snapshot_id", AvroValue::Long(snapshot_id));
+            deleted_files += 1;
+            deleted_rows += record_count;
+        } else {
+            set_record_field(&mut entry, "status", AvroValue::Int(STATUS_EXISTING));
+            existing_files += 1;
+            existing_rows += record_count;
+        }
+        set_record_field(
+            &mut entry,
+            "sequence_number",
+            AvroValue::Long(data_sequence_number),
+        );
+        set_record_field(
+            &mut entry,
+            "file_sequence_number",
+            AvroValue::Long(file_sequence_number),
+        );
+        writer.append(entry).context(AvroSnafu)?;
+
+        min_sequence_number = Some(
+            min_sequence_number.map_or(data_sequence_number, |min| min.min(data_sequence_number)),
+        );
+    }
+    let Some(min_sequence_number) = min_sequence_number.filter(|_| deleted_files > 0) else {
+        return Ok(None);
+    };
+    let bytes = writer.into_inner().context(AvroSnafu)?;
+    let deleted = ManifestFile {
+        manifest_path: manifest_path.to_string(),
+        manifest_length: bytes.len() as i64,
+        sequence_number,
+        min_sequence_number,
+        added_snapshot_id: snapshot_id,
+        added_files_count: 0,
+        existing_files_count: existing_files,
+        deleted_files_count: deleted_files,
+        added_rows_count: 0,
+        existing_rows_count: existing_rows,
+        deleted_rows_count: deleted_rows,
+        ..manifest.clone()
+    };
+    Ok(Some((bytes, deleted)))
+}
+
+/// Reads the manifests listed in a manifest list.
+pub(crate) fn read_manifest_list(bytes: &[u8]) -> Result<Vec<ManifestFile>> {
+    Reader::new(bytes)
+        .context(AvroSnafu)?
+        .map(|record| ManifestFile::from_avro(&record.context(AvroSnafu)?))
+        .collect()
+}
+
+/// Reads the paths and formats of the live files listed in a manifest, which are delete files
+/// rather than data files if it is a delete manifest.
+pub(crate) fn read_live_files(manifest_bytes: &[u8]) -> Result<Vec<(String, String)>> {
+    let mut files = Vec::new();
+    for entry in Reader::new(manifest_bytes).context(AvroSnafu)? {
+        let entry = entry.context(AvroSnafu)?;
+        if int_field(&entry, "status")? == i64::from(STATUS_DELETED) {
+            continue;
+        }
+        let data_file = record_field(&entry, "data_file");
+        match (
+            data_file.and_then(|data_file| record_field(data_file, "file_path")),
+            data_file.and_then(|data_file| record_field(data_file, "file_format")),
+        ) {
+            (Some(AvroValue::String(path)), Some(AvroValue::String(format))) => {
+                files.push((path.clone(), format.clone()));
+            }
+            _ => return Err(invalid_manifest(format!("invalid data file {data_file:?}"))),
+        }
+    }
+    Ok(files)
+}
+
+/// Writes the manifest list of snapshot `snapshot_id`.
+pub(crate) fn write_manifest_list(
+    manifests: &[ManifestFile],
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    sequence_number: i64,
+) -> Result<Vec<u8>> {
+    let schema = manifest_file_schema()?;
+    let mut writer = Writer::with_codec(&schema, Vec::new(), Codec::Deflate);
+    let metadata = [
+        ("snapshot-id", Some(snapshot_id.to_string())),
+        (
+            "parent-snapshot-id",
+            parent_snapshot_id.map(|id| id.to_string()),
+        ),
+        ("sequence-number", Some(sequence_number.to_string())),
+        ("format-version", Some("2".to_string())),
+    ];
+    for (key, value) in metadata {
+        writer
+            .add_user_metadata(key.to_string(), value.unwrap_or_else(|| "null".to_string()))
+            .context(AvroSnafu)?;
+    }
+    for manifest in manifests {
+        writer.append(manifest.to_avro()).context(AvroSnafu)?;
+    }
+    writer.into_inner().context(AvroSnafu)
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_catalog_iceberg_rest_catalog::metadata::TableMetadata;
+
+    use super::*;
+
+    fn partition_type() -> PartitionType {
+        let metadata: TableMetadata = serde_json::from_value(json!({
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": "file:///tmp/tbl",
+            "current-schema-id": 0,
+            "schemas": [{"type": "struct", "schema-id": 0, "fields": [
+                {"id": 1, "name": "id", "required": false, "type": "long"},
+                {"id": 2, "name": "city", "required": false, "type": "string"}
+            ]}],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": [
+                {"name": "city", "transform": "identity", "source-id": 2, "field-id": 1000}
+            ]}]
+        }))
+        .unwrap();
+        PartitionType::try_new(&metadata).unwrap()
+    }
+
+    fn data_file(path: &str, city: Option<&str>) -> DataFile {
+        DataFile {
+            file_path: path.to_string(),
+            file_format: "PARQUET".to_string(),
+            partition: vec![city.map(|city| PartitionValue::String(city.to_string()))],
+            record_count: 10,
+            file_size_in_bytes: 100,
+            value_counts: BTreeMap::from([(1, 10), (2, 10)]),
+            lower_bounds: BTreeMap::from([(1, 0i64.to_le_bytes().to_vec())]),
+            ..Default::default()
+        }
+    }
+
+    fn statuses(bytes: &[u8]) -> Vec<(i64, Option<i64>, String)> {
+        Reader::new(bytes)
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                let data_file = record_field(&entry, "data_file").unwrap();
+                let Some(AvroValue::String(path)) = record_field(data_file, "file_path") else {
+                    panic!("expected a file path");
+                };
+                (
+                    int_field(&entry, "status").unwrap(),
+                    optional_int_field(&entry, "sequence_number").unwrap(),
+                    path.clone(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_write_and_delete_manifest() {
+        let partition_type = partition_type();
+        let files = [
+            data_file("a.parquet", Some("Paris")),
+            data_file("b.parquet", None),
+        ];
+        let (bytes, manifest) =
+            write_added_manifest("m0.avro", &partition_type, 1, 1, &files).unwrap();
+        assert_eq!(manifest.added_files_count, 2);
+        assert_eq!(manifest.added_rows_count, 20);
+        assert_eq!(
+            statuses(&bytes),
+            vec![
+                (1, None, "a.parquet".to_string()),
+                (1, None, "b.parquet".to_string())
+            ]
+        );
+        let reader = Reader::new(&bytes[..]).unwrap();
+        assert_eq!(reader.user_metadata()["partition-spec-id"], b"0");
+
+        let (deleted_bytes, deleted) =
+            write_deleted_manifest("m1.avro", &manifest, &bytes, 2, 2, |_| true)
+                .unwrap()
+                .unwrap();
+        assert_eq!(deleted.deleted_files_count, 2);
+        assert_eq!(deleted.deleted_rows_count, 20);
+        assert_eq!(deleted.sequence_number, 2);
+        assert_eq!(deleted.min_sequence_number, 1);
+        assert_eq!(
+            statuses(&deleted_bytes),
+            vec![
+                (2, Some(1), "a.parquet".to_string()),
+                (2, Some(1), "b.parquet".to_string())
+            ]
+        );
+
+        // Deleted entries are dropped when the manifest is rewritten again.
+        assert!(
+            write_deleted_manifest("m2.avro", &deleted, &deleted_bytes, 3, 3, |_| true)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_delete_some_files_of_manifest() {
+        let files = [
+            data_file("a.parquet", Some("Paris")),
+            data_file("b.parquet", None),
+        ];
+        let (bytes, manifest) =
+            write_added_manifest("m0.avro", &partition_type(), 1, 1, &files).unwrap();
+        let (rewritten_bytes, rewritten) =
+            write_deleted_manifest("m1.avro", &manifest, &bytes, 2, 2, |path| {
+                path == "a.parquet"
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(rewritten.deleted_files_count, 1);
+        assert_eq!(rewritten.existing_files_count, 1);
+        assert_eq!(rewritten.existing_rows_count, 10);
+        assert_eq!(
+            statuses(&rewritten_bytes),
+            vec![
+                (2, Some(1), "a.parquet".to_string()),
+                (0, Some(1), "b.parquet".to_string())
+            ]
+        );
+
+        // Manifests without any deleted files are kept as they are.
+        assert!(
+            write_deleted_manifest("m2.avro", &manifest, &bytes, 2, 2, |_| false)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_read_live_files() {
+        let files = [
+            data_file("a.parquet", Some("Paris")),
+            data_file("b.parquet", None),
+        ];
+        let (bytes, manifest) =
+            write_added_manifest("m0.avro", &partition_type(), 1, 1, &files).unwrap();
+        assert_eq!(
+            read_live_files(&bytes).unwrap(),
+            vec![
+                ("a.parquet".to_string(), "PARQUET".to_string()),
+                ("b.parquet".to_string(), "PARQUET".to_string())
+            ]
+        );
+
+        let (rewritten_bytes, _) =
+            write_deleted_manifest("m1.avro", &manifest, &bytes, 2, 2, |path| {
+                path == "a.parquet"
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            read_live_files(&rewritten_bytes).unwrap(),
+            vec![("b.parquet".to_string(), "PARQUET".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_manifest_list_roundtrip() {
+        let (_, manifest) = write_added_manifest("m0.avro", &partition_type(), 1, 1, &[]).unwrap();
+        let bytes = write_manifest_list(&[manifest.clone()], 1, None, 1).unwrap();
+        assert_eq!(read_manifest_list(&bytes).unwrap(), vec![manifest]);
+    }
+}
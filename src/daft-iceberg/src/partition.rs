@@ -0,0 +1,411 @@
+use apache_avro::{types::Value as AvroValue, Decimal};
+use daft_catalog_iceberg_rest_catalog::metadata::TableMetadata;
+use serde_json::{json, Value};
+
+use crate::{manifest::PartitionValue, Error, Result};
+
+fn invalid(what: &str, reason: impl Into<String>) -> Error {
+    Error::Invalid {
+        what: what.to_string(),
+        reason: reason.into(),
+    }
+}
+
+/// The primitive types of Iceberg, which partition values are made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrimitiveType {
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Date,
+    Time,
+    Timestamp,
+    Timestamptz,
+    TimestampNs,
+    TimestamptzNs,
+    String,
+    Uuid,
+    Fixed(usize),
+    Binary,
+    Decimal { precision: u32, scale: u32 },
+}
+
+impl PrimitiveType {
+    /// Parses a primitive type from its name in the JSON of an Iceberg schema.
+    fn parse(name: &str) -> Option<Self> {
+        let parse_params = |prefix: &str, suffix: &str| {
+            name.strip_prefix(prefix)?
+                .strip_suffix(suffix)
+                .map(|params| params.split(',').map(str::trim).collect::<Vec<_>>())
+        };
+        Some(match name {
+            "boolean" => Self::Boolean,
+            "int" => Self::Int,
+            "long" => Self::Long,
+            "float" => Self::Float,
+            "double" => Self::Double,
+            "date" => Self::Date,
+            "time" => Self::Time,
+            "timestamp" => Self::Timestamp,
+            "timestamptz" => Self::Timestamptz,
+            "timestamp_ns" => Self::TimestampNs,
+            "timestamptz_ns" => Self::TimestamptzNs,
+            "string" => Self::String,
+            "uuid" => Self::Uuid,
+            "binary" => Self::Binary,
+            _ => {
+                if let Some([length]) = parse_params("fixed[", "]").as_deref() {
+                    Self::Fixed(length.parse().ok()?)
+                } else if let Some([precision, scale]) = parse_params("decimal(", ")").as_deref() {
+                    Self::Decimal {
+                        precision: precision.parse().ok()?,
+                        scale: scale.parse().ok()?,
+                    }
+                } else {
+                    return None;
+                }
+            }
+        })
+    }
+
+    /// The Avro schema of values of this type, named after `field_id` where Avro requires a name.
+    fn avro_schema(self, field_id: i64) -> Value {
+        let name = format!("fixed_{field_id}");
+        match self {
+            Self::Boolean => json!("boolean"),
+            Self::Int => json!("int"),
+            Self::Long | Self::TimestampNs | Self::TimestamptzNs => json!("long"),
+            Self::Float => json!("float"),
+            Self::Double => json!("double"),
+            Self::Date => json!({"type": "int", "logicalType": "date"}),
+            Self::Time => json!({"type": "long", "logicalType": "time-micros"}),
+            Self::Timestamp => json!({
+                "type": "long",
+                "logicalType": "timestamp-micros",
+                "adjust-to-utc": false,
+            }),
+            Self::Timestamptz => json!({
+                "type": "long",
+                "logicalType": "timestamp-micros",
+                "adjust-to-utc": true,
+            }),
+            Self::String => json!("string"),
+            Self::Uuid => json!({"type": "fixed", "name": name, "size": 16}),
+            Self::Fixed(size) => json!({"type": "fixed", "name": name, "size": size}),
+            Self::Binary => json!("bytes"),
+            Self::Decimal { precision, scale } => json!({
+                "type": "fixed",
+                "name": name,
+                "size": decimal_size(precision),
+                "logicalType": "decimal",
+                "precision": precision,
+                "scale": scale,
+            }),
+        }
+    }
+
+    /// Converts a partition value to the Avro value of this type.
+    fn avro_value(self, value: &PartitionValue) -> Option<AvroValue> {
+        Some(match (self, value) {
+            (Self::Boolean, PartitionValue::Boolean(v)) => AvroValue::Boolean(*v),
+            (Self::Int, PartitionValue::Int(v)) => AvroValue::Int(*v),
+            (Self::Date, PartitionValue::Int(v)) => AvroValue::Date(*v),
+            (Self::Long | Self::TimestampNs | Self::TimestamptzNs, PartitionValue::Int(v)) => {
+                AvroValue::Long((*v).into())
+            }
+            (Self::Long | Self::TimestampNs | Self::TimestamptzNs, PartitionValue::Long(v)) => {
+                AvroValue::Long(*v)
+            }
+            (Self::Time, PartitionValue::Long(v)) => AvroValue::TimeMicros(*v),
+            (Self::Timestamp | Self::Timestamptz, PartitionValue::Long(v)) => {
+                AvroValue::TimestampMicros(*v)
+            }
+            (Self::Float, PartitionValue::Float(v)) => AvroValue::Float(*v),
+            // Values from Python are always doubles.
+            (Self::Float, PartitionValue::Double(v)) => AvroValue::Float(*v as f32),
+            (Self::Double, PartitionValue::Float(v)) => AvroValue::Double((*v).into()),
+            (Self::Double, PartitionValue::Double(v)) => AvroValue::Double(*v),
+            (Self::String, PartitionValue::String(v)) => AvroValue::String(v.clone()),
+            (Self::Binary, PartitionValue::Binary(v)) => AvroValue::Bytes(v.clone()),
+            (Self::Uuid, PartitionValue::Binary(v)) if v.len() == 16 => {
+                AvroValue::Fixed(16, v.clone())
+            }
+            (Self::Fixed(size), PartitionValue::Binary(v)) if v.len() == size => {
+                AvroValue::Fixed(size, v.clone())
+            }
+            (Self::Decimal { .. }, PartitionValue::Binary(v)) => {
+                AvroValue::Decimal(Decimal::from(v.clone()))
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// The number of bytes needed to store the unscaled values of decimals of `precision` digits.
+fn decimal_size(precision: u32) -> usize {
+    (1..)
+        .find(|bytes| ((8 * bytes - 1) as f64) * 2f64.log10() >= precision as f64)
+        .expect("some number of bytes holds any precision")
+}
+
+/// A field of the partition tuple of a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PartitionField {
+    pub name: String,
+    pub field_id: i64,
+    pub result_type: PrimitiveType,
+}
+
+/// The partitioning of the data files written to a table, as given by its default partition spec.
+#[derive(Debug, Clone)]
+pub(crate) struct PartitionType {
+    pub spec_id: i64,
+    /// The fields of the partition spec as they appear in the table metadata, which manifests
+    /// record along with the schema of the table.
+    pub spec_fields: Value,
+    pub schema: Value,
+    pub schema_id: i64,
+    pub fields: Vec<PartitionField>,
+}
+
+impl PartitionType {
+    /// Resolves the default partition spec of a table against its current schema.
+    pub fn try_new(metadata: &TableMetadata) -> Result<Self> {
+        let schema_id = metadata.current_schema_id.unwrap_or(0);
+        let schema = metadata
+            .schemas
+            .iter()
+            .find(|schema| schema["schema-id"].as_i64() == Some(schema_id))
+            .ok_or_else(|| invalid("table metadata", format!("no schema {schema_id}")))?
+            .clone();
+        let spec_id = metadata.default_spec_id.unwrap_or(0);
+        let spec = metadata
+            .partition_specs
+            .iter()
+            .find(|spec| spec["spec-id"].as_i64() == Some(spec_id))
+            .ok_or_else(|| invalid("table metadata", format!("no partition spec {spec_id}")))?;
+        let spec_fields = spec["fields"].clone();
+
+        let fields = spec_fields
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|field| {
+                let name = field["name"].as_str();
+                let field_id = field["field-id"].as_i64();
+                let source_id = field["source-id"].as_i64();
+                let transform = field["transform"].as_str();
+                let (Some(name), Some(field_id), Some(source_id), Some(transform)) =
+                    (name, field_id, source_id, transform)
+                else {
+                    return Err(invalid(
+                        "partition spec",
+                        format!("malformed field {field}"),
+                    ));
+                };
+                let source_type = find_field_type(&schema, source_id).ok_or_else(|| {
+                    invalid(
+                        "partition spec",
+                        format!("field {name} has no primitive source field {source_id}"),
+                    )
+                })?;
+                Ok(PartitionField {
+                    name: name.to_string(),
+                    field_id,
+                    result_type: transform_result_type(transform, source_type).ok_or_else(
+                        || {
+                            invalid(
+                                "partition spec",
+                                format!("field {name} has unsupported transform {transform}"),
+                            )
+                        },
+                    )?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            spec_id,
+            spec_fields,
+            schema,
+            schema_id,
+            fields,
+        })
+    }
+
+    /// The Avro schema of the `partition` record of data files in manifests.
+    pub fn avro_schema(&self) -> Value {
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                json!({
+                    "name": field.name,
+                    "type": ["null", field.result_type.avro_schema(field.field_id)],
+                    "default": null,
+                    "field-id": field.field_id,
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({"type": "record", "name": "r102", "fields": fields})
+    }
+
+    /// Converts the partition values of a data file, in the order of the partition fields, to the
+    /// Avro record of its partition.
+    pub fn avro_value(
+        &self,
+        file_path: &str,
+        values: &[Option<PartitionValue>],
+    ) -> Result<AvroValue> {
+        if values.len() != self.fields.len() {
+            return Err(invalid(
+                "data file",
+                format!(
+                    "{file_path} has {} partition values but the table has {} partition fields",
+                    values.len(),
+                    self.fields.len()
+                ),
+            ));
+        }
+        let fields = self
+            .fields
+            .iter()
+            .zip(values)
+            .map(|(field, value)| {
+                let value = match value {
+                    None => AvroValue::Union(0, Box::new(AvroValue::Null)),
+                    Some(value) => {
+                        let value = field.result_type.avro_value(value).ok_or_else(|| {
+                            invalid(
+                                "data file",
+                                format!(
+                                    "{file_path} has partition value {value:?} for field {} of type {:?}",
+                                    field.name, field.result_type
+                                ),
+                            )
+                        })?;
+                        AvroValue::Union(1, Box::new(value))
+                    }
+                };
+                Ok((field.name.clone(), value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(AvroValue::Record(fields))
+    }
+}
+
+/// Finds the type of the primitive field with id `field_id` in a struct of a schema, looking
+/// into nested structs but not into lists or maps, whose fields cannot be partitioned on.
+fn find_field_type(struct_type: &Value, field_id: i64) -> Option<PrimitiveType> {
+    struct_type["fields"]
+        .as_array()?
+        .iter()
+        .find_map(|field| match &field["type"] {
+            Value::String(name) if field["id"].as_i64() == Some(field_id) => {
+                PrimitiveType::parse(name)
+            }
+            nested @ Value::Object(_) if nested["type"] == "struct" => {
+                find_field_type(nested, field_id)
+            }
+            _ => None,
+        })
+}
+
+/// The type of the values that a partition transform produces from values of `source_type`.
+fn transform_result_type(transform: &str, source_type: PrimitiveType) -> Option<PrimitiveType> {
+    match transform {
+        "identity" | "void" => Some(source_type),
+        "year" | "month" | "hour" => Some(PrimitiveType::Int),
+        "day" => Some(PrimitiveType::Date),
+        _ if transform.starts_with("bucket[") => Some(PrimitiveType::Int),
+        _ if transform.starts_with("truncate[") => Some(source_type),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_primitive_types() {
+        assert_eq!(PrimitiveType::parse("long"), Some(PrimitiveType::Long));
+        assert_eq!(
+            PrimitiveType::parse("fixed[4]"),
+            Some(PrimitiveType::Fixed(4))
+        );
+        assert_eq!(
+            PrimitiveType::parse("decimal(9, 2)"),
+            Some(PrimitiveType::Decimal {
+                precision: 9,
+                scale: 2
+            })
+        );
+        assert_eq!(PrimitiveType::parse("list"), None);
+        assert_eq!(decimal_size(9), 4);
+        assert_eq!(decimal_size(38), 16);
+    }
+
+    #[test]
+    fn test_partition_type() {
+        let metadata: TableMetadata = serde_json::from_value(json!({
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": "file:///tmp/tbl",
+            "current-schema-id": 0,
+            "schemas": [{"type": "struct", "schema-id": 0, "fields": [
+                {"id": 1, "name": "ts", "required": false, "type": "timestamp"},
+                {"id": 2, "name": "location", "required": false, "type": {"type": "struct", "fields": [
+                    {"id": 3, "name": "city", "required": false, "type": "string"}
+                ]}}
+            ]}],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": [
+                {"name": "ts_day", "transform": "day", "source-id": 1, "field-id": 1000},
+                {"name": "city_bucket", "transform": "bucket[16]", "source-id": 3, "field-id": 1001},
+                {"name": "city", "transform": "identity", "source-id": 3, "field-id": 1002}
+            ]}]
+        }))
+        .unwrap();
+        let partition_type = PartitionType::try_new(&metadata).unwrap();
+        let result_types = partition_type
+            .fields
+            .iter()
+            .map(|field| field.result_type)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            result_types,
+            vec![
+                PrimitiveType::Date,
+                PrimitiveType::Int,
+                PrimitiveType::String
+            ]
+        );
+
+        let value = partition_type
+            .avro_value(
+                "a.parquet",
+                &[
+                    Some(PartitionValue::Int(19000)),
+                    None,
+                    Some(PartitionValue::String("Paris".to_string())),
+                ],
+            )
+            .unwrap();
+        let AvroValue::Record(fields) = value else {
+            panic!("expected a record");
+        };
+        assert_eq!(
+            fields[0].1,
+            AvroValue::Union(1, Box::new(AvroValue::Date(19000)))
+        );
+        assert_eq!(fields[1].1, AvroValue::Union(0, Box::new(AvroValue::Null)));
+
+        assert!(partition_type
+            .avro_value("a.parquet", &[None, None, Some(PartitionValue::Int(1))])
+            .is_err());
+        assert!(partition_type.avro_value("a.parquet", &[None]).is_err());
+    }
+}
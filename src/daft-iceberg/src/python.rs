@@ -0,0 +1,238 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_catalog_iceberg_rest_catalog::{
+    metadata::TableIdent, IcebergRestClient, IcebergRestConfig,
+};
+use daft_io::{get_io_client, python::IOConfig};
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyBool, PyBytes, PyFloat, PyInt, PyString},
+};
+
+use crate::{
+    DataFile, IcebergCatalog, IcebergRestCatalog, IcebergWrite, PartitionValue, WriteMode,
+    DEFAULT_MAX_COMMIT_ATTEMPTS,
+};
+
+/// A partition value of a data file, which Python passes as a plain bool, int, float, str or bytes.
+struct PyPartitionValue(PartitionValue);
+
+impl<'py> FromPyObject<'py> for PyPartitionValue {
+    fn extract_bound(item: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let value = if item.is_instance_of::<PyBool>() {
+            PartitionValue::Boolean(item.extract()?)
+        } else if let Ok(int) = item.downcast::<PyInt>() {
+            // Ints fit both int and long fields, which is all a Python int can tell apart.
+            match int.extract::<i32>() {
+                Ok(val) => PartitionValue::Int(val),
+                Err(_) => PartitionValue::Long(int.extract()?),
+            }
+        } else if let Ok(float) = item.downcast::<PyFloat>() {
+            PartitionValue::Double(float.value())
+        } else if let Ok(pystr) = item.downcast::<PyString>() {
+            PartitionValue::String(pystr.extract()?)
+        } else if let Ok(pybytes) = item.downcast::<PyBytes>() {
+            PartitionValue::Binary(pybytes.as_bytes().to_vec())
+        } else {
+            return Err(PyValueError::new_err(format!(
+                "Unsupported Iceberg partition value: {item}"
+            )));
+        };
+        Ok(Self(value))
+    }
+}
+
+/// A data file written to a table, as a dict with the fields of [`DataFile`].
+#[derive(FromPyObject)]
+struct PyDataFile {
+    #[pyo3(item)]
+    file_path: String,
+    #[pyo3(item)]
+    file_format: String,
+    #[pyo3(item)]
+    partition: Vec<Option<PyPartitionValue>>,
+    #[pyo3(item)]
+    record_count: i64,
+    #[pyo3(item)]
+    file_size_in_bytes: i64,
+    #[pyo3(item)]
+    column_sizes: Option<BTreeMap<i32, i64>>,
+    #[pyo3(item)]
+    value_counts: Option<BTreeMap<i32, i64>>,
+    #[pyo3(item)]
+    null_value_counts: Option<BTreeMap<i32, i64>>,
+    #[pyo3(item)]
+    nan_value_counts: Option<BTreeMap<i32, i64>>,
+    #[pyo3(item)]
+    lower_bounds: Option<BTreeMap<i32, Vec<u8>>>,
+    #[pyo3(item)]
+    upper_bounds: Option<BTreeMap<i32, Vec<u8>>>,
+    #[pyo3(item)]
+    split_offsets: Option<Vec<i64>>,
+}
+
+impl From<PyDataFile> for DataFile {
+    fn from(file: PyDataFile) -> Self {
+        Self {
+            file_path: file.file_path,
+            file_format: file.file_format,
+            partition: file
+                .partition
+                .into_iter()
+                .map(|value| value.map(|value| value.0))
+                .collect(),
+            record_count: file.record_count,
+            file_size_in_bytes: file.file_size_in_bytes,
+            column_sizes: file.column_sizes.unwrap_or_default(),
+            value_counts: file.value_counts.unwrap_or_default(),
+            null_value_counts: file.null_value_counts.unwrap_or_default(),
+            nan_value_counts: file.nan_value_counts.unwrap_or_default(),
+            lower_bounds: file.lower_bounds.unwrap_or_default(),
+            upper_bounds: file.upper_bounds.unwrap_or_default(),
+            split_offsets: file.split_offsets,
+        }
+    }
+}
+
+/// Commits data files written to an Iceberg table as a new snapshot, returning the id of the
+/// snapshot. Tables of a REST catalog are committed through the catalog at `rest_uri`, and other
+/// tables must be file-system tables whose metadata files are numbered versions under
+/// `table_location`. Each data file is a dict with the fields of an Iceberg data file, whose
+/// partition is the list of its partition values in the order of the fields of the partition spec.
+/// Replaces remove the data files at `replaced_paths` from the table.
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (
+    mode,
+    data_files,
+    table_location=None,
+    rest_uri=None,
+    rest_table=None,
+    rest_warehouse=None,
+    rest_token=None,
+    snapshot_properties=None,
+    replaced_paths=None,
+    max_attempts=DEFAULT_MAX_COMMIT_ATTEMPTS,
+    io_config=None
+))]
+pub fn iceberg_commit(
+    py: Python,
+    mode: &str,
+    data_files: Vec<PyDataFile>,
+    table_location: Option<String>,
+    rest_uri: Option<String>,
+    rest_table: Option<String>,
+    rest_warehouse: Option<String>,
+    rest_token: Option<String>,
+    snapshot_properties: Option<BTreeMap<String, String>>,
+    replaced_paths: Option<Vec<String>>,
+    max_attempts: usize,
+    io_config: Option<IOConfig>,
+) -> PyResult<i64> {
+    let mode = match mode {
+        "append" => WriteMode::Append,
+        "overwrite" => WriteMode::Overwrite,
+        "replace" => WriteMode::Replace,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Iceberg commits must append, overwrite or replace, found mode: {mode}"
+            )))
+        }
+    };
+    let write = IcebergWrite {
+        mode,
+        data_files: data_files.into_iter().map(DataFile::from).collect(),
+        snapshot_properties: snapshot_properties.unwrap_or_default(),
+        replaced_paths: replaced_paths.unwrap_or_default(),
+    };
+    let snapshot_id = py.allow_threads(|| -> DaftResult<_> {
+        let io_client = get_io_client(true, io_config.unwrap_or_default().config.into())?;
+        get_io_runtime(true).block_on_current_thread(async {
+            let catalog = match (rest_uri, rest_table, table_location) {
+                (Some(uri), Some(table), _) => {
+                    let table = TableIdent::parse(&table).ok_or_else(|| crate::Error::Invalid {
+                        what: "table identifier".to_string(),
+                        reason: format!("expected a namespace and table name, found: {table}"),
+                    })?;
+                    let config = IcebergRestConfig {
+                        uri,
+                        warehouse: rest_warehouse,
+                        token: rest_token,
+                    };
+                    let client = IcebergRestClient::try_new(config)
+                        .await
+                        .map_err(crate::Error::from)?;
+                    IcebergCatalog::Rest {
+                        client: Arc::new(client),
+                        table,
+                    }
+                }
+                (None, None, Some(table_location)) => IcebergCatalog::FileSystem { table_location },
+                _ => {
+                    return Err(crate::Error::Invalid {
+                        what: "commit".to_string(),
+                        reason: "a REST catalog uri and table, or a table location, is required"
+                            .to_string(),
+                    }
+                    .into())
+                }
+            };
+            let snapshot = catalog.commit(&io_client, &write, max_attempts).await?;
+            Ok::<_, DaftError>(snapshot.snapshot_id)
+        })
+    })?;
+    Ok(snapshot_id)
+}
+
+/// Registers an Iceberg REST catalog with the Daft catalog system
+///
+/// Args:
+///     uri (str): Base uri of the REST catalog, e.g. "http://localhost:8181".
+///     warehouse (Optional[str]): Warehouse to request from the catalog.
+///     token (Optional[str]): Bearer token to authenticate with the catalog.
+///     io_config (Optional[IOConfig]): IOConfig used to read the files of the tables.
+///     catalog_name (Optional[str]): The name to give the catalog. If None, it is registered as the default catalog.
+///
+/// Returns:
+///     str: The name of the registered catalog.
+///
+/// Example:
+///     >>> import daft
+///     >>> daft.catalog.register_iceberg_rest_catalog("http://localhost:8181", name="lake")
+///     'lake'
+#[pyfunction]
+#[pyo3(name = "register_iceberg_rest_catalog")]
+#[pyo3(signature = (uri, warehouse=None, token=None, catalog_name=None, io_config=None))]
+pub fn py_register_iceberg_rest_catalog(
+    py: Python,
+    uri: String,
+    warehouse: Option<String>,
+    token: Option<String>,
+    catalog_name: Option<&str>,
+    io_config: Option<IOConfig>,
+) -> PyResult<String> {
+    let config = IcebergRestConfig {
+        uri,
+        warehouse,
+        token,
+    };
+    let catalog = py.allow_threads(|| {
+        IcebergRestCatalog::try_new(config, io_config.unwrap_or_default().config)
+    })?;
+    daft_catalog::global_catalog::register_catalog(Arc::new(catalog), catalog_name);
+    Ok(catalog_name.unwrap_or("default").to_string())
+}
+
+pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
+    parent.add_function(wrap_pyfunction_bound!(iceberg_commit, parent)?)?;
+    Ok(())
+}
+
+/// Registers the functions of the `daft.daft.catalog` module
+pub fn register_catalog_modules(parent: &Bound<PyModule>) -> PyResult<()> {
+    parent.add_wrapped(wrap_pyfunction!(py_register_iceberg_rest_catalog))?;
+    Ok(())
+}
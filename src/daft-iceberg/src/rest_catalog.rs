@@ -0,0 +1,155 @@
+use std::{future::Future, sync::Arc};
+
+use common_io_config::IOConfig;
+use daft_catalog::{errors::Error as DaftCatalogError, DataCatalog, DataCatalogTable};
+use daft_catalog_iceberg_rest_catalog::{
+    metadata::{LoadTableResult, TableIdent, TableMetadata},
+    IcebergRestClient, IcebergRestConfig,
+};
+use daft_logical_plan::LogicalPlanBuilder;
+
+use crate::{scan::plan_scan, Error, Result};
+
+const CATALOG_TYPE: &str = "Iceberg REST";
+
+impl From<Error> for DaftCatalogError {
+    fn from(value: Error) -> Self {
+        Self::CatalogBackend {
+            catalog_type: CATALOG_TYPE.to_string(),
+            message: value.to_string(),
+        }
+    }
+}
+
+/// Runs a future to completion on the IO runtime
+fn block_on<T, F>(future: F) -> daft_catalog::errors::Result<T>
+where
+    F: Future<Output = Result<T>> + Send + 'static,
+    T: Send + 'static,
+{
+    common_runtime::get_io_runtime(true)
+        .block_on(future)
+        .map_err(|e| DaftCatalogError::CatalogBackend {
+            catalog_type: CATALOG_TYPE.to_string(),
+            message: e.to_string(),
+        })?
+        .map_err(DaftCatalogError::from)
+}
+
+/// A [`DataCatalog`] backed by an Iceberg REST catalog
+///
+/// Tables are identified by their dot-delimited namespace and name, e.g. `"db.schema.table"`.
+/// Their files are read with `io_config`.
+pub struct IcebergRestCatalog {
+    client: Arc<IcebergRestClient>,
+    io_config: IOConfig,
+}
+
+impl IcebergRestCatalog {
+    pub fn new(client: IcebergRestClient, io_config: IOConfig) -> Self {
+        Self {
+            client: Arc::new(client),
+            io_config,
+        }
+    }
+
+    /// Connects to the catalog at `config.uri`
+    pub fn try_new(
+        config: IcebergRestConfig,
+        io_config: IOConfig,
+    ) -> daft_catalog::errors::Result<Self> {
+        let client = block_on(async move { Ok(IcebergRestClient::try_new(config).await?) })?;
+        Ok(Self::new(client, io_config))
+    }
+
+    #[must_use]
+    pub fn client(&self) -> &Arc<IcebergRestClient> {
+        &self.client
+    }
+}
+
+impl DataCatalog for IcebergRestCatalog {
+    fn list_tables(&self, prefix: &str) -> daft_catalog::errors::Result<Vec<String>> {
+        let client = self.client.clone();
+        let mut tables = block_on(async move {
+            let mut tables = Vec::new();
+            // walk the namespace tree depth first, since namespaces may be nested
+            let mut namespaces = client.list_namespaces(None).await?;
+            while let Some(namespace) = namespaces.pop() {
+                namespaces.extend(client.list_namespaces(Some(&namespace)).await?);
+                tables.extend(
+                    client
+                        .list_tables(&namespace)
+                        .await?
+                        .iter()
+                        .map(ToString::to_string),
+                );
+            }
+            Ok(tables)
+        })?;
+        tables.retain(|name| name.starts_with(prefix));
+        tables.sort();
+        Ok(tables)
+    }
+
+    fn get_table(
+        &self,
+        name: &str,
+    ) -> daft_catalog::errors::Result<Option<Box<dyn DataCatalogTable>>> {
+        let Some(ident) = TableIdent::parse(name) else {
+            return Ok(None);
+        };
+
+        let client = self.client.clone();
+        let table_ident = ident.clone();
+        let result = block_on(async move {
+            match client.load_table(&table_ident).await {
+                Ok(result) => Ok(Some(result)),
+                Err(e) if e.is_not_found() => Ok(None),
+                Err(e) => Err(e.into()),
+            }
+        })?;
+
+        Ok(result.map(|result| {
+            Box::new(IcebergRestTable {
+                ident,
+                load_result: result,
+                io_config: self.io_config.clone(),
+            }) as Box<dyn DataCatalogTable>
+        }))
+    }
+}
+
+/// A table loaded from an Iceberg REST catalog
+pub struct IcebergRestTable {
+    ident: TableIdent,
+    load_result: LoadTableResult,
+    io_config: IOConfig,
+}
+
+impl IcebergRestTable {
+    #[must_use]
+    pub fn ident(&self) -> &TableIdent {
+        &self.ident
+    }
+
+    #[must_use]
+    pub fn metadata(&self) -> &TableMetadata {
+        &self.load_result.metadata
+    }
+
+    #[must_use]
+    pub fn metadata_location(&self) -> Option<&str> {
+        self.load_result.metadata_location.as_deref()
+    }
+}
+
+impl DataCatalogTable for IcebergRestTable {
+    /// Scans the live data files of the current snapshot of the table
+    fn to_logical_plan_builder(&self) -> daft_catalog::errors::Result<LogicalPlanBuilder> {
+        let ident = self.ident.clone();
+        let metadata = self.load_result.metadata.clone();
+        let io_config = self.io_config.clone();
+        block_on(async move { plan_scan(&ident, &metadata, io_config).await })
+    }
+}
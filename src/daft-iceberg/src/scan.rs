@@ -0,0 +1,289 @@
+//! Scans of the current snapshot of Iceberg tables.
+//!
+//! The snapshot points at a manifest list, an Avro file listing the manifests of the snapshot,
+//! which in turn are Avro files listing its data files. The live data files are read as Parquet,
+//! with the columns of the files mapped to those of the table schema by their field ids.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
+use common_io_config::IOConfig;
+use common_scan_info::ScanOperatorRef;
+use daft_catalog_iceberg_rest_catalog::metadata::{Snapshot, TableIdent, TableMetadata};
+use daft_io::{get_io_client, IOClient};
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_scan::{storage_config::StorageConfig, AnonymousScanOperator};
+use daft_schema::{dtype::DataType, field::Field, schema::Schema, time_unit::TimeUnit};
+use serde_json::Value;
+use snafu::ResultExt;
+
+use crate::{
+    manifest::{read_live_files, read_manifest_list},
+    Error, Result, UnableToPlanScanSnafu,
+};
+
+/// Plans a scan of the current snapshot of the table, which is empty if the table has none.
+pub(crate) async fn plan_scan(
+    table: &TableIdent,
+    metadata: &TableMetadata,
+    io_config: IOConfig,
+) -> Result<LogicalPlanBuilder> {
+    let unsupported = |reason: String| Error::UnsupportedScan {
+        table: table.to_string(),
+        reason,
+    };
+    let schema = metadata
+        .current_schema()
+        .ok_or_else(|| unsupported("the table metadata has no current schema".to_string()))?;
+    let mut field_id_mapping = BTreeMap::new();
+    let fields = struct_fields(schema, &mut field_id_mapping).map_err(unsupported)?;
+    let schema = Schema::new(fields).context(UnableToPlanScanSnafu {
+        table: table.to_string(),
+    })?;
+
+    let files = match metadata.current_snapshot() {
+        Some(snapshot) => {
+            let io_client = get_io_client(true, Arc::new(io_config.clone())).context(
+                UnableToPlanScanSnafu {
+                    table: table.to_string(),
+                },
+            )?;
+            data_files(&io_client, snapshot)
+                .await?
+                .into_iter()
+                .map(|(file_path, file_format)| {
+                    if file_format.eq_ignore_ascii_case("parquet") {
+                        Ok(file_path)
+                    } else {
+                        Err(unsupported(format!(
+                            "data file {file_path} is in {file_format} format, only Parquet is supported"
+                        )))
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?
+        }
+        None => vec![],
+    };
+
+    let file_format_config = FileFormatConfig::Parquet(ParquetSourceConfig {
+        field_id_mapping: Some(Arc::new(field_id_mapping)),
+        ..Default::default()
+    });
+    let operator = AnonymousScanOperator::new(
+        files,
+        Arc::new(schema),
+        Arc::new(file_format_config),
+        Arc::new(StorageConfig::new_internal(true, Some(io_config))),
+    );
+    LogicalPlanBuilder::table_scan(ScanOperatorRef(Arc::new(operator)), None).context(
+        UnableToPlanScanSnafu {
+            table: table.to_string(),
+        },
+    )
+}
+
+/// The paths and formats of the live data files of a snapshot.
+async fn data_files(io_client: &IOClient, snapshot: &Snapshot) -> Result<Vec<(String, String)>> {
+    let invalid = |reason: &str| Error::Invalid {
+        what: format!("snapshot {}", snapshot.snapshot_id),
+        reason: reason.to_string(),
+    };
+    let Some(manifest_list) = &snapshot.manifest_list else {
+        return Err(invalid(
+            "snapshots without a manifest list are not supported",
+        ));
+    };
+
+    let bytes = io_client
+        .single_url_get(manifest_list.clone(), None, None)
+        .await?
+        .bytes()
+        .await?;
+    let mut files = Vec::new();
+    for manifest in read_manifest_list(&bytes)? {
+        let bytes = io_client
+            .single_url_get(manifest.manifest_path.clone(), None, None)
+            .await?
+            .bytes()
+            .await?;
+        let live_files = read_live_files(&bytes)?;
+        if !manifest.is_data() && !live_files.is_empty() {
+            return Err(invalid("tables with delete files are not supported"));
+        }
+        files.extend(live_files);
+    }
+    Ok(files)
+}
+
+/// Converts the fields of an Iceberg struct type to Daft fields, adding every (nested) field to
+/// the mapping of field ids to the fields they are read as.
+fn struct_fields(
+    struct_type: &Value,
+    field_id_mapping: &mut BTreeMap<i32, Field>,
+) -> std::result::Result<Vec<Field>, String> {
+    let fields = struct_type["fields"]
+        .as_array()
+        .ok_or_else(|| format!("invalid struct type {struct_type}"))?;
+    fields
+        .iter()
+        .map(|field| {
+            let name = field["name"]
+                .as_str()
+                .ok_or_else(|| format!("invalid field {field}"))?;
+            mapped_field(name, &field["id"], &field["type"], field_id_mapping)
+        })
+        .collect()
+}
+
+/// Converts a field of an Iceberg type to a Daft field, adding it to the mapping under its id.
+fn mapped_field(
+    name: &str,
+    id: &Value,
+    field_type: &Value,
+    field_id_mapping: &mut BTreeMap<i32, Field>,
+) -> std::result::Result<Field, String> {
+    let id = id
+        .as_i64()
+        .and_then(|id| i32::try_from(id).ok())
+        .ok_or_else(|| format!("invalid id {id} of field {name}"))?;
+    let field = Field::new(name, to_daft_type(field_type, field_id_mapping)?);
+    field_id_mapping.insert(id, field.clone());
+    Ok(field)
+}
+
+/// Converts an Iceberg type to a Daft type, see the types in the Iceberg spec.
+fn to_daft_type(
+    iceberg_type: &Value,
+    field_id_mapping: &mut BTreeMap<i32, Field>,
+) -> std::result::Result<DataType, String> {
+    let invalid = || format!("unsupported type {iceberg_type}");
+    if let Some(primitive) = iceberg_type.as_str() {
+        return Ok(match primitive {
+            "boolean" => DataType::Boolean,
+            "int" => DataType::Int32,
+            "long" => DataType::Int64,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "date" => DataType::Date,
+            "time" => DataType::Time(TimeUnit::Microseconds),
+            "timestamp" => DataType::Timestamp(TimeUnit::Microseconds, None),
+            "timestamptz" => DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string())),
+            "timestamp_ns" => DataType::Timestamp(TimeUnit::Nanoseconds, None),
+            "timestamptz_ns" => DataType::Timestamp(TimeUnit::Nanoseconds, Some("UTC".to_string())),
+            "string" => DataType::Utf8,
+            "uuid" => DataType::FixedSizeBinary(16),
+            "binary" => DataType::Binary,
+            _ => {
+                if let Some(length) = primitive
+                    .strip_prefix("fixed[")
+                    .and_then(|rest| rest.strip_suffix(']'))
+                {
+                    DataType::FixedSizeBinary(length.trim().parse().map_err(|_| invalid())?)
+                } else if let Some(args) = primitive
+                    .strip_prefix("decimal(")
+                    .and_then(|rest| rest.strip_suffix(')'))
+                {
+                    let (precision, scale) = args.split_once(',').ok_or_else(invalid)?;
+                    DataType::Decimal128(
+                        precision.trim().parse().map_err(|_| invalid())?,
+                        scale.trim().parse().map_err(|_| invalid())?,
+                    )
+                } else {
+                    return Err(invalid());
+                }
+            }
+        });
+    }
+
+    match iceberg_type["type"].as_str() {
+        Some("struct") => Ok(DataType::Struct(struct_fields(
+            iceberg_type,
+            field_id_mapping,
+        )?)),
+        Some("list") => {
+            let element = mapped_field(
+                "element",
+                &iceberg_type["element-id"],
+                &iceberg_type["element"],
+                field_id_mapping,
+            )?;
+            Ok(DataType::List(Box::new(element.dtype)))
+        }
+        Some("map") => {
+            let key = mapped_field(
+                "key",
+                &iceberg_type["key-id"],
+                &iceberg_type["key"],
+                field_id_mapping,
+            )?;
+            let value = mapped_field(
+                "value",
+                &iceberg_type["value-id"],
+                &iceberg_type["value"],
+                field_id_mapping,
+            )?;
+            Ok(DataType::Map {
+                key: Box::new(key.dtype),
+                value: Box::new(value.dtype),
+            })
+        }
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_schema_with_field_ids() {
+        let schema = json!({
+            "type": "struct",
+            "schema-id": 0,
+            "fields": [
+                {"id": 1, "name": "id", "required": true, "type": "long"},
+                {"id": 2, "name": "price", "required": false, "type": "decimal(10, 2)"},
+                {"id": 3, "name": "tags", "required": false, "type": {
+                    "type": "list", "element-id": 5, "element": "string", "element-required": false
+                }},
+                {"id": 4, "name": "location", "required": false, "type": {
+                    "type": "struct", "fields": [
+                        {"id": 6, "name": "lat", "required": true, "type": "double"}
+                    ]
+                }}
+            ]
+        });
+        let mut field_id_mapping = BTreeMap::new();
+        let fields = struct_fields(&schema, &mut field_id_mapping).unwrap();
+
+        assert_eq!(
+            fields,
+            vec![
+                Field::new("id", DataType::Int64),
+                Field::new("price", DataType::Decimal128(10, 2)),
+                Field::new("tags", DataType::List(Box::new(DataType::Utf8))),
+                Field::new(
+                    "location",
+                    DataType::Struct(vec![Field::new("lat", DataType::Float64)])
+                ),
+            ]
+        );
+        assert_eq!(
+            field_id_mapping.keys().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(field_id_mapping[&5], Field::new("element", DataType::Utf8));
+        assert_eq!(field_id_mapping[&6], Field::new("lat", DataType::Float64));
+    }
+
+    #[test]
+    fn test_unsupported_type() {
+        let schema = json!({
+            "type": "struct",
+            "fields": [{"id": 1, "name": "v", "required": true, "type": "variant"}]
+        });
+        assert!(struct_fields(&schema, &mut BTreeMap::new()).is_err());
+    }
+}
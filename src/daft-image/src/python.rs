@@ -42,12 +42,19 @@ pub fn to_mode(s: &PySeries, mode: &ImageMode) -> PyResult<PySeries> {
     Ok(s.into())
 }
 
+#[pyfunction]
+pub fn to_tensor(s: &PySeries) -> PyResult<PySeries> {
+    let s = crate::series::to_tensor(&s.series)?;
+    Ok(s.into())
+}
+
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     let module = PyModule::new_bound(parent.py(), "image")?;
     module.add_wrapped(wrap_pyfunction!(decode))?;
     module.add_wrapped(wrap_pyfunction!(encode))?;
     module.add_wrapped(wrap_pyfunction!(resize))?;
     module.add_wrapped(wrap_pyfunction!(to_mode))?;
+    module.add_wrapped(wrap_pyfunction!(to_tensor))?;
     parent.add_submodule(&module)?;
     Ok(())
 }
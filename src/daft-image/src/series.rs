@@ -197,3 +197,32 @@ pub fn to_mode(s: &Series, mode: ImageMode) -> DaftResult<Series> {
         ))),
     }
 }
+
+/// The tensor type that images of the given type are converted into by [`to_tensor`]: images of
+/// a fixed shape become `height x width x channels` fixed-shape tensors, and other images become
+/// tensors of their pixel type, which is `UInt8` for images of mixed modes.
+pub fn tensor_dtype(dtype: &DataType) -> DaftResult<DataType> {
+    match dtype {
+        DataType::Image(mode) => Ok(DataType::Tensor(Box::new(
+            mode.map_or(DataType::UInt8, |mode| mode.get_dtype()),
+        ))),
+        DataType::FixedShapeImage(mode, h, w) => Ok(DataType::FixedShapeTensor(
+            Box::new(mode.get_dtype()),
+            vec![*h as u64, *w as u64, mode.num_channels() as u64],
+        )),
+        dt => Err(DaftError::ValueError(format!(
+            "Expected input to to_tensor to be an Image type, but received: {dt}"
+        ))),
+    }
+}
+
+/// Converts images in a Series to tensors of shape `height x width x channels`.
+///
+/// # Arguments
+/// * `s` - Input Series containing image data
+///
+/// # Returns
+/// A DaftResult containing a new Series of tensors
+pub fn to_tensor(s: &Series) -> DaftResult<Series> {
+    s.cast(&tensor_dtype(s.data_type())?)
+}
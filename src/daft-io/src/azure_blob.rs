@@ -1,7 +1,7 @@
 use std::{ops::Range, sync::Arc};
 
 use async_trait::async_trait;
-use azure_core::{auth::TokenCredential, new_http_client};
+use azure_core::{auth::TokenCredential, new_http_client, request_options::IfMatchCondition};
 use azure_identity::{ClientSecretCredential, DefaultAzureCredential, TokenCredentialOptions};
 use azure_storage::{prelude::*, CloudLocation};
 use azure_storage_blobs::{
@@ -54,6 +54,12 @@ enum Error {
         source: azure_storage::Error,
     },
 
+    #[snafu(display("Unable to write data to {}: {}", path, source))]
+    UnableToPutFile {
+        path: String,
+        source: azure_storage::Error,
+    },
+
     #[snafu(display("Unable to read metadata about {}: {}", path, source))]
     RequestFailedForPath {
         path: String,
@@ -125,9 +131,21 @@ fn parse_azure_uri(uri: &str) -> super::Result<ParsedAzureUri> {
 
 impl From<Error> for super::Error {
     fn from(error: Error) -> Self {
-        use Error::{NotAFile, NotFound, UnableToOpenFile, UnableToReadBytes};
+        use Error::{NotAFile, NotFound, UnableToOpenFile, UnableToPutFile, UnableToReadBytes};
         match error {
-            UnableToReadBytes { path, source } | UnableToOpenFile { path, source } => {
+            // `If-None-Match: *` fails with 409 Conflict (or 412 during a concurrent write) if the
+            // blob exists.
+            UnableToPutFile { path, source }
+                if matches!(
+                    source.as_http_error().map(|v| v.status().into()),
+                    Some(409 | 412)
+                ) =>
+            {
+                Self::AlreadyExists { path }
+            }
+            UnableToReadBytes { path, source }
+            | UnableToOpenFile { path, source }
+            | UnableToPutFile { path, source } => {
                 match source.as_http_error().map(|v| v.status().into()) {
                     Some(404 | 410) => Self::NotFound {
                         path,
@@ -482,6 +500,44 @@ impl AzureBlobSource {
             .boxed()
     }
 
+    async fn put_impl(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        if_absent: bool,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let parsed_uri = parse_azure_uri(uri)?;
+        let (container, key) = parsed_uri
+            .container_and_key
+            .ok_or_else(|| Error::InvalidUrl {
+                path: uri.into(),
+                source: url::ParseError::EmptyHost,
+            })?;
+
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        let data_len = data.len();
+        let container_client = self.blob_client.container_client(container);
+        let blob_client = container_client.blob_client(key);
+        let request_builder = blob_client.put_block_blob(data);
+        let request_builder = if if_absent {
+            request_builder.if_match(IfMatchCondition::NotMatch("*".to_string()))
+        } else {
+            request_builder
+        };
+        request_builder
+            .await
+            .context(UnableToPutFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(data_len);
+        }
+        Ok(())
+    }
+
     fn _container_to_file_metadata(&self, protocol: &str, container: &Container) -> FileMetadata {
         // NB: Cannot pass through to Azure client's .url() methods here
         // because they return URIs of a very different format (https://.../container/path).
@@ -489,6 +545,7 @@ impl AzureBlobSource {
             filepath: format!("{protocol}://{}/", &container.name),
             size: None,
             filetype: FileType::Directory,
+            etag: None,
         }
     }
 
@@ -503,11 +560,13 @@ impl AzureBlobSource {
                 filepath: format!("{protocol}://{}/{}", container_name, &blob.name),
                 size: Some(blob.properties.content_length),
                 filetype: FileType::File,
+                etag: Some(blob.properties.etag.to_string()),
             },
             BlobItem::BlobPrefix(prefix) => FileMetadata {
                 filepath: format!("{protocol}://{}/{}", container_name, &prefix.name),
                 size: None,
                 filetype: FileType::Directory,
+                etag: None,
             },
         }
     }
@@ -566,11 +625,24 @@ impl ObjectSource for AzureBlobSource {
 
     async fn put(
         &self,
-        _uri: &str,
-        _data: bytes::Bytes,
-        _io_stats: Option<IOStatsRef>,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self.put_impl(uri, data, false, io_stats).await
+    }
+
+    async fn put_if_absent(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
     ) -> super::Result<()> {
-        todo!("PUTs to Azure blob store are not yet supported! Please file an issue.");
+        self.put_impl(uri, data, true, io_stats).await
+    }
+
+    fn supports_put_if_absent(&self) -> bool {
+        true
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
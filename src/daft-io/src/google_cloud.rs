@@ -7,7 +7,11 @@ use futures::{stream::BoxStream, TryStreamExt};
 use google_cloud_storage::{
     client::{google_cloud_auth::credentials::CredentialsFile, Client, ClientConfig},
     http::{
-        objects::{get::GetObjectRequest, list::ListObjectsRequest},
+        objects::{
+            get::GetObjectRequest,
+            list::ListObjectsRequest,
+            upload::{Media, UploadObjectRequest, UploadType},
+        },
         Error as GError,
     },
 };
@@ -37,6 +41,9 @@ enum Error {
     #[snafu(display("Unable to read data from {}: {}", path, source))]
     UnableToReadBytes { path: String, source: GError },
 
+    #[snafu(display("Unable to write data to {}: {}", path, source))]
+    UnableToPutFile { path: String, source: GError },
+
     #[snafu(display("Unable to parse URL: \"{}\"", path))]
     InvalidUrl {
         path: String,
@@ -63,10 +70,17 @@ impl From<Error> for super::Error {
     fn from(error: Error) -> Self {
         use Error::{
             InvalidUrl, NotAFile, NotFound, UnableToCreateClient, UnableToGrabSemaphore,
-            UnableToListObjects, UnableToLoadCredentials, UnableToOpenFile, UnableToReadBytes,
+            UnableToListObjects, UnableToLoadCredentials, UnableToOpenFile, UnableToPutFile,
+            UnableToReadBytes,
         };
         match error {
+            // `ifGenerationMatch=0` fails with 412 Precondition Failed if the object exists.
+            UnableToPutFile {
+                path,
+                source: GError::Response(err),
+            } if err.code == 412 => Self::AlreadyExists { path },
             UnableToReadBytes { path, source }
+            | UnableToPutFile { path, source }
             | UnableToOpenFile { path, source }
             | UnableToListObjects { path, source } => match source {
                 GError::HttpClient(err) => match err.status().map(|s| s.as_u16()) {
@@ -256,6 +270,47 @@ impl GCSClientWrapper {
         }
         Ok(response.size as usize)
     }
+
+    async fn put(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        if_absent: bool,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let uri = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let (bucket, key) = parse_uri(&uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+
+        let data_len = data.len();
+        let req = UploadObjectRequest {
+            bucket: bucket.into(),
+            // A generation of 0 only matches objects that do not exist yet.
+            if_generation_match: if_absent.then_some(0),
+            ..Default::default()
+        };
+        let upload_type = UploadType::Simple(Media::new(key.to_string()));
+        self.client
+            .upload_object(&req, data, &upload_type)
+            .await
+            .context(UnableToPutFileSnafu {
+                path: uri.to_string(),
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(data_len);
+        }
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn _ls_impl(
         &self,
@@ -295,11 +350,13 @@ impl GCSClientWrapper {
             filepath: format!("{GCS_SCHEME}://{}/{}", bucket, obj.name),
             size: Some(obj.size as u64),
             filetype: FileType::File,
+            etag: Some(obj.etag.clone()),
         });
         let dirs = response_prefixes.iter().map(|pref| FileMetadata {
             filepath: format!("{GCS_SCHEME}://{bucket}/{pref}"),
             size: None,
             filetype: FileType::Directory,
+            etag: None,
         });
         Ok(LSResult {
             files: files.chain(dirs).collect(),
@@ -515,11 +572,24 @@ impl ObjectSource for GCSSource {
 
     async fn put(
         &self,
-        _uri: &str,
-        _data: bytes::Bytes,
-        _io_stats: Option<IOStatsRef>,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self.client.put(uri, data, false, io_stats).await
+    }
+
+    async fn put_if_absent(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
     ) -> super::Result<()> {
-        todo!("PUTS to GCS are not yet supported! Please file an issue.");
+        self.client.put(uri, data, true, io_stats).await
+    }
+
+    fn supports_put_if_absent(&self) -> bool {
+        true
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
@@ -6,7 +6,7 @@ use futures::{stream::BoxStream, TryStreamExt};
 use hyper::header;
 use lazy_static::lazy_static;
 use regex::Regex;
-use reqwest::header::{CONTENT_LENGTH, RANGE};
+use reqwest::header::{CONTENT_LENGTH, ETAG, RANGE};
 use snafu::{IntoError, ResultExt, Snafu};
 use url::Position;
 
@@ -131,6 +131,7 @@ fn _get_file_metadata_from_html(path: &str, text: &str) -> super::Result<Vec<Fil
                 // for populating `size` if necessary
                 size: None,
                 filetype,
+                etag: None,
             }))
         })
         .collect::<super::Result<Vec<_>>>()?;
@@ -339,6 +340,11 @@ impl ObjectSource for HttpSource {
                     filepath: path.to_string(),
                     filetype: FileType::File,
                     size: response.content_length(),
+                    etag: response
+                        .headers()
+                        .get(ETAG)
+                        .and_then(|etag| etag.to_str().ok())
+                        .map(str::to_string),
                 }],
                 continuation_token: None,
             }),
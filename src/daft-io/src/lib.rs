@@ -24,8 +24,8 @@ pub mod python;
 use std::{borrow::Cow, collections::HashMap, hash::Hash, ops::Range, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
-pub use common_io_config::{AzureConfig, IOConfig, S3Config};
-use futures::stream::BoxStream;
+pub use common_io_config::{AzureConfig, ByteTransform, IOConfig, S3Config};
+use futures::{stream::BoxStream, StreamExt};
 use object_io::StreamingRetryParams;
 pub use object_io::{FileMetadata, GetResult};
 #[cfg(feature = "python")]
@@ -44,6 +44,9 @@ pub enum Error {
     #[snafu(display("Object at location {} not found\nDetails:\n{}", path, source))]
     NotFound { path: String, source: DynError },
 
+    #[snafu(display("Object at location {} already exists", path))]
+    AlreadyExists { path: String },
+
     #[snafu(display("Invalid Argument: {:?}", msg))]
     InvalidArgument { msg: String },
 
@@ -136,6 +139,9 @@ pub enum Error {
 
     #[snafu(display("Cached error: {}", source))]
     CachedError { source: Arc<Error> },
+
+    #[snafu(display("Unable to transform the bytes of {}: {}", path, source))]
+    UnableToTransform { path: String, source: DynError },
 }
 
 impl From<Error> for DaftError {
@@ -193,6 +199,12 @@ impl IOClient {
         })
     }
 
+    /// Whether the bytes of objects are transformed as they're read and written, in which case
+    /// local files have to be read through the client rather than directly.
+    pub fn has_transform(&self) -> bool {
+        self.config.transform.is_some()
+    }
+
     async fn get_source(&self, input: &str) -> Result<Arc<dyn ObjectSource>> {
         let (source_type, path) = parse_url(input)?;
 
@@ -263,6 +275,11 @@ impl IOClient {
         range: Option<Range<usize>>,
         io_stats: Option<IOStatsRef>,
     ) -> Result<GetResult> {
+        if let Some(transform) = &self.config.transform {
+            return self
+                .transformed_get(transform.as_ref(), input, range, io_stats)
+                .await;
+        }
         let (_, path) = parse_url(&input)?;
         let source = self.get_source(&input).await?;
         let get_result = source
@@ -271,6 +288,65 @@ impl IOClient {
         Ok(get_result.with_retry(StreamingRetryParams::new(source, input, range, io_stats)))
     }
 
+    /// Reads a whole object and decodes it with the transform, since transformed objects can only
+    /// be decoded as a whole, and then takes the range from the decoded bytes.
+    async fn transformed_get(
+        &self,
+        transform: &dyn ByteTransform,
+        input: String,
+        range: Option<Range<usize>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<GetResult> {
+        let (_, path) = parse_url(&input)?;
+        let source = self.get_source(&input).await?;
+        let data = source
+            .get(path.as_ref(), None, io_stats.clone())
+            .await?
+            .with_retry(StreamingRetryParams::new(
+                source,
+                input.clone(),
+                None,
+                io_stats,
+            ))
+            .bytes()
+            .await?;
+        let data = bytes::Bytes::from(transform.decode(&input, &data).context(
+            UnableToTransformSnafu {
+                path: input.clone(),
+            },
+        )?);
+        let data = match range {
+            Some(range) if range.start > range.end || range.end > data.len() => {
+                return Err(Error::InvalidArgument {
+                    msg: format!(
+                        "Range {range:?} is out of bounds of {input}, which is {} bytes long when decoded",
+                        data.len()
+                    ),
+                });
+            }
+            Some(range) => data.slice(range),
+            None => data,
+        };
+        let size = data.len();
+        Ok(GetResult::Stream(
+            futures::stream::once(async move { Ok(data) }).boxed(),
+            Some(size),
+            None,
+            None,
+        ))
+    }
+
+    /// Encodes the bytes of an object with the transform, if there is one.
+    fn encode(&self, dest: &str, data: bytes::Bytes) -> Result<bytes::Bytes> {
+        match &self.config.transform {
+            Some(transform) => Ok(transform
+                .encode(dest, &data)
+                .context(UnableToTransformSnafu { path: dest })?
+                .into()),
+            None => Ok(data),
+        }
+    }
+
     pub async fn single_url_put(
         &self,
         dest: &str,
@@ -279,14 +355,44 @@ impl IOClient {
     ) -> Result<()> {
         let (_, path) = parse_url(dest)?;
         let source = self.get_source(dest).await?;
+        let data = self.encode(dest, data)?;
         source.put(path.as_ref(), data, io_stats.clone()).await
     }
 
+    pub async fn single_url_put_if_absent(
+        &self,
+        dest: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (_, path) = parse_url(dest)?;
+        let source = self.get_source(dest).await?;
+        let data = self.encode(dest, data)?;
+        source
+            .put_if_absent(path.as_ref(), data, io_stats.clone())
+            .await
+    }
+
+    /// Whether [`IOClient::single_url_put_if_absent`] writes `dest` atomically.
+    pub async fn supports_put_if_absent(&self, dest: &str) -> Result<bool> {
+        Ok(self.get_source(dest).await?.supports_put_if_absent())
+    }
+
     pub async fn single_url_get_size(
         &self,
         input: String,
         io_stats: Option<IOStatsRef>,
     ) -> Result<usize> {
+        // the size of a transformed object is the size of its decoded bytes
+        if let Some(transform) = &self.config.transform {
+            return match self
+                .transformed_get(transform.as_ref(), input, None, io_stats)
+                .await?
+            {
+                GetResult::Stream(_, Some(size), ..) => Ok(size),
+                _ => unreachable!("Transformed objects should always be streams of known size"),
+            };
+        }
         let (_, path) = parse_url(&input)?;
         let source = self.get_source(&input).await?;
         source.get_size(path.as_ref(), io_stats).await
@@ -177,6 +177,47 @@ impl ObjectSource for LocalSource {
         }
     }
 
+    async fn put_if_absent(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        const LOCAL_PROTOCOL: &str = "file://";
+        let Some(stripped_uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
+            return Err(Error::InvalidFilePath { path: uri.into() }.into());
+        };
+        if let Some(parent) = std::path::Path::new(stripped_uri).parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|_| UnableToOpenFileForWritingSnafu { path: uri })?;
+        }
+        // `create_new` fails atomically if the file exists, so concurrent writers cannot both win.
+        let mut file = match std::fs::OpenOptions::new()
+            .create_new(true)
+            .write(true)
+            .open(stripped_uri)
+        {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                return Err(super::Error::AlreadyExists { path: uri.into() });
+            }
+            Err(err) => {
+                return Err(Error::UnableToOpenFileForWriting {
+                    path: uri.into(),
+                    source: err,
+                }
+                .into())
+            }
+        };
+        Ok(file
+            .write_all(&data)
+            .with_context(|_| UnableToWriteToFileSnafu { path: uri })?)
+    }
+
+    fn supports_put_if_absent(&self) -> bool {
+        true
+    }
+
     async fn get_size(&self, uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         const LOCAL_PROTOCOL: &str = "file://";
         let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
@@ -283,6 +324,7 @@ impl ObjectSource for LocalSource {
                 filepath: format!("{LOCAL_PROTOCOL}{uri}"),
                 size: Some(meta.len()),
                 filetype: object_io::FileType::File,
+                etag: None,
             })])
             .boxed());
         }
@@ -330,6 +372,7 @@ impl ObjectSource for LocalSource {
                             path: entry.path().to_string_lossy().to_string(),
                         }
                     })?,
+                    etag: None,
                 })
             }
         });
@@ -476,6 +519,7 @@ mod tests {
                 ),
                 size: Some(file1.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                etag: None,
             },
             FileMetadata {
                 filepath: format!(
@@ -485,6 +529,7 @@ mod tests {
                 ),
                 size: Some(file2.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                etag: None,
             },
             FileMetadata {
                 filepath: format!(
@@ -494,6 +539,7 @@ mod tests {
                 ),
                 size: Some(file3.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                etag: None,
             },
         ];
         expected.sort_by(|a, b| a.filepath.cmp(&b.filepath));
@@ -502,4 +548,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_local_put_if_absent() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = format!(
+            "file://{}/nested/file.json",
+            dir.path().to_string_lossy().replace('\\', "/")
+        );
+
+        let client = LocalSource::get_client().await?;
+        client
+            .put_if_absent(&path, bytes::Bytes::from_static(b"first"), None)
+            .await?;
+        let second = client
+            .put_if_absent(&path, bytes::Bytes::from_static(b"second"), None)
+            .await;
+        assert!(matches!(second, Err(crate::Error::AlreadyExists { .. })));
+
+        let contents = client.get(&path, None, None).await?.bytes().await?;
+        assert_eq!(contents.as_ref(), b"first");
+
+        Ok(())
+    }
 }
@@ -164,6 +164,8 @@ pub struct FileMetadata {
     pub filepath: String,
     pub size: Option<u64>,
     pub filetype: FileType,
+    /// Version of the file's contents, if the source provides one
+    pub etag: Option<String>,
 }
 #[derive(Debug)]
 pub struct LSResult {
@@ -189,6 +191,31 @@ pub trait ObjectSource: Sync + Send {
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<()>;
 
+    /// Writes `data` to `uri` only if no object exists there yet, returning
+    /// [`super::Error::AlreadyExists`] otherwise.
+    ///
+    /// The default implementation checks for the object before writing it, so it is not atomic:
+    /// two concurrent writers can both succeed. Sources that support conditional writes should
+    /// override it.
+    async fn put_if_absent(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        match self.get_size(uri, io_stats.clone()).await {
+            Ok(_) => Err(super::Error::AlreadyExists { path: uri.into() }),
+            Err(super::Error::NotFound { .. }) => self.put(uri, data, io_stats).await,
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Whether [`ObjectSource::put_if_absent`] is atomic for this source, i.e. backed by a
+    /// conditional write rather than the default check-then-write.
+    fn supports_put_if_absent(&self) -> bool {
+        false
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
     async fn glob(
@@ -54,6 +54,7 @@ mod py {
             dict.set_item("type", format!("{:?}", file.filetype))?;
             dict.set_item("path", file.filepath)?;
             dict.set_item("size", file.size)?;
+            dict.set_item("etag", file.etag)?;
             to_rtn.push(dict);
         }
         Ok(to_rtn)
@@ -893,6 +893,7 @@ impl S3LikeSource {
                             ),
                             size: None,
                             filetype: FileType::Directory,
+                            etag: None,
                         };
                         all_files.push(fmeta);
                     }
@@ -906,6 +907,7 @@ impl S3LikeSource {
                             ),
                             size: Some(f.size() as u64),
                             filetype: FileType::File,
+                            etag: f.e_tag().map(str::to_string),
                         };
                         all_files.push(fmeta);
                     }
@@ -963,6 +965,7 @@ impl S3LikeSource {
         uri: &str,
         data: bytes::Bytes,
         region: &Region,
+        if_absent: bool,
     ) -> super::Result<()> {
         log::debug!(
             "S3 put at {uri}, num_bytes: {}, in region: {region}",
@@ -990,12 +993,35 @@ impl S3LikeSource {
 
             let response = if self.anonymous {
                 return Err(Error::UploadsCannotBeAnonymous {}.into());
+            } else if if_absent {
+                // `If-None-Match: *` makes S3 reject the write if the key already exists.
+                request
+                    .customize_middleware()
+                    .await
+                    .map_err(|err| UnableToPutFileSnafu { path: uri }.into_error(err))?
+                    .mutate_request(|request| {
+                        request.headers_mut().insert(
+                            hyper::header::IF_NONE_MATCH,
+                            hyper::header::HeaderValue::from_static("*"),
+                        );
+                    })
+                    .send()
+                    .await
             } else {
                 request.send().await
             };
 
             match response {
                 Ok(_) => Ok(()),
+                Err(SdkError::ServiceError(err))
+                    if if_absent
+                        && matches!(
+                            err.raw().http().status(),
+                            StatusCode::PRECONDITION_FAILED | StatusCode::CONFLICT
+                        ) =>
+                {
+                    Err(super::Error::AlreadyExists { path: uri.into() })
+                }
                 Err(err) => Err(UnableToPutFileSnafu { path: uri }.into_error(err).into()),
             }
         }
@@ -1052,7 +1078,7 @@ impl ObjectSource for S3LikeSource {
             .acquire_owned()
             .await
             .context(UnableToGrabSemaphoreSnafu)?;
-        self._put_impl(permit, uri, data, &self.default_region)
+        self._put_impl(permit, uri, data, &self.default_region, false)
             .await?;
 
         if let Some(io_stats) = io_stats {
@@ -1063,6 +1089,34 @@ impl ObjectSource for S3LikeSource {
         Ok(())
     }
 
+    async fn put_if_absent(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let data_len = data.len();
+        let permit = self
+            .connection_pool_sema
+            .clone()
+            .acquire_owned()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        self._put_impl(permit, uri, data, &self.default_region, true)
+            .await?;
+
+        if let Some(io_stats) = io_stats {
+            io_stats.as_ref().mark_put_requests(1);
+            io_stats.as_ref().mark_bytes_uploaded(data_len);
+        }
+
+        Ok(())
+    }
+
+    fn supports_put_if_absent(&self) -> bool {
+        true
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         let permit = self
             .connection_pool_sema
@@ -125,6 +125,16 @@ impl IOStatsContext {
     pub fn load_bytes_uploaded(&self) -> usize {
         self.bytes_uploaded.load(atomic::Ordering::Acquire)
     }
+
+    /// Adds the stats of `other` to these stats
+    pub fn add(&self, other: &Self) {
+        self.mark_get_requests(other.load_get_requests());
+        self.mark_head_requests(other.load_head_requests());
+        self.mark_list_requests(other.load_list_requests());
+        self.mark_put_requests(other.load_put_requests());
+        self.mark_bytes_read(other.load_bytes_read());
+        self.mark_bytes_uploaded(other.load_bytes_uploaded());
+    }
 }
 
 impl IOStatsByteStreamContextHandle {
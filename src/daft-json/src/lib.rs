@@ -17,6 +17,7 @@ pub mod read;
 pub mod schema;
 
 // pub use metadata::read_json_schema_bulk;
+pub use local::parse_json_records;
 pub use options::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
@@ -46,6 +46,51 @@ pub fn read_json_local(
     reader.finish()
 }
 
+/// Parses in-memory JSON objects, such as the values of the messages of a Kafka topic, into a table
+/// with the given schema. Keys missing from an object are read as nulls and keys that aren't in the
+/// schema are dropped.
+pub fn parse_json_records(records: &mut [Vec<u8>], schema: SchemaRef) -> DaftResult<Table> {
+    let arrow_schema = schema.to_arrow()?;
+    let mut columns = arrow_schema
+        .fields
+        .iter()
+        .map(|f| {
+            (
+                Cow::Owned(f.name.to_string()),
+                allocate_array(f, records.len()),
+            )
+        })
+        .collect::<IndexMap<_, _>>();
+    let null = Value::Static(simd_json::StaticNode::Null);
+    for record in records.iter_mut() {
+        let value = crate::deserializer::to_value(record).map_err(|e| {
+            super::Error::JsonDeserializationError {
+                string: e.to_string(),
+            }
+        })?;
+        let Value::Object(record) = value else {
+            return Err(super::Error::JsonDeserializationError {
+                string: "Expected JSON object".to_string(),
+            }
+            .into());
+        };
+        for (s, inner) in &mut columns {
+            deserialize_into(inner, &[record.get(s).unwrap_or(&null)]);
+        }
+    }
+    let columns = columns
+        .into_values()
+        .zip(schema.fields.values())
+        .map(|(mut ma, fld)| {
+            Series::try_from_field_and_arrow_array(
+                Arc::new(fld.clone()),
+                cast_array_for_daft_if_needed(ma.as_box()),
+            )
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::new_with_size(schema, columns, records.len())
+}
+
 struct JsonReader<'a> {
     bytes: &'a [u8],
     schema: SchemaRef,
@@ -477,4 +522,22 @@ mod tests {
         let reader = JsonReader::try_new(json.as_bytes(), None, None, None, None).unwrap();
         let _result = reader.finish();
     }
+
+    #[test]
+    fn test_parse_json_records() -> DaftResult<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ])?);
+        let mut records = vec![
+            br#"{"a": 1, "b": "x"}"#.to_vec(),
+            br#"{"c": true, "a": 2}"#.to_vec(),
+        ];
+        let table = parse_json_records(&mut records, schema)?;
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.get_column("a")?.i64()?.get(1), Some(2));
+        assert_eq!(table.get_column("b")?.utf8()?.get(0), Some("x"));
+        assert_eq!(table.get_column("b")?.utf8()?.get(1), None);
+        Ok(())
+    }
 }
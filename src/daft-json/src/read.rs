@@ -180,7 +180,7 @@ async fn read_json_single_into_table(
 ) -> DaftResult<Table> {
     let (source_type, fixed_uri) = parse_url(uri)?;
     let is_compressed = CompressionCodec::from_uri(uri).is_some();
-    if matches!(source_type, SourceType::File) && !is_compressed {
+    if matches!(source_type, SourceType::File) && !is_compressed && !io_client.has_transform() {
         return read_json_local(
             fixed_uri.as_ref(),
             convert_options,
@@ -0,0 +1,104 @@
+//! Bounded batch reads of Kafka topics.
+//!
+//! A read of a topic is planned by resolving the start and end of the read to a
+//! [`KafkaOffsetRange`] for each of its partitions with [`resolve_offset_ranges`], after which
+//! each range can be read independently into a table with [`read_kafka_range`].
+//!
+//! The values of the messages are decoded from JSON or Avro into the columns of the schema of the
+//! read, except for the metadata columns below, which are filled from the metadata of the messages
+//! if they are in the schema.
+
+mod offsets;
+mod read;
+
+use common_error::DaftError;
+use common_file_formats::KafkaSourceConfig;
+pub use offsets::{resolve_offset_ranges, KafkaOffsetRange, KafkaOffsetSpec};
+use rdkafka::ClientConfig;
+pub use read::read_kafka_range;
+use snafu::Snafu;
+
+/// Column with the partition of each message.
+pub const PARTITION_COLUMN: &str = "_kafka_partition";
+/// Column with the offset of each message in its partition.
+pub const OFFSET_COLUMN: &str = "_kafka_offset";
+/// Column with the timestamp of each message.
+pub const TIMESTAMP_COLUMN: &str = "_kafka_timestamp";
+/// Column with the raw key of each message.
+pub const KEY_COLUMN: &str = "_kafka_key";
+
+pub const METADATA_COLUMNS: [&str; 4] = [
+    PARTITION_COLUMN,
+    OFFSET_COLUMN,
+    TIMESTAMP_COLUMN,
+    KEY_COLUMN,
+];
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Kafka error: {}", source))]
+    Kafka { source: rdkafka::error::KafkaError },
+
+    #[snafu(display("Kafka topic {} does not exist", topic))]
+    TopicNotFound { topic: String },
+
+    #[snafu(display("Partition {} of Kafka topic {} does not exist", partition, topic))]
+    PartitionNotFound { topic: String, partition: i32 },
+
+    #[snafu(display(
+        "Timed out after {}ms waiting for offset {} of partition {} of Kafka topic {}",
+        timeout_ms,
+        offset,
+        partition,
+        topic
+    ))]
+    Timeout {
+        topic: String,
+        partition: i32,
+        offset: i64,
+        timeout_ms: u64,
+    },
+
+    #[snafu(display("Invalid Avro schema for Kafka topic {}: {}", topic, source))]
+    InvalidAvroSchema {
+        topic: String,
+        source: apache_avro::Error,
+    },
+
+    #[snafu(display(
+        "Failed to decode the Avro value at offset {} of partition {} of Kafka topic {}: {}",
+        offset,
+        partition,
+        topic,
+        source
+    ))]
+    AvroDecode {
+        topic: String,
+        partition: i32,
+        offset: i64,
+        source: apache_avro::Error,
+    },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        Self::External(err.into())
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Config of the consumers of a source, which are assigned partitions directly rather than joining
+/// a consumer group, and never commit offsets.
+fn client_config(config: &KafkaSourceConfig) -> ClientConfig {
+    let mut client_config = ClientConfig::new();
+    client_config
+        .set("bootstrap.servers", &config.bootstrap_servers)
+        .set("group.id", "daft")
+        .set("enable.auto.commit", "false")
+        .set("enable.auto.offset.store", "false");
+    for (key, value) in &config.consumer_options {
+        client_config.set(key, value);
+    }
+    client_config
+}
@@ -0,0 +1,220 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use common_error::DaftResult;
+use common_file_formats::KafkaSourceConfig;
+use rdkafka::{
+    consumer::{BaseConsumer, Consumer},
+    Offset, TopicPartitionList,
+};
+use serde::{Deserialize, Serialize};
+use snafu::{ensure, OptionExt, ResultExt};
+
+use crate::{client_config, KafkaSnafu, PartitionNotFoundSnafu, TopicNotFoundSnafu};
+
+/// Where a read starts or ends in each partition of a Kafka topic.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KafkaOffsetSpec {
+    /// The earliest offset still retained by the brokers.
+    Earliest,
+    /// The offset after the latest message when the read is planned.
+    Latest,
+    /// The offset of the earliest message with a timestamp at or after the given milliseconds
+    /// since the epoch.
+    Timestamp(i64),
+    /// Explicit offsets per partition. Partitions without an offset start at their earliest
+    /// offset and end at their latest offset.
+    Offsets(BTreeMap<i32, i64>),
+}
+
+/// A half-open range of offsets in a partition of a Kafka topic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KafkaOffsetRange {
+    pub partition: i32,
+    pub start: i64,
+    pub end: i64,
+}
+
+impl KafkaOffsetRange {
+    /// Number of offsets in the range, which is an upper bound on its number of messages since
+    /// offsets of compacted messages and transaction markers are skipped.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        (self.end - self.start).max(0) as u64
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Splits the range into consecutive ranges of at most `max_len` offsets.
+    #[must_use]
+    pub fn split(&self, max_len: u64) -> Vec<Self> {
+        let max_len = max_len.max(1) as i64;
+        (self.start..self.end)
+            .step_by(max_len as usize)
+            .map(|start| Self {
+                partition: self.partition,
+                start,
+                end: start.saturating_add(max_len).min(self.end),
+            })
+            .collect()
+    }
+}
+
+/// Resolves where a read of the given partitions of a topic, or of all of its partitions, starts
+/// and ends in each of them. Partitions with nothing to read are left out.
+pub fn resolve_offset_ranges(
+    config: &KafkaSourceConfig,
+    partitions: Option<&[i32]>,
+    start: &KafkaOffsetSpec,
+    end: &KafkaOffsetSpec,
+) -> DaftResult<Vec<KafkaOffsetRange>> {
+    let consumer: BaseConsumer = client_config(config).create().context(KafkaSnafu)?;
+    let timeout = Duration::from_millis(config.poll_timeout_ms);
+    let metadata = consumer
+        .fetch_metadata(Some(&config.topic), timeout)
+        .context(KafkaSnafu)?;
+    let topic = metadata
+        .topics()
+        .iter()
+        .find(|topic| topic.name() == config.topic && topic.error().is_none())
+        .context(TopicNotFoundSnafu {
+            topic: config.topic.clone(),
+        })?;
+    let topic_partitions = topic
+        .partitions()
+        .iter()
+        .map(|partition| partition.id())
+        .collect::<Vec<_>>();
+    let partitions = match partitions {
+        Some(partitions) => {
+            for partition in partitions {
+                ensure!(
+                    topic_partitions.contains(partition),
+                    PartitionNotFoundSnafu {
+                        topic: config.topic.clone(),
+                        partition: *partition,
+                    }
+                );
+            }
+            partitions.to_vec()
+        }
+        None => topic_partitions,
+    };
+
+    let start_times = offsets_for_times(&consumer, config, &partitions, start, timeout)?;
+    let end_times = offsets_for_times(&consumer, config, &partitions, end, timeout)?;
+    let mut ranges = vec![];
+    for partition in partitions {
+        let (low, high) = consumer
+            .fetch_watermarks(&config.topic, partition, timeout)
+            .context(KafkaSnafu)?;
+        let range = KafkaOffsetRange {
+            partition,
+            start: resolve_offset(start, partition, (low, high), low, &start_times),
+            end: resolve_offset(end, partition, (low, high), high, &end_times),
+        };
+        if !range.is_empty() {
+            ranges.push(range);
+        }
+    }
+    Ok(ranges)
+}
+
+/// Looks up the offsets of the earliest messages at or after the timestamp of a spec in each
+/// partition, if it is a timestamp.
+fn offsets_for_times(
+    consumer: &BaseConsumer,
+    config: &KafkaSourceConfig,
+    partitions: &[i32],
+    spec: &KafkaOffsetSpec,
+    timeout: Duration,
+) -> DaftResult<BTreeMap<i32, Offset>> {
+    let KafkaOffsetSpec::Timestamp(timestamp) = spec else {
+        return Ok(BTreeMap::new());
+    };
+    let mut timestamps = TopicPartitionList::new();
+    for partition in partitions {
+        timestamps
+            .add_partition_offset(&config.topic, *partition, Offset::Offset(*timestamp))
+            .context(KafkaSnafu)?;
+    }
+    let offsets = consumer
+        .offsets_for_times(timestamps, timeout)
+        .context(KafkaSnafu)?;
+    Ok(offsets
+        .elements()
+        .iter()
+        .map(|element| (element.partition(), element.offset()))
+        .collect())
+}
+
+/// Resolves a spec to an offset of a partition within its `(low, high)` watermarks.
+fn resolve_offset(
+    spec: &KafkaOffsetSpec,
+    partition: i32,
+    (low, high): (i64, i64),
+    default: i64,
+    times: &BTreeMap<i32, Offset>,
+) -> i64 {
+    let offset = match spec {
+        KafkaOffsetSpec::Earliest => low,
+        KafkaOffsetSpec::Latest => high,
+        KafkaOffsetSpec::Timestamp(_) => match times.get(&partition) {
+            Some(Offset::Offset(offset)) => *offset,
+            // There are no messages at or after the timestamp.
+            _ => high,
+        },
+        KafkaOffsetSpec::Offsets(offsets) => offsets.get(&partition).copied().unwrap_or(default),
+    };
+    offset.clamp(low, high)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use rdkafka::Offset;
+
+    use super::{resolve_offset, KafkaOffsetRange, KafkaOffsetSpec};
+
+    #[test]
+    fn test_resolve_offset() {
+        let watermarks = (10, 100);
+        let times = BTreeMap::from([(0, Offset::Offset(42)), (1, Offset::End)]);
+        let resolve = |spec: &KafkaOffsetSpec, partition| {
+            resolve_offset(spec, partition, watermarks, 10, &times)
+        };
+        assert_eq!(resolve(&KafkaOffsetSpec::Earliest, 0), 10);
+        assert_eq!(resolve(&KafkaOffsetSpec::Latest, 0), 100);
+        assert_eq!(resolve(&KafkaOffsetSpec::Timestamp(0), 0), 42);
+        assert_eq!(resolve(&KafkaOffsetSpec::Timestamp(0), 1), 100);
+
+        let offsets = KafkaOffsetSpec::Offsets(BTreeMap::from([(0, 5), (1, 50)]));
+        // Offsets before the earliest retained offset are clamped to it.
+        assert_eq!(resolve(&offsets, 0), 10);
+        assert_eq!(resolve(&offsets, 1), 50);
+        assert_eq!(resolve(&offsets, 2), 10);
+    }
+
+    #[test]
+    fn test_split_offset_range() {
+        let range = KafkaOffsetRange {
+            partition: 3,
+            start: 10,
+            end: 35,
+        };
+        assert_eq!(range.len(), 25);
+        let splits = range.split(10);
+        assert_eq!(
+            splits
+                .iter()
+                .map(|split| (split.start, split.end))
+                .collect::<Vec<_>>(),
+            vec![(10, 20), (20, 30), (30, 35)]
+        );
+        assert!(splits.iter().all(|split| split.partition == 3));
+        assert!(range.split(100) == vec![range]);
+    }
+}
@@ -0,0 +1,301 @@
+use std::{sync::Arc, time::Duration};
+
+use common_error::DaftResult;
+use common_file_formats::{KafkaSourceConfig, KafkaValueFormat};
+use daft_core::prelude::*;
+use daft_table::Table;
+use rdkafka::{
+    consumer::{BaseConsumer, Consumer},
+    Message, Offset, TopicPartitionList,
+};
+use snafu::ResultExt;
+
+use crate::{
+    client_config, AvroDecodeSnafu, InvalidAvroSchemaSnafu, KafkaOffsetRange, KafkaSnafu,
+    TimeoutSnafu, KEY_COLUMN, METADATA_COLUMNS, OFFSET_COLUMN, PARTITION_COLUMN, TIMESTAMP_COLUMN,
+};
+
+struct KafkaMessage {
+    offset: i64,
+    timestamp: Option<i64>,
+    key: Option<Vec<u8>>,
+    value: Option<Vec<u8>>,
+}
+
+/// Reads the messages in a range of offsets of a partition, or the first `limit` of them, into a
+/// table with the given schema.
+pub fn read_kafka_range(
+    config: &KafkaSourceConfig,
+    range: &KafkaOffsetRange,
+    schema: SchemaRef,
+    limit: Option<usize>,
+) -> DaftResult<Table> {
+    let messages = fetch_messages(config, range, limit)?;
+    decode_messages(config, range.partition, &messages, schema)
+}
+
+fn fetch_messages(
+    config: &KafkaSourceConfig,
+    range: &KafkaOffsetRange,
+    limit: Option<usize>,
+) -> DaftResult<Vec<KafkaMessage>> {
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut messages = vec![];
+    if range.is_empty() || limit == 0 {
+        return Ok(messages);
+    }
+
+    let consumer: BaseConsumer = client_config(config).create().context(KafkaSnafu)?;
+    let mut assignment = TopicPartitionList::new();
+    assignment
+        .add_partition_offset(&config.topic, range.partition, Offset::Offset(range.start))
+        .context(KafkaSnafu)?;
+    consumer.assign(&assignment).context(KafkaSnafu)?;
+
+    let timeout = Duration::from_millis(config.poll_timeout_ms);
+    while messages.len() < limit {
+        match consumer.poll(timeout) {
+            Some(message) => {
+                let message = message.context(KafkaSnafu)?;
+                if message.offset() >= range.end {
+                    break;
+                }
+                messages.push(KafkaMessage {
+                    offset: message.offset(),
+                    timestamp: message.timestamp().to_millis(),
+                    key: message.key().map(<[u8]>::to_vec),
+                    value: message.payload().map(<[u8]>::to_vec),
+                });
+                if message.offset() + 1 >= range.end {
+                    break;
+                }
+            }
+            // The offsets of compacted messages and transaction markers are never delivered, so
+            // the end of the range can be reached without a message at its last offset.
+            None if position(&consumer, config, range.partition)? >= range.end => break,
+            None => {
+                return Err(TimeoutSnafu {
+                    topic: config.topic.clone(),
+                    partition: range.partition,
+                    offset: messages.last().map_or(range.start, |m| m.offset + 1),
+                    timeout_ms: config.poll_timeout_ms,
+                }
+                .build()
+                .into())
+            }
+        }
+    }
+    Ok(messages)
+}
+
+/// The offset that the consumer will fetch next from a partition.
+fn position(
+    consumer: &BaseConsumer,
+    config: &KafkaSourceConfig,
+    partition: i32,
+) -> DaftResult<i64> {
+    let position = consumer.position().context(KafkaSnafu)?;
+    Ok(position
+        .find_partition(&config.topic, partition)
+        .and_then(|element| match element.offset() {
+            Offset::Offset(offset) => Some(offset),
+            _ => None,
+        })
+        .unwrap_or(-1))
+}
+
+fn decode_messages(
+    config: &KafkaSourceConfig,
+    partition: i32,
+    messages: &[KafkaMessage],
+    schema: SchemaRef,
+) -> DaftResult<Table> {
+    let value_schema = Arc::new(Schema::new(
+        schema
+            .fields
+            .values()
+            .filter(|field| !METADATA_COLUMNS.contains(&field.name.as_str()))
+            .cloned()
+            .collect(),
+    )?);
+    let mut values = match config.value_format {
+        // Messages without a value, such as tombstones, have null values in all columns.
+        KafkaValueFormat::Json => messages
+            .iter()
+            .map(|message| message.value.clone().unwrap_or_else(|| b"{}".to_vec()))
+            .collect::<Vec<_>>(),
+        KafkaValueFormat::Avro => {
+            let avro_schema =
+                apache_avro::Schema::parse_str(config.avro_schema.as_deref().unwrap_or_default())
+                    .context(InvalidAvroSchemaSnafu {
+                    topic: config.topic.clone(),
+                })?;
+            messages
+                .iter()
+                .map(|message| avro_to_json(config, partition, &avro_schema, message))
+                .collect::<DaftResult<Vec<_>>>()?
+        }
+    };
+    let values = daft_json::parse_json_records(&mut values, value_schema)?;
+
+    let columns = schema
+        .fields
+        .values()
+        .map(|field| {
+            let name = field.name.as_str();
+            let column = match name {
+                PARTITION_COLUMN => {
+                    Int32Array::from((name, vec![partition; messages.len()])).into_series()
+                }
+                OFFSET_COLUMN => Int64Array::from((
+                    name,
+                    messages.iter().map(|message| message.offset).collect(),
+                ))
+                .into_series(),
+                TIMESTAMP_COLUMN => Int64Array::from_iter(
+                    Field::new(name, DataType::Int64),
+                    messages.iter().map(|message| message.timestamp),
+                )
+                .into_series()
+                .cast(&DataType::Timestamp(TimeUnit::Milliseconds, None))?,
+                KEY_COLUMN => BinaryArray::from_iter(
+                    name,
+                    messages.iter().map(|message| message.key.as_ref()),
+                )
+                .into_series(),
+                _ => return Ok(values.get_column(name)?.clone()),
+            };
+            column.cast(&field.dtype)
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::new_with_size(schema, columns, messages.len())
+}
+
+/// Decodes an Avro value into a JSON object, so that it can be parsed into columns like a JSON
+/// value.
+fn avro_to_json(
+    config: &KafkaSourceConfig,
+    partition: i32,
+    schema: &apache_avro::Schema,
+    message: &KafkaMessage,
+) -> DaftResult<Vec<u8>> {
+    let Some(mut value) = message.value.as_deref() else {
+        return Ok(b"{}".to_vec());
+    };
+    let context = || AvroDecodeSnafu {
+        topic: config.topic.clone(),
+        partition,
+        offset: message.offset,
+    };
+    let value =
+        apache_avro::from_avro_datum(schema, &mut value, None).with_context(|_| context())?;
+    let value = serde_json::Value::try_from(value).with_context(|_| context())?;
+    Ok(serde_json::to_vec(&value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Arc};
+
+    use common_error::DaftResult;
+    use common_file_formats::{KafkaSourceConfig, KafkaValueFormat};
+    use daft_core::prelude::*;
+
+    use super::{decode_messages, KafkaMessage};
+    use crate::{KEY_COLUMN, OFFSET_COLUMN, PARTITION_COLUMN, TIMESTAMP_COLUMN};
+
+    fn config(value_format: KafkaValueFormat, avro_schema: Option<&str>) -> KafkaSourceConfig {
+        KafkaSourceConfig {
+            bootstrap_servers: "localhost:9092".to_string(),
+            topic: "events".to_string(),
+            value_format,
+            avro_schema: avro_schema.map(str::to_string),
+            consumer_options: BTreeMap::new(),
+            poll_timeout_ms: KafkaSourceConfig::DEFAULT_POLL_TIMEOUT_MS,
+        }
+    }
+
+    fn message(offset: i64, key: Option<&[u8]>, value: Option<Vec<u8>>) -> KafkaMessage {
+        KafkaMessage {
+            offset,
+            timestamp: Some(1_000 * offset),
+            key: key.map(<[u8]>::to_vec),
+            value,
+        }
+    }
+
+    fn schema(fields: Vec<Field>) -> DaftResult<SchemaRef> {
+        Ok(Arc::new(Schema::new(fields)?))
+    }
+
+    #[test]
+    fn test_decode_json_messages_with_metadata() -> DaftResult<()> {
+        let messages = vec![
+            message(5, Some(b"a"), Some(br#"{"x": 1, "y": "one"}"#.to_vec())),
+            // A tombstone.
+            message(7, Some(b"b"), None),
+        ];
+        let schema = schema(vec![
+            Field::new("x", DataType::Int64),
+            Field::new(PARTITION_COLUMN, DataType::Int32),
+            Field::new(OFFSET_COLUMN, DataType::Int64),
+            Field::new(
+                TIMESTAMP_COLUMN,
+                DataType::Timestamp(TimeUnit::Milliseconds, None),
+            ),
+            Field::new(KEY_COLUMN, DataType::Binary),
+        ])?;
+        let table = decode_messages(&config(KafkaValueFormat::Json, None), 2, &messages, schema)?;
+        assert_eq!(table.len(), 2);
+        let x = table.get_column("x")?.i64()?;
+        assert_eq!((x.get(0), x.get(1)), (Some(1), None));
+        assert_eq!(table.get_column(PARTITION_COLUMN)?.i32()?.get(1), Some(2));
+        assert_eq!(table.get_column(OFFSET_COLUMN)?.i64()?.get(1), Some(7));
+        assert_eq!(
+            table.get_column(TIMESTAMP_COLUMN)?.data_type(),
+            &DataType::Timestamp(TimeUnit::Milliseconds, None)
+        );
+        assert_eq!(
+            table.get_column(KEY_COLUMN)?.binary()?.get(1),
+            Some(b"b".as_slice())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_avro_messages() -> DaftResult<()> {
+        let avro_schema = r#"{
+            "type": "record",
+            "name": "event",
+            "fields": [
+                {"name": "x", "type": "long"},
+                {"name": "y", "type": ["null", "string"]}
+            ]
+        }"#;
+        let parsed_schema = apache_avro::Schema::parse_str(avro_schema).unwrap();
+        let value = apache_avro::types::Value::Record(vec![
+            ("x".to_string(), apache_avro::types::Value::Long(3)),
+            (
+                "y".to_string(),
+                apache_avro::types::Value::Union(
+                    1,
+                    Box::new(apache_avro::types::Value::String("three".to_string())),
+                ),
+            ),
+        ]);
+        let datum = apache_avro::to_avro_datum(&parsed_schema, value).unwrap();
+        let schema = schema(vec![
+            Field::new("x", DataType::Int64),
+            Field::new("y", DataType::Utf8),
+        ])?;
+        let table = decode_messages(
+            &config(KafkaValueFormat::Avro, Some(avro_schema)),
+            0,
+            &[message(0, None, Some(datum))],
+            schema,
+        )?;
+        assert_eq!(table.get_column("x")?.i64()?.get(0), Some(3));
+        assert_eq!(table.get_column("y")?.utf8()?.get(0), Some("three"));
+        Ok(())
+    }
+}
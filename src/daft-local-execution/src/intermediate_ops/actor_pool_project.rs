@@ -31,7 +31,7 @@ struct ActorHandle {
 }
 
 impl ActorHandle {
-    fn try_new(projection: &[ExprRef]) -> DaftResult<Self> {
+    fn try_new(projection: &[ExprRef], use_shared_memory: bool) -> DaftResult<Self> {
         #[cfg(feature = "python")]
         {
             let handle = Python::with_gil(|py| {
@@ -39,10 +39,13 @@ impl ActorHandle {
                 Ok::<PyObject, PyErr>(
                     py.import_bound(pyo3::intern!(py, "daft.execution.actor_pool_udf"))?
                         .getattr(pyo3::intern!(py, "ActorHandle"))?
-                        .call1((projection
-                            .iter()
-                            .map(|expr| PyExpr::from(expr.clone()))
-                            .collect::<Vec<_>>(),))?
+                        .call1((
+                            projection
+                                .iter()
+                                .map(|expr| PyExpr::from(expr.clone()))
+                                .collect::<Vec<_>>(),
+                            use_shared_memory,
+                        ))?
                         .unbind(),
                 )
             })?;
@@ -126,10 +129,11 @@ pub struct ActorPoolProjectOperator {
     projection: Vec<ExprRef>,
     concurrency: usize,
     batch_size: Option<usize>,
+    use_shared_memory: bool,
 }
 
 impl ActorPoolProjectOperator {
-    pub fn new(projection: Vec<ExprRef>) -> Self {
+    pub fn new(projection: Vec<ExprRef>, use_shared_memory: bool) -> Self {
         let num_actor_pool_udfs: usize = count_actor_pool_udfs(&projection);
 
         assert_eq!(
@@ -144,6 +148,7 @@ impl ActorPoolProjectOperator {
             projection,
             concurrency,
             batch_size,
+            use_shared_memory,
         }
     }
 }
@@ -177,7 +182,7 @@ impl IntermediateOperator for ActorPoolProjectOperator {
     fn make_state(&self) -> DaftResult<Box<dyn IntermediateOpState>> {
         // TODO: Pass relevant CUDA_VISIBLE_DEVICES to the actor
         Ok(Box::new(ActorPoolProjectState {
-            actor_handle: ActorHandle::try_new(&self.projection)?,
+            actor_handle: ActorHandle::try_new(&self.projection, self.use_shared_memory)?,
         }))
     }
 
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use common_display::tree::TreeDisplay;
 use common_error::DaftResult;
+use common_resource_request::ResourceRequest;
 use common_runtime::{get_compute_runtime, RuntimeRef};
 use daft_micropartition::MicroPartition;
 use snafu::ResultExt;
@@ -15,6 +16,7 @@ use crate::{
     dispatcher::{DispatchSpawner, RoundRobinDispatcher, UnorderedDispatcher},
     pipeline::PipelineNode,
     progress_bar::ProgressBarColor,
+    resource_manager::ResourcePools,
     runtime_stats::{CountingReceiver, CountingSender, RuntimeStatsContext},
     ExecutionRuntimeContext, OperatorOutput, PipelineExecutionSnafu, NUM_CPUS,
 };
@@ -54,6 +56,12 @@ pub trait IntermediateOperator: Send + Sync {
     fn max_concurrency(&self) -> DaftResult<usize> {
         Ok(*NUM_CPUS)
     }
+    /// The CPUs and GPUs that each morsel of this operator needs while it executes.
+    /// Operators with a resource request share the executor's resource pools with each other,
+    /// so e.g. only as many GPU UDF morsels run at once as there are GPUs.
+    fn resource_request(&self) -> Option<ResourceRequest> {
+        None
+    }
 
     fn dispatch_spawner(
         &self,
@@ -109,17 +117,24 @@ impl IntermediateNode {
         receiver: Receiver<Arc<MicroPartition>>,
         sender: Sender<Arc<MicroPartition>>,
         rt_context: Arc<RuntimeStatsContext>,
+        resource_pools: Arc<ResourcePools>,
     ) -> DaftResult<()> {
         let span = info_span!("IntermediateOp::execute");
         let compute_runtime = get_compute_runtime();
+        let resource_request = op.resource_request();
         let mut state = op.make_state()?;
         while let Some(morsel) = receiver.recv().await {
             loop {
+                let _permit = match &resource_request {
+                    Some(request) => Some(resource_pools.acquire(request).await?),
+                    None => None,
+                };
                 let result = rt_context
                     .in_span(&span, || {
                         op.execute(morsel.clone(), state, &compute_runtime)
                     })
-                    .await??;
+                    .await?
+                    .map_err(|e| e.context(format!("in {}", op.name())))?;
                 state = result.0;
                 match result.1 {
                     IntermediateOperatorResult::NeedMoreInput(Some(mp)) => {
@@ -157,6 +172,7 @@ impl IntermediateNode {
                     input_receiver,
                     output_sender,
                     self.runtime_stats.clone(),
+                    runtime_handle.resource_pools().clone(),
                 ),
                 self.intermediate_op.name(),
             );
@@ -217,9 +233,20 @@ impl PipelineNode for IntermediateNode {
             ));
         }
         let op = self.intermediate_op.clone();
-        let num_workers = op.max_concurrency().context(PipelineExecutionSnafu {
+        let mut num_workers = op.max_concurrency().context(PipelineExecutionSnafu {
             node_name: self.name(),
         })?;
+        if let Some(request) = op.resource_request() {
+            num_workers = num_workers.min(
+                runtime_handle
+                    .resource_pools()
+                    .max_concurrency(&request)
+                    .context(PipelineExecutionSnafu {
+                        node_name: self.name(),
+                    })?,
+            );
+        }
+        let num_workers = runtime_handle.num_workers(num_workers);
         let (destination_sender, destination_receiver) = create_channel(1);
         let counting_sender =
             CountingSender::new(destination_sender, self.runtime_stats.clone(), progress_bar);
@@ -6,5 +6,7 @@ pub mod filter;
 pub mod inner_hash_join_probe;
 pub mod intermediate_op;
 pub mod project;
+pub mod range_join_probe;
 pub mod sample;
+pub mod sort_merge_join_probe;
 pub mod unpivot;
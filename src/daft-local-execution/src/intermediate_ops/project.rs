@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use common_error::{DaftError, DaftResult};
+use common_resource_request::ResourceRequest;
 use common_runtime::RuntimeRef;
 use daft_dsl::{functions::python::get_resource_request, ExprRef};
 use daft_micropartition::MicroPartition;
@@ -71,4 +72,8 @@ impl IntermediateOperator for ProjectOperator {
             _ => Ok(*NUM_CPUS),
         }
     }
+
+    fn resource_request(&self) -> Option<ResourceRequest> {
+        get_resource_request(&self.projection)
+    }
 }
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_runtime::RuntimeRef;
+use daft_core::{
+    prelude::{SchemaRef, UInt64Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::ExprRef;
+use daft_local_plan::RangeBound;
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+use tracing::{info_span, instrument, Instrument};
+
+use super::intermediate_op::{
+    IntermediateOpExecuteResult, IntermediateOpState, IntermediateOperator,
+    IntermediateOperatorResult,
+};
+use crate::{sinks::range_join_build::RangeJoinBuildSide, state_bridge::BroadcastStateBridgeRef};
+
+struct RangeJoinProbeState {
+    bridge: BroadcastStateBridgeRef<RangeJoinBuildSide>,
+}
+
+impl IntermediateOpState for RangeJoinProbeState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// The number of keys of the right side that are less than each of `bounds`, or at most each of
+/// them if `or_equal` is set.
+fn count_keys_below(
+    right: &RangeJoinBuildSide,
+    bounds: &Series,
+    or_equal: bool,
+) -> DaftResult<Vec<u64>> {
+    if or_equal {
+        // Searching the ascending keys finds the position after the keys equal to the bound.
+        Ok(right
+            .key
+            .search_sorted(bounds, false)?
+            .as_arrow()
+            .values()
+            .to_vec())
+    } else {
+        // Searching the descending keys finds the number of keys that are at least the bound.
+        let num_keys = right.key.len() as u64;
+        Ok(right
+            .descending_key
+            .search_sorted(bounds, true)?
+            .as_arrow()
+            .values()
+            .iter()
+            .map(|at_least| num_keys - at_least)
+            .collect())
+    }
+}
+
+/// Joins each row of `left` with the rows of the right side whose keys lie within its bounds.
+fn range_join(
+    left: &Table,
+    right: &RangeJoinBuildSide,
+    lower_bound: Option<&RangeBound>,
+    upper_bound: Option<&RangeBound>,
+) -> DaftResult<Table> {
+    let num_keys = right.key.len() as u64;
+    let mut starts = vec![0; left.len()];
+    let mut ends = vec![num_keys; left.len()];
+    // A null bound compares with no key, which leaves the row without matches.
+    if let Some(bound) = lower_bound {
+        let bounds = left.eval_expression_list(&[bound.left_on.clone()])?;
+        let bounds = bounds.get_column_by_index(0)?;
+        let counts = count_keys_below(right, bounds, !bound.inclusive)?;
+        for (idx, (start, count)) in starts.iter_mut().zip(counts).enumerate() {
+            *start = if bounds.is_valid(idx) {
+                count
+            } else {
+                num_keys
+            };
+        }
+    }
+    if let Some(bound) = upper_bound {
+        let bounds = left.eval_expression_list(&[bound.left_on.clone()])?;
+        let bounds = bounds.get_column_by_index(0)?;
+        let counts = count_keys_below(right, bounds, bound.inclusive)?;
+        for (idx, (end, count)) in ends.iter_mut().zip(counts).enumerate() {
+            *end = if bounds.is_valid(idx) { count } else { 0 };
+        }
+    }
+
+    let (left_indices, right_indices): (Vec<u64>, Vec<u64>) = starts
+        .into_iter()
+        .zip(ends)
+        .enumerate()
+        .flat_map(|(idx, (start, end))| (start..end).map(move |right_idx| (idx as u64, right_idx)))
+        .unzip();
+    let left = left.take(&UInt64Array::from(("left_indices", left_indices)).into_series())?;
+    let right = right
+        .table
+        .take(&UInt64Array::from(("right_indices", right_indices)).into_series())?;
+    left.union(&right)
+}
+
+/// RangeJoinProbeOperator joins each morsel of the left side of a range join with the rows of the
+/// sorted right side collected by the `RangeJoinBuildSink` whose keys lie within its bounds, then
+/// filters the joined rows by the rest of the join predicate.
+pub struct RangeJoinProbeOperator {
+    lower_bound: Option<RangeBound>,
+    upper_bound: Option<RangeBound>,
+    residual_predicate: Option<ExprRef>,
+    output_schema: SchemaRef,
+    state_bridge: BroadcastStateBridgeRef<RangeJoinBuildSide>,
+}
+
+impl RangeJoinProbeOperator {
+    pub(crate) fn new(
+        lower_bound: Option<RangeBound>,
+        upper_bound: Option<RangeBound>,
+        residual_predicate: Option<ExprRef>,
+        output_schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<RangeJoinBuildSide>,
+    ) -> Self {
+        Self {
+            lower_bound,
+            upper_bound,
+            residual_predicate,
+            output_schema,
+            state_bridge,
+        }
+    }
+}
+
+impl IntermediateOperator for RangeJoinProbeOperator {
+    #[instrument(skip_all, name = "RangeJoinProbeOperator::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn IntermediateOpState>,
+        runtime: &RuntimeRef,
+    ) -> IntermediateOpExecuteResult {
+        let lower_bound = self.lower_bound.clone();
+        let upper_bound = self.upper_bound.clone();
+        let residual_predicate = self.residual_predicate.clone();
+        let output_schema = self.output_schema.clone();
+        runtime
+            .spawn(
+                async move {
+                    let right = state
+                        .as_any_mut()
+                        .downcast_mut::<RangeJoinProbeState>()
+                        .expect("RangeJoinProbeState should be used with RangeJoinProbeOperator")
+                        .bridge
+                        .get_state()
+                        .await;
+                    let joined_tables = input
+                        .get_tables()?
+                        .iter()
+                        .map(|left| {
+                            range_join(left, &right, lower_bound.as_ref(), upper_bound.as_ref())
+                        })
+                        .collect::<DaftResult<Vec<_>>>()?;
+                    let joined =
+                        MicroPartition::new_loaded(output_schema, Arc::new(joined_tables), None);
+                    let output = match residual_predicate {
+                        Some(predicate) => joined.filter(&[predicate])?,
+                        None => joined,
+                    };
+                    Ok((
+                        state,
+                        IntermediateOperatorResult::NeedMoreInput(Some(Arc::new(output))),
+                    ))
+                }
+                .instrument(info_span!("RangeJoinProbeOperator::execute")),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "RangeJoinProbeOperator"
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn IntermediateOpState>> {
+        Ok(Box::new(RangeJoinProbeState {
+            bridge: self.state_bridge.clone(),
+        }))
+    }
+}
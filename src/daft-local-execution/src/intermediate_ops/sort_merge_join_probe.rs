@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_runtime::RuntimeRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use tracing::{info_span, instrument, Instrument};
+
+use super::intermediate_op::{
+    IntermediateOpExecuteResult, IntermediateOpState, IntermediateOperator,
+    IntermediateOperatorResult,
+};
+use crate::state_bridge::BroadcastStateBridgeRef;
+
+struct SortMergeJoinProbeState {
+    bridge: BroadcastStateBridgeRef<MicroPartition>,
+}
+
+impl IntermediateOpState for SortMergeJoinProbeState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// SortMergeJoinProbeOperator merges each morsel of the left side of a sort-merge join with the
+/// sorted right side collected by the `SortMergeJoinBuildSink`. Since the join is an inner join,
+/// the morsels can be merged independently of each other, each sorted by itself.
+pub struct SortMergeJoinProbeOperator {
+    left_on: Arc<Vec<ExprRef>>,
+    right_on: Arc<Vec<ExprRef>>,
+    state_bridge: BroadcastStateBridgeRef<MicroPartition>,
+}
+
+impl SortMergeJoinProbeOperator {
+    pub(crate) fn new(
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        state_bridge: BroadcastStateBridgeRef<MicroPartition>,
+    ) -> Self {
+        Self {
+            left_on: Arc::new(left_on),
+            right_on: Arc::new(right_on),
+            state_bridge,
+        }
+    }
+}
+
+impl IntermediateOperator for SortMergeJoinProbeOperator {
+    #[instrument(skip_all, name = "SortMergeJoinProbeOperator::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn IntermediateOpState>,
+        runtime: &RuntimeRef,
+    ) -> IntermediateOpExecuteResult {
+        let left_on = self.left_on.clone();
+        let right_on = self.right_on.clone();
+        runtime
+            .spawn(
+                async move {
+                    let right = state
+                        .as_any_mut()
+                        .downcast_mut::<SortMergeJoinProbeState>()
+                        .expect("SortMergeJoinProbeState should be used with SortMergeJoinProbeOperator")
+                        .bridge
+                        .get_state()
+                        .await;
+                    // Morsels of a sorted input may be buffered out of order, so each one is sorted
+                    // before it is merged.
+                    let ascending = vec![false; left_on.len()];
+                    let left = input.sort(&left_on, &ascending, &ascending)?;
+                    let joined = left.sort_merge_join(&right, &left_on, &right_on, true)?;
+                    Ok((
+                        state,
+                        IntermediateOperatorResult::NeedMoreInput(Some(Arc::new(joined))),
+                    ))
+                }
+                .instrument(info_span!("SortMergeJoinProbeOperator::execute")),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "SortMergeJoinProbeOperator"
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn IntermediateOpState>> {
+        Ok(Box::new(SortMergeJoinProbeState {
+            bridge: self.state_bridge.clone(),
+        }))
+    }
+}
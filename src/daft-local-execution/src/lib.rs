@@ -7,11 +7,16 @@ mod dispatcher;
 mod intermediate_ops;
 mod pipeline;
 mod progress_bar;
+mod resource_manager;
 mod run;
+mod runner;
 mod runtime_stats;
 mod sinks;
 mod sources;
 mod state_bridge;
+mod state_store;
+#[cfg(test)]
+mod test;
 
 use std::{
     future::Future,
@@ -22,9 +27,12 @@ use std::{
 
 use common_error::{DaftError, DaftResult};
 use common_runtime::RuntimeTask;
+use daft_shuffles::QueryTempDir;
 use lazy_static::lazy_static;
 use progress_bar::{OperatorProgressBar, ProgressBarColor, ProgressBarManager};
-pub use run::{run_local, ExecutionEngineResult, NativeExecutor};
+use resource_manager::ResourcePools;
+pub use run::{run_local, ExecutionEngineResult, NativeExecutor, ResultOrdering, StreamingOptions};
+pub use runner::LocalRunner;
 use runtime_stats::RuntimeStatsContext;
 use snafu::{futures::TryFutureExt, ResultExt, Snafu};
 
@@ -121,6 +129,9 @@ pub struct ExecutionRuntimeContext {
     worker_set: TaskSet<crate::Result<()>>,
     default_morsel_size: usize,
     progress_bar_manager: Option<Box<dyn ProgressBarManager>>,
+    resource_pools: Arc<ResourcePools>,
+    temp_dir: Arc<QueryTempDir>,
+    parallelism: Option<usize>,
 }
 
 impl ExecutionRuntimeContext {
@@ -128,11 +139,18 @@ impl ExecutionRuntimeContext {
     pub fn new(
         default_morsel_size: usize,
         progress_bar_manager: Option<Box<dyn ProgressBarManager>>,
+        num_gpus: Option<usize>,
+        temp_dir: Arc<QueryTempDir>,
+        parallelism: Option<usize>,
     ) -> Self {
         Self {
             worker_set: TaskSet::new(),
             default_morsel_size,
             progress_bar_manager,
+            // The native runner resolves unset GPU counts from the visible CUDA devices
+            resource_pools: Arc::new(ResourcePools::new(num_gpus.unwrap_or(0))),
+            temp_dir,
+            parallelism,
         }
     }
     pub fn spawn(
@@ -158,6 +176,25 @@ impl ExecutionRuntimeContext {
         self.default_morsel_size
     }
 
+    /// The number of workers that an operator able to process `max_concurrency` morsels at once
+    /// runs, limited by the parallelism of the query
+    pub(crate) fn num_workers(&self, max_concurrency: usize) -> usize {
+        self.parallelism.map_or(max_concurrency, |parallelism| {
+            max_concurrency.min(parallelism)
+        })
+    }
+
+    pub(crate) fn resource_pools(&self) -> &Arc<ResourcePools> {
+        &self.resource_pools
+    }
+
+    /// The temp directory of the query, which is removed along with the spill files of its
+    /// operators once the query ends
+    #[must_use]
+    pub fn temp_dir(&self) -> &Arc<QueryTempDir> {
+        &self.temp_dir
+    }
+
     pub fn make_progress_bar(
         &self,
         prefix: &str,
@@ -1,4 +1,8 @@
-use std::sync::Arc;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
 
 use common_daft_config::DaftExecutionConfig;
 use common_display::{mermaid::MermaidDisplayVisitor, tree::TreeDisplay};
@@ -10,19 +14,22 @@ use daft_core::{
     prelude::{Schema, SchemaRef},
     utils::supertype,
 };
-use daft_dsl::{col, join::get_common_join_keys};
+use daft_dsl::{col, join::get_common_join_keys, ExprRef};
 use daft_local_plan::{
-    ActorPoolProject, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate, HashJoin,
-    InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, PhysicalWrite, Pivot,
-    Project, Sample, Sort, UnGroupedAggregate, Unpivot,
+    ActorPoolProject, AssertUnique, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate,
+    HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, PhysicalWrite,
+    Pivot, Project, RangeJoin, Repartition, Sample, Sort, SortMergeJoin, StreamScan, TopN,
+    UnGroupedAggregate, Unpivot, Window, WindowAggregate,
 };
 use daft_logical_plan::{stats::StatsState, JoinType};
 use daft_micropartition::{
-    partitioning::{MicroPartitionSet, PartitionSetCache},
+    partitioning::{MicroPartitionSet, PartitionSetCache, PartitionSetRef},
     MicroPartition, MicroPartitionRef,
 };
 use daft_scan::ScanTaskRef;
-use daft_writers::make_physical_writer_factory;
+use daft_shuffles::QueryTempDir;
+use daft_table::ProbeState;
+use daft_writers::{make_manifest_writer_factory, make_physical_writer_factory, prepare_write};
 use indexmap::IndexSet;
 use snafu::ResultExt;
 
@@ -33,10 +40,12 @@ use crate::{
         anti_semi_hash_join_probe::AntiSemiProbeOperator, cross_join::CrossJoinOperator,
         explode::ExplodeOperator, filter::FilterOperator,
         inner_hash_join_probe::InnerHashJoinProbeOperator, intermediate_op::IntermediateNode,
-        project::ProjectOperator, sample::SampleOperator, unpivot::UnpivotOperator,
+        project::ProjectOperator, range_join_probe::RangeJoinProbeOperator, sample::SampleOperator,
+        sort_merge_join_probe::SortMergeJoinProbeOperator, unpivot::UnpivotOperator,
     },
     sinks::{
         aggregate::AggregateSink,
+        assert_unique::AssertUniqueSink,
         blocking_sink::BlockingSinkNode,
         concat::ConcatSink,
         cross_join_collect::CrossJoinCollectSink,
@@ -46,12 +55,19 @@ use crate::{
         monotonically_increasing_id::MonotonicallyIncreasingIdSink,
         outer_hash_join_probe::OuterHashJoinProbeSink,
         pivot::PivotSink,
+        range_join_build::RangeJoinBuildSink,
+        repartition::RepartitionSink,
         sort::SortSink,
+        sort_merge_join_build::SortMergeJoinBuildSink,
         streaming_sink::StreamingSinkNode,
+        top_n::TopNSink,
+        window::WindowSink,
+        window_aggregate::WindowAggregateSink,
         write::{WriteFormat, WriteSink},
     },
     sources::{empty_scan::EmptyScanSource, in_memory::InMemorySource},
-    state_bridge::BroadcastStateBridge,
+    state_bridge::{BroadcastStateBridge, BroadcastStateBridgeRef},
+    state_store::StateStoreConfig,
     ExecutionRuntimeContext, PipelineCreationSnafu,
 };
 
@@ -79,10 +95,58 @@ pub fn viz_pipeline(root: &dyn PipelineNode) -> String {
     output
 }
 
+/// Identifies the probe table that a hash join builds, so that joins with the same build side
+/// can share it, e.g. joins with a dimension table or several `IN` subqueries of the same table,
+/// which are planned as semi joins.
+#[derive(PartialEq, Eq, Hash)]
+struct ProbeTableKey {
+    /// Cache id of the build side, see [`daft_local_plan::HashJoin::left_cache_id`].
+    build_cache_id: usize,
+    build_on: Vec<ExprRef>,
+    null_equals_null: Option<Vec<bool>>,
+    /// Whether the probe table tracks the indices of the build rows, which semi and anti joins
+    /// don't need.
+    track_indices: bool,
+}
+
+/// Probe tables of small build sides that are shared by the joins of a pipeline, which are built
+/// once and handed to every join that probes them.
+type BroadcastCache = HashMap<ProbeTableKey, BroadcastStateBridgeRef<ProbeState>>;
+
+/// Whether the build side of a join is small enough to keep its probe table around for other
+/// joins with the same build side.
+fn is_broadcastable(build_plan: &LocalPhysicalPlan, cfg: &DaftExecutionConfig) -> bool {
+    match build_plan.get_stats_state() {
+        StatsState::Materialized(stats) => stats
+            .approx_stats
+            .upper_bound_bytes
+            .is_some_and(|size| size <= cfg.broadcast_join_size_bytes_threshold),
+        StatsState::NotMaterialized => false,
+    }
+}
+
+/// Builds the pipeline of `physical_plan`, whose operators spill to the query's `temp_dir`.
 pub fn physical_plan_to_pipeline(
     physical_plan: &LocalPhysicalPlan,
     psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
     cfg: &Arc<DaftExecutionConfig>,
+    temp_dir: &Arc<QueryTempDir>,
+) -> crate::Result<Box<dyn PipelineNode>> {
+    build_pipeline(
+        physical_plan,
+        psets,
+        cfg,
+        temp_dir,
+        &mut BroadcastCache::new(),
+    )
+}
+
+fn build_pipeline(
+    physical_plan: &LocalPhysicalPlan,
+    psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
+    cfg: &Arc<DaftExecutionConfig>,
+    temp_dir: &Arc<QueryTempDir>,
+    broadcasts: &mut BroadcastCache,
 ) -> crate::Result<Box<dyn PipelineNode>> {
     use daft_local_plan::PhysicalScan;
 
@@ -112,24 +176,47 @@ pub fn physical_plan_to_pipeline(
 
             let materialized_pset = psets
                 .get_partition_set(&cache_key)
+                .or_else(|| {
+                    MicroPartitionSet::from_cache_entry(&info.cache_entry)
+                        .map(|pset| pset as PartitionSetRef<MicroPartitionRef>)
+                })
                 .unwrap_or_else(|| panic!("Cache key not found: {:?}", info.cache_key));
 
             InMemorySource::new(materialized_pset, info.source_schema.clone())
                 .arced()
                 .into()
         }
+        LocalPhysicalPlan::StreamScan(StreamScan { info, .. }) => {
+            #[cfg(feature = "python")]
+            {
+                crate::sources::stream::StreamSource::new(info.clone())
+                    .arced()
+                    .into()
+            }
+            #[cfg(not(feature = "python"))]
+            {
+                Err(common_error::DaftError::not_implemented(format!(
+                    "Cannot read stream {} without compiling for Python",
+                    info.name
+                )))
+                .with_context(|_| PipelineCreationSnafu {
+                    plan_name: physical_plan.name(),
+                })?
+            }
+        }
         LocalPhysicalPlan::Project(Project {
             input, projection, ..
         }) => {
             let proj_op = ProjectOperator::new(projection.clone());
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             IntermediateNode::new(Arc::new(proj_op), vec![child_node]).boxed()
         }
         LocalPhysicalPlan::ActorPoolProject(ActorPoolProject {
             input, projection, ..
         }) => {
-            let proj_op = ActorPoolProjectOperator::new(projection.clone());
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let proj_op =
+                ActorPoolProjectOperator::new(projection.clone(), cfg.udf_shared_memory_transport);
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             IntermediateNode::new(Arc::new(proj_op), vec![child_node]).boxed()
         }
         LocalPhysicalPlan::Sample(Sample {
@@ -140,33 +227,33 @@ pub fn physical_plan_to_pipeline(
             ..
         }) => {
             let sample_op = SampleOperator::new(*fraction, *with_replacement, *seed);
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             IntermediateNode::new(Arc::new(sample_op), vec![child_node]).boxed()
         }
         LocalPhysicalPlan::Filter(Filter {
             input, predicate, ..
         }) => {
             let filter_op = FilterOperator::new(predicate.clone());
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             IntermediateNode::new(Arc::new(filter_op), vec![child_node]).boxed()
         }
         LocalPhysicalPlan::Explode(Explode {
             input, to_explode, ..
         }) => {
             let explode_op = ExplodeOperator::new(to_explode.clone());
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             IntermediateNode::new(Arc::new(explode_op), vec![child_node]).boxed()
         }
         LocalPhysicalPlan::Limit(Limit {
             input, num_rows, ..
         }) => {
             let sink = LimitSink::new(*num_rows as usize);
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             StreamingSinkNode::new(Arc::new(sink), vec![child_node]).boxed()
         }
         LocalPhysicalPlan::Concat(Concat { input, other, .. }) => {
-            let left_child = physical_plan_to_pipeline(input, psets, cfg)?;
-            let right_child = physical_plan_to_pipeline(other, psets, cfg)?;
+            let left_child = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
+            let right_child = build_pipeline(other, psets, cfg, temp_dir, broadcasts)?;
             let sink = ConcatSink {};
             StreamingSinkNode::new(Arc::new(sink), vec![left_child, right_child]).boxed()
         }
@@ -176,7 +263,7 @@ pub fn physical_plan_to_pipeline(
             schema,
             ..
         }) => {
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             let agg_sink = AggregateSink::new(aggregations, schema).with_context(|_| {
                 PipelineCreationSnafu {
                     plan_name: physical_plan.name(),
@@ -191,13 +278,80 @@ pub fn physical_plan_to_pipeline(
             schema,
             ..
         }) => {
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             let agg_sink = GroupedAggregateSink::new(aggregations, group_by, schema, cfg)
                 .with_context(|_| PipelineCreationSnafu {
                     plan_name: physical_plan.name(),
                 })?;
             BlockingSinkNode::new(Arc::new(agg_sink), child_node).boxed()
         }
+        LocalPhysicalPlan::WindowAggregate(WindowAggregate {
+            input,
+            aggregations,
+            group_by,
+            event_time_column,
+            window_size,
+            window_slide,
+            watermark_delay,
+            schema,
+            ..
+        }) => {
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
+            // The state of the window aggregation is checkpointed under a hash of its parameters,
+            // which is the same across runs of the query
+            let mut hasher = DefaultHasher::new();
+            (
+                aggregations,
+                group_by,
+                event_time_column,
+                window_size,
+                window_slide,
+                watermark_delay,
+                schema,
+            )
+                .hash(&mut hasher);
+            let operator_key = format!("window-aggregate-{:016x}", hasher.finish());
+            let window_aggregate_sink = StateStoreConfig::try_new(cfg, &operator_key)
+                .and_then(|state_store_config| {
+                    WindowAggregateSink::new(
+                        aggregations,
+                        group_by,
+                        event_time_column,
+                        *window_size,
+                        *window_slide,
+                        *watermark_delay,
+                        schema,
+                        state_store_config,
+                    )
+                })
+                .with_context(|_| PipelineCreationSnafu {
+                    plan_name: physical_plan.name(),
+                })?;
+            StreamingSinkNode::new(Arc::new(window_aggregate_sink), vec![child_node]).boxed()
+        }
+        LocalPhysicalPlan::Window(Window {
+            input,
+            partition_by,
+            order_by,
+            descending,
+            nulls_first,
+            functions,
+            names,
+            schema,
+            ..
+        }) => {
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
+            let window_sink = WindowSink::new(
+                partition_by.clone(),
+                order_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                functions.clone(),
+                names.clone(),
+                schema.clone(),
+            );
+            BlockingSinkNode::new(Arc::new(window_sink), child_node).boxed()
+        }
         LocalPhysicalPlan::Unpivot(Unpivot {
             input,
             ids,
@@ -206,7 +360,7 @@ pub fn physical_plan_to_pipeline(
             value_name,
             ..
         }) => {
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             let unpivot_op = UnpivotOperator::new(
                 ids.clone(),
                 values.clone(),
@@ -224,13 +378,14 @@ pub fn physical_plan_to_pipeline(
             names,
             ..
         }) => {
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             let pivot_sink = PivotSink::new(
                 group_by.clone(),
                 pivot_column.clone(),
                 value_column.clone(),
                 aggregation.clone(),
                 names.clone(),
+                cfg.compress_buffered_partitions,
             );
             BlockingSinkNode::new(Arc::new(pivot_sink), child_node).boxed()
         }
@@ -241,22 +396,93 @@ pub fn physical_plan_to_pipeline(
             nulls_first,
             ..
         }) => {
-            let sort_sink = SortSink::new(sort_by.clone(), descending.clone(), nulls_first.clone());
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            // Python objects can't be written to spill files, so sorts of them stay in memory.
+            let memory_limit_bytes = if input
+                .schema()
+                .fields
+                .values()
+                .any(|field| field.dtype.is_python())
+            {
+                usize::MAX
+            } else {
+                cfg.sort_memory_limit_bytes
+            };
+            let sort_sink = SortSink::new(
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                cfg.compress_buffered_partitions,
+                memory_limit_bytes,
+                temp_dir.clone(),
+                cfg.default_morsel_size,
+            );
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             BlockingSinkNode::new(Arc::new(sort_sink), child_node).boxed()
         }
+        LocalPhysicalPlan::TopN(TopN {
+            input,
+            sort_by,
+            descending,
+            nulls_first,
+            limit,
+            schema,
+            ..
+        }) => {
+            let top_n_sink = TopNSink::new(
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                *limit as usize,
+                schema.clone(),
+            );
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
+            BlockingSinkNode::new(Arc::new(top_n_sink), child_node).boxed()
+        }
+        LocalPhysicalPlan::Repartition(Repartition {
+            input,
+            repartition_spec,
+            num_partitions,
+            ..
+        }) => {
+            // Python objects can't be written to spill files, so repartitions of them stay in memory.
+            let memory_limit_bytes = if input
+                .schema()
+                .fields
+                .values()
+                .any(|field| field.dtype.is_python())
+            {
+                usize::MAX
+            } else {
+                cfg.repartition_memory_limit_bytes
+            };
+            let repartition_sink = RepartitionSink::new(
+                repartition_spec.clone(),
+                *num_partitions,
+                input.schema().clone(),
+                cfg.compress_buffered_partitions,
+                memory_limit_bytes,
+                temp_dir.clone(),
+            );
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
+            BlockingSinkNode::new(Arc::new(repartition_sink), child_node).boxed()
+        }
         LocalPhysicalPlan::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
             input,
             column_name,
             schema,
             ..
         }) => {
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             let monotonically_increasing_id_sink =
                 MonotonicallyIncreasingIdSink::new(column_name.clone(), schema.clone());
             StreamingSinkNode::new(Arc::new(monotonically_increasing_id_sink), vec![child_node])
                 .boxed()
         }
+        LocalPhysicalPlan::AssertUnique(AssertUnique { input, keys, .. }) => {
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
+            let assert_unique_sink = AssertUniqueSink::new(keys.clone());
+            StreamingSinkNode::new(Arc::new(assert_unique_sink), vec![child_node]).boxed()
+        }
         LocalPhysicalPlan::HashJoin(HashJoin {
             left,
             right,
@@ -264,6 +490,8 @@ pub fn physical_plan_to_pipeline(
             right_on,
             null_equals_null,
             join_type,
+            left_cache_id,
+            right_cache_id,
             schema,
             ..
         }) => {
@@ -351,9 +579,10 @@ pub fn physical_plan_to_pipeline(
                 // Anti and semi joins always build on the right
                 JoinType::Anti | JoinType::Semi => false,
             };
-            let (build_on, probe_on, build_child, probe_child) = match build_on_left {
-                true => (left_on, right_on, left, right),
-                false => (right_on, left_on, right, left),
+            let (build_on, probe_on, build_child, probe_child, build_cache_id) = match build_on_left
+            {
+                true => (left_on, right_on, left, right, *left_cache_id),
+                false => (right_on, left_on, right, left, *right_cache_id),
             };
 
             let build_schema = build_child.schema();
@@ -393,20 +622,48 @@ pub fn physical_plan_to_pipeline(
                     .zip(key_schema.fields.values())
                     .map(|(e, f)| e.clone().cast(&f.dtype))
                     .collect::<Vec<_>>();
-                // we should move to a builder pattern
-                let probe_state_bridge = BroadcastStateBridge::new();
-                let build_sink = HashJoinBuildSink::new(
-                    key_schema,
-                    casted_build_on,
-                    null_equals_null.clone(),
-                    join_type,
-                    probe_state_bridge.clone(),
-                )?;
-                let build_child_node = physical_plan_to_pipeline(build_child, psets, cfg)?;
-                let build_node =
-                    BlockingSinkNode::new(Arc::new(build_sink), build_child_node).boxed();
-
-                let probe_child_node = physical_plan_to_pipeline(probe_child, psets, cfg)?;
+                // Joins with the same small build side probe a single probe table, which is only
+                // built by the first of them.
+                let probe_table_key = build_cache_id
+                    .filter(|_| is_broadcastable(build_child, cfg))
+                    .map(|build_cache_id| ProbeTableKey {
+                        build_cache_id,
+                        build_on: casted_build_on.clone(),
+                        null_equals_null: null_equals_null.clone(),
+                        track_indices: !matches!(join_type, JoinType::Anti | JoinType::Semi),
+                    });
+                let shared_probe_state_bridge = probe_table_key
+                    .as_ref()
+                    .and_then(|key| broadcasts.get(key).cloned());
+                let (probe_state_bridge, build_node) = match shared_probe_state_bridge {
+                    Some(probe_state_bridge) => (probe_state_bridge, None),
+                    None => {
+                        // we should move to a builder pattern
+                        let probe_state_bridge = BroadcastStateBridge::new();
+                        let build_sink = HashJoinBuildSink::new(
+                            key_schema,
+                            casted_build_on,
+                            null_equals_null.clone(),
+                            join_type,
+                            probe_state_bridge.clone(),
+                        )?;
+                        let build_child_node =
+                            build_pipeline(build_child, psets, cfg, temp_dir, broadcasts)?;
+                        if let Some(key) = probe_table_key {
+                            broadcasts.insert(key, probe_state_bridge.clone());
+                        }
+                        let build_node =
+                            BlockingSinkNode::new(Arc::new(build_sink), build_child_node).boxed();
+                        (probe_state_bridge, Some(build_node))
+                    }
+                };
+
+                let probe_child_node =
+                    build_pipeline(probe_child, psets, cfg, temp_dir, broadcasts)?;
+                let children = build_node
+                    .into_iter()
+                    .chain(std::iter::once(probe_child_node))
+                    .collect::<Vec<_>>();
 
                 match join_type {
                     JoinType::Anti | JoinType::Semi => Ok(IntermediateNode::new(
@@ -416,7 +673,7 @@ pub fn physical_plan_to_pipeline(
                             schema,
                             probe_state_bridge,
                         )),
-                        vec![build_node, probe_child_node],
+                        children,
                     )
                     .boxed()),
                     JoinType::Inner => Ok(IntermediateNode::new(
@@ -429,7 +686,7 @@ pub fn physical_plan_to_pipeline(
                             schema,
                             probe_state_bridge,
                         )),
-                        vec![build_node, probe_child_node],
+                        children,
                     )
                     .boxed()),
                     JoinType::Left | JoinType::Right | JoinType::Outer => {
@@ -444,7 +701,7 @@ pub fn physical_plan_to_pipeline(
                                 schema,
                                 probe_state_bridge,
                             )),
-                            vec![build_node, probe_child_node],
+                            children,
                         )
                         .boxed())
                     }
@@ -491,8 +748,9 @@ pub fn physical_plan_to_pipeline(
                 JoinSide::Right => (right, left),
             };
 
-            let stream_child_node = physical_plan_to_pipeline(stream_child, psets, cfg)?;
-            let collect_child_node = physical_plan_to_pipeline(collect_child, psets, cfg)?;
+            let stream_child_node = build_pipeline(stream_child, psets, cfg, temp_dir, broadcasts)?;
+            let collect_child_node =
+                build_pipeline(collect_child, psets, cfg, temp_dir, broadcasts)?;
 
             let state_bridge = BroadcastStateBridge::new();
             let collect_node = BlockingSinkNode::new(
@@ -511,14 +769,91 @@ pub fn physical_plan_to_pipeline(
             )
             .boxed()
         }
+        LocalPhysicalPlan::SortMergeJoin(SortMergeJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            is_sorted,
+            ..
+        }) => {
+            let left_child_node = build_pipeline(left, psets, cfg, temp_dir, broadcasts)?;
+            let right_child_node = build_pipeline(right, psets, cfg, temp_dir, broadcasts)?;
+
+            let state_bridge = BroadcastStateBridge::new();
+            let build_node = BlockingSinkNode::new(
+                Arc::new(SortMergeJoinBuildSink::new(
+                    right_on.clone(),
+                    *is_sorted,
+                    right.schema().clone(),
+                    state_bridge.clone(),
+                )),
+                right_child_node,
+            )
+            .boxed();
+
+            IntermediateNode::new(
+                Arc::new(SortMergeJoinProbeOperator::new(
+                    left_on.clone(),
+                    right_on.clone(),
+                    state_bridge,
+                )),
+                vec![build_node, left_child_node],
+            )
+            .boxed()
+        }
+        LocalPhysicalPlan::RangeJoin(RangeJoin {
+            left,
+            right,
+            right_on,
+            lower_bound,
+            upper_bound,
+            residual_predicate,
+            schema,
+            ..
+        }) => {
+            let left_child_node = build_pipeline(left, psets, cfg, temp_dir, broadcasts)?;
+            let right_child_node = build_pipeline(right, psets, cfg, temp_dir, broadcasts)?;
+
+            let state_bridge = BroadcastStateBridge::new();
+            let build_node = BlockingSinkNode::new(
+                Arc::new(RangeJoinBuildSink::new(
+                    right_on.clone(),
+                    right.schema().clone(),
+                    state_bridge.clone(),
+                )),
+                right_child_node,
+            )
+            .boxed();
+
+            IntermediateNode::new(
+                Arc::new(RangeJoinProbeOperator::new(
+                    lower_bound.clone(),
+                    upper_bound.clone(),
+                    residual_predicate.clone(),
+                    schema.clone(),
+                    state_bridge,
+                )),
+                vec![build_node, left_child_node],
+            )
+            .boxed()
+        }
         LocalPhysicalPlan::PhysicalWrite(PhysicalWrite {
             input,
             file_info,
             file_schema,
+            copy_into_mode,
             ..
         }) => {
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
-            let writer_factory = make_physical_writer_factory(file_info, cfg);
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
+            let mode = copy_into_mode.unwrap_or_default();
+            prepare_write(file_info, mode).with_context(|_| PipelineCreationSnafu {
+                plan_name: physical_plan.name(),
+            })?;
+            let writer_factory = match copy_into_mode {
+                None => make_physical_writer_factory(file_info, cfg),
+                Some(_) => make_manifest_writer_factory(file_info, cfg),
+            };
             let write_format = match (file_info.file_format, file_info.partition_cols.is_some()) {
                 (FileFormat::Parquet, true) => WriteFormat::PartitionedParquet,
                 (FileFormat::Parquet, false) => WriteFormat::Parquet,
@@ -531,7 +866,8 @@ pub fn physical_plan_to_pipeline(
                 writer_factory,
                 file_info.partition_cols.clone(),
                 file_schema.clone(),
-            );
+            )
+            .with_commit(file_info.clone(), mode);
             BlockingSinkNode::new(Arc::new(write_sink), child_node).boxed()
         }
         #[cfg(feature = "python")]
@@ -543,7 +879,7 @@ pub fn physical_plan_to_pipeline(
         }) => {
             use daft_logical_plan::CatalogType;
 
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             let (partition_by, write_format) = match catalog_type {
                 CatalogType::Iceberg(ic) => {
                     if !ic.partition_cols.is_empty() {
@@ -587,7 +923,7 @@ pub fn physical_plan_to_pipeline(
             file_schema,
             ..
         }) => {
-            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let child_node = build_pipeline(input, psets, cfg, temp_dir, broadcasts)?;
             let writer_factory = daft_writers::make_lance_writer_factory(lance_info.clone());
             let write_sink = WriteSink::new(
                 WriteFormat::Lance,
@@ -601,3 +937,99 @@ pub fn physical_plan_to_pipeline(
 
     Ok(out)
 }
+
+#[cfg(test)]
+mod tests {
+    use daft_local_plan::translate;
+    use daft_logical_plan::LogicalPlanBuilder;
+    use daft_shuffles::TempSpace;
+    use futures::executor::block_on_stream;
+
+    use super::*;
+    use crate::{
+        run::{NativeExecutor, StreamingOptions},
+        test::{in_memory_source, partition_values},
+    };
+
+    /// Joins a fact table with a dimension table twice, and returns the number of probe tables
+    /// that the pipeline builds and the values of the joined rows.
+    fn join_twice(
+        join_type: JoinType,
+        dim: impl Fn(LogicalPlanBuilder) -> DaftResult<LogicalPlanBuilder>,
+    ) -> DaftResult<(usize, Vec<i64>)> {
+        let fact = in_memory_source(vec![vec![1, 2], vec![2, 4]]);
+        let dim_source = in_memory_source(vec![vec![1, 2, 3]]);
+        dim_source.add_to(&fact.psets);
+        let builder = fact
+            .builder
+            .join(
+                &dim(dim_source.builder.clone())?,
+                vec![col("a")],
+                vec![col("a")],
+                join_type,
+                None,
+                None,
+                None,
+                false,
+            )?
+            .join(
+                &dim(dim_source.builder.clone())?,
+                vec![col("a")],
+                vec![col("a")],
+                join_type,
+                None,
+                None,
+                None,
+                false,
+            )?
+            .optimize()?;
+
+        let cfg = Arc::new(DaftExecutionConfig::default());
+        let temp_dir = TempSpace::get_or_create(None, None).query_dir();
+        let pipeline =
+            physical_plan_to_pipeline(&translate(&builder.build())?, &fact.psets, &cfg, &temp_dir)?;
+        let num_builds = count_nodes(pipeline.as_ref(), "HashJoinBuildSink");
+
+        let executor = NativeExecutor::from_logical_plan_builder(&builder)?;
+        let stream = executor.execute_streaming(&fact.psets, cfg, StreamingOptions::default())?;
+        let mut values = partition_values(block_on_stream(Box::pin(stream))).concat();
+        values.sort_unstable();
+        Ok((num_builds, values))
+    }
+
+    fn count_nodes(node: &dyn PipelineNode, name: &str) -> usize {
+        let count = node
+            .children()
+            .into_iter()
+            .map(|child| count_nodes(child, name))
+            .sum::<usize>();
+        count + usize::from(node.name() == name)
+    }
+
+    #[test]
+    fn test_joins_with_same_build_side_share_probe_table() -> DaftResult<()> {
+        let (num_builds, values) = join_twice(JoinType::Inner, Ok)?;
+        assert_eq!(num_builds, 1);
+        assert_eq!(values, vec![1, 2, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_semi_joins_with_same_build_side_share_probe_table() -> DaftResult<()> {
+        let (num_builds, values) = join_twice(JoinType::Semi, Ok)?;
+        assert_eq!(num_builds, 1);
+        assert_eq!(values, vec![1, 2, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unseeded_sample_build_sides_are_not_shared() -> DaftResult<()> {
+        let (num_builds, values) = join_twice(JoinType::Inner, |dim| dim.sample(1.0, false, None))?;
+        assert_eq!(num_builds, 2);
+        assert_eq!(values, vec![1, 2, 2]);
+
+        let (num_builds, _) = join_twice(JoinType::Inner, |dim| dim.sample(1.0, false, Some(0)))?;
+        assert_eq!(num_builds, 1);
+        Ok(())
+    }
+}
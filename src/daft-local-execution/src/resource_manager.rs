@@ -0,0 +1,159 @@
+use common_error::{DaftError, DaftResult};
+use common_resource_request::ResourceRequest;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use crate::NUM_CPUS;
+
+/// Resources are tracked in thousandths, to support fractional requests such as half a GPU
+const UNITS_PER_RESOURCE: f64 = 1000.0;
+
+fn to_units(amount: f64) -> u32 {
+    (amount * UNITS_PER_RESOURCE).ceil() as u32
+}
+
+/// The CPUs and GPUs shared by the operators of a pipeline.
+///
+/// Operators that declare a resource request acquire it from the pools for every morsel they
+/// execute, which limits how many of their morsels run concurrently across the whole pipeline.
+pub(crate) struct ResourcePools {
+    num_gpus: usize,
+    cpus: Semaphore,
+    gpus: Semaphore,
+}
+
+/// Resources acquired from the pools, which are returned when dropped
+pub(crate) struct ResourcePermit<'a> {
+    _cpus: Option<SemaphorePermit<'a>>,
+    _gpus: Option<SemaphorePermit<'a>>,
+}
+
+impl ResourcePools {
+    /// Pools with all CPUs of the machine and `num_gpus` GPUs
+    pub fn new(num_gpus: usize) -> Self {
+        Self {
+            num_gpus,
+            cpus: Semaphore::new(to_units(*NUM_CPUS as f64) as usize),
+            gpus: Semaphore::new(to_units(num_gpus as f64) as usize),
+        }
+    }
+
+    /// The number of morsels of an operator with the given request that fit in the pools at once
+    pub fn max_concurrency(&self, request: &ResourceRequest) -> DaftResult<usize> {
+        let mut max_concurrency = *NUM_CPUS;
+        if let Some(num_gpus) = request.num_gpus()
+            && num_gpus > 0.0
+        {
+            if num_gpus > self.num_gpus as f64 {
+                return Err(DaftError::ValueError(format!(
+                    "Requested {num_gpus} GPUs but found only {} available",
+                    self.num_gpus
+                )));
+            }
+            max_concurrency = max_concurrency.min((self.num_gpus as f64 / num_gpus) as usize);
+        }
+        if let Some(num_cpus) = request.num_cpus()
+            && num_cpus > 0.0
+        {
+            if num_cpus > *NUM_CPUS as f64 {
+                return Err(DaftError::ValueError(format!(
+                    "Requested {num_cpus} CPUs but found only {} available",
+                    *NUM_CPUS
+                )));
+            }
+            max_concurrency = max_concurrency.min((*NUM_CPUS as f64 / num_cpus) as usize);
+        }
+        Ok(max_concurrency.max(1))
+    }
+
+    /// Waits until the requested resources are available
+    pub async fn acquire(&self, request: &ResourceRequest) -> DaftResult<ResourcePermit<'_>> {
+        async fn acquire_units(
+            pool: &Semaphore,
+            amount: Option<f64>,
+        ) -> DaftResult<Option<SemaphorePermit<'_>>> {
+            match amount {
+                Some(amount) if amount > 0.0 => pool
+                    .acquire_many(to_units(amount))
+                    .await
+                    .map(Some)
+                    .map_err(|e| DaftError::InternalError(e.to_string())),
+                _ => Ok(None),
+            }
+        }
+
+        // GPUs are acquired first, as they are the scarcer resource
+        let gpus = acquire_units(&self.gpus, request.num_gpus()).await?;
+        let cpus = acquire_units(&self.cpus, request.num_cpus()).await?;
+        Ok(ResourcePermit {
+            _cpus: cpus,
+            _gpus: gpus,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(num_cpus: Option<f64>, num_gpus: Option<f64>) -> ResourceRequest {
+        ResourceRequest::try_new_internal(num_cpus, num_gpus, None).unwrap()
+    }
+
+    #[test]
+    fn test_max_concurrency_is_limited_by_gpus() {
+        let pools = ResourcePools::new(2);
+        assert_eq!(
+            pools.max_concurrency(&request(None, Some(0.5))).unwrap(),
+            (*NUM_CPUS).min(4)
+        );
+        assert_eq!(pools.max_concurrency(&request(None, Some(2.0))).unwrap(), 1);
+        assert_eq!(
+            pools.max_concurrency(&request(None, None)).unwrap(),
+            *NUM_CPUS
+        );
+    }
+
+    #[test]
+    fn test_max_concurrency_is_limited_by_cpus() {
+        let pools = ResourcePools::new(0);
+        let num_cpus = *NUM_CPUS as f64;
+        assert_eq!(
+            pools
+                .max_concurrency(&request(Some(num_cpus), None))
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            pools.max_concurrency(&request(Some(0.0), None)).unwrap(),
+            *NUM_CPUS
+        );
+    }
+
+    #[test]
+    fn test_requesting_too_many_resources_fails() {
+        let pools = ResourcePools::new(1);
+        assert!(pools.max_concurrency(&request(None, Some(2.0))).is_err());
+        assert!(pools
+            .max_concurrency(&request(Some(*NUM_CPUS as f64 + 1.0), None))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_released_gpus() {
+        let pools = ResourcePools::new(1);
+        let half = request(None, Some(0.5));
+
+        let first = pools.acquire(&half).await.unwrap();
+        let second = pools.acquire(&half).await.unwrap();
+        // both halves of the only GPU are taken
+        assert_eq!(pools.gpus.available_permits(), 0);
+
+        drop(first);
+        let third = pools.acquire(&half).await.unwrap();
+        assert_eq!(pools.gpus.available_permits(), 0);
+
+        drop(second);
+        drop(third);
+        assert_eq!(pools.gpus.available_permits(), to_units(1.0) as usize);
+    }
+}
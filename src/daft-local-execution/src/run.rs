@@ -2,19 +2,22 @@ use std::{
     collections::HashMap,
     fs::File,
     io::Write,
+    path::Path,
     sync::Arc,
     time::{SystemTime, UNIX_EPOCH},
 };
 
 use common_daft_config::DaftExecutionConfig;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use common_tracing::refresh_chrome_trace;
 use daft_local_plan::{translate, LocalPhysicalPlan};
-use daft_logical_plan::LogicalPlanBuilder;
+use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
 use daft_micropartition::{
     partitioning::{InMemoryPartitionSetCache, MicroPartitionSet, PartitionSetCache},
     MicroPartition, MicroPartitionRef,
 };
+use daft_scheduler::execute_adaptive;
+use daft_shuffles::{QueryTempDir, TempSpace};
 use futures::{FutureExt, Stream};
 use loole::RecvFuture;
 use tokio_util::sync::CancellationToken;
@@ -28,9 +31,10 @@ use {
 
 use crate::{
     channel::{create_channel, Receiver},
-    pipeline::{physical_plan_to_pipeline, viz_pipeline},
+    pipeline::{physical_plan_to_pipeline, viz_pipeline, PipelineNode},
     progress_bar::make_progress_bar_manager,
-    Error, ExecutionRuntimeContext,
+    sinks::{preview::PreviewSink, streaming_sink::StreamingSinkNode},
+    Error, ExecutionRuntimeContext, LocalRunner,
 };
 
 #[cfg(feature = "python")]
@@ -81,36 +85,137 @@ impl PyNativeExecutor {
         cfg: PyDaftExecutionConfig,
         results_buffer_size: Option<usize>,
     ) -> PyResult<PyObject> {
-        let native_psets: HashMap<String, Arc<MicroPartitionSet>> = psets
-            .into_iter()
-            .map(|(part_id, parts)| {
-                (
-                    part_id,
-                    Arc::new(
-                        parts
-                            .into_iter()
-                            .map(std::convert::Into::into)
-                            .collect::<Vec<Arc<MicroPartition>>>()
-                            .into(),
-                    ),
-                )
-            })
-            .collect();
+        let native_psets = to_native_psets(psets);
         let psets = InMemoryPartitionSetCache::new(&native_psets);
         let out = py.allow_threads(|| {
             self.executor
                 .run(&psets, cfg.config, results_buffer_size)
                 .map(|res| res.into_iter())
         })?;
-        let iter = Box::new(out.map(|part| {
-            part.map(|p| pyo3::Python::with_gil(|py| PyMicroPartition::from(p).into_py(py)))
-        }));
-        let part_iter = LocalPartitionIterator { iter };
-        Ok(part_iter.into_py(py))
+        Ok(into_partition_iterator(out, py))
+    }
+
+    #[pyo3(signature = (psets, cfg, buffer_size=1, ordered=true))]
+    pub fn execute_streaming(
+        &self,
+        py: Python,
+        psets: HashMap<String, Vec<PyMicroPartition>>,
+        cfg: PyDaftExecutionConfig,
+        buffer_size: usize,
+        ordered: bool,
+    ) -> PyResult<PyObject> {
+        let native_psets = to_native_psets(psets);
+        let psets = InMemoryPartitionSetCache::new(&native_psets);
+        let ordering = if ordered {
+            ResultOrdering::PartitionOrder
+        } else {
+            ResultOrdering::ArrivalOrder
+        };
+        let options = StreamingOptions::default()
+            .with_buffer_size(buffer_size)
+            .with_ordering(ordering);
+        let out = py.allow_threads(|| {
+            self.executor
+                .start_streaming(&psets, cfg.config, options)
+                .map(|res| res.into_iter())
+        })?;
+        Ok(into_partition_iterator(out, py))
+    }
+
+    pub fn preview(
+        &self,
+        py: Python,
+        psets: HashMap<String, Vec<PyMicroPartition>>,
+        cfg: PyDaftExecutionConfig,
+        num_rows: usize,
+    ) -> PyResult<PyObject> {
+        let native_psets = to_native_psets(psets);
+        let psets = InMemoryPartitionSetCache::new(&native_psets);
+        let out = py.allow_threads(|| {
+            self.executor
+                .preview(&psets, cfg.config, num_rows)
+                .map(|res| res.into_iter())
+        })?;
+        Ok(into_partition_iterator(out, py))
+    }
+}
+
+#[cfg(feature = "python")]
+fn to_native_psets(
+    psets: HashMap<String, Vec<PyMicroPartition>>,
+) -> HashMap<String, Arc<MicroPartitionSet>> {
+    psets
+        .into_iter()
+        .map(|(part_id, parts)| {
+            (
+                part_id,
+                Arc::new(
+                    parts
+                        .into_iter()
+                        .map(std::convert::Into::into)
+                        .collect::<Vec<Arc<MicroPartition>>>()
+                        .into(),
+                ),
+            )
+        })
+        .collect()
+}
+
+#[cfg(feature = "python")]
+fn into_partition_iterator(
+    parts: impl Iterator<Item = DaftResult<Arc<MicroPartition>>> + Send + 'static,
+    py: Python,
+) -> PyObject {
+    let iter = Box::new(parts.map(|part| {
+        part.map(|p| pyo3::Python::with_gil(|py| PyMicroPartition::from(p).into_py(py)))
+    }));
+    LocalPartitionIterator { iter }.into_py(py)
+}
+
+/// The order in which [`NativeExecutor::execute_streaming`] yields results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResultOrdering {
+    /// In the order of the partitions of the plan's output, as when the results are collected
+    #[default]
+    PartitionOrder,
+    /// As soon as they are produced, which lets operators run without waiting on each other
+    ArrivalOrder,
+}
+
+/// Options of [`NativeExecutor::execute_streaming`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamingOptions {
+    /// Number of results that are buffered before execution waits for the consumer
+    pub buffer_size: usize,
+    pub ordering: ResultOrdering,
+}
+
+impl Default for StreamingOptions {
+    fn default() -> Self {
+        Self {
+            buffer_size: 1,
+            ordering: ResultOrdering::default(),
+        }
+    }
+}
+
+impl StreamingOptions {
+    #[must_use]
+    pub fn with_buffer_size(self, buffer_size: usize) -> Self {
+        Self {
+            buffer_size,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_ordering(self, ordering: ResultOrdering) -> Self {
+        Self { ordering, ..self }
     }
 }
 
 pub struct NativeExecutor {
+    logical_plan: LogicalPlanRef,
     local_physical_plan: Arc<LocalPhysicalPlan>,
     cancel: CancellationToken,
 }
@@ -123,17 +228,24 @@ impl NativeExecutor {
         let local_physical_plan = translate(&logical_plan)?;
 
         Ok(Self {
+            logical_plan,
             local_physical_plan,
             cancel: CancellationToken::new(),
         })
     }
 
+    /// Runs the plan. If the config has a `checkpoint_dir`, the plan is run stage by stage, resuming
+    /// from the checkpointed outputs of a previous run of the plan.
     pub fn run(
         &self,
-        psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
+        psets: &impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
         cfg: Arc<DaftExecutionConfig>,
         results_buffer_size: Option<usize>,
     ) -> DaftResult<ExecutionEngineResult> {
+        if cfg.checkpoint_dir.is_some() {
+            let runner = LocalRunner::new(psets, cfg.clone(), self.cancel.clone());
+            return execute_adaptive(&runner, self.logical_plan.clone(), cfg);
+        }
         run_local(
             &self.local_physical_plan,
             psets,
@@ -142,6 +254,71 @@ impl NativeExecutor {
             self.cancel.clone(),
         )
     }
+
+    /// Runs the plan, yielding its results as they are produced, for consumers that process
+    /// results incrementally.
+    ///
+    /// Execution is paused while `options.buffer_size` results wait to be consumed. If the config
+    /// has a `checkpoint_dir`, the plan is run stage by stage as in [`Self::run`], which only
+    /// supports yielding the results in partition order.
+    pub fn execute_streaming(
+        &self,
+        psets: &impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: Arc<DaftExecutionConfig>,
+        options: StreamingOptions,
+    ) -> DaftResult<impl Stream<Item = DaftResult<Arc<MicroPartition>>>> {
+        Ok(self.start_streaming(psets, cfg, options)?.into_stream())
+    }
+
+    fn start_streaming(
+        &self,
+        psets: &impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: Arc<DaftExecutionConfig>,
+        options: StreamingOptions,
+    ) -> DaftResult<ExecutionEngineResult> {
+        if options.buffer_size == 0 {
+            return Err(DaftError::ValueError(
+                "The buffer size of a result stream must be at least 1".to_string(),
+            ));
+        }
+        if cfg.checkpoint_dir.is_some() {
+            if options.ordering == ResultOrdering::ArrivalOrder {
+                return Err(DaftError::ValueError(
+                    "Results of a checkpointed run can only be streamed in partition order"
+                        .to_string(),
+                ));
+            }
+            return self.run(psets, cfg, Some(options.buffer_size));
+        }
+        start_local(
+            &self.local_physical_plan,
+            psets,
+            cfg,
+            Some(options.buffer_size),
+            options.ordering == ResultOrdering::PartitionOrder,
+            self.cancel.clone(),
+        )
+    }
+
+    /// Runs only as much of the plan as is needed to produce `num_rows` rows, for previews of the
+    /// plan's output such as `df.show()`.
+    ///
+    /// The rows are taken from whichever partitions produce them first, so they are not
+    /// necessarily the first rows of the output, and execution stops as soon as there are enough
+    /// of them. Previews are never checkpointed.
+    pub fn preview(
+        &self,
+        psets: &impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: Arc<DaftExecutionConfig>,
+        num_rows: usize,
+    ) -> DaftResult<ExecutionEngineResult> {
+        let temp_dir = query_temp_dir(&cfg);
+        let pipeline =
+            physical_plan_to_pipeline(&self.local_physical_plan, psets, &cfg, &temp_dir)?;
+        let pipeline =
+            StreamingSinkNode::new(Arc::new(PreviewSink::new(num_rows)), vec![pipeline]).boxed();
+        start_pipeline(pipeline, cfg, temp_dir, Some(1), false, self.cancel.clone())
+    }
 }
 
 impl Drop for NativeExecutor {
@@ -170,9 +347,13 @@ fn should_enable_progress_bar() -> bool {
     }
 }
 
+/// A callback that runs once the execution engine finished without errors
+type OnSuccess = Box<dyn FnOnce() + Send>;
+
 pub struct ExecutionEngineReceiverIterator {
     receiver: Receiver<Arc<MicroPartition>>,
     handle: Option<std::thread::JoinHandle<DaftResult<()>>>,
+    on_success: Option<OnSuccess>,
 }
 
 impl Iterator for ExecutionEngineReceiverIterator {
@@ -190,7 +371,12 @@ impl Iterator for ExecutionEngineReceiverIterator {
                         .join()
                         .expect("Execution engine thread panicked");
                     match join_result {
-                        Ok(()) => None,
+                        Ok(()) => {
+                            if let Some(on_success) = self.on_success.take() {
+                                on_success();
+                            }
+                            None
+                        }
                         Err(e) => Some(Err(e)),
                     }
                 } else {
@@ -204,6 +390,7 @@ impl Iterator for ExecutionEngineReceiverIterator {
 pub struct ExecutionEngineReceiverStream {
     receive_fut: RecvFuture<Arc<MicroPartition>>,
     handle: Option<std::thread::JoinHandle<DaftResult<()>>>,
+    on_success: Option<OnSuccess>,
 }
 
 impl Stream for ExecutionEngineReceiverStream {
@@ -224,7 +411,12 @@ impl Stream for ExecutionEngineReceiverStream {
                         .join()
                         .expect("Execution engine thread panicked");
                     match join_result {
-                        Ok(()) => std::task::Poll::Ready(None),
+                        Ok(()) => {
+                            if let Some(on_success) = self.on_success.take() {
+                                on_success();
+                            }
+                            std::task::Poll::Ready(None)
+                        }
                         Err(e) => std::task::Poll::Ready(Some(Err(e))),
                     }
                 } else {
@@ -239,6 +431,7 @@ impl Stream for ExecutionEngineReceiverStream {
 pub struct ExecutionEngineResult {
     handle: std::thread::JoinHandle<DaftResult<()>>,
     receiver: Receiver<Arc<MicroPartition>>,
+    on_success: Option<OnSuccess>,
 }
 
 impl ExecutionEngineResult {
@@ -246,8 +439,22 @@ impl ExecutionEngineResult {
         ExecutionEngineReceiverStream {
             receive_fut: self.receiver.into_inner().recv_async(),
             handle: Some(self.handle),
+            on_success: self.on_success,
         }
     }
+
+    /// Runs `f` once all of the results were consumed, if the execution engine finished without
+    /// errors. It does not run if the results are dropped before they were all consumed.
+    pub(crate) fn on_success(mut self, f: impl FnOnce() + Send + 'static) -> Self {
+        let previous = self.on_success.take();
+        self.on_success = Some(Box::new(move || {
+            if let Some(previous) = previous {
+                previous();
+            }
+            f();
+        }));
+        self
+    }
 }
 
 impl IntoIterator for ExecutionEngineResult {
@@ -258,6 +465,7 @@ impl IntoIterator for ExecutionEngineResult {
         ExecutionEngineReceiverIterator {
             receiver: self.receiver,
             handle: Some(self.handle),
+            on_success: self.on_success,
         }
     }
 }
@@ -268,9 +476,48 @@ pub fn run_local(
     cfg: Arc<DaftExecutionConfig>,
     results_buffer_size: Option<usize>,
     cancel: CancellationToken,
+) -> DaftResult<ExecutionEngineResult> {
+    start_local(physical_plan, psets, cfg, results_buffer_size, true, cancel)
+}
+
+fn start_local(
+    physical_plan: &LocalPhysicalPlan,
+    psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
+    cfg: Arc<DaftExecutionConfig>,
+    results_buffer_size: Option<usize>,
+    maintain_order: bool,
+    cancel: CancellationToken,
+) -> DaftResult<ExecutionEngineResult> {
+    let temp_dir = query_temp_dir(&cfg);
+    let pipeline = physical_plan_to_pipeline(physical_plan, psets, &cfg, &temp_dir)?;
+    start_pipeline(
+        pipeline,
+        cfg,
+        temp_dir,
+        results_buffer_size,
+        maintain_order,
+        cancel,
+    )
+}
+
+/// A new temp directory for a query, in the temp space of the configured root directory
+fn query_temp_dir(cfg: &DaftExecutionConfig) -> Arc<QueryTempDir> {
+    TempSpace::get_or_create(
+        cfg.temp_dir.as_deref().map(Path::new),
+        cfg.temp_space_quota_bytes,
+    )
+    .query_dir()
+}
+
+fn start_pipeline(
+    pipeline: Box<dyn PipelineNode>,
+    cfg: Arc<DaftExecutionConfig>,
+    temp_dir: Arc<QueryTempDir>,
+    results_buffer_size: Option<usize>,
+    maintain_order: bool,
+    cancel: CancellationToken,
 ) -> DaftResult<ExecutionEngineResult> {
     refresh_chrome_trace();
-    let pipeline = physical_plan_to_pipeline(physical_plan, psets, &cfg)?;
     let (tx, rx) = create_channel(results_buffer_size.unwrap_or(1));
     let handle = std::thread::spawn(move || {
         let pb_manager = should_enable_progress_bar().then(make_progress_bar_manager);
@@ -279,9 +526,14 @@ pub fn run_local(
             .build()
             .expect("Failed to create tokio runtime");
         let execution_task = async {
-            let mut runtime_handle =
-                ExecutionRuntimeContext::new(cfg.default_morsel_size, pb_manager);
-            let receiver = pipeline.start(true, &mut runtime_handle)?;
+            let mut runtime_handle = ExecutionRuntimeContext::new(
+                cfg.default_morsel_size,
+                pb_manager,
+                cfg.local_num_gpus,
+                temp_dir,
+                cfg.native_parallelism,
+            );
+            let receiver = pipeline.start(maintain_order, &mut runtime_handle)?;
 
             while let Some(val) = receiver.recv().await {
                 if tx.send(val).await.is_err() {
@@ -334,5 +586,130 @@ pub fn run_local(
     Ok(ExecutionEngineResult {
         handle,
         receiver: rx,
+        on_success: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use daft_dsl::col;
+    use futures::executor::block_on_stream;
+
+    use super::*;
+    use crate::test::{in_memory_source, partition_values};
+
+    fn stream_values(
+        executor: &NativeExecutor,
+        psets: &InMemoryPartitionSetCache,
+        cfg: DaftExecutionConfig,
+        options: StreamingOptions,
+    ) -> DaftResult<Vec<Vec<i64>>> {
+        let stream = executor.execute_streaming(psets, Arc::new(cfg), options)?;
+        Ok(partition_values(block_on_stream(Box::pin(stream))))
+    }
+
+    #[test]
+    fn test_execute_streaming_in_partition_order() -> DaftResult<()> {
+        let source = in_memory_source(vec![vec![0, 1], vec![2, 3], vec![4, 5]]);
+        let executor = NativeExecutor::from_logical_plan_builder(&source.builder)?;
+
+        for buffer_size in [1, 3] {
+            let options = StreamingOptions::default().with_buffer_size(buffer_size);
+            let values = stream_values(&executor, &source.psets, Default::default(), options)?;
+            assert_eq!(values.concat(), vec![0, 1, 2, 3, 4, 5]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_streaming_in_arrival_order() -> DaftResult<()> {
+        let source = in_memory_source(vec![vec![0, 1], vec![2, 3], vec![4, 5]]);
+        let executor = NativeExecutor::from_logical_plan_builder(&source.builder)?;
+
+        let options = StreamingOptions::default().with_ordering(ResultOrdering::ArrivalOrder);
+        let mut values =
+            stream_values(&executor, &source.psets, Default::default(), options)?.concat();
+        values.sort_unstable();
+        assert_eq!(values, vec![0, 1, 2, 3, 4, 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_streaming_rejects_empty_buffer() -> DaftResult<()> {
+        let source = in_memory_source(vec![vec![0, 1]]);
+        let executor = NativeExecutor::from_logical_plan_builder(&source.builder)?;
+
+        let options = StreamingOptions::default().with_buffer_size(0);
+        assert!(stream_values(&executor, &source.psets, Default::default(), options).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_streaming_checkpointed_run_in_partition_order() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let cfg = DaftExecutionConfig {
+            checkpoint_dir: Some(dir.path().to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        let source = in_memory_source(vec![vec![0, 1], vec![2, 3]]);
+        let executor = NativeExecutor::from_logical_plan_builder(&source.builder)?;
+
+        let arrival_order = StreamingOptions::default().with_ordering(ResultOrdering::ArrivalOrder);
+        assert!(matches!(
+            stream_values(&executor, &source.psets, cfg.clone(), arrival_order),
+            Err(DaftError::ValueError(_))
+        ));
+        let values = stream_values(&executor, &source.psets, cfg, Default::default())?;
+        assert_eq!(values.concat(), vec![0, 1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_repartition_spills_to_temp_dir() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let source = in_memory_source(vec![vec![0, 1, 2, 3], vec![1, 2, 3, 4], vec![5, 6, 7, 8]]);
+        let builder = source.builder.hash_repartition(Some(3), vec![col("a")])?;
+        let executor = NativeExecutor::from_logical_plan_builder(&builder)?;
+
+        let sorted_partitions = |partitions: Vec<Vec<i64>>| {
+            partitions
+                .into_iter()
+                .map(|mut values| {
+                    values.sort_unstable();
+                    values
+                })
+                .collect::<Vec<_>>()
+        };
+        let in_memory = sorted_partitions(stream_values(
+            &executor,
+            &source.psets,
+            Default::default(),
+            Default::default(),
+        )?);
+        let cfg = DaftExecutionConfig {
+            temp_dir: Some(dir.path().to_string_lossy().to_string()),
+            repartition_memory_limit_bytes: 0,
+            ..Default::default()
+        };
+        let spilled = sorted_partitions(stream_values(
+            &executor,
+            &source.psets,
+            cfg,
+            Default::default(),
+        )?);
+
+        assert_eq!(spilled.len(), 3);
+        assert_eq!(spilled, in_memory);
+        // every occurrence of a key is in the same partition
+        for key in [1, 2, 3] {
+            assert_eq!(
+                spilled
+                    .iter()
+                    .filter(|values| values.contains(&key))
+                    .count(),
+                1
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,420 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use common_daft_config::DaftExecutionConfig;
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_local_plan::translate;
+use daft_logical_plan::InMemoryInfo;
+use daft_micropartition::{
+    partitioning::{MicroPartitionSet, PartitionCacheEntry, PartitionSet, PartitionSetCache},
+    MicroPartition, MicroPartitionRef,
+};
+use daft_scheduler::{QueryStage, Runner, StageCheckpointKey};
+use daft_shuffles::{read_spill_file_with_schema, SpillWriter};
+use tokio_util::sync::CancellationToken;
+
+use crate::{run::run_local, ExecutionEngineResult};
+
+/// Distinguishes the stage outputs of the local runners of a process in the partition set cache
+static NEXT_STAGE_OUTPUT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Runs every stage of a query with the local execution engine, in this process.
+///
+/// If the config has a `checkpoint_dir`, the outputs of each completed stage are written to it as
+/// one Arrow IPC file per partition, under a directory named after the stage's checkpoint key.
+/// The checkpoints of a query are deleted once its final stage succeeds.
+///
+/// Stages that are still running when the runner is dropped are cancelled, except for the final
+/// stage, whose results outlive the runner and which is only cancelled by the token given to
+/// [`LocalRunner::new`].
+pub struct LocalRunner<'a> {
+    psets: &'a dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+    cfg: Arc<DaftExecutionConfig>,
+    cancel: CancellationToken,
+    /// Cancels the stages before the final stage, on drop
+    stages_cancel: CancellationToken,
+}
+
+impl<'a> LocalRunner<'a> {
+    /// `psets` must contain the partition sets of the in-memory sources of the plans to run. The
+    /// outputs of completed stages are added to it.
+    pub fn new(
+        psets: &'a dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+        cfg: Arc<DaftExecutionConfig>,
+        cancel: CancellationToken,
+    ) -> Self {
+        Self {
+            psets,
+            cfg,
+            stages_cancel: cancel.child_token(),
+            cancel,
+        }
+    }
+
+    fn checkpoint_path(&self, key: &StageCheckpointKey) -> Option<PathBuf> {
+        self.cfg
+            .checkpoint_dir
+            .as_ref()
+            .map(|dir| Path::new(dir).join(key.to_string()))
+    }
+}
+
+impl Runner for LocalRunner<'_> {
+    type StageHandle = ExecutionEngineResult;
+    type Partitions = Arc<MicroPartitionSet>;
+
+    fn submit_stage(&self, stage: QueryStage) -> DaftResult<Self::StageHandle> {
+        let local_physical_plan = translate(&stage.logical_plan)?;
+        let cancel = if stage.source_id.is_some() {
+            self.stages_cancel.clone()
+        } else {
+            self.cancel.clone()
+        };
+        run_local(
+            &local_physical_plan,
+            self.psets,
+            self.cfg.clone(),
+            None,
+            cancel,
+        )
+    }
+
+    fn await_partitions(&self, handle: Self::StageHandle) -> DaftResult<Self::Partitions> {
+        let partitions = handle.into_iter().collect::<DaftResult<Vec<_>>>()?;
+        Ok(Arc::new(partitions.into()))
+    }
+
+    fn materialize(
+        &self,
+        partitions: Self::Partitions,
+        schema: SchemaRef,
+    ) -> DaftResult<InMemoryInfo> {
+        let cache_key = format!(
+            "local-stage-output-{}",
+            NEXT_STAGE_OUTPUT_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        self.psets.put_partition_set(&cache_key, &partitions);
+
+        let num_rows = partitions
+            .partitions
+            .iter()
+            .map(|part| part.value().len())
+            .sum();
+        // the clustering spec is filled in by the planner from the stage's plan
+        Ok(InMemoryInfo::new(
+            schema,
+            cache_key.clone(),
+            PartitionCacheEntry::new_rust(cache_key, partitions.clone()),
+            partitions.num_partitions(),
+            partitions.size_bytes()?,
+            num_rows,
+            None,
+        )
+        .with_partition_metadata(partitions.partition_metadata()?)?)
+    }
+
+    fn load_checkpoint(&self, key: &StageCheckpointKey) -> DaftResult<Option<Self::Partitions>> {
+        let Some(path) = self.checkpoint_path(key) else {
+            return Ok(None);
+        };
+        if !path.is_dir() {
+            return Ok(None);
+        }
+
+        let mut files = std::fs::read_dir(&path)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<DaftResult<Vec<_>>>()?;
+        files.sort();
+        let partitions = files
+            .iter()
+            .map(|file| {
+                let (schema, tables) = read_spill_file_with_schema(file)?;
+                Ok(Arc::new(MicroPartition::new_loaded(
+                    schema,
+                    Arc::new(tables),
+                    None,
+                )))
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Some(Arc::new(partitions.into())))
+    }
+
+    fn save_checkpoint(
+        &self,
+        key: &StageCheckpointKey,
+        partitions: &Self::Partitions,
+    ) -> DaftResult<()> {
+        let Some(path) = self.checkpoint_path(key) else {
+            return Ok(());
+        };
+
+        let mut parts = partitions
+            .partitions
+            .iter()
+            .map(|part| (*part.key(), part.value().clone()))
+            .collect::<Vec<_>>();
+        parts.sort_by_key(|(id, _)| *id);
+        // Tables without columns cannot be written to IPC
+        if parts
+            .iter()
+            .any(|(_, part)| part.schema().fields.is_empty())
+        {
+            log::warn!("Not checkpointing stage {key}, whose outputs have no columns");
+            return Ok(());
+        }
+
+        // Write to a temporary directory first, so that a run that fails while checkpointing
+        // does not leave behind a partial checkpoint
+        let tmp_path = path.with_extension("tmp");
+        if tmp_path.exists() {
+            std::fs::remove_dir_all(&tmp_path)?;
+        }
+        std::fs::create_dir_all(&tmp_path)?;
+        for (i, part) in parts.iter().enumerate() {
+            let mut writer =
+                SpillWriter::try_new(tmp_path.join(format!("{i:08}.arrow")), &part.schema())?;
+            for table in part.get_tables()?.iter() {
+                writer.write(table)?;
+            }
+            writer.finish()?;
+        }
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+
+    fn remove_checkpoints_on_success(
+        &self,
+        handle: Self::StageHandle,
+        keys: Vec<StageCheckpointKey>,
+    ) -> Self::StageHandle {
+        let paths = keys
+            .iter()
+            .filter_map(|key| self.checkpoint_path(key))
+            .collect::<Vec<_>>();
+        if paths.is_empty() {
+            return handle;
+        }
+        handle.on_success(move || {
+            for path in &paths {
+                match std::fs::remove_dir_all(path) {
+                    Ok(()) => {}
+                    // stages whose outputs have no columns are not checkpointed
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(e) => log::warn!("Failed to remove checkpoint {}: {e}", path.display()),
+                }
+            }
+            // the query's directory, which only held the checkpoints of its stages
+            if let Some(query_dir) = paths[0].parent() {
+                let _ = std::fs::remove_dir(query_dir);
+            }
+        })
+    }
+}
+
+impl Drop for LocalRunner<'_> {
+    fn drop(&mut self) {
+        self.stages_cancel.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use common_error::DaftError;
+    use daft_dsl::col;
+    use daft_logical_plan::LogicalPlanRef;
+    use daft_scheduler::execute_adaptive;
+
+    use super::*;
+    use crate::test::{in_memory_source, partition_values, InMemorySource};
+
+    /// Runs stages on a [`LocalRunner`], recording the stages that were submitted and the schemas
+    /// of the outputs that were materialized
+    struct RecordingRunner<'a> {
+        inner: LocalRunner<'a>,
+        submitted: Mutex<Vec<String>>,
+        materialized: Mutex<Vec<SchemaRef>>,
+        /// Fails the final stage, to simulate a query that fails after some stages completed
+        fail_final_stage: bool,
+    }
+
+    impl<'a> RecordingRunner<'a> {
+        fn new(source: &'a InMemorySource, cfg: DaftExecutionConfig) -> Self {
+            Self {
+                inner: LocalRunner::new(&source.psets, Arc::new(cfg), CancellationToken::new()),
+                submitted: Mutex::default(),
+                materialized: Mutex::default(),
+                fail_final_stage: false,
+            }
+        }
+
+        fn failing_final_stage(source: &'a InMemorySource, cfg: DaftExecutionConfig) -> Self {
+            Self {
+                fail_final_stage: true,
+                ..Self::new(source, cfg)
+            }
+        }
+    }
+
+    impl Runner for RecordingRunner<'_> {
+        type StageHandle = ExecutionEngineResult;
+        type Partitions = Arc<MicroPartitionSet>;
+
+        fn submit_stage(&self, stage: QueryStage) -> DaftResult<Self::StageHandle> {
+            if self.fail_final_stage && stage.source_id.is_none() {
+                return Err(DaftError::InternalError(
+                    "the final stage failed".to_string(),
+                ));
+            }
+            self.submitted
+                .lock()
+                .unwrap()
+                .push(stage.logical_plan.name().to_string());
+            self.inner.submit_stage(stage)
+        }
+
+        fn await_partitions(&self, handle: Self::StageHandle) -> DaftResult<Self::Partitions> {
+            self.inner.await_partitions(handle)
+        }
+
+        fn materialize(
+            &self,
+            partitions: Self::Partitions,
+            schema: SchemaRef,
+        ) -> DaftResult<InMemoryInfo> {
+            let info = self.inner.materialize(partitions, schema)?;
+            self.materialized
+                .lock()
+                .unwrap()
+                .push(info.source_schema.clone());
+            Ok(info)
+        }
+
+        fn load_checkpoint(
+            &self,
+            key: &StageCheckpointKey,
+        ) -> DaftResult<Option<Self::Partitions>> {
+            self.inner.load_checkpoint(key)
+        }
+
+        fn save_checkpoint(
+            &self,
+            key: &StageCheckpointKey,
+            partitions: &Self::Partitions,
+        ) -> DaftResult<()> {
+            self.inner.save_checkpoint(key, partitions)
+        }
+
+        fn remove_checkpoints_on_success(
+            &self,
+            handle: Self::StageHandle,
+            keys: Vec<StageCheckpointKey>,
+        ) -> Self::StageHandle {
+            self.inner.remove_checkpoints_on_success(handle, keys)
+        }
+    }
+
+    fn checkpoint_cfg(dir: &tempfile::TempDir) -> DaftExecutionConfig {
+        DaftExecutionConfig {
+            checkpoint_dir: Some(dir.path().to_string_lossy().to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn sort_then_project(source: &InMemorySource) -> DaftResult<LogicalPlanRef> {
+        Ok(source
+            .builder
+            .sort(vec![col("a")], vec![false], vec![false])?
+            .select(vec![col("a"), col("a").alias("b")])?
+            .build())
+    }
+
+    fn is_empty_dir(dir: &tempfile::TempDir) -> DaftResult<bool> {
+        Ok(std::fs::read_dir(dir.path())?.next().is_none())
+    }
+
+    #[test]
+    fn test_execute_adaptive_runs_stage_by_stage() -> DaftResult<()> {
+        let source = in_memory_source(vec![vec![3, 1], vec![2, 0]]);
+        let plan = source
+            .builder
+            .sort(vec![col("a")], vec![false], vec![false])?
+            .select(vec![col("a"), col("a").alias("b")])?
+            .build();
+        let runner = RecordingRunner::new(&source, Default::default());
+
+        let result = execute_adaptive(&runner, plan, Arc::new(Default::default()))?;
+        assert_eq!(partition_values(result).concat(), vec![0, 1, 2, 3]);
+
+        // the sort of the two partitions is its own stage, whose outputs the final stage reads
+        assert_eq!(*runner.submitted.lock().unwrap(), vec!["Sort", "Project"]);
+        assert_eq!(
+            *runner.materialized.lock().unwrap(),
+            vec![source.builder.schema()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_adaptive_single_stage() -> DaftResult<()> {
+        let source = in_memory_source(vec![vec![3, 1]]);
+        let plan = source
+            .builder
+            .sort(vec![col("a")], vec![true], vec![false])?
+            .build();
+        let runner = RecordingRunner::new(&source, Default::default());
+
+        let result = execute_adaptive(&runner, plan, Arc::new(Default::default()))?;
+        assert_eq!(partition_values(result).concat(), vec![3, 1]);
+        assert_eq!(*runner.submitted.lock().unwrap(), vec!["Sort"]);
+        assert!(runner.materialized.lock().unwrap().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_adaptive_resumes_from_checkpoints() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let source = in_memory_source(vec![vec![3, 1], vec![2, 0]]);
+
+        let runner = RecordingRunner::failing_final_stage(&source, checkpoint_cfg(&dir));
+        let plan = sort_then_project(&source)?;
+        assert!(execute_adaptive(&runner, plan, Arc::new(checkpoint_cfg(&dir))).is_err());
+        assert_eq!(*runner.submitted.lock().unwrap(), vec!["Sort"]);
+        assert!(!is_empty_dir(&dir)?);
+
+        // the rerun reads the outputs of the sort from its checkpoint instead of sorting again
+        let runner = RecordingRunner::new(&source, checkpoint_cfg(&dir));
+        let plan = sort_then_project(&source)?;
+        let result = execute_adaptive(&runner, plan, Arc::new(checkpoint_cfg(&dir)))?;
+        assert_eq!(partition_values(result).concat(), vec![0, 1, 2, 3]);
+        assert_eq!(*runner.submitted.lock().unwrap(), vec!["Project"]);
+
+        // the checkpoints are deleted once the query succeeded
+        assert!(is_empty_dir(&dir)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_adaptive_does_not_resume_with_changed_inputs() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let source = in_memory_source(vec![vec![3, 1], vec![2, 0]]);
+        let runner = RecordingRunner::failing_final_stage(&source, checkpoint_cfg(&dir));
+        let plan = sort_then_project(&source)?;
+        assert!(execute_adaptive(&runner, plan, Arc::new(checkpoint_cfg(&dir))).is_err());
+
+        let changed = in_memory_source(vec![vec![7, 5], vec![6, 4]]);
+        let runner = RecordingRunner::new(&changed, checkpoint_cfg(&dir));
+        let plan = sort_then_project(&changed)?;
+        let result = execute_adaptive(&runner, plan, Arc::new(checkpoint_cfg(&dir)))?;
+        assert_eq!(partition_values(result).concat(), vec![4, 5, 6, 7]);
+        assert_eq!(*runner.submitted.lock().unwrap(), vec!["Sort", "Project"]);
+        Ok(())
+    }
+}
@@ -125,7 +125,7 @@ impl BlockingSink for AggregateSink {
                 let concated = MicroPartition::concat(all_parts)?;
                 let agged = concated.agg(&params.finalize_agg_exprs, &[])?;
                 let projected = agged.eval_expression_list(&params.final_projections)?;
-                Ok(Some(Arc::new(projected)))
+                Ok(vec![Arc::new(projected)])
             })
             .into()
     }
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use common_runtime::RuntimeRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_table::DuplicateKeyDetector;
+use tracing::instrument;
+
+use super::streaming_sink::{
+    StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeResult, StreamingSinkOutput,
+    StreamingSinkState,
+};
+use crate::{
+    dispatcher::{DispatchSpawner, UnorderedDispatcher},
+    ExecutionRuntimeContext,
+};
+
+struct AssertUniqueState {
+    detector: DuplicateKeyDetector,
+}
+
+impl StreamingSinkState for AssertUniqueState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct AssertUniqueParams {
+    keys: Vec<ExprRef>,
+}
+
+pub struct AssertUniqueSink {
+    params: Arc<AssertUniqueParams>,
+}
+
+impl AssertUniqueSink {
+    pub fn new(keys: Vec<ExprRef>) -> Self {
+        Self {
+            params: Arc::new(AssertUniqueParams { keys }),
+        }
+    }
+}
+
+impl StreamingSink for AssertUniqueSink {
+    #[instrument(skip_all, name = "AssertUniqueSink::sink")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn StreamingSinkState>,
+        runtime_ref: &RuntimeRef,
+    ) -> StreamingSinkExecuteResult {
+        let params = self.params.clone();
+        runtime_ref
+            .spawn(async move {
+                let detector = &mut state
+                    .as_any_mut()
+                    .downcast_mut::<AssertUniqueState>()
+                    .expect("AssertUniqueSink should have AssertUniqueState")
+                    .detector;
+                for table in input.get_tables()?.iter() {
+                    detector.push(table.eval_expression_list(&params.keys)?)?;
+                }
+                // Fail as soon as a duplicate is seen rather than after the whole input is read.
+                detector.check()?;
+
+                Ok((state, StreamingSinkOutput::NeedMoreInput(Some(input))))
+            })
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "AssertUnique"
+    }
+
+    fn finalize(
+        &self,
+        _states: Vec<Box<dyn StreamingSinkState>>,
+        _runtime_ref: &RuntimeRef,
+    ) -> StreamingSinkFinalizeResult {
+        Ok(None).into()
+    }
+
+    fn make_state(&self) -> Box<dyn StreamingSinkState> {
+        Box::new(AssertUniqueState {
+            detector: DuplicateKeyDetector::default(),
+        })
+    }
+
+    // All keys must be seen by the same detector, so this runs on a single worker.
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+
+    fn dispatch_spawner(
+        &self,
+        _runtime_handle: &ExecutionRuntimeContext,
+        _maintain_order: bool,
+    ) -> Arc<dyn DispatchSpawner> {
+        Arc::new(UnorderedDispatcher::new(None))
+    }
+}
@@ -26,8 +26,7 @@ pub enum BlockingSinkStatus {
 }
 
 pub(crate) type BlockingSinkSinkResult = OperatorOutput<DaftResult<BlockingSinkStatus>>;
-pub(crate) type BlockingSinkFinalizeResult =
-    OperatorOutput<DaftResult<Option<Arc<MicroPartition>>>>;
+pub(crate) type BlockingSinkFinalizeResult = OperatorOutput<DaftResult<Vec<Arc<MicroPartition>>>>;
 pub trait BlockingSink: Send + Sync {
     fn sink(
         &self,
@@ -40,6 +39,11 @@ pub trait BlockingSink: Send + Sync {
         states: Vec<Box<dyn BlockingSinkState>>,
         runtime: &RuntimeRef,
     ) -> BlockingSinkFinalizeResult;
+    /// Produces more output once the output of `finalize` has been sent, for sinks whose output
+    /// may not fit in memory at once. Called until it returns no partitions.
+    fn next_output(&self, _runtime: &RuntimeRef) -> BlockingSinkFinalizeResult {
+        Ok(vec![]).into()
+    }
     fn name(&self) -> &'static str;
     fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>>;
     fn dispatch_spawner(
@@ -51,6 +55,11 @@ pub trait BlockingSink: Send + Sync {
         )))
     }
     fn max_concurrency(&self) -> usize;
+    /// Whether the sink receives its input in the order its child produces it, which requires a
+    /// single worker to sink it in that order.
+    fn maintain_input_order(&self) -> bool {
+        false
+    }
 }
 
 pub struct BlockingSinkNode {
@@ -86,7 +95,8 @@ impl BlockingSinkNode {
         while let Some(morsel) = input_receiver.recv().await {
             let result = rt_context
                 .in_span(&span, || op.sink(morsel, state, &compute_runtime))
-                .await??;
+                .await?
+                .map_err(|e| e.context(format!("in {}", op.name())))?;
             match result {
                 BlockingSinkStatus::NeedMoreInput(new_state) => {
                     state = new_state;
@@ -151,7 +161,9 @@ impl PipelineNode for BlockingSinkNode {
             true,
             self.runtime_stats.clone(),
         );
-        let child_results_receiver = self.child.start(false, runtime_handle)?;
+        let child_results_receiver = self
+            .child
+            .start(self.op.maintain_input_order(), runtime_handle)?;
         let counting_receiver = CountingReceiver::new(
             child_results_receiver,
             self.runtime_stats.clone(),
@@ -164,7 +176,7 @@ impl PipelineNode for BlockingSinkNode {
 
         let op = self.op.clone();
         let runtime_stats = self.runtime_stats.clone();
-        let num_workers = op.max_concurrency();
+        let num_workers = runtime_handle.num_workers(op.max_concurrency());
 
         let dispatch_spawner = op.dispatch_spawner(runtime_handle);
         let spawned_dispatch_result = dispatch_spawner.spawn_dispatch(
@@ -194,15 +206,28 @@ impl PipelineNode for BlockingSinkNode {
                 }
 
                 let compute_runtime = get_compute_runtime();
-                let finalized_result = runtime_stats
+                let mut finalized_result = runtime_stats
                     .in_span(&info_span!("BlockingSinkNode::finalize"), || {
                         op.finalize(finished_states, &compute_runtime)
                     })
-                    .await??;
-                if let Some(res) = finalized_result {
-                    let _ = counting_sender.send(res).await;
+                    .await?
+                    .map_err(|e| e.context(format!("finalizing {}", op.name())))?;
+                loop {
+                    for res in finalized_result {
+                        if counting_sender.send(res).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+                    finalized_result = runtime_stats
+                        .in_span(&info_span!("BlockingSinkNode::next_output"), || {
+                            op.next_output(&compute_runtime)
+                        })
+                        .await?
+                        .map_err(|e| e.context(format!("finalizing {}", op.name())))?;
+                    if finalized_result.is_empty() {
+                        return Ok(());
+                    }
                 }
-                Ok(())
             },
             self.name(),
         );
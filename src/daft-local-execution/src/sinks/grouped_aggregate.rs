@@ -412,8 +412,17 @@ impl BlockingSink for GroupedAggregateSink {
                         .await
                         .into_iter()
                         .collect::<DaftResult<Vec<_>>>()?;
-                    let concated = MicroPartition::concat(&results)?;
-                    Ok(Some(Arc::new(concated)))
+                    // The partitions hold disjoint groups, so their results are output as they
+                    // are rather than concatenated. Only empty results are left out, unless all of
+                    // them are empty.
+                    let (non_empty, empty): (Vec<_>, Vec<_>) =
+                        results.into_iter().partition(|result| !result.is_empty());
+                    let outputs = if non_empty.is_empty() {
+                        empty.into_iter().take(1).collect::<Vec<_>>()
+                    } else {
+                        non_empty
+                    };
+                    Ok(outputs.into_iter().map(Arc::new).collect())
                 }
                 .instrument(info_span!("GroupedAggregateSink::finalize")),
             )
@@ -154,7 +154,7 @@ impl BlockingSink for HashJoinBuildSink {
         let finalized_probe_state = probe_table_state.finalize();
         self.probe_state_bridge
             .set_state(finalized_probe_state.into());
-        Ok(None).into()
+        Ok(vec![]).into()
     }
 
     fn max_concurrency(&self) -> usize {
@@ -1,4 +1,5 @@
 pub mod aggregate;
+pub mod assert_unique;
 pub mod blocking_sink;
 pub mod concat;
 pub mod cross_join_collect;
@@ -8,6 +9,13 @@ pub mod limit;
 pub mod monotonically_increasing_id;
 pub mod outer_hash_join_probe;
 pub mod pivot;
+pub mod preview;
+pub mod range_join_build;
+pub mod repartition;
 pub mod sort;
+pub mod sort_merge_join_build;
 pub mod streaming_sink;
+pub mod top_n;
+pub mod window;
+pub mod window_aggregate;
 pub mod write;
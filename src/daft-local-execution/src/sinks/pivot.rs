@@ -53,6 +53,8 @@ struct PivotParams {
 
 pub struct PivotSink {
     pivot_params: Arc<PivotParams>,
+    /// Whether the buffered morsels are compressed until they're pivoted.
+    compress_buffered_partitions: bool,
 }
 
 impl PivotSink {
@@ -62,6 +64,7 @@ impl PivotSink {
         value_column: ExprRef,
         aggregation: AggExpr,
         names: Vec<String>,
+        compress_buffered_partitions: bool,
     ) -> Self {
         Self {
             pivot_params: Arc::new(PivotParams {
@@ -71,6 +74,7 @@ impl PivotSink {
                 aggregation,
                 names,
             }),
+            compress_buffered_partitions,
         }
     }
 }
@@ -81,14 +85,28 @@ impl BlockingSink for PivotSink {
         &self,
         input: Arc<MicroPartition>,
         mut state: Box<dyn BlockingSinkState>,
-        _runtime: &RuntimeRef,
+        runtime: &RuntimeRef,
     ) -> BlockingSinkSinkResult {
-        state
-            .as_any_mut()
-            .downcast_mut::<PivotState>()
-            .expect("PivotSink should have PivotState")
-            .push(input);
-        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+        if !self.compress_buffered_partitions {
+            state
+                .as_any_mut()
+                .downcast_mut::<PivotState>()
+                .expect("PivotSink should have PivotState")
+                .push(input);
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        runtime
+            .spawn(async move {
+                let compressed = input.compress()?;
+                state
+                    .as_any_mut()
+                    .downcast_mut::<PivotState>()
+                    .expect("PivotSink should have PivotState")
+                    .push(compressed);
+                Ok(BlockingSinkStatus::NeedMoreInput(state))
+            })
+            .into()
     }
 
     #[instrument(skip_all, name = "PivotSink::finalize")]
@@ -124,7 +142,7 @@ impl BlockingSink for PivotSink {
                     pivot_params.value_column.clone(),
                     pivot_params.names.clone(),
                 )?);
-                Ok(Some(pivoted))
+                Ok(vec![pivoted])
             })
             .into()
     }
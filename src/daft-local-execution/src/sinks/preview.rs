@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use common_runtime::RuntimeRef;
+use daft_micropartition::MicroPartition;
+use tracing::instrument;
+
+use super::{
+    limit::LimitSink,
+    streaming_sink::{
+        StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeResult, StreamingSinkState,
+    },
+};
+use crate::{
+    dispatcher::{DispatchSpawner, UnorderedDispatcher},
+    ExecutionRuntimeContext,
+};
+
+/// PreviewSink takes the first rows that arrive from its input, from whichever partitions
+/// produce them first, and finishes as soon as it has enough rows to preview.
+///
+/// Finishing closes the input of the sink, which stops the upstream operators and scans
+/// that are still running, so a preview doesn't wait for the rest of the pipeline.
+pub struct PreviewSink {
+    limit: LimitSink,
+}
+
+impl PreviewSink {
+    pub fn new(num_rows: usize) -> Self {
+        Self {
+            limit: LimitSink::new(num_rows),
+        }
+    }
+}
+
+impl StreamingSink for PreviewSink {
+    #[instrument(skip_all, name = "PreviewSink::sink")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        state: Box<dyn StreamingSinkState>,
+        runtime_ref: &RuntimeRef,
+    ) -> StreamingSinkExecuteResult {
+        self.limit.execute(input, state, runtime_ref)
+    }
+
+    fn name(&self) -> &'static str {
+        "Preview"
+    }
+
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn StreamingSinkState>>,
+        runtime_ref: &RuntimeRef,
+    ) -> StreamingSinkFinalizeResult {
+        self.limit.finalize(states, runtime_ref)
+    }
+
+    fn make_state(&self) -> Box<dyn StreamingSinkState> {
+        self.limit.make_state()
+    }
+
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+
+    fn dispatch_spawner(
+        &self,
+        _runtime_handle: &ExecutionRuntimeContext,
+        _maintain_order: bool,
+    ) -> Arc<dyn DispatchSpawner> {
+        Arc::new(UnorderedDispatcher::new(None))
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_runtime::RuntimeRef;
+use daft_core::{
+    prelude::{SchemaRef, UInt64Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::ExprRef;
+use daft_io::IOStatsContext;
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+use tracing::instrument;
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+    BlockingSinkStatus,
+};
+use crate::{state_bridge::BroadcastStateBridgeRef, NUM_CPUS};
+
+/// The right side of a range join, sorted by its key.
+pub(crate) struct RangeJoinBuildSide {
+    /// The rows with a non-null key, in ascending order of the key.
+    pub(crate) table: Table,
+    pub(crate) key: Series,
+    /// The key in descending order, to count the keys that are at least a bound.
+    pub(crate) descending_key: Series,
+}
+
+struct RangeJoinBuildState(Vec<Arc<MicroPartition>>);
+
+impl BlockingSinkState for RangeJoinBuildState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// RangeJoinBuildSink collects the right side of a range join and sorts it by its key, for the
+/// rows of the left side to binary search.
+pub struct RangeJoinBuildSink {
+    right_on: ExprRef,
+    right_schema: SchemaRef,
+    state_bridge: BroadcastStateBridgeRef<RangeJoinBuildSide>,
+}
+
+impl RangeJoinBuildSink {
+    pub(crate) fn new(
+        right_on: ExprRef,
+        right_schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<RangeJoinBuildSide>,
+    ) -> Self {
+        Self {
+            right_on,
+            right_schema,
+            state_bridge,
+        }
+    }
+}
+
+impl BlockingSink for RangeJoinBuildSink {
+    fn name(&self) -> &'static str {
+        "RangeJoinBuildSink"
+    }
+
+    #[instrument(skip_all, name = "RangeJoinBuildSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        _runtime: &RuntimeRef,
+    ) -> BlockingSinkSinkResult {
+        if !input.is_empty() {
+            state
+                .as_any_mut()
+                .downcast_mut::<RangeJoinBuildState>()
+                .expect("RangeJoinBuildSink should have RangeJoinBuildState")
+                .0
+                .push(input);
+        }
+        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+    }
+
+    #[instrument(skip_all, name = "RangeJoinBuildSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        runtime: &RuntimeRef,
+    ) -> BlockingSinkFinalizeResult {
+        let right_on = self.right_on.clone();
+        let right_schema = self.right_schema.clone();
+        let state_bridge = self.state_bridge.clone();
+        runtime
+            .spawn(async move {
+                let parts = states
+                    .into_iter()
+                    .flat_map(|mut state| {
+                        std::mem::take(
+                            &mut state
+                                .as_any_mut()
+                                .downcast_mut::<RangeJoinBuildState>()
+                                .expect("State type mismatch")
+                                .0,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                // Rows with a null key don't satisfy any comparison, so they are dropped.
+                let sorted = if parts.is_empty() {
+                    MicroPartition::empty(Some(right_schema.clone()))
+                } else {
+                    MicroPartition::concat(parts)?
+                        .filter(&[right_on.clone().not_null()])?
+                        .sort(&[right_on.clone()], &[false], &[false])?
+                };
+                let tables =
+                    sorted.concat_or_get(IOStatsContext::new("RangeJoinBuildSink::finalize"))?;
+                let table = match tables.first() {
+                    Some(table) => table.clone(),
+                    None => Table::empty(Some(right_schema))?,
+                };
+
+                let key = table
+                    .eval_expression_list(&[right_on])?
+                    .get_column_by_index(0)?
+                    .clone();
+                let descending_indices = (0..key.len() as u64).rev().collect::<Vec<_>>();
+                let descending_key =
+                    key.take(&UInt64Array::from(("idx", descending_indices)).into_series())?;
+                state_bridge.set_state(Arc::new(RangeJoinBuildSide {
+                    table,
+                    key,
+                    descending_key,
+                }));
+                Ok(vec![])
+            })
+            .into()
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(RangeJoinBuildState(Vec::new())))
+    }
+
+    fn max_concurrency(&self) -> usize {
+        *NUM_CPUS
+    }
+}
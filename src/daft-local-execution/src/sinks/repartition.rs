@@ -0,0 +1,354 @@
+use std::sync::{Arc, Mutex};
+
+use common_error::DaftResult;
+use common_runtime::RuntimeRef;
+use daft_core::{
+    prelude::{SchemaRef, UInt64Array},
+    series::IntoSeries,
+};
+use daft_dsl::{col, ExprRef};
+use daft_io::IOStatsContext;
+use daft_logical_plan::partitioning::{
+    HashRepartitionConfig, RangeRepartitionConfig, RepartitionSpec,
+};
+use daft_micropartition::MicroPartition;
+use daft_shuffles::{
+    InProgressShuffleCache, QueryTempDir, ShuffleCache, ShufflePartitioning, ShuffleReader,
+};
+use daft_table::Table;
+use tracing::instrument;
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+    BlockingSinkStatus,
+};
+use crate::NUM_CPUS;
+
+/// Number of rows sampled per output partition to pick the boundaries of range partitions.
+const RANGE_SAMPLES_PER_PARTITION: usize = 100;
+
+/// Splits `input` into `num_partitions` consecutive ranges of the `by` columns. The boundaries of
+/// the ranges are the quantiles of a sample of the non-null keys, as in the distributed sort, so
+/// the partitions have roughly the same number of rows. Null keys go to the first or last range
+/// according to `nulls_first`.
+pub(crate) fn partition_by_sampled_range(
+    input: &MicroPartition,
+    by: &[ExprRef],
+    descending: &[bool],
+    nulls_first: &[bool],
+    num_partitions: usize,
+) -> DaftResult<Vec<MicroPartition>> {
+    let keys = input.eval_expression_list(by)?;
+    let key_cols = keys
+        .schema()
+        .names()
+        .into_iter()
+        .map(col)
+        .collect::<Vec<_>>();
+    let not_null = key_cols
+        .iter()
+        .map(|key| key.clone().not_null())
+        .reduce(|left, right| left.and(right))
+        .expect("Range partitioning requires at least one column");
+    let sample = keys.filter(&[not_null])?.sample_by_size(
+        num_partitions * RANGE_SAMPLES_PER_PARTITION,
+        false,
+        None,
+    )?;
+    let quantiles = sample
+        .sort(&key_cols, descending, nulls_first)?
+        .quantiles(num_partitions)?;
+    // Without any keys to sample, there are no boundaries and the input stays in one partition.
+    let boundaries = match quantiles
+        .concat_or_get(IOStatsContext::new("partition_by_sampled_range"))?
+        .first()
+    {
+        Some(boundaries) => boundaries.clone(),
+        None => Table::empty(Some(quantiles.schema()))?,
+    };
+
+    let mut parts = input.partition_by_range(by, &boundaries, descending, nulls_first)?;
+    parts.resize_with(num_partitions, || {
+        MicroPartition::empty(Some(input.schema()))
+    });
+    Ok(parts)
+}
+
+/// Deals the rows of `input` out to `num_partitions` partitions in turn, which balances the
+/// partitions without any keys to partition by.
+fn partition_by_round_robin(
+    input: &MicroPartition,
+    num_partitions: usize,
+) -> DaftResult<Vec<MicroPartition>> {
+    (0..num_partitions)
+        .map(|partition| {
+            let indices = (partition as u64..input.len() as u64)
+                .step_by(num_partitions)
+                .collect::<Vec<_>>();
+            input.take(&UInt64Array::from(("idx", indices)).into_series())
+        })
+        .collect()
+}
+
+/// Splits `input` into `num_partitions` consecutive slices, which keeps the order of its rows.
+fn partition_by_slices(
+    input: &MicroPartition,
+    num_partitions: usize,
+) -> DaftResult<Vec<MicroPartition>> {
+    let len = input.len();
+    (0..num_partitions)
+        .map(|partition| {
+            input.slice(
+                partition * len / num_partitions,
+                (partition + 1) * len / num_partitions,
+            )
+        })
+        .collect()
+}
+
+enum RepartitionState {
+    Building(Vec<Arc<MicroPartition>>),
+    /// Hash repartitions split each morsel as it arrives into a shuffle cache, which spills the
+    /// partitions to the query's temp directory past its memory limit.
+    Shuffling(InProgressShuffleCache),
+    Done,
+}
+
+impl RepartitionState {
+    fn push(&mut self, part: Arc<MicroPartition>) -> DaftResult<()> {
+        match self {
+            Self::Building(parts) => parts.push(part),
+            Self::Shuffling(cache) => cache.push_partition(&part)?,
+            Self::Done => panic!("RepartitionSink should be in Building state"),
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
+        let res = if let Self::Building(ref mut parts) = self {
+            std::mem::take(parts)
+        } else {
+            panic!("RepartitionSink should be in Building state");
+        };
+        *self = Self::Done;
+        res
+    }
+
+    fn finalize_shuffle(&mut self) -> DaftResult<ShuffleCache> {
+        let Self::Shuffling(cache) = std::mem::replace(self, Self::Done) else {
+            panic!("RepartitionSink should be in Shuffling state");
+        };
+        cache.close()
+    }
+}
+
+impl BlockingSinkState for RepartitionState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// The partitions of a hash repartition that are still to be output
+struct ShuffleOutput {
+    reader: ShuffleReader,
+    next_partition: usize,
+}
+
+/// RepartitionSink collects its input and outputs it as `num_partitions` partitions, by hash or
+/// range of the keys of the repartition, or without keys by dealing out rows round-robin.
+///
+/// Hash repartitions hash each morsel into its partitions as it arrives, spilling them once the
+/// partitions buffered by a worker exceed its share of the memory limit, and output the
+/// partitions one at a time.
+pub struct RepartitionSink {
+    repartition_spec: Arc<RepartitionSpec>,
+    num_partitions: usize,
+    schema: SchemaRef,
+    /// Whether the buffered morsels are compressed until they're repartitioned. Doesn't apply to
+    /// hash repartitions, which buffer partitions rather than morsels.
+    compress_buffered_partitions: bool,
+    memory_limit_bytes: usize,
+    temp_dir: Arc<QueryTempDir>,
+    shuffle_output: Arc<Mutex<Option<ShuffleOutput>>>,
+}
+
+impl RepartitionSink {
+    pub fn new(
+        repartition_spec: RepartitionSpec,
+        num_partitions: usize,
+        schema: SchemaRef,
+        compress_buffered_partitions: bool,
+        memory_limit_bytes: usize,
+        temp_dir: Arc<QueryTempDir>,
+    ) -> Self {
+        Self {
+            repartition_spec: Arc::new(repartition_spec),
+            num_partitions,
+            schema,
+            compress_buffered_partitions,
+            memory_limit_bytes,
+            temp_dir,
+            shuffle_output: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+impl BlockingSink for RepartitionSink {
+    #[instrument(skip_all, name = "RepartitionSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        runtime_ref: &RuntimeRef,
+    ) -> BlockingSinkSinkResult {
+        let repartition_state = state
+            .as_any_mut()
+            .downcast_mut::<RepartitionState>()
+            .expect("RepartitionSink should have repartition state");
+        if matches!(repartition_state, RepartitionState::Building(_))
+            && !self.compress_buffered_partitions
+        {
+            if let Err(e) = repartition_state.push(input) {
+                return Err(e).into();
+            }
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        let compress = self.compress_buffered_partitions;
+        runtime_ref
+            .spawn(async move {
+                let repartition_state = state
+                    .as_any_mut()
+                    .downcast_mut::<RepartitionState>()
+                    .expect("RepartitionSink should have repartition state");
+                let input =
+                    if compress && matches!(repartition_state, RepartitionState::Building(_)) {
+                        input.compress()?
+                    } else {
+                        input
+                    };
+                repartition_state.push(input)?;
+                Ok(BlockingSinkStatus::NeedMoreInput(state))
+            })
+            .into()
+    }
+
+    #[instrument(skip_all, name = "RepartitionSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        runtime: &RuntimeRef,
+    ) -> BlockingSinkFinalizeResult {
+        let repartition_spec = self.repartition_spec.clone();
+        let num_partitions = self.num_partitions;
+        if let RepartitionSpec::Hash(_) = repartition_spec.as_ref() {
+            let shuffle_output = self.shuffle_output.clone();
+            return runtime
+                .spawn(async move {
+                    let caches = states
+                        .into_iter()
+                        .map(|mut state| {
+                            state
+                                .as_any_mut()
+                                .downcast_mut::<RepartitionState>()
+                                .expect("State type mismatch")
+                                .finalize_shuffle()
+                                .map(Arc::new)
+                        })
+                        .collect::<DaftResult<Vec<_>>>()?;
+                    *shuffle_output
+                        .lock()
+                        .expect("shuffle output lock is poisoned") = Some(ShuffleOutput {
+                        reader: ShuffleReader::try_new(caches)?,
+                        next_partition: 0,
+                    });
+                    Ok(vec![])
+                })
+                .into();
+        }
+        runtime
+            .spawn(async move {
+                let parts = states.into_iter().flat_map(|mut state| {
+                    let state = state
+                        .as_any_mut()
+                        .downcast_mut::<RepartitionState>()
+                        .expect("State type mismatch");
+                    state.finalize()
+                });
+                let concated = MicroPartition::concat(parts)?;
+                let partitioned = match repartition_spec.as_ref() {
+                    RepartitionSpec::Hash(_) => unreachable!("hash repartitions are shuffled"),
+                    RepartitionSpec::Range(RangeRepartitionConfig { by, descending, .. }) => {
+                        partition_by_sampled_range(
+                            &concated,
+                            by,
+                            descending,
+                            descending,
+                            num_partitions,
+                        )?
+                    }
+                    RepartitionSpec::Random(_) => {
+                        partition_by_round_robin(&concated, num_partitions)?
+                    }
+                    RepartitionSpec::IntoPartitions(_) => {
+                        partition_by_slices(&concated, num_partitions)?
+                    }
+                };
+                Ok(partitioned.into_iter().map(Arc::new).collect())
+            })
+            .into()
+    }
+
+    #[instrument(skip_all, name = "RepartitionSink::next_output")]
+    fn next_output(&self, runtime: &RuntimeRef) -> BlockingSinkFinalizeResult {
+        let shuffle_output = self.shuffle_output.clone();
+        runtime
+            .spawn(async move {
+                let mut shuffle_output = shuffle_output
+                    .lock()
+                    .expect("shuffle output lock is poisoned");
+                let Some(output) = shuffle_output.as_mut() else {
+                    return Ok(vec![]);
+                };
+                if output.next_partition == output.reader.num_partitions() {
+                    // Dropping the reader deletes the spill files of the partitions.
+                    *shuffle_output = None;
+                    return Ok(vec![]);
+                }
+                let partition = output.reader.read_partition(output.next_partition)?;
+                output.next_partition += 1;
+                Ok(vec![Arc::new(partition)])
+            })
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "Repartition"
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        let RepartitionSpec::Hash(HashRepartitionConfig { by, .. }) =
+            self.repartition_spec.as_ref()
+        else {
+            return Ok(Box::new(RepartitionState::Building(Vec::new())));
+        };
+        // Each worker buffers its share of the memory limit before spilling.
+        let memory_limit_bytes = if self.memory_limit_bytes == usize::MAX {
+            usize::MAX
+        } else {
+            self.memory_limit_bytes / self.max_concurrency()
+        };
+        let cache = InProgressShuffleCache::try_new(
+            self.schema.clone(),
+            self.num_partitions,
+            ShufflePartitioning::Hash(by.clone()),
+            Some(self.temp_dir.clone()),
+            memory_limit_bytes,
+        )?;
+        Ok(Box::new(RepartitionState::Shuffling(cache)))
+    }
+
+    fn max_concurrency(&self) -> usize {
+        *NUM_CPUS
+    }
+}
@@ -1,37 +1,60 @@
-use std::sync::Arc;
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
 
 use common_error::DaftResult;
 use common_runtime::RuntimeRef;
+use daft_core::prelude::{AsArrow, IntoSeries, UInt64Array};
 use daft_dsl::ExprRef;
+use daft_io::IOStatsContext;
 use daft_micropartition::MicroPartition;
+use daft_shuffles::{QueryTempDir, SpillReader, SpillWriter};
+use daft_table::Table;
+use futures::future::try_join_all;
 use tracing::instrument;
 
-use super::blocking_sink::{
-    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
-    BlockingSinkStatus,
+use super::{
+    blocking_sink::{
+        BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+        BlockingSinkStatus,
+    },
+    repartition::partition_by_sampled_range,
 };
 use crate::NUM_CPUS;
 
+/// Number of rows from which the sort splits its input into ranges that are sorted in parallel.
+const MIN_ROWS_FOR_RANGE_SORT: usize = 100_000;
+
+/// The morsels buffered by a worker of the sort, and the runs that it sorted and spilled.
+#[derive(Default)]
+struct SortBuffer {
+    parts: Vec<Arc<MicroPartition>>,
+    /// Size of `parts`, which counts against the memory limit of the sort
+    size_bytes: usize,
+    /// Spill files of the runs sorted from morsels that were buffered earlier
+    runs: Vec<PathBuf>,
+}
+
 enum SortState {
-    Building(Vec<Arc<MicroPartition>>),
+    Building(SortBuffer),
     Done,
 }
 
 impl SortState {
-    fn push(&mut self, part: Arc<MicroPartition>) {
-        if let Self::Building(ref mut parts) = self {
-            parts.push(part);
+    fn buffer(&mut self) -> &mut SortBuffer {
+        if let Self::Building(ref mut buffer) = self {
+            buffer
         } else {
             panic!("SortSink should be in Building state");
         }
     }
 
-    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
-        let res = if let Self::Building(ref mut parts) = self {
-            std::mem::take(parts)
-        } else {
-            panic!("SortSink should be in Building state");
-        };
+    fn finalize(&mut self) -> SortBuffer {
+        let res = std::mem::take(self.buffer());
         *self = Self::Done;
         res
     }
@@ -48,18 +71,216 @@ struct SortParams {
     descending: Vec<bool>,
     nulls_first: Vec<bool>,
 }
+
+impl SortParams {
+    fn sort_table(&self, table: &Table) -> DaftResult<Table> {
+        table.sort(&self.sort_by, &self.descending, &self.nulls_first)
+    }
+
+    fn argsort_table(&self, table: &Table) -> DaftResult<Vec<u64>> {
+        let argsort = table.argsort(&self.sort_by, &self.descending, &self.nulls_first)?;
+        Ok(argsort.u64()?.as_arrow().values().to_vec())
+    }
+}
+
+/// Spills the morsels buffered by the workers of a sort as sorted runs once they exceed the memory
+/// limit of the sort.
+struct RunSpiller {
+    params: Arc<SortParams>,
+    memory_limit_bytes: usize,
+    /// Size of the morsels buffered by all workers of the sort
+    buffered_bytes: AtomicUsize,
+    temp_dir: Arc<QueryTempDir>,
+    /// Number of rows of the batches that runs are written and merged in
+    batch_size: usize,
+}
+
+impl RunSpiller {
+    /// Buffers a morsel, returning whether the morsels buffered by all workers now exceed the
+    /// memory limit of the sort.
+    fn buffer(&self, buffer: &mut SortBuffer, part: Arc<MicroPartition>) -> DaftResult<bool> {
+        let size_bytes = part.size_bytes()?.unwrap_or(0);
+        buffer.parts.push(part);
+        buffer.size_bytes += size_bytes;
+        let buffered_bytes = self.buffered_bytes.fetch_add(size_bytes, Ordering::Relaxed);
+        Ok(buffered_bytes + size_bytes > self.memory_limit_bytes)
+    }
+
+    /// Sorts the morsels buffered by a worker into a run, which is written to a spill file.
+    fn spill(&self, buffer: &mut SortBuffer) -> DaftResult<()> {
+        let parts = std::mem::take(&mut buffer.parts);
+        if parts.is_empty() {
+            return Ok(());
+        }
+        let sorted = MicroPartition::concat(parts)?.sort(
+            &self.params.sort_by,
+            &self.params.descending,
+            &self.params.nulls_first,
+        )?;
+        let tables = sorted.concat_or_get(IOStatsContext::new("SortSink::spill"))?;
+        let mut writer = SpillWriter::try_new_in(&self.temp_dir, "sort-run", &sorted.schema())?;
+        for table in tables.iter() {
+            for start in (0..table.len()).step_by(self.batch_size) {
+                writer.write(&table.slice(start, (start + self.batch_size).min(table.len()))?)?;
+            }
+        }
+        buffer.runs.push(writer.finish()?);
+        self.buffered_bytes
+            .fetch_sub(std::mem::take(&mut buffer.size_bytes), Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+/// A sorted run in a spill file, which is read back a batch at a time.
+struct SpilledRun {
+    reader: SpillReader,
+    path: PathBuf,
+    /// The last row of the batch of the run that was read last. The rows left in the run don't
+    /// sort before it.
+    last_row: Table,
+}
+
+/// Merges the sorted runs spilled by a sort, reading them back a batch at a time.
+///
+/// The rows read from the runs are buffered until no row left in any of the runs can sort before
+/// them, which is the case for the rows that sort before the lowest of the last rows read from
+/// each run. Each step outputs those rows and reads the next batch of the run with that lowest
+/// last row, so that at most about one batch per run is buffered at once.
+struct SortedRunMerger {
+    params: Arc<SortParams>,
+    temp_dir: Arc<QueryTempDir>,
+    runs: Vec<SpilledRun>,
+    buffered: Vec<Table>,
+}
+
+impl SortedRunMerger {
+    fn try_new(
+        params: Arc<SortParams>,
+        temp_dir: Arc<QueryTempDir>,
+        paths: Vec<PathBuf>,
+        buffered: Vec<Table>,
+    ) -> DaftResult<Self> {
+        let mut merger = Self {
+            params,
+            temp_dir,
+            runs: Vec::with_capacity(paths.len()),
+            buffered,
+        };
+        for path in paths {
+            let reader = SpillReader::try_new(&path)?;
+            merger.read_batch(reader, path)?;
+        }
+        Ok(merger)
+    }
+
+    /// Buffers the next non-empty batch of a run, or removes the spill file of the run once all of
+    /// it has been read.
+    fn read_batch(&mut self, mut reader: SpillReader, path: PathBuf) -> DaftResult<()> {
+        while let Some(table) = reader.next() {
+            let table = table?;
+            if table.is_empty() {
+                continue;
+            }
+            let last_row = table.slice(table.len() - 1, table.len())?;
+            self.buffered.push(table);
+            self.runs.push(SpilledRun {
+                reader,
+                path,
+                last_row,
+            });
+            return Ok(());
+        }
+        drop(reader);
+        self.temp_dir.remove_file(&path);
+        Ok(())
+    }
+
+    /// The next batch of merged rows, or `None` once all of the runs have been merged.
+    fn next_batch(&mut self) -> DaftResult<Option<Table>> {
+        loop {
+            if self.runs.is_empty() {
+                if self.buffered.is_empty() {
+                    return Ok(None);
+                }
+                let rows = Table::concat(&std::mem::take(&mut self.buffered))?;
+                return Ok(Some(self.params.sort_table(&rows)?));
+            }
+
+            let last_rows = Table::concat(
+                &self
+                    .runs
+                    .iter()
+                    .map(|run| &run.last_row)
+                    .collect::<Vec<_>>(),
+            )?;
+            let lowest = self.params.argsort_table(&last_rows)?[0] as usize;
+
+            // The lowest last row is appended to the buffered rows to find where it sorts among
+            // them. The rows before it are output, and the rows after it stay buffered.
+            let mut tables = std::mem::take(&mut self.buffered);
+            let bound_idx = tables.iter().map(Table::len).sum::<usize>() as u64;
+            tables.push(self.runs[lowest].last_row.clone());
+            let rows = Table::concat(&tables)?;
+            let order = self.params.argsort_table(&rows)?;
+            let position = order
+                .iter()
+                .position(|&idx| idx == bound_idx)
+                .expect("The bound should be among the sorted rows");
+            let output =
+                rows.take(&UInt64Array::from(("idx", order[..position].to_vec())).into_series())?;
+            let rest = rows
+                .take(&UInt64Array::from(("idx", order[position + 1..].to_vec())).into_series())?;
+            if !rest.is_empty() {
+                self.buffered.push(rest);
+            }
+
+            let run = self.runs.swap_remove(lowest);
+            self.read_batch(run.reader, run.path)?;
+            if !output.is_empty() {
+                return Ok(Some(output));
+            }
+        }
+    }
+}
+
+/// SortSink buffers its input and sorts it once all of it has arrived. Once the buffered morsels
+/// exceed the memory limit of the sort, the worker that buffered the last of them sorts its own
+/// morsels into a run that it spills to disk, and the runs are merged on output.
 pub struct SortSink {
     params: Arc<SortParams>,
+    /// Whether the buffered morsels are compressed until they're sorted.
+    compress_buffered_partitions: bool,
+    spiller: Arc<RunSpiller>,
+    /// The merge of the spilled runs, once all of the input has arrived
+    merger: Arc<Mutex<Option<SortedRunMerger>>>,
 }
 
 impl SortSink {
-    pub fn new(sort_by: Vec<ExprRef>, descending: Vec<bool>, nulls_first: Vec<bool>) -> Self {
+    pub fn new(
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        compress_buffered_partitions: bool,
+        memory_limit_bytes: usize,
+        temp_dir: Arc<QueryTempDir>,
+        batch_size: usize,
+    ) -> Self {
+        let params = Arc::new(SortParams {
+            sort_by,
+            descending,
+            nulls_first,
+        });
         Self {
-            params: Arc::new(SortParams {
-                sort_by,
-                descending,
-                nulls_first,
+            params: params.clone(),
+            compress_buffered_partitions,
+            spiller: Arc::new(RunSpiller {
+                params,
+                memory_limit_bytes,
+                buffered_bytes: AtomicUsize::new(0),
+                temp_dir,
+                batch_size: batch_size.max(1),
             }),
+            merger: Arc::new(Mutex::new(None)),
         }
     }
 }
@@ -70,14 +291,47 @@ impl BlockingSink for SortSink {
         &self,
         input: Arc<MicroPartition>,
         mut state: Box<dyn BlockingSinkState>,
-        _runtime_ref: &RuntimeRef,
+        runtime_ref: &RuntimeRef,
     ) -> BlockingSinkSinkResult {
-        state
-            .as_any_mut()
-            .downcast_mut::<SortState>()
-            .expect("SortSink should have sort state")
-            .push(input);
-        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+        let spiller = self.spiller.clone();
+        if !self.compress_buffered_partitions {
+            let buffer = state
+                .as_any_mut()
+                .downcast_mut::<SortState>()
+                .expect("SortSink should have sort state")
+                .buffer();
+            match spiller.buffer(buffer, input) {
+                Ok(false) => return Ok(BlockingSinkStatus::NeedMoreInput(state)).into(),
+                Ok(true) => {}
+                Err(e) => return Err(e).into(),
+            }
+            return runtime_ref
+                .spawn(async move {
+                    let buffer = state
+                        .as_any_mut()
+                        .downcast_mut::<SortState>()
+                        .expect("SortSink should have sort state")
+                        .buffer();
+                    spiller.spill(buffer)?;
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                })
+                .into();
+        }
+
+        runtime_ref
+            .spawn(async move {
+                let compressed = input.compress()?;
+                let buffer = state
+                    .as_any_mut()
+                    .downcast_mut::<SortState>()
+                    .expect("SortSink should have sort state")
+                    .buffer();
+                if spiller.buffer(buffer, compressed)? {
+                    spiller.spill(buffer)?;
+                }
+                Ok(BlockingSinkStatus::NeedMoreInput(state))
+            })
+            .into()
     }
 
     #[instrument(skip_all, name = "SortSink::finalize")]
@@ -87,22 +341,95 @@ impl BlockingSink for SortSink {
         runtime: &RuntimeRef,
     ) -> BlockingSinkFinalizeResult {
         let params = self.params.clone();
+        let spiller = self.spiller.clone();
+        let merger = self.merger.clone();
+        let sort_runtime = runtime.clone();
         runtime
             .spawn(async move {
-                let parts = states.into_iter().flat_map(|mut state| {
-                    let state = state
+                let mut parts = Vec::new();
+                let mut runs = Vec::new();
+                for mut state in states {
+                    let buffer = state
                         .as_any_mut()
                         .downcast_mut::<SortState>()
-                        .expect("State type mismatch");
-                    state.finalize()
-                });
+                        .expect("State type mismatch")
+                        .finalize();
+                    parts.extend(buffer.parts);
+                    runs.extend(buffer.runs);
+                }
+
+                if !runs.is_empty() {
+                    // The morsels that are still buffered are sorted into one more run, which is
+                    // merged with the spilled runs from memory.
+                    let buffered = if parts.is_empty() {
+                        vec![]
+                    } else {
+                        let sorted = MicroPartition::concat(parts)?.sort(
+                            &params.sort_by,
+                            &params.descending,
+                            &params.nulls_first,
+                        )?;
+                        sorted
+                            .concat_or_get(IOStatsContext::new("SortSink::finalize"))?
+                            .as_ref()
+                            .clone()
+                    };
+                    let run_merger =
+                        SortedRunMerger::try_new(params, spiller.temp_dir.clone(), runs, buffered)?;
+                    *merger.lock().expect("sort merger lock is poisoned") = Some(run_merger);
+                    return Ok(vec![]);
+                }
+
                 let concated = MicroPartition::concat(parts)?;
-                let sorted = Arc::new(concated.sort(
+                if concated.len() < MIN_ROWS_FOR_RANGE_SORT || *NUM_CPUS == 1 {
+                    let sorted = Arc::new(concated.sort(
+                        &params.sort_by,
+                        &params.descending,
+                        &params.nulls_first,
+                    )?);
+                    return Ok(vec![sorted]);
+                }
+
+                let ranges = partition_by_sampled_range(
+                    &concated,
                     &params.sort_by,
                     &params.descending,
                     &params.nulls_first,
-                )?);
-                Ok(Some(sorted))
+                    *NUM_CPUS,
+                )?;
+                let sorted_ranges = try_join_all(ranges.into_iter().map(|range| {
+                    let params = params.clone();
+                    sort_runtime.spawn(async move {
+                        range.sort(&params.sort_by, &params.descending, &params.nulls_first)
+                    })
+                }))
+                .await?
+                .into_iter()
+                .collect::<DaftResult<Vec<_>>>()?;
+                Ok(vec![Arc::new(MicroPartition::concat(&sorted_ranges)?)])
+            })
+            .into()
+    }
+
+    #[instrument(skip_all, name = "SortSink::next_output")]
+    fn next_output(&self, runtime: &RuntimeRef) -> BlockingSinkFinalizeResult {
+        let merger = self.merger.clone();
+        runtime
+            .spawn(async move {
+                let Some(mut run_merger) =
+                    merger.lock().expect("sort merger lock is poisoned").take()
+                else {
+                    return Ok(vec![]);
+                };
+                let Some(batch) = run_merger.next_batch()? else {
+                    return Ok(vec![]);
+                };
+                *merger.lock().expect("sort merger lock is poisoned") = Some(run_merger);
+                Ok(vec![Arc::new(MicroPartition::new_loaded(
+                    batch.schema.clone(),
+                    Arc::new(vec![batch]),
+                    None,
+                ))])
             })
             .into()
     }
@@ -112,7 +439,7 @@ impl BlockingSink for SortSink {
     }
 
     fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
-        Ok(Box::new(SortState::Building(Vec::new())))
+        Ok(Box::new(SortState::Building(SortBuffer::default())))
     }
 
     fn max_concurrency(&self) -> usize {
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_runtime::RuntimeRef;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use tracing::instrument;
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+    BlockingSinkStatus,
+};
+use crate::{state_bridge::BroadcastStateBridgeRef, NUM_CPUS};
+
+struct SortMergeJoinBuildState(Vec<Arc<MicroPartition>>);
+
+impl BlockingSinkState for SortMergeJoinBuildState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// SortMergeJoinBuildSink collects the right side of a sort-merge join, sorting it by its join
+/// keys unless it is already sorted, for the left side to be merged with.
+pub struct SortMergeJoinBuildSink {
+    right_on: Vec<ExprRef>,
+    is_sorted: bool,
+    right_schema: SchemaRef,
+    state_bridge: BroadcastStateBridgeRef<MicroPartition>,
+}
+
+impl SortMergeJoinBuildSink {
+    pub(crate) fn new(
+        right_on: Vec<ExprRef>,
+        is_sorted: bool,
+        right_schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<MicroPartition>,
+    ) -> Self {
+        Self {
+            right_on,
+            is_sorted,
+            right_schema,
+            state_bridge,
+        }
+    }
+}
+
+impl BlockingSink for SortMergeJoinBuildSink {
+    fn name(&self) -> &'static str {
+        "SortMergeJoinBuildSink"
+    }
+
+    #[instrument(skip_all, name = "SortMergeJoinBuildSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        _runtime: &RuntimeRef,
+    ) -> BlockingSinkSinkResult {
+        if !input.is_empty() {
+            state
+                .as_any_mut()
+                .downcast_mut::<SortMergeJoinBuildState>()
+                .expect("SortMergeJoinBuildSink should have SortMergeJoinBuildState")
+                .0
+                .push(input);
+        }
+        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+    }
+
+    #[instrument(skip_all, name = "SortMergeJoinBuildSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        runtime: &RuntimeRef,
+    ) -> BlockingSinkFinalizeResult {
+        let right_on = self.right_on.clone();
+        let is_sorted = self.is_sorted;
+        let right_schema = self.right_schema.clone();
+        let state_bridge = self.state_bridge.clone();
+        runtime
+            .spawn(async move {
+                let parts = states
+                    .into_iter()
+                    .flat_map(|mut state| {
+                        std::mem::take(
+                            &mut state
+                                .as_any_mut()
+                                .downcast_mut::<SortMergeJoinBuildState>()
+                                .expect("State type mismatch")
+                                .0,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let right = if parts.is_empty() {
+                    MicroPartition::empty(Some(right_schema))
+                } else if is_sorted {
+                    MicroPartition::concat(parts)?
+                } else {
+                    let ascending = vec![false; right_on.len()];
+                    MicroPartition::concat(parts)?.sort(&right_on, &ascending, &ascending)?
+                };
+                state_bridge.set_state(Arc::new(right));
+                Ok(vec![])
+            })
+            .into()
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(SortMergeJoinBuildState(Vec::new())))
+    }
+
+    fn max_concurrency(&self) -> usize {
+        if self.is_sorted {
+            1
+        } else {
+            *NUM_CPUS
+        }
+    }
+
+    // The parts of a sorted input only concatenate into sorted rows in the order they were
+    // produced in.
+    fn maintain_input_order(&self) -> bool {
+        self.is_sorted
+    }
+}
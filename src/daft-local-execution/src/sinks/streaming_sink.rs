@@ -108,7 +108,9 @@ impl StreamingSinkNode {
                 let output = rt_context.in_span(&span, || {
                     op.execute(morsel.clone(), state, &compute_runtime)
                 });
-                let result = output.await??;
+                let result = output
+                    .await?
+                    .map_err(|e| e.context(format!("in {}", op.name())))?;
                 state = result.0;
                 match result.1 {
                     StreamingSinkOutput::NeedMoreInput(mp) => {
@@ -218,7 +220,7 @@ impl PipelineNode for StreamingSinkNode {
 
         let op = self.op.clone();
         let runtime_stats = self.runtime_stats.clone();
-        let num_workers = op.max_concurrency();
+        let num_workers = runtime_handle.num_workers(op.max_concurrency());
 
         let dispatch_spawner = op.dispatch_spawner(runtime_handle, maintain_order);
         let spawned_dispatch_result = dispatch_spawner.spawn_dispatch(
@@ -259,7 +261,8 @@ impl PipelineNode for StreamingSinkNode {
                     .in_span(&info_span!("StreamingSinkNode::finalize"), || {
                         op.finalize(finished_states, &compute_runtime)
                     })
-                    .await??;
+                    .await?
+                    .map_err(|e| e.context(format!("finalizing {}", op.name())))?;
                 if let Some(res) = finalized_result {
                     let _ = counting_sender.send(res).await;
                 }
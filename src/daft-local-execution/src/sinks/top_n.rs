@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_runtime::RuntimeRef;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use tracing::instrument;
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+    BlockingSinkStatus,
+};
+use crate::NUM_CPUS;
+
+/// The morsels buffered by a worker of a TopN since it last kept only the top rows of its buffer.
+#[derive(Default)]
+struct TopNBuffer {
+    parts: Vec<Arc<MicroPartition>>,
+    num_rows: usize,
+}
+
+enum TopNState {
+    Building(TopNBuffer),
+    Done,
+}
+
+impl TopNState {
+    fn buffer(&mut self) -> &mut TopNBuffer {
+        if let Self::Building(ref mut buffer) = self {
+            buffer
+        } else {
+            panic!("TopNSink should be in Building state");
+        }
+    }
+
+    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
+        let res = std::mem::take(&mut self.buffer().parts);
+        *self = Self::Done;
+        res
+    }
+}
+
+impl BlockingSinkState for TopNState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct TopNParams {
+    sort_by: Vec<ExprRef>,
+    descending: Vec<bool>,
+    nulls_first: Vec<bool>,
+    limit: usize,
+}
+
+impl TopNParams {
+    fn top_n(&self, parts: Vec<Arc<MicroPartition>>) -> DaftResult<MicroPartition> {
+        MicroPartition::concat(parts)?
+            .sort(&self.sort_by, &self.descending, &self.nulls_first)?
+            .head(self.limit)
+    }
+
+    /// Keeps only the top rows of a worker's buffer, so that each worker buffers at most about
+    /// twice the limit rows plus a morsel instead of all of its input.
+    fn compact(&self, buffer: &mut TopNBuffer) -> DaftResult<()> {
+        let top_n = self.top_n(std::mem::take(&mut buffer.parts))?;
+        buffer.num_rows = top_n.len();
+        buffer.parts.push(Arc::new(top_n));
+        Ok(())
+    }
+}
+
+/// TopNSink keeps the first `limit` rows of its input in the order of the sort keys. Each worker
+/// only keeps the top rows of the morsels that it has seen, and the top rows of all workers are
+/// merged once all of the input has arrived.
+pub struct TopNSink {
+    params: Arc<TopNParams>,
+    schema: SchemaRef,
+}
+
+impl TopNSink {
+    pub fn new(
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        limit: usize,
+        schema: SchemaRef,
+    ) -> Self {
+        Self {
+            params: Arc::new(TopNParams {
+                sort_by,
+                descending,
+                nulls_first,
+                limit,
+            }),
+            schema,
+        }
+    }
+}
+
+impl BlockingSink for TopNSink {
+    #[instrument(skip_all, name = "TopNSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        runtime: &RuntimeRef,
+    ) -> BlockingSinkSinkResult {
+        if self.params.limit == 0 {
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+        let buffer = state
+            .as_any_mut()
+            .downcast_mut::<TopNState>()
+            .expect("TopNSink should have TopNState")
+            .buffer();
+        buffer.num_rows += input.len();
+        buffer.parts.push(input);
+        if buffer.num_rows <= self.params.limit.saturating_mul(2) {
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        let params = self.params.clone();
+        runtime
+            .spawn(async move {
+                let buffer = state
+                    .as_any_mut()
+                    .downcast_mut::<TopNState>()
+                    .expect("TopNSink should have TopNState")
+                    .buffer();
+                params.compact(buffer)?;
+                Ok(BlockingSinkStatus::NeedMoreInput(state))
+            })
+            .into()
+    }
+
+    #[instrument(skip_all, name = "TopNSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        runtime: &RuntimeRef,
+    ) -> BlockingSinkFinalizeResult {
+        let params = self.params.clone();
+        let schema = self.schema.clone();
+        runtime
+            .spawn(async move {
+                let parts = states
+                    .into_iter()
+                    .flat_map(|mut state| {
+                        state
+                            .as_any_mut()
+                            .downcast_mut::<TopNState>()
+                            .expect("TopNSink should have TopNState")
+                            .finalize()
+                    })
+                    .collect::<Vec<_>>();
+                if parts.is_empty() {
+                    return Ok(vec![Arc::new(MicroPartition::empty(Some(schema)))]);
+                }
+                Ok(vec![Arc::new(params.top_n(parts)?)])
+            })
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "TopNSink"
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(TopNState::Building(TopNBuffer::default())))
+    }
+
+    fn max_concurrency(&self) -> usize {
+        *NUM_CPUS
+    }
+}
@@ -0,0 +1,654 @@
+use std::{ops::Range, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use common_runtime::RuntimeRef;
+use daft_core::{
+    array::ops::build_multi_array_compare,
+    prelude::{
+        AsArrow, DataType, Field, Float64Array, Int64Array, IntoSeries, SchemaRef, UInt64Array,
+    },
+    series::Series,
+};
+use daft_dsl::ExprRef;
+use daft_io::IOStatsContext;
+use daft_logical_plan::ops::{WindowFrame, WindowFunction};
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+use futures::future::try_join_all;
+use tracing::instrument;
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+    BlockingSinkStatus,
+};
+use crate::NUM_CPUS;
+
+enum WindowState {
+    Accumulating(Vec<Arc<MicroPartition>>),
+    Done,
+}
+
+impl WindowState {
+    fn push(&mut self, part: Arc<MicroPartition>) {
+        if let Self::Accumulating(ref mut parts) = self {
+            parts.push(part);
+        } else {
+            panic!("WindowSink should be in Accumulating state");
+        }
+    }
+
+    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
+        let res = if let Self::Accumulating(ref mut parts) = self {
+            std::mem::take(parts)
+        } else {
+            panic!("WindowSink should be in Accumulating state");
+        };
+        *self = Self::Done;
+        res
+    }
+}
+
+impl BlockingSinkState for WindowState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct WindowParams {
+    partition_by: Vec<ExprRef>,
+    order_by: Vec<ExprRef>,
+    descending: Vec<bool>,
+    nulls_first: Vec<bool>,
+    functions: Vec<WindowFunction>,
+    names: Vec<String>,
+    schema: SchemaRef,
+}
+
+impl WindowParams {
+    /// Evaluates the window functions over the rows of `input`, which holds all the rows of each
+    /// of its window partitions. The rows are output grouped by partition, in the window order.
+    fn evaluate(&self, input: &MicroPartition) -> DaftResult<MicroPartition> {
+        let tables = input.concat_or_get(IOStatsContext::new("WindowSink::evaluate"))?;
+        let Some(table) = tables.first() else {
+            return Ok(MicroPartition::empty(Some(self.schema.clone())));
+        };
+
+        // Sorting by the partition keys ahead of the order keys makes the rows of each partition
+        // consecutive and ordered.
+        let sort_by = self
+            .partition_by
+            .iter()
+            .chain(self.order_by.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        let sorted = if sort_by.is_empty() {
+            table.clone()
+        } else {
+            let descending = std::iter::repeat(false)
+                .take(self.partition_by.len())
+                .chain(self.descending.iter().copied())
+                .collect::<Vec<_>>();
+            let nulls_first = std::iter::repeat(false)
+                .take(self.partition_by.len())
+                .chain(self.nulls_first.iter().copied())
+                .collect::<Vec<_>>();
+            table.sort(&sort_by, &descending, &nulls_first)?
+        };
+
+        let partitions =
+            runs_of_equal_keys(&key_equality(&sorted, &self.partition_by)?, 0..sorted.len());
+        let mut columns = table_columns(&sorted)?;
+        for (function, name) in self.functions.iter().zip(self.names.iter()) {
+            let field = self.schema.get_field(name)?;
+            let values = self.evaluate_function(function, field, &sorted, &partitions)?;
+            columns.push(values.rename(name).cast(&field.dtype)?);
+        }
+        let output = Table::new_with_size(self.schema.clone(), columns, sorted.len())?;
+        Ok(MicroPartition::new_loaded(
+            self.schema.clone(),
+            Arc::new(vec![output]),
+            None,
+        ))
+    }
+
+    fn evaluate_function(
+        &self,
+        function: &WindowFunction,
+        field: &Field,
+        sorted: &Table,
+        partitions: &[Range<usize>],
+    ) -> DaftResult<Series> {
+        match function {
+            WindowFunction::RowNumber => {
+                let row_numbers = partitions
+                    .iter()
+                    .flat_map(|partition| 1..=partition.len() as u64)
+                    .collect::<Vec<_>>();
+                Ok(UInt64Array::from((field.name.as_str(), row_numbers)).into_series())
+            }
+            WindowFunction::Rank => {
+                let is_peer = key_equality(sorted, &self.order_by)?;
+                let mut ranks = Vec::with_capacity(sorted.len());
+                for partition in partitions {
+                    for peers in runs_of_equal_keys(&is_peer, partition.clone()) {
+                        let rank = (peers.start - partition.start + 1) as u64;
+                        ranks.extend(std::iter::repeat(rank).take(peers.len()));
+                    }
+                }
+                Ok(UInt64Array::from((field.name.as_str(), ranks)).into_series())
+            }
+            WindowFunction::Lag { expr, offset } | WindowFunction::Lead { expr, offset } => {
+                let is_lag = matches!(function, WindowFunction::Lag { .. });
+                let offset = *offset as usize;
+                let indices = partitions.iter().flat_map(|partition| {
+                    partition.clone().map(move |idx| {
+                        let idx = if is_lag {
+                            idx.checked_sub(offset)
+                        } else {
+                            idx.checked_add(offset)
+                        };
+                        idx.filter(|idx| partition.contains(idx))
+                            .map(|idx| idx as u64)
+                    })
+                });
+                let indices =
+                    UInt64Array::from_regular_iter(Field::new("idx", DataType::UInt64), indices)?
+                        .into_series();
+                eval_expression(sorted, expr)?.take(&indices)
+            }
+            WindowFunction::Sum { expr, frame } => {
+                let values = eval_expression(sorted, expr)?;
+                frame_aggregate(&values, field, partitions, frame, false)
+            }
+            WindowFunction::Mean { expr, frame } => {
+                let values = eval_expression(sorted, expr)?;
+                frame_aggregate(&values, field, partitions, frame, true)
+            }
+        }
+    }
+}
+
+/// Sums the values within the frame of each row, or averages them if `mean` is set. Integers are
+/// summed as 64-bit integers and floats as 64-bit floats with compensated summation, and means are
+/// computed as floats.
+fn frame_aggregate(
+    values: &Series,
+    field: &Field,
+    partitions: &[Range<usize>],
+    frame: &WindowFrame,
+    mean: bool,
+) -> DaftResult<Series> {
+    let name = field.name.as_str();
+    if !mean && is_signed_integer(&field.dtype) {
+        let values = values.cast(&DataType::Int64)?;
+        let sums = frame_sums(
+            values.i64()?.as_arrow().iter().map(|v| v.copied()),
+            partitions,
+            frame,
+            i64::wrapping_add,
+            i64::wrapping_sub,
+        );
+        Ok(Int64Array::from_regular_iter(
+            Field::new(name, DataType::Int64),
+            sums.into_iter().map(|sum| sum.map(|(sum, _)| sum)),
+        )?
+        .into_series())
+    } else if !mean && is_unsigned_integer(&field.dtype) {
+        let values = values.cast(&DataType::UInt64)?;
+        let sums = frame_sums(
+            values.u64()?.as_arrow().iter().map(|v| v.copied()),
+            partitions,
+            frame,
+            u64::wrapping_add,
+            u64::wrapping_sub,
+        );
+        Ok(UInt64Array::from_regular_iter(
+            Field::new(name, DataType::UInt64),
+            sums.into_iter().map(|sum| sum.map(|(sum, _)| sum)),
+        )?
+        .into_series())
+    } else if values.data_type().is_numeric()
+        && !matches!(values.data_type(), DataType::Decimal128(..))
+    {
+        let values = values.cast(&DataType::Float64)?;
+        let sums = float_frame_sums(
+            &values
+                .f64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            partitions,
+            frame,
+        );
+        Ok(Float64Array::from_regular_iter(
+            Field::new(name, DataType::Float64),
+            sums.into_iter()
+                .map(|sum| sum.map(|(sum, count)| if mean { sum / count as f64 } else { sum })),
+        )?
+        .into_series())
+    } else {
+        Err(DaftError::not_implemented(format!(
+            "Window {} is not implemented for values of type {}",
+            if mean { "mean" } else { "sum" },
+            values.data_type()
+        )))
+    }
+}
+
+fn table_columns(table: &Table) -> DaftResult<Vec<Series>> {
+    (0..table.num_columns())
+        .map(|idx| table.get_column_by_index(idx).cloned())
+        .collect()
+}
+
+fn eval_expression(table: &Table, expr: &ExprRef) -> DaftResult<Series> {
+    table
+        .eval_expression_list(&[expr.clone()])?
+        .get_column_by_index(0)
+        .cloned()
+}
+
+fn is_signed_integer(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64
+    )
+}
+
+fn is_unsigned_integer(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64
+    )
+}
+
+/// Evaluates `keys` over the rows of `table` once, into a function that tells whether two rows
+/// have equal keys. All rows are equal if there are no keys.
+fn key_equality(table: &Table, keys: &[ExprRef]) -> DaftResult<Box<dyn Fn(usize, usize) -> bool>> {
+    if keys.is_empty() {
+        return Ok(Box::new(|_, _| true));
+    }
+    let key_columns = table_columns(&table.eval_expression_list(keys)?)?;
+    let flags = vec![false; key_columns.len()];
+    let comparator = build_multi_array_compare(&key_columns, &flags, &flags)?;
+    Ok(Box::new(move |left, right| comparator(left, right).is_eq()))
+}
+
+/// Splits `range` of rows into the runs of consecutive rows that are equal by `is_equal`.
+fn runs_of_equal_keys(
+    is_equal: &dyn Fn(usize, usize) -> bool,
+    range: Range<usize>,
+) -> Vec<Range<usize>> {
+    if range.is_empty() {
+        return vec![];
+    }
+    let mut runs = vec![];
+    let mut start = range.start;
+    for idx in range.start + 1..range.end {
+        if !is_equal(idx - 1, idx) {
+            runs.push(start..idx);
+            start = idx;
+        }
+    }
+    runs.push(start..range.end);
+    runs
+}
+
+/// The rows of a partition of `len` rows within the frame of the row at `idx`.
+fn frame_bounds(idx: usize, len: usize, frame: &WindowFrame) -> Range<usize> {
+    let start = frame
+        .preceding
+        .map_or(0, |preceding| idx.saturating_sub(preceding as usize));
+    let end = frame.following.map_or(len, |following| {
+        idx.saturating_add(following as usize)
+            .saturating_add(1)
+            .min(len)
+    });
+    start..end
+}
+
+/// Sums the non-null integers within the frame of each row of each partition, using `add` and
+/// `sub` on prefix sums of the partition so that each frame is summed in constant time, which is
+/// exact with wrapping arithmetic. Rows whose frame has no non-null values have no sum, and the
+/// others have their sum and the number of values that it adds.
+fn frame_sums<T: Copy + Default>(
+    values: impl Iterator<Item = Option<T>>,
+    partitions: &[Range<usize>],
+    frame: &WindowFrame,
+    add: impl Fn(T, T) -> T,
+    sub: impl Fn(T, T) -> T,
+) -> Vec<Option<(T, u64)>> {
+    let values = values.collect::<Vec<_>>();
+    let mut sums = Vec::with_capacity(values.len());
+    for partition in partitions {
+        let partition_values = &values[partition.clone()];
+        let mut prefix_sums = Vec::with_capacity(partition_values.len() + 1);
+        let mut prefix_counts = Vec::with_capacity(partition_values.len() + 1);
+        prefix_sums.push(T::default());
+        prefix_counts.push(0u64);
+        for value in partition_values {
+            let (sum, count) = (*prefix_sums.last().unwrap(), *prefix_counts.last().unwrap());
+            match value {
+                Some(value) => {
+                    prefix_sums.push(add(sum, *value));
+                    prefix_counts.push(count + 1);
+                }
+                None => {
+                    prefix_sums.push(sum);
+                    prefix_counts.push(count);
+                }
+            }
+        }
+
+        let len = partition_values.len();
+        for idx in 0..len {
+            let Range { start, end } = frame_bounds(idx, len, frame);
+            let count = if start < end {
+                prefix_counts[end] - prefix_counts[start]
+            } else {
+                0
+            };
+            sums.push((count > 0).then(|| (sub(prefix_sums[end], prefix_sums[start]), count)));
+        }
+    }
+    sums
+}
+
+/// A sum of floats that carries the low-order bits lost to rounding, with Neumaier's variant of
+/// Kahan summation.
+#[derive(Clone, Copy, Default)]
+struct CompensatedSum {
+    sum: f64,
+    compensation: f64,
+    count: u64,
+}
+
+impl CompensatedSum {
+    fn add(&mut self, value: f64) {
+        let sum = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - sum) + value;
+        } else {
+            self.compensation += (value - sum) + self.sum;
+        }
+        self.sum = sum;
+        self.count += 1;
+    }
+
+    /// The sum and the number of values that it adds, if there are any. Infinite and NaN sums
+    /// are not compensated, as their compensation is NaN.
+    fn total(&self) -> Option<(f64, u64)> {
+        let sum = if self.sum.is_finite() {
+            self.sum + self.compensation
+        } else {
+            self.sum
+        };
+        (self.count > 0).then_some((sum, self.count))
+    }
+}
+
+/// Sums the non-null floats within the frame of each row of each partition, like [`frame_sums`].
+/// Subtracting prefix sums would cancel catastrophically when large values leave the frame, so
+/// frames that start at the start of the partition are summed as they grow from it, frames that
+/// end at its end as they grow back from it, and frames bounded on both sides are summed directly.
+fn float_frame_sums(
+    values: &[Option<f64>],
+    partitions: &[Range<usize>],
+    frame: &WindowFrame,
+) -> Vec<Option<(f64, u64)>> {
+    let mut sums = Vec::with_capacity(values.len());
+    for partition in partitions {
+        let partition_values = &values[partition.clone()];
+        let len = partition_values.len();
+        let bounds = (0..len).map(|idx| frame_bounds(idx, len, frame));
+        match (frame.preceding, frame.following) {
+            (None, _) => {
+                // the frames end further into the partition with each row
+                let mut running = CompensatedSum::default();
+                let mut summed = 0;
+                for Range { end, .. } in bounds {
+                    for value in partition_values[summed..end].iter().flatten() {
+                        running.add(*value);
+                    }
+                    summed = end;
+                    sums.push(running.total());
+                }
+            }
+            (Some(_), None) => {
+                // the frames start further back in the partition with each row from its end
+                let mut running = CompensatedSum::default();
+                let mut summed = len;
+                let mut partition_sums = bounds
+                    .rev()
+                    .map(|Range { start, .. }| {
+                        for value in partition_values[start..summed].iter().flatten() {
+                            running.add(*value);
+                        }
+                        summed = start;
+                        running.total()
+                    })
+                    .collect::<Vec<_>>();
+                partition_sums.reverse();
+                sums.extend(partition_sums);
+            }
+            (Some(_), Some(_)) => sums.extend(bounds.map(|bounds| {
+                let mut frame_sum = CompensatedSum::default();
+                for value in partition_values[bounds].iter().flatten() {
+                    frame_sum.add(*value);
+                }
+                frame_sum.total()
+            })),
+        }
+    }
+    sums
+}
+
+/// WindowSink buffers its input and evaluates the window functions once all of it has arrived.
+/// The partitions of the window are hashed into groups that are evaluated in parallel.
+pub struct WindowSink {
+    params: Arc<WindowParams>,
+}
+
+impl WindowSink {
+    pub fn new(
+        partition_by: Vec<ExprRef>,
+        order_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        functions: Vec<WindowFunction>,
+        names: Vec<String>,
+        schema: SchemaRef,
+    ) -> Self {
+        Self {
+            params: Arc::new(WindowParams {
+                partition_by,
+                order_by,
+                descending,
+                nulls_first,
+                functions,
+                names,
+                schema,
+            }),
+        }
+    }
+}
+
+impl BlockingSink for WindowSink {
+    #[instrument(skip_all, name = "WindowSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        _runtime: &RuntimeRef,
+    ) -> BlockingSinkSinkResult {
+        state
+            .as_any_mut()
+            .downcast_mut::<WindowState>()
+            .expect("WindowSink should have WindowState")
+            .push(input);
+        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+    }
+
+    #[instrument(skip_all, name = "WindowSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        runtime: &RuntimeRef,
+    ) -> BlockingSinkFinalizeResult {
+        let params = self.params.clone();
+        let window_runtime = runtime.clone();
+        runtime
+            .spawn(async move {
+                let all_parts = states.into_iter().flat_map(|mut state| {
+                    state
+                        .as_any_mut()
+                        .downcast_mut::<WindowState>()
+                        .expect("WindowSink should have WindowState")
+                        .finalize()
+                });
+                let concated = MicroPartition::concat(all_parts)?;
+                // Without partition keys, all of the rows are in the same window partition.
+                let groups = if params.partition_by.is_empty() || *NUM_CPUS == 1 {
+                    vec![concated]
+                } else {
+                    concated.partition_by_hash(&params.partition_by, *NUM_CPUS)?
+                };
+                let evaluated = try_join_all(groups.into_iter().map(|group| {
+                    let params = params.clone();
+                    window_runtime.spawn(async move { params.evaluate(&group) })
+                }))
+                .await?
+                .into_iter()
+                .collect::<DaftResult<Vec<_>>>()?;
+                Ok(vec![Arc::new(MicroPartition::concat(&evaluated)?)])
+            })
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "WindowSink"
+    }
+
+    fn max_concurrency(&self) -> usize {
+        *NUM_CPUS
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(WindowState::Accumulating(vec![])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::Schema;
+    use daft_dsl::col;
+
+    use super::*;
+
+    fn frame(preceding: Option<u64>, following: Option<u64>) -> WindowFrame {
+        WindowFrame {
+            preceding,
+            following,
+        }
+    }
+
+    #[test]
+    fn test_float_frame_sums_do_not_cancel() {
+        // a prefix sum of 1e20 absorbs the values after it, so subtracting it would lose them
+        let values = [Some(1e20), Some(1.0), Some(2.0), Some(3.0)];
+        let sums = float_frame_sums(&values, &[0..4], &frame(Some(1), Some(0)));
+        assert_eq!(
+            sums,
+            [
+                Some((1e20, 1)),
+                Some((1e20, 2)),
+                Some((3.0, 2)),
+                Some((5.0, 2))
+            ]
+        );
+
+        let values = [Some(1.0), Some(1e100), Some(1.0), Some(-1e100)];
+        let sums = float_frame_sums(&values, &[0..4], &frame(None, Some(0)));
+        assert_eq!(sums[3], Some((2.0, 4)));
+    }
+
+    #[test]
+    fn test_float_frame_sums_over_partitions() {
+        let values = [Some(1.0), None, Some(3.0), Some(4.0), None];
+        let partitions = [0..3, 3..5];
+        assert_eq!(
+            float_frame_sums(&values, &partitions, &frame(Some(1), None)),
+            [
+                Some((4.0, 2)),
+                Some((4.0, 2)),
+                Some((3.0, 1)),
+                Some((4.0, 1)),
+                Some((4.0, 1))
+            ]
+        );
+        assert_eq!(
+            float_frame_sums(&values, &partitions, &frame(Some(0), Some(0))),
+            [Some((1.0, 1)), None, Some((3.0, 1)), Some((4.0, 1)), None]
+        );
+        assert_eq!(
+            float_frame_sums(&values, &partitions, &frame(None, None)),
+            [
+                Some((4.0, 2)),
+                Some((4.0, 2)),
+                Some((4.0, 2)),
+                Some((4.0, 1)),
+                Some((4.0, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_frame_sums_of_integers() {
+        let values = [Some(1i64), Some(2), None, Some(4)];
+        let sums = frame_sums(
+            values.into_iter(),
+            &[0..4],
+            &frame(Some(1), Some(1)),
+            i64::wrapping_add,
+            i64::wrapping_sub,
+        );
+        assert_eq!(
+            sums,
+            [Some((3, 2)), Some((3, 2)), Some((6, 2)), Some((4, 1))]
+        );
+    }
+
+    #[test]
+    fn test_row_number_and_rank_over_partitions() -> DaftResult<()> {
+        let table = Table::from_nonempty_columns(vec![
+            Int64Array::from(("k", vec![1, 2, 1, 1, 2])).into_series(),
+            Int64Array::from(("v", vec![3, 1, 3, 1, 5])).into_series(),
+        ])?;
+        let mut fields = table.schema.fields.values().cloned().collect::<Vec<_>>();
+        fields.push(Field::new("row_number", DataType::UInt64));
+        fields.push(Field::new("rank", DataType::UInt64));
+        let params = WindowParams {
+            partition_by: vec![col("k")],
+            order_by: vec![col("v")],
+            descending: vec![false],
+            nulls_first: vec![false],
+            functions: vec![WindowFunction::RowNumber, WindowFunction::Rank],
+            names: vec!["row_number".to_string(), "rank".to_string()],
+            schema: Arc::new(Schema::new(fields)?),
+        };
+        let input = MicroPartition::new_loaded(table.schema.clone(), Arc::new(vec![table]), None);
+
+        let output = params.evaluate(&input)?;
+        let output = output.concat_or_get(IOStatsContext::new("test"))?;
+        let column = |name: &str| -> DaftResult<Vec<i64>> {
+            let column = output[0].get_column(name)?.cast(&DataType::Int64)?;
+            Ok(column.i64()?.as_arrow().values().to_vec())
+        };
+        assert_eq!(column("k")?, [1, 1, 1, 2, 2]);
+        assert_eq!(column("v")?, [1, 3, 3, 1, 5]);
+        assert_eq!(column("row_number")?, [1, 2, 3, 1, 2]);
+        assert_eq!(column("rank")?, [1, 2, 2, 1, 2]);
+        Ok(())
+    }
+}
@@ -0,0 +1,374 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use common_error::DaftResult;
+use common_runtime::RuntimeRef;
+use daft_core::prelude::{AsArrow, DataType, IntoSeries, SchemaRef, UInt64Array};
+use daft_dsl::{col, lit, Expr, ExprRef};
+use daft_logical_plan::ops::{WINDOW_END_COLUMN_NAME, WINDOW_START_COLUMN_NAME};
+use daft_micropartition::MicroPartition;
+use daft_physical_plan::{extract_agg_expr, populate_aggregation_stages};
+use daft_shuffles::{read_ipc_bytes, write_ipc_bytes};
+use tracing::instrument;
+
+use super::streaming_sink::{
+    StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeResult, StreamingSinkOutput,
+    StreamingSinkState,
+};
+use crate::{
+    dispatcher::{DispatchSpawner, UnorderedDispatcher},
+    state_store::{StateStore, StateStoreConfig},
+    ExecutionRuntimeContext,
+};
+
+/// Key of the latest event time seen so far, as big-endian bytes
+const MAX_EVENT_TIME_KEY: &[u8] = b"m";
+/// Key of the id of the next chunk of rows added to a window, as big-endian bytes
+const NEXT_CHUNK_ID_KEY: &[u8] = b"n";
+/// Prefix of the keys of the chunks of rows of the windows, which is followed by the start of the
+/// window and the id of the chunk
+const WINDOW_KEY_PREFIX: u8 = b'w';
+
+/// Prefix of the keys of the chunks of the window that starts at `window_start`, which sort like
+/// the starts of their windows
+fn window_key(window_start: i64) -> Vec<u8> {
+    let mut key = vec![WINDOW_KEY_PREFIX];
+    key.extend_from_slice(&((window_start as u64) ^ (1 << 63)).to_be_bytes());
+    key
+}
+
+fn window_start_of_key(key: &[u8]) -> i64 {
+    let bytes: [u8; 8] = key[1..9]
+        .try_into()
+        .expect("window keys have a window start");
+    (u64::from_be_bytes(bytes) ^ (1 << 63)) as i64
+}
+
+struct WindowAggregateState {
+    /// Partially aggregated chunks of rows of each open window, or the rows themselves if the
+    /// aggregations can't be partially aggregated, along with the latest event time. Opened on
+    /// first use, restoring the state of the last checkpoint if there is one.
+    store: Option<Box<dyn StateStore>>,
+    state_store_config: StateStoreConfig,
+}
+
+impl StreamingSinkState for WindowAggregateState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl WindowAggregateState {
+    fn store(&mut self) -> DaftResult<&mut dyn StateStore> {
+        if self.store.is_none() {
+            self.store = Some(self.state_store_config.open()?);
+        }
+        Ok(self
+            .store
+            .as_deref_mut()
+            .expect("state store should be open"))
+    }
+
+    fn get_i64(&mut self, key: &[u8]) -> DaftResult<Option<i64>> {
+        Ok(self.store()?.get(key)?.map(|bytes| {
+            i64::from_be_bytes(bytes.try_into().expect("state values should be 8 bytes"))
+        }))
+    }
+
+    fn watermark(&mut self, params: &WindowAggregateParams) -> DaftResult<Option<i64>> {
+        Ok(self
+            .get_i64(MAX_EVENT_TIME_KEY)?
+            .map(|event_time| event_time.saturating_sub(params.watermark_delay)))
+    }
+
+    /// Adds the rows of the input to the windows they fall into, dropping the late rows, i.e.
+    /// those without an event time or with an event time behind the watermark, then advances the
+    /// watermark past the rest.
+    fn push(&mut self, input: &MicroPartition, params: &WindowAggregateParams) -> DaftResult<()> {
+        let watermark = self.watermark(params)?;
+        let mut max_event_time = self.get_i64(MAX_EVENT_TIME_KEY)?;
+        let mut next_chunk_id = self.get_i64(NEXT_CHUNK_ID_KEY)?.unwrap_or(0);
+        for table in input.get_tables()?.iter() {
+            let event_times = table.eval_expression_list(&[params.event_time.clone()])?;
+            let event_times = event_times.get_column_by_index(0)?.i64()?;
+
+            let mut indices_by_window: BTreeMap<i64, Vec<u64>> = BTreeMap::new();
+            for (idx, event_time) in event_times.as_arrow().iter().enumerate() {
+                let Some(&event_time) = event_time else {
+                    continue;
+                };
+                if watermark.is_some_and(|watermark| event_time < watermark) {
+                    continue;
+                }
+                max_event_time = Some(max_event_time.map_or(event_time, |max| max.max(event_time)));
+                let mut window_start =
+                    event_time.div_euclid(params.window_slide) * params.window_slide;
+                while window_start > event_time - params.window_size {
+                    indices_by_window
+                        .entry(window_start)
+                        .or_default()
+                        .push(idx as u64);
+                    window_start -= params.window_slide;
+                }
+            }
+
+            for (window_start, indices) in indices_by_window {
+                let indices = UInt64Array::from(("idx", indices)).into_series();
+                let rows = table.take(&indices)?;
+                let rows = if params.partial_agg_exprs.is_empty() {
+                    rows
+                } else {
+                    rows.agg(&params.partial_agg_exprs, &params.group_by)?
+                };
+                let mut key = window_key(window_start);
+                key.extend_from_slice(&next_chunk_id.to_be_bytes());
+                next_chunk_id += 1;
+                let value = write_ipc_bytes(&rows.schema, &[rows])?;
+                self.store()?.put(&key, &value)?;
+            }
+        }
+        let store = self.store()?;
+        if let Some(max_event_time) = max_event_time {
+            store.put(MAX_EVENT_TIME_KEY, &max_event_time.to_be_bytes())?;
+        }
+        store.put(NEXT_CHUNK_ID_KEY, &next_chunk_id.to_be_bytes())?;
+        Ok(())
+    }
+
+    /// Aggregates and evicts the windows that end at or before `watermark`, or all windows if
+    /// there is no watermark.
+    fn emit(
+        &mut self,
+        watermark: Option<i64>,
+        params: &WindowAggregateParams,
+    ) -> DaftResult<Option<Arc<MicroPartition>>> {
+        let end = match watermark {
+            Some(watermark) => window_key(
+                watermark
+                    .saturating_sub(params.window_size)
+                    .saturating_add(1),
+            ),
+            None => vec![WINDOW_KEY_PREFIX + 1],
+        };
+        let store = self.store()?;
+        let chunks = store.range(&[WINDOW_KEY_PREFIX], Some(end.as_slice()))?;
+        if chunks.is_empty() {
+            return Ok(None);
+        }
+
+        let mut closed: BTreeMap<i64, Vec<MicroPartition>> = BTreeMap::new();
+        for (key, value) in &chunks {
+            let (schema, tables) = read_ipc_bytes(value)?;
+            closed
+                .entry(window_start_of_key(key))
+                .or_default()
+                .push(MicroPartition::new_loaded(schema, Arc::new(tables), None));
+            store.delete(key)?;
+        }
+
+        let aggregated = closed
+            .into_iter()
+            .map(|(window_start, rows)| {
+                let rows = MicroPartition::concat(&rows)?;
+                let aggregated = if params.partial_agg_exprs.is_empty() {
+                    rows.agg(&params.original_aggregations, &params.group_by)?
+                } else {
+                    rows.agg(&params.final_agg_exprs, &params.final_group_by)?
+                        .eval_expression_list(&params.final_projections)?
+                };
+                aggregated
+                    .eval_expression_list(&params.output_projection(window_start))?
+                    .cast_to_schema(params.output_schema.clone())
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Some(Arc::new(MicroPartition::concat(&aggregated)?)))
+    }
+}
+
+struct WindowAggregateParams {
+    // The original aggregations and group by expressions
+    original_aggregations: Vec<ExprRef>,
+    group_by: Vec<ExprRef>,
+    // The expressions for to be used for partial aggregation
+    partial_agg_exprs: Vec<ExprRef>,
+    // The expressions for the final aggregation
+    final_agg_exprs: Vec<ExprRef>,
+    final_group_by: Vec<ExprRef>,
+    final_projections: Vec<ExprRef>,
+    event_time: ExprRef,
+    event_time_dtype: DataType,
+    window_size: i64,
+    window_slide: i64,
+    watermark_delay: i64,
+    output_schema: SchemaRef,
+}
+
+impl WindowAggregateParams {
+    /// Projects the aggregations of a window to the output schema, adding the bounds of the
+    /// window.
+    fn output_projection(&self, window_start: i64) -> Vec<ExprRef> {
+        let window_bound = |bound: i64, name: &str| {
+            lit(bound)
+                .cast(&self.event_time_dtype.to_physical())
+                .cast(&self.event_time_dtype)
+                .alias(name)
+        };
+        self.output_schema
+            .fields
+            .keys()
+            .map(|name| match name.as_str() {
+                WINDOW_START_COLUMN_NAME => window_bound(window_start, name),
+                WINDOW_END_COLUMN_NAME => window_bound(window_start + self.window_size, name),
+                name => col(name),
+            })
+            .collect()
+    }
+}
+
+pub struct WindowAggregateSink {
+    params: Arc<WindowAggregateParams>,
+    state_store_config: StateStoreConfig,
+}
+
+impl WindowAggregateSink {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        aggregations: &[ExprRef],
+        group_by: &[ExprRef],
+        event_time_column: &str,
+        window_size: i64,
+        window_slide: i64,
+        watermark_delay: i64,
+        schema: &SchemaRef,
+        state_store_config: StateStoreConfig,
+    ) -> DaftResult<Self> {
+        let aggregations = aggregations
+            .iter()
+            .map(extract_agg_expr)
+            .collect::<DaftResult<Vec<_>>>()?;
+        let (partial_aggs, final_aggs, final_projections) =
+            populate_aggregation_stages(&aggregations, schema, group_by);
+        let partial_agg_exprs = partial_aggs
+            .into_values()
+            .map(|e| Arc::new(Expr::Agg(e)))
+            .collect::<Vec<_>>();
+        let final_agg_exprs = final_aggs
+            .into_values()
+            .map(|e| Arc::new(Expr::Agg(e)))
+            .collect::<Vec<_>>();
+        let final_group_by = group_by.iter().map(|e| col(e.name())).collect::<Vec<_>>();
+        // The bounds of the windows have the type of the event time column.
+        let event_time_dtype = schema.get_field(WINDOW_START_COLUMN_NAME)?.dtype.clone();
+        Ok(Self {
+            params: Arc::new(WindowAggregateParams {
+                original_aggregations: aggregations
+                    .into_iter()
+                    .map(|e| Expr::Agg(e).into())
+                    .collect(),
+                group_by: group_by.to_vec(),
+                partial_agg_exprs,
+                final_agg_exprs,
+                final_group_by,
+                final_projections,
+                event_time: col(event_time_column).cast(&DataType::Int64),
+                event_time_dtype,
+                window_size,
+                window_slide,
+                watermark_delay,
+                output_schema: schema.clone(),
+            }),
+            state_store_config,
+        })
+    }
+}
+
+impl StreamingSink for WindowAggregateSink {
+    #[instrument(skip_all, name = "WindowAggregateSink::sink")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn StreamingSinkState>,
+        runtime_ref: &RuntimeRef,
+    ) -> StreamingSinkExecuteResult {
+        let params = self.params.clone();
+        runtime_ref
+            .spawn(async move {
+                let window_state = state
+                    .as_any_mut()
+                    .downcast_mut::<WindowAggregateState>()
+                    .expect("WindowAggregateSink should have WindowAggregateState");
+                window_state.push(&input, &params)?;
+                // Windows are only output once the watermark passes their end, so that they
+                // can't receive more rows.
+                let output = match window_state.watermark(&params)? {
+                    Some(watermark) => window_state.emit(Some(watermark), &params)?,
+                    None => None,
+                };
+                window_state.store()?.commit()?;
+
+                Ok((state, StreamingSinkOutput::NeedMoreInput(output)))
+            })
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "WindowAggregate"
+    }
+
+    #[instrument(skip_all, name = "WindowAggregateSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn StreamingSinkState>>,
+        runtime_ref: &RuntimeRef,
+    ) -> StreamingSinkFinalizeResult {
+        let params = self.params.clone();
+        runtime_ref
+            .spawn(async move {
+                // The input has ended, so the remaining windows are complete.
+                let outputs = states
+                    .into_iter()
+                    .map(|mut state| {
+                        let window_state = state
+                            .as_any_mut()
+                            .downcast_mut::<WindowAggregateState>()
+                            .expect("WindowAggregateSink should have WindowAggregateState");
+                        let output = window_state.emit(None, &params)?;
+                        // The windows of the input are done, so a rerun of the query starts over
+                        let store = window_state.store()?;
+                        store.delete(MAX_EVENT_TIME_KEY)?;
+                        store.delete(NEXT_CHUNK_ID_KEY)?;
+                        store.commit()?;
+                        Ok(output)
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                if outputs.is_empty() {
+                    return Ok(None);
+                }
+                Ok(Some(Arc::new(MicroPartition::concat(&outputs)?)))
+            })
+            .into()
+    }
+
+    fn make_state(&self) -> Box<dyn StreamingSinkState> {
+        Box::new(WindowAggregateState {
+            store: None,
+            state_store_config: self.state_store_config.clone(),
+        })
+    }
+
+    // The watermark and the windows must be tracked across all rows in one state store, so this
+    // runs on a single worker.
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+
+    fn dispatch_spawner(
+        &self,
+        _runtime_handle: &ExecutionRuntimeContext,
+        _maintain_order: bool,
+    ) -> Arc<dyn DispatchSpawner> {
+        Arc::new(UnorderedDispatcher::new(None))
+    }
+}
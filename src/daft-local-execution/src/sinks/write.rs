@@ -4,9 +4,10 @@ use common_error::DaftResult;
 use common_runtime::RuntimeRef;
 use daft_core::prelude::SchemaRef;
 use daft_dsl::ExprRef;
+use daft_logical_plan::{OutputFileInfo, WriteMode};
 use daft_micropartition::MicroPartition;
 use daft_table::Table;
-use daft_writers::{FileWriter, WriterFactory};
+use daft_writers::{commit_write, FileWriter, WriterFactory};
 use tracing::instrument;
 
 use super::blocking_sink::{
@@ -53,6 +54,8 @@ pub(crate) struct WriteSink {
     writer_factory: Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<Table>>>,
     partition_by: Option<Vec<ExprRef>>,
     file_schema: SchemaRef,
+    // The files to commit once all writers are closed, and the mode of their write.
+    commit_file_info: Option<(OutputFileInfo, WriteMode)>,
 }
 
 impl WriteSink {
@@ -67,6 +70,15 @@ impl WriteSink {
             writer_factory,
             partition_by,
             file_schema,
+            commit_file_info: None,
+        }
+    }
+
+    /// Commits the written files with [`commit_write`] after closing the writers.
+    pub(crate) fn with_commit(self, file_info: OutputFileInfo, mode: WriteMode) -> Self {
+        Self {
+            commit_file_info: Some((file_info, mode)),
+            ..self
         }
     }
 }
@@ -99,6 +111,7 @@ impl BlockingSink for WriteSink {
         runtime: &RuntimeRef,
     ) -> BlockingSinkFinalizeResult {
         let file_schema = self.file_schema.clone();
+        let commit_file_info = self.commit_file_info.clone();
         runtime
             .spawn(async move {
                 let mut results = vec![];
@@ -109,12 +122,15 @@ impl BlockingSink for WriteSink {
                         .expect("State type mismatch");
                     results.extend(state.writer.close()?);
                 }
+                if let Some((file_info, mode)) = &commit_file_info {
+                    results = commit_write(results, file_info, *mode)?;
+                }
                 let mp = Arc::new(MicroPartition::new_loaded(
                     file_schema,
                     results.into(),
                     None,
                 ));
-                Ok(Some(mp))
+                Ok(vec![mp])
             })
             .into()
     }
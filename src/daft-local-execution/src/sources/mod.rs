@@ -2,3 +2,5 @@ pub mod empty_scan;
 pub mod in_memory;
 pub mod scan_task;
 pub mod source;
+#[cfg(feature = "python")]
+pub mod stream;
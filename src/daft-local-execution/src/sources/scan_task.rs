@@ -1,6 +1,7 @@
 use std::{
     collections::{HashMap, HashSet},
-    sync::Arc,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
 use async_trait::async_trait;
@@ -11,12 +12,16 @@ use common_runtime::get_io_runtime;
 use common_scan_info::Pushdowns;
 use daft_core::prelude::{AsArrow, Int64Array, SchemaRef, Utf8Array};
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
-use daft_io::IOStatsRef;
+use daft_io::{IOStatsContext, IOStatsRef};
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 use daft_micropartition::MicroPartition;
 use daft_parquet::read::{read_parquet_bulk_async, ParquetSchemaInferenceOptions};
-use daft_scan::{ChunkSpec, ScanTask};
-use futures::{Stream, StreamExt, TryStreamExt};
+use daft_scan::{ChunkSpec, DataSource, ScanTask};
+use futures::{
+    future::{BoxFuture, Either},
+    stream::BoxStream,
+    FutureExt, Stream, StreamExt, TryStreamExt,
+};
 use snafu::ResultExt;
 use tracing::instrument;
 
@@ -25,10 +30,64 @@ use crate::{
     NUM_CPUS,
 };
 
+/// Number of scan tasks that must produce output before their median time to first output is used
+/// to detect stragglers
+const MIN_COMPLETED_FOR_SPECULATION: usize = 3;
+/// Scan tasks shorter than this are never duplicated, as a duplicate would not finish much sooner
+const MIN_STRAGGLER_DURATION: Duration = Duration::from_secs(1);
+/// How often running scan tasks are checked for being stragglers
+const STRAGGLER_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The outputs of a scan task, as a stream
+type PartitionStream = BoxStream<'static, DaftResult<Arc<MicroPartition>>>;
+/// The first output of an attempt at reading a scan task, or None if it has no outputs, and the
+/// stream of its remaining outputs
+type AttemptOutput = (Option<DaftResult<Arc<MicroPartition>>>, PartitionStream);
+/// A running attempt at reading a scan task, along with the IO stats of its reads
+type RunningAttempt = BoxFuture<'static, (DaftResult<AttemptOutput>, IOStatsRef)>;
+
+/// Tracks how long the scan tasks of a source take to produce their first output, to detect
+/// stragglers. This includes the scan tasks that were duplicated, so that the median is not biased
+/// towards the faster scan tasks.
+struct StragglerTracker {
+    multiplier: f64,
+    min_straggler_duration: Duration,
+    durations: Mutex<Vec<Duration>>,
+}
+
+impl StragglerTracker {
+    fn new(multiplier: f64) -> Self {
+        Self {
+            multiplier,
+            min_straggler_duration: MIN_STRAGGLER_DURATION,
+            durations: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn record(&self, duration: Duration) {
+        self.durations.lock().unwrap().push(duration);
+    }
+
+    fn is_straggler(&self, elapsed: Duration) -> bool {
+        if elapsed < self.min_straggler_duration {
+            return false;
+        }
+        let mut durations = self.durations.lock().unwrap().clone();
+        if durations.len() < MIN_COMPLETED_FOR_SPECULATION {
+            return false;
+        }
+        let mid = durations.len() / 2;
+        let (_, median, _) = durations.select_nth_unstable(mid);
+        elapsed > median.mul_f64(self.multiplier)
+    }
+}
+
 pub struct ScanTaskSource {
     scan_tasks: Vec<Arc<ScanTask>>,
     num_parallel_tasks: usize,
     schema: SchemaRef,
+    /// Set if stragglers should be duplicated, to how many times the median duration they may run
+    speculative_scan_multiplier: Option<f64>,
 }
 
 impl ScanTaskSource {
@@ -74,6 +133,9 @@ impl ScanTaskSource {
             scan_tasks,
             num_parallel_tasks,
             schema,
+            speculative_scan_multiplier: cfg
+                .enable_speculative_scans
+                .then_some(cfg.speculative_scan_multiplier),
         }
     }
 
@@ -92,6 +154,31 @@ impl Source for ScanTaskSource {
     ) -> DaftResult<SourceStream<'static>> {
         let io_runtime = get_io_runtime(true);
         let delete_map = get_delete_map(&self.scan_tasks).await?.map(Arc::new);
+        if let Some(multiplier) = self.speculative_scan_multiplier {
+            let tracker = Arc::new(StragglerTracker::new(multiplier));
+            let stream_of_streams =
+                futures::stream::iter(self.scan_tasks.clone().into_iter().map(move |scan_task| {
+                    read_scan_task_speculatively(
+                        scan_task,
+                        io_stats.clone(),
+                        delete_map.clone(),
+                        maintain_order,
+                        tracker.clone(),
+                    )
+                }));
+            return match maintain_order {
+                true => Ok(Box::pin(
+                    stream_of_streams
+                        .buffered(self.num_parallel_tasks)
+                        .try_flatten(),
+                )),
+                false => Ok(Box::pin(
+                    stream_of_streams
+                        .buffer_unordered(self.num_parallel_tasks)
+                        .try_flatten_unordered(None),
+                )),
+            };
+        }
         let stream_of_streams =
             futures::stream::iter(self.scan_tasks.clone().into_iter().map(move |scan_task| {
                 let io_stats = io_stats.clone();
@@ -200,39 +287,133 @@ async fn get_delete_map(
         .await?
 }
 
-async fn stream_scan_task(
+/// Reads a scan task, launching a second attempt if the first one becomes a straggler before it
+/// produced any output, and streaming the outputs of whichever attempt successfully produces
+/// output first.
+///
+/// Attempts are only raced until one of them produced output, since its outputs are streamed
+/// downstream as they are produced and could not be taken back if the other attempt won.
+async fn read_scan_task_speculatively(
     scan_task: Arc<ScanTask>,
     io_stats: IOStatsRef,
     delete_map: Option<Arc<HashMap<String, Vec<i64>>>>,
     maintain_order: bool,
-) -> DaftResult<impl Stream<Item = DaftResult<Arc<MicroPartition>>> + Send> {
-    let pushdown_columns = scan_task.pushdowns.columns.as_ref().map(|v| {
-        v.iter()
-            .map(std::string::String::as_str)
-            .collect::<Vec<&str>>()
-    });
-
-    let file_column_names = match (
-        pushdown_columns,
-        scan_task.partition_spec().map(|ps| ps.to_fill_map()),
-    ) {
-        (None, _) => None,
-        (Some(columns), None) => Some(columns.clone()),
-
-        // If the ScanTask has a partition_spec, we elide reads of partition columns from the file
-        (Some(columns), Some(partition_fillmap)) => Some(
-            columns
+    tracker: Arc<StragglerTracker>,
+) -> DaftResult<PartitionStream> {
+    let io_runtime = get_io_runtime(true);
+    let attempt = |io_stats: IOStatsRef| {
+        let scan_task = scan_task.clone();
+        let delete_map = delete_map.clone();
+        let task = io_runtime.spawn(async move {
+            let mut outputs = stream_scan_task(scan_task, io_stats, delete_map, maintain_order)
+                .await?
+                .boxed();
+            let first = outputs.next().await;
+            DaftResult::Ok((first, outputs))
+        });
+        async move { task.await? }.boxed()
+    };
+    let describe = || {
+        format!(
+            "{:?}",
+            scan_task
+                .sources
                 .iter()
-                .filter_map(|s| {
-                    if partition_fillmap.contains_key(s) {
-                        None
-                    } else {
-                        Some(*s)
-                    }
-                })
-                .collect::<Vec<&str>>(),
-        ),
+                .map(|source| source.get_path())
+                .collect::<Vec<_>>()
+        )
+    };
+    race_attempts(attempt, describe, io_stats, &tracker).await
+}
+
+/// Runs an attempt of a scan task, and races it against a second attempt if the tracker deems it
+/// a straggler before it produced its first output.
+///
+/// Each attempt reads with its own IO stats, and only the stats of the attempt whose outputs are
+/// returned are added to `io_stats`, once its outputs are dropped.
+async fn race_attempts(
+    attempt: impl Fn(IOStatsRef) -> BoxFuture<'static, DaftResult<AttemptOutput>>,
+    describe: impl Fn() -> String,
+    io_stats: IOStatsRef,
+    tracker: &StragglerTracker,
+) -> DaftResult<PartitionStream> {
+    let start_attempt = || -> RunningAttempt {
+        let attempt_io_stats = IOStatsContext::new("ScanTaskSource::attempt");
+        attempt(attempt_io_stats.clone())
+            .map(move |result| (result, attempt_io_stats))
+            .boxed()
+    };
+    let start = Instant::now();
+    let mut first = start_attempt();
+    let ((first_output, outputs), attempt_io_stats) = loop {
+        tokio::select! {
+            (result, attempt_io_stats) = &mut first => break (result?, attempt_io_stats),
+            () = tokio::time::sleep(STRAGGLER_CHECK_INTERVAL) => {
+                if tracker.is_straggler(start.elapsed()) {
+                    log::info!(
+                        "Scan task reading {} is a straggler after {:?}, launching a speculative attempt",
+                        describe(),
+                        start.elapsed()
+                    );
+                    let (result, attempt_io_stats) = first_successful(first, start_attempt()).await;
+                    break (result?, attempt_io_stats);
+                }
+            }
+        }
+    };
+    tracker.record(start.elapsed());
+    let winner_io_stats = WinnerIOStats {
+        attempt: attempt_io_stats,
+        source: io_stats,
     };
+    Ok(futures::stream::iter(first_output)
+        .chain(outputs)
+        .map(move |output| {
+            let _ = &winner_io_stats;
+            output
+        })
+        .boxed())
+}
+
+/// Waits for the first of two racing attempts to produce output without failing, or for the
+/// attempt that finishes last if both fail. The other attempt is cancelled when it is dropped.
+async fn first_successful(
+    first: RunningAttempt,
+    second: RunningAttempt,
+) -> (DaftResult<AttemptOutput>, IOStatsRef) {
+    let (finished, other) = match futures::future::select(first, second).await {
+        Either::Left((finished, other)) | Either::Right((finished, other)) => (finished, other),
+    };
+    match &finished.0 {
+        Ok((Some(Ok(_)) | None, _)) => finished,
+        Err(e) | Ok((Some(Err(e)), _)) => {
+            log::warn!("Attempt at reading a scan task failed, waiting for the other attempt: {e}");
+            other.await
+        }
+    }
+}
+
+/// The IO stats of the attempt that won the race to read a scan task, which are added to the
+/// stats of the source when the outputs of the attempt are dropped
+struct WinnerIOStats {
+    attempt: IOStatsRef,
+    source: IOStatsRef,
+}
+
+impl Drop for WinnerIOStats {
+    fn drop(&mut self) {
+        self.source.add(&self.attempt);
+    }
+}
+
+async fn stream_scan_task(
+    scan_task: Arc<ScanTask>,
+    io_stats: IOStatsRef,
+    delete_map: Option<Arc<HashMap<String, Vec<i64>>>>,
+    maintain_order: bool,
+) -> DaftResult<impl Stream<Item = DaftResult<Arc<MicroPartition>>> + Send> {
+    // If the ScanTask has a partition_spec, we elide reads of partition columns from the file
+    let file_column_names = scan_task.file_column_names();
 
     if scan_task.sources.len() != 1 {
         return Err(common_error::DaftError::TypeError(
@@ -355,6 +536,67 @@ async fn stream_scan_task(
             )
             .await?
         }
+        FileFormatConfig::Xml(cfg) => {
+            let include_columns = file_column_names.as_ref().map(|cols| {
+                cols.iter()
+                    .map(|col| (*col).to_string())
+                    .collect::<Vec<_>>()
+            });
+            let table = daft_xml::read_xml(
+                url,
+                cfg,
+                scan_task.schema.clone(),
+                include_columns.as_deref(),
+                scan_task.pushdowns.limit,
+                scan_task.pushdowns.filters.clone(),
+                io_client,
+                Some(io_stats),
+            )
+            .await?;
+            Box::pin(futures::stream::once(async { Ok(table) }))
+        }
+        FileFormatConfig::Excel(cfg) => {
+            let include_columns = file_column_names.as_ref().map(|cols| {
+                cols.iter()
+                    .map(|col| (*col).to_string())
+                    .collect::<Vec<_>>()
+            });
+            let table = daft_excel::read_excel(
+                url,
+                cfg,
+                scan_task.schema.clone(),
+                include_columns.as_deref(),
+                scan_task.pushdowns.limit,
+                scan_task.pushdowns.filters.clone(),
+                io_client,
+                Some(io_stats),
+            )
+            .await?;
+            Box::pin(futures::stream::once(async { Ok(table) }))
+        }
+        FileFormatConfig::Kafka(cfg) => {
+            let DataSource::Kafka { range, .. } = source else {
+                return Err(common_error::DaftError::TypeError(format!(
+                    "Kafka scan task cannot read source {url}"
+                )));
+            };
+            let table = daft_kafka::read_kafka_range(
+                cfg,
+                range,
+                scan_task.schema.clone(),
+                scan_task.pushdowns.limit,
+            )?;
+            Box::pin(futures::stream::once(async { Ok(table) }))
+        }
+        FileFormatConfig::Range => {
+            let DataSource::Range { range, .. } = source else {
+                return Err(common_error::DaftError::TypeError(format!(
+                    "Range scan task cannot read source {url}"
+                )));
+            };
+            let table = range.to_table(scan_task.pushdowns.limit)?;
+            Box::pin(futures::stream::once(async { Ok(table) }))
+        }
         #[cfg(feature = "python")]
         FileFormatConfig::Database(common_file_formats::DatabaseSourceConfig { sql, conn }) => {
             use pyo3::Python;
@@ -396,11 +638,7 @@ async fn stream_scan_task(
         let table = table?;
         let casted_table = table.cast_to_schema_with_fill(
             scan_task.materialized_schema().as_ref(),
-            scan_task
-                .partition_spec()
-                .as_ref()
-                .map(|pspec| pspec.to_fill_map())
-                .as_ref(),
+            scan_task.fill_map().as_ref(),
         )?;
         let mp = Arc::new(MicroPartition::new_loaded(
             scan_task.materialized_schema(),
@@ -410,3 +648,131 @@ async fn stream_scan_task(
         Ok(mp)
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::test::{int_partition, partition_values};
+
+    /// A tracker that deems attempts stragglers once they ran for more than 20ms
+    fn eager_tracker() -> StragglerTracker {
+        let tracker = StragglerTracker {
+            min_straggler_duration: Duration::ZERO,
+            ..StragglerTracker::new(2.0)
+        };
+        for _ in 0..MIN_COMPLETED_FOR_SPECULATION {
+            tracker.record(Duration::from_millis(10));
+        }
+        tracker
+    }
+
+    /// An attempt that outputs the `parts`, or never outputs anything if `hang` is set
+    fn attempt_output(
+        parts: Vec<Vec<i64>>,
+        hang: bool,
+    ) -> BoxFuture<'static, DaftResult<AttemptOutput>> {
+        async move {
+            if hang {
+                futures::future::pending::<()>().await;
+            }
+            let mut outputs =
+                futures::stream::iter(parts.into_iter().map(|values| Ok(int_partition(values))))
+                    .boxed();
+            let first = outputs.next().await;
+            Ok((first, outputs))
+        }
+        .boxed()
+    }
+
+    #[tokio::test]
+    async fn test_straggler_is_raced_against_a_speculative_attempt() -> DaftResult<()> {
+        let tracker = eager_tracker();
+        let attempts = AtomicUsize::new(0);
+        // the first attempt never produces output, so only the speculative attempt can win
+        let attempt = |io_stats: IOStatsRef| {
+            let hang = attempts.fetch_add(1, Ordering::SeqCst) == 0;
+            io_stats.mark_bytes_read(if hang { 100 } else { 1 });
+            attempt_output(vec![vec![0, 1], vec![2]], hang)
+        };
+
+        let io_stats = IOStatsContext::new("test");
+        let outputs = race_attempts(attempt, String::new, io_stats.clone(), &tracker).await?;
+        assert_eq!(
+            partition_values(outputs.collect::<Vec<_>>().await),
+            vec![vec![0, 1], vec![2]]
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        // only the reads of the winning attempt are counted
+        assert_eq!(io_stats.load_bytes_read(), 1);
+        // the duplicated scan task counts towards the median
+        assert_eq!(
+            tracker.durations.lock().unwrap().len(),
+            MIN_COMPLETED_FOR_SPECULATION + 1
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fast_scan_task_is_not_duplicated() -> DaftResult<()> {
+        let tracker = StragglerTracker::new(2.0);
+        let attempts = AtomicUsize::new(0);
+        let attempt = |_: IOStatsRef| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            attempt_output(vec![vec![0], vec![1, 2]], false)
+        };
+
+        let outputs =
+            race_attempts(attempt, String::new, IOStatsContext::new("test"), &tracker).await?;
+        assert_eq!(
+            partition_values(outputs.collect::<Vec<_>>().await),
+            vec![vec![0], vec![1, 2]]
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scan_task_without_outputs() -> DaftResult<()> {
+        let tracker = StragglerTracker::new(2.0);
+        let outputs = race_attempts(
+            |_| attempt_output(vec![], false),
+            String::new,
+            IOStatsContext::new("test"),
+            &tracker,
+        )
+        .await?;
+        assert!(outputs.collect::<Vec<_>>().await.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_failed_attempt_does_not_win_the_race() -> DaftResult<()> {
+        let tracker = eager_tracker();
+        let attempts = AtomicUsize::new(0);
+        // the first attempt fails after the speculative attempt started, but before it finished
+        let attempt = |_: IOStatsRef| {
+            let is_first = attempts.fetch_add(1, Ordering::SeqCst) == 0;
+            async move {
+                if is_first {
+                    tokio::time::sleep(STRAGGLER_CHECK_INTERVAL * 3 / 2).await;
+                    Err(common_error::DaftError::ValueError("failed".to_string()))
+                } else {
+                    tokio::time::sleep(STRAGGLER_CHECK_INTERVAL).await;
+                    attempt_output(vec![vec![0]], false).await
+                }
+            }
+            .boxed()
+        };
+
+        let outputs =
+            race_attempts(attempt, String::new, IOStatsContext::new("test"), &tracker).await?;
+        assert_eq!(
+            partition_values(outputs.collect::<Vec<_>>().await),
+            vec![vec![0]]
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+}
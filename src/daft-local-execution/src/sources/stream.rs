@@ -0,0 +1,142 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use common_error::DaftResult;
+use daft_core::prelude::{DataType, SchemaRef};
+use daft_dsl::{col, lit, ExprRef};
+use daft_io::IOStatsRef;
+use daft_logical_plan::{StreamInfo, Watermark};
+use daft_micropartition::{python::PyMicroPartition, MicroPartition};
+use futures::StreamExt;
+use pyo3::{prelude::*, types::PyIterator};
+use tracing::instrument;
+
+use super::source::Source;
+use crate::sources::source::SourceStream;
+
+/// Source which pulls micro-batches from a Python iterator until it is exhausted, which may
+/// never happen for an unbounded stream.
+pub struct StreamSource {
+    info: StreamInfo,
+}
+
+impl StreamSource {
+    pub fn new(info: StreamInfo) -> Self {
+        Self { info }
+    }
+    pub fn arced(self) -> Arc<dyn Source> {
+        Arc::new(self) as Arc<dyn Source>
+    }
+}
+
+/// Tracks the watermark of a stream from the event times of its micro-batches, and drops the rows
+/// that are behind it.
+struct WatermarkTracker {
+    event_time_column: String,
+    delay: i64,
+    max_event_time: Option<i64>,
+}
+
+impl WatermarkTracker {
+    fn new(watermark: &Watermark) -> Self {
+        Self {
+            event_time_column: watermark.event_time_column.clone(),
+            delay: watermark.delay,
+            max_event_time: None,
+        }
+    }
+
+    fn event_time(&self) -> ExprRef {
+        col(self.event_time_column.as_str()).cast(&DataType::Int64)
+    }
+
+    fn watermark(&self) -> Option<i64> {
+        self.max_event_time
+            .map(|event_time| event_time.saturating_sub(self.delay))
+    }
+
+    /// Drops the late rows of the batch, i.e. those without an event time or with an event time
+    /// behind the watermark, then advances the watermark past the rest.
+    fn apply(&mut self, batch: MicroPartition) -> DaftResult<MicroPartition> {
+        let batch = match self.watermark() {
+            Some(watermark) => batch.filter(&[self.event_time().gt_eq(lit(watermark))])?,
+            None => batch.filter(&[self.event_time().not_null()])?,
+        };
+        let max_event_time = batch.agg(&[self.event_time().max()], &[])?;
+        for table in max_event_time.get_tables()?.iter() {
+            if let Some(event_time) = table.get_column(&self.event_time_column)?.i64()?.get(0) {
+                self.max_event_time = Some(
+                    self.max_event_time
+                        .map_or(event_time, |max| max.max(event_time)),
+                );
+            }
+        }
+        Ok(batch)
+    }
+}
+
+struct StreamState {
+    batches: Py<PyIterator>,
+    schema: SchemaRef,
+    poll_interval: Duration,
+    watermark: Option<WatermarkTracker>,
+}
+
+impl StreamState {
+    /// Pulls the next non-empty micro-batch, waiting for as long as no data is available.
+    async fn next_batch(&mut self) -> DaftResult<Option<Arc<MicroPartition>>> {
+        loop {
+            let batch = Python::with_gil(|py| -> DaftResult<Option<Option<PyMicroPartition>>> {
+                match self.batches.bind(py).clone().next() {
+                    Some(batch) => Ok(Some(batch?.extract()?)),
+                    None => Ok(None),
+                }
+            })?;
+            match batch {
+                Some(Some(batch)) => {
+                    let batch: Arc<MicroPartition> = batch.into();
+                    let mut batch = batch.cast_to_schema(self.schema.clone())?;
+                    if let Some(watermark) = &mut self.watermark {
+                        batch = watermark.apply(batch)?;
+                    }
+                    if !batch.is_empty() {
+                        return Ok(Some(Arc::new(batch)));
+                    }
+                }
+                Some(None) => tokio::time::sleep(self.poll_interval).await,
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Source for StreamSource {
+    #[instrument(name = "StreamSource::get_data", level = "info", skip_all)]
+    async fn get_data(
+        &self,
+        _maintain_order: bool,
+        _io_stats: IOStatsRef,
+    ) -> DaftResult<SourceStream<'static>> {
+        let batches = Python::with_gil(|py| -> DaftResult<Py<PyIterator>> {
+            let batches = self.info.batch_iterator_factory.as_ref().call0(py)?;
+            Ok(batches.bind(py).iter()?.unbind())
+        })?;
+        let state = StreamState {
+            batches,
+            schema: self.info.source_schema.clone(),
+            poll_interval: Duration::from_millis(self.info.poll_interval_ms),
+            watermark: self.info.watermark.as_ref().map(WatermarkTracker::new),
+        };
+        let stream = futures::stream::try_unfold(state, |mut state| async move {
+            Ok(state.next_batch().await?.map(|batch| (batch, state)))
+        });
+        Ok(stream.boxed())
+    }
+    fn name(&self) -> &'static str {
+        "StreamScan"
+    }
+    fn schema(&self) -> &SchemaRef {
+        &self.info.source_schema
+    }
+}
@@ -0,0 +1,74 @@
+use std::{collections::BTreeMap, ops::Bound, path::PathBuf};
+
+use common_error::{DaftError, DaftResult};
+
+use super::StateStore;
+
+/// Name of the file with the latest checkpoint in the checkpoint directory of a store
+const CHECKPOINT_FILE_NAME: &str = "state.bin";
+
+/// A [`StateStore`] that keeps its state in memory, and checkpoints all of it to a file on each
+/// commit if it has a checkpoint directory.
+#[derive(Debug, Default)]
+pub(crate) struct InMemoryStateStore {
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+    checkpoint_dir: Option<PathBuf>,
+}
+
+impl InMemoryStateStore {
+    pub fn open(checkpoint_dir: Option<PathBuf>) -> DaftResult<Self> {
+        let entries = match &checkpoint_dir {
+            Some(dir) if dir.join(CHECKPOINT_FILE_NAME).is_file() => {
+                let bytes = std::fs::read(dir.join(CHECKPOINT_FILE_NAME))?;
+                bincode::deserialize(&bytes).map_err(|e| DaftError::External(e.into()))?
+            }
+            _ => BTreeMap::new(),
+        };
+        Ok(Self {
+            entries,
+            checkpoint_dir,
+        })
+    }
+}
+
+impl StateStore for InMemoryStateStore {
+    fn get(&self, key: &[u8]) -> DaftResult<Option<Vec<u8>>> {
+        Ok(self.entries.get(key).cloned())
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> DaftResult<()> {
+        self.entries.insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &[u8]) -> DaftResult<()> {
+        self.entries.remove(key);
+        Ok(())
+    }
+
+    fn range(&self, start: &[u8], end: Option<&[u8]>) -> DaftResult<Vec<(Vec<u8>, Vec<u8>)>> {
+        if end.is_some_and(|end| end <= start) {
+            return Ok(vec![]);
+        }
+        let end = end.map_or(Bound::Unbounded, Bound::Excluded);
+        Ok(self
+            .entries
+            .range::<[u8], _>((Bound::Included(start), end))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+
+    fn commit(&mut self) -> DaftResult<()> {
+        let Some(dir) = &self.checkpoint_dir else {
+            return Ok(());
+        };
+        let bytes = bincode::serialize(&self.entries).map_err(|e| DaftError::External(e.into()))?;
+        // Write to a temporary file first, so that a failure while checkpointing leaves the
+        // previous checkpoint intact
+        std::fs::create_dir_all(dir)?;
+        let tmp_path = dir.join(CHECKPOINT_FILE_NAME).with_extension("tmp");
+        std::fs::write(&tmp_path, bytes)?;
+        std::fs::rename(&tmp_path, dir.join(CHECKPOINT_FILE_NAME))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//! Key-value state that stateful streaming operators, such as window aggregations, keep across
+//! the micro-batches of a stream.
+//!
+//! Operators read and write their state through the [`StateStore`] trait and commit it after each
+//! micro-batch. If the execution config has a `checkpoint_dir`, each commit is a checkpoint that
+//! the operator's state is restored from when the query is run again, e.g. after a failure.
+
+mod memory;
+#[cfg(feature = "rocksdb")]
+mod rocksdb;
+
+use std::{
+    fmt::{Display, Formatter},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use common_daft_config::DaftExecutionConfig;
+use common_error::{DaftError, DaftResult};
+pub(crate) use memory::InMemoryStateStore;
+
+/// An ordered map of byte keys to byte values, whose changes are made durable by [`Self::commit`].
+///
+/// Changes that were not committed are lost when the query fails, so a store that is restored
+/// from a checkpoint has the state of its last commit.
+pub(crate) trait StateStore: Send + Sync {
+    fn get(&self, key: &[u8]) -> DaftResult<Option<Vec<u8>>>;
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> DaftResult<()>;
+
+    fn delete(&mut self, key: &[u8]) -> DaftResult<()>;
+
+    /// The entries with keys in `[start, end)` in key order, or with keys of at least `start` if
+    /// there is no end.
+    fn range(&self, start: &[u8], end: Option<&[u8]>) -> DaftResult<Vec<(Vec<u8>, Vec<u8>)>>;
+
+    /// Commits the changes since the last commit, checkpointing them if the store has a
+    /// checkpoint directory.
+    fn commit(&mut self) -> DaftResult<()>;
+}
+
+/// Where a [`StateStore`] keeps its state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum StateStoreBackend {
+    /// A map in memory, which is checkpointed by writing all of it on each commit.
+    Memory,
+    /// A RocksDB database on local disk, so that the state may be larger than memory and only
+    /// the changes are written on each commit. Requires Daft to be built with the `rocksdb`
+    /// feature.
+    RocksDb,
+}
+
+impl FromStr for StateStoreBackend {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s {
+            "memory" => Ok(Self::Memory),
+            "rocksdb" => Ok(Self::RocksDb),
+            _ => Err(DaftError::ValueError(format!(
+                "Unknown state store backend {s:?}, expected \"memory\" or \"rocksdb\""
+            ))),
+        }
+    }
+}
+
+impl Display for StateStoreBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Memory => write!(f, "memory"),
+            Self::RocksDb => write!(f, "rocksdb"),
+        }
+    }
+}
+
+/// How to open the state store of a stateful operator.
+#[derive(Clone, Debug)]
+pub(crate) struct StateStoreConfig {
+    backend: StateStoreBackend,
+    /// Directory of the operator's checkpoints, if its state is checkpointed
+    checkpoint_dir: Option<PathBuf>,
+}
+
+impl StateStoreConfig {
+    /// The state store config of the operator identified by `operator_key` in the queries run
+    /// with `cfg`. The key must be the same across runs of the same query for the operator's
+    /// state to be restored.
+    pub fn try_new(cfg: &DaftExecutionConfig, operator_key: &str) -> DaftResult<Self> {
+        Ok(Self {
+            backend: cfg.state_store_backend.parse()?,
+            checkpoint_dir: cfg
+                .checkpoint_dir
+                .as_ref()
+                .map(|dir| Path::new(dir).join("state").join(operator_key)),
+        })
+    }
+
+    /// Opens the store, restoring the state of its last checkpoint if there is one.
+    pub fn open(&self) -> DaftResult<Box<dyn StateStore>> {
+        match self.backend {
+            StateStoreBackend::Memory => Ok(Box::new(InMemoryStateStore::open(
+                self.checkpoint_dir.clone(),
+            )?)),
+            #[cfg(feature = "rocksdb")]
+            StateStoreBackend::RocksDb => Ok(Box::new(rocksdb::RocksDbStateStore::open(
+                self.checkpoint_dir.as_deref(),
+            )?)),
+            #[cfg(not(feature = "rocksdb"))]
+            StateStoreBackend::RocksDb => Err(DaftError::ValueError(
+                "The rocksdb state store backend requires Daft to be built with the rocksdb \
+                feature"
+                    .to_string(),
+            )),
+        }
+    }
+}
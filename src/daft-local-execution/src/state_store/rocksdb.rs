@@ -0,0 +1,106 @@
+use std::{collections::BTreeMap, path::Path};
+
+use common_error::{DaftError, DaftResult};
+use rocksdb::{Direction, IteratorMode, WriteBatch, DB};
+use tempfile::TempDir;
+
+use super::StateStore;
+
+fn to_daft_error(e: rocksdb::Error) -> DaftError {
+    DaftError::External(e.into())
+}
+
+/// A [`StateStore`] backed by a RocksDB database, so that its state may be larger than memory.
+///
+/// Changes are buffered in memory until they are committed, when they are written to the
+/// database in one atomic batch. The database is in the checkpoint directory if there is one, and
+/// otherwise in a temp directory that is removed when the store is dropped.
+pub(crate) struct RocksDbStateStore {
+    db: DB,
+    /// Changes since the last commit by key, with `None` for deleted keys
+    pending: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+    // Kept to remove the database when the store is dropped, if it isn't checkpointed
+    _temp_dir: Option<TempDir>,
+}
+
+impl RocksDbStateStore {
+    pub fn open(checkpoint_dir: Option<&Path>) -> DaftResult<Self> {
+        let (path, temp_dir) = match checkpoint_dir {
+            Some(dir) => (dir.join("rocksdb"), None),
+            None => {
+                let temp_dir = tempfile::Builder::new()
+                    .prefix("daft-state-store-")
+                    .tempdir()?;
+                (temp_dir.path().to_path_buf(), Some(temp_dir))
+            }
+        };
+        std::fs::create_dir_all(&path)?;
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        let db = DB::open(&options, &path).map_err(to_daft_error)?;
+        Ok(Self {
+            db,
+            pending: BTreeMap::new(),
+            _temp_dir: temp_dir,
+        })
+    }
+}
+
+impl StateStore for RocksDbStateStore {
+    fn get(&self, key: &[u8]) -> DaftResult<Option<Vec<u8>>> {
+        match self.pending.get(key) {
+            Some(value) => Ok(value.clone()),
+            None => self.db.get(key).map_err(to_daft_error),
+        }
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> DaftResult<()> {
+        self.pending.insert(key.to_vec(), Some(value.to_vec()));
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &[u8]) -> DaftResult<()> {
+        self.pending.insert(key.to_vec(), None);
+        Ok(())
+    }
+
+    fn range(&self, start: &[u8], end: Option<&[u8]>) -> DaftResult<Vec<(Vec<u8>, Vec<u8>)>> {
+        let in_range = |key: &[u8]| end.map_or(true, |end| key < end);
+        let mut entries = BTreeMap::new();
+        for entry in self
+            .db
+            .iterator(IteratorMode::From(start, Direction::Forward))
+        {
+            let (key, value) = entry.map_err(to_daft_error)?;
+            if !in_range(&key) {
+                break;
+            }
+            entries.insert(key.to_vec(), value.to_vec());
+        }
+        // Apply the changes that haven't been committed yet
+        for (key, value) in self.pending.range::<[u8], _>(start..) {
+            if !in_range(key) {
+                break;
+            }
+            match value {
+                Some(value) => entries.insert(key.clone(), value.clone()),
+                None => entries.remove(key),
+            };
+        }
+        Ok(entries.into_iter().collect())
+    }
+
+    fn commit(&mut self) -> DaftResult<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let mut batch = WriteBatch::default();
+        for (key, value) in std::mem::take(&mut self.pending) {
+            match value {
+                Some(value) => batch.put(key, value),
+                None => batch.delete(key),
+            }
+        }
+        self.db.write(batch).map_err(to_daft_error)
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_micropartition::{
+    partitioning::{
+        InMemoryPartitionSetCache, MicroPartitionSet, PartitionCacheEntry, PartitionMetadata,
+        PartitionSet, PartitionSetCache,
+    },
+    MicroPartition,
+};
+use daft_table::Table;
+
+static NEXT_SOURCE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A partition with the `values` as its Int64 column `a`
+pub fn int_partition(values: Vec<i64>) -> Arc<MicroPartition> {
+    let table =
+        Table::from_nonempty_columns(vec![Int64Array::from(("a", values)).into_series()]).unwrap();
+    Arc::new(MicroPartition::new_loaded(
+        table.schema.clone(),
+        Arc::new(vec![table]),
+        None,
+    ))
+}
+
+/// An in-memory source of Int64 column `a`, with the partition set cache that it is read from
+pub struct InMemorySource {
+    pub builder: LogicalPlanBuilder,
+    pub psets: InMemoryPartitionSetCache,
+    key: String,
+    // The cache only holds a weak reference to the partition set
+    pset: Arc<MicroPartitionSet>,
+}
+
+impl InMemorySource {
+    /// Makes the source readable from another partition set cache, e.g. that of another source
+    /// that is read by the same query
+    pub fn add_to(&self, psets: &InMemoryPartitionSetCache) {
+        psets.put_partition_set(&self.key, &self.pset);
+    }
+}
+
+/// An in-memory source with one partition per item of `partitions`
+pub fn in_memory_source(partitions: Vec<Vec<i64>>) -> InMemorySource {
+    let key = format!(
+        "test-source-{}",
+        NEXT_SOURCE_ID.fetch_add(1, Ordering::Relaxed)
+    );
+    let parts = partitions
+        .into_iter()
+        .map(int_partition)
+        .collect::<Vec<_>>();
+    let schema = parts[0].schema();
+    let pset = Arc::new(MicroPartitionSet::from(parts));
+    let PartitionMetadata {
+        num_rows,
+        size_bytes,
+    } = pset.metadata();
+
+    let psets = InMemoryPartitionSetCache::empty();
+    psets.put_partition_set(&key, &pset);
+    let builder = LogicalPlanBuilder::in_memory_scan(
+        &key,
+        PartitionCacheEntry::new_rust(key.clone(), pset.clone()),
+        schema,
+        pset.num_partitions(),
+        size_bytes,
+        num_rows,
+        None,
+    )
+    .unwrap();
+    InMemorySource {
+        builder,
+        psets,
+        key,
+        pset,
+    }
+}
+
+/// The values of column `a` of each of the `parts`
+pub fn partition_values(
+    parts: impl IntoIterator<Item = DaftResult<Arc<MicroPartition>>>,
+) -> Vec<Vec<i64>> {
+    parts
+        .into_iter()
+        .map(|part| {
+            part.unwrap()
+                .get_tables()
+                .unwrap()
+                .iter()
+                .flat_map(|table| {
+                    table
+                        .get_column("a")
+                        .unwrap()
+                        .i64()
+                        .unwrap()
+                        .as_arrow()
+                        .values_iter()
+                        .copied()
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        })
+        .collect()
+}
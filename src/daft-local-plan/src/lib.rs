@@ -7,8 +7,10 @@ pub use plan::CatalogWrite;
 #[cfg(feature = "python")]
 pub use plan::LanceWrite;
 pub use plan::{
-    ActorPoolProject, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate, HashJoin,
-    InMemoryScan, Limit, LocalPhysicalPlan, LocalPhysicalPlanRef, MonotonicallyIncreasingId,
-    PhysicalScan, PhysicalWrite, Pivot, Project, Sample, Sort, UnGroupedAggregate, Unpivot,
+    ActorPoolProject, AssertUnique, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate,
+    HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, LocalPhysicalPlanRef,
+    MonotonicallyIncreasingId, PhysicalScan, PhysicalWrite, Pivot, Project, RangeBound, RangeJoin,
+    Repartition, Sample, Sort, SortMergeJoin, StreamScan, TopN, UnGroupedAggregate, Unpivot,
+    Window, WindowAggregate,
 };
 pub use translate::translate;
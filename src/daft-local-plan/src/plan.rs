@@ -5,8 +5,10 @@ use common_scan_info::{Pushdowns, ScanTaskLikeRef};
 use daft_core::prelude::*;
 use daft_dsl::{AggExpr, ExprRef};
 use daft_logical_plan::{
+    ops::WindowFunction,
+    partitioning::RepartitionSpec,
     stats::{PlanStats, StatsState},
-    InMemoryInfo, OutputFileInfo,
+    InMemoryInfo, OutputFileInfo, StreamInfo, WriteMode,
 };
 
 pub type LocalPhysicalPlanRef = Arc<LocalPhysicalPlan>;
@@ -15,6 +17,7 @@ pub enum LocalPhysicalPlan {
     InMemoryScan(InMemoryScan),
     PhysicalScan(PhysicalScan),
     EmptyScan(EmptyScan),
+    StreamScan(StreamScan),
     Project(Project),
     ActorPoolProject(ActorPoolProject),
     Filter(Filter),
@@ -22,9 +25,12 @@ pub enum LocalPhysicalPlan {
     Explode(Explode),
     Unpivot(Unpivot),
     Sort(Sort),
+    TopN(TopN),
     // Split(Split),
     Sample(Sample),
+    Repartition(Repartition),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    AssertUnique(AssertUnique),
     // Coalesce(Coalesce),
     // Flatten(Flatten),
     // FanoutRandom(FanoutRandom),
@@ -33,11 +39,14 @@ pub enum LocalPhysicalPlan {
     // ReduceMerge(ReduceMerge),
     UnGroupedAggregate(UnGroupedAggregate),
     HashAggregate(HashAggregate),
+    WindowAggregate(WindowAggregate),
+    Window(Window),
     Pivot(Pivot),
     Concat(Concat),
     HashJoin(HashJoin),
     CrossJoin(CrossJoin),
-    // SortMergeJoin(SortMergeJoin),
+    SortMergeJoin(SortMergeJoin),
+    RangeJoin(RangeJoin),
     // BroadcastJoin(BroadcastJoin),
     PhysicalWrite(PhysicalWrite),
     // TabularWriteJson(TabularWriteJson),
@@ -65,6 +74,7 @@ impl LocalPhysicalPlan {
             Self::InMemoryScan(InMemoryScan { stats_state, .. })
             | Self::PhysicalScan(PhysicalScan { stats_state, .. })
             | Self::EmptyScan(EmptyScan { stats_state, .. })
+            | Self::StreamScan(StreamScan { stats_state, .. })
             | Self::Project(Project { stats_state, .. })
             | Self::ActorPoolProject(ActorPoolProject { stats_state, .. })
             | Self::Filter(Filter { stats_state, .. })
@@ -72,14 +82,21 @@ impl LocalPhysicalPlan {
             | Self::Explode(Explode { stats_state, .. })
             | Self::Unpivot(Unpivot { stats_state, .. })
             | Self::Sort(Sort { stats_state, .. })
+            | Self::TopN(TopN { stats_state, .. })
             | Self::Sample(Sample { stats_state, .. })
+            | Self::Repartition(Repartition { stats_state, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
+            | Self::AssertUnique(AssertUnique { stats_state, .. })
             | Self::UnGroupedAggregate(UnGroupedAggregate { stats_state, .. })
             | Self::HashAggregate(HashAggregate { stats_state, .. })
+            | Self::WindowAggregate(WindowAggregate { stats_state, .. })
+            | Self::Window(Window { stats_state, .. })
             | Self::Pivot(Pivot { stats_state, .. })
             | Self::Concat(Concat { stats_state, .. })
             | Self::HashJoin(HashJoin { stats_state, .. })
             | Self::CrossJoin(CrossJoin { stats_state, .. })
+            | Self::SortMergeJoin(SortMergeJoin { stats_state, .. })
+            | Self::RangeJoin(RangeJoin { stats_state, .. })
             | Self::PhysicalWrite(PhysicalWrite { stats_state, .. }) => stats_state,
             #[cfg(feature = "python")]
             Self::CatalogWrite(CatalogWrite { stats_state, .. })
@@ -121,6 +138,17 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn stream_scan(
+        stream_info: StreamInfo,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::StreamScan(StreamScan {
+            info: stream_info,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn filter(
         input: LocalPhysicalPlanRef,
         predicate: ExprRef,
@@ -228,6 +256,58 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn window_aggregate(
+        input: LocalPhysicalPlanRef,
+        aggregations: Vec<ExprRef>,
+        group_by: Vec<ExprRef>,
+        event_time_column: String,
+        window_size: i64,
+        window_slide: i64,
+        watermark_delay: i64,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::WindowAggregate(WindowAggregate {
+            input,
+            aggregations,
+            group_by,
+            event_time_column,
+            window_size,
+            window_slide,
+            watermark_delay,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn window(
+        input: LocalPhysicalPlanRef,
+        partition_by: Vec<ExprRef>,
+        order_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        functions: Vec<WindowFunction>,
+        names: Vec<String>,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::Window(Window {
+            input,
+            partition_by,
+            order_by,
+            descending,
+            nulls_first,
+            functions,
+            names,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn unpivot(
         input: LocalPhysicalPlanRef,
         ids: Vec<ExprRef>,
@@ -292,6 +372,27 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn top_n(
+        input: LocalPhysicalPlanRef,
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        limit: i64,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        let schema = input.schema().clone();
+        Self::TopN(TopN {
+            input,
+            sort_by,
+            descending,
+            nulls_first,
+            limit,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn sample(
         input: LocalPhysicalPlanRef,
         fraction: f64,
@@ -311,6 +412,23 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn repartition(
+        input: LocalPhysicalPlanRef,
+        repartition_spec: RepartitionSpec,
+        num_partitions: usize,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        let schema = input.schema().clone();
+        Self::Repartition(Repartition {
+            input,
+            repartition_spec,
+            num_partitions,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn monotonically_increasing_id(
         input: LocalPhysicalPlanRef,
         column_name: String,
@@ -326,6 +444,21 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn assert_unique(
+        input: LocalPhysicalPlanRef,
+        keys: Vec<ExprRef>,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::AssertUnique(AssertUnique {
+            input,
+            keys,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn hash_join(
         left: LocalPhysicalPlanRef,
@@ -334,6 +467,8 @@ impl LocalPhysicalPlan {
         right_on: Vec<ExprRef>,
         null_equals_null: Option<Vec<bool>>,
         join_type: JoinType,
+        left_cache_id: Option<usize>,
+        right_cache_id: Option<usize>,
         schema: SchemaRef,
         stats_state: StatsState,
     ) -> LocalPhysicalPlanRef {
@@ -344,6 +479,8 @@ impl LocalPhysicalPlan {
             right_on,
             null_equals_null,
             join_type,
+            left_cache_id,
+            right_cache_id,
             schema,
             stats_state,
         })
@@ -365,6 +502,52 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn sort_merge_join(
+        left: LocalPhysicalPlanRef,
+        right: LocalPhysicalPlanRef,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        is_sorted: bool,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::SortMergeJoin(SortMergeJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            is_sorted,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn range_join(
+        left: LocalPhysicalPlanRef,
+        right: LocalPhysicalPlanRef,
+        right_on: ExprRef,
+        lower_bound: Option<RangeBound>,
+        upper_bound: Option<RangeBound>,
+        residual_predicate: Option<ExprRef>,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::RangeJoin(RangeJoin {
+            left,
+            right,
+            right_on,
+            lower_bound,
+            upper_bound,
+            residual_predicate,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn concat(
         input: LocalPhysicalPlanRef,
         other: LocalPhysicalPlanRef,
@@ -392,6 +575,26 @@ impl LocalPhysicalPlan {
             data_schema,
             file_schema,
             file_info,
+            copy_into_mode: None,
+            stats_state,
+        })
+        .arced()
+    }
+
+    pub(crate) fn copy_into(
+        input: LocalPhysicalPlanRef,
+        data_schema: SchemaRef,
+        file_schema: SchemaRef,
+        file_info: OutputFileInfo,
+        mode: WriteMode,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::PhysicalWrite(PhysicalWrite {
+            input,
+            data_schema,
+            file_schema,
+            file_info,
+            copy_into_mode: Some(mode),
             stats_state,
         })
         .arced()
@@ -443,17 +646,25 @@ impl LocalPhysicalPlan {
             | Self::ActorPoolProject(ActorPoolProject { schema, .. })
             | Self::UnGroupedAggregate(UnGroupedAggregate { schema, .. })
             | Self::HashAggregate(HashAggregate { schema, .. })
+            | Self::WindowAggregate(WindowAggregate { schema, .. })
+            | Self::Window(Window { schema, .. })
             | Self::Pivot(Pivot { schema, .. })
             | Self::Sort(Sort { schema, .. })
+            | Self::TopN(TopN { schema, .. })
             | Self::Sample(Sample { schema, .. })
+            | Self::Repartition(Repartition { schema, .. })
             | Self::HashJoin(HashJoin { schema, .. })
             | Self::CrossJoin(CrossJoin { schema, .. })
+            | Self::SortMergeJoin(SortMergeJoin { schema, .. })
+            | Self::RangeJoin(RangeJoin { schema, .. })
             | Self::Explode(Explode { schema, .. })
             | Self::Unpivot(Unpivot { schema, .. })
             | Self::Concat(Concat { schema, .. })
-            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => schema,
+            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. })
+            | Self::AssertUnique(AssertUnique { schema, .. }) => schema,
             Self::PhysicalWrite(PhysicalWrite { file_schema, .. }) => file_schema,
             Self::InMemoryScan(InMemoryScan { info, .. }) => &info.source_schema,
+            Self::StreamScan(StreamScan { info, .. }) => &info.source_schema,
             #[cfg(feature = "python")]
             Self::CatalogWrite(CatalogWrite { file_schema, .. }) => file_schema,
             #[cfg(feature = "python")]
@@ -482,6 +693,12 @@ pub struct EmptyScan {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct StreamScan {
+    pub info: StreamInfo,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct Project {
     pub input: LocalPhysicalPlanRef,
@@ -532,6 +749,17 @@ pub struct Sort {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct TopN {
+    pub input: LocalPhysicalPlanRef,
+    pub sort_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub nulls_first: Vec<bool>,
+    pub limit: i64,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct Sample {
     pub input: LocalPhysicalPlanRef,
@@ -542,6 +770,15 @@ pub struct Sample {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct Repartition {
+    pub input: LocalPhysicalPlanRef,
+    pub repartition_spec: RepartitionSpec,
+    pub num_partitions: usize,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct MonotonicallyIncreasingId {
     pub input: LocalPhysicalPlanRef,
@@ -550,6 +787,14 @@ pub struct MonotonicallyIncreasingId {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct AssertUnique {
+    pub input: LocalPhysicalPlanRef,
+    pub keys: Vec<ExprRef>,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct UnGroupedAggregate {
     pub input: LocalPhysicalPlanRef,
@@ -578,6 +823,32 @@ pub struct Unpivot {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct WindowAggregate {
+    pub input: LocalPhysicalPlanRef,
+    pub aggregations: Vec<ExprRef>,
+    pub group_by: Vec<ExprRef>,
+    pub event_time_column: String,
+    pub window_size: i64,
+    pub window_slide: i64,
+    pub watermark_delay: i64,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+#[derive(Debug)]
+pub struct Window {
+    pub input: LocalPhysicalPlanRef,
+    pub partition_by: Vec<ExprRef>,
+    pub order_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub nulls_first: Vec<bool>,
+    pub functions: Vec<WindowFunction>,
+    pub names: Vec<String>,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct Pivot {
     pub input: LocalPhysicalPlanRef,
@@ -598,6 +869,10 @@ pub struct HashJoin {
     pub right_on: Vec<ExprRef>,
     pub null_equals_null: Option<Vec<bool>>,
     pub join_type: JoinType,
+    /// Cache ids of the sides of the join, which are shared by the sides of other joins that
+    /// produce the same output, so that a probe table of the same build side can be shared.
+    pub left_cache_id: Option<usize>,
+    pub right_cache_id: Option<usize>,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
 }
@@ -610,6 +885,41 @@ pub struct CrossJoin {
     pub stats_state: StatsState,
 }
 
+/// Inner join of inputs that are sorted by their join keys, or that are sorted on the fly if
+/// `is_sorted` is false.
+#[derive(Debug)]
+pub struct SortMergeJoin {
+    pub left: LocalPhysicalPlanRef,
+    pub right: LocalPhysicalPlanRef,
+    pub left_on: Vec<ExprRef>,
+    pub right_on: Vec<ExprRef>,
+    pub is_sorted: bool,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+/// Inner join of each row of the left side with the rows of the right side whose `right_on` key
+/// lies between the bounds computed from the left row, followed by a filter of the joined rows by
+/// the rest of the join predicate. Runs as a binary search of the right side sorted by its key.
+#[derive(Debug)]
+pub struct RangeJoin {
+    pub left: LocalPhysicalPlanRef,
+    pub right: LocalPhysicalPlanRef,
+    pub right_on: ExprRef,
+    pub lower_bound: Option<RangeBound>,
+    pub upper_bound: Option<RangeBound>,
+    pub residual_predicate: Option<ExprRef>,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+/// A bound of the `right_on` key of a [`RangeJoin`], evaluated on the left side.
+#[derive(Clone, Debug)]
+pub struct RangeBound {
+    pub left_on: ExprRef,
+    pub inclusive: bool,
+}
+
 #[derive(Debug)]
 pub struct Concat {
     pub input: LocalPhysicalPlanRef,
@@ -624,6 +934,8 @@ pub struct PhysicalWrite {
     pub data_schema: SchemaRef,
     pub file_schema: SchemaRef,
     pub file_info: OutputFileInfo,
+    /// Set for COPY INTO exports, whose outputs are a manifest of the written files
+    pub copy_into_mode: Option<WriteMode>,
     pub stats_state: StatsState,
 }
 
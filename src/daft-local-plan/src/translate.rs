@@ -1,14 +1,109 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
-use common_error::{DaftError, DaftResult};
+use common_error::DaftResult;
 use common_scan_info::ScanState;
-use daft_core::join::JoinStrategy;
-use daft_dsl::ExprRef;
-use daft_logical_plan::{JoinType, LogicalPlan, LogicalPlanRef, SourceInfo};
+use daft_algebra::boolean::{combine_conjunction, split_conjunction};
+use daft_core::{join::JoinStrategy, prelude::Schema};
+use daft_dsl::{optimization::get_required_columns, Expr, ExprRef, Operator};
+use daft_logical_plan::{
+    ops::{Filter, Join, Sample, Source},
+    partitioning::{
+        HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RangeRepartitionConfig,
+        RepartitionSpec,
+    },
+    ClusteringSpec, JoinType, LogicalPlan, LogicalPlanRef, PlaceHolderInfo, SourceInfo,
+};
 
-use super::plan::{LocalPhysicalPlan, LocalPhysicalPlanRef};
+use super::plan::{LocalPhysicalPlan, LocalPhysicalPlanRef, RangeBound};
 
 pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
+    translate_node(plan, &mut SharedSubplans::default())
+}
+
+/// Subplans that occur more than once in a plan, e.g. a dimension table that is joined several
+/// times. Every distinct subplan is assigned a cache id and is only translated once, and the
+/// executor only materializes the build side of joins with the same cache id once.
+#[derive(Default)]
+struct SharedSubplans {
+    /// Cache ids of the nodes of the plan by address, so that every node is only identified once.
+    node_ids: HashMap<*const LogicalPlan, Option<usize>>,
+    /// Cache ids of the distinct subplans. They are keyed on the subplan's root with its inputs
+    /// replaced by placeholders that carry the inputs' cache ids, so that looking up a subplan
+    /// doesn't hash or compare all of it.
+    cache_ids: HashMap<LogicalPlan, usize>,
+    translated: HashMap<usize, LocalPhysicalPlanRef>,
+}
+
+impl SharedSubplans {
+    /// The cache id of a subplan, which it shares with every other subplan that produces the same
+    /// output. Subplans whose output differs between evaluations, e.g. an unseeded sample, have
+    /// none and are never shared.
+    fn cache_id(&mut self, plan: &LogicalPlanRef) -> Option<usize> {
+        let address = Arc::as_ptr(plan);
+        if let Some(cache_id) = self.node_ids.get(&address) {
+            return *cache_id;
+        }
+        let cache_id = self.assign_cache_id(plan);
+        self.node_ids.insert(address, cache_id);
+        cache_id
+    }
+
+    fn assign_cache_id(&mut self, plan: &LogicalPlanRef) -> Option<usize> {
+        if matches!(
+            plan.as_ref(),
+            LogicalPlan::Sample(Sample { seed: None, .. })
+        ) {
+            return None;
+        }
+        let children = plan.children();
+        let key = if children.is_empty() {
+            plan.as_ref().clone()
+        } else {
+            let placeholders = children
+                .into_iter()
+                .map(|child| {
+                    let cache_id = self.cache_id(child)?;
+                    let placeholder = PlaceHolderInfo {
+                        source_schema: child.schema(),
+                        clustering_spec: Arc::new(ClusteringSpec::unknown()),
+                        source_id: cache_id,
+                    };
+                    Some(
+                        LogicalPlan::Source(Source::new(
+                            child.schema(),
+                            Arc::new(SourceInfo::PlaceHolder(placeholder)),
+                        ))
+                        .arced(),
+                    )
+                })
+                .collect::<Option<Vec<_>>>()?;
+            plan.with_new_children(&placeholders)
+        };
+        let next_cache_id = self.cache_ids.len();
+        Some(*self.cache_ids.entry(key).or_insert(next_cache_id))
+    }
+}
+
+/// Translates a node of the plan, reusing the translation of subplans that occur more than once.
+fn translate_node(
+    plan: &LogicalPlanRef,
+    shared: &mut SharedSubplans,
+) -> DaftResult<LocalPhysicalPlanRef> {
+    let cache_id = shared.cache_id(plan);
+    if let Some(local_plan) = cache_id.and_then(|cache_id| shared.translated.get(&cache_id)) {
+        return Ok(local_plan.clone());
+    }
+    let local_plan = translate_uncached(plan, shared)?;
+    if let Some(cache_id) = cache_id {
+        shared.translated.insert(cache_id, local_plan.clone());
+    }
+    Ok(local_plan)
+}
+
+fn translate_uncached(
+    plan: &LogicalPlanRef,
+    shared: &mut SharedSubplans,
+) -> DaftResult<LocalPhysicalPlanRef> {
     match plan.as_ref() {
         LogicalPlan::Source(source) => {
             match source.source_info.as_ref() {
@@ -38,10 +133,24 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 SourceInfo::PlaceHolder(_) => {
                     panic!("We should not encounter a PlaceHolder during translation")
                 }
+                SourceInfo::Stream(info) => Ok(LocalPhysicalPlan::stream_scan(
+                    info.clone(),
+                    source.stats_state.clone(),
+                )),
             }
         }
         LogicalPlan::Filter(filter) => {
-            let input = translate(&filter.input)?;
+            if let LogicalPlan::Join(join) = filter.input.as_ref() {
+                if join.left_on.is_empty()
+                    && join.right_on.is_empty()
+                    && join.join_type == JoinType::Inner
+                {
+                    if let Some(range_join) = translate_range_join(filter, join, shared)? {
+                        return Ok(range_join);
+                    }
+                }
+            }
+            let input = translate_node(&filter.input, shared)?;
             Ok(LocalPhysicalPlan::filter(
                 input,
                 filter.predicate.clone(),
@@ -49,7 +158,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Limit(limit) => {
-            let input = translate(&limit.input)?;
+            let input = translate_node(&limit.input, shared)?;
             Ok(LocalPhysicalPlan::limit(
                 input,
                 limit.limit,
@@ -57,7 +166,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Project(project) => {
-            let input = translate(&project.input)?;
+            let input = translate_node(&project.input, shared)?;
             Ok(LocalPhysicalPlan::project(
                 input,
                 project.projection.clone(),
@@ -66,7 +175,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::ActorPoolProject(actor_pool_project) => {
-            let input = translate(&actor_pool_project.input)?;
+            let input = translate_node(&actor_pool_project.input, shared)?;
             Ok(LocalPhysicalPlan::actor_pool_project(
                 input,
                 actor_pool_project.projection.clone(),
@@ -75,7 +184,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Sample(sample) => {
-            let input = translate(&sample.input)?;
+            let input = translate_node(&sample.input, shared)?;
             Ok(LocalPhysicalPlan::sample(
                 input,
                 sample.fraction,
@@ -85,7 +194,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Aggregate(aggregate) => {
-            let input = translate(&aggregate.input)?;
+            let input = translate_node(&aggregate.input, shared)?;
             if aggregate.groupby.is_empty() {
                 Ok(LocalPhysicalPlan::ungrouped_aggregate(
                     input,
@@ -103,8 +212,36 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 ))
             }
         }
+        LogicalPlan::WindowAggregate(window_aggregate) => {
+            let input = translate_node(&window_aggregate.input, shared)?;
+            Ok(LocalPhysicalPlan::window_aggregate(
+                input,
+                window_aggregate.aggregations.clone(),
+                window_aggregate.groupby.clone(),
+                window_aggregate.event_time_column.clone(),
+                window_aggregate.window_size,
+                window_aggregate.window_slide,
+                window_aggregate.watermark_delay,
+                window_aggregate.output_schema.clone(),
+                window_aggregate.stats_state.clone(),
+            ))
+        }
+        LogicalPlan::Window(window) => {
+            let input = translate_node(&window.input, shared)?;
+            Ok(LocalPhysicalPlan::window(
+                input,
+                window.partition_by.clone(),
+                window.order_by.clone(),
+                window.descending.clone(),
+                window.nulls_first.clone(),
+                window.functions.clone(),
+                window.names.clone(),
+                window.output_schema.clone(),
+                window.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Unpivot(unpivot) => {
-            let input = translate(&unpivot.input)?;
+            let input = translate_node(&unpivot.input, shared)?;
             Ok(LocalPhysicalPlan::unpivot(
                 input,
                 unpivot.ids.clone(),
@@ -116,7 +253,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Pivot(pivot) => {
-            let input = translate(&pivot.input)?;
+            let input = translate_node(&pivot.input, shared)?;
             Ok(LocalPhysicalPlan::pivot(
                 input,
                 pivot.group_by.clone(),
@@ -129,7 +266,15 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Sort(sort) => {
-            let input = translate(&sort.input)?;
+            let input = translate_node(&sort.input, shared)?;
+            // Cached data that is already sorted by the sort keys doesn't need to be sorted again.
+            if let LogicalPlan::Source(source) = sort.input.as_ref() {
+                if let SourceInfo::InMemory(info) = source.source_info.as_ref() {
+                    if info.is_sorted_by(&sort.sort_by, &sort.descending, &sort.nulls_first) {
+                        return Ok(input);
+                    }
+                }
+            }
             Ok(LocalPhysicalPlan::sort(
                 input,
                 sort.sort_by.clone(),
@@ -138,14 +283,44 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 sort.stats_state.clone(),
             ))
         }
-        LogicalPlan::Join(join) => {
-            if join.join_strategy.is_some_and(|x| x != JoinStrategy::Hash) {
-                return Err(DaftError::not_implemented(
-                    "Only hash join is supported for now",
-                ));
+        LogicalPlan::TopN(top_n) => {
+            let input = translate_node(&top_n.input, shared)?;
+            // The first rows of cached data that is already sorted by the sort keys are its top N.
+            if let LogicalPlan::Source(source) = top_n.input.as_ref() {
+                if let SourceInfo::InMemory(info) = source.source_info.as_ref() {
+                    if info.is_sorted_by(&top_n.sort_by, &top_n.descending, &top_n.nulls_first) {
+                        return Ok(LocalPhysicalPlan::limit(
+                            input,
+                            top_n.limit,
+                            top_n.stats_state.clone(),
+                        ));
+                    }
+                }
             }
-            let left = translate(&join.left)?;
-            let right = translate(&join.right)?;
+            Ok(LocalPhysicalPlan::top_n(
+                input,
+                top_n.sort_by.clone(),
+                top_n.descending.clone(),
+                top_n.nulls_first.clone(),
+                top_n.limit,
+                top_n.stats_state.clone(),
+            ))
+        }
+        LogicalPlan::Join(join) => {
+            let left = translate_node(&join.left, shared)?;
+            let right = translate_node(&join.right, shared)?;
+
+            // Inner joins of inputs that are already sorted by their join keys are merged instead of
+            // building a hash table, as are sort-merge joins that are asked for explicitly. Every
+            // other join runs as a streaming hash join, which supports all join types.
+            let is_sorted = is_sorted_by_keys(&join.left, &join.left_on)
+                && is_sorted_by_keys(&join.right, &join.right_on);
+            let can_sort_merge = join.join_type == JoinType::Inner
+                && !join.left_on.is_empty()
+                && join
+                    .null_equals_nulls
+                    .as_ref()
+                    .map_or(true, |null_equals_nulls| !null_equals_nulls.contains(&true));
 
             if join.left_on.is_empty()
                 && join.right_on.is_empty()
@@ -157,6 +332,18 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     join.output_schema.clone(),
                     join.stats_state.clone(),
                 ))
+            } else if can_sort_merge
+                && (is_sorted || join.join_strategy == Some(JoinStrategy::SortMerge))
+            {
+                Ok(LocalPhysicalPlan::sort_merge_join(
+                    left,
+                    right,
+                    join.left_on.clone(),
+                    join.right_on.clone(),
+                    is_sorted,
+                    join.output_schema.clone(),
+                    join.stats_state.clone(),
+                ))
             } else {
                 Ok(LocalPhysicalPlan::hash_join(
                     left,
@@ -165,6 +352,8 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     join.right_on.clone(),
                     join.null_equals_nulls.clone(),
                     join.join_type,
+                    shared.cache_id(&join.left),
+                    shared.cache_id(&join.right),
                     join.output_schema.clone(),
                     join.stats_state.clone(),
                 ))
@@ -172,7 +361,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
         }
         LogicalPlan::Distinct(distinct) => {
             let schema = distinct.input.schema();
-            let input = translate(&distinct.input)?;
+            let input = translate_node(&distinct.input, shared)?;
             let col_exprs = input
                 .schema()
                 .names()
@@ -188,8 +377,8 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Concat(concat) => {
-            let input = translate(&concat.input)?;
-            let other = translate(&concat.other)?;
+            let input = translate_node(&concat.input, shared)?;
+            let other = translate_node(&concat.other, shared)?;
             Ok(LocalPhysicalPlan::concat(
                 input,
                 other,
@@ -197,11 +386,31 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Repartition(repartition) => {
-            log::warn!("Repartition not supported on the NativeRunner. This will be a no-op. Please use the RayRunner instead if you need to repartition");
-            translate(&repartition.input)
+            let num_partitions = match &repartition.repartition_spec {
+                RepartitionSpec::Hash(HashRepartitionConfig { num_partitions, .. })
+                | RepartitionSpec::Random(RandomShuffleConfig { num_partitions })
+                | RepartitionSpec::Range(RangeRepartitionConfig { num_partitions, .. }) => {
+                    *num_partitions
+                }
+                RepartitionSpec::IntoPartitions(IntoPartitionsConfig { num_partitions }) => {
+                    Some(*num_partitions)
+                }
+            };
+            let input = translate_node(&repartition.input, shared)?;
+            // The NativeRunner doesn't know the number of partitions of its input upfront, so a
+            // repartition that keeps the number of partitions is a no-op.
+            match num_partitions {
+                Some(num_partitions) => Ok(LocalPhysicalPlan::repartition(
+                    input,
+                    repartition.repartition_spec.clone(),
+                    num_partitions,
+                    repartition.stats_state.clone(),
+                )),
+                None => Ok(input),
+            }
         }
         LogicalPlan::MonotonicallyIncreasingId(monotonically_increasing_id) => {
-            let input = translate(&monotonically_increasing_id.input)?;
+            let input = translate_node(&monotonically_increasing_id.input, shared)?;
             Ok(LocalPhysicalPlan::monotonically_increasing_id(
                 input,
                 monotonically_increasing_id.column_name.clone(),
@@ -209,9 +418,18 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 monotonically_increasing_id.stats_state.clone(),
             ))
         }
+        LogicalPlan::AssertUnique(assert_unique) => {
+            let input = translate_node(&assert_unique.input, shared)?;
+            Ok(LocalPhysicalPlan::assert_unique(
+                input,
+                assert_unique.keys.clone(),
+                assert_unique.input.schema(),
+                assert_unique.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Sink(sink) => {
             use daft_logical_plan::SinkInfo;
-            let input = translate(&sink.input)?;
+            let input = translate_node(&sink.input, shared)?;
             let data_schema = input.schema().clone();
             match sink.sink_info.as_ref() {
                 SinkInfo::OutputFileInfo(info) => Ok(LocalPhysicalPlan::physical_write(
@@ -221,6 +439,14 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     info.clone(),
                     sink.stats_state.clone(),
                 )),
+                SinkInfo::CopyInto(info) => Ok(LocalPhysicalPlan::copy_into(
+                    input,
+                    data_schema,
+                    sink.schema.clone(),
+                    info.file_info.clone(),
+                    info.mode,
+                    sink.stats_state.clone(),
+                )),
                 #[cfg(feature = "python")]
                 SinkInfo::CatalogInfo(info) => match &info.catalog {
                     daft_logical_plan::CatalogType::DeltaLake(..)
@@ -246,7 +472,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             }
         }
         LogicalPlan::Explode(explode) => {
-            let input = translate(&explode.input)?;
+            let input = translate_node(&explode.input, shared)?;
             Ok(LocalPhysicalPlan::explode(
                 input,
                 explode.to_explode.clone(),
@@ -257,3 +483,210 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
         _ => todo!("{} not yet implemented", plan.name()),
     }
 }
+
+/// Whether the rows of `plan` are sorted by `keys` in ascending order with nulls last, which is
+/// the order that sort-merge joins merge their inputs in.
+fn is_sorted_by_keys(plan: &LogicalPlan, keys: &[ExprRef]) -> bool {
+    let ascending = vec![false; keys.len()];
+    match plan {
+        LogicalPlan::Sort(sort) => {
+            sort.sort_by == keys && sort.descending == ascending && sort.nulls_first == ascending
+        }
+        LogicalPlan::Source(source) => match source.source_info.as_ref() {
+            SourceInfo::InMemory(info) => info.is_sorted_by(keys, &ascending, &ascending),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// A comparison of an expression of the right side of a join with a bound evaluated on the left
+/// side.
+struct RangeComparison {
+    right_on: ExprRef,
+    is_lower: bool,
+    bound: RangeBound,
+}
+
+fn as_range_comparison(
+    expr: &ExprRef,
+    left_schema: &Schema,
+    right_schema: &Schema,
+) -> Option<RangeComparison> {
+    let Expr::BinaryOp { op, left, right } = expr.as_ref() else {
+        return None;
+    };
+    // Whether `expr` is a comparable expression of the columns of `schema` only.
+    let is_key_of = |expr: &ExprRef, schema: &Schema| {
+        let columns = get_required_columns(expr);
+        !columns.is_empty()
+            && columns.iter().all(|column| schema.has_field(column))
+            && expr.to_field(schema).is_ok_and(|field| {
+                field.dtype.is_numeric() || field.dtype.is_temporal() || field.dtype.is_string()
+            })
+    };
+    // The kind of bound that `left op right` puts on `left`.
+    let (is_lower, inclusive) = match op {
+        Operator::Lt => (false, false),
+        Operator::LtEq => (false, true),
+        Operator::Gt => (true, false),
+        Operator::GtEq => (true, true),
+        _ => return None,
+    };
+    if is_key_of(left, right_schema) && is_key_of(right, left_schema) {
+        Some(RangeComparison {
+            right_on: left.clone(),
+            is_lower,
+            bound: RangeBound {
+                left_on: right.clone(),
+                inclusive,
+            },
+        })
+    } else if is_key_of(left, left_schema) && is_key_of(right, right_schema) {
+        Some(RangeComparison {
+            right_on: right.clone(),
+            is_lower: !is_lower,
+            bound: RangeBound {
+                left_on: left.clone(),
+                inclusive,
+            },
+        })
+    } else {
+        None
+    }
+}
+
+/// Translates a filter of a cross join that compares the sides of the join into a range join,
+/// which binary searches the right side sorted by one of the compared expressions rather than
+/// filtering the full cross product. Returns `None` if no conjunct of the filter is such a
+/// comparison.
+fn translate_range_join(
+    filter: &Filter,
+    join: &Join,
+    shared: &mut SharedSubplans,
+) -> DaftResult<Option<LocalPhysicalPlanRef>> {
+    let left_schema = join.left.schema();
+    let right_schema = join.right.schema();
+    // Between conditions are split into their comparisons, which can bound the key separately.
+    let conjuncts = split_conjunction(&filter.predicate)
+        .into_iter()
+        .flat_map(|conjunct| match conjunct.as_ref() {
+            Expr::Between(value, lower, upper) => vec![
+                value.clone().gt_eq(lower.clone()),
+                value.clone().lt_eq(upper.clone()),
+            ],
+            _ => vec![conjunct],
+        })
+        .collect::<Vec<_>>();
+    let comparisons = conjuncts
+        .iter()
+        .map(|conjunct| as_range_comparison(conjunct, &left_schema, &right_schema))
+        .collect::<Vec<_>>();
+
+    // The key of the right side is preferably one that is bounded from both sides, as in
+    // point-in-interval joins, since that narrows the matching rows the most.
+    let is_bounded = |right_on: &ExprRef, is_lower: bool| {
+        comparisons
+            .iter()
+            .flatten()
+            .any(|comparison| &comparison.right_on == right_on && comparison.is_lower == is_lower)
+    };
+    let mut keys = comparisons
+        .iter()
+        .flatten()
+        .map(|comparison| comparison.right_on.clone());
+    let Some(right_on) = keys
+        .clone()
+        .find(|key| is_bounded(key, true) && is_bounded(key, false))
+        .or_else(|| keys.next())
+    else {
+        return Ok(None);
+    };
+
+    let mut lower_bound = None;
+    let mut upper_bound = None;
+    let mut residual = vec![];
+    for (conjunct, comparison) in conjuncts.into_iter().zip(comparisons) {
+        match comparison {
+            Some(RangeComparison {
+                right_on: key,
+                is_lower: true,
+                bound,
+            }) if key == right_on && lower_bound.is_none() => lower_bound = Some(bound),
+            Some(RangeComparison {
+                right_on: key,
+                is_lower: false,
+                bound,
+            }) if key == right_on && upper_bound.is_none() => upper_bound = Some(bound),
+            _ => residual.push(conjunct),
+        }
+    }
+
+    let left = translate_node(&join.left, shared)?;
+    let right = translate_node(&join.right, shared)?;
+    Ok(Some(LocalPhysicalPlan::range_join(
+        left,
+        right,
+        right_on,
+        lower_bound,
+        upper_bound,
+        combine_conjunction(residual),
+        join.output_schema.clone(),
+        filter.stats_state.clone(),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use common_partitioning::PartitionCacheEntry;
+    use daft_core::prelude::{DataType, Field, Schema};
+    use daft_dsl::col;
+    use daft_logical_plan::{InMemorySortOrder, LogicalPlanBuilder};
+
+    use super::translate;
+    use crate::LocalPhysicalPlan;
+
+    fn cached_scan(sort_order: Option<InMemorySortOrder>) -> DaftResult<LogicalPlanBuilder> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ])?;
+        LogicalPlanBuilder::in_memory_scan(
+            "cached",
+            PartitionCacheEntry::Rust {
+                key: "cached".to_string(),
+                value: None,
+            },
+            schema.into(),
+            2,
+            0,
+            0,
+            sort_order,
+        )
+    }
+
+    #[test]
+    fn sort_of_sorted_cached_rows_is_removed() -> DaftResult<()> {
+        // The rows of a collected sort keep its order.
+        let sorted = cached_scan(None)?
+            .sort(vec![col("a")], vec![true], vec![false])?
+            .build();
+        let cached = cached_scan(InMemorySortOrder::of_plan(&sorted))?;
+
+        let plan = translate(
+            &cached
+                .sort(vec![col("a")], vec![true], vec![false])?
+                .build(),
+        )?;
+        assert!(matches!(plan.as_ref(), LocalPhysicalPlan::InMemoryScan(_)));
+
+        let plan = translate(
+            &cached
+                .sort(vec![col("b")], vec![true], vec![false])?
+                .build(),
+        )?;
+        assert!(matches!(plan.as_ref(), LocalPhysicalPlan::Sort(_)));
+        Ok(())
+    }
+}
@@ -3,19 +3,26 @@ use std::{
     sync::Arc,
 };
 
-use common_daft_config::DaftPlanningConfig;
+use common_daft_config::{DaftExecutionConfig, DaftPlanningConfig};
 use common_display::mermaid::MermaidDisplayOptions;
 use common_error::{DaftError, DaftResult};
-use common_file_formats::FileFormat;
+use common_file_formats::{CsvWriteOptions, FileFormat};
 use common_io_config::IOConfig;
 use common_scan_info::{PhysicalScanInfo, Pushdowns, ScanOperatorRef};
-use daft_core::join::{JoinStrategy, JoinType};
-use daft_dsl::{col, ExprRef};
+use common_treenode::{Transformed, TransformedResult, TreeNode};
+use daft_core::{
+    array::ops::Collation,
+    join::{JoinStrategy, JoinType},
+    prelude::DataType,
+};
+use daft_dsl::{binary_op, col, functions::python::RuntimePyObject, lit, Expr, ExprRef, Operator};
+use daft_functions::utf8::collation_key;
 use daft_schema::schema::{Schema, SchemaRef};
 #[cfg(feature = "python")]
 use {
     crate::sink_info::{CatalogInfo, IcebergCatalogInfo},
-    common_daft_config::PyDaftPlanningConfig,
+    common_daft_config::{PyDaftExecutionConfig, PyDaftPlanningConfig},
+    common_scan_info::{python::pylib::PyPartitionField, PartitionField},
     daft_dsl::python::PyExpr,
     // daft_scan::python::pylib::ScanOperatorHandle,
     daft_schema::python::schema::PySchema,
@@ -23,14 +30,21 @@ use {
 };
 
 use crate::{
+    lineage::{column_lineage, ColumnLineage},
     logical_plan::LogicalPlan,
     ops,
-    optimization::Optimizer,
+    optimization::{
+        has_correlated_cols, pull_up_correlated_cols, scalar_aggregate_counts, Optimizer,
+    },
     partitioning::{
-        HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RepartitionSpec,
+        HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RangeRepartitionConfig,
+        RepartitionSpec,
+    },
+    sink_info::{
+        ConstraintViolationAction, CopyIntoInfo, OutputFileInfo, SinkInfo, WriteAttempt,
+        WriteConstraint, WriteConstraints, WriteMode,
     },
-    sink_info::{OutputFileInfo, SinkInfo},
-    source_info::{InMemoryInfo, SourceInfo},
+    source_info::{InMemoryInfo, InMemorySortOrder, SourceInfo, StreamInfo},
     LogicalPlanRef,
 };
 
@@ -44,20 +58,23 @@ pub struct LogicalPlanBuilder {
     // The current root of the logical plan in this builder.
     pub plan: Arc<LogicalPlan>,
     config: Option<Arc<DaftPlanningConfig>>,
+    // The execution config that the query of this plan runs with instead of the global one.
+    execution_config: Option<Arc<DaftExecutionConfig>>,
 }
 
 impl LogicalPlanBuilder {
     pub fn new(plan: Arc<LogicalPlan>, config: Option<Arc<DaftPlanningConfig>>) -> Self {
-        Self { plan, config }
+        Self {
+            plan,
+            config,
+            execution_config: None,
+        }
     }
 }
 
 impl From<&Self> for LogicalPlanBuilder {
     fn from(builder: &Self) -> Self {
-        Self {
-            plan: builder.plan.clone(),
-            config: builder.config.clone(),
-        }
+        builder.clone()
     }
 }
 
@@ -105,14 +122,86 @@ impl IntoGlobPath for Vec<&str> {
 impl LogicalPlanBuilder {
     /// Replace the LogicalPlanBuilder's plan with the provided plan
     pub fn with_new_plan<LP: Into<Arc<LogicalPlan>>>(&self, plan: LP) -> Self {
-        Self::new(plan.into(), self.config.clone())
+        Self {
+            plan: plan.into(),
+            ..self.clone()
+        }
     }
 
     /// Parametrize the LogicalPlanBuilder with a DaftPlanningConfig
     pub fn with_config(&self, config: Arc<DaftPlanningConfig>) -> Self {
-        Self::new(self.plan.clone(), Some(config))
+        Self {
+            config: Some(config),
+            ..self.clone()
+        }
+    }
+
+    /// Runs the query of this plan, and of the plans built on it, with `execution_config` instead
+    /// of the global execution config. Plans combining several inputs, such as joins, keep the
+    /// execution config of the plan they are built on.
+    pub fn with_execution_config(&self, execution_config: Arc<DaftExecutionConfig>) -> Self {
+        Self {
+            execution_config: Some(execution_config),
+            ..self.clone()
+        }
+    }
+
+    /// The execution config that the query of this plan runs with, if it overrides the global one
+    pub fn execution_config(&self) -> Option<&Arc<DaftExecutionConfig>> {
+        self.execution_config.as_ref()
+    }
+
+    /// The default collation of the session, if it isn't the binary collation. It is only applied
+    /// to filters and sorts.
+    fn default_collation(&self) -> DaftResult<Option<Collation>> {
+        let Some(collation) = self
+            .config
+            .as_ref()
+            .and_then(|config| config.default_collation.as_deref())
+        else {
+            return Ok(None);
+        };
+        let collation: Collation = collation.parse()?;
+        Ok((collation != Collation::Binary).then_some(collation))
+    }
+
+    fn is_utf8(&self, expr: &ExprRef) -> bool {
+        expr.to_field(&self.schema())
+            .is_ok_and(|field| field.dtype == DataType::Utf8)
     }
 
+    /// Compares the strings of the comparisons in `predicate` by the default collation of the session.
+    fn collate_comparisons(&self, predicate: ExprRef) -> DaftResult<ExprRef> {
+        let Some(collation) = self.default_collation()? else {
+            return Ok(predicate);
+        };
+        predicate
+            .transform(|e| match e.as_ref() {
+                Expr::BinaryOp { op, left, right }
+                    if matches!(
+                        op,
+                        Operator::Eq
+                            | Operator::NotEq
+                            | Operator::Lt
+                            | Operator::LtEq
+                            | Operator::Gt
+                            | Operator::GtEq
+                    ) && self.is_utf8(left)
+                        && self.is_utf8(right) =>
+                {
+                    Ok(Transformed::yes(binary_op(
+                        *op,
+                        collation_key(left.clone(), collation.clone()),
+                        collation_key(right.clone(), collation.clone()),
+                    )))
+                }
+                _ => Ok(Transformed::no(e)),
+            })
+            .data()
+    }
+
+    /// Scans cached partitions, whose rows are in `sort_order` if they were sorted before they
+    /// were cached.
     pub fn in_memory_scan(
         partition_key: &str,
         cache_entry: common_partitioning::PartitionCacheEntry,
@@ -120,8 +209,9 @@ impl LogicalPlanBuilder {
         num_partitions: usize,
         size_bytes: usize,
         num_rows: usize,
+        sort_order: Option<InMemorySortOrder>,
     ) -> DaftResult<Self> {
-        let source_info = SourceInfo::InMemory(InMemoryInfo::new(
+        let mut in_memory_info = InMemoryInfo::new(
             schema.clone(),
             partition_key.into(),
             cache_entry,
@@ -129,12 +219,24 @@ impl LogicalPlanBuilder {
             size_bytes,
             num_rows,
             None, // TODO(sammy) thread through clustering spec to Python
-        ));
+        );
+        if let Some(sort_order) = sort_order {
+            in_memory_info = in_memory_info.with_sort_order(sort_order);
+        }
+        let source_info = SourceInfo::InMemory(in_memory_info);
         let logical_plan: LogicalPlan = ops::Source::new(schema, source_info.into()).into();
 
         Ok(Self::from(Arc::new(logical_plan)))
     }
 
+    /// Reads an unbounded stream of micro-batches.
+    pub fn stream_scan(stream_info: StreamInfo) -> Self {
+        let schema = stream_info.source_schema.clone();
+        let source_info = SourceInfo::Stream(stream_info);
+        let logical_plan: LogicalPlan = ops::Source::new(schema, source_info.into()).into();
+        Self::from(Arc::new(logical_plan))
+    }
+
     pub fn table_scan(
         scan_operator: ScanOperatorRef,
         pushdowns: Option<Pushdowns>,
@@ -245,6 +347,7 @@ impl LogicalPlanBuilder {
     }
 
     pub fn filter(&self, predicate: ExprRef) -> DaftResult<Self> {
+        let predicate = self.collate_comparisons(predicate)?;
         let logical_plan: LogicalPlan = ops::Filter::try_new(self.plan.clone(), predicate)?.into();
         Ok(self.with_new_plan(logical_plan))
     }
@@ -299,6 +402,19 @@ impl LogicalPlanBuilder {
         descending: Vec<bool>,
         nulls_first: Vec<bool>,
     ) -> DaftResult<Self> {
+        let sort_by = match self.default_collation()? {
+            Some(collation) => sort_by
+                .into_iter()
+                .map(|e| {
+                    if self.is_utf8(&e) {
+                        collation_key(e, collation.clone())
+                    } else {
+                        e
+                    }
+                })
+                .collect(),
+            None => sort_by,
+        };
         let logical_plan: LogicalPlan =
             ops::Sort::try_new(self.plan.clone(), sort_by, descending, nulls_first)?.into();
         Ok(self.with_new_plan(logical_plan))
@@ -317,6 +433,24 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    pub fn range_repartition(
+        &self,
+        num_partitions: Option<usize>,
+        partition_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+    ) -> DaftResult<Self> {
+        let logical_plan: LogicalPlan = ops::Repartition::try_new(
+            self.plan.clone(),
+            RepartitionSpec::Range(RangeRepartitionConfig::new(
+                num_partitions,
+                partition_by,
+                descending,
+            )),
+        )?
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn random_shuffle(&self, num_partitions: Option<usize>) -> DaftResult<Self> {
         let logical_plan: LogicalPlan = ops::Repartition::try_new(
             self.plan.clone(),
@@ -340,6 +474,12 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Fails the query if two rows have equal `keys`, and otherwise passes the rows through.
+    pub fn assert_unique(&self, keys: Vec<ExprRef>) -> DaftResult<Self> {
+        let logical_plan: LogicalPlan = ops::AssertUnique::try_new(self.plan.clone(), keys)?.into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn sample(
         &self,
         fraction: f64,
@@ -361,6 +501,56 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Aggregates the rows within event-time windows, which are output once the watermark passes
+    /// their end. Windows are tumbling if no slide is given.
+    #[allow(clippy::too_many_arguments)]
+    pub fn window_aggregate(
+        &self,
+        agg_exprs: Vec<ExprRef>,
+        groupby_exprs: Vec<ExprRef>,
+        event_time_column: String,
+        window_size: i64,
+        window_slide: Option<i64>,
+        watermark_delay: Option<i64>,
+    ) -> DaftResult<Self> {
+        let logical_plan: LogicalPlan = ops::WindowAggregate::try_new(
+            self.plan.clone(),
+            agg_exprs,
+            groupby_exprs,
+            event_time_column,
+            window_size,
+            window_slide,
+            watermark_delay,
+        )?
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
+    /// Evaluates window functions over the partitions of the rows with equal `partition_by` keys,
+    /// ordered by the `order_by` keys, adding a column named after each function.
+    #[allow(clippy::too_many_arguments)]
+    pub fn window(
+        &self,
+        partition_by: Vec<ExprRef>,
+        order_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        functions: Vec<ops::WindowFunction>,
+        names: Vec<String>,
+    ) -> DaftResult<Self> {
+        let logical_plan: LogicalPlan = ops::Window::try_new(
+            self.plan.clone(),
+            partition_by,
+            order_by,
+            descending,
+            nulls_first,
+            functions,
+            names,
+        )?
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn pivot(
         &self,
         group_by: Vec<ExprRef>,
@@ -473,6 +663,102 @@ impl LogicalPlanBuilder {
         )
     }
 
+    /// Joins every row of `self` with the rows of `right`, where `right` may reference columns of
+    /// `self` through outer reference columns, e.g. a SQL `LATERAL` subquery.
+    ///
+    /// The correlated equality predicates of `right` are pulled up into the join keys, other
+    /// correlated predicates are not supported. If `right` is an aggregation without group by
+    /// columns, every row of `self` is kept, with counts of 0 if no rows of `right` match it.
+    pub fn lateral_join(
+        &self,
+        right: &Self,
+        join_type: JoinType,
+        join_prefix: Option<&str>,
+    ) -> DaftResult<Self> {
+        if !matches!(join_type, JoinType::Inner | JoinType::Left) {
+            return Err(DaftError::not_implemented(format!(
+                "Lateral join with join type {join_type}"
+            )));
+        }
+
+        let right_names = right.schema().names();
+        let counts = scalar_aggregate_counts(&right.plan);
+        let (decorrelated, right_on, left_on) = pull_up_correlated_cols(right.plan.clone())?;
+        if has_correlated_cols(&decorrelated) {
+            return Err(DaftError::not_implemented(
+                "Lateral subqueries can only reference outer columns in equality predicates",
+            ));
+        }
+
+        if left_on.is_empty() {
+            return match join_type {
+                JoinType::Inner => self.cross_join(decorrelated, None, join_prefix),
+                _ => Err(DaftError::not_implemented(
+                    "Uncorrelated lateral join with join type other than inner",
+                )),
+            };
+        }
+
+        // give the pulled up join keys unique names so that they can be removed after the join
+        let key_names = (0..right_on.len())
+            .map(|i| format!("__lateral_key_{i}__"))
+            .collect::<Vec<_>>();
+        let projection = right_names
+            .iter()
+            .map(|name| col(name.as_str()))
+            .chain(
+                right_on
+                    .into_iter()
+                    .zip(&key_names)
+                    .map(|(expr, name)| expr.alias(name.as_str())),
+            )
+            .collect();
+        let right_plan: LogicalPlan = ops::Project::try_new(decorrelated, projection)?.into();
+
+        // An aggregation without group by columns produces a row for every row of `self`, even
+        // one without matching rows, which is only kept by a left join.
+        let join_type = if counts.is_some() {
+            JoinType::Left
+        } else {
+            join_type
+        };
+        let joined = self
+            .join(
+                right_plan.arced(),
+                left_on,
+                key_names.iter().map(|name| col(name.as_str())).collect(),
+                join_type,
+                None,
+                None,
+                join_prefix,
+                true,
+            )?
+            .exclude(key_names)?;
+
+        let Some(counts) = counts.filter(|counts| !counts.is_empty()) else {
+            return Ok(joined);
+        };
+        // the columns of `right` are the last columns of the join, possibly renamed
+        let schema = joined.schema();
+        let right_start = schema.len() - right_names.len();
+        let projection = schema
+            .fields
+            .values()
+            .enumerate()
+            .map(|(i, field)| {
+                let column = col(field.name.as_str());
+                if i >= right_start && counts.contains(&right_names[i - right_start]) {
+                    column
+                        .fill_null(lit(0).cast(&field.dtype))
+                        .alias(field.name.as_str())
+                } else {
+                    column
+                }
+            })
+            .collect();
+        joined.select(projection)
+    }
+
     pub fn concat(&self, other: &Self) -> DaftResult<Self> {
         let logical_plan: LogicalPlan =
             ops::Concat::try_new(self.plan.clone(), other.plan.clone())?.into();
@@ -492,12 +778,21 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// The row-level differences between this plan and `other`, whose rows are matched by `keys`.
+    pub fn compare(&self, other: &Self, keys: Vec<String>) -> DaftResult<Self> {
+        let logical_plan: LogicalPlan =
+            ops::Compare::try_new(self.plan.clone(), other.plan.clone(), keys)?
+                .to_logical_plan()?;
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn add_monotonically_increasing_id(&self, column_name: Option<&str>) -> DaftResult<Self> {
         let logical_plan: LogicalPlan =
             ops::MonotonicallyIncreasingId::new(self.plan.clone(), column_name).into();
         Ok(self.with_new_plan(logical_plan))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn table_write(
         &self,
         root_dir: &str,
@@ -505,14 +800,48 @@ impl LogicalPlanBuilder {
         partition_cols: Option<Vec<ExprRef>>,
         compression: Option<String>,
         io_config: Option<IOConfig>,
+        parquet_encryption: Option<RuntimePyObject>,
+        csv_options: Option<CsvWriteOptions>,
+        write_attempt: Option<WriteAttempt>,
+        constraints: Option<WriteConstraints>,
     ) -> DaftResult<Self> {
-        let sink_info = SinkInfo::OutputFileInfo(OutputFileInfo::new(
-            root_dir.into(),
-            file_format,
-            partition_cols,
-            compression,
-            io_config,
-        ));
+        if parquet_encryption.is_some() && file_format != FileFormat::Parquet {
+            return Err(DaftError::ValueError(format!(
+                "Encryption is only supported when writing Parquet files, but got: {file_format:?}"
+            )));
+        }
+        if let Some(csv_options) = &csv_options {
+            if file_format != FileFormat::Csv {
+                return Err(DaftError::ValueError(format!(
+                    "CSV options are only supported when writing CSV files, but got: {file_format:?}"
+                )));
+            }
+            csv_options.validate()?;
+        }
+        let sink_info = SinkInfo::OutputFileInfo(
+            OutputFileInfo::new(
+                root_dir.into(),
+                file_format,
+                partition_cols,
+                compression,
+                io_config,
+            )
+            .with_parquet_encryption(parquet_encryption)
+            .with_csv_options(csv_options)
+            .with_write_attempt(write_attempt)
+            .with_constraints(constraints),
+        );
+
+        let logical_plan: LogicalPlan =
+            ops::Sink::try_new(self.plan.clone(), sink_info.into())?.into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
+    /// Exports the plan's output to the files described by `file_info`. Unlike
+    /// [`Self::table_write`], the resulting plan produces a manifest of the written files with
+    /// their number of rows and size.
+    pub fn copy_into(&self, file_info: OutputFileInfo, mode: WriteMode) -> DaftResult<Self> {
+        let sink_info = SinkInfo::CopyInto(CopyIntoInfo { file_info, mode });
 
         let logical_plan: LogicalPlan =
             ops::Sink::try_new(self.plan.clone(), sink_info.into())?.into();
@@ -526,12 +855,18 @@ impl LogicalPlanBuilder {
         table_name: String,
         table_location: String,
         partition_spec_id: i64,
-        partition_cols: Vec<ExprRef>,
+        partition_fields: Vec<PartitionField>,
         iceberg_schema: PyObject,
         iceberg_properties: PyObject,
         io_config: Option<IOConfig>,
         catalog_columns: Vec<String>,
     ) -> DaftResult<Self> {
+        // The partition values of the written rows are computed from the transforms of the
+        // partition spec of the table.
+        let partition_cols = partition_fields
+            .iter()
+            .map(common_scan_info::partition_values_expr)
+            .collect();
         let sink_info = SinkInfo::CatalogInfo(CatalogInfo {
             catalog: crate::sink_info::CatalogType::Iceberg(IcebergCatalogInfo {
                 table_name,
@@ -634,7 +969,7 @@ impl LogicalPlanBuilder {
             },
         )?;
 
-        let builder = Self::new(optimized_plan, self.config.clone());
+        let builder = self.with_new_plan(optimized_plan);
         Ok(builder)
     }
 
@@ -654,6 +989,11 @@ impl LogicalPlanBuilder {
         use common_display::mermaid::MermaidDisplay;
         self.plan.repr_mermaid(opts)
     }
+
+    /// Source columns that each output column of the plan is derived from.
+    pub fn column_lineage(&self) -> DaftResult<ColumnLineage> {
+        column_lineage(&self.plan)
+    }
 }
 
 /// A Python-facing wrapper of the LogicalPlanBuilder.
@@ -682,7 +1022,10 @@ fn pyexprs_to_exprs(vec: Vec<PyExpr>) -> Vec<ExprRef> {
 #[cfg(feature = "python")]
 #[pymethods]
 impl PyLogicalPlanBuilder {
+    /// Scans cached partitions. If they are the results of `cached_plan`, they keep its sort
+    /// order, which lets sorts of the cached rows by the same keys be skipped.
     #[staticmethod]
+    #[pyo3(signature = (partition_key, cache_entry, schema, num_partitions, size_bytes, num_rows, cached_plan=None))]
     pub fn in_memory_scan(
         partition_key: &str,
         cache_entry: PyObject,
@@ -690,6 +1033,7 @@ impl PyLogicalPlanBuilder {
         num_partitions: usize,
         size_bytes: usize,
         num_rows: usize,
+        cached_plan: Option<&Self>,
     ) -> PyResult<Self> {
         Ok(LogicalPlanBuilder::in_memory_scan(
             partition_key,
@@ -698,10 +1042,42 @@ impl PyLogicalPlanBuilder {
             num_partitions,
             size_bytes,
             num_rows,
+            cached_plan.and_then(|plan| InMemorySortOrder::of_plan(&plan.builder.plan)),
         )?
         .into())
     }
 
+    #[staticmethod]
+    #[pyo3(signature = (
+        name,
+        batch_iterator_factory,
+        schema,
+        poll_interval_ms,
+        watermark_column=None,
+        watermark_delay=None
+    ))]
+    pub fn stream_scan(
+        name: String,
+        batch_iterator_factory: PyObject,
+        schema: PySchema,
+        poll_interval_ms: u64,
+        watermark_column: Option<String>,
+        watermark_delay: Option<i64>,
+    ) -> PyResult<Self> {
+        let watermark = watermark_column.map(|event_time_column| crate::Watermark {
+            event_time_column,
+            delay: watermark_delay.unwrap_or(0),
+        });
+        let stream_info = StreamInfo::new(
+            schema.into(),
+            name,
+            RuntimePyObject::new(batch_iterator_factory),
+            poll_interval_ms,
+            watermark,
+        )?;
+        Ok(LogicalPlanBuilder::stream_scan(stream_info).into())
+    }
+
     pub fn with_planning_config(
         &self,
         daft_planning_config: PyDaftPlanningConfig,
@@ -709,6 +1085,20 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.with_config(daft_planning_config.config).into())
     }
 
+    pub fn with_execution_config(&self, daft_execution_config: PyDaftExecutionConfig) -> Self {
+        self.builder
+            .with_execution_config(daft_execution_config.config)
+            .into()
+    }
+
+    pub fn execution_config(&self) -> Option<PyDaftExecutionConfig> {
+        self.builder
+            .execution_config()
+            .map(|config| PyDaftExecutionConfig {
+                config: config.clone(),
+            })
+    }
+
     pub fn select(&self, to_select: Vec<PyExpr>) -> PyResult<Self> {
         Ok(self.builder.select(pyexprs_to_exprs(to_select))?.into())
     }
@@ -777,6 +1167,18 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    pub fn range_repartition(
+        &self,
+        partition_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+        num_partitions: Option<usize>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .range_repartition(num_partitions, pyexprs_to_exprs(partition_by), descending)?
+            .into())
+    }
+
     pub fn random_shuffle(&self, num_partitions: Option<usize>) -> PyResult<Self> {
         Ok(self.builder.random_shuffle(num_partitions)?.into())
     }
@@ -789,6 +1191,10 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.distinct()?.into())
     }
 
+    pub fn assert_unique(&self, keys: Vec<PyExpr>) -> PyResult<Self> {
+        Ok(self.builder.assert_unique(pyexprs_to_exprs(keys))?.into())
+    }
+
     pub fn sample(
         &self,
         fraction: f64,
@@ -808,6 +1214,58 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    #[pyo3(signature = (
+        agg_exprs,
+        groupby_exprs,
+        event_time_column,
+        window_size,
+        window_slide=None,
+        watermark_delay=None
+    ))]
+    pub fn window_aggregate(
+        &self,
+        agg_exprs: Vec<PyExpr>,
+        groupby_exprs: Vec<PyExpr>,
+        event_time_column: String,
+        window_size: i64,
+        window_slide: Option<i64>,
+        watermark_delay: Option<i64>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .window_aggregate(
+                pyexprs_to_exprs(agg_exprs),
+                pyexprs_to_exprs(groupby_exprs),
+                event_time_column,
+                window_size,
+                window_slide,
+                watermark_delay,
+            )?
+            .into())
+    }
+
+    pub fn window(
+        &self,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        functions: Vec<ops::PyWindowFunction>,
+        names: Vec<String>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .window(
+                pyexprs_to_exprs(partition_by),
+                pyexprs_to_exprs(order_by),
+                descending,
+                nulls_first,
+                functions.into_iter().map(|f| f.function).collect(),
+                names,
+            )?
+            .into())
+    }
+
     pub fn pivot(
         &self,
         group_by: Vec<PyExpr>,
@@ -861,6 +1319,10 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.intersect(&other.builder, is_all)?.into())
     }
 
+    pub fn compare(&self, other: &Self, keys: Vec<String>) -> DaftResult<Self> {
+        Ok(self.builder.compare(&other.builder, keys)?.into())
+    }
+
     pub fn add_monotonically_increasing_id(&self, column_name: Option<&str>) -> PyResult<Self> {
         Ok(self
             .builder
@@ -868,6 +1330,7 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn table_write(
         &self,
         root_dir: &str,
@@ -875,7 +1338,34 @@ impl PyLogicalPlanBuilder {
         partition_cols: Option<Vec<PyExpr>>,
         compression: Option<String>,
         io_config: Option<common_io_config::python::IOConfig>,
+        parquet_encryption: Option<PyObject>,
+        csv_options: Option<CsvWriteOptions>,
+        job_id: Option<String>,
+        attempt_id: Option<String>,
+        constraints: Option<Vec<(String, PyExpr)>>,
+        on_constraint_violation: Option<&str>,
+        quarantine_dir: Option<String>,
+        quarantine_format: Option<FileFormat>,
     ) -> PyResult<Self> {
+        let write_attempt = job_id.map(|job_id| match attempt_id {
+            Some(attempt_id) => WriteAttempt { job_id, attempt_id },
+            None => WriteAttempt::new(job_id),
+        });
+        let on_violation = ConstraintViolationAction::try_new(
+            on_constraint_violation.unwrap_or("fail"),
+            quarantine_dir,
+            quarantine_format.unwrap_or(file_format),
+        )?;
+        let constraints = constraints.map(|constraints| WriteConstraints {
+            constraints: constraints
+                .into_iter()
+                .map(|(name, predicate)| WriteConstraint {
+                    name,
+                    predicate: predicate.into(),
+                })
+                .collect(),
+            on_violation,
+        });
         Ok(self
             .builder
             .table_write(
@@ -884,6 +1374,10 @@ impl PyLogicalPlanBuilder {
                 partition_cols.map(pyexprs_to_exprs),
                 compression,
                 io_config.map(|cfg| cfg.config),
+                parquet_encryption.map(RuntimePyObject::new),
+                csv_options,
+                write_attempt,
+                constraints,
             )?
             .into())
     }
@@ -894,7 +1388,7 @@ impl PyLogicalPlanBuilder {
         table_name: String,
         table_location: String,
         partition_spec_id: i64,
-        partition_cols: Vec<PyExpr>,
+        partition_fields: Vec<PyPartitionField>,
         iceberg_schema: PyObject,
         iceberg_properties: PyObject,
         catalog_columns: Vec<String>,
@@ -906,7 +1400,10 @@ impl PyLogicalPlanBuilder {
                 table_name,
                 table_location,
                 partition_spec_id,
-                pyexprs_to_exprs(partition_cols),
+                partition_fields
+                    .into_iter()
+                    .map(|field| field.0.as_ref().clone())
+                    .collect(),
                 iceberg_schema,
                 iceberg_properties,
                 io_config.map(|cfg| cfg.config),
@@ -977,6 +1474,11 @@ impl PyLogicalPlanBuilder {
     pub fn repr_mermaid(&self, opts: MermaidDisplayOptions) -> String {
         self.builder.repr_mermaid(opts)
     }
+
+    /// Column-level lineage of the plan, as a JSON string.
+    pub fn column_lineage(&self) -> PyResult<String> {
+        Ok(self.builder.column_lineage()?.to_json()?)
+    }
 }
 
 impl From<LogicalPlanBuilder> for PyLogicalPlanBuilder {
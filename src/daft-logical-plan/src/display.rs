@@ -6,7 +6,17 @@ impl TreeDisplay for crate::LogicalPlan {
     fn display_as(&self, level: DisplayLevel) -> String {
         match level {
             DisplayLevel::Compact => self.name().to_string(),
-            DisplayLevel::Default | DisplayLevel::Verbose => self.multiline_display().join("\n"),
+            DisplayLevel::Default => self.multiline_display().join("\n"),
+            DisplayLevel::Verbose => {
+                let mut lines = self.multiline_display();
+                if !lines
+                    .iter()
+                    .any(|line| line.starts_with("Output schema = "))
+                {
+                    lines.push(format!("Output schema = {}", self.schema().short_string()));
+                }
+                lines.join("\n")
+            }
         }
     }
 
@@ -229,6 +239,7 @@ Project1 --> Limit0
         let plan = plan_1();
         let opts = MermaidDisplayOptions {
             simple: false,
+            verbose: false,
             bottom_up: false,
             subgraph_options: Some(SubgraphOptions {
                 name: "Optimized Logical Plan".to_string(),
@@ -243,6 +254,30 @@ Source ID = 0
 Num partitions = 0
 Output schema = text#Utf8, id#Int32"]
 end
+"#;
+        assert_eq!(mermaid_repr, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mermaid_display_verbose() -> DaftResult<()> {
+        let plan = LogicalPlanBuilder::from(plan_1())
+            .filter(col("id").eq(lit(1)))?
+            .build();
+        let opts = MermaidDisplayOptions {
+            verbose: true,
+            ..Default::default()
+        };
+
+        let mermaid_repr = plan.repr_mermaid(opts);
+        let expected = r#"flowchart TD
+Filter0["Filter: col(id) == lit(1)
+Output schema = text#Utf8, id#Int32"]
+Source1["PlaceHolder:
+Source ID = 0
+Num partitions = 0
+Output schema = text#Utf8, id#Int32"]
+Source1 --> Filter0
 "#;
         assert_eq!(mermaid_repr, expected);
         Ok(())
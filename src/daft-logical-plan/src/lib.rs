@@ -4,6 +4,7 @@
 
 pub mod builder;
 pub mod display;
+pub mod lineage;
 pub mod logical_plan;
 pub mod ops;
 pub mod optimization;
@@ -18,18 +19,26 @@ mod treenode;
 pub use builder::{LogicalPlanBuilder, PyLogicalPlanBuilder};
 #[cfg(feature = "python")]
 use common_file_formats::{
-    python::PyFileFormatConfig, CsvSourceConfig, DatabaseSourceConfig, JsonSourceConfig,
-    ParquetSourceConfig,
+    python::PyFileFormatConfig, CsvQuoteStyle, CsvSourceConfig, CsvWriteOptions,
+    DatabaseSourceConfig, ExcelSourceConfig, JsonSourceConfig, KafkaSourceConfig,
+    ParquetSourceConfig, XmlSourceConfig,
 };
 pub use daft_core::join::{JoinStrategy, JoinType};
+pub use lineage::{column_lineage, ColumnLineage};
 pub use logical_plan::{LogicalPlan, LogicalPlanRef};
 pub use partitioning::ClusteringSpec;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 #[cfg(feature = "python")]
 pub use sink_info::{CatalogType, DeltaLakeCatalogInfo, IcebergCatalogInfo, LanceCatalogInfo};
-pub use sink_info::{OutputFileInfo, SinkInfo};
-pub use source_info::{FileInfo, FileInfos, InMemoryInfo, SourceInfo};
+pub use sink_info::{
+    ConstraintViolationAction, CopyIntoInfo, OutputFileInfo, SinkInfo, WriteAttempt,
+    WriteConstraint, WriteConstraints, WriteMode,
+};
+pub use source_info::{
+    FileInfo, FileInfos, InMemoryInfo, InMemorySortOrder, PlaceHolderInfo, SourceInfo, StreamInfo,
+    Watermark,
+};
 
 #[cfg(feature = "python")]
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
@@ -37,10 +46,16 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<PyFileFormatConfig>()?;
     parent.add_class::<ParquetSourceConfig>()?;
     parent.add_class::<JsonSourceConfig>()?;
+    parent.add_class::<XmlSourceConfig>()?;
+    parent.add_class::<ExcelSourceConfig>()?;
     parent.add_class::<CsvSourceConfig>()?;
+    parent.add_class::<CsvWriteOptions>()?;
+    parent.add_class::<CsvQuoteStyle>()?;
+    parent.add_class::<KafkaSourceConfig>()?;
     parent.add_class::<DatabaseSourceConfig>()?;
     parent.add_class::<FileInfos>()?;
     parent.add_class::<FileInfo>()?;
+    parent.add_class::<ops::PyWindowFunction>()?;
 
     Ok(())
 }
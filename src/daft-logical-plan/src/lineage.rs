@@ -0,0 +1,390 @@
+use std::collections::BTreeSet;
+
+use common_error::DaftResult;
+use common_scan_info::ScanState;
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::{
+    ops::{
+        ActorPoolProject, Aggregate, AssertUnique, Concat, Distinct, Explode, Filter, Intersect,
+        Join, Limit, MonotonicallyIncreasingId, Pivot, Project, Repartition, Sample, Sink, Sort,
+        Source, TopN, Union, Unpivot, Window, WindowAggregate, WINDOW_END_COLUMN_NAME,
+        WINDOW_START_COLUMN_NAME,
+    },
+    source_info::SourceInfo,
+    LogicalPlan,
+};
+
+/// A source of a logical plan, such as a scan of files or an in-memory table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LineageSource {
+    pub name: String,
+    /// Paths or glob patterns of the files that the source reads, if it reads files.
+    pub files: Vec<String>,
+}
+
+/// A column of one of the sources of a logical plan.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct SourceColumn {
+    /// Index of the source in [`ColumnLineage::sources`].
+    pub source: usize,
+    pub column: String,
+}
+
+/// The source columns that an output column of a logical plan is derived from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OutputColumnLineage {
+    pub name: String,
+    pub sources: BTreeSet<SourceColumn>,
+}
+
+/// Column-level lineage of a logical plan.
+///
+/// Output columns that aren't derived from any source column, such as literals or generated
+/// ids, have no source columns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ColumnLineage {
+    pub sources: Vec<LineageSource>,
+    pub columns: Vec<OutputColumnLineage>,
+}
+
+impl ColumnLineage {
+    /// Source columns of the output column with the given name.
+    pub fn get(&self, name: &str) -> Option<&BTreeSet<SourceColumn>> {
+        self.columns
+            .iter()
+            .find(|column| column.name == name)
+            .map(|column| &column.sources)
+    }
+
+    pub fn to_json(&self) -> DaftResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+type Lineage = IndexMap<String, BTreeSet<SourceColumn>>;
+
+/// Computes which source columns each output column of the plan is derived from, through
+/// projections, joins, aggregations and the other operators of the plan.
+pub fn column_lineage(plan: &LogicalPlan) -> DaftResult<ColumnLineage> {
+    let mut sources = vec![];
+    let lineage = node_lineage(plan, &mut sources)?;
+    let columns = lineage
+        .into_iter()
+        .map(|(name, sources)| OutputColumnLineage { name, sources })
+        .collect();
+    Ok(ColumnLineage { sources, columns })
+}
+
+fn add_source(sources: &mut Vec<LineageSource>, source: LineageSource) -> usize {
+    if let Some(idx) = sources.iter().position(|s| *s == source) {
+        idx
+    } else {
+        sources.push(source);
+        sources.len() - 1
+    }
+}
+
+fn expr_lineage(expr: &ExprRef, input: &Lineage) -> BTreeSet<SourceColumn> {
+    get_required_columns(expr)
+        .iter()
+        .filter_map(|name| input.get(name))
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+fn exprs_lineage(exprs: &[ExprRef], input: &Lineage) -> Lineage {
+    exprs
+        .iter()
+        .map(|expr| (expr.name().to_string(), expr_lineage(expr, input)))
+        .collect()
+}
+
+/// Merges the lineage of two inputs whose columns line up by name.
+fn merge_by_name(mut lhs: Lineage, rhs: Lineage) -> Lineage {
+    for (name, sources) in rhs {
+        lhs.entry(name).or_default().extend(sources);
+    }
+    lhs
+}
+
+/// Merges the lineage of two inputs whose columns line up by position, keeping the names of `lhs`.
+fn merge_by_position(lhs: Lineage, rhs: Lineage) -> Lineage {
+    lhs.into_iter()
+        .zip(rhs.into_values())
+        .map(|((name, mut sources), rhs_sources)| {
+            sources.extend(rhs_sources);
+            (name, sources)
+        })
+        .collect()
+}
+
+fn node_lineage(plan: &LogicalPlan, sources: &mut Vec<LineageSource>) -> DaftResult<Lineage> {
+    Ok(match plan {
+        LogicalPlan::Source(Source {
+            output_schema,
+            source_info,
+            ..
+        }) => {
+            let source = match source_info.as_ref() {
+                SourceInfo::Physical(info) => match &info.scan_state {
+                    ScanState::Operator(scan_op) => LineageSource {
+                        name: scan_op.0.name().to_string(),
+                        files: scan_op.0.file_paths(),
+                    },
+                    ScanState::Tasks(_) => LineageSource {
+                        name: "ScanTasks".to_string(),
+                        files: vec![],
+                    },
+                },
+                SourceInfo::InMemory(info) => LineageSource {
+                    name: info.cache_key.clone(),
+                    files: vec![],
+                },
+                SourceInfo::PlaceHolder(info) => LineageSource {
+                    name: format!("PlaceHolder({})", info.source_id),
+                    files: vec![],
+                },
+                SourceInfo::Stream(info) => LineageSource {
+                    name: info.name.clone(),
+                    files: vec![],
+                },
+            };
+            let source = add_source(sources, source);
+            output_schema
+                .fields
+                .keys()
+                .map(|name| {
+                    let column = SourceColumn {
+                        source,
+                        column: name.clone(),
+                    };
+                    (name.clone(), BTreeSet::from([column]))
+                })
+                .collect()
+        }
+        LogicalPlan::Project(Project {
+            input, projection, ..
+        })
+        | LogicalPlan::ActorPoolProject(ActorPoolProject {
+            input, projection, ..
+        }) => exprs_lineage(projection, &node_lineage(input, sources)?),
+        LogicalPlan::Filter(Filter { input, .. })
+        | LogicalPlan::Limit(Limit { input, .. })
+        | LogicalPlan::Explode(Explode { input, .. })
+        | LogicalPlan::Sort(Sort { input, .. })
+        | LogicalPlan::TopN(TopN { input, .. })
+        | LogicalPlan::Repartition(Repartition { input, .. })
+        | LogicalPlan::Distinct(Distinct { input, .. })
+        | LogicalPlan::Sample(Sample { input, .. })
+        | LogicalPlan::AssertUnique(AssertUnique { input, .. }) => node_lineage(input, sources)?,
+        LogicalPlan::Sink(Sink { input, schema, .. }) => {
+            let input = node_lineage(input, sources)?;
+            schema
+                .fields
+                .keys()
+                .map(|name| (name.clone(), input.get(name).cloned().unwrap_or_default()))
+                .collect()
+        }
+        LogicalPlan::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
+            input,
+            column_name,
+            ..
+        }) => {
+            let mut lineage = node_lineage(input, sources)?;
+            lineage.shift_insert(0, column_name.clone(), BTreeSet::new());
+            lineage
+        }
+        LogicalPlan::Unpivot(Unpivot {
+            input,
+            ids,
+            values,
+            variable_name,
+            value_name,
+            ..
+        }) => {
+            let input = node_lineage(input, sources)?;
+            let mut lineage = exprs_lineage(ids, &input);
+            let values = values
+                .iter()
+                .flat_map(|expr| expr_lineage(expr, &input))
+                .collect::<BTreeSet<_>>();
+            lineage.insert(variable_name.clone(), values.clone());
+            lineage.insert(value_name.clone(), values);
+            lineage
+        }
+        LogicalPlan::Aggregate(Aggregate {
+            input,
+            aggregations,
+            groupby,
+            ..
+        }) => {
+            let input = node_lineage(input, sources)?;
+            let mut lineage = exprs_lineage(groupby, &input);
+            lineage.extend(exprs_lineage(aggregations, &input));
+            lineage
+        }
+        LogicalPlan::WindowAggregate(WindowAggregate {
+            input,
+            aggregations,
+            groupby,
+            event_time_column,
+            ..
+        }) => {
+            let input = node_lineage(input, sources)?;
+            let mut lineage = exprs_lineage(groupby, &input);
+            let event_time = input.get(event_time_column).cloned().unwrap_or_default();
+            lineage.insert(WINDOW_START_COLUMN_NAME.to_string(), event_time.clone());
+            lineage.insert(WINDOW_END_COLUMN_NAME.to_string(), event_time);
+            lineage.extend(exprs_lineage(aggregations, &input));
+            lineage
+        }
+        LogicalPlan::Window(Window {
+            input,
+            functions,
+            names,
+            ..
+        }) => {
+            let mut lineage = node_lineage(input, sources)?;
+            for (function, name) in functions.iter().zip(names) {
+                let function_lineage = function
+                    .input()
+                    .map(|expr| expr_lineage(expr, &lineage))
+                    .unwrap_or_default();
+                lineage.insert(name.clone(), function_lineage);
+            }
+            lineage
+        }
+        LogicalPlan::Pivot(Pivot {
+            input,
+            group_by,
+            pivot_column,
+            value_column,
+            names,
+            ..
+        }) => {
+            let input = node_lineage(input, sources)?;
+            let mut lineage = exprs_lineage(group_by, &input);
+            let mut values = expr_lineage(pivot_column, &input);
+            values.extend(expr_lineage(value_column, &input));
+            for name in names {
+                lineage.insert(name.clone(), values.clone());
+            }
+            lineage
+        }
+        LogicalPlan::Concat(Concat { input, other, .. }) => {
+            let input = node_lineage(input, sources)?;
+            merge_by_name(input, node_lineage(other, sources)?)
+        }
+        LogicalPlan::Intersect(Intersect { lhs, rhs, .. })
+        | LogicalPlan::Union(Union { lhs, rhs, .. }) => {
+            let lhs = node_lineage(lhs, sources)?;
+            merge_by_position(lhs, node_lineage(rhs, sources)?)
+        }
+        LogicalPlan::Join(Join {
+            left,
+            right,
+            output_schema,
+            ..
+        }) => {
+            // Columns of the right side that collide with the left side have already been renamed
+            // by a projection under the join, so the output columns can be looked up by name.
+            let left = node_lineage(left, sources)?;
+            let right = node_lineage(right, sources)?;
+            output_schema
+                .fields
+                .keys()
+                .map(|name| {
+                    let mut lineage = left.get(name).cloned().unwrap_or_default();
+                    lineage.extend(right.get(name).cloned().unwrap_or_default());
+                    (name.clone(), lineage)
+                })
+                .collect()
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+
+    use super::*;
+    use crate::test::{dummy_scan_node, dummy_scan_operator};
+
+    fn source_columns(columns: &[(usize, &str)]) -> BTreeSet<SourceColumn> {
+        columns
+            .iter()
+            .map(|(source, column)| SourceColumn {
+                source: *source,
+                column: column.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lineage_through_projection_and_aggregation() -> DaftResult<()> {
+        let plan = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Utf8),
+        ]))
+        .select(vec![
+            col("c"),
+            col("a").add(col("b")).alias("sum"),
+            lit(1).alias("one"),
+        ])?
+        .aggregate(vec![col("sum").sum()], vec![col("c")])?
+        .build();
+
+        let lineage = column_lineage(&plan)?;
+        assert_eq!(lineage.sources.len(), 1);
+        assert_eq!(lineage.sources[0].name, "dummy");
+        assert_eq!(lineage.get("c"), Some(&source_columns(&[(0, "c")])));
+        assert_eq!(
+            lineage.get("sum"),
+            Some(&source_columns(&[(0, "a"), (0, "b")]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lineage_through_join() -> DaftResult<()> {
+        let left = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("x", DataType::Int64),
+        ]));
+        let right = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("y", DataType::Int64),
+        ]))
+        .select(vec![col("id").alias("right_id"), col("y")])?;
+        let plan = left
+            .join(
+                right,
+                vec![col("id")],
+                vec![col("right_id")],
+                JoinType::Inner,
+                None,
+                None,
+                None,
+                false,
+            )?
+            .select(vec![col("x").add(col("y")).alias("z")])?
+            .build();
+
+        let lineage = column_lineage(&plan)?;
+        assert_eq!(lineage.columns.len(), 1);
+        // Both scans read the same dummy operator, so they are the same source.
+        assert_eq!(lineage.sources.len(), 1);
+        assert_eq!(
+            lineage.get("z"),
+            Some(&source_columns(&[(0, "x"), (0, "y")]))
+        );
+        assert!(lineage.to_json()?.contains("\"columns\""));
+        Ok(())
+    }
+}
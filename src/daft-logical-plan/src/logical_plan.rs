@@ -26,6 +26,7 @@ pub enum LogicalPlan {
     Explode(Explode),
     Unpivot(Unpivot),
     Sort(Sort),
+    TopN(TopN),
     Repartition(Repartition),
     Distinct(Distinct),
     Aggregate(Aggregate),
@@ -37,6 +38,9 @@ pub enum LogicalPlan {
     Sink(Sink),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    AssertUnique(AssertUnique),
+    WindowAggregate(WindowAggregate),
+    Window(Window),
 }
 
 pub type LogicalPlanRef = Arc<LogicalPlan>;
@@ -62,6 +66,7 @@ impl LogicalPlan {
             }) => exploded_schema.clone(),
             Self::Unpivot(Unpivot { output_schema, .. }) => output_schema.clone(),
             Self::Sort(Sort { input, .. }) => input.schema(),
+            Self::TopN(TopN { input, .. }) => input.schema(),
             Self::Repartition(Repartition { input, .. }) => input.schema(),
             Self::Distinct(Distinct { input, .. }) => input.schema(),
             Self::Aggregate(Aggregate { output_schema, .. }) => output_schema.clone(),
@@ -75,6 +80,9 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => {
                 schema.clone()
             }
+            Self::AssertUnique(AssertUnique { input, .. }) => input.schema(),
+            Self::WindowAggregate(WindowAggregate { output_schema, .. }) => output_schema.clone(),
+            Self::Window(Window { output_schema, .. }) => output_schema.clone(),
         }
     }
 
@@ -107,6 +115,22 @@ impl LogicalPlan {
                 let res = sort.sort_by.iter().flat_map(get_required_columns).collect();
                 vec![res]
             }
+            Self::TopN(top_n) => {
+                let res = top_n
+                    .sort_by
+                    .iter()
+                    .flat_map(get_required_columns)
+                    .collect();
+                vec![res]
+            }
+            Self::AssertUnique(assert_unique) => {
+                let res = assert_unique
+                    .keys
+                    .iter()
+                    .flat_map(get_required_columns)
+                    .collect();
+                vec![res]
+            }
             Self::Repartition(repartition) => {
                 let res = repartition
                     .repartition_spec
@@ -154,6 +178,32 @@ impl LogicalPlan {
                     .collect();
                 vec![res]
             }
+            Self::WindowAggregate(window_aggregate) => {
+                let res = window_aggregate
+                    .aggregations
+                    .iter()
+                    .flat_map(|agg| agg.children())
+                    .flat_map(|e| get_required_columns(&e))
+                    .chain(
+                        window_aggregate
+                            .groupby
+                            .iter()
+                            .flat_map(get_required_columns),
+                    )
+                    .chain(std::iter::once(window_aggregate.event_time_column.clone()))
+                    .collect();
+                vec![res]
+            }
+            Self::Window(window) => {
+                let res = window
+                    .partition_by
+                    .iter()
+                    .chain(window.order_by.iter())
+                    .chain(window.functions.iter().filter_map(|f| f.input()))
+                    .flat_map(get_required_columns)
+                    .collect();
+                vec![res]
+            }
             Self::Pivot(pivot) => {
                 let res = pivot
                     .group_by
@@ -190,6 +240,7 @@ impl LogicalPlan {
             Self::Explode(..) => "Explode",
             Self::Unpivot(..) => "Unpivot",
             Self::Sort(..) => "Sort",
+            Self::TopN(..) => "TopN",
             Self::Repartition(..) => "Repartition",
             Self::Distinct(..) => "Distinct",
             Self::Aggregate(..) => "Aggregate",
@@ -201,6 +252,9 @@ impl LogicalPlan {
             Self::Sink(..) => "Sink",
             Self::Sample(..) => "Sample",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
+            Self::AssertUnique(..) => "AssertUnique",
+            Self::WindowAggregate(..) => "WindowAggregate",
+            Self::Window(..) => "Window",
         }
     }
 
@@ -214,6 +268,7 @@ impl LogicalPlan {
             | Self::Explode(Explode { stats_state, .. })
             | Self::Unpivot(Unpivot { stats_state, .. })
             | Self::Sort(Sort { stats_state, .. })
+            | Self::TopN(TopN { stats_state, .. })
             | Self::Repartition(Repartition { stats_state, .. })
             | Self::Distinct(Distinct { stats_state, .. })
             | Self::Aggregate(Aggregate { stats_state, .. })
@@ -222,9 +277,10 @@ impl LogicalPlan {
             | Self::Join(Join { stats_state, .. })
             | Self::Sink(Sink { stats_state, .. })
             | Self::Sample(Sample { stats_state, .. })
-            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. }) => {
-                stats_state.materialized_stats()
-            }
+            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
+            | Self::AssertUnique(AssertUnique { stats_state, .. })
+            | Self::WindowAggregate(WindowAggregate { stats_state, .. })
+            | Self::Window(Window { stats_state, .. }) => stats_state.materialized_stats(),
             Self::Intersect(_) => {
                 panic!("Intersect nodes should be optimized away before stats are materialized")
             }
@@ -246,6 +302,7 @@ impl LogicalPlan {
             Self::Explode(plan) => Self::Explode(plan.with_materialized_stats()),
             Self::Unpivot(plan) => Self::Unpivot(plan.with_materialized_stats()),
             Self::Sort(plan) => Self::Sort(plan.with_materialized_stats()),
+            Self::TopN(plan) => Self::TopN(plan.with_materialized_stats()),
             Self::Repartition(plan) => Self::Repartition(plan.with_materialized_stats()),
             Self::Distinct(plan) => Self::Distinct(plan.with_materialized_stats()),
             Self::Aggregate(plan) => Self::Aggregate(plan.with_materialized_stats()),
@@ -263,6 +320,9 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(plan) => {
                 Self::MonotonicallyIncreasingId(plan.with_materialized_stats())
             }
+            Self::AssertUnique(plan) => Self::AssertUnique(plan.with_materialized_stats()),
+            Self::WindowAggregate(plan) => Self::WindowAggregate(plan.with_materialized_stats()),
+            Self::Window(plan) => Self::Window(plan.with_materialized_stats()),
         }
     }
 
@@ -276,6 +336,7 @@ impl LogicalPlan {
             Self::Explode(explode) => explode.multiline_display(),
             Self::Unpivot(unpivot) => unpivot.multiline_display(),
             Self::Sort(sort) => sort.multiline_display(),
+            Self::TopN(top_n) => top_n.multiline_display(),
             Self::Repartition(repartition) => repartition.multiline_display(),
             Self::Distinct(distinct) => distinct.multiline_display(),
             Self::Aggregate(aggregate) => aggregate.multiline_display(),
@@ -289,6 +350,9 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
                 monotonically_increasing_id.multiline_display()
             }
+            Self::AssertUnique(assert_unique) => assert_unique.multiline_display(),
+            Self::WindowAggregate(window_aggregate) => window_aggregate.multiline_display(),
+            Self::Window(window) => window.multiline_display(),
         }
     }
 
@@ -302,6 +366,7 @@ impl LogicalPlan {
             Self::Explode(Explode { input, .. }) => vec![input],
             Self::Unpivot(Unpivot { input, .. }) => vec![input],
             Self::Sort(Sort { input, .. }) => vec![input],
+            Self::TopN(TopN { input, .. }) => vec![input],
             Self::Repartition(Repartition { input, .. }) => vec![input],
             Self::Distinct(Distinct { input, .. }) => vec![input],
             Self::Aggregate(Aggregate { input, .. }) => vec![input],
@@ -315,6 +380,9 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 vec![input]
             }
+            Self::AssertUnique(AssertUnique { input, .. }) => vec![input],
+            Self::WindowAggregate(WindowAggregate { input, .. }) => vec![input],
+            Self::Window(Window { input, .. }) => vec![input],
         }
     }
 
@@ -330,6 +398,7 @@ impl LogicalPlan {
                 Self::Limit(Limit { limit, eager, .. }) => Self::Limit(Limit::new(input.clone(), *limit, *eager)),
                 Self::Explode(Explode { to_explode, .. }) => Self::Explode(Explode::try_new(input.clone(), to_explode.clone()).unwrap()),
                 Self::Sort(Sort { sort_by, descending, nulls_first, .. }) => Self::Sort(Sort::try_new(input.clone(), sort_by.clone(), descending.clone(), nulls_first.clone()).unwrap()),
+                Self::TopN(TopN { sort_by, descending, nulls_first, limit, .. }) => Self::TopN(TopN::try_new(input.clone(), sort_by.clone(), descending.clone(), nulls_first.clone(), *limit).unwrap()),
                 Self::Repartition(Repartition {  repartition_spec: scheme_config, .. }) => Self::Repartition(Repartition::try_new(input.clone(), scheme_config.clone()).unwrap()),
                 Self::Distinct(_) => Self::Distinct(Distinct::new(input.clone())),
                 Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::try_new(input.clone(), aggregations.clone(), groupby.clone()).unwrap()),
@@ -339,6 +408,9 @@ impl LogicalPlan {
                 Self::Unpivot(Unpivot {ids, values, variable_name, value_name, output_schema, ..}) =>
                     Self::Unpivot(Unpivot::new(input.clone(), ids.clone(), values.clone(), variable_name.clone(), value_name.clone(), output_schema.clone())),
                 Self::Sample(Sample {fraction, with_replacement, seed, ..}) => Self::Sample(Sample::new(input.clone(), *fraction, *with_replacement, *seed)),
+                Self::AssertUnique(AssertUnique { keys, .. }) => Self::AssertUnique(AssertUnique::try_new(input.clone(), keys.clone()).unwrap()),
+                Self::WindowAggregate(WindowAggregate { aggregations, groupby, event_time_column, window_size, window_slide, watermark_delay, .. }) => Self::WindowAggregate(WindowAggregate::try_new(input.clone(), aggregations.clone(), groupby.clone(), event_time_column.clone(), *window_size, Some(*window_slide), Some(*watermark_delay)).unwrap()),
+                Self::Window(Window { partition_by, order_by, descending, nulls_first, functions, names, .. }) => Self::Window(Window::try_new(input.clone(), partition_by.clone(), order_by.clone(), descending.clone(), nulls_first.clone(), functions.clone(), names.clone()).unwrap()),
                 Self::Concat(_) => panic!("Concat ops should never have only one input, but got one"),
                 Self::Intersect(_) => panic!("Intersect ops should never have only one input, but got one"),
                 Self::Union(_) => panic!("Union ops should never have only one input, but got one"),
@@ -484,6 +556,7 @@ impl_from_data_struct_for_logical_plan!(Limit);
 impl_from_data_struct_for_logical_plan!(Explode);
 impl_from_data_struct_for_logical_plan!(Unpivot);
 impl_from_data_struct_for_logical_plan!(Sort);
+impl_from_data_struct_for_logical_plan!(TopN);
 impl_from_data_struct_for_logical_plan!(Repartition);
 impl_from_data_struct_for_logical_plan!(Distinct);
 impl_from_data_struct_for_logical_plan!(Aggregate);
@@ -495,3 +568,6 @@ impl_from_data_struct_for_logical_plan!(Join);
 impl_from_data_struct_for_logical_plan!(Sink);
 impl_from_data_struct_for_logical_plan!(Sample);
 impl_from_data_struct_for_logical_plan!(MonotonicallyIncreasingId);
+impl_from_data_struct_for_logical_plan!(AssertUnique);
+impl_from_data_struct_for_logical_plan!(WindowAggregate);
+impl_from_data_struct_for_logical_plan!(Window);
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_dsl::{ExprRef, ExprResolver};
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use crate::{logical_plan, logical_plan::CreationSnafu, stats::StatsState, LogicalPlan};
+
+/// Passes its input through unchanged, but fails if two rows have equal keys.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssertUnique {
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    pub keys: Vec<ExprRef>,
+    pub stats_state: StatsState,
+}
+
+impl AssertUnique {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        keys: Vec<ExprRef>,
+    ) -> logical_plan::Result<Self> {
+        if keys.is_empty() {
+            return Err(DaftError::ValueError(
+                "df.assert_unique() must be given at least one column/expression".to_string(),
+            ))
+            .context(CreationSnafu);
+        }
+
+        let expr_resolver = ExprResolver::default();
+        let (keys, _) = expr_resolver
+            .resolve(keys, &input.schema())
+            .context(CreationSnafu)?;
+
+        Ok(Self {
+            input,
+            keys,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // Asserting uniqueness does not affect cardinality.
+        let input_stats = self.input.materialized_stats();
+        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "AssertUnique: {}",
+            self.keys.iter().map(|e| e.to_string()).join(", ")
+        ));
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
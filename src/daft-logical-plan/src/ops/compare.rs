@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_core::join::JoinType;
+use daft_dsl::{col, lit, ExprRef};
+use snafu::ResultExt;
+
+use super::{AssertUnique, Filter, Join, Project};
+use crate::{logical_plan, logical_plan::CreationSnafu, LogicalPlan};
+
+/// Name of the column of a comparison that says how each row differs: `added`, `removed` or
+/// `changed`.
+pub const DIFF_COLUMN_NAME: &str = "diff";
+
+const IN_LEFT_COLUMN_NAME: &str = "__compare_in_left__";
+const IN_RIGHT_COLUMN_NAME: &str = "__compare_in_right__";
+
+/// The row-level differences between two plans with the same columns, whose rows are matched by
+/// keys that must be unique in both plans.
+///
+/// Rows whose keys are only in the right plan are `added`, rows whose keys are only in the left
+/// plan are `removed` and rows whose other columns differ are `changed`. Each other column `c` is
+/// output as `left.c` and `right.c`, along with whether it changed as `changed.c`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Compare {
+    // Upstream nodes.
+    pub lhs: Arc<LogicalPlan>,
+    pub rhs: Arc<LogicalPlan>,
+    pub keys: Vec<String>,
+}
+
+impl Compare {
+    pub(crate) fn try_new(
+        lhs: Arc<LogicalPlan>,
+        rhs: Arc<LogicalPlan>,
+        keys: Vec<String>,
+    ) -> logical_plan::Result<Self> {
+        let lhs_schema = lhs.schema();
+        let rhs_schema = rhs.schema();
+        if keys.is_empty() {
+            return Err(DaftError::ValueError(
+                "Plans must be compared by at least one key column".to_string(),
+            ))
+            .context(CreationSnafu);
+        }
+        if let Some(key) = keys
+            .iter()
+            .find(|key| !lhs_schema.fields.contains_key(*key))
+        {
+            return Err(DaftError::ValueError(format!(
+                "Key column {key} is not in the plans that are compared, whose schema is: {lhs_schema}"
+            )))
+            .context(CreationSnafu);
+        }
+        if keys.iter().any(|key| key == DIFF_COLUMN_NAME) {
+            return Err(DaftError::ValueError(format!(
+                "Key column {DIFF_COLUMN_NAME} conflicts with the column of the comparison that says how rows differ"
+            )))
+            .context(CreationSnafu);
+        }
+        // lhs and rhs should have the same columns with the same types, in any order
+        if lhs_schema.len() != rhs_schema.len()
+            || lhs_schema.fields.values().any(|l| {
+                rhs_schema
+                    .fields
+                    .get(&l.name)
+                    .map_or(true, |r| l.dtype != r.dtype)
+            })
+        {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Both plans must have the same columns with the same types to be compared, \
+                but got lhs schema: {}, rhs schema: {}",
+                lhs_schema, rhs_schema
+            )))
+            .context(CreationSnafu);
+        }
+        Ok(Self { lhs, rhs, keys })
+    }
+
+    /// A comparison is a full outer join of the plans on their keys, which is filtered to the rows
+    /// that differ:
+    /// ```sql
+    /// select k, case when r.k is null then 'removed' when l.k is null then 'added' else 'changed' end as diff,
+    ///   l.c as "left.c", r.c as "right.c", l.c is distinct from r.c as "changed.c"
+    /// from l full outer join r on l.k <=> r.k
+    /// where l.k is null or r.k is null or l.c is distinct from r.c
+    /// ```
+    pub(crate) fn to_logical_plan(&self) -> logical_plan::Result<LogicalPlan> {
+        let keys: Vec<ExprRef> = self.keys.iter().map(|key| col(key.as_str())).collect();
+        let values: Vec<String> = self
+            .lhs
+            .schema()
+            .fields
+            .keys()
+            .filter(|name| !self.keys.contains(name))
+            .cloned()
+            .collect();
+
+        let side = |plan: &Arc<LogicalPlan>, prefix: &str, marker: &str| {
+            let plan: LogicalPlan = AssertUnique::try_new(plan.clone(), keys.clone())?.into();
+            let projection = keys
+                .iter()
+                .cloned()
+                .chain(
+                    values
+                        .iter()
+                        .map(|value| col(value.as_str()).alias(format!("{prefix}.{value}"))),
+                )
+                .chain(std::iter::once(lit(true).alias(marker)))
+                .collect();
+            Ok::<_, logical_plan::Error>(LogicalPlan::from(Project::try_new(
+                plan.arced(),
+                projection,
+            )?))
+        };
+        let lhs = side(&self.lhs, "left", IN_LEFT_COLUMN_NAME)?;
+        let rhs = side(&self.rhs, "right", IN_RIGHT_COLUMN_NAME)?;
+
+        let join: LogicalPlan = Join::try_new(
+            lhs.arced(),
+            rhs.arced(),
+            keys.clone(),
+            keys.clone(),
+            Some(vec![true; keys.len()]),
+            JoinType::Outer,
+            None,
+            None,
+            None,
+            false,
+        )?
+        .into();
+
+        let in_left = col(IN_LEFT_COLUMN_NAME).not_null();
+        let in_right = col(IN_RIGHT_COLUMN_NAME).not_null();
+        let in_both = in_left.clone().and(in_right.clone());
+        let changed: Vec<ExprRef> = values
+            .iter()
+            .map(|value| {
+                let left = col(format!("left.{value}"));
+                let right = col(format!("right.{value}"));
+                let is_distinct = left
+                    .clone()
+                    .is_null()
+                    .not_eq(right.clone().is_null())
+                    .or(left.not_eq(right).fill_null(lit(false)));
+                in_both.clone().and(is_distinct)
+            })
+            .collect();
+        let any_changed = changed
+            .iter()
+            .cloned()
+            .reduce(|a, b| a.or(b))
+            .unwrap_or_else(|| lit(false));
+
+        let filter: LogicalPlan =
+            Filter::try_new(join.arced(), in_both.not().or(any_changed))?.into();
+
+        let diff = in_left.not().if_else(
+            lit("added"),
+            in_right.not().if_else(lit("removed"), lit("changed")),
+        );
+        let projection = keys
+            .into_iter()
+            .chain(std::iter::once(diff.alias(DIFF_COLUMN_NAME)))
+            .chain(values.iter().zip(changed).flat_map(|(value, changed)| {
+                [
+                    col(format!("left.{value}")),
+                    col(format!("right.{value}")),
+                    changed.alias(format!("changed.{value}")),
+                ]
+            }))
+            .collect();
+
+        Ok(Project::try_new(filter.arced(), projection)?.into())
+    }
+}
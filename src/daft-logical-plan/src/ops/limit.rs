@@ -29,32 +29,8 @@ impl Limit {
 
     pub(crate) fn with_materialized_stats(mut self) -> Self {
         let input_stats = self.input.materialized_stats();
-        let limit = self.limit as usize;
-        let est_bytes_per_row_lower = input_stats.approx_stats.lower_bound_bytes
-            / input_stats.approx_stats.lower_bound_rows.max(1);
-        let est_bytes_per_row_upper =
-            input_stats
-                .approx_stats
-                .upper_bound_bytes
-                .and_then(|bytes| {
-                    input_stats
-                        .approx_stats
-                        .upper_bound_rows
-                        .map(|rows| bytes / rows.max(1))
-                });
-        let new_lower_rows = input_stats.approx_stats.lower_bound_rows.min(limit);
-        let new_upper_rows = input_stats
-            .approx_stats
-            .upper_bound_rows
-            .map(|ub| ub.min(limit))
-            .unwrap_or(limit);
-        let approx_stats = ApproxStats {
-            lower_bound_rows: new_lower_rows,
-            upper_bound_rows: Some(new_upper_rows),
-            lower_bound_bytes: new_lower_rows * est_bytes_per_row_lower,
-            upper_bound_bytes: est_bytes_per_row_upper.map(|x| x * new_upper_rows),
-        };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state =
+            StatsState::Materialized(limited_stats(input_stats, self.limit as usize).into());
         self
     }
 
@@ -66,3 +42,31 @@ impl Limit {
         res
     }
 }
+
+/// Estimates the stats of the first `limit` rows of an input.
+pub(super) fn limited_stats(input_stats: &PlanStats, limit: usize) -> PlanStats {
+    let est_bytes_per_row_lower = input_stats.approx_stats.lower_bound_bytes
+        / input_stats.approx_stats.lower_bound_rows.max(1);
+    let est_bytes_per_row_upper = input_stats
+        .approx_stats
+        .upper_bound_bytes
+        .and_then(|bytes| {
+            input_stats
+                .approx_stats
+                .upper_bound_rows
+                .map(|rows| bytes / rows.max(1))
+        });
+    let new_lower_rows = input_stats.approx_stats.lower_bound_rows.min(limit);
+    let new_upper_rows = input_stats
+        .approx_stats
+        .upper_bound_rows
+        .map(|ub| ub.min(limit))
+        .unwrap_or(limit);
+    let approx_stats = ApproxStats {
+        lower_bound_rows: new_lower_rows,
+        upper_bound_rows: Some(new_upper_rows),
+        lower_bound_bytes: new_lower_rows * est_bytes_per_row_lower,
+        upper_bound_bytes: est_bytes_per_row_upper.map(|x| x * new_upper_rows),
+    };
+    PlanStats::new(approx_stats)
+}
@@ -1,5 +1,7 @@
 mod actor_pool_project;
 mod agg;
+mod assert_unique;
+mod compare;
 mod concat;
 mod distinct;
 mod explode;
@@ -15,10 +17,15 @@ mod set_operations;
 mod sink;
 mod sort;
 mod source;
+mod top_n;
 mod unpivot;
+mod window;
+mod window_aggregate;
 
 pub use actor_pool_project::ActorPoolProject;
 pub use agg::Aggregate;
+pub use assert_unique::AssertUnique;
+pub use compare::{Compare, DIFF_COLUMN_NAME};
 pub use concat::Concat;
 pub use distinct::Distinct;
 pub use explode::Explode;
@@ -34,4 +41,9 @@ pub use set_operations::{Intersect, Union};
 pub use sink::Sink;
 pub use sort::Sort;
 pub use source::Source;
+pub use top_n::TopN;
 pub use unpivot::Unpivot;
+#[cfg(feature = "python")]
+pub use window::PyWindowFunction;
+pub use window::{Window, WindowFrame, WindowFunction};
+pub use window_aggregate::{WindowAggregate, WINDOW_END_COLUMN_NAME, WINDOW_START_COLUMN_NAME};
@@ -491,6 +491,24 @@ fn replace_column_with_semantic_id_aggexpr(
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::Concat, |_| e)
         }
+        AggExpr::TopK(ref child, k) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::TopK(transformed_child, k),
+                |_| e,
+            )
+        }
+        AggExpr::ApproxHeavyHitters(ref child, capacity) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::ApproxHeavyHitters(transformed_child, capacity),
+                |_| e,
+            )
+        }
+        AggExpr::MergeHeavyHitters(ref child, capacity) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::MergeHeavyHitters(transformed_child, capacity),
+                |_| e,
+            )
+        }
         AggExpr::MapGroups { func, inputs } => {
             let transforms = inputs
                 .iter()
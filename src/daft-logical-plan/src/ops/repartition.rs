@@ -1,10 +1,10 @@
 use std::sync::Arc;
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_dsl::ExprResolver;
 
 use crate::{
-    partitioning::{HashRepartitionConfig, RepartitionSpec},
+    partitioning::{HashRepartitionConfig, RangeRepartitionConfig, RepartitionSpec},
     stats::StatsState,
     LogicalPlan,
 };
@@ -32,6 +32,32 @@ impl Repartition {
                     by: resolved_by,
                 })
             }
+            RepartitionSpec::Range(RangeRepartitionConfig {
+                num_partitions,
+                by,
+                descending,
+            }) => {
+                if by.is_empty() {
+                    return Err(DaftError::ValueError(
+                        "Range repartitioning requires at least one column".to_string(),
+                    ));
+                }
+                if by.len() != descending.len() {
+                    return Err(DaftError::ValueError(format!(
+                        "Expected as many descending flags as columns to repartition by, got {} and {}",
+                        descending.len(),
+                        by.len()
+                    )));
+                }
+                let expr_resolver = ExprResolver::default();
+
+                let (resolved_by, _) = expr_resolver.resolve(by, &input.schema())?;
+                RepartitionSpec::Range(RangeRepartitionConfig {
+                    num_partitions,
+                    by: resolved_by,
+                    descending,
+                })
+            }
             RepartitionSpec::Random(_) | RepartitionSpec::IntoPartitions(_) => repartition_spec,
         };
 
@@ -1,13 +1,16 @@
 use std::sync::Arc;
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_core::prelude::*;
 use daft_dsl::ExprResolver;
 
 #[cfg(feature = "python")]
 use crate::sink_info::CatalogType;
 use crate::{
-    sink_info::SinkInfo,
+    sink_info::{
+        CopyIntoInfo, SinkInfo, WriteConstraint, WriteConstraints, MANIFEST_NUM_ROWS,
+        MANIFEST_SIZE_BYTES, QUARANTINED,
+    },
     stats::{PlanStats, StatsState},
     LogicalPlan, OutputFileInfo,
 };
@@ -26,32 +29,15 @@ impl Sink {
     pub(crate) fn try_new(input: Arc<LogicalPlan>, sink_info: Arc<SinkInfo>) -> DaftResult<Self> {
         let schema = input.schema();
 
-        // replace partition columns with resolved columns
+        // replace partition columns and constraint predicates with resolved expressions
         let sink_info = match sink_info.as_ref() {
-            SinkInfo::OutputFileInfo(OutputFileInfo {
-                root_dir,
-                file_format,
-                partition_cols,
-                compression,
-                io_config,
-            }) => {
-                let expr_resolver = ExprResolver::default();
-
-                let resolved_partition_cols = partition_cols
-                    .clone()
-                    .map(|cols| {
-                        expr_resolver
-                            .resolve(cols, &schema)
-                            .map(|(resolved_cols, _)| resolved_cols)
-                    })
-                    .transpose()?;
-
-                Arc::new(SinkInfo::OutputFileInfo(OutputFileInfo {
-                    root_dir: root_dir.clone(),
-                    file_format: *file_format,
-                    partition_cols: resolved_partition_cols,
-                    compression: compression.clone(),
-                    io_config: io_config.clone(),
+            SinkInfo::OutputFileInfo(output_file_info) => Arc::new(SinkInfo::OutputFileInfo(
+                resolve_file_info_exprs(output_file_info, &schema)?,
+            )),
+            SinkInfo::CopyInto(CopyIntoInfo { file_info, mode }) => {
+                Arc::new(SinkInfo::CopyInto(CopyIntoInfo {
+                    file_info: resolve_file_info_exprs(file_info, &schema)?,
+                    mode: *mode,
                 }))
             }
             #[cfg(feature = "python")]
@@ -60,13 +46,10 @@ impl Sink {
 
         let fields = match sink_info.as_ref() {
             SinkInfo::OutputFileInfo(output_file_info) => {
-                let mut fields = vec![Field::new("path", DataType::Utf8)];
-                if let Some(ref pcols) = output_file_info.partition_cols {
-                    for pc in pcols {
-                        fields.push(pc.to_field(&schema)?);
-                    }
-                }
-                fields
+                written_file_fields(output_file_info, &schema, false)?
+            }
+            SinkInfo::CopyInto(CopyIntoInfo { file_info, .. }) => {
+                written_file_fields(file_info, &schema, true)?
             }
             #[cfg(feature = "python")]
             SinkInfo::CatalogInfo(catalog_info) => {
@@ -106,6 +89,13 @@ impl Sink {
                 res.push(format!("Sink: {:?}", output_file_info.file_format));
                 res.extend(output_file_info.multiline_display());
             }
+            SinkInfo::CopyInto(copy_into_info) => {
+                res.push(format!(
+                    "Sink: CopyInto({:?})",
+                    copy_into_info.file_info.file_format
+                ));
+                res.extend(copy_into_info.multiline_display());
+            }
             #[cfg(feature = "python")]
             SinkInfo::CatalogInfo(catalog_info) => match &catalog_info.catalog {
                 CatalogType::Iceberg(iceberg_info) => {
@@ -129,3 +119,78 @@ impl Sink {
         res
     }
 }
+
+fn resolve_file_info_exprs(info: &OutputFileInfo, schema: &Schema) -> DaftResult<OutputFileInfo> {
+    let expr_resolver = ExprResolver::default();
+
+    let resolved_partition_cols = info
+        .partition_cols
+        .clone()
+        .map(|cols| {
+            expr_resolver
+                .resolve(cols, schema)
+                .map(|(resolved_cols, _)| resolved_cols)
+        })
+        .transpose()?;
+
+    let resolved_constraints = info
+        .constraints
+        .clone()
+        .map(|constraints| {
+            let resolved = constraints
+                .constraints
+                .into_iter()
+                .map(|constraint| {
+                    let (predicate, field) =
+                        expr_resolver.resolve_single(constraint.predicate, schema)?;
+                    if field.dtype != DataType::Boolean {
+                        return Err(DaftError::ValueError(format!(
+                            "Expected the predicate of write constraint `{}` to be a boolean expression, but got {}",
+                            constraint.name, field.dtype
+                        )));
+                    }
+                    Ok(WriteConstraint {
+                        name: constraint.name,
+                        predicate,
+                    })
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok::<_, DaftError>(WriteConstraints {
+                constraints: resolved,
+                ..constraints
+            })
+        })
+        .transpose()?;
+
+    Ok(OutputFileInfo {
+        partition_cols: resolved_partition_cols,
+        constraints: resolved_constraints,
+        ..info.clone()
+    })
+}
+
+/// The columns of the results of a write to files: the path and partition values of each written
+/// file, followed by its number of rows and size for writes that produce a manifest, and whether
+/// it holds quarantined rows for writes that quarantine rows.
+fn written_file_fields(
+    info: &OutputFileInfo,
+    schema: &Schema,
+    manifest: bool,
+) -> DaftResult<Vec<Field>> {
+    let mut fields = vec![Field::new("path", DataType::Utf8)];
+    if let Some(ref pcols) = info.partition_cols {
+        for pc in pcols {
+            fields.push(pc.to_field(schema)?);
+        }
+    }
+    if manifest {
+        fields.push(Field::new(MANIFEST_NUM_ROWS, DataType::UInt64));
+        fields.push(Field::new(MANIFEST_SIZE_BYTES, DataType::UInt64));
+    }
+    if let Some(constraints) = &info.constraints
+        && constraints.quarantines()
+    {
+        fields.push(Field::new(QUARANTINED, DataType::Boolean));
+    }
+    Ok(fields)
+}
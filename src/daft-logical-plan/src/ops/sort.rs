@@ -31,6 +31,15 @@ impl Sort {
             ))
             .context(CreationSnafu);
         }
+        if descending.len() != sort_by.len() || nulls_first.len() != sort_by.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected as many `descending` and `nulls_first` flags as sort keys, got {} and {} for {} keys",
+                descending.len(),
+                nulls_first.len(),
+                sort_by.len()
+            )))
+            .context(CreationSnafu);
+        }
 
         let expr_resolver = ExprResolver::default();
 
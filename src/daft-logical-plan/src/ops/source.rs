@@ -93,7 +93,7 @@ impl Source {
                     approx_stats
                 }
             },
-            SourceInfo::PlaceHolder(_) => ApproxStats::empty(),
+            SourceInfo::PlaceHolder(_) | SourceInfo::Stream(_) => ApproxStats::empty(),
         };
         self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
         self
@@ -119,9 +119,9 @@ impl Source {
                 ));
                 res.extend(pushdowns.multiline_display());
             }
-            SourceInfo::InMemory(InMemoryInfo { num_partitions, .. }) => {
+            SourceInfo::InMemory(in_memory_info) => {
                 res.push("Source:".to_string());
-                res.push(format!("Number of partitions = {}", num_partitions));
+                res.extend(in_memory_info.multiline_display());
             }
             SourceInfo::PlaceHolder(PlaceHolderInfo {
                 source_id,
@@ -132,6 +132,10 @@ impl Source {
                 res.push(format!("Source ID = {}", source_id));
                 res.extend(clustering_spec.multiline_display());
             }
+            SourceInfo::Stream(stream_info) => {
+                res.push("Source:".to_string());
+                res.extend(stream_info.multiline_display());
+            }
         }
         res.push(format!(
             "Output schema = {}",
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use daft_dsl::ExprRef;
+use itertools::Itertools;
+
+use super::{limit::limited_stats, Sort};
+use crate::{logical_plan, stats::StatsState, LogicalPlan};
+
+/// The first `limit` rows of the input in the order of the sort keys, which is what a Sort followed
+/// by a Limit computes, without sorting all of the input.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TopN {
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    pub sort_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub nulls_first: Vec<bool>,
+    // Number of rows to keep.
+    pub limit: i64,
+    pub stats_state: StatsState,
+}
+
+impl TopN {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        limit: i64,
+    ) -> logical_plan::Result<Self> {
+        // The sort keys are validated the same way as the keys of a Sort.
+        let sort = Sort::try_new(input, sort_by, descending, nulls_first)?;
+        Ok(Self {
+            input: sort.input,
+            sort_by: sort.sort_by,
+            descending: sort.descending,
+            nulls_first: sort.nulls_first,
+            limit,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        let input_stats = self.input.materialized_stats();
+        self.stats_state =
+            StatsState::Materialized(limited_stats(input_stats, self.limit as usize).into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        let pairs = self
+            .sort_by
+            .iter()
+            .zip(self.descending.iter())
+            .zip(self.nulls_first.iter())
+            .map(|((sb, d), nf)| {
+                format!(
+                    "({}, {}, {})",
+                    sb,
+                    if *d { "descending" } else { "ascending" },
+                    if *nf { "nulls first" } else { "nulls last" }
+                )
+            })
+            .join(", ");
+        res.push(format!(
+            "TopN: Sort by = {}, Num Rows = {}",
+            pairs, self.limit
+        ));
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
@@ -0,0 +1,355 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{AggExpr, ExprRef, ExprResolver};
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use crate::{
+    logical_plan::{self, CreationSnafu},
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// The rows around the current row of a window partition that a sliding aggregation covers, as
+/// numbers of rows before and after it. Unbounded ends are `None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WindowFrame {
+    pub preceding: Option<u64>,
+    pub following: Option<u64>,
+}
+
+impl std::fmt::Display for WindowFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bound = |rows: Option<u64>, direction: &str| match rows {
+            Some(0) => "current row".to_string(),
+            Some(rows) => format!("{rows} {direction}"),
+            None => format!("unbounded {direction}"),
+        };
+        write!(
+            f,
+            "rows between {} and {}",
+            bound(self.preceding, "preceding"),
+            bound(self.following, "following")
+        )
+    }
+}
+
+/// A function evaluated for each row of a window partition over the rows of its partition, in the
+/// order of the window.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WindowFunction {
+    /// The position of the row within its partition, starting at 1.
+    RowNumber,
+    /// The position of the first row of the partition that is tied with the row in the window
+    /// order, starting at 1.
+    Rank,
+    /// The value of `expr` at `offset` rows before the row, or null past the start of the
+    /// partition.
+    Lag { expr: ExprRef, offset: u64 },
+    /// The value of `expr` at `offset` rows after the row, or null past the end of the partition.
+    Lead { expr: ExprRef, offset: u64 },
+    /// The sum of the non-null values of `expr` within the frame of the row.
+    Sum { expr: ExprRef, frame: WindowFrame },
+    /// The mean of the non-null values of `expr` within the frame of the row.
+    Mean { expr: ExprRef, frame: WindowFrame },
+}
+
+impl WindowFunction {
+    /// The expression that the function is evaluated over, if any.
+    pub fn input(&self) -> Option<&ExprRef> {
+        match self {
+            Self::RowNumber | Self::Rank => None,
+            Self::Lag { expr, .. }
+            | Self::Lead { expr, .. }
+            | Self::Sum { expr, .. }
+            | Self::Mean { expr, .. } => Some(expr),
+        }
+    }
+
+    fn with_input(&self, input: ExprRef) -> Self {
+        match self {
+            Self::RowNumber | Self::Rank => self.clone(),
+            Self::Lag { offset, .. } => Self::Lag {
+                expr: input,
+                offset: *offset,
+            },
+            Self::Lead { offset, .. } => Self::Lead {
+                expr: input,
+                offset: *offset,
+            },
+            Self::Sum { frame, .. } => Self::Sum {
+                expr: input,
+                frame: *frame,
+            },
+            Self::Mean { frame, .. } => Self::Mean {
+                expr: input,
+                frame: *frame,
+            },
+        }
+    }
+
+    /// The type of the values of the function over rows of `schema`.
+    fn output_dtype(&self, schema: &Schema) -> DaftResult<DataType> {
+        match self {
+            Self::RowNumber | Self::Rank => Ok(DataType::UInt64),
+            Self::Lag { expr, .. } | Self::Lead { expr, .. } => Ok(expr.to_field(schema)?.dtype),
+            Self::Sum { expr, .. } => Ok(AggExpr::Sum(expr.clone()).to_field(schema)?.dtype),
+            Self::Mean { expr, .. } => Ok(AggExpr::Mean(expr.clone()).to_field(schema)?.dtype),
+        }
+    }
+}
+
+impl std::fmt::Display for WindowFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RowNumber => write!(f, "row_number()"),
+            Self::Rank => write!(f, "rank()"),
+            Self::Lag { expr, offset } => write!(f, "lag({expr}, {offset})"),
+            Self::Lead { expr, offset } => write!(f, "lead({expr}, {offset})"),
+            Self::Sum { expr, frame } => write!(f, "sum({expr}) over {frame}"),
+            Self::Mean { expr, frame } => write!(f, "mean({expr}) over {frame}"),
+        }
+    }
+}
+
+/// Evaluates window functions over the partitions of the rows with equal `partition_by` keys, in
+/// the order of the `order_by` keys within each partition. The result has the columns of the
+/// input followed by a column with the values of each function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Window {
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    pub partition_by: Vec<ExprRef>,
+    pub order_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub nulls_first: Vec<bool>,
+    pub functions: Vec<WindowFunction>,
+    /// Names of the output columns of the window functions.
+    pub names: Vec<String>,
+    pub output_schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+impl Window {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        partition_by: Vec<ExprRef>,
+        order_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        functions: Vec<WindowFunction>,
+        names: Vec<String>,
+    ) -> logical_plan::Result<Self> {
+        if functions.is_empty() {
+            return Err(DaftError::ValueError(
+                "A window must be given at least one window function".to_string(),
+            ))
+            .context(CreationSnafu);
+        }
+        if functions.len() != names.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected a name for each window function, got {} names for {} functions",
+                names.len(),
+                functions.len()
+            )))
+            .context(CreationSnafu);
+        }
+        if descending.len() != order_by.len() || nulls_first.len() != order_by.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected as many `descending` and `nulls_first` flags as order by keys, got {} and {} for {} keys",
+                descending.len(),
+                nulls_first.len(),
+                order_by.len()
+            )))
+            .context(CreationSnafu);
+        }
+
+        let upstream_schema = input.schema();
+        let expr_resolver = ExprResolver::default();
+        let (partition_by, _) = expr_resolver
+            .resolve(partition_by, &upstream_schema)
+            .context(CreationSnafu)?;
+        let (order_by, _) = expr_resolver
+            .resolve(order_by, &upstream_schema)
+            .context(CreationSnafu)?;
+
+        let mut fields = upstream_schema.fields.values().cloned().collect::<Vec<_>>();
+        let functions = functions
+            .into_iter()
+            .zip(names.iter())
+            .map(|(function, name)| {
+                let function = match function.input() {
+                    Some(expr) => {
+                        let (expr, _) = expr_resolver
+                            .resolve_single(expr.clone(), &upstream_schema)
+                            .context(CreationSnafu)?;
+                        function.with_input(expr)
+                    }
+                    None => function,
+                };
+                if matches!(function, WindowFunction::Rank) && order_by.is_empty() {
+                    return Err(DaftError::ValueError(
+                        "rank() requires the window to be ordered".to_string(),
+                    ))
+                    .context(CreationSnafu);
+                }
+                let dtype = function
+                    .output_dtype(&upstream_schema)
+                    .context(CreationSnafu)?;
+                fields.push(Field::new(name, dtype));
+                Ok(function)
+            })
+            .collect::<logical_plan::Result<Vec<_>>>()?;
+        let output_schema = Schema::new(fields).context(CreationSnafu)?.into();
+
+        Ok(Self {
+            input,
+            partition_by,
+            order_by,
+            descending,
+            nulls_first,
+            functions,
+            names,
+            output_schema,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // Window functions add columns to each row without changing the number of rows.
+        let input_stats = self.input.materialized_stats();
+        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "Window: {}",
+            self.functions
+                .iter()
+                .zip(self.names.iter())
+                .map(|(function, name)| format!("{function} as {name}"))
+                .join(", ")
+        ));
+        if !self.partition_by.is_empty() {
+            res.push(format!(
+                "Partition by = {}",
+                self.partition_by.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
+        if !self.order_by.is_empty() {
+            let pairs = self
+                .order_by
+                .iter()
+                .zip(self.descending.iter())
+                .zip(self.nulls_first.iter())
+                .map(|((ob, d), nf)| {
+                    format!(
+                        "({}, {}, {})",
+                        ob,
+                        if *d { "descending" } else { "ascending" },
+                        if *nf { "nulls first" } else { "nulls last" }
+                    )
+                })
+                .join(", ");
+            res.push(format!("Order by = {}", pairs));
+        }
+        res.push(format!(
+            "Output schema = {}",
+            self.output_schema.short_string()
+        ));
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
+
+/// Python wrapper of a window function, which is passed to the window of a logical plan builder.
+#[cfg(feature = "python")]
+#[pyo3::pyclass(module = "daft.daft", name = "WindowFunction", frozen)]
+#[derive(Clone, Debug)]
+pub struct PyWindowFunction {
+    pub function: WindowFunction,
+}
+
+#[cfg(feature = "python")]
+#[pyo3::pymethods]
+impl PyWindowFunction {
+    #[staticmethod]
+    pub fn row_number() -> Self {
+        WindowFunction::RowNumber.into()
+    }
+
+    #[staticmethod]
+    pub fn rank() -> Self {
+        WindowFunction::Rank.into()
+    }
+
+    #[staticmethod]
+    pub fn lag(expr: daft_dsl::python::PyExpr, offset: u64) -> Self {
+        WindowFunction::Lag {
+            expr: expr.into(),
+            offset,
+        }
+        .into()
+    }
+
+    #[staticmethod]
+    pub fn lead(expr: daft_dsl::python::PyExpr, offset: u64) -> Self {
+        WindowFunction::Lead {
+            expr: expr.into(),
+            offset,
+        }
+        .into()
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (expr, preceding=None, following=Some(0)))]
+    pub fn sum(
+        expr: daft_dsl::python::PyExpr,
+        preceding: Option<u64>,
+        following: Option<u64>,
+    ) -> Self {
+        WindowFunction::Sum {
+            expr: expr.into(),
+            frame: WindowFrame {
+                preceding,
+                following,
+            },
+        }
+        .into()
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (expr, preceding=None, following=Some(0)))]
+    pub fn mean(
+        expr: daft_dsl::python::PyExpr,
+        preceding: Option<u64>,
+        following: Option<u64>,
+    ) -> Self {
+        WindowFunction::Mean {
+            expr: expr.into(),
+            frame: WindowFrame {
+                preceding,
+                following,
+            },
+        }
+        .into()
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.function.to_string()
+    }
+}
+
+#[cfg(feature = "python")]
+impl From<WindowFunction> for PyWindowFunction {
+    fn from(function: WindowFunction) -> Self {
+        Self { function }
+    }
+}
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_dsl::{Expr, ExprRef, ExprResolver};
+use daft_schema::{
+    field::Field,
+    schema::{Schema, SchemaRef},
+};
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use crate::{
+    logical_plan::{self, CreationSnafu},
+    source_info::SourceInfo,
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// Name of the column with the inclusive start of the event-time window of each output row.
+pub const WINDOW_START_COLUMN_NAME: &str = "window_start";
+/// Name of the column with the exclusive end of the event-time window of each output row.
+pub const WINDOW_END_COLUMN_NAME: &str = "window_end";
+
+/// Aggregates the rows of a stream within event-time windows of `window_size`, which start every
+/// `window_slide`. Windows are tumbling if they're as long as their slide, and hopping if they
+/// overlap, in which case each row is aggregated in every window that it falls into.
+///
+/// A window is output once the watermark passes its end, i.e. once an event time of at least
+/// `end + watermark_delay` has been seen, after which its state is evicted. Rows with an event
+/// time behind the watermark are late and are dropped. The remaining windows are output when the
+/// input ends.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WindowAggregate {
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    pub aggregations: Vec<ExprRef>,
+    pub groupby: Vec<ExprRef>,
+    /// Integer or temporal column with the event time of each row.
+    pub event_time_column: String,
+    /// Length of each window in the units of the event time column.
+    pub window_size: i64,
+    /// Distance between the starts of consecutive windows in the units of the event time column.
+    pub window_slide: i64,
+    /// How far the watermark trails the latest event time, in the units of the event time column.
+    pub watermark_delay: i64,
+    pub output_schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+impl WindowAggregate {
+    /// Creates a window aggregation, which defaults to the watermark delay of the upstream stream
+    /// whose event time column it windows by if no delay is given.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        aggregations: Vec<ExprRef>,
+        groupby: Vec<ExprRef>,
+        event_time_column: String,
+        window_size: i64,
+        window_slide: Option<i64>,
+        watermark_delay: Option<i64>,
+    ) -> logical_plan::Result<Self> {
+        let upstream_schema = input.schema();
+
+        let event_time_field = upstream_schema
+            .get_field(&event_time_column)
+            .context(CreationSnafu)?;
+        if !(event_time_field.dtype.is_integer() || event_time_field.dtype.is_temporal()) {
+            return Err(DaftError::ValueError(format!(
+                "Event time column {} must be an integer or temporal column, but it is {}",
+                event_time_column, event_time_field.dtype
+            )))
+            .context(CreationSnafu);
+        }
+
+        let window_slide = window_slide.unwrap_or(window_size);
+        if window_size <= 0 || window_slide <= 0 || window_slide > window_size {
+            return Err(DaftError::ValueError(format!(
+                "Windows must have a positive size and a positive slide of at most their size, \
+                but got size {window_size} and slide {window_slide}"
+            )))
+            .context(CreationSnafu);
+        }
+
+        let watermark_delay = watermark_delay
+            .or_else(|| stream_watermark_delay(&input, &event_time_column))
+            .unwrap_or(0);
+        if watermark_delay < 0 {
+            return Err(DaftError::ValueError(format!(
+                "Watermark delay must not be negative, but it is {watermark_delay}"
+            )))
+            .context(CreationSnafu);
+        }
+
+        let agg_resolver = ExprResolver::builder().groupby(&groupby).build();
+        let (aggregations, aggregation_fields) = agg_resolver
+            .resolve(aggregations, &upstream_schema)
+            .context(CreationSnafu)?;
+        if let Some(agg) = aggregations.iter().find(|agg| !is_top_level_agg(agg)) {
+            return Err(DaftError::ValueError(format!(
+                "Window aggregations only support top level aggregation expressions, received {agg}"
+            )))
+            .context(CreationSnafu);
+        }
+
+        let groupby_resolver = ExprResolver::default();
+        let (groupby, groupby_fields) = groupby_resolver
+            .resolve(groupby, &upstream_schema)
+            .context(CreationSnafu)?;
+
+        let window_fields = [WINDOW_START_COLUMN_NAME, WINDOW_END_COLUMN_NAME]
+            .map(|name| Field::new(name, event_time_field.dtype.clone()));
+        let fields = [groupby_fields, window_fields.to_vec(), aggregation_fields].concat();
+        let output_schema = Schema::new(fields).context(CreationSnafu)?.into();
+
+        Ok(Self {
+            input,
+            aggregations,
+            groupby,
+            event_time_column,
+            window_size,
+            window_slide,
+            watermark_delay,
+            output_schema,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // There may be as many windows as there are input rows.
+        let input_stats = self.input.materialized_stats();
+        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "WindowAggregate: {}",
+            self.aggregations.iter().map(|e| e.to_string()).join(", ")
+        ));
+        if !self.groupby.is_empty() {
+            res.push(format!(
+                "Group by = {}",
+                self.groupby.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
+        res.push(format!(
+            "Window = {} size {} slide {}",
+            self.event_time_column, self.window_size, self.window_slide
+        ));
+        res.push(format!("Watermark delay = {}", self.watermark_delay));
+        res.push(format!(
+            "Output schema = {}",
+            self.output_schema.short_string()
+        ));
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
+
+fn is_top_level_agg(expr: &ExprRef) -> bool {
+    match expr.as_ref() {
+        Expr::Agg(_) => true,
+        Expr::Alias(expr, _) => is_top_level_agg(expr),
+        _ => false,
+    }
+}
+
+/// Finds the watermark delay of the upstream stream that tracks its watermark by `column`.
+fn stream_watermark_delay(plan: &LogicalPlan, column: &str) -> Option<i64> {
+    match plan {
+        LogicalPlan::Source(source) => match source.source_info.as_ref() {
+            SourceInfo::Stream(info) => info
+                .watermark
+                .as_ref()
+                .filter(|watermark| watermark.event_time_column == column)
+                .map(|watermark| watermark.delay),
+            _ => None,
+        },
+        plan => plan
+            .children()
+            .into_iter()
+            .find_map(|child| stream_watermark_delay(child, column)),
+    }
+}
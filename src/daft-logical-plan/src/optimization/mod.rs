@@ -6,3 +6,4 @@ mod rules;
 mod test;
 
 pub use optimizer::{Optimizer, OptimizerConfig};
+pub(crate) use rules::{has_correlated_cols, pull_up_correlated_cols, scalar_aggregate_counts};
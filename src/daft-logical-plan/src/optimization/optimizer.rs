@@ -6,7 +6,7 @@ use common_treenode::Transformed;
 use super::{
     logical_plan_tracker::LogicalPlanTracker,
     rules::{
-        DropRepartition, EliminateCrossJoin, EnrichWithStats, FilterNullJoinKey,
+        DropRepartition, EliminateCrossJoin, EnrichWithStats, FilterNullJoinKey, FuseSortLimit,
         LiftProjectFromAgg, MaterializeScans, OptimizerRule, PushDownFilter, PushDownLimit,
         PushDownProjection, SimplifyExpressionsRule, SplitActorPoolProjects,
         UnnestPredicateSubquery, UnnestScalarSubquery,
@@ -124,9 +124,14 @@ impl Optimizer {
             // --- Limit pushdowns ---
             // This needs to be separate from PushDownProjection because otherwise the limit and
             // projection just keep swapping places, preventing optimization
-            // (see https://github.com/Eventual-Inc/Daft/issues/2616)
+            // (see https://github.com/Eventual-Inc/Daft/issues/2616).
+            // Sorts followed by limits are fused into TopNs in the same batch, so that limits are
+            // pushed down past projections onto the sorts first.
             RuleBatch::new(
-                vec![Box::new(PushDownLimit::new())],
+                vec![
+                    Box::new(PushDownLimit::new()),
+                    Box::new(FuseSortLimit::new()),
+                ],
                 RuleExecutionStrategy::FixedPoint(Some(3)),
             ),
             // --- Materialize scan nodes ---
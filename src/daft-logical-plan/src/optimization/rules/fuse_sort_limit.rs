@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode};
+
+use super::OptimizerRule;
+use crate::{
+    ops::{Limit as LogicalLimit, Sort, TopN},
+    LogicalPlan,
+};
+
+/// Largest limit that a Sort followed by a Limit is fused into a TopN for. A TopN keeps the first
+/// `limit` rows of each worker in memory, so larger limits are better served by a full sort.
+const MAX_TOP_N_LIMIT: i64 = 100_000;
+
+/// Optimization rule for fusing a Sort followed by a small Limit into a TopN, which only keeps the
+/// first rows of the sort instead of sorting all of its input.
+#[derive(Default, Debug)]
+pub struct FuseSortLimit {}
+
+impl FuseSortLimit {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for FuseSortLimit {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| self.try_optimize_node(node))
+    }
+}
+
+impl FuseSortLimit {
+    #[allow(clippy::only_used_in_recursion)]
+    fn try_optimize_node(
+        &self,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let LogicalPlan::Limit(LogicalLimit { input, limit, .. }) = plan.as_ref() else {
+            return Ok(Transformed::no(plan));
+        };
+        match input.as_ref() {
+            // Limit-Sort -> TopN
+            LogicalPlan::Sort(Sort {
+                input,
+                sort_by,
+                descending,
+                nulls_first,
+                ..
+            }) if *limit <= MAX_TOP_N_LIMIT => Ok(Transformed::yes(
+                LogicalPlan::TopN(TopN::try_new(
+                    input.clone(),
+                    sort_by.clone(),
+                    descending.clone(),
+                    nulls_first.clone(),
+                    *limit,
+                )?)
+                .arced(),
+            )),
+            // Limit-TopN -> TopN[min(limit, top_n_limit)]
+            LogicalPlan::TopN(TopN {
+                input,
+                sort_by,
+                descending,
+                nulls_first,
+                limit: top_n_limit,
+                ..
+            }) => Ok(Transformed::yes(
+                LogicalPlan::TopN(TopN::try_new(
+                    input.clone(),
+                    sort_by.clone(),
+                    descending.clone(),
+                    nulls_first.clone(),
+                    (*limit).min(*top_n_limit),
+                )?)
+                .arced(),
+            )),
+            _ => Ok(Transformed::no(plan)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_scan_info::ScanOperatorRef;
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+
+    use super::MAX_TOP_N_LIMIT;
+    use crate::{
+        ops::TopN,
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            rules::{FuseSortLimit, PushDownLimit},
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan, LogicalPlanBuilder,
+    };
+
+    /// Helper that creates an optimizer with the PushDownLimit and FuseSortLimit rules registered,
+    /// optimizes the provided plan with said optimizer, and compares the optimized plan with the
+    /// provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![
+                    Box::new(PushDownLimit::new()),
+                    Box::new(FuseSortLimit::new()),
+                ],
+                RuleExecutionStrategy::FixedPoint(Some(3)),
+            )],
+        )
+    }
+
+    fn scan_op() -> ScanOperatorRef {
+        dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ])
+    }
+
+    fn top_n(input: LogicalPlanBuilder, limit: i64) -> DaftResult<LogicalPlanBuilder> {
+        let top_n = TopN::try_new(
+            input.build(),
+            vec![col("a")],
+            vec![true],
+            vec![false],
+            limit,
+        )?;
+        Ok(input.with_new_plan(LogicalPlan::TopN(top_n)))
+    }
+
+    /// Tests that a Sort followed by a Limit is fused into a TopN.
+    ///
+    /// Limit-Sort -> TopN
+    #[test]
+    fn limit_fuses_with_sort() -> DaftResult<()> {
+        let scan_op = scan_op();
+        let plan = dummy_scan_node(scan_op.clone())
+            .sort(vec![col("a")], vec![true], vec![false])?
+            .limit(5, false)?
+            .build();
+        let expected = top_n(dummy_scan_node(scan_op), 5)?.build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that a Limit fuses with a Sort past the Projections between them.
+    ///
+    /// Limit-Project-Sort -> Project-TopN
+    #[test]
+    fn limit_fuses_with_sort_past_projection() -> DaftResult<()> {
+        let scan_op = scan_op();
+        let plan = dummy_scan_node(scan_op.clone())
+            .sort(vec![col("a")], vec![true], vec![false])?
+            .select(vec![col("b")])?
+            .limit(5, false)?
+            .build();
+        let expected = top_n(dummy_scan_node(scan_op), 5)?
+            .select(vec![col("b")])?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that a Limit folds into a TopN with the smaller of their limits.
+    ///
+    /// Limit[x]-Limit[y]-Sort -> TopN[min(x,y)]
+    #[test]
+    fn limits_fold_into_top_n() -> DaftResult<()> {
+        let scan_op = scan_op();
+        let plan = dummy_scan_node(scan_op.clone())
+            .sort(vec![col("a")], vec![true], vec![false])?
+            .limit(5, false)?
+            .limit(10, false)?
+            .build();
+        let expected = top_n(dummy_scan_node(scan_op), 5)?.build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that a Sort followed by a large Limit is left alone.
+    #[test]
+    fn large_limit_does_not_fuse_with_sort() -> DaftResult<()> {
+        let scan_op = scan_op();
+        let plan = dummy_scan_node(scan_op.clone())
+            .sort(vec![col("a")], vec![true], vec![false])?
+            .limit(MAX_TOP_N_LIMIT + 1, false)?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ mod drop_repartition;
 mod eliminate_cross_join;
 mod enrich_with_stats;
 mod filter_null_join_key;
+mod fuse_sort_limit;
 mod lift_project_from_agg;
 mod materialize_scans;
 mod push_down_filter;
@@ -17,6 +18,7 @@ pub use drop_repartition::DropRepartition;
 pub use eliminate_cross_join::EliminateCrossJoin;
 pub use enrich_with_stats::EnrichWithStats;
 pub use filter_null_join_key::FilterNullJoinKey;
+pub use fuse_sort_limit::FuseSortLimit;
 pub use lift_project_from_agg::LiftProjectFromAgg;
 pub use materialize_scans::MaterializeScans;
 pub use push_down_filter::PushDownFilter;
@@ -25,4 +27,7 @@ pub use push_down_projection::PushDownProjection;
 pub use rule::OptimizerRule;
 pub use simplify_expressions::SimplifyExpressionsRule;
 pub use split_actor_pool_projects::SplitActorPoolProjects;
+pub(crate) use unnest_subquery::{
+    has_correlated_cols, pull_up_correlated_cols, scalar_aggregate_counts,
+};
 pub use unnest_subquery::{UnnestPredicateSubquery, UnnestScalarSubquery};
@@ -57,8 +57,10 @@ impl PushDownLimit {
                     // Limit-Source -> Limit-Source[with_limit]
                     LogicalPlan::Source(source) => {
                         match source.source_info.as_ref() {
-                            // Limit pushdown is not supported for in-memory sources.
-                            SourceInfo::InMemory(_) => Ok(Transformed::no(plan)),
+                            // Limit pushdown is not supported for in-memory and stream sources.
+                            SourceInfo::InMemory(_) | SourceInfo::Stream(_) => {
+                                Ok(Transformed::no(plan))
+                            }
                             // Do not pushdown if Source node is already more limited than `limit`
                             SourceInfo::Physical(external_info)
                                 if let Some(existing_limit) = external_info.pushdowns.limit
@@ -280,6 +282,7 @@ mod tests {
             Default::default(),
             5,
             3,
+            None,
         )?
         .limit(5, false)?
         .build();
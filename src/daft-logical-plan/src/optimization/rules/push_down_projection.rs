@@ -170,7 +170,7 @@ impl PushDownProjection {
                             Ok(Transformed::no(plan))
                         }
                     }
-                    SourceInfo::InMemory(_) => Ok(Transformed::no(plan)),
+                    SourceInfo::InMemory(_) | SourceInfo::Stream(_) => Ok(Transformed::no(plan)),
                     SourceInfo::PlaceHolder(..) => {
                         panic!("PlaceHolderInfo should not exist for optimization!");
                     }
@@ -342,10 +342,12 @@ impl PushDownProjection {
                 }
             }
             LogicalPlan::Sort(..)
+            | LogicalPlan::TopN(..)
             | LogicalPlan::Repartition(..)
             | LogicalPlan::Limit(..)
             | LogicalPlan::Filter(..)
             | LogicalPlan::Sample(..)
+            | LogicalPlan::AssertUnique(..)
             | LogicalPlan::Explode(..)
             | LogicalPlan::Unpivot(..) => {
                 // Get required columns from projection and upstream.
@@ -498,8 +500,11 @@ impl PushDownProjection {
                 // since Intersect implicitly requires all parent columns.
                 Ok(Transformed::no(plan))
             }
-            LogicalPlan::Pivot(_) | LogicalPlan::MonotonicallyIncreasingId(_) => {
-                // Cannot push down past a Pivot/MonotonicallyIncreasingId because it changes the schema.
+            LogicalPlan::Pivot(_)
+            | LogicalPlan::MonotonicallyIncreasingId(_)
+            | LogicalPlan::WindowAggregate(_)
+            | LogicalPlan::Window(_) => {
+                // Cannot push down past a Pivot/MonotonicallyIncreasingId/WindowAggregate/Window because it changes the schema.
                 Ok(Transformed::no(plan))
             }
             LogicalPlan::Sink(_) => {
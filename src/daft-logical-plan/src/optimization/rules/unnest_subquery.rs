@@ -4,14 +4,14 @@ use common_error::{DaftError, DaftResult};
 use common_treenode::{DynTreeNode, Transformed, TreeNode};
 use daft_algebra::boolean::{combine_conjunction, split_conjunction};
 use daft_core::{join::JoinType, prelude::SchemaRef};
-use daft_dsl::{col, Expr, ExprRef, Operator, Subquery};
+use daft_dsl::{col, AggExpr, Expr, ExprRef, Operator, Subquery};
 use itertools::multiunzip;
 use uuid::Uuid;
 
 use super::OptimizerRule;
 use crate::{
     logical_plan::downcast_subquery,
-    ops::{Aggregate, Filter, Join, Project},
+    ops::{Aggregate, Filter, Join, Project, Sort},
     LogicalPlan, LogicalPlanRef,
 };
 
@@ -358,7 +358,9 @@ impl OptimizerRule for UnnestPredicateSubquery {
     }
 }
 
-fn pull_up_correlated_cols(
+/// Removes correlated equality predicates from `plan`, returning the decorrelated plan along with
+/// the columns of the decorrelated plan and the outer columns that they should be joined on.
+pub(crate) fn pull_up_correlated_cols(
     plan: LogicalPlanRef,
 ) -> DaftResult<(LogicalPlanRef, Vec<ExprRef>, Vec<ExprRef>)> {
     let (new_inputs, subquery_on, outer_on): (Vec<_>, Vec<_>, Vec<_>) = multiunzip(
@@ -490,6 +492,7 @@ fn pull_up_correlated_cols(
         LogicalPlan::Distinct(..)
         | LogicalPlan::MonotonicallyIncreasingId(..)
         | LogicalPlan::Repartition(..)
+        | LogicalPlan::AssertUnique(..)
         | LogicalPlan::Union(..)
         | LogicalPlan::Intersect(..)
         | LogicalPlan::Sort(..) => Ok((plan.clone(), subquery_on, outer_on)),
@@ -497,11 +500,14 @@ fn pull_up_correlated_cols(
         // ops that cannot pull up correlated columns
         LogicalPlan::ActorPoolProject(..)
         | LogicalPlan::Limit(..)
+        | LogicalPlan::TopN(..)
         | LogicalPlan::Sample(..)
         | LogicalPlan::Source(..)
         | LogicalPlan::Explode(..)
         | LogicalPlan::Unpivot(..)
         | LogicalPlan::Pivot(..)
+        | LogicalPlan::WindowAggregate(..)
+        | LogicalPlan::Window(..)
         | LogicalPlan::Concat(..)
         | LogicalPlan::Join(..)
         | LogicalPlan::Sink(..) => {
@@ -517,6 +523,76 @@ fn pull_up_correlated_cols(
     }
 }
 
+/// Whether `plan` still references columns of an outer plan after its correlated equality
+/// predicates were pulled up, e.g. in a correlated range predicate, which can't be decorrelated.
+pub(crate) fn has_correlated_cols(plan: &LogicalPlan) -> bool {
+    plan.exists(|node| {
+        let exprs: Vec<&ExprRef> = match node {
+            LogicalPlan::Filter(Filter { predicate, .. }) => vec![predicate],
+            LogicalPlan::Project(Project { projection, .. }) => projection.iter().collect(),
+            LogicalPlan::Aggregate(Aggregate {
+                aggregations,
+                groupby,
+                ..
+            }) => aggregations.iter().chain(groupby).collect(),
+            LogicalPlan::Sort(Sort { sort_by, .. }) => sort_by.iter().collect(),
+            LogicalPlan::Join(Join {
+                left_on, right_on, ..
+            }) => left_on.iter().chain(right_on).collect(),
+            _ => vec![],
+        };
+        exprs
+            .into_iter()
+            .any(|expr| expr.exists(|e| matches!(e.as_ref(), Expr::OuterReferenceColumn(..))))
+    })
+}
+
+/// The output columns of `plan` that are counts of a scalar aggregation, i.e. one without group
+/// by columns, which are 0 rather than null for the outer rows without matches once the
+/// aggregation is decorrelated. None if `plan` is not a scalar aggregation.
+pub(crate) fn scalar_aggregate_counts(plan: &LogicalPlan) -> Option<HashSet<String>> {
+    match plan {
+        LogicalPlan::Aggregate(Aggregate {
+            aggregations,
+            groupby,
+            ..
+        }) if groupby.is_empty() => Some(
+            aggregations
+                .iter()
+                .filter(|agg| {
+                    matches!(
+                        unalias(agg).as_ref(),
+                        Expr::Agg(AggExpr::Count(..) | AggExpr::CountDistinct(..))
+                    )
+                })
+                .map(|agg| agg.name().to_string())
+                .collect(),
+        ),
+        LogicalPlan::Project(Project {
+            input, projection, ..
+        }) => {
+            let counts = scalar_aggregate_counts(input)?;
+            Some(
+                projection
+                    .iter()
+                    .filter(|expr| {
+                        matches!(unalias(expr).as_ref(), Expr::Column(name) if counts.contains(name.as_ref()))
+                    })
+                    .map(|expr| expr.name().to_string())
+                    .collect(),
+            )
+        }
+        _ => None,
+    }
+}
+
+fn unalias(expr: &ExprRef) -> &ExprRef {
+    match expr.as_ref() {
+        Expr::Alias(expr, _) => unalias(expr),
+        _ => expr,
+    }
+}
+
 fn get_missing_exprs(
     subquery_on: Vec<ExprRef>,
     existing_exprs: &[ExprRef],
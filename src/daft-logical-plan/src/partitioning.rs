@@ -11,6 +11,7 @@ pub enum RepartitionSpec {
     Hash(HashRepartitionConfig),
     Random(RandomShuffleConfig),
     IntoPartitions(IntoPartitionsConfig),
+    Range(RangeRepartitionConfig),
 }
 
 impl RepartitionSpec {
@@ -19,12 +20,14 @@ impl RepartitionSpec {
             Self::Hash(_) => "Hash",
             Self::Random(_) => "Random",
             Self::IntoPartitions(_) => "IntoPartitions",
+            Self::Range(_) => "Range",
         }
     }
 
     pub fn repartition_by(&self) -> Vec<ExprRef> {
         match self {
             Self::Hash(HashRepartitionConfig { by, .. }) => by.clone(),
+            Self::Range(RangeRepartitionConfig { by, .. }) => by.clone(),
             _ => vec![],
         }
     }
@@ -34,6 +37,7 @@ impl RepartitionSpec {
             Self::Hash(conf) => conf.multiline_display(),
             Self::Random(conf) => conf.multiline_display(),
             Self::IntoPartitions(conf) => conf.multiline_display(),
+            Self::Range(conf) => conf.multiline_display(),
         }
     }
 
@@ -51,6 +55,15 @@ impl RepartitionSpec {
             Self::IntoPartitions(IntoPartitionsConfig { num_partitions }) => {
                 ClusteringSpec::Unknown(UnknownClusteringConfig::new(*num_partitions))
             }
+            Self::Range(RangeRepartitionConfig {
+                num_partitions,
+                by,
+                descending,
+            }) => ClusteringSpec::Range(RangeClusteringConfig::new(
+                num_partitions.unwrap_or(upstream_num_partitions),
+                by.clone(),
+                descending.clone(),
+            )),
         }
     }
 }
@@ -77,6 +90,45 @@ impl HashRepartitionConfig {
     }
 }
 
+/// Repartitioning into ranges of the `by` columns, e.g. to cluster the rows of written files.
+///
+/// The boundaries of the ranges are computed at execution time from samples of the input, so
+/// that the partitions have roughly the same number of rows.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+pub struct RangeRepartitionConfig {
+    pub num_partitions: Option<usize>,
+    pub by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+}
+
+impl RangeRepartitionConfig {
+    pub fn new(num_partitions: Option<usize>, by: Vec<ExprRef>, descending: Vec<bool>) -> Self {
+        Self {
+            num_partitions,
+            by,
+            descending,
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("Num partitions = {:?}", self.num_partitions));
+        res.push(format!(
+            "By = {}",
+            display_range_by(&self.by, &self.descending)
+        ));
+        res.push("Boundaries = sampled from input".to_string());
+        res
+    }
+}
+
+fn display_range_by(by: &[ExprRef], descending: &[bool]) -> String {
+    by.iter()
+        .zip(descending.iter())
+        .map(|(sb, d)| format!("({}, {})", sb, if *d { "descending" } else { "ascending" },))
+        .join(", ")
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
 pub struct RandomShuffleConfig {
     pub num_partitions: Option<usize>,
@@ -372,14 +424,11 @@ impl RangeClusteringConfig {
 
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
-        let pairs = self
-            .by
-            .iter()
-            .zip(self.descending.iter())
-            .map(|(sb, d)| format!("({}, {})", sb, if *d { "descending" } else { "ascending" },))
-            .join(", ");
         res.push(format!("Num partitions = {}", self.num_partitions));
-        res.push(format!("By = {}", pairs));
+        res.push(format!(
+            "By = {}",
+            display_range_by(&self.by, &self.descending)
+        ));
         res
     }
 }
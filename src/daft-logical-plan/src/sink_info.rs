@@ -1,10 +1,11 @@
-use std::hash::Hash;
+use std::{fmt::Display, hash::Hash, str::FromStr};
 
-use common_file_formats::FileFormat;
+use common_error::{DaftError, DaftResult};
+use common_file_formats::{CsvWriteOptions, FileFormat};
 use common_io_config::IOConfig;
 #[cfg(feature = "python")]
 use common_py_serde::{deserialize_py_object, serialize_py_object};
-use daft_dsl::ExprRef;
+use daft_dsl::{functions::python::RuntimePyObject, ExprRef};
 use derivative::Derivative;
 use itertools::Itertools;
 #[cfg(feature = "python")]
@@ -15,6 +16,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum SinkInfo {
     OutputFileInfo(OutputFileInfo),
+    CopyInto(CopyIntoInfo),
     #[cfg(feature = "python")]
     CatalogInfo(CatalogInfo),
 }
@@ -26,6 +28,209 @@ pub struct OutputFileInfo {
     pub partition_cols: Option<Vec<ExprRef>>,
     pub compression: Option<String>,
     pub io_config: Option<IOConfig>,
+    /// `daft.io.ParquetEncryptionConfig` of the columns to encrypt and the keys to encrypt them
+    /// with, for Parquet files
+    pub parquet_encryption: Option<RuntimePyObject>,
+    /// Delimiter, quoting, header and null representation of CSV files
+    pub csv_options: Option<CsvWriteOptions>,
+    /// The job and attempt that the write is part of, if it's retry-safe
+    pub write_attempt: Option<WriteAttempt>,
+    /// Data-quality checks of the written rows
+    pub constraints: Option<WriteConstraints>,
+}
+
+/// An attempt of a write job that may be retried, such as a query re-executed by the Spark Connect
+/// server after a client retries it.
+///
+/// The files of the attempt are written under staging names, and renamed when the write succeeds
+/// after deleting the files of any other attempt of the job, so that a job leaves the files of only
+/// one attempt visible.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WriteAttempt {
+    pub job_id: String,
+    pub attempt_id: String,
+}
+
+impl WriteAttempt {
+    /// A new attempt of the job `job_id`
+    pub fn new(job_id: String) -> Self {
+        Self {
+            job_id,
+            attempt_id: uuid::Uuid::new_v4().to_string(),
+        }
+    }
+
+    /// Prefix of the names of the files written by this attempt until they are committed, which
+    /// must match `daft.filesystem.staged_file_prefix`
+    pub fn staged_file_prefix(&self) -> String {
+        format!("_staged-{}-{}-", self.job_id, self.attempt_id)
+    }
+}
+
+/// A data-quality check of the rows that a write writes, which a row satisfies if `predicate`
+/// evaluates to true for it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WriteConstraint {
+    /// Describes the constraint in the errors of violations, e.g. `x IS NOT NULL`
+    pub name: String,
+    pub predicate: ExprRef,
+}
+
+/// What a write does with the rows that violate any of its constraints
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConstraintViolationAction {
+    /// Fail the write
+    #[default]
+    Fail,
+    /// Skip the rows
+    Drop,
+    /// Write the rows to `file_format` files under `root_dir` instead, along with the constraints
+    /// that they violate
+    Quarantine {
+        root_dir: String,
+        file_format: FileFormat,
+    },
+}
+
+impl ConstraintViolationAction {
+    /// Parses `fail`, `drop` or `quarantine`, the latter of which needs a `quarantine_dir` to
+    /// write `quarantine_format` files to
+    pub fn try_new(
+        action: &str,
+        quarantine_dir: Option<String>,
+        quarantine_format: FileFormat,
+    ) -> DaftResult<Self> {
+        match (action.to_lowercase().as_str(), quarantine_dir) {
+            ("fail", None) => Ok(Self::Fail),
+            ("drop", None) => Ok(Self::Drop),
+            ("quarantine", Some(root_dir)) => match quarantine_format {
+                FileFormat::Parquet | FileFormat::Csv => Ok(Self::Quarantine {
+                    root_dir,
+                    file_format: quarantine_format,
+                }),
+                other => Err(DaftError::ValueError(format!(
+                    "Quarantined rows can only be written to Parquet or CSV files, not {other:?}"
+                ))),
+            },
+            ("quarantine", None) => Err(DaftError::ValueError(
+                "A quarantine directory is required to quarantine rows that violate constraints"
+                    .to_string(),
+            )),
+            ("fail" | "drop", Some(_)) => Err(DaftError::ValueError(format!(
+                "A quarantine directory can only be set to quarantine rows, not to {action} them"
+            ))),
+            (other, _) => Err(DaftError::ValueError(format!(
+                "Unsupported constraint violation action: {other}, expected one of `fail`, `drop` or `quarantine`"
+            ))),
+        }
+    }
+}
+
+impl Display for ConstraintViolationAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fail => write!(f, "fail"),
+            Self::Drop => write!(f, "drop"),
+            Self::Quarantine {
+                root_dir,
+                file_format,
+            } => write!(f, "quarantine to {root_dir} as {file_format:?}"),
+        }
+    }
+}
+
+/// The constraints of a write, which are checked as the rows are written
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WriteConstraints {
+    pub constraints: Vec<WriteConstraint>,
+    pub on_violation: ConstraintViolationAction,
+}
+
+impl WriteConstraints {
+    /// Whether violating rows are quarantined, in which case the results of the write contain
+    /// the files of quarantined rows too
+    pub fn quarantines(&self) -> bool {
+        matches!(
+            self.on_violation,
+            ConstraintViolationAction::Quarantine { .. }
+        )
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![
+            format!(
+                "Constraints = {}",
+                self.constraints.iter().map(|c| &c.name).join(", ")
+            ),
+            format!("On violation = {}", self.on_violation),
+        ]
+    }
+}
+
+/// Column of the results of a write that quarantines rows, which tells the files of quarantined
+/// rows apart from the written files
+pub const QUARANTINED: &str = "quarantined";
+/// Column of quarantined rows with the names of the constraints that each of them violates
+pub const QUARANTINE_REASON: &str = "_violated_constraints";
+
+/// Column of a COPY INTO manifest with the number of rows in each written file
+pub const MANIFEST_NUM_ROWS: &str = "num_rows";
+/// Column of a COPY INTO manifest with the size in bytes of each written file
+pub const MANIFEST_SIZE_BYTES: &str = "size_bytes";
+
+/// What a COPY INTO does if its location already contains files
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WriteMode {
+    /// Write new files next to the existing ones
+    #[default]
+    Append,
+    /// Fail before writing anything
+    ErrorIfExists,
+    /// Delete the existing files once the new ones are written
+    Overwrite,
+}
+
+impl FromStr for WriteMode {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s.to_lowercase().as_str() {
+            "append" => Ok(Self::Append),
+            "error" | "error_if_exists" | "errorifexists" => Ok(Self::ErrorIfExists),
+            "overwrite" => Ok(Self::Overwrite),
+            other => Err(DaftError::ValueError(format!(
+                "Unsupported write mode: {other}, expected one of `append`, `error_if_exists` or `overwrite`"
+            ))),
+        }
+    }
+}
+
+impl Display for WriteMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Append => write!(f, "append"),
+            Self::ErrorIfExists => write!(f, "error_if_exists"),
+            Self::Overwrite => write!(f, "overwrite"),
+        }
+    }
+}
+
+/// An export of a query to files, which produces a manifest of the written files.
+///
+/// The manifest has a row per file, with the file's path, partition values, number of rows and
+/// size in bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CopyIntoInfo {
+    pub file_info: OutputFileInfo,
+    pub mode: WriteMode,
+}
+
+impl CopyIntoInfo {
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = self.file_info.multiline_display();
+        res.push(format!("Mode = {}", self.mode));
+        res
+    }
 }
 
 #[cfg(feature = "python")]
@@ -159,6 +364,47 @@ impl OutputFileInfo {
             partition_cols,
             compression,
             io_config,
+            parquet_encryption: None,
+            csv_options: None,
+            write_attempt: None,
+            constraints: None,
+        }
+    }
+
+    /// Encrypts the written Parquet files with Parquet modular encryption.
+    #[must_use]
+    pub fn with_parquet_encryption(self, parquet_encryption: Option<RuntimePyObject>) -> Self {
+        Self {
+            parquet_encryption,
+            ..self
+        }
+    }
+
+    /// Writes CSV files with the given options instead of the defaults.
+    #[must_use]
+    pub fn with_csv_options(self, csv_options: Option<CsvWriteOptions>) -> Self {
+        Self {
+            csv_options,
+            ..self
+        }
+    }
+
+    /// Stages the written files and commits them when the write succeeds, replacing the files of
+    /// other attempts of the same job.
+    #[must_use]
+    pub fn with_write_attempt(self, write_attempt: Option<WriteAttempt>) -> Self {
+        Self {
+            write_attempt,
+            ..self
+        }
+    }
+
+    /// Checks the written rows against `constraints`.
+    #[must_use]
+    pub fn with_constraints(self, constraints: Option<WriteConstraints>) -> Self {
+        Self {
+            constraints,
+            ..self
         }
     }
 
@@ -173,6 +419,21 @@ impl OutputFileInfo {
         if let Some(ref compression) = self.compression {
             res.push(format!("Compression = {}", compression));
         }
+        if self.parquet_encryption.is_some() {
+            res.push("Encrypted = true".to_string());
+        }
+        if let Some(ref csv_options) = self.csv_options {
+            res.extend(csv_options.multiline_display());
+        }
+        if let Some(ref write_attempt) = self.write_attempt {
+            res.push(format!(
+                "Job ID = {}, Attempt ID = {}",
+                write_attempt.job_id, write_attempt.attempt_id
+            ));
+        }
+        if let Some(ref constraints) = self.constraints {
+            res.extend(constraints.multiline_display());
+        }
         res.push(format!("Root dir = {}", self.root_dir));
         match &self.io_config {
             None => res.push("IOConfig = None".to_string()),
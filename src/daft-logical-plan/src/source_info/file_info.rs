@@ -1,3 +1,6 @@
+use std::collections::HashSet;
+
+use common_error::{DaftError, DaftResult};
 use common_py_serde::impl_bincode_py_state_serialization;
 #[cfg(feature = "python")]
 use pyo3::{exceptions::PyKeyError, pyclass, pymethods, PyObject, PyResult, Python};
@@ -10,23 +13,37 @@ pub struct FileInfo {
     pub file_path: String,
     pub file_size: Option<i64>,
     pub num_rows: Option<i64>,
+    /// Version of the file's contents, if the listing provided one
+    pub etag: Option<String>,
 }
 
 #[cfg(feature = "python")]
 #[pymethods]
 impl FileInfo {
     #[new]
-    pub fn new(file_path: String, file_size: Option<i64>, num_rows: Option<i64>) -> Self {
-        Self::new_internal(file_path, file_size, num_rows)
+    #[pyo3(signature = (file_path, file_size, num_rows, etag=None))]
+    pub fn new(
+        file_path: String,
+        file_size: Option<i64>,
+        num_rows: Option<i64>,
+        etag: Option<String>,
+    ) -> Self {
+        Self::new_internal(file_path, file_size, num_rows, etag)
     }
 }
 
 impl FileInfo {
-    pub fn new_internal(file_path: String, file_size: Option<i64>, num_rows: Option<i64>) -> Self {
+    pub fn new_internal(
+        file_path: String,
+        file_size: Option<i64>,
+        num_rows: Option<i64>,
+        etag: Option<String>,
+    ) -> Self {
         Self {
             file_path,
             file_size,
             num_rows,
+            etag,
         }
     }
 }
@@ -38,6 +55,7 @@ pub struct FileInfos {
     pub file_paths: Vec<String>,
     pub file_sizes: Vec<Option<i64>>,
     pub num_rows: Vec<Option<i64>>,
+    pub etags: Vec<Option<String>>,
 }
 
 #[cfg(feature = "python")]
@@ -49,12 +67,22 @@ impl FileInfos {
     }
 
     #[staticmethod]
+    #[pyo3(signature = (file_paths, file_sizes, num_rows, etags=None))]
     pub fn from_infos(
         file_paths: Vec<String>,
         file_sizes: Vec<Option<i64>>,
         num_rows: Vec<Option<i64>>,
-    ) -> Self {
-        Self::new_internal(file_paths, file_sizes, num_rows)
+        etags: Option<Vec<Option<String>>>,
+    ) -> PyResult<Self> {
+        let etags = etags.unwrap_or_else(|| vec![None; file_paths.len()]);
+        Ok(Self::try_new(file_paths, file_sizes, num_rows, etags)?)
+    }
+
+    /// Combine the listings of multiple prefixes or partitions into one, see [`Self::merge`].
+    #[staticmethod]
+    #[pyo3(name = "merge")]
+    pub fn py_merge(infos: Vec<Self>) -> Self {
+        Self::merge(infos)
     }
 
     /// Concatenate two FileInfos together.
@@ -62,17 +90,24 @@ impl FileInfos {
         self.file_paths.extend(new_infos.file_paths);
         self.file_sizes.extend(new_infos.file_sizes);
         self.num_rows.extend(new_infos.num_rows);
+        self.etags.extend(new_infos.etags);
+    }
+
+    #[pyo3(name = "total_size_bytes")]
+    pub fn py_total_size_bytes(&self) -> Option<i64> {
+        self.total_size_bytes()
+    }
+
+    #[pyo3(name = "total_num_rows")]
+    pub fn py_total_num_rows(&self) -> Option<i64> {
+        self.total_num_rows()
     }
 
     pub fn __getitem__(&self, idx: isize) -> PyResult<FileInfo> {
         if idx as usize >= self.len() {
             return Err(PyKeyError::new_err(idx));
         }
-        Ok(FileInfo::new_internal(
-            self.file_paths[0].clone(),
-            self.file_sizes[0],
-            self.num_rows[0],
-        ))
+        Ok(self.get(idx as usize))
     }
 
     pub fn __len__(&self) -> PyResult<usize> {
@@ -87,12 +122,34 @@ impl FileInfos {
         file_paths: Vec<String>,
         file_sizes: Vec<Option<i64>>,
         num_rows: Vec<Option<i64>>,
+        etags: Vec<Option<String>>,
     ) -> Self {
         Self {
             file_paths,
             file_sizes,
             num_rows,
+            etags,
+        }
+    }
+
+    /// Creates the infos of files from their columns, which must all have the same length.
+    pub fn try_new(
+        file_paths: Vec<String>,
+        file_sizes: Vec<Option<i64>>,
+        num_rows: Vec<Option<i64>>,
+        etags: Vec<Option<String>>,
+    ) -> DaftResult<Self> {
+        let num_files = file_paths.len();
+        if file_sizes.len() != num_files || num_rows.len() != num_files || etags.len() != num_files
+        {
+            return Err(DaftError::ValueError(format!(
+                "FileInfos columns must have the same length, got {num_files} file paths, {} file sizes, {} num rows and {} etags",
+                file_sizes.len(),
+                num_rows.len(),
+                etags.len()
+            )));
         }
+        Ok(Self::new_internal(file_paths, file_sizes, num_rows, etags))
     }
 
     pub fn len(&self) -> usize {
@@ -102,10 +159,142 @@ impl FileInfos {
     pub fn is_empty(&self) -> bool {
         self.file_paths.is_empty()
     }
+
+    /// Total size in bytes of the files, or None if the size of any file is unknown.
+    pub fn total_size_bytes(&self) -> Option<i64> {
+        self.file_sizes.iter().copied().sum()
+    }
+
+    /// Total number of rows in the files, or None if the number of rows of any file is unknown.
+    pub fn total_num_rows(&self) -> Option<i64> {
+        self.num_rows.iter().copied().sum()
+    }
+
+    pub fn get(&self, idx: usize) -> FileInfo {
+        FileInfo::new_internal(
+            self.file_paths[idx].clone(),
+            self.file_sizes[idx],
+            self.num_rows[idx],
+            self.etags[idx].clone(),
+        )
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = FileInfo> + '_ {
+        (0..self.len()).map(|idx| self.get(idx))
+    }
+
+    pub fn push(&mut self, info: FileInfo) {
+        self.file_paths.push(info.file_path);
+        self.file_sizes.push(info.file_size);
+        self.num_rows.push(info.num_rows);
+        self.etags.push(info.etag);
+    }
+
+    /// Removes all but the first of the files with the same path and etag, keeping the order of
+    /// the remaining files.
+    ///
+    /// Files with the same path but different etags are different versions of the file and are
+    /// all kept.
+    #[must_use]
+    pub fn dedup(&self) -> Self {
+        let mut seen = HashSet::with_capacity(self.len());
+        let mut deduped = Self::default();
+        for (idx, (path, etag)) in self.file_paths.iter().zip(&self.etags).enumerate() {
+            if seen.insert((path, etag)) {
+                deduped.push(self.get(idx));
+            }
+        }
+        deduped
+    }
+
+    /// Sorts the files by path, and then by etag, so that scans of the same files are planned
+    /// the same way regardless of the order of the listings they came from.
+    #[must_use]
+    pub fn sorted(&self) -> Self {
+        let mut indices = (0..self.len()).collect::<Vec<_>>();
+        indices.sort_by(|&a, &b| {
+            (&self.file_paths[a], &self.etags[a]).cmp(&(&self.file_paths[b], &self.etags[b]))
+        });
+        let mut sorted = Self::default();
+        for idx in indices {
+            sorted.push(self.get(idx));
+        }
+        sorted
+    }
+
+    /// Combines listings, e.g. of multiple prefixes or catalog partitions, into the files of a
+    /// single scan. Files that appear in several listings are only kept once, and the files are
+    /// sorted by path.
+    pub fn merge(infos: impl IntoIterator<Item = Self>) -> Self {
+        let mut merged = Self::default();
+        for info in infos {
+            merged.file_paths.extend(info.file_paths);
+            merged.file_sizes.extend(info.file_sizes);
+            merged.num_rows.extend(info.num_rows);
+            merged.etags.extend(info.etags);
+        }
+        merged.dedup().sorted()
+    }
 }
 
 impl Default for FileInfos {
     fn default() -> Self {
-        Self::new_internal(vec![], vec![], vec![])
+        Self::new_internal(vec![], vec![], vec![], vec![])
+    }
+}
+
+impl FromIterator<FileInfo> for FileInfos {
+    fn from_iter<T: IntoIterator<Item = FileInfo>>(iter: T) -> Self {
+        let mut infos = Self::default();
+        for info in iter {
+            infos.push(info);
+        }
+        infos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn infos(files: &[(&str, Option<i64>, Option<&str>)]) -> FileInfos {
+        files
+            .iter()
+            .map(|(path, size, etag)| {
+                FileInfo::new_internal(path.to_string(), *size, Some(1), etag.map(String::from))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_dedupes_and_sorts() {
+        let merged = FileInfos::merge([
+            infos(&[("b", Some(2), Some("v1")), ("a", Some(1), None)]),
+            infos(&[("b", Some(2), Some("v1")), ("b", Some(3), Some("v2"))]),
+        ]);
+        assert_eq!(merged.file_paths, vec!["a", "b", "b"]);
+        assert_eq!(
+            merged.etags,
+            vec![None, Some("v1".to_string()), Some("v2".to_string())]
+        );
+        assert_eq!(merged.total_size_bytes(), Some(6));
+        assert_eq!(merged.total_num_rows(), Some(3));
+    }
+
+    #[test]
+    fn test_try_new_length_mismatch() {
+        let result = FileInfos::try_new(
+            vec!["a".to_string(), "b".to_string()],
+            vec![Some(1), Some(2)],
+            vec![None],
+            vec![None, None],
+        );
+        assert!(matches!(result, Err(DaftError::ValueError(_))));
+    }
+
+    #[test]
+    fn test_total_size_bytes_unknown() {
+        let infos = infos(&[("a", Some(1), None), ("b", None, None)]);
+        assert_eq!(infos.total_size_bytes(), None);
     }
 }
@@ -4,19 +4,28 @@ use std::{
     sync::atomic::AtomicUsize,
 };
 
-use common_partitioning::PartitionCacheEntry;
+use common_error::{DaftError, DaftResult};
+use common_partitioning::{PartitionCacheEntry, PartitionMetadata};
 use common_scan_info::PhysicalScanInfo;
+use daft_dsl::{functions::python::RuntimePyObject, ExprRef};
 use daft_schema::schema::SchemaRef;
 pub use file_info::{FileInfo, FileInfos};
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::partitioning::ClusteringSpecRef;
+use crate::{
+    partitioning::{
+        ClusteringSpec, ClusteringSpecRef, RangeClusteringConfig, UnknownClusteringConfig,
+    },
+    LogicalPlan,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum SourceInfo {
     InMemory(InMemoryInfo),
     Physical(PhysicalScanInfo),
     PlaceHolder(PlaceHolderInfo),
+    Stream(StreamInfo),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +37,87 @@ pub struct InMemoryInfo {
     pub size_bytes: usize,
     pub num_rows: usize,
     pub clustering_spec: Option<ClusteringSpecRef>,
+    /// Number of rows and bytes of each cached partition, if known
+    pub partitions: Option<Vec<PartitionMetadata>>,
+    /// Order of the cached rows, if they were sorted before they were cached
+    pub sort_order: Option<InMemorySortOrder>,
+}
+
+/// Order of the rows of a cached partition set, across all of its partitions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InMemorySortOrder {
+    pub sort_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub nulls_first: Vec<bool>,
+}
+
+impl InMemorySortOrder {
+    pub fn new(sort_by: Vec<ExprRef>, descending: Vec<bool>, nulls_first: Vec<bool>) -> Self {
+        Self {
+            sort_by,
+            descending,
+            nulls_first,
+        }
+    }
+
+    /// The order of the output rows of a plan, if it ends with a sort that no later operation
+    /// reorders, so that the order can be recorded when its output is cached.
+    pub fn of_plan(plan: &LogicalPlan) -> Option<Self> {
+        match plan {
+            LogicalPlan::Sort(sort) => Some(Self::new(
+                sort.sort_by.clone(),
+                sort.descending.clone(),
+                sort.nulls_first.clone(),
+            )),
+            LogicalPlan::TopN(top_n) => Some(Self::new(
+                top_n.sort_by.clone(),
+                top_n.descending.clone(),
+                top_n.nulls_first.clone(),
+            )),
+            LogicalPlan::Filter(filter) => Self::of_plan(&filter.input),
+            LogicalPlan::Limit(limit) => Self::of_plan(&limit.input),
+            LogicalPlan::Source(source) => match source.source_info.as_ref() {
+                SourceInfo::InMemory(info) => info.sort_order.clone(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Whether rows in this order are also sorted by the given keys, i.e. the keys are a prefix
+    /// of this order.
+    pub fn satisfies(
+        &self,
+        sort_by: &[ExprRef],
+        descending: &[bool],
+        nulls_first: &[bool],
+    ) -> bool {
+        let n = sort_by.len();
+        n <= self.sort_by.len()
+            && descending.len() == n
+            && nulls_first.len() == n
+            && self.sort_by[..n] == *sort_by
+            && self.descending[..n] == *descending
+            && self.nulls_first[..n] == *nulls_first
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let pairs = self
+            .sort_by
+            .iter()
+            .zip(self.descending.iter())
+            .zip(self.nulls_first.iter())
+            .map(|((sb, d), nf)| {
+                format!(
+                    "({}, {}, {})",
+                    sb,
+                    if *d { "descending" } else { "ascending" },
+                    if *nf { "nulls first" } else { "nulls last" }
+                )
+            })
+            .join(", ");
+        vec![format!("Sort order = {}", pairs)]
+    }
 }
 
 impl InMemoryInfo {
@@ -48,7 +138,80 @@ impl InMemoryInfo {
             size_bytes,
             num_rows,
             clustering_spec,
+            partitions: None,
+            sort_order: None,
+        }
+    }
+
+    /// Describe each of the cached partitions, which also sets the total number of rows and bytes.
+    pub fn with_partition_metadata(
+        mut self,
+        partitions: Vec<PartitionMetadata>,
+    ) -> DaftResult<Self> {
+        if partitions.len() != self.num_partitions {
+            return Err(DaftError::ValueError(format!(
+                "Expected metadata for {} partitions for cache entry {}, but got {}",
+                self.num_partitions,
+                self.cache_key,
+                partitions.len()
+            )));
+        }
+        self.num_rows = partitions.iter().map(|p| p.num_rows).sum();
+        self.size_bytes = partitions.iter().map(|p| p.size_bytes).sum();
+        self.partitions = Some(partitions);
+        Ok(self)
+    }
+
+    /// Mark the cached rows as sorted, in partition order, by the given keys.
+    pub fn with_sort_order(mut self, sort_order: InMemorySortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// The clustering of the cached partitions.
+    ///
+    /// Without an explicit clustering spec, sorted partitions are range partitioned by their sort
+    /// keys, which lets the planner drop repartitions and sorts that they already satisfy.
+    pub fn clustering_spec(&self) -> ClusteringSpecRef {
+        if let Some(clustering_spec) = &self.clustering_spec {
+            return clustering_spec.clone();
+        }
+        match &self.sort_order {
+            Some(sort_order) => ClusteringSpec::Range(RangeClusteringConfig::new(
+                self.num_partitions,
+                sort_order.sort_by.clone(),
+                sort_order.descending.clone(),
+            ))
+            .into(),
+            None => {
+                ClusteringSpec::Unknown(UnknownClusteringConfig::new(self.num_partitions)).into()
+            }
+        }
+    }
+
+    /// Whether the cached rows are already sorted by the given keys.
+    pub fn is_sorted_by(
+        &self,
+        sort_by: &[ExprRef],
+        descending: &[bool],
+        nulls_first: &[bool],
+    ) -> bool {
+        self.sort_order
+            .as_ref()
+            .is_some_and(|sort_order| sort_order.satisfies(sort_by, descending, nulls_first))
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!("Number of partitions = {}", self.num_partitions)];
+        if let Some(partitions) = &self.partitions
+            && let Some((min, max)) = partitions.iter().map(|p| p.num_rows).minmax().into_option()
+        {
+            res.push(format!("Rows per partition = {min}..={max}"));
+        }
+        if let Some(sort_order) = &self.sort_order {
+            res.extend(sort_order.multiline_display());
         }
+        res
     }
 }
 
@@ -84,3 +247,212 @@ impl PlaceHolderInfo {
         }
     }
 }
+
+/// An unbounded source, which pulls micro-batches from a Python iterator for as long as it yields
+/// them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StreamInfo {
+    pub source_schema: SchemaRef,
+    pub name: String,
+    /// Callable which returns the iterator of micro-batches. The iterator yields `None` when no
+    /// data is available yet.
+    pub batch_iterator_factory: RuntimePyObject,
+    /// How long to wait before pulling from the iterator again when no data is available
+    pub poll_interval_ms: u64,
+    pub watermark: Option<Watermark>,
+}
+
+/// Event-time watermark of a stream, which trails the latest event time seen by `delay`.
+///
+/// Rows with an event time behind the watermark are late and are dropped by the source.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Watermark {
+    pub event_time_column: String,
+    /// Delay in the units of the event time column, e.g. microseconds for a timestamp column in
+    /// microseconds
+    pub delay: i64,
+}
+
+impl StreamInfo {
+    pub fn new(
+        source_schema: SchemaRef,
+        name: String,
+        batch_iterator_factory: RuntimePyObject,
+        poll_interval_ms: u64,
+        watermark: Option<Watermark>,
+    ) -> DaftResult<Self> {
+        if let Some(watermark) = &watermark {
+            let field = source_schema.get_field(&watermark.event_time_column)?;
+            if !(field.dtype.is_integer() || field.dtype.is_temporal()) {
+                return Err(DaftError::ValueError(format!(
+                    "Event time column {} of stream {} must be an integer or temporal column, but it is {}",
+                    field.name, name, field.dtype
+                )));
+            }
+            if watermark.delay < 0 {
+                return Err(DaftError::ValueError(format!(
+                    "Watermark delay of stream {} must not be negative, but it is {}",
+                    name, watermark.delay
+                )));
+            }
+        }
+        Ok(Self {
+            source_schema,
+            name,
+            batch_iterator_factory,
+            poll_interval_ms,
+            watermark,
+        })
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![
+            format!("Stream = {}", self.name),
+            format!("Poll interval = {}ms", self.poll_interval_ms),
+        ];
+        if let Some(Watermark {
+            event_time_column,
+            delay,
+        }) = &self.watermark
+        {
+            res.push(format!("Watermark = {event_time_column} - {delay}"));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use common_partitioning::{PartitionCacheEntry, PartitionMetadata};
+    use daft_dsl::{col, functions::python::RuntimePyObject, lit};
+    use daft_schema::{dtype::DataType, field::Field, schema::Schema};
+
+    use super::{InMemoryInfo, InMemorySortOrder, StreamInfo, Watermark};
+    use crate::{
+        partitioning::{ClusteringSpec, RangeClusteringConfig},
+        LogicalPlanBuilder,
+    };
+
+    fn in_memory_info(num_partitions: usize) -> InMemoryInfo {
+        InMemoryInfo::new(
+            Schema::empty().into(),
+            "foo".to_string(),
+            PartitionCacheEntry::Rust {
+                key: "foo".to_string(),
+                value: None,
+            },
+            num_partitions,
+            0,
+            0,
+            None,
+        )
+    }
+
+    #[test]
+    fn partition_metadata_sets_totals() -> DaftResult<()> {
+        let partitions = vec![
+            PartitionMetadata {
+                num_rows: 2,
+                size_bytes: 16,
+            },
+            PartitionMetadata {
+                num_rows: 3,
+                size_bytes: 24,
+            },
+        ];
+        let info = in_memory_info(2).with_partition_metadata(partitions)?;
+        assert_eq!(info.num_rows, 5);
+        assert_eq!(info.size_bytes, 40);
+        assert!(in_memory_info(3)
+            .with_partition_metadata(info.partitions.unwrap())
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn sorted_partitions_are_range_clustered() {
+        let info = in_memory_info(2).with_sort_order(InMemorySortOrder::new(
+            vec![col("a"), col("b")],
+            vec![false, true],
+            vec![false, false],
+        ));
+        assert_eq!(
+            info.clustering_spec().as_ref(),
+            &ClusteringSpec::Range(RangeClusteringConfig::new(
+                2,
+                vec![col("a"), col("b")],
+                vec![false, true]
+            ))
+        );
+        assert!(info.is_sorted_by(&[col("a")], &[false], &[false]));
+        assert!(!info.is_sorted_by(&[col("b")], &[true], &[false]));
+        assert!(!info.is_sorted_by(&[col("a")], &[true], &[false]));
+        assert!(!in_memory_info(2).is_sorted_by(&[col("a")], &[false], &[false]));
+    }
+
+    #[test]
+    fn sort_order_of_sorted_plan() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ])?;
+        let source = LogicalPlanBuilder::in_memory_scan(
+            "foo",
+            PartitionCacheEntry::Rust {
+                key: "foo".to_string(),
+                value: None,
+            },
+            schema.into(),
+            1,
+            0,
+            0,
+            None,
+        )?;
+        assert_eq!(InMemorySortOrder::of_plan(&source.build()), None);
+
+        let sorted = source
+            .sort(vec![col("a")], vec![true], vec![false])?
+            .filter(col("b").gt(lit(1)))?
+            .limit(10, false)?
+            .build();
+        assert_eq!(
+            InMemorySortOrder::of_plan(&sorted),
+            Some(InMemorySortOrder::new(
+                vec![col("a")],
+                vec![true],
+                vec![false]
+            ))
+        );
+        let projected = LogicalPlanBuilder::from(sorted)
+            .select(vec![col("b")])?
+            .build();
+        assert_eq!(InMemorySortOrder::of_plan(&projected), None);
+        Ok(())
+    }
+
+    #[test]
+    fn stream_watermark_requires_event_time_column() -> DaftResult<()> {
+        let schema = Schema::new(vec![
+            Field::new("t", DataType::Int64),
+            Field::new("x", DataType::Utf8),
+        ])?;
+        let stream_info = |column: &str, delay: i64| {
+            StreamInfo::new(
+                schema.clone().into(),
+                "stream".to_string(),
+                RuntimePyObject::new_testing_none(),
+                100,
+                Some(Watermark {
+                    event_time_column: column.to_string(),
+                    delay,
+                }),
+            )
+        };
+        assert!(stream_info("t", 10).is_ok());
+        assert!(stream_info("t", -1).is_err());
+        assert!(stream_info("x", 10).is_err());
+        assert!(stream_info("y", 10).is_err());
+        Ok(())
+    }
+}
@@ -7,7 +7,7 @@ use common_treenode::{
 use daft_dsl::ExprRef;
 
 use crate::{
-    partitioning::{HashRepartitionConfig, RepartitionSpec},
+    partitioning::{HashRepartitionConfig, RangeRepartitionConfig, RepartitionSpec},
     LogicalPlan,
 };
 
@@ -41,7 +41,9 @@ impl LogicalPlan {
         self,
         mut f: F,
     ) -> DaftResult<Transformed<Self>> {
-        use crate::ops::{ActorPoolProject, Explode, Filter, Join, Project, Repartition, Sort};
+        use crate::ops::{
+            ActorPoolProject, Explode, Filter, Join, Project, Repartition, Sort, TopN,
+        };
 
         Ok(match self {
             Self::Project(Project {
@@ -85,6 +87,20 @@ impl LogicalPlan {
                             by: expr,
                         })
                     }),
+                RepartitionSpec::Range(RangeRepartitionConfig {
+                    num_partitions,
+                    by,
+                    descending,
+                }) => by
+                    .into_iter()
+                    .map_until_stop_and_collect(f)?
+                    .update_data(|expr| {
+                        RepartitionSpec::Range(RangeRepartitionConfig {
+                            num_partitions,
+                            by: expr,
+                            descending,
+                        })
+                    }),
                 repartition_spec => Transformed::no(repartition_spec),
             }
             .update_data(|repartition_spec| {
@@ -128,6 +144,26 @@ impl LogicalPlan {
                         stats_state,
                     })
                 }),
+            Self::TopN(TopN {
+                input,
+                sort_by,
+                descending,
+                nulls_first,
+                limit,
+                stats_state,
+            }) => sort_by
+                .into_iter()
+                .map_until_stop_and_collect(f)?
+                .update_data(|expr| {
+                    Self::TopN(TopN {
+                        input,
+                        sort_by: expr,
+                        descending,
+                        nulls_first,
+                        limit,
+                        stats_state,
+                    })
+                }),
             Self::Explode(Explode {
                 input,
                 to_explode,
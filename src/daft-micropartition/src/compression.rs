@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use arrow2::io::ipc::{
+    read::{read_stream_metadata, StreamReader, StreamState},
+    write::{Compression, StreamWriter, WriteOptions},
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::SchemaRef, series::Series};
+use daft_table::Table;
+
+/// Tables that are kept compressed in memory, in the Arrow IPC stream format with every buffer
+/// compressed with LZ4.
+#[derive(Debug)]
+pub struct CompressedTables {
+    schema: SchemaRef,
+    num_tables: usize,
+    bytes: Vec<u8>,
+}
+
+impl CompressedTables {
+    /// Compresses `tables`, which fails if their schema has no Arrow representation, e.g. if it
+    /// has Python columns.
+    pub fn try_new(schema: SchemaRef, tables: &[Table]) -> DaftResult<Self> {
+        let options = WriteOptions {
+            compression: Some(Compression::LZ4),
+        };
+        let mut writer = StreamWriter::new(Vec::new(), options);
+        writer.start(&schema.to_arrow()?, None)?;
+        for table in tables {
+            let chunk = arrow2::chunk::Chunk::new(table.get_inner_arrow_arrays().collect());
+            writer.write(&chunk, None)?;
+        }
+        writer.finish()?;
+        Ok(Self {
+            schema,
+            num_tables: tables.len(),
+            bytes: writer.into_inner(),
+        })
+    }
+
+    pub fn num_tables(&self) -> usize {
+        self.num_tables
+    }
+
+    /// Size of the compressed tables in bytes.
+    pub fn size_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn decompress(&self) -> DaftResult<Vec<Table>> {
+        let mut reader = self.bytes.as_slice();
+        let metadata = read_stream_metadata(&mut reader)?;
+        StreamReader::new(reader, metadata, None)
+            .map(|state| {
+                let StreamState::Some(chunk) = state? else {
+                    return Err(DaftError::InternalError(
+                        "Compressed tables ended in the middle of a batch".to_string(),
+                    ));
+                };
+                let num_rows = chunk.len();
+                let columns = self
+                    .schema
+                    .fields
+                    .values()
+                    .zip(chunk.into_arrays())
+                    .map(|(field, array)| Series::from_arrow(Arc::new(field.clone()), array))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                Table::new_with_size(self.schema.clone(), columns, num_rows)
+            })
+            .collect()
+    }
+}
@@ -4,6 +4,7 @@
 
 use common_error::DaftError;
 use snafu::Snafu;
+mod compression;
 mod micropartition;
 mod ops;
 
@@ -25,12 +25,14 @@ use daft_table::Table;
 use parquet2::metadata::FileMetaData;
 use snafu::ResultExt;
 
-use crate::{DaftCSVSnafu, DaftCoreComputeSnafu};
+use crate::{compression::CompressedTables, DaftCSVSnafu, DaftCoreComputeSnafu};
 
 #[derive(Debug)]
 pub enum TableState {
     Unloaded(Arc<ScanTask>),
     Loaded(Arc<Vec<Table>>),
+    /// Loaded tables that are compressed in memory until they're accessed.
+    Compressed(Arc<CompressedTables>),
 }
 
 impl Display for TableState {
@@ -54,6 +56,14 @@ impl Display for TableState {
                 }
                 Ok(())
             }
+            Self::Compressed(compressed) => {
+                write!(
+                    f,
+                    "TableState: Compressed. {} tables in {} bytes",
+                    compressed.num_tables(),
+                    compressed.size_bytes()
+                )
+            }
         }
     }
 }
@@ -71,7 +81,7 @@ pub struct MicroPartition {
     /// of having 0 underlying [`Table`] objects (in an empty [`MicroPartition`])
     pub(crate) schema: SchemaRef,
 
-    /// State of the MicroPartition. Can be Loaded or Unloaded.
+    /// State of the MicroPartition. Can be Loaded, Unloaded or Compressed.
     pub(crate) state: Mutex<TableState>,
 
     /// Metadata about the MicroPartition
@@ -96,13 +106,7 @@ fn materialize_scan_task(
     scan_task: Arc<ScanTask>,
     io_stats: Option<IOStatsRef>,
 ) -> crate::Result<(Vec<Table>, SchemaRef)> {
-    let pushdown_columns = scan_task.pushdowns.columns.as_ref().map(|v| {
-        v.iter()
-            .map(std::string::String::as_str)
-            .collect::<Vec<&str>>()
-    });
-    let file_column_names =
-        _get_file_column_names(pushdown_columns.as_deref(), scan_task.partition_spec());
+    let file_column_names = scan_task.file_column_names();
 
     let urls = scan_task
         .sources
@@ -266,6 +270,91 @@ fn materialize_scan_task(
             )
             .context(DaftCoreComputeSnafu)?
         }
+
+        // ****************
+        // Native XML Reads
+        // ****************
+        FileFormatConfig::Xml(cfg) => {
+            let include_columns = file_column_names.as_ref().map(|cols| {
+                cols.iter()
+                    .map(|col| (*col).to_string())
+                    .collect::<Vec<_>>()
+            });
+            let uris = urls.collect::<Vec<_>>();
+            daft_xml::read_xml_bulk(
+                uris.as_slice(),
+                cfg,
+                scan_task.schema.clone(),
+                include_columns.as_deref(),
+                scan_task.pushdowns.limit,
+                scan_task.pushdowns.filters.clone(),
+                io_client,
+                io_stats,
+                scan_task.storage_config.multithreaded_io,
+                8,
+            )
+            .context(DaftCoreComputeSnafu)?
+        }
+
+        // ******************
+        // Native Excel Reads
+        // ******************
+        FileFormatConfig::Excel(cfg) => {
+            let include_columns = file_column_names.as_ref().map(|cols| {
+                cols.iter()
+                    .map(|col| (*col).to_string())
+                    .collect::<Vec<_>>()
+            });
+            let uris = urls.collect::<Vec<_>>();
+            daft_excel::read_excel_bulk(
+                uris.as_slice(),
+                cfg,
+                scan_task.schema.clone(),
+                include_columns.as_deref(),
+                scan_task.pushdowns.limit,
+                scan_task.pushdowns.filters.clone(),
+                io_client,
+                io_stats,
+                scan_task.storage_config.multithreaded_io,
+                8,
+            )
+            .context(DaftCoreComputeSnafu)?
+        }
+
+        // *****************
+        // Native Kafka Reads
+        // *****************
+        FileFormatConfig::Kafka(cfg) => scan_task
+            .sources
+            .iter()
+            .filter_map(|source| match source {
+                DataSource::Kafka { range, .. } => Some(range),
+                _ => None,
+            })
+            .map(|range| {
+                daft_kafka::read_kafka_range(
+                    cfg,
+                    range,
+                    scan_task.schema.clone(),
+                    scan_task.pushdowns.limit,
+                )
+            })
+            .collect::<DaftResult<Vec<_>>>()
+            .context(DaftCoreComputeSnafu)?,
+
+        // *****************
+        // Generated Ranges
+        // *****************
+        FileFormatConfig::Range => scan_task
+            .sources
+            .iter()
+            .filter_map(|source| match source {
+                DataSource::Range { range, .. } => Some(range),
+                _ => None,
+            })
+            .map(|range| range.to_table(scan_task.pushdowns.limit))
+            .collect::<DaftResult<Vec<_>>>()
+            .context(DaftCoreComputeSnafu)?,
         #[cfg(feature = "python")]
         FileFormatConfig::Database(DatabaseSourceConfig { sql, conn }) => {
             let predicate = scan_task
@@ -303,8 +392,8 @@ fn materialize_scan_task(
     let cast_to_schema = scan_task.materialized_schema();
 
     // If there is a partition spec and partition values aren't duplicated in the data, inline the partition values
-    // into the table when casting the schema.
-    let fill_map = scan_task.partition_spec().map(|pspec| pspec.to_fill_map());
+    // into the table when casting the schema, as well as the defaults of columns that the data doesn't have.
+    let fill_map = scan_task.fill_map();
 
     table_values = table_values
         .iter()
@@ -401,7 +490,8 @@ impl MicroPartition {
             }
 
             // CASE: ScanTask does not provide metadata, but the file format supports metadata retrieval
-            // We can perform an eager **metadata** read to create an unloaded MicroPartition
+            // We can perform an eager **metadata** read to create an unloaded MicroPartition.
+            // Column defaults are only filled in when the data of the ScanTask is materialized.
             (
                 _,
                 _,
@@ -411,7 +501,7 @@ impl MicroPartition {
                     chunk_size,
                     ..
                 }),
-            ) => {
+            ) if scan_task.column_defaults.is_none() => {
                 let uris = scan_task
                     .sources
                     .iter()
@@ -513,6 +603,9 @@ impl MicroPartition {
         } else if let TableState::Unloaded(scan_task) = &*guard {
             // TODO: pass in the execution config once we have it available
             scan_task.estimate_in_memory_size_bytes(None)
+        } else if let TableState::Compressed(compressed) = &*guard {
+            // Compressed tables only take up their compressed size until they're accessed.
+            Some(compressed.size_bytes())
         } else {
             // If the table is not loaded, we don't have stats, and we don't have the file size in bytes, return None.
             // TODO(Clark): Should we pull in the table or trigger a file metadata fetch instead of returning None here?
@@ -526,6 +619,7 @@ impl MicroPartition {
     /// This method:
     /// 1. Returns cached tables if already loaded.
     /// 2. If unloaded, reads data from the source, caches it, and returns the new tables.
+    /// 3. If compressed, decompresses the tables, caches them, and returns them.
     ///
     /// "Reading if necessary" means I/O operations only occur for unloaded data,
     /// optimizing performance by avoiding redundant reads.
@@ -542,6 +636,11 @@ impl MicroPartition {
                 Ok(table_values)
             }
             TableState::Loaded(tables) => Ok(tables.clone()),
+            TableState::Compressed(compressed) => {
+                let table_values = Arc::new(compressed.decompress().context(DaftCoreComputeSnafu)?);
+                *guard = TableState::Loaded(table_values.clone());
+                Ok(table_values)
+            }
         }
     }
 
@@ -1167,7 +1266,7 @@ impl Display for MicroPartition {
         writeln!(f, "MicroPartition with {} rows:", self.len())?;
 
         match &*guard {
-            TableState::Unloaded(..) => {
+            TableState::Unloaded(..) | TableState::Compressed(..) => {
                 writeln!(f, "{}\n{}", self.schema, guard)?;
             }
             TableState::Loaded(tables) => {
@@ -22,14 +22,17 @@ impl MicroPartition {
                 let maybe_new_scan_task = if scan_task.schema == schema {
                     scan_task.clone()
                 } else {
-                    Arc::new(ScanTask::new(
-                        scan_task.sources.clone(),
-                        scan_task.file_format_config.clone(),
-                        schema,
-                        scan_task.storage_config.clone(),
-                        scan_task.pushdowns.clone(),
-                        scan_task.generated_fields.clone(),
-                    ))
+                    Arc::new(
+                        ScanTask::new(
+                            scan_task.sources.clone(),
+                            scan_task.file_format_config.clone(),
+                            schema,
+                            scan_task.storage_config.clone(),
+                            scan_task.pushdowns.clone(),
+                            scan_task.generated_fields.clone(),
+                        )
+                        .with_column_defaults(scan_task.column_defaults.clone()),
+                    )
                 };
                 Ok(Self::new_unloaded(
                     maybe_new_scan_task,
@@ -37,6 +40,18 @@ impl MicroPartition {
                     pruned_statistics.expect("Unloaded MicroPartition should have statistics"),
                 ))
             }
+            // If Tables are compressed, they're decompressed to be cast like loaded Tables
+            TableState::Compressed(compressed) => Ok(Self::new_loaded(
+                schema.clone(),
+                Arc::new(
+                    compressed
+                        .decompress()?
+                        .iter()
+                        .map(|tbl| tbl.cast_to_schema(schema.as_ref()))
+                        .collect::<DaftResult<Vec<_>>>()?,
+                ),
+                pruned_statistics,
+            )),
             // If Tables are already loaded, we map `Table::cast_to_schema` on each Table
             TableState::Loaded(tables) => Ok(Self::new_loaded(
                 schema.clone(),
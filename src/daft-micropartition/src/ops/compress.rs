@@ -0,0 +1,78 @@
+use std::sync::{Arc, Mutex};
+
+use common_error::DaftResult;
+
+use crate::{
+    compression::CompressedTables,
+    micropartition::{MicroPartition, TableState},
+};
+
+impl MicroPartition {
+    /// Compresses the tables of a loaded MicroPartition in memory, which are decompressed again
+    /// when they're accessed.
+    ///
+    /// MicroPartitions that aren't loaded, whose tables can't be represented in Arrow, or that
+    /// don't get smaller when compressed are returned as they are.
+    pub fn compress(self: &Arc<Self>) -> DaftResult<Arc<Self>> {
+        let guard = self.state.lock().unwrap();
+        let TableState::Loaded(tables) = &*guard else {
+            return Ok(self.clone());
+        };
+        if tables.is_empty() || self.schema.to_arrow().is_err() {
+            return Ok(self.clone());
+        }
+
+        let compressed = CompressedTables::try_new(self.schema.clone(), tables)?;
+        let size_bytes = tables
+            .iter()
+            .map(daft_table::Table::size_bytes)
+            .sum::<DaftResult<usize>>()?;
+        if compressed.size_bytes() >= size_bytes {
+            return Ok(self.clone());
+        }
+
+        Ok(Arc::new(Self {
+            schema: self.schema.clone(),
+            state: Mutex::new(TableState::Compressed(Arc::new(compressed))),
+            metadata: self.metadata.clone(),
+            statistics: self.statistics.clone(),
+        }))
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        matches!(*self.state.lock().unwrap(), TableState::Compressed(..))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_table::Table;
+
+    use crate::MicroPartition;
+
+    #[test]
+    fn test_compress_roundtrip() -> DaftResult<()> {
+        let values = (0..10_000).map(|i| i % 10).collect::<Vec<i64>>();
+        let table = Table::from_nonempty_columns(vec![
+            Int64Array::from(("a", values.clone())).into_series()
+        ])?;
+        let part = Arc::new(MicroPartition::new_loaded(
+            table.schema.clone(),
+            Arc::new(vec![table.clone()]),
+            None,
+        ));
+
+        let compressed = part.compress()?;
+        assert!(compressed.is_compressed());
+        assert_eq!(compressed.len(), values.len());
+        assert!(compressed.size_bytes()?.unwrap() < part.size_bytes()?.unwrap());
+
+        assert_eq!(compressed.get_tables()?.as_slice(), &[table]);
+        assert!(!compressed.is_compressed());
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 mod agg;
 mod cast_to_schema;
+mod compress;
 mod concat;
 mod eval_expressions;
 mod filter;
@@ -9,4 +10,5 @@ mod pivot;
 mod slice;
 mod sort;
 mod take;
+mod unique;
 mod unpivot;
@@ -85,6 +85,7 @@ impl MicroPartition {
         partition_keys: &[ExprRef],
         boundaries: &Table,
         descending: &[bool],
+        nulls_first: &[bool],
     ) -> DaftResult<Vec<Self>> {
         let io_stats = IOStatsContext::new("MicroPartition::partition_by_range");
 
@@ -101,7 +102,7 @@ impl MicroPartition {
 
         let part_tables = tables
             .iter()
-            .map(|t| t.partition_by_range(partition_keys, boundaries, descending))
+            .map(|t| t.partition_by_range(partition_keys, boundaries, descending, nulls_first))
             .collect::<DaftResult<Vec<_>>>()?;
         self.vec_part_tables_to_mps(part_tables)
     }
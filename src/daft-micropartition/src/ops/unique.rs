@@ -0,0 +1,18 @@
+use common_error::DaftResult;
+use daft_dsl::ExprRef;
+use daft_io::IOStatsContext;
+use daft_table::DuplicateKeyDetector;
+
+use crate::micropartition::MicroPartition;
+
+impl MicroPartition {
+    /// Fails if two rows have equal values of `keys`, naming some of the duplicate keys.
+    pub fn assert_unique(&self, keys: &[ExprRef]) -> DaftResult<()> {
+        let io_stats = IOStatsContext::new("MicroPartition::assert_unique");
+        let mut detector = DuplicateKeyDetector::default();
+        for table in self.tables_or_read(io_stats)?.iter() {
+            detector.push(table.eval_expression_list(keys)?)?;
+        }
+        detector.check()
+    }
+}
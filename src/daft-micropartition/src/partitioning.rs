@@ -56,6 +56,36 @@ impl MicroPartitionSet {
         let mp = MicroPartition::new_loaded(schema.clone(), Arc::new(tables), None);
         Ok(Self::new(vec![(id, Arc::new(mp))]))
     }
+
+    /// The partition set held by a Rust cache entry, for partition sets that are created in Rust
+    /// without being put into a partition set cache, e.g. the `information_schema` tables of SQL
+    pub fn from_cache_entry(entry: &PartitionCacheEntry) -> Option<Arc<Self>> {
+        match entry {
+            PartitionCacheEntry::Rust {
+                value: Some(value), ..
+            } => value.clone().downcast().ok(),
+            _ => None,
+        }
+    }
+
+    /// The number of rows and bytes of each partition, ordered by partition id
+    pub fn partition_metadata(&self) -> DaftResult<Vec<PartitionMetadata>> {
+        let mut partitions = self
+            .partitions
+            .iter()
+            .map(|part| (*part.key(), part.value().clone()))
+            .collect::<Vec<_>>();
+        partitions.sort_by_key(|(idx, _)| *idx);
+        partitions
+            .into_iter()
+            .map(|(_, part)| {
+                Ok(PartitionMetadata {
+                    num_rows: part.len(),
+                    size_bytes: part.size_bytes()?.unwrap_or(0),
+                })
+            })
+            .collect()
+    }
 }
 
 impl PartitionSet<MicroPartitionRef> for MicroPartitionSet {
@@ -170,6 +170,12 @@ impl PyMicroPartition {
         })
     }
 
+    pub fn assert_unique(&self, py: Python, keys: Vec<PyExpr>) -> PyResult<()> {
+        let converted_keys: Vec<daft_dsl::ExprRef> =
+            keys.into_iter().map(std::convert::Into::into).collect();
+        py.allow_threads(|| Ok(self.inner.assert_unique(converted_keys.as_slice())?))
+    }
+
     pub fn take(&self, py: Python, idx: &PySeries) -> PyResult<Self> {
         py.allow_threads(|| Ok(self.inner.take(&idx.series)?.into()))
     }
@@ -480,6 +486,7 @@ impl PyMicroPartition {
         partition_keys: Vec<PyExpr>,
         boundaries: &PyTable,
         descending: Vec<bool>,
+        nulls_first: Vec<bool>,
     ) -> PyResult<Vec<Self>> {
         let exprs: Vec<daft_dsl::ExprRef> = partition_keys
             .into_iter()
@@ -488,7 +495,12 @@ impl PyMicroPartition {
         py.allow_threads(|| {
             Ok(self
                 .inner
-                .partition_by_range(exprs.as_slice(), &boundaries.table, descending.as_slice())?
+                .partition_by_range(
+                    exprs.as_slice(),
+                    &boundaries.table,
+                    descending.as_slice(),
+                    nulls_first.as_slice(),
+                )?
                 .into_iter()
                 .map(std::convert::Into::into)
                 .collect::<Vec<Self>>())
@@ -760,6 +772,14 @@ impl PyMicroPartition {
         let py_stats_bytes =
             PyBytes::new_bound(py, &bincode::serialize(&self.inner.statistics).unwrap());
 
+        // Compressed tables are decompressed to be pickled like loaded tables.
+        if matches!(
+            &*self.inner.state.lock().unwrap(),
+            TableState::Compressed(..)
+        ) {
+            self.inner.get_tables()?;
+        }
+
         let guard = self.inner.state.lock().unwrap();
         if let TableState::Loaded(tables) = &*guard {
             let _from_pytable = py
@@ -2,9 +2,10 @@
 #![feature(let_chains)]
 #![feature(result_flattening)]
 
-use std::{cmp::max, num::NonZeroUsize};
+use std::cmp::max;
 
 use common_error::DaftError;
+use common_runtime::get_compute_pool_num_threads;
 use daft_core::prelude::SchemaRef;
 use snafu::Snafu;
 
@@ -25,17 +26,15 @@ pub use python::register_modules;
 // This is the default size of an emitted morsel from the parquet reader
 const PARQUET_MORSEL_SIZE: usize = 128 * 1024;
 
-// This function determines the number of parallel deserialize tasks to use when reading parquet files
-// It is calculated by taking 2x the number of cores available (to ensure pipelining), and dividing
-// by the number of columns in the schema.
+// This function determines the number of row groups of a file that are deserialized concurrently.
+// It is calculated by taking 2x the number of threads of the compute pool (to ensure pipelining),
+// and dividing by the number of columns in the schema, since each column of a row group is
+// deserialized by its own task.
 fn determine_parquet_parallelism(daft_schema: &SchemaRef) -> usize {
-    (std::thread::available_parallelism()
-        .unwrap_or(NonZeroUsize::new(2).unwrap())
-        .checked_mul(2.try_into().unwrap())
-        .unwrap()
-        .get() as f64
+    (get_compute_pool_num_threads().saturating_mul(2) as f64
         / max(daft_schema.fields.len(), 1) as f64)
-        .ceil() as usize
+        .ceil()
+        .max(1.0) as usize
 }
 
 #[derive(Debug, Snafu)]
@@ -186,7 +186,9 @@ async fn read_parquet_single(
 
     let (source_type, fixed_uri) = parse_url(uri)?;
 
-    let (metadata, mut table) = if matches!(source_type, SourceType::File) {
+    let (metadata, mut table) = if matches!(source_type, SourceType::File)
+        && !io_client.has_transform()
+    {
         crate::stream_reader::local_parquet_read_async(
             fixed_uri.as_ref(),
             columns_to_read,
@@ -403,7 +405,9 @@ async fn stream_parquet_single(
 
     let (source_type, fixed_uri) = parse_url(uri.as_str())?;
 
-    let (metadata, table_stream) = if matches!(source_type, SourceType::File) {
+    let (metadata, table_stream) = if matches!(source_type, SourceType::File)
+        && !io_client.has_transform()
+    {
         crate::stream_reader::local_parquet_stream(
             fixed_uri.as_ref(),
             columns_to_return,
@@ -529,7 +533,9 @@ async fn read_parquet_single_into_arrow(
 ) -> DaftResult<ParquetPyarrowChunk> {
     let field_id_mapping_provided = field_id_mapping.is_some();
     let (source_type, fixed_uri) = parse_url(uri)?;
-    let (metadata, schema, all_arrays, num_rows_read) = if matches!(source_type, SourceType::File) {
+    let (metadata, schema, all_arrays, num_rows_read) = if matches!(source_type, SourceType::File)
+        && !io_client.has_transform()
+    {
         let (metadata, schema, all_arrays, num_rows_read) =
             crate::stream_reader::local_parquet_read_into_arrow_async(
                 fixed_uri.as_ref(),
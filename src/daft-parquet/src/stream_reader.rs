@@ -617,7 +617,7 @@ pub async fn local_parquet_stream(
     )?;
 
     // We use a semaphore to limit the number of concurrent row group deserialization tasks.
-    // Set the maximum number of concurrent tasks to ceil(number of available threads / columns).
+    // Set the maximum number of concurrent tasks to ceil(2 * compute pool threads / columns).
     let num_parallel_tasks = determine_parquet_parallelism(&schema_ref);
     let semaphore = Arc::new(tokio::sync::Semaphore::new(num_parallel_tasks));
 
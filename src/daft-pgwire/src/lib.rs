@@ -0,0 +1,217 @@
+//! A read-only Postgres wire protocol frontend for Daft, so that tools which speak Postgres, such
+//! as Grafana and Metabase, can query Daft-backed datasets.
+//!
+//! `SELECT` queries are planned with `daft-sql` against the tables of a catalog given when starting
+//! the server, executed on the native executor, and their results streamed back as rows in the
+//! Postgres text format. Both the simple and the extended query protocol are supported, without
+//! statement parameters.
+//!
+//! Connections are neither authenticated nor encrypted, and queries can read any file that the
+//! server process can read, e.g. with `read_csv`. The server therefore listens on the loopback
+//! interface by default, and should only be exposed to other hosts behind a trusted network.
+
+mod protocol;
+mod session;
+mod types;
+
+use std::sync::Arc;
+
+use common_daft_config::DaftExecutionConfig;
+use common_error::DaftResult;
+use daft_sql::catalog::SQLCatalog;
+use session::{serve_connection, QueryEngine};
+
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+pub struct PgWireServerHandle {
+    shutdown_signal: Option<tokio::sync::oneshot::Sender<()>>,
+    port: u16,
+}
+
+#[cfg_attr(feature = "python", pyo3::pymethods)]
+impl PgWireServerHandle {
+    pub fn shutdown(&mut self) {
+        let Some(shutdown_signal) = self.shutdown_signal.take() else {
+            return;
+        };
+        // the server may already have stopped on an error
+        let _ = shutdown_signal.send(());
+    }
+
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl Drop for PgWireServerHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Starts serving SQL queries on the tables of `catalog` at `addr` (e.g. `"127.0.0.1:5432"`) on a
+/// background thread, until the returned handle is shut down or dropped.
+///
+/// The server does not authenticate clients, so binding it to a non-loopback address lets anyone
+/// who can reach it query the tables of `catalog` and the files readable by this process.
+pub fn start(
+    addr: &str,
+    catalog: SQLCatalog,
+    cfg: Arc<DaftExecutionConfig>,
+) -> DaftResult<PgWireServerHandle> {
+    let listener = std::net::TcpListener::bind(addr)?;
+    listener.set_nonblocking(true)?;
+    let port = listener.local_addr()?.port();
+
+    let (shutdown_signal, mut shutdown_receiver) = tokio::sync::oneshot::channel::<()>();
+    let engine = Arc::new(QueryEngine::new(catalog, cfg));
+    let runtime = tokio::runtime::Runtime::new()?;
+
+    std::thread::spawn(move || {
+        let result = runtime.block_on(async {
+            let listener = tokio::net::TcpListener::from_std(listener)?;
+            loop {
+                let (stream, peer) = tokio::select! {
+                    accepted = listener.accept() => accepted?,
+                    _ = &mut shutdown_receiver => return Ok::<_, std::io::Error>(()),
+                };
+                let engine = engine.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = serve_connection(stream, engine).await {
+                        log::warn!("Postgres connection from {peer} closed with error: {e}");
+                    }
+                });
+            }
+        });
+
+        if let Err(e) = result {
+            log::error!("Postgres wire protocol server error: {e}");
+        }
+    });
+
+    Ok(PgWireServerHandle {
+        shutdown_signal: Some(shutdown_signal),
+        port,
+    })
+}
+
+#[cfg(feature = "python")]
+#[pyo3::pyfunction]
+#[pyo3(name = "pgwire_start", signature = (addr = "127.0.0.1:0", catalog = None, config = None))]
+pub fn py_pgwire_start(
+    addr: &str,
+    catalog: Option<daft_sql::python::PyCatalog>,
+    config: Option<common_daft_config::PyDaftExecutionConfig>,
+) -> pyo3::PyResult<PgWireServerHandle> {
+    let catalog = catalog.map_or_else(SQLCatalog::new, |catalog| catalog.catalog().clone());
+    let cfg = config.map_or_else(
+        || Arc::new(DaftExecutionConfig::from_env()),
+        |config| config.config,
+    );
+    Ok(start(addr, catalog, cfg)?)
+}
+
+#[cfg(feature = "python")]
+pub fn register_modules(parent: &pyo3::Bound<pyo3::types::PyModule>) -> pyo3::PyResult<()> {
+    use pyo3::types::PyModuleMethods;
+
+    parent.add_function(pyo3::wrap_pyfunction_bound!(py_pgwire_start, parent)?)?;
+    parent.add_class::<PgWireServerHandle>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+    };
+
+    use super::*;
+    use crate::protocol::{read_message, MessageWriter, PROTOCOL_VERSION_3};
+
+    /// Reads messages until `ReadyForQuery`, returning their tags and the values of data rows
+    async fn read_until_ready(stream: &mut TcpStream) -> (Vec<u8>, Vec<Vec<Option<String>>>) {
+        let mut tags = Vec::new();
+        let mut rows = Vec::new();
+        loop {
+            let (tag, mut body) = read_message(stream).await.unwrap().unwrap();
+            tags.push(tag);
+            match tag {
+                b'D' => {
+                    let num_values = body.read_i16().unwrap();
+                    rows.push(
+                        (0..num_values)
+                            .map(|_| {
+                                body.read_value()
+                                    .unwrap()
+                                    .map(|value| String::from_utf8(value).unwrap())
+                            })
+                            .collect(),
+                    );
+                }
+                b'Z' => return (tags, rows),
+                _ => {}
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_simple_query() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.csv");
+        std::fs::write(&path, "a,b\n1,x\n2,y\n3,\n").unwrap();
+
+        let server = start(
+            "127.0.0.1:0",
+            SQLCatalog::new(),
+            Arc::new(DaftExecutionConfig::default()),
+        )
+        .unwrap();
+        let mut stream = TcpStream::connect(("127.0.0.1", server.port()))
+            .await
+            .unwrap();
+
+        // startup messages have no tag
+        let mut buf = Vec::new();
+        MessageWriter::new(&mut buf, 0)
+            .i32(PROTOCOL_VERSION_3)
+            .cstr("user")
+            .cstr("daft")
+            .u8(0)
+            .finish();
+        stream.write_all(&buf[1..]).await.unwrap();
+        let (tags, _) = read_until_ready(&mut stream).await;
+        assert_eq!(tags[0], b'R');
+
+        let mut buf = Vec::new();
+        let sql = format!(
+            "SET extra_float_digits = 3; SELECT a, b FROM read_csv('{}') WHERE a > 1 ORDER BY a",
+            path.display()
+        );
+        MessageWriter::new(&mut buf, b'Q').cstr(&sql).finish();
+        stream.write_all(&buf).await.unwrap();
+        let (tags, rows) = read_until_ready(&mut stream).await;
+        assert_eq!(tags, b"CTDDCZ");
+        assert_eq!(
+            rows,
+            [
+                vec![Some("2".to_string()), Some("y".to_string())],
+                vec![Some("3".to_string()), None],
+            ]
+        );
+
+        let mut buf = Vec::new();
+        MessageWriter::new(&mut buf, b'Q')
+            .cstr("DROP TABLE t")
+            .finish();
+        stream.write_all(&buf).await.unwrap();
+        let (tags, _) = read_until_ready(&mut stream).await;
+        assert_eq!(tags, b"EZ");
+
+        let mut buf = Vec::new();
+        MessageWriter::new(&mut buf, b'X').finish();
+        stream.write_all(&buf).await.unwrap();
+        assert_eq!(stream.read(&mut [0; 1]).await.unwrap(), 0);
+    }
+}
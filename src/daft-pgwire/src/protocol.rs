@@ -0,0 +1,213 @@
+//! Framing of the messages of the Postgres frontend/backend protocol, version 3.0.
+//!
+//! See <https://www.postgresql.org/docs/current/protocol-message-formats.html>.
+
+use std::io;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+pub(crate) const PROTOCOL_VERSION_3: i32 = 196_608;
+pub(crate) const SSL_REQUEST_CODE: i32 = 80_877_103;
+pub(crate) const GSSENC_REQUEST_CODE: i32 = 80_877_104;
+pub(crate) const CANCEL_REQUEST_CODE: i32 = 80_877_102;
+
+/// Messages larger than this are rejected rather than buffered
+const MAX_MESSAGE_LENGTH: usize = 64 * 1024 * 1024;
+
+fn invalid_data(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
+
+async fn read_body<R: AsyncRead + Unpin>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let len = reader.read_i32().await?;
+    let len = usize::try_from(len)
+        .ok()
+        .filter(|len| (4..=MAX_MESSAGE_LENGTH).contains(len))
+        .ok_or_else(|| invalid_data(format!("Invalid message length {len}")))?;
+    let mut body = vec![0; len - 4];
+    reader.read_exact(&mut body).await?;
+    Ok(body)
+}
+
+/// Reads the untyped message that starts a connection, returning its request code and body
+pub(crate) async fn read_startup<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> io::Result<(i32, MessageReader)> {
+    let mut body = MessageReader::new(read_body(reader).await?);
+    let code = body.read_i32()?;
+    Ok((code, body))
+}
+
+/// Reads a typed message, returning `None` once the client closed the connection
+pub(crate) async fn read_message<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> io::Result<Option<(u8, MessageReader)>> {
+    let tag = match reader.read_u8().await {
+        Ok(tag) => tag,
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let body = read_body(reader).await?;
+    Ok(Some((tag, MessageReader::new(body))))
+}
+
+/// Reads the fields of a message body
+pub(crate) struct MessageReader {
+    body: Vec<u8>,
+    pos: usize,
+}
+
+impl MessageReader {
+    fn new(body: Vec<u8>) -> Self {
+        Self { body, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> io::Result<&[u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.body.len())
+            .ok_or_else(|| invalid_data("Message ended unexpectedly"))?;
+        let bytes = &self.body[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    pub(crate) fn read_u8(&mut self) -> io::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn read_i16(&mut self) -> io::Result<i16> {
+        Ok(i16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn read_i32(&mut self) -> io::Result<i32> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    /// Reads a null-terminated string
+    pub(crate) fn read_cstr(&mut self) -> io::Result<String> {
+        let len = self.body[self.pos..]
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or_else(|| invalid_data("String is not null-terminated"))?;
+        let string = String::from_utf8(self.take(len)?.to_vec())
+            .map_err(|_| invalid_data("String is not valid UTF-8"))?;
+        self.pos += 1;
+        Ok(string)
+    }
+
+    /// Reads a value prefixed by its length, where a length of -1 denotes null
+    pub(crate) fn read_value(&mut self) -> io::Result<Option<Vec<u8>>> {
+        let len = self.read_i32()?;
+        if len < 0 {
+            return Ok(None);
+        }
+        Ok(Some(self.take(len as usize)?.to_vec()))
+    }
+
+    /// Whether the body was read to the end
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pos >= self.body.len()
+    }
+}
+
+/// Appends a message to `buf`, with the length of the body filled in once written
+pub(crate) struct MessageWriter<'a> {
+    buf: &'a mut Vec<u8>,
+    start: usize,
+}
+
+impl<'a> MessageWriter<'a> {
+    pub(crate) fn new(buf: &'a mut Vec<u8>, tag: u8) -> Self {
+        buf.push(tag);
+        let start = buf.len();
+        buf.extend_from_slice(&[0; 4]);
+        Self { buf, start }
+    }
+
+    pub(crate) fn u8(self, value: u8) -> Self {
+        self.buf.push(value);
+        self
+    }
+
+    pub(crate) fn i16(self, value: i16) -> Self {
+        self.buf.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub(crate) fn i32(self, value: i32) -> Self {
+        self.buf.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub(crate) fn cstr(self, value: &str) -> Self {
+        self.buf.extend_from_slice(value.as_bytes());
+        self.buf.push(0);
+        self
+    }
+
+    /// Writes a value prefixed by its length, or a length of -1 for null
+    pub(crate) fn value(self, value: Option<&[u8]>) -> Self {
+        match value {
+            Some(value) => {
+                let this = self.i32(value.len() as i32);
+                this.buf.extend_from_slice(value);
+                this
+            }
+            None => self.i32(-1),
+        }
+    }
+
+    pub(crate) fn finish(self) {
+        let len = (self.buf.len() - self.start) as i32;
+        self.buf[self.start..self.start + 4].copy_from_slice(&len.to_be_bytes());
+    }
+}
+
+/// Appends an `ErrorResponse` with the given SQLSTATE code
+pub(crate) fn write_error(buf: &mut Vec<u8>, code: &str, message: &str) {
+    MessageWriter::new(buf, b'E')
+        .u8(b'S')
+        .cstr("ERROR")
+        .u8(b'V')
+        .cstr("ERROR")
+        .u8(b'C')
+        .cstr(code)
+        .u8(b'M')
+        .cstr(message)
+        .u8(0)
+        .finish();
+}
+
+/// Appends a `ReadyForQuery` reporting that no transaction is open
+pub(crate) fn write_ready_for_query(buf: &mut Vec<u8>) {
+    MessageWriter::new(buf, b'Z').u8(b'I').finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_message_round_trip() {
+        let mut buf = Vec::new();
+        MessageWriter::new(&mut buf, b'B')
+            .cstr("portal")
+            .i16(2)
+            .value(Some(b"abc"))
+            .value(None)
+            .finish();
+
+        let (tag, mut body) = read_message(&mut buf.as_slice()).await.unwrap().unwrap();
+        assert_eq!(tag, b'B');
+        assert_eq!(body.read_cstr().unwrap(), "portal");
+        assert_eq!(body.read_i16().unwrap(), 2);
+        assert_eq!(body.read_value().unwrap(), Some(b"abc".to_vec()));
+        assert_eq!(body.read_value().unwrap(), None);
+        assert!(body.is_empty());
+        assert!(body.read_u8().is_err());
+
+        assert!(read_message(&mut [].as_slice()).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,733 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+    sync::Arc,
+};
+
+use common_daft_config::DaftExecutionConfig;
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::SchemaRef;
+use daft_local_execution::NativeExecutor;
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_micropartition::partitioning::InMemoryPartitionSetCache;
+use daft_sql::{catalog::SQLCatalog, SQLPlanner};
+use daft_table::Table;
+use futures::StreamExt;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+
+use crate::{
+    protocol::{
+        read_message, read_startup, write_error, write_ready_for_query, MessageReader,
+        MessageWriter, CANCEL_REQUEST_CODE, GSSENC_REQUEST_CODE, PROTOCOL_VERSION_3,
+        SSL_REQUEST_CODE,
+    },
+    types::{encode_text, pg_type},
+};
+
+/// SQLSTATE codes of the errors reported to clients
+mod sqlstate {
+    pub(super) const PROTOCOL_VIOLATION: &str = "08P01";
+    pub(super) const FEATURE_NOT_SUPPORTED: &str = "0A000";
+    pub(super) const READ_ONLY_SQL_TRANSACTION: &str = "25006";
+    pub(super) const INVALID_SQL_STATEMENT_NAME: &str = "26000";
+    pub(super) const INVALID_CURSOR_NAME: &str = "34000";
+    pub(super) const SYNTAX_ERROR: &str = "42601";
+    pub(super) const UNDEFINED_OBJECT: &str = "42704";
+    pub(super) const INTERNAL_ERROR: &str = "XX000";
+}
+
+/// The server version reported to clients, which some clients check for features they rely on
+const SERVER_VERSION: &str = "14.0 (Daft)";
+
+/// Size of buffered messages past which they are sent before more rows are encoded
+const FLUSH_THRESHOLD_BYTES: usize = 64 * 1024;
+
+enum Error {
+    Io(io::Error),
+    /// Reported to the client, which may continue using the connection
+    Query {
+        code: &'static str,
+        message: String,
+    },
+}
+
+impl Error {
+    fn query(code: &'static str, message: impl Into<String>) -> Self {
+        Self::Query {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<DaftError> for Error {
+    fn from(err: DaftError) -> Self {
+        Self::query(sqlstate::INTERNAL_ERROR, err.to_string())
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Plans and runs the queries of all connections of a server
+pub(crate) struct QueryEngine {
+    catalog: SQLCatalog,
+    cfg: Arc<DaftExecutionConfig>,
+}
+
+impl QueryEngine {
+    pub(crate) fn new(catalog: SQLCatalog, cfg: Arc<DaftExecutionConfig>) -> Self {
+        Self { catalog, cfg }
+    }
+
+    async fn plan(&self, sql: String) -> Result<LogicalPlanBuilder> {
+        let catalog = self.catalog.clone();
+        // planning may list files of scans
+        tokio::task::spawn_blocking(move || {
+            let mut planner = SQLPlanner::new(catalog);
+            let plan = planner
+                .plan_sql(&sql)
+                .map_err(|e| Error::query(sqlstate::SYNTAX_ERROR, e.to_string()))?;
+            Ok(LogicalPlanBuilder::from(plan).optimize()?)
+        })
+        .await
+        .map_err(|e| Error::query(sqlstate::INTERNAL_ERROR, e.to_string()))?
+    }
+}
+
+/// What a statement does, as far as the server is concerned
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum StatementKind {
+    Empty,
+    /// A query planned and run by Daft
+    Query,
+    /// Reports the value of a run-time parameter
+    Show(String),
+    /// Sets a run-time parameter, which only affects what `SHOW` reports
+    Set {
+        name: String,
+        value: String,
+    },
+    /// A statement accepted without effect, such as a transaction statement, completed with the
+    /// given command tag
+    NoOp(&'static str),
+}
+
+impl StatementKind {
+    fn classify(sql: &str) -> Result<Self> {
+        let words = sql.split_whitespace().collect::<Vec<_>>();
+        let Some(first) = words.first() else {
+            return Ok(Self::Empty);
+        };
+        let kind = match first.to_ascii_uppercase().as_str() {
+            "SELECT" | "WITH" | "(" => Self::Query,
+            "SHOW" if words.len() == 2 => Self::Show(words[1].to_ascii_lowercase()),
+            "SET" => Self::parse_set(sql),
+            "BEGIN" | "START" => Self::NoOp("BEGIN"),
+            "COMMIT" | "END" => Self::NoOp("COMMIT"),
+            "ROLLBACK" | "ABORT" => Self::NoOp("ROLLBACK"),
+            "DISCARD" => Self::NoOp("DISCARD ALL"),
+            "RESET" => Self::NoOp("RESET"),
+            "DEALLOCATE" => Self::NoOp("DEALLOCATE"),
+            _ if first.starts_with('(') => Self::Query,
+            _ => {
+                return Err(Error::query(
+                    sqlstate::READ_ONLY_SQL_TRANSACTION,
+                    "Only SELECT queries are supported by the Daft Postgres server",
+                ))
+            }
+        };
+        Ok(kind)
+    }
+
+    /// Parses `SET [SESSION | LOCAL] name { TO | = } value`
+    fn parse_set(sql: &str) -> Self {
+        let rest = sql.trim()[3..].trim_start();
+        let rest = ["SESSION ", "LOCAL "]
+            .iter()
+            .find_map(|scope| {
+                rest.get(..scope.len())
+                    .filter(|prefix| prefix.eq_ignore_ascii_case(scope))
+                    .map(|_| rest[scope.len()..].trim_start())
+            })
+            .unwrap_or(rest);
+        let (name, value) = match rest.split_once('=') {
+            Some((name, value)) => (name, value),
+            None => {
+                let mut parts = rest.splitn(3, char::is_whitespace);
+                let name = parts.next().unwrap_or_default();
+                let _to = parts.next();
+                (name, parts.next().unwrap_or_default())
+            }
+        };
+        let value = value.trim().trim_matches(|c| c == '\'' || c == '"');
+        Self::Set {
+            name: name.trim().to_ascii_lowercase(),
+            value: value.to_string(),
+        }
+    }
+}
+
+/// Splits a simple query into its statements, leaving semicolons in quotes and comments alone
+fn split_statements(sql: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut quote = None;
+    let mut in_comment = false;
+    let mut chars = sql.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        match (quote, c) {
+            _ if in_comment => in_comment = c != '\n',
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '-') if matches!(chars.peek(), Some((_, '-'))) => in_comment = true,
+            (None, ';') => {
+                statements.push(sql[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    statements.push(sql[start..].trim());
+    statements.retain(|statement| !statement.is_empty());
+    statements
+}
+
+struct PreparedStatement {
+    sql: String,
+    kind: StatementKind,
+    /// Planned when the statement is first described or executed
+    plan: Option<LogicalPlanBuilder>,
+}
+
+struct Portal {
+    statement: String,
+    /// Encoded rows left over from an execution limited to a number of rows
+    pending_rows: Option<VecDeque<Vec<u8>>>,
+    num_sent: usize,
+}
+
+/// The state of a client connection, which is served until the client terminates it
+struct Session<W> {
+    engine: Arc<QueryEngine>,
+    writer: W,
+    buf: Vec<u8>,
+    parameters: HashMap<String, String>,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+    /// Set after an error in the extended query protocol, until the client sends `Sync`
+    skip_until_sync: bool,
+}
+
+/// Serves a client connection until it is closed
+pub(crate) async fn serve_connection<S>(stream: S, engine: Arc<QueryEngine>) -> io::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, writer) = tokio::io::split(stream);
+    let mut reader = BufReader::new(reader);
+    let mut session = Session {
+        engine,
+        writer,
+        buf: Vec::new(),
+        parameters: HashMap::from(
+            [
+                ("server_version", SERVER_VERSION),
+                ("server_encoding", "UTF8"),
+                ("client_encoding", "UTF8"),
+                ("DateStyle", "ISO, MDY"),
+                ("TimeZone", "UTC"),
+                ("integer_datetimes", "on"),
+                ("standard_conforming_strings", "on"),
+                ("transaction_isolation", "read committed"),
+            ]
+            .map(|(name, value)| (name.to_string(), value.to_string())),
+        ),
+        statements: HashMap::new(),
+        portals: HashMap::new(),
+        skip_until_sync: false,
+    };
+
+    if !session.startup(&mut reader).await? {
+        return Ok(());
+    }
+    while let Some((tag, body)) = read_message(&mut reader).await? {
+        if tag == b'X' {
+            break;
+        }
+        if session.skip_until_sync && tag != b'S' {
+            continue;
+        }
+        match session.handle_message(tag, body).await {
+            Ok(()) => {}
+            Err(Error::Io(e)) => return Err(e),
+            Err(Error::Query { code, message }) => {
+                write_error(&mut session.buf, code, &message);
+                if tag == b'Q' {
+                    write_ready_for_query(&mut session.buf);
+                } else {
+                    session.skip_until_sync = true;
+                }
+            }
+        }
+        session.flush().await?;
+    }
+    Ok(())
+}
+
+impl<W: AsyncWrite + Unpin> Session<W> {
+    async fn flush(&mut self) -> io::Result<()> {
+        if !self.buf.is_empty() {
+            self.writer.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        self.writer.flush().await
+    }
+
+    /// Negotiates the start of the connection, returning whether the client may send queries
+    async fn startup<R: AsyncRead + Unpin>(&mut self, reader: &mut R) -> io::Result<bool> {
+        let mut body = loop {
+            let (code, body) = read_startup(reader).await?;
+            match code {
+                // encryption is not supported, so clients continue unencrypted
+                SSL_REQUEST_CODE | GSSENC_REQUEST_CODE => self.writer.write_all(b"N").await?,
+                // queries run to completion, as there is no key to cancel them by
+                CANCEL_REQUEST_CODE => return Ok(false),
+                PROTOCOL_VERSION_3 => break body,
+                code => {
+                    write_error(
+                        &mut self.buf,
+                        sqlstate::PROTOCOL_VIOLATION,
+                        &format!("Unsupported protocol version {code}"),
+                    );
+                    self.flush().await?;
+                    return Ok(false);
+                }
+            }
+        };
+        while !body.is_empty() {
+            let name = body.read_cstr()?;
+            if name.is_empty() {
+                break;
+            }
+            let value = body.read_cstr()?;
+            if name == "application_name" {
+                self.parameters.insert(name, value);
+            }
+        }
+
+        // authentication succeeds without a password
+        MessageWriter::new(&mut self.buf, b'R').i32(0).finish();
+        for (name, value) in &self.parameters {
+            MessageWriter::new(&mut self.buf, b'S')
+                .cstr(name)
+                .cstr(value)
+                .finish();
+        }
+        MessageWriter::new(&mut self.buf, b'K')
+            .i32(std::process::id() as i32)
+            .i32(0)
+            .finish();
+        write_ready_for_query(&mut self.buf);
+        self.flush().await?;
+        Ok(true)
+    }
+
+    async fn handle_message(&mut self, tag: u8, mut body: MessageReader) -> Result<()> {
+        match tag {
+            b'Q' => {
+                let sql = body.read_cstr()?;
+                self.simple_query(&sql).await?;
+                write_ready_for_query(&mut self.buf);
+            }
+            b'P' => {
+                let name = body.read_cstr()?;
+                let sql = body.read_cstr()?;
+                let kind = StatementKind::classify(&sql)?;
+                self.statements.insert(
+                    name,
+                    PreparedStatement {
+                        sql,
+                        kind,
+                        plan: None,
+                    },
+                );
+                MessageWriter::new(&mut self.buf, b'1').finish();
+            }
+            b'B' => {
+                let portal = body.read_cstr()?;
+                let statement = body.read_cstr()?;
+                let num_param_formats = body.read_i16()?;
+                for _ in 0..num_param_formats {
+                    body.read_i16()?;
+                }
+                let num_params = body.read_i16()?;
+                for _ in 0..num_params {
+                    body.read_value()?;
+                }
+                if num_params != 0 {
+                    return Err(Error::query(
+                        sqlstate::FEATURE_NOT_SUPPORTED,
+                        "Statement parameters are not supported",
+                    ));
+                }
+                let num_result_formats = body.read_i16()?;
+                for _ in 0..num_result_formats {
+                    if body.read_i16()? != 0 {
+                        return Err(Error::query(
+                            sqlstate::FEATURE_NOT_SUPPORTED,
+                            "Only the text format is supported for results",
+                        ));
+                    }
+                }
+                self.statement(&statement)?;
+                self.portals.insert(
+                    portal,
+                    Portal {
+                        statement,
+                        pending_rows: None,
+                        num_sent: 0,
+                    },
+                );
+                MessageWriter::new(&mut self.buf, b'2').finish();
+            }
+            b'D' => {
+                let target = body.read_u8()?;
+                let name = body.read_cstr()?;
+                let statement = if target == b'S' {
+                    MessageWriter::new(&mut self.buf, b't').i16(0).finish();
+                    name
+                } else {
+                    self.portal(&name)?.statement.clone()
+                };
+                self.describe(&statement).await?;
+            }
+            b'E' => {
+                let portal = body.read_cstr()?;
+                let max_rows = body.read_i32()?;
+                self.execute_portal(&portal, max_rows).await?;
+            }
+            b'C' => {
+                let target = body.read_u8()?;
+                let name = body.read_cstr()?;
+                if target == b'S' {
+                    self.statements.remove(&name);
+                    self.portals.retain(|_, portal| portal.statement != name);
+                } else {
+                    self.portals.remove(&name);
+                }
+                MessageWriter::new(&mut self.buf, b'3').finish();
+            }
+            b'S' => {
+                self.skip_until_sync = false;
+                write_ready_for_query(&mut self.buf);
+            }
+            // messages are flushed after every message anyway
+            b'H' => {}
+            tag => {
+                return Err(Error::query(
+                    sqlstate::PROTOCOL_VIOLATION,
+                    format!("Unsupported message type '{}'", tag as char),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    async fn simple_query(&mut self, sql: &str) -> Result<()> {
+        let statements = split_statements(sql);
+        if statements.is_empty() {
+            MessageWriter::new(&mut self.buf, b'I').finish();
+        }
+        for sql in statements {
+            match StatementKind::classify(sql)? {
+                StatementKind::Query => {
+                    let plan = self.engine.plan(sql.to_string()).await?;
+                    self.write_row_description(&plan.schema());
+                    let num_rows = self.stream_rows(&plan).await?;
+                    self.write_command_complete(&format!("SELECT {num_rows}"));
+                }
+                kind => self.run_command(&kind)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            Error::query(
+                sqlstate::INVALID_SQL_STATEMENT_NAME,
+                format!("Prepared statement \"{name}\" does not exist"),
+            )
+        })
+    }
+
+    fn portal(&self, name: &str) -> Result<&Portal> {
+        self.portals.get(name).ok_or_else(|| {
+            Error::query(
+                sqlstate::INVALID_CURSOR_NAME,
+                format!("Portal \"{name}\" does not exist"),
+            )
+        })
+    }
+
+    /// Plans a prepared statement, unless it was already planned
+    async fn plan_statement(&mut self, name: &str) -> Result<Option<LogicalPlanBuilder>> {
+        let statement = self.statement(name)?;
+        if statement.kind != StatementKind::Query {
+            return Ok(None);
+        }
+        if let Some(plan) = &statement.plan {
+            return Ok(Some(plan.clone()));
+        }
+        let plan = self.engine.plan(statement.sql.clone()).await?;
+        if let Some(statement) = self.statements.get_mut(name) {
+            statement.plan = Some(plan.clone());
+        }
+        Ok(Some(plan))
+    }
+
+    async fn describe(&mut self, statement: &str) -> Result<()> {
+        let kind = self.statement(statement)?.kind.clone();
+        match (kind, self.plan_statement(statement).await?) {
+            (_, Some(plan)) => self.write_row_description(&plan.schema()),
+            (StatementKind::Show(name), _) => self.write_show_description(&name),
+            _ => MessageWriter::new(&mut self.buf, b'n').finish(),
+        }
+        Ok(())
+    }
+
+    async fn execute_portal(&mut self, name: &str, max_rows: i32) -> Result<()> {
+        let statement = self.portal(name)?.statement.clone();
+        let kind = self.statement(&statement)?.kind.clone();
+        let Some(plan) = self.plan_statement(&statement).await? else {
+            if let StatementKind::Show(name) = &kind {
+                // the row description was sent when the portal was described
+                let value = self.show_value(name)?;
+                MessageWriter::new(&mut self.buf, b'D')
+                    .i16(1)
+                    .value(Some(value.as_bytes()))
+                    .finish();
+                self.write_command_complete("SHOW");
+                return Ok(());
+            }
+            return self.run_command(&kind);
+        };
+
+        let max_rows = usize::try_from(max_rows).unwrap_or(0);
+        let portal = self.portal(name)?;
+        if max_rows == 0 && portal.pending_rows.is_none() {
+            let num_rows = self.stream_rows(&plan).await?;
+            self.write_command_complete(&format!("SELECT {num_rows}"));
+            return Ok(());
+        }
+
+        // Executions limited to a number of rows keep the remaining rows for the next execution
+        let pending_rows = self
+            .portals
+            .get_mut(name)
+            .and_then(|portal| portal.pending_rows.take());
+        let mut pending_rows = match pending_rows {
+            Some(rows) => rows,
+            None => self.collect_rows(&plan).await?,
+        };
+        let num_to_send = if max_rows == 0 {
+            pending_rows.len()
+        } else {
+            max_rows.min(pending_rows.len())
+        };
+        for row in pending_rows.drain(..num_to_send) {
+            self.buf.extend_from_slice(&row);
+        }
+        let portal = self
+            .portals
+            .get_mut(name)
+            .expect("portal was looked up above");
+        portal.num_sent += num_to_send;
+        if pending_rows.is_empty() {
+            let num_sent = portal.num_sent;
+            portal.num_sent = 0;
+            self.write_command_complete(&format!("SELECT {num_sent}"));
+        } else {
+            portal.pending_rows = Some(pending_rows);
+            MessageWriter::new(&mut self.buf, b's').finish();
+        }
+        Ok(())
+    }
+
+    fn run_command(&mut self, kind: &StatementKind) -> Result<()> {
+        match kind {
+            StatementKind::Empty => MessageWriter::new(&mut self.buf, b'I').finish(),
+            StatementKind::Query => unreachable!("queries are not commands"),
+            StatementKind::Show(name) => {
+                let value = self.show_value(name)?;
+                self.write_show_description(name);
+                MessageWriter::new(&mut self.buf, b'D')
+                    .i16(1)
+                    .value(Some(value.as_bytes()))
+                    .finish();
+                self.write_command_complete("SHOW");
+            }
+            StatementKind::Set { name, value } => {
+                self.parameters.insert(name.clone(), value.clone());
+                self.write_command_complete("SET");
+            }
+            StatementKind::NoOp(tag) => self.write_command_complete(tag),
+        }
+        Ok(())
+    }
+
+    fn show_value(&self, name: &str) -> Result<String> {
+        self.parameters
+            .iter()
+            .find(|(parameter, _)| parameter.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| {
+                Error::query(
+                    sqlstate::UNDEFINED_OBJECT,
+                    format!("Unrecognized configuration parameter \"{name}\""),
+                )
+            })
+    }
+
+    fn write_show_description(&mut self, name: &str) {
+        let (oid, size) = pg_type(&daft_core::datatypes::DataType::Utf8);
+        MessageWriter::new(&mut self.buf, b'T')
+            .i16(1)
+            .cstr(name)
+            .i32(0)
+            .i16(0)
+            .i32(oid)
+            .i16(size)
+            .i32(-1)
+            .i16(0)
+            .finish();
+    }
+
+    fn write_row_description(&mut self, schema: &SchemaRef) {
+        let mut message = MessageWriter::new(&mut self.buf, b'T').i16(schema.len() as i16);
+        for field in schema.fields.values() {
+            let (oid, size) = pg_type(&field.dtype);
+            message = message
+                .cstr(&field.name)
+                .i32(0)
+                .i16(0)
+                .i32(oid)
+                .i16(size)
+                .i32(-1)
+                .i16(0);
+        }
+        message.finish();
+    }
+
+    fn write_command_complete(&mut self, tag: &str) {
+        MessageWriter::new(&mut self.buf, b'C').cstr(tag).finish();
+    }
+
+    /// Runs a query, sending its rows as they are produced and returning the number of rows
+    async fn stream_rows(&mut self, plan: &LogicalPlanBuilder) -> Result<usize> {
+        let executor = NativeExecutor::from_logical_plan_builder(plan)?;
+        let mut results = executor
+            .run(
+                &InMemoryPartitionSetCache::empty(),
+                self.engine.cfg.clone(),
+                None,
+            )?
+            .into_stream();
+        let mut num_rows = 0;
+        while let Some(partition) = results.next().await {
+            for table in partition?.get_tables()?.iter() {
+                for row in 0..table.len() {
+                    write_data_row(table, row, &mut self.buf)?;
+                    if self.buf.len() >= FLUSH_THRESHOLD_BYTES {
+                        self.writer.write_all(&self.buf).await?;
+                        self.buf.clear();
+                    }
+                }
+                num_rows += table.len();
+            }
+        }
+        Ok(num_rows)
+    }
+
+    /// Runs a query, returning its encoded rows
+    async fn collect_rows(&self, plan: &LogicalPlanBuilder) -> Result<VecDeque<Vec<u8>>> {
+        let executor = NativeExecutor::from_logical_plan_builder(plan)?;
+        let mut results = executor
+            .run(
+                &InMemoryPartitionSetCache::empty(),
+                self.engine.cfg.clone(),
+                None,
+            )?
+            .into_stream();
+        let mut rows = VecDeque::new();
+        while let Some(partition) = results.next().await {
+            for table in partition?.get_tables()?.iter() {
+                for row in 0..table.len() {
+                    let mut buf = Vec::new();
+                    write_data_row(table, row, &mut buf)?;
+                    rows.push_back(buf);
+                }
+            }
+        }
+        Ok(rows)
+    }
+}
+
+/// Appends a `DataRow` with the values of a row of `table` in the text format
+fn write_data_row(table: &Table, row: usize, buf: &mut Vec<u8>) -> DaftResult<()> {
+    let mut message = MessageWriter::new(buf, b'D').i16(table.num_columns() as i16);
+    for idx in 0..table.num_columns() {
+        let value = encode_text(table.get_column_by_index(idx)?, row)?;
+        message = message.value(value.as_deref());
+    }
+    message.finish();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_statements() {
+        assert_eq!(
+            split_statements("SET a = 'x;y'; SELECT 1 -- done;\n; "),
+            ["SET a = 'x;y'", "SELECT 1 -- done;"]
+        );
+        assert!(split_statements(" ; ").is_empty());
+    }
+
+    #[test]
+    fn test_classify_statements() {
+        assert_eq!(
+            StatementKind::classify("select * from t").ok(),
+            Some(StatementKind::Query)
+        );
+        assert_eq!(
+            StatementKind::classify("SET SESSION extra_float_digits TO 3").ok(),
+            Some(StatementKind::Set {
+                name: "extra_float_digits".to_string(),
+                value: "3".to_string()
+            })
+        );
+        assert_eq!(
+            StatementKind::classify("SET application_name = 'Metabase'").ok(),
+            Some(StatementKind::Set {
+                name: "application_name".to_string(),
+                value: "Metabase".to_string()
+            })
+        );
+        assert_eq!(
+            StatementKind::classify("SHOW TimeZone").ok(),
+            Some(StatementKind::Show("timezone".to_string()))
+        );
+        assert!(StatementKind::classify("DROP TABLE t").is_err());
+    }
+}
@@ -0,0 +1,118 @@
+//! Mapping of Daft types to Postgres types, and encoding of values in the Postgres text format.
+
+use common_display::table_display::StrValue;
+use common_error::DaftResult;
+use daft_core::{datatypes::DataType, series::Series};
+
+const BOOL_OID: i32 = 16;
+const BYTEA_OID: i32 = 17;
+const INT8_OID: i32 = 20;
+const INT2_OID: i32 = 21;
+const INT4_OID: i32 = 23;
+const TEXT_OID: i32 = 25;
+const FLOAT4_OID: i32 = 700;
+const FLOAT8_OID: i32 = 701;
+const DATE_OID: i32 = 1082;
+const TIME_OID: i32 = 1083;
+const TIMESTAMP_OID: i32 = 1114;
+const TIMESTAMPTZ_OID: i32 = 1184;
+const NUMERIC_OID: i32 = 1700;
+
+/// The OID and size of the Postgres type that values of `dtype` are sent as. Types without a
+/// Postgres counterpart or whose formatting differs from Postgres, such as nested types and
+/// durations, are sent as text.
+pub(crate) fn pg_type(dtype: &DataType) -> (i32, i16) {
+    match dtype {
+        DataType::Boolean => (BOOL_OID, 1),
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => (INT2_OID, 2),
+        DataType::Int32 | DataType::UInt16 => (INT4_OID, 4),
+        DataType::Int64 | DataType::UInt32 => (INT8_OID, 8),
+        DataType::UInt64 | DataType::Decimal128(..) => (NUMERIC_OID, -1),
+        DataType::Float32 => (FLOAT4_OID, 4),
+        DataType::Float64 => (FLOAT8_OID, 8),
+        DataType::Binary | DataType::FixedSizeBinary(_) => (BYTEA_OID, -1),
+        DataType::Date => (DATE_OID, 4),
+        DataType::Time(_) => (TIME_OID, 8),
+        DataType::Timestamp(_, None) => (TIMESTAMP_OID, 8),
+        DataType::Timestamp(_, Some(_)) => (TIMESTAMPTZ_OID, 8),
+        _ => (TEXT_OID, -1),
+    }
+}
+
+/// Encodes the value at `idx` in the Postgres text format, or `None` if it is null
+pub(crate) fn encode_text(series: &Series, idx: usize) -> DaftResult<Option<Vec<u8>>> {
+    if !series.is_valid(idx) || series.data_type() == &DataType::Null {
+        return Ok(None);
+    }
+    let text = match series.data_type() {
+        DataType::Boolean => match series.bool()?.get(idx) {
+            Some(true) => "t".to_string(),
+            Some(false) => "f".to_string(),
+            None => return Ok(None),
+        },
+        DataType::Binary => match series.binary()?.get(idx) {
+            Some(bytes) => encode_bytea(bytes),
+            None => return Ok(None),
+        },
+        DataType::FixedSizeBinary(_) => match series.fixed_size_binary()?.get(idx) {
+            Some(bytes) => encode_bytea(bytes),
+            None => return Ok(None),
+        },
+        _ => series.str_value(idx),
+    };
+    Ok(Some(text.into_bytes()))
+}
+
+/// Encodes bytes in the hex format of `bytea`
+fn encode_bytea(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    let mut text = String::with_capacity(2 + bytes.len() * 2);
+    text.push_str("\\x");
+    for byte in bytes {
+        write!(text, "{byte:02x}").unwrap();
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+
+    use super::*;
+
+    fn encode_all(series: &Series) -> Vec<Option<String>> {
+        (0..series.len())
+            .map(|idx| {
+                encode_text(series, idx)
+                    .unwrap()
+                    .map(|bytes| String::from_utf8(bytes).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_text() {
+        let ints = Int64Array::from(("a", vec![1, -2])).into_series();
+        assert_eq!(pg_type(ints.data_type()), (INT8_OID, 8));
+        assert_eq!(
+            encode_all(&ints),
+            [Some("1".to_string()), Some("-2".to_string())]
+        );
+
+        let bools =
+            BooleanArray::from(("b", [Some(true), None, Some(false)].as_slice())).into_series();
+        assert_eq!(
+            encode_all(&bools),
+            [Some("t".to_string()), None, Some("f".to_string())]
+        );
+
+        let bytes = BinaryArray::from(("c", b"\x01\xab".as_slice())).into_series();
+        assert_eq!(pg_type(bytes.data_type()).0, BYTEA_OID);
+        assert_eq!(encode_all(&bytes), [Some("\\x01ab".to_string())]);
+
+        let strings = Utf8Array::from(("d", ["x y"].as_slice())).into_series();
+        assert_eq!(pg_type(strings.data_type()).0, TEXT_OID);
+        assert_eq!(encode_all(&strings), [Some("x y".to_string())]);
+    }
+}
@@ -18,6 +18,7 @@ impl TreeDisplay for PhysicalPlan {
             Self::Unpivot(unpivot) => unpivot.display_as(level),
             Self::Sort(sort) => sort.display_as(level),
             Self::Sample(sample) => sample.display_as(level),
+            Self::AssertUnique(assert_unique) => assert_unique.display_as(level),
             Self::MonotonicallyIncreasingId(id) => id.display_as(level),
             Self::ShuffleExchange(shuffle_exchange) => shuffle_exchange.display_as(level),
             Self::Aggregate(aggr) => aggr.display_as(level),
@@ -0,0 +1,29 @@
+use daft_dsl::ExprRef;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::PhysicalPlanRef;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AssertUnique {
+    // Upstream node, which must be partitioned so that equal keys are in the same partition.
+    pub input: PhysicalPlanRef,
+    pub keys: Vec<ExprRef>,
+}
+
+impl AssertUnique {
+    pub(crate) fn new(input: PhysicalPlanRef, keys: Vec<ExprRef>) -> Self {
+        Self { input, keys }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "AssertUnique: {}",
+            self.keys.iter().map(|e| e.to_string()).join(", ")
+        ));
+        res
+    }
+}
+
+crate::impl_default_tree_display!(AssertUnique);
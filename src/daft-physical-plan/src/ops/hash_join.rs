@@ -15,6 +15,29 @@ pub struct HashJoin {
     pub right_on: Vec<ExprRef>,
     pub null_equals_nulls: Option<Vec<bool>>,
     pub join_type: JoinType,
+    /// If set, the inputs aren't partitioned by the join keys yet, and the join partitions them
+    /// itself while spreading the rows of hot keys across several partitions.
+    pub skew_salting: Option<SkewSalting>,
+    /// Whether the inputs are already hash partitioned by the join keys, so that neither is
+    /// shuffled. If one has more partitions than the other, its number of partitions is a multiple
+    /// of the other's, and each of its partitions is joined with the partition of the other side
+    /// that holds the same keys.
+    pub co_partitioned: bool,
+}
+
+/// Salting of the hot keys of one side of a hash join, so that a few keys with many rows don't
+/// end up in a single, much larger partition.
+///
+/// The hot keys are found in a sample of the salted side. Their rows on the salted side are spread
+/// across `salt_factor` partitions, and their rows on the other side are copied to each of those
+/// partitions. The join type must preserve the rows of the other side at most once, i.e. the
+/// other side can't be an outer side of the join.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SkewSalting {
+    pub num_partitions: usize,
+    pub salt_factor: usize,
+    pub heavy_hitter_threshold: f64,
+    pub salt_left: bool,
 }
 
 impl HashJoin {
@@ -33,9 +56,23 @@ impl HashJoin {
             right_on,
             null_equals_nulls,
             join_type,
+            skew_salting: None,
+            co_partitioned: false,
         }
     }
 
+    #[must_use]
+    pub fn with_skew_salting(mut self, skew_salting: Option<SkewSalting>) -> Self {
+        self.skew_salting = skew_salting;
+        self
+    }
+
+    #[must_use]
+    pub fn with_co_partitioned(mut self, co_partitioned: bool) -> Self {
+        self.co_partitioned = co_partitioned;
+        self
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         res.push(format!("HashJoin: Type = {}", self.join_type));
@@ -64,6 +101,21 @@ impl HashJoin {
                 null_equals_nulls.iter().map(|b| b.to_string()).join(", ")
             ));
         }
+        if let Some(skew_salting) = &self.skew_salting {
+            res.push(format!(
+                "Skew salting = {} side, salt factor {}, {} partitions",
+                if skew_salting.salt_left {
+                    "left"
+                } else {
+                    "right"
+                },
+                skew_salting.salt_factor,
+                skew_salting.num_partitions,
+            ));
+        }
+        if self.co_partitioned {
+            res.push("Co-partitioned = true".to_string());
+        }
         res
     }
 }
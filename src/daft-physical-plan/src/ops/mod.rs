@@ -1,5 +1,6 @@
 mod actor_pool_project;
 mod agg;
+mod assert_unique;
 mod broadcast_join;
 mod concat;
 mod cross_join;
@@ -30,6 +31,7 @@ mod unpivot;
 
 pub use actor_pool_project::ActorPoolProject;
 pub use agg::Aggregate;
+pub use assert_unique::AssertUnique;
 pub use broadcast_join::BroadcastJoin;
 pub use concat::Concat;
 pub use cross_join::CrossJoin;
@@ -39,7 +41,7 @@ pub use deltalake_write::DeltaLakeWrite;
 pub use empty_scan::EmptyScan;
 pub use explode::Explode;
 pub use filter::Filter;
-pub use hash_join::HashJoin;
+pub use hash_join::{HashJoin, SkewSalting};
 #[cfg(feature = "python")]
 pub use iceberg_write::IcebergWrite;
 pub use in_memory::InMemoryScan;
@@ -31,6 +31,7 @@ impl ShuffleExchange {
             ShuffleExchangeStrategy::MapReduceWithPreShuffleMerge { target_spec, .. } => {
                 target_spec.clone()
             }
+            ShuffleExchangeStrategy::FlightShuffle { target_spec } => target_spec.clone(),
         }
     }
 }
@@ -47,6 +48,10 @@ pub enum ShuffleExchangeStrategy {
         pre_shuffle_merge_threshold: usize,
         target_spec: Arc<ClusteringSpec>,
     },
+
+    /// Hash partitions the outputs of the Map into the shuffle caches of the workers, from which
+    /// the Reduce fetches its partitions over Arrow Flight. Only supports hash partitioning.
+    FlightShuffle { target_spec: Arc<ClusteringSpec> },
 }
 
 impl ShuffleExchange {
@@ -62,6 +67,7 @@ impl ShuffleExchange {
                     self.input.clustering_spec().num_partitions(),
                     target_spec.num_partitions(),
                 ));
+                res.extend(self.range_boundaries_display(target_spec));
             }
             ShuffleExchangeStrategy::SplitOrCoalesceToTargetNum {
                 target_num_partitions,
@@ -87,10 +93,33 @@ impl ShuffleExchange {
                     self.input.clustering_spec().num_partitions(),
                     target_spec.num_partitions(),
                 ));
+                res.extend(self.range_boundaries_display(target_spec));
+            }
+            ShuffleExchangeStrategy::FlightShuffle { target_spec } => {
+                res.push("Strategy: FlightShuffle".to_string());
+                res.push(format!("Target Spec: {:?}", target_spec));
+                res.push(format!(
+                    "Number of Partitions: {} → {}",
+                    self.input.clustering_spec().num_partitions(),
+                    target_spec.num_partitions(),
+                ));
             }
         }
         res
     }
+
+    /// Describes how the boundaries of a range partitioning are computed, since they are only
+    /// known at execution time.
+    fn range_boundaries_display(&self, target_spec: &ClusteringSpec) -> Option<String> {
+        match target_spec {
+            ClusteringSpec::Range(RangeClusteringConfig { num_partitions, .. }) => Some(format!(
+                "Range Boundaries: {} boundaries, sampled from {} input partitions",
+                num_partitions.saturating_sub(1),
+                self.input.clustering_spec().num_partitions(),
+            )),
+            _ => None,
+        }
+    }
 }
 
 impl_default_tree_display!(ShuffleExchange);
@@ -120,6 +149,11 @@ impl ShuffleExchangeFactory {
         )));
 
         let strategy = match cfg {
+            Some(cfg) if cfg.shuffle_algorithm == "flight_shuffle" => {
+                ShuffleExchangeStrategy::FlightShuffle {
+                    target_spec: clustering_spec,
+                }
+            }
             Some(cfg) if cfg.shuffle_algorithm == "pre_shuffle_merge" => {
                 ShuffleExchangeStrategy::MapReduceWithPreShuffleMerge {
                     target_spec: clustering_spec,
@@ -38,6 +38,8 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
                 // 2-input nodes
                 // for concat, hash partitioning shouldn't change
                 PhysicalPlan::Concat(..) => return Ok(Transformed::no(c.propagate())),
+                // a skew-salted hash join partitions its inputs itself
+                PhysicalPlan::HashJoin(HashJoin { skew_salting: Some(_), .. }) => return Ok(Transformed::no(c)),
                 // for hash join, send separate partitionings to children
                 PhysicalPlan::HashJoin(HashJoin { left_on, right_on, .. }) => {
                     let (c, old_children) = c.take_children();
@@ -139,11 +141,19 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
                     });
                     Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
                 }
+                PhysicalPlan::ShuffleExchange(ShuffleExchange{input, strategy: ShuffleExchangeStrategy::FlightShuffle { .. }}) => {
+                    let new_plan = PhysicalPlan::ShuffleExchange(ShuffleExchange {
+                        input: input.clone(),
+                        strategy: ShuffleExchangeStrategy::FlightShuffle { target_spec: new_spec.into() }
+                    });
+                    Ok(Transformed::yes(c.with_plan(new_plan.into()).propagate()))
+                }
 
                 // these depend solely on their input
                 PhysicalPlan::Filter(..) |
                 PhysicalPlan::Limit(..) |
                 PhysicalPlan::Sample(..) |
+                PhysicalPlan::AssertUnique(..) |
                 PhysicalPlan::MonotonicallyIncreasingId(..) |
                 PhysicalPlan::Pivot(..) |
                 PhysicalPlan::TabularWriteCsv(..) |
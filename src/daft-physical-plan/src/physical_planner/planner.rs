@@ -39,6 +39,8 @@ pub(super) struct QueryStagePhysicalPlanTranslator {
     pub root: Arc<LogicalPlan>,
     pub cfg: Arc<DaftExecutionConfig>,
     pub source_id: Option<usize>,
+    /// The logical subplan computed by the emitted stage, if it is not the final stage
+    pub stage_logical_plan: Option<LogicalPlanRef>,
 }
 
 fn is_query_stage_boundary(plan: &PhysicalPlan) -> bool {
@@ -83,6 +85,7 @@ impl TreeNodeRewriter for QueryStagePhysicalPlanTranslator {
 
                     assert_eq!(self.source_id, None);
                     self.source_id = Some(ph_info.source_id);
+                    self.stage_logical_plan = Some(node.clone());
 
                     let new_scan = LogicalPlan::Source(Source::new(
                         node.schema(),
@@ -145,6 +148,7 @@ impl TreeNodeRewriter for QueryStagePhysicalPlanTranslator {
 
                             assert_eq!(self.source_id, None);
                             self.source_id = Some(ph_info.source_id);
+                            self.stage_logical_plan = Some(logical_left.clone());
 
                             let new_left_scan = LogicalPlan::Source(Source::new(
                                 logical_left.schema(),
@@ -175,6 +179,7 @@ impl TreeNodeRewriter for QueryStagePhysicalPlanTranslator {
 
                             assert_eq!(self.source_id, None);
                             self.source_id = Some(ph_info.source_id);
+                            self.stage_logical_plan = Some(logical_right.clone());
 
                             let new_right_scan = LogicalPlan::Source(Source::new(
                                 logical_right.schema(),
@@ -302,6 +307,15 @@ impl AdaptivePlanner {
     }
 
     pub fn next_stage(&mut self) -> DaftResult<QueryStageOutput> {
+        self.next_stage_with_logical_plan()
+            .map(|(output, _)| output)
+    }
+
+    /// Like [`Self::next_stage`], but also returns the logical plan that the stage computes, for
+    /// runners that execute logical plans directly
+    pub fn next_stage_with_logical_plan(
+        &mut self,
+    ) -> DaftResult<(QueryStageOutput, LogicalPlanRef)> {
         assert_eq!(self.status, AdaptivePlannerStatus::Ready);
 
         let mut rewriter = QueryStagePhysicalPlanTranslator {
@@ -309,6 +323,7 @@ impl AdaptivePlanner {
             root: self.logical_plan.clone(),
             cfg: self.cfg.clone(),
             source_id: None,
+            stage_logical_plan: None,
         };
         let output = self.logical_plan.clone().rewrite(&mut rewriter)?;
         let physical_plan = rewriter
@@ -330,15 +345,24 @@ impl AdaptivePlanner {
                 "Logical plan remaining:\n {}",
                 self.logical_plan.repr_ascii(true)
             );
-            Ok(QueryStageOutput::Partial {
-                physical_plan,
-                source_id,
-            })
+            let stage_logical_plan = rewriter
+                .stage_logical_plan
+                .expect("If we transformed the plan, the stage should have a logical plan");
+            Ok((
+                QueryStageOutput::Partial {
+                    physical_plan,
+                    source_id,
+                },
+                stage_logical_plan,
+            ))
         } else {
             log::info!("Emitting final plan:\n {}", physical_plan.repr_ascii(true));
 
             self.status = AdaptivePlannerStatus::Done;
-            Ok(QueryStageOutput::Final { physical_plan })
+            Ok((
+                QueryStageOutput::Final { physical_plan },
+                self.logical_plan.clone(),
+            ))
         }
     }
 
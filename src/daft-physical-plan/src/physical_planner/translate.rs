@@ -10,19 +10,23 @@ use common_file_formats::FileFormat;
 use common_scan_info::{PhysicalScanInfo, ScanState, SPLIT_AND_MERGE_PASS};
 use daft_core::{join::JoinSide, prelude::*};
 use daft_dsl::{
-    col, functions::agg::merge_mean, is_partition_compatible, AggExpr, ApproxPercentileParams,
+    col, functions::agg::merge_mean, is_partition_compatible, lit, AggExpr, ApproxPercentileParams,
     Expr, ExprRef, SketchType,
 };
-use daft_functions::{list::unique_count, numeric::sqrt};
+use daft_functions::{
+    list::{slice, sort, unique_count},
+    numeric::sqrt,
+};
 use daft_logical_plan::{
     logical_plan::LogicalPlan,
     ops::{
         ActorPoolProject as LogicalActorPoolProject, Aggregate as LogicalAggregate,
-        Distinct as LogicalDistinct, Explode as LogicalExplode, Filter as LogicalFilter,
-        Join as LogicalJoin, Limit as LogicalLimit,
-        MonotonicallyIncreasingId as LogicalMonotonicallyIncreasingId, Pivot as LogicalPivot,
-        Project as LogicalProject, Repartition as LogicalRepartition, Sample as LogicalSample,
-        Sink as LogicalSink, Sort as LogicalSort, Source, Unpivot as LogicalUnpivot,
+        AssertUnique as LogicalAssertUnique, Distinct as LogicalDistinct,
+        Explode as LogicalExplode, Filter as LogicalFilter, Join as LogicalJoin,
+        Limit as LogicalLimit, MonotonicallyIncreasingId as LogicalMonotonicallyIncreasingId,
+        Pivot as LogicalPivot, Project as LogicalProject, Repartition as LogicalRepartition,
+        Sample as LogicalSample, Sink as LogicalSink, Sort as LogicalSort, Source,
+        TopN as LogicalTopN, Unpivot as LogicalUnpivot,
     },
     partitioning::{
         ClusteringSpec, HashClusteringConfig, RangeClusteringConfig, UnknownClusteringConfig,
@@ -84,10 +88,7 @@ pub(super) fn translate_single_logical_node(
                 }
             }
             SourceInfo::InMemory(mem_info) => {
-                let clustering_spec = mem_info.clustering_spec.clone().unwrap_or_else(|| {
-                    ClusteringSpec::Unknown(UnknownClusteringConfig::new(mem_info.num_partitions))
-                        .into()
-                });
+                let clustering_spec = mem_info.clustering_spec();
 
                 let scan = PhysicalPlan::InMemoryScan(InMemoryScan::new(
                     mem_info.source_schema.clone(),
@@ -100,6 +101,10 @@ pub(super) fn translate_single_logical_node(
             SourceInfo::PlaceHolder(PlaceHolderInfo { source_id, .. }) => {
                 panic!("Placeholder {source_id} should not get to translation. This should have been optimized away");
             }
+            SourceInfo::Stream(stream_info) => Err(DaftError::not_implemented(format!(
+                "Stream {} is unbounded and can only be read by the native runner",
+                stream_info.name
+            ))),
         },
         LogicalPlan::Project(LogicalProject { projection, .. }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
@@ -160,15 +165,26 @@ pub(super) fn translate_single_logical_node(
             ..
         }) => {
             let input_physical = physical_children.pop().expect("requires 1 input");
-            let num_partitions = input_physical.clustering_spec().num_partitions();
-            Ok(PhysicalPlan::Sort(Sort::new(
+            Ok(translate_sort(
                 input_physical,
-                sort_by.clone(),
-                descending.clone(),
-                nulls_first.clone(),
-                num_partitions,
+                sort_by,
+                descending,
+                nulls_first,
             ))
-            .arced())
+        }
+        LogicalPlan::TopN(LogicalTopN {
+            sort_by,
+            descending,
+            nulls_first,
+            limit,
+            ..
+        }) => {
+            // Partitioned execution has no TopN operator, so the input is sorted before the limit
+            // is taken, as it would be without the fusion.
+            let input_physical = physical_children.pop().expect("requires 1 input");
+            let sorted = translate_sort(input_physical, sort_by, descending, nulls_first);
+            let num_partitions = sorted.clustering_spec().num_partitions();
+            Ok(PhysicalPlan::Limit(Limit::new(sorted, *limit, false, num_partitions)).arced())
         }
         LogicalPlan::Repartition(LogicalRepartition {
             repartition_spec, ..
@@ -214,8 +230,15 @@ pub(super) fn translate_single_logical_node(
                         ),
                     )
                 }
-                ClusteringSpec::Range(_) => {
-                    unreachable!("Repartitioning by range is not supported")
+                ClusteringSpec::Range(RangeClusteringConfig { by, descending, .. }) => {
+                    PhysicalPlan::ShuffleExchange(
+                        ShuffleExchangeFactory::new(input_physical).get_range_partitioning(
+                            by,
+                            descending,
+                            num_partitions,
+                            Some(cfg),
+                        ),
+                    )
                 }
             };
             Ok(repartitioned_plan.arced())
@@ -262,6 +285,35 @@ pub(super) fn translate_single_logical_node(
             ))
             .arced())
         }
+        LogicalPlan::AssertUnique(LogicalAssertUnique { keys, .. }) => {
+            let mut input_physical = physical_children.pop().expect("requires 1 input");
+            // Rows with equal keys must be in the same partition to be compared, which holds if
+            // the input is hash partitioned by some of the keys.
+            let clustering_spec = input_physical.clustering_spec();
+            let num_partitions = clustering_spec.num_partitions();
+            let by = clustering_spec.partition_by();
+            let is_partitioned_by_keys =
+                matches!(clustering_spec.as_ref(), ClusteringSpec::Hash(..))
+                    && !by.is_empty()
+                    && by.iter().all(|expr| keys.contains(expr));
+            if num_partitions > 1 && !is_partitioned_by_keys {
+                input_physical = PhysicalPlan::ShuffleExchange(
+                    ShuffleExchangeFactory::new(input_physical).get_hash_partitioning(
+                        keys.clone(),
+                        num_partitions,
+                        Some(cfg),
+                    ),
+                )
+                .arced();
+            }
+            Ok(PhysicalPlan::AssertUnique(AssertUnique::new(input_physical, keys.clone())).arced())
+        }
+        LogicalPlan::WindowAggregate(..) => Err(DaftError::not_implemented(
+            "Window aggregations over event time can only be run by the native runner",
+        )),
+        LogicalPlan::Window(..) => Err(DaftError::not_implemented(
+            "Window functions can only be run by the native runner",
+        )),
         LogicalPlan::Aggregate(LogicalAggregate {
             aggregations,
             groupby,
@@ -434,12 +486,20 @@ pub(super) fn translate_single_logical_node(
                 right_clustering_spec.num_partitions(),
             );
 
-            let is_left_hash_partitioned =
-                matches!(left_clustering_spec.as_ref(), ClusteringSpec::Hash(..))
-                    && is_partition_compatible(&left_clustering_spec.partition_by(), left_on);
-            let is_right_hash_partitioned =
-                matches!(right_clustering_spec.as_ref(), ClusteringSpec::Hash(..))
-                    && is_partition_compatible(&right_clustering_spec.partition_by(), right_on);
+            let is_left_hash_partitioned = is_hash_partitioned_by_keys(
+                &left_clustering_spec,
+                left_on,
+                &left.schema(),
+                right_on,
+                &right.schema(),
+            );
+            let is_right_hash_partitioned = is_hash_partitioned_by_keys(
+                &right_clustering_spec,
+                right_on,
+                &right.schema(),
+                left_on,
+                &left.schema(),
+            );
 
             // Left-side of join is considered to be sort-partitioned on the join key if it is sort-partitioned on a
             // sequence of expressions that has the join key as a prefix.
@@ -633,6 +693,40 @@ pub(super) fn translate_single_logical_node(
                     let num_left_partitions = left_clustering_spec.num_partitions();
                     let num_right_partitions = right_clustering_spec.num_partitions();
 
+                    // Co-partitioned sides are joined without shuffling either of them, even if
+                    // one has a multiple of the other's number of partitions. The partitions of
+                    // the side with fewer partitions are then joined with several partitions of
+                    // the other side, so its rows must be kept at most once.
+                    let fewer = min(num_left_partitions, num_right_partitions);
+                    let more = max(num_left_partitions, num_right_partitions);
+                    let fewer_side_is_kept_once = match join_type {
+                        _ if num_left_partitions == num_right_partitions => true,
+                        JoinType::Inner => true,
+                        JoinType::Left | JoinType::Anti | JoinType::Semi => {
+                            num_right_partitions < num_left_partitions
+                        }
+                        JoinType::Right => num_left_partitions < num_right_partitions,
+                        JoinType::Outer => false,
+                    };
+                    if is_left_hash_partitioned
+                        && is_right_hash_partitioned
+                        && more % fewer == 0
+                        && fewer_side_is_kept_once
+                    {
+                        return Ok(PhysicalPlan::HashJoin(
+                            HashJoin::new(
+                                left_physical,
+                                right_physical,
+                                left_on.clone(),
+                                right_on.clone(),
+                                null_equals_nulls.clone(),
+                                *join_type,
+                            )
+                            .with_co_partitioned(true),
+                        )
+                        .arced());
+                    }
+
                     let num_partitions = match (
                         is_left_hash_partitioned,
                         is_right_hash_partitioned,
@@ -654,9 +748,43 @@ pub(super) fn translate_single_logical_node(
                         (_, _, a, b) => max(a, b),
                     };
 
-                    if num_left_partitions != num_partitions
-                        || (num_partitions > 1 && !is_left_hash_partitioned)
+                    let needs_left_shuffle = num_left_partitions != num_partitions
+                        || (num_partitions > 1 && !is_left_hash_partitioned);
+                    let needs_right_shuffle = num_right_partitions != num_partitions
+                        || (num_partitions > 1 && !is_right_hash_partitioned);
+                    // Hot keys can only be salted on a side whose rows are kept at most once, since
+                    // the matching rows of the other side are copied to several partitions.
+                    let salt_left = match join_type {
+                        JoinType::Inner => Some(left_is_larger),
+                        JoinType::Left | JoinType::Anti | JoinType::Semi => Some(true),
+                        JoinType::Right => Some(false),
+                        JoinType::Outer => None,
+                    };
+                    if cfg.enable_skew_join_salting
+                        && num_partitions > 1
+                        && (needs_left_shuffle || needs_right_shuffle)
+                        && let Some(salt_left) = salt_left
                     {
+                        return Ok(PhysicalPlan::HashJoin(
+                            HashJoin::new(
+                                left_physical,
+                                right_physical,
+                                left_on.clone(),
+                                right_on.clone(),
+                                null_equals_nulls.clone(),
+                                *join_type,
+                            )
+                            .with_skew_salting(Some(SkewSalting {
+                                num_partitions,
+                                salt_factor: cfg.skew_join_salt_factor,
+                                heavy_hitter_threshold: cfg.skew_join_heavy_hitter_threshold,
+                                salt_left,
+                            })),
+                        )
+                        .arced());
+                    }
+
+                    if needs_left_shuffle {
                         left_physical = PhysicalPlan::ShuffleExchange(
                             ShuffleExchangeFactory::new(left_physical).get_hash_partitioning(
                                 left_on.clone(),
@@ -666,9 +794,7 @@ pub(super) fn translate_single_logical_node(
                         )
                         .into();
                     }
-                    if num_right_partitions != num_partitions
-                        || (num_partitions > 1 && !is_right_hash_partitioned)
-                    {
+                    if needs_right_shuffle {
                         right_physical = PhysicalPlan::ShuffleExchange(
                             ShuffleExchangeFactory::new(right_physical).get_hash_partitioning(
                                 right_on.clone(),
@@ -745,14 +871,29 @@ pub(super) fn translate_single_logical_node(
                             ))
                             .arced())
                         }
+                        FileFormat::Xml => Err(common_error::DaftError::ValueError(
+                            "XML sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::Excel => Err(common_error::DaftError::ValueError(
+                            "Excel sink not yet implemented".to_string(),
+                        )),
                         FileFormat::Database => Err(common_error::DaftError::ValueError(
                             "Database sink not yet implemented".to_string(),
                         )),
+                        FileFormat::Kafka => Err(common_error::DaftError::ValueError(
+                            "Kafka sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::Range => Err(common_error::DaftError::ValueError(
+                            "Cannot write to Range file format".to_string(),
+                        )),
                         FileFormat::Python => Err(common_error::DaftError::ValueError(
                             "Cannot write to PythonFunction file format".to_string(),
                         )),
                     }
                 }
+                SinkInfo::CopyInto(_) => Err(DaftError::NotImplemented(
+                    "COPY INTO is only supported by the native runner".to_string(),
+                )),
                 #[cfg(feature = "python")]
                 SinkInfo::CatalogInfo(catalog_info) => {
                     use daft_logical_plan::sink_info::CatalogType;
@@ -804,6 +945,51 @@ pub(super) fn translate_single_logical_node(
     Ok(physical_plan)
 }
 
+/// Sorts the input by the sort keys, unless it's cached data that is already sorted by them and
+/// doesn't need to be sorted again.
+fn translate_sort(
+    input_physical: PhysicalPlanRef,
+    sort_by: &[ExprRef],
+    descending: &[bool],
+    nulls_first: &[bool],
+) -> PhysicalPlanRef {
+    if let PhysicalPlan::InMemoryScan(InMemoryScan { in_memory_info, .. }) = input_physical.as_ref()
+        && in_memory_info.is_sorted_by(sort_by, descending, nulls_first)
+    {
+        return input_physical;
+    }
+    let num_partitions = input_physical.clustering_spec().num_partitions();
+    PhysicalPlan::Sort(Sort::new(
+        input_physical,
+        sort_by.to_vec(),
+        descending.to_vec(),
+        nulls_first.to_vec(),
+        num_partitions,
+    ))
+    .arced()
+}
+
+/// Whether a side of a join with `clustering_spec` is hash partitioned by its join keys `on`, so
+/// that it is co-partitioned with the other side if that side is hash partitioned by its keys
+/// `other_on` too. The partition keys may be in any order, since the physical optimizer reorders
+/// them to the order of the join keys, but both sides' keys must have the same types to hash alike.
+fn is_hash_partitioned_by_keys(
+    clustering_spec: &ClusteringSpec,
+    on: &[ExprRef],
+    schema: &SchemaRef,
+    other_on: &[ExprRef],
+    other_schema: &SchemaRef,
+) -> bool {
+    matches!(clustering_spec, ClusteringSpec::Hash(..))
+        && is_partition_compatible(&clustering_spec.partition_by(), on)
+        && on.iter().zip(other_on).all(|(key, other_key)| {
+            matches!(
+                (key.get_type(schema), other_key.get_type(other_schema)),
+                (Ok(dtype), Ok(other_dtype)) if dtype == other_dtype
+            )
+        })
+}
+
 pub fn extract_agg_expr(expr: &ExprRef) -> DaftResult<AggExpr> {
     match expr.as_ref() {
         Expr::Agg(agg_expr) => Ok(agg_expr.clone()),
@@ -844,6 +1030,13 @@ pub fn extract_agg_expr(expr: &ExprRef) -> DaftResult<AggExpr> {
                 }
                 AggExpr::List(e) => AggExpr::List(Expr::Alias(e, name.clone()).into()),
                 AggExpr::Concat(e) => AggExpr::Concat(Expr::Alias(e, name.clone()).into()),
+                AggExpr::TopK(e, k) => AggExpr::TopK(Expr::Alias(e, name.clone()).into(), k),
+                AggExpr::ApproxHeavyHitters(e, k) => {
+                    AggExpr::ApproxHeavyHitters(Expr::Alias(e, name.clone()).into(), k)
+                }
+                AggExpr::MergeHeavyHitters(e, capacity) => {
+                    AggExpr::MergeHeavyHitters(Expr::Alias(e, name.clone()).into(), capacity)
+                }
                 AggExpr::MapGroups { func, inputs } => AggExpr::MapGroups {
                     func,
                     inputs: inputs
@@ -857,6 +1050,9 @@ pub fn extract_agg_expr(expr: &ExprRef) -> DaftResult<AggExpr> {
     }
 }
 
+/// The number of values kept in the heavy hitters summary of each partition, per value requested.
+const HEAVY_HITTERS_CAPACITY_FACTOR: usize = 10;
+
 /// Given a list of aggregation expressions, return the aggregation expressions to apply in the first and second stages,
 /// as well as the final expressions to project.
 #[allow(clippy::type_complexity)]
@@ -1109,6 +1305,51 @@ pub fn populate_aggregation_stages(
                     ));
                 final_exprs.push(col(concat_of_concat_id.clone()).alias(output_name));
             }
+            &AggExpr::TopK(ref e, k) => {
+                let top_k_id = agg_expr.semantic_id(schema).id;
+                let concat_of_top_k_id = AggExpr::Concat(col(top_k_id.clone()))
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(top_k_id.clone())
+                    .or_insert(AggExpr::TopK(e.alias(top_k_id.clone()), k));
+                second_stage_aggs
+                    .entry(concat_of_top_k_id.clone())
+                    .or_insert(AggExpr::Concat(
+                        col(top_k_id.clone()).alias(concat_of_top_k_id.clone()),
+                    ));
+                // The top k values of each partition include the top k values overall.
+                let sorted = sort(
+                    col(concat_of_top_k_id.clone()),
+                    Some(lit(true)),
+                    Some(lit(false)),
+                );
+                final_exprs.push(slice(sorted, lit(0), lit(k as i64)).alias(output_name));
+            }
+            &AggExpr::ApproxHeavyHitters(ref e, k) => {
+                // Each partition keeps more values than requested so that the merged counts of the
+                // top k values are closer to their true counts.
+                let capacity = k.saturating_mul(HEAVY_HITTERS_CAPACITY_FACTOR);
+                let summary_id = AggExpr::ApproxHeavyHitters(e.clone(), capacity)
+                    .semantic_id(schema)
+                    .id;
+                let merged_id = AggExpr::MergeHeavyHitters(col(summary_id.clone()), capacity)
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(summary_id.clone())
+                    .or_insert(AggExpr::ApproxHeavyHitters(
+                        e.alias(summary_id.clone()),
+                        capacity,
+                    ));
+                second_stage_aggs
+                    .entry(merged_id.clone())
+                    .or_insert(AggExpr::MergeHeavyHitters(
+                        col(summary_id).alias(merged_id.clone()),
+                        capacity,
+                    ));
+                final_exprs.push(slice(col(merged_id), lit(0), lit(k as i64)).alias(output_name));
+            }
             AggExpr::MapGroups { func, inputs } => {
                 let func_id = agg_expr.semantic_id(schema).id;
                 // No first stage aggregation for MapGroups, do all the work in the second stage.
@@ -1174,6 +1415,9 @@ pub fn populate_aggregation_stages(
             AggExpr::MergeSketch(..) => {
                 unimplemented!("User-facing merge_sketch aggregation is not implemented")
             }
+            AggExpr::MergeHeavyHitters(..) => {
+                unimplemented!("User-facing merge_heavy_hitters aggregation is not implemented")
+            }
         }
     }
     (first_stage_aggs, second_stage_aggs, final_exprs)
@@ -1187,7 +1431,10 @@ mod tests {
     use common_error::DaftResult;
     use daft_core::prelude::*;
     use daft_dsl::{col, lit};
-    use daft_logical_plan::LogicalPlanBuilder;
+    use daft_logical_plan::{
+        partitioning::{ClusteringSpec, RangeClusteringConfig, UnknownClusteringConfig},
+        LogicalPlanBuilder,
+    };
 
     use super::HashJoin;
     use crate::{
@@ -1265,6 +1512,72 @@ mod tests {
         Ok(())
     }
 
+    /// Tests that AssertUnique shuffles its input by the keys, unless the input is already hash
+    /// partitioned by some of them.
+    #[test]
+    fn assert_unique_shuffles_by_keys() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let builder = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]));
+
+        let physical_plan = logical_to_physical(
+            builder
+                .into_partitions(10)?
+                .assert_unique(vec![col("a"), col("b")])?
+                .build(),
+            cfg.clone(),
+        )?;
+        let PhysicalPlan::AssertUnique(assert_unique) = physical_plan.as_ref() else {
+            panic!("Expected AssertUnique, got {}", physical_plan.name());
+        };
+        assert_matches!(
+            assert_unique.input.clustering_spec().as_ref(),
+            ClusteringSpec::Hash(..)
+        );
+
+        let physical_plan = logical_to_physical(
+            builder
+                .hash_repartition(Some(10), vec![col("a")])?
+                .filter(col("a").lt(lit(2)))?
+                .assert_unique(vec![col("a"), col("b")])?
+                .build(),
+            cfg,
+        )?;
+        let PhysicalPlan::AssertUnique(assert_unique) = physical_plan.as_ref() else {
+            panic!("Expected AssertUnique, got {}", physical_plan.name());
+        };
+        assert_matches!(assert_unique.input.as_ref(), PhysicalPlan::Filter(_));
+        Ok(())
+    }
+
+    /// Tests that range repartitioning is planned as a range ShuffleExchange, and that repartitioning
+    /// by the same ranges again is dropped.
+    #[test]
+    fn repartition_by_range() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let builder = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]))
+        .range_repartition(Some(10), vec![col("a")], vec![true])?;
+        let physical_plan = logical_to_physical(builder.build(), cfg.clone())?;
+        assert_matches!(physical_plan.as_ref(), PhysicalPlan::ShuffleExchange(_));
+        assert_eq!(
+            physical_plan.clustering_spec().as_ref(),
+            &ClusteringSpec::Range(RangeClusteringConfig::new(10, vec![col("a")], vec![true]))
+        );
+
+        let logical_plan = builder
+            .filter(col("a").lt(lit(2)))?
+            .range_repartition(Some(10), vec![col("a")], vec![true])?
+            .build();
+        let physical_plan = logical_to_physical(logical_plan, cfg)?;
+        assert_matches!(physical_plan.as_ref(), PhysicalPlan::Filter(_));
+        Ok(())
+    }
+
     /// Tests that planner drops a Repartition if both the Repartition and the upstream Aggregation have the same partition spec.
     ///
     /// Repartition-Aggregation -> Aggregation
@@ -1320,10 +1633,28 @@ mod tests {
         cfg: Arc<DaftExecutionConfig>,
         left_partitions: RepartitionOptions,
         right_partitions: RepartitionOptions,
+    ) -> DaftResult<Arc<PhysicalPlan>> {
+        get_typed_hash_join_plan(
+            cfg,
+            left_partitions,
+            right_partitions,
+            JoinType::Inner,
+            DataType::Int64,
+        )
+    }
+
+    /// Helper function to get plan for join repartition tests, with the given join type and type
+    /// of the right side's keys.
+    fn get_typed_hash_join_plan(
+        cfg: Arc<DaftExecutionConfig>,
+        left_partitions: RepartitionOptions,
+        right_partitions: RepartitionOptions,
+        join_type: JoinType,
+        right_key_dtype: DataType,
     ) -> DaftResult<Arc<PhysicalPlan>> {
         let join_node = dummy_scan_node(dummy_scan_operator(vec![
-            Field::new("a", DataType::Int64),
-            Field::new("b", DataType::Int64),
+            Field::new("a", right_key_dtype.clone()),
+            Field::new("b", right_key_dtype),
             Field::new("c", DataType::Int64),
         ]));
         let join_node = force_repartition(join_node, right_partitions)?.select(vec![
@@ -1343,7 +1674,7 @@ mod tests {
                 join_node,
                 vec![col("a"), col("b")],
                 vec![col("a"), col("b")],
-                JoinType::Inner,
+                join_type,
                 Some(JoinStrategy::Hash),
                 None,
                 None,
@@ -1456,6 +1787,111 @@ mod tests {
         Ok(())
     }
 
+    /// Tests that skew salting plans a salted hash join instead of shuffling the inputs, and is
+    /// skipped when neither side needs to be shuffled.
+    #[test]
+    fn hash_join_skew_salting() -> DaftResult<()> {
+        let mut cfg = DaftExecutionConfig::default();
+        cfg.enable_skew_join_salting = true;
+        cfg.skew_join_salt_factor = 4;
+        let cfg = Arc::new(cfg);
+
+        let physical_plan = get_hash_join_plan(
+            cfg.clone(),
+            RepartitionOptions::Good(10),
+            RepartitionOptions::Bad(20),
+        )?;
+        match physical_plan.as_ref() {
+            PhysicalPlan::HashJoin(HashJoin {
+                left,
+                right,
+                skew_salting: Some(skew_salting),
+                ..
+            }) => {
+                assert_matches!(left.as_ref(), PhysicalPlan::Project(_));
+                assert_matches!(right.as_ref(), PhysicalPlan::Project(_));
+                assert_eq!(skew_salting.num_partitions, 10);
+                assert_eq!(skew_salting.salt_factor, 4);
+            }
+            plan => panic!("Expected a salted hash join, got {}", plan.name()),
+        }
+        assert_eq!(
+            physical_plan.clustering_spec().as_ref(),
+            &ClusteringSpec::Unknown(UnknownClusteringConfig::new(10))
+        );
+
+        let physical_plan = get_hash_join_plan(
+            cfg,
+            RepartitionOptions::Good(10),
+            RepartitionOptions::Good(10),
+        )?;
+        assert_matches!(
+            physical_plan.as_ref(),
+            PhysicalPlan::HashJoin(HashJoin {
+                skew_salting: None,
+                ..
+            })
+        );
+        Ok(())
+    }
+
+    /// Tests that co-partitioned sides aren't shuffled when one has a multiple of the other's
+    /// partitions, unless the side with fewer partitions must keep all its rows.
+    #[test]
+    fn hash_join_co_partitioned_tests() -> DaftResult<()> {
+        use RepartitionOptions::*;
+        let cases = vec![
+            (Good(10), Good(30), JoinType::Inner, false, false),
+            (Reversed(10), Good(30), JoinType::Inner, false, false),
+            (Good(30), Good(10), JoinType::Left, false, false),
+            (Good(30), Good(10), JoinType::Semi, false, false),
+            (Good(10), Good(30), JoinType::Right, false, false),
+            (Good(10), Good(30), JoinType::Left, true, false),
+            (Good(10), Good(30), JoinType::Outer, true, false),
+            (Good(20), Good(30), JoinType::Inner, true, false),
+        ];
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        for (l_opts, r_opts, join_type, l_exp, r_exp) in cases {
+            let plan =
+                get_typed_hash_join_plan(cfg.clone(), l_opts, r_opts, join_type, DataType::Int64)?;
+            assert_matches!(
+                plan.as_ref(),
+                PhysicalPlan::HashJoin(HashJoin { co_partitioned, .. }) if *co_partitioned == (!l_exp && !r_exp),
+                "Failed co-partitioned hash join test on case ({:?}, {:?}, {})",
+                l_opts,
+                r_opts,
+                join_type
+            );
+            assert!(
+                check_physical_matches(plan.clone(), l_exp, r_exp),
+                "Failed co-partitioned hash join test on case ({:?}, {:?}, {}, {}, {})",
+                l_opts,
+                r_opts,
+                join_type,
+                l_exp,
+                r_exp
+            );
+            assert_eq!(plan.clustering_spec().num_partitions(), 30);
+        }
+        Ok(())
+    }
+
+    /// Tests that sides hash partitioned by keys of different types are shuffled, since their keys
+    /// don't hash alike.
+    #[test]
+    fn hash_join_key_types_differ() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let plan = get_typed_hash_join_plan(
+            cfg,
+            RepartitionOptions::Good(10),
+            RepartitionOptions::Good(10),
+            JoinType::Inner,
+            DataType::Int32,
+        )?;
+        assert!(check_physical_matches(plan, true, true));
+        Ok(())
+    }
+
     /// Tests that single partitions don't repartition.
     #[test]
     fn hash_join_single_partition_tests() -> DaftResult<()> {
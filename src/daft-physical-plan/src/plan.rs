@@ -27,6 +27,7 @@ pub enum PhysicalPlan {
     Unpivot(Unpivot),
     Sort(Sort),
     Sample(Sample),
+    AssertUnique(AssertUnique),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
     Aggregate(Aggregate),
     Pivot(Pivot),
@@ -79,6 +80,7 @@ impl PhysicalPlan {
                 clustering_spec, ..
             }) => clustering_spec.clone(),
             Self::Sample(Sample { input, .. }) => input.clustering_spec(),
+            Self::AssertUnique(AssertUnique { input, .. }) => input.clustering_spec(),
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 input.clustering_spec()
             }
@@ -130,6 +132,14 @@ impl PhysicalPlan {
                 ))
                 .into()
             }
+            Self::HashJoin(HashJoin {
+                skew_salting: Some(SkewSalting { num_partitions, .. }),
+                ..
+            }) => {
+                // The rows of hot keys are spread across partitions, so the output isn't
+                // partitioned by the join keys.
+                ClusteringSpec::Unknown(UnknownClusteringConfig::new(*num_partitions)).into()
+            }
             Self::HashJoin(HashJoin {
                 left,
                 right,
@@ -274,6 +284,7 @@ impl PhysicalPlan {
             }
             // Propagate child approximation for operations that don't affect cardinality.
             Self::Sort(Sort { input, .. })
+            | Self::AssertUnique(AssertUnique { input, .. })
             | Self::Pivot(Pivot { input, .. })
             | Self::ShuffleExchange(ShuffleExchange { input, .. }) => input.approximate_stats(),
             Self::Concat(Concat { input, other }) => {
@@ -366,6 +377,7 @@ impl PhysicalPlan {
             Self::Explode(Explode { input, .. }) => vec![input],
             Self::Unpivot(Unpivot { input, .. }) => vec![input],
             Self::Sample(Sample { input, .. }) => vec![input],
+            Self::AssertUnique(AssertUnique { input, .. }) => vec![input],
             Self::Sort(Sort { input, .. }) => vec![input],
             Self::Aggregate(Aggregate { input, .. }) => vec![input],
             Self::Pivot(Pivot { input, .. }) => vec![input],
@@ -414,6 +426,7 @@ impl PhysicalPlan {
                 Self::Unpivot(Unpivot { ids, values, variable_name, value_name, .. }) => Self::Unpivot(Unpivot::new(input.clone(), ids.clone(), values.clone(), variable_name, value_name)),
                 Self::Pivot(Pivot { group_by, pivot_column, value_column, names, .. }) => Self::Pivot(Pivot::new(input.clone(), group_by.clone(), pivot_column.clone(), value_column.clone(), names.clone())),
                 Self::Sample(Sample { fraction, with_replacement, seed, .. }) => Self::Sample(Sample::new(input.clone(), *fraction, *with_replacement, *seed)),
+                Self::AssertUnique(AssertUnique { keys, .. }) => Self::AssertUnique(AssertUnique::new(input.clone(), keys.clone())),
                 Self::Sort(Sort { sort_by, descending, nulls_first,  num_partitions, .. }) => Self::Sort(Sort::new(input.clone(), sort_by.clone(), descending.clone(),nulls_first.clone(), *num_partitions)),
                 Self::ShuffleExchange(ShuffleExchange { strategy, .. }) => Self::ShuffleExchange(ShuffleExchange { input: input.clone(), strategy: strategy.clone() }),
                 Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::new(input.clone(), aggregations.clone(), groupby.clone())),
@@ -434,7 +447,7 @@ impl PhysicalPlan {
                 Self::InMemoryScan(..) => panic!("Source nodes don't have children, with_new_children() should never be called for source ops"),
                 Self::TabularScan(..)
                 | Self::EmptyScan(..) => panic!("Source nodes don't have children, with_new_children() should never be called for source ops"),
-                Self::HashJoin(HashJoin { left_on, right_on, null_equals_nulls, join_type, .. }) => Self::HashJoin(HashJoin::new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), null_equals_nulls.clone(), *join_type)),
+                Self::HashJoin(HashJoin { left_on, right_on, null_equals_nulls, join_type, skew_salting, co_partitioned, .. }) => Self::HashJoin(HashJoin::new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), null_equals_nulls.clone(), *join_type).with_skew_salting(skew_salting.clone()).with_co_partitioned(*co_partitioned)),
                 Self::BroadcastJoin(BroadcastJoin {
                     left_on,
                     right_on,
@@ -464,6 +477,7 @@ impl PhysicalPlan {
             Self::Explode(..) => "Explode",
             Self::Unpivot(..) => "Unpivot",
             Self::Sample(..) => "Sample",
+            Self::AssertUnique(..) => "AssertUnique",
             Self::Sort(..) => "Sort",
             Self::ShuffleExchange(..) => "ShuffleExchange",
             Self::Aggregate(..) => "Aggregate",
@@ -499,6 +513,7 @@ impl PhysicalPlan {
             Self::Explode(explode) => explode.multiline_display(),
             Self::Unpivot(unpivot) => unpivot.multiline_display(),
             Self::Sample(sample) => sample.multiline_display(),
+            Self::AssertUnique(assert_unique) => assert_unique.multiline_display(),
             Self::Sort(sort) => sort.multiline_display(),
             Self::ShuffleExchange(shuffle_exchange) => shuffle_exchange.multiline_display(),
             Self::Aggregate(aggregate) => aggregate.multiline_display(),
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use common_error::DaftResult;
-use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
+use common_file_formats::{FileFormat, FileFormatConfig, ParquetSourceConfig};
 use common_scan_info::{PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef};
 use daft_schema::schema::SchemaRef;
 
@@ -72,6 +72,14 @@ impl ScanOperator for AnonymousScanOperator {
         lines
     }
 
+    fn file_paths(&self) -> Vec<String> {
+        self.files.clone()
+    }
+
+    fn file_format(&self) -> Option<FileFormat> {
+        Some(self.file_format_config.as_ref().into())
+    }
+
     fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>> {
         let files = self.files.clone();
         let file_format_config = self.file_format_config.clone();
@@ -1,7 +1,9 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use common_error::DaftResult;
-use common_file_formats::{CsvSourceConfig, FileFormatConfig, ParquetSourceConfig};
+use common_file_formats::{
+    CsvSourceConfig, FileFormatConfig, JsonSourceConfig, ParquetSourceConfig,
+};
 use common_io_config::IOConfig;
 use common_scan_info::ScanOperatorRef;
 use daft_core::prelude::TimeUnit;
@@ -24,6 +26,7 @@ pub struct ParquetScanBuilder {
     pub schema: Option<SchemaRef>,
     pub file_path_column: Option<String>,
     pub hive_partitioning: bool,
+    pub schema_hints: Option<SchemaRef>,
 }
 
 impl ParquetScanBuilder {
@@ -46,6 +49,7 @@ impl ParquetScanBuilder {
             io_config: None,
             file_path_column: None,
             hive_partitioning: false,
+            schema_hints: None,
         }
     }
     pub fn infer_schema(mut self, infer_schema: bool) -> Self {
@@ -94,6 +98,11 @@ impl ParquetScanBuilder {
         self
     }
 
+    pub fn schema_hints(mut self, schema_hints: SchemaRef) -> Self {
+        self.schema_hints = Some(schema_hints);
+        self
+    }
+
     pub async fn finish(self) -> DaftResult<LogicalPlanBuilder> {
         let cfg = ParquetSourceConfig {
             coerce_int96_timestamp_unit: self.coerce_int96_timestamp_unit,
@@ -111,7 +120,7 @@ impl ParquetScanBuilder {
                     self.io_config,
                 )),
                 self.infer_schema,
-                self.schema,
+                schema_with_hints(self.infer_schema, self.schema, self.schema_hints)?,
                 self.file_path_column,
                 self.hive_partitioning,
             )
@@ -122,6 +131,24 @@ impl ParquetScanBuilder {
     }
 }
 
+/// The schema that's passed to the glob scan operator of a builder. Schema hints are merged into
+/// the given schema if the schema is inferred, since it's used as schema hints too, and are only
+/// checked against it otherwise.
+fn schema_with_hints(
+    infer_schema: bool,
+    schema: Option<SchemaRef>,
+    schema_hints: Option<SchemaRef>,
+) -> DaftResult<Option<SchemaRef>> {
+    match (schema, schema_hints) {
+        (Some(schema), Some(schema_hints)) => {
+            let merged = Arc::new(schema.merge_hints(&schema_hints)?);
+            Ok(Some(if infer_schema { merged } else { schema }))
+        }
+        (None, Some(schema_hints)) if infer_schema => Ok(Some(schema_hints)),
+        (schema, _) => Ok(schema),
+    }
+}
+
 pub fn parquet_scan<T: IntoGlobPath>(glob_path: T) -> ParquetScanBuilder {
     ParquetScanBuilder::new(glob_path)
 }
@@ -252,7 +279,92 @@ impl CsvScanBuilder {
                 Arc::new(FileFormatConfig::Csv(cfg)),
                 Arc::new(StorageConfig::new_internal(false, self.io_config)),
                 self.infer_schema,
-                self.schema,
+                schema_with_hints(self.infer_schema, self.schema, self.schema_hints)?,
+                self.file_path_column,
+                self.hive_partitioning,
+            )
+            .await?,
+        );
+
+        LogicalPlanBuilder::table_scan(ScanOperatorRef(operator), None)
+    }
+}
+
+pub struct JsonScanBuilder {
+    pub glob_paths: Vec<String>,
+    pub infer_schema: bool,
+    pub io_config: Option<IOConfig>,
+    pub schema: Option<SchemaRef>,
+    pub file_path_column: Option<String>,
+    pub hive_partitioning: bool,
+    pub buffer_size: Option<usize>,
+    pub chunk_size: Option<usize>,
+    pub schema_hints: Option<SchemaRef>,
+}
+
+impl JsonScanBuilder {
+    pub fn new<T: IntoGlobPath>(glob_paths: T) -> Self {
+        let glob_paths = glob_paths.into_glob_path();
+        Self::new_impl(glob_paths)
+    }
+
+    // concrete implementation to reduce LLVM code duplication
+    fn new_impl(glob_paths: Vec<String>) -> Self {
+        Self {
+            glob_paths,
+            infer_schema: true,
+            schema: None,
+            io_config: None,
+            file_path_column: None,
+            hive_partitioning: false,
+            buffer_size: None,
+            chunk_size: None,
+            schema_hints: None,
+        }
+    }
+    pub fn infer_schema(mut self, infer_schema: bool) -> Self {
+        self.infer_schema = infer_schema;
+        self
+    }
+    pub fn io_config(mut self, io_config: IOConfig) -> Self {
+        self.io_config = Some(io_config);
+        self
+    }
+    pub fn schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+    pub fn file_path_column(mut self, file_path_column: String) -> Self {
+        self.file_path_column = Some(file_path_column);
+        self
+    }
+    pub fn hive_partitioning(mut self, hive_partitioning: bool) -> Self {
+        self.hive_partitioning = hive_partitioning;
+        self
+    }
+    pub fn buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = Some(buffer_size);
+        self
+    }
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+    pub fn schema_hints(mut self, schema_hints: SchemaRef) -> Self {
+        self.schema_hints = Some(schema_hints);
+        self
+    }
+
+    pub async fn finish(self) -> DaftResult<LogicalPlanBuilder> {
+        let cfg = JsonSourceConfig::new_internal(self.buffer_size, self.chunk_size);
+
+        let operator = Arc::new(
+            GlobScanOperator::try_new(
+                self.glob_paths,
+                Arc::new(FileFormatConfig::Json(cfg)),
+                Arc::new(StorageConfig::new_internal(false, self.io_config)),
+                self.infer_schema,
+                schema_with_hints(self.infer_schema, self.schema, self.schema_hints)?,
                 self.file_path_column,
                 self.hive_partitioning,
             )
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use serde::{Deserialize, Serialize};
+
+/// Values of the columns of a table's schema for the sources of the table that don't have them,
+/// such as sources that were written before the columns were added to the table.
+///
+/// A column's value is either a literal default value, or the expression of a generated column
+/// that is computed from the other columns of the source.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+pub struct ColumnDefaults {
+    pub exprs: Vec<(String, ExprRef)>,
+}
+
+impl ColumnDefaults {
+    #[must_use]
+    pub fn new(exprs: Vec<(String, ExprRef)>) -> Self {
+        Self { exprs }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.exprs.is_empty()
+    }
+
+    #[must_use]
+    pub fn to_fill_map(&self) -> HashMap<&str, ExprRef> {
+        self.exprs
+            .iter()
+            .map(|(name, expr)| (name.as_str(), expr.clone()))
+            .collect()
+    }
+
+    /// The columns of a source that the values of `columns` are computed from when the source
+    /// doesn't have them, which have to be read along with `columns`.
+    #[must_use]
+    pub fn required_columns(&self, columns: &[&str]) -> Vec<String> {
+        self.exprs
+            .iter()
+            .filter(|(name, _)| columns.contains(&name.as_str()))
+            .flat_map(|(_, expr)| get_required_columns(expr))
+            .filter(|name| !columns.contains(&name.as_str()))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        self.exprs
+            .iter()
+            .map(|(name, expr)| format!("{name} = {expr}"))
+            .collect()
+    }
+}
@@ -128,6 +128,36 @@ fn run_glob_parallel(
 }
 
 impl GlobScanOperator {
+    /// Creates an operator for files with a known schema without listing them, so the glob
+    /// paths are only expanded once the scan tasks are created. `hive_partition_cols` are the
+    /// columns of `schema` that are read from the hive partitions of the file paths.
+    pub fn try_new_with_schema(
+        glob_paths: Vec<String>,
+        file_format_config: Arc<FileFormatConfig>,
+        storage_config: Arc<StorageConfig>,
+        schema: SchemaRef,
+        hive_partition_cols: &[String],
+    ) -> DaftResult<Self> {
+        let partition_fields = hive_partition_cols
+            .iter()
+            .map(|name| schema.get_field(name).cloned())
+            .collect::<DaftResult<Vec<_>>>()?;
+        let partitioning_keys = partition_fields
+            .iter()
+            .map(|field| PartitionField::new(field.clone(), None, None))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            glob_paths,
+            file_format_config,
+            schema,
+            storage_config,
+            file_path_column: None,
+            hive_partitioning: !hive_partition_cols.is_empty(),
+            partitioning_keys,
+            generated_fields: Arc::new(Schema::new(partition_fields)?),
+        })
+    }
+
     pub async fn try_new(
         glob_paths: Vec<String>,
         file_format_config: Arc<FileFormatConfig>,
@@ -261,6 +291,34 @@ impl GlobScanOperator {
                         )
                         .await?
                     }
+                    FileFormatConfig::Xml(cfg) => {
+                        daft_xml::read_xml_schema(
+                            first_filepath.as_str(),
+                            cfg,
+                            io_client,
+                            Some(io_stats),
+                        )
+                        .await?
+                    }
+                    FileFormatConfig::Excel(cfg) => {
+                        daft_excel::read_excel_schema(
+                            first_filepath.as_str(),
+                            cfg,
+                            io_client,
+                            Some(io_stats),
+                        )
+                        .await?
+                    }
+                    FileFormatConfig::Kafka(_) => {
+                        return Err(DaftError::ValueError(
+                            "Cannot glob a Kafka source".to_string(),
+                        ))
+                    }
+                    FileFormatConfig::Range => {
+                        return Err(DaftError::ValueError(
+                            "Cannot glob a range source".to_string(),
+                        ))
+                    }
                     #[cfg(feature = "python")]
                     FileFormatConfig::Database(_) => {
                         return Err(DaftError::ValueError(
@@ -313,6 +371,14 @@ impl ScanOperator for GlobScanOperator {
         self.file_path_column.as_deref()
     }
 
+    fn file_paths(&self) -> Vec<String> {
+        self.glob_paths.clone()
+    }
+
+    fn file_format(&self) -> Option<FileFormat> {
+        Some(self.file_format_config.as_ref().into())
+    }
+
     fn generated_fields(&self) -> Option<SchemaRef> {
         Some(self.generated_fields.clone())
     }
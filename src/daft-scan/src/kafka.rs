@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_file_formats::{FileFormatConfig, KafkaSourceConfig};
+use common_scan_info::{PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef};
+use daft_kafka::{resolve_offset_ranges, KafkaOffsetRange, KafkaOffsetSpec};
+use daft_schema::schema::SchemaRef;
+
+use crate::{storage_config::StorageConfig, DataSource, ScanTask};
+
+/// Scan of a bounded range of the messages of a Kafka topic.
+///
+/// The start and end of the scan are resolved to offsets of each partition when the operator is
+/// created, so that the scan reads the same messages no matter when it is executed. Each
+/// partition is read by its own scan task, or by several if `max_messages_per_task` is set.
+#[derive(Debug)]
+pub struct KafkaScanOperator {
+    config: KafkaSourceConfig,
+    schema: SchemaRef,
+    ranges: Vec<KafkaOffsetRange>,
+    max_messages_per_task: Option<u64>,
+}
+
+impl KafkaScanOperator {
+    pub fn try_new(
+        config: KafkaSourceConfig,
+        schema: SchemaRef,
+        partitions: Option<Vec<i32>>,
+        start: KafkaOffsetSpec,
+        end: KafkaOffsetSpec,
+        max_messages_per_task: Option<u64>,
+    ) -> DaftResult<Self> {
+        let ranges = resolve_offset_ranges(&config, partitions.as_deref(), &start, &end)?;
+        Ok(Self {
+            config,
+            schema,
+            ranges,
+            max_messages_per_task,
+        })
+    }
+
+    fn task_ranges(&self) -> Vec<KafkaOffsetRange> {
+        match self.max_messages_per_task {
+            Some(max_messages_per_task) => self
+                .ranges
+                .iter()
+                .flat_map(|range| range.split(max_messages_per_task))
+                .collect(),
+            None => self.ranges.clone(),
+        }
+    }
+}
+
+impl ScanOperator for KafkaScanOperator {
+    fn name(&self) -> &str {
+        "KafkaScanOperator"
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn partitioning_keys(&self) -> &[PartitionField] {
+        &[]
+    }
+
+    fn file_path_column(&self) -> Option<&str> {
+        None
+    }
+
+    fn generated_fields(&self) -> Option<SchemaRef> {
+        None
+    }
+
+    fn can_absorb_filter(&self) -> bool {
+        false
+    }
+
+    fn can_absorb_select(&self) -> bool {
+        false
+    }
+
+    fn can_absorb_limit(&self) -> bool {
+        true
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut lines = vec!["KafkaScanOperator".to_string()];
+        lines.extend(self.config.multiline_display());
+        lines.push(format!(
+            "Offsets = [{}]",
+            self.ranges
+                .iter()
+                .map(|range| format!("{}: {}..{}", range.partition, range.start, range.end))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        if let Some(max_messages_per_task) = self.max_messages_per_task {
+            lines.push(format!("Max messages per task = {max_messages_per_task}"));
+        }
+        lines
+    }
+
+    fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>> {
+        let file_format_config = Arc::new(FileFormatConfig::Kafka(self.config.clone()));
+        let storage_config = Arc::new(StorageConfig::default());
+        Ok(self
+            .task_ranges()
+            .into_iter()
+            .map(|range| {
+                Arc::new(ScanTask::new(
+                    vec![DataSource::Kafka {
+                        path: format!("kafka://{}/{}", self.config.topic, range.partition),
+                        range,
+                    }],
+                    file_format_config.clone(),
+                    self.schema.clone(),
+                    storage_config.clone(),
+                    pushdowns.clone(),
+                    None,
+                )) as Arc<dyn ScanTaskLike>
+            })
+            .collect())
+    }
+}
@@ -3,6 +3,7 @@
 use std::{
     any::Any,
     borrow::Cow,
+    collections::HashMap,
     fmt::Debug,
     hash::{Hash, Hasher},
     sync::Arc,
@@ -12,6 +13,8 @@ use common_display::DisplayAs;
 use common_error::DaftError;
 use common_file_formats::FileFormatConfig;
 use common_scan_info::{Pushdowns, ScanTaskLike, ScanTaskLikeRef};
+use daft_dsl::ExprRef;
+use daft_kafka::KafkaOffsetRange;
 use daft_schema::schema::{Schema, SchemaRef};
 use daft_stats::{PartitionSpec, TableMetadata, TableStatistics};
 use itertools::Itertools;
@@ -20,9 +23,17 @@ use serde::{Deserialize, Serialize};
 
 mod anonymous;
 pub use anonymous::AnonymousScanOperator;
+mod column_defaults;
+pub use column_defaults::ColumnDefaults;
 pub mod glob;
 mod hive;
+mod kafka;
+mod locality;
+mod range;
 use common_daft_config::DaftExecutionConfig;
+pub use kafka::KafkaScanOperator;
+pub use locality::LocalityHints;
+pub use range::{IntRange, RangeScanOperator};
 pub mod builder;
 pub mod scan_task_iters;
 
@@ -89,6 +100,16 @@ pub enum Error {
         p2
     ))]
     DifferingPushdownsInScanTaskMerge { p1: Pushdowns, p2: Pushdowns },
+
+    #[snafu(display(
+        "ColumnDefaults were different during ScanTask::merge: {:?} vs {:?}",
+        cd1,
+        cd2
+    ))]
+    DifferingColumnDefaultsInScanTaskMerge {
+        cd1: Option<ColumnDefaults>,
+        cd2: Option<ColumnDefaults>,
+    },
 }
 
 impl From<Error> for DaftError {
@@ -143,6 +164,17 @@ pub enum DataSource {
         metadata: Option<TableMetadata>,
         statistics: Option<TableStatistics>,
     },
+    /// Range of offsets of a partition of a Kafka topic.
+    Kafka {
+        path: String,
+        range: KafkaOffsetRange,
+    },
+    /// Range of integers that are generated rather than read.
+    Range {
+        path: String,
+        range: IntRange,
+        metadata: TableMetadata,
+    },
     #[cfg(feature = "python")]
     PythonFactoryFunction {
         module: String,
@@ -190,6 +222,19 @@ impl Hash for DataSource {
                 metadata.hash(state);
                 statistics.hash(state);
             }
+            Self::Kafka { path, range } => {
+                path.hash(state);
+                range.hash(state);
+            }
+            Self::Range {
+                path,
+                range,
+                metadata,
+            } => {
+                path.hash(state);
+                range.hash(state);
+                metadata.hash(state);
+            }
             #[cfg(feature = "python")]
             Self::PythonFactoryFunction {
                 module,
@@ -216,7 +261,10 @@ impl DataSource {
     #[must_use]
     pub fn get_path(&self) -> &str {
         match self {
-            Self::File { path, .. } | Self::Database { path, .. } => path,
+            Self::File { path, .. }
+            | Self::Database { path, .. }
+            | Self::Kafka { path, .. }
+            | Self::Range { path, .. } => path,
             #[cfg(feature = "python")]
             Self::PythonFactoryFunction { module, .. } => module,
         }
@@ -236,7 +284,7 @@ impl DataSource {
     pub fn get_chunk_spec(&self) -> Option<&ChunkSpec> {
         match self {
             Self::File { chunk_spec, .. } => chunk_spec.as_ref(),
-            Self::Database { .. } => None,
+            Self::Database { .. } | Self::Kafka { .. } | Self::Range { .. } => None,
             #[cfg(feature = "python")]
             Self::PythonFactoryFunction { .. } => None,
         }
@@ -246,6 +294,10 @@ impl DataSource {
     pub fn get_size_bytes(&self) -> Option<u64> {
         match self {
             Self::File { size_bytes, .. } | Self::Database { size_bytes, .. } => *size_bytes,
+            Self::Kafka { .. } => None,
+            Self::Range { metadata, .. } => {
+                Some((metadata.length * std::mem::size_of::<i64>()) as u64)
+            }
             #[cfg(feature = "python")]
             Self::PythonFactoryFunction { size_bytes, .. } => *size_bytes,
         }
@@ -255,6 +307,8 @@ impl DataSource {
     pub fn get_metadata(&self) -> Option<&TableMetadata> {
         match self {
             Self::File { metadata, .. } | Self::Database { metadata, .. } => metadata.as_ref(),
+            Self::Kafka { .. } => None,
+            Self::Range { metadata, .. } => Some(metadata),
             #[cfg(feature = "python")]
             Self::PythonFactoryFunction { metadata, .. } => metadata.as_ref(),
         }
@@ -266,6 +320,7 @@ impl DataSource {
             Self::File { statistics, .. } | Self::Database { statistics, .. } => {
                 statistics.as_ref()
             }
+            Self::Kafka { .. } | Self::Range { .. } => None,
             #[cfg(feature = "python")]
             Self::PythonFactoryFunction { statistics, .. } => statistics.as_ref(),
         }
@@ -275,7 +330,7 @@ impl DataSource {
     pub fn get_partition_spec(&self) -> Option<&PartitionSpec> {
         match self {
             Self::File { partition_spec, .. } => partition_spec.as_ref(),
-            Self::Database { .. } => None,
+            Self::Database { .. } | Self::Kafka { .. } | Self::Range { .. } => None,
             #[cfg(feature = "python")]
             Self::PythonFactoryFunction { partition_spec, .. } => partition_spec.as_ref(),
         }
@@ -355,6 +410,17 @@ impl DataSource {
                     res.push(format!("Statistics = {statistics}"));
                 }
             }
+            Self::Kafka { path, range } => {
+                res.push(format!("Path = {path}"));
+                res.push(format!("Offsets = {}..{}", range.start, range.end));
+            }
+            Self::Range { path, range, .. } => {
+                res.push(format!("Path = {path}"));
+                res.push(format!(
+                    "Range = {}..{} step {}",
+                    range.start, range.end, range.step
+                ));
+            }
             #[cfg(feature = "python")]
             Self::PythonFactoryFunction {
                 module,
@@ -399,6 +465,10 @@ impl DisplayAs for DataSource {
                         format!("File {{{path}}}")
                     }
                     Self::Database { path, .. } => format!("Database {{{path}}}"),
+                    Self::Kafka { path, range } => {
+                        format!("Kafka {{{path} @ {}..{}}}", range.start, range.end)
+                    }
+                    Self::Range { path, .. } => format!("Range {{{path}}}"),
                     #[cfg(feature = "python")]
                     Self::PythonFactoryFunction {
                         module, func_name, ..
@@ -432,6 +502,10 @@ pub struct ScanTask {
     pub metadata: Option<TableMetadata>,
     pub statistics: Option<TableStatistics>,
     pub generated_fields: Option<SchemaRef>,
+    /// Where the data of the sources lives, for scheduling the task close to it.
+    pub locality_hints: Option<LocalityHints>,
+    /// Values of the columns of the schema for sources that don't have them.
+    pub column_defaults: Option<ColumnDefaults>,
 }
 
 #[typetag::serde]
@@ -550,9 +624,23 @@ impl ScanTask {
             metadata,
             statistics,
             generated_fields,
+            locality_hints: None,
+            column_defaults: None,
         }
     }
 
+    #[must_use]
+    pub fn with_locality_hints(mut self, locality_hints: LocalityHints) -> Self {
+        self.locality_hints = (!locality_hints.is_empty()).then_some(locality_hints);
+        self
+    }
+
+    #[must_use]
+    pub fn with_column_defaults(mut self, column_defaults: Option<ColumnDefaults>) -> Self {
+        self.column_defaults = column_defaults.filter(|defaults| !defaults.is_empty());
+        self
+    }
+
     pub fn merge(sc1: &Self, sc2: &Self) -> Result<Self, Error> {
         if sc1.partition_spec() != sc2.partition_spec() {
             return Err(Error::DifferingPartitionSpecsInScanTaskMerge {
@@ -590,6 +678,17 @@ impl ScanTask {
                 fpc2: sc2.generated_fields.clone(),
             });
         }
+        if sc1.column_defaults != sc2.column_defaults {
+            return Err(Error::DifferingColumnDefaultsInScanTaskMerge {
+                cd1: sc1.column_defaults.clone(),
+                cd2: sc2.column_defaults.clone(),
+            });
+        }
+        let locality_hints = sc1
+            .locality_hints
+            .clone()
+            .unwrap_or_default()
+            .merge(&sc2.locality_hints.clone().unwrap_or_default());
         Ok(Self::new(
             sc1.sources
                 .clone()
@@ -601,7 +700,9 @@ impl ScanTask {
             sc1.storage_config.clone(),
             sc1.pushdowns.clone(),
             sc1.generated_fields.clone(),
-        ))
+        )
+        .with_locality_hints(locality_hints)
+        .with_column_defaults(sc1.column_defaults.clone()))
     }
 
     #[must_use]
@@ -668,9 +769,12 @@ impl ScanTask {
                         .map_or_else(|| Cow::Owned(DaftExecutionConfig::default()), Cow::Borrowed);
                     let inflation_factor = match self.file_format_config.as_ref() {
                         FileFormatConfig::Parquet(_) => config.parquet_inflation_factor,
-                        FileFormatConfig::Csv(_) | FileFormatConfig::Json(_) => {
-                            config.csv_inflation_factor
-                        }
+                        FileFormatConfig::Csv(_)
+                        | FileFormatConfig::Json(_)
+                        | FileFormatConfig::Xml(_) => config.csv_inflation_factor,
+                        FileFormatConfig::Excel(_)
+                        | FileFormatConfig::Kafka(_)
+                        | FileFormatConfig::Range => 1.0,
                         #[cfg(feature = "python")]
                         FileFormatConfig::Database(_) => 1.0,
                         #[cfg(feature = "python")]
@@ -746,6 +850,50 @@ impl ScanTask {
         }
     }
 
+    /// Expressions that fill the columns of the materialized schema that a source doesn't have:
+    /// the partition values of the sources, or else the column defaults of the task.
+    #[must_use]
+    pub fn fill_map(&self) -> Option<HashMap<&str, ExprRef>> {
+        let partition_fill_map = self.partition_spec().map(PartitionSpec::to_fill_map);
+        match &self.column_defaults {
+            None => partition_fill_map,
+            Some(column_defaults) => {
+                let mut fill_map = column_defaults.to_fill_map();
+                fill_map.extend(partition_fill_map.unwrap_or_default());
+                Some(fill_map)
+            }
+        }
+    }
+
+    /// The columns to read from the sources for the pushed down columns, if any: partition
+    /// columns aren't read, and the columns that column defaults are computed from are.
+    #[must_use]
+    pub fn file_column_names(&self) -> Option<Vec<&str>> {
+        let columns = self.pushdowns.columns.as_ref()?;
+        let partition_fill_map = self.partition_spec().map(PartitionSpec::to_fill_map);
+        let is_partition_column = |column: &str| {
+            partition_fill_map
+                .as_ref()
+                .is_some_and(|fill_map| fill_map.contains_key(column))
+        };
+        let mut file_columns = columns
+            .iter()
+            .map(String::as_str)
+            .filter(|column| !is_partition_column(column))
+            .collect::<Vec<_>>();
+        if let Some(column_defaults) = &self.column_defaults {
+            for required in column_defaults.required_columns(&file_columns) {
+                if let Some((name, _)) = self.schema.fields.get_key_value(required.as_str())
+                    && !is_partition_column(name)
+                    && !file_columns.contains(&name.as_str())
+                {
+                    file_columns.push(name.as_str());
+                }
+            }
+        }
+        Some(file_columns)
+    }
+
     #[must_use]
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
@@ -786,6 +934,18 @@ impl ScanTask {
         if let Some(statistics) = &self.statistics {
             res.push(format!("Statistics = {statistics}"));
         }
+        if let Some(locality_hints) = &self.locality_hints {
+            res.push(format!(
+                "Locality hints = {}",
+                locality_hints.multiline_display().join(", ")
+            ));
+        }
+        if let Some(column_defaults) = &self.column_defaults {
+            res.push(format!(
+                "Column defaults = {}",
+                column_defaults.multiline_display().join(", ")
+            ));
+        }
         res
     }
 }
@@ -831,16 +991,24 @@ Pushdowns = {pushdowns}
 
 #[cfg(test)]
 mod test {
-    use std::sync::Arc;
+    use std::{
+        hash::{DefaultHasher, Hash, Hasher},
+        sync::Arc,
+    };
 
     use common_display::{DisplayAs, DisplayLevel};
     use common_error::DaftResult;
     use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
-    use common_scan_info::{Pushdowns, ScanOperator};
-    use daft_schema::{schema::Schema, time_unit::TimeUnit};
+    use common_io_config::{IOConfig, S3Config};
+    use common_scan_info::{Pushdowns, ScanOperator, ScanTaskLikeRef};
+    use daft_dsl::{col, lit};
+    use daft_schema::{dtype::DataType, field::Field, schema::Schema, time_unit::TimeUnit};
     use itertools::Itertools;
 
-    use crate::{glob::GlobScanOperator, storage_config::StorageConfig, DataSource, ScanTask};
+    use crate::{
+        glob::GlobScanOperator, storage_config::StorageConfig, ColumnDefaults, DataSource,
+        LocalityHints, ScanTask,
+    };
 
     fn make_scan_task(num_sources: usize) -> ScanTask {
         let sources = (0..num_sources)
@@ -873,6 +1041,116 @@ mod test {
         )
     }
 
+    fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_scan_task_serde_roundtrip() -> DaftResult<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ])?);
+        let io_config = IOConfig {
+            s3: S3Config {
+                region_name: Some("us-west-2".to_string()),
+                anonymous: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let pushdowns = Pushdowns::new(
+            Some(col("a").gt(lit(1))),
+            None,
+            Some(Arc::new(vec!["a".to_string()])),
+            Some(10),
+        );
+        let base = make_scan_task(2);
+        let scan_task = ScanTask::new(
+            base.sources,
+            base.file_format_config,
+            schema,
+            Arc::new(StorageConfig::new_internal(true, Some(io_config))),
+            pushdowns,
+            None,
+        );
+
+        let serialized = bincode::serialize(&scan_task).unwrap();
+        let deserialized: ScanTask = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, scan_task);
+        assert_eq!(hash_of(&deserialized), hash_of(&scan_task));
+
+        // Scan tasks are shipped to workers as trait objects in physical plans.
+        let scan_task: ScanTaskLikeRef = Arc::new(scan_task);
+        let serialized = bincode::serialize(&scan_task).unwrap();
+        let deserialized: ScanTaskLikeRef = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, scan_task);
+        assert_eq!(hash_of(&deserialized), hash_of(&scan_task));
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_locality_hints() -> DaftResult<()> {
+        let hints = |zone: &str, node_ids: &[&str]| {
+            LocalityHints::new_internal(
+                Some("us-west-2".to_string()),
+                Some(zone.to_string()),
+                node_ids.iter().map(|id| (*id).to_string()).collect(),
+            )
+        };
+        let sc1 = make_scan_task(1).with_locality_hints(hints("us-west-2a", &["n1", "n2"]));
+        let sc2 = make_scan_task(1).with_locality_hints(hints("us-west-2b", &["n3", "n2"]));
+
+        let merged = ScanTask::merge(&sc1, &sc2)?;
+        assert_eq!(
+            merged.locality_hints,
+            Some(LocalityHints::new_internal(
+                Some("us-west-2".to_string()),
+                None,
+                vec!["n2".to_string(), "n1".to_string(), "n3".to_string()],
+            ))
+        );
+
+        let merged = ScanTask::merge(&make_scan_task(1), &make_scan_task(1))?;
+        assert_eq!(merged.locality_hints, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_column_defaults_read_their_source_columns() -> DaftResult<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Int64),
+        ])?);
+        let pushdowns = Pushdowns::new(None, None, Some(Arc::new(vec!["b".to_string()])), None);
+        let base = make_scan_task(1);
+        let scan_task = ScanTask::new(
+            base.sources,
+            base.file_format_config,
+            schema,
+            base.storage_config,
+            pushdowns,
+            None,
+        )
+        .with_column_defaults(Some(ColumnDefaults::new(vec![
+            ("b".to_string(), col("a").gt(lit(1))),
+            ("c".to_string(), lit(0)),
+        ])));
+
+        assert_eq!(scan_task.file_column_names(), Some(vec!["b", "a"]));
+        let fill_map = scan_task.fill_map().unwrap();
+        assert_eq!(fill_map.get("b"), Some(&col("a").gt(lit(1))));
+        assert_eq!(fill_map.get("c"), Some(&lit(0)));
+
+        let without_defaults =
+            make_scan_task(1).with_column_defaults(Some(ColumnDefaults::default()));
+        assert_eq!(without_defaults.column_defaults, None);
+        Ok(())
+    }
+
     async fn make_glob_scan_operator(num_sources: usize) -> GlobScanOperator {
         let file_format_config: FileFormatConfig = FileFormatConfig::Parquet(ParquetSourceConfig {
             coerce_int96_timestamp_unit: TimeUnit::Seconds,
@@ -0,0 +1,111 @@
+use common_py_serde::impl_bincode_py_state_serialization;
+use itertools::Itertools;
+#[cfg(feature = "python")]
+use pyo3::{pyclass, pymethods, PyObject, PyResult, Python};
+use serde::{Deserialize, Serialize};
+
+/// Hints about where the data that a scan task reads lives, which distributed runners use to
+/// schedule the task close to its data.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct LocalityHints {
+    /// Region of the bucket that holds the data, e.g. `us-west-2`.
+    pub region: Option<String>,
+    /// Availability zone of the bucket that holds the data, e.g. `us-west-2a`.
+    pub zone: Option<String>,
+    /// Ids of the nodes that have the data cached, from the most to the least preferred.
+    pub cached_node_ids: Vec<String>,
+}
+
+impl LocalityHints {
+    #[must_use]
+    pub fn new_internal(
+        region: Option<String>,
+        zone: Option<String>,
+        cached_node_ids: Vec<String>,
+    ) -> Self {
+        Self {
+            region,
+            zone,
+            cached_node_ids,
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.region.is_none() && self.zone.is_none() && self.cached_node_ids.is_empty()
+    }
+
+    /// Hints for reading the data of both `self` and `other` in a single task.
+    ///
+    /// The region and zone are only kept if both agree on them, and the nodes that have the data
+    /// of both cached are preferred over those that only have the data of either.
+    #[must_use]
+    pub fn merge(&self, other: &Self) -> Self {
+        let agreed = |left: &Option<String>, right: &Option<String>| {
+            if left == right {
+                left.clone()
+            } else {
+                None
+            }
+        };
+        let (shared, unshared): (Vec<_>, Vec<_>) = self
+            .cached_node_ids
+            .iter()
+            .chain(&other.cached_node_ids)
+            .unique()
+            .partition(|node_id| {
+                self.cached_node_ids.contains(node_id) && other.cached_node_ids.contains(node_id)
+            });
+        Self {
+            region: agreed(&self.region, &other.region),
+            zone: agreed(&self.zone, &other.zone),
+            cached_node_ids: shared.into_iter().chain(unshared).cloned().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if let Some(region) = &self.region {
+            res.push(format!("Region = {region}"));
+        }
+        if let Some(zone) = &self.zone {
+            res.push(format!("Zone = {zone}"));
+        }
+        if !self.cached_node_ids.is_empty() {
+            res.push(format!(
+                "Cached on nodes = [{}]",
+                self.cached_node_ids.join(", ")
+            ));
+        }
+        res
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl LocalityHints {
+    /// Create locality hints for a scan task.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - Region of the bucket that holds the data.
+    /// * `zone` - Availability zone of the bucket that holds the data.
+    /// * `cached_node_ids` - Ids of the nodes that have the data cached, from the most to the least preferred.
+    #[new]
+    #[pyo3(signature = (region=None, zone=None, cached_node_ids=None))]
+    fn new(
+        region: Option<String>,
+        zone: Option<String>,
+        cached_node_ids: Option<Vec<String>>,
+    ) -> Self {
+        Self::new_internal(region, zone, cached_node_ids.unwrap_or_default())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl_bincode_py_state_serialization!(LocalityHints);
@@ -53,13 +53,17 @@ impl PythonTablesFactoryArgs {
 
 impl PartialEq for PythonTablesFactoryArgs {
     fn eq(&self, other: &Self) -> bool {
-        if self.args.len() != other.args.len() {
+        if self.hash != other.hash || self.args.len() != other.args.len() {
             return false;
         }
-        self.args
-            .iter()
-            .zip(other.args.iter())
-            .all(|(s, o)| (s.0.as_ptr() as isize) == (o.0.as_ptr() as isize))
+        // Arguments are compared by value rather than by identity so that a deserialized copy of
+        // the arguments, such as one shipped to another process, equals the original.
+        Python::with_gil(|py| {
+            self.args.iter().zip(other.args.iter()).all(|(s, o)| {
+                let (s, o) = (s.0.bind(py), o.0.bind(py));
+                s.is(o) || s.eq(o).unwrap_or(false)
+            })
+        })
     }
 }
 
@@ -68,27 +72,30 @@ pub mod pylib {
 
     use common_daft_config::PyDaftExecutionConfig;
     use common_error::DaftResult;
-    use common_file_formats::{python::PyFileFormatConfig, FileFormatConfig};
+    use common_file_formats::{python::PyFileFormatConfig, FileFormatConfig, KafkaSourceConfig};
     use common_py_serde::impl_bincode_py_state_serialization;
     use common_scan_info::{
         python::pylib::{PyPartitionField, PyPushdowns},
         PartitionField, Pushdowns, ScanOperator, ScanOperatorRef, ScanTaskLike, ScanTaskLikeRef,
     };
+    use daft_dsl::python::PyExpr;
+    use daft_kafka::KafkaOffsetSpec;
     use daft_logical_plan::{LogicalPlanBuilder, PyLogicalPlanBuilder};
     use daft_schema::{python::schema::PySchema, schema::SchemaRef};
     use daft_stats::{PartitionSpec, TableMetadata, TableStatistics};
     use daft_table::{python::PyTable, Table};
     use pyo3::{
+        exceptions::PyValueError,
         prelude::*,
         pyclass,
-        types::{PyIterator, PyList},
+        types::{PyDict, PyIterator, PyList},
     };
     use serde::{Deserialize, Serialize};
 
     use super::PythonTablesFactoryArgs;
     use crate::{
         anonymous::AnonymousScanOperator, glob::GlobScanOperator, storage_config::StorageConfig,
-        DataSource, ScanTask,
+        ColumnDefaults, DataSource, KafkaScanOperator, LocalityHints, ScanTask,
     };
     #[pyclass(module = "daft.daft", frozen)]
     #[derive(Debug, Clone)]
@@ -158,6 +165,33 @@ pub mod pylib {
             })
         }
 
+        #[staticmethod]
+        pub fn kafka_scan(
+            py: Python,
+            config: KafkaSourceConfig,
+            schema: PySchema,
+            partitions: Option<Vec<i32>>,
+            start: &Bound<PyAny>,
+            end: &Bound<PyAny>,
+            max_messages_per_task: Option<u64>,
+        ) -> PyResult<Self> {
+            let start = kafka_offset_spec(start)?;
+            let end = kafka_offset_spec(end)?;
+            py.allow_threads(|| {
+                let operator = KafkaScanOperator::try_new(
+                    config,
+                    schema.schema,
+                    partitions,
+                    start,
+                    end,
+                    max_messages_per_task,
+                )?;
+                Ok(Self {
+                    scan_op: ScanOperatorRef(Arc::new(operator)),
+                })
+            })
+        }
+
         #[staticmethod]
         pub fn from_python_scan_operator(py_scan: PyObject, py: Python) -> PyResult<Self> {
             let scan_op = ScanOperatorRef(Arc::new(PythonScanOperatorBridge::from_python_abc(
@@ -166,6 +200,24 @@ pub mod pylib {
             Ok(Self { scan_op })
         }
     }
+    /// Extracts where a Kafka scan starts or ends from either "earliest", "latest", a timestamp in
+    /// milliseconds since the epoch, or a dict of offsets per partition.
+    fn kafka_offset_spec(spec: &Bound<PyAny>) -> PyResult<KafkaOffsetSpec> {
+        if let Ok(offsets) = spec.downcast::<PyDict>() {
+            return Ok(KafkaOffsetSpec::Offsets(offsets.extract()?));
+        }
+        if let Ok(timestamp) = spec.extract::<i64>() {
+            return Ok(KafkaOffsetSpec::Timestamp(timestamp));
+        }
+        match spec.extract::<String>()?.as_str() {
+            "earliest" => Ok(KafkaOffsetSpec::Earliest),
+            "latest" => Ok(KafkaOffsetSpec::Latest),
+            other => Err(PyValueError::new_err(format!(
+                "Expected a Kafka offset to be \"earliest\", \"latest\", a timestamp or a dict of offsets per partition, but got {other}"
+            ))),
+        }
+    }
+
     #[pyclass(module = "daft.daft")]
     #[derive(Debug)]
     struct PythonScanOperatorBridge {
@@ -334,6 +386,46 @@ pub mod pylib {
                 .map(i64::try_from)
                 .transpose()?)
         }
+
+        pub fn locality_hints(&self) -> Option<LocalityHints> {
+            self.0.locality_hints.clone()
+        }
+
+        /// Returns a copy of this scan task with the given hints about where its data lives.
+        pub fn with_locality_hints(&self, locality_hints: LocalityHints) -> Self {
+            let scan_task = ScanTask::new(
+                self.0.sources.clone(),
+                self.0.file_format_config.clone(),
+                self.0.schema.clone(),
+                self.0.storage_config.clone(),
+                self.0.pushdowns.clone(),
+                self.0.generated_fields.clone(),
+            )
+            .with_locality_hints(locality_hints)
+            .with_column_defaults(self.0.column_defaults.clone());
+            Self(scan_task.into())
+        }
+
+        /// Returns a copy of this scan task that fills the given columns with the values of the
+        /// given expressions for sources that don't have them.
+        pub fn with_column_defaults(&self, column_defaults: Vec<(String, PyExpr)>) -> Self {
+            let mut scan_task = ScanTask::new(
+                self.0.sources.clone(),
+                self.0.file_format_config.clone(),
+                self.0.schema.clone(),
+                self.0.storage_config.clone(),
+                self.0.pushdowns.clone(),
+                self.0.generated_fields.clone(),
+            )
+            .with_column_defaults(Some(ColumnDefaults::new(
+                column_defaults
+                    .into_iter()
+                    .map(|(name, expr)| (name, expr.into()))
+                    .collect(),
+            )));
+            scan_task.locality_hints.clone_from(&self.0.locality_hints);
+            Self(scan_task.into())
+        }
     }
 
     #[pymethods]
@@ -481,6 +573,18 @@ pub mod pylib {
         pub fn __repr__(&self) -> PyResult<String> {
             Ok(format!("{:?}", self.0))
         }
+
+        pub fn __eq__(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+
+        pub fn __hash__(&self) -> u64 {
+            use std::{collections::hash_map::DefaultHasher, hash::Hash};
+
+            let mut hasher = DefaultHasher::new();
+            self.0.hash(&mut hasher);
+            hasher.finish()
+        }
     }
 
     impl From<Arc<ScanTask>> for PyScanTask {
@@ -572,6 +676,7 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
 
     parent.add_class::<pylib::ScanOperatorHandle>()?;
     parent.add_class::<pylib::PyScanTask>()?;
+    parent.add_class::<crate::LocalityHints>()?;
     parent.add_function(wrap_pyfunction_bound!(
         pylib::logical_plan_table_scan,
         parent
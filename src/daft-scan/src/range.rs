@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use common_file_formats::FileFormatConfig;
+use common_scan_info::{PartitionField, Pushdowns, ScanOperator, ScanTaskLike, ScanTaskLikeRef};
+use daft_core::{
+    prelude::{DataType, Field, Int64Array, Schema},
+    series::IntoSeries,
+};
+use daft_schema::schema::SchemaRef;
+use daft_stats::TableMetadata;
+use daft_table::Table;
+use serde::{Deserialize, Serialize};
+
+use crate::{storage_config::StorageConfig, DataSource, ScanTask};
+
+/// The integers from `start` up to but excluding `end`, in increments of `step`, which counts
+/// down if it is negative.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IntRange {
+    pub start: i64,
+    pub end: i64,
+    pub step: i64,
+}
+
+impl IntRange {
+    /// Number of integers in the range.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        let (start, end, step) = (
+            i128::from(self.start),
+            i128::from(self.end),
+            i128::from(self.step),
+        );
+        if (step > 0 && end > start) || (step < 0 && end < start) {
+            ((end - start + step - step.signum()) / step) as usize
+        } else {
+            0
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Splits the range into `num_parts` consecutive ranges whose lengths differ by at most one.
+    #[must_use]
+    pub fn split(&self, num_parts: usize) -> Vec<Self> {
+        let len = self.len();
+        // The end of the last part may lie beyond the bounds of i64, in which case the bound is an
+        // equivalent end since the range never contains it.
+        let bound = |i: usize| {
+            self.nth(i)
+                .clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64
+        };
+        (0..num_parts)
+            .map(|part| Self {
+                start: bound(part * len / num_parts),
+                end: bound((part + 1) * len / num_parts),
+                step: self.step,
+            })
+            .collect()
+    }
+
+    /// Generates the integers of the range, or the first `limit` of them, as the `id` column of a
+    /// table with the schema of a [`RangeScanOperator`].
+    pub fn to_table(&self, limit: Option<usize>) -> DaftResult<Table> {
+        let len = limit.map_or(self.len(), |limit| limit.min(self.len()));
+        let ids = Int64Array::from_values(RANGE_COLUMN_NAME, (0..len).map(|i| self.nth(i) as i64));
+        Table::new_with_size(range_schema(), vec![ids.into_series()], len)
+    }
+
+    /// The `i`th integer of the range, if it were unbounded.
+    fn nth(&self, i: usize) -> i128 {
+        i128::from(self.start) + i as i128 * i128::from(self.step)
+    }
+}
+
+const RANGE_COLUMN_NAME: &str = "id";
+
+fn range_schema() -> SchemaRef {
+    Arc::new(
+        Schema::new(vec![Field::new(RANGE_COLUMN_NAME, DataType::Int64)])
+            .expect("Schema of a single field is valid"),
+    )
+}
+
+/// Scan of a range of integers, which are generated in the `id` column rather than read from a
+/// source. Each of the `num_partitions` partitions of the range is generated by its own scan task.
+#[derive(Debug)]
+pub struct RangeScanOperator {
+    range: IntRange,
+    num_partitions: usize,
+    schema: SchemaRef,
+}
+
+impl RangeScanOperator {
+    pub fn try_new(start: i64, end: i64, step: i64, num_partitions: usize) -> DaftResult<Self> {
+        if step == 0 {
+            return Err(DaftError::ValueError(
+                "The step of a range must not be 0".to_string(),
+            ));
+        }
+        if num_partitions == 0 {
+            return Err(DaftError::ValueError(
+                "A range must have at least 1 partition".to_string(),
+            ));
+        }
+        Ok(Self {
+            range: IntRange { start, end, step },
+            num_partitions,
+            schema: range_schema(),
+        })
+    }
+}
+
+impl ScanOperator for RangeScanOperator {
+    fn name(&self) -> &str {
+        "RangeScanOperator"
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn partitioning_keys(&self) -> &[PartitionField] {
+        &[]
+    }
+
+    fn file_path_column(&self) -> Option<&str> {
+        None
+    }
+
+    fn generated_fields(&self) -> Option<SchemaRef> {
+        None
+    }
+
+    fn can_absorb_filter(&self) -> bool {
+        false
+    }
+
+    fn can_absorb_select(&self) -> bool {
+        false
+    }
+
+    fn can_absorb_limit(&self) -> bool {
+        true
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![
+            "RangeScanOperator".to_string(),
+            format!(
+                "Range = {}..{} step {}",
+                self.range.start, self.range.end, self.range.step
+            ),
+            format!("Num partitions = {}", self.num_partitions),
+        ]
+    }
+
+    fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>> {
+        let file_format_config = Arc::new(FileFormatConfig::Range);
+        let storage_config = Arc::new(StorageConfig::default());
+        Ok(self
+            .range
+            .split(self.num_partitions)
+            .into_iter()
+            .map(|range| {
+                Arc::new(ScanTask::new(
+                    vec![DataSource::Range {
+                        path: format!("range://{}..{}", range.start, range.end),
+                        metadata: TableMetadata {
+                            length: range.len(),
+                        },
+                        range,
+                    }],
+                    file_format_config.clone(),
+                    self.schema.clone(),
+                    storage_config.clone(),
+                    pushdowns.clone(),
+                    None,
+                )) as Arc<dyn ScanTaskLike>
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(range: IntRange) -> Vec<i64> {
+        range
+            .to_table(None)
+            .unwrap()
+            .get_column(RANGE_COLUMN_NAME)
+            .unwrap()
+            .i64()
+            .unwrap()
+            .as_arrow()
+            .values_iter()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn test_range_len() {
+        let len = |start, end, step| IntRange { start, end, step }.len();
+        assert_eq!(len(0, 10, 1), 10);
+        assert_eq!(len(0, 10, 3), 4);
+        assert_eq!(len(10, 0, -3), 4);
+        assert_eq!(len(0, 10, -1), 0);
+        assert_eq!(len(5, 5, 1), 0);
+        assert_eq!(len(i64::MIN, i64::MAX, i64::MAX), 3);
+    }
+
+    #[test]
+    fn test_range_split_is_step_aligned() {
+        let range = IntRange {
+            start: 0,
+            end: 10,
+            step: 3,
+        };
+        let parts = range.split(3);
+        assert_eq!(
+            parts.iter().map(|part| values(*part)).collect::<Vec<_>>(),
+            vec![vec![0], vec![3], vec![6, 9]]
+        );
+        assert_eq!(
+            parts.into_iter().flat_map(values).collect::<Vec<_>>(),
+            values(range)
+        );
+    }
+
+    #[test]
+    fn test_range_split_into_more_parts_than_values() {
+        let range = IntRange {
+            start: 10,
+            end: 8,
+            step: -1,
+        };
+        let parts = range.split(4);
+        assert_eq!(parts.len(), 4);
+        assert_eq!(
+            parts.into_iter().flat_map(values).collect::<Vec<_>>(),
+            vec![10, 9]
+        );
+    }
+
+    #[test]
+    fn test_range_to_table_with_limit() {
+        let range = IntRange {
+            start: 0,
+            end: 100,
+            step: 2,
+        };
+        assert_eq!(range.to_table(Some(3)).unwrap().len(), 3);
+        assert_eq!(range.to_table(Some(1000)).unwrap().len(), 50);
+    }
+
+    #[test]
+    fn test_range_scan_operator_rejects_zero_step() {
+        assert!(RangeScanOperator::try_new(0, 10, 0, 1).is_err());
+        assert!(RangeScanOperator::try_new(0, 10, 1, 0).is_err());
+    }
+}
@@ -284,15 +284,17 @@ pub(crate) fn split_by_row_groups(
                                 curr_size_bytes = 0;
                                 curr_num_rows = 0;
 
-                                new_tasks.push(Ok(ScanTask::new(
+                                let mut new_task = ScanTask::new(
                                     vec![new_source],
                                     t.file_format_config.clone(),
                                     t.schema.clone(),
                                     t.storage_config.clone(),
                                     t.pushdowns.clone(),
                                     t.generated_fields.clone(),
-                                )
-                                .into()));
+                                );
+                                new_task.locality_hints.clone_from(&t.locality_hints);
+                                new_task.column_defaults.clone_from(&t.column_defaults);
+                                new_tasks.push(Ok(new_task.into()));
                             }
                         }
 
@@ -1,9 +1,11 @@
 mod adaptive;
+mod runner;
 mod scheduler;
 
 pub use adaptive::AdaptivePhysicalPlanScheduler;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
+pub use runner::{execute_adaptive, query_fingerprint, QueryStage, Runner, StageCheckpointKey};
 pub use scheduler::PhysicalPlanScheduler;
 
 #[cfg(feature = "python")]
@@ -0,0 +1,254 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Write},
+    sync::Arc,
+    time::UNIX_EPOCH,
+};
+
+use common_daft_config::DaftExecutionConfig;
+use common_display::DisplayAs;
+use common_error::DaftResult;
+use common_scan_info::{ScanOperator, ScanState};
+use daft_core::prelude::SchemaRef;
+use daft_logical_plan::{InMemoryInfo, InMemorySortOrder, LogicalPlan, LogicalPlanRef, SourceInfo};
+use daft_physical_plan::{AdaptivePlanner, MaterializedResults, PhysicalPlanRef};
+use daft_scan::{DataSource, ScanTask};
+
+/// Identifies the outputs of a stage of a query across runs of the query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StageCheckpointKey {
+    /// Hash of the query's logical plan and of its inputs, see [`query_fingerprint`].
+    /// It is only computed when checkpointing is enabled, and is 0 otherwise.
+    pub query_fingerprint: u64,
+    /// Position of the stage in the query, starting at 0
+    pub stage_id: usize,
+}
+
+impl Display for StageCheckpointKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}/stage-{}", self.query_fingerprint, self.stage_id)
+    }
+}
+
+/// A stage of a query, emitted by the adaptive planner once the stages it depends on completed
+pub struct QueryStage {
+    pub checkpoint_key: StageCheckpointKey,
+    /// Id of the placeholder that the stage's outputs replace in the remaining plan, or None for
+    /// the final stage
+    pub source_id: Option<usize>,
+    pub physical_plan: PhysicalPlanRef,
+    /// The logical plan computed by the stage, for runners that plan execution themselves
+    pub logical_plan: LogicalPlanRef,
+    /// Where the partitions of the stage's in-memory inputs live, by cache key
+    pub input_locality: HashMap<String, Vec<Option<String>>>,
+}
+
+/// A backend that executes the stages of a query, e.g. the local executor or a cluster of workers.
+///
+/// Planning stays in [`execute_adaptive`], so backends only have to run stages and report where
+/// their outputs are.
+pub trait Runner {
+    /// A stage that was submitted but may not have completed yet
+    type StageHandle;
+    /// The materialized outputs of a stage
+    type Partitions;
+
+    /// Starts executing a stage
+    fn submit_stage(&self, stage: QueryStage) -> DaftResult<Self::StageHandle>;
+
+    /// Waits for a submitted stage to complete
+    fn await_partitions(&self, handle: Self::StageHandle) -> DaftResult<Self::Partitions>;
+
+    /// Makes the outputs of a stage, which have the given schema, readable by later stages as an
+    /// in-memory source
+    fn materialize(
+        &self,
+        partitions: Self::Partitions,
+        schema: SchemaRef,
+    ) -> DaftResult<InMemoryInfo>;
+
+    /// The worker holding each partition of a stage's outputs, if known, so that later stages can
+    /// be scheduled close to their inputs
+    fn locality_hints(&self, _partitions: &Self::Partitions) -> Vec<Option<String>> {
+        Vec::new()
+    }
+
+    /// Loads the outputs of a stage that completed in a previous run of the query, if they were
+    /// checkpointed
+    fn load_checkpoint(&self, _key: &StageCheckpointKey) -> DaftResult<Option<Self::Partitions>> {
+        Ok(None)
+    }
+
+    /// Persists the outputs of a completed stage, so that a rerun of the query can skip it
+    fn save_checkpoint(
+        &self,
+        _key: &StageCheckpointKey,
+        _partitions: &Self::Partitions,
+    ) -> DaftResult<()> {
+        Ok(())
+    }
+
+    /// Deletes the checkpoints of `keys` once the final stage of `handle` completes successfully,
+    /// so that the outputs of a query that succeeded are not kept around
+    fn remove_checkpoints_on_success(
+        &self,
+        handle: Self::StageHandle,
+        _keys: Vec<StageCheckpointKey>,
+    ) -> Self::StageHandle {
+        handle
+    }
+}
+
+/// A hash of `plan` that stays the same across processes as long as the plan and its inputs do.
+///
+/// The hash covers the display of the plan, the cache keys of its in-memory sources, and the
+/// scan operators of its scans with their pushdowns and the paths they read, plus the
+/// modification time of local paths. Scans are not planned into scan tasks for the hash, so
+/// remote files that are added under a glob or rewritten in place are not detected as changed,
+/// and neither are local files that are changed under a directory or glob without changing the
+/// modification time of the path itself.
+pub fn query_fingerprint(plan: &LogicalPlanRef) -> DaftResult<u64> {
+    let mut fingerprint = plan.repr_indent();
+    write_inputs(plan, &mut fingerprint)?;
+    Ok(xxhash_rust::xxh3::xxh3_64(fingerprint.as_bytes()))
+}
+
+fn write_inputs(plan: &LogicalPlan, out: &mut String) -> DaftResult<()> {
+    if let LogicalPlan::Source(source) = plan {
+        match source.source_info.as_ref() {
+            SourceInfo::InMemory(info) => writeln!(out, "in-memory {}", info.cache_key)?,
+            SourceInfo::Physical(info) => {
+                writeln!(out, "pushdowns {:?}", info.pushdowns)?;
+                match &info.scan_state {
+                    ScanState::Operator(scan_op) => write_scan_operator(scan_op.0.as_ref(), out)?,
+                    ScanState::Tasks(scan_tasks) => {
+                        for scan_task in scan_tasks.iter() {
+                            match scan_task.as_any().downcast_ref::<ScanTask>() {
+                                Some(scan_task) => {
+                                    for source in &scan_task.sources {
+                                        write_data_source(source, out)?;
+                                    }
+                                }
+                                None => writeln!(
+                                    out,
+                                    "scan task {}",
+                                    scan_task.display_as(common_display::DisplayLevel::Default)
+                                )?,
+                            }
+                        }
+                    }
+                }
+            }
+            SourceInfo::PlaceHolder(_) | SourceInfo::Stream(_) => {}
+        }
+    }
+    for child in plan.children() {
+        write_inputs(child, out)?;
+    }
+    Ok(())
+}
+
+/// Writes the identity of a scan operator, from its display, schema and the paths it reads
+fn write_scan_operator(scan_op: &dyn ScanOperator, out: &mut String) -> DaftResult<()> {
+    writeln!(
+        out,
+        "scan {} schema={}",
+        scan_op.name(),
+        scan_op.schema().short_string()
+    )?;
+    for line in scan_op.multiline_display() {
+        writeln!(out, "{line}")?;
+    }
+    for path in scan_op.file_paths() {
+        write!(out, "path {path}")?;
+        write_modified_time(&path, out)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn write_data_source(source: &DataSource, out: &mut String) -> DaftResult<()> {
+    let path = source.get_path();
+    write!(out, "file {path} size={:?}", source.get_size_bytes())?;
+    write_modified_time(path, out)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+/// Writes the modification time of `path` if it is a local path that exists
+fn write_modified_time(path: &str, out: &mut String) -> DaftResult<()> {
+    if !path.contains("://") || path.starts_with("file://") {
+        let local_path = path.strip_prefix("file://").unwrap_or(path);
+        if let Ok(modified) = std::fs::metadata(local_path).and_then(|m| m.modified()) {
+            let modified = modified.duration_since(UNIX_EPOCH).unwrap_or_default();
+            write!(out, " modified={}", modified.as_nanos())?;
+        }
+    }
+    Ok(())
+}
+
+/// Executes a logical plan stage by stage on `runner`, replanning after each stage with the
+/// statistics of its outputs, and returns the handle of the final stage.
+///
+/// Stages whose outputs were checkpointed by a previous run of the same plan over the same inputs
+/// are not executed again, and the checkpoints are deleted once the final stage succeeds.
+pub fn execute_adaptive<R: Runner>(
+    runner: &R,
+    logical_plan: LogicalPlanRef,
+    cfg: Arc<DaftExecutionConfig>,
+) -> DaftResult<R::StageHandle> {
+    let query_fingerprint = if cfg.checkpoint_dir.is_some() {
+        query_fingerprint(&logical_plan)?
+    } else {
+        0
+    };
+
+    let mut planner = AdaptivePlanner::new(logical_plan, cfg);
+    let mut locality = HashMap::new();
+    let mut checkpoint_keys = Vec::new();
+    for stage_id in 0usize.. {
+        let checkpoint_key = StageCheckpointKey {
+            query_fingerprint,
+            stage_id,
+        };
+        let (output, logical_plan) = planner.next_stage_with_logical_plan()?;
+        let (source_id, physical_plan) = output.unwrap();
+        let stage = QueryStage {
+            checkpoint_key,
+            source_id,
+            physical_plan,
+            logical_plan,
+            input_locality: locality.clone(),
+        };
+
+        let Some(source_id) = source_id else {
+            let handle = runner.submit_stage(stage)?;
+            return Ok(runner.remove_checkpoints_on_success(handle, checkpoint_keys));
+        };
+        checkpoint_keys.push(checkpoint_key);
+        let schema = stage.logical_plan.schema();
+        let sort_order = InMemorySortOrder::of_plan(&stage.logical_plan);
+        let partitions = if let Some(partitions) = runner.load_checkpoint(&checkpoint_key)? {
+            log::info!("Resuming from checkpointed outputs of stage {checkpoint_key}");
+            partitions
+        } else {
+            let partitions = runner.await_partitions(runner.submit_stage(stage)?)?;
+            runner.save_checkpoint(&checkpoint_key, &partitions)?;
+            partitions
+        };
+
+        let hints = runner.locality_hints(&partitions);
+        let mut in_memory_info = runner.materialize(partitions, schema)?;
+        if let Some(sort_order) = sort_order {
+            in_memory_info = in_memory_info.with_sort_order(sort_order);
+        }
+        if !hints.is_empty() {
+            locality.insert(in_memory_info.cache_key.clone(), hints);
+        }
+        planner.update(MaterializedResults {
+            source_id,
+            in_memory_info,
+        })?;
+    }
+    unreachable!("a query cannot have more than usize::MAX stages")
+}
@@ -13,10 +13,10 @@ use daft_physical_plan::ops::{DeltaLakeWrite, IcebergWrite, LanceWrite};
 use daft_physical_plan::{
     logical_to_physical,
     ops::{
-        ActorPoolProject, Aggregate, BroadcastJoin, Concat, EmptyScan, Explode, Filter, HashJoin,
-        InMemoryScan, Limit, MonotonicallyIncreasingId, Pivot, Project, Sample, Sort,
-        SortMergeJoin, TabularScan, TabularWriteCsv, TabularWriteJson, TabularWriteParquet,
-        Unpivot,
+        ActorPoolProject, Aggregate, AssertUnique, BroadcastJoin, Concat, EmptyScan, Explode,
+        Filter, HashJoin, InMemoryScan, Limit, MonotonicallyIncreasingId, Pivot, Project, Sample,
+        SkewSalting, Sort, SortMergeJoin, TabularScan, TabularWriteCsv, TabularWriteJson,
+        TabularWriteParquet, Unpivot,
     },
     PhysicalPlan, PhysicalPlanRef, QueryStageOutput,
 };
@@ -24,11 +24,13 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "python")]
 use {
     common_daft_config::PyDaftExecutionConfig,
+    common_file_formats::CsvWriteOptions,
     common_io_config::IOConfig,
     daft_core::prelude::SchemaRef,
     daft_core::python::PySchema,
-    daft_dsl::python::PyExpr,
-    daft_logical_plan::{OutputFileInfo, PyLogicalPlanBuilder},
+    daft_dsl::{functions::python::RuntimePyObject, python::PyExpr},
+    daft_logical_plan::{OutputFileInfo, PyLogicalPlanBuilder, WriteAttempt, WriteConstraints},
+    daft_micropartition::{partitioning::MicroPartitionSet, python::PyMicroPartition},
     daft_scan::python::pylib::PyScanTask,
     pyo3::{pyclass, pymethods, types::PyAnyMethods, PyObject, PyRef, PyRefMut, PyResult, Python},
     std::collections::HashMap,
@@ -143,6 +145,41 @@ impl PartitionIterator {
     }
 }
 
+/// The partitions of an in-memory scan that are held by its Rust cache entry instead of the
+/// partition set cache of the runner, as materialized results
+#[cfg(feature = "python")]
+fn cache_entry_partitions(py: Python, info: &InMemoryInfo) -> PyResult<Vec<PyObject>> {
+    let Some(pset) = MicroPartitionSet::from_cache_entry(&info.cache_entry) else {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Cache key not found: {}",
+            info.cache_key
+        )));
+    };
+    let micropartition_class = py
+        .import_bound(pyo3::intern!(py, "daft.table"))?
+        .getattr(pyo3::intern!(py, "MicroPartition"))?;
+    let materialized_result_class = py
+        .import_bound(pyo3::intern!(py, "daft.runners.partitioning"))?
+        .getattr(pyo3::intern!(py, "LocalMaterializedResult"))?;
+
+    let mut partitions = pset
+        .partitions
+        .iter()
+        .map(|part| (*part.key(), part.value().clone()))
+        .collect::<Vec<_>>();
+    partitions.sort_by_key(|(idx, _)| *idx);
+    partitions
+        .into_iter()
+        .map(|(_, part)| {
+            let part = micropartition_class.call_method1(
+                pyo3::intern!(py, "_from_pymicropartition"),
+                (PyMicroPartition::from(part),),
+            )?;
+            Ok(materialized_result_class.call1((part,))?.unbind())
+        })
+        .collect()
+}
+
 #[cfg(feature = "python")]
 fn exprs_to_pyexprs(exprs: &[ExprRef]) -> Vec<PyExpr> {
     exprs.iter().map(|e| e.clone().into()).collect()
@@ -159,7 +196,16 @@ fn tabular_write(
     compression: &Option<String>,
     partition_cols: &Option<Vec<ExprRef>>,
     io_config: &Option<IOConfig>,
+    parquet_encryption: &Option<RuntimePyObject>,
+    csv_options: &Option<CsvWriteOptions>,
+    write_attempt: &Option<WriteAttempt>,
+    constraints: &Option<WriteConstraints>,
 ) -> PyResult<PyObject> {
+    if constraints.is_some() {
+        return Err(pyo3::exceptions::PyNotImplementedError::new_err(
+            "Write constraints are only supported by the native runner",
+        ));
+    }
     let py_iter = py
         .import_bound(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
         .getattr(pyo3::intern!(py, "write_file"))?
@@ -175,6 +221,12 @@ fn tabular_write(
                 .map(|cfg| common_io_config::python::IOConfig {
                     config: cfg.clone(),
                 }),
+            parquet_encryption
+                .as_ref()
+                .map(|encryption| encryption.as_ref().clone_ref(py)),
+            csv_options.clone(),
+            write_attempt.as_ref().map(|attempt| &attempt.job_id),
+            write_attempt.as_ref().map(|attempt| &attempt.attempt_id),
         ))?;
     Ok(py_iter.into())
 }
@@ -267,12 +319,13 @@ fn physical_plan_to_partition_tasks(
     use daft_physical_plan::ops::{CrossJoin, ShuffleExchange, ShuffleExchangeStrategy};
 
     match physical_plan {
-        PhysicalPlan::InMemoryScan(InMemoryScan {
-            in_memory_info: InMemoryInfo { cache_key, .. },
-            ..
-        }) => {
+        PhysicalPlan::InMemoryScan(InMemoryScan { in_memory_info, .. }) => {
+            let parts = match psets.get(&in_memory_info.cache_key) {
+                Some(parts) => parts.clone(),
+                None => cache_entry_partitions(py, in_memory_info)?,
+            };
             let partition_iter = PartitionIterator {
-                parts: psets[cache_key].clone(),
+                parts,
                 index: 0usize,
             };
             let py_iter = py
@@ -450,6 +503,17 @@ fn physical_plan_to_partition_tasks(
                 .call1((upstream_iter, *fraction, *with_replacement, *seed))?;
             Ok(py_iter.into())
         }
+        PhysicalPlan::AssertUnique(AssertUnique { input, keys }) => {
+            let upstream_iter =
+                physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
+            let keys_pyexprs: Vec<PyExpr> =
+                keys.iter().map(|expr| PyExpr::from(expr.clone())).collect();
+            let py_iter = py
+                .import_bound(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                .getattr(pyo3::intern!(py, "assert_unique"))?
+                .call1((upstream_iter, keys_pyexprs))?;
+            Ok(py_iter.into())
+        }
         PhysicalPlan::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
             input,
             column_name,
@@ -515,8 +579,23 @@ fn physical_plan_to_partition_tasks(
                             .import_bound(pyo3::intern!(py, "daft.execution.physical_plan"))?
                             .getattr(pyo3::intern!(py, "fanout_random"))?
                             .call1((upstream_iter, random_clustering_config.num_partitions()))?,
-                        daft_logical_plan::ClusteringSpec::Range(_) => {
-                            unimplemented!("FanoutByRange not implemented, since only use case (sorting) doesn't need it yet.");
+                        daft_logical_plan::ClusteringSpec::Range(range_clustering_config) => {
+                            let partition_by_pyexprs: Vec<PyExpr> = range_clustering_config
+                                .by
+                                .iter()
+                                .map(|expr| PyExpr::from(expr.clone()))
+                                .collect();
+                            py.import_bound(pyo3::intern!(
+                                py,
+                                "daft.execution.rust_physical_plan_shim"
+                            ))?
+                            .getattr(pyo3::intern!(py, "fanout_by_range"))?
+                            .call1((
+                                upstream_iter,
+                                range_clustering_config.num_partitions,
+                                partition_by_pyexprs,
+                                range_clustering_config.descending.clone(),
+                            ))?
                         }
                         daft_logical_plan::ClusteringSpec::Unknown(_) => {
                             unreachable!("Cannot use NaiveFullyMaterializingMapReduce ShuffleExchange to map to an Unknown ClusteringSpec");
@@ -561,8 +640,23 @@ fn physical_plan_to_partition_tasks(
                             .import_bound(pyo3::intern!(py, "daft.execution.physical_plan"))?
                             .getattr(pyo3::intern!(py, "fanout_random"))?
                             .call1((merged, random_clustering_config.num_partitions()))?,
-                        daft_logical_plan::ClusteringSpec::Range(_) => {
-                            unimplemented!("FanoutByRange not implemented, since only use case (sorting) doesn't need it yet.");
+                        daft_logical_plan::ClusteringSpec::Range(range_clustering_config) => {
+                            let partition_by_pyexprs: Vec<PyExpr> = range_clustering_config
+                                .by
+                                .iter()
+                                .map(|expr| PyExpr::from(expr.clone()))
+                                .collect();
+                            py.import_bound(pyo3::intern!(
+                                py,
+                                "daft.execution.rust_physical_plan_shim"
+                            ))?
+                            .getattr(pyo3::intern!(py, "fanout_by_range"))?
+                            .call1((
+                                merged,
+                                range_clustering_config.num_partitions,
+                                partition_by_pyexprs,
+                                range_clustering_config.descending.clone(),
+                            ))?
                         }
                         daft_logical_plan::ClusteringSpec::Unknown(_) => {
                             unreachable!("Cannot use NaiveFullyMaterializingMapReduce ShuffleExchange to map to an Unknown ClusteringSpec");
@@ -574,6 +668,29 @@ fn physical_plan_to_partition_tasks(
                         .call1((mapped,))?;
                     Ok(reduced.into())
                 }
+                ShuffleExchangeStrategy::FlightShuffle { target_spec } => {
+                    let daft_logical_plan::ClusteringSpec::Hash(hash_clustering_config) =
+                        target_spec.as_ref()
+                    else {
+                        unreachable!(
+                            "FlightShuffle ShuffleExchange only supports hash partitioning"
+                        );
+                    };
+                    let partition_by_pyexprs: Vec<PyExpr> = hash_clustering_config
+                        .by
+                        .iter()
+                        .map(|expr| PyExpr::from(expr.clone()))
+                        .collect();
+                    let shuffled = py
+                        .import_bound(pyo3::intern!(py, "daft.execution.shuffles.flight_shuffle"))?
+                        .getattr(pyo3::intern!(py, "flight_shuffle"))?
+                        .call1((
+                            upstream_iter,
+                            hash_clustering_config.num_partitions,
+                            partition_by_pyexprs,
+                        ))?;
+                    Ok(shuffled.into())
+                }
                 ShuffleExchangeStrategy::SplitOrCoalesceToTargetNum {
                     target_num_partitions,
                 } => {
@@ -678,7 +795,8 @@ fn physical_plan_to_partition_tasks(
             right_on,
             null_equals_nulls,
             join_type,
-            ..
+            skew_salting,
+            co_partitioned,
         }) => {
             let upstream_left_iter =
                 physical_plan_to_partition_tasks(left, py, psets, actor_pool_manager)?;
@@ -692,17 +810,53 @@ fn physical_plan_to_partition_tasks(
                 .iter()
                 .map(|expr| PyExpr::from(expr.clone()))
                 .collect();
-            let py_iter = py
-                .import_bound(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
-                .getattr(pyo3::intern!(py, "hash_join"))?
-                .call1((
-                    upstream_left_iter,
-                    upstream_right_iter,
-                    left_on_pyexprs,
-                    right_on_pyexprs,
-                    null_equals_nulls.clone(),
-                    *join_type,
-                ))?;
+            let py_iter = if let Some(SkewSalting {
+                num_partitions,
+                salt_factor,
+                heavy_hitter_threshold,
+                salt_left,
+            }) = skew_salting
+            {
+                py.import_bound(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                    .getattr(pyo3::intern!(py, "skew_salted_hash_join"))?
+                    .call1((
+                        upstream_left_iter,
+                        upstream_right_iter,
+                        left_on_pyexprs,
+                        right_on_pyexprs,
+                        null_equals_nulls.clone(),
+                        *join_type,
+                        *num_partitions,
+                        *salt_factor,
+                        *heavy_hitter_threshold,
+                        *salt_left,
+                    ))?
+            } else if *co_partitioned
+                && left.clustering_spec().num_partitions()
+                    != right.clustering_spec().num_partitions()
+            {
+                py.import_bound(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                    .getattr(pyo3::intern!(py, "co_partitioned_hash_join"))?
+                    .call1((
+                        upstream_left_iter,
+                        upstream_right_iter,
+                        left_on_pyexprs,
+                        right_on_pyexprs,
+                        null_equals_nulls.clone(),
+                        *join_type,
+                    ))?
+            } else {
+                py.import_bound(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                    .getattr(pyo3::intern!(py, "hash_join"))?
+                    .call1((
+                        upstream_left_iter,
+                        upstream_right_iter,
+                        left_on_pyexprs,
+                        right_on_pyexprs,
+                        null_equals_nulls.clone(),
+                        *join_type,
+                    ))?
+            };
             Ok(py_iter.into())
         }
         PhysicalPlan::SortMergeJoin(SortMergeJoin {
@@ -813,6 +967,10 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    parquet_encryption,
+                    csv_options,
+                    write_attempt,
+                    constraints,
                 },
             input,
         }) => tabular_write(
@@ -824,6 +982,10 @@ fn physical_plan_to_partition_tasks(
             compression,
             partition_cols,
             io_config,
+            parquet_encryption,
+            csv_options,
+            write_attempt,
+            constraints,
         ),
         PhysicalPlan::TabularWriteCsv(TabularWriteCsv {
             schema,
@@ -834,6 +996,10 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    parquet_encryption,
+                    csv_options,
+                    write_attempt,
+                    constraints,
                 },
             input,
         }) => tabular_write(
@@ -845,6 +1011,10 @@ fn physical_plan_to_partition_tasks(
             compression,
             partition_cols,
             io_config,
+            parquet_encryption,
+            csv_options,
+            write_attempt,
+            constraints,
         ),
         PhysicalPlan::TabularWriteJson(TabularWriteJson {
             schema,
@@ -855,6 +1025,10 @@ fn physical_plan_to_partition_tasks(
                     partition_cols,
                     compression,
                     io_config,
+                    parquet_encryption,
+                    csv_options,
+                    write_attempt,
+                    constraints,
                 },
             input,
         }) => tabular_write(
@@ -866,6 +1040,10 @@ fn physical_plan_to_partition_tasks(
             compression,
             partition_cols,
             io_config,
+            parquet_encryption,
+            csv_options,
+            write_attempt,
+            constraints,
         ),
         #[cfg(feature = "python")]
         PhysicalPlan::IcebergWrite(IcebergWrite {
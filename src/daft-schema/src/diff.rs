@@ -0,0 +1,270 @@
+//! Differences between schemas, and whether data of one schema can be appended to or unioned
+//! with data of another.
+
+use std::collections::HashMap;
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::{dtype::DataType, field::Field, schema::Schema};
+
+/// A change to a field between two schemas
+#[derive(Clone, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FieldChange {
+    #[display("added with type {}", _0.dtype)]
+    Added(Field),
+    #[display("removed, had type {}", _0.dtype)]
+    Removed(Field),
+    #[display("type changed from {from} to {to}")]
+    TypeChanged { from: DataType, to: DataType },
+    #[display("moved from position {from} to {to}")]
+    Moved { from: usize, to: usize },
+}
+
+/// A field that differs between two schemas
+#[derive(Clone, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
+#[display("{name}: {change}")]
+pub struct FieldDiff {
+    pub name: String,
+    pub change: FieldChange,
+}
+
+/// A field that makes two schemas incompatible, and why
+#[derive(Clone, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
+#[display("{name}: {reason}")]
+pub struct Incompatibility {
+    pub name: String,
+    pub reason: String,
+}
+
+/// The changes to the fields of a schema that make up another schema, in the order of the fields
+/// of the old schema followed by the fields added by the new one
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaDiff {
+    pub changes: Vec<FieldDiff>,
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Why data with the new schema can't be appended to a table with the old schema. Fields
+    /// missing from the data are filled with nulls and may be reordered, but the data may not have
+    /// new fields or change a type to one that the table type can't represent exactly.
+    pub fn append_incompatibilities(&self) -> Vec<Incompatibility> {
+        self.changes
+            .iter()
+            .filter_map(|diff| {
+                let reason = match &diff.change {
+                    FieldChange::Added(field) => {
+                        format!("is not in the table, has type {}", field.dtype)
+                    }
+                    FieldChange::TypeChanged { from, to } if !can_widen(to, from) => {
+                        format!("has type {to}, which can't be written to the table type {from}")
+                    }
+                    _ => return None,
+                };
+                Some(Incompatibility {
+                    name: diff.name.clone(),
+                    reason,
+                })
+            })
+            .collect()
+    }
+
+    /// Why data with the two schemas can't be unioned. They must have the same fields in the same
+    /// positions, with types of which one can represent the other exactly.
+    pub fn union_incompatibilities(&self) -> Vec<Incompatibility> {
+        self.changes
+            .iter()
+            .filter_map(|diff| {
+                let reason = match &diff.change {
+                    FieldChange::Added(_) => "is only in the second schema".to_string(),
+                    FieldChange::Removed(_) => "is only in the first schema".to_string(),
+                    FieldChange::TypeChanged { from, to }
+                        if !can_widen(from, to) && !can_widen(to, from) =>
+                    {
+                        format!("has types {from} and {to}, which have no common type")
+                    }
+                    FieldChange::Moved { from, to } => {
+                        format!("is at position {from} in the first schema and {to} in the second")
+                    }
+                    FieldChange::TypeChanged { .. } => return None,
+                };
+                Some(Incompatibility {
+                    name: diff.name.clone(),
+                    reason,
+                })
+            })
+            .collect()
+    }
+
+    /// Why evolving a table from the old to the new schema would break readers of the table.
+    /// Fields may be added, reordered, or widened to a type that represents the old one exactly.
+    pub fn breaking_changes(&self) -> Vec<Incompatibility> {
+        self.changes
+            .iter()
+            .filter_map(|diff| {
+                let reason = match &diff.change {
+                    FieldChange::Removed(field) => {
+                        format!("is removed, had type {}", field.dtype)
+                    }
+                    FieldChange::TypeChanged { from, to } if !can_widen(from, to) => {
+                        format!(
+                            "changes type from {from} to {to}, which can't represent it exactly"
+                        )
+                    }
+                    _ => return None,
+                };
+                Some(Incompatibility {
+                    name: diff.name.clone(),
+                    reason,
+                })
+            })
+            .collect()
+    }
+
+    pub fn can_append(&self) -> bool {
+        self.append_incompatibilities().is_empty()
+    }
+
+    pub fn can_union(&self) -> bool {
+        self.union_incompatibilities().is_empty()
+    }
+}
+
+impl std::fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "No changes");
+        }
+        for (i, diff) in self.changes.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{diff}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of bits of an integer type and whether it is signed
+fn integer_bits(dtype: &DataType) -> Option<(u32, bool)> {
+    match dtype {
+        DataType::Int8 => Some((8, true)),
+        DataType::Int16 => Some((16, true)),
+        DataType::Int32 => Some((32, true)),
+        DataType::Int64 => Some((64, true)),
+        DataType::UInt8 => Some((8, false)),
+        DataType::UInt16 => Some((16, false)),
+        DataType::UInt32 => Some((32, false)),
+        DataType::UInt64 => Some((64, false)),
+        _ => None,
+    }
+}
+
+/// Whether every value of type `from` can be represented exactly in type `to`
+pub fn can_widen(from: &DataType, to: &DataType) -> bool {
+    if from == to || from == &DataType::Null {
+        return true;
+    }
+    if let (Some((from_bits, from_signed)), Some((to_bits, to_signed))) =
+        (integer_bits(from), integer_bits(to))
+    {
+        return match (from_signed, to_signed) {
+            (true, false) => false,
+            (false, true) => from_bits < to_bits,
+            _ => from_bits <= to_bits,
+        };
+    }
+    match (from, to) {
+        // floats represent integers exactly up to their mantissa bits
+        (_, DataType::Float32) if from.is_integer() => integer_bits(from).unwrap().0 <= 16,
+        (_, DataType::Float64) if from.is_integer() => integer_bits(from).unwrap().0 <= 32,
+        (DataType::Float32, DataType::Float64) => true,
+        (
+            DataType::Decimal128(from_precision, from_scale),
+            DataType::Decimal128(to_precision, to_scale),
+        ) => to_scale >= from_scale && to_precision - to_scale >= from_precision - from_scale,
+        (DataType::FixedSizeBinary(_), DataType::Binary) => true,
+        (DataType::List(from), DataType::List(to))
+        | (DataType::FixedSizeList(from, _), DataType::List(to)) => can_widen(from, to),
+        (DataType::FixedSizeList(from, from_size), DataType::FixedSizeList(to, to_size)) => {
+            from_size == to_size && can_widen(from, to)
+        }
+        (
+            DataType::Map {
+                key: from_key,
+                value: from_value,
+            },
+            DataType::Map {
+                key: to_key,
+                value: to_value,
+            },
+        ) => can_widen(from_key, to_key) && can_widen(from_value, to_value),
+        (DataType::Struct(from), DataType::Struct(to)) => from.iter().all(|from_field| {
+            to.iter().any(|to_field| {
+                to_field.name == from_field.name && can_widen(&from_field.dtype, &to_field.dtype)
+            })
+        }),
+        _ => false,
+    }
+}
+
+impl Schema {
+    /// The changes from this schema to `other`. Fields are only reported as moved if their
+    /// position among the fields of both schemas changed.
+    pub fn diff(&self, other: &Self) -> SchemaDiff {
+        let shared_positions = |schema: &Self, of: &Self| {
+            schema
+                .fields
+                .keys()
+                .filter(|name| of.fields.contains_key(*name))
+                .enumerate()
+                .map(|(position, name)| (name.clone(), position))
+                .collect::<HashMap<_, _>>()
+        };
+        let positions = shared_positions(self, other);
+        let other_positions = shared_positions(other, self);
+
+        let mut changes = Vec::new();
+        for (name, field) in &self.fields {
+            let change = match other.fields.get(name) {
+                None => FieldChange::Removed(field.clone()),
+                Some(other_field) if other_field.dtype != field.dtype => FieldChange::TypeChanged {
+                    from: field.dtype.clone(),
+                    to: other_field.dtype.clone(),
+                },
+                Some(_) if positions[name] != other_positions[name] => FieldChange::Moved {
+                    from: positions[name],
+                    to: other_positions[name],
+                },
+                Some(_) => continue,
+            };
+            changes.push(FieldDiff {
+                name: name.clone(),
+                change,
+            });
+        }
+        for (name, field) in &other.fields {
+            if !self.fields.contains_key(name) {
+                changes.push(FieldDiff {
+                    name: name.clone(),
+                    change: FieldChange::Added(field.clone()),
+                });
+            }
+        }
+        SchemaDiff { changes }
+    }
+
+    /// Whether data with schema `data` can be appended to a table with this schema
+    pub fn can_append(&self, data: &Self) -> bool {
+        self.diff(data).can_append()
+    }
+
+    /// Whether data with this schema can be unioned with data with schema `other`
+    pub fn can_union(&self, other: &Self) -> bool {
+        self.diff(other).can_union()
+    }
+}
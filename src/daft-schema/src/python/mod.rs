@@ -11,6 +11,7 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<datatype::PyDataType>()?;
     parent.add_class::<datatype::PyTimeUnit>()?;
     parent.add_class::<schema::PySchema>()?;
+    parent.add_class::<schema::PySchemaDiff>()?;
     parent.add_class::<field::PyField>()?;
     parent.add_class::<ImageMode>()?;
     parent.add_class::<ImageFormat>()?;
@@ -5,7 +5,11 @@ use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use super::{datatype::PyDataType, field::PyField};
-use crate::{field::Field, schema};
+use crate::{
+    diff::{FieldChange, Incompatibility, SchemaDiff},
+    field::Field,
+    schema,
+};
 
 #[pyclass(module = "daft.daft")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -99,10 +103,88 @@ impl PySchema {
         let new_schema = Arc::new(self.schema.apply_hints(&hints.schema)?);
         Ok(new_schema.into())
     }
+
+    pub fn merge_hints(&self, other: &Self) -> PyResult<Self> {
+        let new_schema = Arc::new(self.schema.merge_hints(&other.schema)?);
+        Ok(new_schema.into())
+    }
+
+    pub fn diff(&self, other: &Self) -> PySchemaDiff {
+        self.schema.diff(&other.schema).into()
+    }
 }
 
 impl_bincode_py_state_serialization!(PySchema);
 
+#[pyclass(module = "daft.daft")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PySchemaDiff {
+    pub diff: SchemaDiff,
+}
+
+fn incompatibilities_to_tuples(incompatibilities: Vec<Incompatibility>) -> Vec<(String, String)> {
+    incompatibilities
+        .into_iter()
+        .map(|incompatibility| (incompatibility.name, incompatibility.reason))
+        .collect()
+}
+
+#[pymethods]
+impl PySchemaDiff {
+    /// The changed fields, as tuples of their name, the kind of change and a description of it.
+    pub fn changes(&self) -> Vec<(String, &'static str, String)> {
+        self.diff
+            .changes
+            .iter()
+            .map(|diff| {
+                let kind = match diff.change {
+                    FieldChange::Added(_) => "added",
+                    FieldChange::Removed(_) => "removed",
+                    FieldChange::TypeChanged { .. } => "type_changed",
+                    FieldChange::Moved { .. } => "moved",
+                };
+                (diff.name.clone(), kind, diff.change.to_string())
+            })
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diff.is_empty()
+    }
+
+    pub fn can_append(&self) -> bool {
+        self.diff.can_append()
+    }
+
+    pub fn can_union(&self) -> bool {
+        self.diff.can_union()
+    }
+
+    pub fn append_incompatibilities(&self) -> Vec<(String, String)> {
+        incompatibilities_to_tuples(self.diff.append_incompatibilities())
+    }
+
+    pub fn union_incompatibilities(&self) -> Vec<(String, String)> {
+        incompatibilities_to_tuples(self.diff.union_incompatibilities())
+    }
+
+    pub fn breaking_changes(&self) -> Vec<(String, String)> {
+        incompatibilities_to_tuples(self.diff.breaking_changes())
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.diff.to_string()
+    }
+}
+
+impl_bincode_py_state_serialization!(PySchemaDiff);
+
+impl From<SchemaDiff> for PySchemaDiff {
+    fn from(diff: SchemaDiff) -> Self {
+        Self { diff }
+    }
+}
+
 impl From<schema::SchemaRef> for PySchema {
     fn from(schema: schema::SchemaRef) -> Self {
         Self { schema }
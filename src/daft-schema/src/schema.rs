@@ -156,6 +156,33 @@ impl Schema {
         })
     }
 
+    /// Merges two sets of schema hints into one with the hints of both, reporting the columns
+    /// that they give different types.
+    pub fn merge_hints(&self, other: &Self) -> DaftResult<Self> {
+        let conflicts = self
+            .fields
+            .iter()
+            .filter_map(|(name, field)| {
+                let other_field = other.fields.get(name)?;
+                (field.dtype != other_field.dtype).then(|| {
+                    format!(
+                        "{name} is hinted as both {} and {}",
+                        field.dtype, other_field.dtype
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if !conflicts.is_empty() {
+            return Err(DaftError::ValueError(format!(
+                "Conflicting schema hints: {}",
+                conflicts.join("; ")
+            )));
+        }
+
+        Ok(self.non_distinct_union(other))
+    }
+
     pub fn to_arrow(&self) -> DaftResult<arrow2::datatypes::Schema> {
         let arrow_fields: DaftResult<Vec<arrow2::datatypes::Field>> =
             self.fields.iter().map(|(_, f)| f.to_arrow()).collect();
@@ -0,0 +1,182 @@
+//! Exchange of shuffled partitions between worker processes over Arrow Flight.
+//!
+//! Partitions are addressed by their [`ShuffleKey`](crate::ShuffleKey): `DoGet` tickets contain
+//! the key as `query_id/stage_id/partition`, and `DoPut` descriptors contain it as the path
+//! `[query_id, stage_id, partition]`.
+//!
+//! Connections are not encrypted. When the server is started with an [`AuthToken`], every request
+//! must carry it as a bearer token in its `authorization` header, which clients given the same
+//! token send, checking it with a handshake when they connect. Servers should therefore only be
+//! bound to addresses other than loopback with a token, and behind a trusted network.
+
+mod client;
+mod server;
+
+use std::sync::Arc;
+
+use arrow2::io::{
+    flight::{
+        default_ipc_fields, deserialize_message, deserialize_schemas, serialize_batch,
+        serialize_schema,
+    },
+    ipc::{read::Dictionaries, write::WriteOptions},
+};
+use arrow_flight::FlightData;
+pub use client::ShuffleFlightClient;
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{Schema, SchemaRef},
+    series::Series,
+};
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+pub use server::{start_flight_server, ShuffleFlightServer, ShuffleFlightServerHandle};
+use tonic::{metadata::MetadataValue, Request, Status};
+
+/// Action of the flight service that drops all stage outputs of the query in the action body
+pub const REMOVE_QUERY_ACTION: &str = "remove_query";
+
+/// Secret shared by the workers of a cluster, which their flight servers require of every request
+#[derive(Clone, PartialEq, Eq)]
+pub struct AuthToken(Arc<str>);
+
+impl AuthToken {
+    pub fn new(token: impl Into<Arc<str>>) -> Self {
+        Self(token.into())
+    }
+
+    fn bearer(&self) -> String {
+        format!("Bearer {}", self.0)
+    }
+
+    /// Adds the token to the `authorization` header of an outgoing request
+    fn authorize(&self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let value = MetadataValue::try_from(self.bearer())
+            .map_err(|_| Status::invalid_argument("Auth token must be ASCII"))?;
+        request.metadata_mut().insert("authorization", value);
+        Ok(request)
+    }
+
+    /// Rejects requests whose `authorization` header doesn't carry the token
+    fn check(&self, request: Request<()>) -> Result<Request<()>, Status> {
+        let authorization = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        // compares every byte, so that the time taken doesn't reveal how much of the token matched
+        let expected = self.bearer();
+        let matches = authorization.len() == expected.len()
+            && authorization
+                .bytes()
+                .zip(expected.bytes())
+                .fold(0, |diff, (a, b)| diff | (a ^ b))
+                == 0;
+        if matches {
+            Ok(request)
+        } else {
+            Err(Status::unauthenticated("Invalid shuffle auth token"))
+        }
+    }
+}
+
+impl std::fmt::Debug for AuthToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AuthToken(..)")
+    }
+}
+
+fn to_flight_data(data: arrow_format::flight::data::FlightData) -> FlightData {
+    FlightData {
+        flight_descriptor: None,
+        data_header: data.data_header.into(),
+        app_metadata: data.app_metadata.into(),
+        data_body: data.data_body.into(),
+    }
+}
+
+fn from_flight_data(data: &FlightData) -> arrow_format::flight::data::FlightData {
+    arrow_format::flight::data::FlightData {
+        flight_descriptor: None,
+        data_header: data.data_header.to_vec(),
+        app_metadata: data.app_metadata.to_vec(),
+        data_body: data.data_body.to_vec(),
+    }
+}
+
+/// Encodes a partition as a schema message followed by one message per table
+fn encode_partition(partition: &MicroPartition) -> DaftResult<Vec<FlightData>> {
+    let arrow_schema = partition.schema().to_arrow()?;
+    let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+    let options = WriteOptions { compression: None };
+
+    let mut messages = vec![to_flight_data(serialize_schema(
+        &arrow_schema,
+        Some(&ipc_fields),
+    ))];
+    for table in partition.get_tables()?.iter() {
+        let chunk = arrow2::chunk::Chunk::new(table.get_inner_arrow_arrays().collect());
+        let (dictionaries, batch) = serialize_batch(&chunk, &ipc_fields, &options)?;
+        messages.extend(dictionaries.into_iter().map(to_flight_data));
+        messages.push(to_flight_data(batch));
+    }
+    Ok(messages)
+}
+
+/// Decodes the messages produced by [`encode_partition`]
+struct PartitionDecoder {
+    schema: SchemaRef,
+    arrow_schema: arrow2::datatypes::Schema,
+    ipc_schema: arrow2::io::ipc::IpcSchema,
+    dictionaries: Dictionaries,
+    tables: Vec<Table>,
+}
+
+impl PartitionDecoder {
+    fn try_new(schema_message: &FlightData) -> DaftResult<Self> {
+        let (arrow_schema, ipc_schema) = deserialize_schemas(&schema_message.data_header)?;
+        let schema = Arc::new(Schema::try_from(&arrow_schema)?);
+        Ok(Self {
+            schema,
+            arrow_schema,
+            ipc_schema,
+            dictionaries: Dictionaries::default(),
+            tables: Vec::new(),
+        })
+    }
+
+    fn push(&mut self, message: &FlightData) -> DaftResult<()> {
+        let Some(chunk) = deserialize_message(
+            &from_flight_data(message),
+            &self.arrow_schema.fields,
+            &self.ipc_schema,
+            &mut self.dictionaries,
+        )?
+        else {
+            // dictionary batches only update the dictionaries
+            return Ok(());
+        };
+        let num_rows = chunk.len();
+        let columns = self
+            .schema
+            .fields
+            .values()
+            .zip(chunk.into_arrays())
+            .map(|(field, array)| Series::from_arrow(Arc::new(field.clone()), array))
+            .collect::<DaftResult<Vec<_>>>()?;
+        self.tables.push(Table::new_with_size(
+            self.schema.clone(),
+            columns,
+            num_rows,
+        )?);
+        Ok(())
+    }
+
+    fn finish(self) -> MicroPartition {
+        MicroPartition::new_loaded(self.schema, Arc::new(self.tables), None)
+    }
+}
+
+fn status_to_error(status: tonic::Status) -> DaftError {
+    DaftError::External(Box::new(status))
+}
@@ -0,0 +1,192 @@
+use arrow_flight::{
+    flight_service_client::FlightServiceClient, Action, FlightDescriptor, HandshakeRequest, Ticket,
+};
+use common_error::{DaftError, DaftResult};
+use daft_micropartition::MicroPartition;
+use futures::{stream, StreamExt};
+use tonic::{
+    service::{interceptor::InterceptedService, Interceptor},
+    transport::Channel,
+    Request, Status,
+};
+
+use super::{encode_partition, status_to_error, AuthToken, PartitionDecoder, REMOVE_QUERY_ACTION};
+use crate::ShuffleKey;
+
+/// Sends the auth token, if any, with every request
+#[derive(Clone)]
+struct ClientAuth(Option<AuthToken>);
+
+impl Interceptor for ClientAuth {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        match &self.0 {
+            Some(auth_token) => auth_token.authorize(request),
+            None => Ok(request),
+        }
+    }
+}
+
+/// Fetches partitions from, and pushes partitions to, the flight server of another worker
+#[derive(Clone)]
+pub struct ShuffleFlightClient {
+    client: FlightServiceClient<InterceptedService<Channel, ClientAuth>>,
+}
+
+impl ShuffleFlightClient {
+    /// Connects to the server at `addr`, e.g. `"http://10.0.0.2:8815"`, with the auth token of the
+    /// server if it has one. Fails if the server doesn't accept the token.
+    pub async fn connect(
+        addr: impl Into<String>,
+        auth_token: Option<AuthToken>,
+    ) -> DaftResult<Self> {
+        let channel = Channel::from_shared(addr.into())
+            .map_err(|e| DaftError::ValueError(format!("Invalid shuffle server address: {e}")))?
+            .connect()
+            .await
+            .map_err(|e| DaftError::External(e.into()))?;
+        let mut client = FlightServiceClient::with_interceptor(channel, ClientAuth(auth_token));
+
+        let mut responses = client
+            .handshake(stream::once(async { HandshakeRequest::default() }))
+            .await
+            .map_err(status_to_error)?
+            .into_inner();
+        while let Some(response) = responses.next().await {
+            response.map_err(status_to_error)?;
+        }
+        Ok(Self { client })
+    }
+
+    pub async fn fetch_partition(&mut self, key: &ShuffleKey) -> DaftResult<MicroPartition> {
+        let ticket = Ticket {
+            ticket: key.to_string().into(),
+        };
+        let mut messages = self
+            .client
+            .do_get(ticket)
+            .await
+            .map_err(status_to_error)?
+            .into_inner();
+
+        let schema_message = messages.next().await.ok_or_else(|| {
+            DaftError::InternalError(format!("Shuffle server sent no data for {key}"))
+        })?;
+        let mut decoder = PartitionDecoder::try_new(&schema_message.map_err(status_to_error)?)?;
+        while let Some(message) = messages.next().await {
+            decoder.push(&message.map_err(status_to_error)?)?;
+        }
+        Ok(decoder.finish())
+    }
+
+    /// Adds `partition` to the stage output served by the remote worker
+    pub async fn put_partition(
+        &mut self,
+        key: &ShuffleKey,
+        partition: &MicroPartition,
+    ) -> DaftResult<()> {
+        let mut messages = encode_partition(partition)?;
+        messages[0].flight_descriptor = Some(FlightDescriptor::new_path(key.to_path()));
+
+        let mut results = self
+            .client
+            .do_put(stream::iter(messages))
+            .await
+            .map_err(status_to_error)?
+            .into_inner();
+        while let Some(result) = results.next().await {
+            result.map_err(status_to_error)?;
+        }
+        Ok(())
+    }
+
+    /// Drops all stage outputs of a query from the remote worker
+    pub async fn remove_query(&mut self, query_id: &str) -> DaftResult<()> {
+        let action = Action {
+            r#type: REMOVE_QUERY_ACTION.to_string(),
+            body: query_id.to_string().into(),
+        };
+        let mut results = self
+            .client
+            .do_action(action)
+            .await
+            .map_err(status_to_error)?
+            .into_inner();
+        while let Some(result) = results.next().await {
+            result.map_err(status_to_error)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use daft_core::prelude::*;
+    use daft_table::Table;
+
+    use super::*;
+    use crate::{flight::start_flight_server, ShuffleRegistry};
+
+    fn make_partition(values: Vec<i64>) -> MicroPartition {
+        let table =
+            Table::from_nonempty_columns(vec![Int64Array::from(("a", values)).into_series()])
+                .unwrap();
+        MicroPartition::new_loaded(table.schema.clone(), Arc::new(vec![table]), None)
+    }
+
+    #[tokio::test]
+    async fn test_put_then_fetch_partition() {
+        let registry = Arc::new(ShuffleRegistry::default());
+        let server = start_flight_server("127.0.0.1:0", registry.clone(), None).unwrap();
+        let mut client =
+            ShuffleFlightClient::connect(format!("http://127.0.0.1:{}", server.port()), None)
+                .await
+                .unwrap();
+
+        let key = ShuffleKey::new("query", 1, 0);
+        client
+            .put_partition(&key, &make_partition(vec![1, 2]))
+            .await
+            .unwrap();
+        client
+            .put_partition(&key, &make_partition(vec![3]))
+            .await
+            .unwrap();
+
+        let fetched = client.fetch_partition(&key).await.unwrap();
+        assert_eq!(fetched.len(), 3);
+        assert_eq!(fetched.schema(), make_partition(vec![]).schema());
+
+        client.remove_query("query").await.unwrap();
+        assert!(client.fetch_partition(&key).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_auth_token() {
+        let registry = Arc::new(ShuffleRegistry::default());
+        let token = AuthToken::new("secret");
+        let server = start_flight_server("127.0.0.1:0", registry, Some(token.clone())).unwrap();
+        let addr = format!("http://127.0.0.1:{}", server.port());
+
+        // the handshake rejects clients without the server's token
+        assert!(ShuffleFlightClient::connect(addr.clone(), None)
+            .await
+            .is_err());
+        assert!(
+            ShuffleFlightClient::connect(addr.clone(), Some(AuthToken::new("wrong")))
+                .await
+                .is_err()
+        );
+
+        let mut client = ShuffleFlightClient::connect(addr, Some(token))
+            .await
+            .unwrap();
+        let key = ShuffleKey::new("query", 1, 0);
+        client
+            .put_partition(&key, &make_partition(vec![1, 2]))
+            .await
+            .unwrap();
+        assert_eq!(client.fetch_partition(&key).await.unwrap().len(), 2);
+    }
+}
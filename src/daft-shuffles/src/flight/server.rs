@@ -0,0 +1,259 @@
+use std::{pin::Pin, sync::Arc};
+
+use arrow_flight::{
+    flight_service_server::{FlightService, FlightServiceServer},
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PollInfo, PutResult, SchemaResult, Ticket,
+};
+use common_error::{DaftError, DaftResult};
+use futures::{stream, Stream, StreamExt};
+use tonic::{transport::Server, Request, Response, Status, Streaming};
+
+use super::{encode_partition, AuthToken, PartitionDecoder, REMOVE_QUERY_ACTION};
+use crate::{ShuffleKey, ShuffleRegistry};
+
+type BoxedStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + 'static>>;
+
+fn error_to_status(err: DaftError) -> Status {
+    match err {
+        DaftError::ValueError(msg) => Status::invalid_argument(msg),
+        DaftError::SchemaMismatch(msg) => Status::failed_precondition(msg),
+        err => Status::internal(err.to_string()),
+    }
+}
+
+/// Serves the partitions of a [`ShuffleRegistry`] over Arrow Flight.
+///
+/// `DoGet` streams a partition of a stage's output, `DoPut` adds a partition to it, and the
+/// `remove_query` action drops all stage outputs of a query once it finished. `Handshake` lets
+/// clients check that the server accepts their auth token before they send any partitions.
+pub struct ShuffleFlightServer {
+    registry: Arc<ShuffleRegistry>,
+}
+
+impl ShuffleFlightServer {
+    #[must_use]
+    pub fn new(registry: Arc<ShuffleRegistry>) -> Self {
+        Self { registry }
+    }
+}
+
+#[tonic::async_trait]
+impl FlightService for ShuffleFlightServer {
+    type HandshakeStream = BoxedStream<HandshakeResponse>;
+    type ListFlightsStream = BoxedStream<FlightInfo>;
+    type DoGetStream = BoxedStream<FlightData>;
+    type DoPutStream = BoxedStream<PutResult>;
+    type DoActionStream = BoxedStream<arrow_flight::Result>;
+    type ListActionsStream = BoxedStream<ActionType>;
+    type DoExchangeStream = BoxedStream<FlightData>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        // the auth token, if any, was already checked when the request was intercepted
+        Ok(Response::new(Box::pin(stream::once(async {
+            Ok(HandshakeResponse::default())
+        }))))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("list_flights is not supported"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("get_flight_info is not supported"))
+    }
+
+    async fn poll_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<PollInfo>, Status> {
+        Err(Status::unimplemented("poll_flight_info is not supported"))
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        Err(Status::unimplemented("get_schema is not supported"))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let ticket = String::from_utf8(request.into_inner().ticket.to_vec())
+            .map_err(|_| Status::invalid_argument("Shuffle ticket must be valid UTF-8"))?;
+        let key: ShuffleKey = ticket.parse().map_err(error_to_status)?;
+
+        // Partitions may have to be read back from spill files
+        let registry = self.registry.clone();
+        let messages = tokio::task::spawn_blocking(move || -> DaftResult<_> {
+            let partition = registry.read_partition(&key)?.ok_or_else(|| {
+                DaftError::ValueError(format!("No shuffle outputs registered for {key}"))
+            })?;
+            encode_partition(&partition)
+        })
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?
+        .map_err(|e| match e {
+            DaftError::ValueError(msg) => Status::not_found(msg),
+            e => error_to_status(e),
+        })?;
+
+        Ok(Response::new(Box::pin(stream::iter(
+            messages.into_iter().map(Ok),
+        ))))
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        let mut messages = request.into_inner();
+        let first = messages
+            .next()
+            .await
+            .ok_or_else(|| Status::invalid_argument("DoPut stream was empty"))??;
+        let descriptor = first.flight_descriptor.as_ref().ok_or_else(|| {
+            Status::invalid_argument("First message of DoPut must contain a flight descriptor")
+        })?;
+        let key = ShuffleKey::from_path(&descriptor.path).map_err(error_to_status)?;
+
+        let mut decoder = PartitionDecoder::try_new(&first).map_err(error_to_status)?;
+        while let Some(message) = messages.next().await {
+            decoder.push(&message?).map_err(error_to_status)?;
+        }
+        self.registry.put_partition(&key, decoder.finish());
+
+        Ok(Response::new(Box::pin(stream::once(async {
+            Ok(PutResult::default())
+        }))))
+    }
+
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        let action = request.into_inner();
+        if action.r#type != REMOVE_QUERY_ACTION {
+            return Err(Status::invalid_argument(format!(
+                "Unknown action {}",
+                action.r#type
+            )));
+        }
+        let query_id = String::from_utf8(action.body.to_vec())
+            .map_err(|_| Status::invalid_argument("Query id must be valid UTF-8"))?;
+        let removed = self.registry.remove_query(&query_id);
+
+        Ok(Response::new(Box::pin(stream::once(async move {
+            Ok(arrow_flight::Result {
+                body: removed.to_string().into(),
+            })
+        }))))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        let action = ActionType {
+            r#type: REMOVE_QUERY_ACTION.to_string(),
+            description: "Drops all shuffle outputs of the query in the action body".to_string(),
+        };
+        Ok(Response::new(Box::pin(stream::once(async { Ok(action) }))))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("do_exchange is not supported"))
+    }
+}
+
+pub struct ShuffleFlightServerHandle {
+    shutdown_signal: Option<tokio::sync::oneshot::Sender<()>>,
+    port: u16,
+}
+
+impl ShuffleFlightServerHandle {
+    pub fn shutdown(&mut self) {
+        let Some(shutdown_signal) = self.shutdown_signal.take() else {
+            return;
+        };
+        // the server may already have stopped on an error
+        let _ = shutdown_signal.send(());
+    }
+
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl Drop for ShuffleFlightServerHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Starts serving the partitions of `registry` at `addr` (e.g. `"127.0.0.1:0"`) on a background
+/// thread, until the returned handle is shut down or dropped. Requests are only accepted with
+/// `auth_token` if one is given.
+pub fn start_flight_server(
+    addr: &str,
+    registry: Arc<ShuffleRegistry>,
+    auth_token: Option<AuthToken>,
+) -> DaftResult<ShuffleFlightServerHandle> {
+    let listener = std::net::TcpListener::bind(addr)?;
+    listener.set_nonblocking(true)?;
+    let port = listener.local_addr()?.port();
+
+    let (shutdown_signal, shutdown_receiver) = tokio::sync::oneshot::channel::<()>();
+    // The runtime is built before spawning the server thread, so that failing to build it is
+    // returned to the caller instead of only stopping the thread
+    let runtime = tokio::runtime::Runtime::new()?;
+
+    std::thread::spawn(move || {
+        let result = runtime.block_on(async {
+            let listener = tokio::net::TcpListener::from_std(listener)?;
+            let incoming = stream::unfold(listener, |listener| async move {
+                let accepted = listener.accept().await.map(|(stream, _)| stream);
+                Some((accepted, listener))
+            });
+
+            let service = FlightServiceServer::with_interceptor(
+                ShuffleFlightServer::new(registry),
+                move |request: Request<()>| match &auth_token {
+                    Some(auth_token) => auth_token.check(request),
+                    None => Ok(request),
+                },
+            );
+            Server::builder()
+                .add_service(service)
+                .serve_with_incoming_shutdown(incoming, async {
+                    let _ = shutdown_receiver.await;
+                })
+                .await
+                .map_err(|e| DaftError::External(e.into()))
+        });
+
+        if let Err(e) = result {
+            log::error!("Shuffle flight server error: {e}");
+        }
+    });
+
+    Ok(ShuffleFlightServerHandle {
+        shutdown_signal: Some(shutdown_signal),
+        port,
+    })
+}
@@ -0,0 +1,178 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use arrow2::io::ipc::{
+    read::{read_stream_metadata, StreamReader, StreamState},
+    write::{StreamWriter, WriteOptions},
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{Schema, SchemaRef},
+    series::Series,
+};
+use daft_table::Table;
+
+use crate::temp_space::QueryTempDir;
+
+/// Appends tables to a spill file in the Arrow IPC stream format
+pub struct SpillWriter {
+    writer: StreamWriter<BufWriter<Box<dyn Write + Send>>>,
+    path: PathBuf,
+}
+
+impl SpillWriter {
+    pub fn try_new(path: PathBuf, schema: &SchemaRef) -> DaftResult<Self> {
+        let file = File::create(&path)?;
+        Self::try_new_with_writer(Box::new(file), path, schema)
+    }
+
+    /// Creates a spill file in the temp directory of a query, counting against its quota
+    pub fn try_new_in(
+        temp_dir: &Arc<QueryTempDir>,
+        prefix: &str,
+        schema: &SchemaRef,
+    ) -> DaftResult<Self> {
+        let file = temp_dir.create_file(prefix, "arrow")?;
+        let path = file.path().to_path_buf();
+        Self::try_new_with_writer(Box::new(file), path, schema)
+    }
+
+    fn try_new_with_writer(
+        file: Box<dyn Write + Send>,
+        path: PathBuf,
+        schema: &SchemaRef,
+    ) -> DaftResult<Self> {
+        let mut writer =
+            StreamWriter::new(BufWriter::new(file), WriteOptions { compression: None });
+        writer.start(&schema.to_arrow()?, None)?;
+        Ok(Self { writer, path })
+    }
+
+    pub fn write(&mut self, table: &Table) -> DaftResult<()> {
+        let chunk = arrow2::chunk::Chunk::new(table.get_inner_arrow_arrays().collect());
+        self.writer.write(&chunk, None)?;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> DaftResult<PathBuf> {
+        self.writer.finish()?;
+        Ok(self.path)
+    }
+}
+
+/// Reads back the tables of a spill file written by a [`SpillWriter`]
+pub fn read_spill_file(path: &Path, schema: &SchemaRef) -> DaftResult<Vec<Table>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let metadata = read_stream_metadata(&mut reader)?;
+    read_tables(
+        StreamReader::new(reader, metadata, None),
+        &format!("Spill file {}", path.display()),
+        schema,
+    )
+}
+
+/// Reads back the tables of a spill file written by a [`SpillWriter`] one at a time, so that only
+/// one of them is in memory at once
+pub struct SpillReader {
+    reader: StreamReader<BufReader<File>>,
+    schema: SchemaRef,
+    source: String,
+}
+
+impl SpillReader {
+    /// Opens a spill file, taking the schema of its tables from the file
+    pub fn try_new(path: &Path) -> DaftResult<Self> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let metadata = read_stream_metadata(&mut reader)?;
+        let schema = Arc::new(Schema::try_from(&metadata.schema)?);
+        Ok(Self {
+            reader: StreamReader::new(reader, metadata, None),
+            schema,
+            source: format!("Spill file {}", path.display()),
+        })
+    }
+}
+
+impl Iterator for SpillReader {
+    type Item = DaftResult<Table>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = self.reader.next()?;
+        Some(read_table(state, &self.source, &self.schema))
+    }
+}
+
+/// Reads back a spill file written by a [`SpillWriter`], taking the schema from the file
+pub fn read_spill_file_with_schema(path: &Path) -> DaftResult<(SchemaRef, Vec<Table>)> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let schema = Arc::new(Schema::try_from(&metadata.schema)?);
+    let tables = read_tables(
+        StreamReader::new(reader, metadata, None),
+        &format!("Spill file {}", path.display()),
+        &schema,
+    )?;
+    Ok((schema, tables))
+}
+
+/// Serializes tables to bytes in the Arrow IPC stream format, e.g. to keep them in a state store
+pub fn write_ipc_bytes(schema: &SchemaRef, tables: &[Table]) -> DaftResult<Vec<u8>> {
+    let mut writer = StreamWriter::new(Vec::new(), WriteOptions { compression: None });
+    writer.start(&schema.to_arrow()?, None)?;
+    for table in tables {
+        let chunk = arrow2::chunk::Chunk::new(table.get_inner_arrow_arrays().collect());
+        writer.write(&chunk, None)?;
+    }
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
+
+/// Reads back the schema and tables of bytes written by [`write_ipc_bytes`]
+pub fn read_ipc_bytes(bytes: &[u8]) -> DaftResult<(SchemaRef, Vec<Table>)> {
+    let mut reader = bytes;
+    let metadata = read_stream_metadata(&mut reader)?;
+    let schema = Arc::new(Schema::try_from(&metadata.schema)?);
+    let tables = read_tables(
+        StreamReader::new(reader, metadata, None),
+        "IPC bytes",
+        &schema,
+    )?;
+    Ok((schema, tables))
+}
+
+fn read_tables<R: Read>(
+    reader: StreamReader<R>,
+    source: &str,
+    schema: &SchemaRef,
+) -> DaftResult<Vec<Table>> {
+    reader
+        .map(|state| read_table(state, source, schema))
+        .collect()
+}
+
+fn read_table(
+    state: arrow2::error::Result<StreamState>,
+    source: &str,
+    schema: &SchemaRef,
+) -> DaftResult<Table> {
+    let chunk = match state? {
+        StreamState::Some(chunk) => chunk,
+        StreamState::Waiting => {
+            return Err(DaftError::InternalError(format!(
+                "{source} ended in the middle of a batch"
+            )))
+        }
+    };
+    let num_rows = chunk.len();
+    let columns = schema
+        .fields
+        .values()
+        .zip(chunk.into_arrays())
+        .map(|(field, array)| Series::from_arrow(Arc::new(field.clone()), array))
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::new_with_size(schema.clone(), columns, num_rows)
+}
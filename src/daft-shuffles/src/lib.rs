@@ -0,0 +1,28 @@
+//! Primitives to exchange partitions between the stages of a query without Ray.
+//!
+//! A map stage pushes its outputs into an [`InProgressShuffleCache`], which splits them into the
+//! partitions of the next stage and buffers them in memory, spilling to disk once a memory limit
+//! is reached. Once closed, the resulting [`ShuffleCache`]s of all map tasks are read back
+//! partition by partition with a [`ShuffleReader`]. Spill files are written to the per-query
+//! directory of a [`TempSpace`], which limits the disk space taken by all queries of a process.
+//!
+//! To run stages on multiple workers, each worker registers its caches in a [`ShuffleRegistry`]
+//! served by a flight server, from which the workers of the next stage fetch their partitions
+//! with a [`ShuffleFlightClient`](flight::ShuffleFlightClient). The Ray runner does so with the
+//! `flight_shuffle` shuffle algorithm, running one worker per node.
+
+pub mod flight;
+mod ipc;
+#[cfg(feature = "python")]
+pub mod python;
+mod registry;
+mod shuffle_cache;
+mod temp_space;
+
+pub use ipc::{
+    read_ipc_bytes, read_spill_file, read_spill_file_with_schema, write_ipc_bytes, SpillReader,
+    SpillWriter,
+};
+pub use registry::{ShuffleKey, ShuffleRegistry};
+pub use shuffle_cache::{InProgressShuffleCache, ShuffleCache, ShufflePartitioning, ShuffleReader};
+pub use temp_space::{QueryTempDir, TempFile, TempSpace};
@@ -0,0 +1,178 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use common_daft_config::{DaftExecutionConfig, PyDaftExecutionConfig};
+use common_error::DaftResult;
+use common_runtime::get_io_runtime;
+use daft_dsl::python::PyExpr;
+use daft_micropartition::{python::PyMicroPartition, MicroPartition};
+use pyo3::prelude::*;
+
+use crate::{
+    flight::{start_flight_server, AuthToken, ShuffleFlightClient, ShuffleFlightServerHandle},
+    InProgressShuffleCache, ShuffleKey, ShufflePartitioning, ShuffleRegistry, TempSpace,
+};
+
+/// The shuffle service of a worker of the distributed runner.
+///
+/// Map tasks push their outputs into the worker, which hash partitions them into a shuffle cache
+/// per stage, spilling to its temp space past `repartition_memory_limit_bytes`. Once a stage is
+/// finished, its partitions are served over Arrow Flight to the reduce tasks of all workers, which
+/// fetch them with [`fetch_flight_shuffle_partition`].
+#[pyclass(module = "daft.daft")]
+pub struct FlightShuffleWorker {
+    registry: Arc<ShuffleRegistry>,
+    server: ShuffleFlightServerHandle,
+    temp_space: Arc<TempSpace>,
+    memory_limit_bytes: usize,
+    caches: Mutex<HashMap<(String, u64), InProgressShuffleCache>>,
+}
+
+#[pymethods]
+impl FlightShuffleWorker {
+    /// Starts the flight server of the worker at `addr`, which only accepts requests with
+    /// `auth_token` if one is given.
+    #[new]
+    #[pyo3(signature = (addr = "127.0.0.1:0", auth_token = None, config = None))]
+    fn new(
+        addr: &str,
+        auth_token: Option<String>,
+        config: Option<PyDaftExecutionConfig>,
+    ) -> PyResult<Self> {
+        let cfg = config.map_or_else(
+            || Arc::new(DaftExecutionConfig::from_env()),
+            |config| config.config,
+        );
+        let registry = Arc::new(ShuffleRegistry::default());
+        let server = start_flight_server(addr, registry.clone(), auth_token.map(AuthToken::new))?;
+        Ok(Self {
+            registry,
+            server,
+            temp_space: TempSpace::get_or_create(
+                cfg.temp_dir.as_deref().map(Path::new),
+                cfg.temp_space_quota_bytes,
+            ),
+            memory_limit_bytes: cfg.repartition_memory_limit_bytes,
+            caches: Mutex::default(),
+        })
+    }
+
+    fn port(&self) -> u16 {
+        self.server.port()
+    }
+
+    /// Hash partitions `partition` into `num_partitions` partitions of the output of a stage
+    fn push_partition(
+        &self,
+        py: Python,
+        query_id: String,
+        stage_id: u64,
+        num_partitions: usize,
+        partition_by: Vec<PyExpr>,
+        partition: PyMicroPartition,
+    ) -> PyResult<()> {
+        let partition: Arc<MicroPartition> = partition.into();
+        py.allow_threads(|| -> DaftResult<()> {
+            let mut caches = self.caches.lock().expect("shuffle caches lock is poisoned");
+            let cache = match caches.entry((query_id, stage_id)) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let schema = partition.schema();
+                    // Python objects can't be written to spill files, so they stay in memory.
+                    let memory_limit_bytes =
+                        if schema.fields.values().any(|field| field.dtype.is_python()) {
+                            usize::MAX
+                        } else {
+                            self.memory_limit_bytes
+                        };
+                    entry.insert(InProgressShuffleCache::try_new(
+                        schema,
+                        num_partitions,
+                        ShufflePartitioning::Hash(
+                            partition_by.into_iter().map(|expr| expr.expr).collect(),
+                        ),
+                        Some(self.temp_space.query_dir()),
+                        memory_limit_bytes,
+                    )?)
+                }
+            };
+            cache.push_partition(&partition)
+        })?;
+        Ok(())
+    }
+
+    /// Starts serving the output of a stage once all of its map tasks pushed their partitions.
+    /// Returns whether the worker received any partitions of the stage.
+    fn finish_stage(&self, py: Python, query_id: String, stage_id: u64) -> PyResult<bool> {
+        py.allow_threads(|| -> DaftResult<bool> {
+            let cache = self
+                .caches
+                .lock()
+                .expect("shuffle caches lock is poisoned")
+                .remove(&(query_id.clone(), stage_id));
+            let Some(cache) = cache else {
+                return Ok(false);
+            };
+            self.registry
+                .register_cache(&query_id, stage_id, Arc::new(cache.close()?));
+            Ok(true)
+        })
+        .map_err(Into::into)
+    }
+
+    /// Drops the outputs of all stages of a query, deleting their spill files
+    fn remove_query(&self, query_id: &str) {
+        self.caches
+            .lock()
+            .expect("shuffle caches lock is poisoned")
+            .retain(|(id, _), _| id != query_id);
+        self.registry.remove_query(query_id);
+    }
+
+    fn shutdown(&mut self) {
+        self.server.shutdown();
+    }
+}
+
+/// Fetches a partition of the output of a stage from the flight servers of the workers at
+/// `addresses`, e.g. `"http://10.0.0.2:8815"`, concatenated in the order of the addresses.
+#[pyfunction(signature = (addresses, query_id, stage_id, partition, auth_token = None))]
+fn fetch_flight_shuffle_partition(
+    py: Python,
+    addresses: Vec<String>,
+    query_id: String,
+    stage_id: u64,
+    partition: usize,
+    auth_token: Option<String>,
+) -> PyResult<PyMicroPartition> {
+    let key = ShuffleKey::new(query_id, stage_id, partition);
+    let auth_token = auth_token.map(AuthToken::new);
+    let parts = py.allow_threads(|| {
+        get_io_runtime(true).block_on(async move {
+            let fetches = addresses.into_iter().map(|addr| {
+                let key = key.clone();
+                let auth_token = auth_token.clone();
+                async move {
+                    ShuffleFlightClient::connect(addr, auth_token)
+                        .await?
+                        .fetch_partition(&key)
+                        .await
+                }
+            });
+            futures::future::try_join_all(fetches).await
+        })
+    })??;
+    Ok(MicroPartition::concat(parts.iter())?.into())
+}
+
+pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
+    parent.add_class::<FlightShuffleWorker>()?;
+    parent.add_function(wrap_pyfunction_bound!(
+        fetch_flight_shuffle_partition,
+        parent
+    )?)?;
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    str::FromStr,
+    sync::{Arc, RwLock},
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_micropartition::MicroPartition;
+
+use crate::ShuffleCache;
+
+/// Identifies a partition of the output of a query stage. Its string form is
+/// `{query_id}/{stage_id}/{partition}`, where the query id may itself contain '/'.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShuffleKey {
+    pub query_id: String,
+    pub stage_id: u64,
+    pub partition: usize,
+}
+
+impl ShuffleKey {
+    #[must_use]
+    pub fn new(query_id: impl Into<String>, stage_id: u64, partition: usize) -> Self {
+        Self {
+            query_id: query_id.into(),
+            stage_id,
+            partition,
+        }
+    }
+
+    /// Path segments of the key, as used in flight descriptors
+    #[must_use]
+    pub fn to_path(&self) -> Vec<String> {
+        vec![
+            self.query_id.clone(),
+            self.stage_id.to_string(),
+            self.partition.to_string(),
+        ]
+    }
+
+    pub fn from_path(path: &[String]) -> DaftResult<Self> {
+        let [query_id, stage_id, partition] = path else {
+            return Err(DaftError::ValueError(format!(
+                "Expected a shuffle key path of [query_id, stage_id, partition], got {path:?}"
+            )));
+        };
+        let parse_err = |e: std::num::ParseIntError| {
+            DaftError::ValueError(format!("Invalid shuffle key path {path:?}: {e}"))
+        };
+        Ok(Self {
+            query_id: query_id.clone(),
+            stage_id: stage_id.parse().map_err(parse_err)?,
+            partition: partition.parse().map_err(parse_err)?,
+        })
+    }
+}
+
+impl Display for ShuffleKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}/{}", self.query_id, self.stage_id, self.partition)
+    }
+}
+
+impl FromStr for ShuffleKey {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        // The stage id and partition are numbers, so the query id is everything before them,
+        // including any '/' of its own.
+        let mut path = s.rsplitn(3, '/').map(str::to_string).collect::<Vec<_>>();
+        path.reverse();
+        Self::from_path(&path)
+    }
+}
+
+#[derive(Default)]
+struct StageOutputs {
+    /// Caches of the map tasks of the stage that ran in this process
+    caches: Vec<Arc<ShuffleCache>>,
+    /// Partitions pushed by map tasks that ran elsewhere
+    pushed: HashMap<usize, Vec<MicroPartition>>,
+}
+
+/// The stage outputs a process serves to the other workers of a query
+#[derive(Default)]
+pub struct ShuffleRegistry {
+    stages: RwLock<HashMap<(String, u64), StageOutputs>>,
+}
+
+impl ShuffleRegistry {
+    /// Serves the partitions of a map task's cache as part of the output of a stage
+    pub fn register_cache(&self, query_id: &str, stage_id: u64, cache: Arc<ShuffleCache>) {
+        self.stages
+            .write()
+            .unwrap()
+            .entry((query_id.to_string(), stage_id))
+            .or_default()
+            .caches
+            .push(cache);
+    }
+
+    /// Adds a partition produced by a remote map task to the output of a stage
+    pub fn put_partition(&self, key: &ShuffleKey, partition: MicroPartition) {
+        self.stages
+            .write()
+            .unwrap()
+            .entry((key.query_id.clone(), key.stage_id))
+            .or_default()
+            .pushed
+            .entry(key.partition)
+            .or_default()
+            .push(partition);
+    }
+
+    /// Reads a partition of a stage's output, or None if nothing was registered for the stage
+    pub fn read_partition(&self, key: &ShuffleKey) -> DaftResult<Option<MicroPartition>> {
+        let stages = self.stages.read().unwrap();
+        let Some(outputs) = stages.get(&(key.query_id.clone(), key.stage_id)) else {
+            return Ok(None);
+        };
+
+        let mut parts = outputs
+            .caches
+            .iter()
+            .map(|cache| cache.read_partition(key.partition))
+            .collect::<DaftResult<Vec<_>>>()?;
+        if let Some(pushed) = outputs.pushed.get(&key.partition) {
+            parts.extend(pushed.iter().cloned());
+        }
+        if parts.is_empty() {
+            return Ok(None);
+        }
+        MicroPartition::concat(parts.iter()).map(Some)
+    }
+
+    /// Drops all stage outputs of a query, returning the number of stages removed
+    pub fn remove_query(&self, query_id: &str) -> usize {
+        let mut stages = self.stages.write().unwrap();
+        let before = stages.len();
+        stages.retain(|(id, _), _| id != query_id);
+        before - stages.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shuffle_key_roundtrip() {
+        for query_id in ["query", "runs/2024/query", ""] {
+            let key = ShuffleKey::new(query_id, 3, 7);
+            assert_eq!(key.to_string().parse::<ShuffleKey>().unwrap(), key);
+            assert_eq!(ShuffleKey::from_path(&key.to_path()).unwrap(), key);
+        }
+        assert!("query/3".parse::<ShuffleKey>().is_err());
+        assert!("query/x/7".parse::<ShuffleKey>().is_err());
+    }
+}
@@ -0,0 +1,403 @@
+use std::{path::PathBuf, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+
+use crate::{
+    ipc::{read_spill_file, SpillWriter},
+    temp_space::QueryTempDir,
+};
+
+/// How the rows pushed into a shuffle cache are assigned to its partitions
+#[derive(Debug, Clone)]
+pub enum ShufflePartitioning {
+    /// Rows with equal values of the expressions go to the same partition
+    Hash(Vec<ExprRef>),
+    /// Rows are spread randomly, e.g. to rebalance partitions
+    Random { seed: u64 },
+}
+
+#[derive(Default)]
+struct PartitionBuffer {
+    tables: Vec<Table>,
+    spill_writer: Option<SpillWriter>,
+    num_rows: usize,
+    size_bytes: usize,
+}
+
+/// Collects the outputs of a map task, split into the partitions of the next stage.
+///
+/// Partitions are buffered in memory. If a temp directory is given, all buffered partitions are
+/// appended to one spill file each in it whenever the buffered data exceeds the memory limit. A
+/// memory limit can only be given together with a temp directory to spill to.
+pub struct InProgressShuffleCache {
+    schema: SchemaRef,
+    partitioning: ShufflePartitioning,
+    temp_dir: Option<Arc<QueryTempDir>>,
+    memory_limit_bytes: usize,
+    num_pushed: u64,
+    buffered_bytes: usize,
+    partitions: Vec<PartitionBuffer>,
+}
+
+impl InProgressShuffleCache {
+    pub fn try_new(
+        schema: SchemaRef,
+        num_partitions: usize,
+        partitioning: ShufflePartitioning,
+        temp_dir: Option<Arc<QueryTempDir>>,
+        memory_limit_bytes: usize,
+    ) -> DaftResult<Self> {
+        if num_partitions == 0 {
+            return Err(DaftError::ValueError(
+                "Shuffle cache must have at least one partition".to_string(),
+            ));
+        }
+        if temp_dir.is_none() && memory_limit_bytes != usize::MAX {
+            return Err(DaftError::ValueError(format!(
+                "Shuffle cache with a memory limit of {memory_limit_bytes} bytes needs a temp directory to spill to"
+            )));
+        }
+        Ok(Self {
+            schema,
+            partitioning,
+            temp_dir,
+            memory_limit_bytes,
+            num_pushed: 0,
+            buffered_bytes: 0,
+            partitions: std::iter::repeat_with(PartitionBuffer::default)
+                .take(num_partitions)
+                .collect(),
+        })
+    }
+
+    /// Splits `input` into the partitions of the cache
+    pub fn push_partition(&mut self, input: &MicroPartition) -> DaftResult<()> {
+        if input.schema() != self.schema {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Shuffle cache expected schema {} but got {}",
+                self.schema,
+                input.schema()
+            )));
+        }
+
+        let num_partitions = self.partitions.len();
+        let parts = match &self.partitioning {
+            ShufflePartitioning::Hash(exprs) => input.partition_by_hash(exprs, num_partitions)?,
+            ShufflePartitioning::Random { seed } => {
+                // vary the seed so that consecutive inputs are not split identically
+                input.partition_by_random(num_partitions, seed.wrapping_add(self.num_pushed))?
+            }
+        };
+        self.num_pushed += 1;
+
+        for (buffer, part) in self.partitions.iter_mut().zip(parts) {
+            for table in part.get_tables()?.iter() {
+                if table.is_empty() {
+                    continue;
+                }
+                let size_bytes = table.size_bytes()?;
+                buffer.num_rows += table.len();
+                buffer.size_bytes += size_bytes;
+                buffer.tables.push(table.clone());
+                self.buffered_bytes += size_bytes;
+            }
+        }
+
+        if self.buffered_bytes > self.memory_limit_bytes {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> DaftResult<()> {
+        let temp_dir = self
+            .temp_dir
+            .as_ref()
+            .expect("shuffle caches with a memory limit have a temp dir");
+        // Tables without columns cannot be written to IPC, but take no memory either
+        if self.schema.fields.is_empty() {
+            return Ok(());
+        }
+
+        for buffer in &mut self.partitions {
+            if buffer.tables.is_empty() {
+                continue;
+            }
+            if buffer.spill_writer.is_none() {
+                buffer.spill_writer =
+                    Some(SpillWriter::try_new_in(temp_dir, "shuffle", &self.schema)?);
+            }
+            let writer = buffer
+                .spill_writer
+                .as_mut()
+                .expect("spill writer was just created");
+            for table in buffer.tables.drain(..) {
+                writer.write(&table)?;
+            }
+        }
+        self.buffered_bytes = 0;
+        Ok(())
+    }
+
+    /// Finishes the spill files, making the partitions available for reading
+    pub fn close(self) -> DaftResult<ShuffleCache> {
+        let partitions = self
+            .partitions
+            .into_iter()
+            .map(|buffer| {
+                Ok(ShufflePartition {
+                    spill_file: buffer.spill_writer.map(SpillWriter::finish).transpose()?,
+                    tables: buffer.tables,
+                    num_rows: buffer.num_rows,
+                    size_bytes: buffer.size_bytes,
+                })
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(ShuffleCache {
+            schema: self.schema,
+            temp_dir: self.temp_dir,
+            partitions,
+        })
+    }
+}
+
+struct ShufflePartition {
+    /// Tables spilled before the ones still in memory
+    spill_file: Option<PathBuf>,
+    tables: Vec<Table>,
+    num_rows: usize,
+    size_bytes: usize,
+}
+
+/// The partitioned output of a map task. Spill files are deleted when the cache is dropped.
+pub struct ShuffleCache {
+    schema: SchemaRef,
+    temp_dir: Option<Arc<QueryTempDir>>,
+    partitions: Vec<ShufflePartition>,
+}
+
+impl ShuffleCache {
+    #[must_use]
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    #[must_use]
+    pub fn num_partitions(&self) -> usize {
+        self.partitions.len()
+    }
+
+    /// Number of rows in each partition
+    #[must_use]
+    pub fn partition_num_rows(&self) -> Vec<usize> {
+        self.partitions.iter().map(|p| p.num_rows).collect()
+    }
+
+    /// In-memory size of each partition, in bytes
+    #[must_use]
+    pub fn partition_size_bytes(&self) -> Vec<usize> {
+        self.partitions.iter().map(|p| p.size_bytes).collect()
+    }
+
+    /// Reads a partition, including the parts of it that were spilled
+    pub fn read_partition(&self, idx: usize) -> DaftResult<MicroPartition> {
+        let partition = self.partitions.get(idx).ok_or_else(|| {
+            DaftError::ValueError(format!(
+                "Partition {idx} is out of range for a shuffle cache with {} partitions",
+                self.partitions.len()
+            ))
+        })?;
+
+        let mut tables = match &partition.spill_file {
+            Some(path) => read_spill_file(path, &self.schema)?,
+            None => Vec::new(),
+        };
+        tables.extend(partition.tables.iter().cloned());
+        Ok(MicroPartition::new_loaded(
+            self.schema.clone(),
+            Arc::new(tables),
+            None,
+        ))
+    }
+}
+
+impl Drop for ShuffleCache {
+    fn drop(&mut self) {
+        let Some(temp_dir) = &self.temp_dir else {
+            return;
+        };
+        for path in self.partitions.iter().filter_map(|p| p.spill_file.as_ref()) {
+            temp_dir.remove_file(path);
+        }
+    }
+}
+
+/// Reads the partitions of the next stage from the caches of all tasks of a map stage
+pub struct ShuffleReader {
+    caches: Vec<Arc<ShuffleCache>>,
+}
+
+impl ShuffleReader {
+    pub fn try_new(caches: Vec<Arc<ShuffleCache>>) -> DaftResult<Self> {
+        if let Some(first) = caches.first() {
+            if let Some(other) = caches
+                .iter()
+                .find(|c| c.num_partitions() != first.num_partitions() || c.schema != first.schema)
+            {
+                return Err(DaftError::ValueError(format!(
+                    "Shuffle caches of a stage must have the same schema and number of partitions, got {} partitions of {} and {} partitions of {}",
+                    first.num_partitions(),
+                    first.schema,
+                    other.num_partitions(),
+                    other.schema
+                )));
+            }
+        }
+        Ok(Self { caches })
+    }
+
+    #[must_use]
+    pub fn num_partitions(&self) -> usize {
+        self.caches.first().map_or(0, |c| c.num_partitions())
+    }
+
+    /// Reads partition `idx` from every cache, in the order the caches were given
+    pub fn read_partition(&self, idx: usize) -> DaftResult<MicroPartition> {
+        let parts = self
+            .caches
+            .iter()
+            .map(|cache| cache.read_partition(idx))
+            .collect::<DaftResult<Vec<_>>>()?;
+        MicroPartition::concat(parts.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+
+    use super::*;
+    use crate::temp_space::TempSpace;
+
+    fn make_partition(values: Vec<i64>) -> MicroPartition {
+        let table =
+            Table::from_nonempty_columns(vec![Int64Array::from(("a", values)).into_series()])
+                .unwrap();
+        MicroPartition::new_loaded(table.schema.clone(), Arc::new(vec![table]), None)
+    }
+
+    fn read_values(reader: &ShuffleReader, idx: usize) -> Vec<i64> {
+        let part = reader.read_partition(idx).unwrap();
+        let mut values = part
+            .get_tables()
+            .unwrap()
+            .iter()
+            .flat_map(|t| {
+                t.get_column("a")
+                    .unwrap()
+                    .i64()
+                    .unwrap()
+                    .as_arrow()
+                    .values_iter()
+                    .copied()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        values.sort_unstable();
+        values
+    }
+
+    fn try_shuffle(
+        temp_dir: Option<Arc<QueryTempDir>>,
+        memory_limit_bytes: usize,
+    ) -> DaftResult<ShuffleReader> {
+        let schema = make_partition(vec![]).schema();
+        let caches = [vec![1, 2, 3, 4], vec![1, 2, 5, 6]]
+            .into_iter()
+            .map(|values| {
+                let mut cache = InProgressShuffleCache::try_new(
+                    schema.clone(),
+                    3,
+                    ShufflePartitioning::Hash(vec![col("a")]),
+                    temp_dir.clone(),
+                    memory_limit_bytes,
+                )?;
+                cache.push_partition(&make_partition(values.clone()))?;
+                cache.push_partition(&make_partition(values))?;
+                Ok(Arc::new(cache.close()?))
+            })
+            .collect::<DaftResult<_>>()?;
+        ShuffleReader::try_new(caches)
+    }
+
+    fn shuffle(temp_dir: Option<Arc<QueryTempDir>>, memory_limit_bytes: usize) -> ShuffleReader {
+        try_shuffle(temp_dir, memory_limit_bytes).unwrap()
+    }
+
+    /// The partition out of `num_partitions` that hash partitioning assigns `value` to
+    fn hash_partition_of(value: i64, num_partitions: usize) -> usize {
+        make_partition(vec![value])
+            .partition_by_hash(&[col("a")], num_partitions)
+            .unwrap()
+            .iter()
+            .position(|part| !part.is_empty())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_hash_shuffle_colocates_keys() {
+        let reader = shuffle(None, usize::MAX);
+        assert_eq!(reader.num_partitions(), 3);
+
+        // every occurrence of a key, from both map outputs, ends up in the key's hash partition
+        let mut expected = vec![Vec::new(); 3];
+        for (value, count) in [(1, 4), (2, 4), (3, 2), (4, 2), (5, 2), (6, 2)] {
+            expected[hash_partition_of(value, 3)].extend(std::iter::repeat(value).take(count));
+        }
+        for (idx, expected) in expected.into_iter().enumerate() {
+            assert_eq!(read_values(&reader, idx), expected);
+        }
+    }
+
+    #[test]
+    fn test_memory_limit_needs_temp_dir() {
+        assert!(try_shuffle(None, 0).is_err());
+    }
+
+    #[test]
+    fn test_spilled_shuffle_matches_in_memory() {
+        let dir = tempfile::tempdir().unwrap();
+        let space = TempSpace::new(dir.path().to_path_buf(), None);
+        let temp_dir = space.query_dir();
+        let in_memory = shuffle(None, usize::MAX);
+        let spilled = shuffle(Some(temp_dir.clone()), 0);
+
+        assert!(std::fs::read_dir(temp_dir.path()).unwrap().next().is_some());
+        assert!(space.used_bytes() > 0);
+        for idx in 0..3 {
+            assert_eq!(read_values(&in_memory, idx), read_values(&spilled, idx));
+        }
+
+        drop(spilled);
+        assert!(std::fs::read_dir(temp_dir.path()).unwrap().next().is_none());
+        assert_eq!(space.used_bytes(), 0);
+    }
+
+    #[test]
+    fn test_spill_fails_past_temp_space_quota() {
+        let dir = tempfile::tempdir().unwrap();
+        let space = TempSpace::new(dir.path().to_path_buf(), Some(16));
+        let temp_dir = space.query_dir();
+        let temp_dir_path = temp_dir.path().to_path_buf();
+
+        assert!(try_shuffle(Some(temp_dir.clone()), 0).is_err());
+        drop(temp_dir);
+        assert!(!temp_dir_path.exists());
+        assert_eq!(space.used_bytes(), 0);
+    }
+}
@@ -0,0 +1,398 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, LazyLock, Mutex,
+    },
+};
+
+/// Prefix of the names of query directories, followed by the id of the process and of the query
+const QUERY_DIR_PREFIX: &str = "daft-query-";
+
+/// The temp spaces of the process by root directory, so that all queries creating temp files in
+/// the same directory share its quota
+static TEMP_SPACES: LazyLock<Mutex<HashMap<PathBuf, Arc<TempSpace>>>> =
+    LazyLock::new(Default::default);
+
+/// Distinguishes the query directories of a process
+static NEXT_QUERY_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Disk space for the temp files of queries, such as spill files and shuffle outputs.
+///
+/// Each query gets its own [`QueryTempDir`] under the root directory, which is deleted along with
+/// all its files once the query drops it, whether the query succeeded, failed or was cancelled.
+/// The total size of the files of all queries is limited by an optional quota, past which writing
+/// temp files fails.
+#[derive(Debug)]
+pub struct TempSpace {
+    root: PathBuf,
+    /// `usize::MAX` if there is no quota
+    quota_bytes: usize,
+    used_bytes: AtomicUsize,
+    /// Lock file of the process in the root, which marks its query directories as in use, see
+    /// [`remove_stale_query_dirs`]
+    _lock: Option<File>,
+}
+
+impl TempSpace {
+    pub fn new(root: PathBuf, quota_bytes: Option<usize>) -> Arc<Self> {
+        let lock = lock_root(&root);
+        remove_stale_query_dirs(&root);
+        Arc::new(Self {
+            root,
+            quota_bytes: quota_bytes.unwrap_or(usize::MAX),
+            used_bytes: AtomicUsize::new(0),
+            _lock: lock,
+        })
+    }
+
+    /// The temp space of the process at `root`, or at the system temp directory if there is no
+    /// root, created with `quota_bytes` on first use. An existing temp space keeps the quota it
+    /// was created with.
+    pub fn get_or_create(root: Option<&Path>, quota_bytes: Option<usize>) -> Arc<Self> {
+        let root = root.map_or_else(std::env::temp_dir, Path::to_path_buf);
+        let mut temp_spaces = TEMP_SPACES.lock().expect("temp spaces lock is poisoned");
+        temp_spaces
+            .entry(root.clone())
+            .or_insert_with(|| Self::new(root, quota_bytes))
+            .clone()
+    }
+
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    #[must_use]
+    pub fn quota_bytes(&self) -> Option<usize> {
+        Some(self.quota_bytes).filter(|quota| *quota != usize::MAX)
+    }
+
+    /// Total size of the temp files of all queries
+    #[must_use]
+    pub fn used_bytes(&self) -> usize {
+        self.used_bytes.load(Ordering::Relaxed)
+    }
+
+    /// A temp directory for a new query. The directory is only created once the query creates a
+    /// file in it.
+    #[must_use]
+    pub fn query_dir(self: &Arc<Self>) -> Arc<QueryTempDir> {
+        let name = format!(
+            "{QUERY_DIR_PREFIX}{}-{}",
+            std::process::id(),
+            NEXT_QUERY_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        Arc::new(QueryTempDir {
+            space: self.clone(),
+            path: self.root.join(name),
+            next_file_id: AtomicUsize::new(0),
+            used_bytes: AtomicUsize::new(0),
+        })
+    }
+
+    fn reserve(&self, bytes: usize) -> std::io::Result<()> {
+        let quota = self.quota_bytes;
+        self.used_bytes
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(bytes).filter(|total| *total <= quota)
+            })
+            .map(|_| ())
+            .map_err(|used| {
+                std::io::Error::other(format!(
+                    "Temp space quota of {quota} bytes in {} is exhausted, with {used} bytes used \
+                     and {bytes} more requested. Raise `temp_space_quota_bytes` in the execution \
+                     config to allow queries to spill more data",
+                    self.root.display()
+                ))
+            })
+    }
+
+    fn release(&self, bytes: usize) {
+        self.used_bytes.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
+
+/// Name of the lock file that a process holds a shared lock on for as long as it may have query
+/// directories in a root
+fn lock_file_name(pid: u32) -> String {
+    format!("{QUERY_DIR_PREFIX}{pid}.lock")
+}
+
+/// Takes a shared lock on the lock file of this process in `root`, which is released when the
+/// returned file is closed, including when the process is killed.
+#[cfg(unix)]
+fn lock_root(root: &Path) -> Option<File> {
+    use std::os::unix::fs::MetadataExt;
+
+    let path = root.join(lock_file_name(std::process::id()));
+    let result = (|| loop {
+        std::fs::create_dir_all(root)?;
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)?;
+        flock(&file, libc::LOCK_SH)?;
+        // Another process may have removed the file as stale before it was locked, e.g. if it
+        // belonged to an earlier process with the same id, in which case it is created again.
+        let (locked, current) = (file.metadata()?, std::fs::metadata(&path));
+        if let Ok(current) = current {
+            if (current.dev(), current.ino()) == (locked.dev(), locked.ino()) {
+                return Ok::<_, std::io::Error>(file);
+            }
+        }
+    })();
+    result
+        .map_err(|e| {
+            log::warn!(
+                "Failed to lock temp directory {}, its query directories may be removed by other \
+                 processes: {e}",
+                root.display()
+            );
+        })
+        .ok()
+}
+
+#[cfg(not(unix))]
+fn lock_root(_root: &Path) -> Option<File> {
+    None
+}
+
+#[cfg(unix)]
+fn flock(file: &File, operation: libc::c_int) -> std::io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    // SAFETY: the file descriptor is open for as long as `file` is borrowed.
+    if unsafe { libc::flock(file.as_raw_fd(), operation) } == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+/// Removes the query directories left behind by processes that are no longer running, e.g.
+/// because they were killed before they could clean up. A process is no longer running if it
+/// has no lock file in the root or holds no lock on it, since locks are released when processes
+/// exit. Locks are only available on Unix, so nothing is removed elsewhere.
+#[cfg(unix)]
+fn remove_stale_query_dirs(root: &Path) {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return;
+    };
+    let mut paths_by_pid: HashMap<u32, Vec<PathBuf>> = HashMap::new();
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let Some(pid) = file_name
+            .to_str()
+            .and_then(|name| name.strip_prefix(QUERY_DIR_PREFIX))
+            .and_then(|ids| ids.split(['-', '.']).next())
+            .and_then(|pid| pid.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        paths_by_pid.entry(pid).or_default().push(entry.path());
+    }
+    for (pid, paths) in paths_by_pid {
+        // The exclusive lock is held until the lock file is removed, so that a process that
+        // opened the file in the meantime sees that it was removed once it gets its lock.
+        let _lock = match File::open(root.join(lock_file_name(pid))) {
+            Ok(file) => match flock(&file, libc::LOCK_EX | libc::LOCK_NB) {
+                Ok(()) => Some(file),
+                Err(_) => continue,
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(_) => continue,
+        };
+        for path in paths {
+            let result = if path.is_dir() {
+                std::fs::remove_dir_all(&path)
+            } else {
+                std::fs::remove_file(&path)
+            };
+            if let Err(e) = result {
+                log::warn!(
+                    "Failed to remove stale temp directory {}: {e}",
+                    path.display()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn remove_stale_query_dirs(_root: &Path) {}
+
+/// The temp directory of a query, which is deleted along with its files when dropped.
+#[derive(Debug)]
+pub struct QueryTempDir {
+    space: Arc<TempSpace>,
+    path: PathBuf,
+    next_file_id: AtomicUsize,
+    used_bytes: AtomicUsize,
+}
+
+impl QueryTempDir {
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Total size of the temp files of the query
+    #[must_use]
+    pub fn used_bytes(&self) -> usize {
+        self.used_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Creates a new temp file of the query, named after `prefix` and `extension`. Writes to the
+    /// file count against the quota of the temp space.
+    pub fn create_file(
+        self: &Arc<Self>,
+        prefix: &str,
+        extension: &str,
+    ) -> std::io::Result<TempFile> {
+        std::fs::create_dir_all(&self.path)?;
+        let path = self.path.join(format!(
+            "{prefix}-{}.{extension}",
+            self.next_file_id.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = File::create(&path)?;
+        Ok(TempFile {
+            file,
+            path,
+            dir: self.clone(),
+        })
+    }
+
+    /// Reserves `bytes` of the quota of the temp space for a file of this query
+    pub fn reserve(&self, bytes: usize) -> std::io::Result<()> {
+        self.space.reserve(bytes)?;
+        self.used_bytes.fetch_add(bytes, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Returns `bytes` reserved by this query to the quota of the temp space
+    pub fn release(&self, bytes: usize) {
+        self.used_bytes.fetch_sub(bytes, Ordering::Relaxed);
+        self.space.release(bytes);
+    }
+
+    /// Deletes a temp file of this query before the query ends, returning its space to the quota
+    pub fn remove_file(&self, path: &Path) {
+        let size = std::fs::metadata(path).map_or(0, |metadata| metadata.len() as usize);
+        match std::fs::remove_file(path) {
+            Ok(()) => self.release(size.min(self.used_bytes())),
+            Err(e) => log::warn!("Failed to remove temp file {}: {e}", path.display()),
+        }
+    }
+}
+
+impl Drop for QueryTempDir {
+    fn drop(&mut self) {
+        if self.path.exists() {
+            if let Err(e) = std::fs::remove_dir_all(&self.path) {
+                log::warn!(
+                    "Failed to remove query temp directory {}: {e}",
+                    self.path.display()
+                );
+            }
+        }
+        self.space.release(self.used_bytes());
+    }
+}
+
+/// A file in the temp directory of a query, whose writes count against the quota of the temp
+/// space. The file is deleted along with the directory unless it is removed earlier with
+/// [`QueryTempDir::remove_file`].
+#[derive(Debug)]
+pub struct TempFile {
+    file: File,
+    path: PathBuf,
+    dir: Arc<QueryTempDir>,
+}
+
+impl TempFile {
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Write for TempFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.dir.reserve(buf.len())?;
+        let result = self.file.write(buf);
+        let written = *result.as_ref().unwrap_or(&0);
+        self.dir.release(buf.len() - written);
+        result
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quota_and_cleanup() {
+        let root = tempfile::tempdir().unwrap();
+        let space = TempSpace::new(root.path().to_path_buf(), Some(10));
+        let first = space.query_dir();
+        let second = space.query_dir();
+        assert_ne!(first.path(), second.path());
+        // directories are only created when needed
+        assert!(!first.path().exists());
+
+        let mut file = first.create_file("spill", "arrow").unwrap();
+        file.write_all(b"12345678").unwrap();
+        assert_eq!(first.used_bytes(), 8);
+        assert_eq!(space.used_bytes(), 8);
+
+        // the quota is shared by the queries of the temp space
+        let mut other = second.create_file("spill", "arrow").unwrap();
+        assert!(other.write_all(b"123").is_err());
+        other.write_all(b"12").unwrap();
+        assert_eq!(space.used_bytes(), 10);
+
+        let path = file.path().to_path_buf();
+        drop(file);
+        first.remove_file(&path);
+        assert!(!path.exists());
+        assert_eq!(space.used_bytes(), 2);
+
+        let second_path = second.path().to_path_buf();
+        drop(other);
+        drop(second);
+        assert!(!second_path.exists());
+        assert_eq!(space.used_bytes(), 0);
+    }
+
+    #[test]
+    fn test_remove_stale_query_dirs() {
+        let root = tempfile::tempdir().unwrap();
+        // the process of this directory has no lock file, so it is no longer running
+        let stale = root.path().join(format!("{QUERY_DIR_PREFIX}999999999-0"));
+        let live = root
+            .path()
+            .join(format!("{QUERY_DIR_PREFIX}{}-0", std::process::id()));
+        let unrelated = root.path().join("other");
+        for dir in [&stale, &live, &unrelated] {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+
+        let _space = TempSpace::new(root.path().to_path_buf(), None);
+        assert_eq!(stale.exists(), !cfg!(unix));
+        assert!(live.exists());
+        assert!(unrelated.exists());
+
+        // the lock file of this process keeps its directories when other temp spaces are created
+        let _other = TempSpace::new(root.path().to_path_buf(), None);
+        assert!(live.exists());
+    }
+}
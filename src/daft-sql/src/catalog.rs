@@ -1,11 +1,93 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
-use daft_logical_plan::{LogicalPlan, LogicalPlanRef};
+use common_error::DaftResult;
+use common_file_formats::{CsvSourceConfig, FileFormat, FileFormatConfig, ParquetSourceConfig};
+use common_scan_info::ScanOperatorRef;
+use daft_core::prelude::SchemaRef;
+use daft_logical_plan::{LogicalPlan, LogicalPlanBuilder, LogicalPlanRef, OutputFileInfo};
+use daft_scan::{glob::GlobScanOperator, storage_config::StorageConfig};
+
+use crate::information_schema;
+
+/// Resolves tables that are not registered with a catalog when they are looked up, e.g. tables
+/// of an external catalog.
+#[derive(Clone)]
+pub struct TableResolver(
+    Arc<dyn Fn(&SQLCatalog, &str) -> DaftResult<Option<LogicalPlanRef>> + Send + Sync>,
+);
+
+impl TableResolver {
+    pub fn new(
+        resolve: impl Fn(&SQLCatalog, &str) -> DaftResult<Option<LogicalPlanRef>>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self(Arc::new(resolve))
+    }
+}
+
+impl std::fmt::Debug for TableResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TableResolver")
+    }
+}
+
+/// The table formats of `CREATE TABLE ... AS SELECT` statements that are written with a commit to
+/// their table format, through the DataFrame API's `write_iceberg` and `write_deltalake`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    Iceberg,
+    DeltaLake,
+}
+
+impl TableFormat {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Iceberg => "iceberg",
+            Self::DeltaLake => "deltalake",
+        }
+    }
+}
+
+/// A table created with `CREATE TABLE ... AS SELECT` in a [`TableFormat`]. The planned statement
+/// only produces the rows of the table, which the caller writes and commits before registering
+/// the table under `name`.
+#[derive(Debug, Clone)]
+pub struct TableFormatWrite {
+    pub name: String,
+    pub format: TableFormat,
+    /// The catalog the table is created in, or the default catalog if unspecified
+    pub catalog: Option<String>,
+    pub location: Option<String>,
+    pub partition_cols: Vec<String>,
+    /// Whether an existing table is replaced, otherwise the write fails if the table exists
+    pub overwrite: bool,
+}
 
 /// A simple map of table names to logical plans
 #[derive(Debug, Clone)]
 pub struct SQLCatalog {
     tables: HashMap<String, Arc<LogicalPlan>>,
+    /// Where tables created with `CREATE TABLE ... AS SELECT` are written to. These tables are
+    /// scanned from their location, and their registered plans only provide their schemas.
+    outputs: HashMap<String, OutputFileInfo>,
+    /// Tables that were registered as views with `CREATE TEMP VIEW`
+    views: HashSet<String>,
+    /// Tables whose files are written by the planned statement, which must only be registered
+    /// elsewhere once the statement was executed and its write committed
+    pending: HashSet<String>,
+    /// The table in a table format that the planned statement creates, if any
+    table_format_write: Option<TableFormatWrite>,
+    /// Tables that were created or changed by SQL statements since the catalog was created or
+    /// since [`Self::take_changes`] was last called
+    changed: HashSet<String>,
+    /// Resolves the tables that are not registered with the catalog
+    resolver: Option<TableResolver>,
 }
 
 impl SQLCatalog {
@@ -14,26 +96,215 @@ impl SQLCatalog {
     pub fn new() -> Self {
         Self {
             tables: HashMap::new(),
+            outputs: HashMap::new(),
+            views: HashSet::new(),
+            pending: HashSet::new(),
+            table_format_write: None,
+            changed: HashSet::new(),
+            resolver: None,
         }
     }
 
+    /// Set the resolver of tables that are not registered with the catalog
+    pub fn set_resolver(&mut self, resolver: TableResolver) {
+        self.resolver = Some(resolver);
+    }
+
     /// Register a table with the catalog
     pub fn register_table(&mut self, name: &str, plan: LogicalPlanRef) {
         self.tables.insert(name.to_string(), plan);
+        self.outputs.remove(name);
+        self.views.remove(name);
+        self.pending.remove(name);
+        self.changed.insert(name.to_string());
+    }
+
+    /// Register a table that is written to `output` by the planned statement. Its plan only
+    /// provides the schema of the table, which is scanned from the location once it was written.
+    pub fn register_pending_output(
+        &mut self,
+        name: &str,
+        plan: LogicalPlanRef,
+        output: OutputFileInfo,
+    ) {
+        self.register_table(name, plan);
+        self.outputs.insert(name.to_string(), output);
+        self.pending.insert(name.to_string());
+    }
+
+    /// Whether the tables of the catalog may only be registered elsewhere once the planned
+    /// statement was executed, because it writes them
+    #[must_use]
+    pub fn has_pending_writes(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Record the table in a table format that the planned statement creates
+    pub fn register_table_format_write(&mut self, write: TableFormatWrite) {
+        self.table_format_write = Some(write);
+    }
+
+    /// The table in a table format that the planned statement creates, if any
+    #[must_use]
+    pub fn table_format_write(&self) -> Option<&TableFormatWrite> {
+        self.table_format_write.as_ref()
+    }
+
+    /// Register a view with the catalog
+    pub fn register_view(&mut self, name: &str, plan: LogicalPlanRef) {
+        self.register_table(name, plan);
+        self.views.insert(name.to_string());
+    }
+
+    /// Get the location a table is written to, if any
+    #[must_use]
+    pub fn get_output(&self, name: &str) -> Option<OutputFileInfo> {
+        self.outputs.get(name).cloned()
+    }
+
+    /// Get a table from the catalog. Tables that were written to a location are scanned from
+    /// there, but their files are only listed once the plan is executed. Tables that are not
+    /// registered are either the `information_schema` tables, which describe the tables of the
+    /// catalog, or looked up with the resolver of the catalog, if any, and then in the data
+    /// catalogs registered with Daft, e.g. an Iceberg REST catalog.
+    pub fn get_table(&self, name: &str) -> DaftResult<Option<LogicalPlanRef>> {
+        let Some(plan) = self.tables.get(name) else {
+            if let Some(plan) = information_schema::get_table(self, name)? {
+                return Ok(Some(plan));
+            }
+            if let Some(resolver) = &self.resolver {
+                if let Some(plan) = (resolver.0)(self, name)? {
+                    return Ok(Some(plan));
+                }
+            }
+            return match daft_catalog::global_catalog::read_table(name) {
+                Ok(builder) => Ok(Some(builder.build())),
+                Err(daft_catalog::errors::Error::TableNotFound { .. }) => Ok(None),
+                Err(err) => Err(err.into()),
+            };
+        };
+        match self.outputs.get(name) {
+            Some(output) => Ok(Some(scan_output(output, plan.schema())?.build())),
+            None => Ok(Some(plan.clone())),
+        }
+    }
+
+    /// Get the schema of a table in the catalog, without scanning it
+    #[must_use]
+    pub fn get_schema(&self, name: &str) -> Option<SchemaRef> {
+        self.tables.get(name).map(|plan| plan.schema())
+    }
+
+    /// Whether there is a table with the name in the catalog
+    #[must_use]
+    pub fn has_table(&self, name: &str) -> bool {
+        self.tables.contains_key(name)
     }
 
-    /// Get a table from the catalog
+    /// Whether the table was registered as a view
     #[must_use]
-    pub fn get_table(&self, name: &str) -> Option<LogicalPlanRef> {
-        self.tables.get(name).cloned()
+    pub fn is_view(&self, name: &str) -> bool {
+        self.views.contains(name)
+    }
+
+    /// Names of all tables in the catalog, in sorted order
+    #[must_use]
+    pub fn table_names(&self) -> Vec<String> {
+        let mut names = self.tables.keys().cloned().collect::<Vec<_>>();
+        names.sort();
+        names
     }
 
     /// Copy from another catalog, using tables from other in case of conflict
     pub fn copy_from(&mut self, other: &Self) {
         for (name, plan) in &other.tables {
-            self.tables.insert(name.clone(), plan.clone());
+            self.register_table(name, plan.clone());
+        }
+        for (name, output) in &other.outputs {
+            self.outputs.insert(name.clone(), output.clone());
+        }
+        self.views.extend(other.views.iter().cloned());
+    }
+
+    /// Takes the tables that were created or changed by SQL statements out of the catalog into a
+    /// new catalog, so that they can be registered with the catalog the statements were run
+    /// against. If the changes have [pending writes](Self::has_pending_writes) or a
+    /// [table format write](Self::table_format_write), they must only be registered once the
+    /// planned statement was executed.
+    pub fn take_changes(&mut self) -> Self {
+        let mut changes = Self::new();
+        changes.table_format_write = self.table_format_write.take();
+        for name in std::mem::take(&mut self.changed) {
+            let Some(plan) = self.tables.get(&name) else {
+                continue;
+            };
+            changes.tables.insert(name.clone(), plan.clone());
+            if let Some(output) = self.outputs.get(&name) {
+                changes.outputs.insert(name.clone(), output.clone());
+            }
+            if self.pending.remove(&name) {
+                changes.pending.insert(name.clone());
+            }
+            if self.views.contains(&name) {
+                changes.views.insert(name);
+            }
         }
+        changes
     }
+
+    /// Copy only the views from another catalog, using views from other in case of conflict
+    pub fn copy_views_from(&mut self, other: &Self) {
+        for name in &other.views {
+            if let Some(plan) = other.tables.get(name) {
+                self.register_view(name, plan.clone());
+            }
+        }
+    }
+}
+
+/// Scans the files that were written to the location of a table, without listing them until the
+/// scan tasks of the plan are created.
+fn scan_output(output: &OutputFileInfo, schema: SchemaRef) -> DaftResult<LogicalPlanBuilder> {
+    let root_dir = output.root_dir.trim_end_matches('/');
+    let (glob_path, file_format_config, multithreaded_io) = match output.file_format {
+        FileFormat::Csv => (
+            format!("{root_dir}/**/*.csv"),
+            FileFormatConfig::Csv(CsvSourceConfig {
+                delimiter: None,
+                has_headers: true,
+                double_quote: true,
+                quote: None,
+                escape_char: None,
+                comment: None,
+                allow_variable_columns: false,
+                buffer_size: None,
+                chunk_size: None,
+            }),
+            false,
+        ),
+        _ => (
+            format!("{root_dir}/**/*.parquet"),
+            FileFormatConfig::Parquet(ParquetSourceConfig::default()),
+            true,
+        ),
+    };
+    let hive_partition_cols = output
+        .partition_cols
+        .iter()
+        .flatten()
+        .map(|expr| expr.name().to_string())
+        .collect::<Vec<_>>();
+    let operator = GlobScanOperator::try_new_with_schema(
+        vec![glob_path],
+        Arc::new(file_format_config),
+        Arc::new(StorageConfig::new_internal(
+            multithreaded_io,
+            output.io_config.clone(),
+        )),
+        schema,
+        &hive_partition_cols,
+    )?;
+    LogicalPlanBuilder::table_scan(ScanOperatorRef(Arc::new(operator)), None)
 }
 
 impl Default for SQLCatalog {
@@ -0,0 +1,168 @@
+//! The `information_schema` tables, which describe the tables of a [`SQLCatalog`] at the time
+//! they are looked up.
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
+use daft_micropartition::partitioning::{MicroPartitionSet, PartitionCacheEntry};
+use daft_table::Table;
+
+use crate::catalog::SQLCatalog;
+
+/// Counter of the partition sets of `information_schema` tables, which keeps their keys unique
+static PARTITION_SET_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The plan of the `information_schema` table `name` of the catalog, or None if `name` is not one
+/// of `information_schema.tables` and `information_schema.columns`
+pub(crate) fn get_table(catalog: &SQLCatalog, name: &str) -> DaftResult<Option<LogicalPlanRef>> {
+    let table = match name {
+        "information_schema.tables" => tables(catalog)?,
+        "information_schema.columns" => columns(catalog)?,
+        _ => return Ok(None),
+    };
+    in_memory_scan(table).map(Some)
+}
+
+fn tables(catalog: &SQLCatalog) -> DaftResult<Table> {
+    let names = catalog.table_names();
+    let types = names
+        .iter()
+        .map(|name| {
+            if catalog.is_view(name) {
+                "VIEW"
+            } else {
+                "BASE TABLE"
+            }
+        })
+        .collect::<Vec<_>>();
+    to_table(vec![
+        Utf8Array::from(("table_name", names.as_slice())).into_series(),
+        Utf8Array::from(("table_type", types.as_slice())).into_series(),
+    ])
+}
+
+fn columns(catalog: &SQLCatalog) -> DaftResult<Table> {
+    let mut table_names = Vec::new();
+    let mut column_names = Vec::new();
+    let mut positions = Vec::new();
+    let mut data_types = Vec::new();
+    for table_name in catalog.table_names() {
+        let Some(schema) = catalog.get_schema(&table_name) else {
+            continue;
+        };
+        for (position, field) in (1..).zip(schema.fields.values()) {
+            table_names.push(table_name.clone());
+            column_names.push(field.name.clone());
+            positions.push(position);
+            data_types.push(field.dtype.to_string());
+        }
+    }
+    to_table(vec![
+        Utf8Array::from(("table_name", table_names.as_slice())).into_series(),
+        Utf8Array::from(("column_name", column_names.as_slice())).into_series(),
+        Int64Array::from(("ordinal_position", positions)).into_series(),
+        Utf8Array::from(("data_type", data_types.as_slice())).into_series(),
+    ])
+}
+
+fn to_table(columns: Vec<Series>) -> DaftResult<Table> {
+    let num_rows = columns.first().map_or(0, Series::len);
+    let schema = Schema::new(
+        columns
+            .iter()
+            .map(|column| column.field().clone())
+            .collect(),
+    )?;
+    Table::new_with_size(schema, columns, num_rows)
+}
+
+/// Scans the rows of the table from memory. The partition set of the scan is held by its cache
+/// entry, so that executors find it without it being put into their partition set cache.
+fn in_memory_scan(table: Table) -> DaftResult<LogicalPlanRef> {
+    let schema = table.schema.clone();
+    let num_rows = table.len();
+    let size_bytes = table.size_bytes()?;
+    let key = format!(
+        "information_schema-{}",
+        PARTITION_SET_ID.fetch_add(1, Ordering::Relaxed)
+    );
+    let pset = Arc::new(MicroPartitionSet::from_tables(0, vec![table])?);
+    Ok(LogicalPlanBuilder::in_memory_scan(
+        &key,
+        PartitionCacheEntry::new_rust(key.clone(), pset),
+        schema,
+        1,
+        size_bytes,
+        num_rows,
+        None,
+    )?
+    .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_logical_plan::{
+        logical_plan::Source, source_info::PlaceHolderInfo, ClusteringSpec, LogicalPlan, SourceInfo,
+    };
+
+    use super::*;
+
+    fn placeholder(fields: Vec<Field>) -> LogicalPlanRef {
+        let schema = Arc::new(Schema::new(fields).unwrap());
+        LogicalPlan::Source(Source::new(
+            schema.clone(),
+            Arc::new(SourceInfo::PlaceHolder(PlaceHolderInfo {
+                source_schema: schema,
+                clustering_spec: Arc::new(ClusteringSpec::unknown()),
+                source_id: 0,
+            })),
+        ))
+        .arced()
+    }
+
+    fn utf8_values(table: &Table, name: &str) -> Vec<String> {
+        let column = table.get_column(name).unwrap();
+        let column = column.utf8().unwrap();
+        (0..column.len())
+            .map(|idx| column.get(idx).unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_describes_tables_and_views() {
+        let mut catalog = SQLCatalog::new();
+        catalog.register_table(
+            "t",
+            placeholder(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("name", DataType::Utf8),
+            ]),
+        );
+        catalog.register_view("v", placeholder(vec![Field::new("id", DataType::Int64)]));
+
+        let tables = tables(&catalog).unwrap();
+        assert_eq!(utf8_values(&tables, "table_name"), vec!["t", "v"]);
+        assert_eq!(
+            utf8_values(&tables, "table_type"),
+            vec!["BASE TABLE", "VIEW"]
+        );
+
+        let columns = columns(&catalog).unwrap();
+        assert_eq!(utf8_values(&columns, "table_name"), vec!["t", "t", "v"]);
+        assert_eq!(
+            utf8_values(&columns, "column_name"),
+            vec!["id", "name", "id"]
+        );
+        assert_eq!(
+            utf8_values(&columns, "data_type"),
+            vec!["Int64", "Utf8", "Int64"]
+        );
+        let positions = columns.get_column("ordinal_position").unwrap();
+        assert_eq!(positions.i64().unwrap().as_slice(), &[1, 2, 1]);
+    }
+}
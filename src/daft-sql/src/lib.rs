@@ -3,6 +3,7 @@
 pub mod catalog;
 pub mod error;
 pub mod functions;
+mod information_schema;
 mod modules;
 
 mod planner;
@@ -10,6 +11,7 @@ pub use planner::*;
 
 #[cfg(feature = "python")]
 pub mod python;
+mod statements;
 mod table_provider;
 
 #[cfg(feature = "python")]
@@ -32,8 +34,8 @@ mod tests {
     use daft_core::prelude::*;
     use daft_dsl::{col, lit, Expr, OuterReferenceColumn, Subquery};
     use daft_logical_plan::{
-        logical_plan::Source, source_info::PlaceHolderInfo, ClusteringSpec, LogicalPlan,
-        LogicalPlanBuilder, LogicalPlanRef, SourceInfo,
+        logical_plan::Source, source_info::PlaceHolderInfo, ClusteringSpec, CopyIntoInfo,
+        LogicalPlan, LogicalPlanBuilder, LogicalPlanRef, SinkInfo, SourceInfo, WriteMode,
     };
     use error::SQLPlannerResult;
     use rstest::{fixture, rstest};
@@ -499,4 +501,340 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[case::comma(
+        "select * from tbl2, lateral (select max(id) as m from tbl3 where tbl3.id = tbl2.id) as s"
+    )]
+    #[case::join_on_true(
+        "select * from tbl2 join lateral (select first_name from tbl3 where id = tbl2.id) s on true"
+    )]
+    #[case::left_join(
+        "select * from tbl2 left join lateral (select first_name from tbl3 where id = tbl2.id) s on true"
+    )]
+    #[case::cross_join(
+        "select * from tbl2 cross join lateral (select first_name from tbl3 where id = tbl2.id) s"
+    )]
+    #[case::uncorrelated("select * from tbl2, lateral (select max(id) as m from tbl3) as s")]
+    fn test_compiles_lateral(mut planner: SQLPlanner, #[case] query: &str) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query);
+        assert!(&plan.is_ok(), "query: {query}\nerror: {plan:?}");
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_lateral_schema(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(
+            "select * from tbl2, lateral (select max(id) as m from tbl3 where tbl3.id = tbl2.id) as s",
+        )?;
+        assert_eq!(plan.schema().names(), vec!["text", "id", "val", "m"]);
+
+        let err = planner.plan_sql(
+            "select * from tbl2 join lateral (select first_name from tbl3 where id = tbl2.id) s on s.first_name = tbl2.text",
+        );
+        assert!(err.is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_lateral_aggregate_keeps_every_row(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(
+            "select * from tbl2, lateral (select count(*) as c from tbl3 where tbl3.id = tbl2.id) as s",
+        )?;
+        assert_eq!(plan.schema().names(), vec!["text", "id", "val", "c"]);
+        fn has_left_join(plan: &LogicalPlan) -> bool {
+            matches!(plan, LogicalPlan::Join(join) if join.join_type == JoinType::Left)
+                || plan.children().into_iter().any(has_left_join)
+        }
+        assert!(has_left_join(&plan));
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_lateral_rejects_non_equality_correlation(mut planner: SQLPlanner) {
+        let plan = planner.plan_sql(
+            "select * from tbl2, lateral (select id from tbl3 where tbl3.id < tbl2.id) as s",
+        );
+        assert!(plan.is_err(), "plan: {plan:?}");
+    }
+
+    #[rstest]
+    #[case::ctas("create table t as select * from tbl1")]
+    #[case::ctas_or_replace("create or replace table tbl1 as select * from tbl2")]
+    #[case::ctas_if_not_exists("create table if not exists tbl1 as select * from tbl2")]
+    #[case::ctas_parquet(
+        "create table t with (format = 'parquet', location = '/tmp/t') as select * from tbl1"
+    )]
+    #[case::ctas_csv(
+        "create table t with (format = 'csv', location = '/tmp/t') as select * from tbl1"
+    )]
+    #[case::ctas_partitioned(
+        "create table t with (location = '/tmp/t') partition by (utf8, i32) as select * from tbl1"
+    )]
+    #[case::insert("insert into tbl2 select * from tbl2")]
+    #[case::insert_columns("insert into tbl3 (id, first_name) select id, text from tbl2")]
+    fn test_compiles_statements(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+    ) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(query);
+        assert!(&plan.is_ok(), "query: {query}\nerror: {plan:?}");
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::ctas_exists("create table tbl1 as select * from tbl2")]
+    #[case::ctas_iceberg(
+        "create table t with (format = 'iceberg', location = '/tmp/t') as select * from tbl1"
+    )]
+    #[case::ctas_partition_no_location("create table t partition by (utf8) as select * from tbl1")]
+    #[case::insert_missing_table("insert into missing select * from tbl2")]
+    #[case::insert_column_count("insert into tbl2 select text from tbl2")]
+    #[case::insert_overwrite("insert overwrite table tbl2 select * from tbl2")]
+    fn test_statements_errors(mut planner: SQLPlanner, #[case] query: &str) {
+        let plan = planner.plan_sql(query);
+        assert!(plan.is_err(), "query: {query}\nplan: {plan:?}");
+    }
+
+    #[rstest]
+    fn test_create_temp_view(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql("create temp view v (a, b) as select id, val from tbl2")?;
+        assert_eq!(plan.schema().names(), vec!["a", "b"]);
+        assert!(planner.catalog().is_view("v"));
+
+        planner.plan_sql("select a from v where b > 1")?;
+        assert!(planner
+            .plan_sql("create temp view v as select * from tbl3")
+            .is_err());
+        planner.plan_sql("create or replace temp view v as select * from tbl3")?;
+        assert_eq!(
+            planner.catalog().get_schema("v").unwrap().names(),
+            vec!["first_name", "last_name", "id"]
+        );
+        assert!(planner
+            .plan_sql("create view p as select * from tbl3")
+            .is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_information_schema(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(
+            "select table_name from information_schema.tables where table_type = 'BASE TABLE'",
+        )?;
+        assert_eq!(plan.schema().names(), vec!["table_name"]);
+
+        let plan = planner.plan_sql(
+            "select column_name, ordinal_position, data_type from information_schema.columns",
+        )?;
+        assert_eq!(
+            plan.schema().names(),
+            vec!["column_name", "ordinal_position", "data_type"]
+        );
+        assert!(!planner.catalog().has_table("information_schema.tables"));
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_resolves_unregistered_tables() -> SQLPlannerResult<()> {
+        let mut catalog = SQLCatalog::new();
+        catalog.register_table("tbl2", tbl_2());
+        catalog.set_resolver(catalog::TableResolver::new(|catalog, name| {
+            Ok((name == "resolved").then(|| catalog.get_table("tbl2").unwrap().unwrap()))
+        }));
+        let mut planner = SQLPlanner::new(catalog);
+
+        let plan = planner.plan_sql("select id from resolved")?;
+        assert_eq!(plan.schema().names(), vec!["id"]);
+        assert!(!planner.catalog().has_table("resolved"));
+        assert!(planner.plan_sql("select * from missing").is_err());
+        Ok(())
+    }
+
+    struct MockDataCatalog;
+
+    struct MockDataCatalogTable;
+
+    impl daft_catalog::DataCatalog for MockDataCatalog {
+        fn list_tables(&self, _prefix: &str) -> daft_catalog::errors::Result<Vec<String>> {
+            Ok(vec!["ns.tbl".to_string()])
+        }
+
+        fn get_table(
+            &self,
+            name: &str,
+        ) -> daft_catalog::errors::Result<Option<Box<dyn daft_catalog::DataCatalogTable>>> {
+            Ok((name == "ns.tbl")
+                .then(|| Box::new(MockDataCatalogTable) as Box<dyn daft_catalog::DataCatalogTable>))
+        }
+    }
+
+    impl daft_catalog::DataCatalogTable for MockDataCatalogTable {
+        fn to_logical_plan_builder(&self) -> daft_catalog::errors::Result<LogicalPlanBuilder> {
+            Ok(tbl_2().into())
+        }
+    }
+
+    #[rstest]
+    fn test_resolves_data_catalog_tables(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        daft_catalog::global_catalog::register_catalog(
+            Arc::new(MockDataCatalog),
+            Some("sql_test_lake"),
+        );
+
+        let plan = planner.plan_sql("select id from sql_test_lake.ns.tbl")?;
+        assert_eq!(plan.schema().names(), vec!["id"]);
+        assert!(planner
+            .plan_sql("select * from sql_test_lake.ns.missing")
+            .is_err());
+
+        daft_catalog::global_catalog::unregister_catalog(Some("sql_test_lake"));
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_ctas_registers_table(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql("create table t as select id, val from tbl2")?;
+        assert_eq!(plan.schema().names(), vec!["num_affected_rows"]);
+
+        let schema = planner
+            .catalog()
+            .get_schema("t")
+            .expect("table t should be registered");
+        assert_eq!(schema.names(), vec!["id", "val"]);
+
+        planner.plan_sql("select val from t where id > 1")?;
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_insert_appends_to_table(
+        mut planner: SQLPlanner,
+        tbl_3: LogicalPlanRef,
+    ) -> SQLPlannerResult<()> {
+        let plan =
+            planner.plan_sql("insert into tbl3 (id, first_name) select id, text from tbl2")?;
+        assert_eq!(plan.schema().names(), vec!["num_affected_rows"]);
+
+        let table = planner.catalog().get_table("tbl3")?.unwrap();
+        assert!(matches!(table.as_ref(), LogicalPlan::Concat(_)));
+        assert_eq!(table.schema(), tbl_3.schema());
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_insert_into_ctas_location(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(
+            "create table t with (format = 'parquet', location = '/tmp/t') as select * from tbl2",
+        )?;
+        assert_eq!(plan.schema().names(), vec!["num_affected_rows"]);
+        assert!(planner.catalog().get_output("t").is_some());
+
+        let plan = planner.plan_sql("insert into t select * from tbl2")?;
+        assert_eq!(plan.schema().names(), vec!["num_affected_rows"]);
+        let LogicalPlan::Aggregate(aggregate) = plan.as_ref() else {
+            panic!("expected an aggregate, found {}", plan.name());
+        };
+        let LogicalPlan::Sink(sink) = aggregate.input.as_ref() else {
+            panic!("expected a sink, found {}", aggregate.input.name());
+        };
+        assert!(matches!(
+            sink.sink_info.as_ref(),
+            SinkInfo::CopyInto(CopyIntoInfo {
+                mode: WriteMode::Append,
+                ..
+            })
+        ));
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_ctas_location_is_pending_until_written(
+        mut planner: SQLPlanner,
+    ) -> SQLPlannerResult<()> {
+        planner.plan_sql(
+            "create table t with (location = '/tmp/daft-sql-missing-location') as select * from tbl2",
+        )?;
+        let changes = planner.catalog_mut().take_changes();
+        assert!(changes.has_pending_writes());
+        assert!(changes.has_table("t"));
+
+        // Nothing was written to the location, but it's only listed once the scan is executed.
+        let table = changes.get_table("t")?.unwrap();
+        assert!(matches!(table.as_ref(), LogicalPlan::Source(_)));
+        assert_eq!(table.schema(), tbl_2().schema());
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_ctas_table_format(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(
+            "create or replace table t with (format = 'delta', location = '/tmp/t') partition by (val) as select id, val from tbl2",
+        )?;
+        assert_eq!(plan.schema().names(), vec!["id", "val"]);
+        assert!(!planner.catalog().has_table("t"));
+
+        let changes = planner.catalog_mut().take_changes();
+        let write = changes.table_format_write().unwrap();
+        assert_eq!(write.name, "t");
+        assert_eq!(write.format, catalog::TableFormat::DeltaLake);
+        assert_eq!(write.location.as_deref(), Some("/tmp/t"));
+        assert_eq!(write.partition_cols, vec!["val"]);
+        assert!(write.overwrite);
+
+        assert!(planner
+            .plan_sql("create table t with (format = 'delta') as select * from tbl2")
+            .is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_copy_into_produces_manifest(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let plan = planner.plan_sql(
+            "copy into '/tmp/export' from (select id, val from tbl2) file_format = csv mode = error_if_exists partition by (val)",
+        )?;
+        let LogicalPlan::Sink(sink) = plan.as_ref() else {
+            panic!("expected a sink, found {}", plan.name());
+        };
+        assert!(matches!(
+            sink.sink_info.as_ref(),
+            SinkInfo::CopyInto(CopyIntoInfo {
+                mode: WriteMode::ErrorIfExists,
+                ..
+            })
+        ));
+        assert_eq!(
+            plan.schema().names(),
+            vec!["path", "val", "num_rows", "size_bytes"]
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_copy_to_is_copy_into(mut planner: SQLPlanner) -> SQLPlannerResult<()> {
+        let plan =
+            planner.plan_sql("copy (select * from tbl2) to '/tmp/export' with (format parquet)")?;
+        assert_eq!(
+            plan.schema().names(),
+            vec!["path", "num_rows", "size_bytes"]
+        );
+        let plan = planner
+            .plan_sql("copy into '/tmp/export' from (select * from tbl2) mode = overwrite")?;
+        let LogicalPlan::Sink(sink) = plan.as_ref() else {
+            panic!("expected a sink, found {}", plan.name());
+        };
+        assert!(matches!(
+            sink.sink_info.as_ref(),
+            SinkInfo::CopyInto(CopyIntoInfo {
+                mode: WriteMode::Overwrite,
+                ..
+            })
+        ));
+        assert!(planner
+            .plan_sql("copy into '/tmp/export' from (select * from tbl2) mode = replace")
+            .is_err());
+        Ok(())
+    }
 }
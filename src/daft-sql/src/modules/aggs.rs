@@ -134,6 +134,9 @@ fn to_expr(expr: &AggExpr, args: &[ExprRef]) -> SQLPlannerResult<ExprRef> {
         AggExpr::AnyValue(_, _) => unsupported_sql_err!("any_value"),
         AggExpr::List(_) => unsupported_sql_err!("list"),
         AggExpr::Concat(_) => unsupported_sql_err!("concat"),
+        AggExpr::TopK(_, _) => unsupported_sql_err!("top_k"),
+        AggExpr::ApproxHeavyHitters(_, _) => unsupported_sql_err!("approx_heavy_hitters"),
+        AggExpr::MergeHeavyHitters(_, _) => unsupported_sql_err!("merge_heavy_hitters"),
         AggExpr::MapGroups { .. } => unsupported_sql_err!("map_groups"),
     }
 }
@@ -5,6 +5,7 @@ pub mod decode;
 pub mod encode;
 pub mod resize;
 pub mod to_mode;
+pub mod to_tensor;
 
 pub struct SQLModuleImage;
 
@@ -15,5 +16,6 @@ impl SQLModule for SQLModuleImage {
         parent.add_fn("image_encode", encode::SQLImageEncode {});
         parent.add_fn("image_resize", resize::SQLImageResize {});
         parent.add_fn("image_to_mode", to_mode::SQLImageToMode {});
+        parent.add_fn("image_to_tensor", to_tensor::SQLImageToTensor {});
     }
 }
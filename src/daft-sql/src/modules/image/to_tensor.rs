@@ -0,0 +1,31 @@
+use daft_dsl::ExprRef;
+use daft_functions::image::to_tensor::image_to_tensor;
+use sqlparser::ast::FunctionArg;
+
+use crate::{error::SQLPlannerResult, functions::SQLFunction, unsupported_sql_err};
+
+pub struct SQLImageToTensor;
+
+impl SQLFunction for SQLImageToTensor {
+    fn to_expr(
+        &self,
+        inputs: &[FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input] => {
+                let input = planner.plan_function_arg(input)?;
+                Ok(image_to_tensor(input))
+            }
+            _ => unsupported_sql_err!("Invalid arguments for image_to_tensor: '{inputs:?}'"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Converts an image to a tensor of shape (height, width, channels).".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input_image"]
+    }
+}
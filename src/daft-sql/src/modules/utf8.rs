@@ -2,7 +2,10 @@ use daft_core::array::ops::Utf8NormalizeOptions;
 use daft_dsl::{binary_op, ExprRef, LiteralValue, Operator};
 use daft_functions::{
     count_matches::{utf8_count_matches, CountMatchesFunction},
-    tokenize::{tokenize_decode, tokenize_encode, TokenizeDecodeFunction, TokenizeEncodeFunction},
+    tokenize::{
+        detokenize, tokenize, tokenize_decode, tokenize_encode, TokenizeDecodeFunction,
+        TokenizeEncodeFunction,
+    },
 };
 
 use super::SQLModule;
@@ -179,8 +182,11 @@ impl SQLModule for SQLModuleUtf8 {
         parent.add_fn("to_datetime", SQLUtf8ToDatetime);
         parent.add_fn("count_matches", SQLCountMatches);
         parent.add_fn("normalize", SQLNormalize);
+        parent.add_fn("collation_key", SQLCollationKey);
         parent.add_fn("tokenize_encode", SQLTokenizeEncode);
         parent.add_fn("tokenize_decode", SQLTokenizeDecode);
+        parent.add_fn("tokenize", SQLTokenize);
+        parent.add_fn("detokenize", SQLDetokenize);
         parent.add_fn("concat", SQLConcat);
     }
 }
@@ -633,6 +639,43 @@ impl SQLFunction for SQLNormalize {
     }
 }
 
+pub struct SQLCollationKey;
+
+impl SQLFunction for SQLCollationKey {
+    fn to_expr(
+        &self,
+        inputs: &[sqlparser::ast::FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, collation] => {
+                let input = planner.plan_function_arg(input)?;
+                let collation = planner.plan_function_arg(collation)?;
+                let collation = collation
+                    .as_literal()
+                    .and_then(|lit| lit.as_str())
+                    .ok_or_else(|| {
+                        PlannerError::invalid_operation("collation_key collation must be a string")
+                    })?;
+                Ok(daft_functions::utf8::collation_key(
+                    input,
+                    collation.parse()?,
+                ))
+            }
+            _ => invalid_operation_err!("collation_key takes exactly two arguments"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Returns binary keys that sort and compare the way the strings do under a collation."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["string_input", "collation"]
+    }
+}
+
 pub struct SQLTokenizeEncode;
 impl TryFrom<SQLFunctionArguments> for TokenizeEncodeFunction {
     type Error = PlannerError;
@@ -800,6 +843,93 @@ impl SQLFunction for SQLTokenizeDecode {
     }
 }
 
+/// Plans the arguments of `tokenize` and `detokenize`: the input, the path of the tokenizer and
+/// whether to add (or skip) special tokens, which defaults to true.
+fn plan_hf_tokenizer_args(
+    sql_name: &str,
+    inputs: &[sqlparser::ast::FunctionArg],
+    planner: &crate::planner::SQLPlanner,
+) -> SQLPlannerResult<(ExprRef, String, bool)> {
+    let (input, tokenizer_path, special_tokens) = match inputs {
+        [input, tokenizer_path] => (input, tokenizer_path, None),
+        [input, tokenizer_path, special_tokens] => (input, tokenizer_path, Some(special_tokens)),
+        _ => invalid_operation_err!("{sql_name} takes two or three arguments"),
+    };
+    let input = planner.plan_function_arg(input)?;
+    let tokenizer_path = planner.plan_function_arg(tokenizer_path)?;
+    let tokenizer_path = tokenizer_path
+        .as_literal()
+        .and_then(|lit| lit.as_str())
+        .ok_or_else(|| {
+            PlannerError::invalid_operation(format!(
+                "{sql_name} tokenizer_path argument must be a string"
+            ))
+        })?
+        .to_string();
+    let special_tokens = match special_tokens {
+        Some(special_tokens) => planner
+            .plan_function_arg(special_tokens)?
+            .as_literal()
+            .and_then(LiteralValue::as_bool)
+            .ok_or_else(|| {
+                PlannerError::invalid_operation(format!(
+                    "{sql_name} special tokens argument must be a boolean"
+                ))
+            })?,
+        None => true,
+    };
+    Ok((input, tokenizer_path, special_tokens))
+}
+
+pub struct SQLTokenize;
+
+impl SQLFunction for SQLTokenize {
+    fn to_expr(
+        &self,
+        inputs: &[sqlparser::ast::FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        let (input, tokenizer_path, add_special_tokens) =
+            plan_hf_tokenizer_args("tokenize", inputs, planner)?;
+        Ok(tokenize(input, &tokenizer_path, None, add_special_tokens))
+    }
+
+    fn docstrings(&self, _: &str) -> String {
+        "Encodes each string as a list of token ids using a Hugging Face tokenizer.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "tokenizer_path", "add_special_tokens"]
+    }
+}
+
+pub struct SQLDetokenize;
+
+impl SQLFunction for SQLDetokenize {
+    fn to_expr(
+        &self,
+        inputs: &[sqlparser::ast::FunctionArg],
+        planner: &crate::planner::SQLPlanner,
+    ) -> SQLPlannerResult<ExprRef> {
+        let (input, tokenizer_path, skip_special_tokens) =
+            plan_hf_tokenizer_args("detokenize", inputs, planner)?;
+        Ok(detokenize(
+            input,
+            &tokenizer_path,
+            None,
+            skip_special_tokens,
+        ))
+    }
+
+    fn docstrings(&self, _: &str) -> String {
+        "Decodes each list of token ids into a string using a Hugging Face tokenizer.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "tokenizer_path", "skip_special_tokens"]
+    }
+}
+
 pub struct SQLConcat;
 
 impl SQLFunction for SQLConcat {
@@ -7,7 +7,7 @@ use std::{
 
 use common_error::{DaftError, DaftResult};
 use daft_algebra::boolean::combine_conjunction;
-use daft_core::prelude::*;
+use daft_core::{array::ops::Collation, prelude::*};
 use daft_dsl::{
     col,
     common_treenode::{Transformed, TreeNode},
@@ -22,20 +22,31 @@ use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
 use sqlparser::{
     ast::{
         ArrayElemTypeDef, BinaryOperator, CastKind, ColumnDef, DateTimeField, Distinct,
-        ExactNumberInfo, ExcludeSelectItem, GroupByExpr, Ident, Query, SelectItem, SetExpr,
-        Statement, StructField, Subscript, TableAlias, TableWithJoins, TimezoneInfo, UnaryOperator,
-        Value, WildcardAdditionalOptions, With,
+        ExactNumberInfo, ExcludeSelectItem, GroupByExpr, Ident, ObjectName, Query, SelectItem,
+        SetExpr, Statement, StructField, Subscript, TableAlias, TableWithJoins, TimezoneInfo,
+        UnaryOperator, Value, WildcardAdditionalOptions, With,
     },
     dialect::GenericDialect,
+    keywords::Keyword,
     parser::{Parser, ParserOptions},
     tokenizer::Tokenizer,
 };
 
 use crate::{
     catalog::SQLCatalog, column_not_found_err, error::*, invalid_operation_err,
-    table_not_found_err, unsupported_sql_err,
+    statements::CopyInto, table_not_found_err, unsupported_sql_err,
 };
 
+fn parse_collation(collation: &ObjectName) -> SQLPlannerResult<Collation> {
+    let collation = collation
+        .0
+        .iter()
+        .map(|ident| ident.value.as_str())
+        .collect::<Vec<_>>()
+        .join(".");
+    Ok(collation.parse()?)
+}
+
 /// A named logical plan
 /// This is used to keep track of the table name associated with a logical plan while planning a SQL query
 #[derive(Debug, Clone)]
@@ -118,7 +129,7 @@ impl<'a> SQLPlanner<'a> {
         }
     }
 
-    fn new_with_context(&'a self) -> Self {
+    pub(crate) fn new_with_context(&'a self) -> Self {
         Self {
             context: self.context.clone(),
             ..Default::default()
@@ -144,10 +155,15 @@ impl<'a> SQLPlanner<'a> {
         Ref::map(self.context.borrow(), |i| &i.cte_map)
     }
 
-    fn catalog(&self) -> Ref<'_, SQLCatalog> {
+    /// The catalog that statements are planned against, including the tables they registered
+    pub fn catalog(&self) -> Ref<'_, SQLCatalog> {
         Ref::map(self.context.borrow(), |i| &i.catalog)
     }
 
+    pub(crate) fn catalog_mut(&self) -> RefMut<'_, SQLCatalog> {
+        RefMut::map(self.context_mut(), |i| &mut i.catalog)
+    }
+
     /// Clears the current context used for planning a SQL query
     fn clear_context(&mut self) {
         self.current_relation = None;
@@ -212,12 +228,16 @@ impl<'a> SQLPlanner<'a> {
             })
             .with_tokens(tokens);
 
-        let statements = parser.parse_statements()?;
+        let plan = if parser.parse_keywords(&[Keyword::COPY, Keyword::INTO]) {
+            CopyInto::parse(&mut parser).and_then(|copy_into| self.plan_copy_into(&copy_into))
+        } else {
+            let statements = parser.parse_statements()?;
 
-        let plan = match statements.len() {
-            1 => Ok(self.plan_statement(&statements[0])?),
-            other => {
-                unsupported_sql_err!("Only exactly one SQL statement allowed, found {}", other)
+            match statements.len() {
+                1 => Ok(self.plan_statement(&statements[0])?),
+                other => {
+                    unsupported_sql_err!("Only exactly one SQL statement allowed, found {}", other)
+                }
             }
         };
         self.clear_context();
@@ -227,11 +247,44 @@ impl<'a> SQLPlanner<'a> {
     fn plan_statement(&mut self, statement: &Statement) -> SQLPlannerResult<LogicalPlanRef> {
         match statement {
             Statement::Query(query) => Ok(self.plan_query(query)?.build()),
+            Statement::CreateTable(create) => self.plan_create_table(create),
+            Statement::Insert(insert) => self.plan_insert(insert),
+            Statement::Copy {
+                source,
+                to,
+                target,
+                options,
+                legacy_options,
+                ..
+            } => {
+                if !legacy_options.is_empty() {
+                    unsupported_sql_err!("Legacy COPY options are not supported");
+                }
+                self.plan_copy(source, *to, target, options)
+            }
+            Statement::CreateView {
+                name,
+                columns,
+                query,
+                or_replace,
+                if_not_exists,
+                temporary,
+                materialized,
+                ..
+            } => self.plan_create_view(
+                name,
+                columns,
+                query,
+                *or_replace,
+                *if_not_exists,
+                *temporary,
+                *materialized,
+            ),
             other => unsupported_sql_err!("{}", other),
         }
     }
 
-    fn plan_query(&mut self, query: &Query) -> SQLPlannerResult<LogicalPlanBuilder> {
+    pub(crate) fn plan_query(&mut self, query: &Query) -> SQLPlannerResult<LogicalPlanBuilder> {
         check_query_features(query)?;
 
         let selection = match query.body.as_ref() {
@@ -748,13 +801,32 @@ impl<'a> SQLPlanner<'a> {
             if order_by_expr.with_fill.is_some() {
                 unsupported_sql_err!("WITH FILL");
             }
-            let expr = self.plan_expr(&order_by_expr.expr)?;
+            let expr = match self.plan_collated_expr(&order_by_expr.expr)? {
+                (expr, Some(collation)) => daft_functions::utf8::collation_key(expr, collation),
+                (expr, None) => expr,
+            };
 
             exprs.push(expr);
         }
         Ok((exprs, desc, nulls_first))
     }
 
+    /// Plans an expression that is compared or sorted by, along with its collation if it is
+    /// collated, e.g. `name COLLATE case_insensitive`.
+    fn plan_collated_expr(
+        &self,
+        expr: &sqlparser::ast::Expr,
+    ) -> SQLPlannerResult<(ExprRef, Option<Collation>)> {
+        match expr {
+            sqlparser::ast::Expr::Collate { expr, collation } => {
+                let collation = parse_collation(collation)?;
+                Ok((self.plan_expr(expr)?, Some(collation)))
+            }
+            sqlparser::ast::Expr::Nested(expr) => self.plan_collated_expr(expr),
+            _ => Ok((self.plan_expr(expr)?, None)),
+        }
+    }
+
     /// Plans the FROM clause of a query and populates self.current_relation and self.table_map
     /// Should only be called once per query.
     fn plan_from(&mut self, from: &[TableWithJoins]) -> SQLPlannerResult<()> {
@@ -762,18 +834,26 @@ impl<'a> SQLPlanner<'a> {
             let mut from_iter = from.iter();
 
             let first = from_iter.next().unwrap();
-            let mut rel = self.plan_relation(&first.relation)?;
+            let rel = self.plan_relation(&first.relation)?;
             self.table_map.insert(rel.get_name(), rel.clone());
+            self.current_relation = Some(rel);
             for tbl in from_iter {
                 let right = self.plan_relation(&tbl.relation)?;
                 self.table_map.insert(right.get_name(), right.clone());
                 let right_join_prefix = Some(format!("{}.", right.get_name()));
 
-                rel.inner =
+                let rel = self.relation_mut();
+                rel.inner = if is_lateral(&tbl.relation) {
+                    rel.inner.lateral_join(
+                        &right.inner,
+                        JoinType::Inner,
+                        right_join_prefix.as_deref(),
+                    )?
+                } else {
                     rel.inner
-                        .cross_join(right.inner, None, right_join_prefix.as_deref())?;
+                        .cross_join(right.inner, None, right_join_prefix.as_deref())?
+                };
             }
-            self.current_relation = Some(rel);
             return Ok(());
         }
 
@@ -905,6 +985,28 @@ impl<'a> SQLPlanner<'a> {
             let right_rel_name = right_rel.get_name();
             let right_join_prefix = Some(format!("{right_rel_name}."));
 
+            if is_lateral(&join.relation) {
+                use sqlparser::ast::JoinOperator::CrossJoin;
+
+                let join_type = match &join.join_operator {
+                    Inner(constraint) if is_trivial_constraint(constraint) => JoinType::Inner,
+                    LeftOuter(constraint) if is_trivial_constraint(constraint) => JoinType::Left,
+                    CrossJoin => JoinType::Inner,
+                    _ => unsupported_sql_err!(
+                        "LATERAL is only supported with CROSS JOIN, JOIN ... ON TRUE or LEFT JOIN ... ON TRUE"
+                    ),
+                };
+
+                let rel = self.relation_mut();
+                rel.inner = rel.inner.lateral_join(
+                    &right_rel.inner,
+                    join_type,
+                    right_join_prefix.as_deref(),
+                )?;
+                self.table_map.insert(right_rel_name, right_rel);
+                continue;
+            }
+
             // construct a planner with the right table to use for expr planning
             let mut right_planner = self.new_with_context();
             right_planner.current_relation = Some(right_rel.clone());
@@ -1003,17 +1105,19 @@ impl<'a> SQLPlanner<'a> {
                 ..
             } => {
                 let table_name = name.to_string();
-                let Some(rel) = self
+                let rel = match self
                     .table_map
                     .get(&table_name)
                     .cloned()
                     .or_else(|| self.cte_map().get(&table_name).cloned())
-                    .or_else(|| {
-                        self.catalog()
-                            .get_table(&table_name)
-                            .map(|table| Relation::new(table.into(), table_name.clone()))
-                    })
-                else {
+                {
+                    Some(rel) => Some(rel),
+                    None => self
+                        .catalog()
+                        .get_table(&table_name)?
+                        .map(|table| Relation::new(table.into(), table_name.clone())),
+                };
+                let Some(rel) = rel else {
                     table_not_found_err!(table_name)
                 };
 
@@ -1024,10 +1128,13 @@ impl<'a> SQLPlanner<'a> {
                 subquery,
                 alias: Some(alias),
             } => {
-                if *lateral {
-                    unsupported_sql_err!("LATERAL");
-                }
-                let subquery = self.new_with_context().plan_query(subquery)?;
+                // a lateral subquery can reference columns of the relations before it,
+                // which are resolved as outer reference columns and decorrelated when joining
+                let subquery = if *lateral {
+                    self.new_child().plan_query(subquery)?
+                } else {
+                    self.new_with_context().plan_query(subquery)?
+                };
                 let rel_name = ident_to_str(&alias.name);
                 let rel = Relation::new(subquery, rel_name);
 
@@ -1312,9 +1419,37 @@ impl<'a> SQLPlanner<'a> {
             SQLExpr::Identifier(ident) => self.plan_identifier(std::slice::from_ref(ident)),
             SQLExpr::Value(v) => self.value_to_lit(v).map(Expr::Literal).map(Arc::new),
             SQLExpr::BinaryOp { left, op, right } => {
-                let left = self.plan_expr(left)?;
-                let right = self.plan_expr(right)?;
                 let op = self.sql_operator_to_operator(op)?;
+                if !matches!(
+                    op,
+                    Operator::Eq
+                        | Operator::NotEq
+                        | Operator::Lt
+                        | Operator::LtEq
+                        | Operator::Gt
+                        | Operator::GtEq
+                ) {
+                    let left = self.plan_expr(left)?;
+                    let right = self.plan_expr(right)?;
+                    return Ok(Expr::BinaryOp { left, op, right }.arced());
+                }
+                // Both sides of a comparison are compared by the collation of either side.
+                let (left, left_collation) = self.plan_collated_expr(left)?;
+                let (right, right_collation) = self.plan_collated_expr(right)?;
+                let (left, right) = match (left_collation, right_collation) {
+                    (Some(left_collation), Some(right_collation))
+                        if left_collation != right_collation =>
+                    {
+                        invalid_operation_err!(
+                            "Cannot compare strings of collation {left_collation} to strings of collation {right_collation}"
+                        )
+                    }
+                    (Some(collation), _) | (None, Some(collation)) => (
+                        daft_functions::utf8::collation_key(left, collation.clone()),
+                        daft_functions::utf8::collation_key(right, collation),
+                    ),
+                    (None, None) => (left, right),
+                };
                 Ok(Expr::BinaryOp { left, op, right }.arced())
             }
             SQLExpr::Cast {
@@ -1490,7 +1625,12 @@ impl<'a> SQLPlanner<'a> {
             }
             SQLExpr::Trim { .. } => unsupported_sql_err!("TRIM"),
             SQLExpr::Overlay { .. } => unsupported_sql_err!("OVERLAY"),
-            SQLExpr::Collate { .. } => unsupported_sql_err!("COLLATE"),
+            // A collation only changes how strings compare, so it only applies to comparisons and
+            // sort keys, which are planned by `plan_collated_expr`.
+            SQLExpr::Collate { expr, collation } => {
+                parse_collation(collation)?;
+                self.plan_expr(expr)
+            }
             SQLExpr::Nested(e) => self.plan_expr(e),
             SQLExpr::IntroducedString { .. } => unsupported_sql_err!("INTRODUCED STRING"),
             SQLExpr::TypedString { data_type, value } => match data_type {
@@ -2208,6 +2348,24 @@ fn idents_to_str(idents: &[Ident]) -> String {
         .join(".")
 }
 
+fn is_lateral(rel: &sqlparser::ast::TableFactor) -> bool {
+    matches!(
+        rel,
+        sqlparser::ast::TableFactor::Derived { lateral: true, .. }
+    )
+}
+
+/// Whether a join constraint does not restrict the join, i.e. it is absent or `ON TRUE`
+fn is_trivial_constraint(constraint: &sqlparser::ast::JoinConstraint) -> bool {
+    use sqlparser::ast::JoinConstraint;
+
+    matches!(
+        constraint,
+        JoinConstraint::None
+            | JoinConstraint::On(sqlparser::ast::Expr::Value(Value::Boolean(true)))
+    )
+}
+
 /// unresolves an alias in a projection
 /// Example:
 /// ```sql
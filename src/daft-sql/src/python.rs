@@ -1,4 +1,5 @@
 use common_daft_config::PyDaftPlanningConfig;
+use daft_core::python::PySchema;
 use daft_dsl::python::PyExpr;
 use daft_logical_plan::{LogicalPlanBuilder, PyLogicalPlanBuilder};
 use pyo3::prelude::*;
@@ -30,15 +31,24 @@ impl SQLFunctionStub {
     }
 }
 
+/// Plans a SQL statement against the catalog, returning the plan and a catalog of the tables that
+/// the statement created or changed, e.g. with `CREATE TABLE` or `INSERT INTO`, which the caller
+/// registers wherever they should persist.
 #[pyfunction]
 pub fn sql(
     sql: &str,
-    catalog: PyCatalog,
+    catalog: &PyCatalog,
     daft_planning_config: PyDaftPlanningConfig,
-) -> PyResult<PyLogicalPlanBuilder> {
-    let mut planner = SQLPlanner::new(catalog.catalog);
+) -> PyResult<(PyLogicalPlanBuilder, PyCatalog)> {
+    let mut catalog = catalog.catalog.clone();
+    catalog.take_changes();
+    let mut planner = SQLPlanner::new(catalog);
     let plan = planner.plan_sql(sql)?;
-    Ok(LogicalPlanBuilder::new(plan, Some(daft_planning_config.config)).into())
+    let changes = planner.catalog_mut().take_changes();
+    Ok((
+        LogicalPlanBuilder::new(plan, Some(daft_planning_config.config)).into(),
+        PyCatalog { catalog: changes },
+    ))
 }
 
 #[pyfunction]
@@ -92,12 +102,72 @@ impl PyCatalog {
         self.catalog.copy_from(&other.catalog);
     }
 
+    /// Copy only the views from another catalog, using views from other in case of conflict
+    pub fn copy_views_from(&mut self, other: &Self) {
+        self.catalog.copy_views_from(&other.catalog);
+    }
+
+    /// List the names of all tables in the catalog.
+    pub fn list_tables(&self) -> Vec<String> {
+        self.catalog.table_names()
+    }
+
+    /// Get the schema of a table in the catalog.
+    pub fn get_schema(&self, name: &str) -> Option<PySchema> {
+        self.catalog.get_schema(name).map(Into::into)
+    }
+
+    /// Whether the table was registered as a view.
+    pub fn is_view(&self, name: &str) -> bool {
+        self.catalog.is_view(name)
+    }
+
+    /// Whether the tables may only be registered once the planned statement was executed, because
+    /// it writes them.
+    pub fn has_pending_writes(&self) -> bool {
+        self.catalog.has_pending_writes()
+    }
+
+    /// The Iceberg or Delta Lake table that the planned statement creates, as a tuple of its
+    /// name, format, catalog, location, partition columns and whether it replaces an existing
+    /// table. The planned statement produces the rows to write to the table.
+    #[allow(clippy::type_complexity)]
+    pub fn table_format_write(
+        &self,
+    ) -> Option<(
+        String,
+        &'static str,
+        Option<String>,
+        Option<String>,
+        Vec<String>,
+        bool,
+    )> {
+        self.catalog.table_format_write().map(|write| {
+            (
+                write.name.clone(),
+                write.format.as_str(),
+                write.catalog.clone(),
+                write.location.clone(),
+                write.partition_cols.clone(),
+                write.overwrite,
+            )
+        })
+    }
+
     /// __str__ to print the catalog's tables
     fn __str__(&self) -> String {
         format!("{:?}", self.catalog)
     }
 }
 
+impl PyCatalog {
+    /// The catalog of tables that SQL queries are planned against
+    #[must_use]
+    pub fn catalog(&self) -> &SQLCatalog {
+        &self.catalog
+    }
+}
+
 impl Default for PyCatalog {
     fn default() -> Self {
         Self::new()
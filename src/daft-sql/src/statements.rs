@@ -0,0 +1,509 @@
+use common_file_formats::FileFormat;
+use daft_core::{
+    count_mode::CountMode,
+    prelude::{DataType, SchemaRef},
+};
+use daft_dsl::{col, lit, null_lit, ExprRef};
+use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef, OutputFileInfo, WriteMode};
+use sqlparser::{
+    ast::{
+        CopyOption, CopySource, CopyTarget, CreateTable, Expr as SQLExpr, Insert, ObjectName,
+        Query, SqlOption, Value, ViewColumnDef,
+    },
+    keywords::Keyword,
+    parser::Parser,
+    tokenizer::Token,
+};
+
+use crate::{
+    catalog::{TableFormat, TableFormatWrite},
+    column_not_found_err,
+    error::SQLPlannerResult,
+    invalid_operation_err,
+    planner::SQLPlanner,
+    table_not_found_err, unsupported_sql_err,
+};
+
+/// Name of the single column returned by statements that only report how many rows they touched.
+pub(crate) const NUM_AFFECTED_ROWS: &str = "num_affected_rows";
+
+/// Options that can be passed to `CREATE TABLE ... WITH (...) AS SELECT ...`
+#[derive(Default)]
+struct CreateTableOptions {
+    format: Option<String>,
+    location: Option<String>,
+    compression: Option<String>,
+    catalog: Option<String>,
+}
+
+impl CreateTableOptions {
+    fn try_new(create: &CreateTable) -> SQLPlannerResult<Self> {
+        let mut opts = Self {
+            location: create
+                .location
+                .clone()
+                .or_else(|| create.hive_formats.as_ref()?.location.clone()),
+            ..Default::default()
+        };
+
+        for opt in &create.with_options {
+            let SqlOption::KeyValue { key, value } = opt else {
+                unsupported_sql_err!("Unsupported table option: {opt}");
+            };
+            let value = option_value_to_str(value)?;
+            match key.value.to_lowercase().as_str() {
+                "format" => opts.format = Some(value),
+                "location" | "path" => opts.location = Some(value),
+                "compression" => opts.compression = Some(value),
+                "catalog" => opts.catalog = Some(value),
+                other => unsupported_sql_err!("Unsupported table option: {other}"),
+            }
+        }
+        Ok(opts)
+    }
+}
+
+/// A `COPY INTO '<location>' FROM (<query>) [<option> = <value> ...] [PARTITION BY (...)]`
+/// statement, which sqlparser does not support.
+pub(crate) struct CopyInto {
+    location: String,
+    query: Box<Query>,
+    format: Option<String>,
+    compression: Option<String>,
+    mode: WriteMode,
+    partition_by: Vec<SQLExpr>,
+}
+
+impl CopyInto {
+    /// Parses the statement, after its leading `COPY INTO` keywords were consumed
+    pub(crate) fn parse(parser: &mut Parser) -> SQLPlannerResult<Self> {
+        let location = parser.parse_literal_string()?;
+        parser.expect_keyword(Keyword::FROM)?;
+        parser.expect_token(&Token::LParen)?;
+        let query = parser.parse_query()?;
+        parser.expect_token(&Token::RParen)?;
+
+        let mut copy_into = Self {
+            location,
+            query,
+            format: None,
+            compression: None,
+            mode: WriteMode::default(),
+            partition_by: vec![],
+        };
+        loop {
+            if parser.parse_keywords(&[Keyword::PARTITION, Keyword::BY]) {
+                parser.expect_token(&Token::LParen)?;
+                copy_into.partition_by = parser.parse_comma_separated(Parser::parse_expr)?;
+                parser.expect_token(&Token::RParen)?;
+                continue;
+            }
+            match parser.peek_token().token {
+                Token::EOF => break,
+                Token::SemiColon => {
+                    parser.next_token();
+                    if parser.peek_token().token != Token::EOF {
+                        unsupported_sql_err!("Only exactly one SQL statement allowed");
+                    }
+                    break;
+                }
+                _ => {}
+            }
+            let key = parser.parse_identifier(false)?;
+            parser.expect_token(&Token::Eq)?;
+            let value = option_value_to_str(&parser.parse_expr()?)?;
+            match key.value.to_lowercase().as_str() {
+                "file_format" | "format" => copy_into.format = Some(value),
+                "compression" => copy_into.compression = Some(value),
+                "mode" => copy_into.mode = value.parse()?,
+                other => unsupported_sql_err!("Unsupported COPY INTO option: {other}"),
+            }
+        }
+        Ok(copy_into)
+    }
+}
+
+/// The table format of a `CREATE TABLE` format option, if it is written with a table format commit
+fn table_format_from_str(format: Option<&str>) -> Option<TableFormat> {
+    match format.map(str::to_lowercase).as_deref() {
+        Some("iceberg") => Some(TableFormat::Iceberg),
+        Some("delta" | "deltalake") => Some(TableFormat::DeltaLake),
+        _ => None,
+    }
+}
+
+/// The file format of a write, parquet if unspecified
+fn file_format_from_str(format: Option<&str>) -> SQLPlannerResult<FileFormat> {
+    Ok(match format.map(str::to_lowercase).as_deref() {
+        None | Some("parquet") => FileFormat::Parquet,
+        Some("csv") => FileFormat::Csv,
+        Some(fmt @ ("iceberg" | "delta" | "deltalake")) => unsupported_sql_err!(
+            "Writing format '{fmt}' requires a catalog commit, use CREATE TABLE AS SELECT instead"
+        ),
+        Some(other) => unsupported_sql_err!("Unsupported table format: {other}"),
+    })
+}
+
+fn option_value_to_str(value: &SQLExpr) -> SQLPlannerResult<String> {
+    match value {
+        SQLExpr::Value(
+            Value::SingleQuotedString(s) | Value::DoubleQuotedString(s) | Value::Number(s, _),
+        ) => Ok(s.clone()),
+        SQLExpr::Identifier(ident) => Ok(ident.value.clone()),
+        other => invalid_operation_err!("Expected a literal table option value, found {other}"),
+    }
+}
+
+impl<'a> SQLPlanner<'a> {
+    /// Plans a `CREATE TABLE <name> [WITH (...)] [PARTITION BY (...)] AS <query>` statement.
+    ///
+    /// If a location is given, the result of the query is written there and the table is
+    /// registered as a scan of the location once the write committed. Iceberg and Delta Lake
+    /// tables are recorded as a [`TableFormatWrite`] of the result of the query, which the caller
+    /// writes with a commit to the table. Otherwise the query is registered in the catalog under
+    /// `<name>`. The returned plan produces the number of rows in the new table, except for table
+    /// format writes, where it produces the rows to write.
+    pub(crate) fn plan_create_table(
+        &mut self,
+        create: &CreateTable,
+    ) -> SQLPlannerResult<LogicalPlanRef> {
+        let Some(query) = &create.query else {
+            unsupported_sql_err!("CREATE TABLE is only supported with AS SELECT");
+        };
+        if !create.columns.is_empty() {
+            unsupported_sql_err!("Column definitions are not supported in CREATE TABLE AS SELECT");
+        }
+
+        let name = create.name.to_string();
+        if !create.or_replace && self.catalog().has_table(&name) {
+            if create.if_not_exists {
+                let existing = self.catalog().get_table(&name)?.unwrap();
+                return self
+                    .plan_affected_rows(LogicalPlanBuilder::from(existing).limit(0, false)?);
+            }
+            invalid_operation_err!("Table {name} already exists");
+        }
+
+        let opts = CreateTableOptions::try_new(create)?;
+        let plan = self.new_with_context().plan_query(query)?;
+
+        let partition_cols = match create.partition_by.as_deref() {
+            None => None,
+            Some(SQLExpr::Tuple(exprs)) => Some(
+                exprs
+                    .iter()
+                    .map(|e| self.plan_partition_col(e, &plan))
+                    .collect::<SQLPlannerResult<Vec<_>>>()?,
+            ),
+            Some(expr) => Some(vec![self.plan_partition_col(expr, &plan)?]),
+        };
+
+        if let Some(format) = table_format_from_str(opts.format.as_deref()) {
+            if opts.compression.is_some() {
+                unsupported_sql_err!(
+                    "Compression is not supported for {} tables",
+                    format.as_str()
+                );
+            }
+            if format == TableFormat::DeltaLake && opts.location.is_none() {
+                invalid_operation_err!("Delta Lake tables require a table location");
+            }
+            let partition_cols = partition_cols
+                .into_iter()
+                .flatten()
+                .map(|expr| expr.name().to_string())
+                .collect();
+            self.catalog_mut()
+                .register_table_format_write(TableFormatWrite {
+                    name,
+                    format,
+                    catalog: opts.catalog,
+                    location: opts.location,
+                    partition_cols,
+                    overwrite: create.or_replace,
+                });
+            return Ok(plan.build());
+        }
+        if opts.catalog.is_some() {
+            invalid_operation_err!("A catalog can only be given for Iceberg and Delta Lake tables");
+        }
+
+        let output = match (opts.location, opts.format) {
+            (None, None) => {
+                if partition_cols.is_some() {
+                    invalid_operation_err!("PARTITION BY requires a table location");
+                }
+                None
+            }
+            (None, Some(_)) => invalid_operation_err!("Table format requires a table location"),
+            (Some(location), format) => {
+                let file_format = file_format_from_str(format.as_deref())?;
+                Some(OutputFileInfo::new(
+                    location,
+                    file_format,
+                    partition_cols,
+                    opts.compression,
+                    None,
+                ))
+            }
+        };
+
+        match output {
+            Some(info) => {
+                self.catalog_mut()
+                    .register_pending_output(&name, plan.build(), info.clone());
+                let mode = if create.or_replace {
+                    WriteMode::Overwrite
+                } else {
+                    WriteMode::ErrorIfExists
+                };
+                self.plan_written_rows(plan.copy_into(info, mode)?)
+            }
+            None => {
+                self.catalog_mut().register_table(&name, plan.build());
+                self.plan_affected_rows(plan)
+            }
+        }
+    }
+
+    /// Plans an `INSERT INTO <name> [(cols)] <query>` statement against a registered table.
+    ///
+    /// Tables created with a location get the new rows appended to that location, other tables
+    /// are replaced in the catalog by the concatenation of the existing and new rows. The returned
+    /// plan produces the number of inserted rows.
+    pub(crate) fn plan_insert(&mut self, insert: &Insert) -> SQLPlannerResult<LogicalPlanRef> {
+        if insert.overwrite {
+            unsupported_sql_err!("INSERT OVERWRITE is not supported");
+        }
+        if insert.partitioned.is_some() {
+            unsupported_sql_err!("INSERT with PARTITION is not supported");
+        }
+        if insert.on.is_some() || insert.returning.is_some() {
+            unsupported_sql_err!("INSERT with ON CONFLICT or RETURNING is not supported");
+        }
+        let Some(source) = &insert.source else {
+            unsupported_sql_err!("INSERT without a source query is not supported");
+        };
+
+        let name = insert.table_name.to_string();
+        let Some(schema) = self.catalog().get_schema(&name) else {
+            table_not_found_err!(name);
+        };
+        let source = self.new_with_context().plan_query(source)?;
+        let rows = conform_to_schema(&source, &schema, &insert.columns)?;
+
+        let output = self.catalog().get_output(&name);
+        match output {
+            Some(info) => self.plan_written_rows(rows.copy_into(info, WriteMode::Append)?),
+            None => {
+                let table = self.catalog().get_table(&name)?.unwrap();
+                let table = LogicalPlanBuilder::from(table).concat(&rows)?;
+                self.catalog_mut().register_table(&name, table.build());
+                self.plan_affected_rows(rows)
+            }
+        }
+    }
+
+    /// Plans a `COPY INTO '<location>' FROM (<query>) ...` export.
+    ///
+    /// The returned plan writes the result of the query to the location and produces a manifest
+    /// of the written files.
+    pub(crate) fn plan_copy_into(
+        &mut self,
+        copy_into: &CopyInto,
+    ) -> SQLPlannerResult<LogicalPlanRef> {
+        let plan = self.new_with_context().plan_query(&copy_into.query)?;
+        let partition_cols = if copy_into.partition_by.is_empty() {
+            None
+        } else {
+            Some(
+                copy_into
+                    .partition_by
+                    .iter()
+                    .map(|e| self.plan_partition_col(e, &plan))
+                    .collect::<SQLPlannerResult<Vec<_>>>()?,
+            )
+        };
+        let info = OutputFileInfo::new(
+            copy_into.location.clone(),
+            file_format_from_str(copy_into.format.as_deref())?,
+            partition_cols,
+            copy_into.compression.clone(),
+            None,
+        );
+        Ok(plan.copy_into(info, copy_into.mode)?.build())
+    }
+
+    /// Plans a `COPY (<query>) TO '<location>' [WITH (FORMAT <format>)]` statement like the
+    /// equivalent `COPY INTO`.
+    pub(crate) fn plan_copy(
+        &mut self,
+        source: &CopySource,
+        to: bool,
+        target: &CopyTarget,
+        options: &[CopyOption],
+    ) -> SQLPlannerResult<LogicalPlanRef> {
+        let (CopySource::Query(query), true) = (source, to) else {
+            unsupported_sql_err!("Only COPY (<query>) TO '<location>' is supported");
+        };
+        let CopyTarget::File { filename } = target else {
+            unsupported_sql_err!("COPY can only write to a file location, found {target}");
+        };
+        let mut format = None;
+        for option in options {
+            match option {
+                CopyOption::Format(ident) => format = Some(ident.value.clone()),
+                other => unsupported_sql_err!("Unsupported COPY option: {other}"),
+            }
+        }
+        self.plan_copy_into(&CopyInto {
+            location: filename.clone(),
+            query: query.clone(),
+            format,
+            compression: None,
+            mode: WriteMode::default(),
+            partition_by: vec![],
+        })
+    }
+
+    /// Plans a `CREATE [OR REPLACE] TEMP VIEW <name> [(cols)] AS <query>` statement.
+    ///
+    /// The view is registered in the catalog under `<name>` and the returned plan is the
+    /// (empty) result of the view, so that the schema of the new view can be inspected.
+    #[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+    pub(crate) fn plan_create_view(
+        &mut self,
+        name: &ObjectName,
+        columns: &[ViewColumnDef],
+        query: &Query,
+        or_replace: bool,
+        if_not_exists: bool,
+        temporary: bool,
+        materialized: bool,
+    ) -> SQLPlannerResult<LogicalPlanRef> {
+        if materialized {
+            unsupported_sql_err!("MATERIALIZED views are not supported");
+        }
+        if !temporary {
+            unsupported_sql_err!("Only temporary views are supported, use CREATE TEMP VIEW");
+        }
+
+        let name = name.to_string();
+        if let Some(existing) = self.catalog().get_table(&name)?
+            && !or_replace
+        {
+            if if_not_exists {
+                return Ok(LogicalPlanBuilder::from(existing).limit(0, false)?.build());
+            }
+            invalid_operation_err!("Table {name} already exists");
+        }
+
+        let mut plan = self.new_with_context().plan_query(query)?;
+        if !columns.is_empty() {
+            let names = plan.schema().names();
+            if names.len() != columns.len() {
+                invalid_operation_err!(
+                    "Column count mismatch: expected {} columns, found {}",
+                    columns.len(),
+                    names.len()
+                );
+            }
+            plan = plan.select(
+                names
+                    .into_iter()
+                    .zip(columns)
+                    .map(|(name, column)| col(name).alias(column.name.value.as_str()))
+                    .collect(),
+            )?;
+        }
+
+        self.catalog_mut().register_view(&name, plan.build());
+        Ok(plan.limit(0, false)?.build())
+    }
+
+    fn plan_partition_col(
+        &self,
+        expr: &SQLExpr,
+        plan: &LogicalPlanBuilder,
+    ) -> SQLPlannerResult<ExprRef> {
+        let SQLExpr::Identifier(ident) = expr else {
+            unsupported_sql_err!("Only column references are supported in PARTITION BY: {expr}");
+        };
+        let name = ident.value.clone();
+        if plan.schema().get_field(&name).is_err() {
+            column_not_found_err!(name, "PARTITION BY");
+        }
+        Ok(col(name))
+    }
+
+    /// Produces a single row containing the number of rows that a `copy_into` wrote, from the
+    /// manifest of the files it wrote.
+    fn plan_written_rows(&self, manifest: LogicalPlanBuilder) -> SQLPlannerResult<LogicalPlanRef> {
+        Ok(manifest
+            .aggregate(
+                vec![col("num_rows")
+                    .sum()
+                    .cast(&DataType::Int64)
+                    .fill_null(lit(0i64))
+                    .alias(NUM_AFFECTED_ROWS)],
+                vec![],
+            )?
+            .build())
+    }
+
+    /// Produces a single row containing the number of rows in `plan`.
+    fn plan_affected_rows(&self, plan: LogicalPlanBuilder) -> SQLPlannerResult<LogicalPlanRef> {
+        let Some(first) = plan.schema().names().into_iter().next() else {
+            invalid_operation_err!("Cannot count the rows of a table without columns");
+        };
+        Ok(plan
+            .aggregate(
+                vec![col(first).count(CountMode::All).alias(NUM_AFFECTED_ROWS)],
+                vec![],
+            )?
+            .build())
+    }
+}
+
+/// Lines up the columns of `source` with `schema`, either positionally or by the
+/// explicit column list of the `INSERT`. Columns that are not provided are filled with nulls.
+fn conform_to_schema(
+    source: &LogicalPlanBuilder,
+    schema: &SchemaRef,
+    columns: &[sqlparser::ast::Ident],
+) -> SQLPlannerResult<LogicalPlanBuilder> {
+    let source_names = source.schema().names();
+    let target_names = if columns.is_empty() {
+        schema.names()
+    } else {
+        columns.iter().map(|c| c.value.clone()).collect()
+    };
+
+    if target_names.len() != source_names.len() {
+        invalid_operation_err!(
+            "INSERT has {} target columns but the query produces {} columns",
+            target_names.len(),
+            source_names.len()
+        );
+    }
+    for name in &target_names {
+        if schema.get_field(name).is_err() {
+            column_not_found_err!(name.clone(), "INSERT");
+        }
+    }
+
+    let projection = schema
+        .fields
+        .values()
+        .map(|field| {
+            match target_names.iter().position(|n| n == &field.name) {
+                Some(idx) => col(source_names[idx].clone()),
+                None => null_lit(),
+            }
+            .cast(&field.dtype)
+            .alias(field.name.clone())
+        })
+        .collect::<Vec<ExprRef>>();
+
+    Ok(source.select(projection)?)
+}
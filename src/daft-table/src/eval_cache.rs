@@ -0,0 +1,112 @@
+use std::collections::{HashMap, HashSet};
+
+use daft_core::series::Series;
+use daft_dsl::{functions::FunctionExpr, Expr, ExprRef};
+
+/// Results of the sub-expressions that occur more than once in the expressions evaluated against
+/// one table, so that each of them is only computed once. Sub-expressions are identified by their
+/// whole tree, which includes the columns they read, and the cache lives only as long as the
+/// evaluation of a single table.
+#[derive(Default)]
+pub(crate) struct EvalCache {
+    repeated: HashSet<ExprRef>,
+    results: HashMap<ExprRef, Series>,
+}
+
+impl EvalCache {
+    pub fn new(exprs: impl IntoIterator<Item = ExprRef>) -> Self {
+        let mut seen = HashSet::new();
+        let mut repeated = HashSet::new();
+        let mut stack = exprs.into_iter().collect::<Vec<_>>();
+        while let Some(expr) = stack.pop() {
+            if is_cacheable(&expr) {
+                if repeated.contains(&expr) {
+                    continue;
+                }
+                if !seen.insert(expr.clone()) {
+                    // the children of a repeated sub-expression are only evaluated along with it
+                    repeated.insert(expr);
+                    continue;
+                }
+            }
+            stack.extend(expr.children());
+        }
+        Self {
+            repeated,
+            results: HashMap::new(),
+        }
+    }
+
+    pub fn get(&self, expr: &Expr) -> Option<Series> {
+        self.results.get(expr).cloned()
+    }
+
+    pub fn insert(&mut self, expr: &Expr, series: &Series) {
+        if let Some(key) = self.repeated.get(expr) {
+            self.results.insert(key.clone(), series.clone());
+        }
+    }
+}
+
+/// Whether the result of an expression may be reused. Columns, literals and aliases are cheaper
+/// to evaluate than to look up, and Python UDFs may be nondeterministic or have side effects.
+fn is_cacheable(expr: &Expr) -> bool {
+    !matches!(
+        expr,
+        Expr::Column(..)
+            | Expr::Literal(..)
+            | Expr::Alias(..)
+            | Expr::Function {
+                func: FunctionExpr::Python(..),
+                ..
+            }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+
+    use super::*;
+    use crate::Table;
+
+    #[test]
+    fn test_repeated_sub_expressions() {
+        let sum = col("a").add(col("b"));
+        let product = sum.clone().mul(sum.clone());
+        let cache = EvalCache::new([product.clone(), product.clone().alias("c"), col("a")]);
+
+        // the sum is evaluated twice by each evaluation of the product, while columns are never cached
+        assert_eq!(cache.repeated, HashSet::from([product, sum]));
+    }
+
+    #[test]
+    fn test_eval_repeated_sub_expressions() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
+        let b = Int64Array::from(("b", vec![4, 5, 6])).into_series();
+        let table = Table::from_nonempty_columns(vec![a, b])?;
+
+        let sum = col("a").add(col("b"));
+        let result = table.eval_expression_list(&[
+            sum.clone().mul(lit(2)),
+            sum.clone().mul(lit(3)).alias("c"),
+            sum.gt(lit(6)).alias("d"),
+        ])?;
+        assert_eq!(
+            result.get_column("a")?.i64()?.as_slice(),
+            [10, 14, 18].as_slice()
+        );
+        assert_eq!(
+            result.get_column("c")?.i64()?.as_slice(),
+            [15, 21, 27].as_slice()
+        );
+        let d = result.get_column("d")?.bool()?;
+        assert_eq!(
+            (0..d.len()).map(|idx| d.get(idx)).collect::<Vec<_>>(),
+            [Some(false), Some(true), Some(true)]
+        );
+        Ok(())
+    }
+}
@@ -11,7 +11,7 @@ use std::{
 
 use arrow2::array::Array;
 use common_display::table_display::{make_comfy_table, StrValue};
-use common_error::{DaftError, DaftResult};
+use common_error::{DaftError, DaftResult, DaftResultExt};
 use daft_core::{
     array::ops::{
         full::FullNull, DaftApproxCountDistinctAggable, DaftHllSketchAggable, GroupIndices,
@@ -23,7 +23,9 @@ use daft_dsl::{
     LiteralValue, SketchType,
 };
 use daft_logical_plan::FileInfos;
+use eval_cache::EvalCache;
 use num_traits::ToPrimitive;
+mod eval_cache;
 #[cfg(feature = "python")]
 pub mod ffi;
 mod growable;
@@ -32,6 +34,7 @@ mod probeable;
 mod repr_html;
 
 pub use growable::GrowableTable;
+pub use ops::unique::DuplicateKeyDetector;
 pub use probeable::{make_probeable_builder, ProbeState, Probeable, ProbeableBuilder};
 
 #[cfg(feature = "python")]
@@ -518,6 +521,13 @@ impl Table {
             }
             AggExpr::List(expr) => self.eval_expression(expr)?.agg_list(groups),
             AggExpr::Concat(expr) => self.eval_expression(expr)?.agg_concat(groups),
+            &AggExpr::TopK(ref expr, k) => self.eval_expression(expr)?.top_k(groups, k),
+            &AggExpr::ApproxHeavyHitters(ref expr, capacity) => self
+                .eval_expression(expr)?
+                .approx_heavy_hitters(groups, capacity),
+            &AggExpr::MergeHeavyHitters(ref expr, capacity) => self
+                .eval_expression(expr)?
+                .merge_heavy_hitters(groups, capacity),
             AggExpr::MapGroups { .. } => Err(DaftError::ValueError(
                 "MapGroups not supported via aggregation, use map_groups instead".to_string(),
             )),
@@ -525,35 +535,42 @@ impl Table {
     }
 
     fn eval_expression(&self, expr: &Expr) -> DaftResult<Series> {
+        self.eval_expression_with_cache(expr, &mut EvalCache::new(expr.children()))
+    }
+
+    fn eval_expression_with_cache(&self, expr: &Expr, cache: &mut EvalCache) -> DaftResult<Series> {
+        if let Some(series) = cache.get(expr) {
+            return Ok(series);
+        }
         let expected_field = expr.to_field(self.schema.as_ref())?;
         let series = match expr {
-            Expr::Alias(child, name) => Ok(self.eval_expression(child)?.rename(name)),
+            Expr::Alias(child, name) => Ok(self.eval_expression_with_cache(child, cache)?.rename(name)),
             Expr::Agg(agg_expr) => self.eval_agg_expression(agg_expr, None),
-            Expr::Cast(child, dtype) => self.eval_expression(child)?.cast(dtype),
+            Expr::Cast(child, dtype) => self.eval_expression_with_cache(child, cache)?.cast(dtype),
             Expr::Column(name) => self.get_column(name).cloned(),
-            Expr::Not(child) => !(self.eval_expression(child)?),
-            Expr::IsNull(child) => self.eval_expression(child)?.is_null(),
-            Expr::NotNull(child) => self.eval_expression(child)?.not_null(),
+            Expr::Not(child) => !(self.eval_expression_with_cache(child, cache)?),
+            Expr::IsNull(child) => self.eval_expression_with_cache(child, cache)?.is_null(),
+            Expr::NotNull(child) => self.eval_expression_with_cache(child, cache)?.not_null(),
             Expr::FillNull(child, fill_value) => {
-                let fill_value = self.eval_expression(fill_value)?;
-                self.eval_expression(child)?.fill_null(&fill_value)
+                let fill_value = self.eval_expression_with_cache(fill_value, cache)?;
+                self.eval_expression_with_cache(child, cache)?.fill_null(&fill_value)
             }
             Expr::IsIn(child, items) => {
-                let items = items.iter().map(|i| self.eval_expression(i)).collect::<DaftResult<Vec<_>>>()?;
+                let items = items.iter().map(|i| self.eval_expression_with_cache(i, cache)).collect::<DaftResult<Vec<_>>>()?;
 
                 let items = items.iter().collect::<Vec<&Series>>();
                 let s = Series::concat(items.as_slice())?;
                 self
-                .eval_expression(child)?
+                .eval_expression_with_cache(child, cache)?
                 .is_in(&s)
             }
 
             Expr::Between(child, lower, upper) => self
-                .eval_expression(child)?
-                .between(&self.eval_expression(lower)?, &self.eval_expression(upper)?),
+                .eval_expression_with_cache(child, cache)?
+                .between(&self.eval_expression_with_cache(lower, cache)?, &self.eval_expression_with_cache(upper, cache)?),
             Expr::BinaryOp { op, left, right } => {
-                let lhs = self.eval_expression(left)?;
-                let rhs = self.eval_expression(right)?;
+                let lhs = self.eval_expression_with_cache(left, cache)?;
+                let rhs = self.eval_expression_with_cache(right, cache)?;
                 use daft_core::array::ops::{DaftCompare, DaftLogical};
                 use daft_dsl::Operator::*;
                 match op {
@@ -579,7 +596,7 @@ impl Table {
             Expr::Function { func, inputs } => {
                 let evaluated_inputs = inputs
                     .iter()
-                    .map(|e| self.eval_expression(e))
+                    .map(|e| self.eval_expression_with_cache(e, cache))
                     .collect::<DaftResult<Vec<_>>>()?;
                 func.evaluate(evaluated_inputs.as_slice(), func)
             }
@@ -587,7 +604,7 @@ impl Table {
                 let evaluated_inputs = func
                     .inputs
                     .iter()
-                    .map(|e| self.eval_expression(e))
+                    .map(|e| self.eval_expression_with_cache(e, cache))
                     .collect::<DaftResult<Vec<_>>>()?;
                 func.udf.evaluate(evaluated_inputs.as_slice())
             }
@@ -597,14 +614,14 @@ impl Table {
                 if_false,
                 predicate,
             } => match predicate.as_ref() {
-                Expr::Literal(LiteralValue::Boolean(true)) => self.eval_expression(if_true),
+                Expr::Literal(LiteralValue::Boolean(true)) => self.eval_expression_with_cache(if_true, cache),
                 Expr::Literal(LiteralValue::Boolean(false)) => {
-                    Ok(self.eval_expression(if_false)?.rename(if_true.name()))
+                    Ok(self.eval_expression_with_cache(if_false, cache)?.rename(if_true.name()))
                 }
                 _ => {
-                    let if_true_series = self.eval_expression(if_true)?;
-                    let if_false_series = self.eval_expression(if_false)?;
-                    let predicate_series = self.eval_expression(predicate)?;
+                    let if_true_series = self.eval_expression_with_cache(if_true, cache)?;
+                    let if_false_series = self.eval_expression_with_cache(if_false, cache)?;
+                    let predicate_series = self.eval_expression_with_cache(predicate, cache)?;
                     Ok(if_true_series.if_else(&if_false_series, &predicate_series)?)
                 }
             },
@@ -641,13 +658,18 @@ impl Table {
             series.field().dtype,
             expr
         );
+        cache.insert(expr, &series);
         Ok(series)
     }
 
     pub fn eval_expression_list(&self, exprs: &[ExprRef]) -> DaftResult<Self> {
+        let mut cache = EvalCache::new(exprs.iter().cloned());
         let result_series: Vec<_> = exprs
             .iter()
-            .map(|e| self.eval_expression(e))
+            .map(|e| {
+                self.eval_expression_with_cache(e, &mut cache)
+                    .with_context(|| format!("evaluating expression {e}"))
+            })
             .try_collect()?;
 
         let fields: Vec<_> = result_series.iter().map(|s| s.field().clone()).collect();
@@ -860,7 +882,20 @@ impl TryFrom<Table> for FileInfos {
             .iter()
             .map(|n| n.copied())
             .collect::<Vec<_>>();
-        Ok(Self::new_internal(file_paths, file_sizes, num_rows))
+        // Listings only have etags if their source provides them
+        let etags = match table.get_column("etag") {
+            Ok(etags) => etags
+                .utf8()?
+                .data()
+                .as_any()
+                .downcast_ref::<arrow2::array::Utf8Array<i64>>()
+                .unwrap()
+                .iter()
+                .map(|etag| etag.map(str::to_string))
+                .collect::<Vec<_>>(),
+            Err(_) => vec![None; file_paths.len()],
+        };
+        Self::try_new(file_paths, file_sizes, num_rows, etags)
     }
 }
 
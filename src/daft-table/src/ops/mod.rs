@@ -7,4 +7,5 @@ mod partition;
 mod pivot;
 mod search_sorted;
 mod sort;
+pub(crate) mod unique;
 mod unpivot;
@@ -92,12 +92,13 @@ impl Table {
         partition_keys: &[ExprRef],
         boundaries: &Self,
         descending: &[bool],
+        nulls_first: &[bool],
     ) -> DaftResult<Vec<Self>> {
         if boundaries.is_empty() {
             return Ok(vec![self.clone()]);
         }
         let partition_key_table = self.eval_expression_list(partition_keys)?;
-        let targets = boundaries.search_sorted(&partition_key_table, descending)?;
+        let targets = boundaries.search_sorted(&partition_key_table, descending, nulls_first)?;
         self.partition_by_index(&targets, boundaries.len() + 1)
     }
 
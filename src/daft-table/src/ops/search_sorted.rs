@@ -7,7 +7,12 @@ use daft_core::{
 use crate::Table;
 
 impl Table {
-    pub fn search_sorted(&self, keys: &Self, descending: &[bool]) -> DaftResult<UInt64Array> {
+    pub fn search_sorted(
+        &self,
+        keys: &Self,
+        descending: &[bool],
+        nulls_first: &[bool],
+    ) -> DaftResult<UInt64Array> {
         if self.schema != keys.schema {
             return Err(DaftError::SchemaMismatch(format!(
                 "Schema Mismatch in search_sorted: data: {} vs keys: {}",
@@ -17,14 +22,24 @@ impl Table {
         if self.num_columns() != descending.len() {
             return Err(DaftError::ValueError(format!("Mismatch in number of arguments for `descending` in search sorted: num_columns: {} vs : descending.len() {}", self.num_columns(), descending.len())));
         }
+        if self.num_columns() != nulls_first.len() {
+            return Err(DaftError::ValueError(format!("Mismatch in number of arguments for `nulls_first` in search sorted: num_columns: {} vs : nulls_first.len() {}", self.num_columns(), nulls_first.len())));
+        }
 
-        if self.num_columns() == 1 {
+        // The search of a single column places nulls last when ascending and first when
+        // descending, so other null orderings go through the multi-column search.
+        if self.num_columns() == 1 && nulls_first[0] == descending[0] {
             return self
                 .get_column_by_index(0)?
                 .search_sorted(keys.get_column_by_index(0)?, *descending.first().unwrap());
         }
         unsafe {
-            multicol_search_sorted(self.columns.as_slice(), keys.columns.as_slice(), descending)
+            multicol_search_sorted(
+                self.columns.as_slice(),
+                keys.columns.as_slice(),
+                descending,
+                nulls_first,
+            )
         }
     }
 }
@@ -33,6 +48,7 @@ unsafe fn multicol_search_sorted(
     data: &[Series],
     keys: &[Series],
     descending: &[bool],
+    nulls_first: &[bool],
 ) -> DaftResult<UInt64Array> {
     let data_arrow_vec: Vec<_> = data.iter().map(|s| s.to_arrow()).collect();
     let keys_arrow_vec: Vec<_> = keys.iter().map(|s| s.to_arrow()).collect();
@@ -43,7 +59,8 @@ unsafe fn multicol_search_sorted(
     let indices = search_sorted_multi_array(
         &data_arrow_ref_vec,
         &keys_arrow_ref_vec,
-        &Vec::from(descending),
+        descending,
+        nulls_first,
     )?;
     Ok(DataArray::from(("indices", Box::new(indices))))
 }
@@ -0,0 +1,129 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use common_display::table_display::StrValue;
+use common_error::{DaftError, DaftResult};
+use daft_core::array::ops::{arrow2::comparison::build_multi_array_is_equal, as_arrow::AsArrow};
+
+use crate::Table;
+
+/// The number of duplicate keys that the error of a failed check names
+const MAX_SAMPLED_DUPLICATES: usize = 5;
+
+/// Finds the rows of a stream of tables of keys whose keys equal those of an earlier row. Only the
+/// keys are kept, and rows are only compared if their keys hash alike. Null keys equal each other.
+#[derive(Default)]
+pub struct DuplicateKeyDetector {
+    keys: Vec<Table>,
+    // The table and row index of the first row of each distinct key, by the hash of the key.
+    rows_by_hash: HashMap<u64, Vec<(usize, usize)>>,
+    num_duplicates: usize,
+    sampled_duplicates: Vec<String>,
+}
+
+impl DuplicateKeyDetector {
+    /// Adds the rows of `keys`, which must have the same schema as the keys added before.
+    pub fn push(&mut self, keys: Table) -> DaftResult<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        let hashes = keys.hash_rows()?;
+        let table_idx = self.keys.len();
+        self.keys.push(keys);
+        let keys = &self.keys[table_idx];
+        let equal = vec![true; keys.num_columns()];
+
+        let mut comparators = HashMap::new();
+        for (row_idx, hash) in hashes.as_arrow().values_iter().enumerate() {
+            let rows = self.rows_by_hash.entry(*hash).or_default();
+            let mut is_duplicate = false;
+            for &(other_table_idx, other_row_idx) in rows.iter() {
+                let is_equal = match comparators.entry(other_table_idx) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(build_multi_array_is_equal(
+                        self.keys[other_table_idx].columns.as_slice(),
+                        keys.columns.as_slice(),
+                        &equal,
+                        &equal,
+                    )?),
+                };
+                if is_equal(other_row_idx, row_idx) {
+                    is_duplicate = true;
+                    break;
+                }
+            }
+            if !is_duplicate {
+                rows.push((table_idx, row_idx));
+                continue;
+            }
+            self.num_duplicates += 1;
+            if self.sampled_duplicates.len() < MAX_SAMPLED_DUPLICATES {
+                self.sampled_duplicates.push(format!(
+                    "({})",
+                    keys.columns
+                        .iter()
+                        .map(|key| format!("{}={}", key.name(), key.str_value(row_idx)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fails if any of the rows added so far had the keys of an earlier row, naming some of them.
+    pub fn check(&self) -> DaftResult<()> {
+        if self.num_duplicates == 0 {
+            return Ok(());
+        }
+        Err(DaftError::ValueError(format!(
+            "Found {} rows with duplicate keys, including: {}",
+            self.num_duplicates,
+            self.sampled_duplicates.join(", ")
+        )))
+    }
+}
+
+impl Table {
+    /// Fails if two rows have equal values of `keys`, naming some of the duplicate keys.
+    pub fn assert_unique(&self, keys: &[daft_dsl::ExprRef]) -> DaftResult<()> {
+        let mut detector = DuplicateKeyDetector::default();
+        detector.push(self.eval_expression_list(keys)?)?;
+        detector.check()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+
+    use super::*;
+
+    fn table(a: Vec<Option<i64>>, b: Vec<&str>) -> DaftResult<Table> {
+        Table::from_nonempty_columns(vec![
+            Int64Array::from_iter(Field::new("a", DataType::Int64), a.into_iter()).into_series(),
+            Utf8Array::from(("b", b.as_slice())).into_series(),
+        ])
+    }
+
+    #[test]
+    fn test_detects_duplicates_across_tables() -> DaftResult<()> {
+        let mut detector = DuplicateKeyDetector::default();
+        detector.push(table(vec![Some(1), Some(1), None], vec!["x", "y", "x"])?)?;
+        detector.check()?;
+
+        detector.push(table(vec![Some(2), Some(1), None], vec!["x", "y", "x"])?)?;
+        let err = detector.check().unwrap_err().to_string();
+        assert!(err.contains("Found 2 rows with duplicate keys"), "{err}");
+        assert!(err.contains("(a=1, b=y)"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_unique() -> DaftResult<()> {
+        let table = table(vec![Some(1), Some(1)], vec!["x", "y"])?;
+        table.assert_unique(&[col("a"), col("b")])?;
+        assert!(table.assert_unique(&[col("a")]).is_err());
+        Ok(())
+    }
+}
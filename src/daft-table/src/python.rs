@@ -321,6 +321,7 @@ impl PyTable {
         partition_keys: Vec<PyExpr>,
         boundaries: &Self,
         descending: Vec<bool>,
+        nulls_first: Vec<bool>,
     ) -> PyResult<Vec<Self>> {
         let exprs: Vec<daft_dsl::ExprRef> = partition_keys
             .into_iter()
@@ -329,7 +330,12 @@ impl PyTable {
         py.allow_threads(|| {
             Ok(self
                 .table
-                .partition_by_range(exprs.as_slice(), &boundaries.table, descending.as_slice())?
+                .partition_by_range(
+                    exprs.as_slice(),
+                    &boundaries.table,
+                    descending.as_slice(),
+                    nulls_first.as_slice(),
+                )?
                 .into_iter()
                 .map(std::convert::Into::into)
                 .collect::<Vec<Self>>())
@@ -0,0 +1,189 @@
+use common_error::DaftResult;
+use daft_core::{
+    array::ops::as_arrow::AsArrow,
+    prelude::Utf8Array,
+    series::{IntoSeries, Series},
+};
+use daft_logical_plan::{sink_info::QUARANTINED, OutputFileInfo, WriteAttempt, WriteMode};
+use daft_table::Table;
+
+use crate::manifest::ensure_location_is_empty;
+
+/// Column of the results of the file writers with the path of each written file
+const PATH_COLUMN: &str = "path";
+
+/// Checks the location of a write before any of its files are written. A write in the
+/// `ErrorIfExists` mode fails if there already are files under its root directory.
+///
+/// Staged files are committed, and the files replaced by an overwrite deleted, through the
+/// filesystems of Python, so builds without Python refuse those writes before writing any files.
+pub fn prepare_write(file_info: &OutputFileInfo, mode: WriteMode) -> DaftResult<()> {
+    #[cfg(not(feature = "python"))]
+    {
+        if file_info.write_attempt.is_some() || mode == WriteMode::Overwrite {
+            return Err(common_error::DaftError::not_implemented(
+                "Retry-safe and overwriting writes require Python",
+            ));
+        }
+    }
+    match mode {
+        WriteMode::ErrorIfExists => {
+            ensure_location_is_empty(&file_info.root_dir, file_info.io_config.as_ref())
+        }
+        WriteMode::Append | WriteMode::Overwrite => Ok(()),
+    }
+}
+
+/// Commits the files of a write once all of its writers are closed.
+///
+/// The files of a retry-safe write are staged: the files of other attempts of its job are deleted
+/// and the staged files of this attempt are renamed. The results of the writers name the staged
+/// files, and the returned results name the committed ones. Writes that aren't retry-safe write
+/// their files under their final names, so their results are returned unchanged. The same goes for
+/// the files of quarantined rows, which are never staged.
+///
+/// A write in the `Overwrite` mode then deletes all other files under its root directory.
+pub fn commit_write(
+    results: Vec<Table>,
+    file_info: &OutputFileInfo,
+    mode: WriteMode,
+) -> DaftResult<Vec<Table>> {
+    let results = match &file_info.write_attempt {
+        Some(write_attempt) => commit_staged_write(results, file_info, write_attempt)?,
+        None => results,
+    };
+    if mode == WriteMode::Overwrite {
+        let written_paths = results
+            .iter()
+            .filter(|result| !is_quarantined(result))
+            .map(result_paths)
+            .collect::<DaftResult<Vec<_>>>()?;
+        delete_other_files(written_paths.concat(), file_info)?;
+    }
+    Ok(results)
+}
+
+fn commit_staged_write(
+    results: Vec<Table>,
+    file_info: &OutputFileInfo,
+    write_attempt: &WriteAttempt,
+) -> DaftResult<Vec<Table>> {
+    let (quarantined, results): (Vec<_>, Vec<_>) = results.into_iter().partition(is_quarantined);
+    let staged_paths = results
+        .iter()
+        .map(result_paths)
+        .collect::<DaftResult<Vec<_>>>()?;
+    let committed_paths = commit_staged_paths(staged_paths.concat(), file_info, write_attempt)?;
+
+    let mut committed_paths = committed_paths.iter().map(String::as_str);
+    results
+        .into_iter()
+        .map(|result| {
+            let paths = committed_paths
+                .by_ref()
+                .take(result.len())
+                .collect::<Vec<_>>();
+            let columns = (0..result.num_columns())
+                .map(|idx| {
+                    let column = result.get_column_by_index(idx)?;
+                    Ok(if column.name() == PATH_COLUMN {
+                        Utf8Array::from((PATH_COLUMN, paths.as_slice())).into_series()
+                    } else {
+                        column.clone()
+                    })
+                })
+                .collect::<DaftResult<Vec<Series>>>()?;
+            Table::new_with_size(result.schema.clone(), columns, result.len())
+        })
+        .chain(quarantined.into_iter().map(Ok))
+        .collect()
+}
+
+/// The paths of the files named by a writer's result
+fn result_paths(result: &Table) -> DaftResult<Vec<String>> {
+    Ok(result
+        .get_column(PATH_COLUMN)?
+        .utf8()?
+        .as_arrow()
+        .values_iter()
+        .map(str::to_string)
+        .collect())
+}
+
+/// Whether `result` names files of quarantined rows, which each writer's results either all do or
+/// all don't.
+fn is_quarantined(result: &Table) -> bool {
+    result
+        .get_column(QUARANTINED)
+        .ok()
+        .and_then(|column| column.bool().ok()?.get(0))
+        .unwrap_or(false)
+}
+
+#[cfg(feature = "python")]
+fn commit_staged_paths(
+    staged_paths: Vec<String>,
+    file_info: &OutputFileInfo,
+    write_attempt: &WriteAttempt,
+) -> DaftResult<Vec<String>> {
+    use pyo3::{types::PyAnyMethods, Python};
+
+    Python::with_gil(|py| {
+        let committed_paths = py
+            .import_bound(pyo3::intern!(py, "daft.filesystem"))?
+            .getattr(pyo3::intern!(py, "commit_write"))?
+            .call1((
+                staged_paths,
+                &file_info.root_dir,
+                &write_attempt.job_id,
+                &write_attempt.attempt_id,
+                file_info
+                    .io_config
+                    .as_ref()
+                    .map(|cfg| daft_io::python::IOConfig {
+                        config: cfg.clone(),
+                    }),
+            ))?
+            .extract::<Vec<String>>()?;
+        Ok(committed_paths)
+    })
+}
+
+#[cfg(not(feature = "python"))]
+fn commit_staged_paths(
+    _staged_paths: Vec<String>,
+    _file_info: &OutputFileInfo,
+    _write_attempt: &WriteAttempt,
+) -> DaftResult<Vec<String>> {
+    Err(common_error::DaftError::ComputeError(
+        "Retry-safe writes require Python".to_string(),
+    ))
+}
+
+#[cfg(feature = "python")]
+fn delete_other_files(written_paths: Vec<String>, file_info: &OutputFileInfo) -> DaftResult<()> {
+    use pyo3::{types::PyAnyMethods, Python};
+
+    Python::with_gil(|py| {
+        py.import_bound(pyo3::intern!(py, "daft.filesystem"))?
+            .getattr(pyo3::intern!(py, "delete_other_files"))?
+            .call1((
+                written_paths,
+                &file_info.root_dir,
+                file_info
+                    .io_config
+                    .as_ref()
+                    .map(|cfg| daft_io::python::IOConfig {
+                        config: cfg.clone(),
+                    }),
+            ))?;
+        Ok(())
+    })
+}
+
+#[cfg(not(feature = "python"))]
+fn delete_other_files(_written_paths: Vec<String>, _file_info: &OutputFileInfo) -> DaftResult<()> {
+    Err(common_error::DaftError::ComputeError(
+        "Overwriting writes require Python".to_string(),
+    ))
+}
@@ -0,0 +1,438 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{BooleanArray, DataType, Field, Schema, Utf8Array},
+    series::IntoSeries,
+};
+use daft_dsl::{lit, ExprRef};
+use daft_logical_plan::{
+    sink_info::{QUARANTINED, QUARANTINE_REASON},
+    ConstraintViolationAction, WriteConstraint, WriteConstraints,
+};
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+
+use crate::{FileWriter, WriterFactory};
+
+/// Whether a row satisfies `constraint`, counting rows for which the predicate is null as
+/// violations.
+fn satisfies(constraint: &WriteConstraint) -> ExprRef {
+    constraint.predicate.clone().fill_null(lit(false))
+}
+
+/// Adds the [`QUARANTINED`] column to the results of a writer, which tells the files of
+/// quarantined rows apart from the other written files.
+fn label_results(results: Vec<Table>, quarantined: bool) -> DaftResult<Vec<Table>> {
+    results
+        .into_iter()
+        .map(|result| {
+            let labels =
+                BooleanArray::from_iter(QUARANTINED, (0..result.len()).map(|_| Some(quarantined)));
+            result.union(&Table::from_nonempty_columns(vec![labels.into_series()])?)
+        })
+        .collect()
+}
+
+/// ConstrainedWriter checks the rows written to it against the constraints of a write, and only
+/// passes the rows that satisfy all of them on to its writer. What happens to the other rows is up
+/// to the write's `on_violation` action.
+struct ConstrainedWriter {
+    writer: Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Vec<Table>>>,
+    constraints: Arc<WriteConstraints>,
+    satisfies_all: ExprRef,
+    // Created once the first rows are quarantined.
+    quarantine_writer:
+        Option<Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Vec<Table>>>>,
+    quarantine_writer_factory:
+        Option<Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<Table>>>>,
+    file_idx: usize,
+}
+
+impl ConstrainedWriter {
+    fn violation_error(&self, input: &MicroPartition) -> DaftResult<DaftError> {
+        for constraint in &self.constraints.constraints {
+            let num_violations = input.filter(&[satisfies(constraint).not()])?.len();
+            if num_violations > 0 {
+                return Ok(DaftError::ValueError(format!(
+                    "{num_violations} rows violate the write constraint `{}`",
+                    constraint.name
+                )));
+            }
+        }
+        Ok(DaftError::InternalError(
+            "Rows violated the write constraints, but none of the constraints individually"
+                .to_string(),
+        ))
+    }
+
+    /// Adds the [`QUARANTINE_REASON`] column to `rows`, with the names of the constraints that
+    /// each row violates.
+    fn with_reasons(&self, rows: &MicroPartition) -> DaftResult<MicroPartition> {
+        let checks = self
+            .constraints
+            .constraints
+            .iter()
+            .enumerate()
+            .map(|(idx, constraint)| satisfies(constraint).alias(idx.to_string()))
+            .collect::<Vec<_>>();
+        let tables = rows
+            .get_tables()?
+            .iter()
+            .map(|table| {
+                let checks = table.eval_expression_list(&checks)?;
+                let checks = (0..checks.num_columns())
+                    .map(|idx| Ok(checks.get_column_by_index(idx)?.bool()?.clone()))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                let reasons = (0..table.len())
+                    .map(|row| {
+                        let violated = self
+                            .constraints
+                            .constraints
+                            .iter()
+                            .zip(&checks)
+                            .filter(|(_, check)| check.get(row) != Some(true))
+                            .map(|(constraint, _)| constraint.name.as_str())
+                            .collect::<Vec<_>>();
+                        Some(violated.join("; "))
+                    })
+                    .collect::<Vec<_>>();
+                let reasons = Utf8Array::from_iter(QUARANTINE_REASON, reasons.into_iter());
+                table.union(&Table::from_nonempty_columns(vec![reasons.into_series()])?)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let mut fields = rows.schema().fields.values().cloned().collect::<Vec<_>>();
+        fields.push(Field::new(QUARANTINE_REASON, DataType::Utf8));
+        Ok(MicroPartition::new_loaded(
+            Arc::new(Schema::new(fields)?),
+            Arc::new(tables),
+            None,
+        ))
+    }
+
+    fn quarantine(&mut self, rows: &MicroPartition) -> DaftResult<()> {
+        let rows = self.with_reasons(rows)?;
+        let writer = match &mut self.quarantine_writer {
+            Some(writer) => writer,
+            None => {
+                let factory = self.quarantine_writer_factory.as_ref().ok_or_else(|| {
+                    DaftError::InternalError(
+                        "Quarantining rows requires a quarantine writer".to_string(),
+                    )
+                })?;
+                self.quarantine_writer
+                    .insert(factory.create_writer(self.file_idx, None)?)
+            }
+        };
+        writer.write(Arc::new(rows))?;
+        Ok(())
+    }
+}
+
+impl FileWriter for ConstrainedWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Vec<Table>;
+
+    fn write(&mut self, input: Self::Input) -> DaftResult<usize> {
+        let satisfying = input.filter(&[self.satisfies_all.clone()])?;
+        if satisfying.len() == input.len() {
+            return self.writer.write(input);
+        }
+
+        match &self.constraints.on_violation {
+            ConstraintViolationAction::Fail => return Err(self.violation_error(&input)?),
+            ConstraintViolationAction::Drop => {}
+            ConstraintViolationAction::Quarantine { .. } => {
+                let violating = input.filter(&[self.satisfies_all.clone().not()])?;
+                self.quarantine(&violating)?;
+            }
+        }
+
+        if satisfying.is_empty() {
+            Ok(0)
+        } else {
+            self.writer.write(Arc::new(satisfying))
+        }
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.writer.bytes_written()
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        let results = self.writer.close()?;
+        if !self.constraints.quarantines() {
+            return Ok(results);
+        }
+
+        let mut results = label_results(results, false)?;
+        if let Some(mut quarantine_writer) = self.quarantine_writer.take() {
+            results.extend(label_results(quarantine_writer.close()?, true)?);
+        }
+        Ok(results)
+    }
+}
+
+/// ConstrainedWriterFactory creates writers that check the written rows against the constraints
+/// of a write before writing them, quarantining the violating rows with writers of
+/// `quarantine_writer_factory` if the write asks to.
+pub(crate) struct ConstrainedWriterFactory {
+    writer_factory: Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<Table>>>,
+    quarantine_writer_factory:
+        Option<Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<Table>>>>,
+    constraints: Arc<WriteConstraints>,
+}
+
+impl ConstrainedWriterFactory {
+    pub(crate) fn new(
+        writer_factory: Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<Table>>>,
+        quarantine_writer_factory: Option<
+            Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<Table>>>,
+        >,
+        constraints: WriteConstraints,
+    ) -> Self {
+        Self {
+            writer_factory,
+            quarantine_writer_factory,
+            constraints: Arc::new(constraints),
+        }
+    }
+}
+
+impl WriterFactory for ConstrainedWriterFactory {
+    type Input = Arc<MicroPartition>;
+    type Result = Vec<Table>;
+
+    fn create_writer(
+        &self,
+        file_idx: usize,
+        partition_values: Option<&Table>,
+    ) -> DaftResult<Box<dyn FileWriter<Input = Self::Input, Result = Self::Result>>> {
+        let satisfies_all = self
+            .constraints
+            .constraints
+            .iter()
+            .map(satisfies)
+            .reduce(|left, right| left.and(right))
+            .unwrap_or_else(|| lit(true));
+        Ok(Box::new(ConstrainedWriter {
+            writer: self
+                .writer_factory
+                .create_writer(file_idx, partition_values)?,
+            constraints: self.constraints.clone(),
+            satisfies_all,
+            quarantine_writer: None,
+            quarantine_writer_factory: self.quarantine_writer_factory.clone(),
+            file_idx,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use common_file_formats::FileFormat;
+    use daft_dsl::col;
+
+    use super::*;
+    use crate::{
+        file::TargetFileSizeWriterFactory,
+        test::{make_dummy_mp, DummyWriterFactory},
+        TargetInMemorySizeBytesCalculator,
+    };
+
+    fn file_writer_factory() -> Arc<TargetFileSizeWriterFactory> {
+        Arc::new(TargetFileSizeWriterFactory::new(
+            Arc::new(DummyWriterFactory),
+            Arc::new(TargetInMemorySizeBytesCalculator::new(1000, 1.0)),
+        ))
+    }
+
+    fn constraints(on_violation: ConstraintViolationAction) -> WriteConstraints {
+        WriteConstraints {
+            constraints: vec![
+                WriteConstraint {
+                    name: "ints < 42".to_string(),
+                    predicate: col("ints").lt(lit(42u32)),
+                },
+                WriteConstraint {
+                    name: "ints IS NOT NULL".to_string(),
+                    predicate: col("ints").not_null(),
+                },
+            ],
+            on_violation,
+        }
+    }
+
+    fn write_counts(results: &[Table]) -> Vec<u64> {
+        results
+            .iter()
+            .map(|result| {
+                result
+                    .get_column("write_count")
+                    .unwrap()
+                    .u64()
+                    .unwrap()
+                    .get(0)
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    /// Writer factory that records the rows written by its writers
+    struct RecordingWriterFactory(Arc<Mutex<Vec<Arc<MicroPartition>>>>);
+
+    struct RecordingWriter(Arc<Mutex<Vec<Arc<MicroPartition>>>>);
+
+    impl WriterFactory for RecordingWriterFactory {
+        type Input = Arc<MicroPartition>;
+        type Result = Vec<Table>;
+
+        fn create_writer(
+            &self,
+            _file_idx: usize,
+            _partition_values: Option<&Table>,
+        ) -> DaftResult<Box<dyn FileWriter<Input = Self::Input, Result = Self::Result>>> {
+            Ok(Box::new(RecordingWriter(self.0.clone())))
+        }
+    }
+
+    impl FileWriter for RecordingWriter {
+        type Input = Arc<MicroPartition>;
+        type Result = Vec<Table>;
+
+        fn write(&mut self, input: Self::Input) -> DaftResult<usize> {
+            self.0.lock().unwrap().push(input);
+            Ok(0)
+        }
+
+        fn bytes_written(&self) -> usize {
+            0
+        }
+
+        fn close(&mut self) -> DaftResult<Self::Result> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn test_constrained_writer_fails_on_violations() {
+        let factory = ConstrainedWriterFactory::new(
+            file_writer_factory(),
+            None,
+            constraints(ConstraintViolationAction::Fail),
+        );
+        let mut writer = factory.create_writer(0, None).unwrap();
+
+        let err = writer.write(make_dummy_mp(3)).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("3 rows violate the write constraint `ints < 42`"));
+    }
+
+    #[test]
+    fn test_constrained_writer_drops_violations() {
+        let factory = ConstrainedWriterFactory::new(
+            file_writer_factory(),
+            None,
+            constraints(ConstraintViolationAction::Drop),
+        );
+        let mut writer = factory.create_writer(0, None).unwrap();
+
+        assert_eq!(writer.write(make_dummy_mp(3)).unwrap(), 0);
+        let results = writer.close().unwrap();
+        assert_eq!(write_counts(&results), vec![0]);
+        assert!(results[0].get_column(QUARANTINED).is_err());
+    }
+
+    #[test]
+    fn test_constrained_writer_quarantines_violations() {
+        let quarantined = Arc::new(Mutex::new(vec![]));
+        let factory = ConstrainedWriterFactory::new(
+            file_writer_factory(),
+            Some(Arc::new(RecordingWriterFactory(quarantined.clone()))),
+            constraints(ConstraintViolationAction::Quarantine {
+                root_dir: "quarantine".to_string(),
+                file_format: FileFormat::Parquet,
+            }),
+        );
+        let mut writer = factory.create_writer(0, None).unwrap();
+
+        writer.write(make_dummy_mp(3)).unwrap();
+        let results = writer.close().unwrap();
+        assert_eq!(write_counts(&results), vec![0]);
+        assert_eq!(
+            results[0]
+                .get_column(QUARANTINED)
+                .unwrap()
+                .bool()
+                .unwrap()
+                .get(0),
+            Some(false)
+        );
+
+        let quarantined = quarantined.lock().unwrap();
+        let [rows] = quarantined.as_slice() else {
+            panic!("Expected the violating rows to be quarantined at once");
+        };
+        assert_eq!(rows.len(), 3);
+        let tables = rows.get_tables().unwrap();
+        let reasons = tables[0]
+            .get_column(QUARANTINE_REASON)
+            .unwrap()
+            .utf8()
+            .unwrap();
+        assert_eq!(reasons.get(0), Some("ints < 42"));
+    }
+
+    #[test]
+    fn test_constrained_writer_labels_quarantine_results() {
+        let factory = ConstrainedWriterFactory::new(
+            file_writer_factory(),
+            Some(file_writer_factory()),
+            constraints(ConstraintViolationAction::Quarantine {
+                root_dir: "quarantine".to_string(),
+                file_format: FileFormat::Csv,
+            }),
+        );
+        let mut writer = factory.create_writer(0, None).unwrap();
+
+        writer.write(make_dummy_mp(3)).unwrap();
+        let results = writer.close().unwrap();
+        let labels = results
+            .iter()
+            .map(|result| {
+                result
+                    .get_column(QUARANTINED)
+                    .unwrap()
+                    .bool()
+                    .unwrap()
+                    .get(0)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec![false, true]);
+        assert_eq!(write_counts(&results), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_constrained_writer_passes_satisfying_rows() {
+        let factory = ConstrainedWriterFactory::new(
+            file_writer_factory(),
+            None,
+            WriteConstraints {
+                constraints: vec![WriteConstraint {
+                    name: "ints IS NOT NULL".to_string(),
+                    predicate: col("ints").not_null(),
+                }],
+                on_violation: ConstraintViolationAction::Fail,
+            },
+        );
+        let mut writer = factory.create_writer(0, None).unwrap();
+
+        writer.write(make_dummy_mp(3)).unwrap();
+        assert_eq!(write_counts(&writer.close().unwrap()), vec![1]);
+    }
+}
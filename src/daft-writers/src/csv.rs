@@ -0,0 +1,245 @@
+use std::{io::Write, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use common_file_formats::{CsvQuoteStyle, CsvWriteOptions};
+use daft_core::prelude::*;
+use daft_io::{IOConfig, IOStatsContext};
+use daft_logical_plan::WriteAttempt;
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+
+use crate::{
+    output::{CountingSink, OutputFile},
+    FileWriter,
+};
+
+/// NativeCsvWriter writes a CSV file without going through Python. Values are written as they're
+/// cast to strings, and the header is written along with the first rows.
+pub(crate) struct NativeCsvWriter {
+    file: OutputFile,
+    options: CsvWriteOptions,
+    sink: Option<CountingSink>,
+    is_closed: bool,
+}
+
+impl NativeCsvWriter {
+    pub(crate) fn new(
+        root_dir: &str,
+        file_idx: usize,
+        csv_options: &Option<CsvWriteOptions>,
+        io_config: &Option<IOConfig>,
+        partition_values: Option<&Table>,
+        write_attempt: &Option<WriteAttempt>,
+    ) -> DaftResult<Self> {
+        let options = csv_options.clone().unwrap_or_default();
+        options.validate()?;
+        Ok(Self {
+            file: OutputFile::new(
+                root_dir,
+                file_idx,
+                "csv",
+                io_config,
+                partition_values,
+                write_attempt,
+            )?,
+            options,
+            sink: None,
+            is_closed: false,
+        })
+    }
+}
+
+impl FileWriter for NativeCsvWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<Table>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        assert!(!self.is_closed, "Cannot write to a closed NativeCsvWriter");
+        let tables = data.concat_or_get(IOStatsContext::new("NativeCsvWriter::write"))?;
+        let Some(table) = tables.first() else {
+            return Ok(0);
+        };
+
+        let mut buf = Vec::new();
+        if self.sink.is_none() {
+            self.sink = Some(self.file.create_sink()?);
+            if self.options.header {
+                let names = table.schema.fields.keys().map(|name| Some(name.as_str()));
+                write_row(&mut buf, names, &self.options)?;
+            }
+        }
+        let columns = (0..table.num_columns())
+            .map(|idx| csv_values(table.get_column_by_index(idx)?))
+            .collect::<DaftResult<Vec<_>>>()?;
+        for row in 0..table.len() {
+            let values = columns.iter().map(|column| column.get(row));
+            write_row(&mut buf, values, &self.options)?;
+        }
+
+        let sink = self.sink.as_mut().unwrap();
+        sink.write_all(&buf)?;
+        Ok(buf.len())
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.file.bytes_written()
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        self.is_closed = true;
+        let Some(sink) = self.sink.take() else {
+            return Ok(None);
+        };
+        Ok(Some(self.file.finish(sink)?))
+    }
+}
+
+/// The values of a column as they're written to a CSV file
+fn csv_values(column: &Series) -> DaftResult<Utf8Array> {
+    let values = column.cast(&DataType::Utf8).map_err(|e| {
+        DaftError::ValueError(format!(
+            "Cannot write column {} of type {} to a CSV file: {e}",
+            column.name(),
+            column.data_type()
+        ))
+    })?;
+    Ok(values.utf8()?.clone())
+}
+
+fn write_row<'a>(
+    buf: &mut Vec<u8>,
+    values: impl Iterator<Item = Option<&'a str>>,
+    options: &CsvWriteOptions,
+) -> DaftResult<()> {
+    for (idx, value) in values.enumerate() {
+        if idx > 0 {
+            buf.push(options.delimiter as u8);
+        }
+        match value {
+            Some(value) => write_value(buf, value, options)?,
+            None => buf.extend_from_slice(options.null_value.as_bytes()),
+        }
+    }
+    buf.push(b'\n');
+    Ok(())
+}
+
+/// Writes a value, quoted according to the quote style. Empty strings are quoted whenever quoting
+/// is allowed, so that they can be told apart from nulls.
+fn write_value(buf: &mut Vec<u8>, value: &str, options: &CsvWriteOptions) -> DaftResult<()> {
+    let quote = options.quote as u8;
+    let needs_quotes = value.is_empty()
+        || value.bytes().any(|byte| {
+            byte == options.delimiter as u8 || byte == quote || byte == b'\n' || byte == b'\r'
+        });
+    let quoted = match options.quote_style {
+        CsvQuoteStyle::Needed => needs_quotes,
+        CsvQuoteStyle::AllValid => true,
+        CsvQuoteStyle::Never if needs_quotes && !value.is_empty() => {
+            return Err(DaftError::ValueError(format!(
+                "Value {value:?} must be quoted to be written to a CSV file, but the quote style is `none`"
+            )));
+        }
+        CsvQuoteStyle::Never => false,
+    };
+    if !quoted {
+        buf.extend_from_slice(value.as_bytes());
+        return Ok(());
+    }
+    buf.push(quote);
+    for byte in value.bytes() {
+        if byte == quote {
+            buf.push(quote);
+        }
+        buf.push(byte);
+    }
+    buf.push(quote);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes the table twice to a CSV file, returning the contents of the file
+    fn write_table(table: Table, options: CsvWriteOptions) -> DaftResult<String> {
+        let root_dir = std::env::temp_dir().join(format!("daft-writers-{}", uuid::Uuid::new_v4()));
+        let root_dir = root_dir.to_string_lossy().to_string();
+        let write = || -> DaftResult<String> {
+            let mut writer =
+                NativeCsvWriter::new(&root_dir, 0, &Some(options), &None, None, &None)?;
+            for _ in 0..2 {
+                writer.write(Arc::new(MicroPartition::new_loaded(
+                    table.schema.clone(),
+                    Arc::new(vec![table.clone()]),
+                    None,
+                )))?;
+            }
+            let result = writer.close()?.unwrap();
+            let path = result
+                .get_column("path")?
+                .utf8()?
+                .get(0)
+                .unwrap()
+                .to_string();
+            assert!(path.ends_with("-0.csv"));
+            assert_eq!(
+                std::fs::metadata(&path)?.len() as usize,
+                writer.bytes_written()
+            );
+            Ok(std::fs::read_to_string(path)?)
+        };
+        let contents = write();
+        let _ = std::fs::remove_dir_all(&root_dir);
+        contents
+    }
+
+    fn make_table() -> DaftResult<Table> {
+        Table::from_nonempty_columns(vec![
+            Int64Array::from_iter(
+                Field::new("a", DataType::Int64),
+                vec![Some(1), None, Some(3)].into_iter(),
+            )
+            .into_series(),
+            Utf8Array::from_iter("b", vec![Some("x,y"), Some(""), None].into_iter()).into_series(),
+            Utf8Array::from_iter(
+                "c",
+                vec![Some("say \"hi\""), Some("z"), Some("w")].into_iter(),
+            )
+            .into_series(),
+        ])
+    }
+
+    #[test]
+    fn test_native_csv_writer_default_options() -> DaftResult<()> {
+        let contents = write_table(make_table()?, CsvWriteOptions::default())?;
+        let rows = "1,\"x,y\",\"say \"\"hi\"\"\"\n,\"\",z\n3,,w\n";
+        assert_eq!(contents, format!("a,b,c\n{rows}{rows}"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_native_csv_writer_options() -> DaftResult<()> {
+        let options = CsvWriteOptions {
+            delimiter: '|',
+            quote: '\'',
+            quote_style: CsvQuoteStyle::AllValid,
+            header: false,
+            null_value: "NULL".to_string(),
+        };
+        let contents = write_table(make_table()?, options)?;
+        let rows = "'1'|'x,y'|'say \"hi\"'\nNULL|''|'z'\n'3'|NULL|'w'\n";
+        assert_eq!(contents, format!("{rows}{rows}"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_native_csv_writer_fails_on_unquotable_values() -> DaftResult<()> {
+        let options = CsvWriteOptions {
+            quote_style: CsvQuoteStyle::Never,
+            ..Default::default()
+        };
+        assert!(write_table(make_table()?, options).is_err());
+        Ok(())
+    }
+}
@@ -1,7 +1,13 @@
 #![feature(hash_raw_entry)]
 #![feature(let_chains)]
 mod batch;
+mod commit;
+mod constraint;
+mod csv;
 mod file;
+mod manifest;
+mod output;
+mod parquet;
 mod partition;
 mod physical;
 
@@ -21,16 +27,19 @@ use std::{
 };
 
 use batch::TargetBatchWriterFactory;
+pub use commit::{commit_write, prepare_write};
 use common_daft_config::DaftExecutionConfig;
 use common_error::DaftResult;
 use common_file_formats::FileFormat;
+use constraint::ConstrainedWriterFactory;
 use daft_dsl::ExprRef;
-use daft_logical_plan::OutputFileInfo;
+use daft_logical_plan::{ConstraintViolationAction, OutputFileInfo};
 use daft_micropartition::MicroPartition;
 use daft_table::Table;
 use file::TargetFileSizeWriterFactory;
 #[cfg(feature = "python")]
 pub use lance::make_lance_writer_factory;
+use manifest::ManifestWriterFactory;
 use partition::PartitionedWriterFactory;
 use physical::PhysicalWriterFactory;
 /// This trait is used to abstract the writing of data to a file.
@@ -68,7 +77,72 @@ pub fn make_physical_writer_factory(
     file_info: &OutputFileInfo,
     cfg: &DaftExecutionConfig,
 ) -> Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<Table>>> {
-    let base_writer_factory = PhysicalWriterFactory::new(file_info.clone());
+    make_output_writer_factory(file_info, cfg, false)
+}
+
+/// Like [`make_physical_writer_factory`], but the results of the writers also contain the number of
+/// rows written to each file and its size, as the manifest of a COPY INTO export.
+pub fn make_manifest_writer_factory(
+    file_info: &OutputFileInfo,
+    cfg: &DaftExecutionConfig,
+) -> Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<Table>>> {
+    make_output_writer_factory(file_info, cfg, true)
+}
+
+fn make_output_writer_factory(
+    file_info: &OutputFileInfo,
+    cfg: &DaftExecutionConfig,
+    manifest: bool,
+) -> Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<Table>>> {
+    let physical_writer_factory = Arc::new(PhysicalWriterFactory::new(file_info.clone(), cfg));
+    let base_writer_factory: Arc<
+        dyn WriterFactory<Input = Arc<MicroPartition>, Result = Option<Table>>,
+    > = if manifest {
+        Arc::new(ManifestWriterFactory::new(
+            physical_writer_factory,
+            file_info.io_config.clone(),
+        ))
+    } else {
+        physical_writer_factory
+    };
+    let writer_factory = make_file_writer_factory(base_writer_factory, file_info, cfg);
+
+    let Some(constraints) = &file_info.constraints else {
+        return writer_factory;
+    };
+    // Quarantined rows are partitioned like the written rows, but aren't part of the write's
+    // commit.
+    let quarantine_writer_factory = match &constraints.on_violation {
+        ConstraintViolationAction::Quarantine {
+            root_dir,
+            file_format,
+        } => Some(make_output_writer_factory(
+            &OutputFileInfo {
+                root_dir: root_dir.clone(),
+                file_format: *file_format,
+                write_attempt: None,
+                constraints: None,
+                ..file_info.clone()
+            },
+            cfg,
+            manifest,
+        )),
+        ConstraintViolationAction::Fail | ConstraintViolationAction::Drop => None,
+    };
+    Arc::new(ConstrainedWriterFactory::new(
+        writer_factory,
+        quarantine_writer_factory,
+        constraints.clone(),
+    ))
+}
+
+fn make_file_writer_factory(
+    base_writer_factory: Arc<
+        dyn WriterFactory<Input = Arc<MicroPartition>, Result = Option<Table>>,
+    >,
+    file_info: &OutputFileInfo,
+    cfg: &DaftExecutionConfig,
+) -> Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<Table>>> {
     match file_info.file_format {
         FileFormat::Parquet => {
             let file_size_calculator = TargetInMemorySizeBytesCalculator::new(
@@ -83,7 +157,7 @@ pub fn make_physical_writer_factory(
                 cfg.parquet_inflation_factor,
             );
             let row_group_writer_factory = TargetBatchWriterFactory::new(
-                Arc::new(base_writer_factory),
+                base_writer_factory,
                 Arc::new(row_group_size_calculator),
             );
             let file_writer_factory = TargetFileSizeWriterFactory::new(
@@ -108,7 +182,7 @@ pub fn make_physical_writer_factory(
             );
 
             let file_writer_factory = TargetFileSizeWriterFactory::new(
-                Arc::new(base_writer_factory),
+                base_writer_factory,
                 Arc::new(file_size_calculator),
             );
 
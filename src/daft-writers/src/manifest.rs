@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{AsArrow, Schema, UInt64Array},
+    series::IntoSeries,
+};
+use daft_io::{get_io_client, IOConfig};
+use daft_logical_plan::sink_info::{MANIFEST_NUM_ROWS, MANIFEST_SIZE_BYTES};
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+use futures::StreamExt;
+
+use crate::{FileWriter, WriterFactory};
+
+/// ManifestWriter wraps the writer of a single file, and adds the number of rows written to the
+/// file and the file's size to the result of the writer.
+struct ManifestWriter {
+    writer: Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Option<Table>>>,
+    num_rows: usize,
+    io_config: Option<IOConfig>,
+}
+
+impl FileWriter for ManifestWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<Table>;
+
+    fn write(&mut self, input: Self::Input) -> DaftResult<usize> {
+        self.num_rows += input.len();
+        self.writer.write(input)
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.writer.bytes_written()
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        let Some(result) = self.writer.close()? else {
+            return Ok(None);
+        };
+        let num_rows = UInt64Array::from_values(
+            MANIFEST_NUM_ROWS,
+            std::iter::repeat(self.num_rows as u64).take(result.len()),
+        )
+        .into_series();
+        // The bytes counted by the writer miss what it writes when it closes the file, such as a
+        // parquet footer, so the size is read back from the written file
+        let size_bytes = UInt64Array::from_values(
+            MANIFEST_SIZE_BYTES,
+            written_file_sizes(&result, self.io_config.as_ref())?,
+        )
+        .into_series();
+        let stats = Table::new_with_size(
+            Schema::new(vec![num_rows.field().clone(), size_bytes.field().clone()])?,
+            vec![num_rows, size_bytes],
+            result.len(),
+        )?;
+        Ok(Some(result.union(&stats)?))
+    }
+}
+
+/// The sizes of the files named by the `path` column of a writer's result
+fn written_file_sizes(result: &Table, io_config: Option<&IOConfig>) -> DaftResult<Vec<u64>> {
+    let paths = result
+        .get_column("path")?
+        .utf8()?
+        .as_arrow()
+        .values_iter()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    let io_client = get_io_client(true, Arc::new(io_config.cloned().unwrap_or_default()))?;
+    common_runtime::get_io_runtime(true).block_on_current_thread(async move {
+        let mut sizes = Vec::with_capacity(paths.len());
+        for path in paths {
+            sizes.push(io_client.single_url_get_size(path, None).await? as u64);
+        }
+        Ok(sizes)
+    })
+}
+
+/// ManifestWriterFactory creates writers whose results describe the written files, for COPY INTO
+/// exports.
+pub(crate) struct ManifestWriterFactory {
+    writer_factory: Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Option<Table>>>,
+    io_config: Option<IOConfig>,
+}
+
+impl ManifestWriterFactory {
+    pub(crate) fn new(
+        writer_factory: Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Option<Table>>>,
+        io_config: Option<IOConfig>,
+    ) -> Self {
+        Self {
+            writer_factory,
+            io_config,
+        }
+    }
+}
+
+impl WriterFactory for ManifestWriterFactory {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<Table>;
+
+    fn create_writer(
+        &self,
+        file_idx: usize,
+        partition_values: Option<&Table>,
+    ) -> DaftResult<Box<dyn FileWriter<Input = Self::Input, Result = Self::Result>>> {
+        let writer = self
+            .writer_factory
+            .create_writer(file_idx, partition_values)?;
+        Ok(Box::new(ManifestWriter {
+            writer,
+            num_rows: 0,
+            io_config: self.io_config.clone(),
+        }))
+    }
+}
+
+/// Returns an error if there are files under `root_dir`, for exports that must not mix their
+/// files with existing ones.
+pub(crate) fn ensure_location_is_empty(
+    root_dir: &str,
+    io_config: Option<&IOConfig>,
+) -> DaftResult<()> {
+    let io_client = get_io_client(true, Arc::new(io_config.cloned().unwrap_or_default()))?;
+    let glob_path = format!("{}/**", root_dir.trim_end_matches('/'));
+    let first_file = common_runtime::get_io_runtime(true).block_on_current_thread(async move {
+        let mut files = io_client
+            .glob(glob_path, None, None, Some(1), None, None)
+            .await?;
+        files.next().await.transpose()
+    });
+    match first_file {
+        Ok(None) | Err(daft_io::Error::NotFound { .. }) => Ok(()),
+        Ok(Some(file)) => Err(DaftError::ValueError(format!(
+            "Cannot write to {root_dir}, which already contains files such as {}",
+            file.filepath
+        ))),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use daft_core::prelude::Utf8Array;
+
+    use super::*;
+    use crate::test::make_dummy_mp;
+
+    /// Writes a byte per row to a file in `dir`, and a footer when the file is closed
+    struct FooterWriterFactory {
+        dir: std::path::PathBuf,
+    }
+
+    struct FooterWriter {
+        path: std::path::PathBuf,
+        file: std::fs::File,
+        bytes_written: usize,
+    }
+
+    impl WriterFactory for FooterWriterFactory {
+        type Input = Arc<MicroPartition>;
+        type Result = Option<Table>;
+
+        fn create_writer(
+            &self,
+            file_idx: usize,
+            _partition_values: Option<&Table>,
+        ) -> DaftResult<Box<dyn FileWriter<Input = Self::Input, Result = Self::Result>>> {
+            let path = self.dir.join(format!("{file_idx}.bin"));
+            Ok(Box::new(FooterWriter {
+                file: std::fs::File::create(&path)?,
+                path,
+                bytes_written: 0,
+            }))
+        }
+    }
+
+    impl FileWriter for FooterWriter {
+        type Input = Arc<MicroPartition>;
+        type Result = Option<Table>;
+
+        fn write(&mut self, input: Self::Input) -> DaftResult<usize> {
+            self.file.write_all(&vec![0; input.len()])?;
+            self.bytes_written += input.len();
+            Ok(input.len())
+        }
+
+        fn bytes_written(&self) -> usize {
+            self.bytes_written
+        }
+
+        fn close(&mut self) -> DaftResult<Self::Result> {
+            self.file.write_all(b"footer")?;
+            self.file.flush()?;
+            let path = self.path.to_string_lossy().to_string();
+            let path = Utf8Array::from_values("path", std::iter::once(path.as_str())).into_series();
+            Ok(Some(Table::from_nonempty_columns(vec![path])?))
+        }
+    }
+
+    #[test]
+    fn test_manifest_writer_counts_rows_and_file_size() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let factory = ManifestWriterFactory::new(
+            Arc::new(FooterWriterFactory {
+                dir: dir.path().to_path_buf(),
+            }),
+            None,
+        );
+        let mut writer = factory.create_writer(0, None)?;
+
+        writer.write(make_dummy_mp(2))?;
+        writer.write(make_dummy_mp(3))?;
+        let res = writer.close()?.unwrap();
+
+        let get_stat = |name| res.get_column(name).unwrap().u64().unwrap().get(0).unwrap();
+        assert_eq!(get_stat(MANIFEST_NUM_ROWS), 5);
+        // the size includes the footer written when the file was closed
+        assert_eq!(get_stat(MANIFEST_SIZE_BYTES), 5 + b"footer".len() as u64);
+        assert!(res.get_column("path").is_ok());
+        Ok(())
+    }
+}
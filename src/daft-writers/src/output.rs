@@ -0,0 +1,176 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use common_error::DaftResult;
+use daft_core::{prelude::Utf8Array, series::IntoSeries};
+use daft_io::{get_io_client, parse_url, IOConfig, IOStatsContext, SourceType};
+use daft_logical_plan::WriteAttempt;
+use daft_table::Table;
+
+/// Directory name of the partition of rows whose partition value is null
+pub(crate) const PARTITION_NULL_FALLBACK: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Where the bytes of a file go: local files are streamed to disk, while files in object stores are
+/// buffered and uploaded when the file is closed.
+pub(crate) enum FileSink {
+    Local(BufWriter<File>),
+    Remote(Vec<u8>),
+}
+
+impl Write for FileSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Local(file) => file.write(buf),
+            Self::Remote(buffer) => buffer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Local(file) => file.flush(),
+            Self::Remote(buffer) => buffer.flush(),
+        }
+    }
+}
+
+/// Counts the bytes written to a sink, which may be owned by a format's writer
+pub(crate) struct CountingSink {
+    sink: FileSink,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+impl Write for CountingSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.sink.write(buf)?;
+        self.bytes_written.fetch_add(written, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+/// A file written by a native writer under `{root_dir}/{partition dirs}/`, along with the table of
+/// its path and partition values that the writer returns when it's closed.
+pub(crate) struct OutputFile {
+    path: String,
+    is_local: bool,
+    io_config: Option<IOConfig>,
+    partition_values: Option<Table>,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+impl OutputFile {
+    pub fn new(
+        root_dir: &str,
+        file_idx: usize,
+        extension: &str,
+        io_config: &Option<IOConfig>,
+        partition_values: Option<&Table>,
+        write_attempt: &Option<WriteAttempt>,
+    ) -> DaftResult<Self> {
+        let (source_type, _) = parse_url(root_dir)?;
+        let is_local = source_type == SourceType::File;
+        let root_dir = if is_local {
+            let root_dir = root_dir.strip_prefix("file://").unwrap_or(root_dir);
+            std::path::absolute(root_dir)?.to_string_lossy().to_string()
+        } else {
+            root_dir.to_string()
+        };
+        let dir = match partition_values {
+            Some(partition_values) => partition_dir(&root_dir, partition_values)?,
+            None => root_dir.trim_end_matches('/').to_string(),
+        };
+        let staged_prefix = write_attempt
+            .as_ref()
+            .map(WriteAttempt::staged_file_prefix)
+            .unwrap_or_default();
+        let path = format!(
+            "{dir}/{staged_prefix}{}-{file_idx}.{extension}",
+            uuid::Uuid::new_v4()
+        );
+        Ok(Self {
+            path,
+            is_local,
+            io_config: io_config.clone(),
+            partition_values: partition_values.cloned(),
+            bytes_written: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// Creates the file, or the buffer of a remote file
+    pub fn create_sink(&self) -> DaftResult<CountingSink> {
+        let sink = if self.is_local {
+            let path = Path::new(&self.path);
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            FileSink::Local(BufWriter::new(File::create(path)?))
+        } else {
+            FileSink::Remote(Vec::new())
+        };
+        Ok(CountingSink {
+            sink,
+            bytes_written: self.bytes_written.clone(),
+        })
+    }
+
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+
+    /// Flushes a local file or uploads a remote one, returning the table of the file's path and
+    /// partition values.
+    pub fn finish(&self, sink: CountingSink) -> DaftResult<Table> {
+        match sink.sink {
+            FileSink::Local(mut file) => file.flush()?,
+            FileSink::Remote(buffer) => self.upload(buffer)?,
+        }
+        self.result()
+    }
+
+    fn upload(&self, data: Vec<u8>) -> DaftResult<()> {
+        let io_client = get_io_client(true, Arc::new(self.io_config.clone().unwrap_or_default()))?;
+        let io_stats = IOStatsContext::new(format!("OutputFile::upload: {}", self.path));
+        let path = self.path.clone();
+        common_runtime::get_io_runtime(true).block_on_current_thread(async move {
+            io_client
+                .single_url_put(&path, data.into(), Some(io_stats))
+                .await
+        })?;
+        Ok(())
+    }
+
+    fn result(&self) -> DaftResult<Table> {
+        let path =
+            Utf8Array::from_values("path", std::iter::once(self.path.as_str())).into_series();
+        let path_table = Table::from_nonempty_columns(vec![path])?;
+        match &self.partition_values {
+            Some(partition_values) => path_table.union(partition_values),
+            None => Ok(path_table),
+        }
+    }
+}
+
+/// The directory of the files of a partition, `{root_dir}/{key}={value}/...`
+fn partition_dir(root_dir: &str, partition_values: &Table) -> DaftResult<String> {
+    let mut dir = root_dir.trim_end_matches('/').to_string();
+    for idx in 0..partition_values.num_columns() {
+        let column = partition_values.get_column_by_index(idx)?;
+        let value = if column.is_valid(0) {
+            column.to_str_values()?.utf8()?.get(0).unwrap().to_string()
+        } else {
+            PARTITION_NULL_FALLBACK.to_string()
+        };
+        dir = format!("{dir}/{}={value}", column.name());
+    }
+    Ok(dir)
+}
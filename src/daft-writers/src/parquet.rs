@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use arrow2::{
+    chunk::Chunk,
+    io::parquet::write::{
+        transverse, CompressionOptions, Encoding, FileWriter as ArrowFileWriter, RowGroupIterator,
+        Version, WriteOptions,
+    },
+};
+use common_error::{DaftError, DaftResult};
+use daft_io::{IOConfig, IOStatsContext};
+use daft_logical_plan::WriteAttempt;
+use daft_micropartition::MicroPartition;
+use daft_table::Table;
+
+use crate::{
+    output::{CountingSink, OutputFile},
+    FileWriter,
+};
+
+/// NativeParquetWriter writes a parquet file with arrow2, without going through Python. Each write
+/// is written as one row group, so row groups are sized by the batching of the writers above it,
+/// and every column chunk has statistics.
+pub(crate) struct NativeParquetWriter {
+    file: OutputFile,
+    options: WriteOptions,
+    writer: Option<ArrowFileWriter<CountingSink>>,
+    is_closed: bool,
+}
+
+impl NativeParquetWriter {
+    pub(crate) fn new(
+        root_dir: &str,
+        file_idx: usize,
+        compression: &Option<String>,
+        io_config: &Option<IOConfig>,
+        partition_values: Option<&Table>,
+        write_attempt: &Option<WriteAttempt>,
+    ) -> DaftResult<Self> {
+        Ok(Self {
+            file: OutputFile::new(
+                root_dir,
+                file_idx,
+                "parquet",
+                io_config,
+                partition_values,
+                write_attempt,
+            )?,
+            options: WriteOptions {
+                write_statistics: true,
+                version: Version::V2,
+                compression: compression_options(compression.as_deref())?,
+                data_pagesize_limit: None,
+            },
+            writer: None,
+            is_closed: false,
+        })
+    }
+}
+
+impl FileWriter for NativeParquetWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<Table>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        assert!(
+            !self.is_closed,
+            "Cannot write to a closed NativeParquetWriter"
+        );
+        let tables = data.concat_or_get(IOStatsContext::new("NativeParquetWriter::write"))?;
+        let Some(table) = tables.first() else {
+            return Ok(0);
+        };
+        let schema = table.schema.to_arrow()?;
+        if self.writer.is_none() {
+            let sink = self.file.create_sink()?;
+            self.writer = Some(ArrowFileWriter::try_new(
+                sink,
+                schema.clone(),
+                self.options,
+            )?);
+        }
+        let writer = self.writer.as_mut().unwrap();
+
+        let bytes_before = self.file.bytes_written();
+        let encodings = schema
+            .fields
+            .iter()
+            .map(|field| transverse(&field.data_type, |_| Encoding::Plain))
+            .collect();
+        let chunk = Chunk::new(table.get_inner_arrow_arrays().collect());
+        let row_groups = RowGroupIterator::try_new(
+            std::iter::once(Ok(chunk)),
+            &schema,
+            self.options,
+            encodings,
+        )?;
+        for row_group in row_groups {
+            writer.write(row_group?)?;
+        }
+        Ok(self.file.bytes_written() - bytes_before)
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.file.bytes_written()
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        self.is_closed = true;
+        let Some(mut writer) = self.writer.take() else {
+            return Ok(None);
+        };
+        writer.end(None)?;
+        Ok(Some(self.file.finish(writer.into_inner())?))
+    }
+}
+
+fn compression_options(compression: Option<&str>) -> DaftResult<CompressionOptions> {
+    match compression.map(str::to_lowercase).as_deref() {
+        None | Some("none" | "uncompressed") => Ok(CompressionOptions::Uncompressed),
+        Some("snappy") => Ok(CompressionOptions::Snappy),
+        Some("gzip") => Ok(CompressionOptions::Gzip(None)),
+        Some("lz4") => Ok(CompressionOptions::Lz4Raw),
+        Some("zstd") => Ok(CompressionOptions::Zstd(None)),
+        Some("brotli") => Ok(CompressionOptions::Brotli(None)),
+        Some(other) => Err(DaftError::ValueError(format!(
+            "Unsupported parquet compression: {other}, expected one of: none, snappy, gzip, lz4, zstd, brotli"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+
+    use super::*;
+    use crate::output::PARTITION_NULL_FALLBACK;
+
+    #[test]
+    fn test_native_parquet_writer_writes_partitions() -> DaftResult<()> {
+        let root_dir = std::env::temp_dir().join(format!("daft-writers-{}", uuid::Uuid::new_v4()));
+        let root_dir = root_dir.to_string_lossy().to_string();
+        let partition_values = Table::from_nonempty_columns(vec![
+            Utf8Array::from(("a", ["x"].as_slice())).into_series(),
+            Series::full_null("b", &DataType::Int64, 1),
+        ])?;
+        let mut writer = NativeParquetWriter::new(
+            &root_dir,
+            0,
+            &Some("zstd".to_string()),
+            &None,
+            Some(&partition_values),
+            &Some(WriteAttempt::new("job".to_string())),
+        )?;
+
+        let data = Int64Array::from(("c", vec![1, 2, 3])).into_series();
+        let table = Table::from_nonempty_columns(vec![data])?;
+        let bytes_written = writer.write(Arc::new(MicroPartition::new_loaded(
+            table.schema.clone(),
+            Arc::new(vec![table]),
+            None,
+        )))?;
+        assert!(bytes_written > 0);
+        assert_eq!(writer.bytes_written(), bytes_written);
+
+        let result = writer.close()?.unwrap();
+        let path = result
+            .get_column("path")?
+            .utf8()?
+            .get(0)
+            .unwrap()
+            .to_string();
+        assert!(path.starts_with(&format!(
+            "{root_dir}/a=x/b={PARTITION_NULL_FALLBACK}/_staged-job-"
+        )));
+        assert!(path.ends_with("-0.parquet"));
+        assert!(result.get_column("a").is_ok());
+        // the footer is written when the file is closed
+        assert!(std::fs::metadata(&path)?.len() as usize > bytes_written);
+
+        std::fs::remove_dir_all(&root_dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_compression_options() {
+        assert_eq!(
+            compression_options(Some("SNAPPY")).unwrap(),
+            CompressionOptions::Snappy
+        );
+        assert_eq!(
+            compression_options(None).unwrap(),
+            CompressionOptions::Uncompressed
+        );
+        assert!(compression_options(Some("lzo")).is_err());
+    }
+}
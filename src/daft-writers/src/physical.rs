@@ -1,24 +1,35 @@
 use std::sync::Arc;
 
+use common_daft_config::DaftExecutionConfig;
 use common_error::{DaftError, DaftResult};
-use common_file_formats::FileFormat;
-use daft_logical_plan::OutputFileInfo;
+use common_file_formats::{CsvWriteOptions, FileFormat};
+use daft_dsl::functions::python::RuntimePyObject;
+use daft_logical_plan::{OutputFileInfo, WriteAttempt};
 use daft_micropartition::MicroPartition;
 use daft_table::Table;
 
-use crate::{FileWriter, WriterFactory};
+use crate::{csv::NativeCsvWriter, parquet::NativeParquetWriter, FileWriter, WriterFactory};
 
 /// PhysicalWriterFactory is a factory for creating physical writers, i.e. parquet, csv writers.
 pub struct PhysicalWriterFactory {
     output_file_info: OutputFileInfo,
-    native: bool, // TODO: Implement native writer
+    native: bool,
 }
 
 impl PhysicalWriterFactory {
-    pub fn new(output_file_info: OutputFileInfo) -> Self {
+    pub fn new(output_file_info: OutputFileInfo, cfg: &DaftExecutionConfig) -> Self {
+        let native = match output_file_info.file_format {
+            // Parquet modular encryption is only supported by PyArrow
+            FileFormat::Parquet => {
+                cfg.native_parquet_writer && output_file_info.parquet_encryption.is_none()
+            }
+            // Without Python, CSV files can only be written natively
+            FileFormat::Csv => cfg.native_csv_writer || cfg!(not(feature = "python")),
+            _ => false,
+        };
         Self {
             output_file_info,
-            native: false,
+            native,
         }
     }
 }
@@ -32,16 +43,34 @@ impl WriterFactory for PhysicalWriterFactory {
         file_idx: usize,
         partition_values: Option<&Table>,
     ) -> DaftResult<Box<dyn FileWriter<Input = Self::Input, Result = Self::Result>>> {
-        match self.native {
-            true => unimplemented!(),
-            false => {
+        match (self.native, self.output_file_info.file_format) {
+            (true, FileFormat::Parquet) => Ok(Box::new(NativeParquetWriter::new(
+                &self.output_file_info.root_dir,
+                file_idx,
+                &self.output_file_info.compression,
+                &self.output_file_info.io_config,
+                partition_values,
+                &self.output_file_info.write_attempt,
+            )?)),
+            (true, FileFormat::Csv) => Ok(Box::new(NativeCsvWriter::new(
+                &self.output_file_info.root_dir,
+                file_idx,
+                &self.output_file_info.csv_options,
+                &self.output_file_info.io_config,
+                partition_values,
+                &self.output_file_info.write_attempt,
+            )?)),
+            _ => {
                 let writer = create_pyarrow_file_writer(
                     &self.output_file_info.root_dir,
                     file_idx,
                     &self.output_file_info.compression,
                     &self.output_file_info.io_config,
+                    &self.output_file_info.parquet_encryption,
+                    &self.output_file_info.csv_options,
                     self.output_file_info.file_format,
                     partition_values,
+                    &self.output_file_info.write_attempt,
                 )?;
                 Ok(writer)
             }
@@ -49,13 +78,17 @@ impl WriterFactory for PhysicalWriterFactory {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_pyarrow_file_writer(
     root_dir: &str,
     file_idx: usize,
     compression: &Option<String>,
     io_config: &Option<daft_io::IOConfig>,
+    parquet_encryption: &Option<RuntimePyObject>,
+    csv_options: &Option<CsvWriteOptions>,
     format: FileFormat,
     partition: Option<&Table>,
+    write_attempt: &Option<WriteAttempt>,
 ) -> DaftResult<Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Option<Table>>>> {
     match format {
         #[cfg(feature = "python")]
@@ -64,11 +97,18 @@ pub fn create_pyarrow_file_writer(
             file_idx,
             compression,
             io_config,
+            parquet_encryption,
             partition,
+            write_attempt,
         )?)),
         #[cfg(feature = "python")]
         FileFormat::Csv => Ok(Box::new(crate::pyarrow::PyArrowWriter::new_csv_writer(
-            root_dir, file_idx, io_config, partition,
+            root_dir,
+            file_idx,
+            csv_options,
+            io_config,
+            partition,
+            write_attempt,
         )?)),
         _ => Err(DaftError::ComputeError(
             "Unsupported file format for physical write".to_string(),
@@ -1,9 +1,15 @@
 use std::sync::Arc;
 
 use common_error::DaftResult;
+use common_file_formats::CsvWriteOptions;
+use daft_dsl::functions::python::RuntimePyObject;
+use daft_logical_plan::WriteAttempt;
 use daft_micropartition::{python::PyMicroPartition, MicroPartition};
 use daft_table::{python::PyTable, Table};
-use pyo3::{types::PyAnyMethods, PyObject, Python};
+use pyo3::{
+    types::{PyAnyMethods, PyDict, PyDictMethods},
+    Bound, PyObject, PyResult, Python,
+};
 
 use crate::FileWriter;
 
@@ -19,7 +25,9 @@ impl PyArrowWriter {
         file_idx: usize,
         compression: &Option<String>,
         io_config: &Option<daft_io::IOConfig>,
+        parquet_encryption: &Option<RuntimePyObject>,
         partition_values: Option<&Table>,
+        write_attempt: &Option<WriteAttempt>,
     ) -> DaftResult<Self> {
         Python::with_gil(|py| {
             let file_writer_module = py.import_bound(pyo3::intern!(py, "daft.io.writer"))?;
@@ -36,15 +44,26 @@ impl PyArrowWriter {
                 None => None,
             };
 
-            let py_writer = file_writer_class.call1((
-                root_dir,
-                file_idx,
-                partition_values,
-                compression.as_ref().map(|c| c.as_str()),
-                io_config.as_ref().map(|cfg| daft_io::python::IOConfig {
-                    config: cfg.clone(),
-                }),
-            ))?;
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item(
+                pyo3::intern!(py, "encryption"),
+                parquet_encryption
+                    .as_ref()
+                    .map(|encryption| encryption.as_ref().clone_ref(py)),
+            )?;
+            set_write_attempt(&kwargs, write_attempt)?;
+            let py_writer = file_writer_class.call(
+                (
+                    root_dir,
+                    file_idx,
+                    partition_values,
+                    compression.as_ref().map(|c| c.as_str()),
+                    io_config.as_ref().map(|cfg| daft_io::python::IOConfig {
+                        config: cfg.clone(),
+                    }),
+                ),
+                Some(&kwargs),
+            )?;
             Ok(Self {
                 py_writer: py_writer.into(),
                 is_closed: false,
@@ -56,8 +75,10 @@ impl PyArrowWriter {
     pub fn new_csv_writer(
         root_dir: &str,
         file_idx: usize,
+        csv_options: &Option<CsvWriteOptions>,
         io_config: &Option<daft_io::IOConfig>,
         partition_values: Option<&Table>,
+        write_attempt: &Option<WriteAttempt>,
     ) -> DaftResult<Self> {
         Python::with_gil(|py| {
             let file_writer_module = py.import_bound(pyo3::intern!(py, "daft.io.writer"))?;
@@ -73,14 +94,20 @@ impl PyArrowWriter {
                 }
                 None => None,
             };
-            let py_writer = file_writer_class.call1((
-                root_dir,
-                file_idx,
-                partition_values,
-                io_config.as_ref().map(|cfg| daft_io::python::IOConfig {
-                    config: cfg.clone(),
-                }),
-            ))?;
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item(pyo3::intern!(py, "csv_options"), csv_options.clone())?;
+            set_write_attempt(&kwargs, write_attempt)?;
+            let py_writer = file_writer_class.call(
+                (
+                    root_dir,
+                    file_idx,
+                    partition_values,
+                    io_config.as_ref().map(|cfg| daft_io::python::IOConfig {
+                        config: cfg.clone(),
+                    }),
+                ),
+                Some(&kwargs),
+            )?;
             Ok(Self {
                 py_writer: py_writer.into(),
                 is_closed: false,
@@ -172,6 +199,20 @@ impl PyArrowWriter {
     }
 }
 
+/// Passes the job and attempt of a retry-safe write to a Python file writer, which then writes the
+/// file under a staging name.
+fn set_write_attempt(
+    kwargs: &Bound<'_, PyDict>,
+    write_attempt: &Option<WriteAttempt>,
+) -> PyResult<()> {
+    if let Some(write_attempt) = write_attempt {
+        let py = kwargs.py();
+        kwargs.set_item(pyo3::intern!(py, "job_id"), &write_attempt.job_id)?;
+        kwargs.set_item(pyo3::intern!(py, "attempt_id"), &write_attempt.attempt_id)?;
+    }
+    Ok(())
+}
+
 impl FileWriter for PyArrowWriter {
     type Input = Arc<MicroPartition>;
     type Result = Option<Table>;
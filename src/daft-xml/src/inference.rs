@@ -0,0 +1,191 @@
+use daft_core::prelude::*;
+
+use crate::parse::XmlElement;
+
+/// Infers the fields of rows. Columns that are empty in every row are read as strings.
+pub(crate) fn infer_fields(rows: &[XmlElement], value_tag: &str) -> Vec<Field> {
+    let dtype = rows
+        .iter()
+        .map(|row| infer_struct(row, value_tag))
+        .reduce(|a, b| merge(a, b, value_tag))
+        .unwrap_or(DataType::Struct(vec![]));
+    match null_to_utf8(dtype) {
+        DataType::Struct(fields) => fields,
+        _ => unreachable!("rows are inferred as structs"),
+    }
+}
+
+/// Infers the dtype of a struct with a field per child of the element, or with just the text of the
+/// element if it doesn't have any children.
+fn infer_struct(element: &XmlElement, value_tag: &str) -> DataType {
+    if element.is_leaf() {
+        let fields = element
+            .text
+            .as_deref()
+            .map(|text| Field::new(value_tag, infer_text(text)))
+            .into_iter()
+            .collect();
+        return DataType::Struct(fields);
+    }
+    let mut fields: Vec<(&str, DataType, usize)> = vec![];
+    for (name, child) in &element.children {
+        let dtype = infer_element(child, value_tag);
+        match fields
+            .iter_mut()
+            .find(|(field, ..)| *field == name.as_str())
+        {
+            Some((_, field_dtype, count)) => {
+                *field_dtype = merge(
+                    std::mem::replace(field_dtype, DataType::Null),
+                    dtype,
+                    value_tag,
+                );
+                *count += 1;
+            }
+            None => fields.push((name.as_str(), dtype, 1)),
+        }
+    }
+    DataType::Struct(
+        fields
+            .into_iter()
+            .map(|(name, dtype, count)| match count {
+                1 => Field::new(name, dtype),
+                _ => Field::new(name, DataType::List(Box::new(dtype))),
+            })
+            .collect(),
+    )
+}
+
+fn infer_element(element: &XmlElement, value_tag: &str) -> DataType {
+    if element.is_leaf() {
+        element.text.as_deref().map_or(DataType::Null, infer_text)
+    } else {
+        infer_struct(element, value_tag)
+    }
+}
+
+fn infer_text(text: &str) -> DataType {
+    if text.eq_ignore_ascii_case("true") || text.eq_ignore_ascii_case("false") {
+        DataType::Boolean
+    } else if text.parse::<i64>().is_ok() {
+        DataType::Int64
+    } else if text.bytes().any(|b| b.is_ascii_digit()) && text.parse::<f64>().is_ok() {
+        DataType::Float64
+    } else {
+        DataType::Utf8
+    }
+}
+
+/// The dtype of values of both dtypes. A value is merged into a list as one of its items, and into a
+/// struct as its field named by the value tag.
+fn merge(a: DataType, b: DataType, value_tag: &str) -> DataType {
+    match (a, b) {
+        (a, b) if a == b => a,
+        (DataType::Null, other) | (other, DataType::Null) => other,
+        (DataType::Int64, DataType::Float64) | (DataType::Float64, DataType::Int64) => {
+            DataType::Float64
+        }
+        (DataType::List(a), DataType::List(b)) => {
+            DataType::List(Box::new(merge(*a, *b, value_tag)))
+        }
+        (DataType::List(a), other) | (other, DataType::List(a)) => {
+            DataType::List(Box::new(merge(*a, other, value_tag)))
+        }
+        (DataType::Struct(a), DataType::Struct(b)) => {
+            DataType::Struct(merge_fields(a, b, value_tag))
+        }
+        (DataType::Struct(fields), other) | (other, DataType::Struct(fields)) => DataType::Struct(
+            merge_fields(fields, vec![Field::new(value_tag, other)], value_tag),
+        ),
+        _ => DataType::Utf8,
+    }
+}
+
+fn merge_fields(mut a: Vec<Field>, b: Vec<Field>, value_tag: &str) -> Vec<Field> {
+    for field in b {
+        match a.iter_mut().find(|f| f.name == field.name) {
+            Some(f) => {
+                f.dtype = merge(
+                    std::mem::replace(&mut f.dtype, DataType::Null),
+                    field.dtype,
+                    value_tag,
+                );
+            }
+            None => a.push(field),
+        }
+    }
+    a
+}
+
+fn null_to_utf8(dtype: DataType) -> DataType {
+    match dtype {
+        DataType::Null => DataType::Utf8,
+        DataType::List(child) => DataType::List(Box::new(null_to_utf8(*child))),
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .into_iter()
+                .map(|field| Field::new(field.name, null_to_utf8(field.dtype)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_file_formats::XmlSourceConfig;
+    use daft_core::prelude::*;
+
+    use super::infer_fields;
+    use crate::parse::parse_rows;
+
+    fn infer(xml: &str) -> Vec<Field> {
+        let config = XmlSourceConfig::new_internal("row".to_string(), None, None);
+        let rows = parse_rows(xml.as_bytes(), "test.xml", &config, None).unwrap();
+        infer_fields(&rows, &config.value_tag)
+    }
+
+    #[test]
+    fn test_infer_scalars() {
+        let fields = infer(
+            "<rows>
+              <row><a>1</a><b>1.5</b><c>true</c><d>x</d><e/></row>
+              <row><a>2</a><b>2</b><c>FALSE</c><d>3</d></row>
+            </rows>",
+        );
+        assert_eq!(
+            fields,
+            vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Float64),
+                Field::new("c", DataType::Boolean),
+                Field::new("d", DataType::Utf8),
+                Field::new("e", DataType::Utf8),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_infer_nested() {
+        let fields = infer(
+            r#"<rows>
+              <row id="1"><tag>a</tag><tag>b</tag><size unit="cm">3</size></row>
+              <row id="2"><tag>c</tag><size>4</size></row>
+            </rows>"#,
+        );
+        assert_eq!(
+            fields,
+            vec![
+                Field::new("_id", DataType::Int64),
+                Field::new("tag", DataType::List(Box::new(DataType::Utf8))),
+                Field::new(
+                    "size",
+                    DataType::Struct(vec![
+                        Field::new("_unit", DataType::Utf8),
+                        Field::new("_VALUE", DataType::Int64),
+                    ])
+                ),
+            ]
+        );
+    }
+}
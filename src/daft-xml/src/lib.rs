@@ -0,0 +1,70 @@
+//! Reads of XML files, where each element with the row tag of the read is a row.
+//!
+//! The children of a row element are its columns, and so are its attributes, whose columns are
+//! named by the attribute prefix of the read followed by the name of the attribute. Children that
+//! have children or attributes of their own are read as structs in the same way, and children that
+//! occur more than once in an element are read as lists. The text of an element that also has
+//! children or attributes is read into a field named by the value tag of the read.
+//!
+//! For example, with the row tag `book`,
+//!
+//! ```xml
+//! <catalog>
+//!   <book id="1">
+//!     <title lang="en">Dune</title>
+//!     <author>Frank Herbert</author>
+//!     <year>1965</year>
+//!   </book>
+//! </catalog>
+//! ```
+//!
+//! is read as a row with an `_id` column of integers, a `title` column of structs with `_lang` and
+//! `_VALUE` fields, an `author` column of strings and a `year` column of integers.
+
+mod inference;
+mod parse;
+mod read;
+
+use common_error::DaftError;
+pub use read::{read_xml, read_xml_bulk, read_xml_schema};
+use snafu::Snafu;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+
+    #[snafu(display("{source}"))]
+    StdIOError { source: std::io::Error },
+
+    #[snafu(display("Error parsing XML file {} at byte {}: {}", path, position, source))]
+    XmlParse {
+        path: String,
+        position: usize,
+        source: quick_xml::Error,
+    },
+
+    #[snafu(display(
+        "Cannot infer the schema of XML file {} without any <{}> elements",
+        path,
+        row_tag
+    ))]
+    NoRows { path: String, row_tag: String },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => Self::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Self::IOError { source: err }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
@@ -0,0 +1,157 @@
+use common_file_formats::XmlSourceConfig;
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use crate::{Error, Result};
+
+/// An element of an XML document. Its attributes are children without children or text of their
+/// own, named by the attribute prefix followed by the name of the attribute, and if it has both text
+/// and children, its text is also a child named by the value tag.
+#[derive(Debug, Default)]
+pub(crate) struct XmlElement {
+    pub children: Vec<(String, XmlElement)>,
+    pub text: Option<String>,
+}
+
+impl XmlElement {
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Self> {
+        self.children
+            .iter()
+            .filter(move |(child_name, _)| child_name == name)
+            .map(|(_, child)| child)
+    }
+}
+
+/// Parses the elements with the row tag of the config out of an XML document, or the first `limit`
+/// of them. Elements with the row tag inside of other rows are part of those rows.
+pub(crate) fn parse_rows(
+    bytes: &[u8],
+    path: &str,
+    config: &XmlSourceConfig,
+    limit: Option<usize>,
+) -> Result<Vec<XmlElement>> {
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut parser = RowParser {
+        reader: Reader::from_reader(bytes),
+        path,
+        config,
+    };
+    parser.reader.trim_text(true);
+
+    let mut rows = vec![];
+    while rows.len() < limit {
+        match parser.next_event()? {
+            Event::Start(start) if start.name().as_ref() == config.row_tag.as_bytes() => {
+                rows.push(parser.parse_element(&start)?);
+            }
+            Event::Empty(start) if start.name().as_ref() == config.row_tag.as_bytes() => {
+                rows.push(XmlElement {
+                    children: parser.parse_attributes(&start)?,
+                    text: None,
+                });
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(rows)
+}
+
+struct RowParser<'a> {
+    reader: Reader<&'a [u8]>,
+    path: &'a str,
+    config: &'a XmlSourceConfig,
+}
+
+impl<'a> RowParser<'a> {
+    fn next_event(&mut self) -> Result<Event<'a>> {
+        self.reader.read_event().map_err(|e| self.error(e))
+    }
+
+    fn error(&self, source: quick_xml::Error) -> Error {
+        Error::XmlParse {
+            path: self.path.to_string(),
+            position: self.reader.buffer_position(),
+            source,
+        }
+    }
+
+    /// Parses the rest of an element, up to and including its end tag.
+    fn parse_element(&mut self, start: &BytesStart) -> Result<XmlElement> {
+        let mut children = self.parse_attributes(start)?;
+        let mut text: Option<String> = None;
+        loop {
+            match self.next_event()? {
+                Event::Start(child) => {
+                    let element = self.parse_element(&child)?;
+                    children.push((tag_name(&child), element));
+                }
+                Event::Empty(child) => {
+                    let element = XmlElement {
+                        children: self.parse_attributes(&child)?,
+                        text: None,
+                    };
+                    children.push((tag_name(&child), element));
+                }
+                Event::Text(t) => {
+                    let t = t.unescape().map_err(|e| self.error(e))?;
+                    text.get_or_insert_with(String::new).push_str(&t);
+                }
+                Event::CData(data) => {
+                    let data = data.into_inner();
+                    text.get_or_insert_with(String::new)
+                        .push_str(&String::from_utf8_lossy(&data));
+                }
+                Event::End(_) => break,
+                Event::Eof => {
+                    return Err(self.error(quick_xml::Error::UnexpectedEof(format!(
+                        "</{}>",
+                        tag_name(start)
+                    ))))
+                }
+                _ => {}
+            }
+        }
+        if text.is_some() && !children.is_empty() {
+            children.push((
+                self.config.value_tag.clone(),
+                XmlElement {
+                    children: vec![],
+                    text: text.clone(),
+                },
+            ));
+        }
+        Ok(XmlElement { children, text })
+    }
+
+    /// Parses the attributes of an element, other than namespace declarations, into children.
+    fn parse_attributes(&self, start: &BytesStart) -> Result<Vec<(String, XmlElement)>> {
+        let mut attributes = vec![];
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|e| self.error(e.into()))?;
+            let key = String::from_utf8_lossy(attribute.key.as_ref());
+            if key == "xmlns" || key.starts_with("xmlns:") {
+                continue;
+            }
+            let value = attribute.unescape_value().map_err(|e| self.error(e))?;
+            attributes.push((
+                format!("{}{key}", self.config.attribute_prefix),
+                XmlElement {
+                    children: vec![],
+                    text: Some(value.into_owned()),
+                },
+            ));
+        }
+        Ok(attributes)
+    }
+}
+
+fn tag_name(start: &BytesStart) -> String {
+    String::from_utf8_lossy(start.name().as_ref()).into_owned()
+}
@@ -0,0 +1,274 @@
+use std::sync::Arc;
+
+use arrow2::{bitmap::Bitmap, offset::OffsetsBuffer};
+use common_error::DaftResult;
+use common_file_formats::XmlSourceConfig;
+use common_runtime::get_io_runtime;
+use daft_compression::CompressionCodec;
+use daft_core::prelude::*;
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{StreamExt, TryStreamExt};
+use snafu::ResultExt;
+use tokio::io::AsyncReadExt;
+
+use crate::{
+    inference::infer_fields,
+    parse::{parse_rows, XmlElement},
+    NoRowsSnafu, StdIOSnafu,
+};
+
+/// Number of rows that the schema of a file is inferred from.
+const INFERENCE_ROWS: usize = 1024;
+
+/// Infers the schema of an XML file from its first rows.
+pub async fn read_xml_schema(
+    uri: &str,
+    config: &XmlSourceConfig,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let bytes = read_file(uri, io_client, io_stats).await?;
+    let rows = parse_rows(&bytes, uri, config, Some(INFERENCE_ROWS))?;
+    if rows.is_empty() {
+        return Err(NoRowsSnafu {
+            path: uri,
+            row_tag: config.row_tag.as_str(),
+        }
+        .build()
+        .into());
+    }
+    Schema::new(infer_fields(&rows, &config.value_tag))
+}
+
+/// Reads the rows of an XML file, or the first `limit` of them, into a table with the given schema.
+/// Only the columns in `include_columns` are read if it's set, and rows that don't match the
+/// predicate are dropped.
+#[allow(clippy::too_many_arguments)]
+pub async fn read_xml(
+    uri: &str,
+    config: &XmlSourceConfig,
+    schema: SchemaRef,
+    include_columns: Option<&[String]>,
+    limit: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Table> {
+    let bytes = read_file(uri, io_client, io_stats).await?;
+    // the limit applies to the rows that match the predicate
+    let rows = parse_rows(
+        &bytes,
+        uri,
+        config,
+        if predicate.is_some() { None } else { limit },
+    )?;
+
+    let read_columns = include_columns.map(|columns| {
+        let mut read_columns = columns.to_vec();
+        if let Some(predicate) = &predicate {
+            for column in get_required_columns(predicate) {
+                if !read_columns.contains(&column) {
+                    read_columns.push(column);
+                }
+            }
+        }
+        read_columns
+    });
+    let fields = schema
+        .fields
+        .values()
+        .filter(|field| {
+            read_columns
+                .as_ref()
+                .map_or(true, |columns| columns.contains(&field.name))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let columns = fields
+        .iter()
+        .map(|field| {
+            let values = rows
+                .iter()
+                .map(|row| field_values(row, &field.name, &config.value_tag))
+                .collect::<Vec<_>>();
+            to_series(&field.name, &field.dtype, &values, &config.value_tag)
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    let mut table = Table::new_with_size(Schema::new(fields)?, columns, rows.len())?;
+
+    if let Some(predicate) = predicate {
+        table = table.filter(&[predicate])?;
+        if let Some(limit) = limit {
+            table = table.head(limit)?;
+        }
+    }
+    if let Some(columns) = include_columns {
+        let columns = columns
+            .iter()
+            .filter(|column| table.schema.fields.contains_key(column.as_str()))
+            .collect::<Vec<_>>();
+        table = table.get_columns(&columns)?;
+    }
+    Ok(table)
+}
+
+/// Reads XML files into a table each, with up to `num_parallel_tasks` of them read at a time.
+#[allow(clippy::too_many_arguments)]
+pub fn read_xml_bulk(
+    uris: &[&str],
+    config: &XmlSourceConfig,
+    schema: SchemaRef,
+    include_columns: Option<&[String]>,
+    limit: Option<usize>,
+    predicate: Option<ExprRef>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<Table>> {
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    runtime_handle.block_on_current_thread(async {
+        futures::stream::iter(uris.iter().map(|uri| {
+            read_xml(
+                uri,
+                config,
+                schema.clone(),
+                include_columns,
+                limit,
+                predicate.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            )
+        }))
+        .buffered(num_parallel_tasks)
+        .try_collect::<Vec<_>>()
+        .await
+    })
+}
+
+async fn read_file(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Vec<u8>> {
+    let bytes = io_client
+        .single_url_get(uri.to_string(), None, io_stats)
+        .await?
+        .bytes()
+        .await?;
+    match CompressionCodec::from_uri(uri) {
+        Some(compression) => {
+            let mut decoded = vec![];
+            compression
+                .to_decoder(std::io::Cursor::new(bytes))
+                .read_to_end(&mut decoded)
+                .await
+                .context(StdIOSnafu)?;
+            Ok(decoded)
+        }
+        None => Ok(bytes.to_vec()),
+    }
+}
+
+/// The occurrences of a field in an element, where the text of an element without children is its
+/// field named by the value tag.
+fn field_values<'a>(
+    element: &'a XmlElement,
+    name: &'a str,
+    value_tag: &str,
+) -> Vec<&'a XmlElement> {
+    if element.is_leaf() && name == value_tag {
+        vec![element]
+    } else {
+        element.children_named(name).collect()
+    }
+}
+
+/// Builds a column of the dtype from the occurrences of an element in each row. Lists are made of
+/// all of the occurrences of the element in a row, while other dtypes are read from the first one.
+fn to_series(
+    name: &str,
+    dtype: &DataType,
+    values: &[Vec<&XmlElement>],
+    value_tag: &str,
+) -> DaftResult<Series> {
+    let texts = || {
+        values
+            .iter()
+            .map(|occurrences| occurrences.first().and_then(|e| e.text.as_deref()))
+    };
+    match dtype {
+        DataType::Null => Ok(Series::full_null(name, dtype, values.len())),
+        DataType::Boolean => Ok(BooleanArray::from_iter(
+            name,
+            texts().map(|text| {
+                text.and_then(|text| {
+                    if text.eq_ignore_ascii_case("true") {
+                        Some(true)
+                    } else if text.eq_ignore_ascii_case("false") {
+                        Some(false)
+                    } else {
+                        None
+                    }
+                })
+            }),
+        )
+        .into_series()),
+        DataType::Binary => Ok(BinaryArray::from_iter(
+            name,
+            texts().map(|text| text.map(str::as_bytes)),
+        )
+        .into_series()),
+        DataType::List(child_dtype) => {
+            let validity: Bitmap = values
+                .iter()
+                .map(|occurrences| !occurrences.is_empty())
+                .collect();
+            let mut offsets = Vec::with_capacity(values.len() + 1);
+            offsets.push(0i64);
+            let mut items = vec![];
+            for occurrences in values {
+                items.extend(occurrences.iter().map(|item| vec![*item]));
+                offsets.push(items.len() as i64);
+            }
+            let flat_child = to_series(name, child_dtype, &items, value_tag)?;
+            Ok(ListArray::new(
+                Field::new(name, dtype.clone()),
+                flat_child,
+                OffsetsBuffer::try_from(offsets)?,
+                Some(validity),
+            )
+            .into_series())
+        }
+        DataType::Struct(fields) => {
+            let validity: Bitmap = values
+                .iter()
+                .map(|occurrences| !occurrences.is_empty())
+                .collect();
+            let children = fields
+                .iter()
+                .map(|field| {
+                    let field_values = values
+                        .iter()
+                        .map(|occurrences| match occurrences.first() {
+                            Some(element) => field_values(element, &field.name, value_tag),
+                            None => vec![],
+                        })
+                        .collect::<Vec<_>>();
+                    to_series(&field.name, &field.dtype, &field_values, value_tag)
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Ok(
+                StructArray::new(Field::new(name, dtype.clone()), children, Some(validity))
+                    .into_series(),
+            )
+        }
+        // other dtypes are parsed from strings, where text that can't be parsed is read as null
+        _ => Utf8Array::from_iter(name, texts())
+            .into_series()
+            .cast(dtype),
+    }
+}
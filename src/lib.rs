@@ -111,19 +111,25 @@ pub mod pylib {
         daft_io::register_modules(m)?;
         daft_parquet::register_modules(m)?;
         daft_csv::register_modules(m)?;
+        daft_delta_lake::python::register_modules(m)?;
+        daft_iceberg::python::register_modules(m)?;
         daft_json::register_modules(m)?;
         daft_logical_plan::register_modules(m)?;
         daft_micropartition::register_modules(m)?;
         daft_scan::register_modules(m)?;
         daft_scheduler::register_modules(m)?;
+        daft_shuffles::python::register_modules(m)?;
         daft_sql::register_modules(m)?;
         daft_functions::register_modules(m)?;
         daft_functions_json::register_modules(m)?;
         daft_connect::register_modules(m)?;
+        daft_flight_sql::register_modules(m)?;
+        daft_pgwire::register_modules(m)?;
 
         // Register catalog module
         let catalog_module = daft_catalog::python::register_modules(m)?;
         daft_catalog_python_catalog::python::register_modules(&catalog_module)?;
+        daft_iceberg::python::register_catalog_modules(&catalog_module)?;
 
         // Register testing module
         let testing_module = PyModule::new_bound(m.py(), "testing")?;
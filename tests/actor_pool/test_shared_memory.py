@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import multiprocessing as mp
+
+import pytest
+
+import daft
+from daft import DataType, col
+from daft.execution.shared_memory import SHARED_MEMORY_SUPPORTED, recv_partition, send_partition
+from daft.series import Series
+from daft.table import MicroPartition
+
+
+def echo(conn, use_shared_memory):
+    while (partition := recv_partition(conn)) is not None:
+        send_partition(conn, partition, use_shared_memory)
+
+
+def roundtrip(partition: MicroPartition, use_shared_memory: bool) -> MicroPartition:
+    conn, child_conn = mp.Pipe()
+    process = mp.Process(target=echo, args=(child_conn, use_shared_memory))
+    process.start()
+    try:
+        send_partition(conn, partition, use_shared_memory)
+        return recv_partition(conn)
+    finally:
+        send_partition(conn, None, use_shared_memory)
+        process.join()
+
+
+@pytest.mark.skipif(not SHARED_MEMORY_SUPPORTED, reason="Shared memory transport requires memfd support")
+def test_roundtrip_through_shared_memory():
+    partition = MicroPartition.from_pydict(
+        {
+            "a": [1, 2, None],
+            "b": ["foo", None, "baz"],
+            "c": [[1.0], [], None],
+        }
+    )
+    # Types that the schema of the Arrow table wouldn't be inferred as are restored.
+    partition = partition.eval_expression_list([col("a").cast(DataType.int32()), col("b"), col("c")])
+
+    result = roundtrip(partition, use_shared_memory=True)
+
+    assert result.schema() == partition.schema()
+    assert result.to_pydict() == partition.to_pydict()
+
+
+@pytest.mark.parametrize("use_shared_memory", [False, True])
+def test_roundtrip_python_columns(use_shared_memory):
+    partition = MicroPartition.from_pydict({"a": [1, 2, 3]})
+    partition = partition.eval_expression_list(
+        [col("a"), col("a").apply(lambda x: {"x": x}, DataType.python()).alias("obj")]
+    )
+
+    result = roundtrip(partition, use_shared_memory)
+
+    assert result.schema() == partition.schema()
+    assert result.to_pydict() == {"a": [1, 2, 3], "obj": [{"x": 1}, {"x": 2}, {"x": 3}]}
+
+
+@pytest.mark.parametrize("use_shared_memory", [False, True])
+def test_actor_pool_udf_with_shared_memory(use_shared_memory):
+    @daft.udf(return_dtype=DataType.int64(), batch_size=2)
+    class AddOne:
+        def __call__(self, data):
+            return Series.from_pylist([x + 1 for x in data.to_pylist()])
+
+    AddOne = AddOne.with_concurrency(2)
+
+    with daft.execution_config_ctx(udf_shared_memory_transport=use_shared_memory):
+        df = daft.from_pydict({"x": list(range(10))}).select(AddOne(col("x")))
+        assert sorted(df.to_pydict()["x"]) == list(range(1, 11))
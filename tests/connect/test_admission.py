@@ -0,0 +1,25 @@
+from __future__ import annotations
+
+from concurrent.futures import ThreadPoolExecutor
+
+from pyspark.sql import SparkSession
+
+
+def test_queries_are_queued_beyond_the_limits():
+    from daft.daft import connect_start
+
+    server = connect_start(max_concurrent_queries=1, max_concurrent_queries_per_session=1)
+    url = f"sc://localhost:{server.port()}"
+    sessions = [SparkSession.builder.appName(f"DaftAdmissionTest{i}").remote(url).create() for i in range(2)]
+    try:
+
+        def run(i):
+            session = sessions[i % len(sessions)]
+            return len(session.range(i).collect())
+
+        with ThreadPoolExecutor(max_workers=4) as executor:
+            assert list(executor.map(run, range(8))) == list(range(8))
+    finally:
+        for session in sessions:
+            session.stop()
+        server.shutdown()
@@ -0,0 +1,31 @@
+from __future__ import annotations
+
+import pytest
+
+
+@pytest.fixture
+def df(spark_session):
+    data = [(1, "a", 10), (1, "a", 10), (1, "b", 20), (2, "b", 20)]
+    return spark_session.createDataFrame(data, ["id", "key", "value"])
+
+
+def test_drop_duplicates(df):
+    rows = sorted(tuple(row) for row in df.dropDuplicates().collect())
+    assert rows == [(1, "a", 10), (1, "b", 20), (2, "b", 20)]
+
+
+def test_distinct(df):
+    assert len(df.distinct().collect()) == 3
+
+
+def test_drop_duplicates_with_subset(df):
+    result = df.dropDuplicates(["key", "value"])
+    assert result.columns == ["id", "key", "value"]
+
+    rows = sorted((row.key, row.value) for row in result.collect())
+    assert rows == [("a", 10), ("b", 20)]
+
+
+def test_drop_duplicates_with_missing_column(df):
+    with pytest.raises(Exception, match="Cannot deduplicate"):
+        df.dropDuplicates(["missing"]).collect()
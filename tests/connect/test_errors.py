@@ -0,0 +1,12 @@
+from __future__ import annotations
+
+import pytest
+from pyspark.errors import AnalysisException
+from pyspark.sql.functions import col
+
+
+def test_missing_column_raises_analysis_exception(spark_session):
+    df = spark_session.range(10).select(col("missing"))
+
+    with pytest.raises(AnalysisException, match="missing"):
+        df.collect()
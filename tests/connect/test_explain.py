@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+import pytest
+from pyspark.sql import functions as F
+from pyspark.sql.functions import col
+
+
+def test_explain_simple(spark_session):
+    df = spark_session.range(10).filter(col("id") < 5).select((col("id") * 2).alias("doubled"))
+
+    explain = df._explain_string(mode="simple")
+
+    assert explain.startswith("== Physical Plan ==\n")
+    assert "Project [(id * 2) AS doubled]" in explain
+    assert "(id < 5)" in explain
+    assert "+- " in explain
+
+
+def test_explain_aggregate(spark_session):
+    df = spark_session.range(10).withColumn("group", col("id") % 3).groupBy("group").agg(F.sum(col("id")))
+
+    explain = df._explain_string(mode="simple")
+
+    assert "HashAggregate(keys=[group], functions=[sum(id)])" in explain
+
+
+def test_explain_extended(spark_session):
+    df = spark_session.range(10).filter(col("id") < 5)
+
+    explain = df._explain_string(mode="extended")
+
+    sections = [
+        "== Parsed Logical Plan ==",
+        "== Analyzed Logical Plan ==",
+        "== Optimized Logical Plan ==",
+        "== Physical Plan ==",
+    ]
+    positions = [explain.index(section) for section in sections]
+    assert positions == sorted(positions)
+    assert "id: bigint" in explain
+
+
+def test_explain_formatted(spark_session):
+    df = spark_session.range(10).filter(col("id") < 5)
+
+    explain = df._explain_string(mode="formatted")
+
+    assert explain.startswith("== Physical Plan ==\n")
+    assert "(1) " in explain
+    assert "Output [1]: [id]" in explain
+
+
+def test_explain_unsupported_mode(spark_session):
+    df = spark_session.range(10)
+
+    with pytest.raises(Exception, match="not yet supported"):
+        df._explain_string(mode="cost")
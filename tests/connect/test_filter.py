@@ -17,3 +17,20 @@ def test_filter(spark_session):
     df_filtered_pandas = df_filtered.toPandas()
     assert len(df_filtered_pandas) == 5, "Should have 5 rows after filtering < 5"
     assert all(df_filtered_pandas["id"] < 5), "All values should be less than 5"
+
+
+def test_filter_combined_conditions(spark_session):
+    df = spark_session.range(10)
+
+    df_and = df.filter((col("id") >= 2) & (col("id") < 5)).toPandas()
+    assert df_and["id"].tolist() == [2, 3, 4], "Should keep rows matching both conditions"
+
+    df_or = df.filter((col("id") < 2) | (col("id") >= 8)).toPandas()
+    assert df_or["id"].tolist() == [0, 1, 8, 9], "Should keep rows matching either condition"
+
+
+def test_filter_negated_condition(spark_session):
+    df = spark_session.range(10)
+
+    df_filtered = df.filter(~(col("id") > 2)).toPandas()
+    assert df_filtered["id"].tolist() == [0, 1, 2], "Should keep rows not matching the condition"
@@ -0,0 +1,68 @@
+from __future__ import annotations
+
+import datetime
+
+import pytest
+from pyspark.sql import functions as F
+
+
+def test_string_functions(spark_session):
+    df = spark_session.createDataFrame([("Hello",), (None,)], ["s"])
+    rows = df.select(
+        F.upper("s").alias("upper"),
+        F.lower("s").alias("lower"),
+        F.length("s").alias("length"),
+        F.substring("s", 2, 3).alias("substring"),
+        F.concat("s", F.lit("!")).alias("concat"),
+    ).collect()
+
+    assert rows[0].asDict() == {"upper": "HELLO", "lower": "hello", "length": 5, "substring": "ell", "concat": "Hello!"}
+    assert rows[1].asDict() == {"upper": None, "lower": None, "length": None, "substring": None, "concat": None}
+
+
+def test_conditional_functions(spark_session):
+    df = spark_session.createDataFrame([(1,), (5,), (None,)], ["x"])
+    rows = df.select(
+        F.when(F.col("x") > 3, "big").when(F.col("x") > 0, "small").otherwise("none").alias("size"),
+        F.coalesce("x", F.lit(0)).alias("filled"),
+    ).collect()
+
+    assert [row.size for row in rows] == ["small", "big", "none"]
+    assert [row.filled for row in rows] == [1, 5, 0]
+
+
+def test_math_functions(spark_session):
+    df = spark_session.createDataFrame([(-2.5,)], ["x"])
+    row = df.select(
+        F.abs("x").alias("abs"),
+        F.ceil("x").alias("ceil"),
+        F.floor("x").alias("floor"),
+        F.round(F.abs("x"), 0).alias("round"),
+    ).collect()[0]
+
+    assert row.abs == 2.5
+    assert row.ceil == -2
+    assert row.floor == -3
+    assert row.round == 3
+
+
+def test_date_functions(spark_session):
+    df = spark_session.createDataFrame([(datetime.date(2024, 1, 31),)], ["d"])
+    row = df.select(
+        F.date_add("d", 1).alias("next_day"),
+        F.year("d").alias("year"),
+        F.month("d").alias("month"),
+        F.dayofmonth("d").alias("day"),
+        F.dayofweek("d").alias("dayofweek"),
+    ).collect()[0]
+
+    assert row.next_day == datetime.date(2024, 2, 1)
+    assert (row.year, row.month, row.day) == (2024, 1, 31)
+    # 2024-01-31 is a Wednesday, and Spark counts the days of the week from Sunday = 1
+    assert row.dayofweek == 4
+
+
+def test_unsupported_function(spark_session):
+    df = spark_session.range(1)
+    with pytest.raises(Exception, match="not yet supported"):
+        df.select(F.soundex(F.lit("a"))).collect()
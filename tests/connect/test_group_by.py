@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+from pyspark.sql import functions as F
 from pyspark.sql.functions import col
 
 
@@ -31,3 +32,33 @@ def test_group_by(spark_session):
 
     assert df_grouped_pandas["group"].tolist() == expected["group"]
     assert df_grouped_pandas["id"].tolist() == expected["id"]
+
+
+def test_group_by_aggregations(spark_session):
+    df = spark_session.range(10).withColumn("group", col("id") % 2)
+
+    df_grouped = df.groupBy("group").agg(
+        F.count(col("id")).alias("count"),
+        F.sum(col("id")).alias("sum"),
+        F.avg(col("id")).alias("avg"),
+        F.min(col("id")).alias("min"),
+        F.max(col("id")).alias("max"),
+    )
+
+    df_grouped_pandas = df_grouped.toPandas().sort_values("group").reset_index(drop=True)
+
+    assert df_grouped_pandas["group"].tolist() == [0, 1]
+    assert df_grouped_pandas["count"].tolist() == [5, 5]
+    assert df_grouped_pandas["sum"].tolist() == [20, 25]
+    assert df_grouped_pandas["avg"].tolist() == [4.0, 5.0]
+    assert df_grouped_pandas["min"].tolist() == [0, 1]
+    assert df_grouped_pandas["max"].tolist() == [8, 9]
+
+
+def test_global_aggregation(spark_session):
+    df = spark_session.range(10)
+
+    result = df.agg(F.min(col("id")).alias("min"), F.mean(col("id")).alias("mean")).toPandas()
+
+    assert result["min"].tolist() == [0]
+    assert result["mean"].tolist() == [4.5]
@@ -0,0 +1,65 @@
+from __future__ import annotations
+
+import pytest
+
+
+@pytest.fixture
+def left_df(spark_session):
+    return spark_session.createDataFrame([(1, 10), (2, 20), (3, 30)], ["id", "left_value"])
+
+
+@pytest.fixture
+def right_df(spark_session):
+    return spark_session.createDataFrame([(2, 200), (3, 300), (4, 400)], ["id", "right_value"])
+
+
+def sorted_rows(df, *columns):
+    return sorted(df.toPandas()[list(columns)].itertuples(index=False, name=None), key=lambda row: str(row))
+
+
+def test_inner_join_using_columns(left_df, right_df):
+    df = left_df.join(right_df, on="id", how="inner")
+
+    assert df.columns == ["id", "left_value", "right_value"]
+    assert sorted_rows(df, "id", "left_value", "right_value") == [(2, 20, 200), (3, 30, 300)]
+
+
+@pytest.mark.parametrize(
+    ["how", "expected_ids"],
+    [
+        ("left", [1, 2, 3]),
+        ("right", [2, 3, 4]),
+        ("full", [1, 2, 3, 4]),
+    ],
+)
+def test_outer_joins(left_df, right_df, how, expected_ids):
+    df = left_df.join(right_df, on="id", how=how)
+
+    assert sorted(df.toPandas()["id"].tolist()) == expected_ids
+
+
+def test_semi_and_anti_joins(left_df, right_df):
+    semi = left_df.join(right_df, on="id", how="left_semi")
+    anti = left_df.join(right_df, on="id", how="left_anti")
+
+    assert semi.columns == ["id", "left_value"]
+    assert sorted(semi.toPandas()["id"].tolist()) == [2, 3]
+    assert sorted(anti.toPandas()["id"].tolist()) == [1]
+
+
+def test_join_condition(left_df, right_df):
+    right_df = right_df.withColumnRenamed("id", "key")
+    df = left_df.join(right_df, left_df["id"] == right_df["key"], how="inner")
+
+    assert sorted_rows(df, "id", "key", "right_value") == [(2, 2, 200), (3, 3, 300)]
+
+
+def test_cross_join(left_df, right_df):
+    df = left_df.select("left_value").crossJoin(right_df.select("right_value"))
+
+    assert len(df.toPandas()) == 9
+
+
+def test_non_equi_join_condition(left_df, right_df):
+    with pytest.raises(Exception, match="equality join conditions"):
+        left_df.join(right_df, left_df["left_value"] < right_df["right_value"]).toPandas()
@@ -12,3 +12,31 @@ def test_range_limit(spark_session):
     limited_df = spark_range.limit(5).toPandas()
     assert len(limited_df) == 5, "Limited DataFrame should have 5 rows"
     assert list(limited_df["id"]) == list(range(5)), "Limited DataFrame should contain values 0-4"
+
+
+def test_range_offset(spark_session):
+    spark_range = spark_session.range(10)
+    offset_df = spark_range.offset(7).toPandas()
+    assert list(offset_df["id"]) == [7, 8, 9], "Offset DataFrame should skip the first 7 rows"
+
+    assert len(spark_range.offset(20).toPandas()) == 0, "Offsetting past the end should return no rows"
+
+
+def test_range_limit_offset(spark_session):
+    spark_range = spark_session.range(10)
+    paged_df = spark_range.offset(2).limit(3).toPandas()
+    assert list(paged_df["id"]) == [2, 3, 4], "Should return the rows after the offset up to the limit"
+
+
+def test_range_tail(spark_session):
+    spark_range = spark_session.range(10)
+    tail_rows = spark_range.tail(3)
+    assert [row["id"] for row in tail_rows] == [7, 8, 9], "Tail should return the last 3 rows"
+
+    assert len(spark_range.tail(20)) == 10, "Tail larger than the DataFrame should return all rows"
+
+
+def test_offset_and_tail_across_partitions(spark_session):
+    spark_range = spark_session.range(0, 10, numPartitions=3).sort("id", ascending=False)
+    assert list(spark_range.offset(6).toPandas()["id"]) == [3, 2, 1, 0], "Offset should skip rows in sorted order"
+    assert [row["id"] for row in spark_range.tail(2)] == [1, 0], "Tail should return the last rows in sorted order"
@@ -0,0 +1,38 @@
+from __future__ import annotations
+
+import datetime
+import decimal
+
+from pyspark.sql import functions as F
+
+
+def test_literals(spark_session):
+    df = spark_session.range(1).select(
+        F.lit(True).alias("bool"),
+        F.lit(1).alias("long"),
+        F.lit(1.5).alias("double"),
+        F.lit("a").alias("string"),
+        F.lit(decimal.Decimal("-12.50")).alias("decimal"),
+        F.lit(datetime.date(2024, 1, 2)).alias("date"),
+    )
+
+    row = df.collect()[0]
+    assert row.bool is True
+    assert row.long == 1
+    assert row.double == 1.5
+    assert row.string == "a"
+    assert row.decimal == decimal.Decimal("-12.50")
+    assert row.date == datetime.date(2024, 1, 2)
+
+
+def test_arithmetic_and_comparisons(spark_session):
+    df = spark_session.range(4).select(
+        ((F.col("id") + 1) * 2 - F.col("id") / 2).alias("arithmetic"),
+        (F.col("id") % 2 == 0).alias("even"),
+        ((F.col("id") >= 1) & (F.col("id") < 3) | F.col("id").isNull()).alias("between"),
+    )
+
+    rows = df.sort("arithmetic").collect()
+    assert [row.arithmetic for row in rows] == [2.0, 3.5, 5.0, 6.5]
+    assert [row.even for row in rows] == [True, False, True, False]
+    assert [row.between for row in rows] == [False, True, True, False]
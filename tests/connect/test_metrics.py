@@ -0,0 +1,42 @@
+from __future__ import annotations
+
+import urllib.error
+import urllib.request
+
+import pytest
+from pyspark.sql import SparkSession
+
+
+def test_metrics_endpoint():
+    from daft.daft import connect_start
+
+    server = connect_start(metrics_addr="127.0.0.1:0")
+    session = SparkSession.builder.appName("DaftMetricsTest").remote(f"sc://localhost:{server.port()}").getOrCreate()
+    try:
+        assert len(session.range(10).collect()) == 10
+
+        metrics_url = f"http://127.0.0.1:{server.metrics_port()}"
+        with urllib.request.urlopen(f"{metrics_url}/metrics") as response:
+            body = response.read().decode()
+
+        assert 'daft_connect_requests_total{method="execute_plan"}' in body
+        assert 'daft_connect_request_duration_seconds_count{method="execute_plan"}' in body
+        assert "daft_connect_executor_pool_utilization" not in body
+        assert "daft_connect_active_sessions 1" in body
+        assert "daft_connect_running_queries 0" in body
+
+        with pytest.raises(urllib.error.HTTPError):
+            urllib.request.urlopen(f"{metrics_url}/other")
+    finally:
+        session.stop()
+        server.shutdown()
+
+
+def test_no_metrics_endpoint_by_default():
+    from daft.daft import connect_start
+
+    server = connect_start()
+    try:
+        assert server.metrics_port() is None
+    finally:
+        server.shutdown()
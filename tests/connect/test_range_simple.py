@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import pytest
+
 
 def test_range_operation(spark_session):
     # Create a range using Spark
@@ -12,3 +14,18 @@ def test_range_operation(spark_session):
     # Verify the DataFrame has expected values
     assert len(pandas_df) == 10, "DataFrame should have 10 rows"
     assert list(pandas_df["id"]) == list(range(10)), "DataFrame should contain values 0-9"
+
+
+@pytest.mark.parametrize("num_partitions", [1, 2, 3, 20])
+def test_range_num_partitions(spark_session, num_partitions):
+    spark_range = spark_session.range(0, 10, 3, numPartitions=num_partitions)
+    assert sorted(spark_range.toPandas()["id"]) == [0, 3, 6, 9]
+
+
+def test_range_negative_step(spark_session):
+    spark_range = spark_session.range(10, 0, -3, numPartitions=2)
+    assert list(spark_range.toPandas()["id"]) == [10, 7, 4, 1]
+
+
+def test_range_limit(spark_session):
+    assert list(spark_session.range(0, 1_000_000, numPartitions=4).limit(3).toPandas()["id"]) == [0, 1, 2]
@@ -0,0 +1,59 @@
+from __future__ import annotations
+
+import json
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+
+def test_read_parquet(spark_session, tmp_path):
+    path = tmp_path / "data.parquet"
+    papq.write_table(pa.table({"a": [1, 2, 3], "b": ["x", "y", "z"]}), path)
+
+    df = spark_session.read.parquet(str(path))
+    assert df.toPandas().to_dict(orient="list") == {"a": [1, 2, 3], "b": ["x", "y", "z"]}
+
+
+def test_read_json(spark_session, tmp_path):
+    path = tmp_path / "data.jsonl"
+    rows = [{"a": 1, "b": "x"}, {"a": 2, "b": "y"}]
+    path.write_text("\n".join(json.dumps(row) for row in rows))
+
+    df = spark_session.read.json(str(path))
+    assert df.toPandas().to_dict(orient="list") == {"a": [1, 2], "b": ["x", "y"]}
+
+
+def test_read_with_format_and_load(spark_session, tmp_path):
+    path = tmp_path / "data.jsonl"
+    path.write_text(json.dumps({"a": 1}))
+
+    df = spark_session.read.format("json").load(str(path))
+    assert df.toPandas().to_dict(orient="list") == {"a": [1]}
+
+
+def test_read_csv_with_options(spark_session, tmp_path):
+    path = tmp_path / "data.csv"
+    path.write_text("# a comment\n1;x\n2;y\n")
+
+    df = (
+        spark_session.read.option("header", False)
+        .option("sep", ";")
+        .option("comment", "#")
+        .schema("a BIGINT, b STRING")
+        .csv(str(path))
+    )
+    assert df.toPandas().to_dict(orient="list") == {"a": [1, 2], "b": ["x", "y"]}
+
+
+def test_read_with_invalid_option(spark_session, tmp_path):
+    path = tmp_path / "data.csv"
+    path.write_text("a,b\n1,x\n")
+
+    with pytest.raises(Exception, match="single character"):
+        spark_session.read.option("sep", ";;").csv(str(path)).toPandas()
+
+
+def test_read_unsupported_format(spark_session, tmp_path):
+    with pytest.raises(Exception, match="Unsupported format"):
+        spark_session.read.format("avro").load(str(tmp_path)).toPandas()
@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+import pytest
+
+
+def test_sample(spark_session):
+    df = spark_session.range(100)
+
+    rows = df.sample(fraction=0.5, seed=42).collect()
+
+    assert 0 < len(rows) < 100
+    assert all(0 <= row.id < 100 for row in rows)
+    assert len({row.id for row in rows}) == len(rows)
+
+
+def test_sample_is_deterministic_with_seed(spark_session):
+    df = spark_session.range(100)
+
+    first = sorted(row.id for row in df.sample(fraction=0.3, seed=7).collect())
+    second = sorted(row.id for row in df.sample(fraction=0.3, seed=7).collect())
+
+    assert first == second
+
+
+def test_sample_with_replacement(spark_session):
+    df = spark_session.range(10)
+
+    rows = df.sample(withReplacement=True, fraction=2.0, seed=42).collect()
+
+    assert all(0 <= row.id < 10 for row in rows)
+
+
+def test_sample_fraction_bounds(spark_session):
+    df = spark_session.range(10)
+
+    assert len(df.sample(fraction=0.0, seed=1).collect()) == 0
+    assert len(df.sample(fraction=1.0, seed=1).collect()) == 10
+
+
+def test_random_split_is_unsupported(spark_session):
+    df = spark_session.range(10)
+
+    with pytest.raises(Exception, match="randomSplit"):
+        df.randomSplit([0.5, 0.5], seed=1)[1].collect()
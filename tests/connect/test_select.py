@@ -18,3 +18,22 @@ def test_select(spark_session):
     df_data = [row["id"] for row in df.collect()]
     df_selected_data = [row["id"] for row in df_selected.collect()]
     assert df_data == df_selected_data, "Data should be unchanged after select"
+
+
+def test_select_expressions(spark_session):
+    df = spark_session.range(5)
+
+    df_selected = df.select(col("id"), (col("id") * 2 + 1).alias("odd"), (col("id") - 1).alias("prev"))
+
+    assert df_selected.columns == ["id", "odd", "prev"], "Should have a column per selected expression"
+
+    df_selected_pandas = df_selected.toPandas()
+    assert df_selected_pandas["odd"].tolist() == [1, 3, 5, 7, 9], "Arithmetic should be applied to each row"
+    assert df_selected_pandas["prev"].tolist() == [-1, 0, 1, 2, 3], "Arithmetic should be applied to each row"
+
+
+def test_select_division(spark_session):
+    df = spark_session.range(1, 5)
+
+    df_selected = df.select((col("id") / 2).alias("half")).toPandas()
+    assert df_selected["half"].tolist() == [0.5, 1.0, 1.5, 2.0], "Division should return floats"
@@ -0,0 +1,76 @@
+from __future__ import annotations
+
+import pytest
+
+
+@pytest.fixture
+def left(spark_session):
+    return spark_session.createDataFrame([(1, "a"), (2, "b"), (2, "b"), (3, None)], ["id", "value"])
+
+
+@pytest.fixture
+def right(spark_session):
+    return spark_session.createDataFrame([(2, "b"), (3, None), (4, "d")], ["id", "value"])
+
+
+def rows(df):
+    return sorted((tuple(row) for row in df.collect()), key=str)
+
+
+def test_union_all(left, right):
+    expected = sorted([(1, "a"), (2, "b"), (2, "b"), (3, None), (2, "b"), (3, None), (4, "d")], key=str)
+
+    assert rows(left.union(right)) == expected
+    assert rows(left.unionAll(right)) == expected
+
+
+def test_union_distinct(left, right):
+    assert rows(left.union(right).distinct()) == sorted([(1, "a"), (2, "b"), (3, None), (4, "d")], key=str)
+
+
+def test_union_by_position(left, spark_session):
+    other = spark_session.createDataFrame([(5, "e")], ["other_id", "other_value"])
+
+    result = left.union(other)
+
+    assert result.columns == ["id", "value"]
+    assert (5, "e") in rows(result)
+
+
+def test_union_by_name(left, spark_session):
+    other = spark_session.createDataFrame([("e", 5)], ["value", "id"])
+
+    result = left.unionByName(other)
+
+    assert result.columns == ["id", "value"]
+    assert (5, "e") in rows(result)
+
+
+def test_union_by_name_with_missing_columns(left, spark_session):
+    other = spark_session.createDataFrame([(5, True)], ["id", "flag"])
+
+    result = left.unionByName(other, allowMissingColumns=True)
+
+    assert result.columns == ["id", "value", "flag"]
+    assert (5, None, True) in rows(result)
+    assert (1, "a", None) in rows(result)
+
+
+def test_union_by_name_without_missing_columns_fails(left, spark_session):
+    other = spark_session.createDataFrame([(5, True)], ["id", "flag"])
+
+    with pytest.raises(Exception, match="missing"):
+        left.unionByName(other).collect()
+
+
+def test_intersect(left, right):
+    assert rows(left.intersect(right)) == sorted([(2, "b"), (3, None)], key=str)
+
+
+def test_except(left, right):
+    assert rows(left.subtract(right)) == [(1, "a")]
+
+
+def test_except_all_is_unsupported(left, right):
+    with pytest.raises(Exception, match="EXCEPT ALL"):
+        left.exceptAll(right).collect()
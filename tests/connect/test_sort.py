@@ -0,0 +1,34 @@
+from __future__ import annotations
+
+from pyspark.sql.functions import col
+
+
+def test_sort(spark_session):
+    df = spark_session.range(10)
+
+    df_asc = df.sort(col("id")).toPandas()
+    assert df_asc["id"].tolist() == list(range(10)), "Should be sorted in ascending order"
+
+    df_desc = df.orderBy(col("id").desc()).toPandas()
+    assert df_desc["id"].tolist() == list(range(9, -1, -1)), "Should be sorted in descending order"
+
+
+def test_sort_multiple_columns(spark_session):
+    df = spark_session.createDataFrame([(1, "b"), (2, "a"), (1, "a"), (2, "b")], ["x", "y"])
+
+    df_sorted = df.sort(col("x").desc(), col("y").asc()).toPandas()
+    assert list(zip(df_sorted["x"], df_sorted["y"])) == [(2, "a"), (2, "b"), (1, "a"), (1, "b")]
+
+
+def test_sort_nulls(spark_session):
+    df = spark_session.createDataFrame([(2,), (None,), (1,)], ["x"])
+
+    def sorted_values(order):
+        return [None if v != v else v for v in df.sort(order).toPandas()["x"].tolist()]
+
+    # nulls are first in ascending order and last in descending order by default, like in Spark
+    assert sorted_values(col("x").asc()) == [None, 1, 2]
+    assert sorted_values(col("x").desc()) == [2, 1, None]
+
+    assert sorted_values(col("x").asc_nulls_last()) == [1, 2, None]
+    assert sorted_values(col("x").desc_nulls_first()) == [None, 2, 1]
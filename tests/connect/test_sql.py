@@ -0,0 +1,28 @@
+from __future__ import annotations
+
+import pytest
+from pyspark.sql.functions import col
+
+
+def test_sql(spark_session):
+    df = spark_session.sql("SELECT 1 AS x, 'a' AS y")
+    assert df.columns == ["x", "y"]
+    assert df.toPandas().to_dict(orient="list") == {"x": [1], "y": ["a"]}
+
+
+def test_sql_with_table_function(spark_session, tmp_path):
+    path = str(tmp_path / "parquet")
+    spark_session.range(3).write.parquet(path)
+
+    df = spark_session.sql(f"SELECT id * 2 AS doubled FROM read_parquet('{path}/*.parquet') WHERE id > 0")
+    assert sorted(df.toPandas()["doubled"]) == [2, 4]
+
+
+def test_sql_then_dataframe_operations(spark_session):
+    df = spark_session.sql("SELECT 1 AS x").withColumn("y", col("x") + 1)
+    assert df.toPandas().to_dict(orient="list") == {"x": [1], "y": [2]}
+
+
+def test_sql_with_invalid_query(spark_session):
+    with pytest.raises(Exception):
+        spark_session.sql("SELECT * FROM missing_table")
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import pytest
+
 
 def test_with_columns_renamed(spark_session):
     # Test withColumnRenamed
@@ -22,3 +24,33 @@ def test_with_columns_renamed(spark_session):
     # assert set(renamed_df.columns) == {"number", "character"}
     # assert "id" not in renamed_df.columns
     # assert [(row["number"], row["character"]) for row in collected] == [(0, 0), (1, 1)]
+
+
+def test_with_column_renamed_keeps_other_columns(spark_session):
+    df = spark_session.createDataFrame([(1, "a", True)], ["id", "name", "flag"])
+    renamed_df = df.withColumnRenamed("name", "label").withColumnRenamed("missing", "ignored")
+
+    assert renamed_df.columns == ["id", "label", "flag"]
+    assert renamed_df.collect()[0].asDict() == {"id": 1, "label": "a", "flag": True}
+
+
+def test_with_columns_renamed_map(spark_session):
+    df = spark_session.createDataFrame([(1, "a", True)], ["id", "name", "flag"])
+    renamed_df = df.withColumnsRenamed({"id": "key", "flag": "enabled"})
+
+    assert renamed_df.columns == ["key", "name", "enabled"]
+    assert renamed_df.collect()[0].asDict() == {"key": 1, "name": "a", "enabled": True}
+
+
+def test_to_df(spark_session):
+    df = spark_session.createDataFrame([(1, "a")], ["id", "name"])
+    renamed_df = df.toDF("x", "y")
+
+    assert renamed_df.columns == ["x", "y"]
+    assert renamed_df.collect()[0].asDict() == {"x": 1, "y": "a"}
+
+
+def test_to_df_with_wrong_number_of_names(spark_session):
+    df = spark_session.createDataFrame([(1, "a")], ["id", "name"])
+    with pytest.raises(Exception, match="Number of column names"):
+        df.toDF("x").collect()
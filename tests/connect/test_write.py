@@ -0,0 +1,77 @@
+from __future__ import annotations
+
+import os
+
+import pyarrow.parquet as papq
+import pytest
+
+
+def files(path, extension):
+    return sorted(
+        os.path.join(root, f) for root, _, filenames in os.walk(path) for f in filenames if f.endswith(extension)
+    )
+
+
+def test_write_parquet_with_partitioning(spark_session, tmp_path):
+    df = spark_session.createDataFrame([(1, "a"), (2, "b"), (3, "a")], ["id", "key"])
+    path = str(tmp_path / "parquet")
+    df.write.partitionBy("key").parquet(path)
+
+    assert sorted(os.listdir(path)) == ["key=a", "key=b"]
+
+    df_read = spark_session.read.parquet(os.path.join(path, "key=a"))
+    assert sorted(df_read.toPandas()["id"]) == [1, 3]
+
+
+def test_write_error_if_exists(spark_session, tmp_path):
+    df = spark_session.range(3)
+    path = str(tmp_path / "parquet")
+    df.write.parquet(path)
+
+    with pytest.raises(Exception, match="already exists"):
+        df.write.mode("error").parquet(path)
+
+
+def test_write_ignore(spark_session, tmp_path):
+    path = str(tmp_path / "parquet")
+    spark_session.range(3).write.parquet(path)
+    written = files(path, ".parquet")
+
+    spark_session.range(5).write.mode("ignore").parquet(path)
+    assert files(path, ".parquet") == written
+    assert len(spark_session.read.parquet(path).toPandas()) == 3
+
+
+def test_write_overwrite(spark_session, tmp_path):
+    path = str(tmp_path / "parquet")
+    spark_session.range(3).write.parquet(path)
+
+    spark_session.range(5).write.mode("overwrite").parquet(path)
+    assert len(spark_session.read.parquet(path).toPandas()) == 5
+
+
+def test_write_append(spark_session, tmp_path):
+    path = str(tmp_path / "parquet")
+    spark_session.range(3).write.parquet(path)
+
+    spark_session.range(5).write.mode("append").parquet(path)
+    assert len(spark_session.read.parquet(path).toPandas()) == 8
+
+
+def test_write_parquet_with_compression(spark_session, tmp_path):
+    path = str(tmp_path / "parquet")
+    spark_session.range(3).write.option("compression", "zstd").parquet(path)
+
+    for file in files(path, ".parquet"):
+        assert papq.ParquetFile(file).metadata.row_group(0).column(0).compression == "ZSTD"
+
+
+def test_write_csv_without_header(spark_session, tmp_path):
+    path = str(tmp_path / "csv")
+    spark_session.range(2).write.option("header", False).csv(path)
+
+    lines = []
+    for file in files(path, ".csv"):
+        with open(file) as f:
+            lines.extend(f.read().split())
+    assert sorted(lines) == ["0", "1"]
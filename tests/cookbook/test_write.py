@@ -219,6 +219,24 @@ def test_parquet_partitioned_write_with_some_empty_partitions(tmp_path, with_mor
     assert read_back == data
 
 
+@pytest.mark.parametrize("clustering", ["z-order", "hilbert"])
+def test_parquet_write_with_clustering(tmp_path, clustering, with_morsel_size):
+    points = [(x, y) for x in range(4) for y in range(4)]
+    df = daft.from_pydict({"x": [x for x, _ in reversed(points)], "y": [y for _, y in reversed(points)]})
+    df.write_parquet(tmp_path, cluster_by=["x", "y"], clustering=clustering)
+
+    read_back = daft.read_parquet(tmp_path.as_posix() + "/*.parquet").to_pydict()
+    assert sorted(zip(read_back["x"], read_back["y"])) == points
+    # both curves fill the quadrant at the origin before any other
+    assert set(zip(read_back["x"][:4], read_back["y"][:4])) == {(0, 0), (0, 1), (1, 0), (1, 1)}
+
+
+def test_parquet_write_with_clustering_by_string_column(tmp_path):
+    df = daft.from_pydict({"x": ["a", "b"]})
+    with pytest.raises(ValueError, match="Can only cluster by numeric or temporal columns"):
+        df.write_parquet(tmp_path, cluster_by=["x"])
+
+
 def test_csv_write(tmp_path, with_morsel_size):
     df = daft.read_csv(COOKBOOK_DATA_CSV)
 
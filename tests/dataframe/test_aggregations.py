@@ -592,3 +592,101 @@ def test_agg_with_groupby_key_in_agg(make_df, repartition_nparts, with_morsel_si
         "group_plus_1": [2, 3, 4],
         "id_plus_group": [7, 11, 15],
     }
+
+
+@pytest.mark.parametrize("partial_aggregation_threshold", [1, 10000])
+def test_agg_groupby_high_cardinality(make_df, partial_aggregation_threshold, with_morsel_size):
+    data = {"group": [i % 500 for i in range(1000)], "value": list(range(1000))}
+
+    with daft.execution_config_ctx(
+        partial_aggregation_threshold=partial_aggregation_threshold,
+        high_cardinality_aggregation_threshold=0.1,
+    ):
+        daft_df = make_df(data, repartition=4).groupby("group").agg(col("value").sum()).sort("group")
+        result = daft_df.to_pydict()
+
+    assert result == {"group": list(range(500)), "value": [2 * i + 500 for i in range(500)]}
+
+
+@pytest.mark.parametrize("partial_aggregation_threshold", [1, 10000])
+def test_agg_groupby_partial_and_final_phases(make_df, partial_aggregation_threshold, with_morsel_size):
+    data = {"group": [i % 3 for i in range(12)], "value": [i % 4 for i in range(12)]}
+
+    with daft.execution_config_ctx(partial_aggregation_threshold=partial_aggregation_threshold):
+        daft_df = (
+            make_df(data, repartition=4)
+            .groupby("group")
+            .agg(
+                col("value").sum().alias("sum"),
+                col("value").count().alias("count"),
+                col("value").min().alias("min"),
+                col("value").max().alias("max"),
+                col("value").mean().alias("mean"),
+                col("value").count_distinct().alias("count_distinct"),
+            )
+            .sort("group")
+        )
+        result = daft_df.to_pydict()
+
+    assert result == {
+        "group": [0, 1, 2],
+        "sum": [6, 6, 6],
+        "count": [4, 4, 4],
+        "min": [0, 0, 0],
+        "max": [3, 3, 3],
+        "mean": [1.5, 1.5, 1.5],
+        "count_distinct": [4, 4, 4],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_top_k_global(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df({"values": [4, 1, None, 7, 3, 7, 2]}, repartition=repartition_nparts)
+    daft_df = daft_df.agg(col("values").top_k(3).alias("top_k"))
+
+    assert daft_df.to_pydict() == {"top_k": [[7, 7, 4]]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_groupby_top_k_by(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 2, 3],
+            "values": [1, 5, 2, None, 6, 4, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").top_k_by("values", 2).sort("group")
+
+    assert daft_df.to_pydict() == {"group": [1, 2, 3], "values": [[5, 2], [6, 4], []]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_approx_heavy_hitters_global(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {"values": ["a", "b", "a", "c", "a", None, "b", "a", "d", "b", "a", "c"]},
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.agg(col("values").approx_heavy_hitters(2).alias("heavy_hitters"))
+
+    # Every partition has fewer distinct values than its summary can hold, so the counts are exact.
+    assert daft_df.to_pydict() == {
+        "heavy_hitters": [[{"value": "a", "count": 5}, {"value": "b", "count": 3}]],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_approx_heavy_hitters_groupby(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 2, 2, 2, 2],
+            "values": [3, 3, 1, 3, 2, 2, None, 5],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg(col("values").approx_heavy_hitters(1).alias("heavy_hitters")).sort("group")
+
+    assert daft_df.to_pydict() == {
+        "group": [1, 2],
+        "heavy_hitters": [[{"value": 3, "count": 3}], [{"value": 2, "count": 2}]],
+    }
@@ -0,0 +1,44 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col
+from daft.exceptions import DaftCoreException
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
+def test_assert_unique_passes_rows_through(make_df, repartition_nparts, with_morsel_size):
+    df = make_df(
+        {
+            "id": [1, 2, 2, None],
+            "values": ["a", "b", "c", "a"],
+        },
+        repartition=repartition_nparts,
+    )
+    df = df.assert_unique("id", "values").sort("values")
+    assert df.to_pydict() == {"id": [1, None, 2, 2], "values": ["a", "a", "b", "c"]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
+def test_assert_unique_fails_on_duplicates(make_df, repartition_nparts, with_morsel_size):
+    df = make_df(
+        {
+            "id": [1, 2, 3, 2, None, None],
+            "values": ["a", "b", "c", "d", "e", "f"],
+        },
+        repartition=repartition_nparts,
+    )
+    with pytest.raises(DaftCoreException, match="Found 2 rows with duplicate keys"):
+        df.assert_unique(col("id")).collect()
+
+
+def test_assert_unique_names_duplicate_keys(make_df):
+    df = make_df({"id": [1, 2, 2], "values": ["a", "b", "b"]})
+    with pytest.raises(DaftCoreException, match=r"\(id=2, values=b\)"):
+        df.assert_unique("id", "values").collect()
+
+
+def test_assert_unique_requires_keys(make_df):
+    df = make_df({"id": [1, 2, 3]})
+    with pytest.raises(DaftCoreException, match="at least one column"):
+        df.assert_unique()
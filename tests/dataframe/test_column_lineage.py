@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+import daft
+from daft import col
+
+
+def _lineage(df):
+    lineage = df.column_lineage()
+    return {
+        column["name"]: {(lineage["sources"][s["source"]]["name"], s["column"]) for s in column["sources"]}
+        for column in lineage["columns"]
+    }
+
+
+def test_column_lineage_of_scan(tmp_path):
+    daft.from_pydict({"a": [1, 2], "b": ["x", "y"]}).write_parquet(str(tmp_path))
+    df = daft.read_parquet(str(tmp_path))
+
+    lineage = df.column_lineage()
+    assert len(lineage["sources"]) == 1
+    assert len(lineage["sources"][0]["files"]) == 1
+    assert [c["name"] for c in lineage["columns"]] == ["a", "b"]
+
+
+def test_column_lineage_through_join_and_aggregation():
+    left = daft.from_pydict({"id": [1, 2], "x": [3, 4]})
+    right = daft.from_pydict({"id": [1, 2], "y": [5, 6]})
+    df = (
+        left.join(right, on="id")
+        .with_column("z", col("x") * col("y"))
+        .groupby("id")
+        .agg(col("z").sum(), col("x").max().alias("max_x"))
+    )
+
+    lineage = _lineage(df)
+    [left_name] = {name for name, _ in lineage["max_x"]}
+    right_name = next(name for name, column in lineage["z"] if column == "y")
+    assert lineage["z"] == {(left_name, "x"), (right_name, "y")}
+    assert lineage["max_x"] == {(left_name, "x")}
+    assert (left_name, "id") in lineage["id"]
+
+
+def test_column_lineage_of_literals():
+    df = daft.from_pydict({"a": [1, 2]}).select(daft.lit(1).alias("one"), col("a"))
+    lineage = _lineage(df)
+    assert lineage["one"] == set()
+    assert {column for _, column in lineage["a"]} == {"a"}
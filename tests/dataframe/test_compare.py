@@ -0,0 +1,95 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft.testing import assert_df_equal, diff_counts
+
+
+def test_compare():
+    df1 = daft.from_pydict({"id": [1, 2, 3, 4], "a": [1, 2, 3, None], "b": ["x", "y", "z", "w"]})
+    df2 = daft.from_pydict({"id": [2, 3, 4, 5], "a": [2, 30, 4, 5], "b": ["y", "z", "w", "v"]})
+
+    diff = df1.compare(df2, on="id").sort("id")
+    assert diff.column_names == ["id", "diff", "left.a", "right.a", "changed.a", "left.b", "right.b", "changed.b"]
+    assert diff.to_pydict() == {
+        "id": [1, 3, 4, 5],
+        "diff": ["removed", "changed", "changed", "added"],
+        "left.a": [1, 3, None, None],
+        "right.a": [None, 30, 4, 5],
+        "changed.a": [False, True, True, False],
+        "left.b": ["x", "z", "w", None],
+        "right.b": [None, "z", "w", "v"],
+        "changed.b": [False, False, False, False],
+    }
+
+
+def test_compare_with_multiple_keys():
+    df1 = daft.from_pydict({"k1": [1, 1], "k2": ["a", "b"], "v": [1, 2]})
+    df2 = daft.from_pydict({"k2": ["b", "a"], "k1": [1, 1], "v": [3, 1]})
+
+    diff = df1.compare(df2, on=["k1", "k2"])
+    assert diff.to_pydict() == {
+        "k1": [1],
+        "k2": ["b"],
+        "diff": ["changed"],
+        "left.v": [2],
+        "right.v": [3],
+        "changed.v": [True],
+    }
+
+
+def test_compare_equal_dataframes():
+    df = daft.from_pydict({"id": [1, 2], "v": [None, "x"]})
+    assert len(df.compare(df, on="id").collect()) == 0
+
+
+def test_compare_with_mismatched_schemas():
+    df1 = daft.from_pydict({"id": [1], "v": [1]})
+    df2 = daft.from_pydict({"id": [1], "v": ["1"]})
+    with pytest.raises(Exception, match="same columns with the same types"):
+        df1.compare(df2, on="id")
+
+
+def test_compare_with_missing_key():
+    df = daft.from_pydict({"id": [1], "v": [1]})
+    with pytest.raises(Exception, match="Key column missing"):
+        df.compare(df, on="missing")
+
+
+def test_compare_with_duplicate_keys():
+    df1 = daft.from_pydict({"id": [1, 1], "v": [1, 2]})
+    df2 = daft.from_pydict({"id": [1], "v": [1]})
+    with pytest.raises(Exception):
+        df1.compare(df2, on="id").collect()
+
+
+def test_diff_counts():
+    df1 = daft.from_pydict({"id": [1, 2, 3], "a": [1, 2, 3], "b": [1, 2, 3]})
+    df2 = daft.from_pydict({"id": [2, 3, 4], "a": [20, 30, 4], "b": [2, 30, 4]})
+    assert diff_counts(df1.compare(df2, on="id")) == {
+        "added": 1,
+        "removed": 1,
+        "changed": 2,
+        "changed.a": 2,
+        "changed.b": 1,
+    }
+
+
+def test_assert_df_equal():
+    df1 = daft.from_pydict({"id": [1, 2], "v": ["a", "b"]})
+    df2 = daft.from_pydict({"id": [2, 1], "v": ["b", "a"]})
+    assert_df_equal(df1, df2, on="id")
+    assert_df_equal(df1, df2)
+
+
+def test_assert_df_equal_fails():
+    actual = daft.from_pydict({"id": [1, 2], "v": ["a", "x"]})
+    expected = daft.from_pydict({"id": [1, 2, 3], "v": ["a", "b", "c"]})
+    with pytest.raises(AssertionError, match=r"0 added, 1 removed, 1 changed; changed columns: v \(1\)"):
+        assert_df_equal(actual, expected, on="id")
+
+
+def test_assert_df_equal_with_different_schemas():
+    with pytest.raises(AssertionError, match="different schemas"):
+        assert_df_equal(daft.from_pydict({"a": [1]}), daft.from_pydict({"b": [1]}))
@@ -0,0 +1,38 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+from daft.context import get_context
+
+
+def test_with_execution_config_overrides_only_the_dataframe():
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    overridden = df.with_execution_config(default_morsel_size=1, native_parallelism=1)
+
+    config = overridden._builder.execution_config()
+    assert config.default_morsel_size == 1
+    assert config.native_parallelism == 1
+    assert df._builder.execution_config().native_parallelism == get_context().daft_execution_config.native_parallelism
+
+
+def test_with_execution_config_is_kept_by_later_operations():
+    df = daft.from_pydict({"a": [1, 2, 3], "b": [4, 5, 6]}).with_execution_config(default_morsel_size=1)
+    other = daft.from_pydict({"a": [1, 2], "c": [7, 8]})
+
+    df = df.where(col("a") > 1).join(other, on="a").with_column("d", col("b") + col("c"))
+    assert df._builder.execution_config().default_morsel_size == 1
+    assert df.sort("a").to_pydict() == {"a": [2], "b": [5], "c": [8], "d": [13]}
+
+
+def test_with_execution_config_results():
+    df = daft.from_pydict({"a": list(range(100))}).with_execution_config(default_morsel_size=1, native_parallelism=1)
+    df = df.groupby(col("a") % 3).agg(col("a").count().alias("count")).sort("a")
+    assert df.to_pydict() == {"a": [0, 1, 2], "count": [34, 33, 33]}
+
+
+def test_with_execution_config_rejects_invalid_values():
+    df = daft.from_pydict({"a": [1]})
+    with pytest.raises(ValueError):
+        df.with_execution_config(native_parallelism=0)
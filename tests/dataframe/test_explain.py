@@ -0,0 +1,31 @@
+from __future__ import annotations
+
+import io
+
+import daft
+from daft import col
+
+
+def test_explain_mermaid_shows_schemas():
+    df = daft.from_pydict({"a": [1, 2], "b": ["x", "y"]}).where(col("a") > 1)
+    mermaid = df.explain(format="mermaid")
+    assert mermaid.startswith("```mermaid\nflowchart TD\n")
+    # Every node shows its output schema, not only the source
+    assert mermaid.count("Output schema = a#Int64, b#Utf8") == 2
+
+
+def test_explain_html():
+    df = daft.from_pydict({"a": [1, 2]}).where(col("a") < 2)
+    page = df.explain(format="html", show_all=True)
+    assert page.startswith("<!DOCTYPE html>")
+    assert '<pre class="mermaid">' in page
+    assert "Optimized LogicalPlan" in page
+    # Node labels are escaped so that they are shown as text by the browser
+    assert "col(a) &lt; lit(2)" in page
+
+
+def test_explain_html_to_file():
+    df = daft.from_pydict({"a": [1, 2]})
+    out = io.StringIO()
+    df.explain(format="html", file=out)
+    assert out.getvalue().startswith("<!DOCTYPE html>")
@@ -40,3 +40,13 @@ def test_explode_bad_col_type(make_df):
     df = make_df({"a": [1, 2, 3]})
     with pytest.raises(ValueError, match="cannot be exploded"):
         df = df.explode(col("a"))
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_explode_multiple_partitions(make_df, repartition_nparts):
+    df = make_df(
+        {"id": [0, 1, 2, 3], "nested": [[1, 2], [], [3], None]},
+        repartition=repartition_nparts,
+    )
+    df = df.explode(col("nested")).where(col("nested").not_null()).sort("nested")
+    assert df.to_pydict() == {"id": [0, 0, 2], "nested": [1, 2, 3]}
@@ -7,19 +7,14 @@ import daft
 from daft import col
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
-from tests.conftest import get_tests_daft_runner_name
 from tests.utils import sort_arrow_table
 
 
 def skip_invalid_join_strategies(join_strategy, join_type):
-    if get_tests_daft_runner_name() == "native":
-        if join_strategy not in [None, "hash"]:
-            pytest.skip("Native executor fails for these tests")
-    else:
-        if (join_strategy == "sort_merge" or join_strategy == "sort_merge_aligned_boundaries") and join_type != "inner":
-            pytest.skip("Sort merge currently only supports inner joins")
-        elif join_strategy == "broadcast" and join_type == "outer":
-            pytest.skip("Broadcast join does not support outer joins")
+    if (join_strategy == "sort_merge" or join_strategy == "sort_merge_aligned_boundaries") and join_type != "inner":
+        pytest.skip("Sort merge currently only supports inner joins")
+    elif join_strategy == "broadcast" and join_type == "outer":
+        pytest.skip("Broadcast join does not support outer joins")
 
 
 def test_invalid_join_strategies(make_df):
@@ -1254,3 +1249,106 @@ def test_join_empty(join_type, repartition_nparts, left, right, expected, make_d
     expected_result = expected[join_type]
 
     assert result.to_pydict() == expected_result
+
+
+@pytest.mark.parametrize("join_type", ["inner", "left", "right", "outer", "semi", "anti"])
+def test_join_with_skew_salting(join_type, make_df):
+    # Most of the rows of both sides share the hot key 0.
+    left = make_df(
+        {"a": [0] * 60 + list(range(1, 21)), "b": list(range(80))},
+        repartition=4,
+    )
+    right = make_df(
+        {"c": [0] * 5 + list(range(10, 30)), "d": list(range(25))},
+        repartition=3,
+    )
+
+    def run_join():
+        joined = left.join(right, left_on="a", right_on="c", how=join_type, strategy="hash")
+        return joined.sort(joined.column_names).to_pydict()
+
+    expected = run_join()
+    with daft.execution_config_ctx(
+        enable_skew_join_salting=True,
+        skew_join_salt_factor=4,
+        skew_join_heavy_hitter_threshold=0.5,
+    ):
+        assert run_join() == expected
+
+
+@pytest.mark.parametrize("join_type", ["inner", "left", "right", "outer", "semi", "anti"])
+@pytest.mark.parametrize("left_partitions, right_partitions", [(2, 4), (4, 2), (3, 3)])
+def test_join_co_partitioned(join_type, left_partitions, right_partitions, make_df):
+    left = make_df({"a": list(range(20)), "b": list(range(20))}).repartition(left_partitions, "a")
+    right = make_df({"a": list(range(10, 30)), "c": list(range(20))}).repartition(right_partitions, "a")
+
+    joined = left.join(right, on="a", how=join_type, strategy="hash")
+    result = joined.sort("a").to_pydict()
+
+    expected = (
+        make_df({"a": list(range(20)), "b": list(range(20))})
+        .join(make_df({"a": list(range(10, 30)), "c": list(range(20))}), on="a", how=join_type, strategy="hash")
+        .sort("a")
+        .to_pydict()
+    )
+    assert result == expected
+
+
+@pytest.mark.parametrize("join_strategy", [None, "sort_merge"])
+def test_join_sorted_inputs(join_strategy, make_df):
+    # Both sides are sorted on their join keys, so the join can merge them without a hash build.
+    left = make_df({"a": [5, 3, None, 1, 3, 4], "b": list(range(6))}, repartition=2).sort("a")
+    right = make_df({"c": [3, 1, 2, None, 3, 5], "d": list(range(6))}, repartition=3).sort("c")
+
+    joined = left.join(right, left_on="a", right_on="c", strategy=join_strategy)
+    result = joined.sort(["a", "b", "d"]).to_pydict()
+
+    assert result == {
+        "a": [1, 3, 3, 3, 3, 5],
+        "b": [3, 1, 1, 4, 4, 0],
+        "c": [1, 3, 3, 3, 3, 5],
+        "d": [1, 0, 4, 0, 4, 5],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_range_join_point_in_interval(repartition_nparts, make_df, with_morsel_size):
+    points = make_df({"pos": [1, 5, 10, None, 15], "name": ["a", "b", "c", "d", "e"]}, repartition=repartition_nparts)
+    intervals = make_df(
+        {"start": [0, 4, 5, None, 12], "end": [5, 10, 5, 20, 14], "id": [0, 1, 2, 3, 4]},
+        repartition=repartition_nparts,
+    )
+
+    df = points.join(intervals, how="cross").where(
+        (col("pos") >= col("start")) & (col("pos") < col("end")) & (col("id") != 0)
+    )
+    assert df.sort(["pos", "id"]).to_pydict() == {
+        "pos": [5],
+        "name": ["b"],
+        "start": [4],
+        "end": [10],
+        "id": [1],
+    }
+
+    df = points.join(intervals, how="cross").where(col("pos").between(col("start"), col("end")))
+    assert df.sort(["pos", "id"]).to_pydict() == {
+        "pos": [1, 5, 5, 5, 10],
+        "name": ["a", "b", "b", "b", "c"],
+        "start": [0, 0, 4, 5, 4],
+        "end": [5, 5, 10, 5, 10],
+        "id": [0, 0, 1, 2, 1],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_range_join_interval_overlap(repartition_nparts, make_df, with_morsel_size):
+    left = make_df({"l_start": [0, 10, 20], "l_end": [5, 15, 25]}, repartition=repartition_nparts)
+    right = make_df({"r_start": [3, 5, 14, 30], "r_end": [4, 12, 21, 31]}, repartition=repartition_nparts)
+
+    df = left.join(right, how="cross").where((col("l_start") < col("r_end")) & (col("r_start") < col("l_end")))
+    assert df.sort(["l_start", "r_start"]).to_pydict() == {
+        "l_start": [0, 10, 10, 20],
+        "l_end": [5, 15, 15, 25],
+        "r_start": [3, 5, 14, 14],
+        "r_end": [4, 12, 21, 21],
+    }
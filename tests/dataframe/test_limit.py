@@ -0,0 +1,41 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+from tests.conftest import get_tests_daft_runner_name
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 3, 7])
+@pytest.mark.parametrize("limit", [0, 1, 5, 20, 100])
+def test_limit(make_df, repartition_nparts, limit, with_morsel_size):
+    df = make_df({"x": list(range(20))}, repartition=repartition_nparts)
+    result = df.limit(limit).to_pydict()["x"]
+    assert len(result) == min(limit, 20)
+    assert set(result) <= set(range(20))
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 3, 7])
+def test_limit_after_sort(make_df, repartition_nparts, with_morsel_size):
+    df = make_df({"x": list(range(20))}, repartition=repartition_nparts)
+    assert df.sort("x", desc=True).limit(3).to_pydict() == {"x": [19, 18, 17]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Only the native runner stops its inputs early in this process"
+)
+def test_limit_stops_reading_input_early():
+    num_partitions = 100
+    calls = []
+
+    @daft.udf(return_dtype=daft.DataType.int64())
+    def record_calls(x):
+        calls.append(len(x))
+        return x
+
+    with daft.context.execution_config_ctx(default_morsel_size=10):
+        df = daft.from_pydict({"x": list(range(num_partitions * 10))}).into_partitions(num_partitions)
+        df = df.with_column("y", record_calls(col("x"))).limit(1)
+        assert len(df.to_pydict()["y"]) == 1
+    assert sum(calls) < num_partitions * 10
@@ -1,5 +1,9 @@
 from __future__ import annotations
 
+import pytest
+
+from tests.conftest import get_tests_daft_runner_name
+
 
 def test_into_partitions_some_empty(make_df) -> None:
     data = {"foo": [1, 2, 3]}
@@ -23,3 +27,42 @@ def test_into_partitions_some_no_split(make_df) -> None:
     df = df.into_partitions(4).collect()
 
     assert df.to_pydict() == data
+
+
+@pytest.mark.parametrize("desc", [False, True])
+def test_repartition_by_range(make_df, desc) -> None:
+    data = {"foo": list(range(100)), "bar": [str(i) for i in range(100)]}
+    df = make_df(data).into_partitions(4).repartition_by_range(5, "foo", desc=desc).collect()
+    assert sorted(df.to_pydict()["foo"]) == data["foo"]
+
+    # Each partition holds a range of values that doesn't overlap with the other partitions.
+    parts = [p.to_pydict()["foo"] for p in df.iter_partitions()]
+    ranges = [(min(part), max(part)) for part in parts if len(part) > 0]
+    ranges = sorted(ranges)
+    assert all(prev[1] < curr[0] for prev, curr in zip(ranges, ranges[1:]))
+
+
+def test_repartition_by_range_requires_columns(make_df) -> None:
+    with pytest.raises(ValueError):
+        make_df({"foo": [1, 2, 3]}).repartition_by_range(2)
+
+
+def test_repartition_by_hash(make_df) -> None:
+    data = {"foo": [i % 10 for i in range(100)]}
+    df = make_df(data).repartition(3, "foo").collect()
+    assert sorted(df.to_pydict()["foo"]) == sorted(data["foo"])
+
+    # Each value is in a single partition.
+    parts = [set(p.to_pydict()["foo"]) for p in df.iter_partitions()]
+    assert sum(len(part) for part in parts) == 10
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native",
+    reason="Only the native runner spreads rows round-robin, the other runners shuffle them randomly",
+)
+def test_repartition_without_keys_balances_partitions(make_df) -> None:
+    data = {"foo": list(range(100))}
+    df = make_df(data).repartition(4).collect()
+    assert sorted(df.to_pydict()["foo"]) == data["foo"]
+    assert [len(p) for p in df.iter_partitions()] == [25, 25, 25, 25]
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import daft
+
 
 def test_show_default(make_df, valid_data):
     df = make_df(valid_data)
@@ -72,3 +74,13 @@ def test_show_not_from_cached_repr(make_df, valid_data, data_source):
     assert len(df_display.preview.preview_partition) == len(valid_data)
     assert df_display.preview.dataframe_num_rows == 3
     assert df_display.num_rows == 3
+
+
+def test_show_many_partitions():
+    df = daft.from_pydict({"a": list(range(100))}).into_partitions(10)
+    df_display = df._construct_show_display(5)
+
+    rows = df_display.preview.preview_partition.to_pydict()["a"]
+    assert len(rows) == 5
+    assert set(rows).issubset(range(100))
+    assert df_display.num_rows == 5
@@ -148,3 +148,40 @@ def test_pre_shuffle_merge_randomly_sized_partitions(pre_shuffle_merge_ctx, inpu
             .collect()
         )
         assert len(df) == input_partitions * output_partitions
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "ray",
+    reason="shuffle tests are meant for the ray runner",
+)
+@pytest.mark.parametrize(
+    "input_partitions, output_partitions",
+    [(10, 10), (10, 1), (10, 20)],
+)
+def test_flight_shuffle(input_partitions, output_partitions):
+    import ray
+
+    def num_rows_fn():
+        return 100
+
+    def bytes_per_row_fn():
+        return 10
+
+    # a memory limit of 0 makes the shuffle workers spill every partition
+    with daft.execution_config_ctx(shuffle_algorithm="flight_shuffle", repartition_memory_limit_bytes=0):
+        df = read_generator(
+            generator(input_partitions, num_rows_fn, bytes_per_row_fn),
+            schema=daft.Schema._from_field_name_and_types(
+                [
+                    ("ints", daft.DataType.uint64()),
+                    ("bytes", daft.DataType.binary()),
+                ]
+            ),
+        ).repartition(output_partitions, "ints")
+        partitions = [ray.get(part).to_pydict()["ints"] for part in df.iter_partitions()]
+
+    assert len(partitions) == output_partitions
+    assert sum(len(part) for part in partitions) == input_partitions * 100
+    # every key ends up in a single partition
+    keys = [set(part) for part in partitions]
+    assert sum(len(k) for k in keys) == len(set().union(*keys))
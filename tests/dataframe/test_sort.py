@@ -5,6 +5,7 @@ import math
 import pyarrow as pa
 import pytest
 
+import daft
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
 
@@ -216,6 +217,46 @@ def test_sort_with_nulls_multikey(make_df, repartition_nparts, with_morsel_size)
     assert pa.Table.from_pydict(daft_df.to_pydict()) == expected
 
 
+@pytest.mark.parametrize("desc", [True, False])
+@pytest.mark.parametrize("nulls_first", [True, False])
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_int_sort_with_nulls_first(make_df, desc, nulls_first, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "id": [2, None, 1, 3, None],
+            "values": ["a1", "b1", "c1", "d1", "e1"],
+        },
+        repartition=repartition_nparts,
+    )
+    result = daft_df.sort("id", desc=desc, nulls_first=nulls_first).to_pydict()
+
+    non_null = [1, 2, 3][::-1] if desc else [1, 2, 3]
+    expected = [None, None] + non_null if nulls_first else non_null + [None, None]
+    assert result["id"] == expected
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 4, 6])
+def test_sort_with_nulls_first_multikey(make_df, repartition_nparts, with_morsel_size):
+    daft_df = make_df(
+        {
+            "id1": [2, None, 2, None, 1],
+            "id2": [2, None, 1, 1, None],
+            "values": ["a1", "b1", "c1", "d1", "e1"],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.sort(["id1", "id2"], desc=[False, True], nulls_first=[True, False])
+
+    expected = pa.Table.from_pydict(
+        {
+            "id1": [None, None, 1, 2, 2],
+            "id2": [1, None, None, 2, 1],
+            "values": ["d1", "b1", "e1", "a1", "c1"],
+        }
+    )
+    assert pa.Table.from_pydict(daft_df.to_pydict()) == expected
+
+
 @pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
 def test_sort_with_all_nulls(make_df, repartition_nparts, with_morsel_size):
     daft_df = make_df(
@@ -260,3 +301,98 @@ def test_sort_with_all_null_type_column(make_df):
 
     with pytest.raises((ExpressionTypeError, ValueError)):
         daft_df = daft_df.sort(daft_df["id"])
+
+
+def test_sort_with_compressed_buffered_partitions(make_df):
+    data = {"id": list(range(100, 0, -1)), "s": [f"value {i % 7}" for i in range(100)]}
+    with daft.execution_config_ctx(compress_buffered_partitions=True):
+        df = make_df(data, repartition=4).sort("id")
+        result = df.to_pydict()
+
+    assert result["id"] == list(range(1, 101))
+    assert result["s"] == [f"value {i % 7}" for i in range(99, -1, -1)]
+
+
+@pytest.mark.parametrize("desc", [True, False])
+@pytest.mark.parametrize("nulls_first", [True, False])
+def test_sort_large_input_with_nulls(make_df, desc, nulls_first):
+    # Large enough for the native executor to sort ranges of the input in parallel
+    ids = [None if i % 97 == 0 else (i * 7919) % 150_001 for i in range(150_000)]
+    df = make_df({"id": ids}, repartition=3).sort("id", desc=desc, nulls_first=nulls_first)
+
+    non_null = sorted((i for i in ids if i is not None), reverse=desc)
+    nulls = [None] * (len(ids) - len(non_null))
+    expected = nulls + non_null if nulls_first else non_null + nulls
+    assert df.to_pydict()["id"] == expected
+
+
+@pytest.mark.parametrize("desc", [True, False])
+@pytest.mark.parametrize("nulls_first", [True, False])
+def test_sort_spilled_runs(make_df, desc, nulls_first):
+    # A memory limit of one byte spills every buffered morsel as a run, and small morsels make the
+    # runs merge over several batches each
+    ids = [None if i % 13 == 0 else (i * 31) % 101 for i in range(300)]
+    data = {"id": ids, "group": [i % 3 for i in range(300)]}
+    with daft.execution_config_ctx(sort_memory_limit_bytes=1, default_morsel_size=7):
+        df = make_df(data, repartition=4).sort(["group", "id"], desc=[False, desc], nulls_first=[False, nulls_first])
+        result = df.to_pydict()
+
+    expected = []
+    for group in range(3):
+        group_ids = [id for i, id in enumerate(ids) if i % 3 == group]
+        non_null = sorted((id for id in group_ids if id is not None), reverse=desc)
+        nulls = [None] * (len(group_ids) - len(non_null))
+        expected.extend(nulls + non_null if nulls_first else non_null + nulls)
+    assert result["id"] == expected
+    assert result["group"] == sorted(data["group"])
+
+
+@pytest.mark.parametrize("desc", [True, False])
+@pytest.mark.parametrize("nulls_first", [True, False])
+@pytest.mark.parametrize("limit", [0, 1, 10, 1000])
+def test_sort_then_limit(make_df, desc, nulls_first, limit):
+    # Small morsels make each worker of the native executor keep only its top rows several times
+    ids = [None if i % 13 == 0 else (i * 31) % 101 for i in range(300)]
+    data = {"id": ids, "group": [i % 3 for i in range(300)]}
+    with daft.execution_config_ctx(default_morsel_size=7):
+        df = make_df(data, repartition=4).sort(["group", "id"], desc=[False, desc], nulls_first=[False, nulls_first])
+        result = df.limit(limit).to_pydict()
+
+    expected = []
+    for group in range(3):
+        group_ids = [id for i, id in enumerate(ids) if i % 3 == group]
+        non_null = sorted((id for id in group_ids if id is not None), reverse=desc)
+        nulls = [None] * (len(group_ids) - len(non_null))
+        expected.extend(nulls + non_null if nulls_first else non_null + nulls)
+    assert result["id"] == expected[:limit]
+    assert result["group"] == sorted(data["group"])[:limit]
+
+
+def test_sort_then_limit_is_fused(make_df):
+    df = make_df({"id": list(range(10))}).sort("id", desc=True).limit(3)
+
+    assert "TopN" in df._builder.optimize().pretty_print()
+    assert df.to_pydict() == {"id": [9, 8, 7]}
+
+
+def test_sort_of_collected_sorted_rows_is_removed(make_df):
+    df = make_df({"id": [3, 1, 2], "x": ["c", "a", "b"]}).sort("id").collect()
+
+    # the cached rows keep the order of the sort, so sorting them again by the same key is redundant
+    resorted = df.sort("id")
+    assert "Sort order = (col(id), ascending, nulls last)" in resorted._builder.pretty_print()
+    physical_plan = resorted._builder.optimize().to_physical_plan_scheduler(resorted.execution_config())
+    assert "Sort" not in physical_plan.pretty_print().replace("Sort order", "")
+    assert resorted.to_pydict() == {"id": [1, 2, 3], "x": ["a", "b", "c"]}
+
+
+def test_sort_with_default_collation(make_df):
+    df = make_df({"name": ["b", "C", "a", "B"]})
+
+    # the collation applies to filters and sorts of DataFrames created before it was set
+    with daft.context.planning_config_ctx(default_collation="case_insensitive"):
+        sorted_names = df.sort("name").to_pydict()["name"]
+        filtered_names = df.where(daft.col("name") == "b").to_pydict()["name"]
+    assert [name.lower() for name in sorted_names] == ["a", "b", "b", "c"]
+    assert sorted(filtered_names) == ["B", "b"]
+    assert df.sort("name").to_pydict() == {"name": ["B", "C", "a", "b"]}
@@ -254,3 +254,18 @@ def test_unpivot_empty_partition(make_df):
     }
 
     assert df.to_pydict() == expected
+
+
+@pytest.mark.parametrize("n_partitions", [1, 2, 4])
+def test_unpivot_pivot_roundtrip(make_df, n_partitions, with_morsel_size):
+    data = {
+        "id": ["x", "y", "z"],
+        "a": [1, 3, 5],
+        "b": [2, 4, 6],
+    }
+    df = make_df(data, repartition=n_partitions)
+
+    df = df.unpivot("id", ["a", "b"]).pivot(group_by="id", pivot_col="variable", value_col="value", agg_fn="sum")
+    df = df.select("id", "a", "b").sort("id")
+
+    assert df.to_pydict() == data
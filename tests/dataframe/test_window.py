@@ -0,0 +1,107 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col, window
+from tests.conftest import get_tests_daft_runner_name
+
+native_only = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Window functions can only be evaluated by the native runner"
+)
+
+
+def sorted_rows(df, by):
+    return df.sort(by).to_pydict()
+
+
+@native_only
+@pytest.mark.parametrize("repartition_nparts", [1, 3])
+def test_row_number_and_rank(make_df, repartition_nparts):
+    df = make_df(
+        {"k": ["a", "a", "b", "a", "b", "a"], "x": [3, 1, 2, 1, 5, 4], "id": [0, 1, 2, 3, 4, 5]},
+        repartition=repartition_nparts,
+    )
+    df = df.window(window.rank(), partition_by="k", order_by="x")
+    assert sorted_rows(df, "id") == {
+        "k": ["a", "a", "b", "a", "b", "a"],
+        "x": [3, 1, 2, 1, 5, 4],
+        "id": [0, 1, 2, 3, 4, 5],
+        "rank": [3, 1, 1, 1, 2, 4],
+    }
+
+    df = make_df({"k": ["a", "a", "b", "a"], "x": [3, 1, 2, 2]}, repartition=repartition_nparts)
+    df = df.window(window.row_number().alias("rn"), partition_by="k", order_by="x", desc=True)
+    assert sorted_rows(df, ["k", "rn"]) == {"k": ["a", "a", "a", "b"], "x": [3, 2, 1, 2], "rn": [1, 2, 3, 1]}
+
+
+@native_only
+def test_lag_and_lead():
+    df = daft.from_pydict({"k": [1, 1, 1, 2, 2], "t": [1, 2, 3, 1, 2], "x": [10, 20, 30, 40, 50]})
+    df = df.window(
+        window.lag(col("x")).alias("prev"),
+        window.lead(col("x"), 2).alias("next_next"),
+        partition_by="k",
+        order_by="t",
+    )
+    assert sorted_rows(df, ["k", "t"]) == {
+        "k": [1, 1, 1, 2, 2],
+        "t": [1, 2, 3, 1, 2],
+        "x": [10, 20, 30, 40, 50],
+        "prev": [None, 10, 20, None, 40],
+        "next_next": [30, None, None, None, None],
+    }
+
+
+@native_only
+def test_running_and_sliding_aggregations():
+    df = daft.from_pydict({"k": ["a"] * 4 + ["b"] * 2, "t": [1, 2, 3, 4, 1, 2], "x": [1, 2, None, 4, 5, 6]})
+    df = df.window(
+        window.sum(col("x")).alias("running_sum"),
+        window.sum(col("x"), preceding=1, following=1).alias("sliding_sum"),
+        window.mean(col("x"), preceding=1).alias("sliding_mean"),
+        window.sum(col("x"), preceding=None, following=None).alias("total"),
+        partition_by="k",
+        order_by="t",
+    )
+    assert sorted_rows(df, ["k", "t"]) == {
+        "k": ["a", "a", "a", "a", "b", "b"],
+        "t": [1, 2, 3, 4, 1, 2],
+        "x": [1, 2, None, 4, 5, 6],
+        "running_sum": [1, 3, 3, 7, 5, 11],
+        "sliding_sum": [3, 3, 6, 4, 11, 11],
+        "sliding_mean": [1.0, 1.5, 2.0, 4.0, 5.0, 5.5],
+        "total": [7, 7, 7, 7, 11, 11],
+    }
+
+
+@native_only
+def test_window_without_partitions():
+    df = daft.from_pydict({"x": [3, None, 1, 2]})
+    df = df.window(window.row_number(), window.sum(col("x")).alias("running"), order_by="x", nulls_first=True)
+    assert sorted_rows(df, "row_number") == {
+        "x": [None, 1, 2, 3],
+        "row_number": [1, 2, 3, 4],
+        "running": [None, 1, 3, 6],
+    }
+
+
+@native_only
+def test_window_with_null_partition_keys():
+    df = daft.from_pydict({"k": [None, "a", None], "x": [1, 2, 3]})
+    df = df.window(window.sum(col("x"), preceding=None, following=None).alias("total"), partition_by="k")
+    assert sorted_rows(df, "x") == {"k": [None, "a", None], "x": [1, 2, 3], "total": [4, 2, 4]}
+
+
+def test_rank_requires_order():
+    df = daft.from_pydict({"x": [1, 2]})
+    with pytest.raises(Exception, match="rank\\(\\) requires the window to be ordered"):
+        df.window(window.rank())
+
+
+def test_window_requires_functions():
+    df = daft.from_pydict({"x": [1, 2]})
+    with pytest.raises(ValueError, match="at least one window function"):
+        df.window()
+    with pytest.raises(TypeError, match="only accepts window functions"):
+        df.window(col("x").sum())
@@ -0,0 +1,138 @@
+from __future__ import annotations
+
+import datetime
+import itertools
+
+import pytest
+
+import daft
+from daft import col
+from tests.conftest import get_tests_daft_runner_name
+
+native_only = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Windows can only be aggregated by the native runner"
+)
+
+SCHEMA = daft.Schema._from_field_name_and_types(
+    [("t", daft.DataType.int64()), ("k", daft.DataType.string()), ("x", daft.DataType.int64())]
+)
+
+
+@native_only
+def test_tumbling_windows():
+    df = daft.from_pydict({"t": [1, 4, 6, 12], "x": [1, 2, 3, 4]})
+    df = df.window_agg("t", 5, col("x").sum(), col("x").count().alias("count"))
+    assert df.sort("window_start").to_pydict() == {
+        "window_start": [0, 5, 10],
+        "window_end": [5, 10, 15],
+        "x": [3, 3, 4],
+        "count": [2, 1, 1],
+    }
+
+
+@native_only
+def test_hopping_windows():
+    df = daft.from_pydict({"t": [1, 4, 6], "x": [1, 2, 3]})
+    df = df.window_agg("t", 4, col("x").sum(), window_slide=2)
+    assert df.sort("window_start").to_pydict() == {
+        "window_start": [-2, 0, 2, 4, 6],
+        "window_end": [2, 4, 6, 8, 10],
+        "x": [1, 1, 2, 5, 3],
+    }
+
+
+@native_only
+def test_windows_with_group_by():
+    df = daft.from_pydict({"t": [1, 2, 3, 7], "k": ["a", "b", "a", "a"], "x": [1, 2, 3, 4]})
+    df = df.window_agg("t", 5, col("x").sum(), col("x").agg_list().alias("xs"), group_by="k")
+    assert df.sort(["window_start", "k"]).to_pydict() == {
+        "k": ["a", "b", "a"],
+        "window_start": [0, 0, 5],
+        "window_end": [5, 5, 10],
+        "x": [4, 2, 4],
+        "xs": [[1, 3], [2], [4]],
+    }
+
+
+@native_only
+def test_windows_of_timestamps():
+    start = datetime.datetime(2024, 1, 1)
+    df = daft.from_pydict(
+        {"t": [start, start + datetime.timedelta(seconds=30), start + datetime.timedelta(minutes=1)], "x": [1, 2, 3]}
+    )
+    df = df.window_agg("t", datetime.timedelta(minutes=1), col("x").sum())
+    assert df.sort("window_start").to_pydict() == {
+        "window_start": [start, start + datetime.timedelta(minutes=1)],
+        "window_end": [start + datetime.timedelta(minutes=1), start + datetime.timedelta(minutes=2)],
+        "x": [3, 3],
+    }
+
+
+@native_only
+def test_windows_of_stream_are_output_once_the_watermark_passes_them():
+    def batches():
+        for i in itertools.count():
+            yield {"t": [i], "k": ["a"], "x": [1]}
+
+    df = daft.read_stream(batches, SCHEMA, event_time_column="t", watermark_delay=2)
+    df = df.window_agg("t", 10, col("x").sum())
+    # The stream is unbounded, so the windows can only be returned once they're closed.
+    assert df.limit(2).to_pydict() == {"window_start": [0, 10], "window_end": [10, 20], "x": [10, 10]}
+
+
+@native_only
+def test_windows_drop_late_rows():
+    def batches():
+        yield {"t": [1, 12], "k": ["a", "a"], "x": [1, 2]}
+        # The watermark is now 12, which closed the window [0, 10), so 3 is late.
+        yield {"t": [3, 14, None], "k": ["a", "a", "a"], "x": [3, 4, 5]}
+
+    df = daft.read_stream(batches, SCHEMA).window_agg("t", 10, col("x").sum(), watermark_delay=0)
+    assert df.sort("window_start").to_pydict() == {"window_start": [0, 10], "window_end": [10, 20], "x": [1, 6]}
+
+
+def test_window_agg_invalid_arguments():
+    df = daft.from_pydict({"t": [1], "x": [1]})
+    with pytest.raises(ValueError, match="not in the schema"):
+        df.window_agg("y", 5, col("x").sum())
+    with pytest.raises(Exception, match="slide of at most their size"):
+        df.window_agg("t", 5, col("x").sum(), window_slide=10)
+    with pytest.raises(Exception, match="top level aggregation"):
+        df.window_agg("t", 5, col("x").sum() + 1)
+    with pytest.raises(ValueError, match="timestamp or date"):
+        df.window_agg("t", datetime.timedelta(seconds=5), col("x").sum())
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() == "native", reason="The native runner can aggregate windows")
+def test_window_agg_requires_native_runner():
+    df = daft.from_pydict({"t": [1], "x": [1]}).window_agg("t", 5, col("x").sum())
+    with pytest.raises(Exception, match="native runner"):
+        df.collect()
+
+
+@native_only
+def test_window_state_is_restored_from_checkpoint(tmp_path):
+    def first_batches():
+        yield {"t": [1, 12], "k": ["a", "a"], "x": [1, 2]}
+        # Wait for more data, which never comes, so that window [10, 20) stays open.
+        while True:
+            yield None
+
+    def second_batches():
+        yield {"t": [15], "k": ["a"], "x": [3]}
+
+    def window_agg(batches):
+        return daft.read_stream(batches, SCHEMA, poll_interval=datetime.timedelta(milliseconds=1)).window_agg(
+            "t", 10, col("x").sum()
+        )
+
+    with daft.execution_config_ctx(checkpoint_dir=str(tmp_path)):
+        assert window_agg(first_batches).limit(1).to_pydict() == {"window_start": [0], "window_end": [10], "x": [1]}
+        # The open window is restored along with its rows from the first run.
+        assert window_agg(second_batches).to_pydict() == {"window_start": [10], "window_end": [20], "x": [5]}
+
+
+def test_invalid_state_store_backend():
+    with pytest.raises(ValueError, match="state_store_backend"):
+        with daft.execution_config_ctx(state_store_backend="bogus"):
+            pass
@@ -376,6 +376,24 @@ def test_sql_with_infer_schema_false_and_no_schema_provided(test_db) -> None:
         daft.read_sql(f"SELECT * FROM {TEST_TABLE_NAME}", test_db, infer_schema=False)
 
 
+@pytest.mark.integration()
+def test_sql_read_with_protocol(test_db, pdf) -> None:
+    if not test_db.startswith("postgresql://"):
+        pytest.skip("ConnectorX protocols are only tested for Postgres")
+
+    df = daft.read_sql(f"SELECT * FROM {TEST_TABLE_NAME}", test_db, protocol="cursor")
+    assert_df_equals(df.to_pandas(coerce_temporal_nanoseconds=True), pdf, sort_key="id")
+
+
+@pytest.mark.integration()
+def test_sql_protocol_with_connection_factory(test_db) -> None:
+    def create_conn():
+        return sqlalchemy.create_engine(test_db).connect()
+
+    with pytest.raises(ValueError, match="protocol can only be specified"):
+        daft.read_sql(f"SELECT * FROM {TEST_TABLE_NAME}", create_conn, protocol="binary")
+
+
 @pytest.mark.integration()
 def test_sql_bad_url() -> None:
     with pytest.raises(RuntimeError, match="Failed to execute sql"):
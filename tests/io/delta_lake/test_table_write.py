@@ -67,6 +67,46 @@ def test_deltalake_write_cloud(base_table, cloud_paths):
     assert read_delta.to_pyarrow_table() == base_table
 
 
+def test_deltalake_write_dynamodb_lock(base_table, s3_path, aws_server, aws_credentials, monkeypatch):
+    deltalake = pytest.importorskip("deltalake")
+    boto3 = pytest.importorskip("boto3")
+    path, io_config, _ = s3_path
+    monkeypatch.setenv("AWS_ENDPOINT_URL_DYNAMODB", aws_server)
+    monkeypatch.setenv("AWS_REGION", "us-west-2")
+    for key, value in aws_credentials.items():
+        monkeypatch.setenv(key, value)
+    dynamodb = boto3.client(
+        "dynamodb",
+        region_name="us-west-2",
+        endpoint_url=aws_server,
+        aws_access_key_id=aws_credentials["AWS_ACCESS_KEY_ID"],
+        aws_secret_access_key=aws_credentials["AWS_SECRET_ACCESS_KEY"],
+        aws_session_token=aws_credentials["AWS_SESSION_TOKEN"],
+    )
+    dynamodb.create_table(
+        TableName="delta_log",
+        AttributeDefinitions=[
+            {"AttributeName": "tablePath", "AttributeType": "S"},
+            {"AttributeName": "fileName", "AttributeType": "S"},
+        ],
+        KeySchema=[
+            {"AttributeName": "tablePath", "KeyType": "HASH"},
+            {"AttributeName": "fileName", "KeyType": "RANGE"},
+        ],
+        BillingMode="PAY_PER_REQUEST",
+    )
+
+    df = daft.from_arrow(base_table)
+    result = df.write_deltalake(path, dynamo_table_name="delta_log", io_config=io_config).to_pydict()
+    assert result["operation"] == ["ADD"]
+
+    # The commit went through the lock table rather than a conditional write
+    assert dynamodb.scan(TableName="delta_log")["Count"] == 1
+    storage_options = io_config_to_storage_options(io_config, path)
+    read_delta = deltalake.DeltaTable(path, storage_options=storage_options)
+    assert read_delta.to_pyarrow_table() == base_table
+
+
 def test_deltalake_write_overwrite_basic(tmp_path):
     deltalake = pytest.importorskip("deltalake")
     path = tmp_path / "some_table"
@@ -306,3 +346,92 @@ def test_deltalake_write_roundtrip(tmp_path):
     read_df = daft.read_deltalake(str(path))
     assert df.schema() == read_df.schema()
     assert df.to_arrow() == read_df.to_arrow()
+
+
+def test_deltalake_write_transaction_log(tmp_path):
+    import json
+
+    path = tmp_path / "some_table"
+    df = daft.from_pydict({"a": [1, 2, 3, 4]})
+    df.write_deltalake(str(path), configuration={"delta.checkpointInterval": "2"})
+    df.write_deltalake(str(path))
+    df.write_deltalake(str(path), mode="overwrite")
+
+    log_dir = path / "_delta_log"
+    assert (log_dir / f"{2:020}.json").exists()
+    assert (log_dir / f"{2:020}.checkpoint.parquet").exists()
+    assert json.loads((log_dir / "_last_checkpoint").read_text())["version"] == 2
+
+    actions = [json.loads(line) for line in (log_dir / f"{2:020}.json").read_text().splitlines()]
+    assert sum("remove" in action for action in actions) == 2
+    assert sum("add" in action for action in actions) == 1
+    assert [action["commitInfo"]["readVersion"] for action in actions if "commitInfo" in action] == [1]
+
+
+def test_deltalake_write_audit(tmp_path):
+    path = tmp_path / "some_table"
+    df = daft.from_pydict({"a": [1, 2, 3, 4], "b": ["x", "y", "x", "y"]})
+    df.write_deltalake(str(path), partition_cols=["b"], audit=[daft.col("a") > 0, daft.col("b").is_in(["x", "y"])])
+
+    with pytest.raises(ValueError, match="1 rows failed"):
+        daft.from_pydict({"a": [5, -1], "b": ["x", "y"]}).write_deltalake(
+            str(path), partition_cols=["b"], audit=[daft.col("a") > 0]
+        )
+
+    # the failed write is neither committed nor left behind in the table directory
+    assert not (path / "_delta_log" / f"{1:020}.json").exists()
+    assert len(list(path.rglob("*.parquet"))) == 2
+    assert daft.read_deltalake(str(path)).sort("a").to_pydict() == df.to_pydict()
+
+
+def test_deltalake_compaction(tmp_path):
+    import json
+
+    path = tmp_path / "some_table"
+    for i in range(3):
+        daft.from_pydict({"a": [i, i + 10], "b": ["x", "y"]}).write_deltalake(str(path), partition_cols=["b"])
+    expected = daft.read_deltalake(str(path)).sort("a").to_pydict()
+
+    compaction = daft.io.compact_deltalake(str(path))
+    assert len(compaction.replaced_files) == 6
+    # nothing is rewritten until the compaction is executed
+    assert daft.read_deltalake(str(path)).sort("a").to_pydict() == expected
+    result = compaction.execute().to_pydict()
+    assert result["operation"].count("ADD") == 2
+    assert result["operation"].count("DELETE") == 6
+    assert sum(rows for op, rows in zip(result["operation"], result["rows"]) if op == "ADD") == 6
+
+    assert daft.read_deltalake(str(path)).sort("a").to_pydict() == expected
+    commit = [json.loads(line) for line in (path / "_delta_log" / f"{3:020}.json").read_text().splitlines()]
+    assert [action["commitInfo"]["operation"] for action in commit if "commitInfo" in action] == ["OPTIMIZE"]
+    assert all(not action["add"]["dataChange"] for action in commit if "add" in action)
+
+    # files that are already compacted are left alone
+    assert daft.io.compact_deltalake(str(path)).execute().to_pydict()["operation"] == []
+
+    # the replaced files are no longer part of the table, so they can't be replaced again
+    with pytest.raises(Exception, match="concurrently removed"):
+        compaction.execute()
+
+
+def test_deltalake_write_append_compatible_schema(tmp_path):
+    path = tmp_path / "some_table"
+    daft.from_pydict({"a": [1, 2], "b": ["x", "y"]}).write_deltalake(str(path))
+
+    # missing columns are filled with nulls and narrower types are widened to the table's
+    df = daft.from_pydict({"a": [3]}).with_column("a", daft.col("a").cast(daft.DataType.int32()))
+    df.write_deltalake(str(path))
+
+    read = daft.read_deltalake(str(path)).sort("a")
+    assert read.schema()["a"].dtype == daft.DataType.int64()
+    assert read.to_pydict() == {"a": [1, 2, 3], "b": ["x", "y", None]}
+
+
+def test_deltalake_write_append_incompatible_schema(tmp_path):
+    path = tmp_path / "some_table"
+    daft.from_pydict({"a": [1, 2]}).write_deltalake(str(path))
+
+    with pytest.raises(ValueError, match="c: is not in the table"):
+        daft.from_pydict({"a": [3], "c": [4]}).write_deltalake(str(path))
+    with pytest.raises(ValueError, match="a: has type Float64"):
+        daft.from_pydict({"a": [3.5]}).write_deltalake(str(path))
@@ -137,6 +137,52 @@ def test_read_after_write_overwrite(simple_local_table):
     assert as_arrow == read_back.to_arrow().sort_by("x")
 
 
+def test_write_reports_transformed_partition_values(local_catalog):
+    schema = Schema(NestedField(field_id=1, name="x", type=LongType()))
+    partition_spec = PartitionSpec(
+        PartitionField(source_id=1, field_id=1000, transform=TruncateTransform(2), name="x_trunc")
+    )
+    table = local_catalog.create_table("default.test", schema, partition_spec=partition_spec)
+
+    df = daft.from_pydict({"x": [1, 2, 3, 4, 5]})
+    result = df.write_iceberg(table).to_pydict()
+    assert sorted(p["x_trunc"] for p in result["partitioning"]) == [0, 2, 4]
+    assert sorted(daft.read_iceberg(table).to_pydict()["x"]) == [1, 2, 3, 4, 5]
+
+
+def test_compaction(simple_local_table):
+    table, num_partitions = simple_local_table
+    for _ in range(3):
+        daft.from_pydict({"x": [1, 2, 3, 4, 5]}).write_iceberg(table)
+    expected = sorted(daft.read_iceberg(table).to_pydict()["x"])
+
+    compaction = daft.io.compact_iceberg(table)
+    assert len(compaction.replaced_files) == 3 * num_partitions
+    result = compaction.execute().to_pydict()
+    assert result["operation"].count("ADD") == num_partitions
+    assert result["operation"].count("DELETE") == 3 * num_partitions
+    assert sorted(daft.read_iceberg(table).to_pydict()["x"]) == expected
+
+    # files that are already compacted are left alone
+    assert daft.io.compact_iceberg(table).execute().to_pydict()["operation"] == []
+
+
+def test_write_audit(simple_local_table):
+    table, _ = simple_local_table
+
+    df = daft.from_pydict({"x": [1, 2, 3, 4, 5]})
+    df.write_iceberg(table, audit=[daft.col("x") > 0])
+    table.refresh()
+    snapshot_id = table.current_snapshot().snapshot_id
+
+    with pytest.raises(ValueError, match="Audit of the written data failed"):
+        daft.from_pydict({"x": [6, None]}).write_iceberg(table, audit=[daft.col("x") > 0])
+
+    table.refresh()
+    assert table.current_snapshot().snapshot_id == snapshot_id
+    assert df.to_arrow() == daft.read_iceberg(table).to_arrow().sort_by("x")
+
+
 def test_read_and_overwrite(simple_local_table):
     table, num_partitions = simple_local_table
 
@@ -426,3 +472,87 @@ def test_complex_table_write_read(local_catalog, complex_table, partition_spec,
     assert sum(as_dict["rows"]) == 3, as_dict["rows"]
     read_back = daft.read_iceberg(table)
     assert df.to_arrow() == read_back.to_arrow().sort_by("int")
+
+
+@pytest.fixture(
+    scope="function",
+    params=[
+        pytest.param(UNPARTITIONED_PARTITION_SPEC, id="unpartitioned"),
+        pytest.param(
+            PartitionSpec(PartitionField(source_id=1, field_id=1000, transform=IdentityTransform(), name="x")),
+            id="identity_partitioned",
+        ),
+        pytest.param(
+            PartitionSpec(PartitionField(source_id=1, field_id=1000, transform=BucketTransform(4), name="x")),
+            id="bucket_partitioned",
+        ),
+    ],
+)
+def file_system_table(request, tmp_path):
+    from pyiceberg.table.metadata import new_table_metadata
+    from pyiceberg.table.sorting import UNSORTED_SORT_ORDER
+
+    # A table whose metadata files are numbered versions with a version hint, as native commits write them
+    location = f"file://{tmp_path}"
+    metadata = new_table_metadata(
+        schema=Schema(NestedField(field_id=1, name="x", type=LongType())),
+        partition_spec=request.param,
+        sort_order=UNSORTED_SORT_ORDER,
+        location=location,
+        properties={},
+    )
+    (tmp_path / "metadata").mkdir()
+    (tmp_path / "metadata" / "v1.metadata.json").write_text(metadata.model_dump_json())
+    (tmp_path / "metadata" / "version-hint.text").write_text("1")
+    return location
+
+
+def _latest_file_system_table(location):
+    from pyiceberg.table import StaticTable
+
+    with open(location.removeprefix("file://") + "/metadata/version-hint.text") as f:
+        version = f.read().strip()
+    return StaticTable.from_metadata(f"{location}/metadata/v{version}.metadata.json")
+
+
+def test_native_commit_append_and_overwrite(file_system_table):
+    df = daft.from_pydict({"x": [1, 2, 3, 4, 5]})
+    with daft.execution_config_ctx(native_iceberg_commit=True):
+        df.write_iceberg(_latest_file_system_table(file_system_table))
+        df.write_iceberg(_latest_file_system_table(file_system_table))
+
+        table = _latest_file_system_table(file_system_table)
+        assert table.metadata_location.endswith("v3.metadata.json")
+        assert sorted(daft.read_iceberg(table).to_pydict()["x"]) == [1, 1, 2, 2, 3, 3, 4, 4, 5, 5]
+
+        result = daft.from_pydict({"x": [6, 7]}).write_iceberg(table, mode="overwrite").to_pydict()
+        assert sum(op == "ADD" for op in result["operation"]) >= 1
+        assert sum(op == "DELETE" for op in result["operation"]) >= 2
+
+    table = _latest_file_system_table(file_system_table)
+    assert table.current_snapshot().summary.operation.value == "overwrite"
+    assert sorted(daft.read_iceberg(table).to_pydict()["x"]) == [6, 7]
+
+
+def test_native_commit_rejects_unsupported_catalogs(local_catalog):
+    table = local_catalog.create_table("default.test", Schema(NestedField(field_id=1, name="x", type=LongType())))
+    with daft.execution_config_ctx(native_iceberg_commit=True):
+        with pytest.raises(ValueError, match="Native Iceberg commits only support"):
+            daft.from_pydict({"x": [1, 2, 3]}).write_iceberg(table)
+
+
+def test_native_commit_compaction(file_system_table):
+    with daft.execution_config_ctx(native_iceberg_commit=True):
+        for _ in range(3):
+            daft.from_pydict({"x": [1, 2]}).write_iceberg(_latest_file_system_table(file_system_table))
+        compaction = daft.io.compact_iceberg(_latest_file_system_table(file_system_table))
+        compaction.execute()
+
+    table = _latest_file_system_table(file_system_table)
+    assert table.current_snapshot().summary.operation.value == "replace"
+    assert sorted(daft.read_iceberg(table).to_pydict()["x"]) == [1, 1, 1, 2, 2, 2]
+
+    # the replaced files are no longer part of the table, so they can't be replaced again
+    with daft.execution_config_ctx(native_iceberg_commit=True):
+        with pytest.raises(Exception, match="no longer part of the Iceberg table"):
+            compaction.execute()
@@ -0,0 +1,60 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from tests.conftest import get_tests_daft_runner_name
+
+native_only = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Only the native runner writes with the native CSV writer"
+)
+
+
+def make_df():
+    return daft.from_pydict({"a": [1, None, 3], "b": ["x|y", "", None]})
+
+
+def read_written(written) -> str:
+    [path] = written.to_pydict()["path"]
+    with open(path) as f:
+        return f.read()
+
+
+def test_csv_write_delimiter_and_header(tmp_path):
+    written = make_df().write_csv(str(tmp_path), delimiter=";", header=False)
+    read_back = daft.read_csv(str(tmp_path), delimiter=";", has_headers=False).to_pydict()
+    assert read_back["column_1"] == [1, None, 3]
+    assert len(written) == 1
+
+
+@native_only
+def test_native_csv_writer_options(tmp_path):
+    with daft.execution_config_ctx(native_csv_writer=True):
+        written = make_df().write_csv(str(tmp_path), delimiter="|", null_value="NULL", quote="'")
+    assert read_written(written) == "a|b\n1|'x|y'\nNULL|''\n3|NULL\n"
+
+
+@native_only
+def test_native_csv_writer_quote_styles(tmp_path):
+    with daft.execution_config_ctx(native_csv_writer=True):
+        written = make_df().write_csv(str(tmp_path / "all"), quote_style="all_valid")
+        assert read_written(written) == '"a","b"\n"1","x|y"\n,""\n"3",\n'
+
+        df = daft.from_pydict({"a": ["x,y"]})
+        with pytest.raises(Exception, match="must be quoted"):
+            df.write_csv(str(tmp_path / "none"), quote_style="none")
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() == "native", reason="The native runner supports custom null values"
+)
+def test_csv_write_custom_null_value_requires_native_writer(tmp_path):
+    with pytest.raises(Exception, match="native CSV writer"):
+        make_df().write_csv(str(tmp_path), null_value="NULL")
+
+
+def test_csv_write_invalid_options(tmp_path):
+    with pytest.raises(ValueError, match="must be different"):
+        make_df().write_csv(str(tmp_path), delimiter='"')
+    with pytest.raises(ValueError, match="quote style"):
+        make_df().write_csv(str(tmp_path), quote_style="always")
@@ -0,0 +1,130 @@
+from __future__ import annotations
+
+import datetime
+
+import pytest
+
+import daft
+from daft import DataType, col
+
+openpyxl = pytest.importorskip("openpyxl")
+
+
+def write_workbook(path, sheets):
+    workbook = openpyxl.Workbook()
+    workbook.remove(workbook.active)
+    for name, rows in sheets.items():
+        sheet = workbook.create_sheet(name)
+        for row in rows:
+            sheet.append(row)
+    workbook.save(path)
+    return str(path)
+
+
+@pytest.fixture
+def orders_path(tmp_path):
+    return write_workbook(
+        tmp_path / "orders.xlsx",
+        {
+            "Orders": [
+                ["id", "customer", "amount", "paid", "ordered_at"],
+                [1, "Ada", 9.99, True, datetime.datetime(2024, 1, 2, 3, 4, 5)],
+                [2, "Grace", 5, False, datetime.datetime(2024, 2, 3)],
+                [3, None, None, None, None],
+            ],
+            "Customers": [
+                ["name", "city"],
+                ["Ada", "London"],
+                ["Grace", "New York"],
+            ],
+        },
+    )
+
+
+def test_read_excel_infers_schema(orders_path):
+    df = daft.read_excel(orders_path)
+
+    assert df.schema().column_names() == ["id", "customer", "amount", "paid", "ordered_at"]
+    assert df.schema()["id"].dtype == DataType.int64()
+    assert df.schema()["customer"].dtype == DataType.string()
+    assert df.schema()["amount"].dtype == DataType.float64()
+    assert df.schema()["paid"].dtype == DataType.bool()
+    assert df.schema()["ordered_at"].dtype == DataType.timestamp("ms")
+
+    assert df.to_pydict() == {
+        "id": [1, 2, 3],
+        "customer": ["Ada", "Grace", None],
+        "amount": [9.99, 5.0, None],
+        "paid": [True, False, None],
+        "ordered_at": [datetime.datetime(2024, 1, 2, 3, 4, 5), datetime.datetime(2024, 2, 3), None],
+    }
+
+
+def test_read_excel_sheet_name(orders_path):
+    df = daft.read_excel(orders_path, sheet_name="Customers")
+
+    assert df.to_pydict() == {"name": ["Ada", "Grace"], "city": ["London", "New York"]}
+
+
+def test_read_excel_missing_sheet(orders_path):
+    with pytest.raises(Exception, match="does not have a sheet named Products"):
+        daft.read_excel(orders_path, sheet_name="Products")
+
+
+def test_read_excel_header_row(tmp_path):
+    path = write_workbook(
+        tmp_path / "report.xlsx",
+        {"Report": [["Quarterly report"], [], ["region", "sales"], ["north", 10], ["south", 20]]},
+    )
+    df = daft.read_excel(path, header_row=2)
+
+    assert df.to_pydict() == {"region": ["north", "south"], "sales": [10, 20]}
+
+
+def test_read_excel_without_header_row(tmp_path):
+    path = write_workbook(tmp_path / "values.xlsx", {"Values": [["a", 1], ["b", None, 2.5]]})
+    df = daft.read_excel(path, header_row=None)
+
+    assert df.to_pydict() == {"column_1": ["a", "b"], "column_2": [1, None], "column_3": [None, 2.5]}
+
+
+def test_read_excel_mixed_column(tmp_path):
+    path = write_workbook(tmp_path / "mixed.xlsx", {"Mixed": [["value"], [1], ["two"], [3.5]]})
+    df = daft.read_excel(path)
+
+    assert df.to_pydict() == {"value": ["1", "two", "3.5"]}
+
+
+def test_read_excel_with_schema(orders_path):
+    schema = {"id": DataType.string(), "paid": DataType.int8(), "discount": DataType.float64()}
+    df = daft.read_excel(orders_path, infer_schema=False, schema=schema)
+
+    assert df.to_pydict() == {"id": ["1", "2", "3"], "paid": [None, None, None], "discount": [None, None, None]}
+
+
+def test_read_excel_schema_hints(orders_path):
+    df = daft.read_excel(orders_path, schema={"amount": DataType.float32()})
+
+    assert df.schema()["amount"].dtype == DataType.float32()
+    assert df.select("id").to_pydict() == {"id": [1, 2, 3]}
+
+
+def test_read_excel_projection_filter_and_limit(orders_path):
+    df = daft.read_excel(orders_path)
+
+    assert df.select("customer").to_pydict() == {"customer": ["Ada", "Grace", None]}
+    assert df.where(col("id") > 1).select("customer").to_pydict() == {"customer": ["Grace", None]}
+    assert df.limit(1).select("id").to_pydict() == {"id": [1]}
+
+
+def test_read_excel_multiple_files(tmp_path):
+    for i in range(3):
+        write_workbook(tmp_path / f"part-{i}.xlsx", {"Sheet": [["n"], [i]]})
+    df = daft.read_excel(str(tmp_path / "*.xlsx"))
+
+    assert sorted(df.to_pydict()["n"]) == [0, 1, 2]
+
+
+def test_read_excel_negative_header_row(orders_path):
+    with pytest.raises(ValueError, match="header row"):
+        daft.read_excel(orders_path, header_row=-1)
@@ -0,0 +1,105 @@
+from __future__ import annotations
+
+import datetime
+
+import pytest
+
+import daft
+from daft.catalog.hive import (
+    HiveFileFormat,
+    HivePartition,
+    HiveTable,
+    _hive_file_format,
+    _hive_type_to_daft,
+)
+from daft.datatype import DataType
+
+
+@pytest.mark.parametrize(
+    ["hive_type", "expected"],
+    [
+        ("int", DataType.int32()),
+        ("BIGINT", DataType.int64()),
+        ("varchar(10)", DataType.string()),
+        ("decimal(12, 2)", DataType.decimal128(12, 2)),
+        ("decimal", DataType.decimal128(10, 0)),
+    ],
+)
+def test_hive_type_to_daft(hive_type, expected):
+    assert _hive_type_to_daft(hive_type) == expected
+
+
+def test_hive_type_to_daft_unsupported():
+    with pytest.raises(ValueError, match="Unsupported Hive column type"):
+        _hive_type_to_daft("map<string,int>")
+
+
+def test_hive_file_format():
+    assert _hive_file_format(
+        "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat",
+        "org.apache.hadoop.hive.ql.io.parquet.serde.ParquetHiveSerDe",
+        {},
+    ) == HiveFileFormat("parquet")
+    assert _hive_file_format(
+        "org.apache.hadoop.mapred.TextInputFormat",
+        "org.apache.hadoop.hive.serde2.lazy.LazySimpleSerDe",
+        {"serialization.format": "9"},
+    ) == HiveFileFormat("csv", delimiter="\t", null_format="\\N")
+    assert _hive_file_format(
+        "org.apache.hadoop.mapred.TextInputFormat", "org.apache.hadoop.hive.serde2.OpenCSVSerde", {}
+    ) == HiveFileFormat("csv", delimiter=",", quote='"')
+    with pytest.raises(ValueError, match="Unsupported Hive table format"):
+        _hive_file_format(
+            "org.apache.hadoop.hive.ql.io.orc.OrcInputFormat", "org.apache.hadoop.hive.ql.io.orc.OrcSerde", {}
+        )
+
+
+def test_read_partitioned_text_table(tmp_path):
+    for value in ["a", "b"]:
+        (tmp_path / value).mkdir()
+        (tmp_path / value / "data.txt").write_text(f"1|{value}\n2|{value}\n")
+
+    table = HiveTable(
+        name="db.tbl",
+        location=str(tmp_path),
+        file_format=HiveFileFormat("csv", delimiter="|"),
+        columns=[("id", "bigint"), ("name", "string")],
+        partition_columns=[("part", "string"), ("day", "int")],
+        partitions=[
+            HivePartition(location=str(tmp_path / "a"), values=["a", "1"]),
+            HivePartition(location=str(tmp_path / "b"), values=["b", None]),
+        ],
+    )
+    df = table.to_dataframe().sort(["part", "id"])
+    assert df.schema()["day"].dtype == DataType.int32()
+    assert df.to_pydict() == {
+        "id": [1, 2, 1, 2],
+        "name": ["a", "a", "b", "b"],
+        "part": ["a", "a", "b", "b"],
+        "day": [1, 1, None, None],
+    }
+
+
+def test_read_text_table_with_nulls(tmp_path):
+    (tmp_path / "data.txt").write_text("1|a|\\N\n\\N|\\N|2024-01-02\n")
+
+    table = HiveTable(
+        name="db.tbl",
+        location=str(tmp_path),
+        file_format=HiveFileFormat("csv", delimiter="|", null_format="\\N"),
+        columns=[("id", "bigint"), ("name", "string"), ("day", "date")],
+        partition_columns=[],
+        partitions=[],
+    )
+    df = table.to_dataframe().sort("id")
+    assert df.schema()["id"].dtype == DataType.int64()
+    assert df.to_pydict() == {
+        "id": [1, None],
+        "name": ["a", None],
+        "day": [None, datetime.date(2024, 1, 2)],
+    }
+
+
+def test_register_rejects_non_thrift_uri():
+    with pytest.raises(ValueError, match="thrift://host:port"):
+        daft.catalog.register_hive_metastore_catalog("http://localhost:9083")
@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+from daft.io import IOConfig
+
+KEY = 0x5A
+
+
+class XorTransform:
+    """A stand-in for client-side encryption that XORs every byte of objects with a key."""
+
+    def encode(self, path: str, data: bytes) -> bytes:
+        return xor(data)
+
+    def decode(self, path: str, data: bytes) -> bytes:
+        return xor(data)
+
+
+def xor(data: bytes) -> bytes:
+    return bytes(b ^ KEY for b in data)
+
+
+@pytest.fixture
+def transform():
+    return XorTransform()
+
+
+@pytest.fixture
+def io_config(transform):
+    return IOConfig(transform=transform)
+
+
+def test_io_config_transform(transform, io_config):
+    assert io_config.transform is transform
+    assert IOConfig().transform is None
+    assert io_config.replace().transform is transform
+
+
+def test_url_upload_and_download(tmp_path, io_config):
+    data = [b"hello", b"world"]
+    df = daft.from_pydict({"data": data})
+    df = df.with_column("path", df["data"].url.upload(str(tmp_path), io_config=io_config)).collect()
+
+    paths = df.to_pydict()["path"]
+    assert [open(path.removeprefix("file://"), "rb").read() for path in paths] == [xor(d) for d in data]
+
+    df = df.with_column("downloaded", df["path"].url.download(io_config=io_config))
+    assert df.to_pydict()["downloaded"] == data
+
+
+def test_read_csv(tmp_path, io_config):
+    path = tmp_path / "data.csv"
+    path.write_bytes(xor(b"a,b\n1,x\n2,y\n"))
+
+    df = daft.read_csv(str(path), io_config=io_config)
+    assert df.to_pydict() == {"a": [1, 2], "b": ["x", "y"]}
+
+
+def test_read_parquet(tmp_path, io_config):
+    plain_path = tmp_path / "plain.parquet"
+    papq.write_table(pa.table({"a": [1, 2, 3], "b": ["x", "y", "z"]}), plain_path)
+    path = tmp_path / "data.parquet"
+    path.write_bytes(xor(plain_path.read_bytes()))
+
+    df = daft.read_parquet(str(path), io_config=io_config)
+    assert df.to_pydict() == {"a": [1, 2, 3], "b": ["x", "y", "z"]}
+
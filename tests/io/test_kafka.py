@@ -0,0 +1,25 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+
+SCHEMA = daft.Schema._from_field_name_and_types(
+    [("user", daft.DataType.string()), ("_kafka_offset", daft.DataType.int64())]
+)
+
+
+def test_read_kafka_invalid_value_format():
+    with pytest.raises(ValueError, match="json"):
+        daft.read_kafka("localhost:9092", "events", SCHEMA, value_format="protobuf")
+
+
+def test_read_kafka_avro_requires_schema():
+    with pytest.raises(ValueError, match="Avro schema is required"):
+        daft.read_kafka("localhost:9092", "events", SCHEMA, value_format="avro")
+
+
+@pytest.mark.parametrize("offset", ["first", 10])
+def test_read_kafka_invalid_offset(offset):
+    with pytest.raises(ValueError, match="Kafka offset"):
+        daft.read_kafka("localhost:9092", "events", SCHEMA, start=offset)
@@ -0,0 +1,74 @@
+from __future__ import annotations
+
+import datetime
+import os
+
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Only the native runner writes with the native parquet writer"
+)
+
+
+def make_df():
+    return daft.from_pydict(
+        {
+            "a": ["x", "y", None, "x"],
+            "b": [1, 2, 3, None],
+            "c": [[1], [], None, [2, 3]],
+            "d": [datetime.date(2024, 1, i) for i in range(1, 5)],
+        }
+    )
+
+
+@pytest.mark.parametrize("compression", ["snappy", "zstd", "gzip", "none"])
+def test_native_parquet_writer_matches_pyarrow(tmp_path, compression):
+    df = make_df()
+    with daft.execution_config_ctx(native_parquet_writer=True):
+        df.write_parquet(str(tmp_path / "native"), compression=compression)
+    with daft.execution_config_ctx(native_parquet_writer=False):
+        df.write_parquet(str(tmp_path / "pyarrow"), compression=compression)
+
+    native = daft.read_parquet(str(tmp_path / "native")).sort("d")
+    expected = daft.read_parquet(str(tmp_path / "pyarrow")).sort("d")
+    assert native.schema() == expected.schema()
+    assert native.to_pydict() == expected.to_pydict()
+
+
+def test_native_parquet_writer_files_are_readable_by_pyarrow(tmp_path):
+    with daft.execution_config_ctx(native_parquet_writer=True):
+        written = make_df().write_parquet(str(tmp_path), compression="zstd").to_pydict()["path"]
+
+    [path] = written
+    metadata = papq.ParquetFile(path).metadata
+    assert metadata.num_rows == 4
+    column = metadata.row_group(0).column(1)
+    assert column.compression == "ZSTD"
+    assert column.statistics.min == 1 and column.statistics.max == 3
+    assert papq.read_table(path).column("a").to_pylist() == ["x", "y", None, "x"]
+
+
+def test_native_parquet_writer_partitions(tmp_path):
+    with daft.execution_config_ctx(native_parquet_writer=True):
+        written = make_df().write_parquet(str(tmp_path), partition_cols=["a"]).sort("a").to_pydict()
+
+    assert written["a"] == ["x", "y", None]
+    dirs = [os.path.basename(os.path.dirname(path)) for path in written["path"]]
+    assert dirs == ["a=x", "a=y", "a=__HIVE_DEFAULT_PARTITION__"]
+
+    read_back = daft.read_parquet(str(tmp_path / "**")).sort("d").to_pydict()
+    assert read_back["b"] == [1, 2, 3, None]
+
+
+def test_native_parquet_writer_respects_row_group_size(tmp_path):
+    df = daft.from_pydict({"a": list(range(10_000))})
+    with daft.execution_config_ctx(native_parquet_writer=True, parquet_target_row_group_size=16 * 1024):
+        [path] = df.write_parquet(str(tmp_path)).to_pydict()["path"]
+
+    metadata = papq.ParquetFile(path).metadata
+    assert metadata.num_row_groups > 1
+    assert metadata.num_rows == 10_000
@@ -144,6 +144,15 @@ def test_row_groups():
     assert df.count_rows() == 20
 
 
+@pytest.mark.parametrize("num_columns", [1, 64])
+def test_read_many_row_groups_of_one_file(num_columns):
+    data = pa.Table.from_pydict({f"col_{i}": list(range(i, i + 10_000)) for i in range(num_columns)})
+    with _parquet_write_helper(data, row_group_size=100) as f:
+        assert papq.ParquetFile(f).num_row_groups == 100
+        assert daft.read_parquet(f).to_arrow() == data
+        assert daft.read_parquet(f).limit(250).to_arrow() == data.slice(0, 250)
+
+
 # Test fix for issue #2537.
 # This issue arose when the last row of a top-level column has a leaf field with values that span
 # more than one data page.
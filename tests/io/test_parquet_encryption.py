@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+import base64
+
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+from daft.exceptions import DaftCoreException
+from daft.io import KmsConfig, ParquetDecryptionConfig, ParquetEncryptionConfig
+
+pqe = pytest.importorskip("pyarrow.parquet.encryption")
+
+MASTER_KEYS = {"footer_key": "0123456789012345", "col_key": "1234567890123450"}
+
+
+class InMemoryKmsClient(pqe.KmsClient):
+    """KMS client that wraps keys by prepending the master key to them, for testing only."""
+
+    def __init__(self, config):
+        pqe.KmsClient.__init__(self)
+        self.master_keys = config.custom_kms_conf
+
+    def wrap_key(self, key_bytes, master_key_identifier):
+        master_key = self.master_keys[master_key_identifier].encode("utf-8")
+        return base64.b64encode(master_key + key_bytes)
+
+    def unwrap_key(self, wrapped_key, master_key_identifier):
+        master_key = self.master_keys[master_key_identifier].encode("utf-8")
+        decoded = base64.b64decode(wrapped_key)
+        assert decoded[: len(master_key)] == master_key
+        return decoded[len(master_key) :]
+
+
+KMS = KmsConfig(client_factory=InMemoryKmsClient, custom_kms_conf=MASTER_KEYS)
+
+
+def test_parquet_encryption_roundtrip(tmp_path):
+    data = {"id": [1, 2, 3], "ssn": ["a", "b", "c"]}
+    encryption = ParquetEncryptionConfig(kms=KMS, footer_key="footer_key", column_keys={"col_key": ["ssn"]})
+    daft.from_pydict(data).write_parquet(str(tmp_path), encryption=encryption)
+
+    # Without the keys, the files can't be read
+    with pytest.raises(OSError):
+        papq.read_table(str(tmp_path))
+
+    df = daft.read_parquet(str(tmp_path), decryption=ParquetDecryptionConfig(kms=KMS))
+    assert df.sort("id").to_pydict() == data
+
+
+def test_parquet_decryption_column_pushdown(tmp_path):
+    data = {"id": [1, 2, 3], "ssn": ["a", "b", "c"]}
+    encryption = ParquetEncryptionConfig(kms=KMS, footer_key="footer_key", column_keys={"col_key": ["ssn"]})
+    daft.from_pydict(data).write_parquet(str(tmp_path), encryption=encryption)
+
+    df = daft.read_parquet(str(tmp_path), decryption=ParquetDecryptionConfig(kms=KMS))
+    assert df.select("id").sort("id").to_pydict() == {"id": [1, 2, 3]}
+
+
+def test_parquet_encryption_only_for_parquet(tmp_path):
+    encryption = ParquetEncryptionConfig(kms=KMS, footer_key="footer_key", column_keys={})
+    df = daft.from_pydict({"id": [1]})
+    with pytest.raises(DaftCoreException, match="only supported"):
+        df._builder.write_tabular(
+            root_dir=str(tmp_path),
+            file_format=daft.daft.FileFormat.Csv,
+            io_config=daft.io.IOConfig(),
+            parquet_encryption=encryption,
+        )
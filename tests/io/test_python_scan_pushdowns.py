@@ -0,0 +1,189 @@
+from __future__ import annotations
+
+import json
+import operator
+
+import pytest
+
+import daft
+from daft.daft import Pushdowns, ScanOperatorHandle, ScanTask
+from daft.dataframe import DataFrame
+from daft.io.pushdowns import (
+    Expr,
+    Literal,
+    Reference,
+    Term,
+    filter_terms,
+    filter_terms_json,
+    from_json,
+    to_expression,
+    to_json,
+)
+from daft.io.scan import ScanOperator
+from daft.logical.builder import LogicalPlanBuilder
+from daft.logical.schema import Schema
+from daft.table import Table
+
+DATA = {"a": [1, 2, 3, 4, 5], "b": ["x", "y", "x", "y", None]}
+
+PROCS = {
+    "==": operator.eq,
+    "!=": operator.ne,
+    "<": operator.lt,
+    "<=": operator.le,
+    ">": operator.gt,
+    ">=": operator.ge,
+}
+
+
+def evaluate(term: Term, row: dict):
+    if isinstance(term, Reference):
+        return row[term.name]
+    if isinstance(term, Literal):
+        return term.value
+    assert isinstance(term, Expr)
+    args = [evaluate(arg, row) for arg in term.args]
+    if term.proc == "not_null":
+        return args[0] is not None
+    if term.proc == "is_in":
+        return args[0] in args[1:]
+    return PROCS[term.proc](*args)
+
+
+def read_rows(predicates: list[Term], columns: list[str] | None, limit: int | None):
+    rows = [dict(zip(DATA, values)) for values in zip(*DATA.values())]
+    rows = [row for row in rows if all(evaluate(predicate, row) for predicate in predicates)][:limit]
+    columns = columns or list(DATA)
+    yield Table.from_pydict({column: [row[column] for row in rows] for column in columns})._table
+
+
+class RecordingScanOperator(ScanOperator):
+    def __init__(self, procs: set[str]):
+        self._procs = procs
+        self.predicates: list[Term] = []
+        self.columns: list[str] | None = None
+        self.limit: int | None = None
+        self.terms: list[Term] = []
+        self.terms_json: str = "[]"
+
+    def name(self) -> str:
+        return "RecordingScanOperator"
+
+    def display_name(self) -> str:
+        return "RecordingScanOperator"
+
+    def schema(self) -> Schema:
+        return Schema._from_field_name_and_types([("a", daft.DataType.int64()), ("b", daft.DataType.string())])
+
+    def partitioning_keys(self):
+        return []
+
+    def can_absorb_filter(self) -> bool:
+        return True
+
+    def can_absorb_limit(self) -> bool:
+        return True
+
+    def can_absorb_select(self) -> bool:
+        return True
+
+    def can_absorb_predicate(self, predicate: Term) -> bool:
+        return isinstance(predicate, Expr) and predicate.proc in self._procs
+
+    def multiline_display(self) -> list[str]:
+        return [self.display_name()]
+
+    def to_scan_tasks(self, pushdowns: Pushdowns):
+        self.predicates = self.absorbable_predicates(pushdowns)
+        self.terms = filter_terms(pushdowns)
+        self.terms_json = filter_terms_json(pushdowns)
+        self.columns = self.absorbable_columns(pushdowns)
+        self.limit = self.absorbable_limit(pushdowns)
+        columns = self.columns
+        if columns is not None:
+            columns = columns + [c for c in pushdowns.filter_required_column_names() or [] if c not in columns]
+        yield ScanTask.python_factory_func_scan_task(
+            module=read_rows.__module__,
+            func_name=read_rows.__name__,
+            func_args=(self.predicates, columns, self.limit),
+            schema=self.schema()._schema,
+            num_rows=None,
+            size_bytes=None,
+            pushdowns=pushdowns,
+            stats=None,
+        )
+
+
+def read(scan_operator: ScanOperator) -> DataFrame:
+    handle = ScanOperatorHandle.from_python_scan_operator(scan_operator)
+    return DataFrame(LogicalPlanBuilder.from_tabular_scan(scan_operator=handle))
+
+
+def test_filter_predicates_are_translated_into_terms():
+    scan_operator = RecordingScanOperator({">", "==", "is_in", "not_null"})
+    df = read(scan_operator).where((daft.col("a") > 1) & (daft.col("b") == "x"))
+    assert df.to_pydict() == {"a": [3], "b": ["x"]}
+    assert scan_operator.predicates == [
+        Expr(">", (Reference("a"), Literal(1))),
+        Expr("==", (Reference("b"), Literal("x"))),
+    ]
+
+
+def test_only_absorbable_predicates_are_pushed_down():
+    scan_operator = RecordingScanOperator({"<="})
+    df = read(scan_operator).where((daft.col("a") <= 4) & daft.col("b").is_in(["y"]))
+    assert df.to_pydict() == {"a": [2, 4], "b": ["y", "y"]}
+    assert scan_operator.predicates == [Expr("<=", (Reference("a"), Literal(4)))]
+
+
+def test_untranslatable_predicates_are_left_out():
+    scan_operator = RecordingScanOperator({">", "is_in", "not_null"})
+    df = read(scan_operator).where((daft.col("a") > 1) & (daft.col("b").str.contains("y")))
+    assert df.to_pydict() == {"a": [2, 4], "b": ["y", "y"]}
+    assert scan_operator.predicates == [Expr(">", (Reference("a"), Literal(1)))]
+
+
+def test_columns_and_limit_are_pushed_down():
+    scan_operator = RecordingScanOperator(set())
+    df = read(scan_operator).select("b").limit(2)
+    assert df.to_pydict() == {"b": ["x", "y"]}
+    assert scan_operator.columns == ["b"]
+    assert scan_operator.limit == 2
+
+
+def test_filter_terms_are_serialized_into_json():
+    scan_operator = RecordingScanOperator(set())
+    df = read(scan_operator).where((daft.col("a") > 1) & daft.col("b").not_null() & ~(daft.col("a") == 3.5))
+    assert df.to_pydict() == {"a": [2, 3, 4], "b": ["y", "x", "y"]}
+    assert from_json(scan_operator.terms_json) == scan_operator.terms
+    assert json.loads(scan_operator.terms_json) == json.loads(to_json(scan_operator.terms))
+
+
+def test_terms_translate_back_into_expressions():
+    terms = [
+        Expr("between", (Reference("a"), Literal(2), Literal(4))),
+        Expr("is_in", (Reference("b"), Literal("x"), Literal("z"))),
+        Expr(
+            "|",
+            (
+                Expr("is_null", (Reference("b"),)),
+                Expr(">=", (Expr("+", (Reference("a"), Literal(1))), Literal(4))),
+            ),
+        ),
+    ]
+    assert from_json(to_json(terms)) == terms
+    df = daft.from_pydict(DATA)
+    for term in terms:
+        df = df.where(to_expression(from_json(to_json([term]))[0]))
+    assert df.to_pydict() == {"a": [3], "b": ["x"]}
+
+
+def test_invalid_terms_are_rejected():
+    with pytest.raises(ValueError, match="Invalid JSON for a term"):
+        from_json('[{"column": "a"}]')
+    with pytest.raises(ValueError, match="Unknown procedure of a term"):
+        to_expression(Expr("like", (Reference("b"), Literal("x%"))))
+    with pytest.raises(ValueError, match="expects 2 arguments"):
+        to_expression(Expr("==", (Reference("a"),)))
+    with pytest.raises(ValueError):
+        to_json([Literal(float("nan"))])
@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+import pickle
+
+import pytest
+
+from daft import DataType
+from daft.daft import (
+    FileFormatConfig,
+    IOConfig,
+    ParquetSourceConfig,
+    S3Config,
+    ScanTask,
+    StorageConfig,
+)
+from daft.logical.schema import Schema
+
+
+@pytest.fixture
+def schema():
+    return Schema._from_field_name_and_types([("a", DataType.int64()), ("b", DataType.string())])
+
+
+def make_catalog_scan_task(schema, path="s3://bucket/file.parquet"):
+    io_config = IOConfig(s3=S3Config(region_name="us-west-2", anonymous=True))
+    return ScanTask.catalog_scan_task(
+        file=path,
+        file_format=FileFormatConfig.from_parquet_config(ParquetSourceConfig()),
+        schema=schema._schema,
+        storage_config=StorageConfig(True, io_config),
+        num_rows=10,
+        size_bytes=1024,
+        iceberg_delete_files=None,
+        pushdowns=None,
+        partition_values=None,
+        stats=None,
+    )
+
+
+def make_python_scan_task(schema, *args):
+    return ScanTask.python_factory_func_scan_task(
+        module="daft.io._generator",
+        func_name="_generator_factory_function",
+        func_args=args,
+        schema=schema._schema,
+        num_rows=None,
+        size_bytes=None,
+        pushdowns=None,
+        stats=None,
+    )
+
+
+def test_catalog_scan_task_pickle_roundtrip(schema):
+    scan_task = make_catalog_scan_task(schema)
+    roundtripped = pickle.loads(pickle.dumps(scan_task))
+    assert roundtripped == scan_task
+    assert hash(roundtripped) == hash(scan_task)
+    assert roundtripped != make_catalog_scan_task(schema, path="s3://bucket/other.parquet")
+
+
+def test_python_scan_task_pickle_roundtrip(schema):
+    scan_task = make_python_scan_task(schema, "foo", [1, 2, 3])
+    roundtripped = pickle.loads(pickle.dumps(scan_task))
+    assert roundtripped == scan_task
+    assert hash(roundtripped) == hash(scan_task)
+    assert roundtripped != make_python_scan_task(schema, "bar", [1, 2, 3])
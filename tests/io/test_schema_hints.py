@@ -0,0 +1,49 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import DataType
+from daft.context import planning_config_ctx
+
+
+@pytest.fixture
+def csv_path(tmp_path):
+    path = tmp_path / "data.csv"
+    path.write_text("a,b,c\n1,2,x\n3,4,y\n")
+    return str(path)
+
+
+def test_default_schema_hints(csv_path):
+    with planning_config_ctx(default_schema_hints={"a": DataType.string()}):
+        df = daft.read_csv(csv_path)
+    assert df.schema()["a"].dtype == DataType.string()
+    assert df.schema()["b"].dtype == DataType.int64()
+    assert df.to_pydict() == {"a": ["1", "3"], "b": [2, 4], "c": ["x", "y"]}
+
+
+def test_default_schema_hints_are_merged_with_schema(csv_path):
+    with planning_config_ctx(default_schema_hints={"a": DataType.string()}):
+        df = daft.read_csv(csv_path, schema={"b": DataType.float64()})
+    assert df.schema()["a"].dtype == DataType.string()
+    assert df.schema()["b"].dtype == DataType.float64()
+    assert df.schema()["c"].dtype == DataType.string()
+
+
+def test_default_schema_hints_agreeing_with_schema(csv_path):
+    with planning_config_ctx(default_schema_hints={"a": DataType.string()}):
+        df = daft.read_csv(csv_path, schema={"a": DataType.string()})
+    assert df.schema()["a"].dtype == DataType.string()
+
+
+def test_conflicting_schema_hints(csv_path):
+    with planning_config_ctx(default_schema_hints={"a": DataType.string()}):
+        with pytest.raises(ValueError, match="a is hinted as both Utf8 and Float64"):
+            daft.read_csv(csv_path, schema={"a": DataType.float64()})
+
+
+def test_default_schema_hints_are_not_applied_to_given_schemas(csv_path):
+    schema = {"a": DataType.int64(), "b": DataType.int64(), "c": DataType.string()}
+    with planning_config_ctx(default_schema_hints={"a": DataType.string()}):
+        df = daft.read_csv(csv_path, infer_schema=False, schema=schema)
+    assert df.schema()["a"].dtype == DataType.int64()
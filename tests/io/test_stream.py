@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+import datetime
+import itertools
+
+import pyarrow as pa
+import pytest
+
+import daft
+from tests.conftest import get_tests_daft_runner_name
+
+native_only = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Streams can only be read by the native runner"
+)
+
+SCHEMA = daft.Schema._from_field_name_and_types([("t", daft.DataType.int64()), ("x", daft.DataType.string())])
+
+
+@native_only
+def test_read_stream_batches():
+    def batches():
+        yield {"t": [1, 2], "x": ["a", "b"]}
+        # No data available yet.
+        yield None
+        yield pa.table({"t": [3], "x": ["c"]})
+        yield pa.record_batch({"t": [4], "x": ["d"]})
+
+    df = daft.read_stream(batches, SCHEMA, poll_interval=datetime.timedelta(milliseconds=1))
+    assert df.sort("t").to_pydict() == {"t": [1, 2, 3, 4], "x": ["a", "b", "c", "d"]}
+
+
+@native_only
+def test_read_unbounded_stream_with_limit():
+    def batches():
+        for i in itertools.count():
+            yield {"t": [i], "x": [str(i)]}
+
+    df = daft.read_stream(batches, SCHEMA)
+    assert len(df.limit(5).to_pydict()["t"]) == 5
+
+
+@native_only
+def test_read_stream_drops_late_rows():
+    def batches():
+        yield {"t": [10, 5], "x": ["a", "b"]}
+        # The watermark is now 10 - 3 = 7, so 6 is late.
+        yield {"t": [6, 8, None], "x": ["c", "d", "e"]}
+        yield {"t": [20], "x": ["f"]}
+        # The watermark is now 17.
+        yield {"t": [16, 17], "x": ["g", "h"]}
+
+    df = daft.read_stream(batches, SCHEMA, event_time_column="t", watermark_delay=3)
+    assert df.sort("t").to_pydict() == {"t": [5, 8, 10, 17, 20], "x": ["b", "d", "a", "h", "f"]}
+
+
+def test_read_stream_invalid_event_time_column():
+    with pytest.raises(ValueError, match="not in the schema"):
+        daft.read_stream(lambda: iter([]), SCHEMA, event_time_column="y")
+    with pytest.raises(ValueError, match="timestamp or date"):
+        daft.read_stream(lambda: iter([]), SCHEMA, event_time_column="t", watermark_delay=datetime.timedelta(seconds=1))
+    with pytest.raises(Exception, match="must be an integer or temporal column"):
+        daft.read_stream(lambda: iter([]), SCHEMA, event_time_column="x")
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() == "native", reason="The native runner can read streams")
+def test_read_stream_requires_native_runner():
+    df = daft.read_stream(lambda: iter([{"t": [1], "x": ["a"]}]), SCHEMA)
+    with pytest.raises(Exception, match="native runner"):
+        df.collect()
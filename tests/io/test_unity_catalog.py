@@ -0,0 +1,79 @@
+from __future__ import annotations
+
+import pickle
+from types import SimpleNamespace
+
+import pytest
+
+pytest.importorskip("unitycatalog")
+
+from daft.unity_catalog import UnityCatalog
+from daft.unity_catalog.unity_catalog import _UnityS3CredentialsProvider
+
+
+class FakeTemporaryTableCredentials:
+    def __init__(self):
+        self.num_calls = 0
+
+    def create(self, operation, table_id):
+        self.num_calls += 1
+        return SimpleNamespace(
+            aws_temp_credentials=SimpleNamespace(
+                access_key_id=f"key-{self.num_calls}",
+                secret_access_key="secret",
+                session_token="session",
+            ),
+            gcp_oauth_token=SimpleNamespace(oauth_token="gcp-token"),
+            azure_user_delegation_sas={"sas_token": "sas"},
+            expiration_time=1_700_000_000_000,
+        )
+
+
+class FakeTables:
+    def __init__(self, storage_location):
+        self.storage_location = storage_location
+
+    def retrieve(self, table_name):
+        return SimpleNamespace(
+            table_id="1234",
+            storage_location=self.storage_location,
+            data_source_format="DELTA",
+        )
+
+
+def fake_catalog(storage_location: str) -> UnityCatalog:
+    catalog = UnityCatalog("https://unity.example.com", token="token")
+    catalog._client = SimpleNamespace(
+        tables=FakeTables(storage_location),
+        temporary_table_credentials=FakeTemporaryTableCredentials(),
+    )
+    return catalog
+
+
+def test_load_table_vends_s3_credentials():
+    table = fake_catalog("s3://bucket/table").load_table("cat.schema.table", operation="READ")
+    assert table.table_uri == "s3://bucket/table"
+    assert table.table_name == "cat.schema.table"
+    assert table.table_id == "1234"
+    assert table.data_source_format == "DELTA"
+    assert table.io_config.s3.key_id == "key-1"
+    assert table.io_config.s3.session_token == "session"
+    assert table.io_config.s3.credentials_provider is not None
+
+
+def test_load_table_vends_gcs_and_azure_credentials():
+    assert fake_catalog("gs://bucket/table").load_table("cat.schema.table").io_config.gcs.token == "gcp-token"
+    assert fake_catalog("abfss://container/table").load_table("cat.schema.table").io_config.azure.sas_token == "sas"
+
+
+def test_s3_credentials_provider_refreshes():
+    provider = _UnityS3CredentialsProvider("https://unity.example.com", "token", "1234", "READ")
+    provider._client = SimpleNamespace(temporary_table_credentials=FakeTemporaryTableCredentials())
+
+    assert provider().key_id == "key-1"
+    credentials = provider()
+    assert credentials.key_id == "key-2"
+    assert credentials.expiry.timestamp() == 1_700_000_000
+
+    # The client is dropped when pickling, to be recreated on the worker
+    assert pickle.loads(pickle.dumps(provider))._client is None
@@ -0,0 +1,108 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft.io import WriteConstraint
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Write constraints are only supported by the native runner"
+)
+
+CONSTRAINTS = [
+    WriteConstraint.not_null("id"),
+    WriteConstraint.between("score", min=0, max=100),
+    WriteConstraint.accepted_values("status", ["active", "inactive"]),
+]
+
+
+def make_df():
+    return daft.from_pydict(
+        {
+            "id": [1, 2, None, 4, 5],
+            "score": [10, 200, 30, None, 50],
+            "status": ["active", "inactive", "active", "deleted", None],
+        }
+    )
+
+
+@pytest.mark.parametrize("format", ["parquet", "csv"])
+def test_write_constraints_fail(tmp_path, format):
+    write = daft.DataFrame.write_parquet if format == "parquet" else daft.DataFrame.write_csv
+    with pytest.raises(Exception, match="violate the write constraint `id IS NOT NULL`"):
+        write(make_df(), str(tmp_path), constraints=CONSTRAINTS)
+
+
+def test_write_constraints_pass(tmp_path):
+    df = make_df().where(daft.col("id") == 1)
+    df.write_parquet(str(tmp_path), constraints=CONSTRAINTS)
+    assert daft.read_parquet(str(tmp_path)).to_pydict() == {"id": [1], "score": [10], "status": ["active"]}
+
+
+def test_write_constraints_drop(tmp_path):
+    make_df().write_parquet(str(tmp_path), constraints=CONSTRAINTS, on_constraint_violation="drop")
+    read_back = daft.read_parquet(str(tmp_path)).sort("id").to_pydict()
+    # Nulls satisfy bounds and accepted values, only `not_null` rejects them
+    assert read_back == {"id": [1, 5], "score": [10, 50], "status": ["active", None]}
+
+
+def test_write_constraints_quarantine(tmp_path):
+    quarantine_dir = tmp_path / "quarantine"
+    written = make_df().write_parquet(
+        str(tmp_path / "data"),
+        constraints=CONSTRAINTS,
+        on_constraint_violation="quarantine",
+        quarantine_dir=str(quarantine_dir),
+    )
+    assert daft.read_parquet(str(tmp_path / "data")).sort("id").to_pydict()["id"] == [1, 5]
+
+    quarantined = daft.read_parquet(str(quarantine_dir)).sort("id").to_pydict()
+    assert quarantined["id"] == [2, 4, None]
+    assert quarantined["_violated_constraints"] == [
+        "score >= 0 AND score <= 100",
+        "status IN ['active', 'inactive']",
+        "id IS NOT NULL",
+    ]
+
+    # The files of quarantined rows are part of the result of the write, told apart by the `quarantined` column
+    result = written.to_pydict()
+    quarantined_paths = [path for path, q in zip(result["path"], result["quarantined"]) if q]
+    assert quarantined_paths and all(path.startswith(str(quarantine_dir)) for path in quarantined_paths)
+    assert not all(result["quarantined"])
+
+
+def test_write_constraints_quarantine_format(tmp_path):
+    quarantine_dir = tmp_path / "quarantine"
+    make_df().write_parquet(
+        str(tmp_path / "data"),
+        constraints=CONSTRAINTS,
+        on_constraint_violation="quarantine",
+        quarantine_dir=str(quarantine_dir),
+        quarantine_format="csv",
+    )
+    assert sorted(daft.read_csv(str(quarantine_dir)).to_pydict()["score"], key=str) == [200, 30, None]
+
+
+def test_write_constraints_quarantine_with_job_id(tmp_path):
+    quarantine_dir = tmp_path / "quarantine"
+    written = make_df().write_csv(
+        str(tmp_path / "data"),
+        job_id="job",
+        constraints=CONSTRAINTS,
+        on_constraint_violation="quarantine",
+        quarantine_dir=str(quarantine_dir),
+    )
+    assert len(daft.read_csv(str(tmp_path / "data")).to_pydict()["id"]) == 2
+    assert len(daft.read_csv(str(quarantine_dir)).to_pydict()["id"]) == 3
+    assert sum(written.to_pydict()["quarantined"]) >= 1
+
+
+def test_write_constraints_quarantine_requires_dir(tmp_path):
+    with pytest.raises(Exception, match="quarantine directory is required"):
+        make_df().write_parquet(str(tmp_path), constraints=CONSTRAINTS, on_constraint_violation="quarantine")
+
+
+def test_between_requires_a_bound():
+    with pytest.raises(ValueError, match="at least one of `min` and `max`"):
+        WriteConstraint.between("score")
@@ -1,3 +1,4 @@
+import os
 import uuid
 from typing import List, Optional
 
@@ -122,6 +123,46 @@ def test_write_modes_local_empty_data(tmp_path, write_mode, format):
         raise ValueError(f"Unsupported write_mode: {write_mode}")
 
 
+@pytest.mark.parametrize("format", ["csv", "parquet"])
+@pytest.mark.parametrize("partition_cols", [None, ["a"]])
+def test_write_retries_local(tmp_path, format, partition_cols):
+    path = str(tmp_path)
+    write_with_job_id = daft.DataFrame.write_parquet if format == "parquet" else daft.DataFrame.write_csv
+    first_attempt = daft.from_pydict({"a": ["a", "b"], "b": [1, 2]}).into_partitions(2)
+    retry = daft.from_pydict({"a": ["a", "b"], "b": [3, 4]}).into_partitions(2)
+    other_job = daft.from_pydict({"a": ["a"], "b": [5]})
+
+    write_with_job_id(first_attempt, path, partition_cols=partition_cols, job_id="job")
+    written = write_with_job_id(retry, path, partition_cols=partition_cols, job_id="job").to_pydict()["path"]
+    write_with_job_id(other_job, path, partition_cols=partition_cols, job_id="other-job")
+
+    # The retry replaces the files of the first attempt, and leaves no staged files behind
+    assert all(os.path.basename(file).startswith("job-") for file in written)
+    files = [os.path.join(root, file) for root, _, names in os.walk(path) for file in names]
+    assert not [file for file in files if os.path.basename(file).startswith("_staged-")]
+    assert len([file for file in files if os.path.basename(file).startswith("job-")]) == len(written)
+
+    read_path = path + "/**" if partition_cols is not None else path
+    read_back = read(read_path, format).sort("b").to_pydict()
+    assert read_back["b"] == [3, 4, 5]
+
+
+def test_commit_write_paths_with_scheme(tmp_path):
+    from daft.filesystem import commit_write, staged_file_prefix
+
+    attempt_id = str(uuid.uuid4())
+    staged_name = f"{staged_file_prefix('job', attempt_id)}{uuid.uuid4()}-0.parquet"
+    earlier_name = f"job-{uuid.uuid4()}-0.parquet"
+    for name in [staged_name, earlier_name]:
+        (tmp_path / name).write_bytes(b"data")
+
+    # Native writers name their files with the scheme of the root directory
+    [committed] = commit_write([f"file://{tmp_path}/{staged_name}"], f"file://{tmp_path}", "job", attempt_id, None)
+
+    assert committed == f"file://{tmp_path}/job-{staged_name[len(staged_file_prefix('job', attempt_id)):]}"
+    assert os.listdir(tmp_path) == [os.path.basename(committed)]
+
+
 @pytest.fixture(scope="function")
 def bucket(minio_io_config):
     BUCKET = "write-modes-bucket"
@@ -214,3 +255,22 @@ def test_write_modes_s3_minio_empty_data(
         assert read_back["b"] == []
     else:
         raise ValueError(f"Unsupported write_mode: {write_mode}")
+
+
+@pytest.mark.integration()
+@pytest.mark.parametrize("partition_cols", [None, ["a"]])
+def test_write_retries_s3_minio(minio_io_config, bucket, partition_cols):
+    path = f"s3://{bucket}/{uuid.uuid4()!s}"
+    first_attempt = daft.from_pydict({"a": ["a", "b"], "b": [1, 2]}).into_partitions(2)
+    retry = daft.from_pydict({"a": ["a", "b"], "b": [3, 4]}).into_partitions(2)
+
+    first_attempt.write_parquet(path, partition_cols=partition_cols, io_config=minio_io_config, job_id="job")
+    written = retry.write_parquet(
+        path, partition_cols=partition_cols, io_config=minio_io_config, job_id="job"
+    ).to_pydict()["path"]
+
+    # The committed paths keep the scheme of the written paths
+    assert all(file.startswith(f"{path}/") and "/job-" in file for file in written)
+    read_path = path + "/**" if partition_cols is not None else path
+    read_back = read(read_path, "parquet", minio_io_config).sort("b").to_pydict()
+    assert read_back["b"] == [3, 4]
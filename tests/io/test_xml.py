@@ -0,0 +1,163 @@
+from __future__ import annotations
+
+import gzip
+
+import pytest
+
+import daft
+from daft import DataType, col
+
+BOOKS = """<?xml version="1.0" encoding="UTF-8"?>
+<catalog>
+  <book id="1">
+    <title lang="en">Dune</title>
+    <author>Frank Herbert</author>
+    <year>1965</year>
+    <price>9.99</price>
+    <tag>scifi</tag>
+    <tag>classic</tag>
+  </book>
+  <book id="2">
+    <title lang="en">Emma</title>
+    <author>Jane Austen</author>
+    <year>1815</year>
+    <price>5</price>
+    <tag>romance</tag>
+  </book>
+  <book id="3">
+    <title lang="fr">Candide</title>
+    <year>1759</year>
+  </book>
+</catalog>
+"""
+
+
+@pytest.fixture
+def books_path(tmp_path):
+    path = tmp_path / "books.xml"
+    path.write_text(BOOKS)
+    return str(path)
+
+
+def test_read_xml_infers_schema(books_path):
+    df = daft.read_xml(books_path, row_tag="book")
+
+    assert df.schema().column_names() == ["_id", "title", "author", "year", "price", "tag"]
+    assert df.schema()["_id"].dtype == DataType.int64()
+    assert df.schema()["title"].dtype == DataType.struct({"_lang": DataType.string(), "_VALUE": DataType.string()})
+    assert df.schema()["year"].dtype == DataType.int64()
+    assert df.schema()["price"].dtype == DataType.float64()
+    assert df.schema()["tag"].dtype == DataType.list(DataType.string())
+
+    assert df.to_pydict() == {
+        "_id": [1, 2, 3],
+        "title": [
+            {"_lang": "en", "_VALUE": "Dune"},
+            {"_lang": "en", "_VALUE": "Emma"},
+            {"_lang": "fr", "_VALUE": "Candide"},
+        ],
+        "author": ["Frank Herbert", "Jane Austen", None],
+        "year": [1965, 1815, 1759],
+        "price": [9.99, 5.0, None],
+        "tag": [["scifi", "classic"], ["romance"], None],
+    }
+
+
+def test_read_xml_attribute_prefix_and_value_tag(books_path):
+    df = daft.read_xml(books_path, row_tag="title", attribute_prefix="@", value_tag="text")
+
+    assert df.to_pydict() == {"@lang": ["en", "en", "fr"], "text": ["Dune", "Emma", "Candide"]}
+
+
+def test_read_xml_nested_structs(tmp_path):
+    path = tmp_path / "orders.xml"
+    path.write_text(
+        """<orders>
+          <order>
+            <customer><name>Ada</name><address><city>London</city></address></customer>
+            <item sku="a1"><qty>2</qty></item>
+            <item sku="b2"><qty>1</qty></item>
+          </order>
+          <order>
+            <customer><name>Grace</name></customer>
+          </order>
+        </orders>"""
+    )
+    df = daft.read_xml(str(path), row_tag="order")
+
+    assert df.to_pydict() == {
+        "customer": [
+            {"name": "Ada", "address": {"city": "London"}},
+            {"name": "Grace", "address": None},
+        ],
+        "item": [[{"_sku": "a1", "qty": 2}, {"_sku": "b2", "qty": 1}], None],
+    }
+
+
+def test_read_xml_text_escapes_and_cdata(tmp_path):
+    path = tmp_path / "notes.xml"
+    path.write_text(
+        """<notes>
+          <note><body>Fish &amp; chips</body></note>
+          <note><body><![CDATA[<b>bold</b>]]></body></note>
+          <note><body/></note>
+        </notes>"""
+    )
+    df = daft.read_xml(str(path), row_tag="note")
+
+    assert df.to_pydict() == {"body": ["Fish & chips", "<b>bold</b>", None]}
+
+
+def test_read_xml_with_schema(books_path):
+    schema = {"_id": DataType.string(), "year": DataType.int32(), "isbn": DataType.string()}
+    df = daft.read_xml(books_path, row_tag="book", infer_schema=False, schema=schema)
+
+    assert df.to_pydict() == {"_id": ["1", "2", "3"], "year": [1965, 1815, 1759], "isbn": [None, None, None]}
+
+
+def test_read_xml_schema_hints(books_path):
+    df = daft.read_xml(books_path, row_tag="book", schema={"year": DataType.string()})
+
+    assert df.schema()["year"].dtype == DataType.string()
+    assert df.select("year").to_pydict() == {"year": ["1965", "1815", "1759"]}
+
+
+def test_read_xml_projection_filter_and_limit(books_path):
+    df = daft.read_xml(books_path, row_tag="book")
+
+    assert df.select("author").to_pydict() == {"author": ["Frank Herbert", "Jane Austen", None]}
+    assert df.where(col("year") < 1900).select("_id").to_pydict() == {"_id": [2, 3]}
+    assert df.limit(1).select("_id").to_pydict() == {"_id": [1]}
+
+
+def test_read_xml_gzip(tmp_path):
+    path = tmp_path / "books.xml.gz"
+    path.write_bytes(gzip.compress(BOOKS.encode()))
+    df = daft.read_xml(str(path), row_tag="book")
+
+    assert df.select("_id").to_pydict() == {"_id": [1, 2, 3]}
+
+
+def test_read_xml_multiple_files(tmp_path):
+    for i in range(3):
+        (tmp_path / f"part-{i}.xml").write_text(f"<rows><row><n>{i}</n></row></rows>")
+    df = daft.read_xml(str(tmp_path / "*.xml"), row_tag="row")
+
+    assert sorted(df.to_pydict()["n"]) == [0, 1, 2]
+
+
+def test_read_xml_without_rows(books_path):
+    with pytest.raises(Exception, match="without any <movie> elements"):
+        daft.read_xml(books_path, row_tag="movie")
+
+
+def test_read_xml_malformed(tmp_path):
+    path = tmp_path / "bad.xml"
+    path.write_text("<rows><row><n>1</n></row><row><n>2</m></row></rows>")
+    with pytest.raises(Exception, match="Error parsing XML file"):
+        daft.read_xml(str(path), row_tag="row").collect()
+
+
+def test_read_xml_empty_row_tag(books_path):
+    with pytest.raises(ValueError, match="row tag"):
+        daft.read_xml(books_path, row_tag="")
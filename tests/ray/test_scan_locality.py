@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+from daft.daft import FileFormatConfig, LocalityHints, ParquetSourceConfig, ScanTask, StorageConfig
+from daft.datatype import DataType
+from daft.logical.schema import Schema
+from daft.runners.ray_runner import RAY_REGION_LABEL, RAY_ZONE_LABEL, _preferred_scan_node
+
+NODES = [
+    {"NodeID": "a", "Labels": {RAY_REGION_LABEL: "us-west-2", RAY_ZONE_LABEL: "us-west-2a"}},
+    {"NodeID": "b", "Labels": {RAY_REGION_LABEL: "us-west-2", RAY_ZONE_LABEL: "us-west-2b"}},
+    {"NodeID": "c", "Labels": {RAY_REGION_LABEL: "us-west-2", RAY_ZONE_LABEL: "us-west-2b"}},
+    {"NodeID": "d", "Labels": {}},
+]
+
+
+def test_prefers_nodes_with_cached_data():
+    hints = LocalityHints(zone="us-west-2a", cached_node_ids=["gone", "d", "b"])
+    assert _preferred_scan_node(hints, NODES) == "d"
+
+
+def test_prefers_nodes_in_zone():
+    hints = LocalityHints(region="us-west-2", zone="us-west-2b", cached_node_ids=["gone"])
+    picked = {_preferred_scan_node(hints, NODES) for _ in range(100)}
+    assert picked == {"b", "c"}
+
+
+def test_falls_back_to_nodes_in_region():
+    hints = LocalityHints(region="us-west-2", zone="us-west-2c")
+    assert _preferred_scan_node(hints, NODES) in {"a", "b", "c"}
+
+
+def test_no_preferred_node():
+    assert _preferred_scan_node(LocalityHints(region="eu-west-1"), NODES) is None
+    assert _preferred_scan_node(LocalityHints(), NODES) is None
+
+
+def test_scan_task_locality_hints():
+    scan_task = ScanTask.catalog_scan_task(
+        file="s3://bucket/file.parquet",
+        file_format=FileFormatConfig.from_parquet_config(ParquetSourceConfig()),
+        schema=Schema._from_field_name_and_types([("a", DataType.int64())])._schema,
+        storage_config=StorageConfig(True, None),
+        num_rows=None,
+        size_bytes=None,
+        iceberg_delete_files=None,
+        pushdowns=None,
+        partition_values=None,
+        stats=None,
+    )
+    assert scan_task.locality_hints() is None
+
+    hinted = scan_task.with_locality_hints(LocalityHints(zone="us-west-2a", cached_node_ids=["a"]))
+    assert hinted.locality_hints().zone == "us-west-2a"
+    assert hinted.locality_hints().cached_node_ids == ["a"]
+    assert hinted != scan_task
+    # Empty hints are dropped.
+    assert scan_task.with_locality_hints(LocalityHints()) == scan_task
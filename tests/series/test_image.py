@@ -442,7 +442,7 @@ def test_image_encode_decode_pil_roundtrip(fixed_shape, mode, file_format):
         ("RGB", "bmp"),
         ("RGBA", "png"),
         ("RGBA", "tiff"),
-        # Rust image crate doesn't support WebP encoding.
+        # OpenCV encodes WebP images lossily.
         # ("RGBA", "webp"),
         # TODO(Clark): Support uint16 images.
         # ("L16", "png"),
@@ -594,7 +594,7 @@ def test_image_decode_opencv_multi_modes(output_mode):
         ("RGB", "bmp"),
         ("RGBA", "png"),
         ("RGBA", "tiff"),
-        # Rust image crate doesn't support WebP encoding.
+        # OpenCV encodes WebP images lossily.
         # ("RGBA", "webp"),
         # TODO(Clark): Support uint16 images.
         # ("L16", "png"),
@@ -642,6 +642,27 @@ def test_image_encode_decode_opencv_roundtrip(mode, file_format):
     np.testing.assert_equal(opencv_decoded_imgs, [arr, arr, arr])
 
 
+@pytest.mark.parametrize("mode", ["RGB", "RGBA"])
+def test_image_encode_decode_webp_roundtrip(mode):
+    num_channels = MODE_TO_NUM_CHANNELS[mode]
+    arr1 = np.arange(2 * 3 * num_channels, dtype=np.uint8).reshape((2, 3, num_channels))
+    arr2 = np.arange(4 * 4 * num_channels, dtype=np.uint8).reshape((4, 4, num_channels))
+    arrs = [arr1, arr2, None]
+    if mode == "RGBA":
+        for arr in arrs[:2]:
+            arr[..., -1] = 255
+
+    s = Series.from_pylist(arrs, pyobj="force").cast(DataType.image(mode))
+    encoded = s.image.encode("webp")
+    assert encoded.datatype() == DataType.binary()
+    for bytes_ in encoded.to_pylist()[:2]:
+        assert Image.open(io.BytesIO(bytes_)).format == "WEBP"
+
+    # WebP images are encoded losslessly.
+    decoded = encoded.image.decode(mode=mode)
+    np.testing.assert_equal(decoded.to_pylist(), arrs)
+
+
 @pytest.mark.parametrize("mode", ["L", "LA", "RGB", "RGBA"])
 @pytest.mark.parametrize("fixed_shape", [True, False])
 def test_image_resize_same_mode(fixed_shape, mode):
@@ -837,3 +858,33 @@ def test_image_to_mode_fixed_size(input_mode, output_mode):
     s = s.cast(DataType.image(input_mode, 2, 2)).image.to_mode(output_mode)
     assert s.datatype() == DataType.image(output_mode, 2, 2)
     assert s.to_pylist()[0].shape[2] == MODE_TO_NUM_CHANNELS[output_mode]
+
+
+@pytest.mark.parametrize("mode", ["L", "LA", "RGB", "RGBA"])
+def test_image_to_tensor(mode):
+    channels = MODE_TO_NUM_CHANNELS[mode]
+    data = [
+        np.arange(4 * channels, dtype=np.uint8).reshape((2, 2, channels)),
+        np.arange(4 * channels, 13 * channels, dtype=np.uint8).reshape((3, 3, channels)),
+        None,
+    ]
+    s = Series.from_pylist(data, pyobj="force").cast(DataType.image(mode))
+
+    t = s.image.to_tensor()
+    assert t.datatype() == DataType.tensor(DataType.uint8())
+    np.testing.assert_equal(t.to_pylist(), data)
+
+
+@pytest.mark.parametrize("mode", ["L", "LA", "RGB", "RGBA"])
+def test_image_to_tensor_fixed_size(mode):
+    channels = MODE_TO_NUM_CHANNELS[mode]
+    data = [
+        np.arange(4 * channels, dtype=np.uint8).reshape((2, 2, channels)),
+        np.arange(4 * channels, 8 * channels, dtype=np.uint8).reshape((2, 2, channels)),
+        None,
+    ]
+    s = Series.from_pylist(data, pyobj="force").cast(DataType.image(mode, 2, 2))
+
+    t = s.image.to_tensor()
+    assert t.datatype() == DataType.tensor(DataType.uint8(), (2, 2, channels))
+    np.testing.assert_equal(t.to_pylist(), data)
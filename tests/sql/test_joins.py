@@ -195,3 +195,27 @@ def test_cross_join():
         "C": [2, 4, 6, 8, 2, 4, 6, 8, 2, 4, 6, 8],
         "D": ["d", "e", "f", "g", "d", "e", "f", "g", "d", "e", "f", "g"],
     }
+
+
+def test_lateral_count_keeps_rows_without_matches():
+    x = daft.from_pydict({"id": [1, 2, 3]})
+    y = daft.from_pydict({"id": [1, 1, 3]})
+
+    catalog = SQLCatalog({"x": x, "y": y})
+
+    df = daft.sql(
+        "select x.id, s.n from x, lateral (select count(*) as n from y where y.id = x.id) as s order by x.id",
+        catalog,
+    )
+
+    assert df.to_pydict() == {"id": [1, 2, 3], "n": [2, 0, 1]}
+
+
+def test_lateral_rejects_non_equality_correlation():
+    x = daft.from_pydict({"id": [1, 2, 3]})
+    y = daft.from_pydict({"id": [1, 1, 3]})
+
+    catalog = SQLCatalog({"x": x, "y": y})
+
+    with pytest.raises(Exception, match="equality predicates"):
+        daft.sql("select * from x, lateral (select id from y where y.id < x.id) as s", catalog)
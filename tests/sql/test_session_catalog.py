@@ -0,0 +1,56 @@
+import daft
+from daft.sql.sql import SQLCatalog
+
+
+def test_create_temp_view():
+    df = daft.from_pydict({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    catalog = SQLCatalog({"df": df})
+
+    daft.sql("CREATE TEMP VIEW small AS SELECT a FROM df WHERE a < 3", catalog=catalog).collect()
+    actual = daft.sql("SELECT * FROM small ORDER BY a").to_pydict()
+
+    assert actual == {"a": [1, 2]}
+
+
+def test_create_or_replace_temp_view():
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    catalog = SQLCatalog({"df": df})
+
+    daft.sql("CREATE TEMP VIEW replaced AS SELECT a FROM df", catalog=catalog).collect()
+    daft.sql("CREATE OR REPLACE TEMP VIEW replaced (c) AS SELECT a + 1 FROM df", catalog=catalog).collect()
+    actual = daft.sql("SELECT c FROM replaced ORDER BY c").to_pydict()
+
+    assert actual == {"c": [2, 3, 4]}
+
+
+def test_information_schema():
+    df = daft.from_pydict({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    catalog = SQLCatalog({"my_table": df})
+    daft.sql("CREATE TEMP VIEW my_view AS SELECT b FROM my_table", catalog=catalog, register_globals=False).collect()
+
+    tables = daft.sql(
+        "SELECT table_name, table_type FROM information_schema.tables ORDER BY table_name",
+        catalog=catalog,
+        register_globals=False,
+    ).to_pydict()
+    assert tables == {"table_name": ["my_table", "my_view"], "table_type": ["BASE TABLE", "VIEW"]}
+
+    columns = daft.sql(
+        "SELECT column_name, ordinal_position, data_type FROM information_schema.columns WHERE table_name = 'my_table'",
+        catalog=catalog,
+        register_globals=False,
+    ).to_pydict()
+    assert columns == {"column_name": ["a", "b"], "ordinal_position": [1, 2], "data_type": ["Int64", "Utf8"]}
+
+
+def test_information_schema_does_not_change_the_catalog():
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    catalog = SQLCatalog({"my_table": df})
+
+    daft.sql("SELECT * FROM information_schema.tables", catalog=catalog, register_globals=False).collect()
+    actual = daft.sql(
+        "SELECT 'information_schema' AS s FROM my_table LIMIT 1", catalog=catalog, register_globals=False
+    ).to_pydict()
+
+    assert actual == {"s": ["information_schema"]}
+    assert catalog._catalog.list_tables() == ["my_table"]
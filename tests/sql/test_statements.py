@@ -0,0 +1,133 @@
+from __future__ import annotations
+
+import os
+
+import pytest
+
+import daft
+from daft.sql.sql import SQLCatalog
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="SQL writes are only supported by the native runner"
+)
+
+
+@pytest.fixture
+def catalog():
+    return SQLCatalog({"src": daft.from_pydict({"a": [1, 2, 3], "b": ["x", "y", "z"]})})
+
+
+def data_files(path):
+    return sorted(
+        os.path.join(root, name) for root, _, names in os.walk(path) for name in names if not name.startswith(".")
+    )
+
+
+def test_copy_into_manifest_has_file_sizes(tmp_path, catalog):
+    location = str(tmp_path / "export")
+    manifest = daft.sql(f"copy into '{location}' from (select * from src)", catalog=catalog).to_pydict()
+
+    assert sum(manifest["num_rows"]) == 3
+    assert manifest["size_bytes"] == [os.path.getsize(path) for path in manifest["path"]]
+
+
+def test_copy_into_error_if_exists(tmp_path, catalog):
+    location = str(tmp_path / "export")
+    daft.sql(f"copy into '{location}' from (select * from src)", catalog=catalog).collect()
+
+    with pytest.raises(Exception, match="already contains files"):
+        daft.sql(f"copy into '{location}' from (select * from src) mode = error_if_exists", catalog=catalog).collect()
+
+
+def test_copy_into_overwrite(tmp_path, catalog):
+    location = str(tmp_path / "export")
+    daft.sql(f"copy into '{location}' from (select * from src)", catalog=catalog).collect()
+    daft.sql(f"copy into '{location}' from (select * from src)", catalog=catalog).collect()
+    assert daft.read_parquet(location).count_rows() == 6
+
+    manifest = daft.sql(
+        f"copy into '{location}' from (select * from src where a > 1) mode = overwrite", catalog=catalog
+    ).to_pydict()
+    assert data_files(location) == sorted(manifest["path"])
+    assert sorted(daft.read_parquet(location).to_pydict()["a"]) == [2, 3]
+
+
+def test_ctas_with_location_returns_row_count_and_reads_location(tmp_path, catalog):
+    location = str(tmp_path / "t")
+    result = daft.sql(
+        f"create table t with (location = '{location}') as select * from src where a > 1", catalog=catalog
+    ).to_pydict()
+    assert result == {"num_affected_rows": [2]}
+
+    # the table is read back from its location
+    assert data_files(location)
+    assert daft.sql("select a, b from t order by a", catalog=catalog).to_pydict() == {"a": [2, 3], "b": ["y", "z"]}
+
+    result = daft.sql("insert into t select * from src where a = 1", catalog=catalog).to_pydict()
+    assert result == {"num_affected_rows": [1]}
+    assert daft.sql("select a from t order by a", catalog=catalog).to_pydict() == {"a": [1, 2, 3]}
+
+
+def test_ctas_with_location_fails_on_existing_files(tmp_path, catalog):
+    location = str(tmp_path / "t")
+    daft.sql(f"create table t with (location = '{location}') as select * from src", catalog=catalog).collect()
+
+    with pytest.raises(Exception, match="already contains files"):
+        daft.sql(f"create table u with (location = '{location}') as select * from src", catalog=catalog).collect()
+
+    daft.sql(
+        f"create or replace table t with (location = '{location}') as select * from src where a = 3", catalog=catalog
+    ).collect()
+    assert daft.sql("select a from t", catalog=catalog).to_pydict() == {"a": [3]}
+
+
+def test_ctas_with_location_is_registered_after_write(tmp_path, catalog):
+    location = str(tmp_path / "t")
+    daft.sql(f"create table t with (location = '{location}') as select * from src", catalog=catalog).collect()
+
+    with pytest.raises(Exception, match="already contains files"):
+        daft.sql(f"create table u with (location = '{location}') as select * from src", catalog=catalog)
+    # the failed write didn't register the table
+    with pytest.raises(Exception, match="u"):
+        daft.sql("select * from u", catalog=catalog).collect()
+
+
+def test_ctas_delta(tmp_path, catalog):
+    pytest.importorskip("deltalake")
+    location = str(tmp_path / "t")
+    result = daft.sql(
+        f"create table t with (format = 'delta', location = '{location}') partition by (b) as select * from src",
+        catalog=catalog,
+    ).to_pydict()
+    assert result == {"num_affected_rows": [3]}
+    assert daft.sql("select a from t order by a", catalog=catalog).to_pydict() == {"a": [1, 2, 3]}
+    assert daft.read_deltalake(location).count_rows() == 3
+
+    with pytest.raises(Exception):
+        daft.sql(f"create table u with (format = 'delta', location = '{location}') as select * from src", catalog=catalog)
+
+    daft.sql(
+        f"create or replace table t with (format = 'delta', location = '{location}') as select * from src where a = 1",
+        catalog=catalog,
+    )
+    assert daft.sql("select a from t", catalog=catalog).to_pydict() == {"a": [1]}
+
+
+def test_statements_persist_to_supplied_catalog(catalog):
+    daft.sql("create table t as select a from src", catalog=catalog, register_globals=False).collect()
+    daft.sql("insert into t select a + 10 from src", catalog=catalog).collect()
+    assert sorted(daft.sql("select a from t", catalog=catalog, register_globals=False).to_pydict()["a"]) == [
+        1,
+        2,
+        3,
+        11,
+        12,
+        13,
+    ]
+
+
+def test_statements_persist_to_session_without_catalog():
+    src = daft.from_pydict({"a": [1, 2, 3]})  # noqa: F841
+    daft.sql("create or replace table persisted_t as select a from src where a > 1").collect()
+    assert daft.sql("select a from persisted_t order by a").to_pydict() == {"a": [2, 3]}
@@ -120,3 +120,31 @@ def test_utf8_exprs():
     )
     actual = actual.to_pydict()
     assert actual == expected
+
+
+def test_hf_tokenize_exprs():
+    tokenizer = "tests/assets/tokens/wordlevel_tokenizer.json"
+    df = daft.from_pydict({"a": ["hello world", "daft", None]})
+    sql = f"""
+    SELECT
+        tokenize(a, '{tokenizer}') as tokenize_a,
+        tokenize(a, '{tokenizer}', false) as tokenize_no_special_a,
+        detokenize(tokenize(a, '{tokenizer}'), '{tokenizer}') as detokenize_a
+    FROM df
+    """
+    actual = daft.sql(sql).collect().to_pydict()
+    expected = df.select(
+        col("a").str.tokenize(tokenizer).alias("tokenize_a"),
+        col("a").str.tokenize(tokenizer, add_special_tokens=False).alias("tokenize_no_special_a"),
+        col("a").str.tokenize(tokenizer).str.detokenize(tokenizer).alias("detokenize_a"),
+    ).to_pydict()
+    assert actual == expected
+
+
+def test_collate():
+    df = daft.from_pydict({"a": ["b", "C", "a", "B"]})
+
+    assert daft.sql("SELECT a COLLATE case_insensitive AS a FROM df").to_pydict() == {"a": ["b", "C", "a", "B"]}
+    assert daft.sql("SELECT a FROM df WHERE a COLLATE case_insensitive = 'b'").to_pydict() == {"a": ["b", "B"]}
+    assert daft.sql("SELECT a FROM df WHERE 'b' < (a COLLATE case_insensitive)").to_pydict() == {"a": ["C"]}
+    assert daft.sql("SELECT a FROM df ORDER BY a COLLATE case_insensitive, a").to_pydict() == {"a": ["a", "B", "b", "C"]}
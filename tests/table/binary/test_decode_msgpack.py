@@ -0,0 +1,84 @@
+from __future__ import annotations
+
+import msgpack
+import pytest
+
+from daft import DataType
+from daft.exceptions import DaftCoreException
+from daft.expressions import col
+from daft.table import MicroPartition
+
+EVENT_DTYPE = DataType.struct(
+    {
+        "id": DataType.int64(),
+        "name": DataType.string(),
+        "tags": DataType.list(DataType.string()),
+        "scores": DataType.map(DataType.string(), DataType.float64()),
+        "raw": DataType.binary(),
+    }
+)
+
+
+def decode(payloads, dtype):
+    mp = MicroPartition.from_pydict({"payload": payloads})
+    return mp.eval_expression_list([col("payload").binary.decode_msgpack(dtype)])
+
+
+def test_decode_msgpack_struct_from_map():
+    payloads = [
+        msgpack.packb({"id": 1, "name": "a", "tags": ["x", "y"], "scores": {"p": 0.5}, "raw": b"\x00", "extra": 1}),
+        msgpack.packb({"id": -2, "tags": []}),
+        None,
+    ]
+    result = decode(payloads, EVENT_DTYPE)
+    assert result.schema()["payload"].dtype == EVENT_DTYPE
+    assert result.to_pydict()["payload"] == [
+        {"id": 1, "name": "a", "tags": ["x", "y"], "scores": [("p", 0.5)], "raw": b"\x00"},
+        {"id": -2, "name": None, "tags": [], "scores": None, "raw": None},
+        None,
+    ]
+
+
+def test_decode_msgpack_struct_from_array():
+    payloads = [msgpack.packb([1, "a", ["x"], {"p": 1}, None])]
+    result = decode(payloads, EVENT_DTYPE)
+    assert result.to_pydict()["payload"] == [
+        {"id": 1, "name": "a", "tags": ["x"], "scores": [("p", 1.0)], "raw": None},
+    ]
+
+
+@pytest.mark.parametrize(
+    "values, dtype",
+    [
+        ([1, 2**40, None], DataType.int64()),
+        ([1, 255, None], DataType.uint8()),
+        ([1.5, 2, None], DataType.float32()),
+        ([True, False, None], DataType.bool()),
+        (["a", "", None], DataType.string()),
+        ([[1, 2], [], None], DataType.list(DataType.int32())),
+    ],
+)
+def test_decode_msgpack_scalars(values, dtype):
+    result = decode([msgpack.packb(value) for value in values], dtype)
+    assert result.schema()["payload"].dtype == dtype
+    assert result.to_pydict()["payload"] == values
+
+
+def test_decode_msgpack_type_mismatch():
+    with pytest.raises(DaftCoreException, match="Cannot decode string value as Int64"):
+        decode([msgpack.packb("not a number")], DataType.int64())
+
+
+def test_decode_msgpack_negative_unsigned():
+    with pytest.raises(DaftCoreException, match="Integer -1 is out of range of UInt32"):
+        decode([msgpack.packb(-1)], DataType.uint32())
+
+
+def test_decode_msgpack_trailing_bytes():
+    with pytest.raises(DaftCoreException, match="bytes remain after the value"):
+        decode([msgpack.packb(1) + msgpack.packb(2)], DataType.int64())
+
+
+def test_decode_msgpack_unsupported_dtype():
+    with pytest.raises(Exception, match="Decoding values as Date is not supported"):
+        decode([msgpack.packb(1)], DataType.date())
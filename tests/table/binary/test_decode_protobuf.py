@@ -0,0 +1,110 @@
+from __future__ import annotations
+
+import pytest
+from google.protobuf import descriptor_pb2, descriptor_pool, message_factory
+
+from daft import DataType
+from daft.exceptions import DaftCoreException
+from daft.expressions import col
+from daft.table import MicroPartition
+
+FieldProto = descriptor_pb2.FieldDescriptorProto
+
+
+def make_descriptor_set() -> bytes:
+    file = descriptor_pb2.FileDescriptorProto(name="events.proto", package="test.events", syntax="proto3")
+
+    kind = file.enum_type.add(name="Kind")
+    kind.value.add(name="UNKNOWN", number=0)
+    kind.value.add(name="CLICK", number=1)
+
+    user = file.message_type.add(name="User")
+    user.field.add(name="id", number=1, type=FieldProto.TYPE_INT64, label=FieldProto.LABEL_OPTIONAL)
+    user.field.add(name="name", number=2, type=FieldProto.TYPE_STRING, label=FieldProto.LABEL_OPTIONAL)
+
+    event = file.message_type.add(name="Event")
+    event.field.add(name="id", number=1, type=FieldProto.TYPE_UINT64, label=FieldProto.LABEL_OPTIONAL)
+    event.field.add(
+        name="kind", number=2, type=FieldProto.TYPE_ENUM, type_name=".test.events.Kind", label=FieldProto.LABEL_OPTIONAL
+    )
+    event.field.add(
+        name="user", number=3, type=FieldProto.TYPE_MESSAGE, type_name=".test.events.User", label=FieldProto.LABEL_OPTIONAL
+    )
+    event.field.add(name="tags", number=4, type=FieldProto.TYPE_STRING, label=FieldProto.LABEL_REPEATED)
+    event.field.add(name="score", number=5, type=FieldProto.TYPE_DOUBLE, label=FieldProto.LABEL_OPTIONAL)
+    entry = event.nested_type.add(name="CountsEntry")
+    entry.options.map_entry = True
+    entry.field.add(name="key", number=1, type=FieldProto.TYPE_STRING, label=FieldProto.LABEL_OPTIONAL)
+    entry.field.add(name="value", number=2, type=FieldProto.TYPE_INT32, label=FieldProto.LABEL_OPTIONAL)
+    event.field.add(
+        name="counts",
+        number=6,
+        type=FieldProto.TYPE_MESSAGE,
+        type_name=".test.events.Event.CountsEntry",
+        label=FieldProto.LABEL_REPEATED,
+    )
+
+    return descriptor_pb2.FileDescriptorSet(file=[file]).SerializeToString()
+
+
+DESCRIPTOR_SET = make_descriptor_set()
+
+
+def event_class():
+    pool = descriptor_pool.DescriptorPool()
+    for file in descriptor_pb2.FileDescriptorSet.FromString(DESCRIPTOR_SET).file:
+        pool.Add(file)
+    return message_factory.GetMessageClass(pool.FindMessageTypeByName("test.events.Event"))
+
+
+def test_decode_protobuf():
+    Event = event_class()
+    first = Event(id=1, kind=1, tags=["a", "b"], score=0.5, counts={"y": 2, "x": 1})
+    first.user.id = 7
+    first.user.name = "daft"
+    payloads = [first.SerializeToString(), Event(id=2).SerializeToString(), None]
+
+    mp = MicroPartition.from_pydict({"payload": payloads})
+    result = mp.eval_expression_list([col("payload").binary.decode_protobuf(DESCRIPTOR_SET, "test.events.Event")])
+
+    assert result.schema()["payload"].dtype == DataType.struct(
+        {
+            "id": DataType.uint64(),
+            "kind": DataType.string(),
+            "user": DataType.struct({"id": DataType.int64(), "name": DataType.string()}),
+            "tags": DataType.list(DataType.string()),
+            "score": DataType.float64(),
+            "counts": DataType.map(DataType.string(), DataType.int32()),
+        }
+    )
+    assert result.to_pydict()["payload"] == [
+        {
+            "id": 1,
+            "kind": "CLICK",
+            "user": {"id": 7, "name": "daft"},
+            "tags": ["a", "b"],
+            "score": 0.5,
+            "counts": [("x", 1), ("y", 2)],
+        },
+        # unset scalars have their default values, while unset messages are null
+        {"id": 2, "kind": "UNKNOWN", "user": None, "tags": [], "score": 0.0, "counts": []},
+        None,
+    ]
+
+
+def test_decode_protobuf_unknown_message():
+    mp = MicroPartition.from_pydict({"payload": [b""]})
+    with pytest.raises(DaftCoreException, match="is not defined by the descriptor set"):
+        mp.eval_expression_list([col("payload").binary.decode_protobuf(DESCRIPTOR_SET, "test.events.Missing")])
+
+
+def test_decode_protobuf_invalid_payload():
+    mp = MicroPartition.from_pydict({"payload": [b"\xff\xff\xff"]})
+    with pytest.raises(DaftCoreException, match="Error decoding protobuf message test.events.Event"):
+        mp.eval_expression_list([col("payload").binary.decode_protobuf(DESCRIPTOR_SET, "test.events.Event")])
+
+
+def test_decode_protobuf_requires_binary():
+    mp = MicroPartition.from_pydict({"payload": ["not binary"]})
+    with pytest.raises(Exception, match="Expects input to decode_protobuf to be binary"):
+        mp.eval_expression_list([col("payload").binary.decode_protobuf(DESCRIPTOR_SET, "test.events.Event")])
@@ -323,6 +323,31 @@ def test_table_partition_by_range_multi_column_string() -> None:
     assert split_tables[0].to_pydict() == {"x": ["c", "c"], "y": ["2", "4"]}
 
 
+@pytest.mark.parametrize(
+    "desc, nulls_first, expected",
+    [
+        (False, False, [[1], [3, None, None, 2]]),
+        (False, True, [[None, 1, None], [3, 2]]),
+        (True, False, [[3], [None, 1, None, 2]]),
+        (True, True, [[3, None, None], [1, 2]]),
+    ],
+)
+def test_table_partition_by_range_nulls_first(desc, nulls_first, expected) -> None:
+    table = MicroPartition.from_pydict({"x": [3, None, 1, None, 2]})
+    boundaries = Table.from_pydict({"x": [2]})
+
+    split_tables = table.partition_by_range([col("x")], boundaries, [desc], nulls_first=[nulls_first])
+    assert [st.to_pydict()["x"] for st in split_tables] == expected
+
+
+def test_table_partition_by_range_nulls_first_multi_column() -> None:
+    table = MicroPartition.from_pydict({"x": [1, 1, 1, 2], "y": [None, 5, 1, None]})
+    boundaries = Table.from_pydict({"x": [1], "y": [3]})
+
+    split_tables = table.partition_by_range([col("x"), col("y")], boundaries, [False, False], nulls_first=[False, True])
+    assert [st.to_pydict()["y"] for st in split_tables] == [[None, 1], [5, None]]
+
+
 def test_table_partition_by_range_input() -> None:
     data = {"x": [1, 2, 3], "b": [0, 1, 2]}
     table_cls = MicroPartition.from_pydict(data)
@@ -214,3 +214,52 @@ def test_tokenize_builtin_tokens_disabled(encoding, end_token):
     s = daft.from_pydict({"a": test_data})
     a = s.select(col("a").str.tokenize_encode(encoding, use_special_tokens=False)).to_pydict()["a"]
     assert len(a[0]) >= 1 and a[0][0] != end_token
+
+
+HF_TOKENIZER_PATH = "tests/assets/tokens/wordlevel_tokenizer.json"
+
+
+def test_tokenize_hf_tokenizer():
+    test_data = ["Hello world", "hello daft!", "", "unknown words", None]
+    s = daft.from_pydict({"a": test_data})
+    result = s.select(
+        col("a").str.tokenize(HF_TOKENIZER_PATH).alias("with_special"),
+        col("a").str.tokenize(HF_TOKENIZER_PATH, add_special_tokens=False).alias("without_special"),
+    )
+    assert result.schema()["with_special"].dtype == DataType.list(DataType.int32())
+    assert result.to_pydict() == {
+        "with_special": [[1, 3, 4, 2], [1, 3, 5, 6, 2], [1, 2], [1, 0, 0, 2], None],
+        "without_special": [[3, 4], [3, 5, 6], [], [0, 0], None],
+    }
+
+
+@pytest.mark.parametrize("num_type", [DataType.int32(), DataType.int64(), DataType.uint32()])
+def test_detokenize_hf_tokenizer(num_type: DataType):
+    token_data = [[1, 3, 4, 2], [3, 5, 6], [], None]
+    s = daft.from_pydict({"a": token_data}).select(col("a").cast(DataType.list(num_type)))
+    result = s.select(
+        col("a").str.detokenize(HF_TOKENIZER_PATH).alias("skip_special"),
+        col("a").str.detokenize(HF_TOKENIZER_PATH, skip_special_tokens=False).alias("with_special"),
+    )
+    assert result.to_pydict() == {
+        "skip_special": ["hello world", "hello daft !", "", None],
+        "with_special": ["[CLS] hello world [SEP]", "hello daft !", "", None],
+    }
+
+
+def test_tokenize_hf_tokenizer_roundtrip():
+    s = daft.from_pydict({"a": ["hello world", "daft daft hello"]})
+    result = s.select(col("a").str.tokenize(HF_TOKENIZER_PATH).str.detokenize(HF_TOKENIZER_PATH))
+    assert result.to_pydict() == {"a": ["hello world", "daft daft hello"]}
+
+
+def test_tokenize_hf_tokenizer_invalid_file():
+    s = daft.from_pydict({"a": ["this should fail"]})
+    with pytest.raises(DaftCoreException, match="Error loading Hugging Face tokenizer"):
+        s.select(col("a").str.tokenize("tests/assets/tokens/tokens_5k.tiktoken")).collect()
+
+
+def test_detokenize_invalid_dtype():
+    s = daft.from_pydict({"a": ["not", "tokens"]})
+    with pytest.raises(Exception, match="Expected input to detokenize to be list\\[integer\\]"):
+        s.select(col("a").str.detokenize(HF_TOKENIZER_PATH)).collect()
@@ -0,0 +1,130 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft.context import get_context
+from daft.execution.native_executor import NativeExecutor
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="NativeExecutor is only used by the native runner"
+)
+
+
+def execute_streaming(df, config=None, **kwargs):
+    builder = df._builder.optimize()
+    runner = get_context().get_or_create_runner()
+    psets = {k: v.values() for k, v in runner._part_set_cache.get_all_partition_sets().items()}
+    config = config if config is not None else builder.execution_config()
+    executor = NativeExecutor.from_logical_plan_builder(builder)
+    return executor.execute_streaming(psets, config, **kwargs)
+
+
+@pytest.mark.parametrize("buffer_size", [1, 4])
+def test_execute_streaming_in_partition_order(buffer_size):
+    df = daft.from_pydict({"a": list(range(100))}).into_partitions(5)
+
+    parts = list(execute_streaming(df, buffer_size=buffer_size))
+    assert [value for part in parts for value in part.to_pydict()["a"]] == list(range(100))
+
+
+def test_execute_streaming_in_arrival_order():
+    df = daft.from_pydict({"a": list(range(100))}).into_partitions(5)
+
+    parts = list(execute_streaming(df, ordered=False))
+    assert sorted(value for part in parts for value in part.to_pydict()["a"]) == list(range(100))
+
+
+def test_execute_streaming_rejects_empty_buffer():
+    df = daft.from_pydict({"a": [1, 2, 3]})
+
+    with pytest.raises(Exception, match="at least 1"):
+        execute_streaming(df, buffer_size=0)
+
+
+def test_execute_streaming_checkpointed_run_requires_partition_order(tmp_path):
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    config = df._builder.execution_config().with_config_values(checkpoint_dir=str(tmp_path))
+
+    with pytest.raises(Exception, match="partition order"):
+        execute_streaming(df, config=config, ordered=False)
+    assert [part.to_pydict() for part in execute_streaming(df, config=config)] == [{"a": [1, 2, 3]}]
+
+
+class QueryState:
+    def __init__(self):
+        self.fail = True
+        self.calls = 0
+
+
+@pytest.fixture
+def checkpointed_query(tmp_path):
+    """A two-stage query over parquet files whose first stage counts its calls and whose final stage fails on demand."""
+    data_dir = tmp_path / "data"
+    data_dir.mkdir()
+    checkpoint_dir = tmp_path / "checkpoints"
+    state = QueryState()
+
+    @daft.udf(return_dtype=daft.DataType.int64())
+    def count_calls(x):
+        state.calls += 1
+        return x
+
+    @daft.udf(return_dtype=daft.DataType.int64())
+    def fail_on_demand(x):
+        if state.fail:
+            raise RuntimeError("final stage failed")
+        return x
+
+    def run():
+        df = daft.read_parquet(str(data_dir / "*.parquet"))
+        df = df.select(count_calls(df["a"]).alias("a")).into_partitions(2)
+        df = df.select(fail_on_demand(df["a"]).alias("a"))
+        with daft.execution_config_ctx(checkpoint_dir=str(checkpoint_dir)):
+            return sorted(df.to_pydict()["a"])
+
+    return data_dir, checkpoint_dir, state, run
+
+
+def test_checkpointed_query_resumes_after_failure(checkpointed_query):
+    data_dir, checkpoint_dir, state, run = checkpointed_query
+    daft.from_pydict({"a": [3, 1, 2]}).write_parquet(str(data_dir))
+
+    with pytest.raises(Exception, match="final stage failed"):
+        run()
+    assert state.calls > 0
+
+    state.fail = False
+    state.calls = 0
+    assert run() == [1, 2, 3]
+    # the first stage was resumed from its checkpoint, which is deleted once the query succeeded
+    assert state.calls == 0
+    assert list(checkpoint_dir.iterdir()) == []
+
+
+def test_checkpointed_query_reruns_with_changed_input(checkpointed_query):
+    data_dir, _, state, run = checkpointed_query
+    daft.from_pydict({"a": [3, 1, 2]}).write_parquet(str(data_dir))
+
+    with pytest.raises(Exception, match="final stage failed"):
+        run()
+
+    for path in data_dir.iterdir():
+        path.unlink()
+    daft.from_pydict({"a": [10, 20, 30, 40]}).write_parquet(str(data_dir))
+    state.fail = False
+    state.calls = 0
+    assert run() == [10, 20, 30, 40]
+    assert state.calls > 0
+
+
+def test_joins_with_shared_build_side():
+    fact = daft.from_pydict({"a": [1, 2, 2, 4], "b": ["w", "x", "y", "z"]})
+    dim = daft.from_pydict({"a": [1, 2, 3], "c": [10, 20, 30]})
+
+    df = fact.join(dim, on="a").join(dim.select("a", (dim["c"] * 2).alias("d")), on="a")
+    assert df.sort("b").to_pydict() == {"a": [1, 2, 2], "b": ["w", "x", "y"], "c": [10, 20, 20], "d": [20, 40, 40]}
+
+    df = daft.sql("select b from fact where a in (select a from dim) and a + 1 in (select a from dim)")
+    assert df.sort("b").to_pydict() == {"b": ["w", "x", "y"]}
@@ -0,0 +1,60 @@
+from __future__ import annotations
+
+import json
+
+import pytest
+
+import daft
+from daft import col
+
+
+@pytest.fixture
+def events():
+    events = []
+    daft.set_query_event_log(events.append)
+    yield events
+    daft.set_query_event_log(None)
+
+
+def test_logs_succeeded_query(events):
+    df = daft.from_pydict({"a": [1, 2, 3]}).where(col("a") > 1)
+    df.collect()
+
+    assert len(events) == 1
+    event = events[0]
+    assert event["status"] == "succeeded"
+    assert event["output_rows"] == 2
+    assert event["output_bytes"] > 0
+    assert "Filter" in event["plan"]
+    assert event["error"] is None
+    assert event["start_time"] <= event["end_time"]
+    assert event["duration_seconds"] >= 0
+
+
+def test_logs_failed_query(events):
+    @daft.udf(return_dtype=daft.DataType.int64())
+    def fail(x):
+        raise ValueError("boom")
+
+    df = daft.from_pydict({"a": [1, 2, 3]}).select(fail(col("a")))
+    with pytest.raises(Exception):
+        df.collect()
+
+    assert len(events) == 1
+    assert events[0]["status"] == "failed"
+    assert "boom" in events[0]["error"]
+
+
+def test_logs_to_json_lines_file(tmp_path):
+    path = tmp_path / "events.jsonl"
+    daft.set_query_event_log(str(path))
+    try:
+        daft.from_pydict({"a": [1]}).collect()
+        daft.from_pydict({"a": [1, 2]}).collect()
+    finally:
+        daft.set_query_event_log(None)
+
+    lines = [json.loads(line) for line in path.read_text().splitlines()]
+    assert [line["output_rows"] for line in lines] == [1, 2]
+    assert lines[0]["session_id"] == lines[1]["session_id"]
+    assert lines[0]["query_id"] != lines[1]["query_id"]
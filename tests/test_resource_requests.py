@@ -26,6 +26,26 @@ def my_udf(c):
     return [1] * len(c)
 
 
+@pytest.mark.parametrize(
+    "env, expected",
+    [("", []), ("0", ["0"]), ("0,2", ["0", "2"]), (" 1 , 3 ,", ["1", "3"]), ("GPU-1a2b,GPU-3c4d", ["GPU-1a2b", "GPU-3c4d"])],
+)
+def test_cuda_visible_devices_parsing(monkeypatch, env, expected):
+    monkeypatch.setenv("CUDA_VISIBLE_DEVICES", env)
+    assert cuda_visible_devices() == expected
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="Native runner resource pools")
+def test_native_runner_gpus_from_cuda_visible_devices(monkeypatch):
+    monkeypatch.setenv("CUDA_VISIBLE_DEVICES", "0")
+    gpu_udf = my_udf.override_options(num_gpus=2)
+    df = daft.from_pydict(DATA).with_column("out", gpu_udf(col("id")))
+
+    # a single visible device can't serve a UDF requesting two GPUs
+    with pytest.raises(Exception, match="GPU"):
+        df.collect()
+
+
 ###
 # Test behavior of overriding options
 ###
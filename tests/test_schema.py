@@ -220,3 +220,62 @@ def test_schema_pyarrow_roundtrip():
     )
 
     assert Schema.from_pyarrow_schema(pa_schema).to_pyarrow_schema() == roundtrip_pa_schema
+
+
+def test_schema_diff():
+    old = Schema._from_field_name_and_types(
+        [("a", DataType.int64()), ("b", DataType.string()), ("c", DataType.float32())]
+    )
+    new = Schema._from_field_name_and_types(
+        [("c", DataType.float64()), ("a", DataType.int64()), ("d", DataType.bool())]
+    )
+    diff = old.diff(new)
+    assert [(name, kind) for name, kind, _ in diff.changes()] == [
+        ("a", "moved"),
+        ("b", "removed"),
+        ("c", "type_changed"),
+        ("d", "added"),
+    ]
+    assert old.diff(old).is_empty()
+    assert not old.diff(old)
+
+
+def test_schema_diff_append():
+    table = Schema._from_field_name_and_types([("a", DataType.int64()), ("b", DataType.string())])
+
+    # missing fields are filled with nulls and types may be widened to the table's
+    data = Schema._from_field_name_and_types([("a", DataType.int32())])
+    assert table.diff(data).can_append()
+
+    data = Schema._from_field_name_and_types(
+        [("a", DataType.float64()), ("b", DataType.string()), ("c", DataType.int64())]
+    )
+    diff = table.diff(data)
+    assert not diff.can_append()
+    assert [name for name, _ in diff.append_incompatibilities()] == ["a", "c"]
+
+
+def test_schema_diff_union():
+    schema = Schema._from_field_name_and_types([("a", DataType.int32()), ("b", DataType.string())])
+    assert schema.diff(Schema._from_field_name_and_types([("a", DataType.int64()), ("b", DataType.string())])).can_union()
+
+    reordered = Schema._from_field_name_and_types([("b", DataType.string()), ("a", DataType.int32())])
+    assert not schema.diff(reordered).can_union()
+
+    incompatible = Schema._from_field_name_and_types([("a", DataType.string()), ("b", DataType.string())])
+    assert schema.diff(incompatible).union_incompatibilities() == [
+        ("a", "has types Int32 and Utf8, which have no common type")
+    ]
+
+
+def test_schema_diff_breaking_changes():
+    old = Schema._from_field_name_and_types([("a", DataType.int32()), ("b", DataType.list(DataType.int8()))])
+
+    # adding fields and widening types is safe
+    new = Schema._from_field_name_and_types(
+        [("a", DataType.int64()), ("b", DataType.list(DataType.int16())), ("c", DataType.bool())]
+    )
+    assert old.diff(new).breaking_changes() == []
+
+    new = Schema._from_field_name_and_types([("a", DataType.int16())])
+    assert [name for name, _ in old.diff(new).breaking_changes()] == ["a", "b"]